@@ -1,6 +1,7 @@
 """
 Build executor for Rust projects with cross-compilation support.
 """
+import hashlib
 import os
 import shutil
 import subprocess
@@ -172,6 +173,11 @@ class BuildExecutor:
                 shutil.copy2(result.binary_path, dest_path)
                 dest_path.chmod(0o755)
 
+                # Checksum verified by `cokacdir --self-update`
+                digest = hashlib.sha256(dest_path.read_bytes()).hexdigest()
+                checksum_path = self.dist_dir / f"{dest_name}.sha256"
+                checksum_path.write_text(f"{digest}  {dest_name}\n")
+
                 # Get file size
                 size = dest_path.stat().st_size
                 size_str = self._format_size(size)
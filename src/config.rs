@@ -1,12 +1,73 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io;
 use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use crate::ui::theme::{Theme, DEFAULT_THEME_NAME};
 use crate::services::remote::RemoteProfile;
+use crate::services::watch::WatchRule;
+use crate::services::archive_profile::ArchiveProfile;
+use crate::services::backup::BackupJob;
+use crate::services::file_ops::ConflictPolicy;
+use crate::utils::format::FormatSettings;
+use crate::services::webhook::Webhook;
+use crate::services::email::EmailSettings;
 use crate::keybindings::KeybindingsConfig;
 
+/// Built-in behavior an extension_handler entry can select with a `:` prefix
+/// (e.g. `{"log": [":view"]}`). Can be mixed with external commands as fallbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BuiltinAction {
+    /// Text/hex viewer
+    View,
+    Edit,
+    /// Image viewer
+    Image,
+    /// Extract the archive into a directory of its name
+    Extract,
+    /// File info screen
+    Info,
+}
+
+impl BuiltinAction {
+    pub const ALL: [BuiltinAction; 5] = [
+        BuiltinAction::View,
+        BuiltinAction::Edit,
+        BuiltinAction::Image,
+        BuiltinAction::Extract,
+        BuiltinAction::Info,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BuiltinAction::View => "view",
+            BuiltinAction::Edit => "edit",
+            BuiltinAction::Image => "image",
+            BuiltinAction::Extract => "extract",
+            BuiltinAction::Info => "info",
+        }
+    }
+
+    /// Handler entry for this action (":view")
+    pub fn handler(self) -> String {
+        format!(":{}", self.name())
+    }
+
+    /// Parse a handler entry. None for external commands and unknown names.
+    pub fn from_handler(handler: &str) -> Option<Self> {
+        let name = handler.trim().strip_prefix(':')?;
+        Self::ALL.into_iter().find(|a| a.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Next (or previous) action in `ALL`, wrapping around
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|&a| a == self).unwrap_or(0);
+        let next = if forward { (idx + 1) % len } else { (idx + len - 1) % len };
+        Self::ALL[next]
+    }
+}
+
 /// Panel-specific settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PanelSettings {
@@ -16,6 +77,9 @@ pub struct PanelSettings {
     pub sort_by: String,
     #[serde(default = "default_sort_order")]
     pub sort_order: String,
+    /// Show dotfiles in this panel
+    #[serde(default = "default_true")]
+    pub show_hidden: bool,
 }
 
 fn default_sort_by() -> String {
@@ -38,12 +102,19 @@ fn default_telegram_polling_time() -> u64 {
     3000
 }
 
+/// Maximum number of remembered "Open with" commands per extension
+const OPEN_WITH_HISTORY_MAX: usize = 5;
+
+/// Maximum number of entries in the recent files list
+const RECENT_FILES_MAX: usize = 50;
+
 impl Default for PanelSettings {
     fn default() -> Self {
         Self {
             start_path: None,
             sort_by: default_sort_by(),
             sort_order: default_sort_order(),
+            show_hidden: true,
         }
     }
 }
@@ -53,6 +124,10 @@ impl Default for PanelSettings {
 pub struct ThemeSettings {
     #[serde(default = "default_theme_name")]
     pub name: String,
+    /// Mark the cursor row (">"), marked files ("*") and the active panel with text,
+    /// not color alone (for colorblind users and screen readers)
+    #[serde(default)]
+    pub text_markers: bool,
 }
 
 fn default_theme_name() -> String {
@@ -63,10 +138,213 @@ impl Default for ThemeSettings {
     fn default() -> Self {
         Self {
             name: default_theme_name(),
+            text_markers: false,
+        }
+    }
+}
+
+/// Notifications for long-running jobs (copy, tar, download, AI)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationSettings {
+    /// Show a desktop notification (notify-send / osascript)
+    #[serde(default = "default_true")]
+    pub desktop: bool,
+    /// Ring the terminal bell
+    #[serde(default)]
+    pub bell: bool,
+    /// Only notify for jobs that ran at least this many seconds
+    #[serde(default = "default_notify_min_seconds")]
+    pub min_seconds: u64,
+    /// Slack/Discord/JSON webhooks notified when scheduled jobs and long file operations finish
+    /// Example: [{"url": "https://hooks.slack.com/services/..."}, {"url": "https://example.com/hook", "format": "json"}]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<Webhook>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_notify_min_seconds() -> u64 {
+    10
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            desktop: true,
+            bell: false,
+            min_seconds: default_notify_min_seconds(),
+            webhooks: Vec::new(),
         }
     }
 }
 
+/// Smallest/largest share of the screen the AI pane can take, in percent
+pub const AI_PANE_MIN_PERCENT: u16 = 20;
+pub const AI_PANE_MAX_PERCENT: u16 = 80;
+
+/// Size and orientation of the AI pane when it shares the screen with
+/// file panels, the editor or the viewer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AiPaneSettings {
+    /// Share of the screen taken by the AI pane, in percent
+    #[serde(default = "default_ai_pane_percent")]
+    pub percent: u16,
+    /// Stack the AI pane below/above the other panels instead of beside them
+    #[serde(default)]
+    pub vertical: bool,
+}
+
+fn default_ai_pane_percent() -> u16 {
+    50
+}
+
+impl Default for AiPaneSettings {
+    fn default() -> Self {
+        Self {
+            percent: default_ai_pane_percent(),
+            vertical: false,
+        }
+    }
+}
+
+/// Most favorite directories, one per jump key 1-9
+pub const FAVORITES_MAX: usize = 9;
+
+/// Numbered favorite directories shown in the favorites bar
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FavoritesSettings {
+    /// Show the favorites bar above the panels
+    #[serde(default)]
+    pub show_bar: bool,
+    /// Favorite number n is `paths[n - 1]`. Same format as `bookmarked_path`,
+    /// so remote locations (`user@host:/path`) work too
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+}
+
+impl FavoritesSettings {
+    /// Append `path`. Returns its number (1-based), or None if the list is full.
+    /// A path already in the list keeps its number
+    pub fn add(&mut self, path: String) -> Option<usize> {
+        if let Some(pos) = self.paths.iter().position(|p| *p == path) {
+            return Some(pos + 1);
+        }
+        if self.paths.len() >= FAVORITES_MAX {
+            return None;
+        }
+        self.paths.push(path);
+        Some(self.paths.len())
+    }
+
+    /// Swap the favorite at `index` with its neighbour above (`up`) or below.
+    /// Returns the entry's new index
+    pub fn move_entry(&mut self, index: usize, up: bool) -> usize {
+        let target = if up { index.checked_sub(1) } else { Some(index + 1) };
+        match target {
+            Some(target) if index < self.paths.len() && target < self.paths.len() => {
+                self.paths.swap(index, target);
+                target
+            }
+            _ => index,
+        }
+    }
+}
+
+impl AiPaneSettings {
+    /// Percent limited to AI_PANE_MIN_PERCENT..=AI_PANE_MAX_PERCENT
+    pub fn clamped_percent(&self) -> u16 {
+        self.percent.clamp(AI_PANE_MIN_PERCENT, AI_PANE_MAX_PERCENT)
+    }
+
+    /// Grow (positive) or shrink (negative) the AI pane
+    pub fn resize(&mut self, delta: i16) {
+        let percent = self.clamped_percent() as i16 + delta;
+        self.percent = percent.clamp(AI_PANE_MIN_PERCENT as i16, AI_PANE_MAX_PERCENT as i16) as u16;
+    }
+}
+
+/// Confirmation of destructive AI tool calls (Bash, Write, Edit)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiApprovalSettings {
+    /// Ask before each gated tool call (can be toggled per conversation)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Unanswered requests are denied after this many seconds
+    #[serde(default = "default_ai_approval_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_ai_approval_timeout() -> u64 {
+    60
+}
+
+impl Default for AiApprovalSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timeout_secs: default_ai_approval_timeout(),
+        }
+    }
+}
+
+/// A recently viewed or edited file and where it was left
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentFile {
+    pub path: String,
+    /// Last opened in the editor (false = viewer)
+    #[serde(default)]
+    pub edited: bool,
+    /// First visible line
+    #[serde(default)]
+    pub scroll: usize,
+    /// Cursor position (editor only)
+    #[serde(default)]
+    pub line: usize,
+    #[serde(default)]
+    pub col: usize,
+    /// Named marks (letter -> line)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub marks: BTreeMap<char, usize>,
+    /// Viewer search term, searched again when the file is reopened
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub search: String,
+}
+
+/// Saved advanced search criteria ("smart folder"), listed in the Jump dialog.
+/// Opening it searches `path` recursively and shows the matching files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SmartFolder {
+    pub name: String,
+    /// Directory searched
+    pub path: String,
+    /// Case-insensitive part of the file name
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pattern: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u64>,
+    /// Modified on or after this date (YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_after: Option<String>,
+    /// Modified on or before this date (YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_before: Option<String>,
+}
+
+/// Screens open at exit, restored on the next start when `restore_session` is on
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionState {
+    /// File open in the viewer or editor, with its position
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_file: Option<RecentFile>,
+    /// Panel index showing the AI assistant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_panel: Option<usize>,
+}
+
 /// Application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
@@ -78,6 +356,8 @@ pub struct Settings {
     /// Example: {"jpg": ["imageviewer {{FILEPATH}}", "imgviewer {{FILEPATH}}"]}
     /// Commands are tried in order until one succeeds (fallback)
     /// {{FILEPATH}} is replaced with the actual file path
+    /// Built-in actions: ":view", ":edit", ":image", ":extract", ":info"
+    /// Example: {"log|out": [":view"], "svg": [":edit"]}
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub extension_handler: HashMap<String, Vec<String>>,
     /// Bookmarked paths for quick navigation
@@ -104,6 +384,80 @@ pub struct Settings {
     /// Telegram API polling interval in milliseconds (minimum 2500, default 3000)
     #[serde(default = "default_telegram_polling_time")]
     pub telegram_polling_time: u64,
+    /// Commands recently chosen in the "Open with" dialog, per extension (most recent first)
+    /// Example: {"pdf": ["@evince \"{{FILEPATH}}\""]}
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub open_with_history: HashMap<String, Vec<String>>,
+    /// Desktop notification / bell when a long job finishes in the background
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+    /// Watch folder rules applied in the background while cokacdir runs (or with --watch)
+    /// Example: [{"dir": "~/Downloads", "pattern": "*.pdf", "action": "move", "dest": "~/Documents/PDF"}]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub watch_rules: Vec<WatchRule>,
+    /// SMTP delivery of results of `cokacdir --scheduler` jobs
+    /// Example: {"smtp_host": "smtp.example.com", "from": "me@example.com", "to": ["me@example.com"]}
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<EmailSettings>,
+    /// Presets offered in the Create Archive dialog (Tab cycles through them)
+    /// Example: [{"name": "backup", "format": "tar.xz", "level": 9, "exclude": ["node_modules", ".git"], "name_template": "{dir}-{date}"}]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archive_profiles: Vec<ArchiveProfile>,
+    /// How paste conflicts are resolved: "ask" (prompt), "overwrite", "skip",
+    /// "overwrite_if_newer" or "overwrite_if_larger"
+    #[serde(default)]
+    pub conflict_policy: ConflictPolicy,
+    /// Exclude patterns last used with "Paste excluding" (prefilled in its dialog)
+    /// Example: ["target/", "node_modules/", "*.o"]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paste_exclude: Vec<String>,
+    /// Per-call confirmation of Bash/Write/Edit in the AI screen
+    /// Example: {"enabled": true, "timeout_secs": 60}
+    #[serde(default)]
+    pub ai_approval: AiApprovalSettings,
+    /// AI pane split when it shares the screen (resize with Alt+Left/Right, toggle with Alt+L)
+    /// Example: {"percent": 40, "vertical": false}
+    #[serde(default)]
+    pub ai_pane: AiPaneSettings,
+    /// Numbered favorite directories (Alt+1..Alt+9) and the bar listing them (Alt+B)
+    /// Example: {"show_bar": true, "paths": ["/home/me/projects", "/var/log"]}
+    #[serde(default)]
+    pub favorites: FavoritesSettings,
+    /// Saved advanced searches (save with Ctrl+S in the Advanced Search dialog)
+    /// Example: [{"name": "Big logs", "path": "/var/log", "pattern": ".log", "min_size": 10485760}]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub smart_folders: Vec<SmartFolder>,
+    /// Backup jobs (managed from the Backups screen; scheduled ones run by `cokacdir --scheduler`)
+    /// Example: [{"name": "docs", "source": "/home/me/docs", "destination": "/mnt/usb/docs", "exclude": ["*.tmp"], "schedule": "0 2 * * *"}]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backups: Vec<BackupJob>,
+    /// Recently viewed/edited files with their positions (most recent first)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_files: Vec<RecentFile>,
+    /// Reopen the viewer/editor and AI panel from the last session on startup
+    /// (panel paths are always restored)
+    #[serde(default)]
+    pub restore_session: bool,
+    /// Session saved on exit (only when restore_session is on)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<SessionState>,
+    /// Local deletes move files to the trash instead of removing them (toggle with Alt+D)
+    #[serde(default)]
+    pub delete_to_trash: bool,
+    /// Measure directories in the background and show their sizes in the
+    /// panels' Size column (toggle with Alt+Z)
+    #[serde(default)]
+    pub index_dir_sizes: bool,
+    /// Show git status badges (M/A/D/??/!!) next to entries inside a work tree
+    #[serde(default)]
+    pub git_status_badges: bool,
+    /// Check for new versions on exit and allow --self-update (off for air-gapped machines)
+    #[serde(default = "default_true")]
+    pub check_updates: bool,
+    /// Size units, clock, date order and digit grouping ("auto" follows the locale)
+    /// Example: {"size_units": "si", "clock": "12h", "date_order": "dmy", "digit_grouping": "comma"}
+    #[serde(default)]
+    pub format: FormatSettings,
 }
 
 impl Default for Settings {
@@ -146,6 +500,26 @@ impl Default for Settings {
             keybindings: KeybindingsConfig::default(),
             encrypt_split_size: default_encrypt_split_size(),
             telegram_polling_time: default_telegram_polling_time(),
+            open_with_history: HashMap::new(),
+            notifications: NotificationSettings::default(),
+            watch_rules: Vec::new(),
+            email: None,
+            archive_profiles: Vec::new(),
+            conflict_policy: ConflictPolicy::default(),
+            paste_exclude: Vec::new(),
+            ai_approval: AiApprovalSettings::default(),
+            ai_pane: AiPaneSettings::default(),
+            favorites: FavoritesSettings::default(),
+            smart_folders: Vec::new(),
+            backups: Vec::new(),
+            recent_files: Vec::new(),
+            restore_session: false,
+            session: None,
+            delete_to_trash: false,
+            index_dir_sizes: false,
+            git_status_badges: false,
+            check_updates: true,
+            format: FormatSettings::default(),
         }
     }
 }
@@ -207,6 +581,16 @@ impl Settings {
             if !dawn_theme_path.exists() {
                 let _ = fs::write(&dawn_theme_path, Theme::dawn_of_coding().to_json());
             }
+
+            // Create accessible "high_contrast.json" / "monochrome.json" if not exists
+            let high_contrast_path = themes_dir.join("high_contrast.json");
+            if !high_contrast_path.exists() {
+                let _ = fs::write(&high_contrast_path, Theme::high_contrast().to_json());
+            }
+            let monochrome_path = themes_dir.join("monochrome.json");
+            if !monochrome_path.exists() {
+                let _ = fs::write(&monochrome_path, Theme::monochrome().to_json());
+            }
         }
 
         // Create default settings.json if not exists
@@ -325,6 +709,44 @@ impl Settings {
         }
         None
     }
+
+    /// Extensions mapped to a single built-in action (edited in the Settings dialog),
+    /// sorted by extension key
+    pub fn builtin_extension_actions(&self) -> Vec<(String, BuiltinAction)> {
+        let mut actions: Vec<(String, BuiltinAction)> = self
+            .extension_handler
+            .iter()
+            .filter_map(|(ext, handlers)| match handlers.as_slice() {
+                [only] => BuiltinAction::from_handler(only).map(|a| (ext.clone(), a)),
+                _ => None,
+            })
+            .collect();
+        actions.sort();
+        actions
+    }
+
+    /// Record a command chosen in the "Open with" dialog for an extension
+    pub fn add_open_with_history(&mut self, extension: &str, command: &str) {
+        let list = self.open_with_history.entry(extension.to_lowercase()).or_default();
+        list.retain(|c| c != command);
+        list.insert(0, command.to_string());
+        list.truncate(OPEN_WITH_HISTORY_MAX);
+    }
+
+    /// Save a smart folder, replacing one with the same name
+    pub fn save_smart_folder(&mut self, folder: SmartFolder) {
+        match self.smart_folders.iter_mut().find(|f| f.name == folder.name) {
+            Some(existing) => *existing = folder,
+            None => self.smart_folders.push(folder),
+        }
+    }
+
+    /// Move a file to the top of the recent files list, replacing its previous entry
+    pub fn add_recent_file(&mut self, entry: RecentFile) {
+        self.recent_files.retain(|f| f.path != entry.path);
+        self.recent_files.insert(0, entry);
+        self.recent_files.truncate(RECENT_FILES_MAX);
+    }
 }
 
 #[cfg(test)]
@@ -341,12 +763,159 @@ mod tests {
         assert_eq!(settings.theme.name, DEFAULT_THEME_NAME);
     }
 
+    #[test]
+    fn test_builtin_extension_actions() {
+        assert_eq!(BuiltinAction::from_handler(":View"), Some(BuiltinAction::View));
+        assert_eq!(BuiltinAction::from_handler("vim {{FILEPATH}}"), None);
+        assert_eq!(BuiltinAction::from_handler(":open"), None);
+        assert_eq!(BuiltinAction::Info.cycle(true), BuiltinAction::View);
+
+        let mut settings = Settings::default();
+        settings.extension_handler.insert("log|out".to_string(), vec![":view".to_string()]);
+        settings.extension_handler.insert("svg".to_string(), vec![":edit".to_string(), "inkscape {{FILEPATH}}".to_string()]);
+        assert_eq!(settings.builtin_extension_actions(), vec![("log|out".to_string(), BuiltinAction::View)]);
+    }
+
     #[test]
     fn test_parse_partial_json() {
         let json = r#"{"panels":[{"start_path":"/tmp"}]}"#;
         let settings: Settings = serde_json::from_str(json).unwrap();
         assert_eq!(settings.panels[0].start_path, Some("/tmp".to_string()));
         assert_eq!(settings.panels[0].sort_by, "name");
+        assert!(settings.notifications.desktop);
+        assert!(!settings.notifications.bell);
+    }
+
+    #[test]
+    fn test_open_with_history() {
+        let mut settings = Settings::default();
+        settings.add_open_with_history("PDF", "@evince \"{{FILEPATH}}\"");
+        settings.add_open_with_history("pdf", "@okular \"{{FILEPATH}}\"");
+        settings.add_open_with_history("pdf", "@evince \"{{FILEPATH}}\"");
+        let list = &settings.open_with_history["pdf"];
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0], "@evince \"{{FILEPATH}}\"");
+
+        for i in 0..10 {
+            settings.add_open_with_history("pdf", &format!("cmd{}", i));
+        }
+        assert_eq!(settings.open_with_history["pdf"].len(), OPEN_WITH_HISTORY_MAX);
+        assert_eq!(settings.open_with_history["pdf"][0], "cmd9");
+    }
+
+    #[test]
+    fn test_recent_files() {
+        let mut settings = Settings::default();
+        let entry = |path: &str, line: usize| RecentFile {
+            path: path.to_string(),
+            edited: false,
+            scroll: 0,
+            line,
+            col: 0,
+            marks: BTreeMap::new(),
+            search: String::new(),
+        };
+        settings.add_recent_file(entry("/a", 1));
+        settings.add_recent_file(entry("/b", 2));
+        settings.add_recent_file(entry("/a", 3));
+        assert_eq!(settings.recent_files.len(), 2);
+        assert_eq!(settings.recent_files[0], entry("/a", 3));
+
+        for i in 0..RECENT_FILES_MAX + 5 {
+            settings.add_recent_file(entry(&format!("/f{}", i), 0));
+        }
+        assert_eq!(settings.recent_files.len(), RECENT_FILES_MAX);
+
+        // The viewer search term is kept, and omitted from the file when empty
+        let mut searched = entry("/log", 0);
+        searched.search = "ERROR".to_string();
+        let json = serde_json::to_string(&searched).unwrap();
+        assert_eq!(serde_json::from_str::<RecentFile>(&json).unwrap(), searched);
+        assert!(!serde_json::to_string(&entry("/a", 0)).unwrap().contains("search"));
+    }
+
+    #[test]
+    fn test_session_round_trip() {
+        let mut settings = Settings::default();
+        assert!(!settings.restore_session);
+        settings.restore_session = true;
+        settings.session = Some(SessionState {
+            open_file: Some(RecentFile {
+                path: "/tmp/a.rs".to_string(),
+                edited: true,
+                scroll: 3,
+                line: 10,
+                col: 4,
+                marks: BTreeMap::from([('a', 2), ('Z', 40)]),
+                search: String::new(),
+            }),
+            ai_panel: Some(1),
+        });
+        let json = serde_json::to_string(&settings).unwrap();
+        let loaded: Settings = serde_json::from_str(&json).unwrap();
+        assert!(loaded.restore_session);
+        assert_eq!(loaded.session, settings.session);
+    }
+
+    #[test]
+    fn test_ai_pane_resize() {
+        let mut pane: AiPaneSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(pane, AiPaneSettings::default());
+        pane.resize(5);
+        assert_eq!(pane.percent, 55);
+        pane.resize(100);
+        assert_eq!(pane.percent, AI_PANE_MAX_PERCENT);
+        pane.percent = 3;
+        assert_eq!(pane.clamped_percent(), AI_PANE_MIN_PERCENT);
+        pane.resize(-5);
+        assert_eq!(pane.percent, AI_PANE_MIN_PERCENT);
+    }
+
+    #[test]
+    fn test_favorites() {
+        let mut favorites: FavoritesSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(favorites, FavoritesSettings::default());
+        assert_eq!(favorites.add("/a".to_string()), Some(1));
+        assert_eq!(favorites.add("/b".to_string()), Some(2));
+        // Adding again keeps the existing number
+        assert_eq!(favorites.add("/a".to_string()), Some(1));
+        assert_eq!(favorites.paths, vec!["/a", "/b"]);
+
+        assert_eq!(favorites.move_entry(1, true), 0);
+        assert_eq!(favorites.paths, vec!["/b", "/a"]);
+        assert_eq!(favorites.move_entry(0, true), 0);
+        assert_eq!(favorites.move_entry(1, false), 1);
+
+        for i in 0..FAVORITES_MAX {
+            favorites.add(format!("/f{}", i));
+        }
+        assert_eq!(favorites.paths.len(), FAVORITES_MAX);
+        assert_eq!(favorites.add("/full".to_string()), None);
+    }
+
+    #[test]
+    fn test_smart_folders() {
+        let mut settings = Settings::default();
+        let folder = |name: &str, min_size: Option<u64>| SmartFolder {
+            name: name.to_string(),
+            path: "/var/log".to_string(),
+            pattern: ".log".to_string(),
+            min_size,
+            max_size: None,
+            modified_after: Some("2024-01-01".to_string()),
+            modified_before: None,
+        };
+        settings.save_smart_folder(folder("Big logs", Some(1024)));
+        settings.save_smart_folder(folder("Recent logs", None));
+        // Same name replaces the saved criteria
+        settings.save_smart_folder(folder("Big logs", Some(4096)));
+        assert_eq!(settings.smart_folders.len(), 2);
+        assert_eq!(settings.smart_folders[0].min_size, Some(4096));
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(!json.contains("max_size"));
+        let loaded: Settings = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.smart_folders, settings.smart_folders);
     }
 
     #[test]
@@ -359,6 +928,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_accessible_themes_round_trip() {
+        assert_eq!(Theme::high_contrast().name(), "high_contrast");
+        assert_eq!(Theme::monochrome().name(), "monochrome");
+        for theme in [Theme::high_contrast(), Theme::monochrome()] {
+            let json: crate::ui::theme_loader::ThemeJson = serde_json::from_str(&theme.to_json()).unwrap();
+            let loaded = crate::ui::theme_loader::theme_from_json(&json);
+            assert_eq!(loaded.name(), theme.name());
+            assert_eq!(loaded.panel.selected_text, theme.panel.selected_text);
+            assert_eq!(loaded.editor.match_bg, theme.editor.match_bg);
+        }
+        let monochrome = Theme::monochrome();
+        assert_ne!(monochrome.panel.file_text, monochrome.panel.selected_text);
+        assert_ne!(monochrome.dialog.button_selected_bg, monochrome.dialog.button_selected_text);
+    }
+
     #[test]
     fn test_theme_to_json() {
         let json = Theme::light().to_json();
@@ -253,6 +253,10 @@ pub enum PanelAction {
     MoveDown,
     PageUp,
     PageDown,
+    HalfPageUp,
+    HalfPageDown,
+    ThirdPageUp,
+    ThirdPageDown,
     GoHome,
     GoEnd,
     Open,
@@ -263,11 +267,16 @@ pub enum PanelAction {
     ToggleSelect,
     SelectAll,
     SelectByExtension,
+    InvertSelection,
+    SelectByFilter,
+    Calculator,
     SelectUp,
     SelectDown,
     Copy,
     Cut,
     Paste,
+    PasteExcluding,
+    PasteImage,
     SortByName,
     SortByType,
     SortBySize,
@@ -295,15 +304,89 @@ pub enum PanelAction {
     GitScreen,
     ToggleBookmark,
     SetHandler,
+    OpenWith,
+    RecentFiles,
+    CopyContents,
+    EditInSplit,
+    EditInWindow,
+    ShellInSplit,
+    ShellInWindow,
     EncryptAll,
     DecryptAll,
     RemoveDuplicates,
+    Checksums,
+    CompareChecksums,
+    AuditLog,
+    QuickView,
+    CompareClipboard,
+    CopyShellCommand,
+    TrashScreen,
+    ToggleDeleteToTrash,
+    Jobs,
+    ToggleDirSizes,
+    QuickFilter,
+    ToggleHidden,
+    GoToLinkTarget,
+    SymlinkToOtherPanel,
+    BulkRename,
+    ChangeExtension,
+    UndoExtensionChange,
+    TogglePreviewPane,
+    DrivesScreen,
+    CleanupScreen,
+    Backups,
+    RecentChanges,
+    JumpDirectory,
+    PathMenu,
+    TreeSidebar,
+    AdvancedSearch,
+    ToggleFavoritesBar,
+    Favorites,
+    #[serde(rename = "favorite_1")]
+    Favorite1,
+    #[serde(rename = "favorite_2")]
+    Favorite2,
+    #[serde(rename = "favorite_3")]
+    Favorite3,
+    #[serde(rename = "favorite_4")]
+    Favorite4,
+    #[serde(rename = "favorite_5")]
+    Favorite5,
+    #[serde(rename = "favorite_6")]
+    Favorite6,
+    #[serde(rename = "favorite_7")]
+    Favorite7,
+    #[serde(rename = "favorite_8")]
+    Favorite8,
+    #[serde(rename = "favorite_9")]
+    Favorite9,
+    AddToBasket,
+    Basket,
     #[cfg(target_os = "macos")]
     OpenInFinder,
     #[cfg(target_os = "macos")]
     OpenInVSCode,
 }
 
+impl PanelAction {
+    /// Favorite number (1-9) jumped to by a `Favorite*` action
+    pub fn favorite_number(self) -> Option<usize> {
+        let n = match self {
+            PanelAction::Favorite1 => 1,
+            PanelAction::Favorite2 => 2,
+            PanelAction::Favorite3 => 3,
+            PanelAction::Favorite4 => 4,
+            PanelAction::Favorite5 => 5,
+            PanelAction::Favorite6 => 6,
+            PanelAction::Favorite7 => 7,
+            PanelAction::Favorite8 => 8,
+            PanelAction::Favorite9 => 9,
+            _ => return None,
+        };
+        Some(n)
+    }
+}
+
 /// Default keybindings for FilePanel (matches the original hardcoded keys).
 pub fn default_panel_keybindings() -> HashMap<PanelAction, Vec<String>> {
     let mut m = HashMap::new();
@@ -319,6 +402,10 @@ pub fn default_panel_keybindings() -> HashMap<PanelAction, Vec<String>> {
     m.insert(PanelAction::MoveDown, vec!["//Move cursor down".into(), "down".into()]);
     m.insert(PanelAction::PageUp, vec!["//Page up (10 lines)".into(), "pageup".into()]);
     m.insert(PanelAction::PageDown, vec!["//Page down (10 lines)".into(), "pagedown".into()]);
+    m.insert(PanelAction::HalfPageUp, vec!["//Scroll up half a page".into(), "ctrl+u".into()]);
+    m.insert(PanelAction::HalfPageDown, vec!["//Scroll down half a page".into(), "ctrl+d".into()]);
+    m.insert(PanelAction::ThirdPageUp, vec!["//Scroll up a third of a page".into(), "alt+up".into()]);
+    m.insert(PanelAction::ThirdPageDown, vec!["//Scroll down a third of a page".into(), "alt+down".into()]);
     m.insert(PanelAction::GoHome, vec!["//Go to first item".into(), "home".into()]);
     m.insert(PanelAction::GoEnd, vec!["//Go to last item".into(), "end".into()]);
     m.insert(PanelAction::Open, vec!["//Open file or enter directory".into(), "enter".into()]);
@@ -337,6 +424,9 @@ pub fn default_panel_keybindings() -> HashMap<PanelAction, Vec<String>> {
     m.insert(PanelAction::ToggleSelect, vec!["//Toggle file selection".into(), "space".into()]);
     m.insert(PanelAction::SelectAll, vec!["//Select/deselect all".into(), "*".into(), "ctrl+a".into()]);
     m.insert(PanelAction::SelectByExtension, vec!["//Select by extension".into(), ";".into()]);
+    m.insert(PanelAction::InvertSelection, vec!["//Invert selection".into(), "alt+i".into()]);
+    m.insert(PanelAction::SelectByFilter, vec!["//Select files by age or size (newer 7d, larger 10M)".into(), "alt+f".into()]);
+    m.insert(PanelAction::Calculator, vec!["//Calculator with byte-unit conversion (1.5 GiB in MB)".into(), "alt+=".into()]);
     m.insert(PanelAction::SelectUp, vec!["//Select and move up".into(), "shift+up".into()]);
     m.insert(PanelAction::SelectDown, vec!["//Select and move down".into(), "shift+down".into()]);
 
@@ -344,6 +434,8 @@ pub fn default_panel_keybindings() -> HashMap<PanelAction, Vec<String>> {
     m.insert(PanelAction::Copy, vec!["//Copy selected files".into(), "ctrl+c".into()]);
     m.insert(PanelAction::Cut, vec!["//Cut selected files".into(), "ctrl+x".into()]);
     m.insert(PanelAction::Paste, vec!["//Paste files".into(), "ctrl+v".into(), "shift+v".into()]);
+    m.insert(PanelAction::PasteExcluding, vec!["//Paste files, skipping entries that match exclude patterns".into(), "alt+shift+v".into()]);
+    m.insert(PanelAction::PasteImage, vec!["//Save clipboard image as PNG file".into(), "alt+v".into()]);
 
     // Sort
     m.insert(PanelAction::SortByName, vec!["//Sort by name".into(), "n".into()]);
@@ -360,7 +452,11 @@ pub fn default_panel_keybindings() -> HashMap<PanelAction, Vec<String>> {
     m.insert(PanelAction::Rename, vec!["//Rename file".into(), "r".into()]);
     m.insert(PanelAction::Tar, vec!["//Archive (tar)".into(), "t".into()]);
     m.insert(PanelAction::Search, vec!["//Search files".into(), "f".into()]);
+    m.insert(PanelAction::AdvancedSearch, vec!["//Advanced search: select by name, size and date; save as smart folder".into(), "shift+f".into()]);
     m.insert(PanelAction::SetHandler, vec!["//Set extension handler".into(), "u".into()]);
+    m.insert(PanelAction::OpenWith, vec!["//Open with application".into(), "w".into()]);
+    m.insert(PanelAction::RecentFiles, vec!["//Recently viewed/edited files".into(), "shift+r".into()]);
+    m.insert(PanelAction::CopyContents, vec!["//Copy file contents to system clipboard".into(), "shift+y".into()]);
 
     // Tools
     m.insert(PanelAction::ProcessManager, vec!["//Process manager".into(), "p".into()]);
@@ -369,13 +465,59 @@ pub fn default_panel_keybindings() -> HashMap<PanelAction, Vec<String>> {
 
     // Git / Diff
     m.insert(PanelAction::GitScreen, vec!["//Git screen".into(), "g".into()]);
-    m.insert(PanelAction::GitLogDiff, vec!["//Git log diff".into(), "7".into()]);
+    m.insert(PanelAction::GitLogDiff, vec!["//Git log browser (commit graph, diffs)".into(), "7".into()]);
     m.insert(PanelAction::StartDiff, vec!["//Start diff".into(), "8".into()]);
 
     // Encryption
     m.insert(PanelAction::EncryptAll, vec!["//Encrypt all files in directory".into(), "shift+e".into()]);
     m.insert(PanelAction::DecryptAll, vec!["//Decrypt all .cokacenc files".into(), "shift+d".into()]);
     m.insert(PanelAction::RemoveDuplicates, vec!["//Remove duplicate files".into(), "shift+x".into()]);
+    m.insert(PanelAction::Checksums, vec!["//Calculate checksums (or verify a .sha256sum manifest)".into(), "shift+c".into()]);
+    m.insert(PanelAction::CompareChecksums, vec!["//Compare two files by checksum".into(), "alt+c".into()]);
+    m.insert(PanelAction::AuditLog, vec!["//Telegram bot audit log".into(), "alt+a".into()]);
+    m.insert(PanelAction::QuickView, vec!["//Quick view file under cursor (reuses the open viewer)".into(), "f3".into(), "alt+q".into()]);
+    m.insert(PanelAction::CompareClipboard, vec!["//Diff file under cursor against clipboard text".into(), "alt+shift+c".into()]);
+    m.insert(PanelAction::CopyShellCommand, vec!["//Copy cd/cp shell command for the selection".into(), "alt+y".into()]);
+    m.insert(PanelAction::TrashScreen, vec!["//Trash: restore or permanently delete trashed files".into(), "alt+t".into()]);
+    m.insert(PanelAction::ToggleDeleteToTrash, vec!["//Toggle delete to trash / delete permanently".into(), "alt+d".into()]);
+    m.insert(PanelAction::Jobs, vec!["//Background jobs pane: expand/focus, collapse".into(), "alt+j".into()]);
+    m.insert(PanelAction::ToggleDirSizes, vec!["//Toggle directory sizes in the Size column".into(), "alt+z".into()]);
+    m.insert(PanelAction::QuickFilter, vec!["//Filter the list as you type (Esc restores)".into(), "ctrl+f".into()]);
+    m.insert(PanelAction::ToggleHidden, vec!["//Show/hide dotfiles in this panel".into(), "alt+h".into()]);
+    m.insert(PanelAction::GoToLinkTarget, vec!["//Go to the target of the symlink under cursor".into(), "alt+l".into()]);
+    m.insert(PanelAction::SymlinkToOtherPanel, vec!["//Create symlinks to the selection in the other panel".into(), "alt+k".into()]);
+    m.insert(PanelAction::BulkRename, vec!["//Bulk rename selected files (pattern, regex, case)".into(), "alt+r".into()]);
+    m.insert(PanelAction::ChangeExtension, vec!["//Change the extension of selected files (jpeg → jpg)".into(), "alt+shift+r".into()]);
+    m.insert(PanelAction::UndoExtensionChange, vec!["//Undo the last extension change".into(), "ctrl+z".into()]);
+    m.insert(PanelAction::TogglePreviewPane, vec!["//Quick view: preview the file under cursor in the other panel".into(), "alt+p".into()]);
+    m.insert(PanelAction::DrivesScreen, vec!["//Drives: mounted filesystems with free space, go to a mount point".into(), "alt+m".into()]);
+    m.insert(PanelAction::CleanupScreen, vec!["//Storage cleanup: review and delete space hogs under the current folder".into(), "alt+u".into()]);
+    m.insert(PanelAction::Backups, vec!["//Backups: manage scheduled incremental backup jobs".into(), "alt+n".into()]);
+    m.insert(PanelAction::RecentChanges, vec!["//Recent changes: most recently modified files under the current folder".into(), "alt+shift+t".into()]);
+    m.insert(PanelAction::JumpDirectory, vec!["//Jump to a frequently visited directory (type a fragment)".into(), "alt+g".into()]);
+    m.insert(PanelAction::PathMenu, vec!["//Path menu: jump to an ancestor or a sibling folder".into(), "alt+shift+p".into()]);
+    m.insert(PanelAction::TreeSidebar, vec!["//Directory tree sidebar: show and focus, hide".into(), "ctrl+t".into()]);
+
+    // Favorites
+    m.insert(PanelAction::ToggleFavoritesBar, vec!["//Show/hide the favorites bar".into(), "alt+b".into()]);
+    m.insert(PanelAction::Favorites, vec!["//Edit favorite directories (add, remove, reorder)".into(), "alt+o".into()]);
+    m.insert(PanelAction::Favorite1, vec!["//Go to favorite directory 1".into(), "alt+1".into()]);
+    m.insert(PanelAction::Favorite2, vec!["//Go to favorite directory 2".into(), "alt+2".into()]);
+    m.insert(PanelAction::Favorite3, vec!["//Go to favorite directory 3".into(), "alt+3".into()]);
+    m.insert(PanelAction::Favorite4, vec!["//Go to favorite directory 4".into(), "alt+4".into()]);
+    m.insert(PanelAction::Favorite5, vec!["//Go to favorite directory 5".into(), "alt+5".into()]);
+    m.insert(PanelAction::Favorite6, vec!["//Go to favorite directory 6".into(), "alt+6".into()]);
+    m.insert(PanelAction::Favorite7, vec!["//Go to favorite directory 7".into(), "alt+7".into()]);
+    m.insert(PanelAction::Favorite8, vec!["//Go to favorite directory 8".into(), "alt+8".into()]);
+    m.insert(PanelAction::Favorite9, vec!["//Go to favorite directory 9".into(), "alt+9".into()]);
+    m.insert(PanelAction::AddToBasket, vec!["//Add selected files to the basket (collect from several folders)".into(), "alt+x".into()]);
+    m.insert(PanelAction::Basket, vec!["//Basket: copy, move or zip the collected files here".into(), "alt+w".into()]);
+
+    // Terminal multiplexer (tmux / zellij)
+    m.insert(PanelAction::EditInSplit, vec!["//Edit file in split pane".into(), "alt+e".into()]);
+    m.insert(PanelAction::EditInWindow, vec!["//Edit file in new window".into(), "alt+shift+e".into()]);
+    m.insert(PanelAction::ShellInSplit, vec!["//Open shell in split pane".into(), "alt+s".into()]);
+    m.insert(PanelAction::ShellInWindow, vec!["//Open shell in new window".into(), "alt+shift+s".into()]);
 
     // macOS only
     #[cfg(target_os = "macos")]
@@ -398,6 +540,7 @@ pub fn default_panel_keybindings() -> HashMap<PanelAction, Vec<String>> {
 #[serde(rename_all = "snake_case")]
 pub enum EditorAction {
     Save,
+    SudoSave,
     Cut,
     Undo,
     Redo,
@@ -420,6 +563,8 @@ pub enum EditorAction {
     Find,
     Replace,
     GotoLine,
+    SetMark,
+    JumpToMark,
     GoToFileStart,
     GoToFileEnd,
     MoveLineUp,
@@ -433,6 +578,7 @@ pub fn default_editor_keybindings() -> HashMap<EditorAction, Vec<String>> {
 
     // File
     m.insert(EditorAction::Save, vec!["//Save file".into(), "ctrl+s".into()]);
+    m.insert(EditorAction::SudoSave, vec!["//Save with elevated privileges (sudo/pkexec)".into(), "alt+s".into()]);
 
     // Clipboard & selection
     m.insert(EditorAction::Cut, vec!["//Cut (line if no selection)".into(), "ctrl+x".into()]);
@@ -465,6 +611,8 @@ pub fn default_editor_keybindings() -> HashMap<EditorAction, Vec<String>> {
     m.insert(EditorAction::Find, vec!["//Find".into(), "ctrl+f".into()]);
     m.insert(EditorAction::Replace, vec!["//Find and replace".into(), "ctrl+h".into()]);
     m.insert(EditorAction::GotoLine, vec!["//Go to line".into(), "ctrl+g".into()]);
+    m.insert(EditorAction::SetMark, vec!["//Set mark (then a letter)".into(), "alt+m".into()]);
+    m.insert(EditorAction::JumpToMark, vec!["//Jump to mark (then a letter)".into(), "alt+j".into()]);
     m.insert(EditorAction::GoToFileStart, vec!["//Go to file start".into(), "ctrl+home".into(), "ctrl+shift+home".into()]);
     m.insert(EditorAction::GoToFileEnd, vec!["//Go to file end".into(), "ctrl+end".into(), "ctrl+shift+end".into()]);
 
@@ -484,11 +632,15 @@ pub fn default_editor_keybindings() -> HashMap<EditorAction, Vec<String>> {
 #[serde(rename_all = "snake_case")]
 pub enum FileInfoAction {
     Close,
+    Apply,
+    Recalculate,
 }
 
 pub fn default_file_info_keybindings() -> HashMap<FileInfoAction, Vec<String>> {
     let mut m = HashMap::new();
-    m.insert(FileInfoAction::Close, vec!["//Close file info".into(), "esc".into()]);
+    m.insert(FileInfoAction::Close, vec!["//Close file info (discard changes)".into(), "esc".into()]);
+    m.insert(FileInfoAction::Apply, vec!["//Apply property changes".into(), "enter".into()]);
+    m.insert(FileInfoAction::Recalculate, vec!["//Recalculate a cached directory size".into(), "f5".into()]);
     m
 }
 
@@ -549,6 +701,7 @@ pub enum AdvancedSearchAction {
     Submit,
     MoveUp,
     MoveDown,
+    SaveSmartFolder,
 }
 
 pub fn default_advanced_search_keybindings() -> HashMap<AdvancedSearchAction, Vec<String>> {
@@ -557,6 +710,7 @@ pub fn default_advanced_search_keybindings() -> HashMap<AdvancedSearchAction, Ve
     m.insert(AdvancedSearchAction::Submit, vec!["//Submit search".into(), "enter".into()]);
     m.insert(AdvancedSearchAction::MoveUp, vec!["//Previous field".into(), "up".into()]);
     m.insert(AdvancedSearchAction::MoveDown, vec!["//Next field".into(), "down".into(), "tab".into()]);
+    m.insert(AdvancedSearchAction::SaveSmartFolder, vec!["//Save the criteria as a smart folder".into(), "ctrl+s".into()]);
     m
 }
 
@@ -611,6 +765,7 @@ pub enum DiffScreenAction {
     SortByType,
     ExpandAll,
     CollapseAll,
+    Sync,
     Open,
     Close,
 }
@@ -642,6 +797,9 @@ pub fn default_diff_screen_keybindings() -> HashMap<DiffScreenAction, Vec<String
     m.insert(DiffScreenAction::ExpandAll, vec!["//Expand all".into(), "e".into()]);
     m.insert(DiffScreenAction::CollapseAll, vec!["//Collapse all".into(), "c".into()]);
 
+    // Sync mode: choose a direction per item, preview, then run
+    m.insert(DiffScreenAction::Sync, vec!["//Synchronize the two sides".into(), "shift+s".into()]);
+
     // Actions
     m.insert(DiffScreenAction::Open, vec!["//View file diff / toggle dir".into(), "enter".into()]);
     m.insert(DiffScreenAction::Close, vec!["//Return to file panel".into(), "esc".into()]);
@@ -671,11 +829,18 @@ pub enum ViewerAction {
     ToggleWrap,
     ToggleHex,
     GotoLine,
+    ToggleLineSelection,
+    CopyToClipboard,
+    SetMark,
+    JumpToMark,
+    NextFile,
+    PrevFile,
+    DownloadFull,
 }
 
 pub fn default_viewer_keybindings() -> HashMap<ViewerAction, Vec<String>> {
     let mut m = HashMap::new();
-    m.insert(ViewerAction::Quit, vec!["//Close viewer".into(), "esc".into(), "ctrl+q".into()]);
+    m.insert(ViewerAction::Quit, vec!["//Close viewer".into(), "esc".into(), "ctrl+q".into(), "f3".into()]);
     m.insert(ViewerAction::Edit, vec!["//Open in editor".into(), "e".into()]);
     m.insert(ViewerAction::ScrollUp, vec!["//Scroll up".into(), "up".into(), "k".into()]);
     m.insert(ViewerAction::ScrollDown, vec!["//Scroll down".into(), "down".into(), "j".into()]);
@@ -692,6 +857,13 @@ pub fn default_viewer_keybindings() -> HashMap<ViewerAction, Vec<String>> {
     m.insert(ViewerAction::ToggleWrap, vec!["//Toggle word wrap".into(), "w".into()]);
     m.insert(ViewerAction::ToggleHex, vec!["//Toggle hex mode".into(), "h".into(), "shift+h".into()]);
     m.insert(ViewerAction::GotoLine, vec!["//Go to line".into(), "ctrl+g".into(), ":".into()]);
+    m.insert(ViewerAction::ToggleLineSelection, vec!["//Start/cancel line selection".into(), "v".into()]);
+    m.insert(ViewerAction::CopyToClipboard, vec!["//Copy selection or file to system clipboard".into(), "y".into()]);
+    m.insert(ViewerAction::SetMark, vec!["//Set mark (then a letter)".into(), "m".into()]);
+    m.insert(ViewerAction::JumpToMark, vec!["//Jump to mark (then a letter)".into(), "'".into(), "`".into()]);
+    m.insert(ViewerAction::NextFile, vec!["//View next file of the panel".into(), "alt+down".into(), "ctrl+n".into()]);
+    m.insert(ViewerAction::PrevFile, vec!["//View previous file of the panel".into(), "alt+up".into(), "ctrl+p".into()]);
+    m.insert(ViewerAction::DownloadFull, vec!["//Download the full file of a remote preview".into(), "d".into()]);
     m
 }
 
@@ -712,6 +884,8 @@ pub enum ImageViewerAction {
     NextImage,
     ToggleSelect,
     Delete,
+    SaveAs,
+    Resize,
 }
 
 pub fn default_image_viewer_keybindings() -> HashMap<ImageViewerAction, Vec<String>> {
@@ -728,6 +902,8 @@ pub fn default_image_viewer_keybindings() -> HashMap<ImageViewerAction, Vec<Stri
     m.insert(ImageViewerAction::NextImage, vec!["//Next image".into(), "pagedown".into(), "shift+down".into()]);
     m.insert(ImageViewerAction::ToggleSelect, vec!["//Select image".into(), "space".into()]);
     m.insert(ImageViewerAction::Delete, vec!["//Delete image".into(), "delete".into(), "backspace".into()]);
+    m.insert(ImageViewerAction::SaveAs, vec!["//Save as PNG/JPEG/WebP".into(), "s".into()]);
+    m.insert(ImageViewerAction::Resize, vec!["//Save a resized copy".into(), "shift+r".into()]);
     m
 }
 
@@ -798,6 +974,17 @@ pub enum AIScreenAction {
     DeleteWordLeft,
     ClearHistory,
     ToggleFullscreen,
+    RunInBackground,
+    NewConversation,
+    NextConversation,
+    ResponseActions,
+    ToggleToolTimeline,
+    ToggleApproval,
+    ChangeDirectory,
+    SearchSessions,
+    GrowPane,
+    ShrinkPane,
+    ToggleSplitLayout,
 }
 
 pub fn default_ai_screen_keybindings() -> HashMap<AIScreenAction, Vec<String>> {
@@ -825,6 +1012,17 @@ pub fn default_ai_screen_keybindings() -> HashMap<AIScreenAction, Vec<String>> {
     m.insert(AIScreenAction::DeleteWordLeft, vec!["//Delete word left".into(), "ctrl+w".into()]);
     m.insert(AIScreenAction::ClearHistory, vec!["//Clear conversation".into(), "ctrl+l".into()]);
     m.insert(AIScreenAction::ToggleFullscreen, vec!["//Toggle fullscreen".into(), "ctrl+f".into()]);
+    m.insert(AIScreenAction::RunInBackground, vec!["//Hide assistant, keep a running request going in the background".into(), "ctrl+b".into()]);
+    m.insert(AIScreenAction::NewConversation, vec!["//Start another conversation".into(), "ctrl+n".into()]);
+    m.insert(AIScreenAction::NextConversation, vec!["//Switch to next conversation".into(), "ctrl+t".into()]);
+    m.insert(AIScreenAction::ResponseActions, vec!["//Actions for code blocks/paths in the last response".into(), "ctrl+o".into()]);
+    m.insert(AIScreenAction::ToggleToolTimeline, vec!["//Collapse/expand tool activity".into(), "ctrl+g".into()]);
+    m.insert(AIScreenAction::ToggleApproval, vec!["//Confirm each Bash/Write/Edit call".into(), "ctrl+y".into()]);
+    m.insert(AIScreenAction::ChangeDirectory, vec!["//Change AI working directory".into(), "ctrl+p".into()]);
+    m.insert(AIScreenAction::SearchSessions, vec!["//Search saved conversations".into(), "ctrl+s".into()]);
+    m.insert(AIScreenAction::GrowPane, vec!["//Make the AI pane larger".into(), "alt+right".into()]);
+    m.insert(AIScreenAction::ShrinkPane, vec!["//Make the AI pane smaller".into(), "alt+left".into()]);
+    m.insert(AIScreenAction::ToggleSplitLayout, vec!["//Toggle side-by-side / stacked AI pane".into(), "alt+l".into()]);
 
     m
 }
@@ -8,7 +8,7 @@ mod enc;
 use std::io;
 use std::env;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyModifiers},
+    event::{self, DisableMouseCapture, EnableMouseCapture, DisableBracketedPaste, EnableBracketedPaste, DisableFocusChange, EnableFocusChange, Event, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -22,6 +22,7 @@ use crate::ui::app::{App, Screen};
 use crate::services::claude;
 use crate::utils::markdown::{render_markdown, MarkdownTheme, is_line_empty};
 use crate::keybindings::PanelAction;
+use crate::{log_debug, log_error, log_warn};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -33,29 +34,107 @@ fn print_help() {
     println!();
     println!("ARGS:");
     println!("    [PATH...]               Open panels at given paths (max 10)");
+    println!("                            FILE:LINE[:COL] opens the editor at that position");
     println!();
     println!("OPTIONS:");
     println!("    -h, --help              Print help information");
     println!("    -v, --version           Print version information");
-    println!("    --prompt <TEXT>         Send prompt to AI and print rendered response");
+    println!("    --prompt <TEXT> [--format json|markdown|plain]");
+    println!("                            Send prompt to AI and print the response (default: plain)");
+    println!("    --view <PATH|->         Open a file (or piped stdin with -) in the viewer");
+    println!("    --lang <NAME>           Syntax highlighting for --view (e.g. rust, py, json)");
+    println!("    --tree [PATH] [--depth <N>] [--json] [--all] [--no-gitignore]");
+    println!("                            Print directory tree (skips git-ignored and hidden files)");
     println!("    --design                Enable theme hot-reload (for theme development)");
+    println!("    --watch                 Apply watch_rules from settings.json in the foreground");
+    println!("    --self-update [--force] Download the latest release (checksum verified) and replace this binary");
+    println!("    --doctor [--json]       Check external tools and terminal support, with fix suggestions");
+    println!("    --log-level <LEVEL>     Log to ~/.cokacdir/logs: off, error, warn (default), info, debug, trace");
+    println!("                            (also COKACDIR_LOG=<LEVEL>; COKACDIR_DEBUG=1 means debug)");
     println!("    --base64 <TEXT>         Decode base64 and print (internal use)");
-    println!("    --ccserver <TOKEN>...   Start Telegram bot server(s)");
+    println!("    --ccserver <TOKEN>... [--log-format text|dashboard|json]");
+    println!("                            Start Telegram bot server(s) (default: dashboard for several bots)");
     println!("    --sendfile <PATH> --chat <ID> --key <HASH>");
     println!("                            Send file via Telegram bot (internal use, HASH = token hash)");
     println!("    --currenttime            Print current server time");
-    println!("    --cron <PROMPT> --at <TIME> --chat <ID> --key <HASH> [--once] [--session <SID>]");
-    println!("                            Register a scheduled task");
-    println!("    --cron-list --chat <ID> --key <HASH>");
+    println!("    --cron <PROMPT> --at <TIME> [--chat <ID> --key <HASH>] [--once] [--session <SID>]");
+    println!("                            Register a scheduled task (without --chat/--key: run by --scheduler;");
+    println!("                            a PROMPT starting with ! is a shell command)");
+    println!("    --cron-list [--chat <ID> --key <HASH>]");
     println!("                            List registered schedules");
-    println!("    --cron-remove <SID> --chat <ID> --key <HASH>");
+    println!("    --cron-remove <SID> [--chat <ID> --key <HASH>]");
     println!("                            Remove a schedule");
-    println!("    --cron-update <SID> --at <TIME> --chat <ID> --key <HASH>");
+    println!("    --cron-update <SID> --at <TIME> [--chat <ID> --key <HASH>]");
     println!("                            Update schedule time");
+    println!("    --scheduler             Run local schedules in the foreground (no Telegram bot needed)");
     println!();
     println!("HOMEPAGE: https://cokacdir.cokac.com");
 }
 
+fn handle_watch() {
+    let settings = match config::Settings::load_with_error() {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let (rules, errors) = services::watch::valid_rules(&settings.watch_rules);
+    for err in &errors {
+        eprintln!("Warning: {}", err);
+    }
+    if rules.is_empty() {
+        eprintln!("Error: no watch rules configured");
+        eprintln!("Add \"watch_rules\" to ~/.cokacdir/settings.json, e.g.");
+        eprintln!("  [{{\"dir\": \"~/Downloads\", \"pattern\": \"*.pdf\", \"action\": \"move\", \"dest\": \"~/Documents/PDF\"}}]");
+        std::process::exit(1);
+    }
+    services::watch::run_daemon(rules);
+}
+
+fn handle_doctor(as_json: bool) {
+    let checks = services::doctor::run_checks();
+    if as_json {
+        match serde_json::to_string_pretty(&checks) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Error: {}", e),
+        }
+    } else {
+        println!("cokacdir {} doctor", VERSION);
+        println!();
+        print!("{}", services::doctor::render_text(&checks));
+    }
+    if checks.iter().any(|c| c.status == services::doctor::Status::Missing) {
+        std::process::exit(1);
+    }
+}
+
+fn handle_scheduler() {
+    let settings = match config::Settings::load_with_error() {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    services::scheduler::run_daemon(services::scheduler::Delivery {
+        webhooks: settings.notifications.webhooks,
+        email: settings.email,
+    });
+}
+
+/// Schedule owner from --chat/--key; local schedules (run by --scheduler) when both are omitted
+fn cron_target(chat_id: Option<i64>, key: Option<String>) -> Option<(i64, String)> {
+    use services::scheduler::LOCAL_BOT_KEY;
+
+    match (chat_id, key) {
+        (Some(cid), Some(k)) => Some((cid, k)),
+        (None, None) => Some((0, LOCAL_BOT_KEY.to_string())),
+        (None, Some(k)) if k == LOCAL_BOT_KEY => Some((0, k)),
+        _ => None,
+    }
+}
+
 fn handle_base64(encoded: &str) {
     use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
     match BASE64.decode(encoded) {
@@ -125,80 +204,76 @@ fn handle_sendfile(path: &str, chat_id: i64, hash_key: &str) {
     }
 }
 
-fn cron_debug(msg: &str) {
-    claude::debug_log_to("cron.log", msg);
-}
-
 fn handle_cron_register(prompt: &str, at_value: &str, chat_id: i64, hash_key: &str, once: bool, session_id: Option<&str>) {
     use services::telegram;
     use services::claude;
 
-    cron_debug("========================================");
-    cron_debug("=== handle_cron_register START ===");
-    cron_debug("========================================");
-    cron_debug(&format!("  prompt: {}", prompt));
-    cron_debug(&format!("  at_value: {}", at_value));
-    cron_debug(&format!("  chat_id: {}", chat_id));
-    cron_debug(&format!("  hash_key: {}", hash_key));
-    cron_debug(&format!("  once(raw): {}", once));
-    cron_debug(&format!("  session_id: {:?}", session_id));
+    log_debug!("cron", "========================================");
+    log_debug!("cron", "=== handle_cron_register START ===");
+    log_debug!("cron", "========================================");
+    log_debug!("cron", "  prompt: {}", prompt);
+    log_debug!("cron", "  at_value: {}", at_value);
+    log_debug!("cron", "  chat_id: {}", chat_id);
+    log_debug!("cron", "  hash_key: {}", hash_key);
+    log_debug!("cron", "  once(raw): {}", once);
+    log_debug!("cron", "  session_id: {:?}", session_id);
 
     let now = chrono::Local::now();
-    cron_debug(&format!("  now: {}", now.format("%Y-%m-%d %H:%M:%S%.3f")));
+    log_debug!("cron", "  now: {}", now.format("%Y-%m-%d %H:%M:%S%.3f"));
 
     // Determine schedule_type and schedule value
-    cron_debug("  Parsing --at value...");
+    log_debug!("cron", "  Parsing --at value...");
     let (schedule_type, schedule_value) = if let Some(dt) = telegram::parse_relative_time_pub(at_value) {
         // Relative time → convert to absolute
-        cron_debug(&format!("  Parsed as relative time → absolute: {}", dt.format("%Y-%m-%d %H:%M:%S")));
+        log_debug!("cron", "  Parsed as relative time → absolute: {}", dt.format("%Y-%m-%d %H:%M:%S"));
         ("absolute".to_string(), dt.format("%Y-%m-%d %H:%M:%S").to_string())
     } else if at_value.split_whitespace().count() == 5 {
         // Cron expression (5 fields)
-        cron_debug(&format!("  Parsed as cron expression: {}", at_value));
+        log_debug!("cron", "  Parsed as cron expression: {}", at_value);
         ("cron".to_string(), at_value.to_string())
     } else {
         // Try absolute time: "YYYY-MM-DD HH:MM:SS"
         if chrono::NaiveDateTime::parse_from_str(at_value, "%Y-%m-%d %H:%M:%S").is_ok() {
-            cron_debug(&format!("  Parsed as absolute time: {}", at_value));
+            log_debug!("cron", "  Parsed as absolute time: {}", at_value);
             ("absolute".to_string(), at_value.to_string())
         } else {
-            cron_debug(&format!("  ERROR: invalid --at value: {}", at_value));
+            log_error!("cron", "  invalid --at value: {}", at_value);
             eprintln!("{}", serde_json::json!({"status":"error","message":format!("invalid --at value: {}", at_value)}));
             std::process::exit(1);
         }
     };
-    cron_debug(&format!("  schedule_type={}, schedule_value={}", schedule_type, schedule_value));
+    log_debug!("cron", "  schedule_type={}, schedule_value={}", schedule_type, schedule_value);
 
     // Generate 8-char uppercase hex ID (0-9, A-F), unique among existing schedule files
-    cron_debug("  Generating unique ID...");
+    log_debug!("cron", "  Generating unique ID...");
     let id = {
         use std::collections::HashSet;
         let existing: HashSet<String> = telegram::list_all_schedule_ids_pub();
-        cron_debug(&format!("  Existing schedule IDs: {:?}", existing));
+        log_debug!("cron", "  Existing schedule IDs: {:?}", existing);
         loop {
             let candidate = format!("{:08X}", rand::random::<u32>());
             if !existing.contains(&candidate) {
-                cron_debug(&format!("  Generated ID: {}", candidate));
+                log_debug!("cron", "  Generated ID: {}", candidate);
                 break candidate;
             }
-            cron_debug(&format!("  ID collision: {}, retrying...", candidate));
+            log_debug!("cron", "  ID collision: {}, retrying...", candidate);
         }
     };
 
     // Resolve current_path from bot_settings using chat_id + hash_key
-    cron_debug("  Resolving current_path...");
+    log_debug!("cron", "  Resolving current_path...");
     let current_path = telegram::resolve_current_path_for_chat(chat_id, hash_key)
         .unwrap_or_else(|| {
             let fallback = std::env::current_dir()
                 .map(|p| p.display().to_string())
                 .unwrap_or_else(|_| "/".to_string());
-            cron_debug(&format!("  current_path fallback: {}", fallback));
+            log_debug!("cron", "  current_path fallback: {}", fallback);
             fallback
         });
-    cron_debug(&format!("  current_path: {}", current_path));
+    log_debug!("cron", "  current_path: {}", current_path);
 
     // Step 1: Register schedule immediately (without context_summary) and output result
-    cron_debug("  Writing schedule entry (without context_summary)...");
+    log_debug!("cron", "  Writing schedule entry (without context_summary)...");
     telegram::write_schedule_entry_pub(&telegram::ScheduleEntryData {
         id: id.clone(),
         chat_id,
@@ -212,11 +287,11 @@ fn handle_cron_register(prompt: &str, at_value: &str, chat_id: i64, hash_key: &s
         created_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
         context_summary: None,
     }).unwrap_or_else(|e| {
-        cron_debug(&format!("  ERROR: write_schedule_entry failed: {}", e));
+        log_error!("cron", "  write_schedule_entry failed: {}", e);
         eprintln!("{}", serde_json::json!({"status":"error","message":format!("{}", e)}));
         std::process::exit(1);
     });
-    cron_debug("  Schedule entry written successfully");
+    log_debug!("cron", "  Schedule entry written successfully");
 
     let mut output = serde_json::json!({
         "status": "ok",
@@ -228,12 +303,12 @@ fn handle_cron_register(prompt: &str, at_value: &str, chat_id: i64, hash_key: &s
     if schedule_type == "cron" {
         output.as_object_mut().unwrap().insert("once".to_string(), serde_json::json!(once));
     }
-    cron_debug(&format!("  Output: {}", output));
+    log_debug!("cron", "  Output: {}", output);
     // Write result to temp file so the bot can read it even if Bash tool misses stdout
     if let Some(home) = dirs::home_dir() {
         let result_path = home.join(".cokacdir").join("schedule").join(format!("{}.result", id));
         let _ = std::fs::write(&result_path, output.to_string());
-        cron_debug(&format!("  Result file written: {}", result_path.display()));
+        log_debug!("cron", "  Result file written: {}", result_path.display());
     }
     println!("{}", output);
     // Flush stdout immediately so the Bash tool captures the output
@@ -242,7 +317,7 @@ fn handle_cron_register(prompt: &str, at_value: &str, chat_id: i64, hash_key: &s
 
     // Step 2: Spawn a detached child process to extract context summary and update the schedule
     if let Some(sid) = session_id {
-        cron_debug(&format!("  Spawning background process for context summary extraction: session={}", sid));
+        log_debug!("cron", "  Spawning background process for context summary extraction: session={}", sid);
         let exe = std::env::current_exe().unwrap_or_else(|_| std::path::PathBuf::from("cokacdir"));
         let child = std::process::Command::new(exe)
             .arg("--cron-context")
@@ -261,14 +336,14 @@ fn handle_cron_register(prompt: &str, at_value: &str, chat_id: i64, hash_key: &s
             .stderr(std::process::Stdio::null())
             .spawn();
         match child {
-            Ok(c) => cron_debug(&format!("  Background process spawned: pid={:?}", c.id())),
-            Err(e) => cron_debug(&format!("  WARNING: Failed to spawn background process: {}", e)),
+            Ok(c) => log_debug!("cron", "  Background process spawned: pid={:?}", c.id()),
+            Err(e) => log_warn!("cron", "  Failed to spawn background process: {}", e),
         }
     } else {
-        cron_debug("  No session_id provided, skipping context summary");
+        log_debug!("cron", "  No session_id provided, skipping context summary");
     }
 
-    cron_debug("=== handle_cron_register END ===");
+    log_debug!("cron", "=== handle_cron_register END ===");
 }
 
 struct CronContextArgs {
@@ -310,28 +385,28 @@ fn handle_cron_context(args: &[String]) {
     use services::telegram;
     use services::claude;
 
-    cron_debug("=== handle_cron_context START ===");
+    log_debug!("cron", "=== handle_cron_context START ===");
     let ctx = match CronContextArgs::from_args(args) {
         Some(c) => c,
         None => {
-            cron_debug(&format!("  ERROR: insufficient args: {:?}", args));
+            log_error!("cron", "  insufficient args: {:?}", args);
             return;
         }
     };
 
-    cron_debug(&format!("  id={}, session_id={}, prompt_len={}", ctx.id, ctx.session_id, ctx.prompt.len()));
+    log_debug!("cron", "  id={}, session_id={}, prompt_len={}", ctx.id, ctx.session_id, ctx.prompt.len());
 
     let extract_start = std::time::Instant::now();
     match claude::extract_context_summary(&ctx.session_id, &ctx.prompt, &ctx.current_path) {
         Ok(summary) => {
-            cron_debug(&format!("  Context summary extracted in {:?}, len={}", extract_start.elapsed(), summary.len()));
+            log_debug!("cron", "  Context summary extracted in {:?}, len={}", extract_start.elapsed(), summary.len());
 
             // 실행 중 삭제된 스케줄 부활 방지: 파일이 아직 존재하는지 확인
             if let Some(home) = dirs::home_dir() {
                 let path = home.join(".cokacdir").join("schedule").join(format!("{}.json", ctx.id));
                 if !path.exists() {
-                    cron_debug(&format!("  Schedule {} already deleted, skipping context_summary write", ctx.id));
-                    cron_debug("=== handle_cron_context END ===");
+                    log_debug!("cron", "  Schedule {} already deleted, skipping context_summary write", ctx.id);
+                    log_debug!("cron", "=== handle_cron_context END ===");
                     return;
                 }
             }
@@ -349,23 +424,23 @@ fn handle_cron_context(args: &[String]) {
                 created_at: ctx.created_at.clone(),
                 context_summary: Some(summary),
             }).unwrap_or_else(|e| {
-                cron_debug(&format!("  ERROR: write_schedule_entry failed: {}", e));
+                log_error!("cron", "  write_schedule_entry failed: {}", e);
             });
-            cron_debug("  Schedule entry updated with context_summary");
+            log_debug!("cron", "  Schedule entry updated with context_summary");
         }
         Err(e) => {
-            cron_debug(&format!("  WARNING: extract_context_summary failed in {:?}: {}", extract_start.elapsed(), e));
+            log_warn!("cron", "  extract_context_summary failed in {:?}: {}", extract_start.elapsed(), e);
         }
     }
-    cron_debug("=== handle_cron_context END ===");
+    log_debug!("cron", "=== handle_cron_context END ===");
 }
 
 fn handle_cron_list(chat_id: i64, hash_key: &str) {
     use services::telegram;
 
-    cron_debug(&format!("[handle_cron_list] chat_id={}, hash_key={}", chat_id, hash_key));
+    log_debug!("cron", "[handle_cron_list] chat_id={}, hash_key={}", chat_id, hash_key);
     let entries = telegram::list_schedule_entries_pub(hash_key, Some(chat_id));
-    cron_debug(&format!("[handle_cron_list] found {} entries", entries.len()));
+    log_debug!("cron", "[handle_cron_list] found {} entries", entries.len());
     let items: Vec<serde_json::Value> = entries.iter().map(|e| {
         let mut obj = serde_json::json!({
             "id": e.id,
@@ -385,20 +460,20 @@ fn handle_cron_list(chat_id: i64, hash_key: &str) {
 fn handle_cron_remove(id: &str, chat_id: i64, hash_key: &str) {
     use services::telegram;
 
-    cron_debug(&format!("[handle_cron_remove] id={}, chat_id={}, hash_key={}", id, chat_id, hash_key));
+    log_debug!("cron", "[handle_cron_remove] id={}, chat_id={}, hash_key={}", id, chat_id, hash_key);
     // Verify ownership
     let entries = telegram::list_schedule_entries_pub(hash_key, Some(chat_id));
     if !entries.iter().any(|e| e.id == id) {
-        cron_debug(&format!("[handle_cron_remove] id={}, not found or access denied", id));
+        log_debug!("cron", "[handle_cron_remove] id={}, not found or access denied", id);
         eprintln!("{}", serde_json::json!({"status":"error","message":format!("schedule not found or access denied: {}", id)}));
         std::process::exit(1);
     }
 
     if telegram::delete_schedule_entry_pub(id) {
-        cron_debug(&format!("[handle_cron_remove] id={}, deleted successfully", id));
+        log_debug!("cron", "[handle_cron_remove] id={}, deleted successfully", id);
         println!("{}", serde_json::json!({"status":"ok","id":id}));
     } else {
-        cron_debug(&format!("[handle_cron_remove] id={}, delete failed", id));
+        log_debug!("cron", "[handle_cron_remove] id={}, delete failed", id);
         eprintln!("{}", serde_json::json!({"status":"error","message":format!("failed to remove schedule: {}", id)}));
         std::process::exit(1);
     }
@@ -407,28 +482,28 @@ fn handle_cron_remove(id: &str, chat_id: i64, hash_key: &str) {
 fn handle_cron_update(id: &str, at_value: &str, chat_id: i64, hash_key: &str) {
     use services::telegram;
 
-    cron_debug(&format!("[handle_cron_update] id={}, at_value={:?}, chat_id={}, hash_key={}", id, at_value, chat_id, hash_key));
+    log_debug!("cron", "[handle_cron_update] id={}, at_value={:?}, chat_id={}, hash_key={}", id, at_value, chat_id, hash_key);
     // Find the entry
     let entries = telegram::list_schedule_entries_pub(hash_key, Some(chat_id));
     let entry = entries.iter().find(|e| e.id == id);
     let Some(entry) = entry else {
-        cron_debug(&format!("[handle_cron_update] id={}, not found or access denied", id));
+        log_debug!("cron", "[handle_cron_update] id={}, not found or access denied", id);
         eprintln!("{}", serde_json::json!({"status":"error","message":format!("schedule not found or access denied: {}", id)}));
         std::process::exit(1);
     };
 
     // Parse new schedule value
     let (schedule_type, schedule_value) = if let Some(dt) = telegram::parse_relative_time_pub(at_value) {
-        cron_debug(&format!("[handle_cron_update] id={}, parsed as relative → absolute: {}", id, dt.format("%Y-%m-%d %H:%M:%S")));
+        log_debug!("cron", "[handle_cron_update] id={}, parsed as relative → absolute: {}", id, dt.format("%Y-%m-%d %H:%M:%S"));
         ("absolute".to_string(), dt.format("%Y-%m-%d %H:%M:%S").to_string())
     } else if at_value.split_whitespace().count() == 5 {
-        cron_debug(&format!("[handle_cron_update] id={}, parsed as cron: {}", id, at_value));
+        log_debug!("cron", "[handle_cron_update] id={}, parsed as cron: {}", id, at_value);
         ("cron".to_string(), at_value.to_string())
     } else if chrono::NaiveDateTime::parse_from_str(at_value, "%Y-%m-%d %H:%M:%S").is_ok() {
-        cron_debug(&format!("[handle_cron_update] id={}, parsed as absolute datetime: {}", id, at_value));
+        log_debug!("cron", "[handle_cron_update] id={}, parsed as absolute datetime: {}", id, at_value);
         ("absolute".to_string(), at_value.to_string())
     } else {
-        cron_debug(&format!("[handle_cron_update] id={}, invalid --at value: {:?}", id, at_value));
+        log_debug!("cron", "[handle_cron_update] id={}, invalid --at value: {:?}", id, at_value);
         eprintln!("{}", serde_json::json!({"status":"error","message":format!("invalid --at value: {}", at_value)}));
         std::process::exit(1);
     };
@@ -445,81 +520,200 @@ fn handle_cron_update(id: &str, at_value: &str, chat_id: i64, hash_key: &str) {
         updated.once = Some(false);
     }
 
-    cron_debug(&format!("[handle_cron_update] id={}, writing: type={}, schedule={}, last_run=None", id, schedule_type, schedule_value));
+    log_debug!("cron", "[handle_cron_update] id={}, writing: type={}, schedule={}, last_run=None", id, schedule_type, schedule_value);
     telegram::write_schedule_entry_pub(&updated).unwrap_or_else(|e| {
-        cron_debug(&format!("[handle_cron_update] id={}, write failed: {}", id, e));
+        log_debug!("cron", "[handle_cron_update] id={}, write failed: {}", id, e);
         eprintln!("{}", serde_json::json!({"status":"error","message":format!("{}", e)}));
         std::process::exit(1);
     });
 
-    cron_debug(&format!("[handle_cron_update] id={}, updated successfully", id));
+    log_debug!("cron", "[handle_cron_update] id={}, updated successfully", id);
     println!("{}", serde_json::json!({"status":"ok","id":id,"schedule":schedule_value}));
 }
 
+fn handle_tree(path: &str, options: &services::tree::TreeOptions, as_json: bool) {
+    let root = std::path::Path::new(path);
+    let tree = match services::tree::build_tree(root, options) {
+        Ok(t) => t,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if as_json {
+        match serde_json::to_string_pretty(&tree) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Error: {}", e),
+        }
+    } else {
+        print!("{}", services::tree::render_text(&tree));
+    }
+}
+
 fn print_version() {
     println!("cokacdir {}", VERSION);
 }
 
-fn handle_ccserver(tokens: Vec<String>) {
-    let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+fn handle_ccserver(tokens: Vec<String>, log_format: Option<services::bot_console::LogFormat>) {
+    use services::bot_console::{self, LogFormat};
+    use services::telegram::ShutdownMode;
 
-    let title = format!("  cokacdir v{}  |  Telegram Bot Server  ", VERSION);
-    let width = title.chars().count();
-    println!();
-    println!("  ┌{}┐", "─".repeat(width));
-    println!("  │{}│", title);
-    println!("  └{}┘", "─".repeat(width));
-    println!();
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+    let log_format = log_format.unwrap_or_else(|| LogFormat::default_for(tokens.len()));
+    bot_console::set_format(log_format);
 
-    if tokens.len() == 1 {
-        println!("  ▸ Bot instance : 1");
-        println!("  ▸ Status       : Connecting...");
+    // With JSON logs every line on stdout is an event: no banner
+    if log_format != LogFormat::Json {
+        let title = format!("  cokacdir v{}  |  Telegram Bot Server  ", VERSION);
+        let width = title.chars().count();
         println!();
-        rt.block_on(services::telegram::run_bot(&tokens[0]));
-    } else {
-        println!("  ▸ Bot instances : {}", tokens.len());
-        println!("  ▸ Status        : Connecting...");
+        println!("  ┌{}┐", "─".repeat(width));
+        println!("  │{}│", title);
+        println!("  └{}┘", "─".repeat(width));
         println!();
-        rt.block_on(async {
-            let mut handles = Vec::new();
-            for (i, token) in tokens.into_iter().enumerate() {
-                handles.push(tokio::spawn(async move {
-                    println!("  ✓ Bot #{} connected", i + 1);
-                    services::telegram::run_bot(&token).await;
-                }));
-            }
-            for handle in handles {
-                let _ = handle.await;
-            }
-        });
+        if tokens.len() == 1 {
+            println!("  ▸ Bot instance : 1");
+            println!("  ▸ Status       : Connecting...");
+        } else {
+            println!("  ▸ Bot instances : {}", tokens.len());
+            println!("  ▸ Status        : Connecting...");
+        }
+        println!();
+    }
+
+    if rt.block_on(run_bots(tokens)) == ShutdownMode::Restart {
+        rt.shutdown_timeout(std::time::Duration::from_secs(1));
+        restart_self();
     }
 }
 
-fn handle_prompt(prompt: &str) {
-    use crate::ui::theme::Theme;
+/// Run all bots concurrently; Restart if any of them was asked to restart
+async fn run_bots(tokens: Vec<String>) -> services::telegram::ShutdownMode {
+    use services::telegram::ShutdownMode;
+
+    services::telegram::spawn_signal_listener();
+    services::bot_console::spawn_dashboard();
+    let mut handles = Vec::new();
+    for (i, token) in tokens.into_iter().enumerate() {
+        handles.push(tokio::spawn(async move {
+            services::telegram::run_bot(&token, i + 1).await
+        }));
+    }
+    let mut mode = ShutdownMode::Stop;
+    for handle in handles {
+        if let Ok(ShutdownMode::Restart) = handle.await {
+            mode = ShutdownMode::Restart;
+        }
+    }
+    mode
+}
+
+/// Replace this process with a fresh instance of the (possibly updated) binary
+/// started with the same arguments
+fn restart_self() {
+    use std::os::unix::process::CommandExt;
+
+    let exe = match std::env::current_exe() {
+        Ok(path) => restart_exe_path(path),
+        Err(e) => {
+            eprintln!("Error: cannot locate executable for restart: {}", e);
+            std::process::exit(1);
+        }
+    };
+    services::bot_console::line(&format!("  ↻ Restarting {}", exe.display()));
+    let err = std::process::Command::new(&exe).args(std::env::args_os().skip(1)).exec();
+    eprintln!("Error: restart failed: {}", err);
+    std::process::exit(1);
+}
+
+/// Path to re-exec for a restart. Linux reports "<path> (deleted)" once the
+/// binary has been replaced by an update; the new binary lives at <path>.
+fn restart_exe_path(path: std::path::PathBuf) -> std::path::PathBuf {
+    let text = path.display().to_string();
+    match text.strip_suffix(" (deleted)") {
+        Some(original) => std::path::PathBuf::from(original),
+        None => path,
+    }
+}
+
+/// Output format for --prompt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PromptFormat {
+    /// Rendered markdown as plain text (default)
+    Plain,
+    /// Raw AI response (markdown source)
+    Markdown,
+    /// JSON envelope with response and metadata
+    Json,
+}
+
+impl PromptFormat {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "plain" | "text" => Some(PromptFormat::Plain),
+            "markdown" | "md" => Some(PromptFormat::Markdown),
+            "json" => Some(PromptFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+fn handle_prompt(prompt: &str, format: PromptFormat) {
+    let prompt_error = |message: &str| -> ! {
+        if format == PromptFormat::Json {
+            eprintln!("{}", serde_json::json!({"status":"error","message":message}));
+        } else {
+            eprintln!("Error: {}", message);
+        }
+        std::process::exit(1);
+    };
 
     // Check if Claude is available
     if !claude::is_claude_available() {
-        eprintln!("Error: Claude CLI is not available.");
-        eprintln!("Please install Claude CLI: https://claude.ai/cli");
-        return;
+        prompt_error("Claude CLI is not available. Please install Claude CLI: https://claude.ai/cli");
     }
 
     // Execute Claude command
     let current_dir = std::env::current_dir()
         .map(|p| p.display().to_string())
         .unwrap_or_else(|_| ".".to_string());
+    let started = std::time::Instant::now();
     let response = claude::execute_command(prompt, None, &current_dir, None, None);
+    let duration_ms = started.elapsed().as_millis() as u64;
 
     if !response.success {
-        eprintln!("Error: {}", response.error.unwrap_or_else(|| "Unknown error".to_string()));
-        return;
+        prompt_error(response.error.as_deref().unwrap_or("Unknown error"));
+    }
+
+    let content = response.response.clone().unwrap_or_default();
+
+    match format {
+        PromptFormat::Plain => print!("{}", render_prompt_plain(&content)),
+        PromptFormat::Markdown => println!("{}", content),
+        PromptFormat::Json => {
+            let envelope = serde_json::json!({
+                "status": "ok",
+                "response": content,
+                "session_id": response.session_id,
+                "working_dir": current_dir,
+                "duration_ms": duration_ms,
+                "usage": response.usage,
+                "total_cost_usd": response.total_cost_usd,
+            });
+            match serde_json::to_string_pretty(&envelope) {
+                Ok(json) => println!("{}", json),
+                Err(e) => prompt_error(&e.to_string()),
+            }
+        }
     }
+}
 
-    let content = response.response.unwrap_or_default();
+/// Render a markdown response as plain text (one line per rendered line)
+fn render_prompt_plain(content: &str) -> String {
+    use crate::ui::theme::Theme;
 
     // Normalize empty lines first
-    let normalized = normalize_consecutive_empty_lines(&content);
+    let normalized = normalize_consecutive_empty_lines(content);
 
     // Render markdown
     let theme = Theme::default();
@@ -527,22 +721,24 @@ fn handle_prompt(prompt: &str) {
     let lines = render_markdown(&normalized, md_theme);
 
     // Remove consecutive empty lines from rendered output
+    let mut output = String::new();
     let mut prev_was_empty = false;
     for line in lines {
         let is_empty = is_line_empty(&line);
         if is_empty {
             if !prev_was_empty {
-                println!();
+                output.push('\n');
             }
             prev_was_empty = true;
         } else {
-            let content: String = line.spans.iter()
-                .map(|s| s.content.as_ref())
-                .collect();
-            println!("{}", content);
+            for span in &line.spans {
+                output.push_str(span.content.as_ref());
+            }
+            output.push('\n');
             prev_was_empty = false;
         }
     }
+    output
 }
 
 /// Normalize consecutive empty lines to maximum of one
@@ -568,13 +764,33 @@ fn normalize_consecutive_empty_lines(text: &str) -> String {
 }
 
 fn main() -> io::Result<()> {
-    // Initialize debug flag from environment variable
-    claude::init_debug_from_env();
-
     // Handle command line arguments
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    // --log-level applies to every mode, so it is taken out before the other options
+    let mut log_level = None;
+    if let Some(pos) = args.iter().position(|a| a == "--log-level") {
+        match args.get(pos + 1).map(|v| v.parse::<services::logging::LevelFilter>()) {
+            Some(Ok(level)) => log_level = Some(level),
+            Some(Err(e)) => {
+                eprintln!("Error: {}", e);
+                return Ok(());
+            }
+            None => {
+                eprintln!("Error: --log-level requires a level (off, error, warn, info, debug, trace)");
+                return Ok(());
+            }
+        }
+        args.drain(pos..pos + 2);
+    }
+    services::logging::init(log_level);
+    // Debug mode from COKACDIR_DEBUG=1 or a bot saved with /debug on
+    claude::init_debug_from_env();
     let mut design_mode = false;
     let mut start_paths: Vec<std::path::PathBuf> = Vec::new();
+    let mut view_target: Option<String> = None;
+    let mut open_at: Option<(std::path::PathBuf, usize, usize)> = None;
+    let mut view_lang: Option<String> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -588,14 +804,40 @@ fn main() -> io::Result<()> {
                 return Ok(());
             }
             "--prompt" => {
+                // Parse: --prompt "text" [--format json|markdown|plain]
                 if i + 1 >= args.len() {
                     eprintln!("Error: --prompt requires a text argument");
-                    eprintln!("Usage: cokacdir --prompt \"your question\"");
+                    eprintln!("Usage: cokacdir --prompt \"your question\" [--format json|markdown|plain]");
                     return Ok(());
                 }
-                handle_prompt(&args[i + 1]);
+                let mut format = PromptFormat::Plain;
+                let mut j = i + 2;
+                while j < args.len() {
+                    match args[j].as_str() {
+                        "--format" => {
+                            match args.get(j + 1).and_then(|f| PromptFormat::parse(f)) {
+                                Some(f) => format = f,
+                                None => {
+                                    eprintln!("Error: --format requires one of: json, markdown, plain");
+                                    std::process::exit(1);
+                                }
+                            }
+                            j += 2;
+                        }
+                        other => {
+                            eprintln!("Unknown option for --prompt: {}", other);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                handle_prompt(&args[i + 1], format);
                 return Ok(());
             }
+            "--ai-approval-hook" => {
+                // Claude PreToolUse hook started by the AI screen in approval mode
+                let Some(socket) = args.get(i + 1) else { std::process::exit(2) };
+                std::process::exit(services::ai_approval::run_hook(socket));
+            }
             "--base64" => {
                 if i + 1 >= args.len() {
                     std::process::exit(1);
@@ -604,16 +846,35 @@ fn main() -> io::Result<()> {
                 return Ok(());
             }
             "--ccserver" => {
-                let tokens: Vec<String> = args[i + 1..].iter()
-                    .filter(|a| !a.starts_with('-'))
-                    .cloned()
-                    .collect();
+                let mut tokens: Vec<String> = Vec::new();
+                let mut log_format = None;
+                let mut j = i + 1;
+                while j < args.len() {
+                    if args[j] == "--log-format" {
+                        let value = args.get(j + 1).map(|s| s.as_str()).unwrap_or("");
+                        match services::bot_console::LogFormat::parse(value) {
+                            Some(format) => log_format = Some(format),
+                            None => {
+                                eprintln!("Error: --log-format must be text, dashboard or json");
+                                return Ok(());
+                            }
+                        }
+                        j += 1;
+                    } else if !args[j].starts_with('-') {
+                        tokens.push(args[j].clone());
+                    }
+                    j += 1;
+                }
                 if tokens.is_empty() {
                     eprintln!("Error: --ccserver requires at least one token argument");
-                    eprintln!("Usage: cokacdir --ccserver <TOKEN> [TOKEN2] ...");
+                    eprintln!("Usage: cokacdir --ccserver <TOKEN> [TOKEN2] ... [--log-format text|dashboard|json]");
                     return Ok(());
                 }
-                handle_ccserver(tokens);
+                handle_ccserver(tokens, log_format);
+                return Ok(());
+            }
+            "--scheduler" => {
+                handle_scheduler();
                 return Ok(());
             }
             "--currenttime" => {
@@ -621,8 +882,8 @@ fn main() -> io::Result<()> {
                 return Ok(());
             }
             "--cron" => {
-                cron_debug("=== --cron argument parsing START ===");
-                cron_debug(&format!("  Raw args: {:?}", &args[i..]));
+                log_debug!("cron", "=== --cron argument parsing START ===");
+                log_debug!("cron", "  Raw args: {:?}", &args[i..]);
                 // Parse: --cron "prompt" --at "time" --chat ID --key KEY [--once] [--session SID]
                 let mut prompt: Option<String> = None;
                 let mut at_value: Option<String> = None;
@@ -656,19 +917,19 @@ fn main() -> io::Result<()> {
                         _ => { j += 1; }
                     }
                 }
-                cron_debug(&format!("  Parsed: prompt={:?}, at={:?}, chat_id={:?}, key={:?}, once={}, session_id={:?}",
-                    prompt, at_value, chat_id, key, once, session_id));
-                match (prompt, at_value, chat_id, key) {
-                    (Some(p), Some(at), Some(cid), Some(k)) => {
-                        cron_debug("  All required args present, calling handle_cron_register");
+                log_debug!("cron", "  Parsed: prompt={:?}, at={:?}, chat_id={:?}, key={:?}, once={}, session_id={:?}",
+                    prompt, at_value, chat_id, key, once, session_id);
+                match (prompt, at_value, cron_target(chat_id, key)) {
+                    (Some(p), Some(at), Some((cid, k))) => {
+                        log_debug!("cron", "  All required args present, calling handle_cron_register");
                         handle_cron_register(&p, &at, cid, &k, once, session_id.as_deref());
                     }
                     _ => {
-                        cron_debug("  ERROR: Missing required arguments");
-                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron requires \"prompt\", --at \"time\", --chat <ID>, --key <HASH>"}));
+                        log_error!("cron", "  Missing required arguments");
+                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron requires \"prompt\", --at \"time\" [--chat <ID> --key <HASH>]"}));
                     }
                 }
-                cron_debug("=== --cron argument parsing END ===");
+                log_debug!("cron", "=== --cron argument parsing END ===");
                 return Ok(());
             }
             "--cron-context" => {
@@ -694,10 +955,10 @@ fn main() -> io::Result<()> {
                         _ => { j += 1; }
                     }
                 }
-                match (chat_id, key) {
-                    (Some(cid), Some(k)) => handle_cron_list(cid, &k),
+                match cron_target(chat_id, key) {
+                    Some((cid, k)) => handle_cron_list(cid, &k),
                     _ => {
-                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron-list requires --chat <ID> --key <HASH>"}));
+                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron-list requires --chat <ID> --key <HASH> (or neither)"}));
                     }
                 }
                 return Ok(());
@@ -723,10 +984,10 @@ fn main() -> io::Result<()> {
                         _ => { j += 1; }
                     }
                 }
-                match (sched_id, chat_id, key) {
-                    (Some(sid), Some(cid), Some(k)) => handle_cron_remove(&sid, cid, &k),
+                match (sched_id, cron_target(chat_id, key)) {
+                    (Some(sid), Some((cid, k))) => handle_cron_remove(&sid, cid, &k),
                     _ => {
-                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron-remove requires <ID> --chat <ID> --key <HASH>"}));
+                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron-remove requires <ID> [--chat <ID> --key <HASH>]"}));
                     }
                 }
                 return Ok(());
@@ -757,10 +1018,10 @@ fn main() -> io::Result<()> {
                         _ => { j += 1; }
                     }
                 }
-                match (sched_id, at_value, chat_id, key) {
-                    (Some(sid), Some(at), Some(cid), Some(k)) => handle_cron_update(&sid, &at, cid, &k),
+                match (sched_id, at_value, cron_target(chat_id, key)) {
+                    (Some(sid), Some(at), Some((cid, k))) => handle_cron_update(&sid, &at, cid, &k),
                     _ => {
-                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron-update requires <ID> --at \"time\" --chat <ID> --key <HASH>"}));
+                        eprintln!("{}", serde_json::json!({"status":"error","message":"--cron-update requires <ID> --at \"time\" [--chat <ID> --key <HASH>]"}));
                     }
                 }
                 return Ok(());
@@ -806,15 +1067,92 @@ fn main() -> io::Result<()> {
                 }
                 return Ok(());
             }
+            "--tree" => {
+                // Parse: --tree [PATH] [--depth N] [--json] [--all] [--no-gitignore]
+                let mut path: Option<String> = None;
+                let mut options = services::tree::TreeOptions::default();
+                let mut as_json = false;
+                let mut j = i + 1;
+                while j < args.len() {
+                    match args[j].as_str() {
+                        "--depth" => {
+                            if j + 1 < args.len() { options.max_depth = args[j + 1].parse().ok(); j += 2; }
+                            else { j += 1; }
+                        }
+                        "--json" => { as_json = true; j += 1; }
+                        "--all" | "-a" => { options.show_hidden = true; j += 1; }
+                        "--no-gitignore" => { options.respect_gitignore = false; j += 1; }
+                        _ if path.is_none() && !args[j].starts_with('-') => {
+                            path = Some(args[j].clone()); j += 1;
+                        }
+                        other => {
+                            eprintln!("Unknown option for --tree: {}", other);
+                            return Ok(());
+                        }
+                    }
+                }
+                handle_tree(path.as_deref().unwrap_or("."), &options, as_json);
+                return Ok(());
+            }
             "--design" => {
                 design_mode = true;
             }
+            "--watch" => {
+                handle_watch();
+                return Ok(());
+            }
+            "--self-update" => {
+                let force = match args.get(i + 1).map(|s| s.as_str()) {
+                    None => false,
+                    Some("--force") => true,
+                    Some(other) => {
+                        eprintln!("Unknown option for --self-update: {}", other);
+                        return Ok(());
+                    }
+                };
+                handle_self_update(force);
+                return Ok(());
+            }
+            "--doctor" => {
+                let as_json = match args.get(i + 1).map(|s| s.as_str()) {
+                    None => false,
+                    Some("--json") => true,
+                    Some(other) => {
+                        eprintln!("Unknown option for --doctor: {}", other);
+                        return Ok(());
+                    }
+                };
+                handle_doctor(as_json);
+                return Ok(());
+            }
+            "--view" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Error: --view requires a file path or - for stdin");
+                    eprintln!("Usage: some-command | cokacdir --view - [--lang <NAME>]");
+                    return Ok(());
+                }
+                view_target = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "--lang" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Error: --lang requires a language name");
+                    return Ok(());
+                }
+                view_lang = Some(args[i + 1].clone());
+                i += 1;
+            }
             arg if arg.starts_with('-') => {
                 eprintln!("Unknown option: {}", arg);
                 eprintln!("Use --help for usage information");
                 return Ok(());
             }
             path => {
+                // file:line[:col] opens the editor at that position (e.g. from compiler errors)
+                let (path, position) = match ui::app::split_path_position(path) {
+                    (file_part, Some(pos)) if !std::path::Path::new(path).exists() => (file_part, Some(pos)),
+                    _ => (path, None),
+                };
                 // Treat as a directory path
                 let p = std::path::PathBuf::from(path);
                 let resolved = if p.is_absolute() {
@@ -822,21 +1160,59 @@ fn main() -> io::Result<()> {
                 } else {
                     env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("/")).join(p)
                 };
+                if let Some((line, col)) = position {
+                    if resolved.is_file() {
+                        open_at = Some((resolved.clone(), line, col));
+                    }
+                }
                 start_paths.push(resolved);
             }
         }
         i += 1;
     }
 
+    // --lang is only meaningful with --view
+    let view_language = match view_lang {
+        Some(ref name) => match ui::syntax::Language::from_name(name) {
+            Some(lang) => Some(lang),
+            None => {
+                eprintln!("Error: Unknown language for --lang: {}", name);
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+
+    // Read piped input before the terminal switches to raw mode
+    let stdin_content = if view_target.as_deref() == Some("-") {
+        use std::io::{IsTerminal, Read};
+        if io::stdin().is_terminal() {
+            eprintln!("Error: --view - expects piped input");
+            eprintln!("Usage: some-command | cokacdir --view - [--lang <NAME>]");
+            return Ok(());
+        }
+        let mut buf = Vec::new();
+        io::stdin().read_to_end(&mut buf)?;
+        Some(buf)
+    } else {
+        None
+    };
+
     // Setup panic hook to restore terminal on panic
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
+        // 작업 스레드 패닉은 진행 다이얼로그에 실패로 표시됨: 화면은 그대로 두고 로그만 남김
+        if services::file_ops::panic_is_caught() {
+            log_error!("file_ops", "{}", panic_info);
+            return;
+        }
         let _ = disable_raw_mode();
         let _ = execute!(
             io::stdout(),
             LeaveAlternateScreen,
             DisableMouseCapture,
             DisableBracketedPaste,
+            DisableFocusChange,
             crossterm::cursor::Show
         );
         original_hook(panic_info);
@@ -852,7 +1228,8 @@ fn main() -> io::Result<()> {
         crossterm::cursor::MoveTo(0, 0),
         EnterAlternateScreen,
         EnableMouseCapture,
-        EnableBracketedPaste
+        EnableBracketedPaste,
+        EnableFocusChange
     )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
@@ -871,18 +1248,27 @@ fn main() -> io::Result<()> {
         Ok(s) => (s, None),
         Err(e) => (config::Settings::default(), Some(e)),
     };
+    utils::format::configure(&settings.format);
+    ui::theme::set_text_markers(settings.theme.text_markers);
     let mut app = App::with_settings(settings);
     app.image_picker = Some(picker);
     app.design_mode = design_mode;
 
+    // Control socket is optional; the UI works the same without it
+    app.ipc_server = services::ipc::IpcServer::start().ok();
+    let watch_error = app.start_watch_service();
+
     // Override panels with command-line paths if provided
-    if !start_paths.is_empty() {
+    let has_start_paths = !start_paths.is_empty();
+    if has_start_paths {
         app.set_panels_from_paths(start_paths);
     }
 
     // Show settings load error if any
     if let Some(err) = settings_error {
         app.show_message(&format!("Settings error: {} (using defaults)", err));
+    } else if let Some(err) = watch_error {
+        app.show_message(&err);
     }
 
     // Show design mode message if active
@@ -890,14 +1276,63 @@ fn main() -> io::Result<()> {
         app.show_message("Design mode: theme hot-reload enabled");
     }
 
+    // Open the editor at file:line:col given on the command line
+    if let Some((path, line, col)) = open_at {
+        app.open_file_at(&path, line, col);
+    } else if !has_start_paths && view_target.is_none() {
+        // Restore the last session only when nothing was asked for on the command line
+        app.restore_session();
+    }
+
+    // Offer to resume or clean up after a crashed or killed session
+    app.show_recovery_screen_if_needed();
+
+    // Open the viewer directly for --view
+    if let Some(ref target) = view_target {
+        let mut viewer = ui::file_viewer::ViewerState::new();
+        viewer.set_syntax_colors(app.theme.syntax);
+        let loaded = match stdin_content {
+            Some(bytes) => viewer.load_stdin(bytes, view_language),
+            None => {
+                let path = std::path::PathBuf::from(target);
+                let result = viewer.load_file(&path);
+                app.restore_viewer_position(&mut viewer, &path);
+                if let Some(lang) = view_language {
+                    viewer.language = lang;
+                    viewer.set_syntax_colors(app.theme.syntax);
+                }
+                result
+            }
+        };
+        if let Err(e) = loaded {
+            disable_raw_mode()?;
+            execute!(
+                terminal.backend_mut(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                DisableBracketedPaste,
+                crossterm::cursor::Show
+            )?;
+            eprintln!("Error: Cannot view {}: {}", target, e);
+            return Ok(());
+        }
+        app.viewer_state = Some(viewer);
+        app.current_screen = Screen::FileViewer;
+        app.view_only = true;
+    }
+
     // Run app
     let result = run_app(&mut terminal, &mut app);
 
-    // Save settings before exit
-    app.save_settings();
+    // Save settings before exit (pager mode never touches panels)
+    if !app.view_only {
+        app.record_recent_file();
+        app.capture_session();
+        app.save_settings();
+    }
 
     // Save last directory for shell cd (skip remote paths)
-    if !app.active_panel().is_remote() {
+    if !app.view_only && !app.active_panel().is_remote() {
         let last_dir = app.active_panel().path.display().to_string();
         if let Some(config_dir) = config::Settings::config_dir() {
             let lastdir_path = config_dir.join("lastdir");
@@ -912,6 +1347,7 @@ fn main() -> io::Result<()> {
         LeaveAlternateScreen,
         DisableMouseCapture,
         DisableBracketedPaste,
+        DisableFocusChange,
         crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
         crossterm::cursor::MoveTo(0, 0),
         crossterm::cursor::Show
@@ -921,8 +1357,10 @@ fn main() -> io::Result<()> {
         eprintln!("Error: {}", err);
     }
 
-    // Print goodbye message
-    print_goodbye_message();
+    // Print goodbye message (skipped when used as a pager)
+    if !app.view_only {
+        print_goodbye_message();
+    }
 
     Ok(())
 }
@@ -941,75 +1379,45 @@ fn print_goodbye_message() {
 }
 
 fn check_for_updates() {
-    let current_version = env!("CARGO_PKG_VERSION");
-
-    // Fetch latest version from GitHub (with timeout)
-    let output = std::process::Command::new("curl")
-        .args([
-            "-fsSL",
-            "--max-time", "3",
-            "https://raw.githubusercontent.com/kstost/cokacdir/refs/heads/main/Cargo.toml"
-        ])
-        .output();
-
-    let latest_version = match output {
-        Ok(output) if output.status.success() => {
-            let content = String::from_utf8_lossy(&output.stdout);
-            parse_version_from_cargo_toml(&content)
-        }
-        _ => None,
-    };
+    use services::self_update;
 
-    if let Some(latest) = latest_version {
-        if is_newer_version(&latest, current_version) {
+    if !config::Settings::load_with_error().map_or(true, |s| s.check_updates) {
+        return;
+    }
+    let current_version = self_update::CURRENT_VERSION;
+    if let Some(latest) = self_update::latest_version(self_update::CHECK_TIMEOUT) {
+        if self_update::is_newer_version(&latest, current_version) {
             println!("┌──────────────────────────────────────────────────────────────────────────┐");
             println!("│  🚀 New version available: v{} (current: v{})                            ", latest, current_version);
             println!("│                                                                          │");
             println!("│  Update with:                                                            │");
-            println!("│  /bin/bash -c \"$(curl -fsSL https://cokacdir.cokac.com/install.sh)\"      │");
+            println!("│  cokacdir --self-update                                                  │");
             println!("└──────────────────────────────────────────────────────────────────────────┘");
             println!();
         }
     }
 }
 
-fn parse_version_from_cargo_toml(content: &str) -> Option<String> {
-    for line in content.lines() {
-        let line = line.trim();
-        if line.starts_with("version") {
-            // Parse: version = "x.x.x"
-            if let Some(start) = line.find('"') {
-                if let Some(end) = line.rfind('"') {
-                    if start < end {
-                        return Some(line[start + 1..end].to_string());
-                    }
-                }
-            }
-        }
-    }
-    None
-}
-
-fn is_newer_version(latest: &str, current: &str) -> bool {
-    let parse = |v: &str| -> Vec<u32> {
-        v.split('.')
-            .filter_map(|s| s.parse().ok())
-            .collect()
-    };
-
-    let latest_parts = parse(latest);
-    let current_parts = parse(current);
+fn handle_self_update(force: bool) {
+    use services::self_update::{self, UpdateOutcome};
 
-    for i in 0..latest_parts.len().max(current_parts.len()) {
-        let l = latest_parts.get(i).copied().unwrap_or(0);
-        let c = current_parts.get(i).copied().unwrap_or(0);
-        if l > c {
-            return true;
-        } else if l < c {
-            return false;
+    let settings = config::Settings::load_with_error().unwrap_or_default();
+    if !settings.check_updates {
+        eprintln!("Error: updates are disabled (\"check_updates\": false in ~/.cokacdir/settings.json)");
+        std::process::exit(1);
+    }
+    match self_update::self_update(force, |step| println!("{}", step)) {
+        Ok(UpdateOutcome::AlreadyLatest(version)) => {
+            println!("cokacdir v{} is the latest version", version);
+        }
+        Ok(UpdateOutcome::Updated { from, to, path }) => {
+            println!("Updated {} from v{} to v{}", path.display(), from, to);
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
         }
     }
-    false
 }
 
 fn run_app<B: ratatui::backend::Backend>(
@@ -1030,35 +1438,46 @@ fn run_app<B: ratatui::backend::Backend>(
             && app.file_info_state.as_ref().map(|s| s.is_calculating).unwrap_or(false);
         let is_image_loading = app.current_screen == Screen::ImageViewer
             && app.image_viewer_state.as_ref().map(|s| s.is_loading).unwrap_or(false);
+        let is_highlighting = app.current_screen == Screen::FileEditor
+            && app.editor_state.as_ref().map(|s| s.highlight_cache.is_busy()).unwrap_or(false);
+        let is_searching = app.search_result_state.searching;
+        let is_viewer_searching = app.current_screen == Screen::FileViewer
+            && app.viewer_state.as_ref().map(|s| s.is_searching()).unwrap_or(false);
         let is_diff_comparing = app.current_screen == Screen::DiffScreen
             && app.diff_state.as_ref().map(|s| s.is_comparing).unwrap_or(false);
         let is_dedup_active = app.current_screen == Screen::DedupScreen
             && app.dedup_screen_state.as_ref().map(|s| !s.is_complete).unwrap_or(false);
+        let is_checksum_active = app.current_screen == Screen::ChecksumScreen
+            && app.checksum_state.as_ref().map(|s| !s.is_complete).unwrap_or(false);
+        let is_cleanup_active = app.current_screen == Screen::CleanupScreen
+            && app.cleanup_screen_state.as_ref().map(|s| s.busy).unwrap_or(false);
+        let is_backup_running = app.current_screen == Screen::BackupScreen
+            && app.backup_screen_state.as_ref().map(|s| s.is_running()).unwrap_or(false);
+        let is_timeline_scanning = app.current_screen == Screen::TimelineScreen
+            && app.timeline_screen_state.as_ref().map(|s| s.scanning).unwrap_or(false);
+        let is_git_log_loading = app.current_screen == Screen::GitLogScreen
+            && app.git_log_screen_state.as_ref().map(|s| s.is_loading()).unwrap_or(false);
         let is_progress_active = app.file_operation_progress
             .as_ref()
             .map(|p| p.is_active)
             .unwrap_or(false);
         let is_remote_spinner = app.remote_spinner.is_some();
+        let has_jobs = !app.jobs.jobs.is_empty();
 
         let poll_timeout = if is_progress_active || is_dedup_active {
             Duration::from_millis(16) // ~60fps for smooth real-time updates
-        } else if is_remote_spinner {
-            Duration::from_millis(100) // Fast polling for spinner animation
-        } else if app.current_screen == Screen::AIScreen || app.is_ai_mode() || is_file_info_calculating || is_image_loading || is_diff_comparing {
+        } else if is_remote_spinner || has_jobs {
+            Duration::from_millis(100) // Fast polling for spinner animation / job progress
+        } else if app.current_screen == Screen::AIScreen || app.ai_state.is_some() || !app.ai_conversations.is_empty() || is_file_info_calculating || is_image_loading || is_diff_comparing || is_highlighting || is_viewer_searching || is_searching || is_checksum_active || is_cleanup_active || is_backup_running || is_timeline_scanning || is_git_log_loading {
             Duration::from_millis(100) // Fast polling for spinner animation
         } else {
             Duration::from_millis(250)
         };
 
-        // Poll for AI responses if on AI screen or AI mode (panel)
-        if app.current_screen == Screen::AIScreen || app.is_ai_mode() {
-            if let Some(ref mut state) = app.ai_state {
-                // poll_response()가 true를 반환하면 새 내용이 추가된 것
-                let has_new_content = state.poll_response();
-                if has_new_content {
-                    app.refresh_panels();
-                }
-            }
+        // Poll for AI responses (AI screen, AI panel, or running in the background)
+        // poll_ai_conversations()가 true를 반환하면 보이는 대화에 새 내용이 추가된 것
+        if app.poll_ai_conversations() {
+            app.refresh_panels();
         }
 
         // Poll for file info calculation if on FileInfo screen
@@ -1091,9 +1510,13 @@ fn run_app<B: ratatui::backend::Backend>(
             if let Some(ref mut state) = app.diff_state {
                 let just_completed = state.poll();
                 if just_completed && !state.has_differences() {
+                    let message = match state.last_sync.take() {
+                        Some(sync) => format!("{} - no differences left", sync),
+                        None => "No differences found".to_string(),
+                    };
                     app.diff_state = None;
                     app.current_screen = Screen::FilePanel;
-                    app.show_message("No differences found");
+                    app.show_message(&message);
                 }
             }
         }
@@ -1101,64 +1524,35 @@ fn run_app<B: ratatui::backend::Backend>(
         // Poll for remote spinner completion
         app.poll_remote_spinner();
 
+        // Report files handled by watch rules
+        app.poll_watch_service();
+
+        // Handle requests from the control socket
+        if handle_ipc_requests(app) {
+            return Ok(());
+        }
+
         // Check for theme file changes (hot-reload, only in design mode)
         if app.design_mode && app.theme_watch_state.check_for_changes() {
             app.reload_theme();
         }
 
+        // Poll background jobs
+        app.poll_jobs();
+        app.poll_dir_sizes();
+        app.poll_git_status();
+        app.poll_preview_pane();
+        app.poll_search_results();
+        app.record_directory_visits();
+        app.sync_tree_sidebar();
+        app.journal_unsaved_buffer();
+
         // Poll for file operation progress
         let progress_message: Option<String> = if let Some(ref mut progress) = app.file_operation_progress {
             let still_active = progress.poll();
             if !still_active {
                 // Operation completed - extract result info before releasing borrow
-                let msg = if let Some(ref result) = progress.result {
-                    // Special handling for Tar - show archive name
-                    if progress.operation_type == crate::services::file_ops::FileOperationType::Tar {
-                        if result.failure_count == 0 {
-                            if let Some(ref archive_name) = app.pending_tar_archive {
-                                Some(format!("Created: {}", archive_name))
-                            } else {
-                                Some(format!("Archived {} file(s)", result.success_count))
-                            }
-                        } else {
-                            Some(format!("Error: {}", result.last_error.as_deref().unwrap_or("Archive failed")))
-                        }
-                    } else if progress.operation_type == crate::services::file_ops::FileOperationType::Untar {
-                        if result.failure_count == 0 {
-                            if let Some(ref extract_dir) = app.pending_extract_dir {
-                                Some(format!("Extracted to: {}", extract_dir))
-                            } else {
-                                Some(format!("Extracted {} file(s)", result.success_count))
-                            }
-                        } else {
-                            Some(format!("Error: {}", result.last_error.as_deref().unwrap_or("Extract failed")))
-                        }
-                    } else {
-                        let op_name = match progress.operation_type {
-                            crate::services::file_ops::FileOperationType::Copy => "Copied",
-                            crate::services::file_ops::FileOperationType::Move => "Moved",
-                            crate::services::file_ops::FileOperationType::Tar => "Archived",
-                            crate::services::file_ops::FileOperationType::Untar => "Extracted",
-                            crate::services::file_ops::FileOperationType::Download => "Downloaded",
-                            crate::services::file_ops::FileOperationType::Encrypt => "Encrypted",
-                            crate::services::file_ops::FileOperationType::Decrypt => "Decrypted",
-                        };
-                        let total = result.success_count + result.failure_count;
-                        if result.failure_count == 0 {
-                            Some(format!("{} {} file(s)", op_name, result.success_count))
-                        } else {
-                            Some(format!("{} {}/{}. Error: {}",
-                                op_name,
-                                result.success_count,
-                                total,
-                                result.last_error.as_deref().unwrap_or("Unknown error")
-                            ))
-                        }
-                    }
-                } else {
-                    None
-                };
-                msg
+                progress.completion_message(app.pending_tar_archive.as_deref(), app.pending_extract_dir.as_deref())
             } else {
                 None
             }
@@ -1168,6 +1562,13 @@ fn run_app<B: ratatui::backend::Backend>(
 
         // Handle progress completion (outside of borrow)
         if progress_message.is_some() {
+            if let (Some(msg), Some(progress)) = (progress_message.as_deref(), app.file_operation_progress.as_ref()) {
+                let job_in_view = app.current_screen == Screen::FilePanel;
+                app.notify_job_finished("cokacdir", msg, progress.started_at, job_in_view);
+                let success = progress.result.as_ref().map_or(false, |r| r.failure_count == 0);
+                let title = format!("{:?} {}", progress.operation_type, if success { "finished" } else { "failed" });
+                app.notify_webhooks(&title, msg, success, progress.started_at);
+            }
             // 원격 다운로드 완료 → 편집기/뷰어 열기
             if let Some(pending) = app.pending_remote_open.take() {
                 app.file_operation_progress = None;
@@ -1177,6 +1578,7 @@ fn run_app<B: ratatui::backend::Backend>(
                 let tmp_exists = match &pending {
                     crate::ui::app::PendingRemoteOpen::Editor { tmp_path, .. } => tmp_path.exists(),
                     crate::ui::app::PendingRemoteOpen::ImageViewer { tmp_path } => tmp_path.exists(),
+                    crate::ui::app::PendingRemoteOpen::Viewer { tmp_path, .. } => tmp_path.exists(),
                 };
 
                 if !tmp_exists {
@@ -1224,9 +1626,19 @@ fn run_app<B: ratatui::backend::Backend>(
                                 app.current_screen = Screen::ImageViewer;
                             }
                         }
+                        crate::ui::app::PendingRemoteOpen::Viewer { tmp_path, preview, scroll } => {
+                            app.open_remote_preview(&tmp_path, preview, scroll);
+                        }
                     }
                 }
             } else {
+                // 디렉터리 동기화 완료 → 다시 비교
+                if app.current_screen == Screen::DiffScreen {
+                    if let Some(ref mut state) = app.diff_state {
+                        state.last_sync = progress_message.clone();
+                        state.start_comparison();
+                    }
+                }
                 if let Some(msg) = progress_message {
                     app.show_message(&msg);
                 }
@@ -1280,6 +1692,9 @@ fn run_app<B: ratatui::backend::Backend>(
                         }
                         Screen::FileViewer => {
                             ui::file_viewer::handle_input(app, key.code, key.modifiers);
+                            if app.view_only && app.current_screen == Screen::FilePanel {
+                                return Ok(());
+                            }
                         }
                         Screen::FileEditor => {
                             ui::file_editor::handle_input(app, key.code, key.modifiers);
@@ -1296,15 +1711,19 @@ fn run_app<B: ratatui::backend::Backend>(
                             }
                         }
                         Screen::AIScreen => {
-                            if let Some(ref mut state) = app.ai_state {
-                                if ui::ai_screen::handle_input(state, key.code, key.modifiers, &app.keybindings) {
-                                    // Save session to file before leaving
-                                    state.save_session_to_file();
-                                    app.current_screen = Screen::FilePanel;
-                                    app.ai_state = None;
-                                    // Refresh panels in case AI modified files
-                                    app.refresh_panels();
-                                }
+                            app.update_ai_panel_context();
+                            let leave = match app.ai_state {
+                                Some(ref mut state) => ui::ai_screen::handle_input(state, key.code, key.modifiers, &app.keybindings),
+                                None => false,
+                            };
+                            if leave {
+                                // Save session (or keep a running request in the background)
+                                app.current_screen = Screen::FilePanel;
+                                app.release_ai_state();
+                                // Refresh panels in case AI modified files
+                                app.refresh_panels();
+                            } else {
+                                app.handle_ai_screen_requests();
                             }
                         }
                         Screen::SystemInfo => {
@@ -1339,7 +1758,12 @@ fn run_app<B: ratatui::backend::Backend>(
                             }
                         }
                         Screen::DiffScreen => {
-                            ui::diff_screen::handle_input(app, key.code, key.modifiers);
+                            // 동기화 진행 다이얼로그
+                            if app.dialog.is_some() {
+                                ui::dialogs::handle_dialog_input(app, key.code, key.modifiers);
+                            } else {
+                                ui::diff_screen::handle_input(app, key.code, key.modifiers);
+                            }
                         }
                         Screen::DiffFileView => {
                             ui::diff_file_view::handle_input(app, key.code, key.modifiers);
@@ -1356,8 +1780,140 @@ fn run_app<B: ratatui::backend::Backend>(
                                 }
                             }
                         }
+                        Screen::ChecksumScreen => {
+                            if let Some(ref mut state) = app.checksum_state {
+                                if ui::checksum_screen::handle_input(state, key.code, key.modifiers) {
+                                    app.current_screen = Screen::FilePanel;
+                                    app.checksum_state = None;
+                                    // A manifest may have been written
+                                    app.refresh_panels();
+                                }
+                            }
+                        }
+                        Screen::AuditScreen => {
+                            if let Some(ref mut state) = app.audit_screen_state {
+                                if ui::audit_screen::handle_input(state, key.code, key.modifiers) {
+                                    app.current_screen = Screen::FilePanel;
+                                    app.audit_screen_state = None;
+                                }
+                            }
+                        }
+                        Screen::ArchiveViewer => {
+                            if let Some(ref mut state) = app.archive_viewer_state {
+                                if ui::archive_viewer::handle_input(state, key.code, key.modifiers) {
+                                    let extract_all = state.extract_all_request.then(|| state.archive_path.clone());
+                                    let extracted = state.extracted;
+                                    app.current_screen = Screen::FilePanel;
+                                    app.archive_viewer_state = None;
+                                    if let Some(path) = extract_all {
+                                        app.execute_extract(&path);
+                                    } else if extracted {
+                                        app.refresh_panels();
+                                    }
+                                }
+                            }
+                        }
+                        Screen::TrashScreen => {
+                            if let Some(ref mut state) = app.trash_screen_state {
+                                if ui::trash_screen::handle_input(state, key.code, key.modifiers) {
+                                    let restored = state.restored;
+                                    app.current_screen = Screen::FilePanel;
+                                    app.trash_screen_state = None;
+                                    if restored {
+                                        app.refresh_panels();
+                                    }
+                                }
+                            }
+                        }
+                        Screen::BulkRename => {
+                            if let Some(ref mut state) = app.bulk_rename_state {
+                                if ui::bulk_rename::handle_input(state, key.code, key.modifiers) {
+                                    let renamed = state.renamed;
+                                    app.current_screen = Screen::FilePanel;
+                                    app.bulk_rename_state = None;
+                                    if let Some(count) = renamed {
+                                        app.refresh_panels();
+                                        app.show_message(&format!("Renamed {} file(s)", count));
+                                    }
+                                }
+                            }
+                        }
+                        Screen::RecoveryScreen => {
+                            if let Some(ref mut state) = app.recovery_screen_state {
+                                if ui::recovery_screen::handle_input(state, key.code, key.modifiers) {
+                                    let action = state.action.take();
+                                    let message = std::mem::take(&mut state.message);
+                                    let remaining = state.remaining();
+                                    app.current_screen = Screen::FilePanel;
+                                    app.recovery_screen_state = None;
+                                    match action {
+                                        Some(ui::recovery_screen::RecoveryAction::Resume(op)) => app.resume_operation(op),
+                                        Some(ui::recovery_screen::RecoveryAction::RecoverBuffer { path, content }) => {
+                                            app.recover_buffer(&path, &content);
+                                        }
+                                        None if !message.is_empty() => app.show_message(&message),
+                                        None if remaining > 0 => {
+                                            app.show_message(&format!("{} item(s) to recover will be offered on the next start", remaining));
+                                        }
+                                        None => {}
+                                    }
+                                }
+                            }
+                        }
+                        Screen::DrivesScreen => {
+                            if let Some(ref mut state) = app.drives_screen_state {
+                                if ui::drives_screen::handle_input(state, key.code, key.modifiers) {
+                                    let chosen = state.chosen.take();
+                                    app.current_screen = Screen::FilePanel;
+                                    app.drives_screen_state = None;
+                                    if let Some(path) = chosen {
+                                        app.goto_directory_with_focus(&path, None);
+                                    }
+                                }
+                            }
+                        }
+                        Screen::CleanupScreen => {
+                            if let Some(ref mut state) = app.cleanup_screen_state {
+                                if ui::cleanup_screen::handle_input(state, key.code, key.modifiers) {
+                                    app.current_screen = Screen::FilePanel;
+                                    app.cleanup_screen_state = None;
+                                    app.refresh_panels();
+                                }
+                            }
+                        }
+                        Screen::BackupScreen => {
+                            ui::backup_screen::handle_input(app, key.code, key.modifiers);
+                            if app.current_screen == Screen::FilePanel {
+                                app.refresh_panels();
+                            }
+                        }
+                        Screen::TimelineScreen => {
+                            if let Some(ref mut state) = app.timeline_screen_state {
+                                if ui::timeline_screen::handle_input(state, key.code, key.modifiers) {
+                                    let chosen = state.chosen.take();
+                                    app.current_screen = Screen::FilePanel;
+                                    app.timeline_screen_state = None;
+                                    if let Some(path) = chosen {
+                                        if let Some(dir) = path.parent() {
+                                            let name = path.file_name().map(|n| n.to_string_lossy().to_string());
+                                            app.goto_directory_with_focus(dir, name);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        Screen::GitLogScreen => {
+                            // 커밋 비교 다이얼로그
+                            if app.dialog.is_some() {
+                                ui::dialogs::handle_dialog_input(app, key.code, key.modifiers);
+                            } else {
+                                ui::git_log_screen::handle_input(app, key.code, key.modifiers);
+                            }
+                        }
                     }
                 }
+                Event::FocusGained => app.terminal_focused = true,
+                Event::FocusLost => app.terminal_focused = false,
                 Event::Paste(text) => {
                     match app.current_screen {
                         Screen::AIScreen => {
@@ -1367,12 +1923,12 @@ fn run_app<B: ratatui::backend::Backend>(
                         }
                         Screen::FilePanel => {
                             // AI mode with focus on AI panel
-                            if app.is_ai_mode() && app.ai_panel_index == Some(app.active_panel_index) {
+                            if app.dialog.is_some() {
+                                ui::dialogs::handle_paste(app, &text);
+                            } else if app.is_ai_mode() && app.ai_panel_index == Some(app.active_panel_index) {
                                 if let Some(ref mut state) = app.ai_state {
                                     ui::ai_screen::handle_paste(state, &text);
                                 }
-                            } else if app.dialog.is_some() {
-                                ui::dialogs::handle_paste(app, &text);
                             } else if app.advanced_search_state.active {
                                 ui::advanced_search::handle_paste(&mut app.advanced_search_state, &text);
                             }
@@ -1399,9 +1955,45 @@ fn run_app<B: ratatui::backend::Backend>(
     }
 }
 
+/// Handle pending control socket requests. Returns true if a request asked the app to quit.
+fn handle_ipc_requests(app: &mut App) -> bool {
+    use crate::services::ipc::{IpcError, METHOD_NOT_FOUND};
+
+    while let Some(request) = app.ipc_server.as_ref().and_then(|s| s.try_recv()) {
+        let mut quit = false;
+        let result = match request.method.as_str() {
+            "ping" => Ok(serde_json::json!({ "version": VERSION })),
+            "get_state" => Ok(app.ipc_state()),
+            "get_selection" => Ok(app.ipc_selection()),
+            "navigate" => app.ipc_navigate(&request.params),
+            "select" => app.ipc_select(&request.params),
+            "action" => match request.str_param("name") {
+                None => Err(IpcError::invalid_params("Missing 'name'")),
+                Some(name) => match serde_json::from_value::<PanelAction>(serde_json::json!(name)) {
+                    Err(_) => Err(IpcError::invalid_params(format!("Unknown action: {}", name))),
+                    Ok(action) => app.ipc_check_idle().map(|_| {
+                        quit = execute_panel_action(app, action);
+                        serde_json::json!({ "action": name })
+                    }),
+                },
+            },
+            other => Err(IpcError::new(METHOD_NOT_FOUND, format!("Unknown method: {}", other))),
+        };
+        request.respond(result);
+        if quit {
+            return true;
+        }
+    }
+    false
+}
+
 fn handle_panel_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool {
     // AI 모드일 때: active_panel이 AI 패널 쪽이면 AI로 입력 전달, 아니면 파일 패널 조작
     if app.is_ai_mode() {
+        // AI 화면에서 띄운 다이얼로그 (코드 블록 저장 등)
+        if app.dialog.is_some() {
+            return ui::dialogs::handle_dialog_input(app, code, modifiers);
+        }
         let ai_has_focus = app.ai_panel_index == Some(app.active_panel_index);
         if app.keybindings.panel_action(code, modifiers) == Some(PanelAction::SwitchPanel) {
             // AI fullscreen 모드에서는 패널 전환 차단
@@ -1412,10 +2004,13 @@ fn handle_panel_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) ->
             return false;
         }
         if ai_has_focus {
+            app.update_ai_panel_context();
             if let Some(ref mut state) = app.ai_state {
                 if ui::ai_screen::handle_input(state, code, modifiers, &app.keybindings) {
                     // AI 화면 종료 요청
                     app.close_ai_screen();
+                } else {
+                    app.handle_ai_screen_requests();
                 }
             }
             return false;
@@ -1425,8 +2020,11 @@ fn handle_panel_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) ->
 
     // Handle advanced search dialog first
     if app.advanced_search_state.active {
-        if let Some(criteria) = ui::advanced_search::handle_input(&mut app.advanced_search_state, code, modifiers, &app.keybindings) {
-            app.execute_advanced_search(&criteria);
+        use ui::advanced_search::AdvancedSearchOutcome;
+        match ui::advanced_search::handle_input(&mut app.advanced_search_state, code, modifiers, &app.keybindings) {
+            Some(AdvancedSearchOutcome::Search(criteria)) => app.execute_advanced_search(&criteria),
+            Some(AdvancedSearchOutcome::SaveSmartFolder(criteria)) => app.show_save_smart_folder_dialog(criteria),
+            None => {}
         }
         return false;
     }
@@ -1436,71 +2034,195 @@ fn handle_panel_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) ->
         return ui::dialogs::handle_dialog_input(app, code, modifiers);
     }
 
+    // Jobs 패널에 포커스가 있으면 작업 선택/취소 (Alt+J는 패널 토글로 전달)
+    if app.jobs.focused && app.keybindings.panel_action(code, modifiers) != Some(PanelAction::Jobs) {
+        if ui::jobs::handle_input(&mut app.jobs, code, modifiers) {
+            app.jobs.focused = false;
+        }
+        return false;
+    }
+
+    // 트리 사이드바에 포커스가 있으면 트리 탐색 (Ctrl+T는 사이드바 토글로 전달)
+    if app.tree_sidebar.as_ref().is_some_and(|t| t.focused)
+        && app.keybindings.panel_action(code, modifiers) != Some(PanelAction::TreeSidebar)
+    {
+        if let Some(ref mut tree) = app.tree_sidebar {
+            if ui::tree_sidebar::handle_input(tree, code, modifiers) {
+                tree.focused = false;
+            }
+        }
+        app.sync_tree_sidebar();
+        return false;
+    }
+
+    // 빠른 필터 입력 중: 문자/Backspace는 필터 편집, Esc는 전체 목록 복원, 나머지 키는 평소대로
+    if let Some(text) = app.active_panel().quick_filter.as_ref().map(|f| f.text.clone()) {
+        let plain = !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match code {
+            KeyCode::Esc => {
+                app.active_panel_mut().clear_quick_filter();
+                return false;
+            }
+            KeyCode::Char(c) if plain => {
+                app.active_panel_mut().set_quick_filter_text(format!("{}{}", text, c));
+                return false;
+            }
+            KeyCode::Backspace => {
+                let mut text = text;
+                text.pop();
+                app.active_panel_mut().set_quick_filter_text(text);
+                return false;
+            }
+            _ => {}
+        }
+    }
 
     // Look up action from keybindings
     if let Some(action) = app.keybindings.panel_action(code, modifiers) {
-        match action {
-            PanelAction::Quit => return true,
-            PanelAction::MoveUp => app.move_cursor(-1),
-            PanelAction::MoveDown => app.move_cursor(1),
-            PanelAction::PageUp => app.move_cursor(-10),
-            PanelAction::PageDown => app.move_cursor(10),
-            PanelAction::GoHome => app.cursor_to_start(),
-            PanelAction::GoEnd => app.cursor_to_end(),
-            PanelAction::Open => app.enter_selected(),
-            PanelAction::ParentDir => {
-                if app.diff_first_panel.is_some() {
-                    app.diff_first_panel = None;
-                    app.show_message("Diff cancelled");
-                } else {
-                    app.go_to_parent();
-                }
+        return execute_panel_action(app, action);
+    }
+    false
+}
+
+/// Execute a file panel action. Returns true if the app should quit.
+fn execute_panel_action(app: &mut App, action: PanelAction) -> bool {
+    match action {
+        PanelAction::Quit => return true,
+        PanelAction::MoveUp => app.move_cursor(-1),
+        PanelAction::MoveDown => app.move_cursor(1),
+        PanelAction::PageUp => app.move_cursor(-10),
+        PanelAction::PageDown => app.move_cursor(10),
+        PanelAction::HalfPageUp => app.scroll_page_fraction(2, false),
+        PanelAction::HalfPageDown => app.scroll_page_fraction(2, true),
+        PanelAction::ThirdPageUp => app.scroll_page_fraction(3, false),
+        PanelAction::ThirdPageDown => app.scroll_page_fraction(3, true),
+        PanelAction::GoHome => app.cursor_to_start(),
+        PanelAction::GoEnd => app.cursor_to_end(),
+        PanelAction::Open => app.enter_selected(),
+        PanelAction::ParentDir => {
+            if app.diff_first_panel.is_some() {
+                app.diff_first_panel = None;
+                app.show_message("Diff cancelled");
+            } else {
+                app.go_to_parent();
             }
-            PanelAction::SwitchPanel => app.switch_panel(),
-            PanelAction::SwitchPanelLeft => app.switch_panel_left(),
-            PanelAction::SwitchPanelRight => app.switch_panel_right(),
-            PanelAction::ToggleSelect => app.toggle_selection(),
-            PanelAction::SelectAll => app.toggle_all_selection(),
-            PanelAction::SelectByExtension => app.select_by_extension(),
-            PanelAction::SelectUp => app.move_cursor_with_selection(-1),
-            PanelAction::SelectDown => app.move_cursor_with_selection(1),
-            PanelAction::Copy => app.clipboard_copy(),
-            PanelAction::Cut => app.clipboard_cut(),
-            PanelAction::Paste => app.clipboard_paste(),
-            PanelAction::SortByName => app.toggle_sort_by_name(),
-            PanelAction::SortByType => app.toggle_sort_by_type(),
-            PanelAction::SortBySize => app.toggle_sort_by_size(),
-            PanelAction::SortByDate => app.toggle_sort_by_date(),
-            PanelAction::Help => app.show_help(),
-            PanelAction::FileInfo => app.show_file_info(),
-            PanelAction::Edit => app.edit_file(),
-            PanelAction::Mkdir => app.show_mkdir_dialog(),
-            PanelAction::Mkfile => app.show_mkfile_dialog(),
-            PanelAction::Delete => app.show_delete_dialog(),
-            PanelAction::ProcessManager => app.show_process_manager(),
-            PanelAction::Rename => app.show_rename_dialog(),
-            PanelAction::Tar => app.show_tar_dialog(),
-            PanelAction::Search => app.show_search_dialog(),
-            PanelAction::GoToPath => app.show_goto_dialog(),
-            PanelAction::AddPanel => app.add_panel(),
-            PanelAction::GoHomeDir => app.goto_home(),
-            PanelAction::Refresh => app.refresh_panels(),
-            PanelAction::GitLogDiff => app.show_git_log_diff_dialog(),
-            PanelAction::StartDiff => app.start_diff(),
-            PanelAction::ClosePanel => app.close_panel(),
-            PanelAction::AIScreen => app.show_ai_screen(),
-            PanelAction::Settings => app.show_settings_dialog(),
-            PanelAction::GitScreen => app.show_git_screen(),
-            PanelAction::ToggleBookmark => app.toggle_bookmark(),
-            PanelAction::SetHandler => app.show_handler_dialog(),
-            PanelAction::EncryptAll => app.show_encrypt_dialog(),
-            PanelAction::DecryptAll => app.show_decrypt_dialog(),
-            PanelAction::RemoveDuplicates => app.show_dedup_screen(),
-            #[cfg(target_os = "macos")]
-            PanelAction::OpenInFinder => app.open_in_finder(),
-            #[cfg(target_os = "macos")]
-            PanelAction::OpenInVSCode => app.open_in_vscode(),
         }
+        PanelAction::SwitchPanel => app.switch_panel(),
+        PanelAction::SwitchPanelLeft => app.switch_panel_left(),
+        PanelAction::SwitchPanelRight => app.switch_panel_right(),
+        PanelAction::ToggleSelect => app.toggle_selection(),
+        PanelAction::SelectAll => app.toggle_all_selection(),
+        PanelAction::SelectByExtension => app.select_by_extension(),
+        PanelAction::InvertSelection => app.invert_selection(),
+        PanelAction::SelectByFilter => app.show_select_filter_dialog(),
+        PanelAction::Calculator => app.show_calculator_dialog(),
+        PanelAction::SelectUp => app.move_cursor_with_selection(-1),
+        PanelAction::SelectDown => app.move_cursor_with_selection(1),
+        PanelAction::Copy => app.clipboard_copy(),
+        PanelAction::Cut => app.clipboard_cut(),
+        PanelAction::Paste => app.clipboard_paste(),
+        PanelAction::PasteExcluding => app.show_paste_exclude_dialog(),
+        PanelAction::PasteImage => app.paste_clipboard_image(),
+        PanelAction::SortByName => app.toggle_sort_by_name(),
+        PanelAction::SortByType => app.toggle_sort_by_type(),
+        PanelAction::SortBySize => app.toggle_sort_by_size(),
+        PanelAction::SortByDate => app.toggle_sort_by_date(),
+        PanelAction::Help => app.show_help(),
+        PanelAction::FileInfo => app.show_file_info(),
+        PanelAction::Edit => app.edit_file(),
+        PanelAction::Mkdir => app.show_mkdir_dialog(),
+        PanelAction::Mkfile => app.show_mkfile_dialog(),
+        PanelAction::Delete => app.show_delete_dialog(),
+        PanelAction::ProcessManager => app.show_process_manager(),
+        PanelAction::Rename => app.show_rename_dialog(),
+        PanelAction::Tar => app.show_tar_dialog(),
+        PanelAction::Search => app.show_search_dialog(),
+        PanelAction::GoToPath => app.show_goto_dialog(),
+        PanelAction::AddPanel => app.add_panel(),
+        PanelAction::GoHomeDir => app.goto_home(),
+        PanelAction::Refresh => app.refresh_panels(),
+        PanelAction::GitLogDiff => app.show_git_log_screen(),
+        PanelAction::StartDiff => app.start_diff(),
+        PanelAction::ClosePanel => app.close_panel(),
+        PanelAction::AIScreen => app.show_ai_screen(),
+        PanelAction::Settings => app.show_settings_dialog(),
+        PanelAction::GitScreen => app.show_git_screen(),
+        PanelAction::ToggleBookmark => app.toggle_bookmark(),
+        PanelAction::SetHandler => app.show_handler_dialog(),
+        PanelAction::OpenWith => app.show_open_with_dialog(),
+        PanelAction::RecentFiles => app.show_recent_files_dialog(),
+        PanelAction::CopyContents => app.copy_file_contents_to_system_clipboard(),
+        PanelAction::EditInSplit => app.open_in_multiplexer(services::multiplexer::PaneTarget::Split, true),
+        PanelAction::EditInWindow => app.open_in_multiplexer(services::multiplexer::PaneTarget::Window, true),
+        PanelAction::ShellInSplit => app.open_in_multiplexer(services::multiplexer::PaneTarget::Split, false),
+        PanelAction::ShellInWindow => app.open_in_multiplexer(services::multiplexer::PaneTarget::Window, false),
+        PanelAction::EncryptAll => app.show_encrypt_dialog(),
+        PanelAction::DecryptAll => app.show_decrypt_dialog(),
+        PanelAction::RemoveDuplicates => app.show_dedup_screen(),
+        PanelAction::Checksums => app.show_checksum_screen(),
+        PanelAction::AuditLog => app.show_audit_screen(),
+        PanelAction::QuickView => app.quick_view(),
+        PanelAction::CompareClipboard => app.compare_with_clipboard(),
+        PanelAction::CopyShellCommand => app.copy_shell_command(),
+        PanelAction::TrashScreen => app.show_trash_screen(),
+        PanelAction::Jobs => app.toggle_jobs_pane(),
+        PanelAction::ToggleDeleteToTrash => app.toggle_delete_to_trash(),
+        PanelAction::ToggleDirSizes => app.toggle_dir_sizes(),
+        PanelAction::QuickFilter => app.active_panel_mut().start_quick_filter(),
+        PanelAction::ToggleHidden => app.toggle_hidden(),
+        PanelAction::GoToLinkTarget => app.goto_link_target(),
+        PanelAction::BulkRename => app.show_bulk_rename(),
+        PanelAction::ChangeExtension => app.show_change_extension_dialog(),
+        PanelAction::UndoExtensionChange => app.undo_extension_change(),
+        PanelAction::DrivesScreen => app.show_drives_screen(),
+        PanelAction::CleanupScreen => app.show_cleanup_screen(),
+        PanelAction::Backups => app.show_backup_screen(),
+        PanelAction::RecentChanges => app.show_timeline_screen(),
+        PanelAction::JumpDirectory => app.show_jump_dialog(),
+        PanelAction::PathMenu => app.show_breadcrumb_menu(),
+        PanelAction::TreeSidebar => app.toggle_tree_sidebar(),
+        PanelAction::AdvancedSearch => app.show_advanced_search_dialog(),
+        PanelAction::ToggleFavoritesBar => app.toggle_favorites_bar(),
+        PanelAction::Favorites => app.show_favorites_dialog(),
+        PanelAction::AddToBasket => app.add_to_basket(),
+        PanelAction::Basket => app.show_basket_dialog(),
+        PanelAction::Favorite1 | PanelAction::Favorite2 | PanelAction::Favorite3
+        | PanelAction::Favorite4 | PanelAction::Favorite5 | PanelAction::Favorite6
+        | PanelAction::Favorite7 | PanelAction::Favorite8 | PanelAction::Favorite9 => {
+            if let Some(n) = action.favorite_number() {
+                app.goto_favorite(n);
+            }
+        }
+        PanelAction::TogglePreviewPane => app.toggle_preview_pane(),
+        PanelAction::SymlinkToOtherPanel => app.symlink_to_target_panel(),
+        PanelAction::CompareChecksums => app.show_checksum_compare(),
+        #[cfg(target_os = "macos")]
+        PanelAction::OpenInFinder => app.open_in_finder(),
+        #[cfg(target_os = "macos")]
+        PanelAction::OpenInVSCode => app.open_in_vscode(),
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_restart_exe_path_strips_deleted_suffix() {
+        assert_eq!(
+            restart_exe_path(PathBuf::from("/usr/local/bin/cokacdir (deleted)")),
+            PathBuf::from("/usr/local/bin/cokacdir")
+        );
+        assert_eq!(
+            restart_exe_path(PathBuf::from("/usr/local/bin/cokacdir")),
+            PathBuf::from("/usr/local/bin/cokacdir")
+        );
+        // Only a trailing suffix is stripped
+        assert_eq!(
+            restart_exe_path(PathBuf::from("/opt/x (deleted)/cokacdir")),
+            PathBuf::from("/opt/x (deleted)/cokacdir")
+        );
+    }
+}
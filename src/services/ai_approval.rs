@@ -0,0 +1,224 @@
+//! Per-call approval of destructive AI tool calls.
+//!
+//! While an AI request runs in approval mode, a socket is created at
+//! `~/.cokacdir/ipc/approve-<pid>-<n>.sock` and Claude is started with a
+//! PreToolUse hook that runs `cokacdir --ai-approval-hook <socket>` for the
+//! gated tools. The hook sends one JSON line to the socket and blocks until
+//! the UI answers:
+//!
+//! ```text
+//! → {"tool":"Bash","input":"{\"command\":\"rm -r build\"}"}
+//! ← allow | deny
+//! ```
+//!
+//! Unanswered requests are denied when the timeout expires, and the hook
+//! denies the call whenever the socket cannot be reached.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+/// Tools that need confirmation in approval mode
+pub const GATED_TOOLS: &[&str] = &["Bash", "Write", "Edit"];
+
+/// Extra seconds the hook waits beyond the approval timeout before giving up
+const HOOK_GRACE_SECS: u64 = 5;
+
+/// Sequence for socket names (several conversations may run at once)
+static SERVER_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// A tool call waiting for the user's decision
+pub struct ApprovalRequest {
+    pub tool: String,
+    /// Raw tool input (JSON)
+    pub input: String,
+    /// When the request is denied automatically
+    pub deadline: Instant,
+    reply: Sender<bool>,
+}
+
+impl ApprovalRequest {
+    /// Send the decision back to the waiting hook
+    pub fn respond(self, allow: bool) {
+        let _ = self.reply.send(allow);
+    }
+
+    /// Seconds left before the automatic denial
+    pub fn remaining_secs(&self) -> u64 {
+        self.deadline.saturating_duration_since(Instant::now()).as_secs()
+    }
+}
+
+/// Approval socket for one AI request. The socket file is removed on drop.
+pub struct ApprovalServer {
+    pub socket_path: PathBuf,
+    pub timeout: Duration,
+    receiver: Receiver<ApprovalRequest>,
+    stop: Arc<AtomicBool>,
+}
+
+impl ApprovalServer {
+    /// Bind the socket and start accepting hook connections in a background thread
+    pub fn start(timeout: Duration) -> std::io::Result<Self> {
+        let dir = dirs::home_dir()
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "home directory not found"))?
+            .join(".cokacdir")
+            .join("ipc");
+        std::fs::create_dir_all(&dir)?;
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))?;
+
+        let seq = SERVER_SEQ.fetch_add(1, Ordering::Relaxed);
+        let socket_path = dir.join(format!("approve-{}-{}.sock", std::process::id(), seq));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path)?;
+        std::fs::set_permissions(&socket_path, std::fs::Permissions::from_mode(0o600))?;
+
+        let (tx, rx) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop_flag.load(Ordering::Relaxed) {
+                    break;
+                }
+                let Ok(stream) = stream else { continue };
+                let tx = tx.clone();
+                thread::spawn(move || handle_connection(stream, tx, timeout));
+            }
+        });
+
+        Ok(Self { socket_path, timeout, receiver: rx, stop })
+    }
+
+    /// Take the next pending request, if any (non-blocking)
+    pub fn try_recv(&self) -> Option<ApprovalRequest> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Claude `--settings` JSON installing the PreToolUse hook for the gated tools
+    pub fn hook_settings(&self) -> String {
+        let exe = std::env::current_exe()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|_| "cokacdir".to_string());
+        let command = format!(
+            "{} --ai-approval-hook {}",
+            shell_quote(&exe),
+            shell_quote(&self.socket_path.display().to_string())
+        );
+        json!({
+            "hooks": {
+                "PreToolUse": [{
+                    "matcher": GATED_TOOLS.join("|"),
+                    "hooks": [{
+                        "type": "command",
+                        "command": command,
+                        "timeout": self.timeout.as_secs() + HOOK_GRACE_SECS,
+                    }],
+                }],
+            },
+        })
+        .to_string()
+    }
+}
+
+impl Drop for ApprovalServer {
+    fn drop(&mut self) {
+        // Wake the accept loop so the thread can exit
+        self.stop.store(true, Ordering::Relaxed);
+        let _ = UnixStream::connect(&self.socket_path);
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+/// Quote a string for POSIX sh
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+fn handle_connection(stream: UnixStream, tx: Sender<ApprovalRequest>, timeout: Duration) {
+    let Ok(mut writer) = stream.try_clone() else { return };
+    let mut line = String::new();
+    if BufReader::new(stream).read_line(&mut line).is_err() {
+        return;
+    }
+    let Ok(value) = serde_json::from_str::<Value>(line.trim()) else {
+        let _ = writeln!(writer, "deny");
+        return;
+    };
+
+    let (reply_tx, reply_rx) = mpsc::channel();
+    let request = ApprovalRequest {
+        tool: value.get("tool").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+        input: value.get("input").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+        deadline: Instant::now() + timeout,
+        reply: reply_tx,
+    };
+    let allow = tx.send(request).is_ok() && reply_rx.recv_timeout(timeout).unwrap_or(false);
+    let _ = writeln!(writer, "{}", if allow { "allow" } else { "deny" });
+}
+
+/// Entry point of `cokacdir --ai-approval-hook <socket>`: reads the
+/// PreToolUse hook input from stdin and returns the process exit code
+/// (0 = allow, 2 = block the tool call).
+pub fn run_hook(socket_path: &str) -> i32 {
+    let mut input = String::new();
+    let _ = std::io::stdin().read_to_string(&mut input);
+    let hook: Value = serde_json::from_str(&input).unwrap_or(Value::Null);
+    let tool = hook.get("tool_name").and_then(|v| v.as_str()).unwrap_or("");
+    let tool_input = hook.get("tool_input").map(|v| v.to_string()).unwrap_or_default();
+
+    match ask(socket_path, tool, &tool_input) {
+        Ok(true) => 0,
+        Ok(false) => {
+            eprintln!("The user denied this {} call in cokacdir. Do not retry it; ask the user how to proceed.", tool);
+            2
+        }
+        Err(e) => {
+            eprintln!("Approval required but cokacdir could not be reached ({}); the {} call was blocked.", e, tool);
+            2
+        }
+    }
+}
+
+fn ask(socket_path: &str, tool: &str, tool_input: &str) -> std::io::Result<bool> {
+    let mut stream = UnixStream::connect(socket_path)?;
+    writeln!(stream, "{}", json!({ "tool": tool, "input": tool_input }))?;
+    let mut answer = String::new();
+    BufReader::new(stream).read_line(&mut answer)?;
+    Ok(answer.trim() == "allow")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_round_trip() {
+        let server = ApprovalServer::start(Duration::from_secs(5)).unwrap();
+        let path = server.socket_path.display().to_string();
+        let client = thread::spawn(move || ask(&path, "Bash", r#"{"command":"ls"}"#).unwrap());
+
+        let request = loop {
+            if let Some(r) = server.try_recv() {
+                break r;
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        assert_eq!(request.tool, "Bash");
+        assert_eq!(request.input, r#"{"command":"ls"}"#);
+        request.respond(true);
+        assert!(client.join().unwrap());
+
+        let settings: Value = serde_json::from_str(&server.hook_settings()).unwrap();
+        assert_eq!(settings["hooks"]["PreToolUse"][0]["matcher"], "Bash|Write|Edit");
+        assert_eq!(settings["hooks"]["PreToolUse"][0]["hooks"][0]["timeout"], 10);
+    }
+}
@@ -0,0 +1,386 @@
+//! Read-only browsing of tar and zip archives (archive viewer screen).
+//!
+//! Listing and extraction use the external `tar` (or `gtar`) and `unzip`
+//! commands, like the Enter-to-extract path does. Entries are kept as a flat
+//! list of full paths inside the archive; directories that only appear as
+//! parents of other entries are added so every level can be browsed.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::process::Command;
+
+const TAR_SUFFIXES: &[&str] = &[
+    ".tar", ".tar.gz", ".tgz", ".tar.bz2", ".tbz2", ".tar.xz", ".txz", ".tar.zst", ".tzst",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    Tar,
+    Zip,
+}
+
+impl ArchiveKind {
+    /// Archive kind recognized from the file name
+    pub fn from_name(name: &str) -> Option<Self> {
+        let lower = name.to_lowercase();
+        if lower.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else if TAR_SUFFIXES.iter().any(|s| lower.ends_with(s)) {
+            Some(ArchiveKind::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    /// Path inside the archive without leading "./" or trailing "/"
+    pub path: String,
+    /// Member name as stored in the archive (used for extraction)
+    pub member: String,
+    pub is_dir: bool,
+    pub size: u64,
+    /// Modification time as listed by the tool (empty for implied directories)
+    pub modified: String,
+}
+
+impl ArchiveEntry {
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+
+    /// Directory containing the entry ("" at the archive root)
+    pub fn parent(&self) -> &str {
+        parent_of(&self.path)
+    }
+}
+
+fn parent_of(path: &str) -> &str {
+    path.rfind('/').map(|i| &path[..i]).unwrap_or("")
+}
+
+/// The configured tar command, or `gtar`/`tar`, if it runs
+pub fn find_tar(custom: Option<&str>) -> Option<String> {
+    let candidates = match custom {
+        Some(cmd) => vec![cmd],
+        None => vec!["gtar", "tar"],
+    };
+    candidates
+        .into_iter()
+        .find(|cmd| {
+            Command::new(cmd)
+                .arg("--version")
+                .output()
+                .map(|o| o.status.success())
+                .unwrap_or(false)
+        })
+        .map(|cmd| cmd.to_string())
+}
+
+/// Decompression flag for tar (zstd and others are auto-detected)
+fn tar_compress_flag(name: &str) -> &'static str {
+    let lower = name.to_lowercase();
+    if lower.ends_with(".tar.gz") || lower.ends_with(".tgz") {
+        "z"
+    } else if lower.ends_with(".tar.bz2") || lower.ends_with(".tbz2") {
+        "j"
+    } else if lower.ends_with(".tar.xz") || lower.ends_with(".txz") {
+        "J"
+    } else {
+        ""
+    }
+}
+
+fn normalize(member: &str) -> String {
+    let mut path = member.trim_end_matches('/');
+    while let Some(rest) = path.strip_prefix("./") {
+        path = rest;
+    }
+    if path == "." {
+        return String::new();
+    }
+    path.trim_start_matches('/').to_string()
+}
+
+/// First `n` whitespace-separated fields of `line` and the rest of the line
+fn split_fields(line: &str, n: usize) -> Option<(Vec<&str>, &str)> {
+    let mut fields = Vec::with_capacity(n);
+    let mut rest = line.trim_start();
+    for _ in 0..n {
+        let end = rest.find(char::is_whitespace)?;
+        fields.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+    if rest.is_empty() {
+        None
+    } else {
+        Some((fields, rest))
+    }
+}
+
+/// Parse `tar -tv` output. GNU tar prints
+/// `-rw-r--r-- user/group 1234 2024-01-01 12:00 name`, bsdtar prints
+/// `-rw-r--r--  0 user group 1234 Jan  1 12:00 name`.
+pub fn parse_tar_listing(output: &str) -> Vec<ArchiveEntry> {
+    let mut entries = Vec::new();
+    for line in output.lines() {
+        let bsd = line
+            .split_whitespace()
+            .nth(1)
+            .map(|f| f.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or(false);
+        let (fields, name) = if bsd {
+            match split_fields(line, 8) {
+                Some((f, name)) => ((f[0], f[4], format!("{} {} {}", f[5], f[6], f[7])), name),
+                None => continue,
+            }
+        } else {
+            match split_fields(line, 5) {
+                Some((f, name)) => ((f[0], f[2], format!("{} {}", f[3], f[4])), name),
+                None => continue,
+            }
+        };
+        let (mode, size, modified) = fields;
+        let name = match mode.chars().next() {
+            Some('l') => name.split(" -> ").next().unwrap_or(name),
+            Some('h') => name.split(" link to ").next().unwrap_or(name),
+            _ => name,
+        };
+        let path = normalize(name);
+        if path.is_empty() {
+            continue;
+        }
+        entries.push(ArchiveEntry {
+            path,
+            member: name.to_string(),
+            is_dir: mode.starts_with('d') || name.ends_with('/'),
+            size: size.parse().unwrap_or(0),
+            modified,
+        });
+    }
+    entries
+}
+
+/// Parse `unzip -l` output: rows between the two dashed lines are
+/// `1234  2024-01-01 12:00   name`
+pub fn parse_zip_listing(output: &str) -> Vec<ArchiveEntry> {
+    let mut entries = Vec::new();
+    let mut in_table = false;
+    for line in output.lines() {
+        if line.trim_start().starts_with("---------") {
+            if in_table {
+                break;
+            }
+            in_table = true;
+            continue;
+        }
+        if !in_table {
+            continue;
+        }
+        let Some((fields, name)) = split_fields(line, 3) else { continue };
+        let path = normalize(name);
+        if path.is_empty() {
+            continue;
+        }
+        entries.push(ArchiveEntry {
+            path,
+            member: name.to_string(),
+            is_dir: name.ends_with('/'),
+            size: fields[0].parse().unwrap_or(0),
+            modified: format!("{} {}", fields[1], fields[2]),
+        });
+    }
+    entries
+}
+
+/// Add directories that only appear as parents of other entries
+fn add_implied_dirs(mut entries: Vec<ArchiveEntry>) -> Vec<ArchiveEntry> {
+    let mut known: HashSet<String> = entries.iter().filter(|e| e.is_dir).map(|e| e.path.clone()).collect();
+    let mut implied = Vec::new();
+    for entry in &entries {
+        // Keep the member prefix ("./") so tar finds the directory
+        let member = entry.member.trim_end_matches('/');
+        let prefix = member.strip_suffix(entry.path.as_str()).unwrap_or("");
+        let mut dir = entry.parent();
+        while !dir.is_empty() {
+            if known.insert(dir.to_string()) {
+                implied.push(ArchiveEntry {
+                    path: dir.to_string(),
+                    member: format!("{}{}", prefix, dir),
+                    is_dir: true,
+                    size: 0,
+                    modified: String::new(),
+                });
+            }
+            dir = parent_of(dir);
+        }
+    }
+    entries.extend(implied);
+    entries
+}
+
+/// List the entries of an archive
+pub fn list(kind: ArchiveKind, tar_cmd: &str, archive: &Path) -> Result<Vec<ArchiveEntry>, String> {
+    let name = archive.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let (program, output) = match kind {
+        ArchiveKind::Tar => (
+            tar_cmd,
+            Command::new(tar_cmd).arg(format!("-tv{}f", tar_compress_flag(&name))).arg(archive).output(),
+        ),
+        ArchiveKind::Zip => ("unzip", Command::new("unzip").arg("-l").arg(archive).output()),
+    };
+    let output = output.map_err(|e| format!("{}: {}", program, e))?;
+    if !output.status.success() {
+        return Err(command_error(program, &output));
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    let entries = match kind {
+        ArchiveKind::Tar => parse_tar_listing(&text),
+        ArchiveKind::Zip => parse_zip_listing(&text),
+    };
+    Ok(add_implied_dirs(entries))
+}
+
+/// Indices of the direct children of `dir`, directories first, then by name
+pub fn children(entries: &[ArchiveEntry], dir: &str) -> Vec<usize> {
+    let mut seen = HashSet::new();
+    let mut indices: Vec<usize> = (0..entries.len())
+        .filter(|&i| entries[i].parent() == dir)
+        // Appended tar members may repeat a path
+        .filter(|&i| seen.insert(entries[i].path.as_str()))
+        .collect();
+    indices.sort_by(|&a, &b| {
+        let (a, b) = (&entries[a], &entries[b]);
+        b.is_dir.cmp(&a.is_dir).then_with(|| a.name().to_lowercase().cmp(&b.name().to_lowercase()))
+    });
+    indices
+}
+
+/// Escape unzip wildcard characters so the name matches literally
+fn zip_literal(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '[' | '*' | '?' => {
+                out.push('[');
+                out.push(c);
+                out.push(']');
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Command-line member arguments extracting `selected` (directories recursively)
+pub fn member_args(kind: ArchiveKind, selected: &[&ArchiveEntry]) -> Vec<String> {
+    selected
+        .iter()
+        .map(|entry| match kind {
+            // tar extracts everything below a directory member
+            ArchiveKind::Tar => entry.member.trim_end_matches('/').to_string(),
+            ArchiveKind::Zip if entry.is_dir => format!("{}/*", zip_literal(entry.member.trim_end_matches('/'))),
+            ArchiveKind::Zip => zip_literal(&entry.member),
+        })
+        .collect()
+}
+
+/// Extract `selected` entries into `dest`, keeping their paths inside the
+/// archive. Existing files are never overwritten.
+pub fn extract(kind: ArchiveKind, tar_cmd: &str, archive: &Path, dest: &Path, selected: &[&ArchiveEntry]) -> Result<(), String> {
+    let members = member_args(kind, selected);
+    let name = archive.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let (program, output) = match kind {
+        ArchiveKind::Tar => (
+            tar_cmd,
+            Command::new(tar_cmd)
+                .arg(format!("-xpk{}f", tar_compress_flag(&name)))
+                .arg(archive)
+                .arg("-C")
+                .arg(dest)
+                .arg("--")
+                .args(&members)
+                .output(),
+        ),
+        ArchiveKind::Zip => (
+            "unzip",
+            Command::new("unzip")
+                .arg("-n")
+                .arg("-q")
+                .arg(archive)
+                .args(&members)
+                .arg("-d")
+                .arg(dest)
+                .output(),
+        ),
+    };
+    let output = output.map_err(|e| format!("{}: {}", program, e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(command_error(program, &output))
+    }
+}
+
+fn command_error(program: &str, output: &std::process::Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    match stderr.lines().map(str::trim).find(|l| !l.is_empty()) {
+        Some(line) => line.to_string(),
+        None => format!("{} exited with {}", program, output.status),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_listings_and_children() {
+        assert_eq!(ArchiveKind::from_name("a.TGZ"), Some(ArchiveKind::Tar));
+        assert_eq!(ArchiveKind::from_name("a.zip"), Some(ArchiveKind::Zip));
+        assert_eq!(ArchiveKind::from_name("a.txt"), None);
+
+        let gnu = "\
+drwxr-xr-x user/user         0 2024-01-01 12:00 ./
+-rw-r--r-- user/user      1234 2024-01-01 12:00 ./src/main.rs
+lrwxrwxrwx user/user         0 2024-01-01 12:00 ./link -> src/main.rs
+-rw-r--r-- user/user         5 2024-01-02 08:30 ./my  notes.txt
+";
+        let entries = add_implied_dirs(parse_tar_listing(gnu));
+        let paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/main.rs", "link", "my  notes.txt", "src"]);
+        assert_eq!(entries[0].size, 1234);
+        assert_eq!(entries[0].modified, "2024-01-01 12:00");
+        // Implied directory keeps the "./" prefix of its children
+        assert_eq!(entries[3].member, "./src");
+        assert!(entries[3].is_dir);
+
+        let bsd = "-rw-r--r--  0 user   staff      42 Jan  1 12:00 docs/readme.md\n";
+        let entries = parse_tar_listing(bsd);
+        assert_eq!(entries[0].path, "docs/readme.md");
+        assert_eq!(entries[0].size, 42);
+        assert_eq!(entries[0].modified, "Jan 1 12:00");
+
+        let zip = "\
+Archive:  test.zip
+  Length      Date    Time    Name
+---------  ---------- -----   ----
+        0  2024-01-01 12:00   dir/
+       10  2024-01-01 12:00   dir/a[1].txt
+        3  2024-01-01 12:00   b.txt
+---------                     -------
+       13                     3 files
+";
+        let entries = add_implied_dirs(parse_zip_listing(zip));
+        assert_eq!(entries.len(), 3);
+        let root = children(&entries, "");
+        assert_eq!(root.iter().map(|&i| entries[i].name()).collect::<Vec<_>>(), vec!["dir", "b.txt"]);
+        let sub = children(&entries, "dir");
+        assert_eq!(sub.iter().map(|&i| entries[i].name()).collect::<Vec<_>>(), vec!["a[1].txt"]);
+
+        let selected = vec![&entries[0], &entries[1]];
+        assert_eq!(member_args(ArchiveKind::Zip, &selected), vec!["dir/*", "dir/a[[]1].txt"]);
+        assert_eq!(member_args(ArchiveKind::Tar, &selected), vec!["dir", "dir/a[1].txt"]);
+    }
+}
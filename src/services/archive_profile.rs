@@ -0,0 +1,164 @@
+//! Archive presets for the "Create Archive" (Tar) dialog.
+//!
+//! Configured in settings.json and cycled with Tab in the dialog:
+//!
+//! ```text
+//! "archive_profiles": [
+//!   { "name": "backup", "format": "tar.xz", "level": 9,
+//!     "exclude": ["node_modules", ".git"], "name_template": "{dir}-{date}" }
+//! ]
+//! ```
+//!
+//! Name template placeholders: `{name}` (first selected file), `{dir}`
+//! (current directory name), `{date}` (YYYY-MM-DD) and `{time}` (HHMMSS).
+//! The format's extension is appended unless the template already has it.
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ArchiveFormat {
+    #[serde(rename = "tar")]
+    Tar,
+    #[default]
+    #[serde(rename = "tar.gz")]
+    TarGz,
+    #[serde(rename = "tar.bz2")]
+    TarBz2,
+    #[serde(rename = "tar.xz")]
+    TarXz,
+    #[serde(rename = "tar.zst")]
+    TarZst,
+    #[serde(rename = "zip")]
+    Zip,
+    /// Created with the external 7z command
+    #[serde(rename = "7z")]
+    SevenZ,
+}
+
+impl ArchiveFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::TarBz2 => "tar.bz2",
+            ArchiveFormat::TarXz => "tar.xz",
+            ArchiveFormat::TarZst => "tar.zst",
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::SevenZ => "7z",
+        }
+    }
+
+    /// Format implied by an archive file name
+    pub fn from_name(name: &str) -> Self {
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            ArchiveFormat::TarGz
+        } else if name.ends_with(".tar.bz2") || name.ends_with(".tbz2") {
+            ArchiveFormat::TarBz2
+        } else if name.ends_with(".tar.xz") || name.ends_with(".txz") {
+            ArchiveFormat::TarXz
+        } else if name.ends_with(".tar.zst") || name.ends_with(".tzst") {
+            ArchiveFormat::TarZst
+        } else if name.ends_with(".zip") {
+            ArchiveFormat::Zip
+        } else if name.ends_with(".7z") {
+            ArchiveFormat::SevenZ
+        } else {
+            ArchiveFormat::Tar
+        }
+    }
+
+    /// Compressor and its valid level range
+    fn compressor(self) -> Option<(&'static str, u32, u32)> {
+        match self {
+            // zip/7z are not tar formats; their level is passed to the archiver
+            ArchiveFormat::Tar | ArchiveFormat::Zip | ArchiveFormat::SevenZ => None,
+            ArchiveFormat::TarGz => Some(("gzip", 1, 9)),
+            ArchiveFormat::TarBz2 => Some(("bzip2", 1, 9)),
+            ArchiveFormat::TarXz => Some(("xz", 0, 9)),
+            ArchiveFormat::TarZst => Some(("zstd", 1, 19)),
+        }
+    }
+
+    /// Command for tar's `--use-compress-program`, or None to use tar's own
+    /// compression flag. zstd always goes through the program since not every
+    /// tar has `--zstd`.
+    pub fn compress_program(self, level: Option<u32>) -> Option<String> {
+        let (program, min, max) = self.compressor()?;
+        match level {
+            Some(level) => Some(format!("{} -{}", program, level.clamp(min, max))),
+            None if self == ArchiveFormat::TarZst => Some(program.to_string()),
+            None => None,
+        }
+    }
+}
+
+fn default_name_template() -> String {
+    "{name}".to_string()
+}
+
+/// A named archive preset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveProfile {
+    pub name: String,
+    #[serde(default)]
+    pub format: ArchiveFormat,
+    /// Compression level (compressor default if unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<u32>,
+    /// Patterns passed to tar as --exclude (e.g. "node_modules", "*.log")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    #[serde(default = "default_name_template")]
+    pub name_template: String,
+}
+
+impl ArchiveProfile {
+    /// Archive file name for `first_file` in directory `dir_name`
+    pub fn archive_name(&self, first_file: &str, dir_name: &str, now: DateTime<Local>) -> String {
+        let name = self
+            .name_template
+            .replace("{name}", first_file)
+            .replace("{dir}", dir_name)
+            .replace("{date}", &now.format("%Y-%m-%d").to_string())
+            .replace("{time}", &now.format("%H%M%S").to_string());
+        let extension = self.format.extension();
+        if name.ends_with(&format!(".{}", extension)) {
+            name
+        } else {
+            format!("{}.{}", name, extension)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_archive_name() {
+        let now = Local.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+        let profile: ArchiveProfile =
+            serde_json::from_str(r#"{"name": "backup", "format": "tar.xz", "name_template": "{dir}-{date}_{time}"}"#).unwrap();
+        assert_eq!(profile.archive_name("a.txt", "project", now), "project-2026-03-04_050607.tar.xz");
+
+        let profile: ArchiveProfile = serde_json::from_str(r#"{"name": "plain"}"#).unwrap();
+        assert_eq!(profile.format, ArchiveFormat::TarGz);
+        assert_eq!(profile.archive_name("src", "project", now), "src.tar.gz");
+    }
+
+    #[test]
+    fn test_compress_program() {
+        assert_eq!(ArchiveFormat::TarGz.compress_program(None), None);
+        assert_eq!(ArchiveFormat::TarGz.compress_program(Some(12)), Some("gzip -9".to_string()));
+        assert_eq!(ArchiveFormat::TarZst.compress_program(None), Some("zstd".to_string()));
+        assert_eq!(ArchiveFormat::Tar.compress_program(Some(5)), None);
+        assert_eq!(ArchiveFormat::from_name("x.tgz"), ArchiveFormat::TarGz);
+        assert_eq!(ArchiveFormat::from_name("x.tar"), ArchiveFormat::Tar);
+        assert_eq!(ArchiveFormat::from_name("x.zip"), ArchiveFormat::Zip);
+        assert_eq!(ArchiveFormat::Zip.compress_program(Some(9)), None);
+        let profile: ArchiveProfile = serde_json::from_str(r#"{"name": "win", "format": "7z"}"#).unwrap();
+        assert_eq!(profile.format, ArchiveFormat::SevenZ);
+    }
+}
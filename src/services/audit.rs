@@ -0,0 +1,198 @@
+//! Append-only audit log of Telegram bot interactions.
+//!
+//! Every message the bot receives (including rejected ones), file transfers
+//! and the tools Claude used to answer a prompt are written as one JSON
+//! object per line to `~/.cokacdir/logs/bot_audit.jsonl`. Entries are never
+//! rewritten; the log is read back by `/audit` and the TUI audit viewer.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Longest prompt/command text kept in an entry
+const MAX_SUMMARY_CHARS: usize = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditKind {
+    /// Slash command
+    Command,
+    /// Message sent to Claude
+    Prompt,
+    /// `!command` shell execution
+    Shell,
+    /// File or photo received from the user
+    Upload,
+    /// File sent to the user (`/down`)
+    Download,
+    /// Claude finished (or was stopped); lists the tools it used
+    AiResult,
+    /// Message from a user who is not allowed to use the bot
+    Rejected,
+}
+
+impl AuditKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AuditKind::Command => "command",
+            AuditKind::Prompt => "prompt",
+            AuditKind::Shell => "shell",
+            AuditKind::Upload => "upload",
+            AuditKind::Download => "download",
+            AuditKind::AiResult => "ai",
+            AuditKind::Rejected => "rejected",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Local time, "%Y-%m-%d %H:%M:%S"
+    pub time: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub user_name: String,
+    pub chat_id: i64,
+    pub kind: AuditKind,
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+}
+
+impl AuditEntry {
+    /// New entry stamped with the current time; `summary` is shortened to one line
+    pub fn new(chat_id: i64, kind: AuditKind, summary: &str) -> Self {
+        Self {
+            time: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            user_id: None,
+            user_name: String::new(),
+            chat_id,
+            kind,
+            summary: summarize(summary),
+            files: Vec::new(),
+            tools: Vec::new(),
+        }
+    }
+
+    pub fn with_user(mut self, user_id: u64, user_name: &str) -> Self {
+        self.user_id = Some(user_id);
+        self.user_name = user_name.to_string();
+        self
+    }
+
+    pub fn with_files(mut self, files: Vec<String>) -> Self {
+        self.files = files;
+        self
+    }
+
+    pub fn with_tools(mut self, tools: Vec<String>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// One-line text form used by `/audit` and the viewer
+    pub fn format_line(&self) -> String {
+        let user = match self.user_id {
+            Some(id) if self.user_name.is_empty() => id.to_string(),
+            Some(_) => self.user_name.clone(),
+            None => "-".to_string(),
+        };
+        let mut line = format!("{} {} [{}] {} {}", self.time, user, self.chat_id, self.kind.label(), self.summary);
+        if !self.files.is_empty() {
+            line.push_str(&format!(" files: {}", self.files.join(", ")));
+        }
+        if !self.tools.is_empty() {
+            line.push_str(&format!(" tools: {}", self.tools.join(", ")));
+        }
+        line
+    }
+}
+
+/// Shorten text to a single line of at most MAX_SUMMARY_CHARS characters
+pub fn summarize(text: &str) -> String {
+    let single: String = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single.chars().count() > MAX_SUMMARY_CHARS {
+        let mut short: String = single.chars().take(MAX_SUMMARY_CHARS).collect();
+        short.push('…');
+        short
+    } else {
+        single
+    }
+}
+
+/// Path of the audit log: ~/.cokacdir/logs/bot_audit.jsonl
+pub fn audit_log_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".cokacdir").join("logs").join("bot_audit.jsonl"))
+}
+
+/// Append an entry to the audit log. Failures are ignored so logging never blocks the bot.
+pub fn record(entry: AuditEntry) {
+    if let Some(path) = audit_log_path() {
+        let _ = append_to(&path, &entry);
+    }
+}
+
+/// Append an entry to the log at `path` (created with mode 0600)
+pub fn append_to(path: &Path, entry: &AuditEntry) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(entry)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let mut file = OpenOptions::new().create(true).append(true).mode(0o600).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/// Last `limit` entries of the audit log, oldest first
+pub fn read_recent(limit: usize) -> Vec<AuditEntry> {
+    audit_log_path().map(|p| read_recent_from(&p, limit)).unwrap_or_default()
+}
+
+/// Last `limit` entries of the log at `path`, oldest first (unparsable lines are skipped)
+pub fn read_recent_from(path: &Path, limit: usize) -> Vec<AuditEntry> {
+    let Ok(content) = fs::read_to_string(path) else { return Vec::new() };
+    let entries: Vec<AuditEntry> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let skip = entries.len().saturating_sub(limit);
+    entries.into_iter().skip(skip).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_and_read_recent() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("bot_audit.jsonl");
+
+        let prompt = AuditEntry::new(42, AuditKind::Prompt, "list   the\nfiles").with_user(7, "kim(7)");
+        let upload = AuditEntry::new(42, AuditKind::Upload, "report.pdf")
+            .with_user(7, "kim(7)")
+            .with_files(vec!["/home/kim/Documents/report.pdf".to_string()]);
+        let result = AuditEntry::new(42, AuditKind::AiResult, "completed")
+            .with_tools(vec!["Bash".to_string(), "Read".to_string()]);
+        for entry in [&prompt, &upload, &result] {
+            append_to(&path, entry).unwrap();
+        }
+        fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"not json\n").unwrap();
+
+        let all = read_recent_from(&path, 10);
+        assert_eq!(all, vec![prompt.clone(), upload, result.clone()]);
+        assert_eq!(all[0].summary, "list the files");
+        assert_eq!(read_recent_from(&path, 1), vec![result]);
+        assert!(all[2].format_line().ends_with("[42] ai completed tools: Bash, Read"));
+        assert!(read_recent_from(&dir.path().join("missing"), 10).is_empty());
+
+        let long = "x".repeat(200);
+        assert_eq!(summarize(&long).chars().count(), MAX_SUMMARY_CHARS + 1);
+    }
+}
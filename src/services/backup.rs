@@ -0,0 +1,570 @@
+//! Backups: source → destination pairs copied incrementally.
+//!
+//! Jobs are configured in settings.json (`backups`) and managed from the
+//! Backups screen. The destination is a local folder or a remote location
+//! (`user@host:/path`, `sftp://<profile>/path`) of a saved SFTP profile.
+//! Only files that changed since the last run are copied: a file is skipped
+//! when the destination has the same size and modification time, or, with
+//! `"compare": "hash"`, the same SHA-256 (local destinations only; a remote job
+//! set to hash fails instead of silently comparing times). Nothing is ever
+//! deleted from the destination.
+//!
+//! A job with a cron `schedule` is registered as a local scheduler entry whose
+//! prompt is `@backup <name>`, so `cokacdir --scheduler` runs it. Every run is
+//! appended to `~/.cokacdir/logs/backup_history.jsonl`.
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::checksum::{self, Algorithm};
+use crate::services::file_ops::is_excluded;
+use crate::services::remote::{self, RemoteProfile, SftpFileEntry, SftpSession};
+use crate::services::scheduler::LOCAL_BOT_KEY;
+use crate::services::telegram::{self, ScheduleEntryData};
+use crate::utils::glob::glob_match;
+
+/// Prompt of scheduler entries that run a backup job
+pub const PROMPT_PREFIX: &str = "@backup ";
+
+/// Error messages kept per run
+const MAX_ERRORS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareMode {
+    /// Same size and modification time
+    #[default]
+    Mtime,
+    /// Same size and SHA-256 (local destinations only)
+    Hash,
+}
+
+impl CompareMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            CompareMode::Mtime => "mtime",
+            CompareMode::Hash => "hash",
+        }
+    }
+}
+
+/// One backup job
+/// Example: {"name": "docs", "source": "/home/me/docs", "destination": "me@nas:/backup/docs",
+///           "exclude": ["*.tmp", "node_modules/"], "schedule": "0 2 * * *"}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupJob {
+    pub name: String,
+    pub source: String,
+    pub destination: String,
+    /// File name patterns to copy (all files when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Name patterns to skip; a trailing `/` matches directories only
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Cron expression (`min hour day month weekday`); empty runs only on demand
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub schedule: String,
+    #[serde(default)]
+    pub compare: CompareMode,
+    /// Id of the scheduler entry that runs this job
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_id: Option<String>,
+}
+
+/// Result of one run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BackupRun {
+    pub name: String,
+    /// Local time, "%Y-%m-%d %H:%M:%S"
+    pub started_at: String,
+    pub finished_at: String,
+    pub duration_ms: u64,
+    pub copied: u64,
+    pub skipped: u64,
+    pub failed: u64,
+    /// Bytes copied
+    pub bytes: u64,
+    pub success: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+impl BackupRun {
+    /// One-line summary ("12 copied, 340 unchanged, 0 failed")
+    pub fn summary(&self) -> String {
+        let mut text = format!("{} copied, {} unchanged, {} failed", self.copied, self.skipped, self.failed);
+        if let Some(first) = self.errors.first() {
+            text.push_str(&format!(": {}", first));
+        }
+        text
+    }
+
+    fn add_error(&mut self, message: String) {
+        self.failed += 1;
+        if self.errors.len() < MAX_ERRORS {
+            self.errors.push(message);
+        }
+    }
+}
+
+/// Name of the job a scheduler prompt runs (`@backup <name>`)
+pub fn job_name_from_prompt(prompt: &str) -> Option<&str> {
+    prompt.trim_start().strip_prefix(PROMPT_PREFIX).map(str::trim).filter(|n| !n.is_empty())
+}
+
+fn mtime_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn is_included(include: &[String], name: &str) -> bool {
+    include.is_empty() || include.iter().any(|p| glob_match(p, name, false))
+}
+
+/// Where files are copied to
+trait Destination {
+    /// Whether the file at `rel` has to be copied
+    fn needs_copy(&mut self, rel: &Path, src: &Path, meta: &fs::Metadata, cancel: &AtomicBool) -> Result<bool, String>;
+    fn ensure_dir(&mut self, rel: &Path) -> Result<(), String>;
+    fn copy(&mut self, rel: &Path, src: &Path, meta: &fs::Metadata) -> Result<(), String>;
+}
+
+struct LocalDestination {
+    root: PathBuf,
+    compare: CompareMode,
+}
+
+fn sha256(path: &Path, cancel: &AtomicBool) -> Result<Option<String>, String> {
+    let file = File::open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    checksum::hash_reader(file, Algorithm::Sha256, cancel, &AtomicU64::new(0))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+impl Destination for LocalDestination {
+    fn needs_copy(&mut self, rel: &Path, src: &Path, meta: &fs::Metadata, cancel: &AtomicBool) -> Result<bool, String> {
+        let dest = self.root.join(rel);
+        let Ok(dest_meta) = fs::metadata(&dest) else { return Ok(true) };
+        if dest_meta.len() != meta.len() {
+            return Ok(true);
+        }
+        match self.compare {
+            CompareMode::Mtime => {
+                let (src_time, dest_time) = (meta.modified().ok(), dest_meta.modified().ok());
+                Ok(src_time.map(mtime_secs) != dest_time.map(mtime_secs))
+            }
+            CompareMode::Hash => Ok(sha256(src, cancel)? != sha256(&dest, cancel)?),
+        }
+    }
+
+    fn ensure_dir(&mut self, rel: &Path) -> Result<(), String> {
+        let dir = self.root.join(rel);
+        fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))
+    }
+
+    fn copy(&mut self, rel: &Path, src: &Path, meta: &fs::Metadata) -> Result<(), String> {
+        let dest = self.root.join(rel);
+        // Copy next to the destination and rename it into place: fs::copy keeps
+        // the source mode, so a read-only copy could neither be reopened for
+        // writing nor overwritten by the next run
+        let name = dest.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let tmp = dest.with_file_name(format!(".{}.cokacdir-backup", name));
+        let result = fs::copy(src, &tmp).and_then(|_| {
+            // Keep the source time so the next run sees the file as unchanged
+            if let Ok(modified) = meta.modified() {
+                File::open(&tmp)?.set_modified(modified)?;
+            }
+            fs::rename(&tmp, &dest)
+        });
+        if let Err(e) = result {
+            let _ = fs::remove_file(&tmp);
+            return Err(format!("{}: {}", dest.display(), e));
+        }
+        Ok(())
+    }
+}
+
+struct RemoteDestination {
+    session: SftpSession,
+    root: String,
+    /// Listings of remote directories already read
+    listings: HashMap<String, HashMap<String, SftpFileEntry>>,
+}
+
+impl RemoteDestination {
+    fn remote_path(&self, rel: &Path) -> String {
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        if rel.is_empty() {
+            self.root.clone()
+        } else {
+            format!("{}/{}", self.root.trim_end_matches('/'), rel)
+        }
+    }
+}
+
+impl Destination for RemoteDestination {
+    fn needs_copy(&mut self, rel: &Path, _src: &Path, meta: &fs::Metadata, _cancel: &AtomicBool) -> Result<bool, String> {
+        let dir = self.remote_path(rel.parent().unwrap_or(Path::new("")));
+        let name = rel.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        if !self.listings.contains_key(&dir) {
+            let entries = self.session.list_dir(&dir).unwrap_or_default();
+            self.listings.insert(dir.clone(), entries.into_iter().map(|e| (e.name.clone(), e)).collect());
+        }
+        let Some(entry) = self.listings.get(&dir).and_then(|l| l.get(&name)) else { return Ok(true) };
+        // Uploads get the upload time, so an older local file is unchanged
+        let local_time = meta.modified().map(mtime_secs).unwrap_or(0);
+        Ok(entry.size != meta.len() || (entry.modified.timestamp().max(0) as u64) < local_time)
+    }
+
+    fn ensure_dir(&mut self, rel: &Path) -> Result<(), String> {
+        let dir = self.remote_path(rel);
+        if self.session.dir_exists(&dir) {
+            return Ok(());
+        }
+        self.session.mkdir(&dir)
+    }
+
+    fn copy(&mut self, rel: &Path, src: &Path, _meta: &fs::Metadata) -> Result<(), String> {
+        let dest = self.remote_path(rel);
+        self.session.upload_file(&src.to_string_lossy(), &dest).map(|_| ())
+    }
+}
+
+/// Why a remote job with `"compare": "hash"` is refused
+pub const HASH_REMOTE_ERROR: &str = "SHA-256 compare is only available for local destinations";
+
+/// Whether the destination is a remote location of a saved profile
+pub fn is_remote_destination(destination: &str, profiles: &[RemoteProfile]) -> bool {
+    remote::resolve_remote_location(destination, profiles).is_some()
+}
+
+/// Connect to the destination of a job (local folder or saved remote profile)
+fn open_destination(job: &BackupJob, profiles: &[RemoteProfile]) -> Result<Box<dyn Destination>, String> {
+    let Some((user, host, port, path)) = remote::resolve_remote_location(&job.destination, profiles) else {
+        return Ok(Box::new(LocalDestination { root: PathBuf::from(&job.destination), compare: job.compare }));
+    };
+    if job.compare == CompareMode::Hash {
+        return Err(HASH_REMOTE_ERROR.to_string());
+    }
+    let profile = remote::find_matching_profile(profiles, &user, &host, port)
+        .ok_or_else(|| format!("No saved remote profile for {}@{}:{}", user, host, port))?
+        .with_resolved_secrets()?;
+    let session = SftpSession::connect(&profile)?;
+    Ok(Box::new(RemoteDestination { session, root: path, listings: HashMap::new() }))
+}
+
+fn backup_dir(
+    job: &BackupJob,
+    rel: &Path,
+    dest: &mut dyn Destination,
+    run: &mut BackupRun,
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(&BackupRun, &Path),
+) {
+    let dir = Path::new(&job.source).join(rel);
+    let mut entries: Vec<_> = match fs::read_dir(&dir) {
+        Ok(entries) => entries.flatten().collect(),
+        Err(e) => {
+            run.add_error(format!("{}: {}", dir.display(), e));
+            return;
+        }
+    };
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        if cancel.load(Ordering::Relaxed) {
+            return;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = entry.path();
+        // Symlinks are not followed
+        let Ok(meta) = fs::symlink_metadata(&path) else { continue };
+        let child = rel.join(&name);
+        if meta.is_dir() {
+            if is_excluded(&job.exclude, &name, true) {
+                continue;
+            }
+            if let Err(e) = dest.ensure_dir(&child) {
+                run.add_error(e);
+                continue;
+            }
+            backup_dir(job, &child, dest, run, cancel, on_progress);
+        } else if meta.is_file() {
+            if is_excluded(&job.exclude, &name, false) || !is_included(&job.include, &name) {
+                continue;
+            }
+            match dest.needs_copy(&child, &path, &meta, cancel) {
+                Ok(false) => run.skipped += 1,
+                Ok(true) => match dest.copy(&child, &path, &meta) {
+                    Ok(()) => {
+                        run.copied += 1;
+                        run.bytes += meta.len();
+                    }
+                    Err(e) => run.add_error(e),
+                },
+                Err(e) => run.add_error(e),
+            }
+            on_progress(run, &child);
+        }
+    }
+}
+
+/// Run a job. `on_progress` is called after every file with the totals so far.
+pub fn run_backup(
+    job: &BackupJob,
+    profiles: &[RemoteProfile],
+    cancel: &AtomicBool,
+    on_progress: &mut dyn FnMut(&BackupRun, &Path),
+) -> BackupRun {
+    let timer = Instant::now();
+    let mut run = BackupRun {
+        name: job.name.clone(),
+        started_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        ..Default::default()
+    };
+
+    if !Path::new(&job.source).is_dir() {
+        run.add_error(format!("Source is not a folder: {}", job.source));
+    } else {
+        match open_destination(job, profiles) {
+            Ok(mut dest) => match dest.ensure_dir(Path::new("")) {
+                Ok(()) => backup_dir(job, Path::new(""), dest.as_mut(), &mut run, cancel, on_progress),
+                Err(e) => run.add_error(e),
+            },
+            Err(e) => run.add_error(e),
+        }
+    }
+    if cancel.load(Ordering::Relaxed) {
+        run.add_error("Cancelled".to_string());
+    }
+
+    run.success = run.failed == 0;
+    run.finished_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    run.duration_ms = timer.elapsed().as_millis() as u64;
+    run
+}
+
+/// Run the job called `name` from settings.json and record it in the history
+/// (used by the scheduler). Returns (success, summary).
+pub fn run_named(name: &str) -> (bool, String) {
+    let settings = crate::config::Settings::load();
+    let Some(job) = settings.backups.iter().find(|j| j.name == name) else {
+        return (false, format!("No backup job named {:?}", name));
+    };
+    let run = run_backup(job, &settings.remote_profiles, &AtomicBool::new(false), &mut |_, _| {});
+    if let Some(path) = history_path() {
+        let _ = append_history(&path, &run);
+    }
+    (run.success, run.summary())
+}
+
+/// Path of the run history: ~/.cokacdir/logs/backup_history.jsonl
+pub fn history_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".cokacdir").join("logs").join("backup_history.jsonl"))
+}
+
+/// Append a run to the history at `path` (created with mode 0600)
+pub fn append_history(path: &Path, run: &BackupRun) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(run)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let mut file = OpenOptions::new().create(true).append(true).mode(0o600).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/// All recorded runs, oldest first (unreadable lines are skipped)
+pub fn load_history(path: &Path) -> Vec<BackupRun> {
+    fs::read_to_string(path)
+        .map(|text| text.lines().filter_map(|l| serde_json::from_str(l).ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Register, update or remove the scheduler entry of a job to match its `schedule`
+pub fn sync_schedule(job: &mut BackupJob) -> Result<(), String> {
+    let schedule = job.schedule.trim().to_string();
+    if schedule.is_empty() {
+        remove_schedule(job);
+        return Ok(());
+    }
+    if schedule.split_whitespace().count() != 5 {
+        return Err(format!("Schedule must be a cron expression with 5 fields: {}", schedule));
+    }
+
+    let existing = job.schedule_id.as_ref().and_then(|id| {
+        telegram::list_schedule_entries_pub(LOCAL_BOT_KEY, None).into_iter().find(|e| &e.id == id)
+    });
+    let id = match existing {
+        Some(ref entry) => entry.id.clone(),
+        None => {
+            let ids = telegram::list_all_schedule_ids_pub();
+            loop {
+                let candidate = format!("{:08X}", rand::random::<u32>());
+                if !ids.contains(&candidate) {
+                    break candidate;
+                }
+            }
+        }
+    };
+    telegram::write_schedule_entry_pub(&ScheduleEntryData {
+        id: id.clone(),
+        chat_id: 0,
+        bot_key: LOCAL_BOT_KEY.to_string(),
+        current_path: job.source.clone(),
+        prompt: format!("{}{}", PROMPT_PREFIX, job.name),
+        schedule,
+        schedule_type: "cron".to_string(),
+        once: Some(false),
+        last_run: existing.as_ref().and_then(|e| e.last_run.clone()),
+        created_at: existing
+            .map(|e| e.created_at)
+            .unwrap_or_else(|| chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string()),
+        context_summary: None,
+    })?;
+    job.schedule_id = Some(id);
+    Ok(())
+}
+
+/// Remove the scheduler entry of a job
+pub fn remove_schedule(job: &mut BackupJob) {
+    if let Some(id) = job.schedule_id.take() {
+        telegram::delete_schedule_entry_pub(&id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn job(source: &Path, destination: &Path) -> BackupJob {
+        BackupJob {
+            name: "test".to_string(),
+            source: source.display().to_string(),
+            destination: destination.display().to_string(),
+            include: Vec::new(),
+            exclude: vec!["*.tmp".to_string(), "cache/".to_string()],
+            schedule: String::new(),
+            compare: CompareMode::Mtime,
+            schedule_id: None,
+        }
+    }
+
+    fn run(job: &BackupJob) -> BackupRun {
+        run_backup(job, &[], &AtomicBool::new(false), &mut |_, _| {})
+    }
+
+    #[test]
+    fn test_job_name_from_prompt() {
+        assert_eq!(job_name_from_prompt("@backup docs"), Some("docs"));
+        assert_eq!(job_name_from_prompt("  @backup  my photos "), Some("my photos"));
+        assert_eq!(job_name_from_prompt("@backup "), None);
+        assert_eq!(job_name_from_prompt("!ls"), None);
+    }
+
+    #[test]
+    fn test_incremental_backup() {
+        let src = tempfile::tempdir().unwrap();
+        let dst = tempfile::tempdir().unwrap();
+        fs::create_dir_all(src.path().join("sub")).unwrap();
+        fs::create_dir_all(src.path().join("cache")).unwrap();
+        fs::write(src.path().join("a.txt"), "aaa").unwrap();
+        fs::write(src.path().join("sub/b.txt"), "bb").unwrap();
+        fs::write(src.path().join("junk.tmp"), "x").unwrap();
+        fs::write(src.path().join("cache/c.bin"), "c").unwrap();
+        let dest = dst.path().join("backup");
+        let mut job = job(src.path(), &dest);
+
+        let first = run(&job);
+        assert!(first.success);
+        assert_eq!((first.copied, first.skipped, first.bytes), (2, 0, 5));
+        assert_eq!(fs::read_to_string(dest.join("sub/b.txt")).unwrap(), "bb");
+        assert!(!dest.join("junk.tmp").exists());
+        assert!(!dest.join("cache").exists());
+
+        // Nothing changed: everything is skipped
+        let second = run(&job);
+        assert_eq!((second.copied, second.skipped), (0, 2));
+
+        // Changed content (different size) is copied again
+        fs::write(src.path().join("a.txt"), "aaaa").unwrap();
+        let third = run(&job);
+        assert_eq!((third.copied, third.skipped), (1, 1));
+
+        // Same size and time but different content is only seen by hashing
+        fs::write(dest.join("sub/b.txt"), "xx").unwrap();
+        let modified = fs::metadata(src.path().join("sub/b.txt")).unwrap().modified().unwrap();
+        File::options().write(true).open(dest.join("sub/b.txt")).unwrap().set_modified(modified).unwrap();
+        assert_eq!(run(&job).copied, 0);
+        job.compare = CompareMode::Hash;
+        assert_eq!(run(&job).copied, 1);
+        assert_eq!(fs::read_to_string(dest.join("sub/b.txt")).unwrap(), "bb");
+
+        // Include patterns limit the copied files
+        job.include = vec!["*.md".to_string()];
+        fs::write(src.path().join("notes.md"), "n").unwrap();
+        let only_md = run(&job);
+        assert_eq!((only_md.copied, only_md.skipped), (1, 0));
+    }
+
+    #[test]
+    fn test_read_only_source() {
+        let src = tempfile::tempdir().unwrap();
+        let dst = tempfile::tempdir().unwrap();
+        let file = src.path().join("ro.txt");
+        fs::write(&file, "read only").unwrap();
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o444)).unwrap();
+        let dest = dst.path().join("backup");
+        let job = job(src.path(), &dest);
+
+        let first = run(&job);
+        assert!(first.success, "{:?}", first.errors);
+        assert_eq!(first.copied, 1);
+        assert_eq!(
+            fs::metadata(dest.join("ro.txt")).unwrap().modified().unwrap(),
+            fs::metadata(&file).unwrap().modified().unwrap()
+        );
+        // The copy kept its time, so the second run skips it
+        let second = run(&job);
+        assert_eq!((second.copied, second.skipped), (0, 1));
+
+        // A changed source replaces the read-only copy
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o644)).unwrap();
+        fs::write(&file, "changed content").unwrap();
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o444)).unwrap();
+        let third = run(&job);
+        assert!(third.success, "{:?}", third.errors);
+        assert_eq!(fs::read_to_string(dest.join("ro.txt")).unwrap(), "changed content");
+        let names: Vec<_> = fs::read_dir(&dest).unwrap().flatten().map(|e| e.file_name()).collect();
+        assert_eq!(names, vec![std::ffi::OsString::from("ro.txt")]);
+    }
+
+    #[test]
+    fn test_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("backup_history.jsonl");
+        let mut failed = BackupRun { name: "a".to_string(), ..Default::default() };
+        failed.add_error("disk full".to_string());
+        let ok = BackupRun { name: "b".to_string(), copied: 3, success: true, ..Default::default() };
+        append_history(&path, &failed).unwrap();
+        append_history(&path, &ok).unwrap();
+        assert_eq!(load_history(&path), vec![failed.clone(), ok]);
+        assert_eq!(failed.summary(), "0 copied, 0 unchanged, 1 failed: disk full");
+    }
+
+    #[test]
+    fn test_missing_source_fails() {
+        let dst = tempfile::tempdir().unwrap();
+        let job = job(&dst.path().join("missing"), dst.path());
+        let result = run(&job);
+        assert!(!result.success);
+        assert!(result.errors[0].starts_with("Source is not a folder"));
+    }
+}
@@ -0,0 +1,300 @@
+//! Console output of the Telegram bot server (`--ccserver`).
+//!
+//! - `text`: one line per event (default with a single bot)
+//! - `dashboard`: live table with one row per bot (default with several bots on a terminal)
+//! - `json`: one JSON object per event (`--log-format json`)
+//!
+//! Each bot runs inside a task-local [`BotConsole`] scope so events printed
+//! from its handlers are attributed to it; tasks spawned with [`spawn`] keep
+//! the scope of the task that spawned them.
+
+use std::future::Future;
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Dashboard,
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "text" | "plain" => Some(LogFormat::Text),
+            "dashboard" => Some(LogFormat::Dashboard),
+            "json" => Some(LogFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// Format used when `--log-format` is not given
+    pub fn default_for(bot_count: usize) -> Self {
+        if bot_count > 1 && std::io::stdout().is_terminal() {
+            LogFormat::Dashboard
+        } else {
+            LogFormat::Text
+        }
+    }
+}
+
+static FORMAT: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_format(format: LogFormat) {
+    let value = match format {
+        LogFormat::Text => 0,
+        LogFormat::Dashboard => 1,
+        LogFormat::Json => 2,
+    };
+    FORMAT.store(value, Ordering::Relaxed);
+}
+
+pub fn format() -> LogFormat {
+    match FORMAT.load(Ordering::Relaxed) {
+        1 => LogFormat::Dashboard,
+        2 => LogFormat::Json,
+        _ => LogFormat::Text,
+    }
+}
+
+/// Owner id and number of queued/running requests of a bot, read by the dashboard
+type StatusProbe = Box<dyn Fn() -> Option<(Option<u64>, usize)> + Send + Sync>;
+
+/// Console state of one bot
+pub struct BotConsole {
+    /// 1-based position in the --ccserver token list
+    pub index: usize,
+    name: Mutex<String>,
+    /// (time, message) of the last event
+    last_activity: Mutex<Option<(String, String)>>,
+    errors: AtomicUsize,
+    probe: Mutex<Option<StatusProbe>>,
+    /// Last values returned by the probe (kept when the bot state is busy)
+    owner: Mutex<Option<u64>>,
+    queue: AtomicUsize,
+}
+
+impl BotConsole {
+    pub fn name(&self) -> String {
+        self.name.lock().map(|n| n.clone()).unwrap_or_default()
+    }
+
+    pub fn set_name(&self, name: &str) {
+        if let Ok(mut n) = self.name.lock() {
+            *n = name.to_string();
+        }
+    }
+
+    pub fn set_probe(&self, probe: impl Fn() -> Option<(Option<u64>, usize)> + Send + Sync + 'static) {
+        if let Ok(mut p) = self.probe.lock() {
+            *p = Some(Box::new(probe));
+        }
+    }
+
+    fn record(&self, message: &str) {
+        if is_error(message) {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        if let Ok(mut last) = self.last_activity.lock() {
+            *last = Some((chrono::Local::now().format("%H:%M:%S").to_string(), message.to_string()));
+        }
+    }
+
+    fn refresh(&self) {
+        let status = self.probe.lock().ok().and_then(|p| p.as_ref().and_then(|f| f()));
+        if let Some((owner, queue)) = status {
+            if let Ok(mut o) = self.owner.lock() {
+                *o = owner;
+            }
+            self.queue.store(queue, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Bots of this process, in registration order (for the dashboard)
+static REGISTRY: Mutex<Vec<Arc<BotConsole>>> = Mutex::new(Vec::new());
+
+/// Last server-level message (not tied to a bot), shown under the dashboard
+static SERVER_STATUS: Mutex<String> = Mutex::new(String::new());
+
+/// Create and register the console of bot `index`
+pub fn register(index: usize) -> Arc<BotConsole> {
+    let console = Arc::new(BotConsole {
+        index,
+        name: Mutex::new(format!("bot #{}", index)),
+        last_activity: Mutex::new(None),
+        errors: AtomicUsize::new(0),
+        probe: Mutex::new(None),
+        owner: Mutex::new(None),
+        queue: AtomicUsize::new(0),
+    });
+    if let Ok(mut registry) = REGISTRY.lock() {
+        registry.push(console.clone());
+        registry.sort_by_key(|c| c.index);
+    }
+    console
+}
+
+tokio::task_local! {
+    static CURRENT: Arc<BotConsole>;
+}
+
+/// Console of the bot running the current task, if any
+pub fn current() -> Option<Arc<BotConsole>> {
+    CURRENT.try_with(|c| c.clone()).ok()
+}
+
+/// Run `future` with `console` as the current bot
+pub async fn scope<F: Future>(console: Arc<BotConsole>, future: F) -> F::Output {
+    CURRENT.scope(console, future).await
+}
+
+/// `tokio::spawn` that keeps the current bot scope
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match current() {
+        Some(console) => tokio::spawn(CURRENT.scope(console, future)),
+        None => tokio::spawn(future),
+    }
+}
+
+/// Warning/error lines are marked with ⚠ or ✗
+fn is_error(message: &str) -> bool {
+    message.contains('⚠') || message.contains('✗')
+}
+
+/// Message without the indentation and "[HH:MM:SS]" prefix of text output
+fn strip_prefix(line: &str) -> &str {
+    let trimmed = line.trim();
+    match trimmed.strip_prefix('[').and_then(|rest| rest.split_once("] ")) {
+        Some((ts, rest)) if ts.len() == 8 && ts.chars().all(|c| c.is_ascii_digit() || c == ':') => rest.trim(),
+        _ => trimmed,
+    }
+}
+
+/// Print one console event in the current format
+pub fn line(text: &str) {
+    let console = current();
+    let message = strip_prefix(text);
+    if let Some(ref console) = console {
+        console.record(message);
+    }
+    match format() {
+        LogFormat::Text => println!("{}", text),
+        LogFormat::Json => {
+            let mut event = serde_json::json!({
+                "time": chrono::Local::now().to_rfc3339(),
+                "level": if is_error(message) { "warn" } else { "info" },
+                "message": message,
+            });
+            if let Some(ref console) = console {
+                event["bot"] = serde_json::json!(console.name());
+                event["bot_index"] = serde_json::json!(console.index);
+            }
+            println!("{}", event);
+        }
+        LogFormat::Dashboard => {
+            if console.is_none() {
+                if let Ok(mut status) = SERVER_STATUS.lock() {
+                    *status = message.to_string();
+                }
+            }
+        }
+    }
+}
+
+fn fit(text: &str, width: usize) -> String {
+    let count = text.chars().count();
+    if count > width {
+        let mut s: String = text.chars().take(width.saturating_sub(1)).collect();
+        s.push('…');
+        s
+    } else {
+        format!("{}{}", text, " ".repeat(width - count))
+    }
+}
+
+/// Rows of the dashboard (header, one per bot, server status)
+fn dashboard_lines(width: usize) -> Vec<String> {
+    let bots: Vec<Arc<BotConsole>> = REGISTRY.lock().map(|r| r.clone()).unwrap_or_default();
+    let activity_width = width.saturating_sub(2 + 20 + 14 + 7 + 7).max(10);
+    let mut lines = vec![format!(
+        "  {}{}{}{}{}",
+        fit("BOT", 20),
+        fit("OWNER", 14),
+        fit("LAST ACTIVITY", activity_width),
+        fit("QUEUE", 7),
+        fit("ERRORS", 7)
+    )];
+    for bot in &bots {
+        bot.refresh();
+        let owner = bot.owner.lock().ok().and_then(|o| *o).map(|id| id.to_string()).unwrap_or_else(|| "-".to_string());
+        let activity = bot
+            .last_activity
+            .lock()
+            .ok()
+            .and_then(|a| a.clone())
+            .map(|(time, message)| format!("{} {}", time, message))
+            .unwrap_or_else(|| "-".to_string());
+        lines.push(format!(
+            "  {}{}{}{}{}",
+            fit(&format!("#{} {}", bot.index, bot.name()), 20),
+            fit(&owner, 14),
+            fit(&activity, activity_width),
+            fit(&bot.queue.load(Ordering::Relaxed).to_string(), 7),
+            fit(&bot.errors.load(Ordering::Relaxed).to_string(), 7)
+        ));
+    }
+    let status = SERVER_STATUS.lock().map(|s| s.clone()).unwrap_or_default();
+    lines.push(format!("  {}", fit(&status, width.saturating_sub(2))));
+    lines.into_iter().map(|l| l.chars().take(width).collect()).collect()
+}
+
+/// Redraw the dashboard every second (no-op unless the format is `dashboard`)
+pub fn spawn_dashboard() {
+    if format() != LogFormat::Dashboard {
+        return;
+    }
+    tokio::spawn(async {
+        let mut drawn = 0;
+        loop {
+            let width = crossterm::terminal::size().map(|(w, _)| w as usize).unwrap_or(100).max(40);
+            let lines = dashboard_lines(width);
+            let mut out = std::io::stdout().lock();
+            if drawn > 0 {
+                let _ = write!(out, "\x1b[{}A", drawn);
+            }
+            for line in &lines {
+                let _ = writeln!(out, "\x1b[2K{}", line);
+            }
+            let _ = out.flush();
+            drop(out);
+            drawn = lines.len();
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_prefix_and_fit() {
+        assert_eq!(strip_prefix("  [12:03:04] ◀ [kim(1)] /pwd"), "◀ [kim(1)] /pwd");
+        assert_eq!(strip_prefix("  ✓ Owner: 1"), "✓ Owner: 1");
+        assert_eq!(strip_prefix("[Schedule] Failed"), "[Schedule] Failed");
+        assert!(is_error("  [12:03:04]   ⚠ edit_message failed"));
+        assert_eq!(fit("abc", 5), "abc  ");
+        assert_eq!(fit("abcdef", 4), "abc…");
+        assert_eq!(LogFormat::parse("JSON"), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse("xml"), None);
+    }
+}
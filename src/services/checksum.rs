@@ -0,0 +1,364 @@
+//! File checksums (MD5 / SHA-1 / SHA-256 / BLAKE3) and `*sum` manifests.
+//!
+//! Manifests use the coreutils format (`<hash>  <name>`, `*` before the name
+//! for binary mode), so files written here can be checked with `sha256sum -c`
+//! and manifests made by those tools can be verified here.
+
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+const READ_BUF_SIZE: usize = 256 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Blake3,
+}
+
+impl Algorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            Algorithm::Md5 => "MD5",
+            Algorithm::Sha1 => "SHA-1",
+            Algorithm::Sha256 => "SHA-256",
+            Algorithm::Blake3 => "BLAKE3",
+        }
+    }
+
+    /// Manifest file extension (same as the coreutils/b3sum tool names)
+    pub fn manifest_extension(self) -> &'static str {
+        match self {
+            Algorithm::Md5 => "md5sum",
+            Algorithm::Sha1 => "sha1sum",
+            Algorithm::Sha256 => "sha256sum",
+            Algorithm::Blake3 => "b3sum",
+        }
+    }
+
+    /// Next algorithm in the selection cycle
+    pub fn next(self) -> Self {
+        match self {
+            Algorithm::Md5 => Algorithm::Sha1,
+            Algorithm::Sha1 => Algorithm::Sha256,
+            Algorithm::Sha256 => Algorithm::Blake3,
+            Algorithm::Blake3 => Algorithm::Md5,
+        }
+    }
+
+    /// Algorithm of a manifest file, from its extension (`x.sha256sum`)
+    /// or conventional name (`SHA256SUMS`)
+    pub fn from_manifest_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        let ext = path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase());
+        let all = [Algorithm::Md5, Algorithm::Sha1, Algorithm::Sha256, Algorithm::Blake3];
+        all.into_iter().find(|a| {
+            let tool = a.manifest_extension();
+            ext.as_deref() == Some(tool) || name == format!("{}s", tool)
+        })
+    }
+}
+
+enum Hasher {
+    Md5(Md5),
+    Sha1(Sha1),
+    Sha256(Sha256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Hasher {
+    fn new(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Md5 => Hasher::Md5(Md5::new()),
+            Algorithm::Sha1 => Hasher::Sha1(Sha1::new()),
+            Algorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+            Algorithm::Blake3 => Hasher::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Md5(h) => h.update(data),
+            Hasher::Sha1(h) => h.update(data),
+            Hasher::Sha256(h) => h.update(data),
+            Hasher::Blake3(h) => {
+                h.update(data);
+            }
+        }
+    }
+
+    fn finalize_hex(self) -> String {
+        match self {
+            Hasher::Md5(h) => hex::encode(h.finalize()),
+            Hasher::Sha1(h) => hex::encode(h.finalize()),
+            Hasher::Sha256(h) => hex::encode(h.finalize()),
+            Hasher::Blake3(h) => h.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+/// Hash everything read from `reader`. Returns None if cancelled.
+/// `bytes_done` is increased as data is read (shared progress counter).
+pub fn hash_reader<R: Read>(
+    mut reader: R,
+    algorithm: Algorithm,
+    cancel_flag: &AtomicBool,
+    bytes_done: &AtomicU64,
+) -> io::Result<Option<String>> {
+    let mut hasher = Hasher::new(algorithm);
+    let mut buf = vec![0u8; READ_BUF_SIZE];
+    loop {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buf[..n]);
+        bytes_done.fetch_add(n as u64, Ordering::Relaxed);
+    }
+    Ok(Some(hasher.finalize_hex()))
+}
+
+/// One line of a manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub hash: String,
+    pub name: String,
+}
+
+/// Parse a coreutils-style manifest, skipping blank, comment and malformed lines
+pub fn parse_manifest(text: &str) -> Vec<ManifestEntry> {
+    text.lines()
+        .filter_map(|line| {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let (hash, rest) = line.split_once(' ')?;
+            if hash.is_empty() || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            // "<hash>  <name>" (text mode) or "<hash> *<name>" (binary mode)
+            let name = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*'))?;
+            if name.is_empty() {
+                return None;
+            }
+            Some(ManifestEntry { hash: hash.to_ascii_lowercase(), name: name.to_string() })
+        })
+        .collect()
+}
+
+/// Manifest text for (hash, name) pairs
+pub fn format_manifest<'a>(entries: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    let mut out = String::new();
+    for (hash, name) in entries {
+        out.push_str(hash);
+        out.push_str("  ");
+        out.push_str(name);
+        out.push('\n');
+    }
+    out
+}
+
+/// Default manifest file name: `<item>.<ext>` for a single item, else `checksums.<ext>`
+pub fn manifest_file_name(selected: &[String], algorithm: Algorithm) -> String {
+    match selected {
+        [single] => format!("{}.{}", single.trim_end_matches('/'), algorithm.manifest_extension()),
+        _ => format!("checksums.{}", algorithm.manifest_extension()),
+    }
+}
+
+/// Result of checking one manifest entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyStatus {
+    Ok,
+    Mismatch,
+    Missing,
+}
+
+pub enum ChecksumMessage {
+    /// Number of files and total bytes to process
+    Totals { files: usize, bytes: u64 },
+    /// Started processing a file (relative name)
+    Started(String),
+    Hashed { name: String, hash: String },
+    Verified { name: String, status: VerifyStatus },
+    Failed { name: String, error: String },
+    Complete,
+}
+
+/// Expand the selected names into regular files (relative to `base_dir`) with sizes.
+/// Directories are walked recursively; symlinks are not followed.
+pub fn collect_files(base_dir: &Path, names: &[String], cancel_flag: &AtomicBool) -> Vec<(String, u64)> {
+    fn walk(base_dir: &Path, rel: &str, out: &mut Vec<(String, u64)>, cancel_flag: &AtomicBool) {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return;
+        }
+        let path = base_dir.join(rel);
+        let Ok(metadata) = fs::symlink_metadata(&path) else { return };
+        if metadata.is_file() {
+            out.push((rel.to_string(), metadata.len()));
+        } else if metadata.is_dir() {
+            let Ok(entries) = fs::read_dir(&path) else { return };
+            let mut children: Vec<String> = entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.file_name().to_string_lossy().into_owned())
+                .collect();
+            children.sort();
+            for child in children {
+                walk(base_dir, &format!("{}/{}", rel, child), out, cancel_flag);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    for name in names {
+        walk(base_dir, name, &mut out, cancel_flag);
+    }
+    out
+}
+
+/// Hash the selected files and report each result
+pub fn run_compute(
+    base_dir: PathBuf,
+    names: Vec<String>,
+    algorithm: Algorithm,
+    tx: Sender<ChecksumMessage>,
+    cancel_flag: Arc<AtomicBool>,
+    bytes_done: Arc<AtomicU64>,
+) {
+    let files = collect_files(&base_dir, &names, &cancel_flag);
+    let bytes: u64 = files.iter().map(|(_, size)| size).sum();
+    let _ = tx.send(ChecksumMessage::Totals { files: files.len(), bytes });
+
+    for (name, _) in files {
+        if cancel_flag.load(Ordering::Relaxed) {
+            break;
+        }
+        let _ = tx.send(ChecksumMessage::Started(name.clone()));
+        let result = File::open(base_dir.join(&name))
+            .and_then(|f| hash_reader(f, algorithm, &cancel_flag, &bytes_done));
+        let msg = match result {
+            Ok(Some(hash)) => ChecksumMessage::Hashed { name, hash },
+            Ok(None) => break,
+            Err(e) => ChecksumMessage::Failed { name, error: e.to_string() },
+        };
+        if tx.send(msg).is_err() {
+            return;
+        }
+    }
+    let _ = tx.send(ChecksumMessage::Complete);
+}
+
+/// Check the files listed in `manifest` (names relative to the manifest's directory)
+pub fn run_verify(
+    manifest: PathBuf,
+    algorithm: Algorithm,
+    tx: Sender<ChecksumMessage>,
+    cancel_flag: Arc<AtomicBool>,
+    bytes_done: Arc<AtomicU64>,
+) {
+    let entries = match fs::read_to_string(&manifest) {
+        Ok(text) => parse_manifest(&text),
+        Err(e) => {
+            let name = manifest.display().to_string();
+            let _ = tx.send(ChecksumMessage::Failed { name, error: e.to_string() });
+            let _ = tx.send(ChecksumMessage::Complete);
+            return;
+        }
+    };
+    let base_dir = manifest.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    let bytes: u64 = entries.iter()
+        .filter_map(|e| fs::metadata(base_dir.join(&e.name)).ok())
+        .map(|m| m.len())
+        .sum();
+    let _ = tx.send(ChecksumMessage::Totals { files: entries.len(), bytes });
+
+    for entry in entries {
+        if cancel_flag.load(Ordering::Relaxed) {
+            break;
+        }
+        let _ = tx.send(ChecksumMessage::Started(entry.name.clone()));
+        let path = base_dir.join(&entry.name);
+        let msg = if !path.is_file() {
+            ChecksumMessage::Verified { name: entry.name, status: VerifyStatus::Missing }
+        } else {
+            match File::open(&path).and_then(|f| hash_reader(f, algorithm, &cancel_flag, &bytes_done)) {
+                Ok(Some(hash)) => {
+                    let status = if hash.eq_ignore_ascii_case(&entry.hash) {
+                        VerifyStatus::Ok
+                    } else {
+                        VerifyStatus::Mismatch
+                    };
+                    ChecksumMessage::Verified { name: entry.name, status }
+                }
+                Ok(None) => break,
+                Err(e) => ChecksumMessage::Failed { name: entry.name, error: e.to_string() },
+            }
+        };
+        if tx.send(msg).is_err() {
+            return;
+        }
+    }
+    let _ = tx.send(ChecksumMessage::Complete);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_str(data: &str, algorithm: Algorithm) -> String {
+        let cancel = AtomicBool::new(false);
+        let done = AtomicU64::new(0);
+        hash_reader(data.as_bytes(), algorithm, &cancel, &done).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_hash_known_vectors() {
+        assert_eq!(hash_str("abc", Algorithm::Md5), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(hash_str("abc", Algorithm::Sha1), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            hash_str("abc", Algorithm::Sha256),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hash_str("abc", Algorithm::Blake3),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_round_trip() {
+        let text = format_manifest([("ABCDEF01", "a.txt"), ("0123", "dir/b c.bin")]);
+        let mut entries = parse_manifest(&text);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], ManifestEntry { hash: "abcdef01".into(), name: "a.txt".into() });
+        assert_eq!(entries[1].name, "dir/b c.bin");
+
+        // Binary-mode marker, comments and garbage
+        entries = parse_manifest("# comment\n\nffff *bin.dat\nnot a hash line\n");
+        assert_eq!(entries, vec![ManifestEntry { hash: "ffff".into(), name: "bin.dat".into() }]);
+    }
+
+    #[test]
+    fn test_algorithm_from_manifest_path() {
+        assert_eq!(Algorithm::from_manifest_path(Path::new("/x/a.sha256sum")), Some(Algorithm::Sha256));
+        assert_eq!(Algorithm::from_manifest_path(Path::new("SHA1SUMS")), Some(Algorithm::Sha1));
+        assert_eq!(Algorithm::from_manifest_path(Path::new("data.b3sum")), Some(Algorithm::Blake3));
+        assert_eq!(Algorithm::from_manifest_path(Path::new("notes.txt")), None);
+    }
+}
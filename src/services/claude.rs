@@ -2,18 +2,16 @@ use std::process::{Command, Stdio};
 use std::io::{BufRead, BufReader, Write};
 use std::sync::mpsc::Sender;
 use std::sync::OnceLock;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::fs::OpenOptions;
 use regex::Regex;
 use serde_json::Value;
 
-/// Global debug flag — toggled by /debug command or COKACDIR_DEBUG=1 env var
-pub static DEBUG_ENABLED: AtomicBool = AtomicBool::new(false);
+use crate::services::logging;
+use crate::{log_debug, log_error, log_trace, log_warn};
 
-/// Initialize debug flag from environment variable or bot_settings.json (call once at startup)
+/// Turn on debug logging from COKACDIR_DEBUG=1 or bot_settings.json (call once at startup)
 pub fn init_debug_from_env() {
     if std::env::var("COKACDIR_DEBUG").map(|v| v == "1").unwrap_or(false) {
-        DEBUG_ENABLED.store(true, Ordering::Relaxed);
+        logging::set_debug_mode(true);
         return;
     }
     // Also check bot_settings.json for any bot with debug=true
@@ -24,7 +22,7 @@ pub fn init_debug_from_env() {
                 if let Some(obj) = json.as_object() {
                     for (_key, entry) in obj {
                         if entry.get("debug").and_then(|v| v.as_bool()).unwrap_or(false) {
-                            DEBUG_ENABLED.store(true, Ordering::Relaxed);
+                            logging::set_debug_mode(true);
                             return;
                         }
                     }
@@ -69,37 +67,18 @@ fn resolve_claude_path() -> Option<String> {
 }
 
 /// Get the cached claude binary path, resolving it on first call.
-fn get_claude_path() -> Option<&'static str> {
+pub fn get_claude_path() -> Option<&'static str> {
     CLAUDE_PATH.get_or_init(|| resolve_claude_path()).as_deref()
 }
 
-/// Debug logging helper (active when /debug toggled ON or COKACDIR_DEBUG=1)
-pub fn debug_log(msg: &str) {
-    debug_log_to("claude.log", msg);
-}
-
-pub fn debug_log_to(filename: &str, msg: &str) {
-    if !DEBUG_ENABLED.load(Ordering::Relaxed) { return; }
-    if let Some(home) = dirs::home_dir() {
-        let debug_dir = home.join(".cokacdir").join("debug");
-        let _ = std::fs::create_dir_all(&debug_dir);
-        let log_path = debug_dir.join(filename);
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(log_path)
-        {
-            let timestamp = chrono::Local::now().format("%H:%M:%S%.3f");
-            let _ = writeln!(file, "[{}] {}", timestamp, msg);
-        }
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct ClaudeResponse {
     pub success: bool,
     pub response: Option<String>,
     pub session_id: Option<String>,
+    /// Token usage reported by the CLI (`usage` object of the result message)
+    pub usage: Option<serde_json::Value>,
+    pub total_cost_usd: Option<f64>,
     pub error: Option<String>,
 }
 
@@ -219,6 +198,8 @@ IMPORTANT: Format your responses using Markdown for better readability:
                 success: false,
                 response: None,
                 session_id: None,
+                usage: None,
+                total_cost_usd: None,
                 error: Some("Invalid session ID format".to_string()),
             };
         }
@@ -233,6 +214,8 @@ IMPORTANT: Format your responses using Markdown for better readability:
                 success: false,
                 response: None,
                 session_id: None,
+                usage: None,
+                total_cost_usd: None,
                 error: Some("Claude CLI not found. Is Claude CLI installed?".to_string()),
             };
         }
@@ -256,6 +239,8 @@ IMPORTANT: Format your responses using Markdown for better readability:
                 success: false,
                 response: None,
                 session_id: None,
+                usage: None,
+                total_cost_usd: None,
                 error: Some(format!("Failed to start Claude: {}. Is Claude CLI installed?", e)),
             };
         }
@@ -278,6 +263,8 @@ IMPORTANT: Format your responses using Markdown for better readability:
                     success: false,
                     response: None,
                     session_id: None,
+                    usage: None,
+                    total_cost_usd: None,
                     error: Some(if stderr.is_empty() {
                         format!("Process exited with code {:?}", output.status.code())
                     } else {
@@ -290,6 +277,8 @@ IMPORTANT: Format your responses using Markdown for better readability:
             success: false,
             response: None,
             session_id: None,
+            usage: None,
+            total_cost_usd: None,
             error: Some(format!("Failed to read output: {}", e)),
         },
     }
@@ -299,6 +288,8 @@ IMPORTANT: Format your responses using Markdown for better readability:
 fn parse_claude_output(output: &str) -> ClaudeResponse {
     let mut session_id: Option<String> = None;
     let mut response_text = String::new();
+    let mut usage: Option<serde_json::Value> = None;
+    let mut total_cost_usd: Option<f64> = None;
 
     for line in output.trim().lines() {
         if let Ok(json) = serde_json::from_str::<serde_json::Value>(line) {
@@ -307,6 +298,14 @@ fn parse_claude_output(output: &str) -> ClaudeResponse {
                 session_id = Some(sid.to_string());
             }
 
+            // Extract usage metadata
+            if let Some(u) = json.get("usage").filter(|v| v.is_object()) {
+                usage = Some(u.clone());
+            }
+            if let Some(cost) = json.get("total_cost_usd").and_then(|v| v.as_f64()) {
+                total_cost_usd = Some(cost);
+            }
+
             // Extract response text
             if let Some(result) = json.get("result").and_then(|v| v.as_str()) {
                 response_text = result.to_string();
@@ -330,6 +329,8 @@ fn parse_claude_output(output: &str) -> ClaudeResponse {
         success: true,
         response: Some(response_text.trim().to_string()),
         session_id,
+        usage,
+        total_cost_usd,
         error: None,
     }
 }
@@ -338,23 +339,23 @@ fn parse_claude_output(output: &str) -> ClaudeResponse {
 /// Forks the session, asks Claude to summarize the context relevant to the schedule prompt,
 /// and returns the summary text (not a session_id).
 pub fn extract_context_summary(session_id: &str, schedule_prompt: &str, working_dir: &str) -> Result<String, String> {
-    debug_log("=== extract_context_summary START ===");
-    debug_log(&format!("  session_id: {}", session_id));
-    debug_log(&format!("  schedule_prompt: {}", schedule_prompt));
-    debug_log(&format!("  working_dir: {}", working_dir));
+    log_debug!("claude", "=== extract_context_summary START ===");
+    log_debug!("claude", "  session_id: {}", session_id);
+    log_debug!("claude", "  schedule_prompt: {}", schedule_prompt);
+    log_debug!("claude", "  working_dir: {}", working_dir);
 
     if !is_valid_session_id(session_id) {
-        debug_log("  ERROR: Invalid session ID format");
+        log_error!("claude", "  Invalid session ID format");
         return Err("Invalid session ID format".to_string());
     }
-    debug_log("  session_id validation: OK");
+    log_debug!("claude", "  session_id validation: OK");
 
     let claude_bin = get_claude_path()
         .ok_or_else(|| {
-            debug_log("  ERROR: Claude CLI not found");
+            log_error!("claude", "  Claude CLI not found");
             "Claude CLI not found".to_string()
         })?;
-    debug_log(&format!("  claude_bin: {}", claude_bin));
+    log_debug!("claude", "  claude_bin: {}", claude_bin);
 
     let args = vec![
         "-p",
@@ -365,7 +366,7 @@ pub fn extract_context_summary(session_id: &str, schedule_prompt: &str, working_
         "--resume", session_id,
         "--fork-session",
     ];
-    debug_log(&format!("  args: {:?}", args));
+    log_debug!("claude", "  args: {:?}", args);
 
     let summary_prompt = format!(
         "Summarize the current session context needed to perform the following scheduled task. \
@@ -377,9 +378,9 @@ pub fn extract_context_summary(session_id: &str, schedule_prompt: &str, working_
          Keep it concise.",
         schedule_prompt
     );
-    debug_log(&format!("  summary_prompt len: {} chars", summary_prompt.len()));
+    log_debug!("claude", "  summary_prompt len: {} chars", summary_prompt.len());
 
-    debug_log("  Spawning Claude process...");
+    log_debug!("claude", "  Spawning Claude process...");
     let spawn_start = std::time::Instant::now();
     let mut child = Command::new(&claude_bin)
         .args(&args)
@@ -390,91 +391,91 @@ pub fn extract_context_summary(session_id: &str, schedule_prompt: &str, working_
         .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| {
-            debug_log(&format!("  ERROR: Failed to spawn: {}", e));
+            log_error!("claude", "  Failed to spawn: {}", e);
             format!("Failed to start Claude for context summary: {}", e)
         })?;
-    debug_log(&format!("  Process spawned in {:?}, pid={:?}", spawn_start.elapsed(), child.id()));
+    log_debug!("claude", "  Process spawned in {:?}, pid={:?}", spawn_start.elapsed(), child.id());
 
     if let Some(mut stdin) = child.stdin.take() {
-        debug_log("  Writing summary_prompt to stdin...");
+        log_debug!("claude", "  Writing summary_prompt to stdin...");
         let write_result = stdin.write_all(summary_prompt.as_bytes());
-        debug_log(&format!("  stdin write result: {:?}", write_result.is_ok()));
+        log_debug!("claude", "  stdin write result: {:?}", write_result.is_ok());
         drop(stdin);
-        debug_log("  stdin dropped (closed)");
+        log_debug!("claude", "  stdin dropped (closed)");
     } else {
-        debug_log("  WARNING: Could not get stdin handle");
+        log_warn!("claude", "  Could not get stdin handle");
     }
 
-    debug_log("  Waiting for process to complete (wait_with_output)...");
+    log_debug!("claude", "  Waiting for process to complete (wait_with_output)...");
     let wait_start = std::time::Instant::now();
     let output = child.wait_with_output()
         .map_err(|e| {
-            debug_log(&format!("  ERROR: wait_with_output failed after {:?}: {}", wait_start.elapsed(), e));
+            log_error!("claude", "  wait_with_output failed after {:?}: {}", wait_start.elapsed(), e);
             format!("Failed to read context summary output: {}", e)
         })?;
-    debug_log(&format!("  Process completed in {:?}", wait_start.elapsed()));
-    debug_log(&format!("  exit status: {:?}", output.status));
-    debug_log(&format!("  stdout len: {} bytes", output.stdout.len()));
-    debug_log(&format!("  stderr len: {} bytes", output.stderr.len()));
+    log_debug!("claude", "  Process completed in {:?}", wait_start.elapsed());
+    log_debug!("claude", "  exit status: {:?}", output.status);
+    log_debug!("claude", "  stdout len: {} bytes", output.stdout.len());
+    log_debug!("claude", "  stderr len: {} bytes", output.stderr.len());
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        debug_log(&format!("  ERROR: Process failed. exit_code={:?}", output.status.code()));
-        debug_log(&format!("  stderr: {}", &stderr[..stderr.len().min(500)]));
-        debug_log(&format!("  stdout: {}", &stdout[..stdout.len().min(500)]));
+        log_error!("claude", "  Process failed. exit_code={:?}", output.status.code());
+        log_debug!("claude", "  stderr: {}", &stderr[..stderr.len().min(500)]);
+        log_debug!("claude", "  stdout: {}", &stdout[..stdout.len().min(500)]);
         return Err(format!("Context summary process failed (exit {:?}). stderr: {}",
             output.status.code(), stderr));
     }
-    debug_log("  Process exit status: success");
+    log_debug!("claude", "  Process exit status: success");
 
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stdout_preview: String = stdout.chars().take(300).collect();
-    debug_log(&format!("  stdout preview: {}", stdout_preview));
+    log_debug!("claude", "  stdout preview: {}", stdout_preview);
 
     let resp = parse_claude_output(&stdout);
-    debug_log(&format!("  parse_claude_output: success={}, response_len={:?}",
-        resp.success, resp.response.as_ref().map(|s| s.len())));
+    log_debug!("claude", "  parse_claude_output: success={}, response_len={:?}",
+        resp.success, resp.response.as_ref().map(|s| s.len()));
 
     let result = resp.response
         .filter(|s| !s.is_empty())
         .ok_or_else(|| {
             let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-            debug_log(&format!("  ERROR: Empty response. stderr: {}", &stderr[..stderr.len().min(500)]));
+            log_error!("claude", "  Empty response. stderr: {}", &stderr[..stderr.len().min(500)]);
             format!("Context summary extraction returned empty. stderr: {}", stderr)
         });
 
     match &result {
         Ok(summary) => {
             let preview: String = summary.chars().take(200).collect();
-            debug_log(&format!("  SUCCESS: summary preview: {}", preview));
+            log_debug!("claude", "  SUCCESS: summary preview: {}", preview);
         }
         Err(e) => {
-            debug_log(&format!("  FAILED: {}", e));
+            log_warn!("claude", "  FAILED: {}", e);
         }
     }
-    debug_log("=== extract_context_summary END ===");
+    log_debug!("claude", "=== extract_context_summary END ===");
     result
 }
 
 /// Resume an existing session to extract a result summary (no tools, max 1 turn).
 /// Used after cron execution to summarize results for the next run's context.
 pub fn extract_result_summary(session_id: &str, working_dir: &str, model: Option<&str>) -> Result<String, String> {
-    debug_log("=== extract_result_summary START ===");
-    debug_log(&format!("  session_id: {}", session_id));
-    debug_log(&format!("  working_dir: {}", working_dir));
-    debug_log(&format!("  model: {:?}", model));
+    log_debug!("claude", "=== extract_result_summary START ===");
+    log_debug!("claude", "  session_id: {}", session_id);
+    log_debug!("claude", "  working_dir: {}", working_dir);
+    log_debug!("claude", "  model: {:?}", model);
 
     if !is_valid_session_id(session_id) {
-        debug_log("  ERROR: Invalid session ID format");
+        log_error!("claude", "  Invalid session ID format");
         return Err("Invalid session ID format".to_string());
     }
     let claude_bin = get_claude_path()
         .ok_or_else(|| {
-            debug_log("  ERROR: Claude CLI not found");
+            log_error!("claude", "  Claude CLI not found");
             "Claude CLI not found".to_string()
         })?;
-    debug_log(&format!("  claude_bin: {}", claude_bin));
+    log_debug!("claude", "  claude_bin: {}", claude_bin);
 
     let mut args = vec![
         "-p",
@@ -491,12 +492,12 @@ pub fn extract_result_summary(session_id: &str, working_dir: &str, model: Option
         args.push("--model");
         args.push(&model_str);
     }
-    debug_log(&format!("  args: {:?}", args));
+    log_debug!("claude", "  args: {:?}", args);
 
     let summary_prompt = "Summarize the results of the task just performed. \
         Provide key information concisely so it can be used as context for the next execution.";
 
-    debug_log("  Spawning Claude process...");
+    log_debug!("claude", "  Spawning Claude process...");
     let spawn_start = std::time::Instant::now();
     let mut child = Command::new(claude_bin)
         .args(&args)
@@ -507,70 +508,70 @@ pub fn extract_result_summary(session_id: &str, working_dir: &str, model: Option
         .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| {
-            debug_log(&format!("  ERROR: Failed to spawn: {}", e));
+            log_error!("claude", "  Failed to spawn: {}", e);
             format!("Failed to start Claude for result summary: {}", e)
         })?;
-    debug_log(&format!("  Process spawned in {:?}, pid={:?}", spawn_start.elapsed(), child.id()));
+    log_debug!("claude", "  Process spawned in {:?}, pid={:?}", spawn_start.elapsed(), child.id());
 
     if let Some(mut stdin) = child.stdin.take() {
-        debug_log("  Writing summary_prompt to stdin...");
+        log_debug!("claude", "  Writing summary_prompt to stdin...");
         let write_result = stdin.write_all(summary_prompt.as_bytes());
-        debug_log(&format!("  stdin write result: {:?}", write_result.is_ok()));
+        log_debug!("claude", "  stdin write result: {:?}", write_result.is_ok());
         drop(stdin);
-        debug_log("  stdin dropped (closed)");
+        log_debug!("claude", "  stdin dropped (closed)");
     } else {
-        debug_log("  WARNING: Could not get stdin handle");
+        log_warn!("claude", "  Could not get stdin handle");
     }
 
-    debug_log("  Waiting for process to complete...");
+    log_debug!("claude", "  Waiting for process to complete...");
     let wait_start = std::time::Instant::now();
     let output = child.wait_with_output()
         .map_err(|e| {
-            debug_log(&format!("  ERROR: wait_with_output failed after {:?}: {}", wait_start.elapsed(), e));
+            log_error!("claude", "  wait_with_output failed after {:?}: {}", wait_start.elapsed(), e);
             format!("Failed to read result summary output: {}", e)
         })?;
-    debug_log(&format!("  Process completed in {:?}", wait_start.elapsed()));
-    debug_log(&format!("  exit status: {:?}", output.status));
-    debug_log(&format!("  stdout len: {} bytes", output.stdout.len()));
-    debug_log(&format!("  stderr len: {} bytes", output.stderr.len()));
+    log_debug!("claude", "  Process completed in {:?}", wait_start.elapsed());
+    log_debug!("claude", "  exit status: {:?}", output.status);
+    log_debug!("claude", "  stdout len: {} bytes", output.stdout.len());
+    log_debug!("claude", "  stderr len: {} bytes", output.stderr.len());
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        debug_log(&format!("  ERROR: Process failed. exit_code={:?}", output.status.code()));
-        debug_log(&format!("  stderr: {}", &stderr[..stderr.len().min(500)]));
-        debug_log(&format!("  stdout: {}", &stdout[..stdout.len().min(500)]));
+        log_error!("claude", "  Process failed. exit_code={:?}", output.status.code());
+        log_debug!("claude", "  stderr: {}", &stderr[..stderr.len().min(500)]);
+        log_debug!("claude", "  stdout: {}", &stdout[..stdout.len().min(500)]);
         return Err(format!("Result summary process failed (exit {:?}). stderr: {}",
             output.status.code(), stderr));
     }
-    debug_log("  Process exit status: success");
+    log_debug!("claude", "  Process exit status: success");
 
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stdout_preview: String = stdout.chars().take(300).collect();
-    debug_log(&format!("  stdout preview: {}", stdout_preview));
+    log_debug!("claude", "  stdout preview: {}", stdout_preview);
 
     let resp = parse_claude_output(&stdout);
-    debug_log(&format!("  parse_claude_output: success={}, response_len={:?}",
-        resp.success, resp.response.as_ref().map(|s| s.len())));
+    log_debug!("claude", "  parse_claude_output: success={}, response_len={:?}",
+        resp.success, resp.response.as_ref().map(|s| s.len()));
 
     let result = resp.response
         .filter(|s| !s.is_empty())
         .ok_or_else(|| {
             let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-            debug_log(&format!("  ERROR: Empty response. stderr: {}", &stderr[..stderr.len().min(500)]));
+            log_error!("claude", "  Empty response. stderr: {}", &stderr[..stderr.len().min(500)]);
             format!("Result summary extraction returned empty. stderr: {}", stderr)
         });
 
     match &result {
         Ok(summary) => {
             let preview: String = summary.chars().take(200).collect();
-            debug_log(&format!("  SUCCESS: summary preview: {}", preview));
+            log_debug!("claude", "  SUCCESS: summary preview: {}", preview);
         }
         Err(e) => {
-            debug_log(&format!("  FAILED: {}", e));
+            log_warn!("claude", "  FAILED: {}", e);
         }
     }
-    debug_log("=== extract_result_summary END ===");
+    log_debug!("claude", "=== extract_result_summary END ===");
     result
 }
 
@@ -595,6 +596,7 @@ pub fn is_ai_supported() -> bool {
 /// Execute a command using Claude CLI with streaming output
 /// If `system_prompt` is None, uses the default file manager system prompt.
 /// If `system_prompt` is Some(""), no system prompt is appended.
+/// `settings` is extra Claude settings JSON passed with --settings (e.g. approval hooks).
 pub fn execute_command_streaming(
     prompt: &str,
     session_id: Option<&str>,
@@ -605,16 +607,17 @@ pub fn execute_command_streaming(
     cancel_token: Option<std::sync::Arc<CancelToken>>,
     model: Option<&str>,
     no_session_persistence: bool,
+    settings: Option<&str>,
 ) -> Result<(), String> {
-    debug_log("========================================");
-    debug_log("=== execute_command_streaming START ===");
-    debug_log("========================================");
-    debug_log(&format!("prompt_len: {} chars", prompt.len()));
+    log_debug!("claude", "========================================");
+    log_debug!("claude", "=== execute_command_streaming START ===");
+    log_debug!("claude", "========================================");
+    log_debug!("claude", "prompt_len: {} chars", prompt.len());
     let prompt_preview: String = prompt.chars().take(200).collect();
-    debug_log(&format!("prompt_preview: {:?}", prompt_preview));
-    debug_log(&format!("session_id: {:?}", session_id));
-    debug_log(&format!("working_dir: {}", working_dir));
-    debug_log(&format!("timestamp: {:?}", std::time::SystemTime::now()));
+    log_debug!("claude", "prompt_preview: {:?}", prompt_preview);
+    log_debug!("claude", "session_id: {:?}", session_id);
+    log_debug!("claude", "working_dir: {}", working_dir);
+    log_debug!("claude", "timestamp: {:?}", std::time::SystemTime::now());
 
     let default_system_prompt = r#"You are a terminal file manager assistant. Be concise. Focus on file operations. Respond in the same language as the user.
 
@@ -680,10 +683,15 @@ IMPORTANT: Format your responses using Markdown for better readability:
         args.push("--no-session-persistence".to_string());
     }
 
+    if let Some(s) = settings {
+        args.push("--settings".to_string());
+        args.push(s.to_string());
+    }
+
     // Resume session if available
     if let Some(sid) = session_id {
         if !is_valid_session_id(sid) {
-            debug_log("ERROR: Invalid session ID format");
+            log_error!("claude", "Invalid session ID format");
             return Err("Invalid session ID format".to_string());
         }
         args.push("--resume".to_string());
@@ -692,23 +700,23 @@ IMPORTANT: Format your responses using Markdown for better readability:
 
     let claude_bin = get_claude_path()
         .ok_or_else(|| {
-            debug_log("ERROR: Claude CLI not found");
+            log_error!("claude", "Claude CLI not found");
             "Claude CLI not found. Is Claude CLI installed?".to_string()
         })?;
 
-    debug_log("--- Spawning claude process ---");
-    debug_log(&format!("Command: {}", claude_bin));
-    debug_log(&format!("Args count: {}", args.len()));
+    log_debug!("claude", "--- Spawning claude process ---");
+    log_debug!("claude", "Command: {}", claude_bin);
+    log_debug!("claude", "Args count: {}", args.len());
     for (i, arg) in args.iter().enumerate() {
         if arg.len() > 100 {
-            debug_log(&format!("  arg[{}]: {}... (truncated, {} chars total)", i, &arg[..100], arg.len()));
+            log_debug!("claude", "  arg[{}]: {}... (truncated, {} chars total)", i, &arg[..100], arg.len());
         } else {
-            debug_log(&format!("  arg[{}]: {}", i, arg));
+            log_debug!("claude", "  arg[{}]: {}", i, arg);
         }
     }
-    debug_log("Env: CLAUDE_CODE_MAX_OUTPUT_TOKENS=64000");
-    debug_log("Env: BASH_DEFAULT_TIMEOUT_MS=86400000");
-    debug_log("Env: BASH_MAX_TIMEOUT_MS=86400000");
+    log_debug!("claude", "Env: CLAUDE_CODE_MAX_OUTPUT_TOKENS=64000");
+    log_debug!("claude", "Env: BASH_DEFAULT_TIMEOUT_MS=86400000");
+    log_debug!("claude", "Env: BASH_MAX_TIMEOUT_MS=86400000");
 
     let spawn_start = std::time::Instant::now();
     let mut child = Command::new(claude_bin)
@@ -723,10 +731,10 @@ IMPORTANT: Format your responses using Markdown for better readability:
         .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| {
-            debug_log(&format!("ERROR: Failed to spawn after {:?}: {}", spawn_start.elapsed(), e));
+            log_error!("claude", "Failed to spawn after {:?}: {}", spawn_start.elapsed(), e);
             format!("Failed to start Claude: {}. Is Claude CLI installed?", e)
         })?;
-    debug_log(&format!("Claude process spawned successfully in {:?}, pid={:?}", spawn_start.elapsed(), child.id()));
+    log_debug!("claude", "Claude process spawned successfully in {:?}, pid={:?}", spawn_start.elapsed(), child.id());
 
     // Store child PID in cancel token so the caller can kill it externally
     if let Some(ref token) = cancel_token {
@@ -735,51 +743,51 @@ IMPORTANT: Format your responses using Markdown for better readability:
 
     // Write prompt to stdin
     if let Some(mut stdin) = child.stdin.take() {
-        debug_log(&format!("Writing prompt to stdin ({} bytes)...", prompt.len()));
+        log_debug!("claude", "Writing prompt to stdin ({} bytes)...", prompt.len());
         let write_start = std::time::Instant::now();
         let write_result = stdin.write_all(prompt.as_bytes());
-        debug_log(&format!("stdin.write_all completed in {:?}, result={:?}", write_start.elapsed(), write_result.is_ok()));
+        log_debug!("claude", "stdin.write_all completed in {:?}, result={:?}", write_start.elapsed(), write_result.is_ok());
         // stdin is dropped here, which closes it - this signals end of input to claude
-        debug_log("stdin handle dropped (closed)");
+        log_debug!("claude", "stdin handle dropped (closed)");
     } else {
-        debug_log("WARNING: Could not get stdin handle!");
+        log_warn!("claude", "Could not get stdin handle!");
     }
 
     // Read stdout line by line for streaming
-    debug_log("Taking stdout handle...");
+    log_debug!("claude", "Taking stdout handle...");
     let stdout = child.stdout.take()
         .ok_or_else(|| {
-            debug_log("ERROR: Failed to capture stdout");
+            log_error!("claude", "Failed to capture stdout");
             "Failed to capture stdout".to_string()
         })?;
     let reader = BufReader::new(stdout);
-    debug_log("BufReader created, ready to read lines...");
+    log_debug!("claude", "BufReader created, ready to read lines...");
 
     let mut last_session_id: Option<String> = None;
     let mut final_result: Option<String> = None;
     let mut stdout_error: Option<(String, String)> = None; // (message, raw_line)
     let mut line_count = 0;
 
-    debug_log("Entering lines loop - will block until first line arrives...");
+    log_debug!("claude", "Entering lines loop - will block until first line arrives...");
     for line in reader.lines() {
         // Check cancel token before processing each line
         if let Some(ref token) = cancel_token {
             if token.cancelled.load(std::sync::atomic::Ordering::Relaxed) {
-                debug_log("Cancel detected — killing child process");
+                log_debug!("claude", "Cancel detected — killing child process");
                 let _ = child.kill();
                 let _ = child.wait();
                 return Ok(());
             }
         }
 
-        debug_log(&format!("Line {} - read started", line_count + 1));
+        log_trace!("claude", "Line {} - read started", line_count + 1);
         let line = match line {
             Ok(l) => {
-                debug_log(&format!("Line {} - read completed: {} chars", line_count + 1, l.len()));
+                log_trace!("claude", "Line {} - read completed: {} chars", line_count + 1, l.len());
                 l
             },
             Err(e) => {
-                debug_log(&format!("ERROR: Failed to read line: {}", e));
+                log_error!("claude", "Failed to read line: {}", e);
                 let _ = sender.send(StreamMessage::Error {
                     message: format!("Failed to read output: {}", e),
                     stdout: String::new(), stderr: String::new(), exit_code: None,
@@ -789,96 +797,96 @@ IMPORTANT: Format your responses using Markdown for better readability:
         };
 
         line_count += 1;
-        debug_log(&format!("Line {}: {} chars", line_count, line.len()));
+        log_trace!("claude", "Line {}: {} chars", line_count, line.len());
 
         if line.trim().is_empty() {
-            debug_log("  (empty line, skipping)");
+            log_trace!("claude", "  (empty line, skipping)");
             continue;
         }
 
         let line_preview: String = line.chars().take(200).collect();
-        debug_log(&format!("  Raw line preview: {}", line_preview));
+        log_trace!("claude", "  Raw line preview: {}", line_preview);
 
         if let Ok(json) = serde_json::from_str::<Value>(&line) {
             let msg_type = json.get("type").and_then(|v| v.as_str()).unwrap_or("unknown");
             let msg_subtype = json.get("subtype").and_then(|v| v.as_str()).unwrap_or("-");
-            debug_log(&format!("  JSON parsed: type={}, subtype={}", msg_type, msg_subtype));
+            log_trace!("claude", "  JSON parsed: type={}, subtype={}", msg_type, msg_subtype);
 
             // Log more details for specific message types
             if msg_type == "assistant" {
                 if let Some(content) = json.get("message").and_then(|m| m.get("content")) {
-                    debug_log(&format!("  Assistant content array: {}", content));
+                    log_trace!("claude", "  Assistant content array: {}", content);
                 }
             }
 
-            debug_log("  Calling parse_stream_message...");
+            log_trace!("claude", "  Calling parse_stream_message...");
             if let Some(msg) = parse_stream_message(&json) {
-                debug_log(&format!("  Parsed message variant: {:?}", std::mem::discriminant(&msg)));
+                log_trace!("claude", "  Parsed message variant: {:?}", std::mem::discriminant(&msg));
 
                 // Track session_id and final result for Done message
                 match &msg {
                     StreamMessage::Init { session_id } => {
-                        debug_log(&format!("  >>> Init: session_id={}", session_id));
+                        log_trace!("claude", "  >>> Init: session_id={}", session_id);
                         last_session_id = Some(session_id.clone());
                     }
                     StreamMessage::Text { content } => {
                         let preview: String = content.chars().take(100).collect();
-                        debug_log(&format!("  >>> Text: {} chars, preview: {:?}", content.len(), preview));
+                        log_trace!("claude", "  >>> Text: {} chars, preview: {:?}", content.len(), preview);
                     }
                     StreamMessage::ToolUse { name, input } => {
                         let input_preview: String = input.chars().take(200).collect();
-                        debug_log(&format!("  >>> ToolUse: name={}, input_preview={:?}", name, input_preview));
+                        log_trace!("claude", "  >>> ToolUse: name={}, input_preview={:?}", name, input_preview);
                     }
                     StreamMessage::ToolResult { content, is_error } => {
                         let content_preview: String = content.chars().take(200).collect();
-                        debug_log(&format!("  >>> ToolResult: is_error={}, content_len={}, preview={:?}",
-                            is_error, content.len(), content_preview));
+                        log_trace!("claude", "  >>> ToolResult: is_error={}, content_len={}, preview={:?}",
+                            is_error, content.len(), content_preview);
                     }
                     StreamMessage::Done { result, session_id } => {
                         let result_preview: String = result.chars().take(100).collect();
-                        debug_log(&format!("  >>> Done: result_len={}, session_id={:?}, preview={:?}",
-                            result.len(), session_id, result_preview));
+                        log_trace!("claude", "  >>> Done: result_len={}, session_id={:?}, preview={:?}",
+                            result.len(), session_id, result_preview);
                         final_result = Some(result.clone());
                         if session_id.is_some() {
                             last_session_id = session_id.clone();
                         }
                     }
                     StreamMessage::Error { ref message, .. } => {
-                        debug_log(&format!("  >>> Error: {}", message));
+                        log_trace!("claude", "  >>> Error: {}", message);
                         stdout_error = Some((message.clone(), line.clone()));
                         continue; // don't send yet; will combine with stderr after process exits
                     }
                     StreamMessage::TaskNotification { task_id, status, summary } => {
-                        debug_log(&format!("  >>> TaskNotification: task_id={}, status={}, summary={}", task_id, status, summary));
+                        log_trace!("claude", "  >>> TaskNotification: task_id={}, status={}, summary={}", task_id, status, summary);
                     }
                 }
 
                 // Send message to channel
-                debug_log("  Sending message to channel...");
+                log_trace!("claude", "  Sending message to channel...");
                 let send_result = sender.send(msg);
                 if send_result.is_err() {
-                    debug_log("  ERROR: Channel send failed (receiver dropped)");
+                    log_error!("claude", "  Channel send failed (receiver dropped)");
                     break;
                 }
-                debug_log("  Message sent to channel successfully");
+                log_trace!("claude", "  Message sent to channel successfully");
             } else {
-                debug_log(&format!("  parse_stream_message returned None for type={}", msg_type));
+                log_trace!("claude", "  parse_stream_message returned None for type={}", msg_type);
             }
         } else {
             let invalid_preview: String = line.chars().take(200).collect();
-            debug_log(&format!("  NOT valid JSON: {}", invalid_preview));
+            log_trace!("claude", "  NOT valid JSON: {}", invalid_preview);
         }
     }
 
-    debug_log("--- Exited lines loop ---");
-    debug_log(&format!("Total lines read: {}", line_count));
-    debug_log(&format!("final_result present: {}", final_result.is_some()));
-    debug_log(&format!("last_session_id: {:?}", last_session_id));
+    log_debug!("claude", "--- Exited lines loop ---");
+    log_debug!("claude", "Total lines read: {}", line_count);
+    log_debug!("claude", "final_result present: {}", final_result.is_some());
+    log_debug!("claude", "last_session_id: {:?}", last_session_id);
 
     // Check cancel token after exiting the loop
     if let Some(ref token) = cancel_token {
         if token.cancelled.load(std::sync::atomic::Ordering::Relaxed) {
-            debug_log("Cancel detected after loop — killing child process");
+            log_debug!("claude", "Cancel detected after loop — killing child process");
             let _ = child.kill();
             let _ = child.wait();
             return Ok(());
@@ -886,14 +894,14 @@ IMPORTANT: Format your responses using Markdown for better readability:
     }
 
     // Wait for process to finish
-    debug_log("Waiting for child process to finish (child.wait())...");
+    log_debug!("claude", "Waiting for child process to finish (child.wait())...");
     let wait_start = std::time::Instant::now();
     let status = child.wait().map_err(|e| {
-        debug_log(&format!("ERROR: Process wait failed after {:?}: {}", wait_start.elapsed(), e));
+        log_error!("claude", "Process wait failed after {:?}: {}", wait_start.elapsed(), e);
         format!("Process error: {}", e)
     })?;
-    debug_log(&format!("Process finished in {:?}, status: {:?}, exit_code: {:?}",
-        wait_start.elapsed(), status, status.code()));
+    log_debug!("claude", "Process finished in {:?}, status: {:?}, exit_code: {:?}",
+        wait_start.elapsed(), status, status.code());
 
     // Handle stdout error or non-zero exit code
     if stdout_error.is_some() || !status.success() {
@@ -907,7 +915,7 @@ IMPORTANT: Format your responses using Markdown for better readability:
             (format!("Process exited with code {:?}", status.code()), String::new())
         };
 
-        debug_log(&format!("Sending error: message={}, exit_code={:?}", message, status.code()));
+        log_debug!("claude", "Sending error: message={}, exit_code={:?}", message, status.code());
         let _ = sender.send(StreamMessage::Error {
             message,
             stdout: stdout_raw,
@@ -919,19 +927,19 @@ IMPORTANT: Format your responses using Markdown for better readability:
 
     // If we didn't get a proper Done message, send one now
     if final_result.is_none() {
-        debug_log("No Done message received, sending synthetic Done message...");
+        log_debug!("claude", "No Done message received, sending synthetic Done message...");
         let send_result = sender.send(StreamMessage::Done {
             result: String::new(),
             session_id: last_session_id.clone(),
         });
-        debug_log(&format!("Synthetic Done message sent, result={:?}", send_result.is_ok()));
+        log_debug!("claude", "Synthetic Done message sent, result={:?}", send_result.is_ok());
     } else {
-        debug_log("Done message was already received, not sending synthetic one");
+        log_debug!("claude", "Done message was already received, not sending synthetic one");
     }
 
-    debug_log("========================================");
-    debug_log("=== execute_command_streaming END (success) ===");
-    debug_log("========================================");
+    log_debug!("claude", "========================================");
+    log_debug!("claude", "=== execute_command_streaming END (success) ===");
+    log_debug!("claude", "========================================");
     Ok(())
 }
 
@@ -1113,6 +1121,8 @@ mod tests {
             success: true,
             response: Some("Hello".to_string()),
             session_id: Some("abc123".to_string()),
+            usage: None,
+            total_cost_usd: None,
             error: None,
         };
 
@@ -1128,6 +1138,8 @@ mod tests {
             success: false,
             response: None,
             session_id: None,
+            usage: None,
+            total_cost_usd: None,
             error: Some("Connection failed".to_string()),
         };
 
@@ -1146,6 +1158,16 @@ mod tests {
         assert!(response.success);
         assert_eq!(response.response, Some("Hello, world!".to_string()));
         assert_eq!(response.session_id, Some("test-123".to_string()));
+        assert!(response.usage.is_none());
+    }
+
+    #[test]
+    fn test_parse_claude_output_usage() {
+        let output = r#"{"session_id": "s1", "result": "ok", "total_cost_usd": 0.0125, "usage": {"input_tokens": 10, "output_tokens": 5}}"#;
+        let response = parse_claude_output(output);
+
+        assert_eq!(response.total_cost_usd, Some(0.0125));
+        assert_eq!(response.usage.unwrap()["output_tokens"], 5);
     }
 
     #[test]
@@ -0,0 +1,279 @@
+//! Storage cleanup suggestions: find common space hogs under a directory.
+//!
+//! The scan looks for dependency and build folders of projects that have not
+//! been touched for a while, tool caches, core dumps and very large files that
+//! were neither modified nor read for a year. Matched directories are measured
+//! but not descended into, so nested matches (a `node_modules` inside another)
+//! are never counted twice.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// `node_modules` of projects untouched this long are suggested
+const OLD_PROJECT_AGE: Duration = Duration::from_secs(90 * 24 * 60 * 60);
+
+/// Large files must be untouched (modified and accessed) this long
+const LARGE_FILE_AGE: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+/// Size from which a file counts as large
+const LARGE_FILE_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// Directory names that only hold regenerable caches
+const CACHE_DIR_NAMES: &[&str] = &[
+    ".cache",
+    "__pycache__",
+    ".pytest_cache",
+    ".mypy_cache",
+    ".ruff_cache",
+    ".parcel-cache",
+    ".sass-cache",
+    ".gradle",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CleanupCategory {
+    NodeModules,
+    BuildOutput,
+    Caches,
+    CoreDumps,
+    LargeOldFiles,
+}
+
+impl CleanupCategory {
+    pub const ALL: [CleanupCategory; 5] = [
+        CleanupCategory::NodeModules,
+        CleanupCategory::BuildOutput,
+        CleanupCategory::Caches,
+        CleanupCategory::CoreDumps,
+        CleanupCategory::LargeOldFiles,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CleanupCategory::NodeModules => "Old node_modules",
+            CleanupCategory::BuildOutput => "Rust build output (target/)",
+            CleanupCategory::Caches => "Caches",
+            CleanupCategory::CoreDumps => "Core dumps",
+            CleanupCategory::LargeOldFiles => "Files over 1 GB untouched for a year",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CleanupItem {
+    pub path: PathBuf,
+    pub category: CleanupCategory,
+    /// Total size (recursive for directories)
+    pub size: u64,
+    pub is_dir: bool,
+}
+
+pub enum CleanupMessage {
+    /// Directory being scanned
+    Scanning(PathBuf),
+    Found(CleanupItem),
+    ScanComplete,
+    /// An item was deleted, freeing `u64` bytes
+    Deleted(PathBuf, u64),
+    Error(String),
+    DeleteComplete,
+}
+
+/// Category a directory entry belongs to, if it is a cleanup candidate
+fn classify(path: &Path, metadata: &fs::Metadata, now: SystemTime) -> Option<CleanupCategory> {
+    let name = path.file_name()?.to_str()?;
+    let parent = path.parent()?;
+
+    if metadata.is_dir() {
+        if name == "node_modules" {
+            let project_age = project_age(parent, now)?;
+            return (project_age >= OLD_PROJECT_AGE).then_some(CleanupCategory::NodeModules);
+        }
+        if name == "target" && parent.join("Cargo.toml").is_file() {
+            return Some(CleanupCategory::BuildOutput);
+        }
+        if CACHE_DIR_NAMES.contains(&name) {
+            return Some(CleanupCategory::Caches);
+        }
+        return None;
+    }
+
+    if !metadata.is_file() {
+        return None;
+    }
+    if is_core_dump_name(name) {
+        return Some(CleanupCategory::CoreDumps);
+    }
+    if metadata.len() >= LARGE_FILE_SIZE {
+        let modified = metadata.modified().ok()?;
+        let last_used = metadata.accessed().map(|a| a.max(modified)).unwrap_or(modified);
+        if now.duration_since(last_used).unwrap_or_default() >= LARGE_FILE_AGE {
+            return Some(CleanupCategory::LargeOldFiles);
+        }
+    }
+    None
+}
+
+/// Time since the project's manifest (package.json, else the folder) was modified
+fn project_age(project: &Path, now: SystemTime) -> Option<Duration> {
+    let manifest = project.join("package.json");
+    let modified = fs::metadata(&manifest)
+        .or_else(|_| fs::metadata(project))
+        .and_then(|m| m.modified())
+        .ok()?;
+    Some(now.duration_since(modified).unwrap_or_default())
+}
+
+/// `core`, `core.1234` and `vgcore.1234`
+fn is_core_dump_name(name: &str) -> bool {
+    let pid_suffix = |rest: &str| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit());
+    name == "core"
+        || name.strip_prefix("core.").is_some_and(pid_suffix)
+        || name.strip_prefix("vgcore.").is_some_and(pid_suffix)
+}
+
+/// Recursive size of a directory (symlinks are not followed)
+fn dir_size(path: &Path, cancel_flag: &AtomicBool) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else { return 0 };
+    let mut total = 0;
+    for entry in entries.flatten() {
+        if cancel_flag.load(Ordering::Relaxed) {
+            break;
+        }
+        let Ok(metadata) = entry.path().symlink_metadata() else { continue };
+        if metadata.is_dir() {
+            total += dir_size(&entry.path(), cancel_flag);
+        } else if metadata.is_file() {
+            total += metadata.len();
+        }
+    }
+    total
+}
+
+fn scan_dir(dir: &Path, now: SystemTime, tx: &Sender<CleanupMessage>, cancel_flag: &AtomicBool) {
+    let _ = tx.send(CleanupMessage::Scanning(dir.to_path_buf()));
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return;
+        }
+        let path = entry.path();
+        let Ok(metadata) = path.symlink_metadata() else { continue };
+
+        match classify(&path, &metadata, now) {
+            Some(category) => {
+                let is_dir = metadata.is_dir();
+                let size = if is_dir { dir_size(&path, cancel_flag) } else { metadata.len() };
+                if size > 0 {
+                    let _ = tx.send(CleanupMessage::Found(CleanupItem { path, category, size, is_dir }));
+                }
+            }
+            None if metadata.is_dir() && path.file_name().is_some_and(|n| n != "node_modules") => {
+                scan_dir(&path, now, tx, cancel_flag);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Scan `root` and report every candidate as it is found
+pub fn run_scan(root: PathBuf, tx: Sender<CleanupMessage>, cancel_flag: Arc<AtomicBool>) {
+    scan_dir(&root, SystemTime::now(), &tx, &cancel_flag);
+    let _ = tx.send(CleanupMessage::ScanComplete);
+}
+
+/// Permanently delete the given items
+pub fn run_delete(items: Vec<CleanupItem>, tx: Sender<CleanupMessage>, cancel_flag: Arc<AtomicBool>) {
+    for item in items {
+        if cancel_flag.load(Ordering::Relaxed) {
+            break;
+        }
+        let result = if item.is_dir {
+            fs::remove_dir_all(&item.path)
+        } else {
+            fs::remove_file(&item.path)
+        };
+        let _ = match result {
+            Ok(()) => tx.send(CleanupMessage::Deleted(item.path, item.size)),
+            Err(e) => tx.send(CleanupMessage::Error(format!("{}: {}", item.path.display(), e))),
+        };
+    }
+    let _ = tx.send(CleanupMessage::DeleteComplete);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(root: &Path) -> Vec<CleanupItem> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        run_scan(root.to_path_buf(), tx, Arc::new(AtomicBool::new(false)));
+        let mut found: Vec<CleanupItem> = rx.iter().filter_map(|m| match m {
+            CleanupMessage::Found(item) => Some(item),
+            _ => None,
+        }).collect();
+        found.sort_by(|a, b| a.path.cmp(&b.path));
+        found
+    }
+
+    #[test]
+    fn test_core_dump_names() {
+        assert!(is_core_dump_name("core"));
+        assert!(is_core_dump_name("core.4711"));
+        assert!(is_core_dump_name("vgcore.12"));
+        assert!(!is_core_dump_name("core.rs"));
+        assert!(!is_core_dump_name("core."));
+        assert!(!is_core_dump_name("score"));
+    }
+
+    #[test]
+    fn test_scan_finds_candidates() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        // Rust project: target/ is reported and not descended into
+        fs::create_dir_all(root.join("app/target/debug/.cache")).unwrap();
+        fs::write(root.join("app/Cargo.toml"), "[package]").unwrap();
+        fs::write(root.join("app/target/debug/app"), vec![0u8; 100]).unwrap();
+        fs::write(root.join("app/target/debug/.cache/x"), vec![0u8; 10]).unwrap();
+        // "target" without Cargo.toml is left alone
+        fs::create_dir_all(root.join("docs/target")).unwrap();
+        fs::write(root.join("docs/target/page.html"), "x").unwrap();
+        // Fresh node_modules is not old enough
+        fs::create_dir_all(root.join("web/node_modules/left-pad")).unwrap();
+        fs::write(root.join("web/package.json"), "{}").unwrap();
+        fs::write(root.join("web/node_modules/left-pad/index.js"), "x").unwrap();
+        // Cache and core dump
+        fs::create_dir_all(root.join("py/__pycache__")).unwrap();
+        fs::write(root.join("py/__pycache__/m.pyc"), vec![0u8; 5]).unwrap();
+        fs::write(root.join("py/core.123"), vec![0u8; 7]).unwrap();
+
+        let found = scan(root);
+        let summary: Vec<(PathBuf, CleanupCategory, u64)> = found.iter()
+            .map(|i| (i.path.strip_prefix(root).unwrap().to_path_buf(), i.category, i.size))
+            .collect();
+        assert_eq!(summary, vec![
+            (PathBuf::from("app/target"), CleanupCategory::BuildOutput, 110),
+            (PathBuf::from("py/__pycache__"), CleanupCategory::Caches, 5),
+            (PathBuf::from("py/core.123"), CleanupCategory::CoreDumps, 7),
+        ]);
+    }
+
+    #[test]
+    fn test_run_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join(".cache");
+        fs::create_dir_all(cache.join("sub")).unwrap();
+        fs::write(cache.join("sub/a"), vec![0u8; 3]).unwrap();
+        let item = CleanupItem { path: cache.clone(), category: CleanupCategory::Caches, size: 3, is_dir: true };
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        run_delete(vec![item], tx, Arc::new(AtomicBool::new(false)));
+        assert!(!cache.exists());
+        assert!(rx.iter().any(|m| matches!(m, CleanupMessage::Deleted(_, 3))));
+    }
+}
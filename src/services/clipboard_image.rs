@@ -0,0 +1,148 @@
+//! Saving an image from the system clipboard (e.g. a screenshot) as a PNG file,
+//! and reading clipboard text.
+//!
+//! The clipboard is read with the platform tool: `wl-paste` on Wayland,
+//! `xclip` on X11, `pngpaste` (or AppleScript) on macOS. Non-PNG images are
+//! converted, so the result is always a PNG.
+
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use chrono::{DateTime, Local};
+
+const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Run a clipboard tool. `Ok(None)` when the tool is not installed.
+fn run(program: &str, args: &[&str]) -> Result<Option<Vec<u8>>, String> {
+    match Command::new(program).args(args).stdin(Stdio::null()).stderr(Stdio::null()).output() {
+        Ok(output) if output.status.success() => Ok(Some(output.stdout)),
+        Ok(_) => Ok(Some(Vec::new())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Failed to run {}: {}", program, e)),
+    }
+}
+
+/// First `image/*` type in a MIME type listing, preferring PNG
+fn pick_image_type(types: &str) -> Option<&str> {
+    let images: Vec<&str> = types.lines().map(str::trim).filter(|t| t.starts_with("image/")).collect();
+    images.iter().find(|&&t| t == "image/png").or_else(|| images.first()).copied()
+}
+
+/// Wayland (`wl-paste`) or X11 (`xclip`)
+fn read_unix() -> Result<Option<Vec<u8>>, String> {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let (program, list_args) = if wayland {
+        ("wl-paste", vec!["--list-types"])
+    } else {
+        ("xclip", vec!["-selection", "clipboard", "-t", "TARGETS", "-o"])
+    };
+
+    let Some(types) = run(program, &list_args)? else {
+        return Err(if wayland {
+            "wl-paste not found (install wl-clipboard)".to_string()
+        } else {
+            "xclip not found (install xclip)".to_string()
+        });
+    };
+    let types = String::from_utf8_lossy(&types);
+    let Some(mime) = pick_image_type(&types) else { return Ok(None) };
+
+    let fetch_args = if wayland {
+        vec!["--no-newline", "--type", mime]
+    } else {
+        vec!["-selection", "clipboard", "-t", mime, "-o"]
+    };
+    Ok(run(program, &fetch_args)?.filter(|data| !data.is_empty()))
+}
+
+/// `pngpaste`, falling back to AppleScript's hex dump of the PNG data
+fn read_macos() -> Result<Option<Vec<u8>>, String> {
+    if let Some(data) = run("pngpaste", &["-"])? {
+        return Ok(Some(data).filter(|d| !d.is_empty()));
+    }
+    let Some(out) = run("osascript", &["-e", "the clipboard as «class PNGf»"])? else {
+        return Err("No clipboard tool found (install pngpaste)".to_string());
+    };
+    // Output looks like «data PNGf89504E47...»
+    let out = String::from_utf8_lossy(&out);
+    let hex = out.trim().trim_start_matches("«data PNGf").trim_end_matches('»');
+    Ok(hex::decode(hex).ok().filter(|d| !d.is_empty()))
+}
+
+/// Read the clipboard image as PNG bytes
+pub fn read_png() -> Result<Vec<u8>, String> {
+    let data = if cfg!(target_os = "macos") { read_macos()? } else { read_unix()? };
+    let data = data.ok_or_else(|| "Clipboard does not contain an image".to_string())?;
+    if data.starts_with(PNG_MAGIC) {
+        return Ok(data);
+    }
+
+    // Other image formats are converted
+    let image = image::load_from_memory(&data).map_err(|_| "Clipboard does not contain an image".to_string())?;
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to convert image: {}", e))?;
+    Ok(png)
+}
+
+/// Read the clipboard as text (`pbpaste` on macOS, `wl-paste`, `xclip` or `xsel` elsewhere)
+pub fn read_text() -> Result<String, String> {
+    let candidates: &[(&str, &[&str])] = if cfg!(target_os = "macos") {
+        &[("pbpaste", &[])]
+    } else if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        &[("wl-paste", &["--no-newline"])]
+    } else {
+        &[("xclip", &["-selection", "clipboard", "-o"]), ("xsel", &["--clipboard", "--output"])]
+    };
+    for (program, args) in candidates {
+        if let Some(data) = run(program, args)? {
+            return Ok(String::from_utf8_lossy(&data).into_owned());
+        }
+    }
+    Err(format!("{} not found", candidates.iter().map(|(p, _)| *p).collect::<Vec<_>>().join("/")))
+}
+
+/// "clipboard_2026-01-31_14-05-09.png", with " (1)" etc. if the name is taken
+pub fn timestamped_path(dir: &Path, now: DateTime<Local>) -> PathBuf {
+    let stem = format!("clipboard_{}", now.format("%Y-%m-%d_%H-%M-%S"));
+    let mut candidate = dir.join(format!("{}.png", stem));
+    let mut n = 1;
+    while candidate.exists() {
+        candidate = dir.join(format!("{} ({}).png", stem, n));
+        n += 1;
+    }
+    candidate
+}
+
+/// Write the clipboard image into `dir`, returning the new file
+pub fn save_to(dir: &Path) -> Result<PathBuf, String> {
+    let png = read_png()?;
+    let path = timestamped_path(dir, Local::now());
+    std::fs::write(&path, &png).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_pick_image_type() {
+        assert_eq!(pick_image_type("text/plain\nimage/jpeg\nimage/png\n"), Some("image/png"));
+        assert_eq!(pick_image_type("TARGETS\nimage/bmp"), Some("image/bmp"));
+        assert_eq!(pick_image_type("UTF8_STRING\ntext/plain"), None);
+    }
+
+    #[test]
+    fn test_timestamped_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = Local.with_ymd_and_hms(2026, 1, 31, 14, 5, 9).unwrap();
+        let first = timestamped_path(dir.path(), now);
+        assert_eq!(first, dir.path().join("clipboard_2026-01-31_14-05-09.png"));
+        std::fs::write(&first, b"x").unwrap();
+        assert_eq!(timestamped_path(dir.path(), now), dir.path().join("clipboard_2026-01-31_14-05-09 (1).png"));
+    }
+}
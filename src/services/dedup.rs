@@ -8,6 +8,8 @@ use std::sync::Arc;
 
 use md5::{Digest, Md5};
 
+use crate::services::gitignore::IgnoreMatcher;
+
 const READ_BUF_SIZE: usize = 64 * 1024; // 64KB
 
 // Marker files: if any of these exist INSIDE a directory, skip that entire directory
@@ -63,6 +65,7 @@ fn scan_directory(
     cancel_flag: &Arc<AtomicBool>,
     size_map: &mut HashMap<u64, Vec<FileEntry>>,
     scanned: &mut usize,
+    ignore: Option<&IgnoreMatcher>,
 ) {
     // Directory-level skip: check if marker files exist INSIDE this directory
     // (matches removeduplicated.js lines 47-50)
@@ -106,8 +109,13 @@ fn scan_directory(
             Err(_) => continue,
         };
 
+        if ignore.is_some_and(|m| m.is_ignored(&path, metadata.is_dir())) {
+            continue;
+        }
+
         if metadata.is_dir() {
-            scan_directory(&path, tx, cancel_flag, size_map, scanned);
+            let child = ignore.map(|m| m.child(&path));
+            scan_directory(&path, tx, cancel_flag, size_map, scanned, child.as_ref());
         } else if metadata.is_file() {
             // Skip specific file names (matches removeduplicated.js lines 60-61)
             if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
@@ -174,19 +182,25 @@ fn compute_md5(path: &Path, file_size: u64, tx: &Sender<DedupMessage>, cancel_fl
     Some(format!("{:032x}", hasher.finalize()))
 }
 
+/// `respect_ignore`: skip paths ignored by .gitignore/.ignore files
 pub fn run_dedup(
     target_path: PathBuf,
+    respect_ignore: bool,
     tx: Sender<DedupMessage>,
     cancel_flag: Arc<AtomicBool>,
 ) {
     // Phase 1: Scan
     let _ = tx.send(DedupMessage::Phase(DedupPhase::Scanning));
     let _ = tx.send(DedupMessage::Log("Scanning files...".into()));
+    if respect_ignore {
+        let _ = tx.send(DedupMessage::Log("Skipping paths ignored by .gitignore/.ignore".into()));
+    }
 
     let mut size_map: HashMap<u64, Vec<FileEntry>> = HashMap::new();
     let mut scanned: usize = 0;
 
-    scan_directory(&target_path, &tx, &cancel_flag, &mut size_map, &mut scanned);
+    let ignore = respect_ignore.then(|| IgnoreMatcher::new(&target_path));
+    scan_directory(&target_path, &tx, &cancel_flag, &mut size_map, &mut scanned, ignore.as_ref());
 
     if cancel_flag.load(Ordering::Relaxed) {
         let _ = tx.send(DedupMessage::Log("Cancelled.".into()));
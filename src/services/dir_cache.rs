@@ -0,0 +1,141 @@
+//! In-memory cache of local directory listings.
+//!
+//! Entries are keyed by path and validated against the directory's mtime, so
+//! switching back to a large directory skips the per-entry stat calls. The
+//! directory mtime does not change when a file is modified in place, so
+//! entries also expire after `MAX_AGE` and are invalidated explicitly after
+//! our own file operations (`clear`) and manual refresh.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::ui::app::FileItem;
+
+/// Maximum number of cached directories (least recently used are evicted)
+const MAX_ENTRIES: usize = 32;
+
+/// Cached listings older than this are re-read even if the mtime matches
+const MAX_AGE: Duration = Duration::from_secs(120);
+
+struct CachedDir {
+    mtime: SystemTime,
+    /// Unsorted entries, without the ".." item
+    items: Vec<FileItem>,
+    cached_at: Instant,
+    /// Value of `DirCache::clock` at the last access (for LRU eviction)
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct DirCache {
+    entries: HashMap<PathBuf, CachedDir>,
+    clock: u64,
+}
+
+impl DirCache {
+    fn get(&mut self, path: &Path, mtime: SystemTime) -> Option<Vec<FileItem>> {
+        let fresh = self.entries.get(path)
+            .map(|c| c.mtime == mtime && c.cached_at.elapsed() < MAX_AGE)?;
+        if !fresh {
+            self.entries.remove(path);
+            return None;
+        }
+        self.clock += 1;
+        let cached = self.entries.get_mut(path)?;
+        cached.last_used = self.clock;
+        Some(cached.items.clone())
+    }
+
+    fn insert(&mut self, path: PathBuf, mtime: SystemTime, items: Vec<FileItem>) {
+        if !self.entries.contains_key(&path) && self.entries.len() >= MAX_ENTRIES {
+            let oldest = self.entries.iter()
+                .min_by_key(|(_, c)| c.last_used)
+                .map(|(p, _)| p.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.clock += 1;
+        let entry = CachedDir { mtime, items, cached_at: Instant::now(), last_used: self.clock };
+        self.entries.insert(path, entry);
+    }
+}
+
+fn cache() -> &'static Mutex<DirCache> {
+    static CACHE: OnceLock<Mutex<DirCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(DirCache::default()))
+}
+
+/// Modification time of a directory (the cache validation key)
+pub fn dir_mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Cached listing of `path` if it is still valid for `mtime`
+pub fn get(path: &Path, mtime: SystemTime) -> Option<Vec<FileItem>> {
+    cache().lock().ok()?.get(path, mtime)
+}
+
+/// Store a freshly read listing (unsorted, without "..")
+pub fn insert(path: &Path, mtime: SystemTime, items: Vec<FileItem>) {
+    if let Ok(mut c) = cache().lock() {
+        c.insert(path.to_path_buf(), mtime, items);
+    }
+}
+
+/// Drop all cached listings (after file operations and manual refresh)
+pub fn clear() {
+    if let Ok(mut c) = cache().lock() {
+        c.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+
+    fn item(name: &str) -> FileItem {
+        FileItem {
+            name: name.to_string(),
+            display_name: None,
+            is_directory: false,
+            is_symlink: false,
+            link_target: None,
+            size: 1,
+            modified: Local::now(),
+            permissions: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_dir_cache_mtime_validation() {
+        let mut cache = DirCache::default();
+        let path = PathBuf::from("/tmp/a");
+        let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(100);
+        cache.insert(path.clone(), mtime, vec![item("x")]);
+
+        assert_eq!(cache.get(&path, mtime).map(|v| v.len()), Some(1));
+        // Changed mtime invalidates the entry
+        assert!(cache.get(&path, mtime + Duration::from_secs(1)).is_none());
+        assert!(cache.get(&path, mtime).is_none());
+    }
+
+    #[test]
+    fn test_dir_cache_evicts_least_recently_used() {
+        let mut cache = DirCache::default();
+        let mtime = SystemTime::UNIX_EPOCH;
+        for i in 0..MAX_ENTRIES {
+            cache.insert(PathBuf::from(format!("/d{}", i)), mtime, Vec::new());
+        }
+        // Touch /d0 so /d1 becomes the oldest
+        assert!(cache.get(Path::new("/d0"), mtime).is_some());
+        cache.insert(PathBuf::from("/new"), mtime, Vec::new());
+
+        assert_eq!(cache.entries.len(), MAX_ENTRIES);
+        assert!(cache.entries.contains_key(Path::new("/d0")));
+        assert!(!cache.entries.contains_key(Path::new("/d1")));
+    }
+}
@@ -0,0 +1,262 @@
+//! Persistent cache of recursive directory sizes.
+//!
+//! Totals are keyed by path and validated against the directory's mtime, so
+//! File Info and the panels can show a size without walking the tree again.
+//! The mtime of a directory only changes when its direct entries change, so a
+//! file added or grown deeper in the tree is picked up when the size is
+//! recalculated (File Info) or the entry is re-indexed after `MAX_AGE`.
+//!
+//! The cache is stored in `~/.cokacdir/dir_sizes.json`. The optional
+//! `Indexer` measures directories on a background thread (one at a time, so
+//! indexing stays out of the way of file operations) and records the results.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Settings;
+
+/// Maximum number of stored directories (oldest measurements are evicted)
+const MAX_ENTRIES: usize = 5000;
+
+/// Entries older than this are still shown but re-measured by the indexer
+const MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Recursive totals of a directory
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DirSize {
+    pub total_size: u64,
+    pub file_count: u64,
+    pub dir_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedSize {
+    /// Directory mtime in nanoseconds since the epoch when it was measured
+    mtime: i64,
+    /// When it was measured (seconds since the epoch)
+    measured_at: u64,
+    #[serde(flatten)]
+    size: DirSize,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SizeStore {
+    /// Keyed by the path as text (JSON object keys must be strings)
+    entries: HashMap<String, CachedSize>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl SizeStore {
+    fn get(&self, path: &Path, mtime: i64) -> Option<&CachedSize> {
+        self.entries.get(path.to_string_lossy().as_ref()).filter(|c| c.mtime == mtime)
+    }
+
+    fn insert(&mut self, path: &Path, mtime: i64, size: DirSize) {
+        let path = path.to_string_lossy().to_string();
+        if !self.entries.contains_key(&path) && self.entries.len() >= MAX_ENTRIES {
+            let oldest = self.entries.iter()
+                .min_by_key(|(_, c)| c.measured_at)
+                .map(|(p, _)| p.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(path, CachedSize { mtime, measured_at: now_secs(), size });
+        self.dirty = true;
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn cache_path() -> Option<PathBuf> {
+    Settings::config_dir().map(|d| d.join("dir_sizes.json"))
+}
+
+fn store() -> &'static Mutex<SizeStore> {
+    static STORE: OnceLock<Mutex<SizeStore>> = OnceLock::new();
+    STORE.get_or_init(|| {
+        let loaded = cache_path()
+            .and_then(|p| fs::read_to_string(p).ok())
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        Mutex::new(loaded)
+    })
+}
+
+/// Cache key for a modification time
+pub fn mtime_key(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_nanos() as i64,
+        Err(e) => -(e.duration().as_nanos() as i64),
+    }
+}
+
+/// Current mtime key of a directory
+pub fn dir_mtime(path: &Path) -> Option<i64> {
+    fs::metadata(path).and_then(|m| m.modified()).ok().map(mtime_key)
+}
+
+/// Cached totals of `path` if they were measured at `mtime`
+pub fn get(path: &Path, mtime: i64) -> Option<DirSize> {
+    store().lock().ok()?.get(path, mtime).map(|c| c.size)
+}
+
+/// Whether `path` has no valid entry or its entry is older than `MAX_AGE`
+pub fn needs_indexing(path: &Path, mtime: i64) -> bool {
+    let Ok(s) = store().lock() else { return false };
+    s.get(path, mtime)
+        .map_or(true, |c| now_secs().saturating_sub(c.measured_at) > MAX_AGE.as_secs())
+}
+
+/// Record totals measured while the directory had `mtime`
+pub fn insert(path: &Path, mtime: i64, size: DirSize) {
+    if let Ok(mut s) = store().lock() {
+        s.insert(path, mtime, size);
+    }
+}
+
+/// Write the cache file if anything changed since the last save
+pub fn save() {
+    let Some(path) = cache_path() else { return };
+    let Ok(mut s) = store().lock() else { return };
+    if !s.dirty {
+        return;
+    }
+    let Ok(content) = serde_json::to_string(&*s) else { return };
+    let temp_path = path.with_extension("json.tmp");
+    if fs::write(&temp_path, content).is_ok() && fs::rename(&temp_path, &path).is_ok() {
+        s.dirty = false;
+    }
+}
+
+/// Kernel pseudo-filesystems that are never measured
+fn is_virtual_fs(path: &Path) -> bool {
+    cfg!(target_os = "linux") && ["/proc", "/sys", "/dev"].iter().any(|p| path == Path::new(p))
+}
+
+/// Walk `path` on the current thread. Returns None when cancelled.
+pub fn measure(path: &Path, cancel_flag: &AtomicBool) -> Option<DirSize> {
+    let mut size = DirSize::default();
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.filter_map(|e| e.ok()) {
+            if cancel_flag.load(Ordering::Relaxed) {
+                return None;
+            }
+            let Ok(metadata) = fs::symlink_metadata(entry.path()) else { continue };
+            if metadata.file_type().is_symlink() {
+                // Symlinks count as files of size 0 and are not followed
+                size.file_count += 1;
+            } else if metadata.is_dir() {
+                size.dir_count += 1;
+                if !is_virtual_fs(&entry.path()) {
+                    pending.push(entry.path());
+                }
+            } else {
+                size.file_count += 1;
+                size.total_size += metadata.len();
+            }
+        }
+    }
+    Some(size)
+}
+
+/// Background thread measuring requested directories into the cache
+pub struct Indexer {
+    requests: Sender<PathBuf>,
+    results: Receiver<(PathBuf, DirSize)>,
+    cancel_flag: Arc<AtomicBool>,
+}
+
+impl Indexer {
+    pub fn spawn() -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<PathBuf>();
+        let (result_tx, result_rx) = mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let cancel = cancel_flag.clone();
+
+        thread::spawn(move || {
+            while let Ok(first) = request_rx.recv() {
+                let mut next = Some(first);
+                while let Some(path) = next.take() {
+                    next = request_rx.try_recv().ok();
+                    // The same directory may have been requested more than once
+                    let Some(mtime) = dir_mtime(&path) else { continue };
+                    if !needs_indexing(&path, mtime) {
+                        continue;
+                    }
+                    let Some(size) = measure(&path, &cancel) else { return };
+                    insert(&path, mtime, size);
+                    if result_tx.send((path, size)).is_err() {
+                        return;
+                    }
+                }
+                // Queue drained: persist what was measured
+                save();
+            }
+        });
+
+        Self { requests: request_tx, results: result_rx, cancel_flag }
+    }
+
+    /// Queue a directory for measuring
+    pub fn request(&self, path: PathBuf) {
+        if !is_virtual_fs(&path) {
+            let _ = self.requests.send(path);
+        }
+    }
+
+    /// Directories measured since the last call
+    pub fn results(&self) -> Vec<(PathBuf, DirSize)> {
+        self.results.try_iter().collect()
+    }
+}
+
+impl Drop for Indexer {
+    fn drop(&mut self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_store_mtime_validation_and_eviction() {
+        let mut store = SizeStore::default();
+        let size = DirSize { total_size: 10, file_count: 2, dir_count: 1 };
+        store.insert(Path::new("/tmp/a"), 100, size);
+        assert_eq!(store.get(Path::new("/tmp/a"), 100).map(|c| c.size), Some(size));
+        assert!(store.get(Path::new("/tmp/a"), 101).is_none());
+
+        for i in 0..MAX_ENTRIES {
+            store.insert(Path::new(&format!("/d{}", i)), 0, DirSize::default());
+        }
+        assert_eq!(store.entries.len(), MAX_ENTRIES);
+    }
+
+    #[test]
+    fn test_measure() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("a/b")).unwrap();
+        fs::write(dir.path().join("x.txt"), b"12345").unwrap();
+        fs::write(dir.path().join("a/b/y.txt"), b"123").unwrap();
+
+        let size = measure(dir.path(), &AtomicBool::new(false)).unwrap();
+        assert_eq!(size, DirSize { total_size: 8, file_count: 2, dir_count: 2 });
+        assert!(measure(dir.path(), &AtomicBool::new(true)).is_none());
+    }
+}
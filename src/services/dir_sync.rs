@@ -0,0 +1,370 @@
+//! Two-way directory synchronization for the Diff screen's Sync mode.
+//!
+//! The Diff screen's comparison is turned into [`SyncItem`]s, one per entry
+//! that differs: only on the left, only on the right, newer on one side, or a
+//! conflict (the contents differ but neither side is newer, or a file faces a
+//! directory). Each item carries a direction the user can change; [`plan`]
+//! turns the items into copy/delete operations that can be listed as a dry
+//! run, and [`execute`] carries them out with progress reporting. Copies keep
+//! the source's modification time so a later time-based compare sees the two
+//! sides as equal.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::services::file_ops::{self, ProgressMessage};
+use crate::{log_info, log_warn};
+
+/// How an entry differs between the two sides
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncClass {
+    OnlyLeft,
+    OnlyRight,
+    LeftNewer,
+    RightNewer,
+    Conflict,
+}
+
+impl SyncClass {
+    /// Classify an entry present on both sides whose contents differ.
+    /// `(is_directory, modified)` of each side.
+    pub fn for_pair(left: (bool, SystemTime), right: (bool, SystemTime)) -> Self {
+        if left.0 != right.0 {
+            return SyncClass::Conflict;
+        }
+        match left.1.cmp(&right.1) {
+            std::cmp::Ordering::Greater => SyncClass::LeftNewer,
+            std::cmp::Ordering::Less => SyncClass::RightNewer,
+            std::cmp::Ordering::Equal => SyncClass::Conflict,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SyncClass::OnlyLeft => "only left",
+            SyncClass::OnlyRight => "only right",
+            SyncClass::LeftNewer => "left newer",
+            SyncClass::RightNewer => "right newer",
+            SyncClass::Conflict => "conflict",
+        }
+    }
+
+    /// Suggested direction: the newer or only copy wins, conflicts are skipped
+    pub fn default_direction(self) -> SyncDirection {
+        match self {
+            SyncClass::OnlyLeft | SyncClass::LeftNewer => SyncDirection::LeftToRight,
+            SyncClass::OnlyRight | SyncClass::RightNewer => SyncDirection::RightToLeft,
+            SyncClass::Conflict => SyncDirection::Skip,
+        }
+    }
+}
+
+/// Which side overwrites the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncDirection {
+    LeftToRight,
+    RightToLeft,
+    Skip,
+}
+
+impl SyncDirection {
+    pub fn arrow(self) -> &'static str {
+        match self {
+            SyncDirection::LeftToRight => "->",
+            SyncDirection::RightToLeft => "<-",
+            SyncDirection::Skip => "==",
+        }
+    }
+}
+
+/// One differing entry of the comparison
+#[derive(Debug, Clone)]
+pub struct SyncItem {
+    pub relative_path: String,
+    pub class: SyncClass,
+    pub is_directory: bool,
+    pub direction: SyncDirection,
+}
+
+impl SyncItem {
+    pub fn new(relative_path: String, class: SyncClass, is_directory: bool) -> Self {
+        Self { relative_path, class, is_directory, direction: class.default_direction() }
+    }
+}
+
+/// A single step of the sync plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncOp {
+    /// Copy `from` over `to` (replacing whatever is there)
+    Copy { from: PathBuf, to: PathBuf },
+    /// Remove an entry that only exists on the target side
+    Delete(PathBuf),
+}
+
+impl SyncOp {
+    /// One line of the dry-run preview
+    pub fn describe(&self) -> String {
+        match self {
+            SyncOp::Copy { from, to } => format!("copy   {} -> {}", from.display(), to.display()),
+            SyncOp::Delete(path) => format!("delete {}", path.display()),
+        }
+    }
+}
+
+/// Turn the items into operations. Copying toward the side an entry is
+/// missing from creates it; copying from the side it is missing from
+/// deletes it, mirroring the chosen side.
+pub fn plan(items: &[SyncItem], left_root: &Path, right_root: &Path) -> Vec<SyncOp> {
+    items.iter().filter_map(|item| {
+        let left = left_root.join(&item.relative_path);
+        let right = right_root.join(&item.relative_path);
+        let (from, to, source_exists) = match item.direction {
+            SyncDirection::Skip => return None,
+            SyncDirection::LeftToRight => (left, right, item.class != SyncClass::OnlyRight),
+            SyncDirection::RightToLeft => (right, left, item.class != SyncClass::OnlyLeft),
+        };
+        Some(if source_exists { SyncOp::Copy { from, to } } else { SyncOp::Delete(to) })
+    }).collect()
+}
+
+/// Run the plan, reporting through the usual file operation progress messages
+pub fn execute(ops: Vec<SyncOp>, cancel_flag: Arc<AtomicBool>, progress_tx: Sender<ProgressMessage>) {
+    let _ = progress_tx.send(ProgressMessage::Preparing("Counting files...".to_string()));
+    let sources: Vec<PathBuf> = ops.iter().filter_map(|op| match op {
+        SyncOp::Copy { from, .. } => Some(from.clone()),
+        SyncOp::Delete(_) => None,
+    }).collect();
+    let total_bytes = file_ops::calculate_total_size(&sources, &[], &cancel_flag).map(|(bytes, _)| bytes).unwrap_or(0);
+    let _ = progress_tx.send(ProgressMessage::PrepareComplete);
+    log_info!("dir_sync", "Sync {} operation(s), {} bytes", ops.len(), total_bytes);
+
+    let total = ops.len();
+    let mut success_count = 0;
+    let mut failure_count = 0;
+    let mut completed_bytes: u64 = 0;
+
+    for op in &ops {
+        if cancel_flag.load(Ordering::Relaxed) {
+            break;
+        }
+        let (name, result) = match op {
+            SyncOp::Copy { from, to } => {
+                let _ = progress_tx.send(ProgressMessage::FileStarted(from.display().to_string()));
+                (from, replace_with_copy(from, to, &cancel_flag, &mut |bytes| completed_bytes += bytes))
+            }
+            SyncOp::Delete(path) => {
+                let _ = progress_tx.send(ProgressMessage::FileStarted(path.display().to_string()));
+                (path, file_ops::delete_file(path))
+            }
+        };
+        let name = name.display().to_string();
+        match result {
+            Ok(()) => {
+                success_count += 1;
+                let _ = progress_tx.send(ProgressMessage::FileCompleted(name));
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => break,
+            Err(e) => {
+                failure_count += 1;
+                log_warn!("dir_sync", "{}: {}", name, e);
+                let _ = progress_tx.send(ProgressMessage::Error(name, e.to_string()));
+            }
+        }
+        let _ = progress_tx.send(ProgressMessage::TotalProgress(
+            success_count + failure_count,
+            total,
+            completed_bytes,
+            total_bytes,
+        ));
+    }
+
+    let untouched = total - success_count - failure_count;
+    if untouched > 0 {
+        failure_count += untouched;
+        let _ = progress_tx.send(ProgressMessage::Error(String::new(), format!("Cancelled, {} item(s) left", untouched)));
+    }
+    log_info!("dir_sync", "Sync finished: {} succeeded, {} failed", success_count, failure_count);
+    let _ = progress_tx.send(ProgressMessage::Completed(success_count, failure_count));
+}
+
+/// Replace `to` with a copy of `from`. A directory replaces a file (or the
+/// other way round) by removing the target first; a directory onto a
+/// directory is merged file by file.
+fn replace_with_copy(
+    from: &Path,
+    to: &Path,
+    cancel_flag: &AtomicBool,
+    on_copied: &mut dyn FnMut(u64),
+) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(from)?;
+    if let Ok(existing) = fs::symlink_metadata(to) {
+        if existing.is_dir() != metadata.is_dir() || existing.is_symlink() {
+            file_ops::delete_file(to)?;
+        }
+    }
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    copy_entry(from, to, &metadata, cancel_flag, on_copied)
+}
+
+fn copy_entry(
+    from: &Path,
+    to: &Path,
+    metadata: &fs::Metadata,
+    cancel_flag: &AtomicBool,
+    on_copied: &mut dyn FnMut(u64),
+) -> io::Result<()> {
+    if cancel_flag.load(Ordering::Relaxed) {
+        return Err(io::Error::new(io::ErrorKind::Interrupted, "Cancelled"));
+    }
+    if metadata.is_symlink() {
+        // symlink() does not overwrite, so drop whatever the target is first
+        if fs::symlink_metadata(to).is_ok() {
+            file_ops::delete_file(to)?;
+        }
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(fs::read_link(from)?, to)?;
+        return Ok(());
+    }
+    if metadata.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            let child_metadata = fs::symlink_metadata(entry.path())?;
+            let target = to.join(entry.file_name());
+            if let Ok(existing) = fs::symlink_metadata(&target) {
+                if existing.is_dir() != child_metadata.is_dir() || existing.is_symlink() {
+                    file_ops::delete_file(&target)?;
+                }
+            }
+            copy_entry(&entry.path(), &target, &child_metadata, cancel_flag, on_copied)?;
+        }
+        return Ok(());
+    }
+    // Copy next to the target and rename it into place, so an interrupted copy
+    // never leaves a half-written target and a read-only target is replaced
+    let name = to.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let tmp = to.with_file_name(format!(".{}.cokacdir-sync", name));
+    let result = fs::copy(from, &tmp).and_then(|_| {
+        if let Ok(modified) = metadata.modified() {
+            // A read-only handle is enough for the owner, and works for 0444 copies
+            File::open(&tmp)?.set_modified(modified)?;
+        }
+        fs::rename(&tmp, to)
+    });
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
+    on_copied(metadata.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_classify_and_plan() {
+        let t = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let later = t + Duration::from_secs(60);
+        assert_eq!(SyncClass::for_pair((false, later), (false, t)), SyncClass::LeftNewer);
+        assert_eq!(SyncClass::for_pair((false, t), (false, later)), SyncClass::RightNewer);
+        assert_eq!(SyncClass::for_pair((false, t), (false, t)), SyncClass::Conflict);
+        assert_eq!(SyncClass::for_pair((true, later), (false, t)), SyncClass::Conflict);
+
+        let mut items = vec![
+            SyncItem::new("a.txt".into(), SyncClass::OnlyLeft, false),
+            SyncItem::new("b.txt".into(), SyncClass::OnlyRight, false),
+            SyncItem::new("c.txt".into(), SyncClass::RightNewer, false),
+            SyncItem::new("d.txt".into(), SyncClass::Conflict, false),
+        ];
+        let (l, r) = (Path::new("/l"), Path::new("/r"));
+        assert_eq!(plan(&items, l, r), vec![
+            SyncOp::Copy { from: l.join("a.txt"), to: r.join("a.txt") },
+            SyncOp::Copy { from: r.join("b.txt"), to: l.join("b.txt") },
+            SyncOp::Copy { from: r.join("c.txt"), to: l.join("c.txt") },
+        ]);
+
+        // Mirroring left onto right deletes what only the right side has
+        for item in &mut items {
+            item.direction = SyncDirection::LeftToRight;
+        }
+        let ops = plan(&items, l, r);
+        assert_eq!(ops[1], SyncOp::Delete(r.join("b.txt")));
+        assert_eq!(ops[3], SyncOp::Copy { from: l.join("d.txt"), to: r.join("d.txt") });
+    }
+
+    #[test]
+    fn test_execute_copies_and_deletes() {
+        let left = TempDir::new().unwrap();
+        let right = TempDir::new().unwrap();
+        fs::create_dir_all(left.path().join("dir/sub")).unwrap();
+        fs::write(left.path().join("dir/sub/new.txt"), "new").unwrap();
+        fs::write(left.path().join("same.txt"), "left").unwrap();
+        fs::write(right.path().join("same.txt"), "right, older").unwrap();
+        fs::write(right.path().join("extra.txt"), "extra").unwrap();
+        fs::write(right.path().join("dir"), "a file where the left has a directory").unwrap();
+        std::os::unix::fs::symlink("same.txt", left.path().join("link")).unwrap();
+        fs::write(right.path().join("link"), "a file where the left has a symlink").unwrap();
+        fs::write(left.path().join("readonly.txt"), "ro").unwrap();
+        fs::set_permissions(left.path().join("readonly.txt"), fs::Permissions::from_mode(0o444)).unwrap();
+
+        fs::write(left.path().join("locked.txt"), "left").unwrap();
+        fs::write(right.path().join("locked.txt"), "right, read-only").unwrap();
+        fs::set_permissions(right.path().join("locked.txt"), fs::Permissions::from_mode(0o444)).unwrap();
+
+        let old = SystemTime::now() - Duration::from_secs(3600);
+        File::options().write(true).open(right.path().join("same.txt")).unwrap().set_modified(old).unwrap();
+
+        let mut items = vec![
+            SyncItem::new("dir".into(), SyncClass::Conflict, true),
+            SyncItem::new("same.txt".into(), SyncClass::LeftNewer, false),
+            SyncItem::new("extra.txt".into(), SyncClass::OnlyRight, false),
+            SyncItem::new("link".into(), SyncClass::Conflict, false),
+            SyncItem::new("readonly.txt".into(), SyncClass::OnlyLeft, false),
+            SyncItem::new("locked.txt".into(), SyncClass::Conflict, false),
+        ];
+        items[0].direction = SyncDirection::LeftToRight;
+        items[2].direction = SyncDirection::LeftToRight;
+        items[3].direction = SyncDirection::LeftToRight;
+        items[5].direction = SyncDirection::LeftToRight;
+
+        let (tx, rx) = mpsc::channel();
+        execute(plan(&items, left.path(), right.path()), Arc::new(AtomicBool::new(false)), tx);
+        let completed = rx.iter().find_map(|m| match m {
+            ProgressMessage::Completed(ok, failed) => Some((ok, failed)),
+            _ => None,
+        });
+        assert_eq!(completed, Some((6, 0)));
+
+        assert_eq!(fs::read_to_string(right.path().join("dir/sub/new.txt")).unwrap(), "new");
+        assert_eq!(fs::read_to_string(right.path().join("same.txt")).unwrap(), "left");
+        assert!(!right.path().join("extra.txt").exists());
+        let modified = |p: &Path| fs::metadata(p).unwrap().modified().unwrap();
+        assert_eq!(modified(&right.path().join("same.txt")), modified(&left.path().join("same.txt")));
+        assert_eq!(fs::read_link(right.path().join("link")).unwrap(), Path::new("same.txt"));
+        assert_eq!(
+            modified(&right.path().join("readonly.txt")),
+            modified(&left.path().join("readonly.txt"))
+        );
+        // A read-only target is replaced, and no temporary copies are left behind
+        assert_eq!(fs::read_to_string(right.path().join("locked.txt")).unwrap(), "left");
+        let leftovers: Vec<_> = fs::read_dir(right.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().ends_with(".cokacdir-sync"))
+            .collect();
+        assert!(leftovers.is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! Mounted filesystems and their free space.
+//!
+//! Used by the panel footer (space of the filesystem holding the current
+//! folder) and the Drives screen (all mount points). Mounts come from
+//! `/proc/mounts` on Linux and `getmntinfo` on macOS. Kernel pseudo
+//! filesystems are left out.
+
+use std::path::{Path, PathBuf};
+
+/// Size of a filesystem in bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiskSpace {
+    pub total: u64,
+    /// Space available to unprivileged users
+    pub available: u64,
+}
+
+impl DiskSpace {
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.available)
+    }
+
+    /// Used fraction in 0.0..=1.0
+    pub fn used_ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.used() as f64 / self.total as f64
+        }
+    }
+}
+
+/// A mounted filesystem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mount {
+    pub mount_point: PathBuf,
+    /// Device or source ("/dev/sda1", "server:/export", ...)
+    pub device: String,
+    pub fs_type: String,
+    pub space: Option<DiskSpace>,
+}
+
+/// Space of the filesystem containing `path`
+#[cfg(unix)]
+pub fn space(path: &Path) -> Option<DiskSpace> {
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
+    // SAFETY: statvfs is a standard POSIX function, c_path is valid
+    let result = unsafe { libc::statvfs(c_path.as_ptr(), stat.as_mut_ptr()) };
+    if result != 0 {
+        return None;
+    }
+    // SAFETY: statvfs succeeded, stat is initialized
+    let stat = unsafe { stat.assume_init() };
+    Some(DiskSpace {
+        total: stat.f_blocks as u64 * stat.f_frsize as u64,
+        available: stat.f_bavail as u64 * stat.f_frsize as u64,
+    })
+}
+
+#[cfg(not(unix))]
+pub fn space(_path: &Path) -> Option<DiskSpace> {
+    None
+}
+
+/// Filesystem types that never hold user files
+const PSEUDO_FS: &[&str] = &[
+    "proc", "sysfs", "devtmpfs", "devpts", "cgroup", "cgroup2", "securityfs", "pstore", "bpf",
+    "tracefs", "debugfs", "configfs", "fusectl", "mqueue", "hugetlbfs", "autofs", "binfmt_misc",
+    "rpc_pipefs", "nsfs", "efivarfs", "selinuxfs", "ramfs", "squashfs", "overlay", "devfs",
+];
+
+fn is_pseudo_fs(fs_type: &str, mount_point: &str) -> bool {
+    PSEUDO_FS.contains(&fs_type)
+        // Snap packages and system tmpfs mounts clutter the list
+        || mount_point.starts_with("/snap/")
+        || (fs_type == "tmpfs" && (mount_point.starts_with("/run") || mount_point.starts_with("/sys") || mount_point == "/dev/shm"))
+        || fs_type == "fuse.snapfuse"
+}
+
+/// Undo the octal escapes of /proc/mounts ("\040" for a space, ...)
+fn unescape_mount_field(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 3 < bytes.len() && bytes[i + 1..i + 4].iter().all(|b| (b'0'..=b'7').contains(b)) {
+            let value = (bytes[i + 1] - b'0') as u32 * 64 + (bytes[i + 2] - b'0') as u32 * 8 + (bytes[i + 3] - b'0') as u32;
+            out.push(value as u8);
+            i += 4;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+/// (device, mount point, fs type) of each line of /proc/mounts, pseudo filesystems excluded
+pub fn parse_proc_mounts(content: &str) -> Vec<(String, String, String)> {
+    let mut mounts: Vec<(String, String, String)> = Vec::new();
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let (Some(device), Some(mount_point), Some(fs_type)) = (fields.next(), fields.next(), fields.next()) else {
+            continue;
+        };
+        let mount_point = unescape_mount_field(mount_point);
+        if is_pseudo_fs(fs_type, &mount_point) {
+            continue;
+        }
+        // 같은 위치에 여러 번 마운트된 경우 마지막(보이는) 것만 유지
+        mounts.retain(|m| m.1 != mount_point);
+        mounts.push((unescape_mount_field(device), mount_point, fs_type.to_string()));
+    }
+    mounts
+}
+
+/// Mounted filesystems sorted by mount point
+pub fn mounts() -> Vec<Mount> {
+    let mut mounts: Vec<Mount> = list_mounts()
+        .into_iter()
+        .map(|(device, mount_point, fs_type)| {
+            let mount_point = PathBuf::from(mount_point);
+            let space = space(&mount_point);
+            Mount { mount_point, device, fs_type, space }
+        })
+        .collect();
+    mounts.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    mounts
+}
+
+#[cfg(target_os = "linux")]
+fn list_mounts() -> Vec<(String, String, String)> {
+    std::fs::read_to_string("/proc/mounts")
+        .map(|content| parse_proc_mounts(&content))
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "macos")]
+fn list_mounts() -> Vec<(String, String, String)> {
+    use std::ffi::CStr;
+
+    let mut buf: *mut libc::statfs = std::ptr::null_mut();
+    // SAFETY: getmntinfo stores a pointer to an internal buffer in buf
+    let count = unsafe { libc::getmntinfo(&mut buf, libc::MNT_NOWAIT) };
+    if count <= 0 || buf.is_null() {
+        return Vec::new();
+    }
+    // SAFETY: getmntinfo returned `count` initialized entries at buf
+    let entries = unsafe { std::slice::from_raw_parts(buf, count as usize) };
+    entries
+        .iter()
+        .filter_map(|stat| {
+            // SAFETY: the name fields are NUL-terminated C strings
+            let (device, mount_point, fs_type) = unsafe {
+                (
+                    CStr::from_ptr(stat.f_mntfromname.as_ptr()).to_string_lossy().to_string(),
+                    CStr::from_ptr(stat.f_mntonname.as_ptr()).to_string_lossy().to_string(),
+                    CStr::from_ptr(stat.f_fstypename.as_ptr()).to_string_lossy().to_string(),
+                )
+            };
+            // System volumes of the sealed macOS layout
+            if is_pseudo_fs(&fs_type, &mount_point) || mount_point.starts_with("/System/Volumes/") {
+                return None;
+            }
+            Some((device, mount_point, fs_type))
+        })
+        .collect()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn list_mounts() -> Vec<(String, String, String)> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_mounts() {
+        let content = "\
+sysfs /sys sysfs rw,nosuid 0 0
+proc /proc proc rw 0 0
+/dev/nvme0n1p2 / ext4 rw,relatime 0 0
+tmpfs /run tmpfs rw 0 0
+/dev/sdb1 /media/usb\\040stick vfat rw 0 0
+/dev/loop3 /snap/core/1 squashfs ro 0 0
+tmpfs /tmp tmpfs rw 0 0
+/dev/sdc1 /tmp ext4 rw 0 0
+";
+        let mounts = parse_proc_mounts(content);
+        assert_eq!(
+            mounts,
+            vec![
+                ("/dev/nvme0n1p2".to_string(), "/".to_string(), "ext4".to_string()),
+                ("/dev/sdb1".to_string(), "/media/usb stick".to_string(), "vfat".to_string()),
+                ("/dev/sdc1".to_string(), "/tmp".to_string(), "ext4".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disk_space() {
+        let space = DiskSpace { total: 1000, available: 250 };
+        assert_eq!(space.used(), 750);
+        assert!((space.used_ratio() - 0.75).abs() < f64::EPSILON);
+        assert_eq!(DiskSpace::default().used_ratio(), 0.0);
+        #[cfg(unix)]
+        assert!(super::space(Path::new("/")).map_or(false, |s| s.total > 0));
+    }
+}
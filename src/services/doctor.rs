@@ -0,0 +1,280 @@
+//! Environment check behind `cokacdir --doctor`.
+//!
+//! Looks for the external programs optional features rely on, reports their
+//! versions and what to install when something is missing. Terminal
+//! capabilities are judged from the environment (no escape-sequence queries),
+//! so the check also works when output is piped.
+
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+
+use crate::config::Settings;
+use crate::services::archive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    /// Works, but with reduced functionality
+    Warn,
+    /// Not found; the features listed in `detail` are unavailable
+    Missing,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+/// First non-empty line of `<program> <args>` output when it succeeds
+fn program_version(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    first_line(&stdout).or_else(|| first_line(&stderr))
+}
+
+fn first_line(text: &str) -> Option<String> {
+    text.lines().map(str::trim).find(|l| !l.is_empty()).map(str::to_string)
+}
+
+fn install_hint(macos: &str, linux: &str) -> String {
+    if cfg!(target_os = "macos") { macos.to_string() } else { linux.to_string() }
+}
+
+fn check_settings() -> Check {
+    match Settings::load_with_error() {
+        Ok(_) => Check {
+            name: "settings",
+            status: Status::Ok,
+            version: None,
+            detail: Settings::config_dir()
+                .map(|d| d.join("settings.json").display().to_string())
+                .unwrap_or_default(),
+            fix: None,
+        },
+        Err(e) => Check {
+            name: "settings",
+            status: Status::Warn,
+            version: None,
+            detail: format!("settings.json could not be read, defaults are used: {}", e),
+            fix: Some("Fix the reported error in ~/.cokacdir/settings.json or delete the file".to_string()),
+        },
+    }
+}
+
+fn check_tar(custom: Option<&str>) -> Check {
+    match archive::find_tar(custom) {
+        Some(cmd) => {
+            let version = program_version(&cmd, &["--version"]);
+            // bsdtar handles most formats but lacks some GNU options used for archiving
+            let is_gnu = version.as_deref().is_some_and(|v| v.contains("GNU"));
+            Check {
+                name: "tar",
+                status: if is_gnu { Status::Ok } else { Status::Warn },
+                version,
+                detail: if is_gnu {
+                    format!("Archives use {}", cmd)
+                } else {
+                    format!("{} is not GNU tar; some archive options may not work", cmd)
+                },
+                fix: (!is_gnu).then(|| install_hint("brew install gnu-tar", "Install GNU tar with your package manager")),
+            }
+        }
+        None => Check {
+            name: "tar",
+            status: Status::Missing,
+            version: None,
+            detail: match custom {
+                Some(cmd) => format!("Configured tar_path \"{}\" does not run; archive features are unavailable", cmd),
+                None => "Neither gtar nor tar found; archive features are unavailable".to_string(),
+            },
+            fix: Some(install_hint("brew install gnu-tar", "sudo apt install tar (or your distribution's equivalent)")),
+        },
+    }
+}
+
+/// `missing` is Warn for programs only used to speed things up
+fn check_program(name: &'static str, purpose: &str, missing: Status, fix: String) -> Check {
+    match program_version(name, &["--version"]) {
+        Some(version) => Check { name, status: Status::Ok, version: Some(version), detail: purpose.to_string(), fix: None },
+        None => Check {
+            name,
+            status: missing,
+            version: None,
+            detail: format!("Not found in PATH ({})", purpose),
+            fix: Some(fix),
+        },
+    }
+}
+
+fn check_claude() -> Check {
+    match crate::services::claude::get_claude_path() {
+        Some(path) => Check {
+            name: "claude",
+            status: Status::Ok,
+            version: program_version(path, &["--version"]),
+            detail: format!("AI assistant uses {}", path),
+            fix: None,
+        },
+        None => Check {
+            name: "claude",
+            status: Status::Missing,
+            version: None,
+            detail: "Claude CLI not found; the AI screen and Telegram bot are unavailable".to_string(),
+            fix: Some("npm install -g @anthropic-ai/claude-code".to_string()),
+        },
+    }
+}
+
+/// Inline image protocol the terminal advertises through its environment
+pub fn image_protocol_from_env(env: impl Fn(&str) -> Option<String>) -> Option<&'static str> {
+    let term = env("TERM").unwrap_or_default();
+    let term_program = env("TERM_PROGRAM").unwrap_or_default();
+    if env("KITTY_WINDOW_ID").is_some() || term == "xterm-kitty" || term == "xterm-ghostty" || term_program == "ghostty" {
+        Some("Kitty")
+    } else if term_program == "iTerm.app" || term_program == "WezTerm" || env("LC_TERMINAL").as_deref() == Some("iTerm2") {
+        Some("iTerm2")
+    } else if term.contains("sixel") || term == "foot" || term == "mlterm" || env("WT_SESSION").is_some() {
+        Some("Sixel")
+    } else {
+        None
+    }
+}
+
+fn check_image_protocol() -> Check {
+    match image_protocol_from_env(|k| std::env::var(k).ok()) {
+        Some(protocol) => Check {
+            name: "image protocol",
+            status: Status::Ok,
+            version: None,
+            detail: format!("{} graphics (images are shown at full resolution)", protocol),
+            fix: None,
+        },
+        None => Check {
+            name: "image protocol",
+            status: Status::Warn,
+            version: None,
+            detail: "No inline graphics protocol detected; images are drawn with half blocks".to_string(),
+            fix: Some("Use a terminal with Kitty, iTerm2 or Sixel graphics (kitty, WezTerm, iTerm2, foot, Ghostty)".to_string()),
+        },
+    }
+}
+
+fn check_truecolor() -> Check {
+    if crate::ui::image_viewer::supports_true_color() {
+        Check { name: "truecolor", status: Status::Ok, version: None, detail: "24-bit color".to_string(), fix: None }
+    } else {
+        Check {
+            name: "truecolor",
+            status: Status::Warn,
+            version: None,
+            detail: "Terminal does not report 24-bit color; theme colors are approximated".to_string(),
+            fix: Some("Set COLORTERM=truecolor if your terminal supports it".to_string()),
+        }
+    }
+}
+
+/// Run all checks
+pub fn run_checks() -> Vec<Check> {
+    let settings = Settings::load_with_error().unwrap_or_default();
+    vec![
+        check_settings(),
+        check_tar(settings.tar_path.as_deref()),
+        check_program(
+            "curl",
+            "checking for new versions",
+            Status::Missing,
+            install_hint("brew install curl", "sudo apt install curl (or your distribution's equivalent)"),
+        ),
+        check_claude(),
+        check_program(
+            "rg",
+            "optional; fast code search for the AI assistant",
+            Status::Warn,
+            install_hint("brew install ripgrep", "sudo apt install ripgrep (or your distribution's equivalent)"),
+        ),
+        check_image_protocol(),
+        check_truecolor(),
+    ]
+}
+
+/// Human readable report
+pub fn render_text(checks: &[Check]) -> String {
+    let name_width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for check in checks {
+        let mark = match check.status {
+            Status::Ok => "ok",
+            Status::Warn => "warn",
+            Status::Missing => "MISSING",
+        };
+        out.push_str(&format!("[{:^7}] {:<w$}  ", mark, check.name, w = name_width));
+        if let Some(ref version) = check.version {
+            out.push_str(&format!("{} - ", version));
+        }
+        out.push_str(&check.detail);
+        out.push('\n');
+        if let Some(ref fix) = check.fix {
+            out.push_str(&format!("{:>9} {:<w$}  fix: {}\n", "", "", fix, w = name_width));
+        }
+    }
+    let problems = checks.iter().filter(|c| c.status != Status::Ok).count();
+    if problems == 0 {
+        out.push_str("\nEverything looks good.\n");
+    } else {
+        out.push_str(&format!("\n{} item(s) need attention.\n", problems));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_protocol_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |k: &str| vars.iter().find(|(n, _)| *n == k).map(|(_, v)| v.to_string())
+        };
+        assert_eq!(image_protocol_from_env(env(&[("TERM", "xterm-kitty")])), Some("Kitty"));
+        assert_eq!(image_protocol_from_env(env(&[("TERM_PROGRAM", "WezTerm")])), Some("iTerm2"));
+        assert_eq!(image_protocol_from_env(env(&[("TERM", "foot")])), Some("Sixel"));
+        assert_eq!(image_protocol_from_env(env(&[("TERM", "xterm-256color")])), None);
+    }
+
+    #[test]
+    fn test_render_text() {
+        let checks = vec![
+            Check { name: "tar", status: Status::Ok, version: Some("tar (GNU tar) 1.35".to_string()), detail: "Archives use tar".to_string(), fix: None },
+            Check { name: "rg", status: Status::Missing, version: None, detail: "Not found".to_string(), fix: Some("install ripgrep".to_string()) },
+        ];
+        let text = render_text(&checks);
+        assert!(text.contains("[  ok   ] tar  tar (GNU tar) 1.35 - Archives use tar"));
+        assert!(text.contains("[MISSING] rg   Not found"));
+        assert!(text.contains("fix: install ripgrep"));
+        assert!(text.ends_with("1 item(s) need attention.\n"));
+
+        let json = serde_json::to_value(&checks).unwrap();
+        assert_eq!(json[1]["status"], "missing");
+        assert!(json[0].get("fix").is_none());
+    }
+
+    #[test]
+    fn test_program_version() {
+        assert!(program_version("cokacdir-no-such-program", &["--version"]).is_none());
+        assert_eq!(first_line("\n  v1.2.3 \nmore"), Some("v1.2.3".to_string()));
+    }
+}
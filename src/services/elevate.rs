@@ -0,0 +1,125 @@
+//! Writing files that need elevated privileges (sudo, or polkit's pkexec).
+//!
+//! The content is piped into `tee` running as root, so the target keeps its
+//! owner and mode. The caller must hand the terminal over first: both tools
+//! prompt for a password on the tty.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, ExitStatus, Stdio};
+
+/// Tools tried in order of preference
+const TOOLS: &[&str] = &["sudo", "pkexec"];
+
+fn in_path(cmd: &str) -> bool {
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(cmd).is_file()))
+        .unwrap_or(false)
+}
+
+/// The first available elevation tool
+pub fn tool() -> Option<&'static str> {
+    TOOLS.iter().copied().find(|t| in_path(t))
+}
+
+/// Whether the current user can write `path` directly
+pub fn is_writable(path: &Path) -> bool {
+    std::fs::OpenOptions::new().write(true).open(path).is_ok()
+}
+
+/// `tool tee -- path`: copies stdin into `path`
+fn tee_command(tool: &str, path: &Path) -> Command {
+    let mut cmd = Command::new(tool);
+    cmd.arg("tee").arg("--").arg(path);
+    cmd
+}
+
+/// Error for a finished `tee`, None if it succeeded.
+/// A failed write usually means the tool exited early, so its status and
+/// stderr (e.g. a wrong password or a read-only target) say why.
+fn tee_error(
+    tool: &str,
+    write_err: Option<std::io::Error>,
+    status: ExitStatus,
+    stderr: &[u8],
+) -> Option<String> {
+    if write_err.is_none() && status.success() {
+        return None;
+    }
+    let mut msg = match write_err {
+        Some(e) => format!("Failed to write to {}: {} ({})", tool, e, status),
+        None => format!("{} tee failed ({})", tool, status),
+    };
+    let stderr = String::from_utf8_lossy(stderr);
+    let stderr = stderr.trim();
+    if !stderr.is_empty() {
+        msg.push_str(": ");
+        msg.push_str(stderr);
+    }
+    Some(msg)
+}
+
+/// Replace the content of `path` as root using `tool`
+pub fn write_file(tool: &str, path: &Path, content: &[u8]) -> Result<(), String> {
+    let mut child = tee_command(tool, path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run {}: {}", tool, e))?;
+
+    // Always reap the child, even if it stopped reading early
+    let write_err = child.stdin.take().and_then(|mut stdin| stdin.write_all(content).err());
+    let output =
+        child.wait_with_output().map_err(|e| format!("Failed to wait for {}: {}", tool, e))?;
+    match tee_error(tool, write_err, output.status, &output.stderr) {
+        Some(msg) => Err(msg),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tee_command_args() {
+        let cmd = tee_command("sudo", Path::new("/etc/-hosts"));
+        assert_eq!(cmd.get_program(), "sudo");
+        let args: Vec<_> = cmd.get_args().collect();
+        assert_eq!(args, vec!["tee", "--", "/etc/-hosts"]);
+    }
+
+    // `env` stands in for sudo: it runs tee as the current user
+
+    #[test]
+    fn test_write_file_replaces_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.txt");
+        std::fs::write(&path, "old content").unwrap();
+        write_file("env", &path, b"new").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[test]
+    fn test_write_file_reports_status_and_stderr() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing/a.txt");
+        let err = write_file("env", &path, b"data").unwrap_err();
+        assert!(err.starts_with("env tee failed (exit status: 1): tee: "), "{}", err);
+        assert!(err.contains("missing/a.txt"), "{}", err);
+
+        let err = write_file("no-such-elevate-tool", &path, b"data").unwrap_err();
+        assert!(err.starts_with("Failed to run no-such-elevate-tool"), "{}", err);
+    }
+
+    #[test]
+    fn test_write_file_waits_when_tool_exits_early() {
+        // `false` exits without reading, so writing a large input fails
+        let dir = tempfile::tempdir().unwrap();
+        let content = vec![b'x'; 1 << 20];
+        let err = write_file("false", &dir.path().join("a.txt"), &content).unwrap_err();
+        assert!(err.starts_with("Failed to write to false: "), "{}", err);
+        assert!(err.ends_with("(exit status: 1)"), "{}", err);
+    }
+}
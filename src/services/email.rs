@@ -0,0 +1,263 @@
+//! Email delivery of scheduled job results over SMTP.
+//!
+//! Configured with `email` in settings.json:
+//!
+//! ```text
+//! "email": {
+//!   "smtp_host": "smtp.example.com",
+//!   "smtp_port": 587,
+//!   "security": "starttls",
+//!   "username": "reports@example.com",
+//!   "password_env": "COKACDIR_SMTP_PASSWORD",
+//!   "from": "cokacdir <reports@example.com>",
+//!   "to": ["me@example.com"],
+//!   "subject": "[cokacdir] {status}: {prompt}",
+//!   "attach_output": true,
+//!   "attach_files": ["reports/*.pdf"]
+//! }
+//! ```
+//!
+//! `attach_files` patterns are relative to the schedule's directory; only
+//! files written during the run are attached.
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use lettre::message::header::ContentType;
+use lettre::message::{Attachment, Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::glob::{expand_home, glob_match};
+
+/// Total size of attached files per email
+pub const MAX_ATTACHMENT_BYTES: u64 = 10 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS (port 587)
+    #[default]
+    Starttls,
+    /// TLS from the start (port 465)
+    Tls,
+    /// Unencrypted (local relays only)
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailSettings {
+    pub smtp_host: String,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Password stored in settings.json (prefer `password_env`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// Environment variable holding the password
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+    /// Placeholders: {id}, {prompt}, {status}, {date}, {time}, {path}
+    #[serde(default = "default_subject")]
+    pub subject: String,
+    /// Attach the full output as `<id>-output.txt`
+    #[serde(default = "default_true")]
+    pub attach_output: bool,
+    /// Glob patterns (relative to the schedule's directory) of files to attach
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attach_files: Vec<String>,
+    /// Only send an email when the job failed
+    #[serde(default)]
+    pub only_failures: bool,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+fn default_subject() -> String {
+    "[cokacdir] {status}: {prompt}".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// File attached to an email
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailAttachment {
+    pub file_name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+impl EmailAttachment {
+    pub fn text(file_name: &str, text: &str) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            content_type: "text/plain; charset=utf-8".to_string(),
+            data: text.as_bytes().to_vec(),
+        }
+    }
+}
+
+/// Replace `{name}` placeholders of a subject template
+pub fn render_subject(template: &str, values: &[(&str, &str)]) -> String {
+    let mut subject = template.to_string();
+    for (name, value) in values {
+        subject = subject.replace(&format!("{{{}}}", name), value);
+    }
+    // Header values must stay on one line
+    subject.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn content_type_for(path: &Path) -> &'static str {
+    let ext = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    match ext.as_str() {
+        "txt" | "log" | "md" => "text/plain",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "zip" => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Files of `dir` matching `patterns` that were modified at or after `since`.
+/// Patterns may contain one directory part ("reports/*.pdf"). Files beyond
+/// MAX_ATTACHMENT_BYTES in total are left out and returned as the second value.
+pub fn collect_attachments(dir: &Path, patterns: &[String], since: SystemTime) -> (Vec<EmailAttachment>, Vec<String>) {
+    let mut attachments = Vec::new();
+    let mut skipped = Vec::new();
+    let mut total: u64 = 0;
+    for pattern in patterns {
+        let full = if pattern.starts_with('~') || pattern.starts_with('/') {
+            expand_home(pattern)
+        } else {
+            dir.join(pattern)
+        };
+        let (Some(parent), Some(name_pattern)) = (full.parent(), full.file_name()) else { continue };
+        let name_pattern = name_pattern.to_string_lossy().to_string();
+        let Ok(entries) = std::fs::read_dir(parent) else { continue };
+        let mut paths: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file())
+            .filter(|p| p.file_name().map(|n| glob_match(&name_pattern, &n.to_string_lossy(), false)).unwrap_or(false))
+            .collect();
+        paths.sort();
+        for path in paths {
+            let Ok(meta) = path.metadata() else { continue };
+            if meta.modified().map(|m| m < since).unwrap_or(true) {
+                continue;
+            }
+            let file_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            if attachments.iter().any(|a: &EmailAttachment| a.file_name == file_name) {
+                continue;
+            }
+            if total + meta.len() > MAX_ATTACHMENT_BYTES {
+                skipped.push(file_name);
+                continue;
+            }
+            if let Ok(data) = std::fs::read(&path) {
+                total += data.len() as u64;
+                attachments.push(EmailAttachment {
+                    content_type: content_type_for(&path).to_string(),
+                    file_name,
+                    data,
+                });
+            }
+        }
+    }
+    (attachments, skipped)
+}
+
+fn password(settings: &EmailSettings) -> Option<String> {
+    settings
+        .password_env
+        .as_ref()
+        .and_then(|name| std::env::var(name).ok())
+        .or_else(|| settings.password.clone())
+}
+
+/// Build the message (text body followed by attachments)
+fn build_message(settings: &EmailSettings, subject: &str, body: &str, attachments: Vec<EmailAttachment>) -> Result<Message, String> {
+    let from: Mailbox = settings.from.parse().map_err(|e| format!("invalid from address {}: {}", settings.from, e))?;
+    let mut builder = Message::builder().from(from).subject(subject);
+    if settings.to.is_empty() {
+        return Err("no recipients (email.to is empty)".to_string());
+    }
+    for to in &settings.to {
+        let mailbox: Mailbox = to.parse().map_err(|e| format!("invalid recipient {}: {}", to, e))?;
+        builder = builder.to(mailbox);
+    }
+    let mut multipart = MultiPart::mixed().singlepart(SinglePart::plain(body.to_string()));
+    for attachment in attachments {
+        let content_type = ContentType::parse(&attachment.content_type)
+            .map_err(|e| format!("invalid content type {}: {}", attachment.content_type, e))?;
+        multipart = multipart.singlepart(Attachment::new(attachment.file_name).body(attachment.data, content_type));
+    }
+    builder.multipart(multipart).map_err(|e| e.to_string())
+}
+
+/// Send an email and wait for the SMTP server to accept it
+pub fn send(settings: &EmailSettings, subject: &str, body: &str, attachments: Vec<EmailAttachment>) -> Result<(), String> {
+    let message = build_message(settings, subject, body, attachments)?;
+    let host = settings.smtp_host.as_str();
+    let builder = match settings.security {
+        SmtpSecurity::Starttls => SmtpTransport::starttls_relay(host).map_err(|e| e.to_string())?,
+        SmtpSecurity::Tls => SmtpTransport::relay(host).map_err(|e| e.to_string())?,
+        SmtpSecurity::None => SmtpTransport::builder_dangerous(host),
+    };
+    let mut builder = builder.port(settings.smtp_port);
+    if let Some(ref username) = settings.username {
+        builder = builder.credentials(Credentials::new(username.clone(), password(settings).unwrap_or_default()));
+    }
+    builder.build().send(&message).map(|_| ()).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subject_settings_and_attachments() {
+        assert_eq!(
+            render_subject("[cokacdir] {status}: {prompt}", &[("status", "failed"), ("prompt", "nightly\nreport")]),
+            "[cokacdir] failed: nightly report"
+        );
+
+        let settings: EmailSettings = serde_json::from_str(
+            r#"{"smtp_host": "smtp.example.com", "from": "me@example.com", "to": ["you@example.com"]}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.smtp_port, 587);
+        assert_eq!(settings.security, SmtpSecurity::Starttls);
+        assert!(settings.attach_output);
+        assert!(build_message(&settings, "s", "b", vec![EmailAttachment::text("out.txt", "hi")]).is_ok());
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("old.pdf"), "old").unwrap();
+        let since = SystemTime::now() + std::time::Duration::from_secs(3600);
+        let (found, _) = collect_attachments(dir.path(), &["*.pdf".to_string()], since);
+        assert!(found.is_empty());
+
+        std::fs::create_dir(dir.path().join("reports")).unwrap();
+        std::fs::write(dir.path().join("reports").join("daily.csv"), "a,b").unwrap();
+        std::fs::write(dir.path().join("reports").join("notes.txt"), "x").unwrap();
+        let (found, skipped) = collect_attachments(dir.path(), &["reports/*.csv".to_string()], SystemTime::UNIX_EPOCH);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].file_name, "daily.csv");
+        assert_eq!(found[0].content_type, "text/csv");
+        assert!(skipped.is_empty());
+    }
+}
@@ -1,11 +1,20 @@
+use std::any::Any;
+use std::cell::Cell;
 use std::collections::HashSet;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
+
+use crate::services::journal;
+use crate::utils::glob::glob_match;
+use crate::{log_debug, log_error, log_info, log_warn};
+
 /// File operation type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileOperationType {
@@ -13,9 +22,32 @@ pub enum FileOperationType {
     Move,
     Tar,
     Untar,
+    Zip,
+    Unzip,
     Download,
     Encrypt,
     Decrypt,
+    Delete,
+    Trash,
+    Sync,
+}
+
+impl FileOperationType {
+    /// Progress title ("Copying", "Creating Archive", ...)
+    pub fn verb(self) -> &'static str {
+        match self {
+            FileOperationType::Copy => "Copying",
+            FileOperationType::Move => "Moving",
+            FileOperationType::Tar | FileOperationType::Zip => "Creating Archive",
+            FileOperationType::Untar | FileOperationType::Unzip => "Extracting Archive",
+            FileOperationType::Download => "Downloading",
+            FileOperationType::Encrypt => "Encrypting",
+            FileOperationType::Decrypt => "Decrypting",
+            FileOperationType::Delete => "Deleting",
+            FileOperationType::Trash => "Moving to Trash",
+            FileOperationType::Sync => "Synchronizing",
+        }
+    }
 }
 
 /// Progress message for file operations
@@ -30,21 +62,87 @@ pub enum ProgressMessage {
     FileStarted(String),
     /// File progress (copied bytes, total bytes)
     FileProgress(u64, u64),
+    /// Download continues an earlier partial one (resumed at bytes, total bytes)
+    Resumed(u64, u64),
     /// File completed (filename)
     FileCompleted(String),
     /// Total progress (completed files, total files, completed bytes, total bytes)
     TotalProgress(usize, usize, u64, u64),
+    /// Entry skipped by an exclude pattern (path)
+    Excluded(String),
     /// Operation completed (success count, failure count)
     Completed(usize, usize),
     /// Error occurred (filename, error message)
     Error(String, String),
 }
 
+/// How paste conflicts (target already exists) are resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Prompt for each conflict
+    #[default]
+    Ask,
+    Overwrite,
+    Skip,
+    /// Overwrite when the source was modified more recently
+    OverwriteIfNewer,
+    /// Overwrite when the source is larger
+    OverwriteIfLarger,
+}
+
+impl ConflictPolicy {
+    pub const ALL: [ConflictPolicy; 5] = [
+        ConflictPolicy::Ask,
+        ConflictPolicy::Overwrite,
+        ConflictPolicy::Skip,
+        ConflictPolicy::OverwriteIfNewer,
+        ConflictPolicy::OverwriteIfLarger,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ConflictPolicy::Ask => "ask",
+            ConflictPolicy::Overwrite => "overwrite",
+            ConflictPolicy::Skip => "skip",
+            ConflictPolicy::OverwriteIfNewer => "if newer",
+            ConflictPolicy::OverwriteIfLarger => "if larger",
+        }
+    }
+
+    /// Next (or previous) policy in `ALL`, wrapping around
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|&p| p == self).unwrap_or(0);
+        let next = if forward { (idx + 1) % len } else { (idx + len - 1) % len };
+        Self::ALL[next]
+    }
+
+    /// Whether `src` should replace the existing `dest` (Ask never overwrites).
+    /// Unreadable metadata counts as "not newer/larger", so the target is kept.
+    pub fn should_overwrite(self, src: &Path, dest: &Path) -> bool {
+        match self {
+            ConflictPolicy::Ask | ConflictPolicy::Skip => false,
+            ConflictPolicy::Overwrite => true,
+            ConflictPolicy::OverwriteIfNewer => {
+                let modified = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+                matches!((modified(src), modified(dest)), (Some(s), Some(d)) if s > d)
+            }
+            ConflictPolicy::OverwriteIfLarger => {
+                let len = |p: &Path| fs::metadata(p).map(|m| m.len()).ok();
+                matches!((len(src), len(dest)), (Some(s), Some(d)) if s > d)
+            }
+        }
+    }
+}
+
 /// File operation result
 #[derive(Debug, Clone)]
 pub struct FileOperationResult {
     pub success_count: usize,
     pub failure_count: usize,
+    /// Entries skipped by exclude patterns
+    pub excluded_count: usize,
     pub last_error: Option<String>,
 }
 
@@ -91,8 +189,166 @@ fn try_clonefile(_src: &Path, _dest: &Path) -> io::Result<bool> {
     Ok(false) // Not supported on non-macOS
 }
 
+/// Bytes per copy_file_range call (keeps progress and cancellation responsive)
+#[cfg(target_os = "linux")]
+const COPY_RANGE_CHUNK: usize = 8 * 1024 * 1024;
+
+/// Copy using copy_file_range (Linux, same filesystem). The kernel copies without
+/// passing data through user space, and can reflink on CoW filesystems.
+/// Returns Ok(false) if the syscall is unsupported before any data was copied;
+/// the file offsets are advanced, so the caller can continue with read/write.
+#[cfg(target_os = "linux")]
+fn try_copy_file_range<F>(
+    src_file: &File,
+    dest_file: &File,
+    copied: &mut u64,
+    total_size: u64,
+    cancel_flag: &Arc<AtomicBool>,
+    progress_callback: &mut F,
+) -> io::Result<bool>
+where
+    F: FnMut(u64, u64),
+{
+    use std::os::unix::fs::MetadataExt;
+    use std::os::unix::io::AsRawFd;
+
+    // Cross-filesystem copy_file_range is refused or emulated by most kernels
+    if src_file.metadata()?.dev() != dest_file.metadata()?.dev() {
+        return Ok(false);
+    }
+
+    loop {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "Cancelled"));
+        }
+
+        let result = unsafe {
+            libc::copy_file_range(
+                src_file.as_raw_fd(),
+                std::ptr::null_mut(),
+                dest_file.as_raw_fd(),
+                std::ptr::null_mut(),
+                COPY_RANGE_CHUNK,
+                0,
+            )
+        };
+
+        if result < 0 {
+            let err = io::Error::last_os_error();
+            return match err.raw_os_error() {
+                Some(libc::ENOSYS) | Some(libc::EXDEV) | Some(libc::EINVAL)
+                | Some(libc::EOPNOTSUPP) | Some(libc::EPERM) if *copied == 0 => Ok(false),
+                Some(libc::EINTR) => continue,
+                _ => Err(err),
+            };
+        }
+        if result == 0 {
+            // EOF. Files that report size 0 (e.g. procfs) copy nothing here; let read/write handle them
+            return Ok(*copied > 0 && *copied >= total_size);
+        }
+
+        *copied += result as u64;
+        progress_callback(*copied, total_size);
+    }
+}
+
+/// Parse exclude patterns typed as a space or comma separated list
+pub fn parse_exclude_patterns(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_string())
+        .collect()
+}
+
+/// Whether an entry named `name` matches one of the exclude patterns.
+/// Patterns are wildcards on the entry name (`*.o`); a trailing `/` restricts
+/// the pattern to directories (`target/`, `node_modules/`). Matching ignores
+/// case, so `*.log` also skips `BUILD.LOG`.
+pub fn is_excluded(excludes: &[String], name: &str, is_dir: bool) -> bool {
+    excludes.iter().any(|pattern| match pattern.strip_suffix('/') {
+        Some(dir_pattern) => is_dir && glob_match(dir_pattern, name, false),
+        None => glob_match(pattern, name, false),
+    })
+}
+
+/// Report a failed item to the progress dialog and the file_ops log
+fn send_error(progress_tx: &Sender<ProgressMessage>, name: String, message: String) {
+    if name.is_empty() {
+        log_warn!("file_ops", "{}", message);
+    } else {
+        log_warn!("file_ops", "{}: {}", name, message);
+    }
+    let _ = progress_tx.send(ProgressMessage::Error(name, message));
+}
+
+/// Report the final counts to the progress dialog and the file_ops log
+fn send_completed(progress_tx: &Sender<ProgressMessage>, operation: &str, success: usize, failure: usize) {
+    log_info!("file_ops", "{} finished: {} succeeded, {} failed", operation, success, failure);
+    let _ = progress_tx.send(ProgressMessage::Completed(success, failure));
+}
+
+thread_local! {
+    /// Set on `spawn_worker` threads, whose panics are caught and reported
+    static CATCHES_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// True on a `spawn_worker` thread. The panic hook leaves the terminal alone
+/// for these panics, since the TUI keeps running and reports them itself.
+pub fn panic_is_caught() -> bool {
+    CATCHES_PANICS.with(|c| c.get())
+}
+
+/// Run an operation's `work` on a background thread, turning a panic into a
+/// failed operation instead of a progress dialog that waits forever.
+///
+/// The panic is logged and sent as an `Error`; the sender is then dropped and
+/// `FileOperationProgress::poll` finishes the operation as failed. A copy or
+/// move removes its partial target while its journal guard unwinds; `partial`
+/// names anything else the worker was writing (an archive, an extract
+/// directory it created) to delete after a panic.
+pub fn spawn_worker<F>(progress_tx: Sender<ProgressMessage>, partial: Option<PathBuf>, work: F)
+where
+    F: FnOnce(Sender<ProgressMessage>) + Send + 'static,
+{
+    std::thread::spawn(move || run_worker(progress_tx, partial, work));
+}
+
+fn run_worker<F>(progress_tx: Sender<ProgressMessage>, partial: Option<PathBuf>, work: F)
+where
+    F: FnOnce(Sender<ProgressMessage>),
+{
+    CATCHES_PANICS.with(|c| c.set(true));
+    let worker_tx = progress_tx.clone();
+    let Err(payload) = panic::catch_unwind(AssertUnwindSafe(move || work(worker_tx))) else {
+        return;
+    };
+    let message = panic_message(payload.as_ref());
+    log_error!("file_ops", "Worker thread panicked: {}", message);
+    if let Some(path) = partial {
+        if let Err(e) = journal::remove_partial(&path) {
+            log_warn!("file_ops", "Could not remove {}: {}", path.display(), e);
+        }
+    }
+    let _ = progress_tx.send(ProgressMessage::Error(String::new(), format!("Internal error: {}", message)));
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn entry_name(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+}
+
 /// Calculate total size of files to be copied/moved
-pub fn calculate_total_size(files: &[PathBuf], cancel_flag: &Arc<AtomicBool>) -> io::Result<(u64, usize)> {
+pub fn calculate_total_size(files: &[PathBuf], excludes: &[String], cancel_flag: &Arc<AtomicBool>) -> io::Result<(u64, usize)> {
     let mut total_size: u64 = 0;
     let mut total_files: usize = 0;
 
@@ -101,8 +357,12 @@ pub fn calculate_total_size(files: &[PathBuf], cancel_flag: &Arc<AtomicBool>) ->
             return Err(io::Error::new(io::ErrorKind::Interrupted, "Cancelled"));
         }
 
+        if is_excluded(excludes, &entry_name(path), path.is_dir()) {
+            continue;
+        }
+
         if path.is_dir() {
-            let (dir_size, dir_files) = calculate_dir_size(path, cancel_flag)?;
+            let (dir_size, dir_files) = calculate_dir_size(path, excludes, cancel_flag)?;
             total_size += dir_size;
             total_files += dir_files;
         } else if path.is_file() {
@@ -115,7 +375,7 @@ pub fn calculate_total_size(files: &[PathBuf], cancel_flag: &Arc<AtomicBool>) ->
 }
 
 /// Calculate total size and file count of a directory
-fn calculate_dir_size(path: &Path, cancel_flag: &Arc<AtomicBool>) -> io::Result<(u64, usize)> {
+fn calculate_dir_size(path: &Path, excludes: &[String], cancel_flag: &Arc<AtomicBool>) -> io::Result<(u64, usize)> {
     let mut total_size: u64 = 0;
     let mut total_files: usize = 0;
 
@@ -128,11 +388,15 @@ fn calculate_dir_size(path: &Path, cancel_flag: &Arc<AtomicBool>) -> io::Result<
             let entry_path = entry.path();
             let metadata = fs::symlink_metadata(&entry_path)?;
 
+            if is_excluded(excludes, &entry.file_name().to_string_lossy(), metadata.is_dir()) {
+                continue;
+            }
+
             if metadata.is_symlink() {
                 // Symlinks count as 0 size
                 total_files += 1;
             } else if metadata.is_dir() {
-                let (sub_size, sub_files) = calculate_dir_size(&entry_path, cancel_flag)?;
+                let (sub_size, sub_files) = calculate_dir_size(&entry_path, excludes, cancel_flag)?;
                 total_size += sub_size;
                 total_files += sub_files;
             } else {
@@ -147,6 +411,7 @@ fn calculate_dir_size(path: &Path, cancel_flag: &Arc<AtomicBool>) -> io::Result<
 
 /// Copy a single file with progress callback
 /// On macOS with APFS, tries clonefile first for instant copy
+/// On Linux, uses copy_file_range for same-filesystem copies
 pub fn copy_file_with_progress<F>(
     src: &Path,
     dest: &Path,
@@ -188,12 +453,30 @@ where
         return Ok(total_size);
     }
 
-    // Fallback to regular copy with progress
     let mut src_file = File::open(src)?;
     let mut dest_file = File::create(dest)?;
+    let mut copied: u64 = 0;
+
+    // Kernel-side copy on Linux; remaining data (if any) goes through the buffer loop
+    #[cfg(target_os = "linux")]
+    {
+        match try_copy_file_range(&src_file, &dest_file, &mut copied, total_size, cancel_flag, &mut progress_callback) {
+            Ok(true) => {
+                fs::set_permissions(dest, metadata.permissions())?;
+                return Ok(copied);
+            }
+            Ok(false) => {}
+            Err(e) => {
+                // Clean up incomplete file
+                drop(dest_file);
+                let _ = fs::remove_file(dest);
+                return Err(e);
+            }
+        }
+    }
 
+    // Fallback to regular copy with progress
     let mut buffer = vec![0u8; COPY_BUFFER_SIZE];
-    let mut copied: u64 = 0;
 
     loop {
         // Check for cancellation
@@ -225,10 +508,12 @@ where
     Ok(copied)
 }
 
-/// Copy directory recursively with progress reporting
+/// Copy directory recursively with progress reporting.
+/// Entries matching `excludes` are skipped and reported as `Excluded`.
 pub fn copy_dir_recursive_with_progress(
     src: &Path,
     dest: &Path,
+    excludes: &[String],
     cancel_flag: &Arc<AtomicBool>,
     progress_tx: &Sender<ProgressMessage>,
     completed_bytes: &mut u64,
@@ -255,6 +540,11 @@ pub fn copy_dir_recursive_with_progress(
 
         let metadata = fs::symlink_metadata(&src_path)?;
 
+        if is_excluded(excludes, &entry.file_name().to_string_lossy(), metadata.is_dir()) {
+            let _ = progress_tx.send(ProgressMessage::Excluded(src_path.display().to_string()));
+            continue;
+        }
+
         if metadata.is_symlink() {
             // Copy symlink as-is
             #[cfg(unix)]
@@ -280,6 +570,7 @@ pub fn copy_dir_recursive_with_progress(
             copy_dir_recursive_with_progress(
                 &src_path,
                 &dest_path,
+                excludes,
                 cancel_flag,
                 progress_tx,
                 completed_bytes,
@@ -323,7 +614,7 @@ pub fn copy_dir_recursive_with_progress(
                     if e.kind() == io::ErrorKind::Interrupted {
                         return Err(e);
                     }
-                    let _ = progress_tx.send(ProgressMessage::Error(filename, e.to_string()));
+                    send_error(progress_tx, filename, e.to_string());
                 }
             }
         }
@@ -335,12 +626,14 @@ pub fn copy_dir_recursive_with_progress(
 /// Copy files with progress reporting (main entry point for progress-enabled copy)
 /// files_to_overwrite: Set of source paths that should overwrite existing destinations
 /// files_to_skip: Set of source paths that should be skipped if destination exists
+/// excludes: Patterns for entries left out of the operation (see `is_excluded`)
 pub fn copy_files_with_progress(
     files: Vec<PathBuf>,
     source_dir: &Path,
     target_dir: &Path,
     files_to_overwrite: HashSet<PathBuf>,
     files_to_skip: HashSet<PathBuf>,
+    excludes: Vec<String>,
     cancel_flag: Arc<AtomicBool>,
     progress_tx: Sender<ProgressMessage>,
 ) {
@@ -357,10 +650,10 @@ pub fn copy_files_with_progress(
     let _ = progress_tx.send(ProgressMessage::Preparing("Calculating file sizes...".to_string()));
 
     // Calculate total size
-    let (total_bytes, total_files) = match calculate_total_size(&full_paths, &cancel_flag) {
+    let (total_bytes, total_files) = match calculate_total_size(&full_paths, &excludes, &cancel_flag) {
         Ok((size, count)) => (size, count),
         Err(e) => {
-            let _ = progress_tx.send(ProgressMessage::Error("".to_string(), e.to_string()));
+            send_error(&progress_tx, "".to_string(), e.to_string());
             let _ = progress_tx.send(ProgressMessage::Completed(0, files.len()));
             return;
         }
@@ -368,6 +661,11 @@ pub fn copy_files_with_progress(
 
     // Send prepare complete
     let _ = progress_tx.send(ProgressMessage::PrepareComplete);
+    log_info!(
+        "file_ops",
+        "Copy {} item(s) ({} files, {} bytes): {} -> {}",
+        full_paths.len(), total_files, total_bytes, source_dir.display(), target_dir.display()
+    );
 
     let mut completed_bytes: u64 = 0;
     let mut completed_files: usize = 0;
@@ -394,35 +692,44 @@ pub fn copy_files_with_progress(
             continue;
         }
 
+        if is_excluded(&excludes, &filename, src.is_dir()) {
+            let _ = progress_tx.send(ProgressMessage::Excluded(src.display().to_string()));
+            continue;
+        }
+
         // Check if destination already exists
         if dest.exists() {
             if files_to_overwrite.contains(&src) {
                 // Delete existing file/directory before copying
                 if let Err(e) = delete_file(&dest) {
                     failure_count += 1;
-                    let _ = progress_tx.send(ProgressMessage::Error(
+                    send_error(
+                        &progress_tx,
                         filename,
                         format!("Failed to remove existing: {}", e),
-                    ));
+                    );
                     continue;
                 }
             } else {
                 // Not in overwrite set and not in skip set - unexpected conflict
                 failure_count += 1;
-                let _ = progress_tx.send(ProgressMessage::Error(
+                send_error(
+                    &progress_tx,
                     filename,
                     "Target already exists".to_string(),
-                ));
+                );
                 continue;
             }
         }
 
         let _ = progress_tx.send(ProgressMessage::FileStarted(filename.clone()));
+        journal::item_started(&dest);
 
         if src.is_dir() {
             match copy_dir_recursive_with_progress(
                 &src,
                 &dest,
+                &excludes,
                 &cancel_flag,
                 &progress_tx,
                 &mut completed_bytes,
@@ -431,6 +738,8 @@ pub fn copy_files_with_progress(
                 total_files,
             ) {
                 Ok(_) => {
+                    journal::item_finished(&src);
+                    log_debug!("file_ops", "Copied {} -> {}", src.display(), dest.display());
                     success_count += 1;
                     let _ = progress_tx.send(ProgressMessage::FileCompleted(filename));
                 }
@@ -441,7 +750,7 @@ pub fn copy_files_with_progress(
                         break;
                     }
                     failure_count += 1;
-                    let _ = progress_tx.send(ProgressMessage::Error(filename, e.to_string()));
+                    send_error(&progress_tx, filename, e.to_string());
                 }
             }
         } else {
@@ -463,6 +772,8 @@ pub fn copy_files_with_progress(
                 },
             ) {
                 Ok(_) => {
+                    journal::item_finished(&src);
+                    log_debug!("file_ops", "Copied {} -> {}", src.display(), dest.display());
                     completed_bytes += file_size;
                     completed_files += 1;
                     success_count += 1;
@@ -473,24 +784,50 @@ pub fn copy_files_with_progress(
                         break;
                     }
                     failure_count += 1;
-                    let _ = progress_tx.send(ProgressMessage::Error(filename, e.to_string()));
+                    send_error(&progress_tx, filename, e.to_string());
                 }
             }
         }
     }
 
-    let _ = progress_tx.send(ProgressMessage::Completed(success_count, failure_count));
+    send_completed(&progress_tx, "Copy", success_count, failure_count);
+}
+
+/// Delete the source of a directory moved with exclude patterns. Excluded
+/// entries, anything missing from `dest`, and the directories holding them
+/// stay in place.
+fn remove_moved_source(src: &Path, dest: &Path, excludes: &[String]) -> io::Result<()> {
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let path = entry.path();
+        let metadata = fs::symlink_metadata(&path)?;
+        if is_excluded(excludes, &entry.file_name().to_string_lossy(), metadata.is_dir()) {
+            continue;
+        }
+        let dest_path = dest.join(entry.file_name());
+        if metadata.is_dir() {
+            remove_moved_source(&path, &dest_path, excludes)?;
+        } else if fs::symlink_metadata(&dest_path).is_ok() {
+            fs::remove_file(&path)?;
+        }
+    }
+    if fs::read_dir(src)?.next().is_none() {
+        fs::remove_dir(src)?;
+    }
+    Ok(())
 }
 
 /// Move files with progress reporting
 /// files_to_overwrite: Set of source paths that should overwrite existing destinations
 /// files_to_skip: Set of source paths that should be skipped if destination exists
+/// excludes: Patterns for entries left out of the operation (see `is_excluded`)
 pub fn move_files_with_progress(
     files: Vec<PathBuf>,
     source_dir: &Path,
     target_dir: &Path,
     files_to_overwrite: HashSet<PathBuf>,
     files_to_skip: HashSet<PathBuf>,
+    excludes: Vec<String>,
     cancel_flag: Arc<AtomicBool>,
     progress_tx: Sender<ProgressMessage>,
 ) {
@@ -507,10 +844,10 @@ pub fn move_files_with_progress(
     let _ = progress_tx.send(ProgressMessage::Preparing("Calculating file sizes...".to_string()));
 
     // Calculate total size upfront for accurate progress
-    let (total_bytes, total_files) = match calculate_total_size(&full_paths, &cancel_flag) {
+    let (total_bytes, total_files) = match calculate_total_size(&full_paths, &excludes, &cancel_flag) {
         Ok((size, count)) => (size, count),
         Err(e) => {
-            let _ = progress_tx.send(ProgressMessage::Error("".to_string(), e.to_string()));
+            send_error(&progress_tx, "".to_string(), e.to_string());
             let _ = progress_tx.send(ProgressMessage::Completed(0, files.len()));
             return;
         }
@@ -518,6 +855,11 @@ pub fn move_files_with_progress(
 
     // Send prepare complete
     let _ = progress_tx.send(ProgressMessage::PrepareComplete);
+    log_info!(
+        "file_ops",
+        "Move {} item(s) ({} files, {} bytes): {} -> {}",
+        full_paths.len(), total_files, total_bytes, source_dir.display(), target_dir.display()
+    );
 
     let mut completed_bytes: u64 = 0;
     let mut completed_files: usize = 0;
@@ -547,9 +889,14 @@ pub fn move_files_with_progress(
             continue;
         }
 
+        if is_excluded(&excludes, &filename, src.is_dir()) {
+            let _ = progress_tx.send(ProgressMessage::Excluded(src.display().to_string()));
+            continue;
+        }
+
         // Get file/dir size for progress tracking
         let (item_size, item_files) = if src.is_dir() {
-            calculate_dir_size(&src, &cancel_flag).unwrap_or((0, 1))
+            calculate_dir_size(&src, &excludes, &cancel_flag).unwrap_or((0, 1))
         } else {
             (fs::metadata(&src).map(|m| m.len()).unwrap_or(0), 1)
         };
@@ -560,28 +907,38 @@ pub fn move_files_with_progress(
                 // Delete existing file/directory before moving
                 if let Err(e) = delete_file(&dest) {
                     failure_count += 1;
-                    let _ = progress_tx.send(ProgressMessage::Error(
+                    send_error(
+                        &progress_tx,
                         filename,
                         format!("Failed to remove existing: {}", e),
-                    ));
+                    );
                     continue;
                 }
             } else {
                 // Not in overwrite set and not in skip set - unexpected conflict
                 failure_count += 1;
-                let _ = progress_tx.send(ProgressMessage::Error(
+                send_error(
+                    &progress_tx,
                     filename,
                     "Target already exists".to_string(),
-                ));
+                );
                 continue;
             }
         }
 
+        // A rename would take the excluded entries along: copy what is kept instead
+        if !excludes.is_empty() && src.is_dir() {
+            needs_copy.push((src, dest, item_size));
+            continue;
+        }
+
         let _ = progress_tx.send(ProgressMessage::FileStarted(filename.clone()));
 
         // Try rename first
         match fs::rename(&src, &dest) {
             Ok(_) => {
+                journal::item_finished(&src);
+                log_debug!("file_ops", "Moved {} -> {}", src.display(), dest.display());
                 success_count += 1;
                 completed_bytes += item_size;
                 completed_files += item_files;
@@ -599,7 +956,7 @@ pub fn move_files_with_progress(
                     needs_copy.push((src, dest, item_size));
                 } else {
                     failure_count += 1;
-                    let _ = progress_tx.send(ProgressMessage::Error(filename, e.to_string()));
+                    send_error(&progress_tx, filename, e.to_string());
                 }
             }
         }
@@ -617,11 +974,13 @@ pub fn move_files_with_progress(
                 .unwrap_or_default();
 
             let _ = progress_tx.send(ProgressMessage::FileStarted(filename.clone()));
+            journal::item_started(&dest);
 
             let copy_result = if src.is_dir() {
                 copy_dir_recursive_with_progress(
                     &src,
                     &dest,
+                    &excludes,
                     &cancel_flag,
                     &progress_tx,
                     &mut completed_bytes,
@@ -654,15 +1013,24 @@ pub fn move_files_with_progress(
 
             match copy_result {
                 Ok(_) => {
+                    // The copy is complete: from here on a crash must not delete it
+                    journal::item_finished(&src);
                     // Delete source after successful copy
-                    if let Err(e) = delete_file(&src) {
+                    let remove_result = if !excludes.is_empty() && src.is_dir() {
+                        remove_moved_source(&src, &dest, &excludes)
+                    } else {
+                        delete_file(&src)
+                    };
+                    if let Err(e) = remove_result {
                         // Copy succeeded but delete failed - this is a move failure
                         failure_count += 1;
-                        let _ = progress_tx.send(ProgressMessage::Error(
+                        send_error(
+                            &progress_tx,
                             filename,
                             format!("Move failed: copied but could not delete source: {}", e),
-                        ));
+                        );
                     } else {
+                        log_debug!("file_ops", "Moved {} -> {} (copied across filesystems)", src.display(), dest.display());
                         success_count += 1;
                         let _ = progress_tx.send(ProgressMessage::FileCompleted(filename));
                     }
@@ -678,13 +1046,13 @@ pub fn move_files_with_progress(
                         break;
                     }
                     failure_count += 1;
-                    let _ = progress_tx.send(ProgressMessage::Error(filename, e.to_string()));
+                    send_error(&progress_tx, filename, e.to_string());
                 }
             }
         }
     }
 
-    let _ = progress_tx.send(ProgressMessage::Completed(success_count, failure_count));
+    send_completed(&progress_tx, "Move", success_count, failure_count);
 }
 
 /// Copy a file or directory
@@ -841,6 +1209,144 @@ pub fn move_file(src: &Path, dest: &Path) -> io::Result<()> {
     }
 }
 
+/// Delete (or move to the trash) files with progress reporting.
+/// Directories are removed entry by entry so the operation can be cancelled;
+/// a cancelled delete leaves the rest of the tree in place and counts the
+/// untouched items as failures.
+pub fn delete_files_with_progress(
+    files: Vec<PathBuf>,
+    to_trash: bool,
+    cancel_flag: Arc<AtomicBool>,
+    progress_tx: Sender<ProgressMessage>,
+) {
+    let mut success_count = 0;
+    let mut failure_count = 0;
+
+    // Moving to the trash is a rename per item: count items, not their contents
+    let (total_bytes, total_files) = if to_trash {
+        (0, files.len())
+    } else {
+        let _ = progress_tx.send(ProgressMessage::Preparing("Counting files...".to_string()));
+        match count_for_delete(&files, &cancel_flag) {
+            Ok(totals) => totals,
+            Err(e) => {
+                send_error(&progress_tx, "".to_string(), e.to_string());
+                let _ = progress_tx.send(ProgressMessage::Completed(0, files.len()));
+                return;
+            }
+        }
+    };
+    let _ = progress_tx.send(ProgressMessage::PrepareComplete);
+    log_info!(
+        "file_ops",
+        "{} {} item(s) ({} files, {} bytes)",
+        if to_trash { "Trash" } else { "Delete" }, files.len(), total_files, total_bytes
+    );
+
+    let mut completed_bytes: u64 = 0;
+    let mut completed_files: usize = 0;
+
+    for path in &files {
+        if cancel_flag.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let filename = entry_name(path);
+        let _ = progress_tx.send(ProgressMessage::FileStarted(filename.clone()));
+
+        let result = if to_trash {
+            crate::services::trash::move_to_trash(path).map(|_| {
+                completed_files += 1;
+            })
+        } else {
+            remove_with_progress(path, &cancel_flag, &mut |size| {
+                completed_bytes += size;
+                completed_files += 1;
+                let _ = progress_tx.send(ProgressMessage::TotalProgress(
+                    completed_files,
+                    total_files,
+                    completed_bytes,
+                    total_bytes,
+                ));
+            })
+        };
+
+        match result {
+            Ok(_) => {
+                log_debug!("file_ops", "{} {}", if to_trash { "Trashed" } else { "Deleted" }, path.display());
+                success_count += 1;
+                let _ = progress_tx.send(ProgressMessage::FileCompleted(filename));
+                let _ = progress_tx.send(ProgressMessage::TotalProgress(
+                    completed_files,
+                    total_files,
+                    completed_bytes,
+                    total_bytes,
+                ));
+            }
+            Err(e) => {
+                if e.kind() == io::ErrorKind::Interrupted {
+                    break;
+                }
+                failure_count += 1;
+                send_error(&progress_tx, filename, e.to_string());
+            }
+        }
+    }
+
+    let untouched = files.len() - success_count - failure_count;
+    if untouched > 0 {
+        failure_count += untouched;
+        send_error(
+            &progress_tx,
+            "".to_string(),
+            format!("Cancelled, {} item(s) left", untouched),
+        );
+    }
+    send_completed(&progress_tx, if to_trash { "Trash" } else { "Delete" }, success_count, failure_count);
+}
+
+/// Total size and file count of the entries to delete (symlinks are not followed)
+fn count_for_delete(files: &[PathBuf], cancel_flag: &Arc<AtomicBool>) -> io::Result<(u64, usize)> {
+    let mut total_size: u64 = 0;
+    let mut total_files: usize = 0;
+    for path in files {
+        let metadata = fs::symlink_metadata(path)?;
+        if metadata.is_dir() {
+            let (dir_size, dir_files) = calculate_dir_size(path, &[], cancel_flag)?;
+            total_size += dir_size;
+            total_files += dir_files;
+        } else {
+            if !metadata.is_symlink() {
+                total_size += metadata.len();
+            }
+            total_files += 1;
+        }
+    }
+    Ok((total_size, total_files))
+}
+
+/// Remove `path` (recursively for directories), calling `on_removed` with the
+/// size of each removed file or symlink. Returns `Interrupted` when cancelled.
+fn remove_with_progress(
+    path: &Path,
+    cancel_flag: &Arc<AtomicBool>,
+    on_removed: &mut dyn FnMut(u64),
+) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+    if !metadata.is_dir() {
+        fs::remove_file(path)?;
+        on_removed(if metadata.is_symlink() { 0 } else { metadata.len() });
+        return Ok(());
+    }
+    for entry in fs::read_dir(path)? {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "Cancelled"));
+        }
+        remove_with_progress(&entry?.path(), cancel_flag, on_removed)?;
+    }
+    fs::remove_dir(path)
+}
+
 /// Delete a file or directory
 pub fn delete_file(path: &Path) -> io::Result<()> {
     // Use symlink_metadata to check if it's a symlink
@@ -868,70 +1374,284 @@ pub fn create_directory(path: &Path) -> io::Result<()> {
     fs::create_dir_all(path)
 }
 
-/// Rename a file or directory
-pub fn rename_file(old_path: &Path, new_path: &Path) -> io::Result<()> {
-    if new_path.exists() {
+/// Create a symbolic link at `link` pointing to `target`
+pub fn create_symlink(target: &Path, link: &Path) -> io::Result<()> {
+    if fs::symlink_metadata(link).is_ok() {
         return Err(io::Error::new(
             io::ErrorKind::AlreadyExists,
-            "Target already exists",
+            "File already exists",
         ));
     }
 
-    fs::rename(old_path, new_path)
-}
-
-/// Maximum filename length (POSIX limit)
-const MAX_FILENAME_LENGTH: usize = 255;
-
-/// Validate filename for dangerous characters
-pub fn is_valid_filename(name: &str) -> Result<(), &'static str> {
-    if name.is_empty() || name.trim().is_empty() {
-        return Err("Filename cannot be empty");
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, link)
     }
-
-    // Check for path separators
-    if name.contains('/') || name.contains('\\') {
-        return Err("Filename cannot contain path separators");
+    #[cfg(not(unix))]
+    {
+        let _ = target;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Symbolic links are not supported on this platform",
+        ))
     }
+}
 
-    // Check for null bytes
-    if name.contains('\0') {
-        return Err("Filename cannot contain null bytes");
+/// Call `f` for every entry below `dir` (not `dir` itself), without following
+/// symlinked directories
+#[cfg(unix)]
+fn for_each_below(dir: &Path, f: &mut dyn FnMut(&Path, &fs::Metadata) -> io::Result<()>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let metadata = fs::symlink_metadata(&path)?;
+        f(&path, &metadata)?;
+        if metadata.is_dir() {
+            for_each_below(&path, f)?;
+        }
     }
+    Ok(())
+}
 
-    // Check for reserved names
-    if name == "." || name == ".." {
-        return Err("Invalid filename");
-    }
+/// Set the mode bits of `path` (and, with `recursive`, of everything below it).
+/// Symlinks inside the tree are skipped: their own mode is not used and
+/// chmod would change the target instead.
+#[cfg(unix)]
+pub fn set_permissions(path: &Path, mode: u32, recursive: bool) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
 
-    // Check length limit
-    if name.len() > MAX_FILENAME_LENGTH {
-        return Err("Filename too long (max 255 characters)");
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+    if recursive && fs::symlink_metadata(path)?.is_dir() {
+        for_each_below(path, &mut |entry, metadata| {
+            if metadata.is_symlink() {
+                return Ok(());
+            }
+            fs::set_permissions(entry, fs::Permissions::from_mode(mode))
+        })?;
     }
+    Ok(())
+}
 
-    // Check for control characters
-    if name.chars().any(|c| c.is_control()) {
-        return Err("Filename cannot contain control characters");
-    }
+/// Change the owner and/or group of `path` (and, with `recursive`, of
+/// everything below it; symlinks inside the tree are changed themselves)
+#[cfg(unix)]
+pub fn set_owner(path: &Path, uid: Option<u32>, gid: Option<u32>, recursive: bool) -> io::Result<()> {
+    use std::os::unix::fs::{chown, lchown};
 
-    // Check for leading/trailing whitespace
-    if name != name.trim() {
-        return Err("Filename cannot start or end with whitespace");
+    chown(path, uid, gid)?;
+    if recursive && fs::symlink_metadata(path)?.is_dir() {
+        for_each_below(path, &mut |entry, _| lchown(entry, uid, gid))?;
     }
+    Ok(())
+}
 
-    // Check for leading hyphen (could be interpreted as option)
-    if name.starts_with('-') {
-        return Err("Filename cannot start with hyphen");
-    }
+/// Name of the user with `uid`
+#[cfg(unix)]
+pub fn user_name(uid: u32) -> Option<String> {
+    let mut buf = vec![0 as libc::c_char; 4096];
+    // SAFETY: passwd is plain data; getpwuid_r fills it with pointers into buf
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    // SAFETY: all pointers are valid for the duration of the call
+    let rc = unsafe { libc::getpwuid_r(uid, &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    // SAFETY: pw_name is a NUL-terminated string inside buf
+    Some(unsafe { std::ffi::CStr::from_ptr(pwd.pw_name) }.to_string_lossy().to_string())
+}
 
-    Ok(())
+/// Name of the group with `gid`
+#[cfg(unix)]
+pub fn group_name(gid: u32) -> Option<String> {
+    let mut buf = vec![0 as libc::c_char; 4096];
+    // SAFETY: group is plain data; getgrgid_r fills it with pointers into buf
+    let mut grp: libc::group = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::group = std::ptr::null_mut();
+    // SAFETY: all pointers are valid for the duration of the call
+    let rc = unsafe { libc::getgrgid_r(gid, &mut grp, buf.as_mut_ptr(), buf.len(), &mut result) };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    // SAFETY: gr_name is a NUL-terminated string inside buf
+    Some(unsafe { std::ffi::CStr::from_ptr(grp.gr_name) }.to_string_lossy().to_string())
 }
 
-/// Sensitive paths that symlinks should not point to
-const SENSITIVE_PATHS: &[&str] = &[
-    "/etc", "/sys", "/proc", "/boot", "/root", "/var/log",
-    "/home", "/dev", "/tmp", "/run", "/var/run",
-];
+/// User id for a user name
+#[cfg(unix)]
+pub fn user_id(name: &str) -> Option<u32> {
+    let c_name = std::ffi::CString::new(name).ok()?;
+    let mut buf = vec![0 as libc::c_char; 4096];
+    // SAFETY: passwd is plain data; getpwnam_r fills it with pointers into buf
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    // SAFETY: all pointers are valid for the duration of the call
+    let rc = unsafe { libc::getpwnam_r(c_name.as_ptr(), &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+    (rc == 0 && !result.is_null()).then_some(pwd.pw_uid)
+}
+
+/// Group id for a group name
+#[cfg(unix)]
+pub fn group_id(name: &str) -> Option<u32> {
+    let c_name = std::ffi::CString::new(name).ok()?;
+    let mut buf = vec![0 as libc::c_char; 4096];
+    // SAFETY: group is plain data; getgrnam_r fills it with pointers into buf
+    let mut grp: libc::group = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::group = std::ptr::null_mut();
+    // SAFETY: all pointers are valid for the duration of the call
+    let rc = unsafe { libc::getgrnam_r(c_name.as_ptr(), &mut grp, buf.as_mut_ptr(), buf.len(), &mut result) };
+    (rc == 0 && !result.is_null()).then_some(grp.gr_gid)
+}
+
+/// Rename a file or directory
+pub fn rename_file(old_path: &Path, new_path: &Path) -> io::Result<()> {
+    if new_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "Target already exists",
+        ));
+    }
+
+    fs::rename(old_path, new_path)
+}
+
+/// Rename several entries of `dir` at once, given `(old, new)` names.
+///
+/// Every new name is checked before anything is touched. The entries are
+/// first moved to temporary names so that swaps and chains (a→b, b→c) work;
+/// if a step fails, the completed steps are undone in reverse order.
+/// Returns the number of renamed entries.
+pub fn bulk_rename(dir: &Path, renames: &[(String, String)]) -> Result<usize, String> {
+    let renames: Vec<&(String, String)> = renames.iter().filter(|(old, new)| old != new).collect();
+    let old_names: HashSet<&str> = renames.iter().map(|(old, _)| old.as_str()).collect();
+    let mut new_names = HashSet::new();
+    for (old, new) in &renames {
+        is_valid_filename(new).map_err(|e| format!("{}: {}", new, e))?;
+        if !new_names.insert(new.as_str()) {
+            return Err(format!("{}: used for more than one file", new));
+        }
+        if fs::symlink_metadata(dir.join(old)).is_err() {
+            return Err(format!("{}: not found", old));
+        }
+        // Names freed by this batch may be reused; any other existing entry blocks the rename
+        // (except the entry itself on case-insensitive filesystems: a.JPG → a.jpg)
+        if !old_names.contains(new.as_str())
+            && fs::symlink_metadata(dir.join(new)).is_ok()
+            && !same_entry(&dir.join(old), &dir.join(new))
+        {
+            return Err(format!("{}: already exists", new));
+        }
+    }
+
+    let temp_path = |i: usize| dir.join(format!(".cokacdir-rename-{}-{}", std::process::id(), i));
+    let mut steps: Vec<(PathBuf, PathBuf)> = Vec::with_capacity(renames.len() * 2);
+    for (i, (old, _)) in renames.iter().enumerate() {
+        steps.push((dir.join(old), temp_path(i)));
+    }
+    for (i, (_, new)) in renames.iter().enumerate() {
+        steps.push((temp_path(i), dir.join(new)));
+    }
+
+    for (done, (from, to)) in steps.iter().enumerate() {
+        if let Err(e) = fs::rename(from, to) {
+            let failed_rollbacks = steps[..done].iter().rev()
+                .filter(|(from, to)| fs::rename(to, from).is_err())
+                .count();
+            let rollback = if failed_rollbacks == 0 {
+                "all changes were undone".to_string()
+            } else {
+                format!("{} change(s) could not be undone", failed_rollbacks)
+            };
+            // Both passes follow the order of `renames`
+            let (old, _) = renames[done % renames.len()];
+            return Err(format!("{}: {} ({})", old, e, rollback));
+        }
+    }
+    Ok(renames.len())
+}
+
+/// Whether two paths name the same directory entry (not following symlinks)
+fn same_entry(a: &Path, b: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        match (fs::symlink_metadata(a), fs::symlink_metadata(b)) {
+            (Ok(ma), Ok(mb)) => ma.dev() == mb.dev() && ma.ino() == mb.ino(),
+            _ => false,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        matches!((a.canonicalize(), b.canonicalize()), (Ok(ca), Ok(cb)) if ca == cb)
+    }
+}
+
+/// `name` with its extension replaced by `ext` (with or without the leading
+/// dot; empty removes the extension). Dotfiles like `.bashrc` have none.
+pub fn replace_extension(name: &str, ext: &str) -> String {
+    let stem = match name.rfind('.') {
+        Some(pos) if pos > 0 => &name[..pos],
+        _ => name,
+    };
+    let ext = ext.trim().trim_start_matches('.');
+    if ext.is_empty() {
+        stem.to_string()
+    } else {
+        format!("{}.{}", stem, ext)
+    }
+}
+
+/// Maximum filename length (POSIX limit)
+const MAX_FILENAME_LENGTH: usize = 255;
+
+/// Validate filename for dangerous characters
+pub fn is_valid_filename(name: &str) -> Result<(), &'static str> {
+    if name.is_empty() || name.trim().is_empty() {
+        return Err("Filename cannot be empty");
+    }
+
+    // Check for path separators
+    if name.contains('/') || name.contains('\\') {
+        return Err("Filename cannot contain path separators");
+    }
+
+    // Check for null bytes
+    if name.contains('\0') {
+        return Err("Filename cannot contain null bytes");
+    }
+
+    // Check for reserved names
+    if name == "." || name == ".." {
+        return Err("Invalid filename");
+    }
+
+    // Check length limit
+    if name.len() > MAX_FILENAME_LENGTH {
+        return Err("Filename too long (max 255 characters)");
+    }
+
+    // Check for control characters
+    if name.chars().any(|c| c.is_control()) {
+        return Err("Filename cannot contain control characters");
+    }
+
+    // Check for leading/trailing whitespace
+    if name != name.trim() {
+        return Err("Filename cannot start or end with whitespace");
+    }
+
+    // Check for leading hyphen (could be interpreted as option)
+    if name.starts_with('-') {
+        return Err("Filename cannot start with hyphen");
+    }
+
+    Ok(())
+}
+
+/// Sensitive paths that symlinks should not point to
+const SENSITIVE_PATHS: &[&str] = &[
+    "/etc", "/sys", "/proc", "/boot", "/root", "/var/log",
+    "/home", "/dev", "/tmp", "/run", "/var/run",
+];
 
 /// Check symlinks in files to be archived for security
 /// Returns an error if any symlink points outside base_dir or to sensitive system paths
@@ -1151,6 +1871,491 @@ fn collect_unsafe_symlinks(
     }
 }
 
+// ─── Zip / 7z archives ─────────────────────────────────────────────────
+
+/// Entry to store in a zip archive
+struct ZipSource {
+    path: PathBuf,
+    /// Path inside the archive ("dir/file.txt")
+    name: String,
+    is_dir: bool,
+    is_symlink: bool,
+    size: u64,
+}
+
+/// Walk `files` (relative to `base_dir`) in archive order, skipping entries
+/// that match `excludes` and the relative paths in `skip`
+fn collect_zip_sources(base_dir: &Path, files: &[String], excludes: &[String], skip: &[String]) -> Vec<ZipSource> {
+    let roots = files.iter().map(|f| (base_dir.join(f), f.clone())).collect();
+    collect_zip_sources_from(roots, excludes, skip)
+}
+
+/// Walk `(path, entry name)` roots into zip sources, directories first-to-last
+fn collect_zip_sources_from(roots: Vec<(PathBuf, String)>, excludes: &[String], skip: &[String]) -> Vec<ZipSource> {
+    let mut sources = Vec::new();
+    let mut stack: Vec<(PathBuf, String)> = roots.into_iter().rev().collect();
+    while let Some((path, name)) = stack.pop() {
+        let Ok(metadata) = fs::symlink_metadata(&path) else { continue };
+        let file_type = metadata.file_type();
+        let is_dir = file_type.is_dir();
+        // Device files, sockets and fifos are not archived
+        if !(is_dir || file_type.is_file() || file_type.is_symlink()) {
+            continue;
+        }
+        if skip.contains(&name) || is_excluded(excludes, &entry_name(&path), is_dir) {
+            continue;
+        }
+        if is_dir {
+            if let Ok(entries) = fs::read_dir(&path) {
+                let mut children: Vec<String> = entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.file_name().to_string_lossy().to_string())
+                    .collect();
+                children.sort();
+                for child in children.into_iter().rev() {
+                    stack.push((path.join(&child), format!("{}/{}", name, child)));
+                }
+            }
+        }
+        sources.push(ZipSource {
+            size: if file_type.is_file() { metadata.len() } else { 0 },
+            path,
+            name,
+            is_dir,
+            is_symlink: file_type.is_symlink(),
+        });
+    }
+    sources
+}
+
+fn zip_error(e: zip::result::ZipError) -> io::Error {
+    io::Error::other(e.to_string())
+}
+
+fn cancelled() -> io::Error {
+    io::Error::new(io::ErrorKind::Interrupted, "Cancelled")
+}
+
+#[cfg(unix)]
+fn unix_mode(path: &Path, default: u32) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    fs::symlink_metadata(path).map(|m| m.permissions().mode() & 0o7777).unwrap_or(default)
+}
+
+#[cfg(not(unix))]
+fn unix_mode(_path: &Path, default: u32) -> u32 {
+    default
+}
+
+/// Copy `input` into `output` in COPY_BUFFER_SIZE chunks, reporting FileProgress
+fn copy_with_progress(
+    input: &mut impl Read,
+    output: &mut impl Write,
+    total: u64,
+    buffer: &mut [u8],
+    tx: &Sender<ProgressMessage>,
+    cancel_flag: &Arc<AtomicBool>,
+) -> io::Result<()> {
+    let mut copied = 0u64;
+    loop {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return Err(cancelled());
+        }
+        let n = input.read(buffer)?;
+        if n == 0 {
+            return Ok(());
+        }
+        output.write_all(&buffer[..n])?;
+        copied += n as u64;
+        let _ = tx.send(ProgressMessage::FileProgress(copied, total));
+    }
+}
+
+fn write_zip(
+    sources: &[ZipSource],
+    archive_path: &Path,
+    level: Option<u32>,
+    tx: &Sender<ProgressMessage>,
+    cancel_flag: &Arc<AtomicBool>,
+) -> io::Result<(usize, usize)> {
+    let total_files = sources.iter().filter(|s| !s.is_dir).count();
+    let total_bytes: u64 = sources.iter().map(|s| s.size).sum();
+    let file = File::options().write(true).create_new(true).open(archive_path)?;
+    let mut writer = zip::ZipWriter::new(file);
+    let base_options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .compression_level(level.map(|l| l.min(9) as i64));
+    let mut buffer = vec![0u8; COPY_BUFFER_SIZE];
+    let (mut completed_files, mut completed_bytes, mut failures) = (0usize, 0u64, 0usize);
+
+    for source in sources {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return Err(cancelled());
+        }
+        let default_mode = if source.is_dir { 0o755 } else { 0o644 };
+        let options = base_options
+            .unix_permissions(unix_mode(&source.path, default_mode))
+            .large_file(source.size >= u32::MAX as u64);
+        if source.is_dir {
+            writer.add_directory(source.name.as_str(), options).map_err(zip_error)?;
+            continue;
+        }
+
+        let _ = tx.send(ProgressMessage::FileStarted(source.name.clone()));
+        let stored = if source.is_symlink {
+            fs::read_link(&source.path).and_then(|target| {
+                writer
+                    .add_symlink(source.name.as_str(), target.to_string_lossy(), options)
+                    .map_err(zip_error)
+            })
+        } else {
+            // Open first so an unreadable file leaves no empty entry behind
+            File::open(&source.path).and_then(|mut input| {
+                writer.start_file(source.name.as_str(), options).map_err(zip_error)?;
+                copy_with_progress(&mut input, &mut writer, source.size, &mut buffer, tx, cancel_flag)
+            })
+        };
+        match stored {
+            Ok(()) => {
+                completed_files += 1;
+                completed_bytes += source.size;
+                let _ = tx.send(ProgressMessage::FileCompleted(source.name.clone()));
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => return Err(e),
+            Err(e) => {
+                failures += 1;
+                send_error(tx, source.name.clone(), e.to_string());
+            }
+        }
+        let _ = tx.send(ProgressMessage::TotalProgress(completed_files, total_files, completed_bytes, total_bytes));
+    }
+
+    writer.finish().map_err(zip_error)?;
+    Ok((completed_files, failures))
+}
+
+/// Create a zip archive of `files` (relative to `base_dir`, directories
+/// recursively). Symlinks are stored as links; `level` is the deflate level
+/// (0-9). The partial archive is removed on failure or cancellation.
+#[allow(clippy::too_many_arguments)]
+pub fn create_zip_with_progress(
+    base_dir: &Path,
+    files: &[String],
+    archive_path: &Path,
+    excludes: &[String],
+    skip: &[String],
+    level: Option<u32>,
+    tx: Sender<ProgressMessage>,
+    cancel_flag: Arc<AtomicBool>,
+) {
+    let _ = tx.send(ProgressMessage::Preparing("Calculating file sizes...".to_string()));
+    let sources = collect_zip_sources(base_dir, files, excludes, skip);
+    write_zip_sources(sources, archive_path, level, tx, cancel_flag);
+}
+
+/// Create a zip archive of files from anywhere (e.g. the basket); each path
+/// becomes a top-level entry named after its last component
+pub fn create_zip_from_paths(
+    paths: &[PathBuf],
+    archive_path: &Path,
+    level: Option<u32>,
+    tx: Sender<ProgressMessage>,
+    cancel_flag: Arc<AtomicBool>,
+) {
+    let _ = tx.send(ProgressMessage::Preparing("Calculating file sizes...".to_string()));
+    let roots = paths.iter().map(|p| (p.clone(), entry_name(p))).collect();
+    let sources = collect_zip_sources_from(roots, &[], &[]);
+    write_zip_sources(sources, archive_path, level, tx, cancel_flag);
+}
+
+fn write_zip_sources(
+    sources: Vec<ZipSource>,
+    archive_path: &Path,
+    level: Option<u32>,
+    tx: Sender<ProgressMessage>,
+    cancel_flag: Arc<AtomicBool>,
+) {
+    let archive_name = entry_name(archive_path);
+    // The new archive may be inside a selected directory
+    let sources: Vec<ZipSource> = sources.into_iter().filter(|s| s.path != archive_path).collect();
+    let total_files = sources.iter().filter(|s| !s.is_dir).count();
+    let total_bytes: u64 = sources.iter().map(|s| s.size).sum();
+    let _ = tx.send(ProgressMessage::PrepareComplete);
+    let _ = tx.send(ProgressMessage::TotalProgress(0, total_files, 0, total_bytes));
+
+    match write_zip(&sources, archive_path, level, &tx, &cancel_flag) {
+        Ok((success, failure)) => {
+            let _ = tx.send(ProgressMessage::Completed(success, failure));
+        }
+        Err(e) => {
+            if e.kind() != io::ErrorKind::AlreadyExists {
+                let _ = fs::remove_file(archive_path);
+            }
+            send_error(&tx, archive_name, e.to_string());
+            let _ = tx.send(ProgressMessage::Completed(0, 1));
+        }
+    }
+}
+
+/// Write one zip entry to `out` (regular file or symlink)
+#[allow(clippy::too_many_arguments)]
+fn extract_zip_entry(
+    entry: &mut impl Read,
+    is_symlink: bool,
+    size: u64,
+    mode: Option<u32>,
+    out: &Path,
+    buffer: &mut [u8],
+    tx: &Sender<ProgressMessage>,
+    cancel_flag: &Arc<AtomicBool>,
+) -> io::Result<()> {
+    if let Some(parent) = out.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if is_symlink {
+        let mut target = String::new();
+        entry.read_to_string(&mut target)?;
+        let target_path = Path::new(&target);
+        // Links must stay inside the extraction directory
+        if target_path.is_absolute() || target_path.components().any(|c| c == std::path::Component::ParentDir) {
+            return Err(io::Error::other(format!("link target outside the archive: {}", target)));
+        }
+        #[cfg(unix)]
+        return std::os::unix::fs::symlink(target_path, out);
+        #[cfg(not(unix))]
+        return Err(io::Error::other("symbolic links are not supported"));
+    }
+
+    let mut output = File::create(out)?;
+    copy_with_progress(entry, &mut output, size, buffer, tx, cancel_flag)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Some(mode) = mode {
+            fs::set_permissions(out, fs::Permissions::from_mode(mode & 0o777))?;
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+    Ok(())
+}
+
+/// Extract a zip archive into `dest_dir` (created by the caller). Entries
+/// whose path would leave `dest_dir`, and links pointing outside it, are skipped.
+pub fn extract_zip_with_progress(
+    archive_path: &Path,
+    dest_dir: &Path,
+    tx: Sender<ProgressMessage>,
+    cancel_flag: Arc<AtomicBool>,
+) {
+    let archive_name = entry_name(archive_path);
+    let _ = tx.send(ProgressMessage::Preparing("Reading archive contents...".to_string()));
+    let opened = File::open(archive_path)
+        .map_err(|e| e.to_string())
+        .and_then(|file| zip::ZipArchive::new(file).map_err(|e| e.to_string()));
+    let mut archive = match opened {
+        Ok(archive) => archive,
+        Err(e) => {
+            send_error(&tx, archive_name, e);
+            let _ = tx.send(ProgressMessage::Completed(0, 1));
+            return;
+        }
+    };
+
+    let (mut total_files, mut total_bytes) = (0usize, 0u64);
+    for i in 0..archive.len() {
+        if let Ok(entry) = archive.by_index_raw(i) {
+            if !entry.is_dir() {
+                total_files += 1;
+                total_bytes += entry.size();
+            }
+        }
+    }
+    let _ = tx.send(ProgressMessage::PrepareComplete);
+    let _ = tx.send(ProgressMessage::TotalProgress(0, total_files, 0, total_bytes));
+
+    let mut buffer = vec![0u8; COPY_BUFFER_SIZE];
+    let (mut completed_files, mut completed_bytes, mut failures) = (0usize, 0u64, 0usize);
+    for i in 0..archive.len() {
+        if cancel_flag.load(Ordering::Relaxed) {
+            send_error(&tx, archive_name, "Cancelled".to_string());
+            let _ = tx.send(ProgressMessage::Completed(completed_files, failures + 1));
+            return;
+        }
+        let mut entry = match archive.by_index(i) {
+            Ok(entry) => entry,
+            Err(e) => {
+                failures += 1;
+                send_error(&tx, format!("entry #{}", i), e.to_string());
+                continue;
+            }
+        };
+        let name = entry.name().to_string();
+        let Some(relative) = entry.enclosed_name() else {
+            failures += 1;
+            send_error(&tx, name, "unsafe path skipped".to_string());
+            continue;
+        };
+        let out = dest_dir.join(relative);
+        if entry.is_dir() {
+            if let Err(e) = fs::create_dir_all(&out) {
+                failures += 1;
+                send_error(&tx, name, e.to_string());
+            }
+            continue;
+        }
+
+        let _ = tx.send(ProgressMessage::FileStarted(name.clone()));
+        let (is_symlink, size, mode) = (entry.is_symlink(), entry.size(), entry.unix_mode());
+        match extract_zip_entry(&mut entry, is_symlink, size, mode, &out, &mut buffer, &tx, &cancel_flag) {
+            Ok(()) => {
+                completed_files += 1;
+                completed_bytes += size;
+                let _ = tx.send(ProgressMessage::FileCompleted(name));
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {
+                let _ = fs::remove_file(&out);
+                send_error(&tx, archive_name, "Cancelled".to_string());
+                let _ = tx.send(ProgressMessage::Completed(completed_files, failures + 1));
+                return;
+            }
+            Err(e) => {
+                failures += 1;
+                send_error(&tx, name, e.to_string());
+            }
+        }
+        let _ = tx.send(ProgressMessage::TotalProgress(completed_files, total_files, completed_bytes, total_bytes));
+    }
+    let _ = tx.send(ProgressMessage::Completed(completed_files, failures));
+}
+
+/// `7zz`, `7z` or `7za`, whichever is installed
+pub fn find_7z() -> Option<&'static str> {
+    ["7zz", "7z", "7za"].into_iter().find(|program| {
+        std::process::Command::new(program)
+            .arg("i")
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    })
+}
+
+/// Number of entries listed by `7z l -ba`
+pub fn count_7z_entries(program: &str, archive_path: &Path) -> usize {
+    std::process::Command::new(program)
+        .args(["l", "-ba"])
+        .arg(archive_path)
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).lines().filter(|l| !l.trim().is_empty()).count())
+        .unwrap_or(0)
+}
+
+/// Run 7z (`a` to create, `x` to extract) in `cwd`, counting the `+ name` /
+/// `- name` lines printed with `-bb1` as completed files. On failure or
+/// cancellation `cleanup` (a partial archive) is removed.
+pub fn run_7z_with_progress(
+    program: &str,
+    args: &[String],
+    cwd: &Path,
+    total_files: usize,
+    cleanup: Option<&Path>,
+    tx: Sender<ProgressMessage>,
+    cancel_flag: Arc<AtomicBool>,
+) {
+    use std::io::BufRead;
+    use std::process::{Command, Stdio};
+
+    let label = cleanup.map(entry_name).unwrap_or_else(|| program.to_string());
+    let remove_partial = || {
+        if let Some(path) = cleanup {
+            let _ = fs::remove_file(path);
+        }
+    };
+    // Switches go right after the command letter (args may end with "--")
+    let (command, rest) = args.split_first().map(|(c, r)| (c.as_str(), r)).unwrap_or(("", &[]));
+    let child = Command::new(program)
+        .current_dir(cwd)
+        .arg(command)
+        .args(["-bb1", "-bsp0", "-y"])
+        .args(rest)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            send_error(&tx, label, format!("{}: {}", program, e));
+            let _ = tx.send(ProgressMessage::Completed(0, 1));
+            return;
+        }
+    };
+    let _ = tx.send(ProgressMessage::PrepareComplete);
+    let _ = tx.send(ProgressMessage::TotalProgress(0, total_files, 0, 0));
+
+    // Collect stderr in background for error messages
+    let stderr_handle = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut text = String::new();
+            let _ = stderr.read_to_string(&mut text);
+            text
+        })
+    });
+
+    let mut completed_files = 0usize;
+    if let Some(stdout) = child.stdout.take() {
+        let mut reader = io::BufReader::with_capacity(64, stdout);
+        let mut line = String::new();
+        loop {
+            if cancel_flag.load(Ordering::Relaxed) {
+                let _ = child.kill();
+                let _ = child.wait();
+                remove_partial();
+                send_error(&tx, label, "Cancelled".to_string());
+                let _ = tx.send(ProgressMessage::Completed(completed_files, 1));
+                return;
+            }
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let Some(name) = line.trim_end().strip_prefix("+ ").or_else(|| line.trim_end().strip_prefix("- ")) else {
+                        continue;
+                    };
+                    completed_files += 1;
+                    let _ = tx.send(ProgressMessage::FileStarted(name.to_string()));
+                    let _ = tx.send(ProgressMessage::FileCompleted(name.to_string()));
+                    let _ = tx.send(ProgressMessage::TotalProgress(completed_files, total_files.max(completed_files), 0, 0));
+                }
+            }
+        }
+    }
+
+    let status = child.wait();
+    let stderr_text = stderr_handle.and_then(|h| h.join().ok()).unwrap_or_default();
+    match status {
+        Ok(status) if status.success() => {
+            let _ = tx.send(ProgressMessage::Completed(completed_files, 0));
+        }
+        other => {
+            remove_partial();
+            let message = stderr_text
+                .lines()
+                .map(str::trim)
+                .find(|l| !l.is_empty())
+                .map(|l| l.to_string())
+                .unwrap_or_else(|| match other {
+                    Ok(status) => format!("{} exited with {}", program, status),
+                    Err(e) => e.to_string(),
+                });
+            send_error(&tx, label, message);
+            let _ = tx.send(ProgressMessage::Completed(completed_files, 1));
+        }
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -1265,6 +2470,27 @@ mod tests {
         cleanup_temp_dir(&temp_dir);
     }
 
+    #[test]
+    fn test_copy_file_with_progress_large() {
+        let temp_dir = create_temp_dir();
+        let src = temp_dir.join("large.bin");
+        let dest = temp_dir.join("large_copy.bin");
+
+        // Larger than one copy chunk so both the fast path and progress reporting loop
+        let data: Vec<u8> = (0..9 * 1024 * 1024 + 123).map(|i| (i % 251) as u8).collect();
+        fs::write(&src, &data).unwrap();
+
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut last_progress = 0;
+        let copied = copy_file_with_progress(&src, &dest, &cancel_flag, |done, _| last_progress = done).unwrap();
+
+        assert_eq!(copied, data.len() as u64);
+        assert_eq!(last_progress, data.len() as u64);
+        assert_eq!(fs::read(&dest).unwrap(), data);
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
     #[test]
     fn test_copy_file_same_path_rejected() {
         let temp_dir = create_temp_dir();
@@ -1440,6 +2666,49 @@ mod tests {
         cleanup_temp_dir(&temp_dir);
     }
 
+    // ========== delete_files_with_progress tests ==========
+
+    #[test]
+    fn test_delete_files_with_progress() {
+        let temp_dir = create_temp_dir();
+        let dir = temp_dir.join("tree");
+        fs::create_dir_all(dir.join("a/b")).unwrap();
+        fs::write(dir.join("a/b/x.txt"), "12345").unwrap();
+        fs::write(dir.join("y.txt"), "123").unwrap();
+        let file = temp_dir.join("single.txt");
+        fs::write(&file, "1").unwrap();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        delete_files_with_progress(
+            vec![dir.clone(), file.clone()],
+            false,
+            Arc::new(AtomicBool::new(false)),
+            tx,
+        );
+        let messages: Vec<ProgressMessage> = rx.try_iter().collect();
+        assert!(!dir.exists());
+        assert!(!file.exists());
+        assert!(messages.iter().any(|m| matches!(m, ProgressMessage::TotalProgress(3, 3, 9, 9))));
+        assert!(matches!(messages.last(), Some(ProgressMessage::Completed(2, 0))));
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_delete_files_with_progress_cancelled() {
+        let temp_dir = create_temp_dir();
+        let file = temp_dir.join("keep.txt");
+        fs::write(&file, "1").unwrap();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        delete_files_with_progress(vec![file.clone()], false, Arc::new(AtomicBool::new(true)), tx);
+        let messages: Vec<ProgressMessage> = rx.try_iter().collect();
+        assert!(file.exists());
+        assert!(matches!(messages.last(), Some(ProgressMessage::Completed(0, 1))));
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
     // ========== create_directory tests ==========
 
     #[test]
@@ -1480,6 +2749,130 @@ mod tests {
         cleanup_temp_dir(&temp_dir);
     }
 
+    // ========== create_symlink tests ==========
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_symlink() {
+        let temp_dir = create_temp_dir();
+        let target = temp_dir.join("target.txt");
+        let link = temp_dir.join("link.txt");
+        fs::write(&target, "content").unwrap();
+
+        create_symlink(&target, &link).unwrap();
+        assert_eq!(fs::read_link(&link).unwrap(), target);
+        assert_eq!(fs::read_to_string(&link).unwrap(), "content");
+
+        // An existing entry is never replaced
+        let result = create_symlink(&temp_dir.join("other"), &link);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::AlreadyExists);
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    // ========== bulk_rename tests ==========
+
+    #[test]
+    fn test_bulk_rename_swap_and_chain() {
+        let temp_dir = create_temp_dir();
+        for (name, content) in [("a", "A"), ("b", "B"), ("c", "C")] {
+            fs::write(temp_dir.join(name), content).unwrap();
+        }
+
+        let renames = vec![
+            ("a".to_string(), "b".to_string()),
+            ("b".to_string(), "a".to_string()),
+            ("c".to_string(), "d".to_string()),
+        ];
+        assert_eq!(bulk_rename(&temp_dir, &renames), Ok(3));
+        assert_eq!(fs::read_to_string(temp_dir.join("a")).unwrap(), "B");
+        assert_eq!(fs::read_to_string(temp_dir.join("b")).unwrap(), "A");
+        assert_eq!(fs::read_to_string(temp_dir.join("d")).unwrap(), "C");
+        assert!(!temp_dir.join("c").exists());
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_bulk_rename_rejects_conflicts() {
+        let temp_dir = create_temp_dir();
+        for name in ["a", "b", "keep"] {
+            fs::write(temp_dir.join(name), name).unwrap();
+        }
+
+        // Target exists and is not part of the batch
+        let renames = vec![("a".to_string(), "keep".to_string())];
+        assert!(bulk_rename(&temp_dir, &renames).is_err());
+        // Two files to the same name
+        let renames = vec![("a".to_string(), "x".to_string()), ("b".to_string(), "x".to_string())];
+        assert!(bulk_rename(&temp_dir, &renames).is_err());
+        // Invalid name
+        let renames = vec![("a".to_string(), "x/y".to_string())];
+        assert!(bulk_rename(&temp_dir, &renames).is_err());
+
+        // Nothing was touched
+        for name in ["a", "b", "keep"] {
+            assert_eq!(fs::read_to_string(temp_dir.join(name)).unwrap(), name);
+        }
+        assert!(!temp_dir.join("x").exists());
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_replace_extension() {
+        assert_eq!(replace_extension("photo.jpeg", "jpg"), "photo.jpg");
+        assert_eq!(replace_extension("notes.txt", ".md"), "notes.md");
+        assert_eq!(replace_extension("archive.tar.gz", "tgz"), "archive.tar.tgz");
+        assert_eq!(replace_extension("README", "md"), "README.md");
+        assert_eq!(replace_extension(".bashrc", "bak"), ".bashrc.bak");
+        assert_eq!(replace_extension("a.txt", ""), "a");
+    }
+
+    // ========== set_permissions / set_owner tests ==========
+
+    #[test]
+    #[cfg(unix)]
+    fn test_set_permissions_recursive() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = create_temp_dir();
+        let dir = temp_dir.join("tree");
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("sub/a.txt"), "a").unwrap();
+        let mode = |p: &Path| fs::metadata(p).unwrap().permissions().mode() & 0o777;
+
+        set_permissions(&dir, 0o750, false).unwrap();
+        assert_eq!(mode(&dir), 0o750);
+        assert_ne!(mode(&dir.join("sub/a.txt")), 0o750);
+
+        set_permissions(&dir, 0o700, true).unwrap();
+        assert_eq!(mode(&dir.join("sub")), 0o700);
+        assert_eq!(mode(&dir.join("sub/a.txt")), 0o700);
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_set_owner_to_current_owner() {
+        use std::os::unix::fs::MetadataExt;
+
+        let temp_dir = create_temp_dir();
+        let file = temp_dir.join("a.txt");
+        fs::write(&file, "a").unwrap();
+        let meta = fs::metadata(&file).unwrap();
+
+        // Keeping the owner and group is always permitted
+        set_owner(&file, Some(meta.uid()), Some(meta.gid()), false).unwrap();
+        set_owner(&temp_dir, None, None, true).unwrap();
+        if let Some(name) = user_name(meta.uid()) {
+            assert_eq!(user_id(&name), Some(meta.uid()));
+        }
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
     // ========== rename_file tests ==========
 
     #[test]
@@ -1669,4 +3062,179 @@ mod tests {
 
         cleanup_temp_dir(&temp_dir);
     }
+
+    // ========== exclude pattern tests ==========
+
+    /// project/{main.c, main.o, target/out.bin, src/lib.c}
+    fn create_exclude_tree(base: &Path) -> PathBuf {
+        let project = base.join("src_dir").join("project");
+        fs::create_dir_all(project.join("target")).unwrap();
+        fs::create_dir_all(project.join("src")).unwrap();
+        fs::write(project.join("main.c"), b"c").unwrap();
+        fs::write(project.join("main.o"), b"o").unwrap();
+        fs::write(project.join("target").join("out.bin"), b"bin").unwrap();
+        fs::write(project.join("src").join("lib.c"), b"c").unwrap();
+        project
+    }
+
+    fn run_with_excludes(temp_dir: &Path, is_move: bool) -> (usize, usize, usize) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let excludes = parse_exclude_patterns("target/, *.o");
+        let source_dir = temp_dir.join("src_dir");
+        let target_dir = temp_dir.join("dest_dir");
+        fs::create_dir_all(&target_dir).unwrap();
+        let files = vec![PathBuf::from("project")];
+        if is_move {
+            move_files_with_progress(files, &source_dir, &target_dir, HashSet::new(), HashSet::new(), excludes, cancel_flag, tx);
+        } else {
+            copy_files_with_progress(files, &source_dir, &target_dir, HashSet::new(), HashSet::new(), excludes, cancel_flag, tx);
+        }
+
+        let mut excluded = 0;
+        let mut completed = (0, 0);
+        for msg in rx.iter() {
+            match msg {
+                ProgressMessage::Excluded(_) => excluded += 1,
+                ProgressMessage::Completed(success, failure) => completed = (success, failure),
+                _ => {}
+            }
+        }
+        (completed.0, completed.1, excluded)
+    }
+
+    #[test]
+    fn test_is_excluded() {
+        let excludes = parse_exclude_patterns("target/ node_modules/,*.o");
+        assert_eq!(excludes, vec!["target/", "node_modules/", "*.o"]);
+        assert!(is_excluded(&excludes, "target", true));
+        assert!(!is_excluded(&excludes, "target", false));
+        assert!(is_excluded(&excludes, "main.o", false));
+        assert!(!is_excluded(&excludes, "main.c", false));
+        assert!(!is_excluded(&[], "main.o", false));
+    }
+
+    #[test]
+    fn test_copy_with_excludes() {
+        let temp_dir = create_temp_dir();
+        let project = create_exclude_tree(&temp_dir);
+
+        assert_eq!(run_with_excludes(&temp_dir, false), (1, 0, 2));
+        let copied = temp_dir.join("dest_dir").join("project");
+        assert!(copied.join("main.c").exists());
+        assert!(copied.join("src").join("lib.c").exists());
+        assert!(!copied.join("main.o").exists());
+        assert!(!copied.join("target").exists());
+        assert!(project.join("main.o").exists());
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_move_with_excludes_leaves_excluded_in_source() {
+        let temp_dir = create_temp_dir();
+        let project = create_exclude_tree(&temp_dir);
+
+        assert_eq!(run_with_excludes(&temp_dir, true), (1, 0, 2));
+        let moved = temp_dir.join("dest_dir").join("project");
+        assert!(moved.join("main.c").exists());
+        assert!(moved.join("src").join("lib.c").exists());
+        assert!(!moved.join("target").exists());
+        // Only the excluded entries remain behind
+        assert!(!project.join("main.c").exists());
+        assert!(!project.join("src").exists());
+        assert!(project.join("main.o").exists());
+        assert!(project.join("target").join("out.bin").exists());
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    // ========== zip tests ==========
+
+    #[test]
+    fn test_zip_round_trip_with_excludes() {
+        let temp_dir = create_temp_dir();
+        create_exclude_tree(&temp_dir);
+        let base = temp_dir.join("src_dir");
+        let archive = temp_dir.join("project.zip");
+        let excludes = vec!["target/".to_string(), "*.o".to_string()];
+
+        let (tx, _rx) = std::sync::mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        create_zip_with_progress(&base, &["project".to_string()], &archive, &excludes, &[], Some(6), tx, cancel_flag.clone());
+        assert!(archive.exists());
+
+        let dest = temp_dir.join("out");
+        fs::create_dir_all(&dest).unwrap();
+        let (tx, _rx) = std::sync::mpsc::channel();
+        extract_zip_with_progress(&archive, &dest, tx, cancel_flag);
+        assert_eq!(fs::read(dest.join("project").join("src").join("lib.c")).unwrap(), b"c");
+        assert!(dest.join("project").join("main.c").exists());
+        assert!(!dest.join("project").join("main.o").exists());
+        assert!(!dest.join("project").join("target").exists());
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    // ========== conflict policy tests ==========
+
+    #[test]
+    fn test_conflict_policy_should_overwrite() {
+        let temp_dir = create_temp_dir();
+        let old = temp_dir.join("old.txt");
+        let new = temp_dir.join("new.txt");
+        fs::write(&old, b"longer content").unwrap();
+        fs::write(&new, b"short").unwrap();
+        let past = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+        File::options().write(true).open(&old).unwrap().set_modified(past).unwrap();
+
+        assert!(ConflictPolicy::OverwriteIfNewer.should_overwrite(&new, &old));
+        assert!(!ConflictPolicy::OverwriteIfNewer.should_overwrite(&old, &new));
+        assert!(ConflictPolicy::OverwriteIfLarger.should_overwrite(&old, &new));
+        assert!(!ConflictPolicy::OverwriteIfLarger.should_overwrite(&new, &old));
+        assert!(ConflictPolicy::Overwrite.should_overwrite(&old, &new));
+        assert!(!ConflictPolicy::Ask.should_overwrite(&new, &old));
+        assert!(!ConflictPolicy::OverwriteIfNewer.should_overwrite(&temp_dir.join("missing"), &old));
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_conflict_policy_serde() {
+        let policy: ConflictPolicy = serde_json::from_str("\"overwrite_if_newer\"").unwrap();
+        assert_eq!(policy, ConflictPolicy::OverwriteIfNewer);
+        assert_eq!(ConflictPolicy::Ask.cycle(false), ConflictPolicy::OverwriteIfLarger);
+    }
+
+    #[test]
+    fn test_worker_panic_is_reported_and_partial_removed() {
+        let temp_dir = create_temp_dir();
+        let partial = temp_dir.join("archive.zip");
+        File::create(&partial).unwrap();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        run_worker(tx, Some(partial.clone()), |tx| {
+            let _ = tx.send(ProgressMessage::FileStarted("a.txt".to_string()));
+            panic!("disk on fire");
+        });
+
+        let messages: Vec<ProgressMessage> = rx.iter().collect();
+        assert!(matches!(&messages[0], ProgressMessage::FileStarted(name) if name == "a.txt"));
+        assert!(matches!(&messages[1], ProgressMessage::Error(_, err) if err == "Internal error: disk on fire"));
+        assert_eq!(messages.len(), 2);
+        assert!(!partial.exists());
+        assert!(panic_is_caught());
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_worker_without_panic_sends_nothing_extra() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        run_worker(tx, None, |tx| {
+            let _ = tx.send(ProgressMessage::Completed(1, 0));
+        });
+        let messages: Vec<ProgressMessage> = rx.iter().collect();
+        assert!(matches!(messages[..], [ProgressMessage::Completed(1, 0)]));
+    }
 }
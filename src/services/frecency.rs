@@ -0,0 +1,254 @@
+//! Frecency-ranked history of visited directories, behind the Jump dialog.
+//!
+//! Every local directory a panel enters is recorded in
+//! `~/.cokacdir/frecency.json` with a rank (one point per visit) and the time
+//! of the last visit. Scores weigh the rank by recency the way zoxide does:
+//! visits within the last hour count four times, within a day twice, within a
+//! week half and older ones a quarter. When the ranks add up to more than
+//! `MAX_TOTAL_RANK` they are all aged (scaled down) and directories that fall
+//! below one visit are forgotten, which keeps the file small.
+//!
+//! The file is re-read before every update, so several running instances add
+//! to the same history instead of overwriting each other's visits.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Settings;
+use crate::utils::fuzzy::fuzzy_match;
+
+/// Sum of all ranks above which the history is aged
+const MAX_TOTAL_RANK: f64 = 10000.0;
+
+/// Factor applied to every rank when aging
+const AGING_FACTOR: f64 = 0.9;
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+
+/// A visited directory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub path: String,
+    /// Number of visits, reduced when the history is aged
+    pub rank: f64,
+    /// Last visit (seconds since the epoch)
+    pub last_access: u64,
+}
+
+impl Entry {
+    /// Rank weighted by how recently the directory was visited
+    pub fn score(&self, now: u64) -> f64 {
+        let age = now.saturating_sub(self.last_access);
+        let factor = if age < HOUR {
+            4.0
+        } else if age < DAY {
+            2.0
+        } else if age < WEEK {
+            0.5
+        } else {
+            0.25
+        };
+        self.rank * factor
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Frecency {
+    pub entries: Vec<Entry>,
+}
+
+pub fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn store_path() -> Option<PathBuf> {
+    Settings::config_dir().map(|d| d.join("frecency.json"))
+}
+
+impl Frecency {
+    /// History from `~/.cokacdir/frecency.json` (empty if missing or unreadable)
+    pub fn load() -> Self {
+        store_path().map(|p| Self::load_from(&p)).unwrap_or_default()
+    }
+
+    fn load_from(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        match store_path() {
+            Some(path) => self.save_to(&path),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, "No config directory")),
+        }
+    }
+
+    /// Write through a temporary file so a crash never leaves a truncated history
+    fn save_to(&self, path: &Path) -> io::Result<()> {
+        let content = serde_json::to_string(self).map_err(io::Error::other)?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, path)
+    }
+
+    /// Count a visit to `path` at `now`
+    pub fn visit(&mut self, path: &Path, now: u64) {
+        let path = path.to_string_lossy();
+        match self.entries.iter_mut().find(|e| e.path == path) {
+            Some(entry) => {
+                entry.rank += 1.0;
+                entry.last_access = now;
+            }
+            None => self.entries.push(Entry { path: path.to_string(), rank: 1.0, last_access: now }),
+        }
+        self.age();
+    }
+
+    /// Scale all ranks down once they add up to more than `MAX_TOTAL_RANK`
+    fn age(&mut self) {
+        let total: f64 = self.entries.iter().map(|e| e.rank).sum();
+        if total <= MAX_TOTAL_RANK {
+            return;
+        }
+        for entry in &mut self.entries {
+            entry.rank *= AGING_FACTOR;
+        }
+        self.entries.retain(|e| e.rank >= 1.0);
+    }
+
+    pub fn remove(&mut self, path: &str) {
+        self.entries.retain(|e| e.path != path);
+    }
+
+    /// Forget directories that no longer exist. Returns true if any were removed.
+    pub fn prune_missing(&mut self) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| Path::new(&e.path).is_dir());
+        self.entries.len() != before
+    }
+
+    /// Entries matching `query`, best first.
+    ///
+    /// Whitespace-separated terms must fuzzy-match the path in order (case
+    /// insensitive). Directories whose own name matches the last term come
+    /// before those where it only matches a parent, then higher scores win.
+    pub fn search(&self, query: &str, now: u64) -> Vec<&Entry> {
+        let terms: Vec<String> = query.split_whitespace().map(|t| t.to_lowercase()).collect();
+        let pattern = terms.concat();
+        let mut matches: Vec<(bool, f64, &Entry)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let path = entry.path.to_lowercase();
+                if !fuzzy_match(&path, &pattern) {
+                    return None;
+                }
+                let name = path.rsplit('/').next().unwrap_or(&path);
+                let name_matches = terms.last().map_or(true, |last| fuzzy_match(name, last));
+                Some((name_matches, entry.score(now), entry))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.total_cmp(&a.1)));
+        matches.into_iter().map(|(_, _, entry)| entry).collect()
+    }
+}
+
+/// Record a visit in the shared history file
+pub fn record_visit(path: &Path) {
+    let Some(store) = store_path() else { return };
+    let mut frecency = Frecency::load_from(&store);
+    frecency.visit(path, now_secs());
+    let _ = frecency.save_to(&store);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(entries: &[&Entry]) -> Vec<String> {
+        entries.iter().map(|e| e.path.clone()).collect()
+    }
+
+    #[test]
+    fn test_visit_and_score() {
+        let mut frecency = Frecency::default();
+        frecency.visit(Path::new("/home/u/proj"), 1000);
+        frecency.visit(Path::new("/home/u/proj"), 2000);
+        assert_eq!(frecency.entries.len(), 1);
+        assert_eq!(frecency.entries[0].rank, 2.0);
+        assert_eq!(frecency.entries[0].last_access, 2000);
+
+        let entry = &frecency.entries[0];
+        assert_eq!(entry.score(2000 + 60), 8.0);
+        assert_eq!(entry.score(2000 + 2 * HOUR), 4.0);
+        assert_eq!(entry.score(2000 + 2 * DAY), 1.0);
+        assert_eq!(entry.score(2000 + 2 * WEEK), 0.5);
+    }
+
+    #[test]
+    fn test_search_prefers_name_match_then_score() {
+        let now = 10 * WEEK;
+        let frecency = Frecency {
+            entries: vec![
+                Entry { path: "/home/u/proj/backend".into(), rank: 3.0, last_access: now },
+                Entry { path: "/home/u/backup".into(), rank: 1.0, last_access: now },
+                Entry { path: "/home/u/proj/backend/src".into(), rank: 20.0, last_access: now },
+                Entry { path: "/var/log".into(), rank: 50.0, last_access: now },
+            ],
+        };
+        // "be" matches the name of backend; src only matches through its parent
+        assert_eq!(paths(&frecency.search("be", now)), vec!["/home/u/proj/backend", "/home/u/proj/backend/src"]);
+        // Only backup has a "u" after its "b"
+        assert_eq!(paths(&frecency.search("bu", now)), vec!["/home/u/backup"]);
+        // Terms match in order, the last one against the directory name
+        assert_eq!(paths(&frecency.search("proj src", now)), vec!["/home/u/proj/backend/src"]);
+        assert_eq!(paths(&frecency.search("BACK", now))[0], "/home/u/proj/backend");
+        // Empty query lists everything by score
+        assert_eq!(paths(&frecency.search("", now))[0], "/var/log");
+    }
+
+    #[test]
+    fn test_aging_forgets_rare_directories() {
+        let mut frecency = Frecency {
+            entries: vec![
+                Entry { path: "/often".into(), rank: MAX_TOTAL_RANK, last_access: 0 },
+                Entry { path: "/once".into(), rank: 1.0, last_access: 0 },
+            ],
+        };
+        frecency.visit(Path::new("/often"), 1);
+        assert_eq!(paths(&frecency.entries.iter().collect::<Vec<_>>()), vec!["/often"]);
+        assert!(frecency.entries[0].rank < MAX_TOTAL_RANK);
+    }
+
+    #[test]
+    fn test_save_load_and_prune() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = dir.path().join("frecency.json");
+        let kept = dir.path().join("kept");
+        fs::create_dir(&kept).unwrap();
+
+        let mut frecency = Frecency::default();
+        frecency.visit(&kept, 5);
+        frecency.visit(&dir.path().join("gone"), 5);
+        frecency.save_to(&store).unwrap();
+
+        let mut loaded = Frecency::load_from(&store);
+        assert_eq!(loaded.entries, frecency.entries);
+        assert!(loaded.prune_missing());
+        assert_eq!(loaded.entries.len(), 1);
+        assert_eq!(loaded.entries[0].path, kept.to_string_lossy());
+        assert!(!loaded.prune_missing());
+
+        // A missing or corrupt file is an empty history
+        fs::write(&store, "not json").unwrap();
+        assert!(Frecency::load_from(&store).entries.is_empty());
+    }
+}
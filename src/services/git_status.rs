@@ -0,0 +1,239 @@
+//! Per-file git status badges for the panel listing.
+//!
+//! When a panel shows a folder inside a git work tree, each entry gets a short
+//! status code (`M`, `A`, `D`, `R`, `U`, `??`, `!!`). Folders take the most
+//! important status of the changes below them, except "ignored", which only
+//! applies to the entry git reports as ignored itself.
+//!
+//! `git status` runs on a background thread (one folder at a time) and the
+//! results are cached per folder, so revisiting a folder shows the last known
+//! badges immediately while they are refreshed.
+//!
+//! The badges are off by default (`"git_status_badges": true` turns them on),
+//! since `git status` runs for every folder the panels open.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+/// Folders kept in the cache before it is flushed
+const MAX_CACHED_DIRS: usize = 256;
+
+/// Status of one panel entry, most important first (folders keep the minimum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GitBadge {
+    Conflicted,
+    Modified,
+    Added,
+    Renamed,
+    Deleted,
+    Untracked,
+    Ignored,
+}
+
+impl GitBadge {
+    pub fn label(self) -> &'static str {
+        match self {
+            GitBadge::Conflicted => "U",
+            GitBadge::Modified => "M",
+            GitBadge::Added => "A",
+            GitBadge::Renamed => "R",
+            GitBadge::Deleted => "D",
+            GitBadge::Untracked => "??",
+            GitBadge::Ignored => "!!",
+        }
+    }
+
+    /// Badge for the two-letter porcelain code (index, work tree)
+    fn from_codes(x: u8, y: u8) -> Option<Self> {
+        match (x, y) {
+            (b'?', b'?') => Some(GitBadge::Untracked),
+            (b'!', b'!') => Some(GitBadge::Ignored),
+            (b'U', _) | (_, b'U') | (b'A', b'A') | (b'D', b'D') => Some(GitBadge::Conflicted),
+            (_, b'M') | (b'M', _) | (_, b'T') | (b'T', _) => Some(GitBadge::Modified),
+            (_, b'D') | (b'D', _) => Some(GitBadge::Deleted),
+            (b'R', _) | (b'C', _) => Some(GitBadge::Renamed),
+            (b'A', _) => Some(GitBadge::Added),
+            _ => None,
+        }
+    }
+}
+
+/// Badges of a folder's entries
+#[derive(Debug, Clone, Default)]
+pub struct Badges {
+    by_name: HashMap<String, GitBadge>,
+    /// The folder itself is untracked or ignored, so every entry is too
+    whole_dir: Option<GitBadge>,
+}
+
+impl Badges {
+    pub fn get(&self, name: &str) -> Option<GitBadge> {
+        self.by_name.get(name).copied().or(self.whole_dir)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_name.is_empty() && self.whole_dir.is_none()
+    }
+}
+
+/// Turn `git status --porcelain=v1 -z` output into badges for the entries of
+/// the folder at `prefix` (relative to the repository root, "" or ending in "/")
+fn parse_status(output: &[u8], prefix: &str) -> Badges {
+    let mut badges = Badges::default();
+    let mut fields = output.split(|b| *b == 0);
+    while let Some(field) = fields.next() {
+        if field.len() < 4 {
+            continue;
+        }
+        let (x, y) = (field[0], field[1]);
+        // Renames and copies are followed by the original path
+        if x == b'R' || x == b'C' {
+            fields.next();
+        }
+        let Some(badge) = GitBadge::from_codes(x, y) else { continue };
+        let path = String::from_utf8_lossy(&field[3..]);
+        // An untracked or ignored folder is reported instead of its content
+        if path.ends_with('/') && prefix.starts_with(path.as_ref()) {
+            badges.whole_dir = Some(badge);
+            continue;
+        }
+        let Some(rest) = path.strip_prefix(prefix) else { continue };
+        let (name, nested) = match rest.split_once('/') {
+            Some((name, tail)) => (name, !tail.is_empty()),
+            None => (rest, false),
+        };
+        if name.is_empty() || (nested && badge == GitBadge::Ignored) {
+            continue;
+        }
+        badges.by_name.entry(name.to_string())
+            .and_modify(|b| *b = (*b).min(badge))
+            .or_insert(badge);
+    }
+    badges
+}
+
+fn git(dir: &Path) -> Command {
+    let mut cmd = Command::new("git");
+    // git refuses repositories owned by another user (safe.directory), which
+    // leaves those folders without badges; a repository's own fsmonitor hook
+    // is never run
+    cmd.arg("-c").arg("core.fsmonitor=false");
+    cmd.arg("--no-optional-locks").arg("-C").arg(dir);
+    cmd.stdin(Stdio::null()).stderr(Stdio::null());
+    cmd
+}
+
+/// Badges for the entries of `dir`, or None when it is not inside a work tree
+pub fn dir_status(dir: &Path) -> Option<Badges> {
+    let prefix = git(dir).args(["rev-parse", "--show-prefix"]).output().ok()
+        .filter(|o| o.status.success())?;
+    let prefix = String::from_utf8_lossy(&prefix.stdout).trim_end_matches('\n').to_string();
+    let status = git(dir)
+        .args(["status", "--porcelain=v1", "-z", "--ignored=matching", "--", "."])
+        .output().ok()
+        .filter(|o| o.status.success())?;
+    Some(parse_status(&status.stdout, &prefix))
+}
+
+/// Background `git status` runner with a per-folder cache
+pub struct Fetcher {
+    requests: Sender<PathBuf>,
+    results: Receiver<(PathBuf, Badges)>,
+    pending: HashSet<PathBuf>,
+    cache: HashMap<PathBuf, Badges>,
+}
+
+impl Fetcher {
+    pub fn spawn() -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<PathBuf>();
+        let (result_tx, result_rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            while let Ok(dir) = request_rx.recv() {
+                // Folders outside a work tree are cached as having no badges
+                let badges = dir_status(&dir).unwrap_or_default();
+                if result_tx.send((dir, badges)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self { requests: request_tx, results: result_rx, pending: HashSet::new(), cache: HashMap::new() }
+    }
+
+    /// Queue a refresh of `dir` (ignored while one is already queued)
+    pub fn request(&mut self, dir: PathBuf) {
+        if self.pending.insert(dir.clone()) {
+            let _ = self.requests.send(dir);
+        }
+    }
+
+    /// Last known badges of `dir`
+    pub fn cached(&self, dir: &Path) -> Option<&Badges> {
+        self.cache.get(dir)
+    }
+
+    /// Folders refreshed since the last call (also stored in the cache)
+    pub fn results(&mut self) -> Vec<(PathBuf, Badges)> {
+        let results: Vec<_> = self.results.try_iter().collect();
+        for (dir, badges) in &results {
+            self.pending.remove(dir);
+            if self.cache.len() >= MAX_CACHED_DIRS && !self.cache.contains_key(dir) {
+                self.cache.clear();
+            }
+            self.cache.insert(dir.clone(), badges.clone());
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status() {
+        let output = b" M src/main.rs\0?? src/new/\0R  src/b.rs\0src/a.rs\0!! src/target/\0!! src/ui/x.log\0A  src/ui/app.rs\0UU README.md\0";
+        let badges = parse_status(output, "src/");
+        assert_eq!(badges.get("main.rs"), Some(GitBadge::Modified));
+        assert_eq!(badges.get("new"), Some(GitBadge::Untracked));
+        assert_eq!(badges.get("b.rs"), Some(GitBadge::Renamed));
+        assert_eq!(badges.get("target"), Some(GitBadge::Ignored));
+        // The ignored file deeper down does not mark the folder, the added one does
+        assert_eq!(badges.get("ui"), Some(GitBadge::Added));
+        assert_eq!(badges.get("a.rs"), None);
+        assert_eq!(badges.get("README.md"), None);
+
+        let root = parse_status(output, "");
+        assert_eq!(root.get("src"), Some(GitBadge::Modified));
+        assert_eq!(root.get("README.md"), Some(GitBadge::Conflicted));
+
+        // Inside an untracked folder git only reports the folder
+        let inside = parse_status(b"?? src/new/\0", "src/new/deep/");
+        assert_eq!(inside.get("anything"), Some(GitBadge::Untracked));
+    }
+
+    #[test]
+    fn test_dir_status() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let Ok(init) = Command::new("git").arg("init").arg("-q").arg(root).status() else { return };
+        if !init.success() {
+            return;
+        }
+        std::fs::create_dir(root.join("sub")).unwrap();
+        std::fs::write(root.join("sub/new.txt"), "x").unwrap();
+        std::fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+        std::fs::write(root.join("sub/debug.log"), "x").unwrap();
+
+        let top = dir_status(root).unwrap();
+        assert_eq!(top.get("sub"), Some(GitBadge::Untracked));
+        assert_eq!(top.get(".gitignore"), Some(GitBadge::Untracked));
+        let badges = dir_status(&root.join("sub")).unwrap();
+        assert_eq!(badges.get("new.txt"), Some(GitBadge::Untracked));
+        assert_eq!(badges.get("debug.log"), Some(GitBadge::Ignored));
+    }
+}
@@ -0,0 +1,270 @@
+//! `.gitignore` / `.ignore` matching for recursive scans (Find File, dedup).
+//!
+//! Patterns are read from every directory the scan enters, plus the
+//! directories between the repository root (the nearest ancestor holding a
+//! `.git` entry) and the scan root, and `.git/info/exclude`. The usual
+//! gitignore rules apply: the last matching pattern wins, deeper files
+//! override shallower ones, `!` re-includes, a trailing `/` matches only
+//! directories and a pattern containing `/` is anchored to its file's
+//! directory. Ignored directories are pruned, so their contents are never
+//! read — which is also why a file below an ignored directory cannot be
+//! re-included, as in git. The global excludes file is not consulted.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use regex::Regex;
+
+/// Per-directory ignore files, in the order they are applied
+pub const IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
+
+#[derive(Debug)]
+struct Rule {
+    regex: Regex,
+    negate: bool,
+    dir_only: bool,
+}
+
+/// Patterns from the ignore files of one directory
+#[derive(Debug)]
+struct RuleSet {
+    base: PathBuf,
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    fn parse(base: &Path, text: &str) -> Self {
+        let rules = text.lines().filter_map(parse_rule).collect();
+        Self { base: base.to_path_buf(), rules }
+    }
+
+    /// Some(true) if ignored, Some(false) if re-included, None if no pattern matches
+    fn matches(&self, path: &Path, is_dir: bool) -> Option<bool> {
+        let relative = path.strip_prefix(&self.base).ok()?;
+        let relative = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| (is_dir || !rule.dir_only) && rule.regex.is_match(&relative))
+            .map(|rule| !rule.negate)
+    }
+}
+
+/// One line of an ignore file (None for blanks, comments and invalid patterns)
+fn parse_rule(line: &str) -> Option<Rule> {
+    let line = line.trim_end_matches('\r');
+    // Trailing spaces are ignored unless escaped with a backslash
+    let line = if line.ends_with("\\ ") { line } else { line.trim_end_matches(' ') };
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (negate, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').filter(|r| r.starts_with(['#', '!'])).unwrap_or(line)),
+    };
+    let (dir_only, pattern) = match pattern.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+    if pattern.is_empty() {
+        return None;
+    }
+    // A slash anywhere but the end anchors the pattern to the ignore file's directory
+    let anchored = pattern.contains('/');
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+
+    let prefix = if anchored { "^" } else { "^(?:.*/)?" };
+    let regex = Regex::new(&format!("{}{}$", prefix, glob_to_regex(pattern))).ok()?;
+    Some(Rule { regex, negate, dir_only })
+}
+
+/// Translate a gitignore glob (`*`, `?`, `[...]`, `**`) to a regex body
+fn glob_to_regex(glob: &str) -> String {
+    let chars: Vec<char> = glob.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                let at_start = i == 0 || chars[i - 1] == '/';
+                let at_end = i + 2 == chars.len();
+                if at_start && chars.get(i + 2) == Some(&'/') {
+                    // "**/" matches zero or more directories
+                    out.push_str("(?:.*/)?");
+                    i += 3;
+                    continue;
+                }
+                if at_start && at_end {
+                    // "/**" matches everything inside
+                    out.push_str(".*");
+                } else {
+                    out.push_str("[^/]*");
+                }
+                i += 2;
+                continue;
+            }
+            '*' => out.push_str("[^/]*"),
+            '?' => out.push_str("[^/]"),
+            '[' => {
+                match chars[i + 1..].iter().position(|&c| c == ']') {
+                    Some(len) if len > 0 => {
+                        let class: String = chars[i + 1..i + 1 + len].iter().collect();
+                        let class = match class.strip_prefix('!') {
+                            Some(rest) => format!("^{}", rest),
+                            None => class,
+                        };
+                        out.push('[');
+                        out.push_str(&class.replace('\\', "\\\\").replace('[', "\\["));
+                        out.push(']');
+                        i += len + 2;
+                        continue;
+                    }
+                    _ => out.push_str("\\["),
+                }
+            }
+            '\\' if i + 1 < chars.len() => {
+                out.push_str(&regex::escape(&chars[i + 1].to_string()));
+                i += 2;
+                continue;
+            }
+            c => out.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+    out
+}
+
+fn load_rules(dir: &Path) -> Option<Arc<RuleSet>> {
+    let text: String = IGNORE_FILES
+        .iter()
+        .filter_map(|name| fs::read_to_string(dir.join(name)).ok())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let rules = RuleSet::parse(dir, &text);
+    (!rules.rules.is_empty()).then(|| Arc::new(rules))
+}
+
+/// Ignore rules in effect for the entries of one directory
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreMatcher {
+    /// Outermost first
+    layers: Vec<Arc<RuleSet>>,
+}
+
+impl IgnoreMatcher {
+    /// Rules for the entries of the scan root: `.git/info/exclude` and the ignore
+    /// files from the repository root down to `root`
+    pub fn new(root: &Path) -> Self {
+        let mut dirs: Vec<&Path> = vec![root];
+        if !root.join(".git").exists() {
+            let repo = root.ancestors().skip(1).position(|dir| dir.join(".git").exists());
+            if let Some(depth) = repo {
+                dirs.extend(root.ancestors().skip(1).take(depth + 1));
+            }
+        }
+        dirs.reverse();
+
+        let mut layers = Vec::new();
+        if let Ok(text) = fs::read_to_string(dirs[0].join(".git/info/exclude")) {
+            let rules = RuleSet::parse(dirs[0], &text);
+            if !rules.rules.is_empty() {
+                layers.push(Arc::new(rules));
+            }
+        }
+        layers.extend(dirs.into_iter().filter_map(load_rules));
+        Self { layers }
+    }
+
+    /// Rules for the entries of `dir`, a subdirectory the scan entered
+    pub fn child(&self, dir: &Path) -> Self {
+        let mut layers = self.layers.clone();
+        layers.extend(load_rules(dir));
+        Self { layers }
+    }
+
+    /// Whether the scan should skip `path`. `.git` directories are always skipped
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if is_dir && path.file_name().is_some_and(|name| name == ".git") {
+            return true;
+        }
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.matches(path, is_dir))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(text: &str) -> RuleSet {
+        RuleSet::parse(Path::new("/repo"), text)
+    }
+
+    fn ignored(set: &RuleSet, path: &str, is_dir: bool) -> Option<bool> {
+        set.matches(&Path::new("/repo").join(path), is_dir)
+    }
+
+    #[test]
+    fn test_patterns() {
+        let set = rules("# build output\ntarget/\n*.log\n!keep.log\n/root-only\ndocs/*.tmp\n**/cache\nlib/**\n\\#hash\n");
+        assert_eq!(ignored(&set, "target", true), Some(true));
+        assert_eq!(ignored(&set, "sub/target", true), Some(true));
+        // Trailing slash: directories only
+        assert_eq!(ignored(&set, "target", false), None);
+        assert_eq!(ignored(&set, "a/b/debug.log", false), Some(true));
+        assert_eq!(ignored(&set, "keep.log", false), Some(false));
+        assert_eq!(ignored(&set, "root-only", false), Some(true));
+        assert_eq!(ignored(&set, "sub/root-only", false), None);
+        assert_eq!(ignored(&set, "docs/a.tmp", false), Some(true));
+        assert_eq!(ignored(&set, "docs/sub/a.tmp", false), None);
+        assert_eq!(ignored(&set, "x/y/cache", true), Some(true));
+        assert_eq!(ignored(&set, "lib/a/b.rs", false), Some(true));
+        assert_eq!(ignored(&set, "#hash", false), Some(true));
+        assert_eq!(ignored(&set, "main.rs", false), None);
+    }
+
+    #[test]
+    fn test_glob_classes_and_wildcards() {
+        let set = rules("file?.[ch]\n[!a]*.bak\n");
+        assert_eq!(ignored(&set, "file1.c", false), Some(true));
+        assert_eq!(ignored(&set, "file10.c", false), None);
+        assert_eq!(ignored(&set, "x.bak", false), Some(true));
+        assert_eq!(ignored(&set, "a.bak", false), None);
+    }
+
+    #[test]
+    fn test_matcher_layers_and_repo_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join(".git/info")).unwrap();
+        fs::write(root.join(".git/info/exclude"), "secret.txt\n").unwrap();
+        fs::write(root.join(".gitignore"), "node_modules/\n*.log\n").unwrap();
+        fs::create_dir_all(root.join("app/node_modules")).unwrap();
+        fs::write(root.join("app/.ignore"), "!important.log\n").unwrap();
+
+        // Scanning from a subdirectory still applies the repository's rules
+        let app = root.join("app");
+        let matcher = IgnoreMatcher::new(&app);
+        assert!(matcher.is_ignored(&app.join("node_modules"), true));
+        assert!(matcher.is_ignored(&app.join("debug.log"), false));
+        assert!(matcher.is_ignored(&app.join("secret.txt"), false));
+        // app/.ignore overrides the root .gitignore
+        assert!(!matcher.is_ignored(&app.join("important.log"), false));
+        assert!(!matcher.is_ignored(&app.join("main.rs"), false));
+
+        let top = IgnoreMatcher::new(root);
+        assert!(top.is_ignored(&root.join(".git"), true));
+        assert!(!top.is_ignored(&root.join("app"), true));
+        let child = top.child(&app);
+        assert!(!child.is_ignored(&app.join("important.log"), false));
+        assert!(top.is_ignored(&root.join("important.log"), false));
+    }
+}
@@ -0,0 +1,190 @@
+//! "Save as" conversions for the image viewer.
+//!
+//! The original file is decoded at full size (the viewer may only hold a
+//! thumbnail), optionally resized, and encoded as PNG, JPEG or WebP next to
+//! the original. Existing files are never overwritten.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageEncoder};
+
+/// Resize range offered by the dialog (percent of the original size)
+pub const SCALE_MIN: u32 = 5;
+pub const SCALE_MAX: u32 = 400;
+pub const DEFAULT_JPEG_QUALITY: u8 = 85;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Png,
+    Jpeg,
+    WebP,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [ExportFormat::Png, ExportFormat::Jpeg, ExportFormat::WebP];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Png => "PNG",
+            ExportFormat::Jpeg => "JPEG",
+            ExportFormat::WebP => "WebP",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Png => "png",
+            ExportFormat::Jpeg => "jpg",
+            ExportFormat::WebP => "webp",
+        }
+    }
+
+    /// Only JPEG takes a quality setting (the WebP encoder is lossless)
+    pub fn has_quality(self) -> bool {
+        self == ExportFormat::Jpeg
+    }
+
+    /// Format of an existing file, by extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_string_lossy().to_lowercase();
+        match ext.as_str() {
+            "png" => Some(ExportFormat::Png),
+            "jpg" | "jpeg" => Some(ExportFormat::Jpeg),
+            "webp" => Some(ExportFormat::WebP),
+            _ => None,
+        }
+    }
+
+    /// Next (or previous) format in `ALL`, wrapping around
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|&f| f == self).unwrap_or(0);
+        let next = if forward { (idx + 1) % len } else { (idx + len - 1) % len };
+        Self::ALL[next]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    /// JPEG quality, 1-100
+    pub quality: u8,
+    /// Output size in percent of the original
+    pub scale_percent: u32,
+}
+
+/// Where `src` is written with `options`: "photo.png" -> "photo.jpg",
+/// "photo_50pct.jpg" when resized, "photo (1).jpg" if that name is taken
+pub fn output_path(src: &Path, options: &ExportOptions) -> PathBuf {
+    let stem = src.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_else(|| "image".to_string());
+    let stem = if options.scale_percent == 100 {
+        stem
+    } else {
+        format!("{}_{}pct", stem, options.scale_percent)
+    };
+    let ext = options.format.extension();
+    let parent = src.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut candidate = parent.join(format!("{}.{}", stem, ext));
+    let mut n = 1;
+    while candidate.exists() {
+        candidate = parent.join(format!("{} ({}).{}", stem, n, ext));
+        n += 1;
+    }
+    candidate
+}
+
+fn encode(image: &DynamicImage, dest: &Path, options: &ExportOptions) -> Result<(), String> {
+    let file = File::create(dest).map_err(|e| format!("Cannot create {}: {}", dest.display(), e))?;
+    let writer = BufWriter::new(file);
+    let result = match options.format {
+        ExportFormat::Png => {
+            let rgba = image.to_rgba8();
+            PngEncoder::new(writer).write_image(&rgba, rgba.width(), rgba.height(), image::ExtendedColorType::Rgba8)
+        }
+        ExportFormat::Jpeg => {
+            // JPEG has no alpha channel
+            let rgb = image.to_rgb8();
+            JpegEncoder::new_with_quality(writer, options.quality.clamp(1, 100))
+                .write_image(&rgb, rgb.width(), rgb.height(), image::ExtendedColorType::Rgb8)
+        }
+        ExportFormat::WebP => {
+            let rgba = image.to_rgba8();
+            WebPEncoder::new_lossless(writer).write_image(&rgba, rgba.width(), rgba.height(), image::ExtendedColorType::Rgba8)
+        }
+    };
+    result.map_err(|e| format!("Failed to encode {}: {}", options.format.name(), e))
+}
+
+/// Convert/resize `src` and write it next to the original.
+/// Returns the new file and its dimensions.
+pub fn export(src: &Path, options: &ExportOptions) -> Result<(PathBuf, (u32, u32)), String> {
+    let image = image::open(src).map_err(|e| format!("Failed to load image: {}", e))?;
+
+    let options = &ExportOptions { scale_percent: options.scale_percent.clamp(SCALE_MIN, SCALE_MAX), ..*options };
+    let scale = options.scale_percent;
+    let image = if scale == 100 {
+        image
+    } else {
+        let width = ((image.width() as u64 * scale as u64) / 100).max(1) as u32;
+        let height = ((image.height() as u64 * scale as u64) / 100).max(1) as u32;
+        image.resize_exact(width, height, FilterType::Lanczos3)
+    };
+
+    let dest = output_path(src, options);
+    if let Err(e) = encode(&image, &dest, options) {
+        let _ = std::fs::remove_file(&dest);
+        return Err(e);
+    }
+    Ok((dest, (image.width(), image.height())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(format: ExportFormat, scale_percent: u32) -> ExportOptions {
+        ExportOptions { format, quality: DEFAULT_JPEG_QUALITY, scale_percent }
+    }
+
+    #[test]
+    fn test_output_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("photo.png");
+        std::fs::write(&src, b"x").unwrap();
+
+        assert_eq!(output_path(&src, &options(ExportFormat::Jpeg, 100)), dir.path().join("photo.jpg"));
+        assert_eq!(output_path(&src, &options(ExportFormat::Jpeg, 50)), dir.path().join("photo_50pct.jpg"));
+        // Same format and size: never overwrite the original
+        assert_eq!(output_path(&src, &options(ExportFormat::Png, 100)), dir.path().join("photo (1).png"));
+    }
+
+    #[test]
+    fn test_export_converts_and_resizes() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("a.png");
+        DynamicImage::new_rgba8(40, 20).save(&src).unwrap();
+
+        let (dest, size) = export(&src, &options(ExportFormat::Jpeg, 50)).unwrap();
+        assert_eq!(dest, dir.path().join("a_50pct.jpg"));
+        assert_eq!(size, (20, 10));
+        assert_eq!(image::image_dimensions(&dest).unwrap(), (20, 10));
+
+        let (dest, _) = export(&src, &options(ExportFormat::WebP, 100)).unwrap();
+        assert_eq!(image::guess_format(&std::fs::read(&dest).unwrap()).unwrap(), image::ImageFormat::WebP);
+    }
+
+    #[test]
+    fn test_format_cycle() {
+        assert_eq!(ExportFormat::Png.cycle(true), ExportFormat::Jpeg);
+        assert_eq!(ExportFormat::Png.cycle(false), ExportFormat::WebP);
+        assert_eq!(ExportFormat::from_path(Path::new("x.JPEG")), Some(ExportFormat::Jpeg));
+        assert_eq!(ExportFormat::from_path(Path::new("x.gif")), None);
+    }
+}
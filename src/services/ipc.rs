@@ -0,0 +1,255 @@
+//! Unix socket control interface.
+//!
+//! While the TUI is running, a socket is created at `~/.cokacdir/ipc/<pid>.sock`
+//! and its path is exported as `COKACDIR_SOCKET` to child processes.
+//! Each connection speaks line-delimited JSON-RPC 2.0:
+//!
+//! ```text
+//! → {"jsonrpc":"2.0","id":1,"method":"navigate","params":{"path":"~/src"}}
+//! ← {"jsonrpc":"2.0","id":1,"result":{"path":"/home/user/src"}}
+//! ```
+//!
+//! Requests are parsed on the connection thread and handed to the UI loop
+//! through a channel, so every method runs on the main thread with full
+//! access to `App`.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+/// Environment variable holding the socket path of the running instance
+pub const SOCKET_ENV: &str = "COKACDIR_SOCKET";
+
+/// How long a connection waits for the UI loop to answer a request
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+// JSON-RPC 2.0 error codes
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+/// Application-defined: the UI cannot handle the request right now (dialog open, spinner running)
+pub const BUSY: i64 = -32000;
+
+/// Error returned by a request handler
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl IpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+
+    pub fn busy(message: impl Into<String>) -> Self {
+        Self::new(BUSY, message)
+    }
+}
+
+/// A request waiting to be handled by the UI loop
+pub struct IpcRequest {
+    pub method: String,
+    pub params: Value,
+    id: Value,
+    reply: Sender<String>,
+}
+
+impl IpcRequest {
+    /// Get a string parameter by name
+    pub fn str_param(&self, name: &str) -> Option<&str> {
+        self.params.get(name).and_then(|v| v.as_str())
+    }
+
+    /// Send the result back to the client
+    pub fn respond(self, result: Result<Value, IpcError>) {
+        let _ = self.reply.send(encode_response(&self.id, result));
+    }
+}
+
+/// Running socket server. The socket file is removed on drop.
+pub struct IpcServer {
+    pub socket_path: PathBuf,
+    receiver: Receiver<IpcRequest>,
+}
+
+impl IpcServer {
+    /// Bind the socket and start accepting connections in a background thread
+    pub fn start() -> std::io::Result<Self> {
+        let dir = dirs::home_dir()
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "home directory not found"))?
+            .join(".cokacdir")
+            .join("ipc");
+        std::fs::create_dir_all(&dir)?;
+        // Only the owner may talk to the socket
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))?;
+        cleanup_stale_sockets(&dir);
+
+        let socket_path = dir.join(format!("{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path)?;
+        std::fs::set_permissions(&socket_path, std::fs::Permissions::from_mode(0o600))?;
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let tx = tx.clone();
+                thread::spawn(move || handle_connection(stream, tx));
+            }
+        });
+
+        std::env::set_var(SOCKET_ENV, &socket_path);
+
+        Ok(Self { socket_path, receiver: rx })
+    }
+
+    /// Take the next pending request, if any (non-blocking)
+    pub fn try_recv(&self) -> Option<IpcRequest> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+/// Remove sockets left behind by instances that are no longer running
+fn cleanup_stale_sockets(dir: &std::path::Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.extension().map(|e| e != "sock").unwrap_or(true) {
+            continue;
+        }
+        // A refused connection means nobody is listening anymore
+        if UnixStream::connect(&path).is_err() {
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+fn handle_connection(stream: UnixStream, tx: Sender<IpcRequest>) {
+    let Ok(mut writer) = stream.try_clone() else { return };
+    let reader = BufReader::new(stream);
+
+    for line in reader.lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match parse_request(&line) {
+            Ok((id, method, params)) => {
+                let (reply_tx, reply_rx) = mpsc::channel();
+                let request = IpcRequest { method, params, id: id.clone(), reply: reply_tx };
+                if tx.send(request).is_err() {
+                    // UI loop has exited
+                    break;
+                }
+                reply_rx.recv_timeout(REPLY_TIMEOUT).unwrap_or_else(|_| {
+                    encode_response(&id, Err(IpcError::new(INTERNAL_ERROR, "Request timed out")))
+                })
+            }
+            Err((id, err)) => encode_response(&id, Err(err)),
+        };
+
+        if writeln!(writer, "{}", response).is_err() {
+            break;
+        }
+    }
+}
+
+/// Parse one JSON-RPC request line into (id, method, params)
+fn parse_request(line: &str) -> Result<(Value, String, Value), (Value, IpcError)> {
+    let value: Value = serde_json::from_str(line)
+        .map_err(|e| (Value::Null, IpcError::new(PARSE_ERROR, format!("Parse error: {}", e))))?;
+
+    let id = value.get("id").cloned().unwrap_or(Value::Null);
+    let method = match value.get("method").and_then(|m| m.as_str()) {
+        Some(m) => m.to_string(),
+        None => return Err((id, IpcError::new(INVALID_REQUEST, "Missing method"))),
+    };
+    let params = value.get("params").cloned().unwrap_or_else(|| json!({}));
+    if !params.is_object() {
+        return Err((id, IpcError::invalid_params("params must be an object")));
+    }
+
+    Ok((id, method, params))
+}
+
+fn encode_response(id: &Value, result: Result<Value, IpcError>) -> String {
+    let body = match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": e.code, "message": e.message },
+        }),
+    };
+    body.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request_valid() {
+        let (id, method, params) =
+            parse_request(r#"{"jsonrpc":"2.0","id":7,"method":"navigate","params":{"path":"/tmp"}}"#)
+                .unwrap();
+        assert_eq!(id, json!(7));
+        assert_eq!(method, "navigate");
+        assert_eq!(params["path"], "/tmp");
+    }
+
+    #[test]
+    fn test_parse_request_defaults_params() {
+        let (_, method, params) = parse_request(r#"{"id":1,"method":"get_state"}"#).unwrap();
+        assert_eq!(method, "get_state");
+        assert!(params.as_object().map(|o| o.is_empty()).unwrap_or(false));
+    }
+
+    #[test]
+    fn test_parse_request_errors() {
+        let (_, err) = parse_request("not json").unwrap_err();
+        assert_eq!(err.code, PARSE_ERROR);
+
+        let (id, err) = parse_request(r#"{"id":"a"}"#).unwrap_err();
+        assert_eq!(id, json!("a"));
+        assert_eq!(err.code, INVALID_REQUEST);
+
+        let (_, err) = parse_request(r#"{"id":1,"method":"x","params":[1]}"#).unwrap_err();
+        assert_eq!(err.code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_encode_response() {
+        let ok: Value = serde_json::from_str(&encode_response(&json!(1), Ok(json!("pong")))).unwrap();
+        assert_eq!(ok["result"], "pong");
+        assert!(ok.get("error").is_none());
+
+        let err: Value = serde_json::from_str(&encode_response(
+            &json!(2),
+            Err(IpcError::new(METHOD_NOT_FOUND, "nope")),
+        ))
+        .unwrap();
+        assert_eq!(err["id"], 2);
+        assert_eq!(err["error"]["code"], METHOD_NOT_FOUND);
+    }
+}
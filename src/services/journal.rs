@@ -0,0 +1,387 @@
+//! Crash journal for in-flight work.
+//!
+//! Each running instance keeps `~/.cokacdir/journal/<pid>.json` up to date
+//! with the copy/move operations it is running (including the overwrite/skip
+//! decisions made for conflicts) and backups of editor buffers with unsaved
+//! changes. A clean finish removes the entries again, so the file only
+//! outlives its process after a crash or a killed terminal. The next launch
+//! finds such journals (`stale_journals`) and offers to resume the operations,
+//! clean up partial copies, or recover the buffers.
+//!
+//! File operations are journaled from their worker thread: the thread holds an
+//! `OperationGuard` and `file_ops` reports each top-level item through
+//! `item_started` / `item_finished`, which do nothing on threads without one.
+
+use std::cell::Cell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::config::Settings;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OperationKind {
+    Copy,
+    Move,
+}
+
+/// A copy or move as it was started, plus how far it got
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Operation {
+    pub id: u64,
+    pub kind: OperationKind,
+    pub source_dir: PathBuf,
+    pub target_dir: PathBuf,
+    /// Top-level items (relative to `source_dir` or absolute)
+    pub files: Vec<PathBuf>,
+    /// Conflict decisions: sources whose existing target is replaced / that are left out
+    #[serde(default)]
+    pub overwrite: Vec<PathBuf>,
+    #[serde(default)]
+    pub skip: Vec<PathBuf>,
+    #[serde(default)]
+    pub excludes: Vec<String>,
+    /// Sources whose target is complete
+    #[serde(default)]
+    pub done: Vec<PathBuf>,
+    /// Target being written; only ever a partial copy of a source that is
+    /// still intact, so it is safe to delete
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress: Option<PathBuf>,
+}
+
+impl Operation {
+    /// Full path of a top-level item
+    pub fn source_path(&self, file: &Path) -> PathBuf {
+        if file.is_absolute() { file.to_path_buf() } else { self.source_dir.join(file) }
+    }
+
+    /// Items still to be copied or moved: not done, not skipped, source still there
+    pub fn remaining(&self) -> Vec<PathBuf> {
+        self.files
+            .iter()
+            .filter(|f| {
+                let src = self.source_path(f);
+                !self.done.contains(&src) && !self.skip.contains(&src) && src.exists()
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Backup of an editor buffer with unsaved changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnsavedBuffer {
+    pub path: PathBuf,
+    /// File holding the buffer contents
+    pub backup: PathBuf,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Journal {
+    #[serde(default)]
+    pub operations: Vec<Operation>,
+    #[serde(default)]
+    pub buffers: Vec<UnsavedBuffer>,
+}
+
+impl Journal {
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty() && self.buffers.is_empty()
+    }
+}
+
+fn journal_dir() -> Option<PathBuf> {
+    Settings::config_dir().map(|d| d.join("journal"))
+}
+
+/// Write `journal` to `file`, or remove the file when there is nothing to keep
+fn write(file: &Path, journal: &Journal) {
+    if journal.is_empty() {
+        let _ = fs::remove_file(file);
+        return;
+    }
+    let Some(dir) = file.parent() else { return };
+    if fs::create_dir_all(dir).is_err() {
+        return;
+    }
+    let Ok(content) = serde_json::to_string_pretty(journal) else { return };
+    let temp_path = file.with_extension("json.tmp");
+    if fs::write(&temp_path, content).is_ok() {
+        let _ = fs::rename(&temp_path, file);
+    }
+}
+
+/// Journal of this process
+fn current() -> &'static Mutex<Journal> {
+    static JOURNAL: OnceLock<Mutex<Journal>> = OnceLock::new();
+    JOURNAL.get_or_init(|| Mutex::new(Journal::default()))
+}
+
+fn own_file() -> Option<PathBuf> {
+    journal_dir().map(|d| d.join(format!("{}.json", std::process::id())))
+}
+
+fn update(f: impl FnOnce(&mut Journal)) {
+    let Ok(mut journal) = current().lock() else { return };
+    f(&mut journal);
+    if let Some(file) = own_file() {
+        write(&file, &journal);
+    }
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Operation journaled by this thread
+    static CURRENT_OPERATION: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Keeps an operation in the journal while the worker thread runs it.
+/// Dropping it (finished, cancelled or failed) removes the entry. When the
+/// worker panicked, the partial copy it was writing is deleted as well.
+pub struct OperationGuard {
+    id: u64,
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        CURRENT_OPERATION.with(|c| c.set(None));
+        let id = self.id;
+        let panicking = std::thread::panicking();
+        update(|j| {
+            j.operations.retain(|op| {
+                if op.id != id {
+                    return true;
+                }
+                if let (true, Some(partial)) = (panicking, &op.in_progress) {
+                    let _ = remove_partial(partial);
+                }
+                false
+            })
+        });
+    }
+}
+
+/// Journal an operation run by the calling thread
+pub fn begin_operation(
+    kind: OperationKind,
+    source_dir: &Path,
+    target_dir: &Path,
+    files: &[PathBuf],
+    overwrite: &[PathBuf],
+    skip: &[PathBuf],
+    excludes: &[String],
+) -> OperationGuard {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    CURRENT_OPERATION.with(|c| c.set(Some(id)));
+    let operation = Operation {
+        id,
+        kind,
+        source_dir: source_dir.to_path_buf(),
+        target_dir: target_dir.to_path_buf(),
+        files: files.to_vec(),
+        overwrite: overwrite.to_vec(),
+        skip: skip.to_vec(),
+        excludes: excludes.to_vec(),
+        done: Vec::new(),
+        in_progress: None,
+    };
+    update(|j| j.operations.push(operation));
+    OperationGuard { id }
+}
+
+fn update_current_operation(f: impl FnOnce(&mut Operation)) {
+    let Some(id) = CURRENT_OPERATION.with(|c| c.get()) else { return };
+    update(|j| {
+        if let Some(op) = j.operations.iter_mut().find(|op| op.id == id) {
+            f(op);
+        }
+    });
+}
+
+/// `dest` is about to be written from `src` (which stays intact meanwhile)
+pub fn item_started(dest: &Path) {
+    update_current_operation(|op| op.in_progress = Some(dest.to_path_buf()));
+}
+
+/// The target of `src` is complete (a move may still remove the source after this)
+pub fn item_finished(src: &Path) {
+    update_current_operation(|op| {
+        op.in_progress = None;
+        op.done.push(src.to_path_buf());
+    });
+}
+
+fn buffer_backup_path(path: &Path) -> Option<PathBuf> {
+    let key = hex::encode(Sha256::digest(path.to_string_lossy().as_bytes()));
+    journal_dir().map(|d| d.join(format!("{}-{}.buf", std::process::id(), &key[..16])))
+}
+
+/// Back up the unsaved contents of `path`
+pub fn save_buffer(path: &Path, content: &str) {
+    let Some(backup) = buffer_backup_path(path) else { return };
+    if let Some(dir) = backup.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if fs::write(&backup, content).is_err() {
+        return;
+    }
+    update(|j| {
+        if !j.buffers.iter().any(|b| b.path == path) {
+            j.buffers.push(UnsavedBuffer { path: path.to_path_buf(), backup });
+        }
+    });
+}
+
+/// Forget the backup of `path` (saved, discarded or closed)
+pub fn discard_buffer(path: &Path) {
+    update(|j| {
+        j.buffers.retain(|b| {
+            if b.path == path {
+                let _ = fs::remove_file(&b.backup);
+                false
+            } else {
+                true
+            }
+        })
+    });
+}
+
+/// Delete a partial copy recorded as `Operation::in_progress`
+pub fn remove_partial(path: &Path) -> std::io::Result<()> {
+    let result = match fs::symlink_metadata(path) {
+        Ok(m) if m.is_dir() => fs::remove_dir_all(path),
+        Ok(_) => fs::remove_file(path),
+        Err(e) => Err(e),
+    };
+    match result {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
+}
+
+/// Journal left behind by a process that is no longer running
+#[derive(Debug)]
+pub struct StaleJournal {
+    pub file: PathBuf,
+    pub journal: Journal,
+}
+
+impl StaleJournal {
+    /// Store what is left to handle (removes the file when nothing is)
+    pub fn save(&self) {
+        write(&self.file, &self.journal);
+    }
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    // SAFETY: signal 0 only checks whether the process exists
+    let result = unsafe { libc::kill(pid as libc::pid_t, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn process_alive(pid: u32) -> bool {
+    pid == std::process::id()
+}
+
+/// Journals of crashed or killed instances
+pub fn stale_journals() -> Vec<StaleJournal> {
+    let Some(dir) = journal_dir() else { return Vec::new() };
+    let Ok(entries) = fs::read_dir(&dir) else { return Vec::new() };
+    let mut stale: Vec<StaleJournal> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|file| {
+            let pid: u32 = file.file_stem()?.to_str()?.parse().ok()?;
+            if process_alive(pid) {
+                return None;
+            }
+            let journal = fs::read_to_string(&file)
+                .ok()
+                .and_then(|s| serde_json::from_str::<Journal>(&s).ok())
+                .unwrap_or_default();
+            Some(StaleJournal { file, journal })
+        })
+        .collect();
+    // Unreadable or empty journals have nothing to offer
+    stale.retain(|s| {
+        if s.journal.is_empty() {
+            let _ = fs::remove_file(&s.file);
+            false
+        } else {
+            true
+        }
+    });
+    stale.sort_by(|a, b| a.file.cmp(&b.file));
+    stale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operation(dir: &Path) -> Operation {
+        Operation {
+            id: 1,
+            kind: OperationKind::Copy,
+            source_dir: dir.join("src"),
+            target_dir: dir.join("dst"),
+            files: vec![PathBuf::from("a"), PathBuf::from("b"), PathBuf::from("c"), PathBuf::from("gone")],
+            overwrite: Vec::new(),
+            skip: vec![dir.join("src/c")],
+            excludes: Vec::new(),
+            done: vec![dir.join("src/a")],
+            in_progress: Some(dir.join("dst/b")),
+        }
+    }
+
+    #[test]
+    fn test_remaining_items() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        for name in ["a", "b", "c"] {
+            fs::write(dir.path().join("src").join(name), name).unwrap();
+        }
+        // a is done, c skipped, gone no longer exists
+        assert_eq!(operation(dir.path()).remaining(), vec![PathBuf::from("b")]);
+    }
+
+    #[test]
+    fn test_write_roundtrip_and_removal() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("journal").join("123.json");
+        let journal = Journal {
+            operations: vec![operation(dir.path())],
+            buffers: vec![UnsavedBuffer { path: PathBuf::from("/x/notes.txt"), backup: PathBuf::from("/j/123-ab.buf") }],
+        };
+        write(&file, &journal);
+        let loaded: Journal = serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+        assert_eq!(loaded, journal);
+
+        write(&file, &Journal::default());
+        assert!(!file.exists());
+    }
+
+    #[test]
+    fn test_item_reports_without_operation_are_ignored() {
+        // Threads without an OperationGuard (e.g. other file_ops callers) journal nothing
+        item_started(Path::new("/tmp/x"));
+        item_finished(Path::new("/tmp/x"));
+        assert!(current().lock().unwrap().operations.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_alive() {
+        assert!(process_alive(std::process::id()));
+    }
+}
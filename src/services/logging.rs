@@ -0,0 +1,245 @@
+//! Leveled logging to rotated files under `~/.cokacdir/logs`.
+//!
+//! Each component writes to its own file, `logs/<target>.log` (`claude`,
+//! `telegram`, `cron`, `file_ops`, ...), one line per record:
+//! `2026-01-31 14:05:00.123 WARN  message`. A file that grows past
+//! `MAX_FILE_SIZE` is renamed to `<target>.log.1` (older ones shift up to
+//! `.log.<MAX_ROTATED>`, the oldest is dropped).
+//!
+//! The level is taken from `--log-level <level>`, else the `COKACDIR_LOG`
+//! environment variable, else `warn`. Levels are `off`, `error`, `warn`,
+//! `info`, `debug` and `trace`. Debug mode (`COKACDIR_DEBUG=1`, a bot saved
+//! with debug on, or the Telegram `/debug` command) raises the level to at
+//! least `debug` until it is turned off again.
+//!
+//! Use the `log_error!` ... `log_trace!` macros; the message is only
+//! formatted when its level is enabled.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// Size after which a log file is rotated
+const MAX_FILE_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Number of rotated files kept per target
+const MAX_ROTATED: usize = 3;
+
+/// Environment variable holding the level (overridden by `--log-level`)
+pub const ENV_VAR: &str = "COKACDIR_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl Level {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Level::Error),
+            2 => Some(Level::Warn),
+            3 => Some(Level::Info),
+            4 => Some(Level::Debug),
+            5 => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+/// Parsed `--log-level` / `COKACDIR_LOG` value: `None` is `off`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelFilter(pub Option<Level>);
+
+impl FromStr for LevelFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let level = match s.trim().to_ascii_lowercase().as_str() {
+            "off" | "none" => None,
+            "error" => Some(Level::Error),
+            "warn" | "warning" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            other => {
+                return Err(format!(
+                    "Unknown log level \"{}\" (use off, error, warn, info, debug or trace)",
+                    other
+                ))
+            }
+        };
+        Ok(LevelFilter(level))
+    }
+}
+
+/// Configured level (0 = off)
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Debug mode: at least `Level::Debug` regardless of the configured level
+static DEBUG_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Serializes writes and rotation between threads
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Set the level from `cli` (`--log-level`), else `COKACDIR_LOG`. Call once at startup.
+pub fn init(cli: Option<LevelFilter>) {
+    let filter = cli.or_else(|| {
+        let value = std::env::var(ENV_VAR).ok()?;
+        match value.parse() {
+            Ok(filter) => Some(filter),
+            Err(e) => {
+                eprintln!("Warning: {}: {}", ENV_VAR, e);
+                None
+            }
+        }
+    });
+    if let Some(LevelFilter(level)) = filter {
+        LEVEL.store(level.map_or(0, |l| l as u8), Ordering::Relaxed);
+    }
+}
+
+/// Turn debug mode on or off (see module docs)
+pub fn set_debug_mode(on: bool) {
+    DEBUG_MODE.store(on, Ordering::Relaxed);
+}
+
+pub fn debug_mode() -> bool {
+    DEBUG_MODE.load(Ordering::Relaxed)
+}
+
+/// Most verbose level currently written
+pub fn max_level() -> Option<Level> {
+    let configured = LEVEL.load(Ordering::Relaxed);
+    let effective = if debug_mode() { configured.max(Level::Debug as u8) } else { configured };
+    Level::from_u8(effective)
+}
+
+pub fn enabled(level: Level) -> bool {
+    max_level().is_some_and(|max| level <= max)
+}
+
+pub fn log_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".cokacdir").join("logs"))
+}
+
+/// Write a record (use the macros, which skip formatting for disabled levels)
+pub fn log(level: Level, target: &str, args: fmt::Arguments) {
+    if !enabled(level) {
+        return;
+    }
+    let Some(dir) = log_dir() else { return };
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f");
+    let line = format!("{} {:<5} {}\n", timestamp, level.as_str(), args);
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _ = append(&dir, target, &line);
+}
+
+fn append(dir: &Path, target: &str, line: &str) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.log", target));
+    if fs::metadata(&path).map(|m| m.len() + line.len() as u64 > MAX_FILE_SIZE).unwrap_or(false) {
+        rotate(&path);
+    }
+    OpenOptions::new().create(true).append(true).open(&path)?.write_all(line.as_bytes())
+}
+
+/// `x.log` -> `x.log.1` -> ... -> `x.log.<MAX_ROTATED>` (dropped)
+fn rotate(path: &Path) {
+    let rotated = |n: usize| PathBuf::from(format!("{}.{}", path.display(), n));
+    let _ = fs::remove_file(rotated(MAX_ROTATED));
+    for n in (1..MAX_ROTATED).rev() {
+        let _ = fs::rename(rotated(n), rotated(n + 1));
+    }
+    let _ = fs::rename(path, rotated(1));
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($target:expr, $($arg:tt)*) => {
+        $crate::services::logging::log($crate::services::logging::Level::Error, $target, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($target:expr, $($arg:tt)*) => {
+        $crate::services::logging::log($crate::services::logging::Level::Warn, $target, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($target:expr, $($arg:tt)*) => {
+        $crate::services::logging::log($crate::services::logging::Level::Info, $target, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($target:expr, $($arg:tt)*) => {
+        $crate::services::logging::log($crate::services::logging::Level::Debug, $target, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! log_trace {
+    ($target:expr, $($arg:tt)*) => {
+        $crate::services::logging::log($crate::services::logging::Level::Trace, $target, format_args!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!("debug".parse::<LevelFilter>(), Ok(LevelFilter(Some(Level::Debug))));
+        assert_eq!(" WARN ".parse::<LevelFilter>(), Ok(LevelFilter(Some(Level::Warn))));
+        assert_eq!("off".parse::<LevelFilter>(), Ok(LevelFilter(None)));
+        assert!("loud".parse::<LevelFilter>().is_err());
+        assert!(Level::Error < Level::Trace);
+    }
+
+    #[test]
+    fn test_append_rotates_large_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("claude.log");
+        for n in 1..=MAX_ROTATED + 1 {
+            fs::write(&path, vec![b'x'; MAX_FILE_SIZE as usize]).unwrap();
+            append(dir.path(), "claude", &format!("line {}\n", n)).unwrap();
+        }
+        assert_eq!(fs::read_to_string(&path).unwrap(), format!("line {}\n", MAX_ROTATED + 1));
+        // The newest full file is .1; nothing beyond MAX_ROTATED is kept
+        assert_eq!(fs::metadata(dir.path().join("claude.log.1")).unwrap().len(), MAX_FILE_SIZE);
+        assert!(dir.path().join(format!("claude.log.{}", MAX_ROTATED)).exists());
+        assert!(!dir.path().join(format!("claude.log.{}", MAX_ROTATED + 1)).exists());
+    }
+
+    #[test]
+    fn test_small_files_are_appended() {
+        let dir = tempfile::tempdir().unwrap();
+        append(dir.path(), "cron", "a\n").unwrap();
+        append(dir.path(), "cron", "b\n").unwrap();
+        assert_eq!(fs::read_to_string(dir.path().join("cron.log")).unwrap(), "a\nb\n");
+        assert!(!dir.path().join("cron.log.1").exists());
+    }
+}
@@ -4,4 +4,40 @@ pub mod claude;
 pub mod remote;
 pub mod remote_transfer;
 pub mod dedup;
+pub mod checksum;
+pub mod xattr;
+pub mod elevate;
 pub mod telegram;
+pub mod ipc;
+pub mod tree;
+pub mod open_with;
+pub mod notify;
+pub mod watch;
+pub mod multiplexer;
+pub mod dir_cache;
+pub mod dir_sizes;
+pub mod thumbnails;
+pub mod image_export;
+pub mod clipboard_image;
+pub mod archive_profile;
+pub mod ai_approval;
+pub mod audit;
+pub mod bot_console;
+pub mod scheduler;
+pub mod webhook;
+pub mod email;
+pub mod archive;
+pub mod trash;
+pub mod secrets;
+pub mod disks;
+pub mod doctor;
+pub mod self_update;
+pub mod journal;
+pub mod frecency;
+pub mod logging;
+pub mod gitignore;
+pub mod dir_sync;
+pub mod cleanup;
+pub mod backup;
+pub mod timeline;
+pub mod git_status;
@@ -0,0 +1,141 @@
+//! Terminal multiplexer integration (tmux, zellij).
+//!
+//! When cokacdir runs inside a multiplexer, files can be opened in `$EDITOR`
+//! and shells started in a new split pane or window next to the file manager.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::utils::format::shell_escape;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multiplexer {
+    Tmux,
+    Zellij,
+}
+
+impl Multiplexer {
+    /// Detect the multiplexer from the environment (innermost wins when nested)
+    pub fn detect() -> Option<Self> {
+        if std::env::var_os("ZELLIJ").is_some() {
+            Some(Multiplexer::Zellij)
+        } else if std::env::var_os("TMUX").is_some() {
+            Some(Multiplexer::Tmux)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Multiplexer::Tmux => "tmux",
+            Multiplexer::Zellij => "zellij",
+        }
+    }
+}
+
+/// Where the new pane should appear
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneTarget {
+    /// Split next to the current pane
+    Split,
+    /// New window (tmux) / tab (zellij)
+    Window,
+}
+
+/// Editor command from $VISUAL / $EDITOR (fallback: vi)
+pub fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .find(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Build the multiplexer command line. `file` = None opens a shell.
+fn build_args(mux: Multiplexer, target: PaneTarget, cwd: &Path, file: Option<&Path>, editor: &str) -> Vec<String> {
+    let cwd = cwd.display().to_string();
+    match mux {
+        Multiplexer::Tmux => {
+            let mut args: Vec<String> = match target {
+                PaneTarget::Split => vec!["split-window".into(), "-h".into()],
+                PaneTarget::Window => vec!["new-window".into()],
+            };
+            args.push("-c".into());
+            args.push(cwd);
+            if let Some(file) = file {
+                // tmux runs a single argument through the shell, so $EDITOR may contain flags
+                args.push(format!("{} {}", editor, shell_escape(&file.display().to_string())));
+            }
+            args
+        }
+        Multiplexer::Zellij => match (file, target) {
+            (Some(file), PaneTarget::Split) => vec![
+                "edit".into(), "--direction".into(), "right".into(),
+                "--cwd".into(), cwd, file.display().to_string(),
+            ],
+            // zellij cannot start a command in a new tab from the CLI; use a floating pane
+            (Some(file), PaneTarget::Window) => vec![
+                "edit".into(), "--floating".into(),
+                "--cwd".into(), cwd, file.display().to_string(),
+            ],
+            (None, PaneTarget::Split) => vec![
+                "action".into(), "new-pane".into(), "--direction".into(), "right".into(),
+                "--cwd".into(), cwd,
+            ],
+            (None, PaneTarget::Window) => vec![
+                "action".into(), "new-tab".into(), "--cwd".into(), cwd,
+            ],
+        },
+    }
+}
+
+/// Open `file` in the editor (or a shell when None) in a new pane/window
+pub fn open(mux: Multiplexer, target: PaneTarget, cwd: &Path, file: Option<&Path>) -> Result<(), String> {
+    let args = build_args(mux, target, cwd, file, &editor_command());
+    let output = Command::new(mux.name())
+        .args(&args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", mux.name(), e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let err = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        Err(if err.is_empty() { format!("{} failed", mux.name()) } else { err })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_build_args_tmux() {
+        let cwd = PathBuf::from("/tmp/dir");
+        let file = PathBuf::from("/tmp/dir/my file.rs");
+        assert_eq!(
+            build_args(Multiplexer::Tmux, PaneTarget::Split, &cwd, Some(&file), "nvim"),
+            vec!["split-window", "-h", "-c", "/tmp/dir", "nvim '/tmp/dir/my file.rs'"]
+        );
+        assert_eq!(
+            build_args(Multiplexer::Tmux, PaneTarget::Window, &cwd, None, "nvim"),
+            vec!["new-window", "-c", "/tmp/dir"]
+        );
+    }
+
+    #[test]
+    fn test_build_args_zellij() {
+        let cwd = PathBuf::from("/tmp/dir");
+        let file = PathBuf::from("/tmp/dir/a.rs");
+        assert_eq!(
+            build_args(Multiplexer::Zellij, PaneTarget::Split, &cwd, Some(&file), "vi"),
+            vec!["edit", "--direction", "right", "--cwd", "/tmp/dir", "/tmp/dir/a.rs"]
+        );
+        assert_eq!(
+            build_args(Multiplexer::Zellij, PaneTarget::Window, &cwd, None, "vi"),
+            vec!["action", "new-tab", "--cwd", "/tmp/dir"]
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Desktop notifications for finished background jobs.
+//!
+//! - Linux/BSD: `notify-send`
+//! - macOS: `osascript -e 'display notification ...'`
+//!
+//! Missing tools are ignored silently; the optional terminal bell works everywhere.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::config::NotificationSettings;
+
+/// Send a notification according to the user's settings (non-blocking)
+pub fn send(settings: &NotificationSettings, title: &str, body: &str) {
+    if settings.bell {
+        let mut out = std::io::stdout();
+        let _ = out.write_all(b"\x07");
+        let _ = out.flush();
+    }
+    if settings.desktop {
+        desktop_notification(title, body);
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn desktop_notification(title: &str, body: &str) {
+    let script = format!(
+        "display notification {} with title {}",
+        applescript_string(body),
+        applescript_string(title)
+    );
+    spawn_detached(Command::new("osascript").arg("-e").arg(script));
+}
+
+#[cfg(not(target_os = "macos"))]
+fn desktop_notification(title: &str, body: &str) {
+    spawn_detached(Command::new("notify-send").arg("--app-name=cokacdir").arg(title).arg(body));
+}
+
+/// Quote a string as an AppleScript literal
+#[cfg(target_os = "macos")]
+fn applescript_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Start the command without touching the terminal and reap it in the background
+fn spawn_detached(command: &mut Command) {
+    let child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    if let Ok(mut child) = child {
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+    }
+}
@@ -0,0 +1,281 @@
+//! Application discovery for the "Open with" dialog.
+//!
+//! - Linux/BSD: freedesktop `.desktop` entries from the XDG data directories
+//! - macOS: application bundles known to LaunchServices (launched with `open -a`)
+//!
+//! Every entry is turned into a command template using the same conventions as
+//! `extension_handler`: `{{FILEPATH}}` is replaced with the file path, and a
+//! leading `@` runs the command in the background.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Where an "Open with" entry came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenWithKind {
+    /// Command previously chosen for this extension
+    Recent,
+    /// Installed desktop application
+    Application,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenWithEntry {
+    pub label: String,
+    /// Command template (extension_handler syntax)
+    pub command: String,
+    pub kind: OpenWithKind,
+    /// Application declares support for the file's MIME type
+    pub preferred: bool,
+}
+
+/// List installed applications for `path`. Applications that declare the
+/// file's MIME type come first, the rest follow alphabetically.
+pub fn list_applications(path: &Path) -> Vec<OpenWithEntry> {
+    let mut entries = platform_applications(path);
+    entries.sort_by(|a, b| {
+        b.preferred.cmp(&a.preferred)
+            .then_with(|| a.label.to_lowercase().cmp(&b.label.to_lowercase()))
+    });
+    let mut seen = HashSet::new();
+    entries.retain(|e| seen.insert(e.label.clone()));
+    entries
+}
+
+#[cfg(target_os = "macos")]
+fn platform_applications(_path: &Path) -> Vec<OpenWithEntry> {
+    let mut app_dirs = vec![
+        PathBuf::from("/Applications"),
+        PathBuf::from("/Applications/Utilities"),
+        PathBuf::from("/System/Applications"),
+        PathBuf::from("/System/Applications/Utilities"),
+    ];
+    if let Some(home) = dirs::home_dir() {
+        app_dirs.push(home.join("Applications"));
+    }
+
+    let mut entries = Vec::new();
+    for dir in app_dirs {
+        let Ok(read_dir) = std::fs::read_dir(&dir) else { continue };
+        for entry in read_dir.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().map(|e| e != "app").unwrap_or(true) {
+                continue;
+            }
+            let Some(name) = path.file_stem().map(|n| n.to_string_lossy().to_string()) else { continue };
+            entries.push(OpenWithEntry {
+                label: name,
+                command: format!("@open -a '{}' \"{{{{FILEPATH}}}}\"", path.display()),
+                kind: OpenWithKind::Application,
+                preferred: false,
+            });
+        }
+    }
+    entries
+}
+
+#[cfg(not(target_os = "macos"))]
+fn platform_applications(path: &Path) -> Vec<OpenWithEntry> {
+    let mime = detect_mime_type(path);
+    let mut entries = Vec::new();
+    let mut seen_ids = HashSet::new();
+
+    // Earlier directories take precedence (user entries override system ones)
+    for dir in application_dirs() {
+        let Ok(read_dir) = std::fs::read_dir(&dir) else { continue };
+        for entry in read_dir.filter_map(|e| e.ok()) {
+            let file_path = entry.path();
+            if file_path.extension().map(|e| e != "desktop").unwrap_or(true) {
+                continue;
+            }
+            let id = entry.file_name().to_string_lossy().to_string();
+            if !seen_ids.insert(id) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(&file_path) else { continue };
+            let Some(desktop) = parse_desktop_entry(&content) else { continue };
+
+            let preferred = mime.as_deref()
+                .map(|m| desktop.mime_types.iter().any(|t| mime_matches(t, m)))
+                .unwrap_or(false);
+            let template = exec_to_template(&desktop.exec);
+            // Terminal applications take over the terminal like foreground handlers
+            let command = if desktop.terminal { template } else { format!("@{}", template) };
+            entries.push(OpenWithEntry {
+                label: desktop.name,
+                command,
+                kind: OpenWithKind::Application,
+                preferred,
+            });
+        }
+    }
+    entries
+}
+
+/// `applications` directories in XDG lookup order
+#[cfg(not(target_os = "macos"))]
+fn application_dirs() -> Vec<PathBuf> {
+    let mut app_dirs = Vec::new();
+    match std::env::var("XDG_DATA_HOME") {
+        Ok(data_home) if !data_home.is_empty() => app_dirs.push(PathBuf::from(data_home).join("applications")),
+        _ => {
+            if let Some(home) = dirs::home_dir() {
+                app_dirs.push(home.join(".local/share/applications"));
+            }
+        }
+    }
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    for dir in data_dirs.split(':').filter(|d| !d.is_empty()) {
+        app_dirs.push(PathBuf::from(dir).join("applications"));
+    }
+    app_dirs
+}
+
+/// Ask xdg-mime (or file) for the MIME type of a file
+#[cfg(not(target_os = "macos"))]
+fn detect_mime_type(path: &Path) -> Option<String> {
+    let attempts: [(&str, &[&str]); 2] = [
+        ("xdg-mime", &["query", "filetype"]),
+        ("file", &["--mime-type", "-b"]),
+    ];
+    for (program, args) in attempts {
+        let output = std::process::Command::new(program)
+            .args(args)
+            .arg(path)
+            .stderr(std::process::Stdio::null())
+            .output();
+        if let Ok(output) = output {
+            let mime = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if output.status.success() && mime.contains('/') {
+                return Some(mime);
+            }
+        }
+    }
+    None
+}
+
+/// Match a desktop entry MIME pattern ("image/png", "image/*") against a type
+#[cfg(not(target_os = "macos"))]
+fn mime_matches(pattern: &str, mime: &str) -> bool {
+    match pattern.strip_suffix("/*") {
+        Some(major) => mime.split('/').next() == Some(major),
+        None => pattern.eq_ignore_ascii_case(mime),
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+#[derive(Debug, Clone, PartialEq)]
+struct DesktopEntry {
+    name: String,
+    exec: String,
+    mime_types: Vec<String>,
+    terminal: bool,
+}
+
+/// Parse the `[Desktop Entry]` group. Hidden, NoDisplay and non-application
+/// entries are skipped.
+#[cfg(not(target_os = "macos"))]
+fn parse_desktop_entry(content: &str) -> Option<DesktopEntry> {
+    let mut in_group = false;
+    let mut name = None;
+    let mut exec = None;
+    let mut mime_types = Vec::new();
+    let mut terminal = false;
+    let mut is_application = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_group = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_group || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else { continue };
+        let value = value.trim();
+        match key.trim() {
+            "Type" => is_application = value == "Application",
+            "Name" => name = Some(value.to_string()),
+            "Exec" => exec = Some(value.to_string()),
+            "MimeType" => {
+                mime_types = value.split(';').filter(|m| !m.is_empty()).map(|m| m.to_string()).collect();
+            }
+            "Terminal" => terminal = value == "true",
+            "NoDisplay" | "Hidden" if value == "true" => return None,
+            _ => {}
+        }
+    }
+
+    if !is_application {
+        return None;
+    }
+    Some(DesktopEntry { name: name?, exec: exec?, mime_types, terminal })
+}
+
+/// Convert a desktop entry Exec line into a command template.
+/// File/URL field codes become `"{{FILEPATH}}"`, other field codes are dropped.
+#[cfg(not(target_os = "macos"))]
+fn exec_to_template(exec: &str) -> String {
+    let mut out = String::new();
+    let mut has_file = false;
+    let mut chars = exec.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('%') => out.push('%'),
+            Some('f') | Some('F') | Some('u') | Some('U') => {
+                if !has_file {
+                    out.push_str("\"{{FILEPATH}}\"");
+                    has_file = true;
+                }
+            }
+            // %i, %c, %k and deprecated codes carry no file information
+            _ => {}
+        }
+    }
+    let mut template = out.split_whitespace().collect::<Vec<_>>().join(" ");
+    if !has_file {
+        template.push_str(" \"{{FILEPATH}}\"");
+    }
+    template
+}
+
+#[cfg(all(test, not(target_os = "macos")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_desktop_entry() {
+        let content = "[Desktop Entry]\nType=Application\nName=Image Viewer\nName[ko]=이미지\nExec=eog %U\nMimeType=image/png;image/jpeg;\nTerminal=false\n\n[Desktop Action new]\nName=New Window\nExec=eog --new-window\n";
+        let entry = parse_desktop_entry(content).unwrap();
+        assert_eq!(entry.name, "Image Viewer");
+        assert_eq!(entry.exec, "eog %U");
+        assert_eq!(entry.mime_types, vec!["image/png", "image/jpeg"]);
+        assert!(!entry.terminal);
+
+        assert!(parse_desktop_entry("[Desktop Entry]\nType=Application\nName=X\nExec=x\nNoDisplay=true\n").is_none());
+        assert!(parse_desktop_entry("[Desktop Entry]\nType=Link\nName=X\nURL=http://x\n").is_none());
+    }
+
+    #[test]
+    fn test_exec_to_template() {
+        assert_eq!(exec_to_template("eog %U"), "eog \"{{FILEPATH}}\"");
+        assert_eq!(exec_to_template("gimp-2.10 %f %F"), "gimp-2.10 \"{{FILEPATH}}\"");
+        assert_eq!(exec_to_template("app --icon %i --name %c"), "app --icon --name \"{{FILEPATH}}\"");
+        assert_eq!(exec_to_template("printf 100%% %f"), "printf 100% \"{{FILEPATH}}\"");
+    }
+
+    #[test]
+    fn test_mime_matches() {
+        assert!(mime_matches("image/png", "image/png"));
+        assert!(mime_matches("image/*", "image/jpeg"));
+        assert!(!mime_matches("image/*", "text/plain"));
+    }
+}
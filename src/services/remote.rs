@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use chrono::{DateTime, Local, TimeZone};
 use serde::{Deserialize, Serialize};
 use tokio::runtime::Runtime;
@@ -105,6 +108,25 @@ fn default_port() -> u16 {
     22
 }
 
+impl RemoteProfile {
+    /// Copy of the profile with password manager references (see `secrets`)
+    /// replaced by the secrets they point to
+    pub fn with_resolved_secrets(&self) -> Result<RemoteProfile, String> {
+        use crate::services::secrets;
+
+        let auth = match &self.auth {
+            RemoteAuth::Password { password } => RemoteAuth::Password {
+                password: secrets::resolve(password)?,
+            },
+            RemoteAuth::KeyFile { path, passphrase } => RemoteAuth::KeyFile {
+                path: path.clone(),
+                passphrase: passphrase.as_deref().map(secrets::resolve).transpose()?,
+            },
+        };
+        Ok(RemoteProfile { auth, ..self.clone() })
+    }
+}
+
 /// File entry from SFTP directory listing
 #[derive(Debug, Clone)]
 pub struct SftpFileEntry {
@@ -116,6 +138,33 @@ pub struct SftpFileEntry {
     pub permissions: String,
 }
 
+/// Sidecar of an interrupted download (`<local>.part.meta`): which remote
+/// file version the `.part` data belongs to and how much of it was written
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PartialDownload {
+    remote_path: String,
+    size: u64,
+    mtime: Option<u32>,
+    /// Bytes of the `.part` file known to be written
+    offset: u64,
+}
+
+impl PartialDownload {
+    /// Offset to resume from, or 0 when the remote file changed or the part is short
+    fn resume_offset(&self, remote_path: &str, size: u64, mtime: Option<u32>, part_len: u64) -> u64 {
+        if self.remote_path != remote_path || self.size != size || self.mtime != mtime {
+            return 0;
+        }
+        if self.offset > part_len || self.offset >= size {
+            return 0;
+        }
+        self.offset
+    }
+}
+
+/// Write the part metadata every this many bytes
+const PART_META_INTERVAL: u64 = 1024 * 1024;
+
 /// Connection status
 #[derive(Debug, Clone)]
 pub enum ConnectionStatus {
@@ -156,11 +205,96 @@ impl client::Handler for SshHandler {
     }
 }
 
+/// Minimum interval between throughput samples
+const RATE_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Bytes transferred to and from one server (all sessions and rsync runs),
+/// shown in the remote panel header
+#[derive(Debug, Default)]
+pub struct TransferStats {
+    downloaded: AtomicU64,
+    uploaded: AtomicU64,
+    rate: Mutex<RateSample>,
+}
+
+#[derive(Debug, Default)]
+struct RateSample {
+    at: Option<Instant>,
+    downloaded: u64,
+    uploaded: u64,
+    down_per_sec: u64,
+    up_per_sec: u64,
+}
+
+/// Throughput and totals of a server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bandwidth {
+    pub down_per_sec: u64,
+    pub up_per_sec: u64,
+    /// Bytes transferred in both directions since startup
+    pub total: u64,
+}
+
+impl TransferStats {
+    pub fn add_downloaded(&self, bytes: u64) {
+        self.downloaded.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn add_uploaded(&self, bytes: u64) {
+        self.uploaded.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Current throughput; rates are re-sampled at most once per RATE_SAMPLE_INTERVAL
+    pub fn sample(&self) -> Bandwidth {
+        let downloaded = self.downloaded.load(Ordering::Relaxed);
+        let uploaded = self.uploaded.load(Ordering::Relaxed);
+        let mut rate = match self.rate.lock() {
+            Ok(r) => r,
+            Err(e) => e.into_inner(),
+        };
+        let now = Instant::now();
+        match rate.at {
+            Some(at) if now.duration_since(at) < RATE_SAMPLE_INTERVAL => {}
+            Some(at) => {
+                let secs = now.duration_since(at).as_secs_f64();
+                rate.down_per_sec = (downloaded.saturating_sub(rate.downloaded) as f64 / secs) as u64;
+                rate.up_per_sec = (uploaded.saturating_sub(rate.uploaded) as f64 / secs) as u64;
+                rate.at = Some(now);
+                rate.downloaded = downloaded;
+                rate.uploaded = uploaded;
+            }
+            None => {
+                rate.at = Some(now);
+                rate.downloaded = downloaded;
+                rate.uploaded = uploaded;
+            }
+        }
+        Bandwidth {
+            down_per_sec: rate.down_per_sec,
+            up_per_sec: rate.up_per_sec,
+            total: downloaded + uploaded,
+        }
+    }
+}
+
+/// Shared transfer counters of the server `user@host:port`
+pub fn transfer_stats(user: &str, host: &str, port: u16) -> Arc<TransferStats> {
+    static STATS: OnceLock<Mutex<HashMap<String, Arc<TransferStats>>>> = OnceLock::new();
+    let key = format!("{}@{}:{}", user, host, port);
+    let mut stats = match STATS.get_or_init(Default::default).lock() {
+        Ok(s) => s,
+        Err(e) => e.into_inner(),
+    };
+    stats.entry(key).or_default().clone()
+}
+
 /// SFTP session wrapper around russh
 pub struct SftpSession {
     runtime: Runtime,
     ssh_handle: Option<client::Handle<SshHandler>>,
     sftp: Option<RusshSftpSession>,
+    /// Counters of the server this session is connected to
+    stats: Arc<TransferStats>,
 }
 
 impl std::fmt::Debug for SftpSession {
@@ -176,7 +310,7 @@ impl SftpSession {
     pub fn connect(profile: &RemoteProfile) -> Result<Self, String> {
         let runtime = Runtime::new().map_err(|e| format!("Failed to create runtime: {}", e))?;
 
-        let profile = profile.clone();
+        let profile = profile.with_resolved_secrets()?;
         let (ssh_handle, sftp) = runtime.block_on(async {
             Self::connect_async(&profile).await
         })?;
@@ -185,6 +319,7 @@ impl SftpSession {
             runtime,
             ssh_handle: Some(ssh_handle),
             sftp: Some(sftp),
+            stats: transfer_stats(&profile.user, &profile.host, profile.port),
         })
     }
 
@@ -417,55 +552,134 @@ impl SftpSession {
                 std::io::Write::write_all(&mut local_file, &buf[..n])
                     .map_err(|e| format!("Failed to write '{}': {}", local_path, e))?;
                 total += n as u64;
+                self.stats.add_downloaded(n as u64);
             }
             Ok(total)
         })
     }
 
-    /// Download remote file with progress callback and cancellation support
-    pub fn download_file_with_progress<F>(
+    /// Read at most `max_bytes` from the start of a remote file (for previews)
+    pub fn read_head(&self, remote_path: &str, max_bytes: usize) -> Result<Vec<u8>, String> {
+        let sftp = self.sftp.as_ref().ok_or("Not connected")?;
+        let remote_path = remote_path.to_string();
+
+        self.runtime.block_on(async {
+            let mut remote_file = sftp.open(&remote_path)
+                .await
+                .map_err(|e| format!("Failed to open '{}': {}", remote_path, e))?;
+
+            let data = read_up_to(&mut remote_file, max_bytes)
+                .await
+                .map_err(|e| format!("Failed to read '{}': {}", remote_path, e))?;
+            self.stats.add_downloaded(data.len() as u64);
+            Ok(data)
+        })
+    }
+
+    /// Download remote file with progress callback and cancellation support.
+    ///
+    /// Data goes to `<local_path>.part`, renamed to `local_path` when complete.
+    /// A cancelled or failed download keeps the part file and its
+    /// `<local_path>.part.meta` sidecar, so downloading the same (unchanged)
+    /// remote file again resumes from the recorded offset. `on_resume` is
+    /// called with that offset before any data is transferred.
+    pub fn download_file_with_progress<F, R>(
         &self,
         remote_path: &str,
         local_path: &str,
         file_size: u64,
         cancel_flag: &std::sync::atomic::AtomicBool,
         on_progress: F,
+        on_resume: R,
     ) -> Result<u64, String>
     where
         F: Fn(u64, u64),
+        R: FnOnce(u64),
     {
         let sftp = self.sftp.as_ref().ok_or("Not connected")?;
         let remote_path = remote_path.to_string();
         let local_path = local_path.to_string();
+        let part_path = format!("{}.part", local_path);
+        let meta_path = format!("{}.part.meta", local_path);
 
         self.runtime.block_on(async {
-            use tokio::io::AsyncReadExt;
+            use std::io::Write;
+            use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+            let mtime = sftp.metadata(&remote_path).await.ok().and_then(|m| m.mtime);
+            let mut meta = PartialDownload {
+                remote_path: remote_path.clone(),
+                size: file_size,
+                mtime,
+                offset: 0,
+            };
+            let part_len = std::fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+            let offset = std::fs::read_to_string(&meta_path)
+                .ok()
+                .and_then(|s| serde_json::from_str::<PartialDownload>(&s).ok())
+                .map_or(0, |prev| prev.resume_offset(&remote_path, file_size, mtime, part_len));
+            let write_meta = |meta: &PartialDownload| {
+                if let Ok(json) = serde_json::to_string(meta) {
+                    let _ = std::fs::write(&meta_path, json);
+                }
+            };
 
             let mut remote_file = sftp.open(&remote_path)
                 .await
                 .map_err(|e| format!("Failed to open '{}': {}", remote_path, e))?;
 
-            let mut local_file = std::fs::File::create(&local_path)
-                .map_err(|e| format!("Failed to create '{}': {}", local_path, e))?;
+            let mut local_file = std::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(offset == 0)
+                .open(&part_path)
+                .map_err(|e| format!("Failed to create '{}': {}", part_path, e))?;
+            if offset > 0 {
+                // 기록된 offset 이후의 데이터는 버리고 이어받기
+                local_file.set_len(offset)
+                    .and_then(|_| std::io::Seek::seek(&mut local_file, std::io::SeekFrom::Start(offset)))
+                    .map_err(|e| format!("Failed to resume '{}': {}", part_path, e))?;
+                remote_file.seek(std::io::SeekFrom::Start(offset))
+                    .await
+                    .map_err(|e| format!("Failed to seek '{}': {}", remote_path, e))?;
+                on_resume(offset);
+            }
+            meta.offset = offset;
+            write_meta(&meta);
 
             let mut buf = vec![0u8; 64 * 1024];
-            let mut total = 0u64;
+            let mut total = offset;
             loop {
                 if cancel_flag.load(std::sync::atomic::Ordering::Relaxed) {
-                    // 취소 시 임시 파일 삭제
-                    drop(local_file);
-                    let _ = std::fs::remove_file(&local_path);
+                    // 취소 시 .part는 남겨 다음 다운로드에서 이어받는다
+                    let _ = local_file.flush();
+                    meta.offset = total;
+                    write_meta(&meta);
                     return Err("Cancelled".to_string());
                 }
-                let n = remote_file.read(&mut buf)
-                    .await
-                    .map_err(|e| format!("Failed to read '{}': {}", remote_path, e))?;
+                let n = match remote_file.read(&mut buf).await {
+                    Ok(n) => n,
+                    Err(e) => {
+                        meta.offset = total;
+                        write_meta(&meta);
+                        return Err(format!("Failed to read '{}': {}", remote_path, e));
+                    }
+                };
                 if n == 0 { break; }
-                std::io::Write::write_all(&mut local_file, &buf[..n])
-                    .map_err(|e| format!("Failed to write '{}': {}", local_path, e))?;
+                local_file.write_all(&buf[..n])
+                    .map_err(|e| format!("Failed to write '{}': {}", part_path, e))?;
                 total += n as u64;
+                self.stats.add_downloaded(n as u64);
+                if total / PART_META_INTERVAL != (total - n as u64) / PART_META_INTERVAL {
+                    meta.offset = total;
+                    write_meta(&meta);
+                }
                 on_progress(total, file_size);
             }
+            drop(local_file);
+            std::fs::rename(&part_path, &local_path)
+                .map_err(|e| format!("Failed to create '{}': {}", local_path, e))?;
+            let _ = std::fs::remove_file(&meta_path);
             Ok(total)
         })
     }
@@ -496,6 +710,7 @@ impl SftpSession {
                     .await
                     .map_err(|e| format!("Failed to write '{}': {}", remote_path, e))?;
                 total += n as u64;
+                self.stats.add_uploaded(n as u64);
             }
             remote_file.shutdown()
                 .await
@@ -528,9 +743,58 @@ impl Drop for SftpSession {
     }
 }
 
-/// Parse user@host:/path format
+/// Parse sftp://[user@]host[:port][/path] (ssh:// is accepted too)
+/// Returns (user, host, port, path); user and port are None when omitted
+fn parse_sftp_url(input: &str) -> Option<(Option<String>, String, Option<u16>, String)> {
+    let rest = input.strip_prefix("sftp://").or_else(|| input.strip_prefix("ssh://"))?;
+    let (authority, path) = match rest.find('/') {
+        Some(pos) => (&rest[..pos], percent_decode(&rest[pos..])),
+        None => (rest, "/".to_string()),
+    };
+    let (user, host_port) = match authority.rfind('@') {
+        Some(pos) => (Some(percent_decode(&authority[..pos])), &authority[pos + 1..]),
+        None => (None, authority),
+    };
+    if user.as_deref() == Some("") {
+        return None;
+    }
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port.parse::<u16>().ok()?)),
+        None => (host_port, None),
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some((user, host.to_string(), port, path))
+}
+
+/// Decode %XX escapes of a URL part (invalid escapes are kept as is)
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(b) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Parse user@host:/path format (or an sftp://user@host[:port]/path URL)
 /// Returns (user, host, port, path) if matched
 pub fn parse_remote_path(input: &str) -> Option<(String, String, u16, String)> {
+    if let Some((user, host, port, path)) = parse_sftp_url(input) {
+        return Some((user?, host, port.unwrap_or(22), path));
+    }
+
     // Format: user@host:/path or user@host:port:/path
     let at_pos = input.find('@')?;
     let user = input[..at_pos].to_string();
@@ -590,6 +854,21 @@ pub fn format_remote_display(profile: &RemoteProfile, path: &str) -> String {
     }
 }
 
+/// Parse a remote location typed in the Goto dialog: user@host:/path, an
+/// sftp:// URL, or sftp://<profile name or host>/path using a saved profile
+/// for the user and port
+pub fn resolve_remote_location(input: &str, profiles: &[RemoteProfile]) -> Option<(String, String, u16, String)> {
+    if let Some(parsed) = parse_remote_path(input) {
+        return Some(parsed);
+    }
+    let (_, alias, port, path) = parse_sftp_url(input)?;
+    let profile = profiles
+        .iter()
+        .find(|p| p.name == alias)
+        .or_else(|| profiles.iter().find(|p| p.host == alias && port.map_or(true, |port| p.port == port)))?;
+    Some((profile.user.clone(), profile.host.clone(), port.unwrap_or(profile.port), path))
+}
+
 /// Find matching profile from profiles list by user, host, port
 pub fn find_matching_profile<'a>(
     profiles: &'a [RemoteProfile],
@@ -600,6 +879,34 @@ pub fn find_matching_profile<'a>(
     profiles.iter().find(|p| p.user == user && p.host == host && p.port == port)
 }
 
+/// Read until `max_bytes` are collected or the reader hits EOF. SFTP reads
+/// return at most one packet, so a single read is usually short.
+async fn read_up_to<R: tokio::io::AsyncRead + Unpin>(reader: &mut R, max_bytes: usize) -> std::io::Result<Vec<u8>> {
+    use tokio::io::AsyncReadExt;
+
+    let mut data = vec![0u8; max_bytes];
+    let mut total = 0;
+    while total < max_bytes {
+        let n = reader.read(&mut data[total..]).await?;
+        if n == 0 {
+            break;
+        }
+        total += n;
+    }
+    data.truncate(total);
+    Ok(data)
+}
+
+/// Drop a UTF-8 sequence cut off at the end of a partial read, so a text
+/// preview is not mistaken for binary. Invalid bytes elsewhere are kept.
+pub fn trim_partial_utf8(bytes: &mut Vec<u8>) {
+    if let Err(e) = std::str::from_utf8(bytes) {
+        if e.error_len().is_none() {
+            bytes.truncate(e.valid_up_to());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -629,9 +936,126 @@ mod tests {
         assert!(parse_remote_path("user@:/path").is_none());
     }
 
+    #[test]
+    fn test_parse_sftp_url() {
+        assert_eq!(
+            parse_remote_path("sftp://admin@server:2222/var/log"),
+            Some(("admin".to_string(), "server".to_string(), 2222, "/var/log".to_string()))
+        );
+        assert_eq!(
+            parse_remote_path("ssh://user@host"),
+            Some(("user".to_string(), "host".to_string(), 22, "/".to_string()))
+        );
+        assert_eq!(
+            parse_remote_path("sftp://user@host/my%20docs"),
+            Some(("user".to_string(), "host".to_string(), 22, "/my docs".to_string()))
+        );
+        assert!(parse_remote_path("sftp://host/path").is_none());
+        assert!(parse_remote_path("sftp://user@host:abc/path").is_none());
+
+        let profiles = vec![RemoteProfile {
+            name: "web".to_string(),
+            host: "web.example.com".to_string(),
+            port: 2200,
+            user: "deploy".to_string(),
+            auth: RemoteAuth::Password { password: String::new() },
+            default_path: String::new(),
+        }];
+        let expected = Some(("deploy".to_string(), "web.example.com".to_string(), 2200, "/srv".to_string()));
+        assert_eq!(resolve_remote_location("sftp://web/srv", &profiles), expected);
+        assert_eq!(resolve_remote_location("sftp://web.example.com/srv", &profiles), expected);
+        assert!(resolve_remote_location("sftp://unknown/srv", &profiles).is_none());
+        assert!(resolve_remote_location("/local/path", &profiles).is_none());
+    }
+
     #[test]
     fn test_format_remote_permissions() {
         assert_eq!(format_remote_permissions(0o755), "rwxr-xr-x");
         assert_eq!(format_remote_permissions(0o644), "rw-r--r--");
     }
+
+    #[test]
+    fn test_transfer_stats_shared_per_server() {
+        let a = transfer_stats("u", "stats-test.example", 22);
+        a.add_downloaded(100);
+        transfer_stats("u", "stats-test.example", 22).add_uploaded(50);
+        assert_eq!(a.sample().total, 150);
+        // First sample only starts the rate window
+        assert_eq!(a.sample().down_per_sec, 0);
+        assert_eq!(transfer_stats("u", "stats-test.example", 2222).sample().total, 0);
+    }
+
+    #[test]
+    fn test_partial_download_resume_offset() {
+        let meta = PartialDownload {
+            remote_path: "/data/big.iso".to_string(),
+            size: 1000,
+            mtime: Some(42),
+            offset: 420,
+        };
+        assert_eq!(meta.resume_offset("/data/big.iso", 1000, Some(42), 500), 420);
+        // Remote file replaced or changed
+        assert_eq!(meta.resume_offset("/data/other.iso", 1000, Some(42), 500), 0);
+        assert_eq!(meta.resume_offset("/data/big.iso", 1200, Some(42), 500), 0);
+        assert_eq!(meta.resume_offset("/data/big.iso", 1000, Some(43), 500), 0);
+        // Part file shorter than the recorded offset
+        assert_eq!(meta.resume_offset("/data/big.iso", 1000, Some(42), 100), 0);
+    }
+
+    /// Returns at most `chunk` bytes per read, like an SFTP file handle
+    struct ChunkedReader {
+        data: Vec<u8>,
+        pos: usize,
+        chunk: usize,
+    }
+
+    impl tokio::io::AsyncRead for ChunkedReader {
+        fn poll_read(
+            mut self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            let start = self.pos;
+            let n = self.chunk.min(self.data.len() - start).min(buf.remaining());
+            buf.put_slice(&self.data[start..start + n]);
+            self.pos += n;
+            std::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn test_read_up_to_collects_short_reads() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let data: Vec<u8> = (0..100u8).collect();
+
+        let mut reader = ChunkedReader { data: data.clone(), pos: 0, chunk: 7 };
+        let head = runtime.block_on(read_up_to(&mut reader, 50)).unwrap();
+        assert_eq!(head, data[..50]);
+
+        // File shorter than the limit: everything up to EOF
+        let mut reader = ChunkedReader { data: data.clone(), pos: 0, chunk: 7 };
+        let head = runtime.block_on(read_up_to(&mut reader, 1000)).unwrap();
+        assert_eq!(head, data);
+
+        let mut reader = ChunkedReader { data: Vec::new(), pos: 0, chunk: 7 };
+        assert!(runtime.block_on(read_up_to(&mut reader, 10)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_trim_partial_utf8() {
+        // "가" is 3 bytes; cut after the first 2
+        let mut bytes = b"ab".to_vec();
+        bytes.extend_from_slice(&"가".as_bytes()[..2]);
+        trim_partial_utf8(&mut bytes);
+        assert_eq!(bytes, b"ab");
+
+        let mut complete = "ab가".as_bytes().to_vec();
+        trim_partial_utf8(&mut complete);
+        assert_eq!(complete, "ab가".as_bytes());
+
+        // Binary data is left alone
+        let mut binary = vec![0xff, 0x00, 0x41, 0xe0];
+        trim_partial_utf8(&mut binary);
+        assert_eq!(binary, vec![0xff, 0x00, 0x41, 0xe0]);
+    }
 }
@@ -8,7 +8,7 @@ use tokio::runtime::Runtime;
 use russh::{client, ChannelMsg, Disconnect};
 
 use crate::services::file_ops::ProgressMessage;
-use crate::services::remote::{RemoteAuth, RemoteProfile, SshHandler};
+use crate::services::remote::{self, RemoteAuth, RemoteProfile, SshHandler};
 
 /// Transfer direction
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -57,7 +57,7 @@ impl SshExec {
         let runtime = Runtime::new()
             .map_err(|e| format!("Failed to create runtime: {}", e))?;
 
-        let profile = profile.clone();
+        let profile = profile.with_resolved_secrets()?;
         let handle = runtime.block_on(async {
             let config = client::Config {
                 inactivity_timeout: Some(std::time::Duration::from_secs(60)),
@@ -258,12 +258,27 @@ fn transfer_rsync(
     let ssh_option = build_ssh_option(&config.profile);
     let total_files = config.source_files.len();
     let mut completed_files: usize = 0;
+    let stats = remote::transfer_stats(&config.profile.user, &config.profile.host, config.profile.port);
+    // Count the bytes rsync reports toward the server's bandwidth indicator
+    // (progress restarts at 0 for each file of a directory)
+    let count_bytes = |transferred: u64, counted: &mut u64| {
+        if transferred < *counted {
+            *counted = 0;
+        }
+        let delta = transferred - *counted;
+        *counted = transferred;
+        match config.direction {
+            TransferDirection::LocalToRemote => stats.add_uploaded(delta),
+            TransferDirection::RemoteToLocal => stats.add_downloaded(delta),
+        }
+    };
 
     // Prepare password auth mechanism
-    let needs_password = matches!(&config.profile.auth, RemoteAuth::Password { .. });
+    let profile = config.profile.with_resolved_secrets()?;
+    let needs_password = matches!(&profile.auth, RemoteAuth::Password { .. });
     let use_sshpass = needs_password && has_sshpass();
     let askpass_script = if needs_password && !use_sshpass {
-        if let RemoteAuth::Password { ref password } = config.profile.auth {
+        if let RemoteAuth::Password { ref password } = profile.auth {
             Some(create_askpass_script(password)?)
         } else {
             None
@@ -306,7 +321,7 @@ fn transfer_rsync(
 
         // Apply password auth
         let mut cmd = if use_sshpass {
-            if let RemoteAuth::Password { ref password } = config.profile.auth {
+            if let RemoteAuth::Password { ref password } = profile.auth {
                 let mut sshpass_cmd = Command::new("sshpass");
                 sshpass_cmd.arg("-p").arg(password);
                 let program = cmd.get_program().to_string_lossy().to_string();
@@ -339,6 +354,7 @@ fn transfer_rsync(
         // rsync --progress uses \r (carriage return) to update progress in-place,
         // so we read byte-by-byte and split on both \r and \n.
         if let Some(stdout) = child.stdout.take() {
+            let mut counted = 0u64;
             let mut reader = BufReader::new(stdout);
             let mut line_buf = Vec::new();
             let mut byte_buf = [0u8; 1];
@@ -358,6 +374,7 @@ fn transfer_rsync(
                             if !line_buf.is_empty() {
                                 let line = String::from_utf8_lossy(&line_buf).to_string();
                                 if let Some(progress) = parse_rsync_progress(&line) {
+                                    count_bytes(progress.0, &mut counted);
                                     let _ = tx.send(ProgressMessage::FileProgress(progress.0, progress.1));
                                 }
                                 line_buf.clear();
@@ -373,6 +390,7 @@ fn transfer_rsync(
             if !line_buf.is_empty() {
                 let line = String::from_utf8_lossy(&line_buf).to_string();
                 if let Some(progress) = parse_rsync_progress(&line) {
+                    count_bytes(progress.0, &mut counted);
                     let _ = tx.send(ProgressMessage::FileProgress(progress.0, progress.1));
                 }
             }
@@ -0,0 +1,415 @@
+//! Standalone schedule engine (`cokacdir --scheduler`).
+//!
+//! Schedules registered with `--cron` without `--chat`/`--key` are stored
+//! under the bot key [`LOCAL_BOT_KEY`] and are run by this daemon instead of
+//! a Telegram bot, so cron-style jobs also work on machines where no bot is
+//! configured. A prompt starting with `!` is run as a shell command,
+//! `@backup <name>` runs the backup job of that name (see [`backup`]);
+//! anything else is sent to Claude in the schedule's directory.
+//!
+//! Every run is appended to `~/.cokacdir/logs/scheduler_history.jsonl`, AI runs
+//! are also saved as AI sessions (resumable from the AI screen), and the result
+//! is sent to the webhooks of `notifications.webhooks` and emailed when `email`
+//! is configured in settings.json.
+
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::audit::summarize;
+use crate::services::backup;
+use crate::services::claude;
+use crate::services::email::{self, EmailAttachment, EmailSettings};
+use crate::services::telegram::{self, ScheduleEntryData};
+use crate::services::webhook::{self, Webhook, WebhookEvent};
+use crate::ui::ai_screen::{self, HistoryItem, HistoryType, SessionData};
+
+/// Bot key of schedules that are not tied to a Telegram bot
+pub const LOCAL_BOT_KEY: &str = "local";
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Longest job output kept in the history
+const MAX_OUTPUT_CHARS: usize = 20_000;
+
+/// Longest job output sent to webhooks
+const MAX_WEBHOOK_OUTPUT_CHARS: usize = 3_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    Ai,
+    Shell,
+    Backup,
+}
+
+/// Result of one scheduled run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobResult {
+    pub id: String,
+    pub prompt: String,
+    pub kind: JobKind,
+    pub current_path: String,
+    /// Local time, "%Y-%m-%d %H:%M:%S"
+    pub started_at: String,
+    pub finished_at: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    /// AI response, shell stdout+stderr or the error message
+    pub output: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+/// Command of a `!command` prompt
+fn shell_command(prompt: &str) -> Option<&str> {
+    prompt.trim_start().strip_prefix('!').map(str::trim).filter(|c| !c.is_empty())
+}
+
+fn truncate_chars(text: &str, max: usize) -> String {
+    if text.chars().count() > max {
+        let mut short: String = text.chars().take(max).collect();
+        short.push('…');
+        short
+    } else {
+        text.to_string()
+    }
+}
+
+/// Run a schedule entry in its directory and wait for it to finish
+pub fn run_job(entry: &ScheduleEntryData) -> JobResult {
+    let started_at = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let timer = Instant::now();
+
+    let (kind, success, output, session_id) = match shell_command(&entry.prompt) {
+        _ if backup::job_name_from_prompt(&entry.prompt).is_some() => {
+            let name = backup::job_name_from_prompt(&entry.prompt).unwrap_or_default();
+            let (success, summary) = backup::run_named(name);
+            (JobKind::Backup, success, summary, None)
+        }
+        Some(cmd) => {
+            let result = Command::new("sh")
+                .arg("-c")
+                .arg(cmd)
+                .current_dir(&entry.current_path)
+                .stdin(Stdio::null())
+                .output();
+            match result {
+                Ok(out) => {
+                    let mut text = String::from_utf8_lossy(&out.stdout).into_owned();
+                    let stderr = String::from_utf8_lossy(&out.stderr);
+                    if !stderr.is_empty() {
+                        if !text.is_empty() && !text.ends_with('\n') {
+                            text.push('\n');
+                        }
+                        text.push_str(&stderr);
+                    }
+                    (JobKind::Shell, out.status.success(), text, None)
+                }
+                Err(e) => (JobKind::Shell, false, format!("Failed to run sh: {}", e), None),
+            }
+        }
+        None => {
+            let response = claude::execute_command(&entry.prompt, None, &entry.current_path, None, None);
+            let output = if response.success {
+                response.response.unwrap_or_default()
+            } else {
+                response.error.unwrap_or_else(|| "Unknown error".to_string())
+            };
+            (JobKind::Ai, response.success, output, response.session_id)
+        }
+    };
+
+    JobResult {
+        id: entry.id.clone(),
+        prompt: entry.prompt.clone(),
+        kind,
+        current_path: entry.current_path.clone(),
+        started_at,
+        finished_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        duration_ms: timer.elapsed().as_millis() as u64,
+        success,
+        output: truncate_chars(&output, MAX_OUTPUT_CHARS),
+        session_id,
+    }
+}
+
+/// Path of the run history: ~/.cokacdir/logs/scheduler_history.jsonl
+pub fn history_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".cokacdir").join("logs").join("scheduler_history.jsonl"))
+}
+
+/// Append a result to the history at `path` (created with mode 0600)
+pub fn append_history(path: &Path, result: &JobResult) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(result)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let mut file = OpenOptions::new().create(true).append(true).mode(0o600).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/// Save an AI run as an AI session so it can be resumed from the AI screen
+fn save_ai_session(result: &JobResult) {
+    let Some(ref session_id) = result.session_id else { return };
+    // Security: whitelist session_id to alphanumeric, hyphens, underscores only
+    if !session_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return;
+    }
+    let Some(dir) = ai_screen::ai_sessions_dir() else { return };
+    if fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+    let session = SessionData {
+        session_id: session_id.clone(),
+        history: vec![
+            HistoryItem { item_type: HistoryType::User, content: result.prompt.clone(), tool_calls: Vec::new() },
+            HistoryItem {
+                item_type: if result.success { HistoryType::Assistant } else { HistoryType::Error },
+                content: result.output.clone(),
+                tool_calls: Vec::new(),
+            },
+        ],
+        current_path: result.current_path.clone(),
+        created_at: result.finished_at.clone(),
+    };
+    if let Ok(json) = serde_json::to_string_pretty(&session) {
+        let _ = fs::write(dir.join(format!("{}.json", session_id)), json);
+    }
+}
+
+/// Webhook notification of a finished job
+pub fn webhook_event(result: &JobResult) -> WebhookEvent {
+    let status = if result.success { "finished" } else { "failed" };
+    WebhookEvent {
+        event: "schedule_finished",
+        title: format!("Schedule {} {}: {}", result.id, status, summarize(&result.prompt)),
+        body: truncate_chars(&result.output, MAX_WEBHOOK_OUTPUT_CHARS),
+        success: result.success,
+        details: serde_json::json!({
+            "id": result.id,
+            "prompt": result.prompt,
+            "kind": result.kind,
+            "path": result.current_path,
+            "started_at": result.started_at,
+            "finished_at": result.finished_at,
+            "duration_ms": result.duration_ms,
+            "session_id": result.session_id,
+        }),
+    }
+}
+
+/// Where results are sent besides the history
+pub struct Delivery {
+    pub webhooks: Vec<Webhook>,
+    pub email: Option<EmailSettings>,
+}
+
+/// Subject, body and attachments of the result email
+fn email_parts(settings: &EmailSettings, result: &JobResult, started: SystemTime) -> (String, String, Vec<EmailAttachment>) {
+    let status = if result.success { "done" } else { "failed" };
+    let (date, time) = result.finished_at.split_once(' ').unwrap_or((result.finished_at.as_str(), ""));
+    let prompt = summarize(&result.prompt);
+    let subject = email::render_subject(
+        &settings.subject,
+        &[
+            ("id", result.id.as_str()),
+            ("prompt", prompt.as_str()),
+            ("status", status),
+            ("date", date),
+            ("time", time),
+            ("path", result.current_path.as_str()),
+        ],
+    );
+
+    let mut body = format!(
+        "Schedule: {}\nPrompt: {}\nDirectory: {}\nStarted: {}\nFinished: {} ({:.1}s)\nStatus: {}\n\n{}",
+        result.id,
+        result.prompt,
+        result.current_path,
+        result.started_at,
+        result.finished_at,
+        result.duration_ms as f64 / 1000.0,
+        status,
+        truncate_chars(&result.output, MAX_WEBHOOK_OUTPUT_CHARS),
+    );
+    let mut attachments = Vec::new();
+    if settings.attach_output {
+        attachments.push(EmailAttachment::text(&format!("{}-output.txt", result.id), &result.output));
+    }
+    let (files, skipped) = email::collect_attachments(Path::new(&result.current_path), &settings.attach_files, started);
+    attachments.extend(files);
+    if !skipped.is_empty() {
+        body.push_str(&format!("\n\nNot attached (over {} MB): {}", email::MAX_ATTACHMENT_BYTES / 1024 / 1024, skipped.join(", ")));
+    }
+    (subject, body, attachments)
+}
+
+/// Run a due job on its own thread and record the result
+fn spawn_job(entry: ScheduleEntryData, delivery: Arc<Delivery>, running: Arc<Mutex<HashSet<String>>>) {
+    // One-time schedules are removed before running (as the bot does), so a
+    // crash during the run does not repeat them
+    let one_time = entry.schedule_type == "absolute" || entry.once == Some(true);
+    if one_time {
+        telegram::delete_schedule_entry_pub(&entry.id);
+    }
+    if let Ok(mut set) = running.lock() {
+        set.insert(entry.id.clone());
+    }
+    let time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+    println!("[{}] ▶ {} {}", time, entry.id, summarize(&entry.prompt));
+
+    thread::spawn(move || {
+        let started = SystemTime::now();
+        let result = run_job(&entry);
+        if !one_time {
+            telegram::update_schedule_after_run_pub(&entry);
+        }
+        if let Some(path) = history_path() {
+            if let Err(e) = append_history(&path, &result) {
+                eprintln!("Warning: cannot write {}: {}", path.display(), e);
+            }
+        }
+        save_ai_session(&result);
+
+        let time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+        let seconds = result.duration_ms as f64 / 1000.0;
+        if result.success {
+            println!("[{}] ✓ {} done ({:.1}s)", time, result.id, seconds);
+        } else {
+            eprintln!("[{}] ✗ {} failed ({:.1}s): {}", time, result.id, seconds, summarize(&result.output));
+        }
+        for err in webhook::post_all(&delivery.webhooks, &webhook_event(&result)) {
+            eprintln!("[{}] ⚠ {} webhook failed: {}", time, result.id, err);
+        }
+        if let Some(ref settings) = delivery.email {
+            if !settings.only_failures || !result.success {
+                let (subject, body, attachments) = email_parts(settings, &result, started);
+                if let Err(e) = email::send(settings, &subject, &body, attachments) {
+                    eprintln!("[{}] ⚠ {} email failed: {}", time, result.id, e);
+                }
+            }
+        }
+
+        if let Ok(mut set) = running.lock() {
+            set.remove(&result.id);
+        }
+    });
+}
+
+/// Run local schedules until the process is killed
+pub fn run_daemon(delivery: Delivery) {
+    let entries = telegram::list_schedule_entries_pub(LOCAL_BOT_KEY, None);
+    println!("Scheduler started: {} local schedule(s)", entries.len());
+    for entry in &entries {
+        println!("  {}  {:<20} {}", entry.id, entry.schedule, summarize(&entry.prompt));
+    }
+    if let Some(path) = history_path() {
+        println!("History: {}", path.display());
+    }
+    if !delivery.webhooks.is_empty() {
+        println!("Webhooks: {}", delivery.webhooks.len());
+    }
+    if let Some(ref settings) = delivery.email {
+        println!("Email: {} via {}:{}", settings.to.join(", "), settings.smtp_host, settings.smtp_port);
+    }
+
+    let delivery = Arc::new(delivery);
+    let running: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+    // Schedules whose directory is missing are reported once
+    let mut missing_paths: HashSet<String> = HashSet::new();
+    loop {
+        for entry in telegram::list_schedule_entries_pub(LOCAL_BOT_KEY, None) {
+            let is_running = running.lock().map(|set| set.contains(&entry.id)).unwrap_or(false);
+            if is_running || !telegram::should_trigger_pub(&entry) {
+                continue;
+            }
+            if !Path::new(&entry.current_path).is_dir() {
+                if missing_paths.insert(entry.id.clone()) {
+                    let time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+                    eprintln!("[{}] ⚠ {} skipped: path not found: {}", time, entry.id, entry.current_path);
+                }
+                continue;
+            }
+            missing_paths.remove(&entry.id);
+            spawn_job(entry, delivery.clone(), running.clone());
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(prompt: &str, dir: &Path) -> ScheduleEntryData {
+        ScheduleEntryData {
+            id: "0000ABCD".to_string(),
+            chat_id: 0,
+            bot_key: LOCAL_BOT_KEY.to_string(),
+            current_path: dir.display().to_string(),
+            prompt: prompt.to_string(),
+            schedule: "0 9 * * *".to_string(),
+            schedule_type: "cron".to_string(),
+            once: Some(false),
+            last_run: None,
+            created_at: "2026-01-01 00:00:00".to_string(),
+            context_summary: None,
+        }
+    }
+
+    #[test]
+    fn test_shell_job_and_history() {
+        assert_eq!(shell_command("!ls -la"), Some("ls -la"));
+        assert_eq!(shell_command("  ! df -h "), Some("df -h"));
+        assert_eq!(shell_command("!"), None);
+        assert_eq!(shell_command("summarize the logs"), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("marker.txt"), "x").unwrap();
+
+        let ok = run_job(&entry("!ls", dir.path()));
+        assert_eq!(ok.kind, JobKind::Shell);
+        assert!(ok.success);
+        assert!(ok.output.contains("marker.txt"));
+
+        let failed = run_job(&entry("!echo oops >&2; exit 3", dir.path()));
+        assert!(!failed.success);
+        assert_eq!(failed.output.trim(), "oops");
+
+        let path = dir.path().join("logs").join("scheduler_history.jsonl");
+        append_history(&path, &ok).unwrap();
+        append_history(&path, &failed).unwrap();
+        let lines: Vec<JobResult> = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines, vec![ok.clone(), failed]);
+
+        let event = webhook_event(&ok);
+        assert_eq!(event.event, "schedule_finished");
+        assert!(event.title.starts_with("Schedule 0000ABCD finished"));
+        assert_eq!(event.details["kind"], "shell");
+        assert!(event.success);
+
+        let settings: EmailSettings = serde_json::from_str(
+            r#"{"smtp_host": "localhost", "from": "a@example.com", "to": ["b@example.com"], "subject": "{status} {id} on {date}"}"#,
+        )
+        .unwrap();
+        let (subject, body, attachments) = email_parts(&settings, &ok, SystemTime::now());
+        assert!(subject.starts_with("done 0000ABCD on "));
+        assert!(body.contains("marker.txt"));
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].file_name, "0000ABCD-output.txt");
+    }
+}
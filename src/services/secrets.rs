@@ -0,0 +1,137 @@
+//! Secrets referenced from settings instead of stored in them.
+//!
+//! A password or passphrase field may hold a reference that is looked up
+//! each time the secret is needed:
+//!
+//! - `pass:<entry>` — first line of `pass show <entry>`
+//! - `op://<vault>/<item>/<field>` — `op read` (1Password CLI)
+//! - `keychain:<service>/<account>` — the OS keychain (`security` on macOS,
+//!   `secret-tool` on Linux)
+//!
+//! Any other value is the secret itself.
+
+use std::process::{Command, Stdio};
+
+/// A parsed secret reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SecretRef<'a> {
+    Pass(&'a str),
+    OnePassword(&'a str),
+    Keychain { service: &'a str, account: &'a str },
+}
+
+impl<'a> SecretRef<'a> {
+    fn parse(value: &'a str) -> Option<Self> {
+        if let Some(entry) = value.strip_prefix("pass:") {
+            return Some(Self::Pass(entry));
+        }
+        if value.starts_with("op://") {
+            return Some(Self::OnePassword(value));
+        }
+        if let Some(rest) = value.strip_prefix("keychain:") {
+            // The account is everything after the last '/' (services may contain '/')
+            let (service, account) = rest.rsplit_once('/').unwrap_or((rest, ""));
+            return Some(Self::Keychain { service, account });
+        }
+        None
+    }
+}
+
+/// Whether `value` is a reference rather than a literal secret
+pub fn is_reference(value: &str) -> bool {
+    SecretRef::parse(value).is_some()
+}
+
+/// The secret `value` refers to, or `value` itself when it is not a reference
+pub fn resolve(value: &str) -> Result<String, String> {
+    let Some(secret_ref) = SecretRef::parse(value) else {
+        return Ok(value.to_string());
+    };
+    match secret_ref {
+        SecretRef::Pass(entry) => {
+            let output = run("pass", &["show", entry])?;
+            Ok(output.lines().next().unwrap_or_default().to_string())
+        }
+        SecretRef::OnePassword(reference) => {
+            run("op", &["read", "--no-newline", reference])
+        }
+        SecretRef::Keychain { service, account } => keychain_lookup(service, account),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn keychain_lookup(service: &str, account: &str) -> Result<String, String> {
+    let mut args = vec!["find-generic-password", "-w", "-s", service];
+    if !account.is_empty() {
+        args.extend(["-a", account]);
+    }
+    run("security", &args)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn keychain_lookup(service: &str, account: &str) -> Result<String, String> {
+    let mut args = vec!["lookup", "service", service];
+    if !account.is_empty() {
+        args.extend(["account", account]);
+    }
+    let secret = run("secret-tool", &args)?;
+    if secret.is_empty() {
+        return Err(format!("No keychain entry for {}", service));
+    }
+    Ok(secret)
+}
+
+#[cfg(not(unix))]
+fn keychain_lookup(_service: &str, _account: &str) -> Result<String, String> {
+    Err("Keychain references are not supported on this platform".to_string())
+}
+
+/// Run a password manager command and return its output without the trailing newline
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().next().unwrap_or("").trim();
+        return Err(if reason.is_empty() {
+            format!("{} failed ({})", program, output.status)
+        } else {
+            format!("{}: {}", program, reason)
+        });
+    }
+    let stdout = String::from_utf8(output.stdout)
+        .map_err(|_| format!("{} returned a non-UTF-8 secret", program))?;
+    Ok(stdout.trim_end_matches(['\n', '\r']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_references() {
+        assert_eq!(SecretRef::parse("pass:servers/web"), Some(SecretRef::Pass("servers/web")));
+        assert_eq!(
+            SecretRef::parse("op://Private/web/password"),
+            Some(SecretRef::OnePassword("op://Private/web/password"))
+        );
+        assert_eq!(
+            SecretRef::parse("keychain:ssh/web.example.com/deploy"),
+            Some(SecretRef::Keychain { service: "ssh/web.example.com", account: "deploy" })
+        );
+        assert_eq!(
+            SecretRef::parse("keychain:cokacdir"),
+            Some(SecretRef::Keychain { service: "cokacdir", account: "" })
+        );
+        assert!(!is_reference("hunter2"));
+        assert!(!is_reference("password:x"));
+    }
+
+    #[test]
+    fn test_literal_passes_through() {
+        assert_eq!(resolve("plain secret").unwrap(), "plain secret");
+    }
+}
@@ -0,0 +1,234 @@
+//! Update check and `cokacdir --self-update`.
+//!
+//! Release binaries are published as `<BASE_URL>/cokacdir-<os>-<arch>` with a
+//! `<binary>.sha256` next to each one (sha256sum format). The new binary is
+//! verified against the checksum, written next to the running executable and
+//! renamed over it, so an interrupted update never leaves a partial binary.
+//!
+//! Setting `check_updates` to false in settings.json disables every network
+//! request made here (the exit-time check and `--self-update`).
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+
+/// Where release binaries are published (same location as install.sh)
+const BASE_URL: &str = "https://cokacdir.cokac.com/dist";
+
+/// Cargo.toml of the main branch; its version is the latest release
+const VERSION_URL: &str = "https://raw.githubusercontent.com/kstost/cokacdir/refs/heads/main/Cargo.toml";
+
+/// Timeout of the quick check shown on exit
+pub const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Timeout of the binary download
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Result of `self_update`
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateOutcome {
+    AlreadyLatest(String),
+    Updated { from: String, to: String, path: PathBuf },
+}
+
+/// Release asset of this platform (`cokacdir-linux-x86_64`, ...)
+pub fn asset_name() -> Option<String> {
+    let os = match std::env::consts::OS {
+        "linux" => "linux",
+        "macos" => "macos",
+        _ => return None,
+    };
+    let arch = match std::env::consts::ARCH {
+        "x86_64" => "x86_64",
+        "aarch64" => "aarch64",
+        _ => return None,
+    };
+    Some(format!("cokacdir-{}-{}", os, arch))
+}
+
+pub fn parse_version_from_cargo_toml(content: &str) -> Option<String> {
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with("version") {
+            // Parse: version = "x.x.x"
+            if let Some(start) = line.find('"') {
+                if let Some(end) = line.rfind('"') {
+                    if start < end {
+                        return Some(line[start + 1..end].to_string());
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+pub fn is_newer_version(latest: &str, current: &str) -> bool {
+    let parse = |v: &str| -> Vec<u32> {
+        v.split('.')
+            .filter_map(|s| s.parse().ok())
+            .collect()
+    };
+
+    let latest_parts = parse(latest);
+    let current_parts = parse(current);
+
+    for i in 0..latest_parts.len().max(current_parts.len()) {
+        let l = latest_parts.get(i).copied().unwrap_or(0);
+        let c = current_parts.get(i).copied().unwrap_or(0);
+        if l > c {
+            return true;
+        } else if l < c {
+            return false;
+        }
+    }
+    false
+}
+
+/// Hex digest of a `.sha256` file (`<hex>  <name>` or just `<hex>`)
+pub fn parse_checksum(content: &str) -> Option<String> {
+    let digest = content.split_whitespace().next()?.to_lowercase();
+    (digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit())).then_some(digest)
+}
+
+fn fetch(url: &str, timeout: Duration) -> Result<Vec<u8>, String> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| e.to_string())?;
+    runtime.block_on(async {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("{} returned {}", url, response.status()));
+        }
+        response.bytes().await.map(|b| b.to_vec()).map_err(|e| e.to_string())
+    })
+}
+
+/// Latest released version, if it can be fetched within `timeout`
+pub fn latest_version(timeout: Duration) -> Option<String> {
+    let content = fetch(VERSION_URL, timeout).ok()?;
+    parse_version_from_cargo_toml(&String::from_utf8_lossy(&content))
+}
+
+/// Write `binary` next to `exe` and rename it over `exe`
+fn replace_executable(exe: &Path, binary: &[u8], expected_version: &str) -> Result<(), String> {
+    use std::io::Write;
+
+    let dir = exe.parent().ok_or("Cannot determine the install directory")?;
+    let temp_path = dir.join(format!(".cokacdir-update-{}", std::process::id()));
+    let write = || -> std::io::Result<()> {
+        let mut file = std::fs::File::create(&temp_path)?;
+        file.write_all(binary)?;
+        file.sync_all()?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&temp_path, std::fs::Permissions::from_mode(0o755))?;
+        }
+        Ok(())
+    };
+    if let Err(e) = write() {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(if e.kind() == std::io::ErrorKind::PermissionDenied {
+            format!("No permission to write to {} (try: sudo cokacdir --self-update)", dir.display())
+        } else {
+            format!("Failed to write the new binary: {}", e)
+        });
+    }
+
+    // Make sure the new binary runs before replacing the old one
+    let runs = std::process::Command::new(&temp_path)
+        .arg("--version")
+        .output()
+        .map(|o| o.status.success() && String::from_utf8_lossy(&o.stdout).contains(expected_version))
+        .unwrap_or(false);
+    if !runs {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err("The downloaded binary does not run on this system".to_string());
+    }
+
+    std::fs::rename(&temp_path, exe).map_err(|e| {
+        let _ = std::fs::remove_file(&temp_path);
+        format!("Failed to replace {}: {}", exe.display(), e)
+    })
+}
+
+/// Download the latest release and replace the running executable.
+/// With `force` the binary is reinstalled even when it is up to date.
+pub fn self_update(force: bool, progress: impl Fn(&str)) -> Result<UpdateOutcome, String> {
+    let asset = asset_name().ok_or("No release binary is published for this platform")?;
+
+    progress("Checking the latest version...");
+    let latest = latest_version(CHECK_TIMEOUT * 5).ok_or("Could not fetch the latest version")?;
+    if !force && !is_newer_version(&latest, CURRENT_VERSION) {
+        return Ok(UpdateOutcome::AlreadyLatest(CURRENT_VERSION.to_string()));
+    }
+
+    let url = format!("{}/{}", BASE_URL, asset);
+    progress(&format!("Downloading {} (v{})...", asset, latest));
+    let checksum_file = fetch(&format!("{}.sha256", url), CHECK_TIMEOUT * 5)?;
+    let expected = parse_checksum(&String::from_utf8_lossy(&checksum_file))
+        .ok_or("The published checksum file is malformed")?;
+    let binary = fetch(&url, DOWNLOAD_TIMEOUT)?;
+
+    progress("Verifying checksum...");
+    let actual = hex::encode(Sha256::digest(&binary));
+    if actual != expected {
+        return Err(format!("Checksum mismatch (expected {}, got {}); nothing was changed", expected, actual));
+    }
+
+    let exe = std::env::current_exe()
+        .and_then(|p| p.canonicalize())
+        .map_err(|e| format!("Cannot locate the running executable: {}", e))?;
+    progress(&format!("Installing to {}...", exe.display()));
+    replace_executable(&exe, &binary, &latest)?;
+
+    Ok(UpdateOutcome::Updated { from: CURRENT_VERSION.to_string(), to: latest, path: exe })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versions() {
+        assert_eq!(
+            parse_version_from_cargo_toml("[package]\nname = \"cokacdir\"\nversion = \"0.4.12\"\n"),
+            Some("0.4.12".to_string())
+        );
+        assert!(is_newer_version("0.4.12", "0.4.9"));
+        assert!(is_newer_version("1.0", "0.9.9"));
+        assert!(!is_newer_version("0.4.9", "0.4.9"));
+        assert!(!is_newer_version("0.4.8", "0.4.9"));
+    }
+
+    #[test]
+    fn test_parse_checksum() {
+        let hex = "a".repeat(64);
+        assert_eq!(parse_checksum(&format!("{}  cokacdir-linux-x86_64\n", hex)), Some(hex.clone()));
+        assert_eq!(parse_checksum(&hex.to_uppercase()), Some(hex));
+        assert_eq!(parse_checksum("abc  cokacdir"), None);
+        assert_eq!(parse_checksum(""), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_replace_executable_rejects_broken_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        let exe = dir.path().join("cokacdir");
+        std::fs::write(&exe, b"old").unwrap();
+        let result = replace_executable(&exe, b"not a binary", "9.9.9");
+        assert!(result.is_err());
+        assert_eq!(std::fs::read(&exe).unwrap(), b"old");
+        // No temporary file is left behind
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::sync::mpsc;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::path::Path;
 use std::fs;
 
@@ -10,45 +10,110 @@ use teloxide::prelude::*;
 use teloxide::types::ParseMode;
 use sha2::{Sha256, Digest};
 
+use crate::services::audit::{self, AuditEntry, AuditKind};
+use crate::services::bot_console;
 use crate::services::claude::{self, CancelToken, StreamMessage, DEFAULT_ALLOWED_TOOLS};
+use crate::services::logging;
 use crate::ui::ai_screen::{self, HistoryItem, HistoryType, SessionData};
+use crate::{log_debug, log_warn};
+
+/// How the bot server stops once a shutdown is requested
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownMode {
+    /// Exit after in-flight work is finished
+    Stop,
+    /// Re-exec the binary after in-flight work is finished (/restart)
+    Restart,
+}
 
-/// Global debug log flag for Telegram API calls
-static TG_DEBUG: AtomicBool = AtomicBool::new(false);
+/// Shutdown request shared by all bots of this process (0 = running, 1 = stop, 2 = restart)
+static SHUTDOWN: AtomicU8 = AtomicU8::new(0);
 
-/// Log Telegram API call result to ~/.cokacdir/debug/ file
-fn tg_debug<T, E: std::fmt::Display>(name: &str, result: &Result<T, E>) {
-    if !TG_DEBUG.load(Ordering::Relaxed) {
-        return;
-    }
-    let Some(debug_dir) = dirs::home_dir().map(|h| h.join(".cokacdir").join("debug")) else {
-        return;
-    };
-    let _ = fs::create_dir_all(&debug_dir);
-    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
-    let log_path = debug_dir.join(format!("{}.log", date));
-    let ts = chrono::Local::now().format("%H:%M:%S%.3f");
-    let status = match result {
-        Ok(_) => "✓".to_string(),
-        Err(e) => format!("✗ {e}"),
+/// Seconds in-flight requests may keep running after a shutdown request before they are stopped
+const SHUTDOWN_GRACE_SECS: u64 = 60;
+
+/// Seconds to wait for stopped requests to save their sessions
+const SHUTDOWN_STOP_WAIT_SECS: u64 = 10;
+
+/// Ask every bot of this process to shut down. The first request wins.
+pub fn request_shutdown(mode: ShutdownMode) {
+    record_shutdown(&SHUTDOWN, mode);
+}
+
+fn shutdown_requested() -> Option<ShutdownMode> {
+    recorded_shutdown(&SHUTDOWN)
+}
+
+/// Store `mode` in `flag` unless a shutdown was already requested
+fn record_shutdown(flag: &AtomicU8, mode: ShutdownMode) {
+    let value = match mode {
+        ShutdownMode::Stop => 1,
+        ShutdownMode::Restart => 2,
     };
-    let line = format!("[{ts}] {name}: {status}\n");
-    let _ = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_path)
-        .and_then(|mut f| std::io::Write::write_all(&mut f, line.as_bytes()));
+    let _ = flag.compare_exchange(0, value, Ordering::SeqCst, Ordering::SeqCst);
+}
+
+fn recorded_shutdown(flag: &AtomicU8) -> Option<ShutdownMode> {
+    match flag.load(Ordering::SeqCst) {
+        1 => Some(ShutdownMode::Stop),
+        2 => Some(ShutdownMode::Restart),
+        _ => None,
+    }
+}
+
+async fn wait_for_shutdown() -> ShutdownMode {
+    loop {
+        if let Some(mode) = shutdown_requested() {
+            return mode;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
+    }
+}
+
+/// Turn SIGTERM/SIGINT into a graceful shutdown request; a second signal exits immediately
+pub fn spawn_signal_listener() {
+    tokio::spawn(async {
+        use tokio::signal::unix::{signal, SignalKind};
+        let Ok(mut term) = signal(SignalKind::terminate()) else { return };
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = term.recv() => {}
+        }
+        console!("  ■ Shutdown requested — finishing in-flight requests (signal again to force)");
+        request_shutdown(ShutdownMode::Stop);
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = term.recv() => {}
+        }
+        console!("  ■ Forced exit");
+        std::process::exit(130);
+    });
 }
 
-/// Wrap a Telegram API call to log its result in debug mode
+/// Log a Telegram API call result (failures at warn level, successes at debug)
+fn tg_log<T, E: std::fmt::Display>(name: &str, result: &Result<T, E>) {
+    match result {
+        Ok(_) => log_debug!("telegram", "{name}: ✓"),
+        Err(e) => log_warn!("telegram", "{name}: ✗ {e}"),
+    }
+}
+
+/// Wrap a Telegram API call to log its result
 macro_rules! tg {
     ($name:expr, $fut:expr) => {{
         let r = $fut;
-        tg_debug($name, &r);
+        tg_log($name, &r);
         r
     }};
 }
 
+/// Server console output (text line, JSON event or dashboard update, see bot_console)
+macro_rules! console {
+    ($($arg:tt)*) => {
+        bot_console::line(&format!($($arg)*))
+    };
+}
+
 /// Per-chat session state
 #[derive(Clone)]
 struct ChatSession {
@@ -76,6 +141,22 @@ struct BotSettings {
     models: HashMap<String, String>,
     /// Debug logging toggle
     debug: bool,
+    /// Owner document routing by file name pattern (first match wins)
+    download_rules: Vec<DownloadRule>,
+}
+
+/// Destination directory for uploaded documents whose name matches `pattern`
+#[derive(Clone)]
+struct DownloadRule {
+    /// Wildcard pattern (`*`, `?`), case-insensitive
+    pattern: String,
+    /// Directory as entered (may start with `~`)
+    dir: String,
+}
+
+/// First download rule matching the file name
+fn find_download_rule<'a>(rules: &'a [DownloadRule], file_name: &str) -> Option<&'a DownloadRule> {
+    rules.iter().find(|r| crate::utils::glob::glob_match(&r.pattern, file_name, false))
 }
 
 impl Default for BotSettings {
@@ -87,6 +168,7 @@ impl Default for BotSettings {
             as_public_for_group_chat: HashMap::new(),
             models: HashMap::new(),
             debug: false,
+            download_rules: Vec::new(),
         }
     }
 }
@@ -125,28 +207,24 @@ struct ScheduleEntry {
 /// Directory for schedule files: ~/.cokacdir/schedule/
 fn schedule_dir() -> Option<std::path::PathBuf> {
     let result = dirs::home_dir().map(|h| h.join(".cokacdir").join("schedule"));
-    sched_debug(&format!("[schedule_dir] → {:?}", result));
+    log_debug!("cron", "[schedule_dir] → {:?}", result);
     result
 }
 
-fn sched_debug(msg: &str) {
-    crate::services::claude::debug_log_to("cron.log", msg);
-}
-
 /// Read a single schedule entry from a JSON file
 fn read_schedule_entry(path: &std::path::Path) -> Option<ScheduleEntry> {
-    sched_debug(&format!("[read_schedule_entry] reading: {}", path.display()));
+    log_debug!("cron", "[read_schedule_entry] reading: {}", path.display());
     let content = match fs::read_to_string(path) {
         Ok(c) => c,
         Err(e) => {
-            sched_debug(&format!("[read_schedule_entry] read failed: {}", e));
+            log_debug!("cron", "[read_schedule_entry] read failed: {}", e);
             return None;
         }
     };
     let v: serde_json::Value = match serde_json::from_str(&content) {
         Ok(v) => v,
         Err(e) => {
-            sched_debug(&format!("[read_schedule_entry] parse failed: {}", e));
+            log_debug!("cron", "[read_schedule_entry] parse failed: {}", e);
             return None;
         }
     };
@@ -163,19 +241,19 @@ fn read_schedule_entry(path: &std::path::Path) -> Option<ScheduleEntry> {
         created_at: v.get("created_at")?.as_str()?.to_string(),
         context_summary: v.get("context_summary").and_then(|v| v.as_str()).map(String::from),
     });
-    sched_debug(&format!("[read_schedule_entry] result: id={}, type={}, schedule={}, last_run={:?}",
+    log_debug!("cron", "[read_schedule_entry] result: id={}, type={}, schedule={}, last_run={:?}",
         entry.as_ref().map(|e| e.id.as_str()).unwrap_or("?"),
         entry.as_ref().map(|e| e.schedule_type.as_str()).unwrap_or("?"),
         entry.as_ref().map(|e| e.schedule.as_str()).unwrap_or("?"),
         entry.as_ref().and_then(|e| e.last_run.as_deref()),
-    ));
+    );
     entry
 }
 
 /// Write a schedule entry to its JSON file
 fn write_schedule_entry(entry: &ScheduleEntry) -> Result<(), String> {
-    sched_debug(&format!("[write_schedule_entry] id={}, type={}, schedule={}, once={:?}, last_run={:?}",
-        entry.id, entry.schedule_type, entry.schedule, entry.once, entry.last_run));
+    log_debug!("cron", "[write_schedule_entry] id={}, type={}, schedule={}, once={:?}, last_run={:?}",
+        entry.id, entry.schedule_type, entry.schedule, entry.once, entry.last_run);
     let dir = schedule_dir().ok_or("Cannot determine home directory")?;
     fs::create_dir_all(&dir).map_err(|e| format!("Failed to create schedule dir: {e}"))?;
     let mut json = serde_json::json!({
@@ -195,25 +273,25 @@ fn write_schedule_entry(entry: &ScheduleEntry) -> Result<(), String> {
     }
     let path = dir.join(format!("{}.json", entry.id));
     let tmp_path = dir.join(format!("{}.json.tmp", entry.id));
-    sched_debug(&format!("[write_schedule_entry] writing tmp: {}", tmp_path.display()));
+    log_debug!("cron", "[write_schedule_entry] writing tmp: {}", tmp_path.display());
     fs::write(&tmp_path, serde_json::to_string_pretty(&json).unwrap_or_default())
         .map_err(|e| format!("Failed to write schedule file: {e}"))?;
-    sched_debug(&format!("[write_schedule_entry] atomic rename: {} → {}", tmp_path.display(), path.display()));
+    log_debug!("cron", "[write_schedule_entry] atomic rename: {} → {}", tmp_path.display(), path.display());
     let result = fs::rename(&tmp_path, &path)
         .map_err(|e| format!("Failed to finalize schedule file: {e}"));
-    sched_debug(&format!("[write_schedule_entry] result: {:?}", result));
+    log_debug!("cron", "[write_schedule_entry] result: {:?}", result);
     result
 }
 
 /// List all schedule entries matching the given bot_key and optionally chat_id
 fn list_schedule_entries(bot_key: &str, chat_id: Option<i64>) -> Vec<ScheduleEntry> {
-    sched_debug(&format!("[list_schedule_entries] bot_key={}, chat_id={:?}", bot_key, chat_id));
+    log_debug!("cron", "[list_schedule_entries] bot_key={}, chat_id={:?}", bot_key, chat_id);
     let Some(dir) = schedule_dir() else {
-        sched_debug("[list_schedule_entries] no schedule dir");
+        log_debug!("cron", "[list_schedule_entries] no schedule dir");
         return Vec::new();
     };
     let Ok(entries) = fs::read_dir(&dir) else {
-        sched_debug("[list_schedule_entries] read_dir failed");
+        log_debug!("cron", "[list_schedule_entries] read_dir failed");
         return Vec::new();
     };
     let mut result: Vec<ScheduleEntry> = entries
@@ -224,29 +302,29 @@ fn list_schedule_entries(bot_key: &str, chat_id: Option<i64>) -> Vec<ScheduleEnt
         .filter(|e| chat_id.map_or(true, |cid| e.chat_id == cid))
         .collect();
     result.sort_by(|a, b| a.created_at.cmp(&b.created_at));
-    sched_debug(&format!("[list_schedule_entries] found {} entries: [{}]",
+    log_debug!("cron", "[list_schedule_entries] found {} entries: [{}]",
         result.len(),
-        result.iter().map(|e| format!("{}({})", e.id, e.schedule_type)).collect::<Vec<_>>().join(", ")));
+        result.iter().map(|e| format!("{}({})", e.id, e.schedule_type)).collect::<Vec<_>>().join(", "));
     result
 }
 
 /// Delete a schedule entry by ID
 fn delete_schedule_entry(id: &str) -> bool {
-    sched_debug(&format!("[delete_schedule_entry] id={}", id));
+    log_debug!("cron", "[delete_schedule_entry] id={}", id);
     let Some(dir) = schedule_dir() else {
-        sched_debug("[delete_schedule_entry] no schedule dir");
+        log_debug!("cron", "[delete_schedule_entry] no schedule dir");
         return false;
     };
     let path = dir.join(format!("{id}.json"));
     let existed = path.exists();
     let ok = fs::remove_file(&path).is_ok();
-    sched_debug(&format!("[delete_schedule_entry] path={}, existed={}, removed={}", path.display(), existed, ok));
+    log_debug!("cron", "[delete_schedule_entry] path={}, existed={}, removed={}", path.display(), existed, ok);
 
     // Also remove the .result file if it exists
     let result_path = dir.join(format!("{id}.result"));
     if result_path.exists() {
         let _ = fs::remove_file(&result_path);
-        sched_debug(&format!("[delete_schedule_entry] also removed .result: {}", result_path.display()));
+        log_debug!("cron", "[delete_schedule_entry] also removed .result: {}", result_path.display());
     }
 
     ok
@@ -254,22 +332,22 @@ fn delete_schedule_entry(id: &str) -> bool {
 
 /// Parse a relative time string (e.g. "4h", "30m", "1d") into a future DateTime
 fn parse_relative_time(s: &str) -> Option<chrono::DateTime<chrono::Local>> {
-    sched_debug(&format!("[parse_relative_time] input: {:?}", s));
+    log_debug!("cron", "[parse_relative_time] input: {:?}", s);
     let s = s.trim();
     if s.len() < 2 {
-        sched_debug("[parse_relative_time] too short → None");
+        log_debug!("cron", "[parse_relative_time] too short → None");
         return None;
     }
     let (num_part, unit) = s.split_at(s.len() - 1);
     let num: i64 = match num_part.parse() {
         Ok(n) => n,
         Err(_) => {
-            sched_debug(&format!("[parse_relative_time] invalid number: {:?} → None", num_part));
+            log_debug!("cron", "[parse_relative_time] invalid number: {:?} → None", num_part);
             return None;
         }
     };
     if num <= 0 {
-        sched_debug("[parse_relative_time] num <= 0 → None");
+        log_debug!("cron", "[parse_relative_time] num <= 0 → None");
         return None;
     }
     let seconds = match unit {
@@ -277,12 +355,12 @@ fn parse_relative_time(s: &str) -> Option<chrono::DateTime<chrono::Local>> {
         "h" => num * 3600,
         "d" => num * 86400,
         _ => {
-            sched_debug(&format!("[parse_relative_time] unknown unit: {:?} → None", unit));
+            log_debug!("cron", "[parse_relative_time] unknown unit: {:?} → None", unit);
             return None;
         }
     };
     let result = Some(chrono::Local::now() + chrono::Duration::seconds(seconds));
-    sched_debug(&format!("[parse_relative_time] → {:?}", result.as_ref().map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())));
+    log_debug!("cron", "[parse_relative_time] → {:?}", result.as_ref().map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string()));
     result
 }
 
@@ -294,7 +372,7 @@ fn cron_matches(expr: &str, dt: chrono::DateTime<chrono::Local>) -> bool {
 
     let fields: Vec<&str> = expr.split_whitespace().collect();
     if fields.len() != 5 {
-        sched_debug(&format!("[cron_matches] invalid field count: {} (expected 5) for expr={:?}", fields.len(), expr));
+        log_debug!("cron", "[cron_matches] invalid field count: {} (expected 5) for expr={:?}", fields.len(), expr);
         return false;
     }
 
@@ -313,12 +391,12 @@ fn cron_matches(expr: &str, dt: chrono::DateTime<chrono::Local>) -> bool {
     for (i, ((field, &val), &range_start)) in fields.iter().zip(values.iter()).zip(range_starts.iter()).enumerate() {
         let matched = cron_field_matches(field, val, range_start);
         if !matched {
-            sched_debug(&format!("[cron_matches] expr={:?}, dt={}, {}({})!={} → false",
-                expr, dt.format("%H:%M"), field_names[i], val, field));
+            log_debug!("cron", "[cron_matches] expr={:?}, dt={}, {}({})!={} → false",
+                expr, dt.format("%H:%M"), field_names[i], val, field);
             return false;
         }
     }
-    sched_debug(&format!("[cron_matches] expr={:?}, dt={} → true", expr, dt.format("%H:%M")));
+    log_debug!("cron", "[cron_matches] expr={:?}, dt={} → true", expr, dt.format("%H:%M"));
     true
 }
 
@@ -336,13 +414,13 @@ fn cron_field_matches(field: &str, val: u32, range_start: u32) -> bool {
                 if step == 0 { continue; }
                 if range_part == "*" {
                     if (val - range_start) % step == 0 {
-                        sched_debug(&format!("[cron_field_matches] field={}, val={}, */{}  → true", field, val, step));
+                        log_debug!("cron", "[cron_field_matches] field={}, val={}, */{}  → true", field, val, step);
                         return true;
                     }
                 } else if let Some((start_str, end_str)) = range_part.split_once('-') {
                     if let (Ok(start), Ok(end)) = (start_str.parse::<u32>(), end_str.parse::<u32>()) {
                         if val >= start && val <= end && (val - start) % step == 0 {
-                            sched_debug(&format!("[cron_field_matches] field={}, val={}, {}-{}/{} → true", field, val, start, end, step));
+                            log_debug!("cron", "[cron_field_matches] field={}, val={}, {}-{}/{} → true", field, val, start, end, step);
                             return true;
                         }
                     }
@@ -352,7 +430,7 @@ fn cron_field_matches(field: &str, val: u32, range_start: u32) -> bool {
             // Range: a-b
             if let (Ok(start), Ok(end)) = (start_str.parse::<u32>(), end_str.parse::<u32>()) {
                 if val >= start && val <= end {
-                    sched_debug(&format!("[cron_field_matches] field={}, val={}, range {}-{} → true", field, val, start, end));
+                    log_debug!("cron", "[cron_field_matches] field={}, val={}, range {}-{} → true", field, val, start, end);
                     return true;
                 }
             }
@@ -360,7 +438,7 @@ fn cron_field_matches(field: &str, val: u32, range_start: u32) -> bool {
             // Single number
             if let Ok(n) = part.parse::<u32>() {
                 if val == n {
-                    sched_debug(&format!("[cron_field_matches] field={}, val={}, exact {} → true", field, val, n));
+                    log_debug!("cron", "[cron_field_matches] field={}, val={}, exact {} → true", field, val, n);
                     return true;
                 }
             }
@@ -456,6 +534,14 @@ pub fn delete_schedule_entry_pub(id: &str) -> bool {
     delete_schedule_entry(id)
 }
 
+pub fn should_trigger_pub(data: &ScheduleEntryData) -> bool {
+    should_trigger(&ScheduleEntry::from(data))
+}
+
+pub fn update_schedule_after_run_pub(data: &ScheduleEntryData) {
+    update_schedule_after_run(&ScheduleEntry::from(data), None)
+}
+
 /// Resolve the current working path for a chat from bot_settings.json
 pub fn resolve_current_path_for_chat(chat_id: i64, hash_key: &str) -> Option<String> {
     let path = bot_settings_path()?;
@@ -680,7 +766,20 @@ fn load_bot_settings(token: &str) -> BotSettings {
 
     let debug = entry.get("debug").and_then(|v| v.as_bool()).unwrap_or(false);
 
-    BotSettings { allowed_tools, last_sessions, owner_user_id, as_public_for_group_chat, models, debug }
+    let download_rules: Vec<DownloadRule> = entry.get("download_rules")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|r| {
+                    let pattern = r.get("pattern")?.as_str()?.to_string();
+                    let dir = r.get("dir")?.as_str()?.to_string();
+                    Some(DownloadRule { pattern, dir })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    BotSettings { allowed_tools, last_sessions, owner_user_id, as_public_for_group_chat, models, debug, download_rules }
 }
 
 /// Save bot settings to bot_settings.json
@@ -704,6 +803,9 @@ fn save_bot_settings(token: &str, settings: &BotSettings) {
         "as_public_for_group_chat": settings.as_public_for_group_chat,
         "models": settings.models,
         "debug": settings.debug,
+        "download_rules": settings.download_rules.iter()
+            .map(|r| serde_json::json!({ "pattern": r.pattern, "dir": r.dir }))
+            .collect::<Vec<_>>(),
     });
     if let Some(owner_id) = settings.owner_user_id {
         entry["owner_user_id"] = serde_json::json!(owner_id);
@@ -775,14 +877,25 @@ fn risk_badge(destructive: bool) -> &'static str {
 }
 
 /// Entry point: start the Telegram bot with long polling
-pub async fn run_bot(token: &str) {
+/// Run one bot (`index` = position in the --ccserver token list) until a shutdown
+/// is requested; returns how the server should stop
+pub async fn run_bot(token: &str, index: usize) -> ShutdownMode {
+    let console = bot_console::register(index);
+    bot_console::scope(console.clone(), run_bot_scoped(token, console)).await
+}
+
+async fn run_bot_scoped(token: &str, console: Arc<bot_console::BotConsole>) -> ShutdownMode {
     let bot = Bot::new(token);
+    if let Ok(me) = tg!("get_me", bot.get_me().await) {
+        if let Some(ref username) = me.user.username {
+            console.set_name(&format!("@{}", username));
+        }
+    }
     let bot_settings = load_bot_settings(token);
 
     // Restore debug flag from saved settings
     if bot_settings.debug {
-        TG_DEBUG.store(true, Ordering::Relaxed);
-        crate::services::claude::DEBUG_ENABLED.store(true, Ordering::Relaxed);
+        logging::set_debug_mode(true);
     }
 
     // Register bot commands for autocomplete
@@ -793,6 +906,7 @@ pub async fn run_bot(token: &str) {
         teloxide::types::BotCommand::new("clear", "Clear AI conversation history"),
         teloxide::types::BotCommand::new("stop", "Stop current AI request"),
         teloxide::types::BotCommand::new("down", "Download file from server"),
+        teloxide::types::BotCommand::new("saveto", "Route uploads by file name (pattern dir)"),
         teloxide::types::BotCommand::new("public", "Toggle public access (group only)"),
         teloxide::types::BotCommand::new("availabletools", "List all available tools"),
         teloxide::types::BotCommand::new("allowedtools", "Show currently allowed tools"),
@@ -800,14 +914,16 @@ pub async fn run_bot(token: &str) {
         teloxide::types::BotCommand::new("setpollingtime", "Set API polling interval (ms)"),
         teloxide::types::BotCommand::new("model", "Set AI model"),
         teloxide::types::BotCommand::new("debug", "Toggle debug logging"),
+        teloxide::types::BotCommand::new("restart", "Restart the bot server"),
+        teloxide::types::BotCommand::new("audit", "Show recent bot activity"),
     ];
     if let Err(e) = tg!("set_my_commands", bot.set_my_commands(commands).await) {
-        println!("  ⚠ Failed to set bot commands: {e}");
+        console!("  ⚠ Failed to set bot commands: {e}");
     }
 
     match bot_settings.owner_user_id {
-        Some(owner_id) => println!("  ✓ Owner: {owner_id}"),
-        None => println!("  ⚠ No owner registered — first user will be registered as owner"),
+        Some(owner_id) => console!("  ✓ Owner: {owner_id}"),
+        None => console!("  ⚠ No owner registered — first user will be registered as owner"),
     }
 
     let app_settings = crate::config::Settings::load();
@@ -823,8 +939,17 @@ pub async fn run_bot(token: &str) {
         pending_schedules: HashMap::new(),
     }));
 
-    println!("  ✓ Bot connected — Listening for messages");
-    println!("  ✓ Scheduler started (5s interval)");
+    // Owner and queue depth for the dashboard (skipped while the state is locked)
+    let probe_state = state.clone();
+    console.set_probe(move || {
+        probe_state.try_lock().ok().map(|data| {
+            let queued: usize = data.pending_schedules.values().map(|s| s.len()).sum();
+            (data.settings.owner_user_id, data.cancel_tokens.len() + queued)
+        })
+    });
+
+    console!("  ✓ Bot connected — Listening for messages");
+    console!("  ✓ Scheduler started (5s interval)");
 
     // Send startup greeting to known chats
     {
@@ -854,20 +979,93 @@ pub async fn run_bot(token: &str) {
     let scheduler_bot = bot.clone();
     let scheduler_state = state.clone();
     let scheduler_token = token.to_string();
-    let scheduler_handle = tokio::spawn(scheduler_loop(scheduler_bot, scheduler_state, scheduler_token));
+    let scheduler_handle = bot_console::spawn(scheduler_loop(scheduler_bot, scheduler_state, scheduler_token));
 
     let shared_state = state.clone();
     let token_owned = token.to_string();
-    teloxide::repl(bot, move |bot: Bot, msg: Message| {
+    let repl = teloxide::repl(bot.clone(), move |bot: Bot, msg: Message| {
         let state = shared_state.clone();
         let token = token_owned.clone();
+        let console = console.clone();
         async move {
-            handle_message(bot, msg, state, &token).await
+            bot_console::scope(console, handle_message(bot, msg, state, &token)).await
         }
-    })
-    .await;
+    });
+
+    // Stop receiving messages once a shutdown is requested (or the dispatcher exits on Ctrl+C)
+    let mode = tokio::select! {
+        _ = repl => shutdown_requested().unwrap_or(ShutdownMode::Stop),
+        mode = wait_for_shutdown() => mode,
+    };
 
     scheduler_handle.abort();
+    graceful_shutdown(&bot, &state, mode).await;
+    mode
+}
+
+/// Notify active chats, let in-flight requests finish (stopping and checkpointing
+/// them after the grace period) and flush the upload queue
+async fn graceful_shutdown(bot: &Bot, state: &SharedState, mode: ShutdownMode) {
+    let notice = match mode {
+        ShutdownMode::Restart => "🔄 Server restarting — running requests are finished first. Back in a moment.",
+        ShutdownMode::Stop => "🔴 Server shutting down — running requests are finished first.",
+    };
+    let chat_ids: Vec<ChatId> = {
+        let data = state.lock().await;
+        let mut ids: Vec<ChatId> = data.sessions.keys().chain(data.cancel_tokens.keys()).copied().collect();
+        ids.sort_by_key(|c| c.0);
+        ids.dedup();
+        ids
+    };
+    for &chat_id in &chat_ids {
+        shared_rate_limit_wait(state, chat_id).await;
+        let _ = tg!("send_message", bot.send_message(chat_id, notice).await);
+    }
+
+    let started = tokio::time::Instant::now();
+    let grace = tokio::time::Duration::from_secs(SHUTDOWN_GRACE_SECS);
+    let mut stop_deadline: Option<tokio::time::Instant> = None;
+    loop {
+        let running: Vec<Arc<CancelToken>> = {
+            let data = state.lock().await;
+            data.cancel_tokens.values().cloned().collect()
+        };
+        if running.is_empty() {
+            break;
+        }
+        match stop_deadline {
+            None if started.elapsed() >= grace => {
+                // Checkpoint: stopped requests save their partial response and session id
+                console!("  ■ Stopping {} request(s) still running after {}s", running.len(), SHUTDOWN_GRACE_SECS);
+                for token in &running {
+                    token.cancelled.store(true, Ordering::Relaxed);
+                    if let Ok(guard) = token.child_pid.lock() {
+                        if let Some(pid) = *guard {
+                            #[cfg(unix)]
+                            unsafe {
+                                libc::kill(pid as libc::pid_t, libc::SIGTERM);
+                            }
+                        }
+                    }
+                }
+                stop_deadline = Some(tokio::time::Instant::now() + tokio::time::Duration::from_secs(SHUTDOWN_STOP_WAIT_SECS));
+            }
+            Some(deadline) if tokio::time::Instant::now() >= deadline => break,
+            _ => {}
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    }
+
+    // Deliver files queued by --sendfile before exiting
+    for &chat_id in &chat_ids {
+        while process_upload_queue(bot, chat_id, state).await {}
+    }
+
+    let ts = chrono::Local::now().format("%H:%M:%S");
+    match mode {
+        ShutdownMode::Restart => console!("  [{ts}] ■ Bot stopped for restart"),
+        ShutdownMode::Stop => console!("  [{ts}] ■ Bot stopped"),
+    }
 }
 
 /// Route incoming messages to appropriate handlers
@@ -897,7 +1095,7 @@ async fn handle_message(
                 // Imprint: register first user as owner
                 data.settings.owner_user_id = Some(uid);
                 save_bot_settings(token, &data.settings);
-                println!("  [{timestamp}] ★ Owner registered: {raw_user_name} (id:{uid})");
+                console!("  [{timestamp}] ★ Owner registered: {raw_user_name} (id:{uid})");
                 true
             }
             Some(owner_id) => {
@@ -908,11 +1106,13 @@ async fn handle_message(
                         && data.settings.as_public_for_group_chat.get(&chat_key).copied().unwrap_or(false);
                     if !is_public {
                         // Unregistered user → reject silently (log only)
-                        println!("  [{timestamp}] ✗ Rejected: {raw_user_name} (id:{uid})");
+                        console!("  [{timestamp}] ✗ Rejected: {raw_user_name} (id:{uid})");
+                        let attempted = msg.text().or(msg.caption()).unwrap_or("(non-text message)");
+                        audit::record(AuditEntry::new(chat_id.0, AuditKind::Rejected, attempted).with_user(uid, raw_user_name));
                         return Ok(());
                     }
                     // Public group chat: allow non-owner user
-                    println!("  [{timestamp}] ○ [{raw_user_name}(id:{uid})] Public group access");
+                    console!("  [{timestamp}] ○ [{raw_user_name}(id:{uid})] Public group access");
                 }
                 false
             }
@@ -940,9 +1140,13 @@ async fn handle_message(
             }
         }
         let file_hint = if msg.document().is_some() { "document" } else { "photo" };
-        println!("  [{timestamp}] ◀ [{user_name}] Upload: {file_hint}");
-        handle_file_upload(&bot, chat_id, &msg, &state).await?;
-        println!("  [{timestamp}] ▶ [{user_name}] Upload complete");
+        console!("  [{timestamp}] ◀ [{user_name}] Upload: {file_hint}");
+        let saved = handle_file_upload(&bot, chat_id, &msg, &state, is_owner).await?;
+        console!("  [{timestamp}] ▶ [{user_name}] Upload complete");
+        let upload_summary = if saved.is_some() { file_hint } else { "(not saved)" };
+        audit::record(AuditEntry::new(chat_id.0, AuditKind::Upload, upload_summary)
+            .with_user(uid, raw_user_name)
+            .with_files(saved.into_iter().collect()));
         // If caption contains text after ';', send it to AI as a follow-up message
         if let Some(caption) = msg.caption() {
             let text_part = if is_group_chat {
@@ -965,6 +1169,7 @@ async fn handle_message(
                         tg!("send_message", bot.send_message(chat_id, "AI request in progress. Use /stop to cancel.")
                             .await)?;
                     } else {
+                        audit::record(AuditEntry::new(chat_id.0, AuditKind::Prompt, text).with_user(uid, raw_user_name));
                         handle_text_message(&bot, chat_id, text, &state).await?;
                     }
                 }
@@ -1021,7 +1226,7 @@ async fn handle_message(
                         session.history = session_data.history.clone();
                     }
                     let ts = chrono::Local::now().format("%H:%M:%S");
-                    println!("  [{ts}] ↻ [{user_name}] Auto-restored session: {last_path}");
+                    console!("  [{ts}] ↻ [{user_name}] Auto-restored session: {last_path}");
                 }
             }
         }
@@ -1032,8 +1237,20 @@ async fn handle_message(
         return Ok(());
     }
 
-    // Block all messages except /stop while an AI request is in progress
-    if !text.starts_with("/stop") {
+    // Audit log (/down is recorded once the file has been sent)
+    if !text.starts_with("/down") {
+        let (kind, summary) = if text.starts_with('/') {
+            (AuditKind::Command, text.as_str())
+        } else if let Some(command) = text.strip_prefix('!') {
+            (AuditKind::Shell, command)
+        } else {
+            (AuditKind::Prompt, text.strip_prefix(';').unwrap_or(&text))
+        };
+        audit::record(AuditEntry::new(chat_id.0, kind, summary).with_user(uid, raw_user_name));
+    }
+
+    // Block all messages except /stop and /restart while an AI request is in progress
+    if !text.starts_with("/stop") && !text.starts_with("/restart") {
         let data = state.lock().await;
         if data.cancel_tokens.contains_key(&chat_id) {
             drop(data);
@@ -1045,51 +1262,65 @@ async fn handle_message(
     }
 
     if text.starts_with("/stop") {
-        println!("  [{timestamp}] ◀ [{user_name}] /stop");
+        console!("  [{timestamp}] ◀ [{user_name}] /stop");
         handle_stop_command(&bot, chat_id, &state).await?;
     } else if text.starts_with("/help") {
-        println!("  [{timestamp}] ◀ [{user_name}] /help");
+        console!("  [{timestamp}] ◀ [{user_name}] /help");
         handle_help_command(&bot, chat_id, &state).await?;
     } else if text.starts_with("/start") {
-        println!("  [{timestamp}] ◀ [{user_name}] /start");
+        console!("  [{timestamp}] ◀ [{user_name}] /start");
         handle_start_command(&bot, chat_id, &text, &state, token).await?;
     } else if text.starts_with("/clear") {
-        println!("  [{timestamp}] ◀ [{user_name}] /clear");
+        console!("  [{timestamp}] ◀ [{user_name}] /clear");
         handle_clear_command(&bot, chat_id, &state).await?;
-        println!("  [{timestamp}] ▶ [{user_name}] Session cleared");
+        console!("  [{timestamp}] ▶ [{user_name}] Session cleared");
     } else if text.starts_with("/pwd") {
-        println!("  [{timestamp}] ◀ [{user_name}] /pwd");
+        console!("  [{timestamp}] ◀ [{user_name}] /pwd");
         handle_pwd_command(&bot, chat_id, &state).await?;
     } else if text.starts_with("/down") {
-        println!("  [{timestamp}] ◀ [{user_name}] /down {}", text.strip_prefix("/down").unwrap_or("").trim());
-        handle_down_command(&bot, chat_id, &text, &state).await?;
+        console!("  [{timestamp}] ◀ [{user_name}] /down {}", text.strip_prefix("/down").unwrap_or("").trim());
+        let sent = handle_down_command(&bot, chat_id, &text, &state).await?;
+        let requested = text.strip_prefix("/down").unwrap_or("").trim();
+        let summary = if sent.is_some() { requested.to_string() } else { format!("{} (not sent)", requested) };
+        audit::record(AuditEntry::new(chat_id.0, AuditKind::Download, &summary)
+            .with_user(uid, raw_user_name)
+            .with_files(sent.into_iter().collect()));
+    } else if text.starts_with("/restart") {
+        console!("  [{timestamp}] ◀ [{user_name}] /restart");
+        handle_restart_command(&bot, chat_id, &state, is_owner).await?;
+    } else if text.starts_with("/audit") {
+        console!("  [{timestamp}] ◀ [{user_name}] /audit {}", text.strip_prefix("/audit").unwrap_or("").trim());
+        handle_audit_command(&bot, chat_id, &text, &state, is_owner).await?;
+    } else if text.starts_with("/saveto") {
+        console!("  [{timestamp}] ◀ [{user_name}] /saveto {}", text.strip_prefix("/saveto").unwrap_or("").trim());
+        handle_saveto_command(&bot, chat_id, &text, &state, token, is_owner).await?;
     } else if text.starts_with("/public") {
-        println!("  [{timestamp}] ◀ [{user_name}] /public {}", text.strip_prefix("/public").unwrap_or("").trim());
+        console!("  [{timestamp}] ◀ [{user_name}] /public {}", text.strip_prefix("/public").unwrap_or("").trim());
         handle_public_command(&bot, chat_id, &text, &state, token, is_group_chat, is_owner).await?;
     } else if text.starts_with("/availabletools") {
-        println!("  [{timestamp}] ◀ [{user_name}] /availabletools");
+        console!("  [{timestamp}] ◀ [{user_name}] /availabletools");
         handle_availabletools_command(&bot, chat_id, &state).await?;
     } else if text.starts_with("/allowedtools") {
-        println!("  [{timestamp}] ◀ [{user_name}] /allowedtools");
+        console!("  [{timestamp}] ◀ [{user_name}] /allowedtools");
         handle_allowedtools_command(&bot, chat_id, &state).await?;
     } else if text.starts_with("/setpollingtime") {
-        println!("  [{timestamp}] ◀ [{user_name}] /setpollingtime {}", text.strip_prefix("/setpollingtime").unwrap_or("").trim());
+        console!("  [{timestamp}] ◀ [{user_name}] /setpollingtime {}", text.strip_prefix("/setpollingtime").unwrap_or("").trim());
         handle_setpollingtime_command(&bot, chat_id, &text, &state).await?;
     } else if text.starts_with("/model") {
-        println!("  [{timestamp}] ◀ [{user_name}] /model {}", text.strip_prefix("/model").unwrap_or("").trim());
+        console!("  [{timestamp}] ◀ [{user_name}] /model {}", text.strip_prefix("/model").unwrap_or("").trim());
         handle_model_command(&bot, chat_id, &text, &state, token).await?;
     } else if text.starts_with("/debug") {
-        println!("  [{timestamp}] ◀ [{user_name}] /debug");
+        console!("  [{timestamp}] ◀ [{user_name}] /debug");
         handle_debug_command(&bot, chat_id, &state, token).await?;
     } else if text.starts_with("/allowed") {
-        println!("  [{timestamp}] ◀ [{user_name}] /allowed {}", text.strip_prefix("/allowed").unwrap_or("").trim());
+        console!("  [{timestamp}] ◀ [{user_name}] /allowed {}", text.strip_prefix("/allowed").unwrap_or("").trim());
         handle_allowed_command(&bot, chat_id, &text, &state, token).await?;
     } else if text.starts_with('/') && is_workspace_id(text[1..].split_whitespace().next().unwrap_or("")) {
         let workspace_id = text[1..].split_whitespace().next().unwrap();
-        println!("  [{timestamp}] ◀ [{user_name}] /{workspace_id}");
+        console!("  [{timestamp}] ◀ [{user_name}] /{workspace_id}");
         handle_workspace_resume(&bot, chat_id, workspace_id, &state, token).await?;
     } else if text.starts_with('!') {
-        println!("  [{timestamp}] ◀ [{user_name}] Shell: {preview}");
+        console!("  [{timestamp}] ◀ [{user_name}] Shell: {preview}");
         handle_shell_command(&bot, chat_id, &text, &state).await?;
     } else if text.starts_with(';') {
         let stripped = text.strip_prefix(';').unwrap_or(&text).trim().to_string();
@@ -1097,10 +1328,10 @@ async fn handle_message(
             return Ok(());
         }
         let preview = &stripped;
-        println!("  [{timestamp}] ◀ [{user_name}] {preview}");
+        console!("  [{timestamp}] ◀ [{user_name}] {preview}");
         handle_text_message(&bot, chat_id, &stripped, &state).await?;
     } else {
-        println!("  [{timestamp}] ◀ [{user_name}] {preview}");
+        console!("  [{timestamp}] ◀ [{user_name}] {preview}");
         handle_text_message(&bot, chat_id, &text, &state).await?;
     }
 
@@ -1127,6 +1358,10 @@ Manage server files &amp; chat with Claude AI.
 <b>File Transfer</b>
 <code>/down &lt;file&gt;</code> — Download file from server
 Send a file/photo — Upload to session directory
+<code>/saveto</code> — Show upload routing rules
+<code>/saveto &lt;pattern&gt; &lt;dir&gt;</code> — Save matching documents to dir
+  e.g. <code>/saveto *.pdf ~/Documents</code>
+<code>/saveto -&lt;pattern&gt;</code> — Remove rule
 
 <b>Shell</b>
 <code>!&lt;command&gt;</code> — Run shell command directly
@@ -1159,6 +1394,8 @@ Ask in natural language to manage schedules.
   Too low may cause Telegram API rate limits.
   Minimum 2500ms, recommended 3000ms+.
 <code>/debug</code> — Toggle debug logging
+<code>/audit [n]</code> — Show last n audit log entries (owner)
+<code>/restart</code> — Restart the server, e.g. after an update (owner)
 
 <code>/help</code> — Show this help";
 
@@ -1249,7 +1486,7 @@ async fn handle_start_command(
             session.history = session_data.history.clone();
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ▶ Session restored: {canonical_path}");
+            console!("  [{ts}] ▶ Session restored: {canonical_path}");
             response_lines.push(format!("Session restored at `{}`.", canonical_path));
             if let Some(folder_name) = std::path::Path::new(&canonical_path).file_name().and_then(|n| n.to_str()) {
                 if is_workspace_id(folder_name) {
@@ -1269,7 +1506,7 @@ async fn handle_start_command(
             session.history.clear();
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ▶ Session started: {canonical_path}");
+            console!("  [{ts}] ▶ Session started: {canonical_path}");
             response_lines.push(format!("Session started at `{}`.", canonical_path));
             // Show workspace ID shortcut if this is a workspace directory
             if let Some(folder_name) = std::path::Path::new(&canonical_path).file_name().and_then(|n| n.to_str()) {
@@ -1317,6 +1554,7 @@ fn build_history_preview(history: &[HistoryItem], budget: usize) -> String {
             HistoryType::System => "⚙️",
             HistoryType::ToolUse => "🔧",
             HistoryType::ToolResult => "📋",
+            HistoryType::ToolTimeline => "🔧",
         };
         let line = format!("{} {}", prefix, item.content);
         let line_len = line.len() + 1; // +1 for newline
@@ -1385,7 +1623,7 @@ async fn handle_workspace_resume(
             session.history = session_data.history.clone();
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ▶ Workspace session restored: {workspace_id} → {canonical_path}");
+            console!("  [{ts}] ▶ Workspace session restored: {workspace_id} → {canonical_path}");
             response_lines.push(format!("Session restored at `{}`.", canonical_path));
 
             let header_len: usize = response_lines.iter().map(|l| l.len() + 1).sum();
@@ -1402,7 +1640,7 @@ async fn handle_workspace_resume(
             session.history.clear();
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ▶ Workspace session started: {workspace_id} → {canonical_path}");
+            console!("  [{ts}] ▶ Workspace session started: {workspace_id} → {canonical_path}");
             response_lines.push(format!("Session started at `{}`.", canonical_path));
         }
     }
@@ -1559,7 +1797,7 @@ async fn handle_stop_command(
             }
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ■ Cancel signal sent");
+            console!("  [{ts}] ■ Cancel signal sent");
         }
         None => {
             shared_rate_limit_wait(state, chat_id).await;
@@ -1577,14 +1815,14 @@ async fn handle_down_command(
     chat_id: ChatId,
     text: &str,
     state: &SharedState,
-) -> ResponseResult<()> {
+) -> ResponseResult<Option<String>> {
     let file_path = text.strip_prefix("/down").unwrap_or("").trim();
 
     if file_path.is_empty() {
         shared_rate_limit_wait(state, chat_id).await;
         tg!("send_message", bot.send_message(chat_id, "Usage: /down <filepath>\nExample: /down /home/kst/file.txt")
             .await)?;
-        return Ok(());
+        return Ok(None);
     }
 
     // Resolve relative path using current session path
@@ -1601,7 +1839,7 @@ async fn handle_down_command(
                 shared_rate_limit_wait(state, chat_id).await;
                 tg!("send_message", bot.send_message(chat_id, "No active session. Use absolute path or /start <path> first.")
                     .await)?;
-                return Ok(());
+                return Ok(None);
             }
         }
     };
@@ -1610,39 +1848,51 @@ async fn handle_down_command(
     if !path.exists() {
         shared_rate_limit_wait(state, chat_id).await;
         tg!("send_message", bot.send_message(chat_id, &format!("File not found: {}", resolved_path)).await)?;
-        return Ok(());
+        return Ok(None);
     }
     if !path.is_file() {
         shared_rate_limit_wait(state, chat_id).await;
         tg!("send_message", bot.send_message(chat_id, &format!("Not a file: {}", resolved_path)).await)?;
-        return Ok(());
+        return Ok(None);
     }
 
     shared_rate_limit_wait(state, chat_id).await;
     tg!("send_document", bot.send_document(chat_id, teloxide::types::InputFile::file(path))
         .await)?;
 
-    Ok(())
+    Ok(Some(resolved_path))
 }
 
-/// Handle file/photo upload - save to current session path
+/// Handle file/photo upload - save to current session path, or to the directory
+/// of the first matching download rule for documents sent by the owner.
+/// Returns the saved path.
 async fn handle_file_upload(
     bot: &Bot,
     chat_id: ChatId,
     msg: &Message,
     state: &SharedState,
-) -> ResponseResult<()> {
-    // Get current session path
-    let current_path = {
+    is_owner: bool,
+) -> ResponseResult<Option<String>> {
+    // Get current session path and the matching download rule
+    let document_name = msg.document().and_then(|d| d.file_name.clone());
+    let (current_path, rule) = {
         let data = state.lock().await;
-        data.sessions.get(&chat_id).and_then(|s| s.current_path.clone())
+        let rule = document_name.as_deref()
+            .filter(|_| is_owner)
+            .and_then(|name| find_download_rule(&data.settings.download_rules, name))
+            .cloned();
+        (data.sessions.get(&chat_id).and_then(|s| s.current_path.clone()), rule)
     };
 
-    let Some(save_dir) = current_path else {
-        shared_rate_limit_wait(state, chat_id).await;
-        tg!("send_message", bot.send_message(chat_id, "No active session. Use /start <path> first.")
-            .await)?;
-        return Ok(());
+    let save_dir = match (&rule, &current_path) {
+        (Some(rule), _) => crate::utils::glob::expand_home(&rule.dir).display().to_string(),
+        (None, Some(path)) => path.clone(),
+        (None, None) => {
+            shared_rate_limit_wait(state, chat_id).await;
+            tg!("send_message", bot.send_message(chat_id, "No active session. Use /start <path> first.")
+                .await)?;
+            return Ok(None);
+        }
     };
 
     // Get file_id and file_name
@@ -1655,10 +1905,10 @@ async fn handle_file_upload(
             let name = format!("photo_{}.jpg", photo.file.unique_id);
             (photo.file.id.clone(), name)
         } else {
-            return Ok(());
+            return Ok(None);
         }
     } else {
-        return Ok(());
+        return Ok(None);
     };
 
     // Download file from Telegram via HTTP
@@ -1671,13 +1921,13 @@ async fn handle_file_upload(
             Err(e) => {
                 shared_rate_limit_wait(state, chat_id).await;
                 tg!("send_message", bot.send_message(chat_id, &format!("Download failed: {}", e)).await)?;
-                return Ok(());
+                return Ok(None);
             }
         },
         Err(e) => {
             shared_rate_limit_wait(state, chat_id).await;
             tg!("send_message", bot.send_message(chat_id, &format!("Download failed: {}", e)).await)?;
-            return Ok(());
+            return Ok(None);
         }
     };
 
@@ -1687,16 +1937,26 @@ async fn handle_file_upload(
         .unwrap_or_else(|| std::ffi::OsStr::new("uploaded_file"));
     let dest = Path::new(&save_dir).join(safe_name);
     let file_size = buf.len();
+    if rule.is_some() {
+        if let Err(e) = fs::create_dir_all(&save_dir) {
+            shared_rate_limit_wait(state, chat_id).await;
+            tg!("send_message", bot.send_message(chat_id, &format!("Failed to create {}: {}", save_dir, e)).await)?;
+            return Ok(None);
+        }
+    }
     match fs::write(&dest, &buf) {
         Ok(_) => {
-            let msg_text = format!("Saved: {}\n({} bytes)", dest.display(), file_size);
+            let mut msg_text = format!("Saved: {}\n({} bytes)", dest.display(), file_size);
+            if let Some(ref rule) = rule {
+                msg_text.push_str(&format!("\nRule: {} → {}", rule.pattern, rule.dir));
+            }
             shared_rate_limit_wait(state, chat_id).await;
             tg!("send_message", bot.send_message(chat_id, &msg_text).await)?;
         }
         Err(e) => {
             shared_rate_limit_wait(state, chat_id).await;
             tg!("send_message", bot.send_message(chat_id, &format!("Failed to save file: {}", e)).await)?;
-            return Ok(());
+            return Ok(None);
         }
     }
 
@@ -1711,13 +1971,16 @@ async fn handle_file_upload(
             session.history.push(HistoryItem {
                 item_type: HistoryType::User,
                 content: upload_record.clone(),
+                tool_calls: Vec::new(),
             });
             session.pending_uploads.push(upload_record);
-            save_session_to_file(session, &save_dir);
+            if let Some(ref path) = current_path {
+                save_session_to_file(session, path);
+            }
         }
     }
 
-    Ok(())
+    Ok(Some(dest.display().to_string()))
 }
 
 /// Shell command output message type
@@ -1841,7 +2104,7 @@ async fn handle_shell_command(
     let bot_owned = bot.clone();
     let state_owned = state.clone();
     let cmd_display_owned = cmd_display.clone();
-    tokio::spawn(async move {
+    bot_console::spawn(async move {
         const SPINNER: &[&str] = &[
             "🕐 P",           "🕑 Pr",          "🕒 Pro",
             "🕓 Proc",        "🕔 Proce",       "🕕 Proces",
@@ -1979,7 +2242,7 @@ async fn handle_shell_command(
                 }
 
                 let ts = chrono::Local::now().format("%H:%M:%S");
-                println!("  [{ts}] ▶ Shell command completed: !{}", cmd_display_owned);
+                console!("  [{ts}] ▶ Shell command completed: !{}", cmd_display_owned);
             }
 
             // Queue processing
@@ -2013,7 +2276,7 @@ async fn handle_shell_command(
             }
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ■ Shell command stopped: !{}", cmd_display_owned);
+            console!("  [{ts}] ■ Shell command stopped: !{}", cmd_display_owned);
 
             let mut data = state_owned.lock().await;
             data.cancel_tokens.remove(&chat_id);
@@ -2158,10 +2421,8 @@ async fn handle_debug_command(
     state: &SharedState,
     token: &str,
 ) -> ResponseResult<()> {
-    let prev = TG_DEBUG.load(Ordering::Relaxed);
-    let next = !prev;
-    TG_DEBUG.store(next, Ordering::Relaxed);
-    crate::services::claude::DEBUG_ENABLED.store(next, Ordering::Relaxed);
+    let next = !logging::debug_mode();
+    logging::set_debug_mode(next);
     {
         let mut data = state.lock().await;
         data.settings.debug = next;
@@ -2169,12 +2430,193 @@ async fn handle_debug_command(
     }
     let status = if next { "ON" } else { "OFF" };
     shared_rate_limit_wait(state, chat_id).await;
-    tg!("send_message", bot.send_message(chat_id, format!("🔍 Debug logging: {status}"))
+    tg!("send_message", bot.send_message(chat_id, format!("🔍 Debug logging: {status} (~/.cokacdir/logs)"))
         .await)?;
     Ok(())
 }
 
 
+/// Handle /restart command - finish running requests, then re-exec the binary (owner only)
+async fn handle_restart_command(
+    bot: &Bot,
+    chat_id: ChatId,
+    state: &SharedState,
+    is_owner: bool,
+) -> ResponseResult<()> {
+    if !is_owner {
+        shared_rate_limit_wait(state, chat_id).await;
+        tg!("send_message", bot.send_message(chat_id, "Only the bot owner can restart the server.")
+            .await)?;
+        return Ok(());
+    }
+    shared_rate_limit_wait(state, chat_id).await;
+    tg!("send_message", bot.send_message(chat_id, "Restart requested.").await)?;
+    request_shutdown(ShutdownMode::Restart);
+    Ok(())
+}
+
+/// Handle /audit command - show the last entries of the audit log (owner only)
+/// Usage: /audit [count]
+async fn handle_audit_command(
+    bot: &Bot,
+    chat_id: ChatId,
+    text: &str,
+    state: &SharedState,
+    is_owner: bool,
+) -> ResponseResult<()> {
+    if !is_owner {
+        shared_rate_limit_wait(state, chat_id).await;
+        tg!("send_message", bot.send_message(chat_id, "Only the bot owner can read the audit log.")
+            .await)?;
+        return Ok(());
+    }
+
+    let arg = text.strip_prefix("/audit").unwrap_or("").trim();
+    let count = if arg.is_empty() { 20 } else {
+        match arg.parse::<usize>() {
+            Ok(n) if n > 0 => n.min(100),
+            _ => {
+                shared_rate_limit_wait(state, chat_id).await;
+                tg!("send_message", bot.send_message(chat_id, "Usage: /audit [count] (1-100, default 20)")
+                    .await)?;
+                return Ok(());
+            }
+        }
+    };
+
+    let entries = audit::read_recent(count);
+    let msg = if entries.is_empty() {
+        "Audit log is empty.".to_string()
+    } else {
+        let lines: Vec<String> = entries.iter().map(|e| e.format_line()).collect();
+        format!("Last {} audit entries:\n\n{}", entries.len(), lines.join("\n"))
+    };
+    send_long_message(bot, chat_id, &msg, None, state).await
+}
+
+/// Parsed arguments of the /saveto command
+#[derive(Debug, PartialEq)]
+enum SaveToArgs {
+    /// No arguments: show the rules
+    List,
+    /// `-<pattern>`: remove the rule for a pattern
+    Remove(String),
+    /// `<pattern> <dir>`: add a rule or replace the directory of an existing one
+    Set { pattern: String, dir: String },
+    /// Anything else
+    Usage,
+}
+
+fn parse_saveto_args(arg: &str) -> SaveToArgs {
+    let arg = arg.trim();
+    if arg.is_empty() {
+        return SaveToArgs::List;
+    }
+    if let Some(pattern) = arg.strip_prefix('-') {
+        let pattern = pattern.trim();
+        return if pattern.is_empty() { SaveToArgs::Usage } else { SaveToArgs::Remove(pattern.to_string()) };
+    }
+    match arg.split_once(char::is_whitespace) {
+        Some((pattern, dir)) if !dir.trim().is_empty() => SaveToArgs::Set {
+            pattern: pattern.to_string(),
+            dir: dir.trim().to_string(),
+        },
+        _ => SaveToArgs::Usage,
+    }
+}
+
+/// Check a /saveto directory: must be absolute (or `~`-relative) and not an existing file
+fn validate_saveto_dir(dir: &str) -> Result<std::path::PathBuf, String> {
+    let expanded = crate::utils::glob::expand_home(dir);
+    if !expanded.is_absolute() {
+        Err(format!("Directory must be absolute or start with ~: {}", dir))
+    } else if expanded.exists() && !expanded.is_dir() {
+        Err(format!("Not a directory: {}", expanded.display()))
+    } else {
+        Ok(expanded)
+    }
+}
+
+/// Add a rule, or replace the directory if the pattern already has one (keeps its position)
+fn set_download_rule(rules: &mut Vec<DownloadRule>, pattern: &str, dir: &str) {
+    match rules.iter_mut().find(|r| r.pattern == pattern) {
+        Some(rule) => rule.dir = dir.to_string(),
+        None => rules.push(DownloadRule { pattern: pattern.to_string(), dir: dir.to_string() }),
+    }
+}
+
+/// Remove the rule for `pattern`. Returns false if there was none.
+fn remove_download_rule(rules: &mut Vec<DownloadRule>, pattern: &str) -> bool {
+    let before = rules.len();
+    rules.retain(|r| r.pattern != pattern);
+    rules.len() != before
+}
+
+/// Handle /saveto command - owner upload routing rules
+/// Usage: /saveto                   (list rules)
+///        /saveto *.pdf ~/Documents (add or replace rule)
+///        /saveto -*.pdf            (remove rule)
+async fn handle_saveto_command(
+    bot: &Bot,
+    chat_id: ChatId,
+    text: &str,
+    state: &SharedState,
+    token: &str,
+    is_owner: bool,
+) -> ResponseResult<()> {
+    // Rules reveal the owner's directory layout, so listing is owner-only too
+    if !is_owner {
+        shared_rate_limit_wait(state, chat_id).await;
+        tg!("send_message", bot.send_message(chat_id, "Only the bot owner can view or change upload rules.")
+            .await)?;
+        return Ok(());
+    }
+
+    let arg = text.strip_prefix("/saveto").unwrap_or("");
+
+    let response_msg = match parse_saveto_args(arg) {
+        SaveToArgs::List => {
+            let rules = {
+                let data = state.lock().await;
+                data.settings.download_rules.clone()
+            };
+            if rules.is_empty() {
+                "No upload rules. Documents are saved to the session directory.\n\
+                 Usage: /saveto <pattern> <dir> (e.g. /saveto *.pdf ~/Documents)".to_string()
+            } else {
+                let mut msg = String::from("Upload rules (first match wins):");
+                for rule in &rules {
+                    msg.push_str(&format!("\n{} → {}", rule.pattern, rule.dir));
+                }
+                msg
+            }
+        }
+        SaveToArgs::Remove(pattern) => {
+            let mut data = state.lock().await;
+            if remove_download_rule(&mut data.settings.download_rules, &pattern) {
+                save_bot_settings(token, &data.settings);
+                format!("Removed rule: {}", pattern)
+            } else {
+                format!("No rule for {}", pattern)
+            }
+        }
+        SaveToArgs::Set { pattern, dir } => match validate_saveto_dir(&dir) {
+            Err(msg) => msg,
+            Ok(_) => {
+                let mut data = state.lock().await;
+                set_download_rule(&mut data.settings.download_rules, &pattern, &dir);
+                save_bot_settings(token, &data.settings);
+                format!("Documents matching {} will be saved to {}", pattern, dir)
+            }
+        },
+        SaveToArgs::Usage => "Usage:\n/saveto <pattern> <dir> — Add rule\n/saveto -<pattern> — Remove rule\n/saveto — Show rules".to_string(),
+    };
+
+    shared_rate_limit_wait(state, chat_id).await;
+    tg!("send_message", bot.send_message(chat_id, response_msg).await)?;
+    Ok(())
+}
+
 /// Handle /allowed command - add/remove tools
 /// Usage: /allowed +toolname  (add)
 ///        /allowed -toolname  (remove)
@@ -2535,6 +2977,7 @@ async fn handle_text_message(
             Some(cancel_token_clone),
             model.as_deref(),
             false,
+            None,
         );
 
         if let Err(e) = result {
@@ -2547,7 +2990,7 @@ async fn handle_text_message(
     let bot_owned = bot.clone();
     let state_owned = state.clone();
     let user_text_owned = user_text.to_string();
-    tokio::spawn(async move {
+    bot_console::spawn(async move {
         const SPINNER: &[&str] = &[
             "🕐 P",           "🕑 Pr",          "🕒 Pro",
             "🕓 Proc",        "🕔 Proce",       "🕕 Proces",
@@ -2562,6 +3005,7 @@ async fn handle_text_message(
         let mut spin_idx: usize = 0;
         let mut pending_cokacdir_cmd: Option<String> = None;
         let mut last_tool_name: String = String::new();
+        let mut tools_used: Vec<String> = Vec::new();
 
 
         let polling_time_ms = {
@@ -2602,9 +3046,12 @@ async fn handle_text_message(
                                 StreamMessage::ToolUse { name, input } => {
                                     pending_cokacdir_cmd = detect_cokacdir_command(&name, &input);
                                     last_tool_name = name.clone();
+                                    if !tools_used.contains(&name) {
+                                        tools_used.push(name.clone());
+                                    }
                                     let summary = format_tool_input(&name, &input);
                                     let ts = chrono::Local::now().format("%H:%M:%S");
-                                    println!("  [{ts}]   ⚙ {name}: {summary}");
+                                    console!("  [{ts}]   ⚙ {name}: {summary}");
                                     if pending_cokacdir_cmd.is_none() {
                                         if name == "Bash" {
                                             full_response.push_str(&format!("\n\n```\n{}\n```\n", format_bash_command(&input)));
@@ -2616,14 +3063,14 @@ async fn handle_text_message(
                                 StreamMessage::ToolResult { content, is_error } => {
                                     if let Some(cmd) = pending_cokacdir_cmd.take() {
                                         let ts = chrono::Local::now().format("%H:%M:%S");
-                                        println!("  [{ts}]   ↩ cokacdir --{cmd}: {content}");
+                                        console!("  [{ts}]   ↩ cokacdir --{cmd}: {content}");
                                         let formatted = format_cokacdir_result(&cmd, &content);
                                         if !formatted.is_empty() {
                                             full_response.push_str(&format!("\n{}\n", formatted));
                                         }
                                     } else if is_error {
                                         let ts = chrono::Local::now().format("%H:%M:%S");
-                                        println!("  [{ts}]   ✗ Error: {content}");
+                                        console!("  [{ts}]   ✗ Error: {content}");
                                         let truncated = truncate_str(&content, 500);
                                         if truncated.contains('\n') {
                                             full_response.push_str(&format!("\n❌\n```\n{}\n```\n", truncated));
@@ -2699,7 +3146,7 @@ async fn handle_text_message(
                         .await)
                     {
                         let ts = chrono::Local::now().format("%H:%M:%S");
-                        println!("  [{ts}]   ⚠ edit_message failed (streaming): {e}");
+                        console!("  [{ts}]   ⚠ edit_message failed (streaming): {e}");
                     }
                     last_edit_text = display_text;
                 } else if !done {
@@ -2735,7 +3182,7 @@ async fn handle_text_message(
                         .await)
                     {
                         let ts = chrono::Local::now().format("%H:%M:%S");
-                        println!("  [{ts}]   ⚠ edit_message failed (HTML): {e}");
+                        console!("  [{ts}]   ⚠ edit_message failed (HTML): {e}");
                         shared_rate_limit_wait(&state_owned, chat_id).await;
                         let _ = tg!("edit_message", bot_owned.edit_message_text(chat_id, placeholder_msg_id, &final_response)
                             .await);
@@ -2749,7 +3196,7 @@ async fn handle_text_message(
                         }
                         Err(e) => {
                             let ts = chrono::Local::now().format("%H:%M:%S");
-                            println!("  [{ts}]   ⚠ send_long_message failed (HTML): {e}");
+                            console!("  [{ts}]   ⚠ send_long_message failed (HTML): {e}");
                             let fallback_result = send_long_message(&bot_owned, chat_id, &final_response, None, &state_owned).await;
                             match fallback_result {
                                 Ok(_) => {
@@ -2757,7 +3204,7 @@ async fn handle_text_message(
                                     let _ = tg!("delete_message", bot_owned.delete_message(chat_id, placeholder_msg_id).await);
                                 }
                                 Err(e2) => {
-                                    println!("  [{ts}]   ⚠ send_long_message failed (plain): {e2}");
+                                    console!("  [{ts}]   ⚠ send_long_message failed (plain): {e2}");
                                     shared_rate_limit_wait(&state_owned, chat_id).await;
                                     let truncated = truncate_str(&final_response, TELEGRAM_MSG_LIMIT);
                                     let _ = tg!("edit_message", bot_owned.edit_message_text(chat_id, placeholder_msg_id, &truncated)
@@ -2785,10 +3232,12 @@ async fn handle_text_message(
                             session.history.push(HistoryItem {
                                 item_type: HistoryType::User,
                                 content: user_text_owned.clone(),
+                                tool_calls: Vec::new(),
                             });
                             session.history.push(HistoryItem {
                                 item_type: HistoryType::Assistant,
                                 content: final_response,
+                                tool_calls: Vec::new(),
                             });
                             save_session_to_file(session, &current_path);
                         }
@@ -2796,7 +3245,7 @@ async fn handle_text_message(
                 }
 
                 let ts = chrono::Local::now().format("%H:%M:%S");
-                println!("  [{ts}] ▶ Response sent");
+                console!("  [{ts}] ▶ Response sent");
             }
 
             // === Queue processing (both during streaming and after done) ===
@@ -2806,6 +3255,9 @@ async fn handle_text_message(
             }
         }
 
+        let outcome = if cancelled { "stopped" } else { "completed" };
+        audit::record(AuditEntry::new(chat_id.0, AuditKind::AiResult, outcome).with_tools(tools_used));
+
         // === Post-loop: cancelled handling or lock release ===
         if cancelled {
             if let Ok(guard) = cancel_token.child_pid.lock() {
@@ -2833,7 +3285,7 @@ async fn handle_text_message(
                     .await)
                 {
                     let ts_err = chrono::Local::now().format("%H:%M:%S");
-                    println!("  [{ts_err}]   ⚠ edit_message failed (stopped/HTML): {e}");
+                    console!("  [{ts_err}]   ⚠ edit_message failed (stopped/HTML): {e}");
                     shared_rate_limit_wait(&state_owned, chat_id).await;
                     let _ = tg!("edit_message", bot_owned.edit_message_text(chat_id, placeholder_msg_id, &stopped_response)
                         .await);
@@ -2847,7 +3299,7 @@ async fn handle_text_message(
                     }
                     Err(e) => {
                         let ts_err = chrono::Local::now().format("%H:%M:%S");
-                        println!("  [{ts_err}]   ⚠ send_long_message failed (stopped/HTML): {e}");
+                        console!("  [{ts_err}]   ⚠ send_long_message failed (stopped/HTML): {e}");
                         let fallback = send_long_message(&bot_owned, chat_id, &stopped_response, None, &state_owned).await;
                         match fallback {
                             Ok(_) => {
@@ -2875,7 +3327,7 @@ async fn handle_text_message(
             }
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ■ Stopped");
+            console!("  [{ts}] ■ Stopped");
 
             let mut data = state_owned.lock().await;
             if let Some(session) = data.sessions.get_mut(&chat_id) {
@@ -2886,10 +3338,12 @@ async fn handle_text_message(
                     session.history.push(HistoryItem {
                         item_type: HistoryType::User,
                         content: user_text_owned,
+                        tool_calls: Vec::new(),
                     });
                     session.history.push(HistoryItem {
                         item_type: HistoryType::Assistant,
                         content: stopped_response,
+                        tool_calls: Vec::new(),
                     });
                     save_session_to_file(session, &current_path);
                 }
@@ -3080,11 +3534,11 @@ async fn process_upload_queue(bot: &Bot, chat_id: ChatId, state: &SharedState) -
         ).await) {
             Ok(_) => {
                 let ts = chrono::Local::now().format("%H:%M:%S");
-                println!("  [{ts}]   📤 Upload sent: {}", file_path);
+                console!("  [{ts}]   📤 Upload sent: {}", file_path);
             }
             Err(e) => {
                 let ts = chrono::Local::now().format("%H:%M:%S");
-                println!("  [{ts}]   ⚠ Upload failed: {e}");
+                console!("  [{ts}]   ⚠ Upload failed: {e}");
             }
         }
         return true;
@@ -3708,21 +4162,21 @@ fn format_tool_input(name: &str, input: &str) -> String {
 /// Check if a schedule entry should trigger now
 fn should_trigger(entry: &ScheduleEntry) -> bool {
     let now = chrono::Local::now();
-    sched_debug(&format!("[should_trigger] id={}, type={}, schedule={}, now={}, last_run={:?}",
-        entry.id, entry.schedule_type, entry.schedule, now.format("%Y-%m-%d %H:%M:%S"), entry.last_run));
+    log_debug!("cron", "[should_trigger] id={}, type={}, schedule={}, now={}, last_run={:?}",
+        entry.id, entry.schedule_type, entry.schedule, now.format("%Y-%m-%d %H:%M:%S"), entry.last_run);
     match entry.schedule_type.as_str() {
         "absolute" => {
             let Ok(schedule_time) = chrono::NaiveDateTime::parse_from_str(&entry.schedule, "%Y-%m-%d %H:%M:%S") else {
-                sched_debug(&format!("[should_trigger] id={}, parse failed → false", entry.id));
+                log_debug!("cron", "[should_trigger] id={}, parse failed → false", entry.id);
                 return false;
             };
             let schedule_dt = schedule_time.and_local_timezone(chrono::Local).single();
             let Some(schedule_dt) = schedule_dt else {
-                sched_debug(&format!("[should_trigger] id={}, timezone conversion failed → false", entry.id));
+                log_debug!("cron", "[should_trigger] id={}, timezone conversion failed → false", entry.id);
                 return false;
             };
             if now < schedule_dt {
-                sched_debug(&format!("[should_trigger] id={}, not yet (now < schedule_dt) → false", entry.id));
+                log_debug!("cron", "[should_trigger] id={}, not yet (now < schedule_dt) → false", entry.id);
                 return false;
             }
             // Already ran?
@@ -3730,19 +4184,19 @@ fn should_trigger(entry: &ScheduleEntry) -> bool {
                 if let Ok(last_dt) = chrono::NaiveDateTime::parse_from_str(last, "%Y-%m-%d %H:%M:%S") {
                     if let Some(last_local) = last_dt.and_local_timezone(chrono::Local).single() {
                         if last_local >= schedule_dt {
-                            sched_debug(&format!("[should_trigger] id={}, already ran (last={} >= sched={}) → false",
-                                entry.id, last_local.format("%H:%M:%S"), schedule_dt.format("%H:%M:%S")));
+                            log_debug!("cron", "[should_trigger] id={}, already ran (last={} >= sched={}) → false",
+                                entry.id, last_local.format("%H:%M:%S"), schedule_dt.format("%H:%M:%S"));
                             return false;
                         }
                     }
                 }
             }
-            sched_debug(&format!("[should_trigger] id={}, absolute ready → true", entry.id));
+            log_debug!("cron", "[should_trigger] id={}, absolute ready → true", entry.id);
             true
         }
         "cron" => {
             if !cron_matches(&entry.schedule, now) {
-                sched_debug(&format!("[should_trigger] id={}, cron not matching → false", entry.id));
+                log_debug!("cron", "[should_trigger] id={}, cron not matching → false", entry.id);
                 return false;
             }
             // Check last_run to avoid duplicate triggers within the same minute
@@ -3752,17 +4206,17 @@ fn should_trigger(entry: &ScheduleEntry) -> bool {
                         let now_min = now.format("%Y-%m-%d %H:%M").to_string();
                         let last_min = last_local.format("%Y-%m-%d %H:%M").to_string();
                         if now_min == last_min {
-                            sched_debug(&format!("[should_trigger] id={}, already ran this minute ({}) → false", entry.id, now_min));
+                            log_debug!("cron", "[should_trigger] id={}, already ran this minute ({}) → false", entry.id, now_min);
                             return false;
                         }
                     }
                 }
             }
-            sched_debug(&format!("[should_trigger] id={}, cron matched → true", entry.id));
+            log_debug!("cron", "[should_trigger] id={}, cron matched → true", entry.id);
             true
         }
         _ => {
-            sched_debug(&format!("[should_trigger] id={}, unknown type={} → false", entry.id, entry.schedule_type));
+            log_debug!("cron", "[should_trigger] id={}, unknown type={} → false", entry.id, entry.schedule_type);
             false
         }
     }
@@ -3771,36 +4225,36 @@ fn should_trigger(entry: &ScheduleEntry) -> bool {
 /// Update schedule entry after a run: set last_run, delete if once
 fn update_schedule_after_run(entry: &ScheduleEntry, new_context_summary: Option<String>) {
     let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-    sched_debug(&format!("[update_schedule_after_run] id={}, type={}, once={:?}, now={}, has_new_context={}",
-        entry.id, entry.schedule_type, entry.once, now, new_context_summary.is_some()));
+    log_debug!("cron", "[update_schedule_after_run] id={}, type={}, once={:?}, now={}, has_new_context={}",
+        entry.id, entry.schedule_type, entry.once, now, new_context_summary.is_some());
 
     // 실행 중 사용자가 삭제한 경우 부활 방지
     let dir = match schedule_dir() {
         Some(d) => d,
         None => {
-            sched_debug(&format!("[update_schedule_after_run] id={}, no schedule dir → skip", entry.id));
+            log_debug!("cron", "[update_schedule_after_run] id={}, no schedule dir → skip", entry.id);
             return;
         }
     };
     let path = dir.join(format!("{}.json", entry.id));
     if !path.exists() {
-        sched_debug(&format!("[update_schedule_after_run] id={}, file already deleted → skip (no resurrection)", entry.id));
+        log_debug!("cron", "[update_schedule_after_run] id={}, file already deleted → skip (no resurrection)", entry.id);
         return; // 이미 삭제됨 - write하지 않음
     }
 
     // One-time schedules (absolute / cron --once) are already deleted before execution,
     // so this function only handles recurring cron updates.
-    sched_debug(&format!("[update_schedule_after_run] id={}, cron recurring → update last_run", entry.id));
+    log_debug!("cron", "[update_schedule_after_run] id={}, cron recurring → update last_run", entry.id);
     let mut updated = entry.clone();
     updated.last_run = Some(now);
     if new_context_summary.is_some() {
         updated.context_summary = new_context_summary;
     }
     if let Err(e) = write_schedule_entry(&updated) {
-        sched_debug(&format!("[update_schedule_after_run] id={}, write failed: {}", entry.id, e));
+        log_debug!("cron", "[update_schedule_after_run] id={}, write failed: {}", entry.id, e);
         eprintln!("[Schedule] Failed to update entry {}: {}", entry.id, e);
     } else {
-        sched_debug(&format!("[update_schedule_after_run] id={}, updated successfully", entry.id));
+        log_debug!("cron", "[update_schedule_after_run] id={}, updated successfully", entry.id);
     }
 }
 
@@ -3813,12 +4267,12 @@ async fn execute_schedule(
     token: &str,
     prev_session: Option<ChatSession>,
 ) {
-    sched_debug(&format!("[execute_schedule] START id={}, chat_id={}, prompt={:?}, has_context={}, has_prev_session={}",
-        entry.id, chat_id, truncate_str(&entry.prompt, 60), entry.context_summary.is_some(), prev_session.is_some()));
+    log_debug!("cron", "[execute_schedule] START id={}, chat_id={}, prompt={:?}, has_context={}, has_prev_session={}",
+        entry.id, chat_id, truncate_str(&entry.prompt, 60), entry.context_summary.is_some(), prev_session.is_some());
     // Build prompt with context summary if available
     let user_prompt = entry.prompt.clone();
     let prompt = if let Some(ref summary) = entry.context_summary {
-        sched_debug(&format!("[execute_schedule] id={}, injecting context summary ({} chars)", entry.id, summary.len()));
+        log_debug!("cron", "[execute_schedule] id={}, injecting context summary ({} chars)", entry.id, summary.len());
         format!(
             "[이전 작업 맥락]\n{}\n\n[작업 지시]\n{}",
             summary, user_prompt
@@ -3831,22 +4285,22 @@ async fn execute_schedule(
 
     // Delete schedule files before execution for one-time schedules (absolute / cron --once)
     if entry.once.unwrap_or(false) || entry.schedule_type == "absolute" {
-        sched_debug(&format!("[execute_schedule] id={}, one-time → deleting schedule files before execution", schedule_id));
+        log_debug!("cron", "[execute_schedule] id={}, one-time → deleting schedule files before execution", schedule_id);
         delete_schedule_entry(&schedule_id);
     }
 
     let ts = chrono::Local::now().format("%H:%M:%S");
-    println!("  [{ts}] ⏰ Schedule Starting: {user_prompt}");
+    console!("  [{ts}] ⏰ Schedule Starting: {user_prompt}");
 
     // Create persistent workspace directory for this schedule execution
     let workspace_dir = dirs::home_dir()
         .map(|h| h.join(".cokacdir").join("workspace").join(&schedule_id))
         .unwrap_or_else(|| std::path::PathBuf::from("/tmp").join("cokacdir-workspace").join(&schedule_id));
-    sched_debug(&format!("[execute_schedule] id={}, creating workspace: {}", schedule_id, workspace_dir.display()));
+    log_debug!("cron", "[execute_schedule] id={}, creating workspace: {}", schedule_id, workspace_dir.display());
     if let Err(e) = fs::create_dir_all(&workspace_dir) {
         let ts = chrono::Local::now().format("%H:%M:%S");
-        println!("  [{ts}] ⚠ [Schedule] Failed to create workspace: {e}");
-        sched_debug(&format!("[execute_schedule] id={}, workspace creation failed: {}, restoring session", schedule_id, e));
+        console!("  [{ts}] ⚠ [Schedule] Failed to create workspace: {e}");
+        log_debug!("cron", "[execute_schedule] id={}, workspace creation failed: {}, restoring session", schedule_id, e);
         let mut data = state.lock().await;
         if let Some(set) = data.pending_schedules.get_mut(&chat_id) {
             set.remove(&schedule_id);
@@ -3873,7 +4327,7 @@ async fn execute_schedule(
         Ok(msg) => msg,
         Err(e) => {
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ⚠ [Schedule] Failed to send placeholder: {e}");
+            console!("  [{ts}] ⚠ [Schedule] Failed to send placeholder: {e}");
             // Clean up pending + cancel_token, restore session (workspace preserved)
             let mut data = state.lock().await;
             if let Some(set) = data.pending_schedules.get_mut(&chat_id) {
@@ -3953,6 +4407,7 @@ async fn execute_schedule(
             Some(cancel_token_clone),
             model.as_deref(),
             false,
+            None,
         );
         if let Err(e) = result {
             let _ = tx.send(StreamMessage::Error { message: e, stdout: String::new(), stderr: String::new(), exit_code: None });
@@ -3964,7 +4419,7 @@ async fn execute_schedule(
     let state_owned = state.clone();
     let entry_clone = entry.clone();
     let workspace_path_owned = workspace_path.clone();
-    tokio::spawn(async move {
+    bot_console::spawn(async move {
         const SPINNER: &[&str] = &[
             "🕐 P",           "🕑 Pr",          "🕒 Pro",
             "🕓 Proc",        "🕔 Proce",       "🕕 Proces",
@@ -4016,7 +4471,7 @@ async fn execute_schedule(
                                 last_tool_name = name.clone();
                                 let summary = format_tool_input(&name, &input);
                                 let ts = chrono::Local::now().format("%H:%M:%S");
-                                println!("  [{ts}]   ⚙ [Schedule] {name}: {summary}");
+                                console!("  [{ts}]   ⚙ [Schedule] {name}: {summary}");
                                 if pending_cokacdir_cmd.is_none() {
                                     if name == "Bash" {
                                         full_response.push_str(&format!("\n\n```\n{}\n```\n", format_bash_command(&input)));
@@ -4028,7 +4483,7 @@ async fn execute_schedule(
                             StreamMessage::ToolResult { content, is_error } => {
                                 if let Some(cmd) = pending_cokacdir_cmd.take() {
                                     let ts = chrono::Local::now().format("%H:%M:%S");
-                                    println!("  [{ts}]   ↩ [Schedule] cokacdir --{cmd}: {content}");
+                                    console!("  [{ts}]   ↩ [Schedule] cokacdir --{cmd}: {content}");
                                     let formatted = format_cokacdir_result(&cmd, &content);
                                     if !formatted.is_empty() {
                                         full_response.push_str(&format!("\n{}\n", formatted));
@@ -4126,10 +4581,10 @@ async fn execute_schedule(
         }
 
         // Final response
-        sched_debug(&format!("[execute_schedule] id={}, polling done: cancelled={}, had_error={}, response_len={}",
-            schedule_id, cancelled, had_error, full_response.len()));
+        log_debug!("cron", "[execute_schedule] id={}, polling done: cancelled={}, had_error={}, response_len={}",
+            schedule_id, cancelled, had_error, full_response.len());
         if cancelled {
-            sched_debug(&format!("[execute_schedule] id={}, cancelled — killing child process", schedule_id));
+            log_debug!("cron", "[execute_schedule] id={}, cancelled — killing child process", schedule_id);
             if let Ok(guard) = cancel_token.child_pid.lock() {
                 if let Some(pid) = *guard {
                     #[cfg(unix)]
@@ -4144,7 +4599,7 @@ async fn execute_schedule(
             let _ = tg!("edit_message", bot_owned.edit_message_text(chat_id, placeholder_msg_id, stopped_text).await);
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ■ [Schedule] Stopped");
+            console!("  [{ts}] ■ [Schedule] Stopped");
         } else {
             if full_response.is_empty() {
                 full_response = "(No response)".to_string();
@@ -4187,15 +4642,15 @@ async fn execute_schedule(
             }
 
             let ts = chrono::Local::now().format("%H:%M:%S");
-            println!("  [{ts}] ✓ [Schedule] Done");
+            console!("  [{ts}] ✓ [Schedule] Done");
         }
 
         // For cron entries with context_summary, extract result summary for next run
         // Skip if execution was cancelled or encountered an error
-        sched_debug(&format!("[execute_schedule] id={}, checking context summary: cancelled={}, had_error={}, type={}, once={:?}, has_context={}",
-            schedule_id, cancelled, had_error, entry_clone.schedule_type, entry_clone.once, entry_clone.context_summary.is_some()));
+        log_debug!("cron", "[execute_schedule] id={}, checking context summary: cancelled={}, had_error={}, type={}, once={:?}, has_context={}",
+            schedule_id, cancelled, had_error, entry_clone.schedule_type, entry_clone.once, entry_clone.context_summary.is_some());
         let new_context_summary = if !cancelled && !had_error && entry_clone.schedule_type == "cron" && !entry_clone.once.unwrap_or(false) && entry_clone.context_summary.is_some() {
-            sched_debug(&format!("[execute_schedule] id={}, extracting result summary", schedule_id));
+            log_debug!("cron", "[execute_schedule] id={}, extracting result summary", schedule_id);
             if let Some(ref sid) = exec_session_id {
                 let sid = sid.clone();
                 let path = workspace_path_owned.clone();
@@ -4209,16 +4664,16 @@ async fn execute_schedule(
                 }).await;
                 match summary_result {
                     Ok(Ok(ref summary)) => {
-                        sched_debug(&format!("[execute_schedule] id={}, new context summary: {} chars", schedule_id, summary.len()));
+                        log_debug!("cron", "[execute_schedule] id={}, new context summary: {} chars", schedule_id, summary.len());
                         Some(summary.clone())
                     }
                     _ => {
-                        sched_debug(&format!("[execute_schedule] id={}, summary extraction failed", schedule_id));
+                        log_debug!("cron", "[execute_schedule] id={}, summary extraction failed", schedule_id);
                         None
                     }
                 }
             } else {
-                sched_debug(&format!("[execute_schedule] id={}, no session_id for summary", schedule_id));
+                log_debug!("cron", "[execute_schedule] id={}, no session_id for summary", schedule_id);
                 None
             }
         } else {
@@ -4238,25 +4693,27 @@ async fn execute_schedule(
             sched_session.history.push(HistoryItem {
                 item_type: HistoryType::User,
                 content: entry_clone.prompt.clone(),
+                tool_calls: Vec::new(),
             });
             if !full_response.is_empty() {
                 sched_session.history.push(HistoryItem {
                     item_type: HistoryType::Assistant,
                     content: full_response.clone(),
+                    tool_calls: Vec::new(),
                 });
             }
             save_session_to_file(&sched_session, &workspace_path_owned);
         }
 
         // Update schedule file (last_run / delete if once)
-        sched_debug(&format!("[execute_schedule] id={}, calling update_schedule_after_run", schedule_id));
+        log_debug!("cron", "[execute_schedule] id={}, calling update_schedule_after_run", schedule_id);
         update_schedule_after_run(&entry_clone, new_context_summary);
 
         // Workspace directory is preserved for user to continue work via /start
 
         // Clean up + restore previous session
-        sched_debug(&format!("[execute_schedule] id={}, cleaning up: removing cancel_token, pending, restoring session (has_prev={})",
-            schedule_id, prev_session.is_some()));
+        log_debug!("cron", "[execute_schedule] id={}, cleaning up: removing cancel_token, pending, restoring session (has_prev={})",
+            schedule_id, prev_session.is_some());
         {
             let mut data = state_owned.lock().await;
             data.cancel_tokens.remove(&chat_id);
@@ -4270,7 +4727,7 @@ async fn execute_schedule(
                 data.sessions.remove(&chat_id);
             }
         }
-        sched_debug(&format!("[execute_schedule] id={}, END", schedule_id));
+        log_debug!("cron", "[execute_schedule] id={}, END", schedule_id);
 
         // Clean up leftover stop message
         let stop_msg_id = {
@@ -4287,7 +4744,7 @@ async fn execute_schedule(
 /// Scheduler loop: runs every 60 seconds, checks for due schedules
 async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
     let bot_key = token_hash(&token);
-    sched_debug("[scheduler_loop] started");
+    log_debug!("cron", "[scheduler_loop] started");
 
     loop {
         tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
@@ -4296,7 +4753,7 @@ async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
         let entries = list_schedule_entries(&bot_key, None);
         if entries.is_empty() { continue; }
 
-        sched_debug(&format!("[scheduler_loop] cycle: {} entries found", entries.len()));
+        log_debug!("cron", "[scheduler_loop] cycle: {} entries found", entries.len());
 
         for entry in &entries {
             let chat_id = ChatId(entry.chat_id);
@@ -4304,8 +4761,8 @@ async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
             // Verify current_path exists (before acquiring lock — involves filesystem I/O)
             if !Path::new(&entry.current_path).is_dir() {
                 let ts = chrono::Local::now().format("%H:%M:%S");
-                println!("  [{ts}] ⚠ [Scheduler] Path not found: {} (schedule: {})", entry.current_path, entry.id);
-                sched_debug(&format!("[scheduler_loop] id={}, path not found: {} → skip", entry.id, entry.current_path));
+                console!("  [{ts}] ⚠ [Scheduler] Path not found: {} (schedule: {})", entry.current_path, entry.id);
+                log_debug!("cron", "[scheduler_loop] id={}, path not found: {} → skip", entry.id, entry.current_path);
                 shared_rate_limit_wait(&state, chat_id).await;
                 let msg = format!("⏰ {}\n\n⚠️ Skipped — path no longer exists\n📂 <code>{}</code>",
                     html_escape(&truncate_str(&entry.prompt, 40)), html_escape(&entry.current_path));
@@ -4326,7 +4783,7 @@ async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
                 let is_already_pending = data.pending_schedules.get(&chat_id)
                     .map_or(false, |set| set.contains(&entry.id));
 
-                sched_debug(&format!("[scheduler_loop] id={}, is_already_pending={}", entry.id, is_already_pending));
+                log_debug!("cron", "[scheduler_loop] id={}, is_already_pending={}", entry.id, is_already_pending);
 
                 // If not pending and not due to trigger, skip
                 if !is_already_pending && !should_trigger(entry) {
@@ -4335,10 +4792,10 @@ async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
                         if let Ok(schedule_time) = chrono::NaiveDateTime::parse_from_str(&entry.schedule, "%Y-%m-%d %H:%M:%S") {
                             if let Some(schedule_dt) = schedule_time.and_local_timezone(chrono::Local).single() {
                                 if chrono::Local::now() > schedule_dt {
-                                    sched_debug(&format!("[scheduler_loop] id={}, expired absolute → discard", entry.id));
+                                    log_debug!("cron", "[scheduler_loop] id={}, expired absolute → discard", entry.id);
                                     SchedAction::DiscardExpired
                                 } else {
-                                    sched_debug(&format!("[scheduler_loop] id={}, not yet due → skip", entry.id));
+                                    log_debug!("cron", "[scheduler_loop] id={}, not yet due → skip", entry.id);
                                     SchedAction::Skip
                                 }
                             } else {
@@ -4353,7 +4810,7 @@ async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
                 } else {
                     // Entry should execute — check if chat is busy
                     let is_busy = data.cancel_tokens.contains_key(&chat_id);
-                    sched_debug(&format!("[scheduler_loop] id={}, should execute, is_busy={}", entry.id, is_busy));
+                    log_debug!("cron", "[scheduler_loop] id={}, should execute, is_busy={}", entry.id, is_busy);
 
                     if is_busy {
                         // Chat is busy — mark as pending if not already, retry next cycle
@@ -4361,16 +4818,16 @@ async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
                         if !is_already_pending {
                             data.pending_schedules.entry(chat_id).or_default().insert(entry.id.clone());
                             let ts = chrono::Local::now().format("%H:%M:%S");
-                            println!("  [{ts}] ⏰ [Scheduler] Chat busy, pending: {}", entry.id);
-                            sched_debug(&format!("[scheduler_loop] id={}, chat busy → marked pending", entry.id));
+                            console!("  [{ts}] ⏰ [Scheduler] Chat busy, pending: {}", entry.id);
+                            log_debug!("cron", "[scheduler_loop] id={}, chat busy → marked pending", entry.id);
                         } else {
-                            sched_debug(&format!("[scheduler_loop] id={}, chat busy, already pending → skip", entry.id));
+                            log_debug!("cron", "[scheduler_loop] id={}, chat busy, already pending → skip", entry.id);
                         }
                         SchedAction::Skip
                     } else {
                         // Not busy — backup session, replace with schedule-specific session, and execute
                         let prev = data.sessions.get(&chat_id).cloned();
-                        sched_debug(&format!("[scheduler_loop] id={}, not busy → execute (has_prev_session={})", entry.id, prev.is_some()));
+                        log_debug!("cron", "[scheduler_loop] id={}, not busy → execute (has_prev_session={})", entry.id, prev.is_some());
                         data.sessions.insert(chat_id, ChatSession {
                             session_id: None,
                             current_path: Some(entry.current_path.clone()),
@@ -4392,15 +4849,102 @@ async fn scheduler_loop(bot: Bot, state: SharedState, token: String) {
                 SchedAction::DiscardExpired => {
                     delete_schedule_entry(&entry.id);
                     let ts = chrono::Local::now().format("%H:%M:%S");
-                    println!("  [{ts}] ⏰ [Scheduler] Discarded expired once-schedule: {}", entry.id);
-                    sched_debug(&format!("[scheduler_loop] id={}, discarded expired", entry.id));
+                    console!("  [{ts}] ⏰ [Scheduler] Discarded expired once-schedule: {}", entry.id);
+                    log_debug!("cron", "[scheduler_loop] id={}, discarded expired", entry.id);
                     continue;
                 }
                 SchedAction::Execute(prev_session) => {
-                    sched_debug(&format!("[scheduler_loop] id={}, calling execute_schedule", entry.id));
+                    log_debug!("cron", "[scheduler_loop] id={}, calling execute_schedule", entry.id);
                     execute_schedule(&bot, chat_id, entry, &state, &token, prev_session).await;
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, dir: &str) -> DownloadRule {
+        DownloadRule { pattern: pattern.to_string(), dir: dir.to_string() }
+    }
+
+    #[test]
+    fn test_shutdown_first_request_wins() {
+        let flag = AtomicU8::new(0);
+        assert_eq!(recorded_shutdown(&flag), None);
+        record_shutdown(&flag, ShutdownMode::Restart);
+        assert_eq!(recorded_shutdown(&flag), Some(ShutdownMode::Restart));
+        // A later /stop (or Ctrl+C) does not turn a pending restart into a stop
+        record_shutdown(&flag, ShutdownMode::Stop);
+        assert_eq!(recorded_shutdown(&flag), Some(ShutdownMode::Restart));
+
+        let flag = AtomicU8::new(0);
+        record_shutdown(&flag, ShutdownMode::Stop);
+        record_shutdown(&flag, ShutdownMode::Restart);
+        assert_eq!(recorded_shutdown(&flag), Some(ShutdownMode::Stop));
+    }
+
+    #[test]
+    fn test_find_download_rule_first_match_wins() {
+        let rules = vec![rule("report*.pdf", "/a"), rule("*.pdf", "/b"), rule("*", "/c")];
+        assert_eq!(find_download_rule(&rules, "report-2024.pdf").unwrap().dir, "/a");
+        assert_eq!(find_download_rule(&rules, "other.pdf").unwrap().dir, "/b");
+        assert_eq!(find_download_rule(&rules, "notes.txt").unwrap().dir, "/c");
+        assert!(find_download_rule(&rules[..2], "notes.txt").is_none());
+    }
+
+    #[test]
+    fn test_find_download_rule_ignores_case() {
+        let rules = vec![rule("*.pdf", "/docs")];
+        assert!(find_download_rule(&rules, "SCAN.PDF").is_some());
+        let rules = vec![rule("*.PDF", "/docs")];
+        assert!(find_download_rule(&rules, "scan.pdf").is_some());
+    }
+
+    #[test]
+    fn test_parse_saveto_args() {
+        assert_eq!(parse_saveto_args(""), SaveToArgs::List);
+        assert_eq!(parse_saveto_args("   "), SaveToArgs::List);
+        assert_eq!(
+            parse_saveto_args(" *.pdf ~/My Documents "),
+            SaveToArgs::Set { pattern: "*.pdf".to_string(), dir: "~/My Documents".to_string() }
+        );
+        assert_eq!(parse_saveto_args("-*.pdf"), SaveToArgs::Remove("*.pdf".to_string()));
+        assert_eq!(parse_saveto_args("- *.pdf"), SaveToArgs::Remove("*.pdf".to_string()));
+        assert_eq!(parse_saveto_args("-"), SaveToArgs::Usage);
+        assert_eq!(parse_saveto_args("*.pdf"), SaveToArgs::Usage);
+    }
+
+    #[test]
+    fn test_validate_saveto_dir() {
+        assert!(validate_saveto_dir("Documents").is_err());
+        assert!(validate_saveto_dir("./Documents").is_err());
+        assert!(validate_saveto_dir("/tmp").is_ok());
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file.txt");
+        std::fs::write(&file, b"x").unwrap();
+        assert!(validate_saveto_dir(file.to_str().unwrap()).is_err());
+        // A directory that does not exist yet is created on first upload
+        assert!(validate_saveto_dir(dir.path().join("new").to_str().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_set_and_remove_download_rule() {
+        let mut rules = Vec::new();
+        set_download_rule(&mut rules, "*.pdf", "/a");
+        set_download_rule(&mut rules, "*.jpg", "/b");
+        // Replacing keeps the rule's position in the match order
+        set_download_rule(&mut rules, "*.pdf", "/c");
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].pattern, "*.pdf");
+        assert_eq!(rules[0].dir, "/c");
+
+        assert!(remove_download_rule(&mut rules, "*.pdf"));
+        assert!(!remove_download_rule(&mut rules, "*.pdf"));
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].pattern, "*.jpg");
+    }
+}
@@ -0,0 +1,262 @@
+//! Downscaled image cache for the image viewer (~/.cokacdir/thumbs).
+//!
+//! Images larger than `THUMB_MAX_DIM` are decoded once, downscaled and stored
+//! as PNG keyed by path + mtime + size. Later views load the small copy
+//! instead of decoding the full-size image. Neighbouring images are prepared
+//! ahead of time by a small worker pool. The cache is capped at
+//! `CACHE_MAX_BYTES`; the least recently used thumbnails are removed first.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use image::DynamicImage;
+use sha2::{Digest, Sha256};
+
+use crate::config::Settings;
+
+/// Longest side of a cached image. Large enough for full-screen display in
+/// high resolution terminals, small enough to decode instantly.
+pub const THUMB_MAX_DIM: u32 = 2048;
+
+/// Number of background workers generating thumbnails
+const PREFETCH_WORKERS: usize = 2;
+
+/// Total size of the thumbnail cache before old entries are pruned
+const CACHE_MAX_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Image ready for display
+pub struct LoadedImage {
+    pub image: DynamicImage,
+    /// Size of the original file's image (may differ from `image` when downscaled)
+    pub original_size: (u32, u32),
+}
+
+fn thumbs_dir() -> Option<PathBuf> {
+    Settings::config_dir().map(|d| d.join("thumbs"))
+}
+
+/// Cache file for `path`; changes whenever the file is modified
+fn cache_path(path: &Path) -> Option<PathBuf> {
+    let canonical = path.canonicalize().ok()?;
+    let metadata = std::fs::metadata(&canonical).ok()?;
+    let mtime = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(canonical.to_string_lossy().as_bytes());
+    hasher.update(mtime.as_nanos().to_le_bytes());
+    hasher.update(metadata.len().to_le_bytes());
+    let key = hex::encode(hasher.finalize());
+    thumbs_dir().map(|d| d.join(format!("{}.png", key)))
+}
+
+fn needs_thumbnail(size: (u32, u32)) -> bool {
+    size.0 > THUMB_MAX_DIM || size.1 > THUMB_MAX_DIM
+}
+
+/// Write a thumbnail atomically (temp file + rename) so readers never see partial files
+fn store(cache_file: &Path, thumb: &DynamicImage) {
+    static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let Some(dir) = cache_file.parent() else { return };
+    if std::fs::create_dir_all(dir).is_err() {
+        return;
+    }
+    // The UI thread and prefetch workers may store the same key concurrently
+    let tmp = cache_file.with_extension(format!(
+        "tmp{}-{}",
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    if thumb.save_with_format(&tmp, image::ImageFormat::Png).is_ok() {
+        let _ = std::fs::rename(&tmp, cache_file);
+        prune_cache(dir);
+    } else {
+        let _ = std::fs::remove_file(&tmp);
+    }
+}
+
+/// Mark a cache entry as recently used (mtime drives LRU pruning)
+fn touch(cache_file: &Path) {
+    if let Ok(file) = std::fs::File::options().write(true).open(cache_file) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+/// Prune the cache directory unless another thread is already doing it
+fn prune_cache(dir: &Path) {
+    static PRUNING: AtomicBool = AtomicBool::new(false);
+    if PRUNING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    prune(dir, CACHE_MAX_BYTES);
+    PRUNING.store(false, Ordering::Release);
+}
+
+/// Delete the least recently used thumbnails until the total size of `dir`
+/// is at most `max_bytes`
+fn prune(dir: &Path, max_bytes: u64) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    let mut files: Vec<(SystemTime, u64, PathBuf)> = entries
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "png"))
+        .filter_map(|e| {
+            let metadata = e.metadata().ok()?;
+            let modified = metadata.modified().unwrap_or(UNIX_EPOCH);
+            Some((modified, metadata.len(), e.path()))
+        })
+        .collect();
+
+    let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+    if total <= max_bytes {
+        return;
+    }
+    files.sort_by_key(|(modified, _, _)| *modified);
+    for (_, len, path) in files {
+        if total <= max_bytes {
+            break;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            total = total.saturating_sub(len);
+        }
+    }
+}
+
+/// Load an image for display, using (and filling) the thumbnail cache
+pub fn load(path: &Path) -> Result<LoadedImage, String> {
+    let cache_file = cache_path(path);
+
+    if let Some(ref cache_file) = cache_file {
+        if let Ok(image) = image::open(cache_file) {
+            touch(cache_file);
+            // Header-only read for the real dimensions
+            let original_size = image::image_dimensions(path)
+                .unwrap_or((image.width(), image.height()));
+            return Ok(LoadedImage { image, original_size });
+        }
+    }
+
+    let image = image::open(path).map_err(|e| format!("Failed to load image: {}", e))?;
+    let original_size = (image.width(), image.height());
+    if !needs_thumbnail(original_size) {
+        return Ok(LoadedImage { image, original_size });
+    }
+
+    let thumb = image.thumbnail(THUMB_MAX_DIM, THUMB_MAX_DIM);
+    drop(image);
+    if let Some(ref cache_file) = cache_file {
+        store(cache_file, &thumb);
+    }
+    Ok(LoadedImage { image: thumb, original_size })
+}
+
+/// Generate the thumbnail for `path` if it is large and not cached yet
+fn generate(path: &Path) {
+    let Ok(size) = image::image_dimensions(path) else { return };
+    if !needs_thumbnail(size) {
+        return;
+    }
+    let Some(cache_file) = cache_path(path) else { return };
+    if cache_file.exists() {
+        return;
+    }
+    if let Ok(image) = image::open(path) {
+        store(&cache_file, &image.thumbnail(THUMB_MAX_DIM, THUMB_MAX_DIM));
+    }
+}
+
+struct PrefetchPool {
+    sender: Sender<PathBuf>,
+    /// Paths queued or being processed (avoids duplicate work)
+    in_flight: Arc<Mutex<HashSet<PathBuf>>>,
+}
+
+fn pool() -> &'static PrefetchPool {
+    static POOL: OnceLock<PrefetchPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        let (sender, receiver): (Sender<PathBuf>, Receiver<PathBuf>) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let in_flight = Arc::new(Mutex::new(HashSet::new()));
+        for _ in 0..PREFETCH_WORKERS {
+            let receiver = receiver.clone();
+            let in_flight = in_flight.clone();
+            thread::spawn(move || loop {
+                let next = match receiver.lock() {
+                    Ok(rx) => rx.recv(),
+                    Err(_) => return,
+                };
+                let Ok(path) = next else { return };
+                generate(&path);
+                if let Ok(mut set) = in_flight.lock() {
+                    set.remove(&path);
+                }
+            });
+        }
+        PrefetchPool { sender, in_flight }
+    })
+}
+
+/// Prepare thumbnails for `paths` in the background
+pub fn prefetch(paths: &[PathBuf]) {
+    let pool = pool();
+    let Ok(mut in_flight) = pool.in_flight.lock() else { return };
+    for path in paths {
+        if in_flight.insert(path.clone()) && pool.sender.send(path.clone()).is_err() {
+            in_flight.remove(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_thumbnail() {
+        assert!(!needs_thumbnail((THUMB_MAX_DIM, THUMB_MAX_DIM)));
+        assert!(needs_thumbnail((THUMB_MAX_DIM + 1, 10)));
+        assert!(needs_thumbnail((10, THUMB_MAX_DIM + 1)));
+    }
+
+    #[test]
+    fn test_cache_path_changes_with_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.png");
+        std::fs::write(&file, b"one").unwrap();
+        let first = cache_path(&file);
+        std::fs::write(&file, b"three").unwrap();
+        let second = cache_path(&file);
+        // Without a home directory there is no cache location
+        if first.is_some() {
+            assert_ne!(first, second);
+            assert_eq!(first.unwrap().extension().unwrap(), "png");
+        }
+    }
+
+    #[test]
+    fn test_prune_removes_least_recently_used() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = SystemTime::now() - std::time::Duration::from_secs(3600);
+        for (i, name) in ["old.png", "mid.png", "new.png"].iter().enumerate() {
+            let path = dir.path().join(name);
+            std::fs::write(&path, vec![0u8; 100]).unwrap();
+            let file = std::fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(base + std::time::Duration::from_secs(i as u64 * 60)).unwrap();
+        }
+        // Temp files of in-progress writes are never pruned
+        std::fs::write(dir.path().join("x.tmp1-0"), vec![0u8; 100]).unwrap();
+
+        prune(dir.path(), 300);
+        assert!(dir.path().join("old.png").exists());
+
+        prune(dir.path(), 200);
+        assert!(!dir.path().join("old.png").exists());
+        assert!(dir.path().join("mid.png").exists());
+        assert!(dir.path().join("new.png").exists());
+        assert!(dir.path().join("x.tmp1-0").exists());
+    }
+}
@@ -0,0 +1,149 @@
+//! File activity timeline: the most recently modified files under a folder.
+//!
+//! The scan walks the tree down to a depth limit, keeps files modified inside
+//! the chosen time window and returns them newest first. VCS metadata folders
+//! (`.git`, `.hg`, `.svn`) are skipped since their churn is rarely what the
+//! user is looking for.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// Folders never descended into
+const SKIPPED_DIRS: &[&str] = &[".git", ".hg", ".svn"];
+
+/// At most this many files are kept (the newest ones)
+pub const MAX_ENTRIES: usize = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeWindow {
+    LastHour,
+    Today,
+    Week,
+    Month,
+}
+
+impl TimeWindow {
+    pub const ALL: [TimeWindow; 4] = [TimeWindow::LastHour, TimeWindow::Today, TimeWindow::Week, TimeWindow::Month];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimeWindow::LastHour => "last hour",
+            TimeWindow::Today => "today",
+            TimeWindow::Week => "last 7 days",
+            TimeWindow::Month => "last 30 days",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|w| *w == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    /// Oldest modification time inside the window ("today" starts at local midnight)
+    pub fn since(self, now: SystemTime) -> SystemTime {
+        let back = |secs: u64| now.checked_sub(Duration::from_secs(secs)).unwrap_or(SystemTime::UNIX_EPOCH);
+        match self {
+            TimeWindow::LastHour => back(3600),
+            TimeWindow::Today => {
+                let midnight = chrono::Local::now()
+                    .date_naive()
+                    .and_hms_opt(0, 0, 0)
+                    .and_then(|t| t.and_local_timezone(chrono::Local).earliest());
+                match midnight {
+                    Some(t) => SystemTime::from(t),
+                    None => back(86400),
+                }
+            }
+            TimeWindow::Week => back(7 * 86400),
+            TimeWindow::Month => back(30 * 86400),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    pub path: PathBuf,
+    pub modified: SystemTime,
+    pub size: u64,
+}
+
+fn walk(dir: &Path, depth: usize, since: SystemTime, out: &mut Vec<TimelineEntry>, cancel_flag: &AtomicBool) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return;
+        }
+        let path = entry.path();
+        let Ok(metadata) = path.symlink_metadata() else { continue };
+        if metadata.is_dir() {
+            let skipped = path.file_name().and_then(|n| n.to_str()).is_some_and(|n| SKIPPED_DIRS.contains(&n));
+            if depth > 1 && !skipped {
+                walk(&path, depth - 1, since, out, cancel_flag);
+            }
+            continue;
+        }
+        let Ok(modified) = metadata.modified() else { continue };
+        if modified >= since {
+            out.push(TimelineEntry { path, modified, size: metadata.len() });
+        }
+    }
+}
+
+/// Files under `root` (down to `max_depth` levels, 1 = only `root` itself)
+/// modified since `since`, newest first and capped at [`MAX_ENTRIES`]
+pub fn recent_files(root: &Path, max_depth: usize, since: SystemTime, cancel_flag: &AtomicBool) -> Vec<TimelineEntry> {
+    let mut entries = Vec::new();
+    walk(root, max_depth.max(1), since, &mut entries, cancel_flag);
+    entries.sort_by(|a, b| b.modified.cmp(&a.modified).then_with(|| a.path.cmp(&b.path)));
+    entries.truncate(MAX_ENTRIES);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_mtime(path: &Path, age_secs: u64) {
+        let time = SystemTime::now() - Duration::from_secs(age_secs);
+        fs::File::options().write(true).open(path).unwrap().set_modified(time).unwrap();
+    }
+
+    #[test]
+    fn test_recent_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("a/b")).unwrap();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        for (name, age) in [("new.txt", 60), ("a/mid.txt", 600), ("a/b/deep.txt", 30), ("old.txt", 7200), (".git/index", 10)] {
+            fs::write(root.join(name), "x").unwrap();
+            set_mtime(&root.join(name), age);
+        }
+
+        let flag = AtomicBool::new(false);
+        let since = SystemTime::now() - Duration::from_secs(3600);
+        let names = |entries: Vec<TimelineEntry>| -> Vec<PathBuf> {
+            entries.into_iter().map(|e| e.path.strip_prefix(root).unwrap().to_path_buf()).collect()
+        };
+
+        assert_eq!(
+            names(recent_files(root, 3, since, &flag)),
+            vec![PathBuf::from("a/b/deep.txt"), PathBuf::from("new.txt"), PathBuf::from("a/mid.txt")]
+        );
+        // Depth 2 stops above a/b
+        assert_eq!(
+            names(recent_files(root, 2, since, &flag)),
+            vec![PathBuf::from("new.txt"), PathBuf::from("a/mid.txt")]
+        );
+    }
+
+    #[test]
+    fn test_window_since() {
+        let now = SystemTime::now();
+        assert_eq!(TimeWindow::LastHour.since(now), now - Duration::from_secs(3600));
+        let today = TimeWindow::Today.since(now);
+        assert!(today <= now && now.duration_since(today).unwrap() <= Duration::from_secs(25 * 3600));
+        assert_eq!(TimeWindow::Month.next(), TimeWindow::LastHour);
+    }
+}
@@ -0,0 +1,258 @@
+//! Trash (recycle bin) for local deletes.
+//!
+//! Uses the freedesktop.org layout: deleted entries are moved to
+//! `<trash>/files/<name>` and described by `<trash>/info/<name>.trashinfo`
+//! (original path and deletion date). On Linux the trash is the XDG home trash
+//! (`$XDG_DATA_HOME/Trash`, shared with desktop file managers); elsewhere it is
+//! `~/.cokacdir/trash`. Entries moved across filesystems are copied, then removed.
+
+use std::ffi::OsStr;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+use crate::services::file_ops;
+
+/// Format of the DeletionDate key (local time, no zone as the spec requires)
+const DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// An entry in the trash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashItem {
+    /// Name under `files/` (unique within the trash)
+    pub name: String,
+    /// Where the entry was deleted from
+    pub original_path: PathBuf,
+    /// DeletionDate as written ("2026-01-31T14:05:00")
+    pub deleted_at: String,
+    pub is_dir: bool,
+    /// File size, or total size of a directory's files. `None` for directories
+    /// until measured with [`stored_size`] (walking them can be slow).
+    pub size: Option<u64>,
+}
+
+/// Trash directory for this platform (see module docs)
+pub fn trash_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "linux") {
+        let data_home = std::env::var_os("XDG_DATA_HOME")
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| dirs::home_dir().map(|h| h.join(".local").join("share")))?;
+        Some(data_home.join("Trash"))
+    } else {
+        dirs::home_dir().map(|h| h.join(".cokacdir").join("trash"))
+    }
+}
+
+fn files_dir(trash: &Path) -> PathBuf {
+    trash.join("files")
+}
+
+fn info_path(trash: &Path, name: &str) -> PathBuf {
+    trash.join("info").join(format!("{}.trashinfo", name))
+}
+
+/// Percent-encode a path for the Path key (`/` and unreserved characters kept)
+fn encode_path(path: &Path) -> String {
+    let mut out = String::new();
+    for &b in path.as_os_str().as_bytes() {
+        if b.is_ascii_alphanumeric() || b"/-_.~".contains(&b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+    out
+}
+
+fn decode_path(s: &str) -> PathBuf {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes.get(i + 1..i + 3).and_then(|h| std::str::from_utf8(h).ok());
+            if let Some(b) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    PathBuf::from(OsStr::from_bytes(&out))
+}
+
+/// Rename, or copy then delete when `dest` is on another filesystem
+fn move_entry(src: &Path, dest: &Path) -> io::Result<()> {
+    match fs::rename(src, dest) {
+        Err(e) if e.raw_os_error() == Some(libc::EXDEV) => file_ops::move_file(src, dest),
+        result => result,
+    }
+}
+
+/// Reserve a unique name in the trash by creating its .trashinfo file
+fn reserve_name(trash: &Path, file_name: &str, contents: &str) -> io::Result<String> {
+    let (stem, ext) = match file_name.rfind('.') {
+        Some(i) if i > 0 => (&file_name[..i], &file_name[i..]),
+        _ => (file_name, ""),
+    };
+    for n in 1.. {
+        let name = if n == 1 { file_name.to_string() } else { format!("{}.{}{}", stem, n, ext) };
+        if fs::symlink_metadata(files_dir(trash).join(&name)).is_ok() {
+            continue;
+        }
+        // create_new: another process may be trashing the same name
+        match OpenOptions::new().write(true).create_new(true).open(info_path(trash, &name)) {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                return Ok(name);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    unreachable!()
+}
+
+/// Move `path` to the trash. Returns the name it was stored under.
+pub fn move_to_trash(path: &Path) -> io::Result<String> {
+    let trash = trash_dir().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Home directory not found"))?;
+    let absolute = if path.is_absolute() { path.to_path_buf() } else { std::env::current_dir()?.join(path) };
+    let file_name = absolute
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Cannot trash this path"))?;
+    if absolute.starts_with(&trash) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Already in the trash"));
+    }
+    // Fail on a missing path before an info file is written
+    fs::symlink_metadata(&absolute)?;
+    fs::create_dir_all(files_dir(&trash))?;
+    fs::create_dir_all(trash.join("info"))?;
+
+    let info = format!(
+        "[Trash Info]\nPath={}\nDeletionDate={}\n",
+        encode_path(&absolute),
+        chrono::Local::now().format(DATE_FORMAT)
+    );
+    let name = reserve_name(&trash, &file_name, &info)?;
+    if let Err(e) = move_entry(&absolute, &files_dir(&trash).join(&name)) {
+        let _ = fs::remove_file(info_path(&trash, &name));
+        return Err(e);
+    }
+    Ok(name)
+}
+
+fn entry_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else { return 0 };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| entry_size(&e.path())).sum())
+        .unwrap_or(0)
+}
+
+fn parse_info(name: &str, contents: &str) -> Option<TrashItem> {
+    let mut original_path = None;
+    let mut deleted_at = String::new();
+    for line in contents.lines() {
+        if let Some(value) = line.strip_prefix("Path=") {
+            original_path = Some(decode_path(value.trim()));
+        } else if let Some(value) = line.strip_prefix("DeletionDate=") {
+            deleted_at = value.trim().to_string();
+        }
+    }
+    Some(TrashItem {
+        name: name.to_string(),
+        original_path: original_path?,
+        deleted_at,
+        is_dir: false,
+        size: None,
+    })
+}
+
+/// Entries in the trash, most recently deleted first. Info files without a
+/// matching entry under `files/` are skipped.
+pub fn list() -> Vec<TrashItem> {
+    let Some(trash) = trash_dir() else { return Vec::new() };
+    let Ok(entries) = fs::read_dir(trash.join("info")) else { return Vec::new() };
+    let mut items: Vec<TrashItem> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let file_name = e.file_name().to_string_lossy().to_string();
+            let name = file_name.strip_suffix(".trashinfo")?;
+            let contents = fs::read_to_string(e.path()).ok()?;
+            let mut item = parse_info(name, &contents)?;
+            let stored = files_dir(&trash).join(name);
+            let metadata = fs::symlink_metadata(&stored).ok()?;
+            item.is_dir = metadata.is_dir();
+            if !item.is_dir {
+                item.size = Some(metadata.len());
+            }
+            Some(item)
+        })
+        .collect();
+    // DeletionDate is ISO 8601, so string order is date order
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at).then_with(|| a.name.cmp(&b.name)));
+    items
+}
+
+/// Total size of an entry as stored in the trash (walks directories)
+pub fn stored_size(item: &TrashItem) -> u64 {
+    trash_dir().map(|trash| entry_size(&files_dir(&trash).join(&item.name))).unwrap_or(0)
+}
+
+/// Move an entry back to its original path (parent directories are recreated)
+pub fn restore(item: &TrashItem) -> io::Result<PathBuf> {
+    let trash = trash_dir().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Home directory not found"))?;
+    let dest = item.original_path.clone();
+    if fs::symlink_metadata(&dest).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dest.display()),
+        ));
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    move_entry(&files_dir(&trash).join(&item.name), &dest)?;
+    let _ = fs::remove_file(info_path(&trash, &item.name));
+    Ok(dest)
+}
+
+/// Permanently delete an entry from the trash
+pub fn purge(item: &TrashItem) -> io::Result<()> {
+    let trash = trash_dir().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Home directory not found"))?;
+    let stored = files_dir(&trash).join(&item.name);
+    if fs::symlink_metadata(&stored).is_ok() {
+        file_ops::delete_file(&stored)?;
+    }
+    fs::remove_file(info_path(&trash, &item.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_encoding_round_trip() {
+        let path = Path::new("/home/user/my file%.txt");
+        let encoded = encode_path(path);
+        assert_eq!(encoded, "/home/user/my%20file%25.txt");
+        assert_eq!(decode_path(&encoded), path);
+        assert_eq!(decode_path("/a/%E2%9C%93"), Path::new("/a/✓"));
+        assert_eq!(decode_path("/bad%zz"), Path::new("/bad%zz"));
+    }
+
+    #[test]
+    fn test_parse_info() {
+        let item = parse_info("a.txt.2", "[Trash Info]\nPath=/tmp/a.txt\nDeletionDate=2026-01-31T14:05:00\n").unwrap();
+        assert_eq!(item.original_path, Path::new("/tmp/a.txt"));
+        assert_eq!(item.deleted_at, "2026-01-31T14:05:00");
+        assert!(parse_info("x", "[Trash Info]\nDeletionDate=2026-01-31T14:05:00\n").is_none());
+    }
+}
@@ -0,0 +1,276 @@
+//! Directory tree walker for `cokacdir --tree`.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+/// Options for building a directory tree
+#[derive(Debug, Clone)]
+pub struct TreeOptions {
+    /// Maximum depth below the root (None = unlimited)
+    pub max_depth: Option<usize>,
+    /// Include dot files and dot directories
+    pub show_hidden: bool,
+    /// Skip paths ignored by git (.gitignore, .git/info/exclude, global excludes)
+    pub respect_gitignore: bool,
+}
+
+impl Default for TreeOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: None,
+            show_hidden: false,
+            respect_gitignore: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeNode {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: TreeNodeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<TreeNode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TreeNodeKind {
+    Directory,
+    File,
+    Symlink,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TreeStats {
+    pub directories: usize,
+    pub files: usize,
+}
+
+/// Build the tree for `root`. Symlinks are listed but never followed.
+pub fn build_tree(root: &Path, options: &TreeOptions) -> Result<TreeNode, String> {
+    let metadata = fs::metadata(root).map_err(|e| format!("{}: {}", root.display(), e))?;
+    if !metadata.is_dir() {
+        return Err(format!("{}: Not a directory", root.display()));
+    }
+
+    let ignored = if options.respect_gitignore {
+        git_ignored_paths(root)
+    } else {
+        HashSet::new()
+    };
+
+    Ok(TreeNode {
+        name: root.display().to_string(),
+        kind: TreeNodeKind::Directory,
+        size: None,
+        target: None,
+        children: walk(root, 1, options, &ignored),
+    })
+}
+
+fn walk(dir: &Path, depth: usize, options: &TreeOptions, ignored: &HashSet<PathBuf>) -> Vec<TreeNode> {
+    if options.max_depth.map(|max| depth > max).unwrap_or(false) {
+        return Vec::new();
+    }
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut nodes: Vec<TreeNode> = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !options.show_hidden && name.starts_with('.') {
+            continue;
+        }
+        // .git is never interesting in a tree listing
+        if options.respect_gitignore && name == ".git" {
+            continue;
+        }
+        let path = entry.path();
+        if ignored.contains(&path) {
+            continue;
+        }
+        let metadata = match fs::symlink_metadata(&path) {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
+
+        let node = if metadata.file_type().is_symlink() {
+            TreeNode {
+                name,
+                kind: TreeNodeKind::Symlink,
+                size: None,
+                target: fs::read_link(&path).ok().map(|t| t.display().to_string()),
+                children: Vec::new(),
+            }
+        } else if metadata.is_dir() {
+            TreeNode {
+                name,
+                kind: TreeNodeKind::Directory,
+                size: None,
+                target: None,
+                children: walk(&path, depth + 1, options, ignored),
+            }
+        } else {
+            TreeNode {
+                name,
+                kind: TreeNodeKind::File,
+                size: Some(metadata.len()),
+                target: None,
+                children: Vec::new(),
+            }
+        };
+        nodes.push(node);
+    }
+
+    // 디렉토리 우선, 그 다음 이름순 (검색 결과와 동일)
+    nodes.sort_by(|a, b| {
+        let a_dir = a.kind == TreeNodeKind::Directory;
+        let b_dir = b.kind == TreeNodeKind::Directory;
+        b_dir.cmp(&a_dir).then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    nodes
+}
+
+/// Ask git for ignored paths under `root` (empty if not inside a repository)
+fn git_ignored_paths(root: &Path) -> HashSet<PathBuf> {
+    let output = crate::ui::git_screen::git_cmd_public(root)
+        .args(["ls-files", "--others", "--ignored", "--exclude-standard", "--directory", "-z"])
+        .stderr(std::process::Stdio::null())
+        .output();
+    let Ok(output) = output else { return HashSet::new() };
+    if !output.status.success() {
+        return HashSet::new();
+    }
+    // Paths are relative to `root` because of `git -C root`
+    output.stdout
+        .split(|&b| b == 0)
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let rel = String::from_utf8_lossy(p);
+            root.join(rel.trim_end_matches('/'))
+        })
+        .collect()
+}
+
+/// Count directories and files below the root (the root itself is not counted)
+pub fn count(node: &TreeNode) -> TreeStats {
+    let mut stats = TreeStats::default();
+    for child in &node.children {
+        match child.kind {
+            TreeNodeKind::Directory => {
+                stats.directories += 1;
+                let sub = count(child);
+                stats.directories += sub.directories;
+                stats.files += sub.files;
+            }
+            TreeNodeKind::File | TreeNodeKind::Symlink => stats.files += 1,
+        }
+    }
+    stats
+}
+
+/// Render as an indented text tree (like the `tree` command)
+pub fn render_text(root: &TreeNode) -> String {
+    let mut out = String::new();
+    out.push_str(&root.name);
+    out.push('\n');
+    render_children(&root.children, "", &mut out);
+
+    let stats = count(root);
+    out.push('\n');
+    out.push_str(&format!(
+        "{} director{}, {} file{}\n",
+        stats.directories,
+        if stats.directories == 1 { "y" } else { "ies" },
+        stats.files,
+        if stats.files == 1 { "" } else { "s" },
+    ));
+    out
+}
+
+fn render_children(children: &[TreeNode], prefix: &str, out: &mut String) {
+    for (i, child) in children.iter().enumerate() {
+        let is_last = i + 1 == children.len();
+        let branch = if is_last { "└── " } else { "├── " };
+        out.push_str(prefix);
+        out.push_str(branch);
+        out.push_str(&child.name);
+        match child.kind {
+            TreeNodeKind::Directory => out.push('/'),
+            TreeNodeKind::Symlink => {
+                if let Some(ref target) = child.target {
+                    out.push_str(" -> ");
+                    out.push_str(target);
+                }
+            }
+            TreeNodeKind::File => {}
+        }
+        out.push('\n');
+        if !child.children.is_empty() {
+            let child_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+            render_children(&child.children, &child_prefix, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cokacdir_tree_test_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_build_tree_depth_and_hidden() {
+        let dir = create_temp_dir("depth");
+        fs::create_dir_all(dir.join("a/b/c")).unwrap();
+        fs::write(dir.join("a/file.txt"), "x").unwrap();
+        fs::write(dir.join(".hidden"), "x").unwrap();
+
+        let options = TreeOptions { max_depth: Some(2), show_hidden: false, respect_gitignore: false };
+        let tree = build_tree(&dir, &options).unwrap();
+        assert_eq!(tree.children.len(), 1);
+        let a = &tree.children[0];
+        assert_eq!(a.name, "a");
+        // Directories first, depth 2 includes "b" but not "c"
+        assert_eq!(a.children[0].name, "b");
+        assert!(a.children[0].children.is_empty());
+        assert_eq!(a.children[1].name, "file.txt");
+        assert_eq!(count(&tree), TreeStats { directories: 2, files: 1 });
+
+        let options = TreeOptions { max_depth: Some(1), show_hidden: true, respect_gitignore: false };
+        let tree = build_tree(&dir, &options).unwrap();
+        assert!(tree.children.iter().any(|c| c.name == ".hidden"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_render_text() {
+        let dir = create_temp_dir("render");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/main.rs"), "").unwrap();
+        fs::write(dir.join("README.md"), "").unwrap();
+
+        let tree = build_tree(&dir, &TreeOptions { respect_gitignore: false, ..Default::default() }).unwrap();
+        let text = render_text(&tree);
+        assert!(text.contains("├── src/\n│   └── main.rs\n└── README.md\n"));
+        assert!(text.ends_with("1 directory, 2 files\n"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
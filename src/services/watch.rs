@@ -0,0 +1,327 @@
+//! Watch folder rules.
+//!
+//! A rule such as "when a file matching `*.pdf` appears in `~/Downloads`,
+//! move it to `~/Documents/PDF`" is configured in settings.json:
+//!
+//! ```text
+//! "watch_rules": [
+//!   { "dir": "~/Downloads", "pattern": "*.pdf", "action": "move", "dest": "~/Documents/PDF" }
+//! ]
+//! ```
+//!
+//! Directories are polled rather than subscribed to, so the same code works
+//! on every platform. Files already present when watching starts are left
+//! alone, and a new file is only acted on once its size and modification time
+//! stop changing between two scans (so half-finished downloads are not moved).
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::file_ops;
+use crate::utils::glob::{expand_home, glob_match};
+
+/// How often watched directories are scanned
+pub const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// What to do with a matching file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WatchAction {
+    #[default]
+    Move,
+    Copy,
+}
+
+impl WatchAction {
+    pub fn past_tense(self) -> &'static str {
+        match self {
+            WatchAction::Move => "Moved",
+            WatchAction::Copy => "Copied",
+        }
+    }
+}
+
+/// A single watch rule from settings.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchRule {
+    /// Directory to watch (`~` is expanded). Only its direct children are checked.
+    pub dir: String,
+    /// File name pattern (`*` and `?` wildcards, case-insensitive)
+    pub pattern: String,
+    #[serde(default)]
+    pub action: WatchAction,
+    /// Destination directory, created if missing
+    pub dest: String,
+}
+
+impl WatchRule {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.dir.trim().is_empty() {
+            return Err("watch rule is missing 'dir'".to_string());
+        }
+        if self.dest.trim().is_empty() {
+            return Err(format!("watch rule for {} is missing 'dest'", self.dir));
+        }
+        if self.pattern.trim().is_empty() {
+            return Err(format!("watch rule for {} is missing 'pattern'", self.dir));
+        }
+        if expand_home(&self.dir) == expand_home(&self.dest) {
+            return Err(format!("watch rule for {} moves files into the same directory", self.dir));
+        }
+        Ok(())
+    }
+}
+
+/// Result of applying a rule to one file
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchEvent {
+    Applied { action: WatchAction, src: PathBuf, dest: PathBuf },
+    Failed { src: PathBuf, error: String },
+}
+
+impl WatchEvent {
+    /// One-line description for the status bar or daemon log
+    pub fn describe(&self) -> String {
+        match self {
+            WatchEvent::Applied { action, src, dest } => format!(
+                "{} {} to {}",
+                action.past_tense(),
+                src.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default(),
+                dest.parent().unwrap_or(dest).display()
+            ),
+            WatchEvent::Failed { src, error } => format!("Watch rule failed for {}: {}", src.display(), error),
+        }
+    }
+}
+
+/// Pick a destination path inside `dir` that does not exist yet ("a.pdf", "a (1).pdf", ...)
+fn unique_destination(dir: &Path, file_name: &str) -> PathBuf {
+    let candidate = dir.join(file_name);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let (stem, ext) = match file_name.rfind('.') {
+        Some(pos) if pos > 0 => (&file_name[..pos], &file_name[pos..]),
+        _ => (file_name, ""),
+    };
+    (1..)
+        .map(|i| dir.join(format!("{} ({}){}", stem, i, ext)))
+        .find(|p| !p.exists())
+        .unwrap_or(candidate)
+}
+
+fn apply_rule(rule: &WatchRule, src: &Path) -> io::Result<PathBuf> {
+    let dest_dir = expand_home(&rule.dest);
+    fs::create_dir_all(&dest_dir)?;
+    let file_name = src
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid file name"))?;
+    let dest = unique_destination(&dest_dir, &file_name);
+    match rule.action {
+        WatchAction::Move => file_ops::move_file(src, &dest)?,
+        WatchAction::Copy => file_ops::copy_file(src, &dest)?,
+    }
+    Ok(dest)
+}
+
+/// (size, modified) used to decide whether a file is still being written
+type Stamp = (u64, Option<SystemTime>);
+
+/// Polling state for a set of rules
+pub struct Watcher {
+    rules: Vec<WatchRule>,
+    /// Files already handled or present at start, per rule
+    seen: Vec<HashSet<PathBuf>>,
+    /// New files waiting for their size to settle, per rule
+    pending: Vec<HashMap<PathBuf, Stamp>>,
+}
+
+impl Watcher {
+    /// Create a watcher; files that already match are remembered and never acted on
+    pub fn new(rules: Vec<WatchRule>) -> Self {
+        let seen = rules.iter().map(|rule| matching_files(rule).into_keys().collect()).collect();
+        let pending = rules.iter().map(|_| HashMap::new()).collect();
+        Self { rules, seen, pending }
+    }
+
+    /// Scan every watched directory once and apply rules to files that have settled
+    pub fn scan(&mut self) -> Vec<WatchEvent> {
+        let mut events = Vec::new();
+        for (i, rule) in self.rules.iter().enumerate() {
+            let current = matching_files(rule);
+            let seen = &mut self.seen[i];
+            let pending = &mut self.pending[i];
+
+            // Forget files that went away so a later file with the same name is picked up again
+            seen.retain(|p| current.contains_key(p));
+            pending.retain(|p, _| current.contains_key(p));
+
+            for (path, stamp) in current {
+                if seen.contains(&path) {
+                    continue;
+                }
+                match pending.get(&path) {
+                    Some(prev) if *prev == stamp => {
+                        pending.remove(&path);
+                        seen.insert(path.clone());
+                        events.push(match apply_rule(rule, &path) {
+                            Ok(dest) => WatchEvent::Applied { action: rule.action, src: path, dest },
+                            Err(e) => WatchEvent::Failed { src: path, error: e.to_string() },
+                        });
+                    }
+                    _ => {
+                        pending.insert(path, stamp);
+                    }
+                }
+            }
+        }
+        events
+    }
+}
+
+/// Regular files directly inside the rule's directory that match its pattern
+fn matching_files(rule: &WatchRule) -> HashMap<PathBuf, Stamp> {
+    let Ok(entries) = fs::read_dir(expand_home(&rule.dir)) else {
+        return HashMap::new();
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| glob_match(&rule.pattern, &e.file_name().to_string_lossy(), false))
+        .filter_map(|e| {
+            let meta = e.metadata().ok()?;
+            meta.is_file().then(|| (e.path(), (meta.len(), meta.modified().ok())))
+        })
+        .collect()
+}
+
+/// Keep only valid rules, returning the errors of the rest
+pub fn valid_rules(rules: &[WatchRule]) -> (Vec<WatchRule>, Vec<String>) {
+    let mut valid = Vec::new();
+    let mut errors = Vec::new();
+    for rule in rules {
+        match rule.validate() {
+            Ok(()) => valid.push(rule.clone()),
+            Err(e) => errors.push(e),
+        }
+    }
+    (valid, errors)
+}
+
+/// Watcher running in a background thread while the TUI is open. Stops on drop.
+pub struct WatchService {
+    receiver: Receiver<WatchEvent>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl WatchService {
+    /// Start watching; returns None when there is nothing to watch
+    pub fn start(rules: Vec<WatchRule>) -> Option<Self> {
+        if rules.is_empty() {
+            return None;
+        }
+        let (tx, rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let thread_cancel = Arc::clone(&cancel);
+        thread::spawn(move || {
+            let mut watcher = Watcher::new(rules);
+            while !thread_cancel.load(Ordering::Relaxed) {
+                thread::sleep(POLL_INTERVAL);
+                for event in watcher.scan() {
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        Some(Self { receiver: rx, cancel })
+    }
+
+    /// Take the next event, if any (non-blocking)
+    pub fn try_recv(&self) -> Option<WatchEvent> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl Drop for WatchService {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Run the watcher in the foreground (`--watch`), logging every action to stdout
+pub fn run_daemon(rules: Vec<WatchRule>) {
+    for rule in &rules {
+        println!(
+            "Watching {} for {} -> {} ({:?})",
+            expand_home(&rule.dir).display(),
+            rule.pattern,
+            expand_home(&rule.dest).display(),
+            rule.action
+        );
+    }
+    let mut watcher = Watcher::new(rules);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        for event in watcher.scan() {
+            let time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+            match event {
+                WatchEvent::Applied { .. } => println!("[{}] {}", time, event.describe()),
+                WatchEvent::Failed { .. } => eprintln!("[{}] {}", time, event.describe()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watcher_moves_new_files_once_settled() {
+        let src = tempfile::tempdir().unwrap();
+        let dest = tempfile::tempdir().unwrap();
+        fs::write(src.path().join("old.pdf"), b"old").unwrap();
+
+        let rule = WatchRule {
+            dir: src.path().display().to_string(),
+            pattern: "*.pdf".to_string(),
+            action: WatchAction::Move,
+            dest: dest.path().join("PDF").display().to_string(),
+        };
+        let mut watcher = Watcher::new(vec![rule]);
+
+        fs::write(src.path().join("new.pdf"), b"new").unwrap();
+        fs::write(src.path().join("notes.txt"), b"txt").unwrap();
+
+        // First sighting only records the size; the second scan acts
+        assert!(watcher.scan().is_empty());
+        let events = watcher.scan();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], WatchEvent::Applied { action: WatchAction::Move, .. }));
+
+        assert!(dest.path().join("PDF").join("new.pdf").exists());
+        assert!(!src.path().join("new.pdf").exists());
+        // Pre-existing and non-matching files are untouched
+        assert!(src.path().join("old.pdf").exists());
+        assert!(src.path().join("notes.txt").exists());
+        assert!(watcher.scan().is_empty());
+    }
+
+    #[test]
+    fn test_unique_destination() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(unique_destination(dir.path(), "a.pdf"), dir.path().join("a.pdf"));
+        fs::write(dir.path().join("a.pdf"), b"x").unwrap();
+        assert_eq!(unique_destination(dir.path(), "a.pdf"), dir.path().join("a (1).pdf"));
+    }
+}
@@ -0,0 +1,195 @@
+//! Webhook notifications for finished jobs (scheduled jobs, long file operations).
+//!
+//! Configured under `notifications` in settings.json:
+//!
+//! ```text
+//! "notifications": {
+//!   "webhooks": [
+//!     { "url": "https://hooks.slack.com/services/T000/B000/XXXX" },
+//!     { "url": "https://discord.com/api/webhooks/123/abc" },
+//!     { "url": "https://example.com/hooks/cokacdir", "format": "json" }
+//!   ]
+//! }
+//! ```
+//!
+//! Without `format`, Slack and Discord are recognized from the URL and any
+//! other URL receives the generic JSON body.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+const TIMEOUT_SECS: u64 = 10;
+
+/// Discord rejects messages longer than 2000 characters
+const DISCORD_MAX_CHARS: usize = 1900;
+
+/// Longest body sent to Slack/Discord
+const CHAT_MAX_BODY_CHARS: usize = 3000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// Slack or Discord when recognized from the URL, JSON otherwise
+    #[default]
+    Auto,
+    /// `{"text": ...}` (Slack incoming webhook, Mattermost, ...)
+    Slack,
+    /// `{"content": ...}`
+    Discord,
+    /// Event object with title, body, success and details
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "is_auto")]
+    pub format: WebhookFormat,
+}
+
+fn is_auto(format: &WebhookFormat) -> bool {
+    *format == WebhookFormat::Auto
+}
+
+impl Webhook {
+    /// Format actually used for this URL
+    pub fn resolved_format(&self) -> WebhookFormat {
+        if self.format != WebhookFormat::Auto {
+            return self.format;
+        }
+        let url = self.url.to_lowercase();
+        if url.contains("hooks.slack.com") {
+            WebhookFormat::Slack
+        } else if url.contains("discord.com/api/webhooks") || url.contains("discordapp.com/api/webhooks") {
+            WebhookFormat::Discord
+        } else {
+            WebhookFormat::Json
+        }
+    }
+}
+
+/// A finished job
+#[derive(Debug, Clone)]
+pub struct WebhookEvent {
+    /// "schedule_finished" or "file_operation_finished"
+    pub event: &'static str,
+    pub title: String,
+    pub body: String,
+    pub success: bool,
+    /// Extra fields of the JSON format
+    pub details: serde_json::Value,
+}
+
+fn truncate_chars(text: &str, max: usize) -> String {
+    if text.chars().count() > max {
+        let mut short: String = text.chars().take(max).collect();
+        short.push('…');
+        short
+    } else {
+        text.to_string()
+    }
+}
+
+/// Request body of `event` for a webhook of the given format
+pub fn payload(format: WebhookFormat, event: &WebhookEvent) -> serde_json::Value {
+    let mark = if event.success { "✅" } else { "❌" };
+    let body = truncate_chars(&event.body, CHAT_MAX_BODY_CHARS);
+    match format {
+        WebhookFormat::Slack => serde_json::json!({
+            "text": format!("{} *{}*\n{}", mark, event.title, body),
+        }),
+        WebhookFormat::Discord => serde_json::json!({
+            "content": truncate_chars(&format!("{} **{}**\n{}", mark, event.title, body), DISCORD_MAX_CHARS),
+        }),
+        WebhookFormat::Auto | WebhookFormat::Json => serde_json::json!({
+            "event": event.event,
+            "title": event.title,
+            "body": event.body,
+            "success": event.success,
+            "time": chrono::Local::now().to_rfc3339(),
+            "details": event.details,
+        }),
+    }
+}
+
+/// POST `event` to every webhook and wait; returns one message per failed URL
+pub fn post_all(webhooks: &[Webhook], event: &WebhookEvent) -> Vec<String> {
+    if webhooks.is_empty() {
+        return Vec::new();
+    }
+    let rt = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+        Ok(rt) => rt,
+        Err(e) => return vec![format!("webhooks: {}", e)],
+    };
+    rt.block_on(async {
+        let client = reqwest::Client::new();
+        let mut errors = Vec::new();
+        for hook in webhooks {
+            let body = payload(hook.resolved_format(), event).to_string();
+            let response = client
+                .post(&hook.url)
+                .header("Content-Type", "application/json")
+                .body(body)
+                .timeout(Duration::from_secs(TIMEOUT_SECS))
+                .send()
+                .await;
+            match response {
+                Ok(resp) if resp.status().is_success() => {}
+                Ok(resp) => errors.push(format!("{}: HTTP {}", hook.url, resp.status())),
+                Err(e) => errors.push(format!("{}: {}", hook.url, e)),
+            }
+        }
+        errors
+    })
+}
+
+/// POST `event` to every webhook in the background (failures are ignored)
+pub fn send(webhooks: &[Webhook], event: WebhookEvent) {
+    if webhooks.is_empty() {
+        return;
+    }
+    let webhooks = webhooks.to_vec();
+    std::thread::spawn(move || {
+        let _ = post_all(&webhooks, &event);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(url: &str, format: WebhookFormat) -> Webhook {
+        Webhook { url: url.to_string(), format }
+    }
+
+    #[test]
+    fn test_format_detection_and_payloads() {
+        assert_eq!(hook("https://hooks.slack.com/services/T/B/X", WebhookFormat::Auto).resolved_format(), WebhookFormat::Slack);
+        assert_eq!(hook("https://discord.com/api/webhooks/1/abc", WebhookFormat::Auto).resolved_format(), WebhookFormat::Discord);
+        assert_eq!(hook("https://example.com/hook", WebhookFormat::Auto).resolved_format(), WebhookFormat::Json);
+        assert_eq!(hook("https://hooks.slack.com/x", WebhookFormat::Json).resolved_format(), WebhookFormat::Json);
+
+        let parsed: Webhook = serde_json::from_str(r#"{"url": "https://example.com/h", "format": "discord"}"#).unwrap();
+        assert_eq!(parsed.format, WebhookFormat::Discord);
+        let parsed: Webhook = serde_json::from_str(r#"{"url": "https://example.com/h"}"#).unwrap();
+        assert_eq!(parsed.format, WebhookFormat::Auto);
+
+        let event = WebhookEvent {
+            event: "file_operation_finished",
+            title: "Copy finished".to_string(),
+            body: "x".repeat(5000),
+            success: true,
+            details: serde_json::json!({"files": 3}),
+        };
+        let slack = payload(WebhookFormat::Slack, &event);
+        assert!(slack["text"].as_str().unwrap().starts_with("✅ *Copy finished*\n"));
+        let discord = payload(WebhookFormat::Discord, &event);
+        assert_eq!(discord["content"].as_str().unwrap().chars().count(), DISCORD_MAX_CHARS + 1);
+        let json = payload(WebhookFormat::Json, &event);
+        assert_eq!(json["event"], "file_operation_finished");
+        assert_eq!(json["success"], true);
+        assert_eq!(json["details"]["files"], 3);
+        assert_eq!(json["body"].as_str().unwrap().len(), 5000);
+    }
+}
@@ -0,0 +1,181 @@
+//! Extended attributes (Linux and macOS) for the file properties screen.
+
+use std::io;
+use std::path::Path;
+
+/// Whether extended attributes can be read on this platform
+pub fn is_supported() -> bool {
+    cfg!(any(target_os = "linux", target_os = "macos"))
+}
+
+/// All attributes of `path` as (name, value), sorted by name
+pub fn list(path: &Path) -> io::Result<Vec<(String, Vec<u8>)>> {
+    imp::list(path)
+}
+
+pub fn set(path: &Path, name: &str, value: &[u8]) -> io::Result<()> {
+    imp::set(path, name, value)
+}
+
+pub fn remove(path: &Path, name: &str) -> io::Result<()> {
+    imp::remove(path, name)
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[allow(unsafe_code)]
+mod imp {
+    use std::ffi::CString;
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    use libc::{c_char, c_void};
+
+    fn c_string(bytes: &[u8]) -> io::Result<CString> {
+        CString::new(bytes).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "name contains NUL byte"))
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe fn sys_list(path: *const c_char, buf: *mut c_char, size: usize) -> isize {
+        libc::listxattr(path, buf, size)
+    }
+    #[cfg(target_os = "macos")]
+    unsafe fn sys_list(path: *const c_char, buf: *mut c_char, size: usize) -> isize {
+        libc::listxattr(path, buf, size, 0)
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe fn sys_get(path: *const c_char, name: *const c_char, buf: *mut c_void, size: usize) -> isize {
+        libc::getxattr(path, name, buf, size)
+    }
+    #[cfg(target_os = "macos")]
+    unsafe fn sys_get(path: *const c_char, name: *const c_char, buf: *mut c_void, size: usize) -> isize {
+        libc::getxattr(path, name, buf, size, 0, 0)
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe fn sys_set(path: *const c_char, name: *const c_char, value: *const c_void, size: usize) -> i32 {
+        libc::setxattr(path, name, value, size, 0)
+    }
+    #[cfg(target_os = "macos")]
+    unsafe fn sys_set(path: *const c_char, name: *const c_char, value: *const c_void, size: usize) -> i32 {
+        libc::setxattr(path, name, value, size, 0, 0)
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe fn sys_remove(path: *const c_char, name: *const c_char) -> i32 {
+        libc::removexattr(path, name)
+    }
+    #[cfg(target_os = "macos")]
+    unsafe fn sys_remove(path: *const c_char, name: *const c_char) -> i32 {
+        libc::removexattr(path, name, 0)
+    }
+
+    /// Call a size-query-then-fill xattr function, retrying if the value grew in between
+    fn read_sized(mut call: impl FnMut(*mut c_char, usize) -> isize) -> io::Result<Vec<u8>> {
+        loop {
+            let size = call(std::ptr::null_mut(), 0);
+            if size < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let mut buf = vec![0u8; size as usize];
+            if buf.is_empty() {
+                return Ok(buf);
+            }
+            let n = call(buf.as_mut_ptr() as *mut c_char, buf.len());
+            if n < 0 {
+                let err = io::Error::last_os_error();
+                if err.raw_os_error() == Some(libc::ERANGE) {
+                    continue;
+                }
+                return Err(err);
+            }
+            buf.truncate(n as usize);
+            return Ok(buf);
+        }
+    }
+
+    pub fn list(path: &Path) -> io::Result<Vec<(String, Vec<u8>)>> {
+        let cpath = c_string(path.as_os_str().as_bytes())?;
+        let names = read_sized(|buf, size| unsafe { sys_list(cpath.as_ptr(), buf, size) })?;
+
+        let mut attrs = Vec::new();
+        for name in names.split(|&b| b == 0).filter(|n| !n.is_empty()) {
+            let cname = c_string(name)?;
+            let value = read_sized(|buf, size| unsafe {
+                sys_get(cpath.as_ptr(), cname.as_ptr(), buf as *mut c_void, size)
+            });
+            // Attributes can disappear or be unreadable (e.g. security.*); skip those
+            if let Ok(value) = value {
+                attrs.push((String::from_utf8_lossy(name).into_owned(), value));
+            }
+        }
+        attrs.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(attrs)
+    }
+
+    pub fn set(path: &Path, name: &str, value: &[u8]) -> io::Result<()> {
+        let cpath = c_string(path.as_os_str().as_bytes())?;
+        let cname = c_string(name.as_bytes())?;
+        let rc = unsafe { sys_set(cpath.as_ptr(), cname.as_ptr(), value.as_ptr() as *const c_void, value.len()) };
+        if rc < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    pub fn remove(path: &Path, name: &str) -> io::Result<()> {
+        let cpath = c_string(path.as_os_str().as_bytes())?;
+        let cname = c_string(name.as_bytes())?;
+        let rc = unsafe { sys_remove(cpath.as_ptr(), cname.as_ptr()) };
+        if rc < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod imp {
+    use std::io;
+    use std::path::Path;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(io::ErrorKind::Unsupported, "extended attributes are not supported")
+    }
+
+    pub fn list(_path: &Path) -> io::Result<Vec<(String, Vec<u8>)>> {
+        Ok(Vec::new())
+    }
+
+    pub fn set(_path: &Path, _name: &str, _value: &[u8]) -> io::Result<()> {
+        Err(unsupported())
+    }
+
+    pub fn remove(_path: &Path, _name: &str) -> io::Result<()> {
+        Err(unsupported())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xattr_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        std::fs::write(&file, b"x").unwrap();
+
+        // Some filesystems (tmpfs on older kernels, overlayfs) reject user xattrs
+        if set(&file, "user.cokacdir.test", b"value").is_err() {
+            return;
+        }
+        let attrs = list(&file).unwrap();
+        assert!(attrs.contains(&("user.cokacdir.test".to_string(), b"value".to_vec())));
+
+        remove(&file, "user.cokacdir.test").unwrap();
+        let attrs = list(&file).unwrap();
+        assert!(!attrs.iter().any(|(n, _)| n == "user.cokacdir.test"));
+    }
+}
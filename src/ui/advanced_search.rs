@@ -8,9 +8,12 @@ use ratatui::{
 };
 
 use super::theme::Theme;
+use crate::config::SmartFolder;
 use crate::utils::format::pad_to_display_width;
 
-#[derive(Debug, Clone)]
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SearchCriteria {
     pub name: String,
     pub min_size: Option<u64>,
@@ -19,6 +22,41 @@ pub struct SearchCriteria {
     pub modified_before: Option<chrono::NaiveDate>,
 }
 
+impl SearchCriteria {
+    /// Save these criteria as a smart folder searching `path`
+    pub fn to_smart_folder(&self, name: &str, path: &str) -> SmartFolder {
+        SmartFolder {
+            name: name.to_string(),
+            path: path.to_string(),
+            pattern: self.name.clone(),
+            min_size: self.min_size,
+            max_size: self.max_size,
+            modified_after: self.modified_after.map(|d| d.format(DATE_FORMAT).to_string()),
+            modified_before: self.modified_before.map(|d| d.format(DATE_FORMAT).to_string()),
+        }
+    }
+
+    /// Criteria saved in a smart folder (unparsable dates are ignored)
+    pub fn from_smart_folder(folder: &SmartFolder) -> Self {
+        Self {
+            name: folder.pattern.clone(),
+            min_size: folder.min_size,
+            max_size: folder.max_size,
+            modified_after: folder.modified_after.as_deref().and_then(parse_date),
+            modified_before: folder.modified_before.as_deref().and_then(parse_date),
+        }
+    }
+}
+
+/// What the user asked for when leaving the dialog
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdvancedSearchOutcome {
+    /// Select the matching files in the current panel
+    Search(SearchCriteria),
+    /// Save the criteria as a smart folder (the name is asked next)
+    SaveSmartFolder(SearchCriteria),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SearchField {
     Name,
@@ -124,11 +162,11 @@ fn parse_date(s: &str) -> Option<chrono::NaiveDate> {
     if s.is_empty() {
         return None;
     }
-    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+    chrono::NaiveDate::parse_from_str(s, DATE_FORMAT).ok()
 }
 
 pub fn draw(frame: &mut Frame, state: &AdvancedSearchState, area: Rect, theme: &Theme, kb: &crate::keybindings::Keybindings) {
-    let width = 50u16;
+    let width = 60u16;
     let height = 12u16;
     let x = area.x + (area.width.saturating_sub(width)) / 2;
     let y = area.y + (area.height.saturating_sub(height)) / 2;
@@ -199,8 +237,9 @@ pub fn draw(frame: &mut Frame, state: &AdvancedSearchState, area: Rect, theme: &
         let nav_key = kb.advanced_search_keys_joined(AdvancedSearchAction::MoveDown, "/");
         let submit_key = kb.advanced_search_first_key(AdvancedSearchAction::Submit);
         let cancel_key = kb.advanced_search_first_key(AdvancedSearchAction::Cancel);
+        let save_key = kb.advanced_search_first_key(AdvancedSearchAction::SaveSmartFolder);
         lines.push(Line::from(Span::styled(
-            format!("[{}] Navigate  [{}] Search  [{}] Cancel", nav_key, submit_key, cancel_key),
+            format!("[{}] Navigate  [{}] Search  [{}] Save  [{}] Cancel", nav_key, submit_key, save_key, cancel_key),
             theme.dim_style(),
         )));
     }
@@ -220,7 +259,7 @@ pub fn handle_paste(state: &mut AdvancedSearchState, text: &str) {
     }
 }
 
-pub fn handle_input(state: &mut AdvancedSearchState, code: KeyCode, modifiers: KeyModifiers, kb: &crate::keybindings::Keybindings) -> Option<SearchCriteria> {
+pub fn handle_input(state: &mut AdvancedSearchState, code: KeyCode, modifiers: KeyModifiers, kb: &crate::keybindings::Keybindings) -> Option<AdvancedSearchOutcome> {
     use crate::keybindings::AdvancedSearchAction;
 
     if let Some(action) = kb.advanced_search_action(code, modifiers) {
@@ -234,7 +273,13 @@ pub fn handle_input(state: &mut AdvancedSearchState, code: KeyCode, modifiers: K
                 state.active = false;
                 let criteria = state.get_criteria();
                 state.reset();
-                return Some(criteria);
+                return Some(AdvancedSearchOutcome::Search(criteria));
+            }
+            AdvancedSearchAction::SaveSmartFolder => {
+                state.active = false;
+                let criteria = state.get_criteria();
+                state.reset();
+                return Some(AdvancedSearchOutcome::SaveSmartFolder(criteria));
             }
             AdvancedSearchAction::MoveUp => {
                 state.active_field = state.active_field.saturating_sub(1);
@@ -9,35 +9,18 @@ use ratatui::{
 use rand::Rng;
 use unicode_width::UnicodeWidthChar;
 use serde::{Deserialize, Serialize};
-use std::fs::{self, OpenOptions};
-use std::io::Write as IoWrite;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 use std::sync::mpsc::{self, Receiver, TryRecvError};
 use std::thread;
 
-use crate::utils::format::safe_truncate;
+use crate::utils::format::{format_duration, safe_truncate};
 use crate::keybindings::{AIScreenAction, Keybindings};
-
-/// Debug logging helper (active when /debug toggled ON or COKACDIR_DEBUG=1)
-fn debug_log(msg: &str) {
-    use std::sync::atomic::Ordering;
-    if !crate::services::claude::DEBUG_ENABLED.load(Ordering::Relaxed) { return; }
-    if let Some(home) = dirs::home_dir() {
-        let debug_dir = home.join(".cokacdir").join("debug");
-        let _ = std::fs::create_dir_all(&debug_dir);
-        let log_path = debug_dir.join("ai_screen.log");
-        if let Ok(mut file) = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(log_path)
-        {
-            let timestamp = chrono::Local::now().format("%H:%M:%S%.3f");
-            let _ = writeln!(file, "[{}] {}", timestamp, msg);
-        }
-    }
-}
+use crate::{log_debug, log_error};
 
 use super::theme::Theme;
+use crate::services::ai_approval::{ApprovalRequest, ApprovalServer, GATED_TOOLS};
 use crate::services::claude::{self, StreamMessage};
 use crate::utils::markdown::{is_line_empty, render_markdown, MarkdownTheme};
 
@@ -316,6 +299,9 @@ fn format_tool_use(name: &str, input: &str) -> String {
 pub struct HistoryItem {
     pub item_type: HistoryType,
     pub content: String,
+    /// Tool calls of a ToolTimeline item (empty for other types)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<ToolCall>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -326,6 +312,425 @@ pub enum HistoryType {
     System,
     ToolUse,      // Tool usage display (e.g., "[Bash]")
     ToolResult,   // Tool execution result
+    ToolTimeline, // Consecutive tool calls with status and duration
+}
+
+/// Status of a tool call in the activity timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolStatus {
+    Running,
+    Ok,
+    Failed,
+    /// Request ended (cancelled, failed or session restored) before a result arrived
+    Cancelled,
+}
+
+/// One tool invocation in the activity timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub name: String,
+    /// Simplified input (command, file path, pattern...)
+    pub summary: String,
+    pub status: ToolStatus,
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+    /// First line of the result (truncated)
+    #[serde(default)]
+    pub result: String,
+    #[serde(skip)]
+    started: Option<Instant>,
+}
+
+/// Maximum characters of a tool result kept in the timeline
+const TOOL_RESULT_PREVIEW_CHARS: usize = 120;
+
+impl ToolCall {
+    fn status_icon(&self) -> &'static str {
+        match self.status {
+            ToolStatus::Running => "…",
+            ToolStatus::Ok => "✓",
+            ToolStatus::Failed => "✗",
+            ToolStatus::Cancelled => "-",
+        }
+    }
+}
+
+/// "0.4s", "12s", "1:05"
+fn format_tool_duration(ms: u64) -> String {
+    if ms < 10_000 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else if ms < 60_000 {
+        format!("{}s", ms / 1000)
+    } else {
+        format_duration(ms / 1000)
+    }
+}
+
+/// One-line summary of a timeline: "3 tool calls · 1.2s · 1 failed"
+pub fn tool_timeline_summary(calls: &[ToolCall]) -> String {
+    let total_ms: u64 = calls.iter().filter_map(|c| c.duration_ms).sum();
+    let mut summary = format!(
+        "{} tool call{} · {}",
+        calls.len(),
+        if calls.len() == 1 { "" } else { "s" },
+        format_tool_duration(total_ms)
+    );
+    let failed = calls.iter().filter(|c| c.status == ToolStatus::Failed).count();
+    if failed > 0 {
+        summary.push_str(&format!(" · {} failed", failed));
+    }
+    if let Some(running) = calls.iter().find(|c| c.status == ToolStatus::Running) {
+        summary.push_str(&format!(" · running {}", running.name));
+    }
+    summary
+}
+
+/// Plain text form of a timeline (stored in `content` for sessions and other consumers)
+fn tool_timeline_text(calls: &[ToolCall]) -> String {
+    calls.iter()
+        .map(|c| match c.duration_ms {
+            Some(ms) => format!("{} {} {} ({})", c.status_icon(), c.name, c.summary, format_tool_duration(ms)),
+            None => format!("{} {} {}", c.status_icon(), c.name, c.summary),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Conversation switch requested from the AI screen (handled by App)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversationRequest {
+    /// Park the current conversation and open a fresh one
+    New,
+    /// Park the current conversation and bring up the next open one
+    Next,
+}
+
+/// Change of the AI pane split requested from the AI screen (handled by App)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneLayoutRequest {
+    Grow,
+    Shrink,
+    ToggleOrientation,
+}
+
+/// Action offered on the latest AI response (selectable list under the response)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseAction {
+    /// Save a fenced code block to a file
+    SaveCode { language: String, code: String },
+    /// Copy a fenced code block to the clipboard
+    CopyCode(String),
+    /// Move the file panel to a path mentioned in the response
+    GotoPath(PathBuf),
+}
+
+/// Action list extracted from the latest response
+#[derive(Debug, Clone)]
+pub struct ResponseActionMenu {
+    /// (label, action) pairs in display order
+    pub items: Vec<(String, ResponseAction)>,
+    pub selected: usize,
+}
+
+/// Maximum number of mentioned paths offered as actions
+const MAX_PATH_ACTIONS: usize = 20;
+
+/// Fenced code blocks in a markdown response as (language, code)
+pub fn extract_code_blocks(text: &str) -> Vec<(String, String)> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if let Some(rest) = trimmed.strip_prefix("```") {
+            match current.take() {
+                Some((language, lines)) => blocks.push((language, lines.join("\n"))),
+                None => current = Some((rest.trim().to_string(), Vec::new())),
+            }
+        } else if let Some((_, ref mut lines)) = current {
+            lines.push(line);
+        }
+    }
+    // Unterminated block (e.g. response cut off) still counts
+    if let Some((language, lines)) = current {
+        if !lines.is_empty() {
+            blocks.push((language, lines.join("\n")));
+        }
+    }
+    blocks
+}
+
+/// Existing paths mentioned outside code blocks, resolved against `base`.
+/// A token counts when it contains '/' or is wrapped in backticks;
+/// a trailing ":line" or ":line:col" suffix is ignored.
+pub fn extract_paths(text: &str, base: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let mut in_code = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        for raw in line.split_whitespace() {
+            let quoted = raw.starts_with('`');
+            let is_wrapper = |c: char| matches!(c, '`' | '"' | '\'' | '(' | ')' | '[' | ']' | '<' | '>' | ',' | ';' | '*');
+            let token = raw.trim_start_matches(is_wrapper)
+                .trim_end_matches(|c: char| is_wrapper(c) || c == '.' || c == ':');
+            let token = strip_line_suffix(token);
+            if token.is_empty() || (!quoted && !token.contains('/')) || token.contains("://") {
+                continue;
+            }
+            let path = if let Some(rest) = token.strip_prefix("~/") {
+                match dirs::home_dir() {
+                    Some(home) => home.join(rest),
+                    None => continue,
+                }
+            } else {
+                base.join(token)
+            };
+            if path.exists() && !paths.contains(&path) {
+                paths.push(path);
+                if paths.len() >= MAX_PATH_ACTIONS {
+                    return paths;
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// "src/main.rs:42:7" -> "src/main.rs"
+fn strip_line_suffix(token: &str) -> &str {
+    let mut result = token;
+    for _ in 0..2 {
+        match result.rsplit_once(':') {
+            Some((head, tail)) if !head.is_empty() && !tail.is_empty() && tail.chars().all(|c| c.is_ascii_digit()) => {
+                result = head;
+            }
+            _ => break,
+        }
+    }
+    result
+}
+
+/// Actions for a response: save/copy per code block, then jump per mentioned path
+pub fn build_response_actions(text: &str, base: &Path) -> Vec<(String, ResponseAction)> {
+    let mut items = Vec::new();
+    for (i, (language, code)) in extract_code_blocks(text).into_iter().enumerate() {
+        let line_count = code.lines().count();
+        let unit = if line_count == 1 { "line" } else { "lines" };
+        let desc = if language.is_empty() {
+            format!("{} {}", line_count, unit)
+        } else {
+            format!("{}, {} {}", language, line_count, unit)
+        };
+        items.push((format!("Save code block {} ({})", i + 1, desc), ResponseAction::SaveCode { language, code: code.clone() }));
+        items.push((format!("Copy code block {} ({})", i + 1, desc), ResponseAction::CopyCode(code)));
+    }
+    for path in extract_paths(text, base) {
+        items.push((format!("Go to {}", path.display()), ResponseAction::GotoPath(path)));
+    }
+    items
+}
+
+/// Default file name for saving a code block in `language`
+pub fn suggested_file_name(language: &str) -> String {
+    let ext = match language.to_lowercase().as_str() {
+        "rust" | "rs" => "rs",
+        "python" | "py" => "py",
+        "javascript" | "js" => "js",
+        "typescript" | "ts" => "ts",
+        "bash" | "sh" | "shell" | "zsh" => "sh",
+        "json" => "json",
+        "toml" => "toml",
+        "yaml" | "yml" => "yaml",
+        "html" => "html",
+        "css" => "css",
+        "c" => "c",
+        "cpp" | "c++" => "cpp",
+        "go" => "go",
+        "java" => "java",
+        "ruby" | "rb" => "rb",
+        "sql" => "sql",
+        "markdown" | "md" => "md",
+        _ => "txt",
+    };
+    format!("snippet.{}", ext)
+}
+
+/// Working-directory field shown in the AI header (Ctrl+P)
+#[derive(Debug, Clone, Default)]
+pub struct PathEdit {
+    pub input: String,
+    /// Cursor position (chars)
+    pub cursor: usize,
+    /// Directory candidates cycled by repeated Tab
+    pub matches: Vec<String>,
+    pub match_index: Option<usize>,
+    /// Validation error shown next to the field
+    pub error: Option<String>,
+}
+
+impl PathEdit {
+    fn set_input(&mut self, input: String) {
+        self.cursor = input.chars().count();
+        self.input = input;
+        self.error = None;
+    }
+
+    /// Any edit ends Tab cycling
+    fn reset_matches(&mut self) {
+        self.matches.clear();
+        self.match_index = None;
+        self.error = None;
+    }
+}
+
+/// Resolve a typed path (`~` expanded, relative to `base`)
+fn resolve_dir_input(input: &str, base: &str) -> PathBuf {
+    let path = super::dialogs::expand_path_string(input.trim());
+    if path.is_absolute() {
+        path
+    } else {
+        Path::new(base).join(path)
+    }
+}
+
+/// Largest file inserted by /content
+const MAX_CONTEXT_FILE_BYTES: u64 = 100 * 1024;
+
+/// Depth and entry limits of the tree inserted by /tree
+const CONTEXT_TREE_DEPTH: usize = 3;
+const CONTEXT_TREE_MAX_ENTRIES: usize = 300;
+
+/// File panel state used to expand /files, /tree and /content in the AI input
+#[derive(Debug, Clone, Default)]
+pub struct PanelContext {
+    /// Directory of the file panel next to the AI screen
+    pub path: PathBuf,
+    /// Selected names, or the name under the cursor when nothing is selected
+    pub files: Vec<String>,
+}
+
+/// Expand the context commands in the input with the file panel state:
+/// `/files` (selected files), `/tree` (directory tree), `/content <file>` (file contents).
+/// Commands must be whole words; other text, including paths such as `/tree/x`, is kept as is.
+pub fn expand_context_commands(input: &str, ctx: Option<&PanelContext>) -> Result<String, String> {
+    let mut lines = Vec::new();
+    for line in input.split('\n') {
+        let words: Vec<&str> = line.split(' ').collect();
+        let mut parts: Vec<String> = Vec::with_capacity(words.len());
+        let mut i = 0;
+        while i < words.len() {
+            let word = words[i];
+            if !matches!(word, "/files" | "/tree" | "/content") {
+                parts.push(word.to_string());
+                i += 1;
+                continue;
+            }
+            let ctx = ctx.ok_or_else(|| format!("{} needs a local file panel", word))?;
+            match word {
+                "/files" => parts.push(files_context(ctx)),
+                "/tree" => parts.push(tree_context(&ctx.path)),
+                _ => {
+                    // 다음 단어가 파일 이름
+                    let Some(arg) = (i + 1..words.len()).find(|&k| !words[k].is_empty()) else {
+                        return Err("Usage: /content <file>".to_string());
+                    };
+                    parts.push(content_context(&ctx.path, words[arg])?);
+                    i = arg;
+                }
+            }
+            i += 1;
+        }
+        lines.push(parts.join(" "));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn files_context(ctx: &PanelContext) -> String {
+    if ctx.files.is_empty() {
+        return format!("\n[No files selected in {}]\n", ctx.path.display());
+    }
+    let mut block = format!("\n[Selected files in {}]\n", ctx.path.display());
+    for name in &ctx.files {
+        block.push_str(&format!("- {}\n", ctx.path.join(name).display()));
+    }
+    block
+}
+
+fn tree_context(root: &Path) -> String {
+    let mut entries = Vec::new();
+    let truncated = collect_tree(root, 0, &mut entries);
+    let mut block = format!("\n[Directory tree of {}]\n```\n", root.display());
+    for entry in &entries {
+        block.push_str(entry);
+        block.push('\n');
+    }
+    if truncated {
+        block.push_str("... (truncated)\n");
+    }
+    block.push_str("```\n");
+    block
+}
+
+/// Append indented tree entries; returns true when the entry limit was reached.
+/// Hidden directories are listed but not descended into.
+fn collect_tree(dir: &Path, depth: usize, entries: &mut Vec<String>) -> bool {
+    let Ok(read) = fs::read_dir(dir) else { return false };
+    let mut children: Vec<(String, bool)> = read
+        .filter_map(|e| e.ok())
+        .map(|e| {
+            let is_dir = e.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            (e.file_name().to_string_lossy().to_string(), is_dir)
+        })
+        .collect();
+    // 디렉토리 먼저, 이름순
+    children.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    for (name, is_dir) in children {
+        if entries.len() >= CONTEXT_TREE_MAX_ENTRIES {
+            return true;
+        }
+        let indent = "  ".repeat(depth);
+        if is_dir {
+            entries.push(format!("{}{}/", indent, name));
+            if depth + 1 < CONTEXT_TREE_DEPTH && !name.starts_with('.')
+                && collect_tree(&dir.join(&name), depth + 1, entries)
+            {
+                return true;
+            }
+        } else {
+            entries.push(format!("{}{}", indent, name));
+        }
+    }
+    false
+}
+
+fn content_context(base: &Path, name: &str) -> Result<String, String> {
+    let path = resolve_dir_input(name, &base.display().to_string());
+    let meta = fs::metadata(&path).map_err(|e| format!("/content {}: {}", name, e))?;
+    if !meta.is_file() {
+        return Err(format!("/content {}: not a file", name));
+    }
+    if meta.len() > MAX_CONTEXT_FILE_BYTES {
+        return Err(format!("/content {}: file is larger than {} KB", name, MAX_CONTEXT_FILE_BYTES / 1024));
+    }
+    let bytes = fs::read(&path).map_err(|e| format!("/content {}: {}", name, e))?;
+    if bytes.contains(&0) {
+        return Err(format!("/content {}: binary file", name));
+    }
+    let text = String::from_utf8_lossy(&bytes);
+    let language = path.extension().map(|e| e.to_string_lossy().to_string()).unwrap_or_default();
+    Ok(format!(
+        "\n[Contents of {}]\n```{}\n{}{}```\n",
+        path.display(),
+        language,
+        text,
+        if text.ends_with('\n') { "" } else { "\n" }
+    ))
 }
 
 /// Placeholder messages for AI input
@@ -365,6 +770,44 @@ pub struct AIScreenState {
     pub last_raw_lines: usize,
     /// Whether AI screen is in fullscreen mode (toggle with Ctrl+F)
     pub ai_fullscreen: bool,
+    /// When the current request was submitted (for finish notifications)
+    pub started_at: Option<Instant>,
+    /// Conversation number assigned by App (stable while the conversation is open)
+    pub conversation_no: usize,
+    /// Title label such as "Chat 2/3" (None when only one conversation is open)
+    pub conversation_label: Option<String>,
+    /// Response finished while the conversation was parked and not yet viewed
+    pub has_unseen_response: bool,
+    /// Pending New/Next conversation request from handle_input
+    pub conversation_request: Option<ConversationRequest>,
+    /// Pending split resize / orientation change for App
+    pub layout_request: Option<PaneLayoutRequest>,
+    /// Action list for the latest response (Ctrl+O)
+    pub response_actions: Option<ResponseActionMenu>,
+    /// Action chosen from the list, executed by App
+    pub response_action_request: Option<ResponseAction>,
+    /// Show tool timelines as a single summary line (Ctrl+G)
+    pub timeline_collapsed: bool,
+    /// Ask before each Bash/Write/Edit call (Ctrl+Y toggles)
+    pub approval_mode: bool,
+    /// Seconds before an unanswered approval request is denied
+    pub approval_timeout_secs: u64,
+    /// Approval socket of the running request (approval mode only)
+    approval_server: Option<ApprovalServer>,
+    /// Tool calls waiting for a decision (first one is shown)
+    pub pending_approvals: Vec<ApprovalRequest>,
+    /// Tools allowed for the rest of this conversation ("always" answer)
+    pub session_allowed_tools: Vec<String>,
+    /// A new approval request arrived (App clears it after notifying)
+    pub approval_requested: bool,
+    /// Working-directory field being edited in the header
+    pub path_edit: Option<PathEdit>,
+    /// Search over saved sessions (Ctrl+S)
+    pub session_search: Option<SessionSearch>,
+    /// File panel state for /files, /tree and /content (None for remote panels)
+    pub panel_context: Option<PanelContext>,
+    /// History item to scroll to on the next draw (resumed search match)
+    pub scroll_to_item: Option<usize>,
 }
 
 /// Maximum number of history items to retain
@@ -384,6 +827,102 @@ pub fn ai_sessions_dir() -> Option<PathBuf> {
     dirs::home_dir().map(|h| h.join(".cokacdir").join("ai_sessions"))
 }
 
+/// Maximum number of results of a session search
+const MAX_SESSION_MATCHES: usize = 200;
+
+/// Characters of context kept on each side of a match in snippets
+const SNIPPET_CONTEXT_CHARS: usize = 30;
+
+/// A history item in a saved session that matches a search
+#[derive(Debug, Clone)]
+pub struct SessionMatch {
+    pub session_id: String,
+    pub current_path: String,
+    /// Last save time of the session ("%Y-%m-%d %H:%M:%S")
+    pub saved_at: String,
+    /// Index of the matching item in the saved history
+    pub item_index: usize,
+    pub item_type: HistoryType,
+    /// Matching line shortened around the match
+    pub snippet: String,
+}
+
+/// Session search in the AI screen (Ctrl+S)
+#[derive(Debug, Clone, Default)]
+pub struct SessionSearch {
+    pub query: String,
+    pub results: Vec<SessionMatch>,
+    pub selected: usize,
+}
+
+/// Case-insensitive position (in chars) of `needle` in `hay`
+fn find_ignore_case(hay: &[char], needle: &[char]) -> Option<usize> {
+    if needle.is_empty() || needle.len() > hay.len() {
+        return None;
+    }
+    (0..=hay.len() - needle.len()).find(|&i| {
+        hay[i..i + needle.len()].iter().zip(needle)
+            .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+    })
+}
+
+/// Snippet of the first line of `content` containing `query`, or None if it does not match
+fn match_snippet(content: &str, query: &[char]) -> Option<String> {
+    for line in content.lines() {
+        let chars: Vec<char> = line.trim().chars().collect();
+        if let Some(pos) = find_ignore_case(&chars, query) {
+            let start = pos.saturating_sub(SNIPPET_CONTEXT_CHARS);
+            let end = (pos + query.len() + SNIPPET_CONTEXT_CHARS).min(chars.len());
+            let mut snippet: String = chars[start..end].iter().collect();
+            if start > 0 {
+                snippet.insert(0, '…');
+            }
+            if end < chars.len() {
+                snippet.push('…');
+            }
+            return Some(snippet);
+        }
+    }
+    None
+}
+
+/// Full-text search over the session files in `dir`, newest sessions first
+pub fn search_sessions_in(dir: &Path, query: &str) -> Vec<SessionMatch> {
+    let query: Vec<char> = query.trim().chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut sessions: Vec<SessionData> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|e| e == "json").unwrap_or(false))
+        .filter_map(|p| fs::read_to_string(p).ok())
+        .filter_map(|content| serde_json::from_str::<SessionData>(&content).ok())
+        .collect();
+    sessions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let mut matches = Vec::new();
+    for session in sessions {
+        for (item_index, item) in session.history.iter().enumerate() {
+            if let Some(snippet) = match_snippet(&item.content, &query) {
+                matches.push(SessionMatch {
+                    session_id: session.session_id.clone(),
+                    current_path: session.current_path.clone(),
+                    saved_at: session.created_at.clone(),
+                    item_index,
+                    item_type: item.item_type,
+                    snippet,
+                });
+                if matches.len() >= MAX_SESSION_MATCHES {
+                    return matches;
+                }
+            }
+        }
+    }
+    matches
+}
+
 impl AIScreenState {
     /// Add item to history with size limit to prevent memory exhaustion
     /// Also normalizes consecutive empty lines in content
@@ -396,6 +935,7 @@ impl AIScreenState {
         let normalized_item = HistoryItem {
             item_type: item.item_type,
             content: normalize_empty_lines(&item.content),
+            tool_calls: item.tool_calls,
         };
         self.history.push(normalized_item);
     }
@@ -538,22 +1078,44 @@ impl AIScreenState {
             last_visible_width: 0,
             last_raw_lines: 0,
             ai_fullscreen: false,
+            started_at: None,
+            conversation_no: 0,
+            conversation_label: None,
+            has_unseen_response: false,
+            conversation_request: None,
+            layout_request: None,
+            response_actions: None,
+            response_action_request: None,
+            timeline_collapsed: false,
+            approval_mode: false,
+            approval_timeout_secs: 60,
+            approval_server: None,
+            pending_approvals: Vec::new(),
+            session_allowed_tools: Vec::new(),
+            approval_requested: false,
+            path_edit: None,
+            session_search: None,
+            scroll_to_item: None,
+            panel_context: None,
         };
 
         // Add warning message first
         state.history.push(HistoryItem {
             item_type: HistoryType::System,
             content: "[!] Warning: AI commands may execute real operations on your system. Please use with caution.".to_string(),
+            tool_calls: Vec::new(),
         });
 
         // Add restored session indicator
         state.history.push(HistoryItem {
             item_type: HistoryType::System,
             content: "Session restored from previous conversation".to_string(),
+            tool_calls: Vec::new(),
         });
 
-        // Append loaded history
+        // Append loaded history (calls saved mid-request never got a result)
         state.history.extend(session_data.history);
+        state.cancel_running_tool_calls();
 
         Some(state)
     }
@@ -581,23 +1143,45 @@ impl AIScreenState {
             last_visible_width: 0,
             last_raw_lines: 0,
             ai_fullscreen: false,
+            started_at: None,
+            conversation_no: 0,
+            conversation_label: None,
+            has_unseen_response: false,
+            conversation_request: None,
+            layout_request: None,
+            response_actions: None,
+            response_action_request: None,
+            timeline_collapsed: false,
+            approval_mode: false,
+            approval_timeout_secs: 60,
+            approval_server: None,
+            pending_approvals: Vec::new(),
+            session_allowed_tools: Vec::new(),
+            approval_requested: false,
+            path_edit: None,
+            session_search: None,
+            scroll_to_item: None,
+            panel_context: None,
         };
 
         // Add warning message as first line
         state.history.push(HistoryItem {
             item_type: HistoryType::System,
             content: "[!] Warning: AI commands may execute real operations on your system. Please use with caution.".to_string(),
+            tool_calls: Vec::new(),
         });
 
         if !claude::is_ai_supported() {
             state.history.push(HistoryItem {
                 item_type: HistoryType::Error,
                 content: "AI features are only available on Linux and macOS.".to_string(),
+                tool_calls: Vec::new(),
             });
         } else if !claude_available {
             state.history.push(HistoryItem {
                 item_type: HistoryType::Error,
                 content: "Claude CLI not found. Run 'which claude' to verify installation.".to_string(),
+                tool_calls: Vec::new(),
             });
         }
 
@@ -813,51 +1397,91 @@ impl AIScreenState {
         self.cursor_col = new_col;
     }
 
+    /// Text of the latest response (assistant items after the last user message)
+    fn latest_response_text(&self) -> String {
+        let start = self.history.iter().rposition(|h| h.item_type == HistoryType::User).map_or(0, |i| i + 1);
+        self.history[start..].iter()
+            .filter(|h| h.item_type == HistoryType::Assistant)
+            .map(|h| h.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Open the action list for the latest response
+    fn open_response_actions(&mut self) {
+        let items = build_response_actions(&self.latest_response_text(), Path::new(&self.current_path));
+        if items.is_empty() {
+            self.add_to_history(HistoryItem {
+                item_type: HistoryType::System,
+                content: "No code blocks or paths found in the last response".to_string(),
+                tool_calls: Vec::new(),
+            });
+            self.scroll_offset = usize::MAX;
+            self.auto_scroll = true;
+            return;
+        }
+        self.response_actions = Some(ResponseActionMenu { items, selected: 0 });
+    }
+
     fn clear_history(&mut self) {
-        debug_log("Handling clear history");
+        log_debug!("ai_screen", "Handling clear history");
         self.history.clear();
         self.session_id = None;
         self.scroll_offset = 0;
     }
 
     pub fn submit(&mut self) {
-        debug_log("=== submit() called ===");
+        log_debug!("ai_screen", "=== submit() called ===");
         let input_text = self.get_input_text();
         if input_text.trim().is_empty() || self.is_processing {
-            debug_log(&format!("submit() early return: empty={}, processing={}", input_text.trim().is_empty(), self.is_processing));
+            log_debug!("ai_screen", "submit() early return: empty={}, processing={}", input_text.trim().is_empty(), self.is_processing);
             return;
         }
 
         let user_input = input_text.trim().to_string();
-        debug_log(&format!("User input: {}", user_input));
+        log_debug!("ai_screen", "User input: {}", user_input);
+
+        // /files, /tree, /content: 전송 전에 패널 정보로 치환 (오류 시 입력 유지)
+        let request_text = match expand_context_commands(&user_input, self.panel_context.as_ref()) {
+            Ok(text) => text,
+            Err(e) => {
+                self.add_to_history(HistoryItem {
+                    item_type: HistoryType::Error,
+                    content: e,
+                    tool_calls: Vec::new(),
+                });
+                return;
+            }
+        };
         self.set_input_text("");
 
         // Check claude availability before actual API call
         if !self.claude_available {
-            debug_log("submit: Claude not available, returning early");
+            log_debug!("ai_screen", "submit: Claude not available, returning early");
             return;
         }
 
-        debug_log(&format!("submit: START - input_len={}, current_path={}", user_input.len(), self.current_path));
+        log_debug!("ai_screen", "submit: START - input_len={}, current_path={}", user_input.len(), self.current_path);
         let input_preview: String = user_input.chars().take(100).collect();
-        debug_log(&format!("submit: user_input preview: {:?}", input_preview));
+        log_debug!("ai_screen", "submit: user_input preview: {:?}", input_preview);
 
         // Add user message immediately
-        debug_log("submit: Adding user message to history");
+        log_debug!("ai_screen", "submit: Adding user message to history");
         self.add_to_history(HistoryItem {
             item_type: HistoryType::User,
             content: user_input.clone(),
+            tool_calls: Vec::new(),
         });
-        debug_log(&format!("submit: History length after add: {}", self.history.len()));
+        log_debug!("ai_screen", "submit: History length after add: {}", self.history.len());
 
         // Set processing state
         self.is_processing = true;
         self.streaming_buffer.clear();
-        debug_log("submit: Set is_processing=true, cleared streaming_buffer");
+        log_debug!("ai_screen", "submit: Set is_processing=true, cleared streaming_buffer");
 
         // Sanitize user input to prevent prompt injection
-        let sanitized_input = sanitize_user_input(&user_input);
-        debug_log(&format!("submit: Sanitized input len={}", sanitized_input.len()));
+        let sanitized_input = sanitize_user_input(&request_text);
+        log_debug!("ai_screen", "submit: Sanitized input len={}", sanitized_input.len());
 
         // Prepare context for async execution with clear boundaries
         let context_prompt = format!(
@@ -874,22 +1498,45 @@ If the user asks to perform file operations, provide clear instructions.
 Keep responses concise and terminal-friendly.",
             self.current_path, sanitized_input
         );
-        debug_log(&format!("submit: Context prompt prepared, total len={}", context_prompt.len()));
+        log_debug!("ai_screen", "submit: Context prompt prepared, total len={}", context_prompt.len());
 
         let session_id = self.session_id.clone();
         let current_path = self.current_path.clone();
-        debug_log(&format!("submit: session_id={:?}", session_id));
+        log_debug!("ai_screen", "submit: session_id={:?}", session_id);
+
+        // Approval mode: gated tools go through a PreToolUse hook that asks us first
+        let hook_settings = if self.approval_mode {
+            match ApprovalServer::start(std::time::Duration::from_secs(self.approval_timeout_secs)) {
+                Ok(server) => {
+                    let settings = server.hook_settings();
+                    self.approval_server = Some(server);
+                    Some(settings)
+                }
+                Err(e) => {
+                    self.add_to_history(HistoryItem {
+                        item_type: HistoryType::Error,
+                        content: format!("Approval mode could not start ({}); request not sent.", e),
+                        tool_calls: Vec::new(),
+                    });
+                    self.is_processing = false;
+                    return;
+                }
+            }
+        } else {
+            None
+        };
 
         // Create channel for streaming response
         let (tx, rx) = mpsc::channel();
         self.response_receiver = Some(rx);
-        debug_log("submit: Channel created, receiver stored");
+        self.started_at = Some(Instant::now());
+        log_debug!("ai_screen", "submit: Channel created, receiver stored");
 
         // Spawn thread to execute Claude command with streaming
-        debug_log("submit: Spawning worker thread...");
+        log_debug!("ai_screen", "submit: Spawning worker thread...");
         thread::spawn(move || {
-            debug_log("submit:thread: === WORKER THREAD STARTED ===");
-            debug_log(&format!("submit:thread: Calling execute_command_streaming with path={}", current_path));
+            log_debug!("ai_screen", "submit:thread: === WORKER THREAD STARTED ===");
+            log_debug!("ai_screen", "submit:thread: Calling execute_command_streaming with path={}", current_path);
             let start_time = std::time::Instant::now();
 
             let result = claude::execute_command_streaming(
@@ -902,21 +1549,22 @@ Keep responses concise and terminal-friendly.",
                 None,
                 None,
                 false,
+                hook_settings.as_deref(),
             );
 
             let elapsed = start_time.elapsed();
-            debug_log(&format!("submit:thread: execute_command_streaming returned after {:?}", elapsed));
+            log_debug!("ai_screen", "submit:thread: execute_command_streaming returned after {:?}", elapsed);
 
             if let Err(e) = result {
-                debug_log(&format!("submit:thread: ERROR from execute_command_streaming: {}", e));
+                log_error!("ai_screen", "submit:thread: execute_command_streaming failed: {}", e);
                 let send_result = tx.send(StreamMessage::Error { message: e, stdout: String::new(), stderr: String::new(), exit_code: None });
-                debug_log(&format!("submit:thread: Error message send result: {:?}", send_result.is_ok()));
+                log_debug!("ai_screen", "submit:thread: Error message send result: {:?}", send_result.is_ok());
             } else {
-                debug_log("submit:thread: execute_command_streaming completed successfully");
+                log_debug!("ai_screen", "submit:thread: execute_command_streaming completed successfully");
             }
-            debug_log("submit:thread: === WORKER THREAD ENDING ===");
+            log_debug!("ai_screen", "submit:thread: === WORKER THREAD ENDING ===");
         });
-        debug_log("submit: Worker thread spawned, submit() returning to caller");
+        log_debug!("ai_screen", "submit: Worker thread spawned, submit() returning to caller");
     }
 
     /// Poll for streaming response from Claude
@@ -964,25 +1612,11 @@ Keep responses concise and terminal-friendly.",
                 StreamMessage::ToolUse { name, input } => {
                     // Format tool use with simplified output (no raw JSON dump)
                     let formatted_content = format_tool_use(&name, &input);
-                    self.add_to_history(HistoryItem {
-                        item_type: HistoryType::ToolUse,
-                        content: format!("{}\n{}", name, formatted_content),
-                    });
+                    self.start_tool_call(name, &formatted_content);
                     has_new_content = true;
                 }
                 StreamMessage::ToolResult { content, is_error } => {
-                    // Add tool result - limit content length for display
-                    let display_content = if content.chars().count() > 500 {
-                        let truncated: String = content.chars().take(500).collect();
-                        let remaining = content.chars().count() - 500;
-                        format!("{}...\n[{} more chars]", truncated, remaining)
-                    } else {
-                        content
-                    };
-                    self.add_to_history(HistoryItem {
-                        item_type: if is_error { HistoryType::Error } else { HistoryType::ToolResult },
-                        content: display_content,
-                    });
+                    self.finish_tool_call(&content, is_error);
                     has_new_content = true;
                 }
                 StreamMessage::TaskNotification { task_id, status, summary } => {
@@ -991,6 +1625,7 @@ Keep responses concise and terminal-friendly.",
                     self.add_to_history(HistoryItem {
                         item_type: HistoryType::System,
                         content: notification,
+                        tool_calls: Vec::new(),
                     });
                     has_new_content = true;
                 }
@@ -1008,6 +1643,7 @@ Keep responses concise and terminal-friendly.",
                     self.add_to_history(HistoryItem {
                         item_type: HistoryType::Error,
                         content: message,
+                        tool_calls: Vec::new(),
                     });
                     processing_done = true;
                     has_new_content = true;
@@ -1029,16 +1665,23 @@ Keep responses concise and terminal-friendly.",
                 self.add_to_history(HistoryItem {
                     item_type: HistoryType::Error,
                     content: "Request was cancelled or failed.".to_string(),
+                    tool_calls: Vec::new(),
                 });
             }
             processing_done = true;
             has_new_content = true;
         }
 
+        if self.poll_approvals() {
+            has_new_content = true;
+        }
+
         // Clean up if processing is done
         if processing_done {
             self.is_processing = false;
             self.response_receiver = None;
+            self.cancel_running_tool_calls();
+            self.stop_approvals();
         }
 
         has_new_content
@@ -1061,6 +1704,7 @@ Keep responses concise and terminal-friendly.",
         self.history.push(HistoryItem {
             item_type: HistoryType::Assistant,
             content: normalized,
+            tool_calls: Vec::new(),
         });
 
         // Enforce history limit
@@ -1089,6 +1733,7 @@ Keep responses concise and terminal-friendly.",
                 self.add_to_history(HistoryItem {
                     item_type: HistoryType::Assistant,
                     content: normalized,
+                    tool_calls: Vec::new(),
                 });
             }
         }
@@ -1099,14 +1744,293 @@ Keep responses concise and terminal-friendly.",
         }
     }
 
+    /// Add a running tool call to the current timeline (new timeline after other output)
+    fn start_tool_call(&mut self, name: String, formatted_input: &str) {
+        let call = ToolCall {
+            name,
+            summary: formatted_input.lines().next().unwrap_or("").trim().to_string(),
+            status: ToolStatus::Running,
+            duration_ms: None,
+            result: String::new(),
+            started: Some(Instant::now()),
+        };
+        if let Some(last) = self.history.last_mut() {
+            if last.item_type == HistoryType::ToolTimeline {
+                last.tool_calls.push(call);
+                last.content = tool_timeline_text(&last.tool_calls);
+                return;
+            }
+        }
+        let calls = vec![call];
+        self.add_to_history(HistoryItem {
+            item_type: HistoryType::ToolTimeline,
+            content: tool_timeline_text(&calls),
+            tool_calls: calls,
+        });
+    }
+
+    /// Record a result for the oldest running call of the latest timeline
+    fn finish_tool_call(&mut self, result: &str, is_error: bool) {
+        let timeline = self.history.iter_mut().rev()
+            .find(|h| h.item_type == HistoryType::ToolTimeline);
+        let Some(item) = timeline else { return };
+        let Some(call) = item.tool_calls.iter_mut().find(|c| c.status == ToolStatus::Running) else { return };
+        call.status = if is_error { ToolStatus::Failed } else { ToolStatus::Ok };
+        call.duration_ms = call.started.take().map(|t| t.elapsed().as_millis() as u64);
+        let first_line = result.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("");
+        call.result = if first_line.chars().count() > TOOL_RESULT_PREVIEW_CHARS {
+            let truncated: String = first_line.chars().take(TOOL_RESULT_PREVIEW_CHARS).collect();
+            format!("{}...", truncated)
+        } else {
+            first_line.to_string()
+        };
+        item.content = tool_timeline_text(&item.tool_calls);
+    }
+
+    /// Mark calls still waiting for a result as cancelled
+    fn cancel_running_tool_calls(&mut self) {
+        for item in self.history.iter_mut().filter(|h| h.item_type == HistoryType::ToolTimeline) {
+            let mut changed = false;
+            for call in item.tool_calls.iter_mut().filter(|c| c.status == ToolStatus::Running) {
+                call.status = ToolStatus::Cancelled;
+                call.duration_ms = call.started.take().map(|t| t.elapsed().as_millis() as u64);
+                changed = true;
+            }
+            if changed {
+                item.content = tool_timeline_text(&item.tool_calls);
+            }
+        }
+    }
+
+    /// Queue new approval requests (auto-allowing session-wide tools) and deny expired ones.
+    /// Returns true when history changed.
+    fn poll_approvals(&mut self) -> bool {
+        let mut changed = false;
+        if let Some(ref server) = self.approval_server {
+            while let Some(request) = server.try_recv() {
+                if self.session_allowed_tools.contains(&request.tool) {
+                    request.respond(true);
+                } else {
+                    self.pending_approvals.push(request);
+                    self.approval_requested = true;
+                }
+            }
+        }
+        let now = Instant::now();
+        let (expired, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_approvals)
+            .into_iter()
+            .partition(|r| r.deadline <= now);
+        self.pending_approvals = pending;
+        for request in expired {
+            self.add_to_history(HistoryItem {
+                item_type: HistoryType::System,
+                content: format!("Denied {} (no answer within {}s)", request.tool, self.approval_timeout_secs),
+                tool_calls: Vec::new(),
+            });
+            request.respond(false);
+            changed = true;
+        }
+        changed
+    }
+
+    /// Deny anything still waiting and close the approval socket
+    fn stop_approvals(&mut self) {
+        for request in self.pending_approvals.drain(..) {
+            request.respond(false);
+        }
+        self.approval_server = None;
+    }
+
+    /// Answer the first pending approval request; `always` allows the tool for the rest of the conversation
+    fn answer_approval(&mut self, allow: bool, always: bool) {
+        if self.pending_approvals.is_empty() {
+            return;
+        }
+        let request = self.pending_approvals.remove(0);
+        if allow && always && !self.session_allowed_tools.contains(&request.tool) {
+            self.add_to_history(HistoryItem {
+                item_type: HistoryType::System,
+                content: format!("{} allowed for this conversation", request.tool),
+                tool_calls: Vec::new(),
+            });
+            self.session_allowed_tools.push(request.tool.clone());
+            // Same tool queued behind this one needs no more questions
+            let tool = request.tool.clone();
+            let (same, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_approvals)
+                .into_iter()
+                .partition(|r| r.tool == tool);
+            self.pending_approvals = rest;
+            for r in same {
+                r.respond(true);
+            }
+        }
+        request.respond(allow);
+    }
+
+    /// Start editing the working directory in the header
+    fn open_path_edit(&mut self) {
+        let mut input = self.current_path.clone();
+        if !input.ends_with('/') {
+            input.push('/');
+        }
+        let mut edit = PathEdit::default();
+        edit.set_input(input);
+        self.path_edit = Some(edit);
+    }
+
+    /// Tab in the path field: complete a unique directory or the common prefix,
+    /// then cycle through the candidates on repeated Tab
+    fn complete_path_edit(&mut self) {
+        let base = self.current_path.clone();
+        let Some(edit) = self.path_edit.as_mut() else { return };
+        if let Some(i) = edit.match_index {
+            let next = (i + 1) % edit.matches.len();
+            edit.match_index = Some(next);
+            let input = edit.matches[next].clone();
+            edit.set_input(input);
+            return;
+        }
+
+        let mut resolved = resolve_dir_input(&edit.input, &base).display().to_string();
+        if edit.input.ends_with('/') && !resolved.ends_with('/') {
+            resolved.push('/');
+        }
+        let (dir, prefix) = super::dialogs::parse_path_for_completion(&resolved);
+        let candidates: Vec<String> = super::dialogs::get_path_suggestions(&dir, &prefix)
+            .into_iter()
+            .filter(|s| s.ends_with('/'))
+            .collect();
+        let full = |name: &str| format!("{}/", dir.join(name.trim_end_matches('/')).display());
+        match candidates.len() {
+            0 => edit.error = Some("no matching directory".to_string()),
+            1 => edit.set_input(full(&candidates[0])),
+            _ => {
+                let common = super::dialogs::find_common_prefix(&candidates);
+                if common.chars().count() > prefix.chars().count() {
+                    edit.set_input(dir.join(&common).display().to_string());
+                } else {
+                    edit.matches = candidates.iter().map(|c| full(c)).collect();
+                    edit.match_index = Some(0);
+                    let input = edit.matches[0].clone();
+                    edit.set_input(input);
+                }
+            }
+        }
+    }
+
+    /// Enter in the path field: switch the working directory.
+    /// Claude sessions belong to a directory, so the next request starts a new session.
+    fn apply_path_edit(&mut self) {
+        let Some(edit) = self.path_edit.as_mut() else { return };
+        let path = resolve_dir_input(&edit.input, &self.current_path);
+        if !path.is_dir() {
+            edit.error = Some("not a directory".to_string());
+            return;
+        }
+        self.path_edit = None;
+        let path = path.canonicalize().unwrap_or(path).display().to_string();
+        if path == self.current_path {
+            return;
+        }
+        self.save_session_to_file();
+        self.current_path = path;
+        self.session_id = None;
+        self.add_to_history(HistoryItem {
+            item_type: HistoryType::System,
+            content: format!("Working directory: {} (next message starts a new session)", self.current_path),
+            tool_calls: Vec::new(),
+        });
+        self.scroll_offset = usize::MAX;
+        self.auto_scroll = true;
+    }
+
+    /// Re-run the session search for the current query
+    fn update_session_search(&mut self) {
+        let Some(search) = self.session_search.as_mut() else { return };
+        search.results = match ai_sessions_dir() {
+            Some(dir) if search.query.trim().chars().count() >= 2 => search_sessions_in(&dir, &search.query),
+            _ => Vec::new(),
+        };
+        search.selected = 0;
+    }
+
+    /// Resume the session of the selected search result, scrolled to the matching turn.
+    /// The session's own directory becomes the working directory (Claude sessions are per directory).
+    fn resume_selected_match(&mut self) {
+        let Some(search) = self.session_search.take() else { return };
+        let Some(found) = search.results.into_iter().nth(search.selected) else { return };
+        if self.is_processing {
+            self.add_to_history(HistoryItem {
+                item_type: HistoryType::System,
+                content: "Cannot switch sessions while a request is running".to_string(),
+                tool_calls: Vec::new(),
+            });
+            return;
+        }
+        let Some(data) = ai_sessions_dir()
+            .and_then(|dir| fs::read_to_string(dir.join(format!("{}.json", found.session_id))).ok())
+            .and_then(|content| serde_json::from_str::<SessionData>(&content).ok())
+        else {
+            return;
+        };
+        if !Self::is_valid_session_id(&data.session_id) {
+            return;
+        }
+
+        self.save_session_to_file();
+        self.history.clear();
+        self.history.push(HistoryItem {
+            item_type: HistoryType::System,
+            content: "[!] Warning: AI commands may execute real operations on your system. Please use with caution.".to_string(),
+            tool_calls: Vec::new(),
+        });
+        let header_len = self.history.len();
+        self.history.extend(data.history);
+        self.cancel_running_tool_calls();
+        if Path::new(&data.current_path).is_dir() {
+            self.current_path = data.current_path;
+            self.session_id = Some(data.session_id);
+        } else {
+            // 세션 디렉토리가 사라지면 이어갈 수 없음: 기록만 보여주고 새 세션으로 시작
+            self.session_id = None;
+            self.add_to_history(HistoryItem {
+                item_type: HistoryType::System,
+                content: format!("{} no longer exists; the next message starts a new session", data.current_path),
+                tool_calls: Vec::new(),
+            });
+        }
+        self.scroll_to_item = Some(header_len + found.item_index);
+        self.auto_scroll = false;
+    }
+
+    /// Toggle approval mode for this conversation (applies from the next request)
+    fn toggle_approval_mode(&mut self) {
+        self.approval_mode = !self.approval_mode;
+        let content = if self.approval_mode {
+            format!("Approval mode on: {} calls need confirmation (from the next request)", GATED_TOOLS.join("/"))
+        } else {
+            "Approval mode off (from the next request)".to_string()
+        };
+        self.add_to_history(HistoryItem {
+            item_type: HistoryType::System,
+            content,
+            tool_calls: Vec::new(),
+        });
+        self.scroll_offset = usize::MAX;
+        self.auto_scroll = true;
+    }
+
     /// Cancel the current processing request
     pub fn cancel_processing(&mut self) {
         if self.is_processing {
             self.is_processing = false;
             self.response_receiver = None;
+            self.cancel_running_tool_calls();
+            self.stop_approvals();
             self.add_to_history(HistoryItem {
                 item_type: HistoryType::System,
                 content: "Cancelled.".to_string(),
+                tool_calls: Vec::new(),
             });
         }
     }
@@ -1154,23 +2078,235 @@ pub fn draw_with_focus(frame: &mut Frame, state: &mut AIScreenState, area: Rect,
     // +1 for bottom border, max 10 lines
     let input_height = (total_display_lines as u16 + 1).min(10);
 
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Min(5),    // History area (no bottom border)
-            Constraint::Length(1), // Separator line (├───┤)
-            Constraint::Length(input_height), // Input area (dynamic height)
-        ])
-        .split(area);
+    // Approval prompt (takes precedence), session search or response action list: header + up to 8 rows
+    let menu_height = if !state.pending_approvals.is_empty() {
+        4
+    } else if let Some(ref search) = state.session_search {
+        (search.results.len() as u16).clamp(1, 8) + 1
+    } else {
+        state.response_actions.as_ref().map_or(0, |m| (m.items.len() as u16).min(8) + 1)
+    };
+
+    if menu_height == 0 {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(5),    // History area (no bottom border)
+                Constraint::Length(1), // Separator line (├───┤)
+                Constraint::Length(input_height), // Input area (dynamic height)
+            ])
+            .split(area);
+
+        // History area (with path and session in title)
+        draw_history(frame, state, chunks[0], theme, focused);
+
+        // Draw separator line between history and input (├───┤)
+        draw_separator(frame, chunks[1], theme, focused);
+
+        // Input area
+        draw_input(frame, state, chunks[2], theme, focused);
+    } else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(5),
+                Constraint::Length(1),
+                Constraint::Length(menu_height), // Action list under the response
+                Constraint::Length(1),
+                Constraint::Length(input_height),
+            ])
+            .split(area);
+
+        draw_history(frame, state, chunks[0], theme, focused);
+        draw_separator(frame, chunks[1], theme, focused);
+        if !state.pending_approvals.is_empty() {
+            draw_approval_prompt(frame, state, chunks[2], theme, focused);
+        } else if state.session_search.is_some() {
+            draw_session_search(frame, state, chunks[2], theme, focused);
+        } else {
+            draw_response_actions(frame, state, chunks[2], theme, focused);
+        }
+        draw_separator(frame, chunks[3], theme, focused);
+        draw_input(frame, state, chunks[4], theme, focused);
+    }
+}
+
+fn draw_approval_prompt(frame: &mut Frame, state: &AIScreenState, area: Rect, theme: &Theme, focused: bool) {
+    let Some(request) = state.pending_approvals.first() else { return };
+    let colors = &theme.ai_screen;
+    let border_color = if focused { colors.history_border } else { theme.panel.border };
+    let block = Block::default()
+        .borders(Borders::LEFT | Borders::RIGHT)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut header = vec![
+        Span::styled(" Allow ", Style::default().fg(colors.error_text).add_modifier(Modifier::BOLD)),
+        Span::styled(request.tool.clone(), Style::default().fg(colors.tool_use_name).add_modifier(Modifier::BOLD)),
+        Span::styled(format!("? (denied in {}s)", request.remaining_secs()), Style::default().fg(colors.footer_text)),
+    ];
+    if state.pending_approvals.len() > 1 {
+        header.push(Span::styled(format!(" +{} waiting", state.pending_approvals.len() - 1), Style::default().fg(colors.footer_text)));
+    }
+    let detail = format_tool_use(&request.tool, &request.input);
+    let mut lines = vec![Line::from(header)];
+    for line in detail.lines().take(2) {
+        lines.push(Line::from(Span::styled(format!("   {}", line), Style::default().fg(colors.tool_use_input))));
+    }
+    while lines.len() < 3 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(vec![
+        Span::styled(" y", Style::default().fg(colors.footer_key)),
+        Span::styled(" allow  ", Style::default().fg(colors.footer_text)),
+        Span::styled("n", Style::default().fg(colors.footer_key)),
+        Span::styled(" deny  ", Style::default().fg(colors.footer_text)),
+        Span::styled("a", Style::default().fg(colors.footer_key)),
+        Span::styled(format!(" allow {} for this conversation", request.tool), Style::default().fg(colors.footer_text)),
+    ]));
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_session_search(frame: &mut Frame, state: &AIScreenState, area: Rect, theme: &Theme, focused: bool) {
+    let Some(ref search) = state.session_search else { return };
+    let colors = &theme.ai_screen;
+    let border_color = if focused { colors.history_border } else { theme.panel.border };
+    let block = Block::default()
+        .borders(Borders::LEFT | Borders::RIGHT)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let count = if search.query.trim().chars().count() < 2 {
+        "  type to search saved sessions".to_string()
+    } else if search.results.len() >= MAX_SESSION_MATCHES {
+        format!("  {}+ matches  Enter: resume", MAX_SESSION_MATCHES)
+    } else {
+        format!("  {} matches  Enter: resume", search.results.len())
+    };
+    let mut lines = vec![Line::from(vec![
+        Span::styled(" Search: ", Style::default().fg(colors.tool_use_name).add_modifier(Modifier::BOLD)),
+        Span::styled(search.query.clone(), Style::default().fg(colors.input_text)),
+        Span::styled(" ", Style::default().fg(colors.input_cursor_fg).bg(colors.input_cursor_bg)),
+        Span::styled(count, Style::default().fg(colors.footer_text)),
+    ])];
+
+    let visible = inner.height.saturating_sub(1) as usize;
+    let start = if visible > 0 && search.selected >= visible { search.selected + 1 - visible } else { 0 };
+    for (i, m) in search.results.iter().enumerate().skip(start).take(visible) {
+        let (icon, icon_color) = match m.item_type {
+            HistoryType::User => ("> ", colors.user_prefix),
+            HistoryType::Assistant => ("< ", colors.assistant_prefix),
+            HistoryType::Error => ("! ", colors.error_prefix),
+            _ => ("* ", colors.system_prefix),
+        };
+        let dir = Path::new(&m.current_path).file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| m.current_path.clone());
+        let date: String = m.saved_at.chars().take(16).collect();
+        let row_style = if i == search.selected {
+            Style::default().fg(colors.input_cursor_fg).bg(colors.input_cursor_bg)
+        } else {
+            Style::default().fg(colors.message_text)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} [{}] ", date, dir), row_style.fg(colors.footer_text)),
+            Span::styled(icon, if i == search.selected { row_style } else { Style::default().fg(icon_color) }),
+            Span::styled(m.snippet.clone(), row_style),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_response_actions(frame: &mut Frame, state: &AIScreenState, area: Rect, theme: &Theme, focused: bool) {
+    let Some(ref menu) = state.response_actions else { return };
+    let border_color = if focused { theme.ai_screen.history_border } else { theme.panel.border };
+    let block = Block::default()
+        .borders(Borders::LEFT | Borders::RIGHT)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(theme.ai_screen.bg));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
 
-    // History area (with path and session in title)
-    draw_history(frame, state, chunks[0], theme, focused);
+    let mut lines = vec![Line::from(vec![
+        Span::styled(" Actions ", Style::default().fg(theme.ai_screen.tool_use_name).add_modifier(Modifier::BOLD)),
+        Span::styled("↑↓", Style::default().fg(theme.ai_screen.footer_key)),
+        Span::styled(" select  ", Style::default().fg(theme.ai_screen.footer_text)),
+        Span::styled("Enter", Style::default().fg(theme.ai_screen.footer_key)),
+        Span::styled(" run  ", Style::default().fg(theme.ai_screen.footer_text)),
+        Span::styled("Esc", Style::default().fg(theme.ai_screen.footer_key)),
+        Span::styled(" close", Style::default().fg(theme.ai_screen.footer_text)),
+    ])];
+
+    // 선택 항목이 보이도록 스크롤
+    let visible = inner.height.saturating_sub(1) as usize;
+    let start = if visible > 0 && menu.selected >= visible { menu.selected + 1 - visible } else { 0 };
+    for (i, (label, _)) in menu.items.iter().enumerate().skip(start).take(visible) {
+        let style = if i == menu.selected {
+            Style::default().fg(theme.ai_screen.input_cursor_fg).bg(theme.ai_screen.input_cursor_bg)
+        } else {
+            Style::default().fg(theme.ai_screen.message_text)
+        };
+        lines.push(Line::from(Span::styled(format!("  {} ", label), style)));
+    }
 
-    // Draw separator line between history and input (├───┤)
-    draw_separator(frame, chunks[1], theme, focused);
+    frame.render_widget(Paragraph::new(lines), inner);
+}
 
-    // Input area
-    draw_input(frame, state, chunks[2], theme, focused);
+/// Tool timeline: header with counts/duration, then one row per call unless collapsed
+fn push_tool_timeline_lines(lines: &mut Vec<Line>, calls: &[ToolCall], collapsed: bool, theme: &Theme) {
+    let colors = &theme.ai_screen;
+    let mut header = vec![
+        Span::styled(if collapsed { "▸ " } else { "▾ " }, Style::default().fg(colors.tool_use_prefix)),
+        Span::styled("Tools", Style::default().fg(colors.tool_use_name).add_modifier(Modifier::BOLD)),
+        Span::styled(format!(" {}", tool_timeline_summary(calls)), Style::default().fg(colors.tool_use_input)),
+    ];
+    if collapsed {
+        let mut names: Vec<&str> = Vec::new();
+        for call in calls {
+            if !names.contains(&call.name.as_str()) {
+                names.push(&call.name);
+            }
+        }
+        header.push(Span::styled(format!(" ({})", names.join(", ")), Style::default().fg(colors.tool_result_text)));
+        lines.push(Line::from(header));
+        return;
+    }
+    lines.push(Line::from(header));
+
+    let spinner_frames = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    let frame_idx = (std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() / 100) as usize % spinner_frames.len();
+    for call in calls {
+        let (icon, icon_color) = match call.status {
+            ToolStatus::Running => (spinner_frames[frame_idx].to_string(), colors.processing_spinner),
+            ToolStatus::Ok => (call.status_icon().to_string(), colors.tool_result_prefix),
+            ToolStatus::Failed => (call.status_icon().to_string(), colors.error_prefix),
+            ToolStatus::Cancelled => (call.status_icon().to_string(), colors.system_prefix),
+        };
+        let mut row = vec![
+            Span::styled(format!("  {} ", icon), Style::default().fg(icon_color).add_modifier(Modifier::BOLD)),
+            Span::styled(call.name.clone(), Style::default().fg(colors.tool_use_name).add_modifier(Modifier::BOLD)),
+            Span::styled(format!(" {}", call.summary), Style::default().fg(colors.tool_use_input)),
+        ];
+        if let Some(ms) = call.duration_ms {
+            row.push(Span::styled(format!(" ({})", format_tool_duration(ms)), Style::default().fg(colors.tool_result_text)));
+        }
+        lines.push(Line::from(row));
+        if !call.result.is_empty() {
+            let result_color = if call.status == ToolStatus::Failed { colors.error_text } else { colors.tool_result_text };
+            lines.push(Line::from(vec![
+                Span::styled("    -> ", Style::default().fg(colors.tool_result_prefix)),
+                Span::styled(call.result.clone(), Style::default().fg(result_color)),
+            ]));
+        }
+    }
 }
 
 fn draw_history(frame: &mut Frame, state: &mut AIScreenState, area: Rect, theme: &Theme, focused: bool) {
@@ -1182,7 +2318,13 @@ fn draw_history(frame: &mut Frame, state: &mut AIScreenState, area: Rect, theme:
         "New Session".to_string()
     };
 
-    let title = format!(" {} | {} ", state.current_path, session_info);
+    let mut title = format!(" {} | {} ", state.current_path, session_info);
+    if state.approval_mode {
+        title.push_str("| Approval ");
+    }
+    if let Some(ref label) = state.conversation_label {
+        title.push_str(&format!("| {} ", label));
+    }
 
     // 포커스 여부에 따라 테두리 색상 결정
     let border_color = if focused { theme.ai_screen.history_border } else { theme.panel.border };
@@ -1190,14 +2332,41 @@ fn draw_history(frame: &mut Frame, state: &mut AIScreenState, area: Rect, theme:
     // 타이틀 색상도 테두리와 동일하게
     let title_color = if focused { theme.ai_screen.history_title } else { theme.panel.border };
 
+    // 작업 디렉토리 편집 중이면 타이틀 자리에 입력 필드 표시
+    let title_line = match state.path_edit {
+        Some(ref edit) => {
+            let cursor_style = Style::default()
+                .fg(theme.ai_screen.input_cursor_fg)
+                .bg(theme.ai_screen.input_cursor_bg);
+            let before: String = edit.input.chars().take(edit.cursor).collect();
+            let at: String = edit.input.chars().nth(edit.cursor).map_or(" ".to_string(), |c| c.to_string());
+            let after: String = edit.input.chars().skip(edit.cursor + 1).collect();
+            let mut spans = vec![
+                Span::styled(" Dir: ", Style::default().fg(title_color).add_modifier(Modifier::BOLD)),
+                Span::styled(before, Style::default().fg(theme.ai_screen.input_text)),
+                Span::styled(at, cursor_style),
+                Span::styled(after, Style::default().fg(theme.ai_screen.input_text)),
+            ];
+            if let Some(ref error) = edit.error {
+                spans.push(Span::styled(format!(" ({}) ", error), Style::default().fg(theme.ai_screen.error_text)));
+            } else if let Some(i) = edit.match_index {
+                spans.push(Span::styled(format!(" (Tab {}/{}) ", i + 1, edit.matches.len()), Style::default().fg(theme.ai_screen.footer_text)));
+            } else {
+                spans.push(Span::styled(" ", Style::default()));
+            }
+            Line::from(spans)
+        }
+        None => Line::from(Span::styled(
+            title,
+            Style::default().fg(title_color).add_modifier(Modifier::BOLD),
+        )),
+    };
+
     let block = Block::default()
         .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
         .border_style(Style::default().fg(border_color))
         .style(Style::default().bg(theme.ai_screen.bg))
-        .title(Span::styled(
-            title,
-            Style::default().fg(title_color).add_modifier(Modifier::BOLD),
-        ));
+        .title(title_line);
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -1217,8 +2386,12 @@ fn draw_history(frame: &mut Frame, state: &mut AIScreenState, area: Rect, theme:
 
     // Build all lines (without manual wrapping - let Paragraph handle it)
     let mut lines: Vec<Line> = Vec::new();
+    // 검색 결과로 이어간 경우 해당 항목으로 스크롤
+    let scroll_target = state.scroll_to_item.take();
+    let mut item_starts: Vec<usize> = Vec::with_capacity(state.history.len());
 
     for item in &state.history {
+        item_starts.push(lines.len());
         match item.item_type {
             HistoryType::ToolUse => {
                 // Tool use: ":: ToolName" followed by simplified parameters
@@ -1241,6 +2414,9 @@ fn draw_history(frame: &mut Frame, state: &mut AIScreenState, area: Rect, theme:
                     }
                 }
             }
+            HistoryType::ToolTimeline => {
+                push_tool_timeline_lines(&mut lines, &item.tool_calls, state.timeline_collapsed, theme);
+            }
             HistoryType::ToolResult => {
                 // Tool result: "->" followed by result
                 let content_lines: Vec<&str> = item.content.lines().collect();
@@ -1293,8 +2469,11 @@ fn draw_history(frame: &mut Frame, state: &mut AIScreenState, area: Rect, theme:
 
     // Remove consecutive empty lines (keep at most one)
     let mut filtered_lines: Vec<Line> = Vec::with_capacity(lines.len());
+    // 원래 줄 번호 -> 정리 후 줄 번호 (스크롤 대상 계산용)
+    let mut index_map: Vec<usize> = Vec::with_capacity(lines.len());
     let mut prev_was_empty = false;
     for line in lines {
+        index_map.push(filtered_lines.len());
         if is_line_empty(&line) {
             if !prev_was_empty {
                 filtered_lines.push(line);
@@ -1317,6 +2496,15 @@ fn draw_history(frame: &mut Frame, state: &mut AIScreenState, area: Rect, theme:
         }
     }).collect();
 
+    if let Some(start) = scroll_target
+        .and_then(|item| item_starts.get(item))
+        .and_then(|&raw| index_map.get(raw))
+    {
+        let before = Paragraph::new(lines[..*start].to_vec()).wrap(Wrap { trim: false });
+        state.scroll_offset = if inner.width == 0 { *start } else { before.line_count(inner.width) };
+        state.auto_scroll = false;
+    }
+
     // Use ratatui's Paragraph::line_count() for accurate wrapped line calculation
     let width = inner.width as usize;
     let raw_line_count = lines.len();
@@ -1639,6 +2827,105 @@ pub fn handle_input(state: &mut AIScreenState, code: KeyCode, modifiers: KeyModi
     let ctrl = modifiers.contains(KeyModifiers::CONTROL);
     let shift = modifiers.contains(KeyModifiers::SHIFT);
 
+    // 승인 대기 중인 도구 호출이 있으면 응답만 처리
+    if !state.pending_approvals.is_empty() {
+        match code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => state.answer_approval(true, false),
+            KeyCode::Char('a') | KeyCode::Char('A') => state.answer_approval(true, true),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => state.answer_approval(false, false),
+            _ => {}
+        }
+        return false;
+    }
+
+    // 세션 검색 중
+    if let Some(ref mut search) = state.session_search {
+        match code {
+            KeyCode::Esc => state.session_search = None,
+            KeyCode::Enter => state.resume_selected_match(),
+            KeyCode::Up => search.selected = search.selected.saturating_sub(1),
+            KeyCode::Down => search.selected = (search.selected + 1).min(search.results.len().saturating_sub(1)),
+            KeyCode::PageUp => search.selected = search.selected.saturating_sub(8),
+            KeyCode::PageDown => search.selected = (search.selected + 8).min(search.results.len().saturating_sub(1)),
+            KeyCode::Backspace => {
+                search.query.pop();
+                state.update_session_search();
+            }
+            KeyCode::Char('u') if ctrl => {
+                search.query.clear();
+                state.update_session_search();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                search.query.push(c);
+                state.update_session_search();
+            }
+            _ => {}
+        }
+        return false;
+    }
+
+    // 작업 디렉토리 입력 중
+    if let Some(ref mut edit) = state.path_edit {
+        match code {
+            KeyCode::Enter => state.apply_path_edit(),
+            KeyCode::Esc => state.path_edit = None,
+            KeyCode::Tab => state.complete_path_edit(),
+            KeyCode::Left => edit.cursor = edit.cursor.saturating_sub(1),
+            KeyCode::Right => edit.cursor = (edit.cursor + 1).min(edit.input.chars().count()),
+            KeyCode::Home => edit.cursor = 0,
+            KeyCode::End => edit.cursor = edit.input.chars().count(),
+            KeyCode::Backspace if edit.cursor > 0 => {
+                let mut chars: Vec<char> = edit.input.chars().collect();
+                chars.remove(edit.cursor - 1);
+                edit.input = chars.into_iter().collect();
+                edit.cursor -= 1;
+                edit.reset_matches();
+            }
+            KeyCode::Delete if edit.cursor < edit.input.chars().count() => {
+                let mut chars: Vec<char> = edit.input.chars().collect();
+                chars.remove(edit.cursor);
+                edit.input = chars.into_iter().collect();
+                edit.reset_matches();
+            }
+            KeyCode::Char('u') if ctrl => {
+                edit.set_input(String::new());
+                edit.reset_matches();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                let mut chars: Vec<char> = edit.input.chars().collect();
+                chars.insert(edit.cursor, c);
+                edit.input = chars.into_iter().collect();
+                edit.cursor += 1;
+                edit.reset_matches();
+            }
+            _ => {}
+        }
+        return false;
+    }
+
+    // 응답 액션 목록이 열려 있으면 목록 조작만 처리
+    if let Some(ref mut menu) = state.response_actions {
+        match code {
+            KeyCode::Up => menu.selected = menu.selected.saturating_sub(1),
+            KeyCode::Down => menu.selected = (menu.selected + 1).min(menu.items.len().saturating_sub(1)),
+            KeyCode::Home => menu.selected = 0,
+            KeyCode::End => menu.selected = menu.items.len().saturating_sub(1),
+            KeyCode::Enter => {
+                let selected = menu.selected;
+                if let Some(menu) = state.response_actions.take() {
+                    state.response_action_request = menu.items.into_iter().nth(selected).map(|(_, action)| action);
+                }
+            }
+            KeyCode::Esc => state.response_actions = None,
+            _ => {
+                if kb.ai_screen_action(code, modifiers) == Some(AIScreenAction::ResponseActions) {
+                    state.response_actions = None;
+                }
+            }
+        }
+        return false;
+    }
+
     if let Some(action) = kb.ai_screen_action(code, modifiers) {
         match action {
             AIScreenAction::Escape => {
@@ -1734,6 +3021,40 @@ pub fn handle_input(state: &mut AIScreenState, code: KeyCode, modifiers: KeyModi
             AIScreenAction::ToggleFullscreen => {
                 state.ai_fullscreen = !state.ai_fullscreen;
             }
+            AIScreenAction::RunInBackground => {
+                // 종료 요청: 처리 중이면 App이 백그라운드로 유지
+                return true;
+            }
+            AIScreenAction::NewConversation => {
+                state.conversation_request = Some(ConversationRequest::New);
+            }
+            AIScreenAction::NextConversation => {
+                state.conversation_request = Some(ConversationRequest::Next);
+            }
+            AIScreenAction::ResponseActions => {
+                state.open_response_actions();
+            }
+            AIScreenAction::ToggleToolTimeline => {
+                state.timeline_collapsed = !state.timeline_collapsed;
+            }
+            AIScreenAction::ToggleApproval => {
+                state.toggle_approval_mode();
+            }
+            AIScreenAction::ChangeDirectory => {
+                state.open_path_edit();
+            }
+            AIScreenAction::SearchSessions => {
+                state.session_search = Some(SessionSearch::default());
+            }
+            AIScreenAction::GrowPane => {
+                state.layout_request = Some(PaneLayoutRequest::Grow);
+            }
+            AIScreenAction::ShrinkPane => {
+                state.layout_request = Some(PaneLayoutRequest::Shrink);
+            }
+            AIScreenAction::ToggleSplitLayout => {
+                state.layout_request = Some(PaneLayoutRequest::ToggleOrientation);
+            }
         }
     } else if let KeyCode::Char(c) = code {
         if !ctrl {
@@ -2767,4 +4088,180 @@ Let me know what you'd like to do!
             }
         }
     }
+
+    #[test]
+    fn test_conversation_keys_request_switch() {
+        let mut state = create_test_state();
+        let kb = default_kb();
+
+        assert!(!handle_input(&mut state, KeyCode::Char('n'), KeyModifiers::CONTROL, &kb));
+        assert_eq!(state.conversation_request.take(), Some(ConversationRequest::New));
+
+        assert!(!handle_input(&mut state, KeyCode::Char('t'), KeyModifiers::CONTROL, &kb));
+        assert_eq!(state.conversation_request.take(), Some(ConversationRequest::Next));
+        assert_eq!(state.get_input_text(), "");
+    }
+
+    #[test]
+    fn test_extract_code_blocks() {
+        let text = "Try this:\n```rust\nfn main() {}\n```\nor\n```\necho hi\nls\n```";
+        let blocks = extract_code_blocks(text);
+        assert_eq!(blocks, vec![
+            ("rust".to_string(), "fn main() {}".to_string()),
+            (String::new(), "echo hi\nls".to_string()),
+        ]);
+        assert_eq!(suggested_file_name("Rust"), "snippet.rs");
+        assert_eq!(suggested_file_name(""), "snippet.txt");
+    }
+
+    #[test]
+    fn test_extract_paths_and_actions() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "").unwrap();
+        fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+        let text = "Edited src/main.rs:12, see `notes.txt` and src/missing.rs.\n\
+                    ```\ncat src/main.rs\n```\nAlso (src/).";
+        let paths = extract_paths(text, dir.path());
+        assert_eq!(paths, vec![
+            dir.path().join("src/main.rs"),
+            dir.path().join("notes.txt"),
+            dir.path().join("src/"),
+        ]);
+
+        let actions = build_response_actions(text, dir.path());
+        assert_eq!(actions.len(), 5);
+        assert_eq!(actions[0].0, "Save code block 1 (1 line)");
+        assert_eq!(actions[1].1, ResponseAction::CopyCode("cat src/main.rs".to_string()));
+        assert_eq!(actions[2].1, ResponseAction::GotoPath(dir.path().join("src/main.rs")));
+    }
+
+    #[test]
+    fn test_tool_timeline_groups_calls_and_results() {
+        let mut state = create_test_state();
+        state.start_tool_call("Bash".to_string(), "$ ls -la\n  (List files)");
+        state.start_tool_call("Read".to_string(), "file: /tmp/a.txt");
+        state.finish_tool_call("total 0\nfoo", false);
+        state.finish_tool_call("No such file", true);
+        state.start_tool_call("Edit".to_string(), "file: /tmp/b.txt");
+
+        assert_eq!(state.history.len(), 1);
+        let calls = &state.history[0].tool_calls;
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[0].summary, "$ ls -la");
+        assert_eq!(calls[0].status, ToolStatus::Ok);
+        assert_eq!(calls[0].result, "total 0");
+        assert_eq!(calls[1].status, ToolStatus::Failed);
+        assert!(tool_timeline_summary(calls).starts_with("3 tool calls · "));
+        assert!(tool_timeline_summary(calls).ends_with(" · 1 failed · running Edit"));
+
+        // Text output starts a new timeline for later calls
+        state.cancel_running_tool_calls();
+        assert_eq!(state.history[0].tool_calls[2].status, ToolStatus::Cancelled);
+        state.add_to_history(HistoryItem {
+            item_type: HistoryType::Assistant,
+            content: "done".to_string(),
+            tool_calls: Vec::new(),
+        });
+        state.start_tool_call("Glob".to_string(), "pattern: *.rs");
+        assert_eq!(state.history.len(), 3);
+        assert_eq!(state.history[2].item_type, HistoryType::ToolTimeline);
+    }
+
+    #[test]
+    fn test_path_edit_completes_and_switches_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        fs::create_dir(root.join("project")).unwrap();
+        fs::write(root.join("pfile.txt"), "").unwrap();
+
+        let mut state = create_test_state();
+        state.current_path = root.display().to_string();
+        state.session_id = Some("abc".to_string());
+        state.open_path_edit();
+
+        // Relative input, files are not offered
+        state.path_edit.as_mut().unwrap().set_input("p".to_string());
+        state.complete_path_edit();
+        assert_eq!(state.path_edit.as_ref().unwrap().input, format!("{}/", root.join("project").display()));
+
+        state.apply_path_edit();
+        assert!(state.path_edit.is_none());
+        assert_eq!(state.current_path, root.join("project").display().to_string());
+        assert_eq!(state.session_id, None);
+
+        state.open_path_edit();
+        state.path_edit.as_mut().unwrap().set_input("missing".to_string());
+        state.apply_path_edit();
+        assert_eq!(state.path_edit.as_ref().unwrap().error.as_deref(), Some("not a directory"));
+    }
+
+    #[test]
+    fn test_search_sessions_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let item = |item_type, content: &str| HistoryItem { item_type, content: content.to_string(), tool_calls: Vec::new() };
+        let old = SessionData {
+            session_id: "old".to_string(),
+            history: vec![item(HistoryType::User, "how do I rename files?")],
+            current_path: "/tmp/a".to_string(),
+            created_at: "2024-01-01 10:00:00".to_string(),
+        };
+        let new = SessionData {
+            session_id: "new".to_string(),
+            history: vec![
+                item(HistoryType::User, "hello"),
+                item(HistoryType::Assistant, "first line\nUse the RENAME dialog to rename several files at once, it supports patterns"),
+            ],
+            current_path: "/tmp/b".to_string(),
+            created_at: "2024-02-01 10:00:00".to_string(),
+        };
+        for data in [&old, &new] {
+            fs::write(dir.path().join(format!("{}.json", data.session_id)), serde_json::to_string(data).unwrap()).unwrap();
+        }
+        fs::write(dir.path().join("broken.json"), "{").unwrap();
+
+        let results = search_sessions_in(dir.path(), "rename");
+        assert_eq!(results.len(), 2);
+        // Newest session first, pointing at the matching turn
+        assert_eq!(results[0].session_id, "new");
+        assert_eq!(results[0].item_index, 1);
+        assert_eq!(results[0].item_type, HistoryType::Assistant);
+        assert!(results[0].snippet.starts_with("Use the RENAME"));
+        assert!(results[0].snippet.ends_with('…'));
+        assert_eq!(results[1].session_id, "old");
+        assert_eq!(results[1].snippet, "how do I rename files?");
+
+        assert!(search_sessions_in(dir.path(), "missing").is_empty());
+        assert!(search_sessions_in(dir.path(), "  ").is_empty());
+    }
+
+    #[test]
+    fn test_expand_context_commands() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_path_buf();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src").join("main.rs"), "fn main() {}").unwrap();
+        fs::write(root.join("notes.txt"), "hello\n").unwrap();
+        let ctx = PanelContext { path: root.clone(), files: vec!["notes.txt".to_string()] };
+
+        let text = expand_context_commands("check /files please", Some(&ctx)).unwrap();
+        assert!(text.starts_with("check \n[Selected files in "));
+        assert!(text.contains(&format!("- {}\n", root.join("notes.txt").display())));
+        assert!(text.ends_with(" please"));
+
+        let text = expand_context_commands("/tree", Some(&ctx)).unwrap();
+        assert!(text.contains("```\nsrc/\n  main.rs\nnotes.txt\n```"));
+
+        let text = expand_context_commands("explain /content  notes.txt\nthanks", Some(&ctx)).unwrap();
+        assert!(text.contains("```txt\nhello\n```"));
+        assert!(!text.contains(" notes.txt"));
+        assert!(text.ends_with("\nthanks"));
+
+        // Not commands: paths and plain text are kept
+        assert_eq!(expand_context_commands("ls /tree/x", Some(&ctx)).unwrap(), "ls /tree/x");
+        assert!(expand_context_commands("/content", Some(&ctx)).is_err());
+        assert!(expand_context_commands("/content missing.txt", Some(&ctx)).is_err());
+        assert!(expand_context_commands("/files", None).is_err());
+    }
 }
@@ -5,18 +5,20 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver};
 use std::sync::Arc;
 use std::thread;
-use std::time::{Instant, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use chrono::{DateTime, Local};
 
 use crate::config::Settings;
 use crate::keybindings::Keybindings;
-use crate::services::file_ops::{self, FileOperationType, ProgressMessage, FileOperationResult};
+use crate::services::file_ops::{self, ConflictPolicy, FileOperationType, ProgressMessage, FileOperationResult};
 use crate::services::remote::{self, RemoteContext, RemoteProfile, ConnectionStatus, SftpFileEntry};
 use crate::services::remote_transfer;
+use crate::services::ipc::IpcError;
 use crate::ui::file_viewer::ViewerState;
 use crate::ui::file_editor::EditorState;
 use crate::ui::file_info::FileInfoState;
 use crate::ui::theme::DEFAULT_THEME_NAME;
+use crate::utils::fuzzy::fuzzy_match;
 
 /// Encode a command as base64 for safe shell execution
 /// This avoids all shell escaping issues by encoding the entire command
@@ -104,6 +106,12 @@ impl Default for HelpState {
     }
 }
 
+/// Name a clipboard entry gets in the target folder: the entry itself, or
+/// the last component of an absolute (basket) path
+fn entry_file_name(entry: &str) -> String {
+    Path::new(entry).file_name().map_or_else(|| entry.to_string(), |n| n.to_string_lossy().to_string())
+}
+
 /// Get a valid directory path, falling back to parent directories if needed
 pub fn get_valid_path(target_path: &Path, fallback: &Path) -> PathBuf {
     let mut current = target_path.to_path_buf();
@@ -137,6 +145,32 @@ pub fn get_valid_path(target_path: &Path, fallback: &Path) -> PathBuf {
     PathBuf::from("/")
 }
 
+/// Split a trailing `:line` or `:line:col` suffix from a path (compiler error format).
+/// Returns the path part and the 1-based (line, col); col defaults to 1.
+/// e.g. "src/main.rs:120:5" -> ("src/main.rs", Some((120, 5)))
+pub fn split_path_position(input: &str) -> (&str, Option<(usize, usize)>) {
+    let trimmed = input.trim_end_matches(':');
+    let mut numbers: Vec<usize> = Vec::new();
+    let mut rest = trimmed;
+    while numbers.len() < 2 {
+        let Some((head, tail)) = rest.rsplit_once(':') else { break };
+        if tail.is_empty() || !tail.chars().all(|c| c.is_ascii_digit()) {
+            break;
+        }
+        let Ok(n) = tail.parse::<usize>() else { break };
+        numbers.push(n);
+        rest = head;
+    }
+    if rest.is_empty() {
+        return (input, None);
+    }
+    match numbers.as_slice() {
+        [line] if *line > 0 => (rest, Some((*line, 1))),
+        [col, line] if *line > 0 => (rest, Some((*line, (*col).max(1)))),
+        _ => (input, None),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortBy {
     Name,
@@ -168,6 +202,17 @@ pub enum Screen {
     DiffFileView,
     GitScreen,
     DedupScreen,
+    ChecksumScreen,
+    AuditScreen,
+    ArchiveViewer,
+    TrashScreen,
+    BulkRename,
+    DrivesScreen,
+    RecoveryScreen,
+    CleanupScreen,
+    BackupScreen,
+    TimelineScreen,
+    GitLogScreen,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -189,6 +234,8 @@ pub enum DialogType {
     ExtensionHandlerError,
     BinaryFileHandler,
     GitLogDiff,
+    /// Open with: choose an application or recent command for a file
+    OpenWith,
     /// Remote connection dialog - enter auth info for new server
     RemoteConnect,
     /// Remote profile save prompt - ask to save after successful connect
@@ -196,6 +243,32 @@ pub enum DialogType {
     EncryptConfirm,
     DecryptConfirm,
     DedupConfirm,
+    /// Recently viewed/edited files
+    RecentFiles,
+    /// Jump to a frequently/recently visited directory
+    JumpDirectory,
+    /// Numbered favorite directories: go, add, remove, reorder
+    Favorites,
+    /// Name for saving advanced search criteria as a smart folder
+    SaveSmartFolder,
+    /// Files collected from several directories: copy, move or zip them together
+    Basket,
+    /// Name of the zip archive made from the basket
+    BasketArchive,
+    /// Image viewer "save as": format, quality and size
+    ImageExport,
+    /// Paste with exclude patterns for the recursive copy/move
+    PasteExclude,
+    /// Select files by modified time or size
+    SelectFilter,
+    /// Save a code block from an AI response to a file
+    SaveAICode,
+    /// Quick calculator with byte-unit conversion
+    Calculator,
+    /// New extension for the selected files
+    ChangeExtension,
+    /// Ancestors of the panel path and their sibling folders
+    Breadcrumb,
 }
 
 /// Settings dialog state
@@ -205,18 +278,32 @@ pub struct SettingsState {
     pub themes: Vec<String>,
     /// Currently selected theme index
     pub theme_index: usize,
-    /// Currently selected field row in settings dialog (0=theme, 1=diff method)
+    /// Currently selected field row in settings dialog (0=theme, 1=diff method, 2=conflict policy,
+    /// 3=text markers, then one row per extension action, then the "add extension" row)
     pub selected_field: usize,
     /// Available diff compare methods
     pub diff_methods: Vec<String>,
     /// Currently selected diff method index
     pub diff_method_index: usize,
+    /// Default resolution for paste conflicts
+    pub conflict_policy: ConflictPolicy,
+    /// Mark cursor/marks/active panel with text as well as color
+    pub text_markers: bool,
+    /// Extensions mapped to a built-in action (extension_handler entries like [":view"])
+    pub ext_actions: Vec<(String, crate::config::BuiltinAction)>,
+    /// Extension typed on the "add" row
+    pub new_ext: String,
 }
 
 impl SettingsState {
     pub fn new(settings: &Settings) -> Self {
         // Scan available themes
-        let mut themes = vec!["light".to_string(), "dark".to_string()];
+        let mut themes = vec![
+            "light".to_string(),
+            "dark".to_string(),
+            "high_contrast".to_string(),
+            "monochrome".to_string(),
+        ];
         if let Some(themes_dir) = Settings::themes_dir() {
             if let Ok(entries) = std::fs::read_dir(&themes_dir) {
                 for entry in entries.filter_map(|e| e.ok()) {
@@ -257,7 +344,44 @@ impl SettingsState {
             selected_field: 0,
             diff_methods,
             diff_method_index,
+            conflict_policy: settings.conflict_policy,
+            text_markers: settings.theme.text_markers,
+            ext_actions: settings.builtin_extension_actions(),
+            new_ext: String::new(),
+        }
+    }
+
+    /// First row of the extension action list
+    pub const EXT_ROW_START: usize = 4;
+
+    /// Row of the "add extension" input (after the action rows)
+    pub fn add_ext_row(&self) -> usize {
+        Self::EXT_ROW_START + self.ext_actions.len()
+    }
+
+    /// Index into `ext_actions` of the selected row
+    pub fn selected_ext_action(&self) -> Option<usize> {
+        self.selected_field
+            .checked_sub(Self::EXT_ROW_START)
+            .filter(|&i| i < self.ext_actions.len())
+    }
+
+    /// Add the typed extension (or select it if already mapped). Returns false when the input is empty.
+    pub fn add_ext_action(&mut self) -> bool {
+        let ext = self.new_ext.trim().trim_start_matches('.').to_lowercase();
+        self.new_ext.clear();
+        if ext.is_empty() {
+            return false;
         }
+        let idx = match self.ext_actions.iter().position(|(e, _)| *e == ext) {
+            Some(idx) => idx,
+            None => {
+                self.ext_actions.push((ext, crate::config::BuiltinAction::View));
+                self.ext_actions.len() - 1
+            }
+        };
+        self.selected_field = Self::EXT_ROW_START + idx;
+        true
     }
 
     pub fn current_theme(&self) -> &str {
@@ -502,22 +626,6 @@ impl RemoteConnectState {
     }
 }
 
-/// Fuzzy match: check if all characters in pattern appear in text in order
-/// e.g., "thse" matches "/path/to/base" (t-h-s-e appear in sequence)
-pub fn fuzzy_match(text: &str, pattern: &str) -> bool {
-    let mut text_chars = text.chars().peekable();
-    for pattern_char in pattern.chars() {
-        loop {
-            match text_chars.next() {
-                Some(c) if c == pattern_char => break,
-                Some(_) => continue,
-                None => return false,
-            }
-        }
-    }
-    true
-}
-
 /// Resolution option for duplicate file conflicts
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConflictResolution {
@@ -525,6 +633,10 @@ pub enum ConflictResolution {
     Skip,
     OverwriteAll,
     SkipAll,
+    /// Overwrite the remaining conflicts where the source is newer, skip the rest
+    OverwriteAllIfNewer,
+    /// Overwrite the remaining conflicts where the source is larger, skip the rest
+    OverwriteAllIfLarger,
 }
 
 /// State for managing file conflict resolution during paste operations
@@ -544,6 +656,23 @@ pub struct ConflictState {
     pub is_move_operation: bool,
     /// Target directory for the operation
     pub target_path: PathBuf,
+    /// Exclude patterns for the copy/move walk
+    pub excludes: Vec<String>,
+}
+
+impl ConflictState {
+    /// Decide the current and all following conflicts with `policy`
+    pub fn resolve_remaining(&mut self, policy: ConflictPolicy) {
+        let start = self.current_index.min(self.conflicts.len());
+        for (src, dest, _) in &self.conflicts[start..] {
+            if policy.should_overwrite(src, dest) {
+                self.files_to_overwrite.push(src.clone());
+            } else {
+                self.files_to_skip.push(src.clone());
+            }
+        }
+        self.current_index = self.conflicts.len();
+    }
 }
 
 /// State for tar exclude confirmation dialog
@@ -571,6 +700,255 @@ pub struct GitLogDiffState {
     pub visible_height: usize,
 }
 
+/// State for "Open with" dialog
+#[derive(Debug, Clone)]
+pub struct OpenWithState {
+    pub file_path: PathBuf,
+    /// Lowercase extension used as the history key (empty if none)
+    pub extension: String,
+    /// Recent commands first, then installed applications
+    pub entries: Vec<crate::services::open_with::OpenWithEntry>,
+    /// Indices into `entries` matching the filter (dialog input)
+    pub filtered: Vec<usize>,
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+    pub visible_height: usize,
+}
+
+impl OpenWithState {
+    /// Re-apply the filter typed in the dialog input
+    pub fn apply_filter(&mut self, filter: &str) {
+        let filter = filter.to_lowercase();
+        self.filtered = self.entries.iter()
+            .enumerate()
+            .filter(|(_, e)| filter.is_empty() || fuzzy_match(&e.label.to_lowercase(), &filter))
+            .map(|(i, _)| i)
+            .collect();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    pub fn selected_entry(&self) -> Option<&crate::services::open_with::OpenWithEntry> {
+        self.filtered.get(self.selected_index).and_then(|&i| self.entries.get(i))
+    }
+}
+
+/// State for the recent files dialog
+#[derive(Debug, Clone)]
+pub struct RecentFilesState {
+    /// Copy of settings.recent_files taken when the dialog opened
+    pub entries: Vec<crate::config::RecentFile>,
+    /// Indices into `entries` matching the filter (dialog input)
+    pub filtered: Vec<usize>,
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+    pub visible_height: usize,
+}
+
+impl RecentFilesState {
+    /// Re-apply the filter typed in the dialog input
+    pub fn apply_filter(&mut self, filter: &str) {
+        let filter = filter.to_lowercase();
+        self.filtered = self.entries.iter()
+            .enumerate()
+            .filter(|(_, e)| filter.is_empty() || fuzzy_match(&e.path.to_lowercase(), &filter))
+            .map(|(i, _)| i)
+            .collect();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    pub fn selected_entry(&self) -> Option<&crate::config::RecentFile> {
+        self.filtered.get(self.selected_index).and_then(|&i| self.entries.get(i))
+    }
+}
+
+/// State for the path breadcrumb menu: ancestors of the panel path and the
+/// sibling folders of the selected one
+#[derive(Debug, Clone)]
+pub struct BreadcrumbState {
+    /// Ancestors of the panel path, root first (the last one is the panel path)
+    pub components: Vec<PathBuf>,
+    /// Selected entry of `components`
+    pub level: usize,
+    /// Folders next to the selected component (subfolders of its parent), sorted
+    pub siblings: Vec<String>,
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+    pub visible_height: usize,
+    /// List dotfolders (follows the panel's hidden-files setting)
+    pub show_hidden: bool,
+}
+
+impl BreadcrumbState {
+    pub fn new(path: &Path, show_hidden: bool) -> Self {
+        let mut components: Vec<PathBuf> = path.ancestors().map(Path::to_path_buf).collect();
+        components.reverse();
+        let level = components.len().saturating_sub(1);
+        let mut state = Self {
+            components,
+            level,
+            siblings: Vec::new(),
+            selected_index: 0,
+            scroll_offset: 0,
+            visible_height: 20,
+            show_hidden,
+        };
+        state.set_level(level);
+        state
+    }
+
+    /// Display name of component `i` (the root shows as its full path)
+    pub fn component_name(&self, i: usize) -> String {
+        let path = &self.components[i];
+        path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string())
+    }
+
+    /// Select component `level` and list its siblings with the cursor on it
+    pub fn set_level(&mut self, level: usize) {
+        self.level = level.min(self.components.len().saturating_sub(1));
+        let name = self.component_name(self.level);
+        self.siblings = match self.level.checked_sub(1).map(|p| &self.components[p]) {
+            Some(parent) => fs::read_dir(parent)
+                .map(|entries| entries.flatten()
+                    .filter(|e| e.path().is_dir())
+                    .map(|e| e.file_name().to_string_lossy().to_string())
+                    .filter(|n| self.show_hidden || !n.starts_with('.'))
+                    .collect())
+                .unwrap_or_default(),
+            None => Vec::new(),
+        };
+        // 숨김 폴더나 읽을 수 없는 부모여도 현재 구성 요소는 항상 목록에 포함
+        if !self.siblings.contains(&name) {
+            self.siblings.push(name.clone());
+        }
+        self.siblings.sort_by_key(|n| n.to_lowercase());
+        self.selected_index = self.siblings.iter().position(|n| *n == name).unwrap_or(0);
+        self.scroll_offset = 0;
+    }
+
+    /// Folder under the cursor
+    pub fn selected_path(&self) -> Option<PathBuf> {
+        let name = self.siblings.get(self.selected_index)?;
+        match self.level.checked_sub(1) {
+            Some(parent) => Some(self.components[parent].join(name)),
+            None => self.components.first().cloned(),
+        }
+    }
+}
+
+/// State for the jump-to-directory dialog
+#[derive(Debug, Clone)]
+pub struct JumpState {
+    /// Visited directories, loaded when the dialog opened
+    pub history: crate::services::frecency::Frecency,
+    /// Copy of settings.smart_folders, listed before the directories
+    pub smart_folders: Vec<crate::config::SmartFolder>,
+    /// Indices into `smart_folders` whose name matches the filter
+    pub smart_matches: Vec<usize>,
+    /// Directories matching the filter (dialog input), best first, with their score
+    pub matches: Vec<(String, f64)>,
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+    pub visible_height: usize,
+}
+
+/// A row of the jump dialog
+#[derive(Debug, Clone, Copy)]
+pub enum JumpEntry<'a> {
+    SmartFolder(&'a crate::config::SmartFolder),
+    Directory(&'a str, f64),
+}
+
+impl JumpState {
+    /// Re-run the search for the text typed in the dialog input
+    pub fn apply_filter(&mut self, filter: &str) {
+        let now = crate::services::frecency::now_secs();
+        let pattern: String = filter.split_whitespace().collect::<String>().to_lowercase();
+        self.smart_matches = self.smart_folders.iter()
+            .enumerate()
+            .filter(|(_, f)| fuzzy_match(&f.name.to_lowercase(), &pattern))
+            .map(|(i, _)| i)
+            .collect();
+        self.matches = self.history.search(filter, now)
+            .into_iter()
+            .map(|e| (e.path.clone(), e.score(now)))
+            .collect();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Number of rows (smart folders, then directories)
+    pub fn len(&self) -> usize {
+        self.smart_matches.len() + self.matches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn entry(&self, index: usize) -> Option<JumpEntry<'_>> {
+        match self.smart_matches.get(index) {
+            Some(&i) => self.smart_folders.get(i).map(JumpEntry::SmartFolder),
+            None => self.matches
+                .get(index - self.smart_matches.len())
+                .map(|(p, score)| JumpEntry::Directory(p.as_str(), *score)),
+        }
+    }
+
+    pub fn selected_path(&self) -> Option<&str> {
+        match self.entry(self.selected_index)? {
+            JumpEntry::Directory(path, _) => Some(path),
+            JumpEntry::SmartFolder(_) => None,
+        }
+    }
+
+    pub fn selected_smart_folder(&self) -> Option<&crate::config::SmartFolder> {
+        match self.entry(self.selected_index)? {
+            JumpEntry::SmartFolder(folder) => Some(folder),
+            JumpEntry::Directory(..) => None,
+        }
+    }
+}
+
+/// State for the favorites dialog (entries are edited in settings.favorites directly)
+#[derive(Debug, Clone, Default)]
+pub struct FavoritesState {
+    pub selected_index: usize,
+}
+
+/// State for the basket dialog (entries are edited in `App::basket` directly)
+#[derive(Debug, Clone, Default)]
+pub struct BasketState {
+    pub selected_index: usize,
+}
+
+/// State for the image "save as" dialog
+#[derive(Debug, Clone)]
+pub struct ImageExportState {
+    pub src: PathBuf,
+    /// Size of the original image, if known
+    pub original_size: Option<(u32, u32)>,
+    pub options: crate::services::image_export::ExportOptions,
+    /// Focused row (0=format, 1=quality, 2=size)
+    pub selected_row: usize,
+}
+
+impl ImageExportState {
+    pub const ROWS: usize = 3;
+
+    /// Output dimensions at the chosen scale
+    pub fn output_size(&self) -> Option<(u32, u32)> {
+        let scale = self.options.scale_percent as u64;
+        self.original_size.map(|(w, h)| (
+            ((w as u64 * scale) / 100).max(1) as u32,
+            ((h as u64 * scale) / 100).max(1) as u32,
+        ))
+    }
+}
+
 /// Clipboard operation type for Ctrl+C/X/V operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ClipboardOperation {
@@ -606,6 +984,14 @@ pub struct FileOperationProgress {
     pub completed_files: usize,
     pub total_bytes: u64,
     pub completed_bytes: u64,
+    /// Entries skipped by exclude patterns
+    pub excluded_count: usize,
+    /// Percent already downloaded when a partial download was resumed
+    pub resumed_percent: Option<u8>,
+
+    // Throughput: smoothed bytes/s and the last (time, completed bytes) sample
+    bytes_per_sec: f64,
+    speed_sample: Option<(Instant, u64)>,
 
     pub result: Option<FileOperationResult>,
 
@@ -614,6 +1000,10 @@ pub struct FileOperationProgress {
 
     // Timestamp when the operation started (for display delay)
     pub started_at: Instant,
+
+    /// Folders the operation changes; panels showing them are reloaded when it
+    /// finishes as a background job
+    pub dirs: Vec<PathBuf>,
 }
 
 impl FileOperationProgress {
@@ -631,9 +1021,14 @@ impl FileOperationProgress {
             completed_files: 0,
             total_bytes: 0,
             completed_bytes: 0,
+            excluded_count: 0,
+            resumed_percent: None,
+            bytes_per_sec: 0.0,
+            speed_sample: None,
             result: None,
             last_error: None,
             started_at: Instant::now(),
+            dirs: Vec::new(),
         }
     }
 
@@ -661,6 +1056,8 @@ impl FileOperationProgress {
                             ProgressMessage::PrepareComplete => {
                                 self.is_preparing = false;
                                 self.preparing_message.clear();
+                                // Measure speed from here, not from the size calculation
+                                self.speed_sample = Some((Instant::now(), self.completed_bytes));
                             }
                             ProgressMessage::FileStarted(name) => {
                                 self.current_file = name;
@@ -671,6 +1068,14 @@ impl FileOperationProgress {
                                     self.current_file_progress = copied as f64 / total as f64;
                                 }
                             }
+                            ProgressMessage::Resumed(offset, total) => {
+                                if total > 0 {
+                                    self.resumed_percent = Some((offset * 100 / total) as u8);
+                                }
+                                // Speed counts only the bytes transferred in this run
+                                self.completed_bytes = offset;
+                                self.speed_sample = Some((Instant::now(), offset));
+                            }
                             ProgressMessage::FileCompleted(_) => {
                                 self.current_file_progress = 1.0;
                             }
@@ -680,10 +1085,14 @@ impl FileOperationProgress {
                                 self.completed_bytes = completed_bytes;
                                 self.total_bytes = total_bytes;
                             }
+                            ProgressMessage::Excluded(_) => {
+                                self.excluded_count += 1;
+                            }
                             ProgressMessage::Completed(success, failure) => {
                                 self.result = Some(FileOperationResult {
                                     success_count: success,
                                     failure_count: failure,
+                                    excluded_count: self.excluded_count,
                                     last_error: self.last_error.take(),
                                 });
                                 self.is_active = false;
@@ -699,6 +1108,14 @@ impl FileOperationProgress {
                         break;
                     }
                     Err(mpsc::TryRecvError::Disconnected) => {
+                        // Completed 없이 워커 종료 (패닉 등): 실패로 마무리해야 진행 다이얼로그가 닫힘
+                        self.result = Some(FileOperationResult {
+                            success_count: self.completed_files,
+                            failure_count: self.total_files.saturating_sub(self.completed_files).max(1),
+                            excluded_count: self.excluded_count,
+                            last_error: Some(self.last_error.take()
+                                .unwrap_or_else(|| "Operation stopped unexpectedly".to_string())),
+                        });
                         self.is_active = false;
                         return false;
                     }
@@ -706,9 +1123,118 @@ impl FileOperationProgress {
             }
         }
 
+        if !self.is_preparing {
+            self.update_speed(Instant::now());
+        }
+
         self.is_active
     }
 
+    /// Minimum interval between speed samples
+    const SPEED_SAMPLE_SECS: f64 = 0.5;
+    /// Weight of the newest sample in the moving average
+    const SPEED_SMOOTHING: f64 = 0.3;
+
+    /// Fold the bytes completed since the last sample into the smoothed speed
+    fn update_speed(&mut self, now: Instant) {
+        let Some((at, bytes)) = self.speed_sample else {
+            self.speed_sample = Some((now, self.completed_bytes));
+            return;
+        };
+        let dt = now.duration_since(at).as_secs_f64();
+        if dt < Self::SPEED_SAMPLE_SECS {
+            return;
+        }
+        let current = self.completed_bytes.saturating_sub(bytes) as f64 / dt;
+        self.bytes_per_sec = if self.bytes_per_sec > 0.0 {
+            Self::SPEED_SMOOTHING * current + (1.0 - Self::SPEED_SMOOTHING) * self.bytes_per_sec
+        } else {
+            current
+        };
+        self.speed_sample = Some((now, self.completed_bytes));
+    }
+
+    /// Smoothed transfer speed in bytes per second (None until measured)
+    pub fn bytes_per_sec(&self) -> Option<f64> {
+        (self.bytes_per_sec > 0.0).then_some(self.bytes_per_sec)
+    }
+
+    /// Estimated time left, from the remaining bytes and the smoothed speed
+    pub fn eta(&self) -> Option<Duration> {
+        let speed = self.bytes_per_sec()?;
+        if self.total_bytes == 0 {
+            return None;
+        }
+        let remaining = self.total_bytes.saturating_sub(self.completed_bytes);
+        Some(Duration::from_secs_f64(remaining as f64 / speed))
+    }
+
+    /// Time since the operation started
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Status message for the finished operation (None if it ended without a result).
+    /// `archive_name` / `extract_dir` name what a Tar/Zip or Untar/Unzip job created.
+    pub fn completion_message(&self, archive_name: Option<&str>, extract_dir: Option<&str>) -> Option<String> {
+        let result = self.result.as_ref()?;
+        let message = match self.operation_type {
+            // Special handling for archives - show archive name
+            FileOperationType::Tar | FileOperationType::Zip => {
+                if result.failure_count == 0 {
+                    match archive_name {
+                        Some(name) => format!("Created: {}", name),
+                        None => format!("Archived {} file(s)", result.success_count),
+                    }
+                } else {
+                    format!("Error: {}", result.last_error.as_deref().unwrap_or("Archive failed"))
+                }
+            }
+            FileOperationType::Untar | FileOperationType::Unzip => {
+                if result.failure_count == 0 {
+                    match extract_dir {
+                        Some(dir) => format!("Extracted to: {}", dir),
+                        None => format!("Extracted {} file(s)", result.success_count),
+                    }
+                } else {
+                    format!("Error: {}", result.last_error.as_deref().unwrap_or("Extract failed"))
+                }
+            }
+            _ => {
+                let op_name = match self.operation_type {
+                    FileOperationType::Copy => "Copied",
+                    FileOperationType::Move => "Moved",
+                    FileOperationType::Download => "Downloaded",
+                    FileOperationType::Encrypt => "Encrypted",
+                    FileOperationType::Decrypt => "Decrypted",
+                    FileOperationType::Delete => "Deleted",
+                    FileOperationType::Trash => "Moved to trash",
+                    FileOperationType::Sync => "Synchronized",
+                    FileOperationType::Tar | FileOperationType::Zip => "Archived",
+                    FileOperationType::Untar | FileOperationType::Unzip => "Extracted",
+                };
+                let total = result.success_count + result.failure_count;
+                let excluded = if result.excluded_count > 0 {
+                    format!(" ({} excluded)", result.excluded_count)
+                } else {
+                    String::new()
+                };
+                if result.failure_count == 0 {
+                    format!("{} {} file(s){}", op_name, result.success_count, excluded)
+                } else {
+                    format!("{} {}/{}{}. Error: {}",
+                        op_name,
+                        result.success_count,
+                        total,
+                        excluded,
+                        result.last_error.as_deref().unwrap_or("Unknown error")
+                    )
+                }
+            }
+        };
+        Some(message)
+    }
+
     /// Get overall progress as percentage (0.0 ~ 1.0)
     /// Incorporates partial progress of the currently transferring file
     pub fn overall_progress(&self) -> f64 {
@@ -734,6 +1260,12 @@ pub enum PendingRemoteOpen {
     ImageViewer {
         tmp_path: PathBuf,
     },
+    /// Reopen a remote preview with the full file
+    Viewer {
+        tmp_path: PathBuf,
+        preview: crate::ui::file_viewer::RemotePreview,
+        scroll: usize,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -762,12 +1294,52 @@ pub struct FileItem {
     pub display_name: Option<String>,
     pub is_directory: bool,
     pub is_symlink: bool,
+    /// Where a symlink points, as stored in the link (local panels only)
+    pub link_target: Option<String>,
     pub size: u64,
     pub modified: DateTime<Local>,
     #[allow(dead_code)]
     pub permissions: String,
 }
 
+/// Condition of "Select by filter": age (seconds) or size (bytes) of files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectFilter {
+    NewerThan(u64),
+    OlderThan(u64),
+    LargerThan(u64),
+    SmallerThan(u64),
+}
+
+impl SelectFilter {
+    /// "newer 7d", "older 2w", "larger 10M" or "smaller 1k"
+    pub fn parse(input: &str) -> Option<Self> {
+        use crate::utils::format::{parse_age, parse_size};
+        let (word, value) = input.trim().split_once(char::is_whitespace)?;
+        match word.to_lowercase().as_str() {
+            "newer" => parse_age(value).map(SelectFilter::NewerThan),
+            "older" => parse_age(value).map(SelectFilter::OlderThan),
+            "larger" => parse_size(value).map(SelectFilter::LargerThan),
+            "smaller" => parse_size(value).map(SelectFilter::SmallerThan),
+            _ => None,
+        }
+    }
+
+    /// Directories never match
+    pub fn matches(&self, file: &FileItem, now: DateTime<Local>) -> bool {
+        if file.is_directory {
+            return false;
+        }
+        let age = now.signed_duration_since(file.modified).num_seconds().max(0) as u64;
+        match *self {
+            SelectFilter::NewerThan(secs) => age < secs,
+            SelectFilter::OlderThan(secs) => age > secs,
+            SelectFilter::LargerThan(bytes) => file.size > bytes,
+            SelectFilter::SmallerThan(bytes) => file.size < bytes,
+        }
+    }
+}
+
 /// Parse sort_by string from settings to SortBy enum
 pub fn parse_sort_by(s: &str) -> SortBy {
     match s.to_lowercase().as_str() {
@@ -829,12 +1401,6 @@ pub enum RemoteSpinnerResult {
         message: Result<String, String>,
         reload: bool,
     },
-    /// Search completed
-    SearchComplete {
-        results: Vec<crate::ui::search_result::SearchResultItem>,
-        search_term: String,
-        base_path: PathBuf,
-    },
     /// Git log diff preparation completed
     GitDiffComplete {
         result: Result<(PathBuf, PathBuf), String>,
@@ -861,6 +1427,13 @@ pub enum PanelOpOutcome {
         exists: bool,
         target_entry: String,
     },
+    /// Head of a remote file written to `tmp_path` for the viewer
+    Preview {
+        result: Result<(), String>,
+        tmp_path: PathBuf,
+        file_name: String,
+        file_size: u64,
+    },
 }
 
 /// Successful connection data
@@ -872,6 +1445,16 @@ pub struct ConnectSuccess {
     pub profile: RemoteProfile,
 }
 
+/// Quick filter typed in a panel (Ctrl+F): `files` shows only fuzzy matches
+#[derive(Debug, Clone)]
+pub struct QuickFilter {
+    pub text: String,
+    /// Full listing, restored when the filter is closed
+    all_files: Vec<FileItem>,
+    /// Directory the filter belongs to (cleared when the panel leaves it)
+    path: PathBuf,
+}
+
 #[derive(Debug)]
 pub struct PanelState {
     pub path: PathBuf,
@@ -888,6 +1471,94 @@ pub struct PanelState {
     pub remote_ctx: Option<Box<RemoteContext>>,
     /// Cached remote display info (user, host, port) — survives while remote_ctx is temporarily taken
     pub remote_display: Option<(String, String, u16)>,
+    /// File list rows visible in the last draw (for half/third page scrolling)
+    pub visible_height: usize,
+    /// Name width, counts and total size of `files` (updated on load)
+    pub list_stats: super::panel::ListStats,
+    /// Formatted rows of `files` (cleared on load)
+    pub row_cache: super::panel::RowCache,
+    /// Recursive sizes of subdirectories by name, shown in the Size column
+    pub dir_sizes: std::collections::HashMap<String, u64>,
+    /// `files` changed since `dir_sizes` was filled (see App::poll_dir_sizes)
+    pub dir_sizes_stale: bool,
+    /// Git status of the entries, shown as a badge column (see App::poll_git_status)
+    pub git_badges: crate::services::git_status::Badges,
+    /// The listing was reloaded since `git_badges` was requested
+    pub git_badges_stale: bool,
+    /// Active quick filter (marks of hidden entries stay in `selected_files`)
+    pub quick_filter: Option<QuickFilter>,
+    /// Show dotfiles (per panel, saved in PanelSettings)
+    pub show_hidden: bool,
+    /// Dotfiles left out of `files` by the last load
+    pub hidden_count: usize,
+}
+
+/// Read a local directory listing (unsorted, without "..")
+fn read_local_items(path: &Path) -> Option<Vec<FileItem>> {
+    let entries = fs::read_dir(path).ok()?;
+    // Estimate capacity based on typical directory size
+    let entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+    let mut items: Vec<FileItem> = Vec::with_capacity(entries.len());
+
+    items.extend(entries.into_iter().filter_map(|entry| {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = entry.path();
+
+        // Check if it's a symlink first
+        let symlink_meta = fs::symlink_metadata(&path).ok()?;
+        let is_symlink = symlink_meta.is_symlink();
+        let link_target = if is_symlink {
+            fs::read_link(&path).ok().map(|t| t.to_string_lossy().to_string())
+        } else {
+            None
+        };
+
+        // For symlinks, follow to get target type; for others, use direct metadata
+        let metadata = if is_symlink {
+            fs::metadata(&path).ok().unwrap_or(symlink_meta.clone())
+        } else {
+            symlink_meta.clone()
+        };
+
+        let is_directory = metadata.is_dir();
+        let size = if is_directory { 0 } else { metadata.len() };
+        let modified = metadata.modified().ok()
+            .map(DateTime::<Local>::from)
+            .unwrap_or_else(Local::now);
+
+        #[cfg(unix)]
+        let permissions = {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = symlink_meta.permissions().mode();
+            crate::utils::format::format_permissions_short(mode)
+        };
+        #[cfg(not(unix))]
+        let permissions = String::new();
+
+        let display_name = if !is_directory && name.ends_with(crate::enc::naming::EXT) {
+            std::fs::File::open(&path).ok()
+                .and_then(|f| {
+                    let mut reader = std::io::BufReader::new(f);
+                    crate::enc::crypto::read_header(&mut reader).ok()
+                })
+                .map(|(_, _, hdr_name)| hdr_name)
+        } else {
+            None
+        };
+
+        Some(FileItem {
+            name,
+            display_name,
+            is_directory,
+            is_symlink,
+            link_target,
+            size,
+            modified,
+            permissions,
+        })
+    }));
+
+    Some(items)
 }
 
 impl PanelState {
@@ -909,6 +1580,16 @@ impl PanelState {
             disk_available: 0,
             remote_ctx: None,
             remote_display: None,
+            visible_height: 0,
+            list_stats: super::panel::ListStats::default(),
+            row_cache: super::panel::RowCache::default(),
+            dir_sizes: std::collections::HashMap::new(),
+            dir_sizes_stale: true,
+            quick_filter: None,
+            show_hidden: true,
+            hidden_count: 0,
+            git_badges: Default::default(),
+            git_badges_stale: true,
         };
         state.load_files();
         state
@@ -936,6 +1617,16 @@ impl PanelState {
             disk_available: 0,
             remote_ctx: None,
             remote_display: None,
+            visible_height: 0,
+            list_stats: super::panel::ListStats::default(),
+            row_cache: super::panel::RowCache::default(),
+            dir_sizes: std::collections::HashMap::new(),
+            dir_sizes_stale: true,
+            quick_filter: None,
+            show_hidden: panel_settings.show_hidden,
+            hidden_count: 0,
+            git_badges: Default::default(),
+            git_badges_stale: true,
         };
         state.load_files();
         state
@@ -966,11 +1657,36 @@ impl PanelState {
         if self.is_remote() {
             self.load_files_remote();
         } else {
-            self.load_files_local();
+            self.load_files_local(false);
+        }
+    }
+
+    /// Re-read the listing after a change made elsewhere (background job, watch
+    /// rule), keeping the cursor entry and the marks of entries that still exist
+    pub fn reload_keep_marks(&mut self) {
+        self.pending_focus = self.current_file().map(|f| f.name.clone());
+        self.load_files();
+        let listed = match self.quick_filter {
+            Some(ref filter) => &filter.all_files,
+            None => &self.files,
+        };
+        let names: HashSet<&str> = listed.iter().map(|f| f.name.as_str()).collect();
+        self.selected_files.retain(|name| names.contains(name.as_str()));
+    }
+
+    /// Like `load_files`, but reuses a cached listing when the directory is unchanged.
+    /// Used when navigating; reloads after operations always read the directory.
+    pub fn load_files_cached(&mut self) {
+        if self.is_remote() {
+            self.load_files_remote();
+        } else {
+            self.load_files_local(true);
         }
     }
 
-    fn load_files_local(&mut self) {
+    fn load_files_local(&mut self, use_cache: bool) {
+        use crate::services::dir_cache;
+
         self.files.clear();
 
         // Add parent directory entry if not at root
@@ -980,69 +1696,29 @@ impl PanelState {
                 display_name: None,
                 is_directory: true,
                 is_symlink: false,
+                link_target: None,
                 size: 0,
                 modified: Local::now(),
                 permissions: String::new(),
             });
         }
 
-        if let Ok(entries) = fs::read_dir(&self.path) {
-            // Estimate capacity based on typical directory size
-            let entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
-            let mut items: Vec<FileItem> = Vec::with_capacity(entries.len());
-
-            items.extend(entries.into_iter().filter_map(|entry| {
-                    let name = entry.file_name().to_string_lossy().to_string();
-                    let path = entry.path();
-
-                    // Check if it's a symlink first
-                    let symlink_meta = fs::symlink_metadata(&path).ok()?;
-                    let is_symlink = symlink_meta.is_symlink();
-
-                    // For symlinks, follow to get target type; for others, use direct metadata
-                    let metadata = if is_symlink {
-                        fs::metadata(&path).ok().unwrap_or(symlink_meta.clone())
-                    } else {
-                        symlink_meta.clone()
-                    };
-
-                    let is_directory = metadata.is_dir();
-                    let size = if is_directory { 0 } else { metadata.len() };
-                    let modified = metadata.modified().ok()
-                        .map(DateTime::<Local>::from)
-                        .unwrap_or_else(Local::now);
-
-                    #[cfg(unix)]
-                    let permissions = {
-                        use std::os::unix::fs::PermissionsExt;
-                        let mode = symlink_meta.permissions().mode();
-                        crate::utils::format::format_permissions_short(mode)
-                    };
-                    #[cfg(not(unix))]
-                    let permissions = String::new();
-
-                    let display_name = if !is_directory && name.ends_with(crate::enc::naming::EXT) {
-                        std::fs::File::open(&path).ok()
-                            .and_then(|f| {
-                                let mut reader = std::io::BufReader::new(f);
-                                crate::enc::crypto::read_header(&mut reader).ok()
-                            })
-                            .map(|(_, _, hdr_name)| hdr_name)
-                    } else {
-                        None
-                    };
-
-                    Some(FileItem {
-                        name,
-                        display_name,
-                        is_directory,
-                        is_symlink,
-                        size,
-                        modified,
-                        permissions,
-                    })
-                }));
+        // mtime is taken before reading so changes during the read invalidate the entry
+        let mtime = dir_cache::dir_mtime(&self.path);
+        let cached = if use_cache {
+            mtime.and_then(|m| dir_cache::get(&self.path, m))
+        } else {
+            None
+        };
+        let items = cached.or_else(|| {
+            let items = read_local_items(&self.path)?;
+            if let Some(m) = mtime {
+                dir_cache::insert(&self.path, m, items.clone());
+            }
+            Some(items)
+        });
 
+        if let Some(mut items) = items {
             self.sort_items(&mut items);
             self.files.reserve(items.len());
             self.files.extend(items);
@@ -1064,6 +1740,7 @@ impl PanelState {
                 display_name: None,
                 is_directory: true,
                 is_symlink: false,
+                link_target: None,
                 size: 0,
                 modified: Local::now(),
                 permissions: String::new(),
@@ -1085,6 +1762,7 @@ impl PanelState {
                         display_name: None,
                         is_directory: entry.is_directory,
                         is_symlink: entry.is_symlink,
+                        link_target: None,
                         size: if entry.is_directory { 0 } else { entry.size },
                         modified: entry.modified,
                         permissions: entry.permissions,
@@ -1126,6 +1804,7 @@ impl PanelState {
                 display_name: None,
                 is_directory: true,
                 is_symlink: false,
+                link_target: None,
                 size: 0,
                 modified: Local::now(),
                 permissions: String::new(),
@@ -1139,6 +1818,7 @@ impl PanelState {
                 display_name: None,
                 is_directory: entry.is_directory,
                 is_symlink: entry.is_symlink,
+                link_target: None,
                 size: if entry.is_directory { 0 } else { entry.size },
                 modified: entry.modified,
                 permissions: entry.permissions,
@@ -1193,6 +1873,31 @@ impl PanelState {
 
     /// Finalize file loading (handle focus and bounds)
     fn finalize_load(&mut self) {
+        // 숨김 파일 제외 (".."은 항상 유지)
+        if self.show_hidden {
+            self.hidden_count = 0;
+        } else {
+            let before = self.files.len();
+            self.files.retain(|f| f.name == ".." || !f.name.starts_with('.'));
+            self.hidden_count = before - self.files.len();
+        }
+
+        // 같은 디렉토리 재로드면 빠른 필터를 새 목록에 다시 적용, 다른 디렉토리면 해제
+        if let Some(filter) = self.quick_filter.take() {
+            if filter.path == self.path {
+                let all_files = std::mem::take(&mut self.files);
+                self.quick_filter = Some(QuickFilter { all_files, ..filter });
+                self.filter_files();
+            }
+        }
+
+        // The list changed: refresh whole-list figures and drop formatted rows
+        self.list_stats = super::panel::ListStats::compute(&self.files);
+        self.row_cache.clear();
+        self.dir_sizes.clear();
+        self.dir_sizes_stale = true;
+        self.git_badges_stale = true;
+
         // Handle pending focus (when going to parent directory)
         if let Some(focus_name) = self.pending_focus.take() {
             if let Some(idx) = self.files.iter().position(|f| f.name == focus_name) {
@@ -1206,35 +1911,70 @@ impl PanelState {
         }
     }
 
-    fn update_disk_info(&mut self) {
-        if self.is_remote() {
-            self.disk_total = 0;
-            self.disk_available = 0;
-            return;
+    /// 빠른 필터 시작 (이미 켜져 있으면 그대로)
+    pub fn start_quick_filter(&mut self) {
+        if self.quick_filter.is_none() {
+            self.quick_filter = Some(QuickFilter {
+                text: String::new(),
+                all_files: self.files.clone(),
+                path: self.path.clone(),
+            });
         }
+    }
 
-        #[cfg(unix)]
-        {
-            use std::ffi::CString;
-            use std::mem::MaybeUninit;
-
-            if let Some(path_str) = self.path.to_str() {
-                if let Ok(c_path) = CString::new(path_str) {
-                    let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
-                    // SAFETY: statvfs is a standard POSIX function, c_path is valid
-                    let result = unsafe { libc::statvfs(c_path.as_ptr(), stat.as_mut_ptr()) };
-                    if result == 0 {
-                        // SAFETY: statvfs succeeded, stat is initialized
-                        let stat = unsafe { stat.assume_init() };
-                        self.disk_total = stat.f_blocks as u64 * stat.f_frsize as u64;
-                        self.disk_available = stat.f_bavail as u64 * stat.f_frsize as u64;
-                        return;
-                    }
+    /// 필터 문자열 변경 후 목록 갱신 (커서는 가능하면 같은 파일에 유지)
+    pub fn set_quick_filter_text(&mut self, text: String) {
+        let Some(ref mut filter) = self.quick_filter else { return };
+        filter.text = text;
+        let current = self.current_file().map(|f| f.name.clone());
+        self.filter_files();
+        self.refresh_after_filter(current);
+    }
+
+    /// 빠른 필터 해제: 전체 목록 복원 (선택 표시는 그대로)
+    pub fn clear_quick_filter(&mut self) {
+        let Some(filter) = self.quick_filter.take() else { return };
+        let current = self.current_file().map(|f| f.name.clone());
+        self.files = filter.all_files;
+        self.refresh_after_filter(current);
+    }
+
+    /// `files` = 필터 문자열에 fuzzy 매칭되는 항목 (빈 문자열이면 전체)
+    fn filter_files(&mut self) {
+        let Some(ref filter) = self.quick_filter else { return };
+        let pattern = filter.text.to_lowercase();
+        self.files = filter.all_files.iter()
+            .filter(|f| {
+                if pattern.is_empty() {
+                    true
+                } else {
+                    f.name != ".." && fuzzy_match(&f.name.to_lowercase(), &pattern)
                 }
-            }
+            })
+            .cloned()
+            .collect();
+    }
+
+    fn refresh_after_filter(&mut self, current: Option<String>) {
+        self.list_stats = super::panel::ListStats::compute(&self.files);
+        self.row_cache.clear();
+        self.dir_sizes_stale = true;
+        self.selected_index = current
+            .and_then(|name| self.files.iter().position(|f| f.name == name))
+            .unwrap_or(0)
+            .min(self.files.len().saturating_sub(1));
+    }
+
+    fn update_disk_info(&mut self) {
+        if self.is_remote() {
+            self.disk_total = 0;
+            self.disk_available = 0;
+            return;
         }
-        self.disk_total = 0;
-        self.disk_available = 0;
+
+        let space = crate::services::disks::space(&self.path).unwrap_or_default();
+        self.disk_total = space.total;
+        self.disk_available = space.available;
     }
 
     pub fn current_file(&self) -> Option<&FileItem> {
@@ -1253,7 +1993,10 @@ impl PanelState {
         }
         self.selected_index = 0;
         if self.is_remote() {
-            // Re-sort existing items locally (no network call)
+            // Re-sort existing items locally (no network call); a quick filter is re-applied after
+            if let Some(ref mut filter) = self.quick_filter {
+                self.files = std::mem::take(&mut filter.all_files);
+            }
             let mut items: Vec<FileItem> = self.files.drain(..)
                 .filter(|f| f.name != "..")
                 .collect();
@@ -1265,6 +2008,7 @@ impl PanelState {
                     display_name: None,
                     is_directory: true,
                     is_symlink: false,
+                    link_target: None,
                     size: 0,
                     modified: Local::now(),
                     permissions: String::new(),
@@ -1273,7 +2017,10 @@ impl PanelState {
             self.sort_items(&mut items);
             self.files.reserve(items.len());
             self.files.extend(items);
+            // Dotfiles were already left out by the last listing
+            let hidden_count = self.hidden_count;
             self.finalize_load();
+            self.hidden_count = hidden_count;
         } else {
             self.load_files();
         }
@@ -1303,6 +2050,12 @@ pub struct App {
     // Design mode flag (--design): enables theme hot-reload
     pub design_mode: bool,
 
+    // Terminal window focus (FocusGained/FocusLost events)
+    pub terminal_focused: bool,
+
+    // Pager mode flag (--view): quit the app when the viewer is closed
+    pub view_only: bool,
+
     // Keybindings (built from settings)
     pub keybindings: Keybindings,
 
@@ -1358,6 +2111,12 @@ pub struct App {
     pub ai_state: Option<crate::ui::ai_screen::AIScreenState>,
     pub ai_panel_index: Option<usize>,  // AI가 표시될 패널 인덱스
     pub ai_previous_panel: Option<usize>,  // AI 화면 띄우기 전 포커스 인덱스
+    /// 화면에 보이지 않는 AI 대화들 (백그라운드 실행 또는 전환 대기)
+    pub ai_conversations: Vec<crate::ui::ai_screen::AIScreenState>,
+    /// 마지막으로 부여한 대화 번호
+    pub ai_conversation_seq: usize,
+    /// 저장 다이얼로그에서 기다리는 AI 응답 코드 블록
+    pub ai_pending_code: Option<String>,
 
     // System info state
     pub system_info_state: crate::ui::system_info::SystemInfoState,
@@ -1382,6 +2141,8 @@ pub struct App {
 
     // Search result state (재귀 검색 결과)
     pub search_result_state: crate::ui::search_result::SearchResultState,
+    // Find File 다이얼로그 옵션 (이름/내용, 정규식, 대소문자)
+    pub search_options: crate::ui::search_result::SearchOptions,
 
     // Track previous screen for back navigation
     pub previous_screen: Option<Screen>,
@@ -1391,12 +2152,22 @@ pub struct App {
 
     // File operation progress state
     pub file_operation_progress: Option<FileOperationProgress>,
+    /// Operations sent to the background (Jobs pane)
+    pub jobs: crate::ui::jobs::JobsState,
+    /// Background directory size indexer (running while settings.index_dir_sizes is on)
+    pub dir_size_indexer: Option<crate::services::dir_sizes::Indexer>,
+    /// Background `git status` runner for the panel badges (settings.git_status_badges)
+    pub git_status_fetcher: Option<crate::services::git_status::Fetcher>,
 
     // Pending tar archive name (for focusing after completion)
     pub pending_tar_archive: Option<String>,
+    // Archive profile selected in the Create Archive dialog (index into settings.archive_profiles)
+    pub tar_profile: Option<usize>,
 
     // Pending extract directory name (for focusing after completion)
     pub pending_extract_dir: Option<String>,
+    /// Last condition entered in the select-by-filter dialog
+    pub last_select_filter: String,
 
     // Pending paste focus names (for focusing on first pasted file after completion)
     pub pending_paste_focus: Option<Vec<String>>,
@@ -1426,15 +2197,59 @@ pub struct App {
 
     // Dedup screen state
     pub dedup_screen_state: Option<crate::ui::dedup_screen::DedupScreenState>,
+    // 중복 제거 시 .gitignore/.ignore로 무시된 경로 건너뛰기 (확인 다이얼로그에서 'g'로 전환)
+    pub dedup_respect_ignore: bool,
+
+    // Checksum screen state
+    pub checksum_state: Option<crate::ui::checksum_screen::ChecksumScreenState>,
+
+    // Bot audit log viewer state
+    pub audit_screen_state: Option<crate::ui::audit_screen::AuditScreenState>,
+    pub archive_viewer_state: Option<crate::ui::archive_viewer::ArchiveViewerState>,
+    pub trash_screen_state: Option<crate::ui::trash_screen::TrashScreenState>,
+    pub bulk_rename_state: Option<crate::ui::bulk_rename::BulkRenameState>,
+    pub drives_screen_state: Option<crate::ui::drives_screen::DrivesScreenState>,
+    pub recovery_screen_state: Option<crate::ui::recovery_screen::RecoveryScreenState>,
+    pub cleanup_screen_state: Option<crate::ui::cleanup_screen::CleanupScreenState>,
+    pub backup_screen_state: Option<crate::ui::backup_screen::BackupScreenState>,
+    pub timeline_screen_state: Option<crate::ui::timeline_screen::TimelineScreenState>,
+    pub git_log_screen_state: Option<crate::ui::git_log_screen::GitLogScreenState>,
+    /// 크래시 저널에 백업 중인 편집기 파일과 마지막 백업 시각
+    journaled_buffer: Option<(PathBuf, Instant)>,
+    /// 마지막 확장자 일괄 변경 (폴더, (이전 이름, 새 이름)) - Ctrl+Z로 되돌림
+    last_extension_change: Option<(PathBuf, Vec<(String, String)>)>,
+    /// Quick view: 다른 패널 자리에 커서 파일 미리보기
+    pub preview_pane: Option<crate::ui::preview_pane::PreviewPane>,
+    /// 왼쪽 가장자리의 디렉토리 트리 (None이면 숨김)
+    pub tree_sidebar: Option<crate::ui::tree_sidebar::TreeSidebar>,
 
     // Git log diff state
     pub git_log_diff_state: Option<GitLogDiffState>,
+    pub open_with_state: Option<OpenWithState>,
+    pub recent_files_state: Option<RecentFilesState>,
+    pub jump_state: Option<JumpState>,
+    pub breadcrumb_state: Option<BreadcrumbState>,
+    pub favorites_state: Option<FavoritesState>,
+    /// Advanced search criteria waiting for a smart folder name
+    pub pending_smart_folder: Option<crate::ui::advanced_search::SearchCriteria>,
+    /// Local files collected from any directory for one copy/move/zip
+    pub basket: Vec<PathBuf>,
+    pub basket_state: Option<BasketState>,
+    /// 패널별로 마지막으로 frecency에 기록한 폴더 (같은 폴더 새로고침은 방문이 아님)
+    last_visited_dirs: Vec<PathBuf>,
+    pub image_export_state: Option<ImageExportState>,
 
     // Pending remote download → open action
     pub pending_remote_open: Option<PendingRemoteOpen>,
 
     // Remote operation spinner (SSH/SFTP background task)
     pub remote_spinner: Option<RemoteSpinner>,
+
+    // Unix socket control interface (None if the socket could not be created)
+    pub ipc_server: Option<crate::services::ipc::IpcServer>,
+
+    // Background watcher for settings.watch_rules (None if there are no rules)
+    pub watch_service: Option<crate::services::watch::WatchService>,
 }
 
 impl App {
@@ -1451,6 +2266,8 @@ impl App {
             theme: crate::ui::theme::Theme::default(),
             theme_watch_state: ThemeWatchState::watch_theme(DEFAULT_THEME_NAME),
             design_mode: false,
+            terminal_focused: true,
+            view_only: false,
             keybindings: Keybindings::from_config(&crate::keybindings::KeybindingsConfig::default()),
 
             // 새로운 고급 상태
@@ -1484,6 +2301,9 @@ impl App {
             process_force_kill: false,
 
             ai_state: None,
+            ai_conversations: Vec::new(),
+            ai_conversation_seq: 0,
+            ai_pending_code: None,
             ai_panel_index: None,
             ai_previous_panel: None,
             system_info_state: crate::ui::system_info::SystemInfoState::default(),
@@ -1494,12 +2314,18 @@ impl App {
             pending_large_file: None,
             pending_binary_file: None,
             search_result_state: crate::ui::search_result::SearchResultState::default(),
+            search_options: crate::ui::search_result::SearchOptions::default(),
             previous_screen: None,
             clipboard: None,
             file_operation_progress: None,
             pending_tar_archive: None,
+            tar_profile: None,
             pending_extract_dir: None,
+            last_select_filter: String::new(),
             pending_paste_focus: None,
+            jobs: crate::ui::jobs::JobsState::default(),
+            dir_size_indexer: None,
+            git_status_fetcher: None,
             conflict_state: None,
             tar_exclude_state: None,
             help_state: HelpState::default(),
@@ -1510,9 +2336,37 @@ impl App {
             diff_file_view_state: None,
             git_screen_state: None,
             dedup_screen_state: None,
+            dedup_respect_ignore: false,
+            checksum_state: None,
+            audit_screen_state: None,
+            archive_viewer_state: None,
+            trash_screen_state: None,
+            bulk_rename_state: None,
+            drives_screen_state: None,
+            recovery_screen_state: None,
+            cleanup_screen_state: None,
+            backup_screen_state: None,
+            timeline_screen_state: None,
+            git_log_screen_state: None,
+            journaled_buffer: None,
+            last_extension_change: None,
+            preview_pane: None,
+            tree_sidebar: None,
             git_log_diff_state: None,
+            open_with_state: None,
+            recent_files_state: None,
+            jump_state: None,
+            breadcrumb_state: None,
+            favorites_state: None,
+            pending_smart_folder: None,
+            basket: Vec::new(),
+            basket_state: None,
+            last_visited_dirs: Vec::new(),
+            image_export_state: None,
             pending_remote_open: None,
             remote_spinner: None,
+            ipc_server: None,
+            watch_service: None,
         }
     }
 
@@ -1553,6 +2407,8 @@ impl App {
             theme,
             theme_watch_state,
             design_mode: false,
+            terminal_focused: true,
+            view_only: false,
             keybindings,
 
             // 새로운 고급 상태
@@ -1586,6 +2442,9 @@ impl App {
             process_force_kill: false,
 
             ai_state: None,
+            ai_conversations: Vec::new(),
+            ai_conversation_seq: 0,
+            ai_pending_code: None,
             ai_panel_index: None,
             ai_previous_panel: None,
             system_info_state: crate::ui::system_info::SystemInfoState::default(),
@@ -1596,12 +2455,18 @@ impl App {
             pending_large_file: None,
             pending_binary_file: None,
             search_result_state: crate::ui::search_result::SearchResultState::default(),
+            search_options: crate::ui::search_result::SearchOptions::default(),
             previous_screen: None,
             clipboard: None,
             file_operation_progress: None,
             pending_tar_archive: None,
+            tar_profile: None,
             pending_extract_dir: None,
+            last_select_filter: String::new(),
             pending_paste_focus: None,
+            jobs: crate::ui::jobs::JobsState::default(),
+            dir_size_indexer: None,
+            git_status_fetcher: None,
             conflict_state: None,
             tar_exclude_state: None,
             help_state: HelpState::default(),
@@ -1612,9 +2477,37 @@ impl App {
             diff_file_view_state: None,
             git_screen_state: None,
             dedup_screen_state: None,
+            dedup_respect_ignore: false,
+            checksum_state: None,
+            audit_screen_state: None,
+            archive_viewer_state: None,
+            trash_screen_state: None,
+            bulk_rename_state: None,
+            drives_screen_state: None,
+            recovery_screen_state: None,
+            cleanup_screen_state: None,
+            backup_screen_state: None,
+            timeline_screen_state: None,
+            git_log_screen_state: None,
+            journaled_buffer: None,
+            last_extension_change: None,
+            preview_pane: None,
+            tree_sidebar: None,
             git_log_diff_state: None,
+            open_with_state: None,
+            recent_files_state: None,
+            jump_state: None,
+            breadcrumb_state: None,
+            favorites_state: None,
+            pending_smart_folder: None,
+            basket: Vec::new(),
+            basket_state: None,
+            last_visited_dirs: Vec::new(),
+            image_export_state: None,
             pending_remote_open: None,
             remote_spinner: None,
+            ipc_server: None,
+            watch_service: None,
         }
     }
 
@@ -1641,6 +2534,7 @@ impl App {
                 start_path: Some(path),
                 sort_by: sort_by_to_string(p.sort_by),
                 sort_order: sort_order_to_string(p.sort_order),
+                show_hidden: p.show_hidden,
             }
         }).collect();
         self.settings.active_panel_index = self.active_panel_index;
@@ -1682,9 +2576,37 @@ impl App {
 
         // Update tar_path setting
         self.settings.tar_path = new_settings.tar_path;
+        self.settings.archive_profiles = new_settings.archive_profiles;
+        self.settings.paste_exclude = new_settings.paste_exclude;
+        self.settings.conflict_policy = new_settings.conflict_policy;
+        self.settings.ai_approval = new_settings.ai_approval;
+        self.settings.ai_pane = new_settings.ai_pane;
 
         // Update extension_handler setting
         self.settings.extension_handler = new_settings.extension_handler;
+        self.settings.open_with_history = new_settings.open_with_history;
+        self.settings.notifications = new_settings.notifications;
+        self.settings.restore_session = new_settings.restore_session;
+        self.settings.delete_to_trash = new_settings.delete_to_trash;
+        self.settings.index_dir_sizes = new_settings.index_dir_sizes;
+        self.settings.git_status_badges = new_settings.git_status_badges;
+        self.settings.email = new_settings.email;
+
+        // Re-format panel rows if sizes/dates are shown differently now
+        if new_settings.format != self.settings.format {
+            self.settings.format = new_settings.format;
+            crate::utils::format::configure(&self.settings.format);
+            for panel in &mut self.panels {
+                panel.row_cache.clear();
+            }
+        }
+
+        // Restart the folder watcher if its rules changed
+        let mut watch_error = None;
+        if new_settings.watch_rules != self.settings.watch_rules {
+            self.settings.watch_rules = new_settings.watch_rules;
+            watch_error = self.start_watch_service();
+        }
 
         // Update diff compare method
         self.settings.diff_compare_method = new_settings.diff_compare_method;
@@ -1694,9 +2616,16 @@ impl App {
         self.settings.keybindings = new_settings.keybindings;
 
         // Update settings
+        if new_settings.theme.text_markers != self.settings.theme.text_markers {
+            crate::ui::theme::set_text_markers(new_settings.theme.text_markers);
+        }
         self.settings.theme = new_settings.theme;
         self.settings.panels = new_settings.panels;
 
+        if let Some(err) = watch_error {
+            self.show_message(&err);
+            return false;
+        }
         self.show_message("Settings reloaded");
         true
     }
@@ -1737,9 +2666,20 @@ impl App {
                 self.theme_watch_state.update_theme(&new_theme_name);
             }
 
+            self.settings.theme.text_markers = state.text_markers;
+
             // Update diff compare method
             let new_diff_method = state.current_diff_method().to_string();
             self.settings.diff_compare_method = new_diff_method;
+            self.settings.conflict_policy = state.conflict_policy;
+
+            // 내장 동작 매핑 교체 (외부 명령 핸들러는 유지)
+            for (ext, _) in self.settings.builtin_extension_actions() {
+                self.settings.extension_handler.remove(&ext);
+            }
+            for (ext, action) in &state.ext_actions {
+                self.settings.extension_handler.insert(ext.clone(), vec![action.handler()]);
+            }
 
             // Save settings
             let _ = self.settings.save();
@@ -1754,6 +2694,7 @@ impl App {
     pub fn cancel_settings_dialog(&mut self) {
         // Restore original theme if it was changed during preview
         self.theme = crate::ui::theme::Theme::load(&self.settings.theme.name);
+        crate::ui::theme::set_text_markers(self.settings.theme.text_markers);
         self.settings_state = None;
         self.dialog = None;
     }
@@ -1857,12 +2798,9 @@ impl App {
         let removed_idx = self.active_panel_index;
         // AI가 이 패널에 있으면 AI 상태만 직접 정리 (close_ai_screen은 active_panel_index를 변경하므로 사용하지 않음)
         if self.ai_panel_index == Some(removed_idx) {
-            if let Some(ref mut state) = self.ai_state {
-                state.save_session_to_file();
-            }
             self.ai_panel_index = None;
             self.ai_previous_panel = None;
-            self.ai_state = None;
+            self.release_ai_state();
         }
         self.panels.remove(removed_idx);
         // AI 인덱스 보정
@@ -1886,6 +2824,21 @@ impl App {
         panel.selected_index = new_index;
     }
 
+    /// Move cursor and view together by 1/`divisor` of the visible list (half/third page)
+    pub fn scroll_page_fraction(&mut self, divisor: usize, forward: bool) {
+        let panel = self.active_panel_mut();
+        let step = (panel.visible_height / divisor.max(1)).max(1);
+        let last = panel.files.len().saturating_sub(1);
+        if forward {
+            panel.selected_index = (panel.selected_index + step).min(last);
+            // Clamped to the list end when drawing
+            panel.scroll_offset += step;
+        } else {
+            panel.selected_index = panel.selected_index.saturating_sub(step);
+            panel.scroll_offset = panel.scroll_offset.saturating_sub(step);
+        }
+    }
+
     pub fn cursor_to_start(&mut self) {
         self.active_panel_mut().selected_index = 0;
     }
@@ -1973,13 +2926,13 @@ impl App {
                         panel.path = parent.to_path_buf();
                         panel.selected_index = 0;
                         panel.selected_files.clear();
-                        panel.load_files();
+                        panel.load_files_cached();
                     }
                 } else {
                     panel.path = panel.path.join(&file.name);
                     panel.selected_index = 0;
                     panel.selected_files.clear();
-                    panel.load_files();
+                    panel.load_files_cached();
                 }
             } else {
                 // 원격 파일: 이미지는 뷰어, 나머지는 편집기 (프로그레스 표시)
@@ -2023,9 +2976,13 @@ impl App {
                 }
 
                 // Default behavior: check file type
-                if Self::is_archive_file(&file.name) {
-                    // It's an archive file - extract it
-                    self.execute_untar(&path);
+                if crate::services::archive::ArchiveKind::from_name(&file.name).is_some() {
+                    // It's an archive file - browse its contents
+                    self.show_archive_viewer(path);
+                    return;
+                }
+                if file.name.to_lowercase().ends_with(".7z") {
+                    self.execute_unzip(&path);
                     return;
                 }
 
@@ -2116,18 +3073,6 @@ impl App {
         }
     }
 
-    /// Check if a file is a supported archive format
-    fn is_archive_file(filename: &str) -> bool {
-        let lower = filename.to_lowercase();
-        lower.ends_with(".tar")
-            || lower.ends_with(".tar.gz")
-            || lower.ends_with(".tgz")
-            || lower.ends_with(".tar.bz2")
-            || lower.ends_with(".tbz2")
-            || lower.ends_with(".tar.xz")
-            || lower.ends_with(".txz")
-    }
-
     /// Check if a file is binary (not a text file)
     /// Reads the first 8KB of the file and checks for null bytes or high proportion of non-text bytes
     fn is_binary_file(path: &std::path::Path) -> bool {
@@ -2205,6 +3150,20 @@ impl App {
 
         // Try each handler in order (fallback mechanism)
         for handler_template in &handlers {
+            // Built-in action (":view", ":edit", ...)
+            if handler_template.starts_with(':') {
+                match crate::config::BuiltinAction::from_handler(handler_template) {
+                    Some(action) => {
+                        self.run_builtin_action(action, path);
+                        return Ok(true);
+                    }
+                    None => {
+                        last_error = format!("Unknown built-in action: {}", handler_template);
+                        continue;
+                    }
+                }
+            }
+
             // Check for background mode prefix (@)
             let (is_background_mode, template) = if handler_template.starts_with('@') {
                 (true, &handler_template[1..])
@@ -2254,9 +3213,63 @@ impl App {
         Err(last_error)
     }
 
+    /// extension_handler의 내장 동작 실행 (path는 커서 위치 파일)
+    fn run_builtin_action(&mut self, action: crate::config::BuiltinAction, path: &std::path::Path) {
+        use crate::config::BuiltinAction;
+        match action {
+            BuiltinAction::View => {
+                // 이미지도 텍스트/hex 뷰어로 연다
+                let mut viewer = ViewerState::new();
+                viewer.set_syntax_colors(self.theme.syntax);
+                match viewer.load_file(path) {
+                    Ok(_) => {
+                        self.restore_viewer_position(&mut viewer, path);
+                        self.viewer_state = Some(viewer);
+                        self.current_screen = Screen::FileViewer;
+                    }
+                    Err(e) => self.show_message(&format!("Cannot read file: {}", e)),
+                }
+            }
+            BuiltinAction::Edit => self.edit_file(),
+            BuiltinAction::Image => {
+                self.image_viewer_state = Some(crate::ui::image_viewer::ImageViewerState::new(path));
+                self.current_screen = Screen::ImageViewer;
+            }
+            BuiltinAction::Extract => self.execute_extract(path),
+            BuiltinAction::Info => self.show_file_info(),
+        }
+    }
+
     /// Execute a command in terminal mode (blocking, inherits stdio)
     /// Suspends the TUI, runs the command, then restores the TUI
     fn execute_terminal_command(&mut self, command: &str, cwd: &std::path::Path) -> Result<bool, String> {
+        // Execute command with inherited stdio and active panel's directory as CWD
+        // Use base64 encoding to avoid shell escaping issues
+        let encoded = encode_command_base64(command);
+        let exe_path = std::env::current_exe()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|_| "cokacdir".to_string());
+        let wrapped_command = format!("eval \"$('{}' --base64 '{}')\"", exe_path, encoded);
+
+        let result = self.with_suspended_terminal(|| {
+            std::process::Command::new("bash")
+                .arg("-c")
+                .arg(&wrapped_command)
+                .current_dir(cwd)
+                .stdin(std::process::Stdio::inherit())
+                .stdout(std::process::Stdio::inherit())
+                .stderr(std::process::Stdio::inherit())
+                .status()
+        });
+
+        match result {
+            Ok(status) => Ok(status.success()),
+            Err(e) => Err(format!("Failed to execute: {}", e)),
+        }
+    }
+
+    /// Hand the terminal to `f` (leave the alternate screen and raw mode), then restore the TUI
+    fn with_suspended_terminal<R>(&mut self, f: impl FnOnce() -> R) -> R {
         use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
         use crossterm::cursor::{Hide, Show};
         use crossterm::execute;
@@ -2271,22 +3284,7 @@ impl App {
         print!("\x1B[2J\x1B[H");
         let _ = stdout().flush();
 
-        // Execute command with inherited stdio and active panel's directory as CWD
-        // Use base64 encoding to avoid shell escaping issues
-        let encoded = encode_command_base64(command);
-        let exe_path = std::env::current_exe()
-            .map(|p| p.display().to_string())
-            .unwrap_or_else(|_| "cokacdir".to_string());
-        let wrapped_command = format!("eval \"$('{}' --base64 '{}')\"", exe_path, encoded);
-
-        let result = std::process::Command::new("bash")
-            .arg("-c")
-            .arg(&wrapped_command)
-            .current_dir(cwd)
-            .stdin(std::process::Stdio::inherit())
-            .stdout(std::process::Stdio::inherit())
-            .stderr(std::process::Stdio::inherit())
-            .status();
+        let result = f();
 
         // Restore: enable raw mode, enter alternate screen, hide cursor
         let _ = enable_raw_mode();
@@ -2294,10 +3292,37 @@ impl App {
 
         // Request full redraw on next frame
         self.needs_full_redraw = true;
+        result
+    }
 
-        match result {
-            Ok(status) => Ok(status.success()),
-            Err(e) => Err(format!("Failed to execute: {}", e)),
+    /// Save the editor buffer as root (sudo or pkexec prompts in the terminal)
+    pub fn save_editor_elevated(&mut self) {
+        use crate::services::elevate;
+
+        let Some(editor) = self.editor_state.as_ref() else { return };
+        let path = editor.file_path.clone();
+        let content = editor.lines.join("\n");
+        let Some(tool) = elevate::tool() else {
+            if let Some(ref mut editor) = self.editor_state {
+                editor.set_message("Neither sudo nor pkexec is available", 50);
+            }
+            return;
+        };
+
+        let result = self.with_suspended_terminal(|| {
+            println!("Saving {} with {}...", path.display(), tool);
+            elevate::write_file(tool, &path, content.as_bytes())
+        });
+
+        if let Some(ref mut editor) = self.editor_state {
+            match result {
+                Ok(()) => {
+                    editor.mark_saved();
+                    editor.pending_exit = false;
+                    editor.set_message(format!("File saved with {}!", tool), 30);
+                }
+                Err(e) => editor.set_message(format!("Save error: {}", e), 50),
+            }
         }
     }
 
@@ -2445,7 +3470,7 @@ impl App {
             panel.path = parent.to_path_buf();
             panel.selected_index = 0;
             panel.selected_files.clear();
-            panel.load_files();
+            panel.load_files_cached();
         }
     }
 
@@ -2461,7 +3486,47 @@ impl App {
             panel.path = home;
             panel.selected_index = 0;
             panel.selected_files.clear();
-            panel.load_files();
+            panel.load_files_cached();
+        }
+    }
+
+    /// Open the current file in $EDITOR (edit = true) or a shell in a new
+    /// tmux/zellij pane or window, starting in the active panel's directory
+    pub fn open_in_multiplexer(&mut self, target: crate::services::multiplexer::PaneTarget, edit: bool) {
+        use crate::services::multiplexer::{self, Multiplexer, PaneTarget};
+
+        let Some(mux) = Multiplexer::detect() else {
+            self.show_message("Not running inside tmux or zellij");
+            return;
+        };
+        if self.active_panel().is_remote() {
+            self.show_message("Not available for remote panels");
+            return;
+        }
+        let panel = self.active_panel();
+        let cwd = panel.path.clone();
+        let file = if edit {
+            match panel.files.get(panel.selected_index) {
+                Some(f) if !f.is_directory => Some(cwd.join(&f.name)),
+                _ => {
+                    self.show_message("Select a file to edit");
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+
+        match multiplexer::open(mux, target, &cwd, file.as_deref()) {
+            Ok(()) => {
+                let place = match target {
+                    PaneTarget::Split => "split",
+                    PaneTarget::Window => "new window",
+                };
+                let what = if file.is_some() { "Editing" } else { "Shell" };
+                self.show_message(&format!("{} in {} {}", what, mux.name(), place));
+            }
+            Err(e) => self.show_message(&e),
         }
     }
 
@@ -2544,6 +3609,70 @@ impl App {
         }
     }
 
+    /// 선택 반전 (.. 제외)
+    pub fn invert_selection(&mut self) {
+        let panel = self.active_panel_mut();
+        let names: Vec<String> = panel.files.iter()
+            .filter(|f| f.name != "..")
+            .map(|f| f.name.clone())
+            .collect();
+        for name in names {
+            if !panel.selected_files.remove(&name) {
+                panel.selected_files.insert(name);
+            }
+        }
+        let count = panel.selected_files.len();
+        self.show_message(&format!("{} item(s) selected", count));
+    }
+
+    /// 수정 시간/크기 조건으로 선택하는 입력 다이얼로그
+    pub fn show_select_filter_dialog(&mut self) {
+        let input = self.last_select_filter.clone();
+        let cursor_pos = input.chars().count();
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::SelectFilter,
+            input,
+            cursor_pos,
+            message: "newer 7d | older 30d | larger 10M | smaller 1k".to_string(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// 계산기 / 바이트 단위 변환 다이얼로그
+    pub fn show_calculator_dialog(&mut self) {
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::Calculator,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// 조건에 맞는 파일을 기존 선택에 추가
+    pub fn execute_select_filter(&mut self, input: &str) {
+        let Some(filter) = SelectFilter::parse(input) else {
+            self.show_message("Usage: newer 7d | older 30d | larger 10M | smaller 1k");
+            return;
+        };
+        self.last_select_filter = input.trim().to_string();
+        let now = Local::now();
+        let panel = self.active_panel_mut();
+        let matching: Vec<String> = panel.files.iter()
+            .filter(|f| f.name != ".." && filter.matches(f, now))
+            .map(|f| f.name.clone())
+            .collect();
+        let count = matching.len();
+        panel.selected_files.extend(matching);
+        self.show_message(&format!("Selected {} file(s) ({})", count, input.trim()));
+    }
+
     pub fn select_by_extension(&mut self) {
         let panel = self.active_panel_mut();
         if let Some(current_file) = panel.files.get(panel.selected_index) {
@@ -2610,24 +3739,30 @@ impl App {
         self.message_timer = 10; // ~1 second at 10 FPS
     }
 
-    /// Toggle bookmark for the current panel's path
-    pub fn toggle_bookmark(&mut self) {
-        let current_path = if self.active_panel().is_remote() {
-            let path = self.active_panel().path.display().to_string();
-            if let Some(ref ctx) = self.active_panel().remote_ctx {
-                remote::format_remote_display(&ctx.profile, &path)
-            } else if let Some((ref user, ref host, port)) = self.active_panel().remote_display {
-                if port != 22 {
-                    format!("{}@{}:{}:{}", user, host, port, path)
-                } else {
-                    format!("{}@{}:{}", user, host, path)
-                }
+    /// The active panel's path as stored in bookmarks and favorites
+    /// (`user@host:/path` for remote panels)
+    pub fn current_location(&self) -> Option<String> {
+        let panel = self.active_panel();
+        let path = panel.path.display().to_string();
+        if !panel.is_remote() {
+            return Some(path);
+        }
+        if let Some(ref ctx) = panel.remote_ctx {
+            Some(remote::format_remote_display(&ctx.profile, &path))
+        } else if let Some((ref user, ref host, port)) = panel.remote_display {
+            if port != 22 {
+                Some(format!("{}@{}:{}:{}", user, host, port, path))
             } else {
-                return;
+                Some(format!("{}@{}:{}", user, host, path))
             }
         } else {
-            self.active_panel().path.display().to_string()
-        };
+            None
+        }
+    }
+
+    /// Toggle bookmark for the current panel's path
+    pub fn toggle_bookmark(&mut self) {
+        let Some(current_path) = self.current_location() else { return };
 
         if let Some(pos) = self.settings.bookmarked_path.iter().position(|p| p == &current_path) {
             self.settings.bookmarked_path.remove(pos);
@@ -2641,6 +3776,9 @@ impl App {
     }
 
     pub fn refresh_panels(&mut self) {
+        // Refresh is the invalidation point after our own file operations
+        crate::services::dir_cache::clear();
+
         // Check if any panel is remote and needs async refresh
         let mut remote_panel_idx = None;
         for (i, panel) in self.panels.iter_mut().enumerate() {
@@ -2663,6 +3801,29 @@ impl App {
         }
     }
 
+    /// Reload only the panels showing one of `dirs`. Unlike `refresh_panels`,
+    /// marks are kept, so a background change does not disturb other work
+    pub fn refresh_panels_showing(&mut self, dirs: &[PathBuf]) {
+        let mut remote_panel_idx = None;
+        for (i, panel) in self.panels.iter_mut().enumerate() {
+            if !dirs.iter().any(|dir| *dir == panel.path) {
+                continue;
+            }
+            if panel.is_remote() {
+                if panel.remote_ctx.is_some() && remote_panel_idx.is_none() {
+                    remote_panel_idx = Some(i);
+                }
+            } else {
+                panel.reload_keep_marks();
+            }
+        }
+        if let Some(idx) = remote_panel_idx {
+            if self.remote_spinner.is_none() {
+                self.spawn_remote_refresh(idx);
+            }
+        }
+    }
+
     /// Start diff comparison between panels
     /// With 2 panels: immediately enter diff screen
     /// With 3+ panels: first call selects first panel, second call selects second panel
@@ -2736,11 +3897,90 @@ impl App {
         self.current_screen = Screen::DiffScreen;
     }
 
+    /// Run a Sync mode plan through the progress pipeline; the diff screen
+    /// compares again once it finishes
+    pub fn execute_dir_sync(&mut self, ops: Vec<crate::services::dir_sync::SyncOp>) {
+        if ops.is_empty() {
+            if let Some(sync) = self.diff_state.as_mut().and_then(|s| s.sync.as_mut()) {
+                sync.preview = None;
+            }
+            return;
+        }
+        if self.file_operation_progress.is_some() { return; }
+
+        let mut progress = FileOperationProgress::new(FileOperationType::Sync);
+        progress.is_active = true;
+        if let Some(ref state) = self.diff_state {
+            progress.dirs = vec![state.left_root.clone(), state.right_root.clone()];
+        }
+        let cancel_flag = progress.cancel_flag.clone();
+
+        let (tx, rx) = mpsc::channel();
+        progress.receiver = Some(rx);
+
+        file_ops::spawn_worker(tx, None, move |tx| {
+            crate::services::dir_sync::execute(ops, cancel_flag, tx);
+        });
+
+        self.file_operation_progress = Some(progress);
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::Progress,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
     /// Enter file content diff view from the diff screen
     pub fn enter_diff_file_view(&mut self, left_path: PathBuf, right_path: PathBuf, file_name: String) {
-        self.diff_file_view_state = Some(
-            crate::ui::diff_file_view::DiffFileViewState::new(left_path, right_path, file_name)
-        );
+        let mut state = crate::ui::diff_file_view::DiffFileViewState::new(left_path, right_path, file_name);
+        state.create_image_protocols(self.image_picker.as_mut());
+        self.diff_file_view_state = Some(state);
+        self.current_screen = Screen::DiffFileView;
+    }
+
+    /// 커서 위치 파일과 클립보드 텍스트 비교 (클립보드 내용은 임시 파일로 저장)
+    pub fn compare_with_clipboard(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Cannot compare remote files with the clipboard");
+            return;
+        }
+        let (path, name) = match self.active_panel().current_file() {
+            Some(file) if !file.is_directory && file.name != ".." => {
+                (self.active_panel().path.join(&file.name), file.name.clone())
+            }
+            _ => {
+                self.show_message("Select a file to compare");
+                return;
+            }
+        };
+        let text = match crate::services::clipboard_image::read_text() {
+            Ok(text) if !text.is_empty() => text,
+            Ok(_) => {
+                self.show_message("Clipboard is empty");
+                return;
+            }
+            Err(e) => {
+                self.show_message(&format!("Cannot read clipboard: {}", e));
+                return;
+            }
+        };
+        let tmp_dir = dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("/tmp"))
+            .join(".cokacdir").join("tmp");
+        let clip_path = tmp_dir.join("clipboard.txt");
+        if let Err(e) = std::fs::create_dir_all(&tmp_dir).and_then(|_| std::fs::write(&clip_path, text)) {
+            self.show_message(&format!("Cannot write {}: {}", clip_path.display(), e));
+            return;
+        }
+        let mut state = crate::ui::diff_file_view::DiffFileViewState::new(path, clip_path, format!("{} ↔ clipboard", name));
+        state.return_screen = Screen::FilePanel;
+        state.create_image_protocols(self.image_picker.as_mut());
+        self.diff_file_view_state = Some(state);
         self.current_screen = Screen::DiffFileView;
     }
 
@@ -2832,21 +4072,20 @@ impl App {
 
         self.info_file_path = file_path.clone();
 
-        // For directories, start async size calculation
+        let mut state = FileInfoState::new();
+        state.load_properties(&file_path);
+        // For directories, use the cached size or start async size calculation
         if is_directory {
-            let mut state = FileInfoState::new();
-            state.start_calculation(&file_path);
-            self.file_info_state = Some(state);
-        } else {
-            self.file_info_state = None;
+            state.start_calculation(&file_path, true);
         }
+        self.file_info_state = Some(state);
 
         self.current_screen = Screen::FileInfo;
     }
 
     pub fn view_file(&mut self) {
         if self.active_panel().is_remote() {
-            self.show_message("Cannot view remote files directly. Use copy to download first.");
+            self.preview_remote_file();
             return;
         }
         let panel = self.active_panel();
@@ -2911,6 +4150,7 @@ impl App {
                 viewer.set_syntax_colors(self.theme.syntax);
                 match viewer.load_file(&path) {
                     Ok(_) => {
+                        self.restore_viewer_position(&mut viewer, &path);
                         self.viewer_state = Some(viewer);
                         self.current_screen = Screen::FileViewer;
                     }
@@ -2924,21 +4164,225 @@ impl App {
         }
     }
 
-    /// 원격 파일의 로컬 tmp 경로 생성
-    fn remote_tmp_path(&self, file_name: &str) -> Option<PathBuf> {
-        let panel = self.active_panel();
-        let remote_path = format!("{}/{}", panel.path.display(), file_name);
-        if let Some(ref ctx) = panel.remote_ctx {
-            let tmp_base = dirs::home_dir()
-                .unwrap_or_else(|| PathBuf::from("/tmp"))
-                .join(".cokacdir").join("tmp")
-                .join(format!("{}@{}", ctx.profile.user, ctx.profile.host));
+    /// 빠른 보기 (F3): 커서 위치의 파일을 뷰어로 연다.
+    /// 이전 뷰어 인스턴스가 남아 있으면 재사용하여 줄바꿈/hex/검색 옵션을 유지한다.
+    pub fn quick_view(&mut self) {
+        if self.active_panel().is_remote() {
+            self.preview_remote_file();
+            return;
+        }
+        let path = match self.active_panel().current_file() {
+            Some(file) if !file.is_directory => self.active_panel().path.join(&file.name),
+            _ => {
+                self.show_message("Select a file to view");
+                return;
+            }
+        };
+        // 이미지는 기존 이미지 뷰어 경로 사용
+        if crate::ui::image_viewer::is_image_file(&path) {
+            self.view_file();
+            return;
+        }
+
+        let mut viewer = match self.viewer_state.take() {
+            Some(v) if !v.is_stdin => v,
+            _ => {
+                let mut v = ViewerState::new();
+                v.set_syntax_colors(self.theme.syntax);
+                v
+            }
+        };
+        match viewer.load_file(&path) {
+            Ok(_) => {
+                self.restore_viewer_position(&mut viewer, &path);
+                self.viewer_state = Some(viewer);
+                self.current_screen = Screen::FileViewer;
+            }
+            Err(e) => {
+                if self.current_screen == Screen::FileViewer {
+                    viewer.set_message(format!("Cannot read file: {}", e), 30);
+                    self.viewer_state = Some(viewer);
+                } else {
+                    self.show_message(&format!("Cannot read file: {}", e));
+                }
+            }
+        }
+    }
+
+    /// 뷰어에서 활성 패널의 다음(delta 1)/이전(-1) 파일로 이동 (패널 커서도 함께 이동)
+    pub fn quick_view_step(&mut self, delta: isize) {
+        let current = match self.viewer_state.as_ref() {
+            Some(v) if !v.is_stdin => v.file_path.clone(),
+            _ => return,
+        };
+        let panel = self.active_panel();
+        if panel.is_remote() || current.parent() != Some(panel.path.as_path()) {
+            if let Some(ref mut v) = self.viewer_state {
+                v.set_message("File is not in the current panel", 30);
+            }
+            return;
+        }
+        let name = current.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let start = panel.files.iter().position(|f| f.name == name).unwrap_or(panel.selected_index) as isize;
+        // 디렉토리와 이미지는 건너뜀
+        let mut i = start + delta;
+        let mut target = None;
+        while i >= 0 && (i as usize) < panel.files.len() {
+            let f = &panel.files[i as usize];
+            if !f.is_directory && !crate::ui::image_viewer::is_image_file(&panel.path.join(&f.name)) {
+                target = Some(i as usize);
+                break;
+            }
+            i += delta;
+        }
+        let Some(target) = target else {
+            if let Some(ref mut v) = self.viewer_state {
+                v.set_message("No more files", 30);
+            }
+            return;
+        };
+        self.record_recent_file();
+        self.active_panel_mut().selected_index = target;
+        self.quick_view();
+    }
+
+    /// 원격 파일의 로컬 tmp 경로 생성
+    fn remote_tmp_path(&self, file_name: &str) -> Option<PathBuf> {
+        let panel = self.active_panel();
+        let remote_path = format!("{}/{}", panel.path.display(), file_name);
+        if let Some(ref ctx) = panel.remote_ctx {
+            let tmp_base = dirs::home_dir()
+                .unwrap_or_else(|| PathBuf::from("/tmp"))
+                .join(".cokacdir").join("tmp")
+                .join(format!("{}@{}", ctx.profile.user, ctx.profile.host));
             Some(tmp_base.join(remote_path.trim_start_matches('/')))
         } else {
             None
         }
     }
 
+    /// 원격 파일 미리보기: 앞부분(REMOTE_PREVIEW_BYTES)만 tmp로 받아 뷰어로 연다.
+    /// 전체 파일은 뷰어에서 download_full_preview로 받는다. 이미지는 전체 다운로드.
+    fn preview_remote_file(&mut self) {
+        const REMOTE_PREVIEW_BYTES: usize = 256 * 1024;
+        if self.remote_spinner.is_some() {
+            return;
+        }
+        let panel_idx = self.active_panel_index;
+        let file = match self.active_panel().current_file() {
+            Some(f) if !f.is_directory => f.clone(),
+            Some(_) => { self.show_message("Select a file to view"); return; }
+            None => return,
+        };
+        let tmp_path = match self.remote_tmp_path(&file.name) {
+            Some(p) => p,
+            None => return,
+        };
+        if crate::ui::image_viewer::is_image_file(Path::new(&file.name)) {
+            self.download_for_remote_open(&file.name, file.size, PendingRemoteOpen::ImageViewer { tmp_path });
+            return;
+        }
+        let remote_path = format!("{}/{}", self.active_panel().path.display(), file.name);
+        let ctx = match self.panels[panel_idx].remote_ctx.take() {
+            Some(ctx) => ctx,
+            None => return,
+        };
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let result = ctx.session.read_head(&remote_path, REMOTE_PREVIEW_BYTES).and_then(|mut bytes| {
+                crate::services::remote::trim_partial_utf8(&mut bytes);
+                if let Some(parent) = tmp_path.parent() {
+                    fs::create_dir_all(parent).map_err(|e| format!("Cannot create tmp dir: {}", e))?;
+                }
+                fs::write(&tmp_path, &bytes).map_err(|e| e.to_string())
+            });
+            let _ = tx.send(RemoteSpinnerResult::PanelOp {
+                ctx,
+                panel_idx,
+                outcome: PanelOpOutcome::Preview {
+                    result,
+                    tmp_path,
+                    file_name: file.name,
+                    file_size: file.size,
+                },
+            });
+        });
+
+        self.remote_spinner = Some(RemoteSpinner {
+            message: "Loading preview...".to_string(),
+            started_at: Instant::now(),
+            receiver: rx,
+        });
+    }
+
+    /// 받아온 미리보기 tmp 파일을 뷰어로 연다
+    pub fn open_remote_preview(&mut self, tmp_path: &PathBuf, preview: crate::ui::file_viewer::RemotePreview, scroll: usize) {
+        let mut viewer = ViewerState::new();
+        viewer.set_syntax_colors(self.theme.syntax);
+        if let Err(e) = viewer.load_file(tmp_path) {
+            self.show_message(&format!("Cannot read file: {}", e));
+            return;
+        }
+        if viewer.file_size < preview.total_size {
+            let key = self.keybindings.viewer_first_key(crate::keybindings::ViewerAction::DownloadFull);
+            viewer.set_message(
+                format!(
+                    "Showing the first {} of {}. Press {} to download the full file",
+                    crate::utils::format::format_size(viewer.file_size),
+                    crate::utils::format::format_size(preview.total_size),
+                    key
+                ),
+                50,
+            );
+        }
+        viewer.scroll = scroll.min(viewer.lines.len().saturating_sub(1));
+        viewer.remote_preview = Some(preview);
+        self.viewer_state = Some(viewer);
+        self.current_screen = Screen::FileViewer;
+    }
+
+    /// 미리보기 뷰어에서 원격 파일 전체를 받아 뷰어(또는 편집기)로 다시 연다
+    pub fn download_full_preview(&mut self, edit: bool) {
+        let (preview, scroll, loaded) = match self.viewer_state.as_ref() {
+            Some(v) => match v.remote_preview.clone() {
+                Some(p) => (p, v.scroll, v.file_size),
+                None => return,
+            },
+            None => return,
+        };
+        if !edit && loaded >= preview.total_size {
+            if let Some(ref mut v) = self.viewer_state {
+                v.set_message("The whole file is already shown", 30);
+            }
+            return;
+        }
+        if self.panels.get(preview.panel_index).map_or(true, |p| p.remote_ctx.is_none()) {
+            if let Some(ref mut v) = self.viewer_state {
+                v.set_message("Remote connection is not available", 30);
+            }
+            return;
+        }
+        // tmp 경로와 다운로드는 활성 패널 기준
+        self.active_panel_index = preview.panel_index;
+        let tmp_path = match self.remote_tmp_path(&preview.file_name) {
+            Some(p) => p,
+            None => return,
+        };
+        let open_action = if edit {
+            PendingRemoteOpen::Editor {
+                tmp_path,
+                panel_index: preview.panel_index,
+                remote_path: format!("{}/{}", self.active_panel().path.display(), preview.file_name),
+            }
+        } else {
+            PendingRemoteOpen::Viewer { tmp_path, preview: preview.clone(), scroll }
+        };
+        self.viewer_state = None;
+        self.current_screen = Screen::FilePanel;
+        self.download_for_remote_open(&preview.file_name, preview.total_size, open_action);
+    }
+
     /// 원격 파일을 tmp로 다운로드 (프로그레스 표시) 후 편집기/뷰어로 열기
     fn download_for_remote_open(&mut self, file_name: &str, file_size: u64, open_action: PendingRemoteOpen) {
         let panel_index = self.active_panel_index;
@@ -2969,11 +4413,15 @@ impl App {
         let (tx, rx) = mpsc::channel();
         progress.receiver = Some(rx);
 
+        // 이전 사본(미리보기 포함)은 지운다: 실패 시 오래된 내용이 열리지 않도록.
+        // 중단된 다운로드의 .part는 남아 있으면 이어받는다.
+        let _ = fs::remove_file(&tmp_path);
+
         let tmp_path_clone = tmp_path.clone();
         let remote_path_clone = remote_path.clone();
         let file_name_owned = file_name.to_string();
 
-        thread::spawn(move || {
+        file_ops::spawn_worker(tx, None, move |tx| {
             let _ = tx.send(file_ops::ProgressMessage::Preparing(
                 format!("Connecting to {}...", profile.host),
             ));
@@ -3005,6 +4453,9 @@ impl App {
                     let _ = tx.send(file_ops::ProgressMessage::FileProgress(downloaded, total));
                     let _ = tx.send(file_ops::ProgressMessage::TotalProgress(0, 1, downloaded, total));
                 },
+                |offset| {
+                    let _ = tx.send(file_ops::ProgressMessage::Resumed(offset, file_size));
+                },
             ) {
                 Ok(_) => {
                     let _ = tx.send(file_ops::ProgressMessage::FileCompleted(file_name_owned));
@@ -3065,6 +4516,7 @@ impl App {
                     editor.set_syntax_colors(self.theme.syntax);
                     match editor.load_file(&path) {
                         Ok(_) => {
+                            editor.marks = self.saved_marks(&path);
                             self.editor_state = Some(editor);
                             self.current_screen = Screen::FileEditor;
                         }
@@ -3094,7 +4546,7 @@ impl App {
             dialog_type: DialogType::Delete,
             input: String::new(),
             cursor_pos: 0,
-            message: format!("Delete {}?", file_list),
+            message: self.delete_prompt(&file_list),
             completion: None,
             selected_button: 1,  // 기본값: No (안전을 위해)
             selection: None,
@@ -3190,12 +4642,13 @@ impl App {
 
         let mut progress = FileOperationProgress::new(FileOperationType::Encrypt);
         progress.is_active = true;
+        progress.dirs = vec![dir.clone()];
         let cancel_flag = progress.cancel_flag.clone();
 
         let (tx, rx) = mpsc::channel();
         progress.receiver = Some(rx);
 
-        thread::spawn(move || {
+        file_ops::spawn_worker(tx, None, move |tx| {
             crate::enc::pack_directory_with_progress(&dir, &key_path, tx, cancel_flag, split_size_mb, use_md5);
         });
 
@@ -3225,12 +4678,13 @@ impl App {
 
         let mut progress = FileOperationProgress::new(FileOperationType::Decrypt);
         progress.is_active = true;
+        progress.dirs = vec![dir.clone()];
         let cancel_flag = progress.cancel_flag.clone();
 
         let (tx, rx) = mpsc::channel();
         progress.receiver = Some(rx);
 
-        thread::spawn(move || {
+        file_ops::spawn_worker(tx, None, move |tx| {
             crate::enc::unpack_directory_with_progress(&dir, &key_path, tx, cancel_flag);
         });
 
@@ -3320,9 +4774,9 @@ impl App {
             return;
         }
 
-        // Generate default archive name based on first file
-        let first_file = &files[0];
-        let archive_name = format!("{}.tar.gz", first_file);
+        // Start with the first archive profile, if any
+        self.tar_profile = if self.settings.archive_profiles.is_empty() { None } else { Some(0) };
+        let archive_name = self.tar_archive_name(&files[0]);
 
         let file_list = if files.len() <= 3 {
             files.join(", ")
@@ -3343,6 +4797,48 @@ impl App {
         });
     }
 
+    /// Default archive name for the selected profile (or "<file>.tar.gz" without one)
+    fn tar_archive_name(&self, first_file: &str) -> String {
+        let profile = self.tar_profile.and_then(|i| self.settings.archive_profiles.get(i));
+        match profile {
+            Some(profile) => {
+                let dir_name = self.active_panel().path.file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "archive".to_string());
+                profile.archive_name(first_file, &dir_name, Local::now())
+            }
+            None => format!("{}.tar.gz", first_file),
+        }
+    }
+
+    /// Switch the Create Archive dialog to the next profile (after the last: no profile)
+    pub fn cycle_tar_profile(&mut self) {
+        let count = self.settings.archive_profiles.len();
+        if count == 0 {
+            return;
+        }
+        self.tar_profile = match self.tar_profile {
+            Some(i) if i + 1 < count => Some(i + 1),
+            Some(_) => None,
+            None => Some(0),
+        };
+        let files = self.get_operation_files();
+        let Some(first_file) = files.first() else { return };
+        let archive_name = self.tar_archive_name(first_file);
+        if let Some(ref mut dialog) = self.dialog {
+            dialog.cursor_pos = archive_name.chars().count();
+            dialog.input = archive_name;
+            dialog.selection = None;
+        }
+    }
+
+    /// Name of the profile selected in the Create Archive dialog
+    pub fn tar_profile_name(&self) -> Option<&str> {
+        self.tar_profile
+            .and_then(|i| self.settings.archive_profiles.get(i))
+            .map(|p| p.name.as_str())
+    }
+
     pub fn show_search_dialog(&mut self) {
         self.dialog = Some(Dialog {
             dialog_type: DialogType::Search,
@@ -3389,12 +4885,26 @@ impl App {
             self.panels.push(PanelState::new(path));
         }
         let current_path = self.active_panel().path.display().to_string();
-        // Try to load the most recent session, fall back to new session
+        // A conversation left in the background is resumed (finished ones first);
+        // otherwise try to load the most recent session, fall back to new session
         // Note: claude availability is checked inside AIScreenState (displays error in UI if unavailable)
-        self.ai_state = Some(
-            crate::ui::ai_screen::AIScreenState::load_latest_session(current_path.clone())
-                .unwrap_or_else(|| crate::ui::ai_screen::AIScreenState::new(current_path))
-        );
+        if self.ai_state.is_none() {
+            let parked = self.ai_conversations.iter().position(|s| !s.pending_approvals.is_empty())
+                .or_else(|| self.ai_conversations.iter().position(|s| s.has_unseen_response))
+                .or(if self.ai_conversations.is_empty() { None } else { Some(0) });
+            let mut state = match parked {
+                Some(idx) => self.ai_conversations.remove(idx),
+                None => {
+                    let mut state = crate::ui::ai_screen::AIScreenState::load_latest_session(current_path.clone())
+                        .unwrap_or_else(|| crate::ui::ai_screen::AIScreenState::new(current_path));
+                    self.init_ai_conversation(&mut state);
+                    state
+                }
+            };
+            state.has_unseen_response = false;
+            self.ai_state = Some(state);
+            self.update_ai_conversation_labels();
+        }
         // 원래 포커스 위치 저장
         self.ai_previous_panel = Some(self.active_panel_index);
         // AI 화면을 비활성 패널(다음 패널)에 표시
@@ -3404,11 +4914,8 @@ impl App {
         self.active_panel_index = ai_idx;
     }
 
-    /// AI 화면을 닫고 상태 초기화
+    /// AI 화면을 닫고 상태 초기화 (응답 대기 중이면 백그라운드로 유지)
     pub fn close_ai_screen(&mut self) {
-        if let Some(ref mut state) = self.ai_state {
-            state.save_session_to_file();
-        }
         // 원래 포커스 위치로 복원
         if let Some(prev) = self.ai_previous_panel {
             if prev < self.panels.len() {
@@ -3417,10 +4924,290 @@ impl App {
         }
         self.ai_panel_index = None;
         self.ai_previous_panel = None;
-        self.ai_state = None;
+        self.release_ai_state();
         self.refresh_panels();
     }
 
+    /// AI 세션 저장 후 해제. 처리 중이거나 확인하지 않은 응답이 있는 대화는
+    /// 해제하지 않고 백그라운드에 남겨 둠
+    pub fn release_ai_state(&mut self) {
+        if let Some(mut state) = self.ai_state.take() {
+            state.save_session_to_file();
+            state.ai_fullscreen = false;
+            self.ai_conversations.push(state);
+        }
+        self.ai_conversations.retain(|s| s.is_processing || s.has_unseen_response);
+        self.update_ai_conversation_labels();
+        let running = self.ai_conversations.iter().filter(|s| s.is_processing).count();
+        match running {
+            0 => {}
+            1 => self.show_message("AI is working in the background"),
+            n => self.show_message(&format!("{} AI conversations are working in the background", n)),
+        }
+    }
+
+    /// AI 화면 입력 후 App이 처리해야 할 요청 (대화 전환, 응답 액션)
+    pub fn handle_ai_screen_requests(&mut self) {
+        self.handle_ai_conversation_request();
+        self.handle_ai_response_action();
+        self.handle_ai_layout_request();
+    }
+
+    /// AI 화면에서 요청한 분할 크기/방향 변경 (settings에 저장)
+    fn handle_ai_layout_request(&mut self) {
+        use crate::ui::ai_screen::PaneLayoutRequest;
+        let Some(request) = self.ai_state.as_mut().and_then(|s| s.layout_request.take()) else { return };
+        if self.ai_state.as_ref().map_or(false, |s| s.ai_fullscreen) {
+            return;
+        }
+        match request {
+            PaneLayoutRequest::Grow => self.settings.ai_pane.resize(5),
+            PaneLayoutRequest::Shrink => self.settings.ai_pane.resize(-5),
+            PaneLayoutRequest::ToggleOrientation => self.settings.ai_pane.vertical = !self.settings.ai_pane.vertical,
+        }
+        let _ = self.settings.save();
+        let pane = self.settings.ai_pane;
+        let orientation = if pane.vertical { "stacked" } else { "side by side" };
+        self.show_message(&format!("AI pane: {}%, {}", pane.clamped_percent(), orientation));
+    }
+
+    /// AI 화면에서 요청한 대화 전환 처리 (새 대화 / 다음 대화)
+    fn handle_ai_conversation_request(&mut self) {
+        use crate::ui::ai_screen::{AIScreenState, ConversationRequest};
+        let Some(request) = self.ai_state.as_mut().and_then(|s| s.conversation_request.take()) else { return };
+        if request == ConversationRequest::Next && self.ai_conversations.is_empty() {
+            self.show_message("No other AI conversation is open");
+            return;
+        }
+        let Some(mut current) = self.ai_state.take() else { return };
+        current.save_session_to_file();
+        let fullscreen = current.ai_fullscreen;
+        let current_path = current.current_path.clone();
+        self.ai_conversations.push(current);
+        let mut next = match request {
+            ConversationRequest::New => {
+                let mut state = AIScreenState::new(current_path);
+                self.init_ai_conversation(&mut state);
+                state
+            }
+            ConversationRequest::Next => self.ai_conversations.remove(0),
+        };
+        next.ai_fullscreen = fullscreen;
+        next.has_unseen_response = false;
+        self.ai_state = Some(next);
+        self.update_ai_conversation_labels();
+    }
+
+    /// 새 대화에 번호와 설정값(승인 모드) 적용
+    fn init_ai_conversation(&mut self, state: &mut crate::ui::ai_screen::AIScreenState) {
+        self.ai_conversation_seq += 1;
+        state.conversation_no = self.ai_conversation_seq;
+        state.approval_mode = self.settings.ai_approval.enabled;
+        state.approval_timeout_secs = self.settings.ai_approval.timeout_secs.max(1);
+    }
+
+    /// AI 응답 액션 목록에서 선택한 항목 실행
+    fn handle_ai_response_action(&mut self) {
+        use crate::ui::ai_screen::ResponseAction;
+        let Some(action) = self.ai_state.as_mut().and_then(|s| s.response_action_request.take()) else { return };
+        match action {
+            ResponseAction::CopyCode(code) => match crate::utils::osc52::copy(&code) {
+                Ok(result) => self.show_message(&result.message("Code block")),
+                Err(e) => self.show_message(&format!("Clipboard error: {}", e)),
+            },
+            ResponseAction::SaveCode { language, code } => {
+                let Some(idx) = self.ai_file_panel_index() else { return };
+                if self.panels[idx].is_remote() {
+                    self.show_message("Cannot save to a remote panel");
+                    return;
+                }
+                let input = crate::ui::ai_screen::suggested_file_name(&language);
+                let message = format!("Save to {}:", self.panels[idx].path.display());
+                self.ai_pending_code = Some(code);
+                self.dialog = Some(Dialog {
+                    dialog_type: DialogType::SaveAICode,
+                    cursor_pos: input.chars().count(),
+                    input,
+                    message,
+                    completion: None,
+                    selected_button: 0,
+                    selection: None,
+                    use_md5: false,
+                });
+            }
+            ResponseAction::GotoPath(path) => {
+                let Some(idx) = self.ai_file_panel_index() else { return };
+                if self.panels[idx].is_remote() {
+                    self.show_message("Cannot open a local path in a remote panel");
+                    return;
+                }
+                let (dir, focus) = if path.is_dir() {
+                    (path.clone(), None)
+                } else {
+                    match path.parent() {
+                        Some(parent) => (parent.to_path_buf(), path.file_name().map(|n| n.to_string_lossy().to_string())),
+                        None => return,
+                    }
+                };
+                self.active_panel_index = idx;
+                self.goto_directory_with_focus(&dir, focus);
+            }
+        }
+    }
+
+    /// AI 화면 옆의 파일 패널 인덱스
+    fn ai_file_panel_index(&self) -> Option<usize> {
+        let ai_idx = self.ai_panel_index?;
+        self.ai_previous_panel
+            .filter(|&i| i < self.panels.len() && i != ai_idx)
+            .or_else(|| (0..self.panels.len()).find(|&i| i != ai_idx))
+    }
+
+    /// AI 입력의 /files, /tree, /content 치환에 쓸 파일 패널 상태 갱신
+    pub fn update_ai_panel_context(&mut self) {
+        use crate::ui::ai_screen::PanelContext;
+        let context = self.ai_file_panel_index()
+            .map(|idx| &self.panels[idx])
+            .filter(|panel| !panel.is_remote())
+            .map(|panel| {
+                let mut files: Vec<String> = panel.selected_files.iter().cloned().collect();
+                files.sort();
+                if files.is_empty() {
+                    files.extend(panel.current_file().filter(|f| f.name != "..").map(|f| f.name.clone()));
+                }
+                PanelContext { path: panel.path.clone(), files }
+            });
+        if let Some(ref mut state) = self.ai_state {
+            state.panel_context = context;
+        }
+    }
+
+    /// 대기 중인 AI 코드 블록을 파일 패널 경로에 저장 (이미 있으면 다이얼로그 유지)
+    pub fn execute_save_ai_code(&mut self, name: &str) {
+        let Some(idx) = self.ai_file_panel_index() else {
+            self.dialog = None;
+            return;
+        };
+        let target = self.panels[idx].path.join(name.trim());
+        if target.exists() {
+            if let Some(ref mut d) = self.dialog {
+                d.message = format!("'{}' already exists!", name.trim());
+            }
+            return;
+        }
+        self.dialog = None;
+        let Some(mut code) = self.ai_pending_code.take() else { return };
+        if !code.ends_with('\n') {
+            code.push('\n');
+        }
+        match std::fs::write(&target, code) {
+            Ok(()) => {
+                let focus = target.file_name().map(|n| n.to_string_lossy().to_string());
+                if target.parent() == Some(self.panels[idx].path.as_path()) {
+                    self.panels[idx].pending_focus = focus;
+                }
+                self.panels[idx].load_files_cached();
+                self.show_message(&format!("Saved {}", target.display()));
+            }
+            Err(e) => self.show_message(&format!("Error: {}", e)),
+        }
+    }
+
+    /// 열린 대화가 둘 이상이면 각 대화 제목에 "Chat i/n" 표시
+    fn update_ai_conversation_labels(&mut self) {
+        let mut numbers: Vec<usize> = self.ai_state.iter()
+            .chain(self.ai_conversations.iter())
+            .map(|s| s.conversation_no)
+            .collect();
+        numbers.sort_unstable();
+        let total = numbers.len();
+        for state in self.ai_state.iter_mut().chain(self.ai_conversations.iter_mut()) {
+            state.conversation_label = if total > 1 {
+                let pos = numbers.iter().position(|&n| n == state.conversation_no).unwrap_or(0) + 1;
+                Some(format!("Chat {}/{}", pos, total))
+            } else {
+                None
+            };
+        }
+    }
+
+    /// 보이는 대화와 백그라운드 대화의 응답을 수신.
+    /// AI 화면이나 AI 패널에 보이는 대화에 새 내용이 있을 때만 true
+    /// (백그라운드 대화는 패널을 새로 고치지 않아 파일 선택이 유지됨)
+    pub fn poll_ai_conversations(&mut self) -> bool {
+        let mut has_new_content = false;
+        let visible = self.current_screen == Screen::AIScreen || self.is_ai_mode();
+        let mut finished: Vec<(Instant, bool)> = Vec::new();
+        // (도구 이름, 보이는 대화 여부) - 승인 대기 알림용
+        let mut approvals: Vec<(String, bool)> = Vec::new();
+        if let Some(ref mut state) = self.ai_state {
+            let was_processing = state.is_processing;
+            has_new_content |= state.poll_response() && visible;
+            if was_processing && !state.is_processing {
+                if let Some(started) = state.started_at.take() {
+                    finished.push((started, true));
+                }
+            }
+            if std::mem::take(&mut state.approval_requested) {
+                if let Some(request) = state.pending_approvals.first() {
+                    approvals.push((request.tool.clone(), true));
+                }
+            }
+        }
+        for state in self.ai_conversations.iter_mut() {
+            let was_processing = state.is_processing;
+            state.poll_response();
+            if std::mem::take(&mut state.approval_requested) {
+                if let Some(request) = state.pending_approvals.first() {
+                    approvals.push((request.tool.clone(), false));
+                }
+            }
+            if was_processing && !state.is_processing {
+                state.has_unseen_response = true;
+                state.save_session_to_file();
+                if let Some(started) = state.started_at.take() {
+                    finished.push((started, false));
+                }
+            }
+        }
+        // 응답 완료 시 알림 (다른 화면/창에 포커스가 있을 때)
+        for (started, visible) in finished {
+            self.notify_job_finished("cokacdir", "AI response finished", started, visible && self.ai_has_focus());
+            if visible {
+                continue;
+            }
+            let message = if self.ai_state.is_some() {
+                let key = self.keybindings.ai_screen_first_key(crate::keybindings::AIScreenAction::NextConversation).to_string();
+                format!("Another AI conversation finished - press {} to switch", key)
+            } else {
+                let key = self.keybindings.panel_first_key(PanelAction::AIScreen).to_string();
+                format!("AI response ready - press {} to view", key)
+            };
+            self.show_message(&message);
+        }
+        // 승인 요청은 시간 제한이 있으므로 AI 화면을 보고 있지 않으면 바로 알림
+        for (tool, visible) in approvals {
+            if visible && self.ai_has_focus() {
+                if !self.terminal_focused {
+                    crate::services::notify::send(&self.settings.notifications, "cokacdir", &format!("AI wants to run {}", tool));
+                }
+                continue;
+            }
+            let message = if visible {
+                format!("AI is waiting for approval to run {} - focus the AI panel to answer", tool)
+            } else if self.ai_state.is_some() {
+                let key = self.keybindings.ai_screen_first_key(crate::keybindings::AIScreenAction::NextConversation).to_string();
+                format!("Another AI conversation wants to run {} - press {} to switch", tool, key)
+            } else {
+                let key = self.keybindings.panel_first_key(PanelAction::AIScreen).to_string();
+                format!("AI wants to run {} - press {} to answer", tool, key)
+            };
+            self.show_message(&message);
+            crate::services::notify::send(&self.settings.notifications, "cokacdir", &format!("AI wants to run {}", tool));
+        }
+        has_new_content
+    }
+
     /// AI 모드가 활성화되어 있는지 확인
     pub fn is_ai_mode(&self) -> bool {
         self.ai_panel_index.is_some() && self.ai_state.is_some()
@@ -3437,7 +5224,9 @@ impl App {
             self.show_message("Not a git repository");
             return;
         }
-        self.git_screen_state = Some(crate::ui::git_screen::GitScreenState::new(path));
+        // status 경로는 저장소 루트 기준이므로 루트에서 연다
+        let root = crate::ui::git_screen::get_repo_root(&path).unwrap_or(path);
+        self.git_screen_state = Some(crate::ui::git_screen::GitScreenState::new(root));
         self.current_screen = Screen::GitScreen;
     }
 
@@ -3457,43 +5246,1054 @@ impl App {
 
     pub fn execute_dedup(&mut self) {
         let path = self.active_panel().path.clone();
-        self.dedup_screen_state = Some(crate::ui::dedup_screen::DedupScreenState::new(path));
+        self.dedup_screen_state = Some(crate::ui::dedup_screen::DedupScreenState::new(path, self.dedup_respect_ignore));
         self.current_screen = Screen::DedupScreen;
     }
 
-    pub fn show_git_log_diff_dialog(&mut self) {
-        let path = self.active_panel().path.clone();
-        if !crate::ui::git_screen::is_git_repo(&path) {
-            self.show_message("Not a git repository");
+    /// Telegram bot audit log viewer
+    pub fn show_audit_screen(&mut self) {
+        self.audit_screen_state = Some(crate::ui::audit_screen::AuditScreenState::new());
+        self.current_screen = Screen::AuditScreen;
+    }
+
+    /// 선택한 파일들(없으면 커서 파일)의 일괄 이름 변경 화면 (패널 순서대로)
+    pub fn show_bulk_rename(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Bulk rename is not available for remote panels");
             return;
         }
-        let repo_root = match crate::ui::git_screen::get_repo_root(&path) {
-            Some(r) => r,
-            None => {
-                self.show_message("Failed to get git repo root");
-                return;
-            }
-        };
-        let project_name = repo_root
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or_else(|| "project".to_string());
-        let log_entries = crate::ui::git_screen::get_log_public(&repo_root, 200);
-        if log_entries.is_empty() {
-            self.show_message("No git commits found");
+        let files = self.get_operation_files();
+        if files.is_empty() {
+            return;
+        }
+        let panel = self.active_panel();
+        let names: Vec<String> = panel.files.iter()
+            .filter(|f| files.contains(&f.name))
+            .map(|f| f.name.clone())
+            .collect();
+        self.bulk_rename_state = Some(crate::ui::bulk_rename::BulkRenameState::new(panel.path.clone(), names));
+        self.current_screen = Screen::BulkRename;
+    }
+
+    /// 선택한 파일들(없으면 커서 파일)의 확장자를 바꾸는 입력 다이얼로그
+    pub fn show_change_extension_dialog(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Changing extensions is not available for remote panels");
+            return;
+        }
+        let files = self.get_operation_files();
+        let count = self.active_panel().files.iter()
+            .filter(|f| !f.is_directory && files.contains(&f.name))
+            .count();
+        if count == 0 {
+            self.show_message("Select the files whose extension should change");
             return;
         }
-        self.git_log_diff_state = Some(GitLogDiffState {
-            repo_path: repo_root,
-            project_name,
-            log_entries,
-            selected_index: 0,
-            scroll_offset: 0,
-            selected_commits: Vec::new(),
-            visible_height: 20,
-        });
         self.dialog = Some(Dialog {
-            dialog_type: DialogType::GitLogDiff,
+            dialog_type: DialogType::ChangeExtension,
+            input: String::new(),
+            cursor_pos: 0,
+            message: format!("New extension for {} file(s) (\".\" removes it)", count),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// 확장자 일괄 변경: 충돌이 하나라도 있으면 아무것도 바꾸지 않음
+    pub fn execute_change_extension(&mut self, input: &str) {
+        let ext = if input.trim() == "." { "" } else { input.trim() };
+        let files = self.get_operation_files();
+        let panel = self.active_panel();
+        let dir = panel.path.clone();
+        let renames: Vec<(String, String)> = panel.files.iter()
+            .filter(|f| !f.is_directory && files.contains(&f.name))
+            .map(|f| (f.name.clone(), file_ops::replace_extension(&f.name, ext)))
+            .filter(|(old, new)| old != new)
+            .collect();
+        if renames.is_empty() {
+            self.show_message("Nothing to change");
+            return;
+        }
+        match file_ops::bulk_rename(&dir, &renames) {
+            Ok(count) => {
+                self.last_extension_change = Some((dir, renames));
+                self.active_panel_mut().selected_files.clear();
+                self.refresh_panels();
+                self.show_message(&format!("Changed the extension of {} file(s) (Ctrl+Z to undo)", count));
+            }
+            Err(e) => self.show_message(&format!("Error: {}", e)),
+        }
+    }
+
+    /// 마지막 확장자 일괄 변경을 되돌림
+    pub fn undo_extension_change(&mut self) {
+        let Some((dir, renames)) = self.last_extension_change.take() else {
+            self.show_message("Nothing to undo");
+            return;
+        };
+        let reverse: Vec<(String, String)> = renames.iter()
+            .map(|(old, new)| (new.clone(), old.clone()))
+            .collect();
+        match file_ops::bulk_rename(&dir, &reverse) {
+            Ok(count) => {
+                self.refresh_panels();
+                self.show_message(&format!("Restored {} original name(s)", count));
+            }
+            Err(e) => {
+                self.last_extension_change = Some((dir, renames));
+                self.show_message(&format!("Undo failed: {}", e));
+            }
+        }
+    }
+
+    /// 마운트된 파일시스템 목록: 선택한 마운트 지점으로 활성 패널 이동
+    pub fn show_drives_screen(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Drives are not available for remote panels");
+            return;
+        }
+        let current = self.active_panel().path.clone();
+        self.drives_screen_state = Some(crate::ui::drives_screen::DrivesScreenState::new(&current));
+        self.current_screen = Screen::DrivesScreen;
+    }
+
+    /// 현재 폴더 아래의 공간 낭비 후보(오래된 node_modules, target/, 캐시, 코어 덤프, 큰 파일) 정리 화면
+    pub fn show_cleanup_screen(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Cleanup is not available for remote panels");
+            return;
+        }
+        let path = self.active_panel().path.clone();
+        self.cleanup_screen_state = Some(crate::ui::cleanup_screen::CleanupScreenState::new(path));
+        self.current_screen = Screen::CleanupScreen;
+    }
+
+    /// 백업 작업(원본 → 대상, 포함/제외 규칙, 일정) 관리 화면
+    pub fn show_backup_screen(&mut self) {
+        self.backup_screen_state = Some(crate::ui::backup_screen::BackupScreenState::new());
+        self.current_screen = Screen::BackupScreen;
+    }
+
+    /// 현재 폴더 아래에서 최근에 수정된 파일을 최신순으로 보여주는 화면
+    pub fn show_timeline_screen(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Recent changes are not available for remote panels");
+            return;
+        }
+        let path = self.active_panel().path.clone();
+        self.timeline_screen_state = Some(crate::ui::timeline_screen::TimelineScreenState::new(path));
+        self.current_screen = Screen::TimelineScreen;
+    }
+
+    /// 커밋 그래프와 함께 저장소 히스토리를 보여주는 로그 브라우저
+    pub fn show_git_log_screen(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Git log is not available for remote panels");
+            return;
+        }
+        let path = self.active_panel().path.clone();
+        let Some(root) = crate::ui::git_screen::get_repo_root(&path) else {
+            self.show_message("Not a git repository");
+            return;
+        };
+        self.git_log_screen_state = Some(crate::ui::git_log_screen::GitLogScreenState::new(root));
+        self.current_screen = Screen::GitLogScreen;
+    }
+
+    /// 비정상 종료된 이전 실행의 저널이 있으면 복구 화면 표시
+    pub fn show_recovery_screen_if_needed(&mut self) {
+        let journals = crate::services::journal::stale_journals();
+        if journals.is_empty() {
+            return;
+        }
+        self.recovery_screen_state = Some(crate::ui::recovery_screen::RecoveryScreenState::new(journals));
+        self.current_screen = Screen::RecoveryScreen;
+    }
+
+    /// 중단된 복사/이동 이어하기: 불완전한 사본을 지우고 남은 항목만 같은 충돌 결정으로 다시 실행
+    pub fn resume_operation(&mut self, op: crate::services::journal::Operation) {
+        use crate::services::journal::{self, OperationKind};
+
+        if let Some(ref partial) = op.in_progress {
+            if let Err(e) = journal::remove_partial(partial) {
+                self.show_message(&format!("Cannot remove partial copy {}: {}", partial.display(), e));
+                return;
+            }
+        }
+        if !op.target_dir.is_dir() {
+            self.show_message(&format!("Target folder no longer exists: {}", op.target_dir.display()));
+            return;
+        }
+        let files = op.remaining();
+        if files.is_empty() {
+            self.show_message("Nothing left to resume");
+            return;
+        }
+        let operation = match op.kind {
+            OperationKind::Copy => ClipboardOperation::Copy,
+            OperationKind::Move => ClipboardOperation::Cut,
+        };
+        let files_to_overwrite: HashSet<PathBuf> = op.overwrite.iter().cloned().collect();
+        let files_to_skip: HashSet<PathBuf> = op.skip.iter().cloned().collect();
+        self.start_paste_worker(operation, files, op.source_dir, op.target_dir, files_to_overwrite, files_to_skip, op.excludes);
+    }
+
+    /// 백업된 저장되지 않은 내용으로 편집기 열기
+    pub fn recover_buffer(&mut self, path: &Path, content: &str) {
+        let mut editor = EditorState::new();
+        editor.set_syntax_colors(self.theme.syntax);
+        if let Err(e) = editor.load_file(&path.to_path_buf()) {
+            self.show_message(&format!("Cannot open file: {}", e));
+            return;
+        }
+        editor.restore_buffer(content);
+        self.editor_state = Some(editor);
+        self.current_screen = Screen::FileEditor;
+    }
+
+    /// 편집기의 저장되지 않은 내용을 주기적으로 크래시 저널에 백업.
+    /// 저장하거나 닫으면 백업은 지워진다
+    pub fn journal_unsaved_buffer(&mut self) {
+        use crate::services::journal;
+        const BACKUP_INTERVAL: Duration = Duration::from_secs(5);
+
+        let open = self.editor_state.as_ref().map(|e| (e.file_path.clone(), e.modified));
+        // 닫혔거나 다른 파일로 바뀌었으면 이전 백업 제거
+        if let Some((ref path, _)) = self.journaled_buffer {
+            if open.as_ref().map(|(p, _)| p) != Some(path) {
+                journal::discard_buffer(path);
+                self.journaled_buffer = None;
+            }
+        }
+        let Some((path, modified)) = open else { return };
+        if !modified {
+            if self.journaled_buffer.take().is_some() {
+                journal::discard_buffer(&path);
+            }
+            return;
+        }
+        if self.journaled_buffer.as_ref().map_or(true, |(_, at)| at.elapsed() >= BACKUP_INTERVAL) {
+            if let Some(ref editor) = self.editor_state {
+                journal::save_buffer(&path, &editor.lines.join("\n"));
+            }
+            self.journaled_buffer = Some((path, Instant::now()));
+        }
+    }
+
+    pub fn show_trash_screen(&mut self) {
+        self.trash_screen_state = Some(crate::ui::trash_screen::TrashScreenState::new());
+        self.current_screen = Screen::TrashScreen;
+    }
+
+    /// 삭제 시 휴지통 이동 / 영구 삭제 전환
+    pub fn toggle_delete_to_trash(&mut self) {
+        self.settings.delete_to_trash = !self.settings.delete_to_trash;
+        let _ = self.settings.save();
+        if self.settings.delete_to_trash {
+            self.show_message("Delete moves files to the trash");
+        } else {
+            self.show_message("Delete removes files permanently");
+        }
+    }
+
+    /// 커서 위치 심볼릭 링크의 대상으로 이동 (대상의 폴더를 열고 대상에 커서)
+    pub fn goto_link_target(&mut self) {
+        let panel = self.active_panel();
+        if panel.is_remote() {
+            self.show_message("Link targets are not available for remote panels");
+            return;
+        }
+        let Some(target) = panel.current_file().and_then(|f| f.link_target.clone()) else {
+            self.show_message("Not a symbolic link");
+            return;
+        };
+        // 상대 경로 대상은 링크가 있는 폴더 기준
+        let full = panel.path.join(&target);
+        if fs::symlink_metadata(&full).is_err() {
+            self.show_message(&format!("Broken link: {} does not exist", target));
+            return;
+        }
+        match (full.parent().and_then(|p| p.canonicalize().ok()), full.file_name()) {
+            (Some(dir), Some(name)) => {
+                let name = name.to_string_lossy().to_string();
+                self.goto_directory_with_focus(&dir, Some(name));
+            }
+            // 대상이 "/" 또는 ".."로 끝나는 경우: 그 폴더 자체로 이동
+            _ => match full.canonicalize() {
+                Ok(dir) => self.goto_directory_with_focus(&dir, None),
+                Err(e) => self.show_message(&format!("Error: {}", e)),
+            },
+        }
+    }
+
+    /// 선택한 파일들의 심볼릭 링크를 대상 패널 폴더에 생성 (절대 경로로 연결)
+    pub fn symlink_to_target_panel(&mut self) {
+        if self.active_panel().is_remote() || self.target_panel().is_remote() {
+            self.show_message("Symbolic links can only be created between local panels");
+            return;
+        }
+        let files = self.get_operation_files();
+        if files.is_empty() {
+            return;
+        }
+        let source_dir = self.active_panel().path.clone();
+        let target_dir = self.target_panel().path.clone();
+        if source_dir == target_dir {
+            self.show_message("Source and target panels are the same directory");
+            return;
+        }
+
+        let mut created = 0;
+        let mut errors = Vec::new();
+        for name in &files {
+            match file_ops::create_symlink(&source_dir.join(name), &target_dir.join(name)) {
+                Ok(_) => created += 1,
+                Err(e) => errors.push(format!("{}: {}", name, e)),
+            }
+        }
+
+        let target_idx = (self.active_panel_index + 1) % self.panels.len();
+        self.panels[target_idx].pending_focus = files.first().cloned();
+        self.refresh_panels();
+        if let Some(first_error) = errors.first() {
+            self.show_message(&format!("Created {} link(s), {} failed: {}", created, errors.len(), first_error));
+        } else {
+            self.show_message(&format!("Created {} link(s) in {}", created, target_dir.display()));
+        }
+    }
+
+    /// 현재 패널의 숨김 파일(dotfile) 표시 전환 (패널별 설정으로 저장)
+    pub fn toggle_hidden(&mut self) {
+        let idx = self.active_panel_index;
+        let panel = &mut self.panels[idx];
+        panel.show_hidden = !panel.show_hidden;
+        panel.pending_focus = panel.current_file().map(|f| f.name.clone());
+        let show_hidden = panel.show_hidden;
+        if panel.is_remote() {
+            // 숨겼던 항목은 목록에 없으므로 다시 읽어온다
+            self.spawn_remote_refresh(idx);
+        } else {
+            panel.load_files_cached();
+        }
+        self.save_settings();
+        if show_hidden {
+            self.show_message("Hidden files: shown");
+        } else {
+            self.show_message("Hidden files: hidden");
+        }
+    }
+
+    /// 패널 Size 열의 디렉토리 크기 표시 (백그라운드 인덱서) 전환
+    pub fn toggle_dir_sizes(&mut self) {
+        self.settings.index_dir_sizes = !self.settings.index_dir_sizes;
+        let _ = self.settings.save();
+        if self.settings.index_dir_sizes {
+            self.show_message("Directory sizes: on (measured in the background)");
+        } else {
+            self.show_message("Directory sizes: off");
+        }
+    }
+
+    /// Quick view 켜기/끄기: 대상 패널 자리에 활성 패널 커서 파일의 미리보기 표시
+    pub fn toggle_preview_pane(&mut self) {
+        if self.preview_pane.take().is_some() {
+            self.show_message("Quick view: off");
+            return;
+        }
+        if self.panels.len() < 2 || self.ai_panel_index.is_some() {
+            self.show_message("Quick view needs a second file panel");
+            return;
+        }
+        let target_idx = (self.active_panel_index + 1) % self.panels.len();
+        self.preview_pane = Some(crate::ui::preview_pane::PreviewPane::new(target_idx, self.settings.tar_path.clone()));
+        self.show_message("Quick view: on");
+    }
+
+    /// 트리 사이드바: 숨김 → 표시+포커스 → (포커스가 패널에 있으면) 포커스 → 숨김 순환
+    pub fn toggle_tree_sidebar(&mut self) {
+        match self.tree_sidebar {
+            None => {
+                let mut tree = crate::ui::tree_sidebar::TreeSidebar::new(self.active_panel().show_hidden);
+                tree.focused = true;
+                self.tree_sidebar = Some(tree);
+                self.sync_tree_sidebar();
+            }
+            Some(ref mut tree) if !tree.focused => tree.focused = true,
+            Some(_) => self.tree_sidebar = None,
+        }
+    }
+
+    /// 트리에서 Enter로 고른 폴더로 활성 패널 이동, 트리는 활성 패널 경로를 따라감
+    pub fn sync_tree_sidebar(&mut self) {
+        let Some(ref mut tree) = self.tree_sidebar else { return };
+        if let Some(path) = tree.chosen.take() {
+            if !self.active_panel().is_remote() {
+                self.goto_directory_with_focus(&path, None);
+            }
+        }
+        let panel = &self.panels[self.active_panel_index];
+        let path = (!panel.is_remote()).then_some(panel.path.as_path());
+        if let Some(ref mut tree) = self.tree_sidebar {
+            tree.sync(path, panel.show_hidden);
+        }
+    }
+
+    /// 패널이 다른 로컬 폴더로 옮겨 갔으면 frecency 기록에 방문 추가
+    pub fn record_directory_visits(&mut self) {
+        if self.view_only {
+            return;
+        }
+        self.last_visited_dirs.resize(self.panels.len(), PathBuf::new());
+        for (panel, last) in self.panels.iter().zip(self.last_visited_dirs.iter_mut()) {
+            if panel.is_remote() || panel.path == *last {
+                continue;
+            }
+            *last = panel.path.clone();
+            crate::services::frecency::record_visit(&panel.path);
+        }
+    }
+
+    /// Quick view 폴링: 커서가 옮겨지면 새 미리보기를 요청하고, 완료된 미리보기를 반영
+    pub fn poll_preview_pane(&mut self) {
+        let Some(ref mut pane) = self.preview_pane else { return };
+        if pane.panel_index >= self.panels.len() || self.ai_panel_index.is_some() {
+            self.preview_pane = None;
+            return;
+        }
+
+        // 미리보기 패널로 포커스가 옮겨가면 마지막 미리보기를 유지
+        if self.active_panel_index != pane.panel_index {
+            let panel = &self.panels[self.active_panel_index];
+            if panel.is_remote() {
+                pane.show_message("Quick view is not available for remote panels");
+            } else {
+                let path = panel.current_file()
+                    .filter(|f| f.name != "..")
+                    .map(|f| panel.path.join(&f.name));
+                pane.show(path);
+            }
+        }
+
+        if pane.poll() {
+            if let Some(crate::ui::preview_pane::PreviewContent::Image { ref image, .. }) = pane.content {
+                if let Some(ref mut picker) = self.image_picker {
+                    pane.protocol = Some(picker.new_resize_protocol(image.clone()));
+                }
+            }
+        }
+    }
+
+    /// 디렉토리 크기 인덱서 폴링: 목록이 바뀐 패널은 캐시로 채우고 빠진 항목을 요청,
+    /// 측정이 끝난 항목은 해당 패널의 Size 열에 반영
+    pub fn poll_dir_sizes(&mut self) {
+        use crate::services::dir_sizes;
+
+        if !self.settings.index_dir_sizes {
+            if self.dir_size_indexer.take().is_some() {
+                for panel in &mut self.panels {
+                    panel.dir_sizes.clear();
+                    panel.row_cache.clear();
+                }
+            }
+            return;
+        }
+        if self.dir_size_indexer.is_none() {
+            // 방금 켜짐: 현재 목록부터 채운다
+            for panel in &mut self.panels {
+                panel.dir_sizes_stale = true;
+            }
+        }
+        let indexer = self.dir_size_indexer.get_or_insert_with(dir_sizes::Indexer::spawn);
+
+        for panel in self.panels.iter_mut().filter(|p| p.dir_sizes_stale) {
+            panel.dir_sizes_stale = false;
+            if panel.is_remote() {
+                continue;
+            }
+            for file in panel.files.iter().filter(|f| f.is_directory && !f.is_symlink && f.name != "..") {
+                let path = panel.path.join(&file.name);
+                let mtime = dir_sizes::mtime_key(SystemTime::from(file.modified));
+                if let Some(size) = dir_sizes::get(&path, mtime) {
+                    panel.dir_sizes.insert(file.name.clone(), size.total_size);
+                }
+                if dir_sizes::needs_indexing(&path, mtime) {
+                    indexer.request(path);
+                }
+            }
+            panel.row_cache.clear();
+        }
+
+        for (path, size) in indexer.results() {
+            let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else { continue };
+            for panel in self.panels.iter_mut().filter(|p| !p.is_remote() && path.parent() == Some(p.path.as_path())) {
+                panel.dir_sizes.insert(name.clone(), size.total_size);
+                panel.row_cache.clear();
+            }
+        }
+    }
+
+    /// git 상태 배지 폴링: 목록이 다시 읽힌 패널은 캐시된 배지를 먼저 보여주고
+    /// 백그라운드에서 `git status`를 다시 요청, 결과가 오면 같은 폴더의 패널에 반영
+    pub fn poll_git_status(&mut self) {
+        use crate::services::git_status;
+
+        if !self.settings.git_status_badges {
+            if self.git_status_fetcher.take().is_some() {
+                for panel in &mut self.panels {
+                    panel.git_badges = Default::default();
+                }
+            }
+            return;
+        }
+        if self.git_status_fetcher.is_none() {
+            for panel in &mut self.panels {
+                panel.git_badges_stale = true;
+            }
+        }
+        let fetcher = self.git_status_fetcher.get_or_insert_with(git_status::Fetcher::spawn);
+
+        for panel in self.panels.iter_mut().filter(|p| p.git_badges_stale) {
+            panel.git_badges_stale = false;
+            if panel.is_remote() {
+                panel.git_badges = Default::default();
+                continue;
+            }
+            panel.git_badges = fetcher.cached(&panel.path).cloned().unwrap_or_default();
+            fetcher.request(panel.path.clone());
+        }
+
+        for (dir, badges) in fetcher.results() {
+            for panel in self.panels.iter_mut().filter(|p| !p.is_remote() && p.path == dir) {
+                panel.git_badges = badges.clone();
+            }
+        }
+    }
+
+    /// 삭제 확인 메시지 (원격 패널은 휴지통 없이 항상 영구 삭제)
+    pub fn delete_prompt(&self, what: &str) -> String {
+        let to_trash = self.settings.delete_to_trash
+            && (self.current_screen == Screen::ImageViewer || !self.active_panel().is_remote());
+        if to_trash {
+            format!("Move {} to trash?", what)
+        } else {
+            format!("Delete {} permanently?", what)
+        }
+    }
+
+    /// Browse a tar/zip archive without extracting it. Entries are extracted
+    /// into the other panel's directory (or next to the archive if that one is remote).
+    pub fn show_archive_viewer(&mut self, archive_path: PathBuf) {
+        if self.active_panel().is_remote() {
+            self.show_message("Archive browsing is not supported on remote panels");
+            return;
+        }
+        let dest = if self.target_panel().is_remote() {
+            archive_path.parent().map(|p| p.to_path_buf()).unwrap_or_default()
+        } else {
+            self.target_panel().path.clone()
+        };
+        match crate::ui::archive_viewer::ArchiveViewerState::new(archive_path, self.settings.tar_path.as_deref(), dest) {
+            Ok(state) => {
+                self.archive_viewer_state = Some(state);
+                self.current_screen = Screen::ArchiveViewer;
+            }
+            Err(e) => self.show_message(&format!("Cannot read archive: {}", e)),
+        }
+    }
+
+    /// Checksums for the selected files, or verification when the cursor is on a manifest
+    pub fn show_checksum_screen(&mut self) {
+        use crate::services::checksum::Algorithm;
+        use crate::ui::checksum_screen::ChecksumScreenState;
+
+        if self.active_panel().is_remote() {
+            self.show_message("Checksums are not available for remote files");
+            return;
+        }
+        let files = self.get_operation_files();
+        if files.is_empty() {
+            self.show_message("No files selected");
+            return;
+        }
+        let base_dir = self.active_panel().path.clone();
+
+        let state = match files.as_slice() {
+            [single] if base_dir.join(single).is_file() => {
+                let path = base_dir.join(single);
+                match Algorithm::from_manifest_path(&path) {
+                    Some(algorithm) => ChecksumScreenState::verify(path, algorithm),
+                    None => ChecksumScreenState::compute(base_dir, files),
+                }
+            }
+            _ => ChecksumScreenState::compute(base_dir, files),
+        };
+        self.checksum_state = Some(state);
+        self.current_screen = Screen::ChecksumScreen;
+    }
+
+    /// Compare two files by checksum: the two files selected in the active panel,
+    /// or the file under the cursor and the same-named file in the target panel
+    pub fn show_checksum_compare(&mut self) {
+        if self.panels.iter().any(|p| p.is_remote()) {
+            self.show_message("Checksum compare is not available for remote panels");
+            return;
+        }
+
+        let pair: Result<(PathBuf, PathBuf), String> = {
+            let panel = self.active_panel();
+            let selected: Vec<PathBuf> = panel.files.iter()
+                .filter(|f| !f.is_directory && panel.selected_files.contains(&f.name))
+                .map(|f| panel.path.join(&f.name))
+                .collect();
+            if selected.len() == 2 {
+                Ok((selected[0].clone(), selected[1].clone()))
+            } else if !panel.selected_files.is_empty() {
+                Err("Select exactly 2 files to compare".to_string())
+            } else {
+                match panel.current_file() {
+                    Some(file) if !file.is_directory && file.name != ".." => {
+                        let other = self.target_panel().path.join(&file.name);
+                        if self.panels.len() < 2 {
+                            Err("Select 2 files or open another panel to compare".to_string())
+                        } else if !other.is_file() {
+                            Err(format!("{} not found in the other panel", file.name))
+                        } else {
+                            Ok((panel.path.join(&file.name), other))
+                        }
+                    }
+                    _ => Err("Select a file to compare".to_string()),
+                }
+            }
+        };
+
+        match pair {
+            Ok((first, second)) => {
+                self.checksum_state = Some(crate::ui::checksum_screen::ChecksumScreenState::compare(first, second));
+                self.current_screen = Screen::ChecksumScreen;
+            }
+            Err(msg) => self.show_message(&msg),
+        }
+    }
+
+    pub fn show_git_log_diff_dialog(&mut self) {
+        let path = self.active_panel().path.clone();
+        if !crate::ui::git_screen::is_git_repo(&path) {
+            self.show_message("Not a git repository");
+            return;
+        }
+        let repo_root = match crate::ui::git_screen::get_repo_root(&path) {
+            Some(r) => r,
+            None => {
+                self.show_message("Failed to get git repo root");
+                return;
+            }
+        };
+        let project_name = repo_root
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "project".to_string());
+        let log_entries = crate::ui::git_screen::get_log_public(&repo_root, 200);
+        if log_entries.is_empty() {
+            self.show_message("No git commits found");
+            return;
+        }
+        self.git_log_diff_state = Some(GitLogDiffState {
+            repo_path: repo_root,
+            project_name,
+            log_entries,
+            selected_index: 0,
+            scroll_offset: 0,
+            selected_commits: Vec::new(),
+            visible_height: 20,
+        });
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::GitLogDiff,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// Show "Open with" dialog for the current file
+    pub fn show_open_with_dialog(&mut self) {
+        use crate::services::open_with::{self, OpenWithEntry, OpenWithKind};
+
+        if self.active_panel().is_remote() {
+            self.show_message("Open with is not available for remote files");
+            return;
+        }
+        let panel = self.active_panel();
+        let Some(file) = panel.files.get(panel.selected_index) else { return };
+        if file.is_directory {
+            return;
+        }
+        let file_path = panel.path.join(&file.name);
+        let extension = file_path.extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        let applications = open_with::list_applications(&file_path);
+        let mut entries: Vec<OpenWithEntry> = Vec::new();
+        if !extension.is_empty() {
+            if let Some(history) = self.settings.open_with_history.get(&extension) {
+                for command in history {
+                    // Show the application name when the command came from one
+                    let label = applications.iter()
+                        .find(|a| &a.command == command)
+                        .map(|a| a.label.clone())
+                        .unwrap_or_else(|| command.trim_start_matches('@').to_string());
+                    entries.push(OpenWithEntry {
+                        label,
+                        command: command.clone(),
+                        kind: OpenWithKind::Recent,
+                        preferred: true,
+                    });
+                }
+            }
+        }
+        entries.extend(applications);
+
+        let mut state = OpenWithState {
+            file_path,
+            extension,
+            entries,
+            filtered: Vec::new(),
+            selected_index: 0,
+            scroll_offset: 0,
+            visible_height: 20,
+        };
+        state.apply_filter("");
+        self.open_with_state = Some(state);
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::OpenWith,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// The file open in the viewer/editor and its position, if that screen is active
+    fn current_file_position(&self) -> Option<crate::config::RecentFile> {
+        use crate::config::RecentFile;
+
+        let entry = match self.current_screen {
+            Screen::FileViewer => self.viewer_state.as_ref()
+                .filter(|v| !v.is_stdin)
+                .map(|v| RecentFile {
+                    path: v.file_path.display().to_string(),
+                    edited: false,
+                    scroll: v.scroll,
+                    line: v.scroll,
+                    col: 0,
+                    marks: v.marks.clone(),
+                    search: v.search_term.clone(),
+                }),
+            Screen::FileEditor => self.editor_state.as_ref()
+                .map(|e| RecentFile {
+                    path: e.file_path.display().to_string(),
+                    edited: true,
+                    scroll: e.scroll,
+                    line: e.cursor_line,
+                    col: e.cursor_col,
+                    marks: e.marks.clone(),
+                    search: String::new(),
+                }),
+            _ => None,
+        }?;
+        // Skip unsaved buffers and local copies of remote files
+        let path = Path::new(&entry.path);
+        if entry.path.is_empty() || Settings::config_dir().map(|d| path.starts_with(d)).unwrap_or(false) {
+            return None;
+        }
+        Some(entry)
+    }
+
+    /// Marks saved for `path` the last time it was closed
+    pub fn saved_marks(&self, path: &Path) -> std::collections::BTreeMap<char, usize> {
+        let path = path.display().to_string();
+        self.settings.recent_files.iter()
+            .find(|f| f.path == path)
+            .map(|f| f.marks.clone())
+            .unwrap_or_default()
+    }
+
+    /// Reopen `path` in `viewer` where it was left: marks, scroll position and search term.
+    /// Call right after `load_file`.
+    pub fn restore_viewer_position(&self, viewer: &mut ViewerState, path: &Path) {
+        let path_str = path.display().to_string();
+        let Some(entry) = self.settings.recent_files.iter().find(|f| f.path == path_str) else { return };
+        viewer.marks = entry.marks.clone();
+        viewer.restore_position(entry.scroll, &entry.search);
+    }
+
+    /// Remember the file open in the viewer/editor and its position in the recent files list.
+    /// Call before leaving the viewer or editor screen.
+    pub fn record_recent_file(&mut self) {
+        if let Some(entry) = self.current_file_position() {
+            self.settings.add_recent_file(entry);
+        }
+    }
+
+    /// Store the open screens in settings so the next start can restore them
+    pub fn capture_session(&mut self) {
+        self.settings.session = if self.settings.restore_session {
+            Some(crate::config::SessionState {
+                open_file: self.current_file_position(),
+                ai_panel: if self.is_ai_mode() { self.ai_panel_index } else { None },
+            })
+        } else {
+            None
+        };
+    }
+
+    /// Reopen the AI panel and viewer/editor saved by `capture_session`
+    pub fn restore_session(&mut self) {
+        if !self.settings.restore_session {
+            return;
+        }
+        let Some(session) = self.settings.session.take() else { return };
+
+        if let Some(ai_idx) = session.ai_panel.filter(|&i| i < self.panels.len()) {
+            let focused = self.active_panel_index;
+            // show_ai_screen opens the AI in the panel after the active one and focuses it
+            self.active_panel_index = (ai_idx + self.panels.len() - 1) % self.panels.len();
+            self.show_ai_screen();
+            if self.is_ai_mode() && focused != ai_idx {
+                self.ai_previous_panel = Some(focused);
+                self.active_panel_index = focused;
+            }
+        }
+        if let Some(entry) = session.open_file {
+            if Path::new(&entry.path).is_file() {
+                self.open_at_position(&entry);
+            }
+        }
+    }
+
+    /// Show the "save as" dialog for the image in the viewer.
+    /// `resize` starts on the size row and keeps the original format.
+    pub fn show_image_export_dialog(&mut self, resize: bool) {
+        use crate::services::image_export::{ExportFormat, ExportOptions, DEFAULT_JPEG_QUALITY};
+
+        if self.active_panel().is_remote() {
+            self.show_message("Save as is not available for remote images");
+            return;
+        }
+        let Some(viewer) = self.image_viewer_state.as_ref() else { return };
+        if viewer.image.is_none() {
+            return;
+        }
+        let source_format = ExportFormat::from_path(&viewer.path);
+        let (format, scale_percent, selected_row) = if resize {
+            (source_format.unwrap_or(ExportFormat::Png), 50, 2)
+        } else {
+            // Default to a different format than the source
+            let format = match source_format {
+                Some(ExportFormat::Png) => ExportFormat::Jpeg,
+                _ => ExportFormat::Png,
+            };
+            (format, 100, 0)
+        };
+        self.image_export_state = Some(ImageExportState {
+            src: viewer.path.clone(),
+            original_size: viewer.original_size,
+            options: ExportOptions { format, quality: DEFAULT_JPEG_QUALITY, scale_percent },
+            selected_row,
+        });
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::ImageExport,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// Write the converted image next to the original and focus it in the panel
+    pub fn execute_image_export(&mut self) {
+        self.dialog = None;
+        let Some(state) = self.image_export_state.take() else { return };
+
+        match crate::services::image_export::export(&state.src, &state.options) {
+            Ok((dest, (width, height))) => {
+                let name = dest.file_name().map(|n| n.to_string_lossy().to_string());
+                self.image_viewer_state = None;
+                self.current_screen = Screen::FilePanel;
+                if let Some(parent) = dest.parent() {
+                    let panel = self.active_panel_mut();
+                    if panel.path != parent {
+                        panel.path = parent.to_path_buf();
+                        panel.selected_index = 0;
+                        panel.selected_files.clear();
+                    }
+                    panel.pending_focus = name.clone();
+                    // Uncached reload so the new file is listed
+                    panel.load_files();
+                }
+                self.show_message(&format!(
+                    "Saved {} ({}x{})",
+                    name.unwrap_or_default(),
+                    width,
+                    height
+                ));
+            }
+            Err(e) => self.show_message(&format!("Save as failed: {}", e)),
+        }
+    }
+
+    /// Show the recent files dialog
+    pub fn show_recent_files_dialog(&mut self) {
+        if self.settings.recent_files.is_empty() {
+            self.show_message("No recent files");
+            return;
+        }
+        let mut state = RecentFilesState {
+            entries: self.settings.recent_files.clone(),
+            filtered: Vec::new(),
+            selected_index: 0,
+            scroll_offset: 0,
+            visible_height: 20,
+        };
+        state.apply_filter("");
+        self.recent_files_state = Some(state);
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::RecentFiles,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// Show the jump dialog: visited directories ranked by frecency
+    pub fn show_jump_dialog(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Jump is not available for remote panels");
+            return;
+        }
+        let mut history = crate::services::frecency::Frecency::load();
+        if history.prune_missing() {
+            let _ = history.save();
+        }
+        if history.entries.is_empty() && self.settings.smart_folders.is_empty() {
+            self.show_message("No visited directories yet");
+            return;
+        }
+        let mut state = JumpState {
+            history,
+            smart_folders: self.settings.smart_folders.clone(),
+            smart_matches: Vec::new(),
+            matches: Vec::new(),
+            selected_index: 0,
+            scroll_offset: 0,
+            visible_height: 20,
+        };
+        state.apply_filter("");
+        self.jump_state = Some(state);
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::JumpDirectory,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// Show or hide the favorites bar above the panels
+    pub fn toggle_favorites_bar(&mut self) {
+        self.settings.favorites.show_bar = !self.settings.favorites.show_bar;
+        let _ = self.settings.save();
+    }
+
+    /// Move the active panel to favorite number `n` (1-9)
+    pub fn goto_favorite(&mut self, n: usize) {
+        match self.settings.favorites.paths.get(n.wrapping_sub(1)).cloned() {
+            Some(path) => self.execute_goto(&path),
+            None => self.show_message(&format!("No favorite {} (edit favorites with {})",
+                n, self.keybindings.panel_first_key(crate::keybindings::PanelAction::Favorites))),
+        }
+    }
+
+    /// Add the active panel's directory to the favorites
+    pub fn add_current_to_favorites(&mut self) {
+        let Some(location) = self.current_location() else { return };
+        match self.settings.favorites.add(location.clone()) {
+            Some(n) => {
+                if let Some(ref mut state) = self.favorites_state {
+                    state.selected_index = n - 1;
+                }
+                self.show_message(&format!("Favorite {}: {}", n, location));
+                let _ = self.settings.save();
+            }
+            None => self.show_message(&format!(
+                "Favorites are full ({}), remove one first", crate::config::FAVORITES_MAX)),
+        }
+    }
+
+    /// Favorites dialog: go to, add, remove and reorder favorite directories
+    pub fn show_favorites_dialog(&mut self) {
+        let current = self.current_location();
+        let selected_index = current
+            .and_then(|c| self.settings.favorites.paths.iter().position(|p| *p == c))
+            .unwrap_or(0);
+        self.favorites_state = Some(FavoritesState { selected_index });
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::Favorites,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// Move the active panel to the directory selected in the jump dialog
+    pub fn jump_to_selected(&mut self) {
+        self.dialog = None;
+        let Some(state) = self.jump_state.take() else { return };
+        if let Some(folder) = state.selected_smart_folder() {
+            self.open_smart_folder(folder);
+            return;
+        }
+        let Some(path) = state.selected_path().map(PathBuf::from) else { return };
+        if !path.is_dir() {
+            self.show_message(&format!("Directory not found: {}", path.display()));
+            return;
+        }
+        self.goto_directory_with_focus(&path, None);
+    }
+
+    /// 경로 브레드크럼 메뉴: 상위 폴더와 각 단계의 형제 폴더로 바로 이동
+    pub fn show_breadcrumb_menu(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("The path menu is not available for remote panels");
+            return;
+        }
+        let panel = self.active_panel();
+        self.breadcrumb_state = Some(BreadcrumbState::new(&panel.path, panel.show_hidden));
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::Breadcrumb,
             input: String::new(),
             cursor_pos: 0,
             message: String::new(),
@@ -3504,6 +6304,124 @@ impl App {
         });
     }
 
+    /// Go to the folder selected in the path menu. Picking an ancestor puts the
+    /// cursor on the folder the panel came from.
+    pub fn breadcrumb_go(&mut self) {
+        self.dialog = None;
+        let Some(state) = self.breadcrumb_state.take() else { return };
+        let Some(path) = state.selected_path() else { return };
+        if !path.is_dir() {
+            self.show_message(&format!("Directory not found: {}", path.display()));
+            return;
+        }
+        let focus = state.components.iter()
+            .position(|c| *c == path)
+            .and_then(|i| state.components.get(i + 1))
+            .and_then(|child| child.file_name())
+            .map(|n| n.to_string_lossy().to_string());
+        self.goto_directory_with_focus(&path, focus);
+    }
+
+    /// Reopen the selected recent file in the viewer or editor at its saved position
+    pub fn open_recent_file(&mut self) {
+        self.dialog = None;
+        let Some(state) = self.recent_files_state.take() else { return };
+        let Some(entry) = state.selected_entry().cloned() else { return };
+
+        let path = PathBuf::from(&entry.path);
+        if !path.is_file() {
+            self.settings.recent_files.retain(|f| f.path != entry.path);
+            self.show_message(&format!("File not found: {}", entry.path));
+            return;
+        }
+        // Move the panel to the file so closing the viewer/editor lands on it
+        if !self.active_panel().is_remote() {
+            if let Some(parent) = path.parent() {
+                let file_name = path.file_name().map(|n| n.to_string_lossy().to_string());
+                self.goto_directory_with_focus(parent, file_name);
+            }
+        }
+
+        self.open_at_position(&entry);
+    }
+
+    /// Open a file in the viewer or editor (as recorded) and restore its position
+    fn open_at_position(&mut self, entry: &crate::config::RecentFile) {
+        let path = PathBuf::from(&entry.path);
+        if entry.edited {
+            let mut editor = EditorState::new();
+            editor.set_syntax_colors(self.theme.syntax);
+            match editor.load_file(&path) {
+                Ok(_) => {
+                    editor.marks = entry.marks.clone();
+                    editor.goto_position(entry.line + 1, entry.col + 1);
+                    editor.scroll = entry.scroll.min(editor.cursor_line);
+                    self.editor_state = Some(editor);
+                    self.current_screen = Screen::FileEditor;
+                }
+                Err(e) => self.show_message(&format!("Cannot open file: {}", e)),
+            }
+        } else {
+            let mut viewer = ViewerState::new();
+            viewer.set_syntax_colors(self.theme.syntax);
+            match viewer.load_file(&path) {
+                Ok(_) => {
+                    viewer.marks = entry.marks.clone();
+                    viewer.restore_position(entry.scroll, &entry.search);
+                    self.viewer_state = Some(viewer);
+                    self.current_screen = Screen::FileViewer;
+                }
+                Err(e) => self.show_message(&format!("Cannot read file: {}", e)),
+            }
+        }
+    }
+
+    /// Run the chosen "Open with" command (or a typed one) and remember it
+    pub fn execute_open_with(&mut self, custom_command: Option<String>) {
+        self.dialog = None;
+        let Some(state) = self.open_with_state.take() else { return };
+
+        let command_template = match custom_command {
+            Some(cmd) => {
+                // A typed command without placeholder gets the file appended
+                if cmd.contains("{{FILEPATH}}") {
+                    cmd
+                } else {
+                    format!("{} \"{{{{FILEPATH}}}}\"", cmd)
+                }
+            }
+            None => match state.selected_entry() {
+                Some(entry) => entry.command.clone(),
+                None => return,
+            },
+        };
+
+        let (is_background_mode, template) = match command_template.strip_prefix('@') {
+            Some(rest) => (true, rest),
+            None => (false, command_template.as_str()),
+        };
+        let command = template.replace("{{FILEPATH}}", &state.file_path.to_string_lossy());
+        let cwd = self.active_panel().path.clone();
+
+        let result = if is_background_mode {
+            self.execute_background_command(&command, template, &cwd)
+        } else {
+            self.execute_terminal_command(&command, &cwd)
+                .and_then(|ok| if ok { Ok(true) } else { Err(format!("Command failed: {}", template)) })
+        };
+
+        match result {
+            Ok(_) => {
+                if !state.extension.is_empty() {
+                    self.settings.add_open_with_history(&state.extension, &command_template);
+                    let _ = self.settings.save();
+                }
+                self.refresh_panels();
+            }
+            Err(e) => self.show_extension_handler_error(&e),
+        }
+    }
+
     pub fn execute_git_log_diff(&mut self) {
         self.dialog = None;
 
@@ -3592,12 +6510,54 @@ impl App {
         });
     }
 
-    #[allow(dead_code)]
     pub fn show_advanced_search_dialog(&mut self) {
         self.advanced_search_state.active = true;
         self.advanced_search_state.reset();
     }
 
+    /// Ask for a name to save advanced search criteria as a smart folder of the current directory
+    pub fn show_save_smart_folder_dialog(&mut self, criteria: crate::ui::advanced_search::SearchCriteria) {
+        if self.active_panel().is_remote() {
+            self.show_message("Smart folders are not available for remote panels");
+            return;
+        }
+        let message = format!("Searches {}", self.active_panel().path.display());
+        self.pending_smart_folder = Some(criteria);
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::SaveSmartFolder,
+            input: String::new(),
+            cursor_pos: 0,
+            message,
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// Save the pending advanced search criteria as smart folder `name`
+    pub fn execute_save_smart_folder(&mut self, name: &str) {
+        let Some(criteria) = self.pending_smart_folder.take() else { return };
+        let path = self.active_panel().path.display().to_string();
+        let name = name.trim();
+        self.settings.save_smart_folder(criteria.to_smart_folder(name, &path));
+        let _ = self.settings.save();
+        self.show_message(&format!("Smart folder saved: {} (open it from {})",
+            name, self.keybindings.panel_first_key(crate::keybindings::PanelAction::JumpDirectory)));
+    }
+
+    /// Run a smart folder's search and show the matching files in the search results
+    pub fn open_smart_folder(&mut self, folder: &crate::config::SmartFolder) {
+        let base_path = PathBuf::from(&folder.path);
+        if !base_path.is_dir() {
+            self.show_message(&format!("Directory not found: {}", folder.path));
+            return;
+        }
+        let criteria = crate::ui::advanced_search::SearchCriteria::from_smart_folder(folder);
+        self.search_result_state.start_criteria_search(base_path, folder.name.clone(), criteria, 1000);
+        self.current_screen = Screen::SearchResult;
+    }
+
     pub fn execute_advanced_search(&mut self, criteria: &crate::ui::advanced_search::SearchCriteria) {
         let panel = self.active_panel_mut();
         let mut matched_count = 0;
@@ -3632,9 +6592,15 @@ impl App {
         if self.current_screen == Screen::ImageViewer {
             if let Some(ref state) = self.image_viewer_state {
                 let path = state.path.clone();
-                match file_ops::delete_file(&path) {
+                let to_trash = self.settings.delete_to_trash;
+                let result = if to_trash {
+                    crate::services::trash::move_to_trash(&path).map(|_| ())
+                } else {
+                    file_ops::delete_file(&path)
+                };
+                match result {
                     Ok(_) => {
-                        self.show_message("Deleted image");
+                        self.show_message(if to_trash { "Moved image to trash" } else { "Deleted image" });
                         // 이미지 뷰어 닫기
                         self.current_screen = Screen::FilePanel;
                         self.image_viewer_state = None;
@@ -3708,90 +6674,379 @@ impl App {
             });
             return;
         } else {
-            // Local delete in background thread with spinner
-            if self.remote_spinner.is_some() { return; }
+            // Local delete through the progress pipeline (per-file progress, cancel with Esc)
+            if self.file_operation_progress.is_some() { return; }
             let files_to_delete: Vec<PathBuf> = files.iter()
                 .map(|f| source_path.join(f))
                 .collect();
-            let total = files_to_delete.len();
+            let to_trash = self.settings.delete_to_trash;
+            let operation_type = if to_trash { FileOperationType::Trash } else { FileOperationType::Delete };
+
+            let mut progress = FileOperationProgress::new(operation_type);
+            progress.is_active = true;
+            progress.dirs = vec![source_path.clone()];
+            let cancel_flag = progress.cancel_flag.clone();
+
             let (tx, rx) = mpsc::channel();
+            progress.receiver = Some(rx);
 
-            thread::spawn(move || {
-                let mut success_count = 0;
-                let mut last_error = String::new();
-                for path in &files_to_delete {
-                    match file_ops::delete_file(path) {
-                        Ok(_) => success_count += 1,
-                        Err(e) => last_error = e.to_string(),
-                    }
+            file_ops::spawn_worker(tx, None, move |tx| {
+                file_ops::delete_files_with_progress(files_to_delete, to_trash, cancel_flag, tx);
+            });
+
+            self.file_operation_progress = Some(progress);
+            self.dialog = Some(Dialog {
+                dialog_type: DialogType::Progress,
+                input: String::new(),
+                cursor_pos: 0,
+                message: String::new(),
+                completion: None,
+                selected_button: 0,
+                selection: None,
+                use_md5: false,
+            });
+        }
+    }
+
+    // ========== Clipboard operations (Ctrl+C/X/V) ==========
+
+    /// Copy the current file's contents to the system clipboard via OSC 52
+    /// Works over SSH and inside tmux; large files are truncated with a warning
+    pub fn copy_file_contents_to_system_clipboard(&mut self) {
+        use std::io::Read;
+        use crate::utils::osc52;
+
+        if self.active_panel().is_remote() {
+            self.show_message("Clipboard copy is not available for remote files");
+            return;
+        }
+        let panel = self.active_panel();
+        let Some(file) = panel.files.get(panel.selected_index) else { return };
+        if file.is_directory {
+            return;
+        }
+        let file_name = file.name.clone();
+        let path = panel.path.join(&file.name);
+        if Self::is_binary_file(&path) {
+            self.show_message("Binary file cannot be copied to clipboard");
+            return;
+        }
+
+        let total = std::fs::metadata(&path).map(|m| m.len() as usize).unwrap_or(0);
+        // Read only what fits in the clipboard (+3 bytes to finish a UTF-8 char)
+        let mut bytes = Vec::new();
+        let read = std::fs::File::open(&path)
+            .and_then(|f| f.take(osc52::MAX_COPY_BYTES as u64 + 3).read_to_end(&mut bytes));
+        if let Err(e) = read {
+            self.show_message(&format!("Read error: {}", e));
+            return;
+        }
+        let text = String::from_utf8_lossy(&bytes);
+
+        match osc52::copy(&text) {
+            Ok(result) => {
+                let result = osc52::CopyResult { total: total.max(result.copied), ..result };
+                self.show_message(&result.message(&file_name));
+                // Truncation warning needs more time to read
+                if result.truncated() {
+                    self.message_timer = 30;
                 }
-                let msg = if success_count == total {
-                    Ok(format!("Deleted {} file(s)", success_count))
+            }
+            Err(e) => self.show_message(&format!("Clipboard error: {}", e)),
+        }
+    }
+
+    /// Copy a shell command for the selection to the system clipboard:
+    /// `cd` into a directory under the cursor, or `cp` the selection to the other panel.
+    /// Paths are quoted with shell_escape so the command can be pasted into a terminal
+    pub fn copy_shell_command(&mut self) {
+        use crate::utils::format::shell_escape;
+
+        if self.active_panel().is_remote() {
+            self.show_message("Shell commands are not available for remote panels");
+            return;
+        }
+        let panel = self.active_panel();
+        let files = self.get_operation_files();
+        let escape = |path: &Path| shell_escape(&path.display().to_string());
+
+        let is_dir = |name: &String| panel.files.iter().any(|f| &f.name == name && f.is_directory);
+        let (command, what) = match files.as_slice() {
+            // 선택 없음 (..) → 현재 디렉토리로 이동
+            [] => (format!("cd {}", escape(&panel.path)), "cd command".to_string()),
+            [name] if panel.selected_files.is_empty() && is_dir(name) => {
+                (format!("cd {}", escape(&panel.path.join(name))), "cd command".to_string())
+            }
+            _ => {
+                let sources: Vec<String> = files.iter().map(|f| escape(&panel.path.join(f))).collect();
+                let target = self.target_panel();
+                // 대상 패널이 원격이거나 같은 경로면 경로 목록만 복사
+                if target.is_remote() || target.path == panel.path {
+                    (sources.join(" "), format!("{} path(s)", sources.len()))
                 } else {
-                    Err(format!("Deleted {}/{}. Error: {}", success_count, total, last_error))
-                };
-                let _ = tx.send(RemoteSpinnerResult::LocalOp {
-                    message: msg,
-                    reload: true,
-                });
-            });
+                    let flag = if files.iter().any(is_dir) { "cp -R" } else { "cp" };
+                    let dest = format!("{}/", escape(&target.path).trim_end_matches('/'));
+                    (format!("{} {} {}", flag, sources.join(" "), dest), "cp command".to_string())
+                }
+            }
+        };
+
+        match crate::utils::osc52::copy(&command) {
+            Ok(result) => self.show_message(&result.message(&what)),
+            Err(e) => self.show_message(&format!("Clipboard error: {}", e)),
+        }
+    }
+
+    /// Copy selected files to clipboard (Ctrl+C)
+    pub fn clipboard_copy(&mut self) {
+        let files = self.get_operation_files();
+        if files.is_empty() {
+            self.show_message("No files selected");
+            return;
+        }
+
+        let source_path = self.active_panel().path.clone();
+        let source_remote_profile = self.active_panel().remote_ctx.as_ref().map(|c| c.profile.clone());
+        let count = files.len();
+
+        self.clipboard = Some(Clipboard {
+            files,
+            source_path,
+            operation: ClipboardOperation::Copy,
+            source_remote_profile,
+        });
+
+        self.show_message(&format!("{} file(s) copied to clipboard", count));
+    }
+
+    /// Cut selected files to clipboard (Ctrl+X)
+    pub fn clipboard_cut(&mut self) {
+        let files = self.get_operation_files();
+        if files.is_empty() {
+            self.show_message("No files selected");
+            return;
+        }
+
+        let source_path = self.active_panel().path.clone();
+        let source_remote_profile = self.active_panel().remote_ctx.as_ref().map(|c| c.profile.clone());
+        let count = files.len();
+
+        self.clipboard = Some(Clipboard {
+            files,
+            source_path,
+            operation: ClipboardOperation::Cut,
+            source_remote_profile,
+        });
+
+        self.show_message(&format!("{} file(s) cut to clipboard", count));
+    }
+
+    // ========== Basket (files from several directories) ==========
+
+    /// Add the selected files (or the one under the cursor) to the basket
+    pub fn add_to_basket(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("The basket only holds local files");
+            return;
+        }
+        let files = self.get_operation_files();
+        if files.is_empty() {
+            self.show_message("No files selected");
+            return;
+        }
+        let dir = self.active_panel().path.clone();
+        let mut added = 0;
+        for name in files.iter().filter(|f| *f != "..") {
+            let path = dir.join(name);
+            if !self.basket.contains(&path) {
+                self.basket.push(path);
+                added += 1;
+            }
+        }
+        self.active_panel_mut().selected_files.clear();
+        self.show_message(&format!("Added {} to the basket ({} item(s))", added, self.basket.len()));
+    }
+
+    /// Basket dialog: copy, move or zip the collected files into the active panel
+    pub fn show_basket_dialog(&mut self) {
+        if self.basket.is_empty() {
+            let key = self.keybindings.panel_first_key(crate::keybindings::PanelAction::AddToBasket);
+            self.show_message(&format!("The basket is empty (add files with {})", key));
+            return;
+        }
+        self.basket_state = Some(BasketState::default());
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::Basket,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
 
-            self.remote_spinner = Some(RemoteSpinner {
-                message: "Deleting...".to_string(),
-                started_at: Instant::now(),
-                receiver: rx,
-            });
+    /// Drop entries that no longer exist and check the rest can sit side by
+    /// side (in `target`, when copying or moving). Returns the reason when
+    /// they cannot.
+    fn check_basket_target(&mut self, target: Option<&Path>) -> Result<(), String> {
+        self.basket.retain(|p| p.symlink_metadata().is_ok());
+        if self.basket.is_empty() {
+            return Err("The basket is empty".to_string());
+        }
+        let mut names = HashSet::new();
+        for path in &self.basket {
+            let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            if !names.insert(name.clone()) {
+                return Err(format!("The basket holds more than one '{}'", name));
+            }
+            if target.is_some() && path.parent() == target {
+                return Err(format!("'{}' is already in this folder", name));
+            }
         }
+        Ok(())
     }
 
-    // ========== Clipboard operations (Ctrl+C/X/V) ==========
-
-    /// Copy selected files to clipboard (Ctrl+C)
-    pub fn clipboard_copy(&mut self) {
-        let files = self.get_operation_files();
-        if files.is_empty() {
-            self.show_message("No files selected");
+    /// Copy or move the whole basket into the active panel as one operation.
+    /// The basket becomes the clipboard, so the usual conflict handling
+    /// applies; a move empties the basket.
+    pub fn basket_paste(&mut self, operation: ClipboardOperation) {
+        self.basket_state = None;
+        self.dialog = None;
+        if self.active_panel().is_remote() {
+            self.show_message("The basket can only be pasted into a local folder");
             return;
         }
-
-        let source_path = self.active_panel().path.clone();
-        let source_remote_profile = self.active_panel().remote_ctx.as_ref().map(|c| c.profile.clone());
-        let count = files.len();
-
+        let target = self.active_panel().path.clone();
+        if let Err(e) = self.check_basket_target(Some(&target)) {
+            self.show_message(&e);
+            return;
+        }
+        // 절대 경로라서 source_path("/")와 합쳐도 그대로
         self.clipboard = Some(Clipboard {
-            files,
-            source_path,
-            operation: ClipboardOperation::Copy,
-            source_remote_profile,
+            files: self.basket.iter().map(|p| p.display().to_string()).collect(),
+            source_path: PathBuf::from("/"),
+            operation,
+            source_remote_profile: None,
         });
-
-        self.show_message(&format!("{} file(s) copied to clipboard", count));
+        if operation == ClipboardOperation::Cut {
+            self.basket.clear();
+        }
+        self.clipboard_paste();
     }
 
-    /// Cut selected files to clipboard (Ctrl+X)
-    pub fn clipboard_cut(&mut self) {
-        let files = self.get_operation_files();
-        if files.is_empty() {
-            self.show_message("No files selected");
+    /// Ask for the name of the zip archive made from the basket
+    pub fn show_basket_archive_dialog(&mut self) {
+        self.basket_state = None;
+        if self.active_panel().is_remote() {
+            self.dialog = None;
+            self.show_message("The basket can only be archived into a local folder");
             return;
         }
+        let input = "basket.zip".to_string();
+        let cursor_pos = input.chars().count() - ".zip".len();
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::BasketArchive,
+            input,
+            cursor_pos,
+            message: format!("Zip {} basket item(s) into this folder as:", self.basket.len()),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
 
-        let source_path = self.active_panel().path.clone();
-        let source_remote_profile = self.active_panel().remote_ctx.as_ref().map(|c| c.profile.clone());
-        let count = files.len();
-
-        self.clipboard = Some(Clipboard {
-            files,
-            source_path,
-            operation: ClipboardOperation::Cut,
-            source_remote_profile,
+    /// Zip the basket into the active panel
+    pub fn execute_basket_archive(&mut self, name: &str) {
+        let name = if name.to_lowercase().ends_with(".zip") { name.to_string() } else { format!("{}.zip", name) };
+        if let Err(e) = file_ops::is_valid_filename(&name) {
+            self.show_message(e);
+            return;
+        }
+        let dir = self.active_panel().path.clone();
+        let archive_path = dir.join(&name);
+        if archive_path.exists() {
+            self.show_message(&format!("'{}' already exists", name));
+            return;
+        }
+        if let Err(e) = self.check_basket_target(None) {
+            self.show_message(&e);
+            return;
+        }
+        let paths = self.basket.clone();
+        let (tx, cancel_flag) = self.start_archive_progress(FileOperationType::Zip);
+        self.pending_tar_archive = Some(name);
+        file_ops::spawn_worker(tx, Some(archive_path.clone()), move |tx| {
+            file_ops::create_zip_from_paths(&paths, &archive_path, None, tx, cancel_flag);
         });
+    }
 
-        self.show_message(&format!("{} file(s) cut to clipboard", count));
+    /// Save the image in the system clipboard as a timestamped PNG in the current panel
+    pub fn paste_clipboard_image(&mut self) {
+        if self.active_panel().is_remote() {
+            self.show_message("Paste image is not available on remote panels");
+            return;
+        }
+        let dir = self.active_panel().path.clone();
+        match crate::services::clipboard_image::save_to(&dir) {
+            Ok(path) => {
+                let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                let panel = self.active_panel_mut();
+                panel.pending_focus = Some(name.clone());
+                panel.load_files();
+                self.show_message(&format!("Saved clipboard image as {}", name));
+            }
+            Err(e) => self.show_message(&format!("Paste image: {}", e)),
+        }
     }
 
     /// Paste files from clipboard to current panel (Ctrl+V)
     pub fn clipboard_paste(&mut self) {
+        self.clipboard_paste_excluding(Vec::new());
+    }
+
+    /// Ask for exclude patterns (e.g. "target/ node_modules/ *.o"), then paste
+    pub fn show_paste_exclude_dialog(&mut self) {
+        let Some(clipboard) = self.clipboard.as_ref() else {
+            self.show_message("Clipboard is empty");
+            return;
+        };
+        if clipboard.source_remote_profile.is_some() || self.active_panel().is_remote() {
+            self.show_message("Exclude patterns are not available for remote transfers");
+            return;
+        }
+
+        let verb = match clipboard.operation {
+            ClipboardOperation::Copy => "Copy",
+            ClipboardOperation::Cut => "Move",
+        };
+        let message = format!("{} {} item(s), excluding:", verb, clipboard.files.len());
+        let input = self.settings.paste_exclude.join(" ");
+        let cursor_pos = input.chars().count();
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::PasteExclude,
+            input,
+            cursor_pos,
+            message,
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+    }
+
+    /// Paste with the patterns entered in the exclude dialog (remembered for next time)
+    pub fn execute_paste_exclude(&mut self, input: &str) {
+        let excludes = file_ops::parse_exclude_patterns(input);
+        self.settings.paste_exclude = excludes.clone();
+        self.clipboard_paste_excluding(excludes);
+    }
+
+    /// Paste clipboard files, leaving out entries that match `excludes`.
+    /// Patterns apply to local copies/moves into another folder.
+    fn clipboard_paste_excluding(&mut self, excludes: Vec<String>) {
         let clipboard = match self.clipboard.take() {
             Some(cb) => cb,
             None => {
@@ -3841,11 +7096,12 @@ impl App {
                 let mut progress = FileOperationProgress::new(op_type);
                 progress.is_active = true;
                 progress.total_files = file_paths.len();
+                progress.dirs = vec![clipboard.source_path.clone(), target_path.clone()];
                 let cancel_flag = progress.cancel_flag.clone();
                 let (tx, rx) = mpsc::channel();
                 progress.receiver = Some(rx);
 
-                thread::spawn(move || {
+                file_ops::spawn_worker(tx, None, move |tx| {
                     remote_transfer::transfer_remote_to_remote_with_progress(
                         source_profile,
                         target_profile,
@@ -3918,6 +7174,7 @@ impl App {
             let mut progress = FileOperationProgress::new(op_type);
             progress.is_active = true;
             progress.total_files = file_paths.len();
+            progress.dirs = vec![clipboard.source_path.clone(), target_path.clone()];
             let cancel_flag = progress.cancel_flag.clone();
             let (tx, rx) = mpsc::channel();
             progress.receiver = Some(rx);
@@ -3930,7 +7187,7 @@ impl App {
                 target_path: target,
             };
 
-            thread::spawn(move || {
+            file_ops::spawn_worker(tx, None, move |tx| {
                 remote_transfer::transfer_files_with_progress(
                     config, cancel_flag, tx, is_cut, source_profile_for_delete,
                 );
@@ -3959,7 +7216,9 @@ impl App {
         let target_path = self.active_panel().path.clone();
 
         // Check if source and target are the same (use canonical paths for robustness)
-        let is_same_folder = match (clipboard.source_path.canonicalize(), target_path.canonicalize()) {
+        // Basket items are absolute paths from different folders
+        let from_basket = clipboard.files.iter().all(|f| Path::new(f).is_absolute());
+        let is_same_folder = !from_basket && match (clipboard.source_path.canonicalize(), target_path.canonicalize()) {
             (Ok(src), Ok(dest)) => src == dest,
             _ => clipboard.source_path == target_path, // Fallback to direct comparison
         };
@@ -4016,9 +7275,9 @@ impl App {
         let conflicts = self.detect_paste_conflicts(&clipboard, &target_path, &valid_files);
 
         if !conflicts.is_empty() {
-            // Has conflicts - show conflict dialog
+            // Has conflicts - resolve with the default policy or show conflict dialog
             let is_move = clipboard.operation == ClipboardOperation::Cut;
-            self.conflict_state = Some(ConflictState {
+            let mut state = ConflictState {
                 conflicts,
                 current_index: 0,
                 files_to_overwrite: Vec::new(),
@@ -4026,13 +7285,22 @@ impl App {
                 clipboard_backup: Some(clipboard),
                 is_move_operation: is_move,
                 target_path: target_path.clone(),
-            });
+                excludes,
+            };
+            let policy = self.settings.conflict_policy;
+            if policy != ConflictPolicy::Ask {
+                state.resolve_remaining(policy);
+                self.conflict_state = Some(state);
+                self.execute_paste_with_conflicts();
+                return;
+            }
+            self.conflict_state = Some(state);
             self.show_duplicate_conflict_dialog();
             return;
         }
 
         // No conflicts - proceed with normal paste
-        self.execute_paste_operation(clipboard, valid_files, target_path);
+        self.execute_paste_operation(clipboard, valid_files, target_path, excludes);
     }
 
     /// Detect files that would conflict (already exist) at paste destination
@@ -4046,7 +7314,7 @@ impl App {
 
         for file_name in valid_files {
             let src = clipboard.source_path.join(file_name);
-            let dest = target_dir.join(file_name);
+            let dest = target_dir.join(entry_file_name(file_name));
 
             if dest.exists() {
                 conflicts.push((src, dest, file_name.clone()));
@@ -4114,42 +7382,69 @@ impl App {
     }
 
     /// Execute paste operation (internal, called after conflict resolution or when no conflicts)
-    fn execute_paste_operation(&mut self, clipboard: Clipboard, valid_files: Vec<String>, target_path: PathBuf) {
+    fn execute_paste_operation(&mut self, clipboard: Clipboard, valid_files: Vec<String>, target_path: PathBuf, excludes: Vec<String>) {
         // Set pending focus to pasted file names (will find first match in sorted file list)
         if !valid_files.is_empty() {
-            self.pending_paste_focus = Some(valid_files.clone());
+            self.pending_paste_focus = Some(valid_files.iter().map(|f| entry_file_name(f)).collect());
+        }
+
+        // Start operation in background thread
+        let file_paths: Vec<PathBuf> = valid_files.iter().map(PathBuf::from).collect();
+        self.start_paste_worker(clipboard.operation, file_paths, clipboard.source_path.clone(), target_path, HashSet::new(), HashSet::new(), excludes);
+
+        // Keep clipboard for copy operations (can paste multiple times)
+        // Clear clipboard for cut operations (files are moved)
+        if clipboard.operation == ClipboardOperation::Copy {
+            self.clipboard = Some(clipboard);
         }
+    }
+
+    /// 복사/이동 작업을 백그라운드 스레드에서 실행하고 프로그레스 다이얼로그 표시.
+    /// 작업은 끝날 때까지 크래시 저널에 기록된다 (다음 실행 시 이어하기/정리 제공)
+    #[allow(clippy::too_many_arguments)]
+    fn start_paste_worker(
+        &mut self,
+        operation: ClipboardOperation,
+        file_paths: Vec<PathBuf>,
+        source_path: PathBuf,
+        target_path: PathBuf,
+        files_to_overwrite: HashSet<PathBuf>,
+        files_to_skip: HashSet<PathBuf>,
+        excludes: Vec<String>,
+    ) {
+        use crate::services::journal::{self, OperationKind};
 
         // Determine operation type for progress
-        let operation_type = match clipboard.operation {
-            ClipboardOperation::Copy => FileOperationType::Copy,
-            ClipboardOperation::Cut => FileOperationType::Move,
+        let (operation_type, journal_kind) = match operation {
+            ClipboardOperation::Copy => (FileOperationType::Copy, OperationKind::Copy),
+            ClipboardOperation::Cut => (FileOperationType::Move, OperationKind::Move),
         };
 
         // Create progress state
         let mut progress = FileOperationProgress::new(operation_type);
         progress.is_active = true;
+        progress.dirs = vec![source_path.clone(), target_path.clone()];
         let cancel_flag = progress.cancel_flag.clone();
 
         // Create channel for progress messages
         let (tx, rx) = mpsc::channel();
         progress.receiver = Some(rx);
 
-        // Convert files to PathBuf
-        let file_paths: Vec<PathBuf> = valid_files.iter().map(PathBuf::from).collect();
-        let source_path = clipboard.source_path.clone();
-
-        // Start operation in background thread
-        let clipboard_operation = clipboard.operation;
-        thread::spawn(move || {
-            match clipboard_operation {
+        file_ops::spawn_worker(tx, None, move |tx| {
+            let overwrite: Vec<PathBuf> = files_to_overwrite.iter().cloned().collect();
+            let skip: Vec<PathBuf> = files_to_skip.iter().cloned().collect();
+            let _journal = journal::begin_operation(
+                journal_kind, &source_path, &target_path, &file_paths, &overwrite, &skip, &excludes,
+            );
+            match operation {
                 ClipboardOperation::Copy => {
                     file_ops::copy_files_with_progress(
                         file_paths,
                         &source_path,
                         &target_path,
-                        HashSet::new(),
-                        HashSet::new(),
+                        files_to_overwrite,
+                        files_to_skip,
+                        excludes,
                         cancel_flag,
                         tx,
                     );
@@ -4159,8 +7454,9 @@ impl App {
                         file_paths,
                         &source_path,
                         &target_path,
-                        HashSet::new(),
-                        HashSet::new(),
+                        files_to_overwrite,
+                        files_to_skip,
+                        excludes,
                         cancel_flag,
                         tx,
                     );
@@ -4180,12 +7476,6 @@ impl App {
             selection: None,
             use_md5: false,
         });
-
-        // Keep clipboard for copy operations (can paste multiple times)
-        // Clear clipboard for cut operations (files are moved)
-        if clipboard.operation == ClipboardOperation::Copy {
-            self.clipboard = Some(clipboard);
-        }
     }
 
     /// Execute paste operation for same folder (creates _dup copies)
@@ -4207,6 +7497,7 @@ impl App {
         // Create progress state
         let mut progress = FileOperationProgress::new(FileOperationType::Copy);
         progress.is_active = true;
+        progress.dirs = vec![source_path.clone()];
         let cancel_flag = progress.cancel_flag.clone();
 
         // Create channel for progress messages
@@ -4233,13 +7524,13 @@ impl App {
         }
 
         // Start operation in background thread
-        thread::spawn(move || {
+        file_ops::spawn_worker(tx, None, move |tx| {
             let mut completed = 0;
             let mut failed = 0;
 
             for (src, dest) in rename_map {
                 if cancel_flag.load(std::sync::atomic::Ordering::Relaxed) {
-                    return;
+                    break;
                 }
 
                 let file_name = src.file_name()
@@ -4330,6 +7621,7 @@ impl App {
         };
 
         let target_path = conflict_state.target_path;
+        let excludes = conflict_state.excludes;
 
         // Build all files to process (from original clipboard)
         let valid_files: Vec<String> = clipboard.files.clone();
@@ -4354,7 +7646,7 @@ impl App {
 
         // Set pending focus to all non-skipped file names (will find first match in sorted file list)
         if !files_to_process.is_empty() {
-            self.pending_paste_focus = Some(files_to_process.iter().map(|f| (*f).clone()).collect());
+            self.pending_paste_focus = Some(files_to_process.iter().map(|f| entry_file_name(f)).collect());
         }
 
         if files_to_process.is_empty() {
@@ -4367,66 +7659,9 @@ impl App {
             return;
         }
 
-        // Determine operation type for progress
-        let operation_type = match clipboard.operation {
-            ClipboardOperation::Copy => FileOperationType::Copy,
-            ClipboardOperation::Cut => FileOperationType::Move,
-        };
-
-        // Create progress state
-        let mut progress = FileOperationProgress::new(operation_type);
-        progress.is_active = true;
-        let cancel_flag = progress.cancel_flag.clone();
-
-        // Create channel for progress messages
-        let (tx, rx) = mpsc::channel();
-        progress.receiver = Some(rx);
-
-        // Convert files to PathBuf
-        let file_paths: Vec<PathBuf> = valid_files.iter().map(PathBuf::from).collect();
-        let source_path = clipboard.source_path.clone();
-
         // Start operation in background thread
-        let clipboard_operation = clipboard.operation;
-        thread::spawn(move || {
-            match clipboard_operation {
-                ClipboardOperation::Copy => {
-                    file_ops::copy_files_with_progress(
-                        file_paths,
-                        &source_path,
-                        &target_path,
-                        files_to_overwrite,
-                        files_to_skip,
-                        cancel_flag,
-                        tx,
-                    );
-                }
-                ClipboardOperation::Cut => {
-                    file_ops::move_files_with_progress(
-                        file_paths,
-                        &source_path,
-                        &target_path,
-                        files_to_overwrite,
-                        files_to_skip,
-                        cancel_flag,
-                        tx,
-                    );
-                }
-            }
-        });
-
-        // Store progress state and show dialog
-        self.file_operation_progress = Some(progress);
-        self.dialog = Some(Dialog {
-            dialog_type: DialogType::Progress,
-            input: String::new(),
-            cursor_pos: 0,
-            message: String::new(),
-            completion: None,
-            selected_button: 0,
-            selection: None,
-            use_md5: false,
-        });
+        let file_paths: Vec<PathBuf> = valid_files.iter().map(PathBuf::from).collect();
+        self.start_paste_worker(clipboard.operation, file_paths, clipboard.source_path.clone(), target_path, files_to_overwrite, files_to_skip, excludes);
 
         // Keep clipboard for copy operations (can paste multiple times)
         // Clear clipboard for cut operations (files are moved)
@@ -4466,6 +7701,7 @@ impl App {
             editor.set_syntax_colors(self.theme.syntax);
             match editor.load_file(&path) {
                 Ok(_) => {
+                    editor.marks = self.saved_marks(&path);
                     self.editor_state = Some(editor);
                     self.current_screen = Screen::FileEditor;
                 }
@@ -4770,8 +8006,25 @@ impl App {
 
         let current_dir = self.active_panel().path.clone();
 
+        // Settings of the archive profile chosen in the dialog
+        let profile = self.tar_profile.take().and_then(|i| self.settings.archive_profiles.get(i).cloned());
+        let format = crate::services::archive_profile::ArchiveFormat::from_name(archive_name);
+        let level = profile.as_ref().and_then(|p| p.level);
+        let compress_program = format.compress_program(level);
+        let profile_excludes = profile.map(|p| p.exclude).unwrap_or_default();
+
+        // zip/7z는 tar 대신 별도 경로로 생성
+        use crate::services::archive_profile::ArchiveFormat;
+        if matches!(format, ArchiveFormat::Zip | ArchiveFormat::SevenZ) {
+            self.execute_zip(archive_name, files, excluded_paths, profile_excludes, level, format == ArchiveFormat::SevenZ);
+            return;
+        }
+
         // Determine compression option based on extension
-        let tar_options = if archive_name.ends_with(".tar.gz") || archive_name.ends_with(".tgz") {
+        let tar_options = if compress_program.is_some() {
+            // Compressed by --use-compress-program
+            "cvfp"
+        } else if archive_name.ends_with(".tar.gz") || archive_name.ends_with(".tgz") {
             "cvfpz"
         } else if archive_name.ends_with(".tar.bz2") || archive_name.ends_with(".tbz2") {
             "cvfpj"
@@ -4790,6 +8043,7 @@ impl App {
         // Create progress state with preparing flag - show dialog immediately
         let mut progress = FileOperationProgress::new(FileOperationType::Tar);
         progress.is_active = true;
+        progress.dirs = vec![current_dir.clone()];
         progress.is_preparing = true;
         progress.preparing_message = "Preparing...".to_string();
         let cancel_flag = progress.cancel_flag.clone();
@@ -4819,7 +8073,7 @@ impl App {
         let tar_path = self.settings.tar_path.clone();
 
         // Start all preparation and execution in background thread
-        thread::spawn(move || {
+        file_ops::spawn_worker(tx, Some(archive_path_clone.clone()), move |tx| {
             // Check for cancellation
             if cancel_flag.load(Ordering::Relaxed) {
                 let _ = tx.send(ProgressMessage::Error(archive_name_owned, "Cancelled".to_string()));
@@ -4830,6 +8084,12 @@ impl App {
             // Build tar_args with --exclude options for unsafe symlinks
             // Note: archive name must come right after options (e.g., cvfpz archive.tar.gz)
             let mut tar_args = vec![tar_options_owned.clone(), archive_name_owned.clone()];
+            if let Some(ref program) = compress_program {
+                tar_args.push(format!("--use-compress-program={}", program));
+            }
+            for pattern in &profile_excludes {
+                tar_args.push(format!("--exclude={}", pattern));
+            }
             for excluded in &excluded_owned {
                 tar_args.push(format!("--exclude=./{}", excluded));
             }
@@ -5095,10 +8355,150 @@ impl App {
             }
         }
 
-        (total_files, total_bytes, size_map)
+        (total_files, total_bytes, size_map)
+    }
+
+    /// Execute archive extraction with progress display
+    /// 아카이브 생성/해제 진행 다이얼로그 표시. 작업 스레드용 송신자와 취소 플래그 반환
+    fn start_archive_progress(&mut self, operation_type: FileOperationType) -> (mpsc::Sender<ProgressMessage>, Arc<AtomicBool>) {
+        let mut progress = FileOperationProgress::new(operation_type);
+        progress.is_active = true;
+        // Archives are created and extracted in the active panel
+        progress.dirs = vec![self.active_panel().path.clone()];
+        progress.is_preparing = true;
+        progress.preparing_message = "Preparing...".to_string();
+        let cancel_flag = progress.cancel_flag.clone();
+        let (tx, rx) = mpsc::channel();
+        progress.receiver = Some(rx);
+
+        self.file_operation_progress = Some(progress);
+        self.dialog = Some(Dialog {
+            dialog_type: DialogType::Progress,
+            input: String::new(),
+            cursor_pos: 0,
+            message: String::new(),
+            completion: None,
+            selected_button: 0,
+            selection: None,
+            use_md5: false,
+        });
+        (tx, cancel_flag)
+    }
+
+    /// zip (내장) 또는 7z (외부 명령) 아카이브 생성
+    fn execute_zip(
+        &mut self,
+        archive_name: &str,
+        files: &[String],
+        excluded_paths: &[String],
+        excludes: Vec<String>,
+        level: Option<u32>,
+        seven_zip: bool,
+    ) {
+        let current_dir = self.active_panel().path.clone();
+        let archive_path = current_dir.join(archive_name);
+        let files = files.to_vec();
+        let skip = excluded_paths.to_vec();
+
+        self.active_panel_mut().selected_files.clear();
+        let (tx, cancel_flag) = self.start_archive_progress(FileOperationType::Zip);
+        self.pending_tar_archive = Some(archive_name.to_string());
+
+        file_ops::spawn_worker(tx, Some(archive_path.clone()), move |tx| {
+            if !seven_zip {
+                file_ops::create_zip_with_progress(&current_dir, &files, &archive_path, &excludes, &skip, level, tx, cancel_flag);
+                return;
+            }
+            let Some(program) = file_ops::find_7z() else {
+                let _ = tx.send(ProgressMessage::Error(archive_path.display().to_string(), "7z command not found (install 7-Zip or p7zip)".to_string()));
+                let _ = tx.send(ProgressMessage::Completed(0, 1));
+                return;
+            };
+            let _ = tx.send(ProgressMessage::Preparing("Calculating file sizes...".to_string()));
+            let (_, size_map) = Self::calculate_tar_sizes(&current_dir, &files);
+
+            let mut args = vec!["a".to_string()];
+            if let Some(level) = level {
+                args.push(format!("-mx={}", level.min(9)));
+            }
+            args.extend(excludes.iter().map(|p| format!("-xr!{}", p.trim_end_matches('/'))));
+            args.extend(skip.iter().map(|p| format!("-x!{}", p)));
+            args.push("--".to_string());
+            args.push(archive_path.to_string_lossy().to_string());
+            args.extend(files.iter().cloned());
+            file_ops::run_7z_with_progress(program, &args, &current_dir, size_map.len(), Some(&archive_path), tx, cancel_flag);
+        });
+    }
+
+    /// 아카이브 전체를 같은 이름의 디렉토리에 해제 (tar, zip, 7z)
+    pub fn execute_extract(&mut self, archive_path: &std::path::Path) {
+        let lower = archive_path.to_string_lossy().to_lowercase();
+        if lower.ends_with(".zip") || lower.ends_with(".7z") {
+            self.execute_unzip(archive_path);
+        } else {
+            self.execute_untar(archive_path);
+        }
+    }
+
+    /// zip (내장) 또는 7z (외부 명령) 아카이브를 같은 이름의 디렉토리에 해제
+    pub fn execute_unzip(&mut self, archive_path: &std::path::Path) {
+        if self.active_panel().is_remote() {
+            self.show_message("Archive extraction is not supported on remote panels");
+            return;
+        }
+        let archive_name = match archive_path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => {
+                self.show_message("Invalid archive path");
+                return;
+            }
+        };
+        let seven_zip = archive_name.to_lowercase().ends_with(".7z");
+        let ext_len = if seven_zip { 3 } else { 4 };
+        let extract_dir_name = archive_name[..archive_name.len().saturating_sub(ext_len)].to_string();
+        let current_dir = match archive_path.parent() {
+            Some(dir) if !extract_dir_name.is_empty() => dir.to_path_buf(),
+            _ => {
+                self.show_message("Invalid archive path");
+                return;
+            }
+        };
+        let extract_path = current_dir.join(&extract_dir_name);
+        if extract_path.exists() {
+            self.show_message(&format!("Error: {} already exists", extract_dir_name));
+            return;
+        }
+        if let Err(e) = std::fs::create_dir(&extract_path) {
+            self.show_message(&format!("Failed to create directory: {}", e));
+            return;
+        }
+
+        let (tx, cancel_flag) = self.start_archive_progress(FileOperationType::Unzip);
+        self.pending_extract_dir = Some(extract_dir_name);
+        let archive_path = archive_path.to_path_buf();
+
+        file_ops::spawn_worker(tx, Some(extract_path.clone()), move |tx| {
+            if !seven_zip {
+                file_ops::extract_zip_with_progress(&archive_path, &extract_path, tx, cancel_flag);
+                return;
+            }
+            let Some(program) = file_ops::find_7z() else {
+                let _ = tx.send(ProgressMessage::Error(archive_name, "7z command not found (install 7-Zip or p7zip)".to_string()));
+                let _ = tx.send(ProgressMessage::Completed(0, 1));
+                return;
+            };
+            let _ = tx.send(ProgressMessage::Preparing("Reading archive contents...".to_string()));
+            let total = file_ops::count_7z_entries(program, &archive_path);
+            let args = vec![
+                "x".to_string(),
+                format!("-o{}", extract_path.display()),
+                "--".to_string(),
+                archive_path.to_string_lossy().to_string(),
+            ];
+            file_ops::run_7z_with_progress(program, &args, &extract_path, total, None, tx, cancel_flag);
+        });
     }
 
-    /// Execute archive extraction with progress display
     pub fn execute_untar(&mut self, archive_path: &std::path::Path) {
         if self.active_panel().is_remote() {
             self.show_message("Archive extraction is not supported on remote panels");
@@ -5167,6 +8567,7 @@ impl App {
         // Create progress state with preparing flag - show dialog immediately
         let mut progress = FileOperationProgress::new(FileOperationType::Untar);
         progress.is_active = true;
+        progress.dirs = vec![current_dir.clone()];
         progress.is_preparing = true;
         progress.preparing_message = "Preparing...".to_string();
         let cancel_flag = progress.cancel_flag.clone();
@@ -5193,7 +8594,7 @@ impl App {
         let tar_path = self.settings.tar_path.clone();
 
         // Start all preparation and execution in background thread
-        thread::spawn(move || {
+        file_ops::spawn_worker(tx, Some(extract_path_clone.clone()), move |tx| {
             // Check for cancellation
             if cancel_flag.load(Ordering::Relaxed) {
                 let _ = tx.send(ProgressMessage::Error(extract_dir_owned, "Cancelled".to_string()));
@@ -5425,35 +8826,32 @@ impl App {
         }
         if self.remote_spinner.is_some() { return; }
 
-        let base_path = self.active_panel().path.clone();
-        let search_term = term.to_string();
-        let base_path_clone = base_path.clone();
-        let term_clone = search_term.clone();
-        let (tx, rx) = mpsc::channel();
-
-        thread::spawn(move || {
-            let results = crate::ui::search_result::execute_recursive_search(
-                &base_path_clone,
-                &term_clone,
-                1000,
-            );
-            let _ = tx.send(RemoteSpinnerResult::SearchComplete {
-                results,
-                search_term: term_clone,
-                base_path: base_path_clone,
-            });
-        });
+        if self.search_options.content {
+            let re = match crate::ui::search_result::build_content_regex(term, self.search_options) {
+                Ok(re) => re,
+                Err(e) => {
+                    self.show_message(&e);
+                    return;
+                }
+            };
+            let base_path = self.active_panel().path.clone();
+            self.search_result_state.start_content_search(base_path, term.to_string(), re, 1000, self.search_options.respect_ignore);
+            self.current_screen = Screen::SearchResult;
+            return;
+        }
 
-        self.remote_spinner = Some(RemoteSpinner {
-            message: "Searching...".to_string(),
-            started_at: Instant::now(),
-            receiver: rx,
-        });
+        // "name:line:col" searches for name, then opens the chosen file at that position
+        let (term, open_position) = split_path_position(term.trim());
+        let base_path = self.active_panel().path.clone();
+        // 결과는 검색 스레드에서 도착하는 대로 검색 결과 화면에 채워짐 (Esc로 중단)
+        self.search_result_state.start_name_search(base_path, term.to_string(), 1000, self.search_options.respect_ignore);
+        self.search_result_state.open_position = open_position;
+        self.current_screen = Screen::SearchResult;
     }
 
     pub fn execute_goto(&mut self, path_str: &str) {
-        // Check if this is a remote path (user@host:/path)
-        if let Some((user, host, port, remote_path)) = remote::parse_remote_path(path_str) {
+        // Check if this is a remote path (user@host:/path or sftp:// URL)
+        if let Some((user, host, port, remote_path)) = remote::resolve_remote_location(path_str, &self.settings.remote_profiles) {
             self.execute_goto_remote(&user, &host, port, &remote_path);
             return;
         }
@@ -5494,6 +8892,21 @@ impl App {
             }
         }
 
+        // path:line:col → open the file in the editor at that position
+        if let (file_part, Some((line, col))) = split_path_position(path_str) {
+            let file_path = if let Some(rest) = file_part.strip_prefix('~') {
+                dirs::home_dir()
+                    .map(|h| h.join(rest.trim_start_matches('/')))
+                    .unwrap_or_else(|| PathBuf::from(file_part))
+            } else {
+                self.active_panel().path.join(file_part)
+            };
+            if file_path.is_file() {
+                self.open_file_at(&file_path, line, col);
+                return;
+            }
+        }
+
         // Security: Check for path traversal attempts
         if path_str.contains("..") {
             // Normalize the path to resolve .. components
@@ -5517,7 +8930,7 @@ impl App {
                         panel.path = valid_path.clone();
                         panel.selected_index = 0;
                         panel.selected_files.clear();
-                        panel.load_files();
+                        panel.load_files_cached();
                         self.show_message(&format!("Moved to: {}", valid_path.display()));
                     } else {
                         self.show_message("Error: Path not found or not accessible");
@@ -5556,7 +8969,7 @@ impl App {
             panel.path = valid_path.clone();
             panel.selected_index = 0;
             panel.selected_files.clear();
-            panel.load_files();
+            panel.load_files_cached();
 
             if valid_path == path {
                 self.show_message(&format!("Moved to: {}", valid_path.display()));
@@ -5904,6 +9317,17 @@ impl App {
                             self.show_extension_handler_error(&format!("Path not found: {}", target_entry));
                         }
                     }
+                    PanelOpOutcome::Preview { result, tmp_path, file_name, file_size } => match result {
+                        Ok(()) => {
+                            let preview = crate::ui::file_viewer::RemotePreview {
+                                panel_index: panel_idx,
+                                file_name,
+                                total_size: file_size,
+                            };
+                            self.open_remote_preview(&tmp_path, preview, 0);
+                        }
+                        Err(e) => self.show_message(&format!("Preview failed: {}", e)),
+                    },
                 }
             }
             RemoteSpinnerResult::LocalOp { message, reload } => {
@@ -5915,19 +9339,6 @@ impl App {
                     self.refresh_panels();
                 }
             }
-            RemoteSpinnerResult::SearchComplete { results, search_term, base_path } => {
-                if results.is_empty() {
-                    self.show_message(&format!("No files found matching \"{}\"", search_term));
-                } else {
-                    self.search_result_state.results = results;
-                    self.search_result_state.selected_index = 0;
-                    self.search_result_state.scroll_offset = 0;
-                    self.search_result_state.search_term = search_term;
-                    self.search_result_state.base_path = base_path;
-                    self.search_result_state.active = true;
-                    self.current_screen = Screen::SearchResult;
-                }
-            }
             RemoteSpinnerResult::GitDiffComplete { result } => {
                 match result {
                     Ok((dir1, dir2)) => {
@@ -5948,12 +9359,83 @@ impl App {
         panel.selected_index = 0;
         panel.selected_files.clear();
         panel.pending_focus = filename;
-        panel.load_files();
+        panel.load_files_cached();
+    }
+
+    /// 파일을 편집기로 열고 커서를 지정 위치(1부터 시작)에 둠
+    /// 활성 패널은 파일이 있는 폴더로 이동하여 편집기를 닫으면 해당 파일에 커서가 위치함
+    pub fn open_file_at(&mut self, path: &Path, line: usize, col: usize) {
+        if let Some(parent) = path.parent() {
+            if !self.active_panel().is_remote() {
+                let file_name = path.file_name().map(|n| n.to_string_lossy().to_string());
+                self.goto_directory_with_focus(parent, file_name);
+            }
+        }
+
+        let mut editor = EditorState::new();
+        editor.set_syntax_colors(self.theme.syntax);
+        match editor.load_file(&path.to_path_buf()) {
+            Ok(_) => {
+                editor.goto_position(line, col);
+                editor.marks = self.saved_marks(path);
+                self.editor_state = Some(editor);
+                self.current_screen = Screen::FileEditor;
+            }
+            Err(e) => {
+                self.show_message(&format!("Cannot open file: {}", e));
+            }
+        }
+    }
+
+    /// Find File 옵션 표시줄 (다이얼로그 하단)
+    pub fn search_options_label(&self) -> String {
+        let check = |on: bool| if on { 'x' } else { ' ' };
+        format!(
+            "Tab: {}  Alt+R: regex [{}]  Alt+C: case [{}]  Alt+G: ignore [{}]",
+            if self.search_options.content { "[Content]" } else { "[Name]" },
+            check(self.search_options.regex),
+            check(self.search_options.case_sensitive),
+            check(self.search_options.respect_ignore),
+        )
+    }
+
+    /// 내용 검색 결과 수집 (메인 루프에서 호출)
+    pub fn poll_search_results(&mut self) {
+        self.search_result_state.poll();
+    }
+
+    /// 내용 검색 결과를 뷰어로 열고 일치한 줄로 이동. 뷰어를 닫으면 검색 결과로 돌아옴
+    fn view_search_match(&mut self, path: &Path, line: usize) {
+        let mut viewer = ViewerState::new();
+        viewer.set_syntax_colors(self.theme.syntax);
+        match viewer.load_file(&path.to_path_buf()) {
+            Ok(_) => {
+                viewer.marks = self.saved_marks(path);
+                viewer.goto_line(&line.to_string());
+                self.viewer_state = Some(viewer);
+                self.previous_screen = Some(Screen::SearchResult);
+                self.current_screen = Screen::FileViewer;
+            }
+            Err(e) => self.show_message(&format!("Cannot read file: {}", e)),
+        }
     }
 
     /// 검색 결과에서 선택한 항목의 경로로 이동
     pub fn goto_search_result(&mut self) {
         if let Some(item) = self.search_result_state.current_item().cloned() {
+            if let Some(ref line_match) = item.line_match {
+                self.view_search_match(&item.full_path, line_match.line);
+                return;
+            }
+            // "name:line:col"로 검색한 경우 파일을 해당 위치에서 편집기로 열기
+            if !item.is_directory {
+                if let Some((line, col)) = self.search_result_state.open_position {
+                    self.search_result_state.cancel();
+                    self.search_result_state.active = false;
+                    self.open_file_at(&item.full_path, line, col);
+                    return;
+                }
+            }
             if item.is_directory {
                 // 디렉토리인 경우 해당 디렉토리로 이동
                 self.goto_directory_with_focus(&item.full_path, None);
@@ -5966,12 +9448,259 @@ impl App {
                     );
                 }
             }
-            // 검색 결과 화면 닫기
+            // 검색 결과 화면 닫기 (진행 중인 검색도 중단)
+            self.search_result_state.cancel();
             self.search_result_state.active = false;
             self.current_screen = Screen::FilePanel;
             self.show_message(&format!("Moved to: {}", item.relative_path));
         }
     }
+
+    // ========== Notifications ==========
+
+    /// (Re)start the background watcher for settings.watch_rules.
+    /// Returns an error message if some rules are invalid (valid ones still run).
+    pub fn start_watch_service(&mut self) -> Option<String> {
+        use crate::services::watch;
+        let (rules, errors) = watch::valid_rules(&self.settings.watch_rules);
+        self.watch_service = watch::WatchService::start(rules);
+        errors.first().map(|e| format!("Settings error: {}", e))
+    }
+
+    /// Show results of watch rules applied since the last call and reload
+    /// the panels showing the folders a file was moved or copied from or to
+    pub fn poll_watch_service(&mut self) {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        while let Some(event) = self.watch_service.as_ref().and_then(|s| s.try_recv()) {
+            if let crate::services::watch::WatchEvent::Applied { ref src, ref dest, .. } = event {
+                dirs.extend([src, dest].into_iter().filter_map(|p| p.parent()).map(Path::to_path_buf));
+            }
+            self.show_message(&event.describe());
+        }
+        if !dirs.is_empty() {
+            self.refresh_panels_showing(&dirs);
+        }
+    }
+
+    /// 진행 중인 작업을 백그라운드 작업 목록으로 옮기고 진행 다이얼로그 닫기.
+    /// 완료 후 파일을 여는 원격 다운로드는 제외
+    pub fn send_progress_to_background(&mut self) {
+        if self.pending_remote_open.is_some() {
+            self.show_message("This download opens the file when done and cannot run in the background");
+            return;
+        }
+        let Some(progress) = self.file_operation_progress.take() else { return };
+        if !progress.is_active {
+            // 이미 끝난 작업은 메인 루프에서 정리
+            self.file_operation_progress = Some(progress);
+            return;
+        }
+        self.pending_paste_focus = None;
+        let archive_name = self.pending_tar_archive.take();
+        let extract_dir = self.pending_extract_dir.take();
+        self.jobs.add(progress, archive_name, extract_dir);
+        self.dialog = None;
+        self.show_message("Running in the background (Alt+J: jobs)");
+    }
+
+    /// Jobs 패널 펼치기/포커스 → 접기 순환
+    pub fn toggle_jobs_pane(&mut self) {
+        if self.jobs.jobs.is_empty() {
+            self.show_message("No background jobs");
+            return;
+        }
+        if !self.jobs.expanded {
+            self.jobs.expanded = true;
+            self.jobs.focused = true;
+        } else if !self.jobs.focused {
+            self.jobs.focused = true;
+        } else {
+            self.jobs.expanded = false;
+            self.jobs.focused = false;
+        }
+    }
+
+    /// 백그라운드 작업 진행 상황 갱신. 끝난 작업은 알림 후 그 작업의 폴더를 보여주는 패널만 새로고침
+    pub fn poll_jobs(&mut self) {
+        if self.jobs.jobs.is_empty() {
+            return;
+        }
+        let finished = self.jobs.poll();
+        if finished.is_empty() {
+            return;
+        }
+        let job_in_view = self.current_screen == Screen::FilePanel;
+        for job in &finished {
+            self.notify_job_finished("cokacdir", &job.message, job.started_at, job_in_view);
+            self.notify_webhooks(&job.title, &job.message, job.success, job.started_at);
+        }
+        if let Some(last) = finished.last() {
+            self.show_message(&last.message);
+        }
+        let dirs: Vec<PathBuf> = finished.into_iter().flat_map(|job| job.dirs).collect();
+        self.refresh_panels_showing(&dirs);
+    }
+
+    /// Notify that a background job finished (desktop notification / bell).
+    /// Skipped for short jobs, and when the terminal is focused and the user
+    /// is already looking at the job's screen.
+    pub fn notify_job_finished(&self, title: &str, body: &str, started_at: Instant, job_in_view: bool) {
+        let min = std::time::Duration::from_secs(self.settings.notifications.min_seconds);
+        if started_at.elapsed() < min {
+            return;
+        }
+        if self.terminal_focused && job_in_view {
+            return;
+        }
+        crate::services::notify::send(&self.settings.notifications, title, body);
+    }
+
+    /// Send a finished file operation to the configured webhooks.
+    /// Unlike desktop notifications this does not depend on terminal focus.
+    pub fn notify_webhooks(&self, title: &str, body: &str, success: bool, started_at: Instant) {
+        let webhooks = &self.settings.notifications.webhooks;
+        let elapsed = started_at.elapsed();
+        if webhooks.is_empty() || elapsed < std::time::Duration::from_secs(self.settings.notifications.min_seconds) {
+            return;
+        }
+        crate::services::webhook::send(webhooks, crate::services::webhook::WebhookEvent {
+            event: "file_operation_finished",
+            title: title.to_string(),
+            body: body.to_string(),
+            success,
+            details: serde_json::json!({ "duration_ms": elapsed.as_millis() as u64 }),
+        });
+    }
+
+    /// Whether the AI pane (fullscreen or split) currently has keyboard focus
+    pub fn ai_has_focus(&self) -> bool {
+        match self.current_screen {
+            Screen::AIScreen => true,
+            Screen::FilePanel => self.is_ai_mode() && self.ai_panel_index == Some(self.active_panel_index),
+            _ => false,
+        }
+    }
+
+    // ========== Control socket (IPC) ==========
+
+    /// Whether the UI can accept a state-changing IPC request right now
+    pub fn ipc_check_idle(&self) -> Result<(), IpcError> {
+        if self.remote_spinner.is_some() {
+            return Err(IpcError::busy("A background operation is running"));
+        }
+        if self.file_operation_progress.as_ref().map(|p| p.is_active).unwrap_or(false) {
+            return Err(IpcError::busy("A file operation is in progress"));
+        }
+        if self.dialog.is_some() || self.current_screen != Screen::FilePanel {
+            return Err(IpcError::busy("The file panel is not focused"));
+        }
+        Ok(())
+    }
+
+    /// Snapshot of all panels for the `get_state` method
+    pub fn ipc_state(&self) -> serde_json::Value {
+        let panels: Vec<serde_json::Value> = self.panels.iter().enumerate().map(|(i, panel)| {
+            serde_json::json!({
+                "index": i,
+                "path": panel.display_path(),
+                "remote": panel.is_remote(),
+                "ai": self.ai_panel_index == Some(i) && self.ai_state.is_some(),
+                "current": panel.current_file().map(|f| f.name.clone()),
+                "selected": Self::ipc_sorted_selection(panel),
+            })
+        }).collect();
+        serde_json::json!({
+            "screen": format!("{:?}", self.current_screen),
+            "active_panel": self.active_panel_index,
+            "panels": panels,
+        })
+    }
+
+    /// Selection of the active panel for the `get_selection` method.
+    /// `files` holds full paths of what a file operation would act on
+    /// (marked files, or the file under the cursor when nothing is marked).
+    pub fn ipc_selection(&self) -> serde_json::Value {
+        let panel = self.active_panel();
+        let files: Vec<String> = self.get_operation_files().iter()
+            .map(|name| panel.path.join(name).display().to_string())
+            .collect();
+        serde_json::json!({
+            "path": panel.display_path(),
+            "current": panel.current_file().map(|f| f.name.clone()),
+            "selected": Self::ipc_sorted_selection(panel),
+            "files": files,
+        })
+    }
+
+    fn ipc_sorted_selection(panel: &PanelState) -> Vec<String> {
+        let mut selected: Vec<String> = panel.selected_files.iter().cloned().collect();
+        selected.sort();
+        selected
+    }
+
+    /// `navigate` method: go to a directory (or reveal a file) in a panel
+    pub fn ipc_navigate(&mut self, params: &serde_json::Value) -> Result<serde_json::Value, IpcError> {
+        self.ipc_check_idle()?;
+
+        let path_str = params.get("path").and_then(|v| v.as_str())
+            .ok_or_else(|| IpcError::invalid_params("Missing 'path'"))?;
+        if let Some(index) = params.get("panel").and_then(|v| v.as_u64()) {
+            let index = index as usize;
+            if index >= self.panels.len() {
+                return Err(IpcError::invalid_params(format!("No panel at index {}", index)));
+            }
+            self.active_panel_index = index;
+        }
+        let mut focus = params.get("focus").and_then(|v| v.as_str()).map(|s| s.to_string());
+
+        // Remote targets are resolved asynchronously by execute_goto
+        if remote::parse_remote_path(path_str).is_some() || self.active_panel().is_remote() {
+            self.execute_goto(path_str);
+            return Ok(serde_json::json!({ "path": self.active_panel().display_path() }));
+        }
+
+        let path = if let Some(rest) = path_str.strip_prefix('~') {
+            dirs::home_dir()
+                .map(|h| h.join(rest.trim_start_matches('/')))
+                .unwrap_or_else(|| PathBuf::from(path_str))
+        } else {
+            self.active_panel().path.join(path_str)
+        };
+        let dir = if path.is_dir() {
+            path
+        } else if path.is_file() {
+            // Reveal a file: open its parent and put the cursor on it
+            focus = path.file_name().map(|n| n.to_string_lossy().to_string());
+            path.parent().map(Path::to_path_buf).unwrap_or(path)
+        } else {
+            return Err(IpcError::invalid_params(format!("Path not found: {}", path_str)));
+        };
+
+        self.goto_directory_with_focus(&dir, focus);
+        Ok(serde_json::json!({ "path": self.active_panel().display_path() }))
+    }
+
+    /// `select` method: replace the marked files of the active panel
+    pub fn ipc_select(&mut self, params: &serde_json::Value) -> Result<serde_json::Value, IpcError> {
+        self.ipc_check_idle()?;
+
+        let names = params.get("names").and_then(|v| v.as_array())
+            .ok_or_else(|| IpcError::invalid_params("Missing 'names' array"))?;
+        let panel = self.active_panel_mut();
+        panel.selected_files.clear();
+        let mut missing = Vec::new();
+        for name in names.iter().filter_map(|n| n.as_str()) {
+            if name != ".." && panel.files.iter().any(|f| f.name == name) {
+                panel.selected_files.insert(name.to_string());
+            } else {
+                missing.push(name.to_string());
+            }
+        }
+        Ok(serde_json::json!({
+            "selected": Self::ipc_sorted_selection(panel),
+            "missing": missing,
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -6045,6 +9774,17 @@ mod tests {
         assert_eq!(result, root);
     }
 
+    #[test]
+    fn test_split_path_position() {
+        assert_eq!(split_path_position("src/main.rs:120:5"), ("src/main.rs", Some((120, 5))));
+        assert_eq!(split_path_position("src/main.rs:120"), ("src/main.rs", Some((120, 1))));
+        assert_eq!(split_path_position("src/main.rs:120:5:"), ("src/main.rs", Some((120, 5))));
+        assert_eq!(split_path_position("src/main.rs"), ("src/main.rs", None));
+        assert_eq!(split_path_position("a:b:3"), ("a:b", Some((3, 1))));
+        assert_eq!(split_path_position("file.rs:0"), ("file.rs:0", None));
+        assert_eq!(split_path_position(":12"), (":12", None));
+    }
+
     // ========== PanelState tests ==========
 
     #[test]
@@ -6120,6 +9860,57 @@ mod tests {
         cleanup_temp_dir(&temp_dir);
     }
 
+    #[test]
+    fn test_panel_show_hidden() {
+        let temp_dir = create_temp_dir();
+        fs::write(temp_dir.join(".env"), "content").unwrap();
+        fs::write(temp_dir.join("visible.txt"), "content").unwrap();
+        let mut panel = PanelState::new(temp_dir.clone());
+        assert!(panel.files.iter().any(|f| f.name == ".env"));
+        assert_eq!(panel.hidden_count, 0);
+
+        panel.show_hidden = false;
+        panel.load_files();
+        assert!(!panel.files.iter().any(|f| f.name == ".env"));
+        assert!(panel.files.iter().any(|f| f.name == ".."));
+        assert_eq!(panel.hidden_count, 1);
+
+        // Re-sorting keeps the count
+        panel.toggle_sort(SortBy::Size);
+        assert_eq!(panel.hidden_count, 1);
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_panel_quick_filter() {
+        let temp_dir = create_temp_dir();
+        for name in ["alpha.txt", "beta.rs", "gamma.txt"] {
+            fs::write(temp_dir.join(name), "content").unwrap();
+        }
+        let mut panel = PanelState::new(temp_dir.clone());
+        let total = panel.files.len();
+        panel.selected_files.insert("beta.rs".to_string());
+
+        panel.start_quick_filter();
+        panel.set_quick_filter_text("atx".to_string());
+        let names: Vec<&str> = panel.files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha.txt", "gamma.txt"]);
+        // Hidden entries keep their marks
+        assert!(panel.selected_files.contains("beta.rs"));
+
+        // A reload of the same directory keeps the filter
+        fs::write(temp_dir.join("delta.txt"), "content").unwrap();
+        panel.load_files();
+        assert_eq!(panel.files.len(), 3);
+
+        panel.clear_quick_filter();
+        assert_eq!(panel.files.len(), total + 1);
+        assert!(panel.quick_filter.is_none());
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
     // ========== App tests ==========
 
     #[test]
@@ -6408,6 +10199,42 @@ mod tests {
         cleanup_temp_dir(&temp_dir);
     }
 
+    #[test]
+    fn test_basket_copy_from_two_folders() {
+        let temp_dir = create_temp_dir();
+        let (a, b, dest) = (temp_dir.join("a"), temp_dir.join("b"), temp_dir.join("dest"));
+        for dir in [&a, &b, &dest] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        fs::write(a.join("one.txt"), "1").unwrap();
+        fs::write(b.join("two.txt"), "2").unwrap();
+
+        let mut app = App::new(a.clone(), dest.clone());
+        app.basket = vec![a.join("one.txt"), b.join("two.txt")];
+
+        // Already in the target folder: refused
+        app.switch_panel();
+        app.active_panel_mut().path = a.clone();
+        app.basket_paste(ClipboardOperation::Copy);
+        assert!(app.file_operation_progress.is_none());
+
+        app.active_panel_mut().path = dest.clone();
+        app.basket_paste(ClipboardOperation::Copy);
+        while app.file_operation_progress.as_ref().map(|p| p.is_active).unwrap_or(false) {
+            if let Some(ref mut progress) = app.file_operation_progress {
+                progress.poll();
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        assert_eq!(fs::read_to_string(dest.join("one.txt")).unwrap(), "1");
+        assert_eq!(fs::read_to_string(dest.join("two.txt")).unwrap(), "2");
+        assert!(a.join("one.txt").exists());
+        assert_eq!(app.basket.len(), 2);
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
     #[test]
     fn test_clipboard_paste_cut() {
         let temp_dir = create_temp_dir();
@@ -6542,4 +10369,185 @@ mod tests {
         assert_eq!(ClipboardOperation::Cut, ClipboardOperation::Cut);
         assert_ne!(ClipboardOperation::Copy, ClipboardOperation::Cut);
     }
+
+    #[test]
+    fn test_file_operation_speed_and_eta() {
+        let mut progress = FileOperationProgress::new(FileOperationType::Copy);
+        progress.total_bytes = 30_000_000;
+        let start = Instant::now();
+        progress.update_speed(start);
+        assert!(progress.bytes_per_sec().is_none());
+        assert!(progress.eta().is_none());
+
+        // 10 MB in 1 s
+        progress.completed_bytes = 10_000_000;
+        progress.update_speed(start + Duration::from_secs(1));
+        assert_eq!(progress.bytes_per_sec(), Some(10_000_000.0));
+        assert_eq!(progress.eta(), Some(Duration::from_secs(2)));
+
+        // Samples closer than the interval are ignored
+        progress.completed_bytes = 20_000_000;
+        progress.update_speed(start + Duration::from_millis(1200));
+        assert_eq!(progress.bytes_per_sec(), Some(10_000_000.0));
+
+        // Then smoothed: 0.3 * 5 MB/s + 0.7 * 10 MB/s
+        progress.update_speed(start + Duration::from_secs(3));
+        let speed = progress.bytes_per_sec().unwrap();
+        assert!((speed - 8_500_000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_worker_exit_without_completed_fails_operation() {
+        let mut progress = FileOperationProgress::new(FileOperationType::Copy);
+        progress.is_active = true;
+        let (tx, rx) = mpsc::channel();
+        progress.receiver = Some(rx);
+
+        // 패닉한 워커: 진행 상황과 오류만 보내고 Completed 없이 송신자 해제
+        tx.send(ProgressMessage::TotalProgress(2, 5, 0, 0)).unwrap();
+        tx.send(ProgressMessage::Error(String::new(), "Internal error: boom".to_string())).unwrap();
+        drop(tx);
+
+        assert!(!progress.poll());
+        let result = progress.result.as_ref().unwrap();
+        assert_eq!(result.success_count, 2);
+        assert_eq!(result.failure_count, 3);
+        assert_eq!(result.last_error.as_deref(), Some("Internal error: boom"));
+        assert!(progress.completion_message(None, None).is_some());
+    }
+
+    #[test]
+    fn test_finished_job_reloads_only_its_panels() {
+        let temp_dir = create_temp_dir();
+        let first_path = temp_dir.join("first");
+        let second_path = temp_dir.join("second");
+        fs::create_dir_all(&first_path).unwrap();
+        fs::create_dir_all(&second_path).unwrap();
+        for dir in [&first_path, &second_path] {
+            fs::write(dir.join("a.txt"), "a").unwrap();
+            fs::write(dir.join("b.txt"), "b").unwrap();
+        }
+        let mut app = App::new(first_path.clone(), second_path.clone());
+        for panel in app.panels.iter_mut() {
+            panel.selected_files.insert("a.txt".to_string());
+            panel.selected_files.insert("b.txt".to_string());
+        }
+
+        // A background job in the first folder removed b.txt and created c.txt
+        fs::remove_file(first_path.join("b.txt")).unwrap();
+        fs::write(first_path.join("c.txt"), "c").unwrap();
+        fs::write(second_path.join("c.txt"), "c").unwrap();
+        let mut progress = FileOperationProgress::new(FileOperationType::Move);
+        progress.is_active = true;
+        progress.dirs = vec![first_path.clone()];
+        let (tx, rx) = mpsc::channel();
+        progress.receiver = Some(rx);
+        tx.send(ProgressMessage::Completed(1, 0)).unwrap();
+        app.jobs.add(progress, None, None);
+        app.poll_jobs();
+
+        assert!(!app.jobs.jobs[0].is_running());
+        let names = |panel: &PanelState| panel.files.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+        assert!(names(&app.panels[0]).contains(&"c.txt".to_string()));
+        assert!(!names(&app.panels[0]).contains(&"b.txt".to_string()));
+        assert_eq!(app.panels[0].selected_files, HashSet::from(["a.txt".to_string()]));
+        // The panel showing another folder is neither reloaded nor unmarked
+        assert!(!names(&app.panels[1]).contains(&"c.txt".to_string()));
+        assert_eq!(app.panels[1].selected_files.len(), 2);
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_select_filter() {
+        assert_eq!(SelectFilter::parse("newer 7d"), Some(SelectFilter::NewerThan(7 * 86400)));
+        assert_eq!(SelectFilter::parse("Larger 10M"), Some(SelectFilter::LargerThan(10 * 1024 * 1024)));
+        assert_eq!(SelectFilter::parse("bigger 10M"), None);
+        assert_eq!(SelectFilter::parse("older"), None);
+
+        let now = Local::now();
+        let file = |size: u64, age_secs: i64, is_directory: bool| FileItem {
+            name: "f".to_string(),
+            display_name: None,
+            is_directory,
+            is_symlink: false,
+            link_target: None,
+            size,
+            modified: now - chrono::Duration::seconds(age_secs),
+            permissions: String::new(),
+        };
+        assert!(SelectFilter::NewerThan(3600).matches(&file(0, 60, false), now));
+        assert!(!SelectFilter::NewerThan(3600).matches(&file(0, 7200, false), now));
+        assert!(SelectFilter::OlderThan(3600).matches(&file(0, 7200, false), now));
+        assert!(SelectFilter::SmallerThan(1024).matches(&file(10, 0, false), now));
+        assert!(!SelectFilter::LargerThan(1024).matches(&file(4096, 0, true), now));
+    }
+
+    #[test]
+    fn test_viewer_resumes_scroll_and_search() {
+        let temp_dir = create_temp_dir();
+        let log = temp_dir.join("app.log");
+        let content: Vec<String> = (0..200)
+            .map(|i| if i == 10 || i == 150 { format!("{} ERROR", i) } else { format!("{} ok", i) })
+            .collect();
+        fs::write(&log, content.join("\n")).unwrap();
+
+        let mut app = App::new(temp_dir.clone(), temp_dir.clone());
+        let mut viewer = ViewerState::new();
+        viewer.load_file(&log).unwrap();
+        viewer.scroll = 100;
+        viewer.search_term = "ERROR".to_string();
+        app.viewer_state = Some(viewer);
+        app.current_screen = Screen::FileViewer;
+        app.record_recent_file();
+        assert_eq!(app.settings.recent_files[0].search, "ERROR");
+
+        let mut viewer = ViewerState::new();
+        viewer.load_file(&log).unwrap();
+        app.restore_viewer_position(&mut viewer, &log);
+        while viewer.is_searching() {
+            viewer.poll_search();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        // The restored search keeps the view where it was and picks the next match below it
+        assert_eq!(viewer.scroll, 100);
+        assert_eq!(viewer.search_input, "ERROR");
+        assert_eq!(viewer.match_lines, vec![10, 150]);
+        assert_eq!(viewer.current_match, 1);
+
+        // A new search still jumps to its first match
+        viewer.perform_search();
+        while viewer.is_searching() {
+            viewer.poll_search();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(viewer.scroll, 5);
+
+        cleanup_temp_dir(&temp_dir);
+    }
+
+    #[test]
+    fn test_breadcrumb_levels_and_siblings() {
+        let temp_dir = create_temp_dir();
+        for dir in ["a/b", "a/C", "a/.hidden", "a/b/deep"] {
+            fs::create_dir_all(temp_dir.join(dir)).unwrap();
+        }
+        fs::write(temp_dir.join("a/file.txt"), "x").unwrap();
+
+        let mut state = BreadcrumbState::new(&temp_dir.join("a/b/deep"), false);
+        assert_eq!(state.components.last(), Some(&temp_dir.join("a/b/deep")));
+        assert_eq!(state.siblings, vec!["deep".to_string()]);
+
+        // One level up: the folders of "a" without files and dotfolders, cursor on "b"
+        state.set_level(state.level - 1);
+        assert_eq!(state.siblings, vec!["b".to_string(), "C".to_string()]);
+        assert_eq!(state.selected_path(), Some(temp_dir.join("a/b")));
+        state.selected_index = 1;
+        assert_eq!(state.selected_path(), Some(temp_dir.join("a/C")));
+
+        let with_hidden = BreadcrumbState::new(&temp_dir.join("a/b"), true);
+        assert_eq!(with_hidden.siblings, vec![".hidden".to_string(), "b".to_string(), "C".to_string()]);
+
+        cleanup_temp_dir(&temp_dir);
+    }
 }
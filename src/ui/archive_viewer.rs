@@ -0,0 +1,355 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::services::archive::{self, ArchiveEntry, ArchiveKind};
+use crate::ui::theme::Theme;
+use crate::utils::format::format_size;
+
+/// Read-only view of an archive's contents
+pub struct ArchiveViewerState {
+    pub archive_path: PathBuf,
+    pub kind: ArchiveKind,
+    tar_cmd: String,
+    pub entries: Vec<ArchiveEntry>,
+    /// Directory inside the archive being shown ("" = root)
+    pub current_dir: String,
+    /// Indices into `entries` of the current directory's children
+    pub visible: Vec<usize>,
+    pub selected: usize,
+    pub scroll: usize,
+    /// Paths of marked entries (may span directories)
+    pub marked: BTreeSet<String>,
+    /// Where extracted entries are written
+    pub dest: PathBuf,
+    pub message: String,
+    pub receiver: Option<Receiver<Result<usize, String>>>,
+    /// Something was extracted (panels need a refresh on close)
+    pub extracted: bool,
+    /// Shift+X: close and extract the whole archive into a directory of its name
+    pub extract_all_request: bool,
+}
+
+impl ArchiveViewerState {
+    pub fn new(archive_path: PathBuf, tar_path: Option<&str>, dest: PathBuf) -> Result<Self, String> {
+        let name = archive_path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        let kind = ArchiveKind::from_name(&name).ok_or_else(|| format!("Not a tar or zip archive: {}", name))?;
+        let tar_cmd = match kind {
+            ArchiveKind::Tar => archive::find_tar(tar_path).ok_or("tar command not found")?,
+            ArchiveKind::Zip => String::new(),
+        };
+        let entries = archive::list(kind, &tar_cmd, &archive_path)?;
+        let mut state = Self {
+            archive_path,
+            kind,
+            tar_cmd,
+            entries,
+            current_dir: String::new(),
+            visible: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            marked: BTreeSet::new(),
+            dest,
+            message: String::new(),
+            receiver: None,
+            extracted: false,
+            extract_all_request: false,
+        };
+        state.visible = archive::children(&state.entries, "");
+        Ok(state)
+    }
+
+    fn current_entry(&self) -> Option<&ArchiveEntry> {
+        self.visible.get(self.selected).map(|&i| &self.entries[i])
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let max = self.visible.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    fn enter_dir(&mut self, dir: String) {
+        self.visible = archive::children(&self.entries, &dir);
+        self.current_dir = dir;
+        self.selected = 0;
+        self.scroll = 0;
+    }
+
+    fn open_selected(&mut self) {
+        match self.current_entry() {
+            Some(entry) if entry.is_dir => {
+                let dir = entry.path.clone();
+                self.enter_dir(dir);
+            }
+            Some(_) => self.message = "Press x to extract the marked entries".to_string(),
+            None => {}
+        }
+    }
+
+    fn go_parent(&mut self) {
+        if self.current_dir.is_empty() {
+            return;
+        }
+        let previous = self.current_dir.clone();
+        let parent = previous.rfind('/').map(|i| previous[..i].to_string()).unwrap_or_default();
+        self.enter_dir(parent);
+        // 빠져나온 디렉토리에 커서 위치
+        if let Some(pos) = self.visible.iter().position(|&i| self.entries[i].path == previous) {
+            self.selected = pos;
+        }
+    }
+
+    fn toggle_mark(&mut self) {
+        if let Some(path) = self.current_entry().map(|e| e.path.clone()) {
+            if !self.marked.remove(&path) {
+                self.marked.insert(path);
+            }
+            self.move_selection(1);
+        }
+    }
+
+    /// Mark every entry of the current directory, or unmark them if all are marked
+    fn toggle_mark_all(&mut self) {
+        let paths: Vec<String> = self.visible.iter().map(|&i| self.entries[i].path.clone()).collect();
+        if paths.iter().all(|p| self.marked.contains(p)) {
+            for path in &paths {
+                self.marked.remove(path);
+            }
+        } else {
+            self.marked.extend(paths);
+        }
+    }
+
+    /// Extract the marked entries (or the one under the cursor) in the background
+    fn start_extract(&mut self) {
+        if self.receiver.is_some() {
+            return;
+        }
+        let selected: Vec<ArchiveEntry> = if self.marked.is_empty() {
+            self.current_entry().cloned().into_iter().collect()
+        } else {
+            // 표시 순서가 아닌 아카이브 순서, 경로 중복 제거
+            let mut seen = BTreeSet::new();
+            self.entries
+                .iter()
+                .filter(|e| self.marked.contains(&e.path) && seen.insert(e.path.clone()))
+                .cloned()
+                .collect()
+        };
+        if selected.is_empty() {
+            return;
+        }
+        let count = selected.len();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let kind = self.kind;
+        let tar_cmd = self.tar_cmd.clone();
+        let archive_path = self.archive_path.clone();
+        let dest = self.dest.clone();
+        std::thread::spawn(move || {
+            let refs: Vec<&ArchiveEntry> = selected.iter().collect();
+            let result = archive::extract(kind, &tar_cmd, &archive_path, &dest, &refs).map(|_| count);
+            let _ = tx.send(result);
+        });
+        self.receiver = Some(rx);
+        self.message = format!("Extracting {} item(s)...", count);
+    }
+
+    fn poll(&mut self) {
+        let Some(ref rx) = self.receiver else { return };
+        let Ok(result) = rx.try_recv() else { return };
+        self.receiver = None;
+        self.extracted = true;
+        match result {
+            Ok(count) => {
+                self.message = format!("Extracted {} item(s) to {}", count, self.dest.display());
+                self.marked.clear();
+            }
+            Err(e) => self.message = format!("Extraction failed: {}", e),
+        }
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut ArchiveViewerState, area: Rect, theme: &Theme) {
+    state.poll();
+
+    let colors = &theme.archive_viewer;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // info box
+            Constraint::Min(3),    // entries
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Info box ──
+    let archive_name = state.archive_path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let info_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.info_border))
+        .title(Span::styled(
+            format!(" {} ", archive_name),
+            Style::default().fg(colors.title).add_modifier(Modifier::BOLD),
+        ))
+        .style(Style::default().bg(colors.bg));
+    let info = Line::from(vec![
+        Span::styled(format!("/{}", state.current_dir), Style::default().fg(colors.path_text)),
+        Span::raw("  "),
+        Span::styled(
+            format!("[{} entries, {} marked]", state.entries.len(), state.marked.len()),
+            Style::default().fg(colors.count_text),
+        ),
+        Span::styled("  → ", Style::default().fg(colors.arrow_text)),
+        Span::styled(state.dest.display().to_string(), Style::default().fg(colors.dest_text)),
+    ]);
+    frame.render_widget(Paragraph::new(info).block(info_block), chunks[0]);
+
+    // ── Entries ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.list_border))
+        .style(Style::default().bg(colors.bg));
+
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    let inner_width = chunks[1].width.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    // 이름 | 크기(10) | 날짜(17)
+    let name_width = inner_width.saturating_sub(2 + 10 + 1 + 17);
+    let lines: Vec<Line> = if state.visible.is_empty() {
+        vec![Line::from(Span::styled("(empty)", Style::default().fg(colors.empty_text)))]
+    } else {
+        state
+            .visible
+            .iter()
+            .enumerate()
+            .skip(state.scroll)
+            .take(inner_height)
+            .map(|(row, &i)| {
+                let entry = &state.entries[i];
+                let is_marked = state.marked.contains(&entry.path);
+                let mut name = entry.name().to_string();
+                if entry.is_dir {
+                    name.push('/');
+                }
+                let name = fit_width(&name, name_width);
+                let size = if entry.is_dir { "<DIR>".to_string() } else { format_size(entry.size) };
+                let name_style = if is_marked {
+                    Style::default().fg(colors.marked_text).add_modifier(Modifier::BOLD)
+                } else if entry.is_dir {
+                    Style::default().fg(colors.directory_text)
+                } else {
+                    Style::default().fg(colors.file_text)
+                };
+                let line = Line::from(vec![
+                    Span::styled(if is_marked { "* " } else { "  " }, Style::default().fg(colors.marked_text)),
+                    Span::styled(name, name_style),
+                    Span::styled(format!("{:>10} ", size), Style::default().fg(colors.size_text)),
+                    Span::styled(format!("{:<17}", entry.modified), Style::default().fg(colors.date_text)),
+                ]);
+                if row == state.selected {
+                    line.style(Style::default().bg(colors.selected_bg).fg(colors.selected_text))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Footer (message replaces the key hints) ──
+    let footer_line = if !state.message.is_empty() {
+        Line::from(Span::styled(format!(" {}", state.message), Style::default().fg(colors.message_text)))
+    } else {
+        let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+        let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+        let items = vec![
+            key(" Esc"),
+            text(" Close  "),
+            key("Enter"),
+            text(" Open dir  "),
+            key("Bksp"),
+            text(" Up  "),
+            key("Space"),
+            text(" Mark  "),
+            key("a"),
+            text(" Mark all  "),
+            key("x"),
+            text(" Extract"),
+        ];
+        items.push(key("  X"));
+        items.push(text(" Extract all"));
+        Line::from(items)
+    };
+    frame.render_widget(Paragraph::new(footer_line).style(Style::default().bg(colors.footer_bg)), chunks[2]);
+}
+
+/// Pad or cut `text` to exactly `width` display columns
+fn fit_width(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return format!("{}{}", text, " ".repeat(width - text.width()));
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        if used + w + 1 > width {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push('…');
+    used += 1;
+    out.push_str(&" ".repeat(width.saturating_sub(used)));
+    out
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut ArchiveViewerState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    state.message.clear();
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            if state.receiver.is_some() {
+                state.message = "Extraction in progress".to_string();
+            } else {
+                return true;
+            }
+        }
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.visible.len().saturating_sub(1),
+        KeyCode::Enter | KeyCode::Right => state.open_selected(),
+        KeyCode::Backspace | KeyCode::Left => state.go_parent(),
+        KeyCode::Char(' ') | KeyCode::Insert => state.toggle_mark(),
+        KeyCode::Char('a') => state.toggle_mark_all(),
+        KeyCode::Char('x') | KeyCode::F(5) => state.start_extract(),
+        KeyCode::Char('X') if state.receiver.is_none() => {
+            state.extract_all_request = true;
+            return true;
+        }
+        _ => {}
+    }
+    false
+}
@@ -0,0 +1,179 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::services::audit::{self, AuditEntry, AuditKind};
+use crate::ui::theme::Theme;
+
+/// Entries loaded into the viewer (newest ones)
+const MAX_ENTRIES: usize = 5000;
+
+pub struct AuditScreenState {
+    /// Newest first
+    pub entries: Vec<AuditEntry>,
+    pub selected: usize,
+    pub scroll: usize,
+}
+
+impl AuditScreenState {
+    pub fn new() -> Self {
+        let mut state = Self { entries: Vec::new(), selected: 0, scroll: 0 };
+        state.reload();
+        state
+    }
+
+    pub fn reload(&mut self) {
+        self.entries = audit::read_recent(MAX_ENTRIES);
+        self.entries.reverse();
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let max = self.entries.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut AuditScreenState, area: Rect, theme: &Theme) {
+    let colors = &theme.audit_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // info box
+            Constraint::Min(3),    // entries
+            Constraint::Length(4), // details of the selected entry
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Info box ──
+    let info_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Bot Audit Log ", Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+    let log_path = audit::audit_log_path().map(|p| p.display().to_string()).unwrap_or_default();
+    let info = Line::from(vec![
+        Span::styled("Log: ", Style::default().fg(colors.label_text)),
+        Span::styled(log_path, Style::default().fg(colors.path_text)),
+        Span::raw("  "),
+        Span::styled(
+            format!("[{} entries]", state.entries.len()),
+            Style::default().fg(colors.count_text).add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(info).block(info_block), chunks[0]);
+
+    // ── Entries ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    let lines: Vec<Line> = if state.entries.is_empty() {
+        vec![Line::from(Span::styled("No bot activity recorded yet", Style::default().fg(colors.empty_text)))]
+    } else {
+        state
+            .entries
+            .iter()
+            .enumerate()
+            .skip(state.scroll)
+            .take(inner_height)
+            .map(|(i, entry)| {
+                let kind_style = match entry.kind {
+                    AuditKind::Rejected => Style::default().fg(colors.kind_rejected),
+                    AuditKind::Shell => Style::default().fg(colors.kind_shell),
+                    AuditKind::Upload | AuditKind::Download => Style::default().fg(colors.kind_transfer),
+                    _ => Style::default().fg(colors.kind_other),
+                };
+                let user = match entry.user_id {
+                    Some(id) => id.to_string(),
+                    None => "-".to_string(),
+                };
+                let line = Line::from(vec![
+                    Span::styled(format!("{}  ", entry.time), Style::default().fg(colors.time_text)),
+                    Span::styled(format!("{:<9}", entry.kind.label()), kind_style),
+                    Span::styled(format!("{:>12} ", user), Style::default().fg(colors.user_text)),
+                    Span::styled(entry.summary.clone(), Style::default().fg(colors.summary_text)),
+                ]);
+                if i == state.selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Details ──
+    let detail_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let label = |t: &'static str| Span::styled(t, Style::default().fg(colors.label_text));
+    let value = |t: String| Span::styled(t, Style::default().fg(colors.value_text));
+    let details = match state.entries.get(state.selected) {
+        Some(entry) => {
+            let user = match entry.user_id {
+                Some(id) if entry.user_name.is_empty() => id.to_string(),
+                Some(_) => entry.user_name.clone(),
+                None => "-".to_string(),
+            };
+            let files = if entry.files.is_empty() { "-".to_string() } else { entry.files.join(", ") };
+            let tools = if entry.tools.is_empty() { "-".to_string() } else { entry.tools.join(", ") };
+            vec![
+                Line::from(vec![label("User: "), value(user), label("  Chat: "), value(entry.chat_id.to_string())]),
+                Line::from(vec![label("Files: "), value(files), label("  Tools: "), value(tools)]),
+            ]
+        }
+        None => Vec::new(),
+    };
+    frame.render_widget(Paragraph::new(details).block(detail_block), chunks[2]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(" Close  "),
+        key("Up/Down"),
+        text(" Select  "),
+        key("r"),
+        text(" Reload"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut AuditScreenState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => return true,
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.entries.len().saturating_sub(1),
+        KeyCode::Char('r') => state.reload(),
+        _ => {}
+    }
+    false
+}
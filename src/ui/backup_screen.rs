@@ -0,0 +1,497 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::services::backup::{self, BackupJob, BackupRun, CompareMode};
+use crate::services::file_ops::parse_exclude_patterns;
+use crate::ui::app::{App, Screen};
+use crate::ui::theme::Theme;
+use crate::utils::format::format_size;
+
+/// 편집 폼의 입력 칸 (Compare는 토글)
+const FORM_LABELS: [&str; 7] = ["Name", "Source", "Destination", "Include", "Exclude", "Schedule", "Compare"];
+const COMPARE_FIELD: usize = 6;
+
+/// 기록 창에 보이는 실행 수
+const HISTORY_ROWS: usize = 6;
+
+enum BackupEvent {
+    Progress { copied: u64, skipped: u64, failed: u64, current: String },
+    Finished(BackupRun),
+}
+
+struct RunningBackup {
+    name: String,
+    receiver: Receiver<BackupEvent>,
+    cancel_flag: Arc<AtomicBool>,
+    copied: u64,
+    skipped: u64,
+    failed: u64,
+    current: String,
+}
+
+struct BackupForm {
+    /// Index of the job being edited (None for a new job)
+    editing: Option<usize>,
+    values: [String; 6],
+    compare: CompareMode,
+    field: usize,
+}
+
+impl BackupForm {
+    fn from_job(editing: Option<usize>, job: &BackupJob) -> Self {
+        Self {
+            editing,
+            values: [
+                job.name.clone(),
+                job.source.clone(),
+                job.destination.clone(),
+                job.include.join(", "),
+                job.exclude.join(", "),
+                job.schedule.clone(),
+            ],
+            compare: job.compare,
+            field: 0,
+        }
+    }
+
+    /// Job from the form, keeping the scheduler entry of the edited job
+    fn to_job(&self, schedule_id: Option<String>) -> BackupJob {
+        BackupJob {
+            name: self.values[0].trim().to_string(),
+            source: self.values[1].trim().to_string(),
+            destination: self.values[2].trim().to_string(),
+            include: parse_exclude_patterns(&self.values[3]),
+            exclude: parse_exclude_patterns(&self.values[4]),
+            schedule: self.values[5].trim().to_string(),
+            compare: self.compare,
+            schedule_id,
+        }
+    }
+}
+
+pub struct BackupScreenState {
+    pub selected: usize,
+    /// All recorded runs, oldest first
+    history: Vec<BackupRun>,
+    form: Option<BackupForm>,
+    running: Option<RunningBackup>,
+    confirm_delete: bool,
+    pub message: String,
+}
+
+impl BackupScreenState {
+    pub fn new() -> Self {
+        let history = backup::history_path().map(|p| backup::load_history(&p)).unwrap_or_default();
+        Self {
+            selected: 0,
+            history,
+            form: None,
+            running: None,
+            confirm_delete: false,
+            message: String::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    fn last_run(&self, name: &str) -> Option<&BackupRun> {
+        self.history.iter().rev().find(|r| r.name == name)
+    }
+
+    pub fn poll(&mut self) {
+        let Some(ref mut running) = self.running else { return };
+        let mut finished = None;
+        for event in running.receiver.try_iter() {
+            match event {
+                BackupEvent::Progress { copied, skipped, failed, current } => {
+                    running.copied = copied;
+                    running.skipped = skipped;
+                    running.failed = failed;
+                    running.current = current;
+                }
+                BackupEvent::Finished(run) => finished = Some(run),
+            }
+        }
+        if let Some(run) = finished {
+            self.message = format!("{}: {} ({})", run.name, run.summary(), format_size(run.bytes));
+            self.history.push(run);
+            self.running = None;
+        }
+    }
+}
+
+impl Default for BackupScreenState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn start_run(state: &mut BackupScreenState, job: BackupJob, profiles: Vec<crate::services::remote::RemoteProfile>) {
+    let (tx, rx) = mpsc::channel();
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    let flag_clone = cancel_flag.clone();
+    let name = job.name.clone();
+    std::thread::spawn(move || {
+        let run = backup::run_backup(&job, &profiles, &flag_clone, &mut |so_far, path| {
+            let _ = tx.send(BackupEvent::Progress {
+                copied: so_far.copied,
+                skipped: so_far.skipped,
+                failed: so_far.failed,
+                current: path.display().to_string(),
+            });
+        });
+        if let Some(path) = backup::history_path() {
+            let _ = backup::append_history(&path, &run);
+        }
+        let _ = tx.send(BackupEvent::Finished(run));
+    });
+    state.message.clear();
+    state.running = Some(RunningBackup {
+        name,
+        receiver: rx,
+        cancel_flag,
+        copied: 0,
+        skipped: 0,
+        failed: 0,
+        current: String::new(),
+    });
+}
+
+pub fn draw(frame: &mut Frame, state: &mut BackupScreenState, jobs: &[BackupJob], area: Rect, theme: &Theme) {
+    state.poll();
+
+    let colors = &theme.backup_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),                        // status
+            Constraint::Min(3),                           // jobs
+            Constraint::Length(HISTORY_ROWS as u16 + 4),  // details + history
+            Constraint::Length(1),                        // footer
+        ])
+        .split(area);
+
+    // ── Status ──
+    let status_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Backups ", Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+    let status = if state.confirm_delete {
+        let name = jobs.get(state.selected).map(|j| j.name.as_str()).unwrap_or("");
+        Line::from(Span::styled(
+            format!("Delete backup job \"{}\" and its schedule? (y/n)", name),
+            Style::default().fg(colors.error_text).add_modifier(Modifier::BOLD),
+        ))
+    } else if let Some(ref running) = state.running {
+        Line::from(vec![
+            Span::styled(format!("Running {}: ", running.name), Style::default().fg(colors.message_text).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("{} copied, {} unchanged, {} failed  ", running.copied, running.skipped, running.failed),
+                Style::default().fg(colors.label_text),
+            ),
+            Span::styled(running.current.clone(), Style::default().fg(colors.dim_text)),
+        ])
+    } else if !state.message.is_empty() {
+        Line::from(Span::styled(state.message.clone(), Style::default().fg(colors.message_text)))
+    } else {
+        Line::from(Span::styled(
+            "Scheduled jobs run while `cokacdir --scheduler` is running",
+            Style::default().fg(colors.dim_text),
+        ))
+    };
+    frame.render_widget(Paragraph::new(status).block(status_block), chunks[0]);
+
+    // ── Jobs ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    let scroll = (state.selected + 1).saturating_sub(inner_height);
+    let lines: Vec<Line> = if jobs.is_empty() {
+        vec![Line::from(Span::styled("No backup jobs yet. Press n to add one.", Style::default().fg(colors.value_text)))]
+    } else {
+        jobs.iter().enumerate().skip(scroll).take(inner_height).map(|(i, job)| {
+            let (result, result_style) = match state.last_run(&job.name) {
+                Some(run) if run.success => (format!("ok {}", run.finished_at), Style::default().fg(colors.ok_text)),
+                Some(run) => (format!("failed {}", run.finished_at), Style::default().fg(colors.error_text)),
+                None => ("never run".to_string(), Style::default().fg(colors.dim_text)),
+            };
+            let schedule = if job.schedule.is_empty() { "manual".to_string() } else { job.schedule.clone() };
+            let line = Line::from(vec![
+                Span::styled(format!("{:<16} ", job.name), Style::default().fg(colors.value_text).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{} → {}  ", job.source, job.destination), Style::default().fg(colors.value_text)),
+                Span::styled(format!("[{}]  ", schedule), Style::default().fg(colors.label_text)),
+                Span::styled(result, result_style),
+            ]);
+            if i == state.selected {
+                line.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        }).collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Details + history of the selected job ──
+    let detail_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" History ", Style::default().fg(colors.title)))
+        .style(Style::default().bg(colors.bg));
+    let mut detail: Vec<Line> = Vec::new();
+    if let Some(job) = jobs.get(state.selected) {
+        let patterns = |list: &[String]| if list.is_empty() { "-".to_string() } else { list.join(", ") };
+        detail.push(Line::from(vec![
+            Span::styled("Include: ", Style::default().fg(colors.label_text)),
+            Span::styled(patterns(&job.include), Style::default().fg(colors.value_text)),
+            Span::styled("  Exclude: ", Style::default().fg(colors.label_text)),
+            Span::styled(patterns(&job.exclude), Style::default().fg(colors.value_text)),
+            Span::styled("  Compare: ", Style::default().fg(colors.label_text)),
+            Span::styled(job.compare.label(), Style::default().fg(colors.value_text)),
+        ]));
+        let runs: Vec<&BackupRun> = state.history.iter().rev().filter(|r| r.name == job.name).take(HISTORY_ROWS).collect();
+        if runs.is_empty() {
+            detail.push(Line::from(Span::styled("No runs recorded", Style::default().fg(colors.dim_text))));
+        }
+        for run in runs {
+            let style = if run.success { colors.value_text } else { colors.error_text };
+            detail.push(Line::from(vec![
+                Span::styled(format!("{}  ", run.started_at), Style::default().fg(colors.label_text)),
+                Span::styled(format!("{:>6.1}s  ", run.duration_ms as f64 / 1000.0), Style::default().fg(colors.dim_text)),
+                Span::styled(format!("{:>9}  ", format_size(run.bytes)), Style::default().fg(colors.dim_text)),
+                Span::styled(run.summary(), Style::default().fg(style)),
+            ]));
+        }
+    }
+    frame.render_widget(Paragraph::new(detail).block(detail_block), chunks[2]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(if state.running.is_some() { " Stop  " } else { " Close  " }),
+        key("n"),
+        text(" New  "),
+        key("Enter/e"),
+        text(" Edit  "),
+        key("r"),
+        text(" Run now  "),
+        key("Del/d"),
+        text(" Delete"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[3]);
+
+    if let Some(ref form) = state.form {
+        draw_form(frame, form, area, theme);
+    }
+}
+
+fn draw_form(frame: &mut Frame, form: &BackupForm, area: Rect, theme: &Theme) {
+    let colors = &theme.backup_screen;
+    let width = area.width.saturating_sub(4).min(80);
+    let height = (FORM_LABELS.len() as u16 + 5).min(area.height);
+    let rect = Rect::new(
+        area.x + (area.width.saturating_sub(width)) / 2,
+        area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+    frame.render_widget(Clear, rect);
+    let title = if form.editing.is_some() { " Edit Backup " } else { " New Backup " };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(title, Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (i, label) in FORM_LABELS.iter().enumerate() {
+        let value = if i == COMPARE_FIELD {
+            match form.compare {
+                CompareMode::Mtime => "size + modification time".to_string(),
+                CompareMode::Hash => "size + SHA-256 (local destinations only)".to_string(),
+            }
+        } else {
+            form.values[i].clone()
+        };
+        let selected = i == form.field;
+        let value_style = if selected {
+            Style::default().fg(colors.form_selected).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(colors.value_text)
+        };
+        let mut spans = vec![
+            Span::styled(format!(" {:<12}", label), Style::default().fg(colors.label_text)),
+            Span::styled(value, value_style),
+        ];
+        if selected && i != COMPARE_FIELD {
+            spans.push(Span::styled("_", Style::default().fg(colors.form_selected).add_modifier(Modifier::SLOW_BLINK)));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::default());
+    let hint = match form.field {
+        1 | 2 => "Local folder, user@host:/path or sftp://<profile>/path for the destination",
+        3 | 4 => "Name patterns separated by commas: *.jpg, node_modules/ (trailing / = folders)",
+        5 => "Cron: min hour day month weekday (\"0 2 * * *\" = 02:00 daily); empty = manual only",
+        COMPARE_FIELD => "Space/←/→ toggles",
+        _ => "Tab/↑/↓ move, Enter saves, Esc cancels",
+    };
+    lines.push(Line::from(Span::styled(format!(" {}", hint), Style::default().fg(colors.dim_text))));
+    frame.render_widget(Paragraph::new(lines).block(block), rect);
+}
+
+/// Save the form into settings (and the scheduler). Returns false if it stays open.
+fn save_form(app: &mut App, state: &mut BackupScreenState) -> bool {
+    let Some(ref form) = state.form else { return true };
+    let schedule_id = form.editing.and_then(|i| app.settings.backups.get(i)).and_then(|j| j.schedule_id.clone());
+    let mut job = form.to_job(schedule_id);
+    if job.name.is_empty() || job.source.is_empty() || job.destination.is_empty() {
+        state.message = "Name, source and destination are required".to_string();
+        return false;
+    }
+    let duplicate = app.settings.backups.iter().enumerate()
+        .any(|(i, j)| j.name == job.name && Some(i) != form.editing);
+    if duplicate {
+        state.message = format!("A backup job named \"{}\" already exists", job.name);
+        return false;
+    }
+    if job.compare == CompareMode::Hash && backup::is_remote_destination(&job.destination, &app.settings.remote_profiles) {
+        state.message = backup::HASH_REMOTE_ERROR.to_string();
+        return false;
+    }
+    if let Err(e) = backup::sync_schedule(&mut job) {
+        state.message = e;
+        return false;
+    }
+    state.message = format!("Saved {}", job.name);
+    match form.editing {
+        Some(i) if i < app.settings.backups.len() => app.settings.backups[i] = job,
+        _ => {
+            app.settings.backups.push(job);
+            state.selected = app.settings.backups.len() - 1;
+        }
+    }
+    let _ = app.settings.save();
+    true
+}
+
+fn handle_form_input(app: &mut App, state: &mut BackupScreenState, code: KeyCode) {
+    let Some(ref mut form) = state.form else { return };
+    match code {
+        KeyCode::Esc => state.form = None,
+        KeyCode::Enter => {
+            if save_form(app, state) {
+                state.form = None;
+            }
+        }
+        KeyCode::Tab | KeyCode::Down => form.field = (form.field + 1) % FORM_LABELS.len(),
+        KeyCode::BackTab | KeyCode::Up => form.field = (form.field + FORM_LABELS.len() - 1) % FORM_LABELS.len(),
+        KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right if form.field == COMPARE_FIELD => {
+            form.compare = match form.compare {
+                CompareMode::Mtime => CompareMode::Hash,
+                CompareMode::Hash => CompareMode::Mtime,
+            };
+        }
+        KeyCode::Backspace if form.field != COMPARE_FIELD => {
+            form.values[form.field].pop();
+        }
+        KeyCode::Char(c) if form.field != COMPARE_FIELD => form.values[form.field].push(c),
+        _ => {}
+    }
+}
+
+pub fn handle_input(app: &mut App, code: KeyCode, _modifiers: KeyModifiers) {
+    let Some(mut state) = app.backup_screen_state.take() else { return };
+
+    if state.form.is_some() {
+        handle_form_input(app, &mut state, code);
+        app.backup_screen_state = Some(state);
+        return;
+    }
+
+    if state.confirm_delete {
+        state.confirm_delete = false;
+        if matches!(code, KeyCode::Char('y') | KeyCode::Char('Y')) && state.selected < app.settings.backups.len() {
+            let mut job = app.settings.backups.remove(state.selected);
+            backup::remove_schedule(&mut job);
+            let _ = app.settings.save();
+            state.selected = state.selected.min(app.settings.backups.len().saturating_sub(1));
+            state.message = format!("Deleted {}", job.name);
+        }
+        app.backup_screen_state = Some(state);
+        return;
+    }
+
+    let count = app.settings.backups.len();
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            if let Some(ref running) = state.running {
+                running.cancel_flag.store(true, Ordering::Relaxed);
+            } else {
+                app.current_screen = Screen::FilePanel;
+                return;
+            }
+        }
+        KeyCode::Up => state.selected = state.selected.saturating_sub(1),
+        KeyCode::Down => {
+            if state.selected + 1 < count {
+                state.selected += 1;
+            }
+        }
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = count.saturating_sub(1),
+        KeyCode::Char('n') => {
+            // 새 작업: 활성 패널 → 다른 패널 (로컬일 때) 을 기본값으로
+            let source = app.active_panel().path.display().to_string();
+            let destination = if app.target_panel().is_remote() {
+                String::new()
+            } else {
+                app.target_panel().path.display().to_string()
+            };
+            let job = BackupJob {
+                name: String::new(),
+                source,
+                destination,
+                include: Vec::new(),
+                exclude: Vec::new(),
+                schedule: String::new(),
+                compare: CompareMode::Mtime,
+                schedule_id: None,
+            };
+            state.form = Some(BackupForm::from_job(None, &job));
+        }
+        KeyCode::Enter | KeyCode::Char('e') => {
+            if let Some(job) = app.settings.backups.get(state.selected) {
+                state.form = Some(BackupForm::from_job(Some(state.selected), job));
+            }
+        }
+        KeyCode::Char('r') if state.running.is_none() => {
+            if let Some(job) = app.settings.backups.get(state.selected).cloned() {
+                start_run(&mut state, job, app.settings.remote_profiles.clone());
+            }
+        }
+        KeyCode::Delete | KeyCode::Char('d') if state.running.is_none() && count > 0 => {
+            state.confirm_delete = true;
+        }
+        _ => {}
+    }
+    app.backup_screen_state = Some(state);
+}
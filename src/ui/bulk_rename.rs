@@ -0,0 +1,547 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use regex::Regex;
+
+use crate::services::file_ops;
+use crate::ui::theme::Theme;
+
+/// 대소문자 변환
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseTransform {
+    Keep,
+    Lower,
+    Upper,
+    Title,
+}
+
+impl CaseTransform {
+    fn label(self) -> &'static str {
+        match self {
+            CaseTransform::Keep => "Keep",
+            CaseTransform::Lower => "lower",
+            CaseTransform::Upper => "UPPER",
+            CaseTransform::Title => "Title Case",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            CaseTransform::Keep => CaseTransform::Lower,
+            CaseTransform::Lower => CaseTransform::Upper,
+            CaseTransform::Upper => CaseTransform::Title,
+            CaseTransform::Title => CaseTransform::Keep,
+        }
+    }
+
+    fn prev(self) -> Self {
+        self.next().next().next()
+    }
+
+    fn apply(self, name: &str) -> String {
+        match self {
+            CaseTransform::Keep => name.to_string(),
+            CaseTransform::Lower => name.to_lowercase(),
+            CaseTransform::Upper => name.to_uppercase(),
+            CaseTransform::Title => {
+                // 영숫자가 아닌 문자 다음의 첫 글자만 대문자
+                let mut result = String::with_capacity(name.len());
+                let mut word_start = true;
+                for c in name.chars() {
+                    if word_start {
+                        result.extend(c.to_uppercase());
+                    } else {
+                        result.extend(c.to_lowercase());
+                    }
+                    word_start = !c.is_alphanumeric();
+                }
+                result
+            }
+        }
+    }
+}
+
+/// 새 이름 규칙: 패턴 → 정규식 치환 → 대소문자 변환 순서로 적용
+///
+/// 패턴 토큰: `{n}` 확장자 없는 이름, `{e}` 확장자(점 포함), `{N}` 원래 이름 전체,
+/// `{c}` 카운터, `{c:W}` W자리 0 채움, `{c:W:S}` S부터 시작
+#[derive(Debug)]
+pub struct RenameRule {
+    pattern: String,
+    search: Option<Regex>,
+    replace: String,
+    case: CaseTransform,
+}
+
+impl RenameRule {
+    pub fn new(pattern: &str, search: &str, replace: &str, case: CaseTransform) -> Result<Self, String> {
+        let search = if search.is_empty() {
+            None
+        } else {
+            Some(Regex::new(search).map_err(|e| format!("Invalid regex: {}", e))?)
+        };
+        Ok(Self {
+            pattern: pattern.to_string(),
+            search,
+            replace: replace.to_string(),
+            case,
+        })
+    }
+
+    /// `index`번째(0부터) 파일의 새 이름
+    pub fn apply(&self, name: &str, index: usize) -> Result<String, String> {
+        let (stem, ext) = split_extension(name);
+        let mut result = String::new();
+        let mut rest = self.pattern.as_str();
+        while let Some(open) = rest.find('{') {
+            result.push_str(&rest[..open]);
+            let Some(close) = rest[open..].find('}') else {
+                return Err("Unclosed '{' in pattern".to_string());
+            };
+            let token = &rest[open + 1..open + close];
+            match token {
+                "n" => result.push_str(stem),
+                "e" => result.push_str(ext),
+                "N" => result.push_str(name),
+                _ if token == "c" || token.starts_with("c:") => {
+                    result.push_str(&format_counter(token, index)?);
+                }
+                _ => return Err(format!("Unknown token {{{}}}", token)),
+            }
+            rest = &rest[open + close + 1..];
+        }
+        result.push_str(rest);
+
+        if let Some(ref search) = self.search {
+            result = search.replace_all(&result, self.replace.as_str()).into_owned();
+        }
+        Ok(self.case.apply(&result))
+    }
+}
+
+/// "archive.tar.gz" → ("archive.tar", ".gz"); 점으로 시작하는 숨김 파일은 확장자 없음
+fn split_extension(name: &str) -> (&str, &str) {
+    match name.rfind('.') {
+        Some(pos) if pos > 0 => (&name[..pos], &name[pos..]),
+        _ => (name, ""),
+    }
+}
+
+/// `c`, `c:W`, `c:W:S` 토큰의 카운터 문자열
+fn format_counter(token: &str, index: usize) -> Result<String, String> {
+    let mut parts = token.split(':').skip(1);
+    let parse = |s: Option<&str>, default: usize| -> Result<usize, String> {
+        match s {
+            None | Some("") => Ok(default),
+            Some(s) => s.parse().map_err(|_| format!("Invalid counter {{{}}}", token)),
+        }
+    };
+    let width = parse(parts.next(), 0)?;
+    let start = parse(parts.next(), 1)?;
+    Ok(format!("{:0width$}", start + index, width = width))
+}
+
+/// 입력 포커스
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Pattern,
+    Search,
+    Replace,
+    Case,
+    List,
+}
+
+impl Field {
+    fn next(self) -> Self {
+        match self {
+            Field::Pattern => Field::Search,
+            Field::Search => Field::Replace,
+            Field::Replace => Field::Case,
+            Field::Case => Field::List,
+            Field::List => Field::Pattern,
+        }
+    }
+
+    fn prev(self) -> Self {
+        self.next().next().next().next()
+    }
+}
+
+pub struct BulkRenameState {
+    pub dir: PathBuf,
+    /// Original names in panel order
+    names: Vec<String>,
+    pattern: String,
+    search: String,
+    replace: String,
+    case: CaseTransform,
+    focus: Field,
+    /// New names typed by hand in the preview, by row
+    overrides: HashMap<usize, String>,
+    /// New names from the rule (or the rule's error)
+    preview: Result<Vec<String>, String>,
+    pub selected: usize,
+    pub scroll: usize,
+    /// Row name being edited
+    editing: Option<String>,
+    message: String,
+    /// Number of renamed files once the rename succeeded
+    pub renamed: Option<usize>,
+}
+
+impl BulkRenameState {
+    pub fn new(dir: PathBuf, names: Vec<String>) -> Self {
+        let mut state = Self {
+            dir,
+            names,
+            pattern: "{n}{e}".to_string(),
+            search: String::new(),
+            replace: String::new(),
+            case: CaseTransform::Keep,
+            focus: Field::Pattern,
+            overrides: HashMap::new(),
+            preview: Ok(Vec::new()),
+            selected: 0,
+            scroll: 0,
+            editing: None,
+            message: String::new(),
+            renamed: None,
+        };
+        state.update_preview();
+        state
+    }
+
+    fn update_preview(&mut self) {
+        self.preview = RenameRule::new(&self.pattern, &self.search, &self.replace, self.case)
+            .and_then(|rule| {
+                self.names.iter().enumerate()
+                    .map(|(i, name)| rule.apply(name, i))
+                    .collect()
+            });
+    }
+
+    /// 행의 새 이름 (직접 수정한 이름 우선)
+    fn new_name(&self, row: usize) -> Option<&str> {
+        if let Some(name) = self.overrides.get(&row) {
+            return Some(name.as_str());
+        }
+        self.preview.as_ref().ok()?.get(row).map(|s| s.as_str())
+    }
+
+    fn renames(&self) -> Result<Vec<(String, String)>, String> {
+        if let Err(ref e) = self.preview {
+            return Err(e.clone());
+        }
+        Ok(self.names.iter().enumerate()
+            .filter_map(|(i, old)| {
+                let new = self.new_name(i)?;
+                (new != old.as_str()).then(|| (old.clone(), new.to_string()))
+            })
+            .collect())
+    }
+
+    fn changed_count(&self) -> usize {
+        self.renames().map(|r| r.len()).unwrap_or(0)
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.names.is_empty() {
+            return;
+        }
+        let max = self.names.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    fn focused_text(&mut self) -> Option<&mut String> {
+        match self.focus {
+            Field::Pattern => Some(&mut self.pattern),
+            Field::Search => Some(&mut self.search),
+            Field::Replace => Some(&mut self.replace),
+            Field::Case | Field::List => None,
+        }
+    }
+
+    /// 이름 변경 실행. 성공하면 true (화면 닫기)
+    fn execute(&mut self) -> bool {
+        let renames = match self.renames() {
+            Ok(renames) => renames,
+            Err(e) => {
+                self.message = e;
+                return false;
+            }
+        };
+        if renames.is_empty() {
+            self.message = "Nothing to rename".to_string();
+            return false;
+        }
+        match file_ops::bulk_rename(&self.dir, &renames) {
+            Ok(count) => {
+                self.renamed = Some(count);
+                true
+            }
+            Err(e) => {
+                self.message = format!("Rename failed: {}", e);
+                false
+            }
+        }
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut BulkRenameState, area: Rect, theme: &Theme) {
+    let colors = &theme.bulk_rename;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(6), // rule fields
+            Constraint::Min(3),    // preview
+            Constraint::Length(3), // status
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Rule fields ──
+    let rule_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(
+            format!(" Bulk Rename ({} files) ", state.names.len()),
+            Style::default().fg(colors.title).add_modifier(Modifier::BOLD),
+        ))
+        .style(Style::default().bg(colors.bg));
+    let field_line = |label: &'static str, value: String, hint: &'static str, field: Field| {
+        let focused = state.focus == field;
+        let label_style = if focused {
+            Style::default().fg(colors.label_active).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(colors.label_text)
+        };
+        Line::from(vec![
+            Span::styled(format!("{:>8}: ", label), label_style),
+            Span::styled(value, Style::default().fg(colors.input_text)),
+            Span::styled(if focused { "_" } else { "" }, Style::default().fg(colors.input_cursor)),
+            Span::styled(format!("  {}", hint), Style::default().fg(colors.hint_text)),
+        ])
+    };
+    let case_hint = if state.focus == Field::Case { "←/→ to change" } else { "" };
+    let rule_lines = vec![
+        field_line("Pattern", state.pattern.clone(), "{n} name {e} .ext {N} full {c} {c:3} {c:3:10} counter", Field::Pattern),
+        field_line("Search", state.search.clone(), "regex", Field::Search),
+        field_line("Replace", state.replace.clone(), "$1 for groups", Field::Replace),
+        Line::from(vec![
+            Span::styled(
+                format!("{:>8}: ", "Case"),
+                if state.focus == Field::Case {
+                    Style::default().fg(colors.label_active).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(colors.label_text)
+                },
+            ),
+            Span::styled(state.case.label(), Style::default().fg(colors.input_text)),
+            Span::styled(format!("  {}", case_hint), Style::default().fg(colors.hint_text)),
+        ]),
+    ];
+    frame.render_widget(Paragraph::new(rule_lines).block(rule_block), chunks[0]);
+
+    // ── Preview ──
+    let list_border = if state.focus == Field::List { colors.list_border_active } else { colors.border };
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(list_border))
+        .style(Style::default().bg(colors.bg));
+
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    let old_width = state.names.iter().map(|n| n.chars().count()).max().unwrap_or(0).min(40);
+    let lines: Vec<Line> = state.names.iter().enumerate()
+        .skip(state.scroll)
+        .take(inner_height)
+        .map(|(i, old)| {
+            let (new, new_style) = match (&state.editing, state.new_name(i)) {
+                (Some(buffer), _) if i == state.selected => {
+                    (format!("{}_", buffer), Style::default().fg(colors.edit_text).add_modifier(Modifier::BOLD))
+                }
+                (_, Some(new)) if file_ops::is_valid_filename(new).is_err() => {
+                    (new.to_string(), Style::default().fg(colors.invalid_name))
+                }
+                (_, Some(new)) if new == old.as_str() => (new.to_string(), Style::default().fg(colors.unchanged_name)),
+                (_, Some(new)) => (new.to_string(), Style::default().fg(colors.new_name)),
+                (_, None) => (String::new(), Style::default()),
+            };
+            let edited = if state.overrides.contains_key(&i) { "*" } else { " " };
+            let line = Line::from(vec![
+                Span::styled(format!("{:<width$}", old, width = old_width), Style::default().fg(colors.old_name)),
+                Span::styled(format!("  →{} ", edited), Style::default().fg(colors.arrow_text)),
+                Span::styled(new, new_style),
+            ]);
+            if i == state.selected && state.focus == Field::List {
+                line.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Status ──
+    let status_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let status = if !state.message.is_empty() {
+        Line::from(Span::styled(state.message.clone(), Style::default().fg(colors.error_text)))
+    } else if let Err(ref e) = state.preview {
+        Line::from(Span::styled(e.clone(), Style::default().fg(colors.error_text)))
+    } else {
+        Line::from(Span::styled(
+            format!("{} of {} files will be renamed", state.changed_count(), state.names.len()),
+            Style::default().fg(colors.status_text),
+        ))
+    };
+    frame.render_widget(Paragraph::new(status).block(status_block), chunks[2]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(" Close  "),
+        key("Tab"),
+        text(" Next field  "),
+        key("Enter"),
+        text(" Edit name (list)  "),
+        key("Del"),
+        text(" Reset name  "),
+        key("Ctrl+S"),
+        text(" Rename"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut BulkRenameState, code: KeyCode, modifiers: KeyModifiers) -> bool {
+    let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+    state.message.clear();
+
+    // 미리보기 행 이름 직접 수정 중
+    if let Some(mut buffer) = state.editing.take() {
+        match code {
+            KeyCode::Enter => {
+                state.overrides.insert(state.selected, buffer);
+                return false;
+            }
+            KeyCode::Esc => return false,
+            KeyCode::Backspace => { buffer.pop(); }
+            KeyCode::Char('u') if ctrl => buffer.clear(),
+            KeyCode::Char(c) if !ctrl => buffer.push(c),
+            _ => {}
+        }
+        state.editing = Some(buffer);
+        return false;
+    }
+
+    match code {
+        KeyCode::Esc => return true,
+        KeyCode::Char('s') if ctrl => return state.execute(),
+        KeyCode::Tab => state.focus = state.focus.next(),
+        KeyCode::BackTab => state.focus = state.focus.prev(),
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        _ => match state.focus {
+            Field::Case => {
+                match code {
+                    KeyCode::Left => state.case = state.case.prev(),
+                    KeyCode::Right | KeyCode::Char(' ') | KeyCode::Enter => state.case = state.case.next(),
+                    _ => return false,
+                }
+                state.update_preview();
+            }
+            Field::List => match code {
+                KeyCode::Home => state.selected = 0,
+                KeyCode::End => state.selected = state.names.len().saturating_sub(1),
+                KeyCode::Enter => {
+                    state.editing = state.new_name(state.selected).map(|s| s.to_string());
+                }
+                KeyCode::Delete => { state.overrides.remove(&state.selected); }
+                _ => {}
+            },
+            Field::Pattern | Field::Search | Field::Replace => {
+                let Some(text) = state.focused_text() else { return false };
+                match code {
+                    KeyCode::Backspace => { text.pop(); }
+                    KeyCode::Char('u') if ctrl => text.clear(),
+                    KeyCode::Char(c) if !ctrl => text.push(c),
+                    KeyCode::Enter => {
+                        state.focus = state.focus.next();
+                        return false;
+                    }
+                    _ => return false,
+                }
+                state.update_preview();
+            }
+        },
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_rule_tokens() {
+        let rule = RenameRule::new("photo_{c:3}{e}", "", "", CaseTransform::Keep).unwrap();
+        assert_eq!(rule.apply("IMG_1234.JPG", 0).unwrap(), "photo_001.JPG");
+        assert_eq!(rule.apply("IMG_1235.JPG", 11).unwrap(), "photo_012.JPG");
+
+        let rule = RenameRule::new("{n} ({c::5}){e}", "", "", CaseTransform::Keep).unwrap();
+        assert_eq!(rule.apply("report.pdf", 0).unwrap(), "report (5).pdf");
+        assert_eq!(rule.apply(".bashrc", 1).unwrap(), ".bashrc (6)");
+
+        assert!(RenameRule::new("{x}", "", "", CaseTransform::Keep).unwrap().apply("a", 0).is_err());
+        assert!(RenameRule::new("{n", "", "", CaseTransform::Keep).unwrap().apply("a", 0).is_err());
+        assert!(RenameRule::new("{c:w}", "", "", CaseTransform::Keep).unwrap().apply("a", 0).is_err());
+    }
+
+    #[test]
+    fn test_rename_rule_regex_and_case() {
+        let rule = RenameRule::new("{N}", r"(\d{4})-(\d{2})", "$2.$1", CaseTransform::Keep).unwrap();
+        assert_eq!(rule.apply("log 2024-05.txt", 0).unwrap(), "log 05.2024.txt");
+        assert!(RenameRule::new("{N}", "(", "", CaseTransform::Keep).is_err());
+
+        let rule = RenameRule::new("{N}", "_", " ", CaseTransform::Title).unwrap();
+        assert_eq!(rule.apply("my_HOLIDAY_photos.jpg", 0).unwrap(), "My Holiday Photos.Jpg");
+        let rule = RenameRule::new("{N}", "", "", CaseTransform::Lower).unwrap();
+        assert_eq!(rule.apply("README.MD", 0).unwrap(), "readme.md");
+    }
+
+    #[test]
+    fn test_overrides_and_unchanged_names() {
+        let mut state = BulkRenameState::new(PathBuf::from("/tmp"), vec!["a.txt".into(), "b.txt".into()]);
+        // Default pattern keeps every name
+        assert!(state.renames().unwrap().is_empty());
+
+        state.overrides.insert(1, "c.txt".to_string());
+        assert_eq!(state.renames().unwrap(), vec![("b.txt".to_string(), "c.txt".to_string())]);
+
+        state.pattern = "{n".to_string();
+        state.update_preview();
+        assert!(state.renames().is_err());
+    }
+}
@@ -0,0 +1,434 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::services::checksum::{self, Algorithm, ChecksumMessage, VerifyStatus};
+use crate::ui::theme::Theme;
+use crate::utils::format::format_size;
+use crate::utils::osc52;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChecksumMode {
+    /// Hash the selected files
+    Compute,
+    /// Check files against an existing manifest
+    Verify(PathBuf),
+    /// Hash two files (absolute paths in `names`) and report whether they are identical
+    Compare,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowStatus {
+    Hash(String),
+    Verified(VerifyStatus),
+    Failed(String),
+}
+
+pub struct ChecksumRow {
+    /// Path relative to `base_dir`
+    pub name: String,
+    pub status: RowStatus,
+}
+
+pub struct ChecksumScreenState {
+    pub mode: ChecksumMode,
+    pub base_dir: PathBuf,
+    /// Selected panel items (re-hashed when the algorithm changes)
+    pub names: Vec<String>,
+    pub algorithm: Algorithm,
+    pub rows: Vec<ChecksumRow>,
+    pub selected: usize,
+    pub scroll: usize,
+    pub current_file: String,
+    pub total_files: usize,
+    pub total_bytes: u64,
+    pub bytes_done: Arc<AtomicU64>,
+    pub is_complete: bool,
+    pub message: Option<String>,
+    pub receiver: Option<Receiver<ChecksumMessage>>,
+    pub cancel_flag: Arc<AtomicBool>,
+}
+
+impl ChecksumScreenState {
+    /// Hash `names` (relative to `base_dir`) with SHA-256
+    pub fn compute(base_dir: PathBuf, names: Vec<String>) -> Self {
+        let mut state = Self::empty(ChecksumMode::Compute, base_dir, names, Algorithm::Sha256);
+        state.start();
+        state
+    }
+
+    /// Compare two files by checksum
+    pub fn compare(first: PathBuf, second: PathBuf) -> Self {
+        // Empty base_dir: names are absolute paths
+        let names = vec![first.display().to_string(), second.display().to_string()];
+        let mut state = Self::empty(ChecksumMode::Compare, PathBuf::new(), names, Algorithm::Sha256);
+        state.start();
+        state
+    }
+
+    /// Verify the files listed in `manifest`
+    pub fn verify(manifest: PathBuf, algorithm: Algorithm) -> Self {
+        let base_dir = manifest.parent().map(PathBuf::from).unwrap_or_default();
+        let mut state = Self::empty(ChecksumMode::Verify(manifest), base_dir, Vec::new(), algorithm);
+        state.start();
+        state
+    }
+
+    fn empty(mode: ChecksumMode, base_dir: PathBuf, names: Vec<String>, algorithm: Algorithm) -> Self {
+        Self {
+            mode,
+            base_dir,
+            names,
+            algorithm,
+            rows: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            current_file: String::new(),
+            total_files: 0,
+            total_bytes: 0,
+            bytes_done: Arc::new(AtomicU64::new(0)),
+            is_complete: false,
+            message: None,
+            receiver: None,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// (Re)start the background job, dropping previous results
+    fn start(&mut self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let bytes_done = Arc::new(AtomicU64::new(0));
+        self.cancel_flag = cancel_flag.clone();
+        self.bytes_done = bytes_done.clone();
+        self.receiver = Some(rx);
+        self.rows.clear();
+        self.selected = 0;
+        self.scroll = 0;
+        self.current_file.clear();
+        self.total_files = 0;
+        self.total_bytes = 0;
+        self.is_complete = false;
+
+        let algorithm = self.algorithm;
+        match self.mode.clone() {
+            ChecksumMode::Compute | ChecksumMode::Compare => {
+                let base_dir = self.base_dir.clone();
+                let names = self.names.clone();
+                std::thread::spawn(move || {
+                    checksum::run_compute(base_dir, names, algorithm, tx, cancel_flag, bytes_done);
+                });
+            }
+            ChecksumMode::Verify(manifest) => {
+                std::thread::spawn(move || {
+                    checksum::run_verify(manifest, algorithm, tx, cancel_flag, bytes_done);
+                });
+            }
+        }
+    }
+
+    fn poll(&mut self) {
+        let messages: Vec<ChecksumMessage> = match self.receiver {
+            Some(ref rx) => rx.try_iter().collect(),
+            None => return,
+        };
+
+        for msg in messages {
+            match msg {
+                ChecksumMessage::Totals { files, bytes } => {
+                    self.total_files = files;
+                    self.total_bytes = bytes;
+                }
+                ChecksumMessage::Started(name) => {
+                    self.current_file = name;
+                }
+                ChecksumMessage::Hashed { name, hash } => {
+                    self.rows.push(ChecksumRow { name, status: RowStatus::Hash(hash) });
+                }
+                ChecksumMessage::Verified { name, status } => {
+                    self.rows.push(ChecksumRow { name, status: RowStatus::Verified(status) });
+                }
+                ChecksumMessage::Failed { name, error } => {
+                    self.rows.push(ChecksumRow { name, status: RowStatus::Failed(error) });
+                }
+                ChecksumMessage::Complete => {
+                    self.is_complete = true;
+                    self.current_file.clear();
+                    self.receiver = None;
+                }
+            }
+        }
+    }
+
+    /// Rows with a computed hash, as (hash, name)
+    fn hashes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.rows.iter().filter_map(|row| match row.status {
+            RowStatus::Hash(ref hash) => Some((hash.as_str(), row.name.as_str())),
+            _ => None,
+        })
+    }
+
+    /// Whether this mode hashes the selected files (as opposed to verifying a manifest)
+    fn hashes_files(&self) -> bool {
+        matches!(self.mode, ChecksumMode::Compute | ChecksumMode::Compare)
+    }
+
+    /// Compare mode: Some(true) if both files hashed to the same value
+    fn is_identical(&self) -> Option<bool> {
+        if self.mode != ChecksumMode::Compare || !self.is_complete {
+            return None;
+        }
+        match self.hashes().collect::<Vec<_>>().as_slice() {
+            [(a, _), (b, _)] => Some(a == b),
+            _ => None,
+        }
+    }
+
+    fn count_status(&self, status: VerifyStatus) -> usize {
+        self.rows.iter().filter(|r| r.status == RowStatus::Verified(status)).count()
+    }
+
+    fn failed_count(&self) -> usize {
+        self.rows.iter().filter(|r| matches!(r.status, RowStatus::Failed(_))).count()
+    }
+
+    fn copy_to_clipboard(&mut self, all: bool) {
+        let (text, what) = if all {
+            (checksum::format_manifest(self.hashes()), format!("{} checksums", self.hashes().count()))
+        } else {
+            match self.rows.get(self.selected) {
+                Some(ChecksumRow { name, status: RowStatus::Hash(hash) }) => (hash.clone(), name.clone()),
+                _ => return,
+            }
+        };
+        if text.is_empty() {
+            return;
+        }
+        self.message = Some(match osc52::copy(&text) {
+            Ok(result) => result.message(&what),
+            Err(e) => format!("Clipboard error: {}", e),
+        });
+    }
+
+    fn write_manifest(&mut self) {
+        if !self.is_complete || self.hashes().next().is_none() {
+            return;
+        }
+        let file_name = checksum::manifest_file_name(&self.names, self.algorithm);
+        let path = self.base_dir.join(&file_name);
+        let text = checksum::format_manifest(self.hashes());
+        self.message = Some(match std::fs::write(&path, text) {
+            Ok(()) => format!("Wrote {}", file_name),
+            Err(e) => format!("Cannot write {}: {}", file_name, e),
+        });
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let max = self.rows.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(max);
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut ChecksumScreenState, area: Rect, theme: &Theme) {
+    state.poll();
+
+    let colors = &theme.checksum_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // info box
+            Constraint::Min(3),    // results
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Info box ──
+    let (title, target) = match state.mode {
+        ChecksumMode::Compute => (" Checksums ", state.base_dir.display().to_string()),
+        ChecksumMode::Verify(ref manifest) => (" Verify Checksums ", manifest.display().to_string()),
+        ChecksumMode::Compare => (" Compare Checksums ", state.names.join("  <->  ")),
+    };
+    let phase_text = if state.is_complete {
+        "Complete".to_string()
+    } else if state.current_file.is_empty() {
+        "Scanning...".to_string()
+    } else {
+        format!("{} / {}", state.rows.len() + 1, state.total_files)
+    };
+
+    let info_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(title, Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+
+    let line1 = Line::from(vec![
+        Span::styled("Target: ", Style::default().fg(colors.label_text)),
+        Span::styled(target, Style::default().fg(colors.target_text)),
+        Span::raw("  "),
+        Span::styled(
+            format!("[{}] [{}]", state.algorithm.name(), phase_text),
+            Style::default().fg(colors.phase_text).add_modifier(Modifier::BOLD),
+        ),
+    ]);
+
+    let done = state.bytes_done.load(Ordering::Relaxed).min(state.total_bytes);
+    let percent = if state.total_bytes > 0 { done * 100 / state.total_bytes } else { 0 };
+    let mut stats = vec![
+        Span::styled("Progress: ", Style::default().fg(colors.label_text)),
+        Span::styled(
+            format!("{}% ({} / {})", percent, format_size(done), format_size(state.total_bytes)),
+            Style::default().fg(colors.progress_text).add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if let ChecksumMode::Verify(_) = state.mode {
+        stats.push(Span::styled("  |  OK: ", Style::default().fg(colors.label_text)));
+        stats.push(Span::styled(
+            state.count_status(VerifyStatus::Ok).to_string(),
+            Style::default().fg(colors.ok_text).add_modifier(Modifier::BOLD),
+        ));
+        stats.push(Span::styled("  |  Mismatch: ", Style::default().fg(colors.label_text)));
+        stats.push(Span::styled(
+            state.count_status(VerifyStatus::Mismatch).to_string(),
+            Style::default().fg(colors.mismatch_text).add_modifier(Modifier::BOLD),
+        ));
+        stats.push(Span::styled("  |  Missing: ", Style::default().fg(colors.label_text)));
+        stats.push(Span::styled(
+            state.count_status(VerifyStatus::Missing).to_string(),
+            Style::default().fg(colors.missing_text).add_modifier(Modifier::BOLD),
+        ));
+    }
+    match state.is_identical() {
+        Some(true) => {
+            stats.push(Span::styled("  |  Result: ", Style::default().fg(colors.label_text)));
+            stats.push(Span::styled("IDENTICAL", Style::default().fg(colors.ok_text).add_modifier(Modifier::BOLD)));
+        }
+        Some(false) => {
+            stats.push(Span::styled("  |  Result: ", Style::default().fg(colors.label_text)));
+            stats.push(Span::styled("DIFFERENT", Style::default().fg(colors.mismatch_text).add_modifier(Modifier::BOLD)));
+        }
+        None => {}
+    }
+    let failed = state.failed_count();
+    if failed > 0 {
+        stats.push(Span::styled("  |  Errors: ", Style::default().fg(colors.label_text)));
+        stats.push(Span::styled(failed.to_string(), Style::default().fg(colors.error_text).add_modifier(Modifier::BOLD)));
+    }
+
+    let info = Paragraph::new(vec![line1, Line::from(stats)]).block(info_block);
+    frame.render_widget(info, chunks[0]);
+
+    // ── Results ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Results ", Style::default().fg(colors.title)))
+        .style(Style::default().bg(colors.bg));
+
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    let name_style = Style::default().fg(colors.name_text);
+    let lines: Vec<Line> = state
+        .rows
+        .iter()
+        .enumerate()
+        .skip(state.scroll)
+        .take(inner_height)
+        .map(|(i, row)| {
+            let (label, label_style) = match row.status {
+                RowStatus::Hash(ref hash) => (hash.clone(), Style::default().fg(colors.hash_text)),
+                RowStatus::Verified(VerifyStatus::Ok) => ("OK      ".to_string(), Style::default().fg(colors.ok_text)),
+                RowStatus::Verified(VerifyStatus::Mismatch) => ("MISMATCH".to_string(), Style::default().fg(colors.mismatch_text)),
+                RowStatus::Verified(VerifyStatus::Missing) => ("MISSING ".to_string(), Style::default().fg(colors.missing_text)),
+                RowStatus::Failed(ref e) => (format!("[ERROR] {}", e), Style::default().fg(colors.error_text)),
+            };
+            let line = Line::from(vec![
+                Span::styled(label, label_style),
+                Span::styled(format!("  {}", row.name), name_style),
+            ]);
+            if i == state.selected {
+                line.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let mut footer_items = vec![
+        key(" Esc"),
+        text(if state.is_complete { " Close  " } else { " Cancel  " }),
+        key("Up/Down"),
+        text(" Select  "),
+    ];
+    if state.hashes_files() {
+        footer_items.extend([
+            key("Tab"),
+            text(" Algorithm  "),
+            key("c"),
+            text(" Copy  "),
+            key("a"),
+            text(" Copy all  "),
+        ]);
+    }
+    if state.mode == ChecksumMode::Compute {
+        footer_items.extend([key("w"), text(" Write manifest  ")]);
+    }
+    if let Some(ref msg) = state.message {
+        footer_items.push(Span::styled(msg.clone(), Style::default().fg(colors.message_text)));
+    }
+
+    let footer = Paragraph::new(Line::from(footer_items)).style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[2]);
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut ChecksumScreenState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    state.message = None;
+    match code {
+        KeyCode::Esc => {
+            if state.is_complete || state.receiver.is_none() {
+                return true;
+            }
+            // Cancel; the worker stops and sends Complete
+            state.cancel_flag.store(true, Ordering::Relaxed);
+        }
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.rows.len().saturating_sub(1),
+        KeyCode::Tab if state.hashes_files() => {
+            state.algorithm = state.algorithm.next();
+            state.start();
+        }
+        KeyCode::Char('c') if state.hashes_files() => state.copy_to_clipboard(false),
+        KeyCode::Char('a') if state.hashes_files() => state.copy_to_clipboard(true),
+        KeyCode::Char('w') if state.mode == ChecksumMode::Compute => state.write_manifest(),
+        _ => {}
+    }
+    false
+}
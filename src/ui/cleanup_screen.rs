@@ -0,0 +1,421 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::services::cleanup::{self, CleanupCategory, CleanupItem, CleanupMessage};
+use crate::ui::theme::Theme;
+use crate::utils::format::format_size;
+
+/// 목록의 한 줄: 분류 머리줄 또는 펼친 분류의 항목
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CleanupRow {
+    Category(CleanupCategory),
+    Item(usize),
+}
+
+pub struct CleanupScreenState {
+    pub root: PathBuf,
+    items: Vec<CleanupItem>,
+    rows: Vec<CleanupRow>,
+    /// Categories marked for deletion
+    marked: HashSet<CleanupCategory>,
+    /// Items of marked categories the user chose to keep
+    kept: HashSet<PathBuf>,
+    expanded: HashSet<CleanupCategory>,
+    pub selected: usize,
+    pub scroll: usize,
+    /// Scan or deletion still running
+    pub busy: bool,
+    pub scanning: bool,
+    pub current: String,
+    /// Waiting for y/n on the delete confirmation
+    pub confirm: bool,
+    pub freed: u64,
+    pub message: String,
+    receiver: Option<Receiver<CleanupMessage>>,
+    cancel_flag: Arc<AtomicBool>,
+}
+
+impl CleanupScreenState {
+    pub fn new(root: PathBuf) -> Self {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let flag_clone = cancel_flag.clone();
+        let root_clone = root.clone();
+        std::thread::spawn(move || cleanup::run_scan(root_clone, tx, flag_clone));
+
+        let mut state = Self {
+            root,
+            items: Vec::new(),
+            rows: Vec::new(),
+            marked: HashSet::new(),
+            kept: HashSet::new(),
+            expanded: HashSet::new(),
+            selected: 0,
+            scroll: 0,
+            busy: true,
+            scanning: true,
+            current: String::new(),
+            confirm: false,
+            freed: 0,
+            message: String::new(),
+            receiver: Some(rx),
+            cancel_flag,
+        };
+        state.rebuild_rows();
+        state
+    }
+
+    pub fn poll(&mut self) {
+        let messages: Vec<CleanupMessage> = match self.receiver {
+            Some(ref rx) => rx.try_iter().collect(),
+            None => return,
+        };
+        let mut changed = false;
+        for msg in messages {
+            match msg {
+                CleanupMessage::Scanning(dir) => self.current = dir.display().to_string(),
+                CleanupMessage::Found(item) => {
+                    self.items.push(item);
+                    changed = true;
+                }
+                CleanupMessage::ScanComplete => {
+                    self.scanning = false;
+                    self.busy = false;
+                    self.receiver = None;
+                    self.current.clear();
+                    self.message = if self.items.is_empty() {
+                        "Nothing to clean up here".to_string()
+                    } else {
+                        format!("Found {} in {} item(s)", format_size(self.total_size(|_| true)), self.items.len())
+                    };
+                }
+                CleanupMessage::Deleted(path, size) => {
+                    self.freed += size;
+                    self.current = path.display().to_string();
+                    self.items.retain(|i| i.path != path);
+                    self.kept.remove(&path);
+                    changed = true;
+                }
+                CleanupMessage::Error(e) => self.message = format!("Error: {}", e),
+                CleanupMessage::DeleteComplete => {
+                    self.busy = false;
+                    self.receiver = None;
+                    self.current.clear();
+                    self.marked.retain(|c| self.items.iter().any(|i| i.category == *c));
+                    if !self.message.starts_with("Error") {
+                        self.message = format!("Freed {}", format_size(self.freed));
+                    }
+                }
+            }
+        }
+        if changed {
+            self.rebuild_rows();
+        }
+    }
+
+    fn rebuild_rows(&mut self) {
+        self.rows.clear();
+        for category in CleanupCategory::ALL {
+            let mut indices: Vec<usize> = (0..self.items.len())
+                .filter(|&i| self.items[i].category == category)
+                .collect();
+            if indices.is_empty() {
+                continue;
+            }
+            self.rows.push(CleanupRow::Category(category));
+            if self.expanded.contains(&category) {
+                indices.sort_by(|&a, &b| self.items[b].size.cmp(&self.items[a].size));
+                self.rows.extend(indices.into_iter().map(CleanupRow::Item));
+            }
+        }
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+    }
+
+    fn total_size(&self, filter: impl Fn(&CleanupItem) -> bool) -> u64 {
+        self.items.iter().filter(|i| filter(i)).map(|i| i.size).sum()
+    }
+
+    fn is_selected_for_deletion(&self, item: &CleanupItem) -> bool {
+        self.marked.contains(&item.category) && !self.kept.contains(&item.path)
+    }
+
+    fn items_to_delete(&self) -> Vec<CleanupItem> {
+        self.items.iter().filter(|i| self.is_selected_for_deletion(i)).cloned().collect()
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let max = self.rows.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    /// Space: mark a category / keep or re-add an item of a marked category
+    fn toggle_selected(&mut self) {
+        match self.rows.get(self.selected).copied() {
+            Some(CleanupRow::Category(category)) => {
+                if !self.marked.remove(&category) {
+                    self.marked.insert(category);
+                }
+            }
+            Some(CleanupRow::Item(idx)) => {
+                let item = &self.items[idx];
+                if !self.marked.contains(&item.category) {
+                    // 분류를 표시하고 이 항목만 남김
+                    self.marked.insert(item.category);
+                    let category = item.category;
+                    let path = item.path.clone();
+                    for other in self.items.iter().filter(|i| i.category == category && i.path != path) {
+                        self.kept.insert(other.path.clone());
+                    }
+                } else if !self.kept.remove(&item.path) {
+                    self.kept.insert(item.path.clone());
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Enter: expand/collapse the category under the cursor (or the item's category)
+    fn toggle_expanded(&mut self) {
+        let category = match self.rows.get(self.selected).copied() {
+            Some(CleanupRow::Category(c)) => c,
+            Some(CleanupRow::Item(idx)) => self.items[idx].category,
+            None => return,
+        };
+        if !self.expanded.remove(&category) {
+            self.expanded.insert(category);
+        }
+        self.rebuild_rows();
+        if let Some(pos) = self.rows.iter().position(|r| *r == CleanupRow::Category(category)) {
+            self.selected = pos;
+        }
+    }
+
+    fn request_delete(&mut self) {
+        let items = self.items_to_delete();
+        if items.is_empty() {
+            self.message = "Mark a category with Space first".to_string();
+            return;
+        }
+        self.confirm = true;
+    }
+
+    fn start_delete(&mut self) {
+        self.confirm = false;
+        let items = self.items_to_delete();
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.cancel_flag = Arc::new(AtomicBool::new(false));
+        let flag_clone = self.cancel_flag.clone();
+        std::thread::spawn(move || cleanup::run_delete(items, tx, flag_clone));
+        self.receiver = Some(rx);
+        self.busy = true;
+        self.message.clear();
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut CleanupScreenState, area: Rect, theme: &Theme) {
+    state.poll();
+
+    let colors = &theme.cleanup_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // info box
+            Constraint::Min(3),    // categories
+            Constraint::Length(3), // status / confirmation
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Info box ──
+    let phase = if state.scanning {
+        "Scanning..."
+    } else if state.busy {
+        "Deleting..."
+    } else {
+        "Review"
+    };
+    let info_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Storage Cleanup ", Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+    let to_delete = state.items_to_delete();
+    let info = vec![
+        Line::from(vec![
+            Span::styled("Target: ", Style::default().fg(colors.label_text)),
+            Span::styled(state.root.display().to_string(), Style::default().fg(colors.target_text)),
+            Span::raw("  "),
+            Span::styled(format!("[{}]", phase), Style::default().fg(colors.phase_text).add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(vec![
+            Span::styled("Found: ", Style::default().fg(colors.label_text)),
+            Span::styled(format_size(state.total_size(|_| true)), Style::default().fg(colors.found_size).add_modifier(Modifier::BOLD)),
+            Span::styled("  |  Marked: ", Style::default().fg(colors.label_text)),
+            Span::styled(
+                format!("{} ({} items)", format_size(to_delete.iter().map(|i| i.size).sum()), to_delete.len()),
+                Style::default().fg(colors.marked_size).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  |  Freed: ", Style::default().fg(colors.label_text)),
+            Span::styled(format_size(state.freed), Style::default().fg(colors.freed_size).add_modifier(Modifier::BOLD)),
+        ]),
+    ];
+    frame.render_widget(Paragraph::new(info).block(info_block), chunks[0]);
+
+    // ── Categories / items ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    let size_width = 10;
+    let lines: Vec<Line> = if state.rows.is_empty() {
+        let text = if state.scanning { "Looking for space hogs..." } else { "Nothing to clean up" };
+        vec![Line::from(Span::styled(text, Style::default().fg(colors.empty_text)))]
+    } else {
+        state.rows.iter().enumerate().skip(state.scroll).take(inner_height).map(|(i, row)| {
+            let line = match *row {
+                CleanupRow::Category(category) => {
+                    let count = state.items.iter().filter(|it| it.category == category).count();
+                    let size = state.total_size(|it| it.category == category);
+                    let check = if state.marked.contains(&category) { "[x] " } else { "[ ] " };
+                    let arrow = if state.expanded.contains(&category) { "▾ " } else { "▸ " };
+                    Line::from(vec![
+                        Span::styled(arrow, Style::default().fg(colors.arrow_text)),
+                        Span::styled(check, Style::default().fg(colors.check_mark)),
+                        Span::styled(format!("{:>w$}  ", format_size(size), w = size_width), Style::default().fg(colors.category_size)),
+                        Span::styled(category.label(), Style::default().fg(colors.category_name).add_modifier(Modifier::BOLD)),
+                        Span::styled(format!("  ({})", count), Style::default().fg(colors.category_count)),
+                    ])
+                }
+                CleanupRow::Item(idx) => {
+                    let item = &state.items[idx];
+                    let check = if state.is_selected_for_deletion(item) { "[x] " } else { "[ ] " };
+                    let shown = item.path.strip_prefix(&state.root).unwrap_or(&item.path);
+                    let suffix = if item.is_dir { "/" } else { "" };
+                    Line::from(vec![
+                        Span::raw("    "),
+                        Span::styled(check, Style::default().fg(colors.check_mark)),
+                        Span::styled(format!("{:>w$}  ", format_size(item.size), w = size_width), Style::default().fg(colors.item_size)),
+                        Span::styled(format!("{}{}", shown.display(), suffix), Style::default().fg(colors.item_path)),
+                    ])
+                }
+            };
+            if i == state.selected {
+                line.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        }).collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Status / confirmation ──
+    let status_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let status = if state.confirm {
+        let categories = to_delete.iter().map(|i| i.category).collect::<HashSet<_>>().len();
+        Line::from(Span::styled(
+            format!(
+                "PERMANENTLY delete {} item(s) in {} categor{} ({})? (y/n)",
+                to_delete.len(),
+                categories,
+                if categories == 1 { "y" } else { "ies" },
+                format_size(to_delete.iter().map(|i| i.size).sum()),
+            ),
+            Style::default().fg(colors.confirm_text).add_modifier(Modifier::BOLD),
+        ))
+    } else if state.busy {
+        Line::from(Span::styled(state.current.clone(), Style::default().fg(colors.progress_text)))
+    } else {
+        Line::from(Span::styled(state.message.clone(), Style::default().fg(colors.message_text)))
+    };
+    frame.render_widget(Paragraph::new(status).block(status_block), chunks[2]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(if state.busy { " Cancel  " } else { " Close  " }),
+        key("Space"),
+        text(" Mark  "),
+        key("Enter"),
+        text(" Expand  "),
+        key("a"),
+        text(" Mark all  "),
+        key("Del/d"),
+        text(" Delete marked"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut CleanupScreenState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    if state.confirm {
+        match code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => state.start_delete(),
+            _ => state.confirm = false,
+        }
+        return false;
+    }
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            if !state.busy {
+                return true;
+            }
+            state.cancel_flag.store(true, Ordering::Relaxed);
+        }
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.rows.len().saturating_sub(1),
+        KeyCode::Enter => state.toggle_expanded(),
+        // 삭제 중에는 표시를 바꾸지 않음
+        _ if state.busy && !state.scanning => {}
+        KeyCode::Char(' ') => {
+            state.toggle_selected();
+            state.move_selection(1);
+        }
+        KeyCode::Char('a') => {
+            let all_marked = CleanupCategory::ALL.iter()
+                .filter(|c| state.items.iter().any(|i| i.category == **c))
+                .all(|c| state.marked.contains(c));
+            if all_marked {
+                state.marked.clear();
+            } else {
+                state.marked.extend(state.items.iter().map(|i| i.category));
+            }
+            state.kept.clear();
+        }
+        KeyCode::Delete | KeyCode::Char('d') if !state.busy => state.request_delete(),
+        _ => {}
+    }
+    false
+}
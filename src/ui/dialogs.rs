@@ -2,7 +2,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crossterm::event::{KeyCode, KeyModifiers};
-use unicode_width::UnicodeWidthChar;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
@@ -12,16 +12,18 @@ use ratatui::{
 };
 
 use crate::keybindings::GotoAction;
-use crate::services::file_ops::FileOperationType;
-use crate::utils::format::{safe_suffix, safe_prefix};
+use crate::services::file_ops::{ConflictPolicy, FileOperationType};
+use crate::utils::calc;
+use crate::utils::fuzzy::fuzzy_match;
+use crate::utils::format::{self, format_duration, format_number, safe_suffix, safe_prefix, truncate_with_ellipsis};
 
 use super::{
-    app::{App, ConflictResolution, ConflictState, Dialog, DialogType, GitLogDiffState, PathCompletion, RemoteConnectState, SettingsState, fuzzy_match},
+    app::{App, BasketState, BreadcrumbState, ClipboardOperation, ConflictResolution, ConflictState, Dialog, DialogType, FavoritesState, GitLogDiffState, ImageExportState, OpenWithState, PathCompletion, JumpEntry, JumpState, RecentFilesState, RemoteConnectState, SettingsState},
     theme::Theme,
 };
 
 /// 경로 문자열을 확장 (~ 홈 경로 확장)
-fn expand_path_string(input: &str) -> PathBuf {
+pub(crate) fn expand_path_string(input: &str) -> PathBuf {
     if input.starts_with('~') {
         if let Some(home) = dirs::home_dir() {
             let rest = input.strip_prefix('~').unwrap_or("");
@@ -41,7 +43,7 @@ fn expand_path_string(input: &str) -> PathBuf {
 
 /// 입력 경로를 (기준 디렉토리, 접두어)로 분리
 /// `~` 홈 경로 확장 처리
-fn parse_path_for_completion(input: &str) -> (PathBuf, String) {
+pub(crate) fn parse_path_for_completion(input: &str) -> (PathBuf, String) {
     // `~` 확장
     let expanded = if input.starts_with('~') {
         if let Some(home) = dirs::home_dir() {
@@ -115,7 +117,7 @@ fn matches_subsequence(text: &str, pattern: &str) -> bool {
 /// 디렉토리 읽기 및 순차 매칭
 /// 대소문자 무시 검색, 디렉토리 우선 정렬
 /// Security: Filters out . and .. entries to prevent path traversal
-fn get_path_suggestions(base_dir: &PathBuf, prefix: &str) -> Vec<String> {
+pub(crate) fn get_path_suggestions(base_dir: &PathBuf, prefix: &str) -> Vec<String> {
     let mut suggestions: Vec<(String, bool)> = Vec::new();
     let lower_prefix = prefix.to_lowercase();
 
@@ -201,7 +203,7 @@ fn trigger_path_completion(dialog: &mut Dialog) {
 }
 
 /// 공통 접두어 찾기
-fn find_common_prefix(suggestions: &[String]) -> String {
+pub(crate) fn find_common_prefix(suggestions: &[String]) -> String {
     if suggestions.is_empty() {
         return String::new();
     }
@@ -254,7 +256,7 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
     const SIMPLE_INPUT_HEIGHT: u16 = 5;       // 간단한 입력 다이얼로그 높이
     const CONFIRM_DIALOG_HEIGHT: u16 = 6;     // 확인 다이얼로그 높이
     const PROGRESS_DIALOG_HEIGHT: u16 = 8;    // 프로그레스 다이얼로그 높이
-    const CONFLICT_DIALOG_HEIGHT: u16 = 9;    // 충돌 다이얼로그 높이 (버튼 2줄)
+    const CONFLICT_DIALOG_HEIGHT: u16 = 10;   // 충돌 다이얼로그 높이 (버튼 3줄)
 
     // 자동완성 목록 현재 높이 계산
     let completion_height = if let Some(ref completion) = dialog.completion {
@@ -275,7 +277,7 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
             (SIMPLE_DIALOG_WIDTH, CONFIRM_DIALOG_HEIGHT, CONFIRM_DIALOG_HEIGHT)
         }
         DialogType::DedupConfirm => {
-            (60, 10, 10)
+            (60, 11, 11)
         }
         DialogType::ExtensionHandlerError => {
             // Error dialog: wider to accommodate error messages, taller for multi-line
@@ -299,9 +301,20 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
 
             (w, h, max_h)
         }
-        DialogType::Search | DialogType::Mkdir | DialogType::Mkfile | DialogType::Rename | DialogType::Tar => {
+        DialogType::Mkdir | DialogType::Mkfile | DialogType::Rename
+        | DialogType::PasteExclude | DialogType::SelectFilter | DialogType::Calculator | DialogType::ChangeExtension | DialogType::SaveAICode
+        | DialogType::SaveSmartFolder | DialogType::BasketArchive => {
             (SIMPLE_DIALOG_WIDTH, SIMPLE_INPUT_HEIGHT, SIMPLE_INPUT_HEIGHT)
         }
+        DialogType::Search => {
+            // Extra row for the search options line
+            (SIMPLE_DIALOG_WIDTH, 7, 7)
+        }
+        DialogType::Tar => {
+            // Extra row for the archive profile line
+            let h = if app.settings.archive_profiles.is_empty() { SIMPLE_INPUT_HEIGHT } else { 7 };
+            (SIMPLE_DIALOG_WIDTH, h, h)
+        }
         DialogType::EncryptConfirm => {
             (SIMPLE_DIALOG_WIDTH, 7, 7)
         }
@@ -315,7 +328,11 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
             (60, 15, 15) // Exclude confirm dialog
         }
         DialogType::Settings => {
-            (42, 6, 6) // Settings dialog: width=42, height=6
+            // 4 fixed rows + extension actions (scrolled) + add row + blank + help + border
+            let ext_rows = app.settings_state.as_ref()
+                .map_or(0, |s| s.ext_actions.len().min(SETTINGS_MAX_EXT_ROWS)) as u16;
+            let h = 9 + ext_rows;
+            (52, h, h)
         }
         DialogType::BinaryFileHandler => {
             // Dynamic height based on input display width
@@ -338,6 +355,33 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
             let h = area.height.saturating_sub(6).max(15).min(30);
             (w, h, h)
         }
+        DialogType::OpenWith => {
+            let w = area.width.saturating_sub(6).max(50).min(80);
+            let h = area.height.saturating_sub(6).max(12).min(24);
+            (w, h, h)
+        }
+        DialogType::RecentFiles | DialogType::JumpDirectory | DialogType::Breadcrumb => {
+            let w = area.width.saturating_sub(6).max(50).min(100);
+            let h = area.height.saturating_sub(6).max(12).min(24);
+            (w, h, h)
+        }
+        DialogType::Basket => {
+            let w = area.width.saturating_sub(6).max(50).min(100);
+            // 항목 + gap + hint + 2 border
+            let h = (app.basket.len() as u16 + 4).clamp(8, area.height.saturating_sub(6).max(8));
+            (w, h, h)
+        }
+        DialogType::Favorites => {
+            // 9 slots + gap + hint + 2 border
+            let w = area.width.saturating_sub(6).max(50).min(80);
+            let h = crate::config::FAVORITES_MAX as u16 + 4;
+            (w, h, h)
+        }
+        DialogType::ImageExport => {
+            // 3 fields + gap + output + hint + 2 border
+            let w = area.width.saturating_sub(DIALOG_MARGIN).max(DIALOG_MIN_WIDTH).min(64);
+            (w, 8, 8)
+        }
         DialogType::RemoteConnect => {
             let w = area.width.saturating_sub(DIALOG_MARGIN).max(DIALOG_MIN_WIDTH).min(80);
             // Password: 5 fields + 1 gap + 1 help + 2 border = 9
@@ -389,7 +433,7 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
             draw_confirm_dialog(frame, dialog, dialog_area, theme, " Decrypt ");
         }
         DialogType::DedupConfirm => {
-            draw_dedup_confirm_dialog(frame, dialog, dialog_area, theme);
+            draw_dedup_confirm_dialog(frame, app, dialog, dialog_area, theme);
         }
         DialogType::LargeImageConfirm => {
             draw_confirm_dialog(frame, dialog, dialog_area, theme, " Large Image ");
@@ -403,8 +447,35 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
         DialogType::Goto => {
             draw_goto_dialog(frame, app, dialog, dialog_area, theme);
         }
-        DialogType::Search | DialogType::Mkdir | DialogType::Mkfile | DialogType::Rename | DialogType::Tar => {
+        DialogType::Mkdir | DialogType::Mkfile | DialogType::Rename
+        | DialogType::PasteExclude | DialogType::SelectFilter | DialogType::Calculator | DialogType::ChangeExtension | DialogType::SaveAICode
+        | DialogType::SaveSmartFolder | DialogType::BasketArchive => {
+            draw_simple_input_dialog(frame, dialog, dialog_area, theme);
+        }
+        DialogType::Search => {
+            draw_simple_input_dialog(frame, dialog, dialog_area, theme);
+            let style = if app.search_options.content {
+                Style::default().fg(theme.dialog.text).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.dialog.text).add_modifier(Modifier::DIM)
+            };
+            // Same row as the Tar profile line
+            let options_area = Rect::new(dialog_area.x + 2, dialog_area.y + 5, dialog_area.width.saturating_sub(4), 1);
+            frame.render_widget(Paragraph::new(format!("  {}", app.search_options_label())).style(style), options_area);
+        }
+        DialogType::Tar => {
             draw_simple_input_dialog(frame, dialog, dialog_area, theme);
+            if !app.settings.archive_profiles.is_empty() {
+                let label = format!("  Tab: profile [{}]", app.tar_profile_name().unwrap_or("none"));
+                let style = if app.tar_profile.is_some() {
+                    Style::default().fg(theme.dialog.text).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.dialog.text).add_modifier(Modifier::DIM)
+                };
+                // Same row as the EncryptConfirm MD5 toggle (inside the border)
+                let profile_area = Rect::new(dialog_area.x + 2, dialog_area.y + 5, dialog_area.width.saturating_sub(4), 1);
+                frame.render_widget(Paragraph::new(label).style(style), profile_area);
+            }
         }
         DialogType::Progress => {
             draw_progress_dialog(frame, app, dialog_area, theme);
@@ -435,6 +506,41 @@ pub fn draw_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, th
                 draw_git_log_diff_dialog(frame, dialog, state, dialog_area, theme);
             }
         }
+        DialogType::OpenWith => {
+            if let Some(ref state) = app.open_with_state {
+                draw_open_with_dialog(frame, dialog, state, dialog_area, theme);
+            }
+        }
+        DialogType::RecentFiles => {
+            if let Some(ref state) = app.recent_files_state {
+                draw_recent_files_dialog(frame, dialog, state, dialog_area, theme);
+            }
+        }
+        DialogType::JumpDirectory => {
+            if let Some(ref state) = app.jump_state {
+                draw_jump_dialog(frame, dialog, state, dialog_area, theme);
+            }
+        }
+        DialogType::Breadcrumb => {
+            if let Some(ref state) = app.breadcrumb_state {
+                draw_breadcrumb_dialog(frame, state, dialog_area, theme);
+            }
+        }
+        DialogType::Favorites => {
+            if let Some(ref state) = app.favorites_state {
+                draw_favorites_dialog(frame, app, state, dialog_area, theme);
+            }
+        }
+        DialogType::Basket => {
+            if let Some(ref state) = app.basket_state {
+                draw_basket_dialog(frame, app, state, dialog_area, theme);
+            }
+        }
+        DialogType::ImageExport => {
+            if let Some(ref state) = app.image_export_state {
+                draw_image_export_dialog(frame, state, dialog_area, theme);
+            }
+        }
         DialogType::RemoteConnect => {
             draw_remote_connect_dialog(frame, app, dialog_area, theme);
         }
@@ -806,6 +912,17 @@ fn get_handler_placeholder(extension: &str) -> String {
     command.to_string()
 }
 
+/// 계산기 입력 아래에 보일 결과 (비어 있으면 사용 예)
+fn calculator_message(input: &str) -> String {
+    if input.trim().is_empty() {
+        return "e.g. 1.5 GiB in MB, (500GB - 120GB) / 3, 4 * 1024^2".to_string();
+    }
+    match calc::evaluate(input) {
+        Ok(result) => calc::describe(&result),
+        Err(e) => format!("? {}", e),
+    }
+}
+
 /// 간결한 입력 다이얼로그 (Find File, Mkdir, Rename)
 fn draw_simple_input_dialog(frame: &mut Frame, dialog: &Dialog, area: Rect, theme: &Theme) {
     let title = match dialog.dialog_type {
@@ -814,6 +931,13 @@ fn draw_simple_input_dialog(frame: &mut Frame, dialog: &Dialog, area: Rect, them
         DialogType::Mkfile => " Create File ",
         DialogType::Rename => " Rename ",
         DialogType::Tar => " Create Archive ",
+        DialogType::PasteExclude => " Paste Excluding ",
+        DialogType::SelectFilter => " Select by Filter ",
+        DialogType::Calculator => " Calculator ",
+        DialogType::ChangeExtension => " Change Extension ",
+        DialogType::SaveAICode => " Save Code Block ",
+        DialogType::SaveSmartFolder => " Save Smart Folder ",
+        DialogType::BasketArchive => " Zip Basket ",
         DialogType::RemoteProfileSave => " Save Profile ",
         DialogType::EncryptConfirm => " Encrypt ",
         _ => " Input ",
@@ -935,25 +1059,39 @@ fn draw_simple_input_dialog(frame: &mut Frame, dialog: &Dialog, area: Rect, them
         ])
     };
 
+    // 계산기는 입력할 때마다 결과를 보여줌
+    let message = if dialog.dialog_type == DialogType::Calculator {
+        calculator_message(&dialog.input)
+    } else {
+        dialog.message.clone()
+    };
+
     // Tar/Mkdir/Mkfile/Rename/RemoteProfileSave 다이얼로그의 경우 메시지 표시 (에러 메시지 포함)
     if (dialog.dialog_type == DialogType::Tar
         || dialog.dialog_type == DialogType::Mkdir
         || dialog.dialog_type == DialogType::Mkfile
         || dialog.dialog_type == DialogType::Rename
         || dialog.dialog_type == DialogType::RemoteProfileSave
-        || dialog.dialog_type == DialogType::EncryptConfirm)
-        && !dialog.message.is_empty()
+        || dialog.dialog_type == DialogType::EncryptConfirm
+        || dialog.dialog_type == DialogType::PasteExclude
+        || dialog.dialog_type == DialogType::SelectFilter
+        || dialog.dialog_type == DialogType::Calculator
+        || dialog.dialog_type == DialogType::ChangeExtension
+        || dialog.dialog_type == DialogType::SaveAICode
+        || dialog.dialog_type == DialogType::SaveSmartFolder
+        || dialog.dialog_type == DialogType::BasketArchive)
+        && !message.is_empty()
     {
         let message_y = inner.y;
         let message_area = Rect::new(inner.x + 1, message_y, inner.width - 2, 1);
         // Use warning style for error messages (ending with !)
-        let message_style = if dialog.message.ends_with('!') {
+        let message_style = if message.ends_with('!') {
             Style::default().fg(theme.state.warning).add_modifier(Modifier::BOLD)
         } else {
             Style::default().fg(theme.dialog.text)
         };
         frame.render_widget(
-            Paragraph::new(dialog.message.clone()).style(message_style),
+            Paragraph::new(message.clone()).style(message_style),
             message_area,
         );
         let input_area = Rect::new(inner.x + 1, inner.y + 2, inner.width - 2, 1);
@@ -1026,7 +1164,7 @@ fn draw_confirm_dialog(frame: &mut Frame, dialog: &Dialog, area: Rect, theme: &T
     );
 }
 
-fn draw_dedup_confirm_dialog(frame: &mut Frame, dialog: &Dialog, area: Rect, theme: &Theme) {
+fn draw_dedup_confirm_dialog(frame: &mut Frame, app: &App, dialog: &Dialog, area: Rect, theme: &Theme) {
     let block = Block::default()
         .title(" Remove Duplicates ")
         .title_style(Style::default().fg(theme.confirm_dialog.title).add_modifier(Modifier::BOLD))
@@ -1056,6 +1194,16 @@ fn draw_dedup_confirm_dialog(frame: &mut Frame, dialog: &Dialog, area: Rect, the
         msg_area,
     );
 
+    // 옵션: .gitignore/.ignore로 무시된 경로 건너뛰기
+    let check = if app.dedup_respect_ignore { 'x' } else { ' ' };
+    let option_area = Rect::new(inner.x + 1, inner.y + 6, inner.width - 2, 1);
+    frame.render_widget(
+        Paragraph::new(format!("g: skip .gitignore/.ignore'd paths [{}]", check))
+            .style(Style::default().fg(theme.confirm_dialog.message_text))
+            .alignment(ratatui::layout::Alignment::Center),
+        option_area,
+    );
+
     // Buttons
     let selected_style = Style::default()
         .fg(theme.confirm_dialog.button_selected_text)
@@ -1508,19 +1656,12 @@ fn draw_progress_dialog(frame: &mut Frame, app: &App, area: Rect, theme: &Theme)
         None => return,
     };
 
-    let title = match progress.operation_type {
-        FileOperationType::Copy => " Copying ",
-        FileOperationType::Move => " Moving ",
-        FileOperationType::Tar => " Creating Archive ",
-        FileOperationType::Untar => " Extracting Archive ",
-        FileOperationType::Download => " Downloading ",
-        FileOperationType::Encrypt => " Encrypting ",
-        FileOperationType::Decrypt => " Decrypting ",
-    };
+    let title = format!(" {} ", progress.operation_type.verb());
 
     let block = Block::default()
         .title(title)
         .title_style(Style::default().fg(theme.dialog.title).add_modifier(Modifier::BOLD))
+        .title_bottom(Line::from(" Esc cancel  B background ").right_aligned())
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.dialog.border))
         .style(Style::default().bg(theme.dialog.bg));
@@ -1549,7 +1690,8 @@ fn draw_progress_dialog(frame: &mut Frame, app: &App, area: Rect, theme: &Theme)
     }
 
     // Current file name (truncated if needed)
-    let max_filename_len = (inner.width - 8) as usize;
+    let resume_note = progress.resumed_percent.map(|p| format!("  Resuming ({}%)", p)).unwrap_or_default();
+    let max_filename_len = (inner.width as usize).saturating_sub(8 + resume_note.len());
     let current_file = if progress.current_file.len() > max_filename_len {
         format!("...{}", safe_suffix(&progress.current_file, max_filename_len.saturating_sub(3)))
     } else {
@@ -1559,6 +1701,7 @@ fn draw_progress_dialog(frame: &mut Frame, app: &App, area: Rect, theme: &Theme)
     let file_line = Line::from(vec![
         Span::styled("File: ", Style::default().fg(theme.dialog.progress_label_text)),
         Span::styled(current_file, Style::default().fg(theme.dialog.progress_value_text)),
+        Span::styled(resume_note, Style::default().fg(theme.dialog.progress_bar_fill)),
     ]);
     let file_area = Rect::new(inner.x + 1, inner.y, inner.width - 2, 1);
     frame.render_widget(Paragraph::new(file_line), file_area);
@@ -1580,18 +1723,24 @@ fn draw_progress_dialog(frame: &mut Frame, app: &App, area: Rect, theme: &Theme)
     frame.render_widget(Paragraph::new(file_bar_line), file_bar_area);
 
     // Total progress info
-    let total_info = if progress.operation_type == FileOperationType::Tar
-        || progress.operation_type == FileOperationType::Untar {
+    let total_info = if matches!(
+        progress.operation_type,
+        FileOperationType::Tar | FileOperationType::Untar | FileOperationType::Zip | FileOperationType::Unzip
+    ) {
         if progress.total_files > 0 {
-            format!("{}/{} files", progress.completed_files, progress.total_files)
+            format!(
+                "{}/{} files",
+                format_number(progress.completed_files as u64),
+                format_number(progress.total_files as u64),
+            )
         } else {
-            format!("{} files processed", progress.completed_files)
+            format!("{} files processed", format_number(progress.completed_files as u64))
         }
     } else {
         format!(
             "{}/{} files ({}/{})",
-            progress.completed_files,
-            progress.total_files,
+            format_number(progress.completed_files as u64),
+            format_number(progress.total_files as u64),
             format_size(progress.completed_bytes),
             format_size(progress.total_bytes),
         )
@@ -1620,6 +1769,24 @@ fn draw_progress_dialog(frame: &mut Frame, app: &App, area: Rect, theme: &Theme)
         frame.render_widget(Paragraph::new(total_bar_line), total_bar_area);
     }
     // Indeterminate progress: don't show progress bar or percentage
+
+    // Speed, ETA and elapsed time
+    let mut stats: Vec<Span> = Vec::new();
+    let label_style = Style::default().fg(theme.dialog.progress_label_text);
+    let value_style = Style::default().fg(theme.dialog.progress_value_text);
+    if let Some(speed) = progress.bytes_per_sec() {
+        stats.push(Span::styled(format!("{}/s", format_size(speed as u64)), value_style));
+        stats.push(Span::styled("  ", label_style));
+    }
+    if let Some(eta) = progress.eta() {
+        stats.push(Span::styled("ETA ", label_style));
+        stats.push(Span::styled(format_duration(eta.as_secs()), value_style));
+        stats.push(Span::styled("  ", label_style));
+    }
+    stats.push(Span::styled("Elapsed ", label_style));
+    stats.push(Span::styled(format_duration(progress.elapsed().as_secs()), value_style));
+    let stats_area = Rect::new(inner.x + 1, inner.y + 5, inner.width - 2, 1);
+    frame.render_widget(Paragraph::new(Line::from(stats)), stats_area);
 }
 
 /// Duplicate conflict dialog for file paste operations
@@ -1676,14 +1843,16 @@ fn draw_duplicate_conflict_dialog(
         progress_area,
     );
 
-    // Buttons - 2 rows of 2 buttons each
+    // Buttons - 3 rows of 2 buttons each
     // Row 1: Overwrite (0), Skip (1)
     // Row 2: Overwrite All (2), Skip All (3)
+    // Row 3: If Newer (4), If Larger (5) - applied to all remaining conflicts
     let selected = dialog.selected_button;
 
     // Calculate button positions
     let button_y1 = inner.y + 5;
     let button_y2 = inner.y + 6;
+    let button_y3 = inner.y + 7;
     let col1_x = inner.x + 4;
     let col2_x = inner.x + inner.width / 2 + 2;
 
@@ -1731,6 +1900,23 @@ fn draw_duplicate_conflict_dialog(
         Span::styled("l ", style),
     ]);
     frame.render_widget(Paragraph::new(btn_skip_all), Rect::new(col2_x, button_y2, 10, 1));
+
+    // Row 3: If Newer, If Larger
+    let (style, key_style) = get_styles(selected == 4);
+    let btn_if_newer = Line::from(vec![
+        Span::styled(" If ", style),
+        Span::styled("N", key_style),
+        Span::styled("ewer ", style),
+    ]);
+    frame.render_widget(Paragraph::new(btn_if_newer), Rect::new(col1_x, button_y3, 10, 1));
+
+    let (style, key_style) = get_styles(selected == 5);
+    let btn_if_larger = Line::from(vec![
+        Span::styled(" If Lar", style),
+        Span::styled("g", key_style),
+        Span::styled("er ", style),
+    ]);
+    frame.render_widget(Paragraph::new(btn_if_larger), Rect::new(col2_x, button_y3, 11, 1));
 }
 
 /// Tar exclude confirmation dialog
@@ -1832,20 +2018,12 @@ fn draw_tar_exclude_confirm_dialog(
     );
 }
 
-/// Format file size for display
+/// Format file size for display (공백 없는 단축형, 예: "1.5MB")
 fn format_size(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = 1024 * 1024;
-    const GB: u64 = 1024 * 1024 * 1024;
-
-    if bytes >= GB {
-        format!("{:.1}GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.1}MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.1}KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{}B", bytes)
+    let size = format::format_size(bytes);
+    match size.rsplit_once(' ') {
+        Some((value, unit)) => format!("{}{}", value, unit),
+        None => size,
     }
 }
 
@@ -2025,7 +2203,8 @@ pub fn handle_paste(app: &mut App, text: &str) {
             // Dialog types with text input
             DialogType::Search | DialogType::Mkdir | DialogType::Mkfile
             | DialogType::Rename | DialogType::Tar | DialogType::BinaryFileHandler
-            | DialogType::EncryptConfirm => {
+            | DialogType::EncryptConfirm | DialogType::PasteExclude | DialogType::SelectFilter | DialogType::Calculator | DialogType::ChangeExtension | DialogType::SaveAICode
+            | DialogType::SaveSmartFolder | DialogType::BasketArchive => {
                 // Delete selection if exists
                 if let Some((sel_start, sel_end)) = dialog.selection.take() {
                     let mut chars: Vec<char> = dialog.input.chars().collect();
@@ -2062,6 +2241,28 @@ pub fn handle_paste(app: &mut App, text: &str) {
                 dialog.cursor_pos += paste_len;
                 update_path_suggestions(dialog);
             }
+            DialogType::OpenWith => {
+                // Input is append-only (filter or command)
+                dialog.input.push_str(&paste_text);
+                dialog.cursor_pos = dialog.input.chars().count();
+                if let Some(ref mut state) = app.open_with_state {
+                    state.apply_filter(&dialog.input);
+                }
+            }
+            DialogType::RecentFiles => {
+                dialog.input.push_str(&paste_text);
+                dialog.cursor_pos = dialog.input.chars().count();
+                if let Some(ref mut state) = app.recent_files_state {
+                    state.apply_filter(&dialog.input);
+                }
+            }
+            DialogType::JumpDirectory => {
+                dialog.input.push_str(&paste_text);
+                dialog.cursor_pos = dialog.input.chars().count();
+                if let Some(ref mut state) = app.jump_state {
+                    state.apply_filter(&dialog.input);
+                }
+            }
             DialogType::RemoteConnect => {
                 // Paste into the active field of remote connect dialog
                 if let Some(ref mut state) = app.remote_connect_state {
@@ -2156,6 +2357,9 @@ pub fn handle_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers
                     KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
                         app.dialog = None;
                     }
+                    KeyCode::Char('g') | KeyCode::Char('G') => {
+                        app.dedup_respect_ignore = !app.dedup_respect_ignore;
+                    }
                     KeyCode::Left | KeyCode::Right | KeyCode::Tab => {
                         dialog.selected_button = 1 - dialog.selected_button;
                     }
@@ -2250,6 +2454,27 @@ pub fn handle_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers
             DialogType::GitLogDiff => {
                 return handle_git_log_diff_input(app, code);
             }
+            DialogType::OpenWith => {
+                return handle_open_with_input(app, code);
+            }
+            DialogType::RecentFiles => {
+                return handle_recent_files_input(app, code);
+            }
+            DialogType::JumpDirectory => {
+                return handle_jump_input(app, code);
+            }
+            DialogType::Breadcrumb => {
+                return handle_breadcrumb_input(app, code);
+            }
+            DialogType::Favorites => {
+                return handle_favorites_input(app, code, modifiers);
+            }
+            DialogType::Basket => {
+                return handle_basket_input(app, code);
+            }
+            DialogType::ImageExport => {
+                return handle_image_export_input(app, code);
+            }
             DialogType::RemoteConnect => {
                 return handle_remote_connect_input(app, code);
             }
@@ -2257,6 +2482,23 @@ pub fn handle_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers
                 return handle_remote_profile_save_input(app, code);
             }
             _ => {
+                // Find File 옵션: Tab 이름/내용 검색, Alt+R 정규식, Alt+C 대소문자 구분, Alt+G ignore 파일 존중
+                if dialog.dialog_type == DialogType::Search {
+                    let alt = modifiers.contains(KeyModifiers::ALT);
+                    let options = &mut app.search_options;
+                    let toggled = match code {
+                        KeyCode::Tab => Some(&mut options.content),
+                        KeyCode::Char('r') if alt => Some(&mut options.regex),
+                        KeyCode::Char('c') if alt => Some(&mut options.case_sensitive),
+                        KeyCode::Char('g') if alt => Some(&mut options.respect_ignore),
+                        _ => None,
+                    };
+                    if let Some(flag) = toggled {
+                        *flag = !*flag;
+                        return false;
+                    }
+                }
+
                 // selection 상태에서의 특수 처리
                 if let Some((sel_start, sel_end)) = dialog.selection {
                     match code {
@@ -2311,6 +2553,12 @@ pub fn handle_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers
                     return false;
                 }
 
+                // Tab: next archive profile for Tar
+                if code == KeyCode::Tab && dialog.dialog_type == DialogType::Tar {
+                    app.cycle_tar_profile();
+                    return false;
+                }
+
                 match code {
                     KeyCode::Enter => {
                         let input = dialog.input.clone();
@@ -2381,6 +2629,33 @@ pub fn handle_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers
                             return false;
                         }
 
+                        if dialog_type == DialogType::SaveAICode {
+                            if input.trim().is_empty() {
+                                app.dialog = None;
+                                app.ai_pending_code = None;
+                            } else {
+                                app.execute_save_ai_code(&input);
+                            }
+                            return false;
+                        }
+
+                        // 결과를 입력으로 가져와 계산을 이어감 (Esc로 닫음)
+                        if dialog_type == DialogType::Calculator {
+                            if let (Ok(result), Some(d)) = (calc::evaluate(&input), app.dialog.as_mut()) {
+                                d.input = calc::as_input(&result);
+                                d.cursor_pos = d.input.chars().count();
+                                d.selection = None;
+                            }
+                            return false;
+                        }
+
+                        // Empty exclude list: plain paste
+                        if dialog_type == DialogType::PasteExclude {
+                            app.dialog = None;
+                            app.execute_paste_exclude(&input);
+                            return false;
+                        }
+
                         app.dialog = None;
                         if !input.trim().is_empty() {
                             match dialog_type {
@@ -2390,6 +2665,10 @@ pub fn handle_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers
                                 DialogType::Tar => app.execute_tar(&input),
                                 DialogType::Search => app.execute_search(&input),
                                 DialogType::Goto => app.execute_goto(&input),
+                                DialogType::SelectFilter => app.execute_select_filter(&input),
+                                DialogType::ChangeExtension => app.execute_change_extension(&input),
+                                DialogType::SaveSmartFolder => app.execute_save_smart_folder(&input),
+                                DialogType::BasketArchive => app.execute_basket_archive(&input),
                                 _ => {}
                             }
                         }
@@ -2616,8 +2895,8 @@ fn handle_goto_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifier
                         return false;
                     }
 
-                    // Check if this is a remote path (user@host:/path) before local validation
-                    if crate::services::remote::parse_remote_path(&input).is_some() {
+                    // Check if this is a remote path (user@host:/path or sftp:// URL) before local validation
+                    if crate::services::remote::resolve_remote_location(&input, &app.settings.remote_profiles).is_some() {
                         app.dialog = None;
                         app.execute_goto(&input);
                         return false;
@@ -2870,7 +3149,7 @@ fn handle_goto_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifier
                                 if path.is_dir() {
                                     app.dialog = None;
                                     app.active_panel_mut().path = path;
-                                    app.active_panel_mut().load_files();
+                                    app.active_panel_mut().load_files_cached();
                                     app.show_message(&format!("Moved to: {}", entry));
                                     return false;
                                 } else {
@@ -2880,10 +3159,10 @@ fn handle_goto_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifier
                             }
                         }
                     } else if code == KeyCode::Enter {
-                        // No matching bookmarks — check if input is a remote path (user@host:/path)
+                        // No matching bookmarks — check if input is a remote path (user@host:/path or sftp:// URL)
                         let input = dialog.input.clone();
                         if !input.trim().is_empty() {
-                            if crate::services::remote::parse_remote_path(&input).is_some() {
+                            if crate::services::remote::resolve_remote_location(&input, &app.settings.remote_profiles).is_some() {
                                 app.dialog = None;
                                 app.execute_goto(&input);
                                 return false;
@@ -3096,14 +3375,18 @@ fn handle_goto_dialog_input(app: &mut App, code: KeyCode, modifiers: KeyModifier
     false
 }
 
-/// Handle progress dialog input (ESC to cancel)
+/// Handle progress dialog input (ESC to cancel, B to continue in the background)
 fn handle_progress_dialog_input(app: &mut App, code: KeyCode) -> bool {
-    if code == KeyCode::Esc {
-        // Cancel the operation
-        if let Some(ref mut progress) = app.file_operation_progress {
-            progress.cancel();
+    match code {
+        KeyCode::Esc => {
+            // Cancel the operation
+            if let Some(ref mut progress) = app.file_operation_progress {
+                progress.cancel();
+            }
+            // Dialog will be closed when the operation completes (or is cancelled)
         }
-        // Dialog will be closed when the operation completes (or is cancelled)
+        KeyCode::Char('b') | KeyCode::Char('B') => app.send_progress_to_background(),
+        _ => {}
     }
     false
 }
@@ -3185,50 +3468,51 @@ fn handle_duplicate_conflict_input(app: &mut App, code: KeyCode, _modifiers: Key
                 resolve_current_conflict(app, ConflictResolution::SkipAll);
                 return false;
             }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                resolve_current_conflict(app, ConflictResolution::OverwriteAllIfNewer);
+                return false;
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                resolve_current_conflict(app, ConflictResolution::OverwriteAllIfLarger);
+                return false;
+            }
 
-            // Navigation - 2x2 grid layout:
+            // Navigation - 2x3 grid layout:
             // 0 (Overwrite)     1 (Skip)
             // 2 (Overwrite All) 3 (Skip All)
+            // 4 (If Newer)      5 (If Larger)
             KeyCode::Left => {
-                // Move left in row: 1->0, 3->2
-                if dialog.selected_button == 1 {
-                    dialog.selected_button = 0;
-                } else if dialog.selected_button == 3 {
-                    dialog.selected_button = 2;
+                // Move left in row: 1->0, 3->2, 5->4
+                if dialog.selected_button % 2 == 1 {
+                    dialog.selected_button -= 1;
                 }
             }
             KeyCode::Right => {
-                // Move right in row: 0->1, 2->3
-                if dialog.selected_button == 0 {
-                    dialog.selected_button = 1;
-                } else if dialog.selected_button == 2 {
-                    dialog.selected_button = 3;
+                // Move right in row: 0->1, 2->3, 4->5
+                if dialog.selected_button % 2 == 0 {
+                    dialog.selected_button += 1;
                 }
             }
             KeyCode::Up => {
-                // Move up between rows: 2->0, 3->1
-                if dialog.selected_button == 2 {
-                    dialog.selected_button = 0;
-                } else if dialog.selected_button == 3 {
-                    dialog.selected_button = 1;
+                // Move up between rows
+                if dialog.selected_button >= 2 {
+                    dialog.selected_button -= 2;
                 }
             }
             KeyCode::Down => {
-                // Move down between rows: 0->2, 1->3
-                if dialog.selected_button == 0 {
-                    dialog.selected_button = 2;
-                } else if dialog.selected_button == 1 {
-                    dialog.selected_button = 3;
+                // Move down between rows
+                if dialog.selected_button < 4 {
+                    dialog.selected_button += 2;
                 }
             }
             KeyCode::Tab => {
-                // Cycle through buttons: 0->1->2->3->0
-                dialog.selected_button = (dialog.selected_button + 1) % 4;
+                // Cycle through buttons: 0->1->...->5->0
+                dialog.selected_button = (dialog.selected_button + 1) % 6;
             }
             KeyCode::BackTab => {
-                // Reverse cycle: 0->3->2->1->0
+                // Reverse cycle: 0->5->4->...->0
                 dialog.selected_button = if dialog.selected_button == 0 {
-                    3
+                    5
                 } else {
                     dialog.selected_button - 1
                 };
@@ -3240,6 +3524,8 @@ fn handle_duplicate_conflict_input(app: &mut App, code: KeyCode, _modifiers: Key
                     1 => ConflictResolution::Skip,
                     2 => ConflictResolution::OverwriteAll,
                     3 => ConflictResolution::SkipAll,
+                    4 => ConflictResolution::OverwriteAllIfNewer,
+                    5 => ConflictResolution::OverwriteAllIfLarger,
                     _ => ConflictResolution::Skip,
                 };
                 resolve_current_conflict(app, resolution);
@@ -3292,20 +3578,20 @@ fn resolve_current_conflict(app: &mut App, resolution: ConflictResolution) {
             }
             ConflictResolution::OverwriteAll => {
                 // Mark all remaining conflicts for overwrite
-                for i in state.current_index..state.conflicts.len() {
-                    if let Some((src, _, _)) = state.conflicts.get(i) {
-                        state.files_to_overwrite.push(src.clone());
-                    }
-                }
+                state.resolve_remaining(ConflictPolicy::Overwrite);
                 true // Finished
             }
             ConflictResolution::SkipAll => {
                 // Mark all remaining conflicts for skip
-                for i in state.current_index..state.conflicts.len() {
-                    if let Some((src, _, _)) = state.conflicts.get(i) {
-                        state.files_to_skip.push(src.clone());
-                    }
-                }
+                state.resolve_remaining(ConflictPolicy::Skip);
+                true // Finished
+            }
+            ConflictResolution::OverwriteAllIfNewer => {
+                state.resolve_remaining(ConflictPolicy::OverwriteIfNewer);
+                true // Finished
+            }
+            ConflictResolution::OverwriteAllIfLarger => {
+                state.resolve_remaining(ConflictPolicy::OverwriteIfLarger);
                 true // Finished
             }
         }
@@ -3328,8 +3614,33 @@ fn finish_conflict_resolution(app: &mut App) {
     app.execute_paste_with_conflicts();
 }
 
+/// Extension action rows shown at once in the settings dialog
+const SETTINGS_MAX_EXT_ROWS: usize = 8;
+
 /// Handle settings dialog input
 fn handle_settings_dialog_input(app: &mut App, code: KeyCode) -> bool {
+    // 확장자 추가 행: 입력 편집, Enter는 추가 (입력이 비어 있으면 저장)
+    if let Some(ref mut state) = app.settings_state {
+        if state.selected_field == state.add_ext_row() {
+            match code {
+                KeyCode::Char(c) if c.is_ascii_alphanumeric() || "|._-+".contains(c) => {
+                    state.new_ext.push(c);
+                    return false;
+                }
+                KeyCode::Backspace => {
+                    state.new_ext.pop();
+                    return false;
+                }
+                KeyCode::Enter if state.add_ext_action() => return false,
+                _ => {}
+            }
+        } else if let Some(idx) = state.selected_ext_action() {
+            if matches!(code, KeyCode::Delete | KeyCode::Backspace) {
+                state.ext_actions.remove(idx);
+                return false;
+            }
+        }
+    }
     match code {
         KeyCode::Esc => {
             app.cancel_settings_dialog();
@@ -3346,7 +3657,7 @@ fn handle_settings_dialog_input(app: &mut App, code: KeyCode) -> bool {
         }
         KeyCode::Down => {
             if let Some(ref mut state) = app.settings_state {
-                if state.selected_field < 1 {
+                if state.selected_field < state.add_ext_row() {
                     state.selected_field += 1;
                 }
             }
@@ -3362,7 +3673,18 @@ fn handle_settings_dialog_input(app: &mut App, code: KeyCode) -> bool {
                     1 => {
                         state.prev_diff_method();
                     }
-                    _ => {}
+                    2 => {
+                        state.conflict_policy = state.conflict_policy.cycle(false);
+                    }
+                    3 => {
+                        state.text_markers = !state.text_markers;
+                        crate::ui::theme::set_text_markers(state.text_markers);
+                    }
+                    _ => {
+                        if let Some(idx) = state.selected_ext_action() {
+                            state.ext_actions[idx].1 = state.ext_actions[idx].1.cycle(false);
+                        }
+                    }
                 }
             }
         }
@@ -3377,7 +3699,18 @@ fn handle_settings_dialog_input(app: &mut App, code: KeyCode) -> bool {
                     1 => {
                         state.next_diff_method();
                     }
-                    _ => {}
+                    2 => {
+                        state.conflict_policy = state.conflict_policy.cycle(true);
+                    }
+                    3 => {
+                        state.text_markers = !state.text_markers;
+                        crate::ui::theme::set_text_markers(state.text_markers);
+                    }
+                    _ => {
+                        if let Some(idx) = state.selected_ext_action() {
+                            state.ext_actions[idx].1 = state.ext_actions[idx].1.cycle(true);
+                        }
+                    }
                 }
             }
         }
@@ -3568,9 +3901,69 @@ fn draw_settings_dialog(frame: &mut Frame, state: &SettingsState, area: Rect, th
         ),
     ]));
 
+    // Default paste conflict policy (row 2)
+    let policy_value = format!("< {} >", state.conflict_policy.name());
+    let policy_prompt = if state.selected_field == 2 { "> " } else { "  " };
+    lines.push(Line::from(vec![
+        Span::styled(policy_prompt, Style::default().fg(theme.settings.prompt)),
+        Span::styled("Paste: ", Style::default().fg(theme.settings.label_text)),
+        Span::styled(
+            policy_value,
+            Style::default().fg(theme.settings.value_text).bg(theme.settings.value_bg),
+        ),
+    ]));
+
+    // Cursor/mark/active panel text markers (row 3)
+    let markers_value = format!("< {} >", if state.text_markers { "text + color" } else { "color" });
+    let markers_prompt = if state.selected_field == 3 { "> " } else { "  " };
+    lines.push(Line::from(vec![
+        Span::styled(markers_prompt, Style::default().fg(theme.settings.prompt)),
+        Span::styled("Marks: ", Style::default().fg(theme.settings.label_text)),
+        Span::styled(
+            markers_value,
+            Style::default().fg(theme.settings.value_text).bg(theme.settings.value_bg),
+        ),
+    ]));
+
+    // Extension actions (rows 4..): scrolled to keep the selected row visible
+    let selected_ext = state.selected_ext_action()
+        .unwrap_or(if state.selected_field >= SettingsState::EXT_ROW_START { state.ext_actions.len() } else { 0 });
+    let first = (selected_ext + 1).saturating_sub(SETTINGS_MAX_EXT_ROWS)
+        .min(state.ext_actions.len().saturating_sub(SETTINGS_MAX_EXT_ROWS));
+    for (i, (ext, action)) in state.ext_actions.iter().enumerate().skip(first).take(SETTINGS_MAX_EXT_ROWS) {
+        let row = SettingsState::EXT_ROW_START + i;
+        let prompt = if state.selected_field == row { "> " } else { "  " };
+        lines.push(Line::from(vec![
+            Span::styled(prompt, Style::default().fg(theme.settings.prompt)),
+            Span::styled(
+                format!("{} ", truncate_with_ellipsis(&format!(".{}:", ext), 18)),
+                Style::default().fg(theme.settings.label_text),
+            ),
+            Span::styled(
+                format!("< {} >", action.name()),
+                Style::default().fg(theme.settings.value_text).bg(theme.settings.value_bg),
+            ),
+        ]));
+    }
+
+    // Add extension row
+    let add_selected = state.selected_field == state.add_ext_row();
+    let add_prompt = if add_selected { "> " } else { "  " };
+    let add_value = if add_selected {
+        format!("{}_", state.new_ext)
+    } else {
+        "(type extension, Enter)".to_string()
+    };
+    lines.push(Line::from(vec![
+        Span::styled(add_prompt, Style::default().fg(theme.settings.prompt)),
+        Span::styled("Add:   ", Style::default().fg(theme.settings.label_text)),
+        Span::styled(add_value, Style::default().fg(theme.settings.value_text)),
+    ]));
+
     lines.push(Line::from(""));
 
     // Help line
+    let help_text = if state.selected_ext_action().is_some() { " Remove" } else { " Cancel" };
     lines.push(Line::from(vec![
         Span::styled("↑↓", Style::default().fg(theme.settings.help_key)),
         Span::styled(" Row  ", Style::default().fg(theme.settings.help_text)),
@@ -3578,8 +3971,8 @@ fn draw_settings_dialog(frame: &mut Frame, state: &SettingsState, area: Rect, th
         Span::styled(" Change  ", Style::default().fg(theme.settings.help_text)),
         Span::styled("Enter", Style::default().fg(theme.settings.help_key)),
         Span::styled(" Save  ", Style::default().fg(theme.settings.help_text)),
-        Span::styled("Esc", Style::default().fg(theme.settings.help_key)),
-        Span::styled(" Cancel", Style::default().fg(theme.settings.help_text)),
+        Span::styled(if state.selected_ext_action().is_some() { "Del" } else { "Esc" }, Style::default().fg(theme.settings.help_key)),
+        Span::styled(help_text, Style::default().fg(theme.settings.help_text)),
     ]));
 
     let paragraph = Paragraph::new(lines);
@@ -3989,7 +4382,9 @@ fn draw_remote_connect_dialog(frame: &mut Frame, app: &App, area: Rect, theme: &
         let ls = if is_selected { selected_style } else { label_style };
         let vs = if is_selected { selected_style } else { value_style };
         let mut spans = vec![Span::styled(format!("{:>width$}: ", "Password", width = label_width), ls)];
-        spans.extend(build_value_spans(&state.password, is_selected, state.cursor_pos, vs, true));
+        // Password manager references (pass:, op://, keychain:) are shown as typed
+        let mask = !crate::services::secrets::is_reference(&state.password);
+        spans.extend(build_value_spans(&state.password, is_selected, state.cursor_pos, vs, mask));
         frame.render_widget(
             Paragraph::new(Line::from(spans)),
             Rect::new(inner.x + 1, y, inner.width - 2, 1),
@@ -4629,3 +5024,1058 @@ pub fn draw_remote_spinner(frame: &mut Frame, message: &str, area: Rect, theme:
     ]);
     frame.render_widget(Paragraph::new(line), inner);
 }
+
+/// Open With dialog: recent commands and installed applications for a file
+fn draw_open_with_dialog(
+    frame: &mut Frame,
+    dialog: &Dialog,
+    state: &OpenWithState,
+    area: Rect,
+    theme: &Theme,
+) {
+    use crate::services::open_with::OpenWithKind;
+
+    let file_name = state.file_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let block = Block::default()
+        .title(format!(" Open With: {} ", file_name))
+        .title_style(Style::default().fg(theme.dialog.open_with_title).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dialog.open_with_border))
+        .style(Style::default().bg(theme.dialog.open_with_bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height < 4 || inner.width < 20 {
+        return;
+    }
+
+    let max_entry_width = (inner.width - 2) as usize;
+
+    // Filter / command input
+    let input_line = format!("> {}_", dialog.input);
+    frame.render_widget(
+        Paragraph::new(safe_suffix(&input_line, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.open_with_input_text)),
+        Rect::new(inner.x + 1, inner.y, inner.width - 2, 1),
+    );
+
+    // Entry list area
+    let list_height = (inner.height - 2) as usize; // input + command preview
+    for (i, &entry_idx) in state.filtered.iter().skip(state.scroll_offset).take(list_height).enumerate() {
+        let Some(entry) = state.entries.get(entry_idx) else { continue };
+        let y = inner.y + 1 + i as u16;
+        let is_cursor = state.scroll_offset + i == state.selected_index;
+
+        let marker = if entry.kind == OpenWithKind::Recent { "* " } else { "  " };
+        let line_text = format!("{}{}", marker, entry.label);
+        let display_text = if line_text.len() > max_entry_width {
+            safe_prefix(&line_text, max_entry_width).to_string()
+        } else {
+            line_text
+        };
+
+        let style = if is_cursor {
+            Style::default()
+                .fg(theme.dialog.open_with_cursor_text)
+                .bg(theme.dialog.open_with_cursor_bg)
+        } else if entry.kind == OpenWithKind::Recent {
+            Style::default().fg(theme.dialog.open_with_recent_text)
+        } else if entry.preferred {
+            Style::default().fg(theme.dialog.open_with_preferred_text)
+        } else {
+            Style::default().fg(theme.dialog.open_with_entry_text)
+        };
+
+        // Pad to full width for cursor background
+        let padded = if is_cursor {
+            format!("{:<width$}", display_text, width = max_entry_width)
+        } else {
+            display_text
+        };
+
+        frame.render_widget(
+            Paragraph::new(padded).style(style),
+            Rect::new(inner.x + 1, y, inner.width - 2, 1),
+        );
+    }
+
+    // Scroll info
+    if state.filtered.len() > list_height {
+        let scroll_info = format!(
+            "[{}-{}/{}]",
+            state.scroll_offset + 1,
+            (state.scroll_offset + list_height).min(state.filtered.len()),
+            state.filtered.len()
+        );
+        let info_len = scroll_info.len() as u16;
+        let info_x = inner.x + inner.width - info_len - 1;
+        frame.render_widget(
+            Paragraph::new(scroll_info).style(Style::default().fg(theme.dialog.open_with_scroll_info)),
+            Rect::new(info_x, inner.y, info_len, 1),
+        );
+    }
+
+    // Command preview of the selected entry (or the typed command when nothing matches)
+    let preview = match state.selected_entry() {
+        Some(entry) => entry.command.clone(),
+        None if !dialog.input.trim().is_empty() => format!("Enter: run '{}'", dialog.input.trim()),
+        None => "No applications found".to_string(),
+    };
+    let preview = if preview.len() > max_entry_width {
+        safe_prefix(&preview, max_entry_width).to_string()
+    } else {
+        preview
+    };
+    frame.render_widget(
+        Paragraph::new(preview).style(Style::default().fg(theme.dialog.open_with_command_text)),
+        Rect::new(inner.x + 1, inner.y + inner.height - 1, inner.width - 2, 1),
+    );
+}
+
+/// Handle input for Open With dialog
+fn handle_open_with_input(app: &mut App, code: KeyCode) -> bool {
+    match code {
+        KeyCode::Up => {
+            if let Some(ref mut state) = app.open_with_state {
+                if state.selected_index > 0 {
+                    state.selected_index -= 1;
+                    if state.selected_index < state.scroll_offset {
+                        state.scroll_offset = state.selected_index;
+                    }
+                }
+            }
+        }
+        KeyCode::Down => {
+            if let Some(ref mut state) = app.open_with_state {
+                if state.selected_index + 1 < state.filtered.len() {
+                    state.selected_index += 1;
+                    let vh = state.visible_height.max(1);
+                    if state.selected_index >= state.scroll_offset + vh {
+                        state.scroll_offset = state.selected_index - vh + 1;
+                    }
+                }
+            }
+        }
+        KeyCode::PageUp => {
+            if let Some(ref mut state) = app.open_with_state {
+                let page = state.visible_height.max(1);
+                state.selected_index = state.selected_index.saturating_sub(page);
+                if state.selected_index < state.scroll_offset {
+                    state.scroll_offset = state.selected_index;
+                }
+            }
+        }
+        KeyCode::PageDown => {
+            if let Some(ref mut state) = app.open_with_state {
+                let page = state.visible_height.max(1);
+                state.selected_index = (state.selected_index + page).min(state.filtered.len().saturating_sub(1));
+                let vh = state.visible_height.max(1);
+                if state.selected_index >= state.scroll_offset + vh {
+                    state.scroll_offset = state.selected_index - vh + 1;
+                }
+            }
+        }
+        KeyCode::Home => {
+            if let Some(ref mut state) = app.open_with_state {
+                state.selected_index = 0;
+                state.scroll_offset = 0;
+            }
+        }
+        KeyCode::End => {
+            if let Some(ref mut state) = app.open_with_state {
+                state.selected_index = state.filtered.len().saturating_sub(1);
+                let vh = state.visible_height.max(1);
+                if state.selected_index >= vh {
+                    state.scroll_offset = state.selected_index - vh + 1;
+                }
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut dialog) = app.dialog {
+                dialog.input.push(c);
+                dialog.cursor_pos = dialog.input.chars().count();
+                if let Some(ref mut state) = app.open_with_state {
+                    state.apply_filter(&dialog.input);
+                }
+            }
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut dialog) = app.dialog {
+                dialog.input.pop();
+                dialog.cursor_pos = dialog.input.chars().count();
+                if let Some(ref mut state) = app.open_with_state {
+                    state.apply_filter(&dialog.input);
+                }
+            }
+        }
+        KeyCode::Enter => {
+            let has_entry = app.open_with_state.as_ref()
+                .map(|s| s.selected_entry().is_some())
+                .unwrap_or(false);
+            let typed = app.dialog.as_ref()
+                .map(|d| d.input.trim().to_string())
+                .unwrap_or_default();
+            if has_entry {
+                app.execute_open_with(None);
+            } else if !typed.is_empty() {
+                // Nothing matches the filter: run the input as a command
+                app.execute_open_with(Some(typed));
+            }
+            return false;
+        }
+        KeyCode::Esc => {
+            app.open_with_state = None;
+            app.dialog = None;
+            return false;
+        }
+        _ => {}
+    }
+    false
+}
+
+/// Recent files dialog: recently viewed/edited files with their last position
+fn draw_recent_files_dialog(
+    frame: &mut Frame,
+    dialog: &Dialog,
+    state: &RecentFilesState,
+    area: Rect,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title(" Recent Files ")
+        .title_style(Style::default().fg(theme.dialog.recent_files_title).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dialog.recent_files_border))
+        .style(Style::default().bg(theme.dialog.recent_files_bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height < 4 || inner.width < 20 {
+        return;
+    }
+
+    let max_entry_width = (inner.width - 2) as usize;
+    let home = dirs::home_dir().map(|h| h.display().to_string());
+
+    // Filter input
+    let input_line = format!("> {}_", dialog.input);
+    frame.render_widget(
+        Paragraph::new(safe_suffix(&input_line, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.recent_files_input_text)),
+        Rect::new(inner.x + 1, inner.y, inner.width - 2, 1),
+    );
+
+    // Entry list area
+    let list_height = (inner.height - 2) as usize; // input + hint
+    for (i, &entry_idx) in state.filtered.iter().skip(state.scroll_offset).take(list_height).enumerate() {
+        let Some(entry) = state.entries.get(entry_idx) else { continue };
+        let y = inner.y + 1 + i as u16;
+        let is_cursor = state.scroll_offset + i == state.selected_index;
+
+        let display_path = match home.as_deref().and_then(|h| entry.path.strip_prefix(h)) {
+            Some(rest) if rest.starts_with('/') => format!("~{}", rest),
+            _ => entry.path.clone(),
+        };
+        let position = if entry.edited {
+            format!("E {}:{}", entry.line + 1, entry.col + 1)
+        } else {
+            format!("V {}", entry.scroll + 1)
+        };
+        // Path on the left (keeping its end visible), position right-aligned
+        let path_width = max_entry_width.saturating_sub(position.len() + 1);
+        let path_text = safe_suffix(&display_path, path_width);
+        let line_text = format!("{:<pw$} {}", path_text, position, pw = path_width);
+
+        let style = if is_cursor {
+            Style::default()
+                .fg(theme.dialog.recent_files_cursor_text)
+                .bg(theme.dialog.recent_files_cursor_bg)
+        } else if entry.edited {
+            Style::default().fg(theme.dialog.recent_files_edited_text)
+        } else {
+            Style::default().fg(theme.dialog.recent_files_entry_text)
+        };
+
+        frame.render_widget(
+            Paragraph::new(line_text).style(style),
+            Rect::new(inner.x + 1, y, inner.width - 2, 1),
+        );
+    }
+
+    // Scroll info
+    if state.filtered.len() > list_height {
+        let scroll_info = format!(
+            "[{}-{}/{}]",
+            state.scroll_offset + 1,
+            (state.scroll_offset + list_height).min(state.filtered.len()),
+            state.filtered.len()
+        );
+        let info_len = scroll_info.len() as u16;
+        let info_x = inner.x + inner.width - info_len - 1;
+        frame.render_widget(
+            Paragraph::new(scroll_info).style(Style::default().fg(theme.dialog.recent_files_scroll_info)),
+            Rect::new(info_x, inner.y, info_len, 1),
+        );
+    }
+
+    let hint = if state.filtered.is_empty() {
+        "No matching files"
+    } else {
+        "Enter: open at last position, Del: remove from list, Esc: close"
+    };
+    frame.render_widget(
+        Paragraph::new(safe_prefix(hint, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.recent_files_hint_text)),
+        Rect::new(inner.x + 1, inner.y + inner.height - 1, inner.width - 2, 1),
+    );
+}
+
+/// Handle input for the recent files dialog
+fn handle_recent_files_input(app: &mut App, code: KeyCode) -> bool {
+    let Some(ref mut state) = app.recent_files_state else {
+        app.dialog = None;
+        return false;
+    };
+    let vh = state.visible_height.max(1);
+    match code {
+        KeyCode::Up => {
+            state.selected_index = state.selected_index.saturating_sub(1);
+        }
+        KeyCode::Down => {
+            if state.selected_index + 1 < state.filtered.len() {
+                state.selected_index += 1;
+            }
+        }
+        KeyCode::PageUp => {
+            state.selected_index = state.selected_index.saturating_sub(vh);
+        }
+        KeyCode::PageDown => {
+            state.selected_index = (state.selected_index + vh).min(state.filtered.len().saturating_sub(1));
+        }
+        KeyCode::Home => {
+            state.selected_index = 0;
+        }
+        KeyCode::End => {
+            state.selected_index = state.filtered.len().saturating_sub(1);
+        }
+        KeyCode::Char(_) | KeyCode::Backspace => {
+            if let Some(ref mut dialog) = app.dialog {
+                match code {
+                    KeyCode::Char(c) => dialog.input.push(c),
+                    _ => { dialog.input.pop(); }
+                }
+                dialog.cursor_pos = dialog.input.chars().count();
+                state.apply_filter(&dialog.input);
+            }
+        }
+        KeyCode::Delete => {
+            if let Some(&entry_idx) = state.filtered.get(state.selected_index) {
+                let removed = state.entries.remove(entry_idx);
+                app.settings.recent_files.retain(|f| f.path != removed.path);
+                let filter = app.dialog.as_ref().map(|d| d.input.clone()).unwrap_or_default();
+                let selected = state.selected_index;
+                state.apply_filter(&filter);
+                state.selected_index = selected.min(state.filtered.len().saturating_sub(1));
+            }
+        }
+        KeyCode::Enter => {
+            app.open_recent_file();
+            return false;
+        }
+        KeyCode::Esc => {
+            app.recent_files_state = None;
+            app.dialog = None;
+            return false;
+        }
+        _ => {}
+    }
+    // Keep the cursor inside the visible window
+    if state.selected_index < state.scroll_offset {
+        state.scroll_offset = state.selected_index;
+    } else if state.selected_index >= state.scroll_offset + vh {
+        state.scroll_offset = state.selected_index + 1 - vh;
+    }
+    false
+}
+
+/// Path menu: the panel path as a breadcrumb on top (selected level highlighted)
+/// and the folders next to the selected level below
+fn draw_breadcrumb_dialog(
+    frame: &mut Frame,
+    state: &BreadcrumbState,
+    area: Rect,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title(" Go to Path ")
+        .title_style(Style::default().fg(theme.dialog.breadcrumb_title).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dialog.breadcrumb_border))
+        .style(Style::default().bg(theme.dialog.breadcrumb_bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height < 4 || inner.width < 20 {
+        return;
+    }
+    let max_entry_width = (inner.width - 2) as usize;
+
+    // Breadcrumb: leading components are dropped while the selected one does not fit
+    let names: Vec<String> = (0..state.components.len()).map(|i| state.component_name(i)).collect();
+    let crumb_width = |from: usize| -> usize {
+        names[from..].iter().map(|n| n.width() + 3).sum::<usize>() + if from > 0 { 2 } else { 0 }
+    };
+    let mut start = 0;
+    while start < state.level && crumb_width(start) > max_entry_width {
+        start += 1;
+    }
+    let separator = Style::default().fg(theme.dialog.breadcrumb_separator);
+    let mut spans = Vec::new();
+    if start > 0 {
+        spans.push(Span::styled("\u{2026} ", separator));
+    }
+    for (i, name) in names.iter().enumerate().skip(start) {
+        if i > start && !names[i - 1].ends_with('/') {
+            spans.push(Span::styled(" / ", separator));
+        } else if i > start {
+            spans.push(Span::styled(" ", separator));
+        }
+        let style = if i == state.level {
+            Style::default()
+                .fg(theme.dialog.breadcrumb_cursor_text)
+                .bg(theme.dialog.breadcrumb_cursor_bg)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.dialog.breadcrumb_path_text)
+        };
+        spans.push(Span::styled(name.clone(), style));
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)),
+        Rect::new(inner.x + 1, inner.y, inner.width - 2, 1),
+    );
+
+    // Folders at the selected level (the breadcrumb's own folder highlighted)
+    let current = &names[state.level];
+    let list_height = (inner.height - 2) as usize; // breadcrumb + hint
+    for (i, name) in state.siblings.iter().enumerate().skip(state.scroll_offset).take(list_height) {
+        let y = inner.y + 1 + (i - state.scroll_offset) as u16;
+        let style = if i == state.selected_index {
+            Style::default()
+                .fg(theme.dialog.breadcrumb_cursor_text)
+                .bg(theme.dialog.breadcrumb_cursor_bg)
+        } else if name == current {
+            Style::default().fg(theme.dialog.breadcrumb_current_text).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.dialog.breadcrumb_entry_text)
+        };
+        let text = format!("{}{}", theme.chars.folder, name);
+        frame.render_widget(
+            Paragraph::new(safe_prefix(&text, max_entry_width).to_string()).style(style),
+            Rect::new(inner.x + 1, y, inner.width - 2, 1),
+        );
+    }
+
+    if state.siblings.len() > list_height {
+        let scroll_info = format!(
+            "[{}-{}/{}]",
+            state.scroll_offset + 1,
+            (state.scroll_offset + list_height).min(state.siblings.len()),
+            state.siblings.len()
+        );
+        let info_len = scroll_info.len() as u16;
+        let info_x = inner.x + inner.width - info_len - 1;
+        frame.render_widget(
+            Paragraph::new(scroll_info).style(Style::default().fg(theme.dialog.breadcrumb_scroll_info)),
+            Rect::new(info_x, inner.y + inner.height - 1, info_len, 1),
+        );
+    }
+
+    let hint = "\u{2190}\u{2192} level  \u{2191}\u{2193} folder  Enter go  Esc close";
+    frame.render_widget(
+        Paragraph::new(safe_prefix(hint, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.breadcrumb_hint_text)),
+        Rect::new(inner.x + 1, inner.y + inner.height - 1, inner.width - 2, 1),
+    );
+}
+
+/// Handle input for the path menu
+fn handle_breadcrumb_input(app: &mut App, code: KeyCode) -> bool {
+    let Some(ref mut state) = app.breadcrumb_state else {
+        app.dialog = None;
+        return false;
+    };
+    let vh = state.visible_height.max(1);
+    let last = state.siblings.len().saturating_sub(1);
+    match code {
+        KeyCode::Left if state.level > 0 => state.set_level(state.level - 1),
+        KeyCode::Right if state.level + 1 < state.components.len() => state.set_level(state.level + 1),
+        KeyCode::Up => state.selected_index = state.selected_index.saturating_sub(1),
+        KeyCode::Down => state.selected_index = (state.selected_index + 1).min(last),
+        KeyCode::PageUp => state.selected_index = state.selected_index.saturating_sub(vh),
+        KeyCode::PageDown => state.selected_index = (state.selected_index + vh).min(last),
+        KeyCode::Home => state.selected_index = 0,
+        KeyCode::End => state.selected_index = last,
+        KeyCode::Char(c) => {
+            // 입력한 글자로 시작하는 다음 폴더로 이동
+            let c = c.to_lowercase().to_string();
+            let count = state.siblings.len();
+            if let Some(next) = (1..=count)
+                .map(|step| (state.selected_index + step) % count)
+                .find(|&i| state.siblings[i].to_lowercase().starts_with(&c))
+            {
+                state.selected_index = next;
+            }
+        }
+        KeyCode::Enter => {
+            app.breadcrumb_go();
+            return false;
+        }
+        KeyCode::Esc => {
+            app.breadcrumb_state = None;
+            app.dialog = None;
+            return false;
+        }
+        _ => {}
+    }
+    if state.selected_index < state.scroll_offset {
+        state.scroll_offset = state.selected_index;
+    } else if state.selected_index >= state.scroll_offset + vh {
+        state.scroll_offset = state.selected_index + 1 - vh;
+    }
+    false
+}
+
+/// Jump dialog: visited directories ranked by frecency, filtered by typing
+fn draw_jump_dialog(
+    frame: &mut Frame,
+    dialog: &Dialog,
+    state: &JumpState,
+    area: Rect,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title(" Jump to Directory ")
+        .title_style(Style::default().fg(theme.dialog.jump_title).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dialog.jump_border))
+        .style(Style::default().bg(theme.dialog.jump_bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height < 4 || inner.width < 20 {
+        return;
+    }
+
+    let max_entry_width = (inner.width - 2) as usize;
+    let home = dirs::home_dir().map(|h| h.display().to_string());
+
+    // Filter input
+    let input_line = format!("> {}_", dialog.input);
+    frame.render_widget(
+        Paragraph::new(safe_suffix(&input_line, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.jump_input_text)),
+        Rect::new(inner.x + 1, inner.y, inner.width - 2, 1),
+    );
+
+    // Directory list area
+    let list_height = (inner.height - 2) as usize; // input + hint
+    let tilde = |path: &str| match home.as_deref().and_then(|h| path.strip_prefix(h)) {
+        Some(rest) if rest.starts_with('/') || rest.is_empty() => format!("~{}", rest),
+        _ => path.to_string(),
+    };
+    let rows = (state.scroll_offset..state.len()).take(list_height);
+    for (i, entry) in rows.filter_map(|index| state.entry(index)).enumerate() {
+        let y = inner.y + 1 + i as u16;
+        let is_cursor = state.scroll_offset + i == state.selected_index;
+
+        // Path on the left (keeping its end visible), score (or "smart") right-aligned
+        let (display_path, score_text) = match entry {
+            JumpEntry::SmartFolder(folder) => (format!("{} - {}", folder.name, tilde(&folder.path)), "smart".to_string()),
+            JumpEntry::Directory(path, score) => (tilde(path), format!("{:.0}", score)),
+        };
+        let path_width = max_entry_width.saturating_sub(score_text.len() + 1);
+        let path_text = safe_suffix(&display_path, path_width);
+        let line_text = format!("{:<pw$} {}", path_text, score_text, pw = path_width);
+
+        let style = if is_cursor {
+            Style::default()
+                .fg(theme.dialog.jump_cursor_text)
+                .bg(theme.dialog.jump_cursor_bg)
+        } else {
+            Style::default().fg(theme.dialog.jump_entry_text)
+        };
+
+        frame.render_widget(
+            Paragraph::new(line_text).style(style),
+            Rect::new(inner.x + 1, y, inner.width - 2, 1),
+        );
+    }
+
+    // Scroll info
+    if state.len() > list_height {
+        let scroll_info = format!(
+            "[{}-{}/{}]",
+            state.scroll_offset + 1,
+            (state.scroll_offset + list_height).min(state.len()),
+            state.len()
+        );
+        let info_len = scroll_info.len() as u16;
+        let info_x = inner.x + inner.width - info_len - 1;
+        frame.render_widget(
+            Paragraph::new(scroll_info).style(Style::default().fg(theme.dialog.jump_scroll_info)),
+            Rect::new(info_x, inner.y, info_len, 1),
+        );
+    }
+
+    let hint = if state.is_empty() {
+        "No matching directories"
+    } else if state.selected_smart_folder().is_some() {
+        "Enter: open smart folder, Del: delete it, Esc: close"
+    } else {
+        "Enter: go, Del: forget directory, Esc: close"
+    };
+    frame.render_widget(
+        Paragraph::new(safe_prefix(hint, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.jump_hint_text)),
+        Rect::new(inner.x + 1, inner.y + inner.height - 1, inner.width - 2, 1),
+    );
+}
+
+/// Handle input for the jump dialog
+fn handle_jump_input(app: &mut App, code: KeyCode) -> bool {
+    let Some(ref mut state) = app.jump_state else {
+        app.dialog = None;
+        return false;
+    };
+    let vh = state.visible_height.max(1);
+    match code {
+        KeyCode::Up => {
+            state.selected_index = state.selected_index.saturating_sub(1);
+        }
+        KeyCode::Down => {
+            if state.selected_index + 1 < state.len() {
+                state.selected_index += 1;
+            }
+        }
+        KeyCode::PageUp => {
+            state.selected_index = state.selected_index.saturating_sub(vh);
+        }
+        KeyCode::PageDown => {
+            state.selected_index = (state.selected_index + vh).min(state.len().saturating_sub(1));
+        }
+        KeyCode::Home => {
+            state.selected_index = 0;
+        }
+        KeyCode::End => {
+            state.selected_index = state.len().saturating_sub(1);
+        }
+        KeyCode::Char(_) | KeyCode::Backspace => {
+            if let Some(ref mut dialog) = app.dialog {
+                match code {
+                    KeyCode::Char(c) => dialog.input.push(c),
+                    _ => { dialog.input.pop(); }
+                }
+                dialog.cursor_pos = dialog.input.chars().count();
+                state.apply_filter(&dialog.input);
+            }
+        }
+        KeyCode::Delete => {
+            if let Some(name) = state.selected_smart_folder().map(|f| f.name.clone()) {
+                state.smart_folders.retain(|f| f.name != name);
+                app.settings.smart_folders.retain(|f| f.name != name);
+                let _ = app.settings.save();
+            } else if let Some(path) = state.selected_path().map(str::to_string) {
+                state.history.remove(&path);
+                let _ = state.history.save();
+            } else {
+                return false;
+            }
+            let filter = app.dialog.as_ref().map(|d| d.input.clone()).unwrap_or_default();
+            let selected = state.selected_index;
+            state.apply_filter(&filter);
+            state.selected_index = selected.min(state.len().saturating_sub(1));
+        }
+        KeyCode::Enter => {
+            app.jump_to_selected();
+            return false;
+        }
+        KeyCode::Esc => {
+            app.jump_state = None;
+            app.dialog = None;
+            return false;
+        }
+        _ => {}
+    }
+    // Keep the cursor inside the visible window
+    if state.selected_index < state.scroll_offset {
+        state.scroll_offset = state.selected_index;
+    } else if state.selected_index >= state.scroll_offset + vh {
+        state.scroll_offset = state.selected_index + 1 - vh;
+    }
+    false
+}
+
+/// Favorites dialog: numbered slots 1-9, the active panel's directory highlighted
+fn draw_favorites_dialog(frame: &mut Frame, app: &App, state: &FavoritesState, area: Rect, theme: &Theme) {
+    let block = Block::default()
+        .title(" Favorites ")
+        .title_style(Style::default().fg(theme.dialog.favorites_title).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dialog.favorites_border))
+        .style(Style::default().bg(theme.dialog.favorites_bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height < 3 || inner.width < 20 {
+        return;
+    }
+
+    let max_entry_width = (inner.width - 2) as usize;
+    let home = dirs::home_dir().map(|h| h.display().to_string());
+    let paths = &app.settings.favorites.paths;
+    let current = app.current_location();
+
+    let list_height = (inner.height - 2) as usize; // gap + hint
+    for slot in 0..crate::config::FAVORITES_MAX.min(list_height) {
+        let y = inner.y + slot as u16;
+        let (text, style) = match paths.get(slot) {
+            Some(path) => {
+                let display_path = match home.as_deref().and_then(|h| path.strip_prefix(h)) {
+                    Some(rest) if rest.starts_with('/') || rest.is_empty() => format!("~{}", rest),
+                    _ => path.clone(),
+                };
+                let text = format!("{}  {}", slot + 1, safe_suffix(&display_path, max_entry_width.saturating_sub(3)));
+                let style = if slot == state.selected_index {
+                    Style::default()
+                        .fg(theme.dialog.favorites_cursor_text)
+                        .bg(theme.dialog.favorites_cursor_bg)
+                } else {
+                    Style::default().fg(theme.dialog.favorites_entry_text)
+                };
+                // 현재 패널 위치는 굵게
+                let style = if current.as_ref() == Some(path) { style.add_modifier(Modifier::BOLD) } else { style };
+                (text, style)
+            }
+            None => (format!("{}  -", slot + 1), Style::default().fg(theme.dialog.favorites_empty_slot_text)),
+        };
+        frame.render_widget(
+            Paragraph::new(text).style(style),
+            Rect::new(inner.x + 1, y, inner.width - 2, 1),
+        );
+    }
+
+    let hint = if paths.is_empty() {
+        "a: add current directory, Esc: close"
+    } else {
+        "Enter/1-9: go, a: add current, Del: remove, Shift+Up/Down: move, Esc: close"
+    };
+    frame.render_widget(
+        Paragraph::new(safe_prefix(hint, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.favorites_hint_text)),
+        Rect::new(inner.x + 1, inner.y + inner.height - 1, inner.width - 2, 1),
+    );
+}
+
+/// Handle input for the favorites dialog. Changes are saved right away
+fn handle_favorites_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool {
+    let Some(selected) = app.favorites_state.as_ref().map(|s| s.selected_index) else {
+        app.dialog = None;
+        return false;
+    };
+    let count = app.settings.favorites.paths.len();
+    let shift = modifiers.contains(KeyModifiers::SHIFT);
+    let new_selected = match code {
+        KeyCode::Up if shift => {
+            let moved = app.settings.favorites.move_entry(selected, true);
+            let _ = app.settings.save();
+            moved
+        }
+        KeyCode::Down if shift => {
+            let moved = app.settings.favorites.move_entry(selected, false);
+            let _ = app.settings.save();
+            moved
+        }
+        KeyCode::Up => selected.saturating_sub(1),
+        KeyCode::Down => (selected + 1).min(count.saturating_sub(1)),
+        KeyCode::Home => 0,
+        KeyCode::End => count.saturating_sub(1),
+        KeyCode::Char('a') | KeyCode::Insert => {
+            app.add_current_to_favorites();
+            return false;
+        }
+        KeyCode::Char('d') | KeyCode::Delete => {
+            if selected < count {
+                let removed = app.settings.favorites.paths.remove(selected);
+                let _ = app.settings.save();
+                app.show_message(&format!("Favorite removed: {}", removed));
+            }
+            selected.min(count.saturating_sub(2))
+        }
+        KeyCode::Char(c @ '1'..='9') => {
+            app.favorites_state = None;
+            app.dialog = None;
+            app.goto_favorite(c as usize - '0' as usize);
+            return false;
+        }
+        KeyCode::Enter => {
+            app.favorites_state = None;
+            app.dialog = None;
+            if selected < count {
+                app.goto_favorite(selected + 1);
+            }
+            return false;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.favorites_state = None;
+            app.dialog = None;
+            return false;
+        }
+        _ => selected,
+    };
+    if let Some(ref mut state) = app.favorites_state {
+        state.selected_index = new_selected;
+    }
+    false
+}
+
+fn draw_basket_dialog(frame: &mut Frame, app: &App, state: &BasketState, area: Rect, theme: &Theme) {
+    let block = Block::default()
+        .title(format!(" Basket ({}) ", app.basket.len()))
+        .title_style(Style::default().fg(theme.dialog.basket_title).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dialog.basket_border))
+        .style(Style::default().bg(theme.dialog.basket_bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height < 3 || inner.width < 20 {
+        return;
+    }
+
+    let max_entry_width = (inner.width - 2) as usize;
+    let home = dirs::home_dir().map(|h| h.display().to_string());
+    let list_height = (inner.height - 2) as usize; // gap + hint
+    let scroll_offset = (state.selected_index + 1).saturating_sub(list_height);
+
+    for (row, path) in app.basket.iter().enumerate().skip(scroll_offset).take(list_height) {
+        let path = path.display().to_string();
+        let display_path = match home.as_deref().and_then(|h| path.strip_prefix(h)) {
+            Some(rest) if rest.starts_with('/') => format!("~{}", rest),
+            _ => path.clone(),
+        };
+        let style = if row == state.selected_index {
+            Style::default()
+                .fg(theme.dialog.basket_cursor_text)
+                .bg(theme.dialog.basket_cursor_bg)
+        } else {
+            Style::default().fg(theme.dialog.basket_entry_text)
+        };
+        frame.render_widget(
+            Paragraph::new(safe_suffix(&display_path, max_entry_width).to_string()).style(style),
+            Rect::new(inner.x + 1, inner.y + (row - scroll_offset) as u16, inner.width - 2, 1),
+        );
+    }
+
+    let hint = "c: copy here, m: move here, z: zip here, Del: remove, x: clear, Esc: close";
+    frame.render_widget(
+        Paragraph::new(safe_prefix(hint, max_entry_width).to_string())
+            .style(Style::default().fg(theme.dialog.basket_hint_text)),
+        Rect::new(inner.x + 1, inner.y + inner.height - 1, inner.width - 2, 1),
+    );
+}
+
+/// Handle input for the basket dialog. Copy/move/zip target the active panel
+fn handle_basket_input(app: &mut App, code: KeyCode) -> bool {
+    let Some(selected) = app.basket_state.as_ref().map(|s| s.selected_index) else {
+        app.dialog = None;
+        return false;
+    };
+    let count = app.basket.len();
+    let new_selected = match code {
+        KeyCode::Up => selected.saturating_sub(1),
+        KeyCode::Down => (selected + 1).min(count.saturating_sub(1)),
+        KeyCode::Home => 0,
+        KeyCode::End => count.saturating_sub(1),
+        KeyCode::Char('c') => {
+            app.basket_paste(ClipboardOperation::Copy);
+            return false;
+        }
+        KeyCode::Char('m') => {
+            app.basket_paste(ClipboardOperation::Cut);
+            return false;
+        }
+        KeyCode::Char('z') => {
+            app.show_basket_archive_dialog();
+            return false;
+        }
+        KeyCode::Char('d') | KeyCode::Delete => {
+            if selected < count {
+                app.basket.remove(selected);
+            }
+            if app.basket.is_empty() {
+                app.basket_state = None;
+                app.dialog = None;
+                return false;
+            }
+            selected.min(count.saturating_sub(2))
+        }
+        KeyCode::Char('x') => {
+            app.basket.clear();
+            app.basket_state = None;
+            app.dialog = None;
+            app.show_message("Basket cleared");
+            return false;
+        }
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.basket_state = None;
+            app.dialog = None;
+            return false;
+        }
+        _ => selected,
+    };
+    if let Some(ref mut state) = app.basket_state {
+        state.selected_index = new_selected;
+    }
+    false
+}
+
+/// Image "save as" dialog: format, JPEG quality and output size
+fn draw_image_export_dialog(
+    frame: &mut Frame,
+    state: &ImageExportState,
+    area: Rect,
+    theme: &Theme,
+) {
+    use crate::services::image_export;
+
+    let block = Block::default()
+        .title(" Save Image As ")
+        .title_style(Style::default().fg(theme.dialog.image_export_title).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.dialog.image_export_border))
+        .style(Style::default().bg(theme.dialog.image_export_bg));
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.height < 6 || inner.width < 30 {
+        return;
+    }
+
+    let max_width = (inner.width - 2) as usize;
+    let options = &state.options;
+
+    const SLIDER_WIDTH: usize = 20;
+    let filled = (options.quality as usize * SLIDER_WIDTH + 50) / 100;
+    let quality = if options.format.has_quality() {
+        format!("{}{} {}", "█".repeat(filled), "░".repeat(SLIDER_WIDTH - filled), options.quality)
+    } else {
+        "lossless".to_string()
+    };
+    let size = match state.output_size() {
+        Some((w, h)) => format!("{}%  ({}x{})", options.scale_percent, w, h),
+        None => format!("{}%", options.scale_percent),
+    };
+    let rows = [
+        ("Format ", format!("< {} >", options.format.name())),
+        ("Quality", quality),
+        ("Size   ", size),
+    ];
+
+    for (i, (label, value)) in rows.iter().enumerate() {
+        let is_cursor = i == state.selected_row;
+        let value_style = if is_cursor {
+            Style::default()
+                .fg(theme.dialog.image_export_cursor_text)
+                .bg(theme.dialog.image_export_cursor_bg)
+        } else if i == 1 && !options.format.has_quality() {
+            Style::default().fg(theme.dialog.image_export_disabled_text)
+        } else {
+            Style::default().fg(theme.dialog.image_export_value_text)
+        };
+        let line = Line::from(vec![
+            Span::styled(format!("{}  ", label), Style::default().fg(theme.dialog.image_export_label_text)),
+            Span::styled(format!(" {} ", value), value_style),
+        ]);
+        frame.render_widget(
+            Paragraph::new(line),
+            Rect::new(inner.x + 1, inner.y + i as u16, inner.width - 2, 1),
+        );
+    }
+
+    // Output file name preview
+    let output = image_export::output_path(&state.src, options);
+    let output_name = output.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let output_line = format!("-> {}", output_name);
+    frame.render_widget(
+        Paragraph::new(safe_suffix(&output_line, max_width).to_string())
+            .style(Style::default().fg(theme.dialog.image_export_output_text)),
+        Rect::new(inner.x + 1, inner.y + 4, inner.width - 2, 1),
+    );
+
+    let hint = "Up/Down: field, Left/Right: change, Enter: save, Esc: cancel";
+    frame.render_widget(
+        Paragraph::new(safe_prefix(hint, max_width).to_string())
+            .style(Style::default().fg(theme.dialog.image_export_hint_text)),
+        Rect::new(inner.x + 1, inner.y + inner.height - 1, inner.width - 2, 1),
+    );
+}
+
+/// Handle input for the image "save as" dialog
+fn handle_image_export_input(app: &mut App, code: KeyCode) -> bool {
+    use crate::services::image_export::{SCALE_MAX, SCALE_MIN};
+
+    let Some(ref mut state) = app.image_export_state else {
+        app.dialog = None;
+        return false;
+    };
+    match code {
+        KeyCode::Up | KeyCode::BackTab => {
+            state.selected_row = (state.selected_row + ImageExportState::ROWS - 1) % ImageExportState::ROWS;
+        }
+        KeyCode::Down | KeyCode::Tab => {
+            state.selected_row = (state.selected_row + 1) % ImageExportState::ROWS;
+        }
+        KeyCode::Left | KeyCode::Right | KeyCode::Char('-') | KeyCode::Char('+') => {
+            let forward = matches!(code, KeyCode::Right | KeyCode::Char('+'));
+            let options = &mut state.options;
+            match state.selected_row {
+                0 => options.format = options.format.cycle(forward),
+                1 if options.format.has_quality() => {
+                    options.quality = if forward {
+                        options.quality.saturating_add(5).min(100)
+                    } else {
+                        options.quality.saturating_sub(5).max(5)
+                    };
+                }
+                2 => {
+                    options.scale_percent = if forward {
+                        (options.scale_percent + 5).min(SCALE_MAX)
+                    } else {
+                        options.scale_percent.saturating_sub(5).max(SCALE_MIN)
+                    };
+                }
+                _ => {}
+            }
+        }
+        KeyCode::Enter => {
+            app.execute_image_export();
+        }
+        KeyCode::Esc => {
+            app.image_export_state = None;
+            app.dialog = None;
+        }
+        _ => {}
+    }
+    false
+}
@@ -1,5 +1,6 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64};
 
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
@@ -10,10 +11,15 @@ use ratatui::{
     Frame,
 };
 
+use image::DynamicImage;
+use ratatui_image::protocol::StatefulProtocol;
 use unicode_width::UnicodeWidthChar;
 
-use super::app::App;
+use super::app::{App, Screen};
+use super::image_viewer::is_image_file;
 use super::theme::Theme;
+use crate::services::checksum::{self, Algorithm};
+use crate::utils::format::{format_number, format_size};
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // Data structures
@@ -49,6 +55,96 @@ pub struct DiffFileViewState {
     pub file_name: String,
     pub max_scroll: usize,        // max visual row offset
     pub change_visual_offsets: Vec<usize>, // visual row offset for each change_positions entry
+    pub return_screen: Screen,    // shown again on close (diff screen, file panel, git log)
+    /// Size/hash (and image) comparison shown instead of lines for binary files
+    pub binary: Option<BinaryDiff>,
+}
+
+/// One side of a binary comparison (None in `BinaryDiff` when the file is missing)
+pub struct BinarySide {
+    pub size: u64,
+    /// SHA-256 of the content
+    pub hash: String,
+    /// Original image dimensions, for image files
+    pub dimensions: Option<(u32, u32)>,
+    pub image: Option<DynamicImage>,
+    /// Inline image protocol for `image`
+    pub protocol: Option<Box<dyn StatefulProtocol>>,
+}
+
+pub struct BinaryDiff {
+    pub left: Option<BinarySide>,
+    pub right: Option<BinarySide>,
+}
+
+impl BinarySide {
+    fn new(path: &Path, data: &[u8]) -> Self {
+        let hash = checksum::hash_reader(data, Algorithm::Sha256, &AtomicBool::new(false), &AtomicU64::new(0))
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        let loaded = if is_image_file(path) {
+            crate::services::thumbnails::load(path).ok()
+        } else {
+            None
+        };
+        Self {
+            size: data.len() as u64,
+            hash,
+            dimensions: loaded.as_ref().map(|l| l.original_size),
+            image: loaded.map(|l| l.image),
+            protocol: None,
+        }
+    }
+}
+
+impl BinaryDiff {
+    pub fn identical(&self) -> bool {
+        match (&self.left, &self.right) {
+            (Some(l), Some(r)) => l.size == r.size && l.hash == r.hash,
+            _ => false,
+        }
+    }
+
+    /// Size and dimension deltas (right relative to left) for the status bar
+    pub fn summary(&self) -> String {
+        let (l, r) = match (&self.left, &self.right) {
+            (Some(l), Some(r)) => (l, r),
+            (Some(_), None) => return " Binary: right file missing".to_string(),
+            (None, Some(_)) => return " Binary: left file missing".to_string(),
+            (None, None) => return " Binary: both files missing".to_string(),
+        };
+        if self.identical() {
+            return " Binary: identical".to_string();
+        }
+        let mut parts = vec![format!(" Binary: differs  Size: {}", size_delta(l.size, r.size))];
+        if let (Some((lw, lh)), Some((rw, rh))) = (l.dimensions, r.dimensions) {
+            if (lw, lh) == (rw, rh) {
+                parts.push(format!("Dimensions: {}x{} (same)", lw, lh));
+            } else {
+                parts.push(format!(
+                    "Dimensions: {}x{} → {}x{} ({:+}, {:+})",
+                    lw, lh, rw, rh, rw as i64 - lw as i64, rh as i64 - lh as i64
+                ));
+            }
+        }
+        parts.join("  ")
+    }
+}
+
+/// "+1.2 KB (+3.4%)" style difference of two sizes
+fn size_delta(left: u64, right: u64) -> String {
+    if left == right {
+        return "same".to_string();
+    }
+    let sign = if right > left { "+" } else { "-" };
+    let diff = format_size(right.abs_diff(left));
+    if left == 0 {
+        format!("{}{}", sign, diff)
+    } else {
+        let percent = (right as f64 - left as f64) / left as f64 * 100.0;
+        format!("{}{} ({:+.1}%)", sign, diff, percent)
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -268,23 +364,20 @@ impl DiffFileViewState {
         let left_data = fs::read(&left_path).ok();
         let right_data = fs::read(&right_path).ok();
 
-        // Check for binary files
+        // Check for binary files (images are always compared as pictures)
         let left_is_binary = left_data.as_ref().map_or(false, |d| is_binary(d));
         let right_is_binary = right_data.as_ref().map_or(false, |d| is_binary(d));
+        let is_image = is_image_file(&left_path) || is_image_file(&right_path);
 
-        if left_is_binary || right_is_binary {
-            // Binary file: show a single informational line
-            let diff_lines = vec![DiffLine {
-                left_line_no: None,
-                left_content: Some("Binary file".to_string()),
-                right_line_no: None,
-                right_content: Some("Binary file".to_string()),
-                line_status: DiffLineStatus::Same,
-            }];
+        if left_is_binary || right_is_binary || is_image {
+            let binary = BinaryDiff {
+                left: left_data.as_ref().map(|d| BinarySide::new(&left_path, d)),
+                right: right_data.as_ref().map(|d| BinarySide::new(&right_path, d)),
+            };
             return Self {
                 left_path,
                 right_path,
-                diff_lines,
+                diff_lines: Vec::new(),
                 scroll: 0,
                 visible_height: 0,
                 left_total_lines: 0,
@@ -294,6 +387,8 @@ impl DiffFileViewState {
                 file_name,
                 max_scroll: 0,
                 change_visual_offsets: Vec::new(),
+                return_screen: Screen::DiffScreen,
+                binary: Some(binary),
             };
         }
 
@@ -371,6 +466,18 @@ impl DiffFileViewState {
             file_name,
             max_scroll: 0,
             change_visual_offsets: Vec::new(),
+            return_screen: Screen::DiffScreen,
+            binary: None,
+        }
+    }
+
+    /// Create inline image protocols for image comparisons (Kitty/iTerm2/Sixel or halfblocks)
+    pub fn create_image_protocols(&mut self, picker: Option<&mut ratatui_image::picker::Picker>) {
+        let (Some(binary), Some(picker)) = (self.binary.as_mut(), picker) else { return };
+        for side in [binary.left.as_mut(), binary.right.as_mut()].into_iter().flatten() {
+            if let Some(ref image) = side.image {
+                side.protocol = Some(picker.new_resize_protocol(image.clone()));
+            }
         }
     }
 }
@@ -403,6 +510,13 @@ pub fn draw(frame: &mut Frame, state: &mut DiffFileViewState, area: Rect, theme:
     // Update visible height
     state.visible_height = content_area.height as usize;
 
+    if let Some(ref mut binary) = state.binary {
+        draw_binary(frame, binary, content_area, theme);
+        let status_text = binary.summary();
+        draw_footer(frame, status_text, status_area, function_area, true, theme, kb);
+        return;
+    }
+
     // ─── Header ─────────────────────────────────────────────────────────────
     let header_text = format!("[FILE DIFF] {}", state.file_name);
     let header_line = Line::from(Span::styled(
@@ -599,6 +713,96 @@ pub fn draw(frame: &mut Frame, state: &mut DiffFileViewState, area: Rect, theme:
         current_display,
         changes_count,
     );
+    draw_footer(frame, status_text, status_area, function_area, false, theme, kb);
+}
+
+/// 바이너리 비교: 좌우에 크기/해시/이미지 크기, 이미지면 그 아래에 그림
+fn draw_binary(frame: &mut Frame, binary: &mut BinaryDiff, area: Rect, theme: &Theme) {
+    let colors = &theme.diff_file_view;
+    let halves = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let identical = binary.identical();
+    let other_hash = |side: &Option<BinarySide>| side.as_ref().map(|s| s.hash.clone());
+    let hashes = [other_hash(&binary.right), other_hash(&binary.left)];
+
+    for (idx, side) in [&mut binary.left, &mut binary.right].into_iter().enumerate() {
+        let block = if idx == 0 {
+            Block::default()
+                .borders(Borders::RIGHT)
+                .border_style(Style::default().fg(colors.border))
+        } else {
+            Block::default()
+        };
+        let inner = block.inner(halves[idx]);
+        frame.render_widget(block.style(Style::default().bg(colors.bg)), halves[idx]);
+
+        let label = Style::default().fg(colors.line_number).bg(colors.bg);
+        let value = if identical {
+            Style::default().fg(colors.same_text).bg(colors.bg)
+        } else {
+            Style::default().fg(colors.modified_text).bg(colors.bg)
+        };
+        let Some(side) = side else {
+            let missing = if idx == 0 { colors.right_only_text } else { colors.left_only_text };
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(" (missing)", Style::default().fg(missing).bg(colors.bg)))),
+                inner,
+            );
+            continue;
+        };
+
+        let hash_style = if hashes[idx].as_deref() == Some(side.hash.as_str()) {
+            Style::default().fg(colors.same_text).bg(colors.bg)
+        } else {
+            value
+        };
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(" Size    ", label),
+                Span::styled(format!("{} ({} bytes)", format_size(side.size), format_number(side.size)), value),
+            ]),
+            Line::from(vec![
+                Span::styled(" SHA-256 ", label),
+                Span::styled(side.hash.chars().take(32).collect::<String>(), hash_style),
+            ]),
+        ];
+        if let Some((w, h)) = side.dimensions {
+            lines.push(Line::from(vec![
+                Span::styled(" Image   ", label),
+                Span::styled(format!("{} x {}", w, h), value),
+            ]));
+        }
+        let info_height = lines.len() as u16 + 1;
+        frame.render_widget(Paragraph::new(lines), inner);
+
+        if inner.height <= info_height {
+            continue;
+        }
+        let image_area = Rect::new(inner.x, inner.y + info_height, inner.width, inner.height - info_height);
+        if let Some(ref mut protocol) = side.protocol {
+            let image_widget = ratatui_image::StatefulImage::new(None);
+            frame.render_stateful_widget(image_widget, image_area, protocol);
+        } else if side.image.is_none() && side.dimensions.is_none() {
+            frame.render_widget(
+                Paragraph::new(Line::from(Span::styled(" Binary file", label))),
+                image_area,
+            );
+        }
+    }
+}
+
+/// 상태바 + 기능키 안내줄
+fn draw_footer(
+    frame: &mut Frame,
+    status_text: String,
+    status_area: Rect,
+    function_area: Rect,
+    binary: bool,
+    theme: &Theme,
+    kb: &crate::keybindings::Keybindings,
+) {
     let status_line = Line::from(Span::styled(
         status_text,
         Style::default()
@@ -617,13 +821,18 @@ pub fn draw(frame: &mut Frame, state: &mut DiffFileViewState, area: Rect, theme:
     let text_style = Style::default()
         .fg(theme.diff_file_view.footer_text)
         .bg(theme.diff_file_view.bg);
-    let shortcuts: Vec<(String, &str)> = vec![
-        (kb.diff_file_view_first_key(DiffFileViewAction::MoveUp).to_string(), "scroll "),
-        (kb.diff_file_view_first_key(DiffFileViewAction::PageUp).to_string(), "page "),
-        (kb.diff_file_view_first_key(DiffFileViewAction::NextChange).to_string(), "next "),
-        (kb.diff_file_view_first_key(DiffFileViewAction::PrevChange).to_string(), "prev "),
-        (kb.diff_file_view_first_key(DiffFileViewAction::Close).to_string(), "back"),
-    ];
+    // 바이너리 비교는 스크롤/변경 이동이 없음
+    let shortcuts: Vec<(String, &str)> = if binary {
+        vec![(kb.diff_file_view_first_key(DiffFileViewAction::Close).to_string(), "back")]
+    } else {
+        vec![
+            (kb.diff_file_view_first_key(DiffFileViewAction::MoveUp).to_string(), "scroll "),
+            (kb.diff_file_view_first_key(DiffFileViewAction::PageUp).to_string(), "page "),
+            (kb.diff_file_view_first_key(DiffFileViewAction::NextChange).to_string(), "next "),
+            (kb.diff_file_view_first_key(DiffFileViewAction::PrevChange).to_string(), "prev "),
+            (kb.diff_file_view_first_key(DiffFileViewAction::Close).to_string(), "back"),
+        ]
+    };
     let mut fn_spans = Vec::new();
     for (key, label) in &shortcuts {
         fn_spans.push(Span::styled(key.as_str(), key_style));
@@ -1105,7 +1314,7 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                 }
             }
             DiffFileViewAction::Close => {
-                app.current_screen = super::app::Screen::DiffScreen;
+                app.current_screen = state.return_screen;
                 app.diff_file_view_state = None;
             }
         }
@@ -1196,6 +1405,32 @@ mod tests {
         assert!(!is_binary(&[]));
     }
 
+    #[test]
+    fn test_binary_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        let left = dir.path().join("a.bin");
+        let right = dir.path().join("b.bin");
+        fs::write(&left, [0u8, 1, 2, 3]).unwrap();
+        fs::write(&right, [0u8, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+
+        let state = DiffFileViewState::new(left.clone(), right, "a.bin".to_string());
+        let binary = state.binary.as_ref().expect("binary comparison");
+        assert!(state.diff_lines.is_empty());
+        assert!(!binary.identical());
+        assert_eq!(binary.left.as_ref().unwrap().size, 4);
+        assert!(binary.summary().contains("(+100.0%)"));
+
+        let same = DiffFileViewState::new(left.clone(), left, "a.bin".to_string());
+        assert!(same.binary.unwrap().identical());
+    }
+
+    #[test]
+    fn test_size_delta() {
+        assert_eq!(size_delta(10, 10), "same");
+        assert_eq!(size_delta(0, 5), format!("+{}", format_size(5)));
+        assert_eq!(size_delta(200, 100), format!("-{} (-50.0%)", format_size(100)));
+    }
+
     #[test]
     fn test_compute_lcs_empty() {
         let left: Vec<String> = Vec::new();
@@ -20,6 +20,7 @@ use unicode_width::UnicodeWidthStr;
 
 use super::app::{App, Screen, SortBy, SortOrder};
 use super::theme::Theme;
+use crate::services::dir_sync::{self, SyncClass, SyncDirection, SyncItem, SyncOp};
 use crate::utils::format::{format_size, safe_suffix};
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -157,6 +158,99 @@ pub struct DiffState {
     pub progress_current: String,
     pub progress_count: usize,
     pub progress_total: usize,
+    /// Sync mode (None while browsing the comparison)
+    pub sync: Option<SyncState>,
+    /// Result of the last sync, shown after the re-comparison
+    pub last_sync: Option<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Sync mode
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Differing entries with the direction chosen for each, and the dry-run
+/// preview once the user asks to run the plan
+pub struct SyncState {
+    pub items: Vec<SyncItem>,
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+    pub marked: HashSet<usize>,
+    pub visible_height: usize,
+    /// Planned operations while the dry-run preview is shown
+    pub preview: Option<Vec<SyncOp>>,
+    pub preview_scroll: usize,
+}
+
+impl SyncState {
+    pub fn new(items: Vec<SyncItem>) -> Self {
+        Self {
+            items,
+            selected_index: 0,
+            scroll_offset: 0,
+            marked: HashSet::new(),
+            visible_height: 0,
+            preview: None,
+            preview_scroll: 0,
+        }
+    }
+
+    pub fn move_cursor(&mut self, delta: i32) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.selected_index = (self.selected_index as i32 + delta)
+            .clamp(0, self.items.len() as i32 - 1) as usize;
+    }
+
+    pub fn toggle_mark(&mut self) {
+        if !self.marked.remove(&self.selected_index) {
+            self.marked.insert(self.selected_index);
+        }
+        self.move_cursor(1);
+    }
+
+    /// Set the direction of the marked items (or the current one)
+    pub fn set_direction(&mut self, direction: SyncDirection) {
+        if self.marked.is_empty() {
+            if let Some(item) = self.items.get_mut(self.selected_index) {
+                item.direction = direction;
+            }
+        } else {
+            for &i in &self.marked {
+                self.items[i].direction = direction;
+            }
+        }
+    }
+
+    /// Set the direction of every item
+    pub fn set_all(&mut self, direction: SyncDirection) {
+        for item in &mut self.items {
+            item.direction = direction;
+        }
+    }
+
+    /// Back to the suggested direction for every item
+    pub fn reset(&mut self) {
+        for item in &mut self.items {
+            item.direction = item.class.default_direction();
+        }
+    }
+
+    pub fn count(&self, direction: SyncDirection) -> usize {
+        self.items.iter().filter(|i| i.direction == direction).count()
+    }
+
+    pub fn adjust_scroll(&mut self, visible_height: usize) {
+        self.visible_height = visible_height;
+        if visible_height == 0 {
+            return;
+        }
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + visible_height {
+            self.scroll_offset = self.selected_index + 1 - visible_height;
+        }
+    }
 }
 
 impl DiffState {
@@ -188,6 +282,8 @@ impl DiffState {
             progress_current: String::new(),
             progress_count: 0,
             progress_total: 0,
+            sync: None,
+            last_sync: None,
         }
     }
 
@@ -197,6 +293,7 @@ impl DiffState {
         self.cancel_flag.store(true, Ordering::Relaxed);
 
         self.is_comparing = true;
+        self.sync = None;
         self.all_entries.clear();
         self.filtered_indices.clear();
         self.collapsed_dirs.clear();
@@ -314,6 +411,42 @@ impl DiffState {
         })
     }
 
+    /// Differing entries of the whole comparison for Sync mode. Entries
+    /// inside a directory that exists on one side only (or faces a file) are
+    /// covered by that directory and not listed on their own.
+    pub fn sync_items(&self) -> Vec<SyncItem> {
+        let mut items: Vec<SyncItem> = Vec::new();
+        for entry in &self.all_entries {
+            let covered = items.iter().any(|item| {
+                item.is_directory
+                    && entry.relative_path.strip_prefix(item.relative_path.as_str())
+                        .map_or(false, |rest| rest.starts_with('/'))
+            });
+            if covered {
+                continue;
+            }
+            let class = match (entry.status, &entry.left, &entry.right) {
+                (DiffStatus::LeftOnly, _, _) => SyncClass::OnlyLeft,
+                (DiffStatus::RightOnly, _, _) => SyncClass::OnlyRight,
+                (DiffStatus::Modified, Some(l), Some(r)) => SyncClass::for_pair(
+                    (l.is_directory, l.modified.into()),
+                    (r.is_directory, r.modified.into()),
+                ),
+                _ => continue,
+            };
+            items.push(SyncItem::new(entry.relative_path.clone(), class, entry.is_directory));
+        }
+        items
+    }
+
+    /// Enter Sync mode (nothing happens when the sides do not differ)
+    pub fn start_sync(&mut self) {
+        let items = self.sync_items();
+        if !items.is_empty() {
+            self.sync = Some(SyncState::new(items));
+        }
+    }
+
     /// Cancel ongoing comparison
     pub fn cancel(&mut self) {
         self.cancel_flag.store(true, Ordering::Relaxed);
@@ -1676,6 +1809,12 @@ pub fn draw(
         return;
     }
 
+    if let Some(ref mut sync) = state.sync {
+        let content = Rect::new(col_header_area.x, col_header_area.y, col_header_area.width, col_header_area.height + content_area.height);
+        draw_sync(frame, sync, content, status_area, fn_bar_area, theme);
+        return;
+    }
+
     // ── Column Headers ──────────────────────────────────────────────────────
     draw_column_headers(frame, col_header_area, theme);
 
@@ -1711,6 +1850,88 @@ pub fn draw(
     draw_function_bar(frame, fn_bar_area, theme, kb);
 }
 
+/// Sync mode: the plan list (or the dry-run preview), its counts and keys
+fn draw_sync(frame: &mut Frame, sync: &mut SyncState, area: Rect, status_area: Rect, fn_bar_area: Rect, theme: &Theme) {
+    let dc = &theme.diff;
+    let status_style = Style::default().fg(dc.status_bar_text).bg(dc.status_bar_bg);
+    let width = area.width as usize;
+    let visible_height = area.height as usize;
+
+    let (lines, status_text, shortcuts): (Vec<Line>, String, Vec<(&str, &str)>) = if let Some(ref ops) = sync.preview {
+        // 드라이런: 실행될 작업 목록만 표시
+        let max_scroll = ops.len().saturating_sub(visible_height);
+        sync.preview_scroll = sync.preview_scroll.min(max_scroll);
+        let lines = ops.iter().skip(sync.preview_scroll).take(visible_height).map(|op| {
+            let color = match op {
+                SyncOp::Copy { .. } => dc.modified_text,
+                SyncOp::Delete(_) => dc.left_only_text,
+            };
+            let text = op.describe();
+            let text = if text.width() > width.saturating_sub(1) {
+                format!("...{}", safe_suffix(&text, width.saturating_sub(4)))
+            } else {
+                text
+            };
+            Line::from(Span::styled(format!(" {}", text), Style::default().fg(color)))
+        }).collect();
+        let copies = ops.iter().filter(|op| matches!(op, SyncOp::Copy { .. })).count();
+        let status = format!(" Dry run: {} copy, {} delete - nothing has changed yet", copies, ops.len() - copies);
+        (lines, status, vec![("\u{2191}\u{2193}", " scroll  "), ("Enter", ":run sync  "), ("Esc", ":back to plan")])
+    } else {
+        sync.adjust_scroll(visible_height);
+        let lines = sync.items.iter().enumerate().skip(sync.scroll_offset).take(visible_height).map(|(i, item)| {
+            let marker = if sync.marked.contains(&i) { "*" } else { " " };
+            let name = if item.is_directory { format!("{}/", item.relative_path) } else { item.relative_path.clone() };
+            let prefix = format!("{}{} {:<11} ", marker, item.direction.arrow(), item.class.label());
+            let name_width = width.saturating_sub(prefix.width() + 1);
+            let name = if name.width() > name_width {
+                format!("...{}", safe_suffix(&name, name_width.saturating_sub(3)))
+            } else {
+                name
+            };
+            let text = format!("{}{:<w$}", prefix, name, w = name_width);
+            let style = if i == sync.selected_index {
+                Style::default().fg(dc.cursor_text).bg(dc.cursor_bg)
+            } else if sync.marked.contains(&i) {
+                Style::default().fg(dc.marked_text).add_modifier(Modifier::BOLD)
+            } else {
+                let color = match item.direction {
+                    SyncDirection::Skip => dc.same_text,
+                    _ if item.class == SyncClass::Conflict => dc.modified_text,
+                    SyncDirection::LeftToRight => dc.left_only_text,
+                    SyncDirection::RightToLeft => dc.right_only_text,
+                };
+                Style::default().fg(color)
+            };
+            Line::from(Span::styled(format!(" {}", text), style))
+        }).collect();
+        let marked = if sync.marked.is_empty() { String::new() } else { format!(" | Marked: {}", sync.marked.len()) };
+        let status = format!(
+            " Sync: {} to right, {} to left, {} skipped{}",
+            sync.count(SyncDirection::LeftToRight),
+            sync.count(SyncDirection::RightToLeft),
+            sync.count(SyncDirection::Skip),
+            marked,
+        );
+        (lines, status, vec![
+            ("Space", ":mark "), ("\u{2192}/>", ":to right "), ("\u{2190}/<", ":to left "), ("=", ":skip "),
+            ("Shift+\u{2190}\u{2192}", ":all "), ("r", ":reset "), ("Enter", ":preview "), ("Esc", ":back"),
+        ])
+    };
+
+    frame.render_widget(Paragraph::new(lines).style(Style::default().bg(dc.bg)), area);
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(format!("{:<w$}", status_text, w = status_area.width as usize), status_style))),
+        status_area,
+    );
+    let mut spans = Vec::new();
+    for (key, label) in shortcuts {
+        spans.push(Span::styled(key, Style::default().fg(dc.footer_key)));
+        spans.push(Span::styled(label, Style::default().fg(dc.footer_text)));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), fn_bar_area);
+}
+
 fn draw_comparing_progress(frame: &mut Frame, state: &DiffState, area: Rect, theme: &Theme, kb: &crate::keybindings::Keybindings) {
     let center_y = area.y + area.height / 2;
 
@@ -2137,11 +2358,14 @@ fn draw_status_bar(frame: &mut Frame, state: &DiffState, area: Rect, theme: &The
         .count();
 
     let selected_count = state.selected_files.len();
-    let sel_str = if selected_count > 0 {
+    let mut sel_str = if selected_count > 0 {
         format!(" | Selected: {}", selected_count)
     } else {
         String::new()
     };
+    if let Some(ref sync) = state.last_sync {
+        sel_str.push_str(&format!(" | {}", sync));
+    }
 
     let status_text = format!(
         " Filter: {} | Compare: {} | Total: {} Different: {} Left: {} Right: {}{}",
@@ -2181,6 +2405,7 @@ fn draw_function_bar(frame: &mut Frame, area: Rect, theme: &Theme, kb: &crate::k
         (kb.diff_screen_first_key(DiffScreenAction::SortBySize).to_string(), "ize "),
         (kb.diff_screen_first_key(DiffScreenAction::SortByDate).to_string(), "ate "),
         (kb.diff_screen_first_key(DiffScreenAction::SortByType).to_string(), ":type "),
+        (kb.diff_screen_first_key(DiffScreenAction::Sync).to_string(), ":sync "),
         (kb.diff_screen_first_key(DiffScreenAction::Close).to_string(), ":back"),
     ];
 
@@ -2222,6 +2447,11 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
         }
     }
 
+    if app.diff_state.as_ref().map_or(false, |s| s.sync.is_some()) {
+        handle_sync_input(app, code, modifiers);
+        return;
+    }
+
     let action = match app.keybindings.diff_screen_action(code, modifiers) {
         Some(a) => a,
         None => return,
@@ -2289,6 +2519,9 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
             DiffScreenAction::CollapseAll => {
                 state.collapse();
             }
+            DiffScreenAction::Sync => {
+                state.start_sync();
+            }
             DiffScreenAction::Open => {
                 // Handle Enter: view file diff if current entry is a file
                 handle_enter(app);
@@ -2303,6 +2536,57 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
     };
 }
 
+/// Sync mode keys: direction per item (or for the marked items), for all
+/// items, then the dry-run preview, which runs the plan on Enter
+fn handle_sync_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    use crate::keybindings::DiffScreenAction;
+
+    let action = app.keybindings.diff_screen_action(code, modifiers);
+    let Some(state) = app.diff_state.as_mut() else { return };
+    let Some(sync) = state.sync.as_mut() else { return };
+
+    if let Some(ref ops) = sync.preview {
+        match (code, action) {
+            (KeyCode::Enter, _) => {
+                let ops = ops.clone();
+                app.execute_dir_sync(ops);
+            }
+            (_, Some(DiffScreenAction::MoveUp)) => sync.preview_scroll = sync.preview_scroll.saturating_sub(1),
+            (_, Some(DiffScreenAction::MoveDown)) => sync.preview_scroll += 1,
+            (_, Some(DiffScreenAction::PageUp)) => sync.preview_scroll = sync.preview_scroll.saturating_sub(sync.visible_height.max(1)),
+            (_, Some(DiffScreenAction::PageDown)) => sync.preview_scroll += sync.visible_height.max(1),
+            (KeyCode::Esc, _) | (_, Some(DiffScreenAction::Close)) => sync.preview = None,
+            _ => {}
+        }
+        return;
+    }
+
+    let all = modifiers.contains(KeyModifiers::SHIFT);
+    match code {
+        KeyCode::Right if all => sync.set_all(SyncDirection::LeftToRight),
+        KeyCode::Left if all => sync.set_all(SyncDirection::RightToLeft),
+        KeyCode::Right | KeyCode::Char('>') => sync.set_direction(SyncDirection::LeftToRight),
+        KeyCode::Left | KeyCode::Char('<') => sync.set_direction(SyncDirection::RightToLeft),
+        KeyCode::Char('=') => sync.set_direction(SyncDirection::Skip),
+        KeyCode::Char('r') => sync.reset(),
+        _ => match action {
+            Some(DiffScreenAction::MoveUp) => sync.move_cursor(-1),
+            Some(DiffScreenAction::MoveDown) => sync.move_cursor(1),
+            Some(DiffScreenAction::PageUp) => sync.move_cursor(-(sync.visible_height.saturating_sub(1).max(1) as i32)),
+            Some(DiffScreenAction::PageDown) => sync.move_cursor(sync.visible_height.saturating_sub(1).max(1) as i32),
+            Some(DiffScreenAction::GoHome) => sync.selected_index = 0,
+            Some(DiffScreenAction::GoEnd) => sync.selected_index = sync.items.len().saturating_sub(1),
+            Some(DiffScreenAction::ToggleSelect) => sync.toggle_mark(),
+            Some(DiffScreenAction::Open) => {
+                sync.preview = Some(dir_sync::plan(&sync.items, &state.left_root, &state.right_root));
+                sync.preview_scroll = 0;
+            }
+            Some(DiffScreenAction::Close) => state.sync = None,
+            _ => {}
+        },
+    }
+}
+
 /// Toggle sort field/order for the diff state
 fn toggle_diff_sort(state: &mut DiffState, sort_by: SortBy) {
     if state.sort_by == sort_by {
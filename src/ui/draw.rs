@@ -7,6 +7,7 @@ use ratatui::{
 };
 use unicode_width::UnicodeWidthStr;
 
+use crate::config::AiPaneSettings;
 use crate::services::remote;
 use crate::keybindings::PanelAction;
 use super::{
@@ -27,6 +28,20 @@ use super::{
     diff_file_view,
     git_screen,
     dedup_screen,
+    checksum_screen,
+    audit_screen,
+    archive_viewer,
+    trash_screen,
+    bulk_rename,
+    drives_screen,
+    recovery_screen,
+    cleanup_screen,
+    backup_screen,
+    timeline_screen,
+    git_log_screen,
+    preview_pane,
+    tree_sidebar,
+    jobs,
     theme::Theme,
 };
 
@@ -117,6 +132,61 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
                 dedup_screen::draw(frame, state, area, &theme);
             }
         }
+        Screen::ChecksumScreen => {
+            if let Some(ref mut state) = app.checksum_state {
+                checksum_screen::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::AuditScreen => {
+            if let Some(ref mut state) = app.audit_screen_state {
+                audit_screen::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::ArchiveViewer => {
+            if let Some(ref mut state) = app.archive_viewer_state {
+                archive_viewer::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::TrashScreen => {
+            if let Some(ref mut state) = app.trash_screen_state {
+                trash_screen::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::BulkRename => {
+            if let Some(ref mut state) = app.bulk_rename_state {
+                bulk_rename::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::DrivesScreen => {
+            if let Some(ref mut state) = app.drives_screen_state {
+                drives_screen::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::RecoveryScreen => {
+            if let Some(ref mut state) = app.recovery_screen_state {
+                recovery_screen::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::CleanupScreen => {
+            if let Some(ref mut state) = app.cleanup_screen_state {
+                cleanup_screen::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::BackupScreen => {
+            if let Some(ref mut state) = app.backup_screen_state {
+                backup_screen::draw(frame, state, &app.settings.backups, area, &theme);
+            }
+        }
+        Screen::TimelineScreen => {
+            if let Some(ref mut state) = app.timeline_screen_state {
+                timeline_screen::draw(frame, state, area, &theme);
+            }
+        }
+        Screen::GitLogScreen => {
+            if let Some(ref mut state) = app.git_log_screen_state {
+                git_log_screen::draw(frame, state, area, &theme);
+            }
+        }
     }
 
     // Draw advanced search dialog overlay if active
@@ -136,6 +206,34 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
                 state.visible_height = inner_h.saturating_sub(3) as usize; // header + gap + buttons
             }
         }
+        if dialog.dialog_type == crate::ui::app::DialogType::OpenWith {
+            if let Some(ref mut state) = app.open_with_state {
+                let dialog_h = area.height.saturating_sub(6).max(12).min(24);
+                let inner_h = dialog_h.saturating_sub(2); // borders
+                state.visible_height = inner_h.saturating_sub(2) as usize; // input + command preview
+            }
+        }
+        if dialog.dialog_type == crate::ui::app::DialogType::RecentFiles {
+            if let Some(ref mut state) = app.recent_files_state {
+                let dialog_h = area.height.saturating_sub(6).max(12).min(24);
+                let inner_h = dialog_h.saturating_sub(2); // borders
+                state.visible_height = inner_h.saturating_sub(2) as usize; // input + hint
+            }
+        }
+        if dialog.dialog_type == crate::ui::app::DialogType::Breadcrumb {
+            if let Some(ref mut state) = app.breadcrumb_state {
+                let dialog_h = area.height.saturating_sub(6).max(12).min(24);
+                let inner_h = dialog_h.saturating_sub(2); // borders
+                state.visible_height = inner_h.saturating_sub(2) as usize; // breadcrumb + hint
+            }
+        }
+        if dialog.dialog_type == crate::ui::app::DialogType::JumpDirectory {
+            if let Some(ref mut state) = app.jump_state {
+                let dialog_h = area.height.saturating_sub(6).max(12).min(24);
+                let inner_h = dialog_h.saturating_sub(2); // borders
+                state.visible_height = inner_h.saturating_sub(2) as usize; // input + hint
+            }
+        }
     }
 
     // Draw remote spinner overlay on top of everything
@@ -152,38 +250,49 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     }
 }
 
-fn draw_panels(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
-    // Layout: Panels, Status Bar, Function Bar (no header - saves 1 line)
-    let chunks = Layout::default()
+/// File panel screen layout: Favorites bar (hidden unless turned on), Panels, Jobs pane,
+/// Status Bar, Function Bar (no header - saves 1 line)
+pub fn panel_screen_chunks(app: &App, area: Rect) -> std::rc::Rc<[Rect]> {
+    let favorites_height = if app.settings.favorites.show_bar { 1 } else { 0 };
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(5),    // Panels
-            Constraint::Length(1), // Status bar
-            Constraint::Length(1), // Function bar / message
+            Constraint::Length(favorites_height),    // Favorites bar
+            Constraint::Min(5),                      // Panels
+            Constraint::Length(app.jobs.height()),   // Background jobs (hidden without jobs)
+            Constraint::Length(1),                   // Status bar
+            Constraint::Length(1),                   // Function bar / message
         ])
-        .split(area);
+        .split(area)
+}
+
+fn draw_panels(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
+    let chunks = panel_screen_chunks(app, area);
+    draw_favorites_bar(frame, app, chunks[0], theme);
+    jobs::draw(frame, &app.jobs, chunks[2], theme);
 
     // Dynamic N-panel layout
     let num_panels = app.panels.len();
-    let constraints: Vec<Constraint> = (0..num_panels)
-        .map(|_| Constraint::Ratio(1, num_panels as u32))
-        .collect();
-    let panel_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(constraints)
-        .split(chunks[0]);
+    let panel_chunks = panel_areas(app, chunks[1]);
 
     // AI fullscreen 체크: fullscreen이면 AI만 전체 영역에 렌더링
     let ai_fullscreen = app.ai_state.as_ref().map_or(false, |s| s.ai_fullscreen);
     if ai_fullscreen && app.ai_panel_index.is_some() {
         if let Some(ref mut state) = app.ai_state {
-            ai_screen::draw_with_focus(frame, state, chunks[0], theme, true);
+            ai_screen::draw_with_focus(frame, state, chunks[1], theme, true);
         }
-        draw_status_bar(frame, app, chunks[1], theme);
-        draw_function_bar(frame, app, chunks[2], theme);
+        draw_status_bar(frame, app, chunks[3], theme);
+        draw_function_bar(frame, app, chunks[4], theme);
         return;
     }
 
+    // 디렉토리 트리 사이드바 (왼쪽 가장자리)
+    let (tree_area, _) = tree_sidebar_split(app, chunks[1]);
+    if let (Some(area), Some(tree)) = (tree_area, app.tree_sidebar.as_mut()) {
+        tree_sidebar::draw(frame, tree, area, theme);
+    }
+    let tree_focused = app.tree_sidebar.as_ref().is_some_and(|t| t.focused);
+
     let is_ai_mode = app.is_ai_mode();
     let has_dialog = app.dialog.is_some();
     let active_idx = app.active_panel_index;
@@ -198,6 +307,9 @@ fn draw_panels(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
                 let ai_focused = active_idx == i && !has_dialog;
                 ai_screen::draw_with_focus(frame, state, panel_chunks[i], theme, ai_focused);
             }
+        } else if let Some(pane) = app.preview_pane.as_mut().filter(|p| p.panel_index == i) {
+            // Quick view: 패널 대신 미리보기
+            preview_pane::draw(frame, pane, panel_chunks[i], theme);
         } else {
             let path_str = app.panels[i].path.display().to_string();
             let bookmarked = if app.panels[i].is_remote() {
@@ -217,7 +329,7 @@ fn draw_panels(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
             } else {
                 app.settings.bookmarked_path.contains(&path_str)
             };
-            let focused = active_idx == i && !has_dialog && (!is_ai_mode || ai_panel_index != Some(i));
+            let focused = active_idx == i && !has_dialog && !tree_focused && (!is_ai_mode || ai_panel_index != Some(i));
             let diff_selected = diff_first_panel == Some(i);
             panel::draw(
                 frame,
@@ -232,10 +344,120 @@ fn draw_panels(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
     }
 
     // Status bar
-    draw_status_bar(frame, app, chunks[1], theme);
+    draw_status_bar(frame, app, chunks[3], theme);
 
     // Function bar or message
-    draw_function_bar(frame, app, chunks[2], theme);
+    draw_function_bar(frame, app, chunks[4], theme);
+}
+
+/// 즐겨찾기 바: "1:proj 2:log ..." (번호는 Alt+1..9 점프 키), 현재 패널 위치는 강조
+fn draw_favorites_bar(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    if area.height == 0 {
+        return;
+    }
+    let colors = &theme.favorites_bar;
+    let key_style = Style::default().fg(colors.key);
+    let label_style = Style::default().fg(colors.label);
+    let favorites = &app.settings.favorites.paths;
+    if favorites.is_empty() {
+        let hint = format!(" No favorites - {}: add current directory",
+            app.keybindings.panel_first_key(PanelAction::Favorites));
+        frame.render_widget(Paragraph::new(Span::styled(hint, Style::default().fg(colors.empty_text))), area);
+        return;
+    }
+
+    let current = app.current_location();
+    let mut spans = vec![Span::raw(" ")];
+    for (i, path) in favorites.iter().enumerate() {
+        // 원격 위치(user@host:/path)도 마지막 경로 요소만
+        let name = path.trim_end_matches('/').rsplit(['/', ':']).next().filter(|n| !n.is_empty()).unwrap_or("/");
+        let style = if current.as_ref() == Some(path) {
+            Style::default().fg(colors.current).add_modifier(Modifier::BOLD | Modifier::REVERSED)
+        } else {
+            label_style
+        };
+        spans.push(Span::styled((i + 1).to_string(), key_style));
+        spans.push(Span::styled(":", label_style));
+        spans.push(Span::styled(name.to_string(), style));
+        spans.push(Span::raw("  "));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Areas of the panels in `area`: equal widths, or the AI pane split of
+/// settings.ai_pane when a panel shows the AI screen
+pub fn panel_areas(app: &App, area: Rect) -> Vec<Rect> {
+    let (_, area) = tree_sidebar_split(app, area);
+    let num_panels = app.panels.len();
+    match app.ai_panel_index {
+        Some(ai_index) if ai_index < num_panels && num_panels > 1 => {
+            ai_split_areas(area, num_panels, ai_index, &app.settings.ai_pane)
+        }
+        _ => {
+            let constraints: Vec<Constraint> = (0..num_panels)
+                .map(|_| Constraint::Ratio(1, num_panels as u32))
+                .collect();
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .split(area)
+                .to_vec()
+        }
+    }
+}
+
+/// 트리 사이드바 영역(표시 중이고 폭이 충분할 때)과 나머지 패널 영역
+fn tree_sidebar_split(app: &App, area: Rect) -> (Option<Rect>, Rect) {
+    let width = tree_sidebar::width_for(area.width);
+    if app.tree_sidebar.is_none() || width == 0 {
+        return (None, area);
+    }
+    let sidebar = Rect { width, ..area };
+    let rest = Rect { x: area.x + width, width: area.width - width, ..area };
+    (Some(sidebar), rest)
+}
+
+/// AI 패널(ai_index)과 나머지 패널 영역: 가로 분할이면 AI가 percent 너비,
+/// 세로 분할이면 AI가 percent 높이를 차지하고 나머지 패널은 남은 영역을 나눠 가짐
+fn ai_split_areas(area: Rect, num_panels: usize, ai_index: usize, pane: &AiPaneSettings) -> Vec<Rect> {
+    let ai_percent = pane.clamped_percent();
+    let others = num_panels.saturating_sub(1).max(1) as u32;
+    if pane.vertical {
+        let ai_first = ai_index == 0;
+        let constraints = if ai_first {
+            [Constraint::Percentage(ai_percent), Constraint::Percentage(100 - ai_percent)]
+        } else {
+            [Constraint::Percentage(100 - ai_percent), Constraint::Percentage(ai_percent)]
+        };
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+        let (ai_area, rest) = if ai_first { (rows[0], rows[1]) } else { (rows[1], rows[0]) };
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints((0..others).map(|_| Constraint::Ratio(1, others)).collect::<Vec<_>>())
+            .split(rest);
+        let mut columns = columns.iter();
+        (0..num_panels)
+            .map(|i| if i == ai_index { ai_area } else { columns.next().copied().unwrap_or(rest) })
+            .collect()
+    } else {
+        let constraints: Vec<Constraint> = (0..num_panels)
+            .map(|i| {
+                if i == ai_index {
+                    Constraint::Percentage(ai_percent)
+                } else {
+                    Constraint::Ratio((100 - ai_percent) as u32, 100 * others)
+                }
+            })
+            .collect();
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(area)
+            .to_vec()
+    }
 }
 
 /// Public function for drawing panel background (used by overlay screens)
@@ -250,11 +472,17 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
     let left_text = if let Some(file) = current_file {
         if file.name != ".." {
             let name = file.display_name.as_deref().unwrap_or(&file.name);
-            format!(
-                "{} ({})",
-                name,
-                crate::utils::format::format_size(file.size)
-            )
+            if let Some(ref target) = file.link_target {
+                // 끊어진 링크는 대상 표시 뒤에 표시
+                let broken = !panel.path.join(target).exists();
+                format!("{} -> {}{}", name, target, if broken { " (broken)" } else { "" })
+            } else {
+                format!(
+                    "{} ({})",
+                    name,
+                    crate::utils::format::format_size(file.size)
+                )
+            }
         } else {
             String::new()
         }
@@ -270,7 +498,7 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
         .map(|f| f.size)
         .sum();
 
-    let right_text = if selected_count > 0 {
+    let mut right_text = if selected_count > 0 {
         format!(
             "{} selected, Total: {}",
             selected_count,
@@ -279,11 +507,35 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
     } else {
         format!("Total: {}", crate::utils::format::format_size(total_size))
     };
+    if panel.hidden_count > 0 {
+        right_text = format!("{} hidden, {}", panel.hidden_count, right_text);
+    }
+
+    // AI requests running (or answered) in background conversations
+    let ai_running = app.ai_conversations.iter().filter(|s| s.is_processing).count();
+    let ai_unseen = app.ai_conversations.iter().filter(|s| s.has_unseen_response).count();
+    let ai_badge = if ai_running > 0 {
+        let spinner_frames = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+        let frame_idx = (std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() / 100) as usize % spinner_frames.len();
+        if ai_running > 1 {
+            format!(" {} AI x{} ", spinner_frames[frame_idx], ai_running)
+        } else {
+            format!(" {} AI ", spinner_frames[frame_idx])
+        }
+    } else if ai_unseen > 0 {
+        " ✓ AI ready ".to_string()
+    } else {
+        String::new()
+    };
 
     let status = Line::from(vec![
+        Span::styled(ai_badge.clone(), theme.status_bar_style().add_modifier(Modifier::REVERSED)),
         Span::styled(format!(" {} ", left_text), theme.status_bar_style()),
         Span::styled(
-            " ".repeat(area.width.saturating_sub(left_text.width() as u16 + right_text.width() as u16 + 4) as usize),
+            " ".repeat(area.width.saturating_sub(ai_badge.width() as u16 + left_text.width() as u16 + right_text.width() as u16 + 4) as usize),
             theme.status_bar_style(),
         ),
         Span::styled(format!(" {} ", right_text), theme.status_bar_style()),
@@ -396,29 +648,16 @@ fn draw_editor_with_ai(frame: &mut Frame, app: &mut App, area: Rect, theme: &The
             ai_screen::draw_with_focus(frame, state, chunks[0], theme, true);
         }
     } else {
-        let panel_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(chunks[0]);
-
+        // AI가 왼쪽(위)이면 0, 에디터가 왼쪽(위)이면 1
         let ai_on_left = app.ai_panel_index.map(|i| i < app.active_panel_index).unwrap_or(false);
+        let ai_index = if ai_on_left { 0 } else { 1 };
+        let areas = ai_split_areas(chunks[0], 2, ai_index, &app.settings.ai_pane);
 
-        if ai_on_left {
-            // AI 왼쪽, 에디터 오른쪽
-            if let Some(ref mut state) = app.ai_state {
-                ai_screen::draw_with_focus(frame, state, panel_chunks[0], theme, false);
-            }
-            if let Some(ref mut state) = app.editor_state {
-                file_editor::draw(frame, state, panel_chunks[1], theme, &app.keybindings);
-            }
-        } else {
-            // 에디터 왼쪽, AI 오른쪽
-            if let Some(ref mut state) = app.editor_state {
-                file_editor::draw(frame, state, panel_chunks[0], theme, &app.keybindings);
-            }
-            if let Some(ref mut state) = app.ai_state {
-                ai_screen::draw_with_focus(frame, state, panel_chunks[1], theme, false);
-            }
+        if let Some(ref mut state) = app.editor_state {
+            file_editor::draw(frame, state, areas[1 - ai_index], theme, &app.keybindings);
+        }
+        if let Some(ref mut state) = app.ai_state {
+            ai_screen::draw_with_focus(frame, state, areas[ai_index], theme, false);
         }
     }
 
@@ -446,29 +685,16 @@ fn draw_viewer_with_ai(frame: &mut Frame, app: &mut App, area: Rect, theme: &The
             ai_screen::draw_with_focus(frame, state, chunks[0], theme, true);
         }
     } else {
-        let panel_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(chunks[0]);
-
+        // AI가 왼쪽(위)이면 0, 뷰어가 왼쪽(위)이면 1
         let ai_on_left = app.ai_panel_index.map(|i| i < app.active_panel_index).unwrap_or(false);
+        let ai_index = if ai_on_left { 0 } else { 1 };
+        let areas = ai_split_areas(chunks[0], 2, ai_index, &app.settings.ai_pane);
 
-        if ai_on_left {
-            // AI 왼쪽, 뷰어 오른쪽
-            if let Some(ref mut state) = app.ai_state {
-                ai_screen::draw_with_focus(frame, state, panel_chunks[0], theme, false);
-            }
-            if let Some(ref mut state) = app.viewer_state {
-                file_viewer::draw(frame, state, panel_chunks[1], theme, &app.keybindings);
-            }
-        } else {
-            // 뷰어 왼쪽, AI 오른쪽
-            if let Some(ref mut state) = app.viewer_state {
-                file_viewer::draw(frame, state, panel_chunks[0], theme, &app.keybindings);
-            }
-            if let Some(ref mut state) = app.ai_state {
-                ai_screen::draw_with_focus(frame, state, panel_chunks[1], theme, false);
-            }
+        if let Some(ref mut state) = app.viewer_state {
+            file_viewer::draw(frame, state, areas[1 - ai_index], theme, &app.keybindings);
+        }
+        if let Some(ref mut state) = app.ai_state {
+            ai_screen::draw_with_focus(frame, state, areas[ai_index], theme, false);
         }
     }
 
@@ -0,0 +1,188 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::services::disks::{self, Mount};
+use crate::ui::theme::Theme;
+use crate::utils::format::format_size;
+
+/// 사용량 막대 폭 (문자 수)
+const BAR_WIDTH: usize = 20;
+
+pub struct DrivesScreenState {
+    pub mounts: Vec<Mount>,
+    pub selected: usize,
+    pub scroll: usize,
+    /// Mount point chosen with Enter (the active panel moves there on close)
+    pub chosen: Option<PathBuf>,
+}
+
+impl DrivesScreenState {
+    /// `current` is the active panel's folder; the mount holding it is preselected
+    pub fn new(current: &std::path::Path) -> Self {
+        let mounts = disks::mounts();
+        // 가장 긴 마운트 지점이 현재 폴더를 담은 파일시스템
+        let selected = mounts
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| current.starts_with(&m.mount_point))
+            .max_by_key(|(_, m)| m.mount_point.as_os_str().len())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        Self { mounts, selected, scroll: 0, chosen: None }
+    }
+
+    pub fn reload(&mut self) {
+        self.mounts = disks::mounts();
+        self.selected = self.selected.min(self.mounts.len().saturating_sub(1));
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.mounts.is_empty() {
+            return;
+        }
+        let max = self.mounts.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut DrivesScreenState, area: Rect, theme: &Theme) {
+    let colors = &theme.drives_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // mounts
+            Constraint::Length(3), // device / type
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Mounts ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Drives ", Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+
+    let inner_height = chunks[0].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    // 마운트 지점 열 폭: 가장 긴 경로에 맞추되 막대와 용량이 들어갈 자리는 남김
+    let inner_width = chunks[0].width.saturating_sub(2) as usize;
+    let path_width = state
+        .mounts
+        .iter()
+        .map(|m| m.mount_point.display().to_string().chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(inner_width.saturating_sub(BAR_WIDTH + 32))
+        .max(8);
+
+    let lines: Vec<Line> = if state.mounts.is_empty() {
+        vec![Line::from(Span::styled("No mounted filesystems found", Style::default().fg(colors.empty_text)))]
+    } else {
+        state
+            .mounts
+            .iter()
+            .enumerate()
+            .skip(state.scroll)
+            .take(inner_height)
+            .map(|(i, mount)| {
+                let path = mount.mount_point.display().to_string();
+                let mut spans = vec![Span::styled(
+                    format!("{:<w$.w$}  ", path, w = path_width),
+                    Style::default().fg(colors.mount_text),
+                )];
+                match mount.space {
+                    Some(space) if space.total > 0 => {
+                        let filled = ((space.used_ratio() * BAR_WIDTH as f64).round() as usize).min(BAR_WIDTH);
+                        // 90% 이상 사용 중이면 경고 색
+                        let fill_color = if space.used_ratio() >= 0.9 { colors.bar_full } else { colors.bar_fill };
+                        spans.push(Span::styled("█".repeat(filled), Style::default().fg(fill_color)));
+                        spans.push(Span::styled("░".repeat(BAR_WIDTH - filled), Style::default().fg(colors.bar_empty)));
+                        spans.push(Span::styled(
+                            format!(" {:3.0}%  ", space.used_ratio() * 100.0),
+                            Style::default().fg(colors.percent_text),
+                        ));
+                        spans.push(Span::styled(
+                            format!("{:>9} free of {:>9}", format_size(space.available), format_size(space.total)),
+                            Style::default().fg(colors.space_text),
+                        ));
+                    }
+                    _ => spans.push(Span::styled("(size unavailable)", Style::default().fg(colors.unavailable_text))),
+                }
+                let line = Line::from(spans);
+                if i == state.selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[0]);
+
+    // ── Device / type ──
+    let detail_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let detail = match state.mounts.get(state.selected) {
+        Some(mount) => Line::from(vec![
+            Span::styled("Device: ", Style::default().fg(colors.label_text)),
+            Span::styled(mount.device.clone(), Style::default().fg(colors.value_text)),
+            Span::styled("  Type: ", Style::default().fg(colors.label_text)),
+            Span::styled(mount.fs_type.clone(), Style::default().fg(colors.value_text)),
+        ]),
+        None => Line::default(),
+    };
+    frame.render_widget(Paragraph::new(detail).block(detail_block), chunks[1]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(" Close  "),
+        key("Enter"),
+        text(" Go to mount point  "),
+        key("F5"),
+        text(" Refresh"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[2]);
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut DrivesScreenState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => return true,
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.mounts.len().saturating_sub(1),
+        KeyCode::Enter => {
+            if let Some(mount) = state.mounts.get(state.selected) {
+                state.chosen = Some(mount.mount_point.clone());
+                return true;
+            }
+        }
+        KeyCode::F(5) => state.reload(),
+        _ => {}
+    }
+    false
+}
@@ -1,6 +1,6 @@
 use std::fs;
 use std::path::PathBuf;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use crossterm::event::{KeyCode, KeyModifiers};
 use unicode_width::UnicodeWidthChar;
 use ratatui::{
@@ -14,7 +14,9 @@ use regex::Regex;
 
 use super::{
     app::{App, Screen},
-    syntax::{Language, SyntaxHighlighter},
+    file_viewer::{format_marks, is_mark_name, MarkPrompt},
+    highlight_cache::HighlightCache,
+    syntax::{CommentStyle, Language, SyntaxHighlighter},
     theme::Theme,
 };
 use crate::keybindings::EditorAction;
@@ -133,6 +135,9 @@ pub struct FindReplaceOptions {
 /// Default maximum memory for undo/redo stacks (50MB)
 const DEFAULT_MAX_UNDO_MEMORY: usize = 50 * 1024 * 1024;
 
+/// Shown in the line number gutter for the continuation rows of a wrapped line
+const WRAP_CONTINUATION_MARKER: &str = "↪";
+
 /// 편집기 상태
 #[derive(Debug)]
 pub struct EditorState {
@@ -176,10 +181,16 @@ pub struct EditorState {
     pub goto_mode: bool,
     pub goto_input: String,
 
+    // 이름 있는 마크 (글자 -> 줄), 파일별로 최근 파일 목록에 저장됨
+    pub marks: BTreeMap<char, usize>,
+    pub mark_prompt: Option<MarkPrompt>,
+
     // 문법 강조
     pub language: Language,
     pub highlighter: Option<SyntaxHighlighter>,
     pub syntax_colors: crate::ui::theme::SyntaxColors,
+    /// 줄별 강조 상태 (백그라운드 계산)
+    pub highlight_cache: HighlightCache,
 
     // 설정
     pub auto_indent: bool,
@@ -211,6 +222,11 @@ pub struct EditorState {
 
     // 원격 파일 편집 원본 정보
     pub remote_origin: Option<RemoteEditOrigin>,
+
+    // 쓰기 권한이 없는 파일: 편집 잠금 (권한 상승 저장을 선택하면 해제)
+    pub read_only: bool,
+    // 저장 시 sudo/pkexec 사용
+    pub elevated_save: bool,
 }
 
 impl EditorState {
@@ -264,9 +280,12 @@ impl EditorState {
             find_error: None,
             goto_mode: false,
             goto_input: String::new(),
+            marks: BTreeMap::new(),
+            mark_prompt: None,
             language: Language::Plain,
             highlighter: None,
             syntax_colors: crate::ui::theme::Theme::default().syntax,
+            highlight_cache: HighlightCache::default(),
             auto_indent: true,
             tab_size: 4,
             use_tabs: false,
@@ -281,6 +300,8 @@ impl EditorState {
             message: None,
             message_timer: 0,
             remote_origin: None,
+            read_only: false,
+            elevated_save: false,
         }
     }
 
@@ -430,6 +451,11 @@ impl EditorState {
         self.selection = None;
         self.find_mode = FindReplaceMode::None;
         self.find_error = None;
+        self.marks.clear();
+        self.mark_prompt = None;
+        // 새 파일은 저장 시점에 판단
+        self.read_only = path.exists() && !crate::services::elevate::is_writable(path);
+        self.elevated_save = false;
 
         // 파일 읽기
         match fs::read_to_string(path) {
@@ -451,6 +477,7 @@ impl EditorState {
         // 언어 감지
         self.language = Language::from_extension(path);
         self.highlighter = Some(SyntaxHighlighter::new(self.language, self.syntax_colors));
+        self.highlight_cache.reset();
 
         Ok(())
     }
@@ -483,9 +510,15 @@ impl EditorState {
         let temp_path = actual_path.with_extension("tmp");
 
         // Write to temporary file
-        fs::write(&temp_path, &content).map_err(|e| {
-            format!("Failed to write temporary file: {}", e)
-        })?;
+        if let Err(e) = fs::write(&temp_path, &content) {
+            // Directory not writable but the file is (e.g. a user-owned file in /etc): write in place
+            if e.kind() == std::io::ErrorKind::PermissionDenied && crate::services::elevate::is_writable(&actual_path) {
+                fs::write(&actual_path, &content).map_err(|e| format!("Failed to save file: {}", e))?;
+                self.mark_saved();
+                return Ok(());
+            }
+            return Err(format!("Failed to write temporary file: {}", e));
+        }
 
         // Restore original permissions on temp file before rename
         #[cfg(unix)]
@@ -500,9 +533,29 @@ impl EditorState {
             format!("Failed to save file: {}", e)
         })?;
 
+        self.mark_saved();
+        Ok(())
+    }
+
+    /// 크래시 저널에 백업된 저장되지 않은 내용으로 버퍼 교체 (원본과 다르면 modified)
+    pub fn restore_buffer(&mut self, content: &str) {
+        self.lines = content.split('\n').map(String::from).collect();
+        if self.lines.is_empty() {
+            self.lines.push(String::new());
+        }
+        self.cursor_line = 0;
+        self.cursor_col = 0;
+        self.scroll = 0;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.highlight_cache.reset();
+        self.update_modified();
+    }
+
+    /// 현재 내용을 저장된 상태로 표시
+    pub fn mark_saved(&mut self) {
         self.modified = false;
         self.original_lines = self.lines.clone();
-        Ok(())
     }
 
     /// 현재 상태와 원본을 비교하여 modified 플래그 업데이트
@@ -536,11 +589,35 @@ impl EditorState {
             }
         }
 
+        Self::report_changed_lines(&mut self.highlight_cache, &action);
         self.undo_memory_usage += action_size;
         self.undo_stack.push_back(action);
         self.modified = true;
     }
 
+    /// 적용된 액션이 바꾼 줄을 문법 강조 캐시에 알림 (변경 범위 아래는 상태가 같아지면 재계산 중단)
+    fn report_changed_lines(cache: &mut HighlightCache, action: &EditAction) {
+        match action {
+            EditAction::Insert { line, .. }
+            | EditAction::Delete { line, .. }
+            | EditAction::Replace { line, .. } => cache.lines_changed(*line, 1, 1),
+            EditAction::InsertLine { line, .. } => cache.lines_changed(*line, 0, 1),
+            EditAction::DeleteLine { line, .. } => cache.lines_changed(*line, 1, 0),
+            EditAction::MergeLine { line, .. } => cache.lines_changed(*line, 2, 1),
+            EditAction::SplitLine { line, .. } => cache.lines_changed(*line, 1, 2),
+            EditAction::SwapLines { line1, line2 } => {
+                let span = line1.abs_diff(*line2) + 1;
+                cache.lines_changed(*line1.min(line2), span, span);
+            }
+            // 하위 액션은 적용된 순서대로
+            EditAction::Batch { actions } => {
+                for a in actions {
+                    Self::report_changed_lines(cache, a);
+                }
+            }
+        }
+    }
+
     /// Undo 실행
     pub fn undo(&mut self) {
         if let Some(action) = self.undo_stack.pop_back() {
@@ -549,6 +626,7 @@ impl EditorState {
 
             let reverse = self.reverse_action(&action);
             self.apply_action(&reverse, false);
+            Self::report_changed_lines(&mut self.highlight_cache, &reverse);
 
             self.redo_memory_usage += action_size;
             self.redo_stack.push_back(action);
@@ -563,6 +641,7 @@ impl EditorState {
             self.redo_memory_usage = self.redo_memory_usage.saturating_sub(action_size);
 
             self.apply_action(&action, false);
+            Self::report_changed_lines(&mut self.highlight_cache, &action);
 
             self.undo_memory_usage += action_size;
             self.undo_stack.push_back(action);
@@ -1544,18 +1623,64 @@ impl EditorState {
         }
     }
 
-    /// 줄 번호로 이동
-    pub fn goto_line(&mut self, line_str: &str) {
-        if let Ok(line_num) = line_str.parse::<usize>() {
-            if line_num > 0 && line_num <= self.lines.len() {
-                self.cursor_line = line_num - 1;
-                self.cursor_col = 0;
-                self.selection = None;
-                self.update_scroll();
-            }
+    /// 줄 번호로 이동 ("줄" 또는 "줄:열", 범위를 벗어나면 마지막 줄/열로 보정)
+    /// 잘못된 입력이면 false 반환
+    pub fn goto_line(&mut self, line_str: &str) -> bool {
+        let (line_part, col_part) = match line_str.split_once(':') {
+            Some((l, c)) => (l, Some(c)),
+            None => (line_str, None),
+        };
+        let Ok(line_num) = line_part.trim().parse::<usize>() else { return false };
+        let col_num = match col_part.map(|c| c.trim()).filter(|c| !c.is_empty()) {
+            Some(c) => match c.parse::<usize>() {
+                Ok(c) => c,
+                Err(_) => return false,
+            },
+            None => 1,
+        };
+        if line_num == 0 || col_num == 0 {
+            return false;
+        }
+        let last_line = self.lines.len().saturating_sub(1);
+        self.cursor_line = (line_num - 1).min(last_line);
+        let line_len = self.lines.get(self.cursor_line).map(|l| l.chars().count()).unwrap_or(0);
+        self.cursor_col = (col_num - 1).min(line_len);
+        self.selection = None;
+        self.update_scroll();
+        true
+    }
+
+    /// 지정 위치로 커서 이동 (1부터 시작하는 줄/열, 범위를 벗어나면 보정)
+    /// 파일을 열자마자 호출되므로 커서 줄이 화면 가운데 오도록 스크롤
+    pub fn goto_position(&mut self, line: usize, col: usize) {
+        let last_line = self.lines.len().saturating_sub(1);
+        self.cursor_line = line.saturating_sub(1).min(last_line);
+        let line_len = self.lines.get(self.cursor_line).map(|l| l.chars().count()).unwrap_or(0);
+        self.cursor_col = col.saturating_sub(1).min(line_len);
+        self.selection = None;
+        self.scroll = self.cursor_line.saturating_sub(self.visible_height / 2);
+        self.update_scroll();
+    }
+
+    /// 커서 줄에 마크 설정
+    pub fn set_mark(&mut self, name: char) {
+        self.marks.insert(name, self.cursor_line);
+        self.set_message(format!("Mark '{}' set at line {}", name, self.cursor_line + 1), 30);
+    }
+
+    /// 마크 줄로 이동 (편집으로 줄이 줄었으면 마지막 줄로)
+    pub fn jump_to_mark(&mut self, name: char) {
+        match self.marks.get(&name) {
+            Some(&line) => self.goto_position(line + 1, 1),
+            None => self.set_message(format!("Mark '{}' not set", name), 30),
         }
     }
 
+    /// 해당 줄에 설정된 마크
+    pub fn mark_at(&self, line: usize) -> Option<char> {
+        self.marks.iter().find(|(_, &l)| l == line).map(|(&name, _)| name)
+    }
+
     /// 문자가 단어 문자인지 확인
     fn is_word_char(c: char) -> bool {
         c.is_alphanumeric() || c == '_'
@@ -2112,95 +2237,112 @@ impl EditorState {
         }
     }
 
-    /// 언어별 주석 문자 가져오기
-    fn get_comment_string(&self) -> Option<&'static str> {
-        match self.language {
-            Language::Rust | Language::C | Language::Cpp |
-            Language::Java | Language::JavaScript | Language::TypeScript |
-            Language::Go | Language::Swift | Language::Kotlin => Some("//"),
-
-            Language::Python | Language::Shell | Language::Ruby |
-            Language::Yaml | Language::Toml => Some("#"),
-
-            Language::Sql => Some("--"),
-
-            Language::Html | Language::Xml | Language::Css => None,
-
-            Language::Php => Some("//"),
-
-            Language::Markdown | Language::Json | Language::Plain => Some("//"),
-        }
-    }
-
     /// 주석 토글 (Ctrl+/)
+    /// 현재 줄 또는 선택된 줄들을 언어의 주석 문법으로 주석 처리/해제.
+    /// 주석 기호는 들여쓰기 뒤에 붙이고, 여러 줄이면 가장 얕은 들여쓰기에 맞춤 (빈 줄은 건너뜀)
     pub fn toggle_comment(&mut self) {
-        let comment = match self.get_comment_string() {
-            Some(c) => c,
-            None => return,
+        let Some(style) = self.language.comment_style() else {
+            self.set_message(format!("No comment syntax for {}", self.language.name()), 30);
+            return;
         };
-        let comment_with_space = format!("{} ", comment);
 
-        if let Some(sel) = &self.selection {
-            let (start_line, _, mut end_line, end_col) = sel.normalized();
-            // 블록 커서: end_col = cursor + 1이므로 end_col <= 1이면 cursor가 라인 시작(col 0)
-            // 여러 줄 선택에서 마지막 라인에 실제 선택된 문자가 없으므로 제외
-            if end_col <= 1 && end_line > start_line {
-                end_line -= 1;
+        let (start_line, end_line) = match &self.selection {
+            Some(sel) => {
+                let (start_line, _, mut end_line, end_col) = sel.normalized();
+                // 블록 커서: end_col = cursor + 1이므로 end_col <= 1이면 cursor가 라인 시작(col 0)
+                // 여러 줄 선택에서 마지막 라인에 실제 선택된 문자가 없으므로 제외
+                if end_col <= 1 && end_line > start_line {
+                    end_line -= 1;
+                }
+                (start_line, end_line.min(self.lines.len().saturating_sub(1)))
             }
-            let mut actions = Vec::new();
+            None => (self.cursor_line, self.cursor_line),
+        };
 
-            // 모든 줄이 주석인지 확인 (라인 맨 앞 기준)
-            let all_commented = (start_line..=end_line).all(|i| {
-                self.lines[i].starts_with(&comment_with_space) || self.lines[i].starts_with(comment)
-            });
+        let is_blank = |line: &str| line.trim().is_empty();
+        let indent_of = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
 
-            for line_idx in start_line..=end_line {
-                let old_content = self.lines[line_idx].clone();
+        let targets: Vec<usize> = (start_line..=end_line)
+            .filter(|&i| !is_blank(&self.lines[i]) || start_line == end_line)
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
 
-                let new_content = if all_commented {
-                    // 주석 제거 (라인 맨 앞에서)
-                    if old_content.starts_with(&comment_with_space) {
-                        old_content[comment_with_space.len()..].to_string()
-                    } else if old_content.starts_with(comment) {
-                        old_content[comment.len()..].to_string()
-                    } else {
-                        old_content.clone()
+        let all_commented = targets.iter().all(|&i| {
+            let body = self.lines[i].trim_start_matches([' ', '\t']);
+            match style {
+                CommentStyle::Line(prefix) => body.starts_with(prefix),
+                CommentStyle::Block(open, close) => {
+                    body.starts_with(open) && body.trim_end().ends_with(close) && body.trim_end().len() >= open.len() + close.len()
+                }
+            }
+        });
+        let min_indent = targets.iter()
+            .filter(|&&i| !is_blank(&self.lines[i]))
+            .map(|&i| indent_of(&self.lines[i]))
+            .min()
+            .unwrap_or(0);
+
+        let mut actions = Vec::new();
+        let mut cursor_shift: isize = 0;
+        for &line_idx in &targets {
+            let old_content = self.lines[line_idx].clone();
+            let (new_content, at, delta) = if all_commented {
+                let indent = indent_of(&old_content);
+                let body = &old_content[indent..];
+                let (inner, removed_front) = match style {
+                    CommentStyle::Line(prefix) => {
+                        let rest = &body[prefix.len()..];
+                        let rest_trimmed = rest.strip_prefix(' ').unwrap_or(rest);
+                        (rest_trimmed.to_string(), body.len() - rest_trimmed.len())
+                    }
+                    CommentStyle::Block(open, close) => {
+                        let trimmed = body.trim_end();
+                        let inner = &trimmed[open.len()..trimmed.len() - close.len()];
+                        let front = inner.strip_prefix(' ').unwrap_or(inner);
+                        let removed_front = open.len() + (inner.len() - front.len());
+                        let inner = front.strip_suffix(' ').unwrap_or(front);
+                        (inner.to_string(), removed_front)
                     }
-                } else {
-                    // 주석 추가 (라인 맨 앞에)
-                    format!("{}{}", comment_with_space, old_content)
                 };
-
-                self.lines[line_idx] = new_content.clone();
-                actions.push(EditAction::Replace {
-                    line: line_idx,
-                    old_content,
-                    new_content,
-                });
-            }
-
-            self.push_undo(EditAction::Batch { actions });
-        } else {
-            let old_content = self.lines[self.cursor_line].clone();
-
-            let new_content = if old_content.starts_with(&comment_with_space) {
-                // 주석 제거 (라인 맨 앞에서)
-                old_content[comment_with_space.len()..].to_string()
-            } else if old_content.starts_with(comment) {
-                old_content[comment.len()..].to_string()
+                (format!("{}{}", &old_content[..indent], inner), indent, -(removed_front as isize))
             } else {
-                // 주석 추가 (라인 맨 앞에)
-                format!("{}{}", comment_with_space, old_content)
+                let at = min_indent.min(old_content.len());
+                let (head, tail) = old_content.split_at(at);
+                let new_content = match style {
+                    CommentStyle::Line(prefix) => format!("{}{} {}", head, prefix, tail),
+                    CommentStyle::Block(open, close) => format!("{}{} {} {}", head, open, tail, close),
+                };
+                let added = match style {
+                    CommentStyle::Line(prefix) => prefix.len() + 1,
+                    CommentStyle::Block(open, _) => open.len() + 1,
+                };
+                (new_content, at, added as isize)
             };
 
-            self.lines[self.cursor_line] = new_content.clone();
-
-            self.push_undo(EditAction::Replace {
-                line: self.cursor_line,
+            if line_idx == self.cursor_line && self.cursor_col >= at {
+                cursor_shift = delta;
+            }
+            self.lines[line_idx] = new_content.clone();
+            actions.push(EditAction::Replace {
+                line: line_idx,
                 old_content,
                 new_content,
             });
         }
+
+        let line_len = self.lines[self.cursor_line].chars().count();
+        self.cursor_col = (self.cursor_col as isize + cursor_shift).max(0) as usize;
+        self.cursor_col = self.cursor_col.min(line_len);
+
+        if actions.len() == 1 {
+            if let Some(action) = actions.pop() {
+                self.push_undo(action);
+            }
+        } else {
+            self.push_undo(EditAction::Batch { actions });
+        }
     }
 }
 
@@ -2254,12 +2396,19 @@ pub fn draw(frame: &mut Frame, state: &mut EditorState, area: Rect, theme: &Them
             theme.header_style(),
         ),
         remote_span,
+        if state.read_only {
+            Span::styled("[Read-only] ", Style::default().fg(theme.editor.read_only_mark))
+        } else if state.elevated_save {
+            Span::styled("[sudo] ", Style::default().fg(theme.editor.sudo_mark))
+        } else {
+            Span::raw("")
+        },
         Span::styled(
             format!("[{}] ", state.language.name()),
             theme.dim_style(),
         ),
         Span::styled(
-            format!("Ln {}, Col {} ", state.cursor_line + 1, state.cursor_visual_col() + 1),
+            format!("Ln {}/{}, Col {} ", state.cursor_line + 1, state.lines.len().max(1), state.cursor_visual_col() + 1),
             theme.dim_style(),
         ),
         if !state.undo_stack.is_empty() {
@@ -2294,14 +2443,18 @@ pub fn draw(frame: &mut Frame, state: &mut EditorState, area: Rect, theme: &Them
     // 선택 영역 정규화
     let selection = state.selection.as_ref().map(|s| s.normalized());
 
-    // 하이라이터
-    let mut highlighter = state.highlighter.clone();
-    if let Some(ref mut hl) = highlighter {
-        hl.reset();
-        for line in state.lines.iter().take(state.scroll) {
-            hl.tokenize_line(line);
-        }
+    // 하이라이터: 화면 첫 줄의 상태는 백그라운드에서 계산된 값 사용
+    // (아직 계산되지 않았으면 강조 없이 바로 표시)
+    if let Some(hl) = state.highlighter {
+        state.highlight_cache.update(&state.lines, hl);
     }
+    let mut highlighter = match (state.highlighter, state.highlight_cache.state_at(state.scroll)) {
+        (Some(mut hl), Some(line_state)) => {
+            hl.set_line_state(line_state);
+            Some(hl)
+        }
+        _ => None,
+    };
 
     if state.word_wrap {
         // Word wrap 모드: 논리적 줄을 시각적 세그먼트로 분할하여 렌더링
@@ -2357,15 +2510,16 @@ pub fn draw(frame: &mut Frame, state: &mut EditorState, area: Rect, theme: &Them
                     Style::default().fg(theme.editor.line_number)
                 };
 
+                // 이어지는 세그먼트는 줄 번호 대신 연속 표시자
                 let line_num_span = if is_first {
                     Span::styled(
-                        format!("{:>width$} ", line_idx + 1, width = line_num_width),
+                        format!("{:>width$}{}", line_idx + 1, state.mark_at(line_idx).unwrap_or(' '), width = line_num_width),
                         line_num_style,
                     )
                 } else {
                     Span::styled(
-                        format!("{:>width$} ", "", width = line_num_width),
-                        line_num_style,
+                        format!("{:>width$} ", WRAP_CONTINUATION_MARKER, width = line_num_width),
+                        Style::default().fg(theme.editor.wrap_indicator),
                     )
                 };
 
@@ -2415,7 +2569,7 @@ pub fn draw(frame: &mut Frame, state: &mut EditorState, area: Rect, theme: &Them
             };
 
             let line_num_span = Span::styled(
-                format!("{:>width$} ", line_num + 1, width = line_num_width),
+                format!("{:>width$}{}", line_num + 1, state.mark_at(line_num).unwrap_or(' '), width = line_num_width),
                 line_num_style,
             );
 
@@ -2474,9 +2628,24 @@ pub fn draw(frame: &mut Frame, state: &mut EditorState, area: Rect, theme: &Them
 
     match state.find_mode {
         FindReplaceMode::None => {
-            if state.goto_mode {
+            if let Some(prompt) = state.mark_prompt {
+                let mark_line = Line::from(match prompt {
+                    MarkPrompt::Set => vec![
+                        Span::styled("Set mark (a-z, A-Z): ", theme.header_style()),
+                        Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+                    ],
+                    MarkPrompt::Jump => vec![
+                        Span::styled("Jump to mark: ", theme.header_style()),
+                        Span::styled(format_marks(&state.marks), theme.dim_style()),
+                    ],
+                });
+                frame.render_widget(
+                    Paragraph::new(mark_line).style(theme.status_bar_style()),
+                    Rect::new(inner.x, footer_y, inner.width, 1),
+                );
+            } else if state.goto_mode {
                 let goto_line = Line::from(vec![
-                    Span::styled("Go to line: ", theme.header_style()),
+                    Span::styled(format!("Go to line (1-{})[:col]: ", state.lines.len().max(1)), theme.header_style()),
                     Span::styled(&state.goto_input, theme.normal_style()),
                     Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
                 ]);
@@ -2484,6 +2653,20 @@ pub fn draw(frame: &mut Frame, state: &mut EditorState, area: Rect, theme: &Them
                     Paragraph::new(goto_line).style(theme.status_bar_style()),
                     Rect::new(inner.x, footer_y, inner.width, 1),
                 );
+            } else if state.read_only {
+                // 쓰기 권한이 없는 파일: 읽기 전용 배너
+                let banner = Line::from(vec![
+                    Span::styled(" READ-ONLY ", Style::default().fg(theme.editor.read_only_banner_text).bg(theme.editor.read_only_banner_bg)),
+                    Span::styled(" No write permission  ", theme.dim_style()),
+                    Span::styled(kb.editor_first_key(EditorAction::SudoSave), theme.header_style()),
+                    Span::styled(":edit & save with sudo ", theme.dim_style()),
+                    Span::styled(kb.editor_first_key(EditorAction::Exit), theme.header_style()),
+                    Span::styled(":exit", theme.dim_style()),
+                ]);
+                frame.render_widget(
+                    Paragraph::new(banner).style(theme.status_bar_style()),
+                    Rect::new(inner.x, footer_y, inner.width, 1),
+                );
             } else {
                 let mut footer_spans = vec![];
 
@@ -2842,12 +3025,37 @@ fn render_editor_line(
     spans
 }
 
+/// 내용을 바꾸는 동작 (읽기 전용 모드에서 차단)
+fn is_editing_action(action: EditorAction) -> bool {
+    matches!(
+        action,
+        EditorAction::Cut
+            | EditorAction::Paste
+            | EditorAction::Undo
+            | EditorAction::Redo
+            | EditorAction::DeleteLine
+            | EditorAction::DuplicateLine
+            | EditorAction::ToggleComment
+            | EditorAction::Indent
+            | EditorAction::InsertLineBelow
+            | EditorAction::InsertLineAbove
+            | EditorAction::DeleteWordBackward
+            | EditorAction::DeleteWordForward
+            | EditorAction::Replace
+            | EditorAction::MoveLineUp
+            | EditorAction::MoveLineDown
+    )
+}
+
 /// Handle paste event for file editor
 pub fn handle_paste(app: &mut App, text: &str) {
     let state = match &mut app.editor_state {
         Some(s) => s,
         None => return,
     };
+    if state.read_only {
+        return;
+    }
     if !text.is_empty() {
         // Normalize line endings
         let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
@@ -2870,9 +3078,11 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                 state.goto_input.clear();
             }
             KeyCode::Enter => {
-                state.goto_line(&state.goto_input.clone());
+                let input = std::mem::take(&mut state.goto_input);
+                if !input.is_empty() && !state.goto_line(&input) {
+                    state.set_message(format!("Invalid position: {}", input), 30);
+                }
                 state.goto_mode = false;
-                state.goto_input.clear();
             }
             KeyCode::Backspace => {
                 state.goto_input.pop();
@@ -2880,11 +3090,28 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
             KeyCode::Char(c) if c.is_ascii_digit() => {
                 state.goto_input.push(c);
             }
+            KeyCode::Char(':') if !state.goto_input.is_empty() && !state.goto_input.contains(':') => {
+                state.goto_input.push(':');
+            }
             _ => {}
         }
         return;
     }
 
+    // 마크 키 다음 글자
+    if let Some(prompt) = state.mark_prompt.take() {
+        state.pending_exit = false;
+        match code {
+            KeyCode::Char(c) if is_mark_name(c) => match prompt {
+                MarkPrompt::Set => state.set_mark(c),
+                MarkPrompt::Jump => state.jump_to_mark(c),
+            },
+            KeyCode::Esc => {}
+            _ => state.set_message("Mark names are letters (a-z, A-Z)", 30),
+        }
+        return;
+    }
+
     // Find/Replace 모드 (텍스트 입력 모드이므로 pending_exit 리셋)
     if state.find_mode != FindReplaceMode::None {
         state.pending_exit = false;
@@ -3027,6 +3254,19 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
         return;
     }
 
+    // 읽기 전용: 내용을 바꾸는 입력은 막고 안내
+    if state.read_only {
+        let blocked = match app.keybindings.editor_action(code, modifiers) {
+            Some(action) => is_editing_action(action),
+            None => matches!(code, KeyCode::Char(_) | KeyCode::Enter | KeyCode::Tab | KeyCode::BackTab | KeyCode::Backspace | KeyCode::Delete),
+        };
+        if blocked {
+            let key = app.keybindings.editor_first_key(EditorAction::SudoSave);
+            state.set_message(format!("Read-only file. Press {} to edit and save with elevated privileges", key), 50);
+            return;
+        }
+    }
+
     // EditorAction 조회 (Ctrl/Alt 조합 및 Esc)
     if let Some(action) = app.keybindings.editor_action(code, modifiers) {
         // Exit 이외의 action은 pending_exit 리셋
@@ -3036,6 +3276,10 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
 
         match action {
             EditorAction::Save => {
+                if state.elevated_save {
+                    app.save_editor_elevated();
+                    return;
+                }
                 let save_result = state.save_file();
                 let is_settings = App::is_settings_file(&state.file_path);
                 let remote_info = state.remote_origin.as_ref().map(|o| {
@@ -3052,7 +3296,15 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                         }
                     }
                     Err(e) => {
-                        state.set_message(format!("Save error: {}", e), 50);
+                        let denied = state.remote_origin.is_none()
+                            && state.file_path.exists()
+                            && !crate::services::elevate::is_writable(&state.file_path);
+                        if denied {
+                            let key = app.keybindings.editor_first_key(EditorAction::SudoSave);
+                            state.set_message(format!("Permission denied. Press {} to save with elevated privileges", key), 50);
+                        } else {
+                            state.set_message(format!("Save error: {}", e), 50);
+                        }
                         return;
                     }
                 }
@@ -3121,6 +3373,31 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                 }
                 app.refresh_panels();
             }
+            EditorAction::SudoSave => {
+                if state.remote_origin.is_some() {
+                    state.set_message("Not available for remote files", 30);
+                } else if state.read_only {
+                    // 첫 단계: 편집 잠금 해제, 이후 저장은 권한 상승으로
+                    state.read_only = false;
+                    state.elevated_save = true;
+                    let save_key = app.keybindings.editor_first_key(EditorAction::Save);
+                    state.set_message(format!("Editing enabled. {} saves with elevated privileges", save_key), 50);
+                } else {
+                    state.elevated_save = true;
+                    app.save_editor_elevated();
+                }
+            }
+            EditorAction::SetMark => {
+                state.mark_prompt = Some(MarkPrompt::Set);
+            }
+            EditorAction::JumpToMark => {
+                if state.marks.is_empty() {
+                    let key = app.keybindings.editor_first_key(EditorAction::SetMark);
+                    state.set_message(format!("No marks. Press {} and a letter to set one", key), 30);
+                } else {
+                    state.mark_prompt = Some(MarkPrompt::Jump);
+                }
+            }
             EditorAction::Cut => {
                 state.cut_line_or_selection();
             }
@@ -3248,9 +3525,11 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                     // 변경사항이 있을 때
                     if state.pending_exit {
                         // 두 번째 Esc: 변경 무시하고 종료
+                        let scroll = state.scroll;
+                        app.record_recent_file();
                         if let Some(Screen::FileViewer) = app.previous_screen {
                             if let Some(ref mut viewer) = app.viewer_state {
-                                viewer.scroll = state.scroll;
+                                viewer.scroll = scroll;
                             }
                             app.previous_screen = None;
                             app.current_screen = Screen::FileViewer;
@@ -3266,9 +3545,10 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                     }
                 } else {
                     // 변경사항 없으면 바로 종료
+                    let scroll = state.scroll;
+                    app.record_recent_file();
                     if let Some(Screen::FileViewer) = app.previous_screen {
                         if let Some(ref mut viewer) = app.viewer_state {
-                            let scroll = state.scroll;
                             let path = viewer.file_path.clone();
                             let _ = viewer.load_file(&path);
                             viewer.scroll = scroll;
@@ -3354,3 +3634,75 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goto_line_and_column() {
+        let mut state = EditorState::new();
+        state.lines = vec!["first".to_string(), "second line".to_string(), "x".to_string()];
+
+        assert!(state.goto_line("2:4"));
+        assert_eq!((state.cursor_line, state.cursor_col), (1, 3));
+
+        // Out of range values are clamped to the last line / end of line
+        assert!(state.goto_line("99:99"));
+        assert_eq!((state.cursor_line, state.cursor_col), (2, 1));
+
+        assert!(state.goto_line("1:"));
+        assert_eq!((state.cursor_line, state.cursor_col), (0, 0));
+
+        assert!(!state.goto_line("0"));
+        assert!(!state.goto_line("abc"));
+        assert!(!state.goto_line("2:x"));
+    }
+
+    #[test]
+    fn test_marks() {
+        let mut state = EditorState::new();
+        state.lines = (0..10).map(|i| format!("line {}", i)).collect();
+        state.goto_position(7, 3);
+        state.set_mark('a');
+        assert_eq!(state.mark_at(6), Some('a'));
+
+        state.goto_position(1, 1);
+        state.jump_to_mark('a');
+        assert_eq!((state.cursor_line, state.cursor_col), (6, 0));
+
+        // A mark past the end (lines deleted since) lands on the last line
+        state.lines.truncate(3);
+        state.jump_to_mark('a');
+        assert_eq!(state.cursor_line, 2);
+
+        state.jump_to_mark('b');
+        assert_eq!(state.cursor_line, 2);
+        assert_eq!(format_marks(&state.marks), "a:7");
+    }
+
+    #[test]
+    fn test_toggle_comment_uses_language_syntax() {
+        let mut state = EditorState::new();
+        state.language = Language::Python;
+        state.lines = vec!["def f():".to_string(), "    x = 1".to_string(), "".to_string(), "    y = 2".to_string()];
+        state.selection = Some(Selection::new(1, 0));
+        if let Some(ref mut sel) = state.selection {
+            sel.end_line = 3;
+            sel.end_col = 5;
+        }
+
+        state.toggle_comment();
+        assert_eq!(state.lines, vec!["def f():", "    # x = 1", "", "    # y = 2"]);
+        state.toggle_comment();
+        assert_eq!(state.lines, vec!["def f():", "    x = 1", "", "    y = 2"]);
+
+        let mut html = EditorState::new();
+        html.language = Language::Html;
+        html.lines = vec!["  <p>hi</p>".to_string()];
+        html.toggle_comment();
+        assert_eq!(html.lines, vec!["  <!-- <p>hi</p> -->"]);
+        html.toggle_comment();
+        assert_eq!(html.lines, vec!["  <p>hi</p>"]);
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::{self, Receiver, Sender};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
+use std::time::SystemTime;
+use chrono::TimeZone;
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::Rect,
@@ -14,7 +17,11 @@ use ratatui::{
 };
 
 use super::{app::{App, Screen}, theme::Theme};
-use crate::utils::format::{format_size, format_permissions};
+use crate::services::{dir_sizes, file_ops, xattr};
+use crate::utils::format::{format_datetime, format_number, format_size, format_permissions, format_permissions_short};
+
+/// Timestamp format used for display and editing
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 
 /// Result of recursive directory calculation
 #[derive(Debug, Clone)]
@@ -22,14 +29,189 @@ pub struct DirCalcResult {
     pub total_size: u64,
     pub file_count: u64,
     pub dir_count: u64,
+    /// Breakdown gathered by a full scan (absent for cached results)
+    pub details: Option<DirCalcDetails>,
+}
+
+/// Symlink, hardlink and per-extension totals of a directory scan
+#[derive(Debug, Clone, Default)]
+pub struct DirCalcDetails {
+    /// Symlinks found (not followed, not counted in `file_count`)
+    pub symlink_count: u64,
+    /// Size of the files the symlinks point to
+    pub symlink_size: u64,
+    /// `total_size` with hardlinked files counted once
+    pub unique_size: u64,
+    /// `(extension, file count, size)` sorted by size, largest first.
+    /// Extensions are lowercased; files without one use an empty string.
+    pub extensions: Vec<(String, u64, u64)>,
+}
+
+/// Totals of one scanned directory level, merged into `DirCalcProgress`
+#[derive(Default)]
+struct LevelTotals {
+    total_size: u64,
+    file_count: u64,
+    symlink_count: u64,
+    symlink_size: u64,
+    unique_size: u64,
+    /// Hardlinked files (`(dev, ino)`, size), deduplicated on merge
+    hardlinks: Vec<((u64, u64), u64)>,
+    extensions: HashMap<String, (u64, u64)>,
+}
+
+/// Running totals updated by the worker threads, read by the UI while calculating
+#[derive(Debug, Default)]
+pub struct DirCalcProgress {
+    total_size: AtomicU64,
+    file_count: AtomicU64,
+    dir_count: AtomicU64,
+    symlink_count: AtomicU64,
+    symlink_size: AtomicU64,
+    unique_size: AtomicU64,
+    /// Hardlinked inodes already counted in `unique_size`
+    seen_inodes: Mutex<HashSet<(u64, u64)>>,
+    extensions: Mutex<HashMap<String, (u64, u64)>>,
 }
 
-/// State for async directory info calculation
+impl DirCalcProgress {
+    fn add(&self, level: LevelTotals, dir_count: u64) {
+        self.total_size.fetch_add(level.total_size, Ordering::Relaxed);
+        self.file_count.fetch_add(level.file_count, Ordering::Relaxed);
+        self.dir_count.fetch_add(dir_count, Ordering::Relaxed);
+        self.symlink_count.fetch_add(level.symlink_count, Ordering::Relaxed);
+        self.symlink_size.fetch_add(level.symlink_size, Ordering::Relaxed);
+
+        let mut unique_size = level.unique_size;
+        if !level.hardlinks.is_empty() {
+            if let Ok(mut seen) = self.seen_inodes.lock() {
+                for (inode, size) in level.hardlinks {
+                    if seen.insert(inode) {
+                        unique_size += size;
+                    }
+                }
+            }
+        }
+        self.unique_size.fetch_add(unique_size, Ordering::Relaxed);
+
+        if !level.extensions.is_empty() {
+            if let Ok(mut extensions) = self.extensions.lock() {
+                for (ext, (count, size)) in level.extensions {
+                    let entry = extensions.entry(ext).or_default();
+                    entry.0 += count;
+                    entry.1 += size;
+                }
+            }
+        }
+    }
+
+    /// Partial totals so far
+    pub fn snapshot(&self) -> DirCalcResult {
+        DirCalcResult {
+            total_size: self.total_size.load(Ordering::Relaxed),
+            file_count: self.file_count.load(Ordering::Relaxed),
+            dir_count: self.dir_count.load(Ordering::Relaxed),
+            details: None,
+        }
+    }
+
+    /// Final totals including the symlink/hardlink/extension breakdown
+    fn finish(&self) -> DirCalcResult {
+        let mut extensions: Vec<(String, u64, u64)> = self.extensions.lock()
+            .map(|m| m.iter().map(|(ext, &(count, size))| (ext.clone(), count, size)).collect())
+            .unwrap_or_default();
+        extensions.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| b.1.cmp(&a.1)).then_with(|| a.0.cmp(&b.0)));
+        DirCalcResult {
+            details: Some(DirCalcDetails {
+                symlink_count: self.symlink_count.load(Ordering::Relaxed),
+                symlink_size: self.symlink_size.load(Ordering::Relaxed),
+                unique_size: self.unique_size.load(Ordering::Relaxed),
+                extensions,
+            }),
+            ..self.snapshot()
+        }
+    }
+}
+
+/// Editable property, shown as an input line
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropKind {
+    Name,
+    /// Octal mode (e.g. 644)
+    Permissions,
+    /// `user:group` (names or numeric ids)
+    Owner,
+    /// Apply permissions and owner to the directory's contents too ("yes"/"no")
+    Recursive,
+    Modified,
+    Accessed,
+    /// Existing extended attribute with a text value (empty value removes it)
+    Xattr(String),
+    /// New extended attribute entered as `name=value`
+    NewXattr,
+}
+
+#[derive(Debug, Clone)]
+pub struct PropField {
+    pub kind: PropKind,
+    pub original: String,
+    pub value: String,
+    /// Cursor position in chars
+    pub cursor: usize,
+}
+
+impl PropField {
+    fn new(kind: PropKind, value: String) -> Self {
+        Self { kind, cursor: value.chars().count(), original: value.clone(), value }
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.value != self.original
+    }
+
+    fn byte_pos(&self) -> usize {
+        self.value.char_indices().nth(self.cursor).map(|(i, _)| i).unwrap_or(self.value.len())
+    }
+
+    fn insert(&mut self, c: char) {
+        let pos = self.byte_pos();
+        self.value.insert(pos, c);
+        self.cursor += 1;
+    }
+
+    fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            let pos = self.byte_pos();
+            self.value.remove(pos);
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.cursor < self.value.chars().count() {
+            let pos = self.byte_pos();
+            self.value.remove(pos);
+        }
+    }
+}
+
+/// State for the file properties dialog (editable fields + async directory calculation)
 pub struct FileInfoState {
     pub is_calculating: bool,
     pub result: Option<DirCalcResult>,
     pub cancel_flag: Arc<AtomicBool>,
+    pub progress: Arc<DirCalcProgress>,
     receiver: Option<Receiver<DirCalcResult>>,
+    pub fields: Vec<PropField>,
+    pub selected_field: usize,
+    /// Extended attributes with binary values (shown read-only): name, size
+    pub binary_xattrs: Vec<(String, usize)>,
+    /// Image dimensions for image files
+    pub dimensions: Option<(u32, u32)>,
+    /// Error from the last apply
+    pub error: Option<String>,
+    /// `result` came from the directory size cache
+    pub cached: bool,
 }
 
 impl Default for FileInfoState {
@@ -38,7 +220,14 @@ impl Default for FileInfoState {
             is_calculating: false,
             result: None,
             cancel_flag: Arc::new(AtomicBool::new(false)),
+            progress: Arc::new(DirCalcProgress::default()),
             receiver: None,
+            fields: Vec::new(),
+            selected_field: 0,
+            binary_xattrs: Vec::new(),
+            dimensions: None,
+            error: None,
+            cached: false,
         }
     }
 }
@@ -48,23 +237,98 @@ impl FileInfoState {
         Self::default()
     }
 
-    /// Start async directory calculation
-    pub fn start_calculation(&mut self, path: &Path) {
+    /// Read the editable properties of `path`
+    pub fn load_properties(&mut self, path: &Path) {
+        self.fields.clear();
+        self.binary_xattrs.clear();
+        self.selected_field = 0;
+        self.error = None;
+        let Ok(meta) = fs::metadata(path) else { return };
+
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        self.fields.push(PropField::new(PropKind::Name, name));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            use std::os::unix::fs::MetadataExt;
+            let mode = meta.permissions().mode() & 0o7777;
+            self.fields.push(PropField::new(PropKind::Permissions, format!("{:03o}", mode)));
+            let user = file_ops::user_name(meta.uid()).unwrap_or_else(|| meta.uid().to_string());
+            let group = file_ops::group_name(meta.gid()).unwrap_or_else(|| meta.gid().to_string());
+            self.fields.push(PropField::new(PropKind::Owner, format!("{}:{}", user, group)));
+            if meta.is_dir() {
+                self.fields.push(PropField::new(PropKind::Recursive, "no".to_string()));
+            }
+        }
+        if let Ok(modified) = meta.modified() {
+            self.fields.push(PropField::new(PropKind::Modified, format_time(modified)));
+        }
+        if let Ok(accessed) = meta.accessed() {
+            self.fields.push(PropField::new(PropKind::Accessed, format_time(accessed)));
+        }
+
+        if xattr::is_supported() {
+            for (name, value) in xattr::list(path).unwrap_or_default() {
+                match String::from_utf8(value) {
+                    Ok(text) if !text.contains('\0') => {
+                        self.fields.push(PropField::new(PropKind::Xattr(name), text));
+                    }
+                    Ok(text) => self.binary_xattrs.push((name, text.len())),
+                    Err(e) => self.binary_xattrs.push((name, e.as_bytes().len())),
+                }
+            }
+            self.fields.push(PropField::new(PropKind::NewXattr, String::new()));
+        }
+
+        if meta.is_file() && super::image_viewer::is_image_file(path) {
+            self.dimensions = image::image_dimensions(path).ok();
+        }
+    }
+
+    /// Start async directory calculation.
+    /// With `use_cache`, a cached size still valid for the directory's mtime is shown instead.
+    pub fn start_calculation(&mut self, path: &Path, use_cache: bool) {
+        let mtime = dir_sizes::dir_mtime(path);
+        if use_cache {
+            if let Some(size) = mtime.and_then(|m| dir_sizes::get(path, m)) {
+                self.is_calculating = false;
+                self.result = Some(DirCalcResult {
+                    total_size: size.total_size,
+                    file_count: size.file_count,
+                    dir_count: size.dir_count,
+                    details: None,
+                });
+                self.cached = true;
+                return;
+            }
+        }
+
         // Reset state
         self.is_calculating = true;
         self.result = None;
+        self.cached = false;
         self.cancel_flag = Arc::new(AtomicBool::new(false));
+        self.progress = Arc::new(DirCalcProgress::default());
 
         let (tx, rx): (Sender<DirCalcResult>, Receiver<DirCalcResult>) = mpsc::channel();
         self.receiver = Some(rx);
 
         let path = path.to_path_buf();
         let cancel_flag = self.cancel_flag.clone();
+        let progress = self.progress.clone();
 
         thread::spawn(move || {
-            let result = calculate_dir_size_recursive(&path, &cancel_flag);
-            // Only send if not cancelled
+            let result = calculate_dir_size_parallel(&path, &cancel_flag, &progress);
+            // Only send (and cache) if not cancelled
             if !cancel_flag.load(Ordering::Relaxed) {
+                if let Some(mtime) = mtime {
+                    dir_sizes::insert(&path, mtime, dir_sizes::DirSize {
+                        total_size: result.total_size,
+                        file_count: result.file_count,
+                        dir_count: result.dir_count,
+                    });
+                    dir_sizes::save();
+                }
                 let _ = tx.send(result);
             }
         });
@@ -107,11 +371,78 @@ impl FileInfoState {
     }
 }
 
-/// Recursively calculate directory size with cancellation support
-fn calculate_dir_size_recursive(path: &Path, cancel_flag: &AtomicBool) -> DirCalcResult {
-    let mut total_size: u64 = 0;
-    let mut file_count: u64 = 0;
-    let mut dir_count: u64 = 0;
+/// Upper bound for size calculation workers (directory walks are I/O bound)
+const MAX_CALC_WORKERS: usize = 16;
+
+/// Directories waiting to be scanned, shared by all workers
+struct DirQueue {
+    pending: Vec<PathBuf>,
+    /// Directories currently being scanned (may still push subdirectories)
+    active: usize,
+}
+
+/// Calculate directory size using a pool of scoped threads sharing one work queue.
+/// Each worker scans a single directory level, adds its files to `progress`
+/// and pushes subdirectories back to the queue for any idle worker to pick up.
+fn calculate_dir_size_parallel(root: &Path, cancel_flag: &AtomicBool, progress: &DirCalcProgress) -> DirCalcResult {
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+        .clamp(2, MAX_CALC_WORKERS);
+    let queue = Mutex::new(DirQueue { pending: vec![root.to_path_buf()], active: 0 });
+    let work_ready = Condvar::new();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| calc_worker(&queue, &work_ready, cancel_flag, progress));
+        }
+    });
+
+    progress.finish()
+}
+
+fn calc_worker(queue: &Mutex<DirQueue>, work_ready: &Condvar, cancel_flag: &AtomicBool, progress: &DirCalcProgress) {
+    loop {
+        // Take the next directory, or stop when nothing is queued and nobody can add more
+        let dir = {
+            let Ok(mut q) = queue.lock() else { return };
+            loop {
+                if cancel_flag.load(Ordering::Relaxed) {
+                    work_ready.notify_all();
+                    return;
+                }
+                if let Some(dir) = q.pending.pop() {
+                    q.active += 1;
+                    break dir;
+                }
+                if q.active == 0 {
+                    work_ready.notify_all();
+                    return;
+                }
+                q = match work_ready.wait(q) {
+                    Ok(q) => q,
+                    Err(_) => return,
+                };
+            }
+        };
+
+        let subdirs = scan_dir_level(&dir, cancel_flag, progress);
+
+        let Ok(mut q) = queue.lock() else { return };
+        q.active -= 1;
+        let wake = !subdirs.is_empty() || q.active == 0;
+        q.pending.extend(subdirs);
+        drop(q);
+        if wake {
+            work_ready.notify_all();
+        }
+    }
+}
+
+/// Count the files of one directory and return its subdirectories
+fn scan_dir_level(path: &Path, cancel_flag: &AtomicBool, progress: &DirCalcProgress) -> Vec<PathBuf> {
+    let mut level = LevelTotals::default();
+    let mut subdirs = Vec::new();
 
     if let Ok(entries) = fs::read_dir(path) {
         for entry in entries.filter_map(|e| e.ok()) {
@@ -123,27 +454,270 @@ fn calculate_dir_size_recursive(path: &Path, cancel_flag: &AtomicBool) -> DirCal
             let entry_path = entry.path();
             if let Ok(metadata) = fs::symlink_metadata(&entry_path) {
                 if metadata.file_type().is_symlink() {
-                    // Symlink: count as file with size 0, don't follow
-                    file_count += 1;
+                    // Symlink: counted separately, don't follow into directories
+                    level.symlink_count += 1;
+                    if let Ok(target) = fs::metadata(&entry_path) {
+                        if target.is_file() {
+                            level.symlink_size += target.len();
+                        }
+                    }
                 } else if metadata.is_dir() {
-                    dir_count += 1;
-                    // Recursively calculate subdirectory
-                    let sub_result = calculate_dir_size_recursive(&entry_path, cancel_flag);
-                    total_size += sub_result.total_size;
-                    file_count += sub_result.file_count;
-                    dir_count += sub_result.dir_count;
+                    subdirs.push(entry_path);
                 } else {
-                    file_count += 1;
-                    total_size += metadata.len();
+                    let size = metadata.len();
+                    level.file_count += 1;
+                    level.total_size += size;
+
+                    let ext = entry_path.extension()
+                        .map(|e| e.to_string_lossy().to_lowercase())
+                        .unwrap_or_default();
+                    let ext_totals = level.extensions.entry(ext).or_default();
+                    ext_totals.0 += 1;
+                    ext_totals.1 += size;
+
+                    match hardlink_id(&metadata) {
+                        Some(inode) => level.hardlinks.push((inode, size)),
+                        None => level.unique_size += size,
+                    }
                 }
             }
         }
     }
 
-    DirCalcResult {
-        total_size,
-        file_count,
-        dir_count,
+    progress.add(level, subdirs.len() as u64);
+    subdirs
+}
+
+/// `(dev, ino)` of a file with more than one hard link
+#[cfg(unix)]
+fn hardlink_id(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    (metadata.nlink() > 1).then(|| (metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn hardlink_id(_metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+fn format_time(time: SystemTime) -> String {
+    let datetime: chrono::DateTime<chrono::Local> = time.into();
+    datetime.format(TIME_FORMAT).to_string()
+}
+
+/// 표시용 시각 (설정/로케일 형식). 편집 필드는 format_time 형식 유지
+fn display_time(time: SystemTime) -> String {
+    format_datetime(&time.into(), true)
+}
+
+fn parse_time(text: &str) -> Result<SystemTime, String> {
+    let invalid = || format!("Invalid time '{}' (use YYYY-MM-DD HH:MM:SS)", text);
+    let naive = chrono::NaiveDateTime::parse_from_str(text.trim(), TIME_FORMAT).map_err(|_| invalid())?;
+    chrono::Local.from_local_datetime(&naive)
+        .earliest()
+        .map(SystemTime::from)
+        .ok_or_else(invalid)
+}
+
+fn parse_mode(text: &str) -> Result<u32, String> {
+    u32::from_str_radix(text.trim(), 8)
+        .ok()
+        .filter(|mode| *mode <= 0o7777)
+        .ok_or_else(|| format!("Invalid permissions '{}' (use octal, e.g. 644)", text))
+}
+
+/// Toggle the r/w/x bit (`bit` = 4/2/1) of the permission class whose digit is
+/// under `cursor` (the last three digits are user, group, other)
+fn toggle_mode_bit(text: &str, cursor: usize, bit: u32) -> Option<String> {
+    let mode = parse_mode(text).ok()?;
+    let digits = text.trim().len().max(3);
+    let class = cursor.min(digits - 1).saturating_sub(digits - 3);
+    let mode = mode ^ (bit << ((2 - class) * 3));
+    Some(if mode > 0o777 { format!("{:04o}", mode) } else { format!("{:03o}", mode) })
+}
+
+/// Parse `user`, `user:group`, `:group` or `user:` (names or numeric ids)
+#[cfg(unix)]
+fn parse_owner(text: &str) -> Result<(Option<u32>, Option<u32>), String> {
+    let (user, group) = text.trim().split_once(':').unwrap_or((text.trim(), ""));
+    let user = match user {
+        "" => None,
+        name => Some(name.parse().ok().or_else(|| file_ops::user_id(name))
+            .ok_or_else(|| format!("Unknown user '{}'", name))?),
+    };
+    let group = match group {
+        "" => None,
+        name => Some(name.parse().ok().or_else(|| file_ops::group_id(name))
+            .ok_or_else(|| format!("Unknown group '{}'", name))?),
+    };
+    if user.is_none() && group.is_none() {
+        return Err("Owner must be user, user:group or :group".to_string());
+    }
+    Ok((user, group))
+}
+
+/// Changes validated up front, so bad input leaves the file untouched
+#[derive(Debug, Default)]
+struct PropChanges {
+    new_name: Option<String>,
+    mode: Option<u32>,
+    owner: Option<(Option<u32>, Option<u32>)>,
+    recursive: bool,
+    modified: Option<SystemTime>,
+    accessed: Option<SystemTime>,
+    set_xattrs: Vec<(String, String)>,
+    remove_xattrs: Vec<String>,
+}
+
+fn collect_changes(fields: &[PropField]) -> Result<PropChanges, String> {
+    let mut changes = PropChanges::default();
+    for field in fields.iter().filter(|f| f.is_changed()) {
+        match field.kind {
+            PropKind::Name => {
+                file_ops::is_valid_filename(&field.value).map_err(|e| e.to_string())?;
+                changes.new_name = Some(field.value.clone());
+            }
+            PropKind::Permissions => changes.mode = Some(parse_mode(&field.value)?),
+            #[cfg(unix)]
+            PropKind::Owner => changes.owner = Some(parse_owner(&field.value)?),
+            #[cfg(not(unix))]
+            PropKind::Owner => {}
+            PropKind::Recursive => changes.recursive = field.value == "yes",            PropKind::Modified => changes.modified = Some(parse_time(&field.value)?),
+            PropKind::Accessed => changes.accessed = Some(parse_time(&field.value)?),
+            PropKind::Xattr(ref name) if field.value.is_empty() => changes.remove_xattrs.push(name.clone()),
+            PropKind::Xattr(ref name) => changes.set_xattrs.push((name.clone(), field.value.clone())),
+            PropKind::NewXattr => {
+                let (name, value) = field.value.split_once('=')
+                    .filter(|(name, _)| !name.trim().is_empty())
+                    .ok_or("New attribute must be name=value")?;
+                changes.set_xattrs.push((name.trim().to_string(), value.to_string()));
+            }
+        }
+    }
+    // A recursive apply sets the shown mode on the contents even when it was not edited
+    if changes.recursive && changes.mode.is_none() {
+        if let Some(field) = fields.iter().find(|f| f.kind == PropKind::Permissions) {
+            changes.mode = Some(parse_mode(&field.value)?);
+        }
+    }
+    Ok(changes)
+}
+
+/// Apply the changed fields to `path`. Returns the new name if the entry was renamed.
+pub fn apply_properties(path: &Path, fields: &[PropField]) -> Result<Option<String>, String> {
+    let changes = collect_changes(fields)?;
+
+    #[cfg(unix)]
+    if let Some(mode) = changes.mode {
+        file_ops::set_permissions(path, mode, changes.recursive)
+            .map_err(|e| format!("Permissions: {}", e))?;
+    }
+
+    #[cfg(unix)]
+    if let Some((uid, gid)) = changes.owner {
+        file_ops::set_owner(path, uid, gid, changes.recursive)
+            .map_err(|e| format!("Owner: {}", e))?;
+    }
+
+    if changes.modified.is_some() || changes.accessed.is_some() {
+        let mut times = fs::FileTimes::new();
+        if let Some(modified) = changes.modified {
+            times = times.set_modified(modified);
+        }
+        if let Some(accessed) = changes.accessed {
+            times = times.set_accessed(accessed);
+        }
+        fs::File::open(path)
+            .and_then(|f| f.set_times(times))
+            .map_err(|e| format!("Timestamps: {}", e))?;
+    }
+
+    for name in &changes.remove_xattrs {
+        xattr::remove(path, name).map_err(|e| format!("Attribute {}: {}", name, e))?;
+    }
+    for (name, value) in &changes.set_xattrs {
+        xattr::set(path, name, value.as_bytes()).map_err(|e| format!("Attribute {}: {}", name, e))?;
+    }
+
+    // Rename last: everything above uses the old path
+    if let Some(ref new_name) = changes.new_name {
+        file_ops::rename_file(path, &path.with_file_name(new_name))
+            .map_err(|e| format!("Rename: {}", e))?;
+    }
+    Ok(changes.new_name)
+}
+
+/// Input line for an editable property; the selected one shows a cursor
+fn field_line(label: String, field: &PropField, selected: bool, label_style: Style, value_style: Style) -> Line<'static> {
+    let marker = if field.is_changed() { "*" } else { " " };
+    let label_style = if selected { label_style.add_modifier(Modifier::BOLD) } else { label_style };
+    let label_span = Span::styled(format!("{}{}", label, marker), label_style);
+    if !selected {
+        return Line::from(vec![label_span, Span::styled(field.value.clone(), value_style)]);
+    }
+
+    let chars: Vec<char> = field.value.chars().collect();
+    let cursor = field.cursor.min(chars.len());
+    let before: String = chars[..cursor].iter().collect();
+    let at = chars.get(cursor).map(|c| c.to_string()).unwrap_or_else(|| " ".to_string());
+    let after: String = chars.iter().skip(cursor + 1).collect();
+    let edit_style = value_style.add_modifier(Modifier::UNDERLINED);
+    Line::from(vec![
+        label_span,
+        Span::styled(before, edit_style),
+        Span::styled(at, value_style.add_modifier(Modifier::REVERSED)),
+        Span::styled(after, edit_style),
+    ])
+}
+
+/// 확장자별로 표시할 최대 줄 수 (나머지는 "others"로 합침)
+const MAX_EXTENSION_LINES: usize = 8;
+
+/// 심볼릭 링크 / 하드링크 중복 제거 크기 / 확장자별 합계 줄 추가
+fn push_calc_details(
+    lines: &mut Vec<Line<'_>>,
+    details: &DirCalcDetails,
+    total_size: u64,
+    label_style: Style,
+    size_style: Style,
+    hint_style: Style,
+) {
+    if details.symlink_count > 0 {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:12}", "Symlinks"), label_style),
+            Span::styled(format_number(details.symlink_count), size_style),
+            Span::styled(format!("  ({} linked files, not included)", format_size(details.symlink_size)), hint_style),
+        ]));
+    }
+    if details.unique_size != total_size {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:12}", "Unique Size"), label_style),
+            Span::styled(format_size(details.unique_size), size_style),
+            Span::styled("  (hardlinks counted once)", hint_style),
+        ]));
+    }
+    if details.extensions.is_empty() {
+        return;
+    }
+
+    lines.push(Line::from(Span::styled(format!("{:12}", "By Type"), label_style)));
+    let ext_label = |ext: &str| if ext.is_empty() { "(none)".to_string() } else { format!(".{}", ext) };
+    for (ext, count, size) in details.extensions.iter().take(MAX_EXTENSION_LINES) {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:10}", ext_label(ext)), label_style),
+            Span::styled(format!("{:>10}", format_size(*size)), size_style),
+            Span::styled(format!("  {} files", format_number(*count)), hint_style),
+        ]));
+    }
+    let rest = &details.extensions[details.extensions.len().min(MAX_EXTENSION_LINES)..];
+    if !rest.is_empty() {
+        let count: u64 = rest.iter().map(|e| e.1).sum();
+        let size: u64 = rest.iter().map(|e| e.2).sum();
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:10}", format!("+{} more", rest.len())), label_style),
+            Span::styled(format!("{:>10}", format_size(size)), size_style),
+            Span::styled(format!("  {} files", format_number(count)), hint_style),
+        ]));
     }
 }
 
@@ -178,16 +752,25 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
     let date_style = Style::default().fg(theme.file_info.value_date);
     let calc_style = Style::default().fg(theme.file_info.calculating_text);
     let spinner_style = Style::default().fg(theme.file_info.calculating_spinner);
+    let hint_style = Style::default().fg(theme.file_info.hint_text);
+
+    // Editable fields (replace the read-only value when present)
+    let fields: &[PropField] = app.file_info_state.as_ref().map(|s| s.fields.as_slice()).unwrap_or(&[]);
+    let selected_field = app.file_info_state.as_ref().map(|s| s.selected_field).unwrap_or(0);
+    let prop = |kind: PropKind, label: String, style: Style| -> Option<Line<'static>> {
+        let idx = fields.iter().position(|f| f.kind == kind)?;
+        Some(field_line(label, &fields[idx], idx == selected_field, label_style, style))
+    };
 
     if let Ok(meta) = metadata {
         let name = path.file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
 
-        lines.push(Line::from(vec![
+        lines.push(prop(PropKind::Name, format!("{:11}", "Name"), name_style).unwrap_or_else(|| Line::from(vec![
             Span::styled(format!("{:12}", "Name"), label_style),
             Span::styled(name, name_style),
-        ]));
+        ])));
         lines.push(Line::from(vec![
             Span::styled(format!("{:12}", "Path"), label_style),
             Span::styled(path.display().to_string(), path_style),
@@ -209,27 +792,41 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
         if meta.is_dir() {
             if let Some(ref state) = app.file_info_state {
                 if state.is_calculating {
-                    // Show spinner while calculating
+                    // Show spinner with partial totals while calculating
                     let spinner = get_spinner_frame();
+                    let partial = state.progress.snapshot();
                     lines.push(Line::from(vec![
                         Span::styled(format!("{:12}", "Total Size"), label_style),
                         Span::styled(format!("{}", spinner), spinner_style),
-                        Span::styled(" Calculating...", calc_style),
+                        Span::styled(format!(" {}", format_size(partial.total_size)), calc_style),
+                    ]));
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{:12}", "Files"), label_style),
+                        Span::styled(format!("{} / {} folders...", format_number(partial.file_count), format_number(partial.dir_count)), calc_style),
                     ]));
                 } else if let Some(ref result) = state.result {
                     // Show calculated results
+                    let recalc_key = app.keybindings.file_info_first_key(crate::keybindings::FileInfoAction::Recalculate);
                     lines.push(Line::from(vec![
                         Span::styled(format!("{:12}", "Total Size"), label_style),
                         Span::styled(format_size(result.total_size), size_style),
+                        if state.cached {
+                            Span::styled(format!("  (cached, {} to recalculate)", recalc_key), calc_style)
+                        } else {
+                            Span::raw("")
+                        },
                     ]));
                     lines.push(Line::from(vec![
                         Span::styled(format!("{:12}", "Files"), label_style),
-                        Span::styled(result.file_count.to_string(), size_style),
+                        Span::styled(format_number(result.file_count), size_style),
                     ]));
                     lines.push(Line::from(vec![
                         Span::styled(format!("{:12}", "Folders"), label_style),
-                        Span::styled(result.dir_count.to_string(), size_style),
+                        Span::styled(format_number(result.dir_count), size_style),
                     ]));
+                    if let Some(ref details) = result.details {
+                        push_calc_details(&mut lines, details, result.total_size, label_style, size_style, hint_style);
+                    }
                 } else {
                     // Calculation not started or cancelled
                     lines.push(Line::from(vec![
@@ -250,18 +847,50 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
             ]));
         }
 
+        if let Some((w, h)) = app.file_info_state.as_ref().and_then(|s| s.dimensions) {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:12}", "Dimensions"), label_style),
+                Span::styled(format!("{} x {}", w, h), value_style),
+            ]));
+        }
+
         #[cfg(unix)]
         {
             use std::os::unix::fs::MetadataExt;
             lines.push(Line::from(Span::raw("")));
-            lines.push(Line::from(vec![
-                Span::styled(format!("{:12}", "Permissions"), label_style),
-                Span::styled(format_permissions(meta.mode()), perm_style),
-            ]));
-            lines.push(Line::from(vec![
-                Span::styled(format!("{:12}", "Owner/Group"), label_style),
-                Span::styled(format!("{}/{}", meta.uid(), meta.gid()), owner_style),
-            ]));
+            match prop(PropKind::Permissions, format!("{:11}", "Permissions"), perm_style) {
+                Some(mut line) => {
+                    // Symbolic form of the (possibly edited) octal value
+                    let edited = fields.iter().find(|f| f.kind == PropKind::Permissions)
+                        .and_then(|f| parse_mode(&f.value).ok());
+                    if let Some(mode) = edited {
+                        line.spans.push(Span::styled(format!("  {}", format_permissions_short(mode)), hint_style));
+                    }
+                    lines.push(line);
+                    let perm_selected = fields.get(selected_field).map_or(false, |f| f.kind == PropKind::Permissions);
+                    if perm_selected {
+                        lines.push(Line::from(Span::styled(
+                            format!("{:12}r/w/x: toggle bit of the digit under the cursor", ""),
+                            hint_style,
+                        )));
+                    }
+                }
+                None => lines.push(Line::from(vec![
+                    Span::styled(format!("{:12}", "Permissions"), label_style),
+                    Span::styled(format_permissions(meta.mode()), perm_style),
+                ])),
+            }
+            match prop(PropKind::Owner, format!("{:11}", "Owner"), owner_style) {
+                Some(line) => lines.push(line),
+                None => lines.push(Line::from(vec![
+                    Span::styled(format!("{:12}", "Owner/Group"), label_style),
+                    Span::styled(format!("{}/{}", meta.uid(), meta.gid()), owner_style),
+                ])),
+            }
+            if let Some(mut line) = prop(PropKind::Recursive, format!("{:11}", "Recursive"), value_style) {
+                line.spans.push(Span::styled("  (Space: apply mode/owner to contents)", hint_style));
+                lines.push(line);
+            }
             lines.push(Line::from(vec![
                 Span::styled(format!("{:12}", "Links"), label_style),
                 Span::styled(meta.nlink().to_string(), value_style),
@@ -275,26 +904,27 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
         lines.push(Line::from(Span::raw("")));
 
         if let Ok(created) = meta.created() {
-            let datetime: chrono::DateTime<chrono::Local> = created.into();
             lines.push(Line::from(vec![
                 Span::styled(format!("{:12}", "Created"), label_style),
-                Span::styled(datetime.format("%Y-%m-%d %H:%M:%S").to_string(), date_style),
+                Span::styled(display_time(created), date_style),
             ]));
         }
 
-        if let Ok(modified) = meta.modified() {
-            let datetime: chrono::DateTime<chrono::Local> = modified.into();
+        if let Some(line) = prop(PropKind::Modified, format!("{:11}", "Modified"), date_style) {
+            lines.push(line);
+        } else if let Ok(modified) = meta.modified() {
             lines.push(Line::from(vec![
                 Span::styled(format!("{:12}", "Modified"), label_style),
-                Span::styled(datetime.format("%Y-%m-%d %H:%M:%S").to_string(), date_style),
+                Span::styled(display_time(modified), date_style),
             ]));
         }
 
-        if let Ok(accessed) = meta.accessed() {
-            let datetime: chrono::DateTime<chrono::Local> = accessed.into();
+        if let Some(line) = prop(PropKind::Accessed, format!("{:11}", "Accessed"), date_style) {
+            lines.push(line);
+        } else if let Ok(accessed) = meta.accessed() {
             lines.push(Line::from(vec![
                 Span::styled(format!("{:12}", "Accessed"), label_style),
-                Span::styled(datetime.format("%Y-%m-%d %H:%M:%S").to_string(), date_style),
+                Span::styled(display_time(accessed), date_style),
             ]));
         }
 
@@ -305,10 +935,43 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
                 lines.push(Line::from(Span::raw("")));
                 lines.push(Line::from(vec![
                     Span::styled(format!("{:12}", "Direct Items"), label_style),
-                    Span::styled(count.to_string(), size_style),
+                    Span::styled(format_number(count as u64), size_style),
                 ]));
             }
         }
+
+        // Extended attributes
+        let binary_xattrs = app.file_info_state.as_ref().map(|s| s.binary_xattrs.as_slice()).unwrap_or(&[]);
+        let xattr_fields: Vec<usize> = fields.iter().enumerate()
+            .filter(|(_, f)| matches!(f.kind, PropKind::Xattr(_) | PropKind::NewXattr))
+            .map(|(i, _)| i)
+            .collect();
+        if !xattr_fields.is_empty() || !binary_xattrs.is_empty() {
+            lines.push(Line::from(Span::raw("")));
+            lines.push(Line::from(Span::styled("Extended Attributes", label_style)));
+            for (name, size) in binary_xattrs {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{} = ", name), label_style),
+                    Span::styled(format!("(binary, {} bytes)", format_number(size as u64)), hint_style),
+                ]));
+            }
+            for idx in xattr_fields {
+                let field = &fields[idx];
+                let selected = idx == selected_field;
+                match field.kind {
+                    PropKind::Xattr(ref name) => {
+                        lines.push(field_line(format!("{} =", name), field, selected, label_style, value_style));
+                    }
+                    _ if field.value.is_empty() && !selected => {
+                        lines.push(Line::from(vec![
+                            Span::styled(format!("{:12}", "New"), label_style),
+                            Span::styled("name=value", hint_style),
+                        ]));
+                    }
+                    _ => lines.push(field_line(format!("{:11}", "New"), field, selected, label_style, value_style)),
+                }
+            }
+        }
     } else {
         lines.push(Line::from(Span::styled(
             "Error reading file information",
@@ -318,22 +981,26 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
 
     lines.push(Line::from(Span::raw("")));
 
+    if let Some(error) = app.file_info_state.as_ref().and_then(|s| s.error.clone()) {
+        lines.push(Line::from(Span::styled(error, Style::default().fg(theme.file_info.error_text))));
+    }
+
     // Show different hint based on calculation state
     let is_calculating = app.file_info_state
         .as_ref()
         .map(|s| s.is_calculating)
         .unwrap_or(false);
 
-    let hint_style = Style::default().fg(theme.file_info.hint_text);
+    let close_key = app.keybindings.file_info_first_key(crate::keybindings::FileInfoAction::Close);
+    let apply_key = app.keybindings.file_info_first_key(crate::keybindings::FileInfoAction::Apply);
     if is_calculating {
-        let close_key = app.keybindings.file_info_first_key(crate::keybindings::FileInfoAction::Close);
         lines.push(Line::from(Span::styled(
-            format!("Press {} to cancel, any other key to close", close_key),
+            format!("{}: stop calculating, Up/Down: field, {}: apply", close_key, apply_key),
             hint_style,
         )));
     } else {
         lines.push(Line::from(Span::styled(
-            "Press any key to close",
+            format!("Up/Down: field, {}: apply, {}: cancel", apply_key, close_key),
             hint_style,
         )));
     }
@@ -380,26 +1047,246 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
 pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
     use crate::keybindings::FileInfoAction;
 
-    // Check if we're calculating
-    let is_calculating = app.file_info_state
-        .as_ref()
-        .map(|s| s.is_calculating)
-        .unwrap_or(false);
+    let action = app.keybindings.file_info_action(code, modifiers);
+    let Some(state) = app.file_info_state.as_mut() else {
+        app.current_screen = Screen::FilePanel;
+        return;
+    };
 
-    if let Some(FileInfoAction::Close) = app.keybindings.file_info_action(code, modifiers) {
-        if is_calculating {
-            // Close key during calculation: cancel the calculation only
-            if let Some(ref mut state) = app.file_info_state {
+    match action {
+        Some(FileInfoAction::Close) => {
+            if state.is_calculating {
+                // Close key during calculation: cancel the calculation only
                 state.cancel();
+                return;
             }
+            close(app);
             return;
         }
+        Some(FileInfoAction::Recalculate) => {
+            if !state.is_calculating && app.info_file_path.is_dir() {
+                state.start_calculation(&app.info_file_path, false);
+            }
+            return;
+        }
+        Some(FileInfoAction::Apply) => {
+            let changed = state.fields.iter().any(|f| f.is_changed());
+            match apply_properties(&app.info_file_path, &state.fields) {
+                Ok(renamed) => {
+                    close(app);
+                    if changed {
+                        if let Some(name) = renamed {
+                            app.active_panel_mut().pending_focus = Some(name);
+                        }
+                        app.show_message("Properties updated");
+                        app.refresh_panels();
+                    }
+                }
+                Err(e) => state.error = Some(e),
+            }
+            return;
+        }
+        None => {}
+    }
+
+    match code {
+        KeyCode::Up | KeyCode::BackTab => {
+            state.selected_field = state.selected_field.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Tab => {
+            if state.selected_field + 1 < state.fields.len() {
+                state.selected_field += 1;
+            }
+        }
+        _ => {
+            let Some(field) = state.fields.get_mut(state.selected_field) else { return };
+            // 권한: 커서 아래 자리(user/group/other)의 r/w/x 비트 토글
+            if field.kind == PropKind::Permissions {
+                let bit = match code {
+                    KeyCode::Char('r') => Some(4),
+                    KeyCode::Char('w') => Some(2),
+                    KeyCode::Char('x') => Some(1),
+                    _ => None,
+                };
+                if let Some(bit) = bit {
+                    if let Some(value) = toggle_mode_bit(&field.value, field.cursor, bit) {
+                        field.cursor = field.cursor.min(value.chars().count());
+                        field.value = value;
+                    }
+                    return;
+                }
+            }
+            // 하위 항목 적용 여부는 예/아니오 전환만
+            if field.kind == PropKind::Recursive {
+                if matches!(code, KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right) {
+                    field.value = if field.value == "yes" { "no".to_string() } else { "yes".to_string() };
+                    field.cursor = field.value.chars().count();
+                }
+                return;
+            }
+            match code {
+                KeyCode::Left => field.cursor = field.cursor.saturating_sub(1),
+                KeyCode::Right => field.cursor = (field.cursor + 1).min(field.value.chars().count()),
+                KeyCode::Home => field.cursor = 0,
+                KeyCode::End => field.cursor = field.value.chars().count(),
+                KeyCode::Backspace => field.backspace(),
+                KeyCode::Delete => field.delete(),
+                KeyCode::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => field.insert(c),
+                _ => {}
+            }
+        }
     }
+}
 
-    // Any key closes the dialog
+fn close(app: &mut App) {
     if let Some(ref mut state) = app.file_info_state {
         state.cancel();
     }
     app.file_info_state = None;
     app.current_screen = Screen::FilePanel;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_dir_size_parallel() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), b"hello").unwrap();
+        for sub in ["x", "x/y", "x/y/z", "w"] {
+            fs::create_dir(dir.path().join(sub)).unwrap();
+            fs::write(dir.path().join(sub).join("f.bin"), vec![0u8; 100]).unwrap();
+        }
+
+        let cancel_flag = AtomicBool::new(false);
+        let progress = DirCalcProgress::default();
+        let result = calculate_dir_size_parallel(dir.path(), &cancel_flag, &progress);
+        assert_eq!(result.total_size, 405);
+        assert_eq!(result.file_count, 5);
+        assert_eq!(result.dir_count, 4);
+    }
+
+    #[test]
+    fn test_calculate_dir_size_cancelled() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("x")).unwrap();
+        let cancel_flag = AtomicBool::new(true);
+        let progress = DirCalcProgress::default();
+        let result = calculate_dir_size_parallel(dir.path(), &cancel_flag, &progress);
+        assert_eq!(result.file_count, 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_calculate_dir_size_details() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("a.TXT"), vec![0u8; 10]).unwrap();
+        fs::write(dir.path().join("sub/b.txt"), vec![0u8; 20]).unwrap();
+        fs::write(dir.path().join("data"), vec![0u8; 50]).unwrap();
+        fs::hard_link(dir.path().join("data"), dir.path().join("sub/data.bak")).unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        fs::write(outside.path().join("big.iso"), vec![0u8; 300]).unwrap();
+        std::os::unix::fs::symlink(outside.path().join("big.iso"), dir.path().join("big.iso")).unwrap();
+        std::os::unix::fs::symlink(outside.path(), dir.path().join("linked_dir")).unwrap();
+
+        let cancel_flag = AtomicBool::new(false);
+        let progress = DirCalcProgress::default();
+        let result = calculate_dir_size_parallel(dir.path(), &cancel_flag, &progress);
+        assert_eq!(result.file_count, 4);
+        assert_eq!(result.dir_count, 1);
+        assert_eq!(result.total_size, 130);
+
+        let details = result.details.unwrap();
+        assert_eq!(details.symlink_count, 2);
+        assert_eq!(details.symlink_size, 300);
+        assert_eq!(details.unique_size, 80);
+        assert_eq!(details.extensions, vec![
+            (String::new(), 1, 50),
+            ("bak".to_string(), 1, 50),
+            ("txt".to_string(), 2, 30),
+        ]);
+    }
+
+    #[test]
+    fn test_apply_properties() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.txt");
+        fs::write(&path, b"hello").unwrap();
+
+        let mut state = FileInfoState::new();
+        state.load_properties(&path);
+        for field in state.fields.iter_mut() {
+            match field.kind {
+                PropKind::Name => field.value = "b.txt".to_string(),
+                PropKind::Modified => field.value = "2020-01-02 03:04:05".to_string(),
+                PropKind::Permissions => field.value = "600".to_string(),
+                _ => {}
+            }
+        }
+        assert_eq!(apply_properties(&path, &state.fields).unwrap(), Some("b.txt".to_string()));
+
+        let renamed = dir.path().join("b.txt");
+        let meta = fs::metadata(&renamed).unwrap();
+        assert_eq!(format_time(meta.modified().unwrap()), "2020-01-02 03:04:05");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_toggle_mode_bit() {
+        // Cursor on the group digit
+        assert_eq!(toggle_mode_bit("644", 1, 2).as_deref(), Some("664"));
+        // Cursor after the last digit toggles "other"
+        assert_eq!(toggle_mode_bit("755", 3, 1).as_deref(), Some("754"));
+        assert_eq!(toggle_mode_bit("4755", 1, 4).as_deref(), Some("4355"));
+        assert_eq!(toggle_mode_bit("9z", 0, 4), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_recursive_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("d");
+        fs::create_dir(&root).unwrap();
+        fs::write(root.join("a.txt"), b"hello").unwrap();
+
+        let mut state = FileInfoState::new();
+        state.load_properties(&root);
+        for field in state.fields.iter_mut() {
+            match field.kind {
+                PropKind::Permissions => field.value = "700".to_string(),
+                PropKind::Recursive => field.value = "yes".to_string(),
+                _ => {}
+            }
+        }
+        apply_properties(&root, &state.fields).unwrap();
+        let mode = fs::metadata(root.join("a.txt")).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o700);
+    }
+
+    #[test]
+    fn test_invalid_input_changes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.txt");
+        fs::write(&path, b"hello").unwrap();
+
+        let mut state = FileInfoState::new();
+        state.load_properties(&path);
+        for field in state.fields.iter_mut() {
+            match field.kind {
+                PropKind::Name => field.value = "b.txt".to_string(),
+                PropKind::Modified => field.value = "yesterday".to_string(),
+                _ => {}
+            }
+        }
+        assert!(apply_properties(&path, &state.fields).is_err());
+        assert!(path.exists());
+    }
+}
@@ -3,12 +3,16 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
 };
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
 use unicode_width::UnicodeWidthChar;
 
 use super::{
@@ -24,6 +28,27 @@ pub enum ViewerMode {
     Hex,
 }
 
+/// 마크 입력 대기 상태 (마크 키 다음에 오는 글자를 기다림)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkPrompt {
+    Set,
+    Jump,
+}
+
+/// 마크 이름으로 쓸 수 있는 글자 (a-z, A-Z)
+pub fn is_mark_name(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+/// 마크 목록 표시용 문자열 ("a:12 b:340", 줄 번호는 1부터)
+pub fn format_marks(marks: &BTreeMap<char, usize>) -> String {
+    marks
+        .iter()
+        .map(|(name, line)| format!("{}:{}", name, line + 1))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// 검색 옵션
 #[derive(Debug, Clone, Default)]
 pub struct SearchOptions {
@@ -32,6 +57,26 @@ pub struct SearchOptions {
     pub whole_word: bool,
 }
 
+/// 검색 스레드가 한 번에 보내는 줄 수
+const SEARCH_CHUNK_LINES: usize = 5000;
+
+/// 검색 스레드가 보내는 부분 결과
+#[derive(Debug)]
+struct SearchChunk {
+    positions: Vec<(usize, usize, usize)>,
+    /// 마지막 청크 여부
+    done: bool,
+}
+
+/// 원격 파일 미리보기 정보 (앞부분만 받아서 연 경우)
+#[derive(Debug, Clone)]
+pub struct RemotePreview {
+    pub panel_index: usize,
+    pub file_name: String,
+    /// 원격 파일 전체 크기
+    pub total_size: u64,
+}
+
 /// 뷰어 상태
 #[derive(Debug)]
 pub struct ViewerState {
@@ -58,9 +103,22 @@ pub struct ViewerState {
     cached_pattern: String,
     cached_case_sensitive: bool,
 
+    // 백그라운드 검색 (큰 파일에서 UI를 막지 않도록 결과를 점진적으로 받음)
+    search_receiver: Option<Receiver<SearchChunk>>,
+    search_cancel: Arc<AtomicBool>,
+    // 첫 매치가 오면 그 위치로 이동할지 (복원한 검색은 false: 보던 위치 유지)
+    jump_to_first_match: bool,
+
     // 북마크
     pub bookmarks: HashSet<usize>,
 
+    // 이름 있는 마크 (글자 -> 줄), 파일별로 최근 파일 목록에 저장됨
+    pub marks: BTreeMap<char, usize>,
+    pub mark_prompt: Option<MarkPrompt>,
+
+    // 줄 선택 (클립보드 복사용): 선택 시작 줄, 현재 줄(scroll)까지 선택됨
+    pub line_selection: Option<usize>,
+
     // Goto line
     pub goto_mode: bool,
     pub goto_input: String,
@@ -74,12 +132,28 @@ pub struct ViewerState {
     pub encoding: String,
     pub is_binary: bool,
 
+    // 표준 입력에서 읽은 내용 (편집 불가)
+    pub is_stdin: bool,
+
+    // 원격 파일의 로컬 tmp 사본 (편집/전체 다운로드는 원격 경로로)
+    pub remote_preview: Option<RemotePreview>,
+
     // 파일 정보
     pub file_size: u64,
     pub total_lines: usize,
 
     // 화면 크기 (렌더링 시 업데이트)
     pub visible_height: usize,
+
+    // 메시지 표시
+    pub message: Option<String>,
+    pub message_timer: u8,
+}
+
+impl Drop for ViewerState {
+    fn drop(&mut self) {
+        self.search_cancel.store(true, Ordering::Relaxed);
+    }
 }
 
 impl ViewerState {
@@ -103,7 +177,13 @@ impl ViewerState {
             cached_regex: None,
             cached_pattern: String::new(),
             cached_case_sensitive: false,
+            search_receiver: None,
+            search_cancel: Arc::new(AtomicBool::new(false)),
+            jump_to_first_match: true,
             bookmarks: HashSet::new(),
+            marks: BTreeMap::new(),
+            mark_prompt: None,
+            line_selection: None,
             goto_mode: false,
             goto_input: String::new(),
             language: Language::Plain,
@@ -111,12 +191,22 @@ impl ViewerState {
             syntax_colors: crate::ui::theme::Theme::default().syntax,
             encoding: "UTF-8".to_string(),
             is_binary: false,
+            is_stdin: false,
+            remote_preview: None,
             file_size: 0,
             total_lines: 0,
             visible_height: 20, // 기본값, 렌더링 시 업데이트됨
+            message: None,
+            message_timer: 0,
         }
     }
 
+    /// 메시지 설정 (지정된 프레임 수 동안 표시)
+    pub fn set_message(&mut self, msg: impl Into<String>, duration: u8) {
+        self.message = Some(msg.into());
+        self.message_timer = duration;
+    }
+
     /// 테마의 syntax colors 설정
     pub fn set_syntax_colors(&mut self, colors: crate::ui::theme::SyntaxColors) {
         self.syntax_colors = colors;
@@ -135,7 +225,11 @@ impl ViewerState {
         self.scroll = 0;
         self.horizontal_scroll = 0;
         self.bookmarks.clear();
+        self.marks.clear();
+        self.mark_prompt = None;
+        self.line_selection = None;
         self.search_term.clear();
+        self.cancel_search();
         self.match_lines.clear();
         self.match_positions.clear();
 
@@ -151,6 +245,53 @@ impl ViewerState {
 
         // 파일 읽기
         let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+        self.is_stdin = false;
+        self.load_content(bytes);
+
+        // 언어 감지 및 하이라이터 초기화
+        self.language = Language::from_extension(path);
+        if !self.is_binary {
+            self.highlighter = Some(SyntaxHighlighter::new(self.language, self.syntax_colors));
+        }
+
+        Ok(())
+    }
+
+    /// 파이프로 받은 표준 입력 내용 로드 (`--view -`)
+    /// language가 없으면 첫 줄의 shebang으로 추측
+    pub fn load_stdin(&mut self, bytes: Vec<u8>, language: Option<Language>) -> Result<(), String> {
+        if bytes.len() as u64 > Self::MAX_FILE_SIZE {
+            return Err(format!(
+                "Input too large. Maximum size is {} MB.",
+                Self::MAX_FILE_SIZE / 1024 / 1024
+            ));
+        }
+        self.file_path = PathBuf::from("(stdin)");
+        self.scroll = 0;
+        self.horizontal_scroll = 0;
+        self.bookmarks.clear();
+        self.marks.clear();
+        self.mark_prompt = None;
+        self.line_selection = None;
+        self.search_term.clear();
+        self.cancel_search();
+        self.match_lines.clear();
+        self.match_positions.clear();
+        self.is_stdin = true;
+        self.load_content(strip_ansi_escapes(bytes));
+
+        self.language = language.unwrap_or_else(|| {
+            self.lines.first().map(|l| Language::from_shebang(l)).unwrap_or(Language::Plain)
+        });
+        if !self.is_binary {
+            self.highlighter = Some(SyntaxHighlighter::new(self.language, self.syntax_colors));
+        }
+
+        Ok(())
+    }
+
+    /// 바이트 내용을 텍스트/헥스 줄로 변환
+    fn load_content(&mut self, bytes: Vec<u8>) {
         self.file_size = bytes.len() as u64;
 
         // 바이너리 파일 감지
@@ -182,14 +323,6 @@ impl ViewerState {
         }
 
         self.total_lines = self.lines.len();
-
-        // 언어 감지 및 하이라이터 초기화
-        self.language = Language::from_extension(path);
-        if !self.is_binary {
-            self.highlighter = Some(SyntaxHighlighter::new(self.language, self.syntax_colors));
-        }
-
-        Ok(())
     }
 
     /// 바이너리 파일 감지
@@ -247,9 +380,13 @@ impl ViewerState {
     }
 
     /// 검색 수행 (with regex caching for performance)
+    /// 매칭은 백그라운드 스레드에서 진행되고 결과는 `poll_search`로 점진적으로 수집됨
     pub fn perform_search(&mut self) {
+        self.cancel_search();
+        self.jump_to_first_match = true;
         self.match_lines.clear();
         self.match_positions.clear();
+        self.current_match = 0;
 
         if self.search_term.is_empty() {
             self.cached_regex = None;
@@ -296,27 +433,101 @@ impl ViewerState {
             }
         }
 
-        // Use cached regex for search
-        if let Some(ref re) = self.cached_regex {
-            for (line_idx, line) in self.lines.iter().enumerate() {
-                let mut has_match = false;
+        let Some(re) = self.cached_regex.clone() else { return };
+        let snapshot = self.lines.clone();
+        let cancel_flag = self.search_cancel.clone();
+        let (tx, rx) = mpsc::channel();
+        self.search_receiver = Some(rx);
+
+        thread::spawn(move || {
+            let mut positions = Vec::new();
+            for (line_idx, line) in snapshot.iter().enumerate() {
+                if cancel_flag.load(Ordering::Relaxed) {
+                    return;
+                }
                 for mat in re.find_iter(line) {
                     // 바이트 인덱스를 문자 인덱스로 변환
                     let byte_start = mat.start();
                     let byte_end = mat.end();
                     let char_start = line[..byte_start].chars().count();
                     let char_end = char_start + line[byte_start..byte_end].chars().count();
-                    self.match_positions.push((line_idx, char_start, char_end));
-                    has_match = true;
+                    positions.push((line_idx, char_start, char_end));
                 }
-                if has_match {
-                    self.match_lines.push(line_idx);
+                if (line_idx + 1) % SEARCH_CHUNK_LINES == 0 && !positions.is_empty() {
+                    let chunk = SearchChunk { positions: std::mem::take(&mut positions), done: false };
+                    if tx.send(chunk).is_err() {
+                        return;
+                    }
                 }
             }
+            let _ = tx.send(SearchChunk { positions, done: true });
+        });
+    }
+
+    /// 진행 중인 검색 중단 (이미 받은 결과는 유지)
+    pub fn cancel_search(&mut self) {
+        self.search_cancel.store(true, Ordering::Relaxed);
+        self.search_cancel = Arc::new(AtomicBool::new(false));
+        self.search_receiver = None;
+    }
+
+    /// 백그라운드 검색이 진행 중인지 여부
+    pub fn is_searching(&self) -> bool {
+        self.search_receiver.is_some()
+    }
+
+    /// 검색 스레드가 보낸 결과 수집 (매 프레임 그리기 전에 호출)
+    pub fn poll_search(&mut self) {
+        let Some(ref receiver) = self.search_receiver else { return };
+        let was_empty = self.match_positions.is_empty();
+        let mut finished = false;
+        loop {
+            match receiver.try_recv() {
+                Ok(chunk) => {
+                    for &(line, _, _) in &chunk.positions {
+                        if self.match_lines.last() != Some(&line) {
+                            self.match_lines.push(line);
+                        }
+                    }
+                    self.match_positions.extend(chunk.positions);
+                    if chunk.done {
+                        finished = true;
+                        break;
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+        if finished {
+            self.search_receiver = None;
+        }
+        // 첫 매치가 도착하면 그 위치로 이동
+        if was_empty && !self.match_positions.is_empty() && self.jump_to_first_match {
+            self.current_match = 0;
+            self.scroll_to_current_match();
+        }
+        // 복원한 검색: 화면은 그대로 두고 현재 매치만 보던 위치 이후의 첫 매치로
+        if finished && !self.jump_to_first_match && !self.match_positions.is_empty() {
+            let top = self.scroll;
+            let next = self.match_positions.partition_point(|&(line, _, _)| line < top);
+            self.current_match = next.min(self.match_positions.len() - 1);
         }
+    }
 
-        self.current_match = 0;
-        self.scroll_to_current_match();
+    /// 다시 연 파일을 지난번 위치로: 스크롤과 검색어 복원 (load_file 다음에 호출)
+    pub fn restore_position(&mut self, scroll: usize, search_term: &str) {
+        self.scroll = scroll.min(self.lines.len().saturating_sub(1));
+        if !search_term.is_empty() && !self.is_binary {
+            self.search_input = search_term.to_string();
+            self.search_cursor_pos = self.search_input.chars().count();
+            self.search_term = search_term.to_string();
+            self.perform_search();
+            self.jump_to_first_match = false;
+        }
     }
 
     /// 현재 매치로 스크롤 (match_positions 기준)
@@ -347,6 +558,46 @@ impl ViewerState {
         }
     }
 
+    /// 줄 선택 시작/해제 (현재 줄 기준)
+    pub fn toggle_line_selection(&mut self) {
+        self.line_selection = match self.line_selection {
+            Some(_) => None,
+            None => Some(self.scroll),
+        };
+    }
+
+    /// 선택된 줄 범위 (시작, 끝 포함)
+    pub fn selected_line_range(&self) -> Option<(usize, usize)> {
+        let anchor = self.line_selection?;
+        let last = self.lines.len().checked_sub(1)?;
+        let (start, end) = if anchor <= self.scroll { (anchor, self.scroll) } else { (self.scroll, anchor) };
+        Some((start.min(last), end.min(last)))
+    }
+
+    /// 클립보드로 복사할 텍스트와 원본 크기 (선택 영역 또는 전체)
+    /// 텍스트는 클립보드 한도를 넘으면 그 직후에서 잘림
+    pub fn clipboard_text(&self) -> Option<(String, usize)> {
+        if self.is_binary || self.mode == ViewerMode::Hex {
+            return None;
+        }
+        let lines = match self.selected_line_range() {
+            Some((start, end)) => &self.lines[start..=end],
+            None => &self.lines[..],
+        };
+        let total = lines.iter().map(|l| l.len() + 1).sum::<usize>().saturating_sub(1);
+        let mut text = String::new();
+        for (i, line) in lines.iter().enumerate() {
+            if text.len() > crate::utils::osc52::MAX_COPY_BYTES {
+                break;
+            }
+            if i > 0 {
+                text.push('\n');
+            }
+            text.push_str(line);
+        }
+        Some((text, total))
+    }
+
     /// 북마크 토글
     pub fn toggle_bookmark(&mut self, line: usize) {
         if self.bookmarks.contains(&line) {
@@ -399,6 +650,25 @@ impl ViewerState {
         self.scroll = sorted[0].saturating_sub(5);
     }
 
+    /// 현재 위치(화면 첫 줄)에 마크 설정
+    pub fn set_mark(&mut self, name: char) {
+        self.marks.insert(name, self.scroll);
+        self.set_message(format!("Mark '{}' set at line {}", name, self.scroll + 1), 30);
+    }
+
+    /// 마크 위치로 이동 (파일이 짧아졌으면 마지막 줄로)
+    pub fn jump_to_mark(&mut self, name: char) {
+        match self.marks.get(&name) {
+            Some(&line) => self.scroll = line.min(self.lines.len().saturating_sub(1)),
+            None => self.set_message(format!("Mark '{}' not set", name), 30),
+        }
+    }
+
+    /// 해당 줄에 설정된 마크
+    pub fn mark_at(&self, line: usize) -> Option<char> {
+        self.marks.iter().find(|(_, &l)| l == line).map(|(&name, _)| name)
+    }
+
     /// 줄 번호로 이동
     pub fn goto_line(&mut self, line_str: &str) {
         if let Ok(line_num) = line_str.parse::<usize>() {
@@ -413,6 +683,8 @@ impl ViewerState {
         if self.is_binary {
             return; // 바이너리 파일은 항상 헥스 모드
         }
+        // 줄 내용이 바뀌므로 진행 중인 검색은 의미 없음
+        self.cancel_search();
 
         match self.mode {
             ViewerMode::Text => {
@@ -430,6 +702,17 @@ impl ViewerState {
     }
 }
 
+/// 파이프 입력의 ANSI 색상/커서 이스케이프 시퀀스 제거 (예: `ls --color | cokacdir --view -`)
+fn strip_ansi_escapes(bytes: Vec<u8>) -> Vec<u8> {
+    if !bytes.contains(&0x1b) {
+        return bytes;
+    }
+    match regex::bytes::Regex::new(r"\x1b\[[0-9;?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(\x07|\x1b\\)") {
+        Ok(re) => re.replace_all(&bytes, &b""[..]).into_owned(),
+        Err(_) => bytes,
+    }
+}
+
 pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Theme, kb: &crate::keybindings::Keybindings) {
     let block = Block::default()
         .borders(Borders::ALL)
@@ -442,6 +725,9 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
         return;
     }
 
+    // 백그라운드 검색 결과 수집
+    state.poll_search();
+
     // 화면 크기 업데이트 (스크롤 계산에 사용)
     let visible_lines = (inner.height - 2) as usize;
     state.visible_height = visible_lines;
@@ -484,6 +770,24 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
         } else {
             Span::raw("")
         },
+        match state.selected_line_range() {
+            Some((start, end)) => Span::styled(
+                format!(" Sel {}-{} ", start + 1, end + 1),
+                Style::default().fg(theme.viewer.selection_fg).bg(theme.viewer.selection_bg),
+            ),
+            None => Span::raw(""),
+        },
+        match state.remote_preview {
+            Some(ref preview) if state.file_size < preview.total_size => Span::styled(
+                format!(
+                    " Preview {}/{} ",
+                    crate::utils::format::format_size(state.file_size),
+                    crate::utils::format::format_size(preview.total_size)
+                ),
+                Style::default().fg(theme.viewer.bookmark_indicator).add_modifier(Modifier::BOLD),
+            ),
+            _ => Span::raw(""),
+        },
     ]);
     frame.render_widget(
         Paragraph::new(header).style(theme.status_bar_style()),
@@ -493,6 +797,7 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
     // Content
     let content_height = (inner.height - 2) as usize;
     let content_width = (inner.width - 5) as usize; // 줄 번호 공간 제외
+    let selection = state.selected_line_range();
 
     // 하이라이터 리셋
     let mut highlighter = state.highlighter.clone();
@@ -538,10 +843,16 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
             .take(content_height)
             .enumerate()
         {
-            let is_bookmarked = state.bookmarks.contains(orig_line_num);
+            let mark = state.mark_at(*orig_line_num);
+            let is_bookmarked = state.bookmarks.contains(orig_line_num) || mark.is_some();
+            let is_selected = selection
+                .map(|(start, end)| *orig_line_num >= start && *orig_line_num <= end)
+                .unwrap_or(false);
 
             // 줄 번호 (첫 줄만 표시)
-            let line_num_style = if is_bookmarked {
+            let line_num_style = if is_selected {
+                Style::default().fg(theme.viewer.selection_fg).bg(theme.viewer.selection_bg)
+            } else if is_bookmarked {
                 Style::default().fg(theme.viewer.bookmark_indicator).add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(theme.viewer.line_number)
@@ -549,7 +860,7 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
 
             let line_num_span = if *is_first {
                 Span::styled(
-                    format!("{:4} ", orig_line_num + 1),
+                    format!("{:4}{}", orig_line_num + 1, mark.unwrap_or(' ')),
                     line_num_style,
                 )
             } else {
@@ -612,17 +923,23 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
             // TAB을 4칸 스페이스로 변환 (잔상 방지)
             let line = original_line.replace('\t', "    ");
             let line_num = state.scroll + i;
-            let is_bookmarked = state.bookmarks.contains(&line_num);
+            let mark = state.mark_at(line_num);
+            let is_bookmarked = state.bookmarks.contains(&line_num) || mark.is_some();
+            let is_selected = selection
+                .map(|(start, end)| line_num >= start && line_num <= end)
+                .unwrap_or(false);
 
             // 줄 번호
-            let line_num_style = if is_bookmarked {
+            let line_num_style = if is_selected {
+                Style::default().fg(theme.viewer.selection_fg).bg(theme.viewer.selection_bg)
+            } else if is_bookmarked {
                 Style::default().fg(theme.viewer.bookmark_indicator).add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(theme.viewer.line_number)
             };
 
             let line_num_span = Span::styled(
-                format!("{:4} ", line_num + 1),
+                format!("{:4}{}", line_num + 1, mark.unwrap_or(' ')),
                 line_num_style,
             );
 
@@ -730,7 +1047,22 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
     // Footer / Search bar / Goto bar
     let footer_y = inner.y + inner.height - 1;
 
-    if state.goto_mode {
+    if let Some(prompt) = state.mark_prompt {
+        let mark_line = Line::from(match prompt {
+            MarkPrompt::Set => vec![
+                Span::styled("Set mark (a-z, A-Z): ", theme.header_style()),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ],
+            MarkPrompt::Jump => vec![
+                Span::styled("Jump to mark: ", theme.header_style()),
+                Span::styled(format_marks(&state.marks), theme.dim_style()),
+            ],
+        });
+        frame.render_widget(
+            Paragraph::new(mark_line).style(theme.status_bar_style()),
+            Rect::new(inner.x, footer_y, inner.width, 1),
+        );
+    } else if state.goto_mode {
         let goto_line = Line::from(vec![
             Span::styled("Go to line: ", theme.header_style()),
             Span::styled(&state.goto_input, theme.normal_style()),
@@ -773,11 +1105,14 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
         let (match_info, match_info_style) = if !state.match_positions.is_empty() {
             let count = state.match_positions.len();
             (format!(
-                " {}/{} ({} matches) ",
+                " {}/{} ({} matches{}) ",
                 state.current_match + 1,
                 count,
-                count
+                count,
+                if state.is_searching() { ", searching..." } else { "" }
             ), theme.dim_style())
+        } else if state.is_searching() {
+            (" Searching... ".to_string(), theme.dim_style())
         } else if !state.search_term.is_empty() {
             (" No matches ".to_string(), theme.dim_style())
         } else {
@@ -845,6 +1180,27 @@ pub fn draw(frame: &mut Frame, state: &mut ViewerState, area: Rect, theme: &Them
             Rect::new(inner.x, footer_y, inner.width, 1),
         );
     }
+
+    // 메시지 표시 (화면 상단에 오버레이)
+    if let Some(ref msg) = state.message {
+        let msg_width = (msg.len() + 4).min(inner.width as usize) as u16;
+        let msg_x = inner.x + (inner.width.saturating_sub(msg_width)) / 2;
+        let msg_area = Rect::new(msg_x, inner.y + 1, msg_width, 1);
+        frame.render_widget(Clear, msg_area);
+        frame.render_widget(
+            Paragraph::new(format!(" {} ", msg))
+                .style(Style::default().fg(theme.message.text).bg(theme.message.bg)),
+            msg_area,
+        );
+    }
+
+    // 메시지 타이머 업데이트
+    if state.message_timer > 0 {
+        state.message_timer -= 1;
+        if state.message_timer == 0 {
+            state.message = None;
+        }
+    }
 }
 
 /// 헥스 라인 렌더링
@@ -1291,12 +1647,26 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
         return;
     }
 
+    // 마크 키 다음 글자
+    if let Some(prompt) = state.mark_prompt.take() {
+        match code {
+            KeyCode::Char(c) if is_mark_name(c) => match prompt {
+                MarkPrompt::Set => state.set_mark(c),
+                MarkPrompt::Jump => state.jump_to_mark(c),
+            },
+            KeyCode::Esc => {}
+            _ => state.set_message("Mark names are letters (a-z, A-Z)", 30),
+        }
+        return;
+    }
+
     // 검색 모드
     if state.search_mode {
         match code {
             KeyCode::Esc => {
                 state.search_mode = false;
                 // 에디터와 동일하게 검색 결과 초기화 (검색어는 유지)
+                state.cancel_search();
                 state.match_positions.clear();
                 state.match_lines.clear();
             }
@@ -1382,15 +1752,33 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
     if let Some(action) = app.keybindings.viewer_action(code, modifiers) {
         match action {
             ViewerAction::Quit => {
-                app.current_screen = Screen::FilePanel;
+                // 줄 선택 중이면 선택만 해제
+                if state.line_selection.is_some() {
+                    state.line_selection = None;
+                } else {
+                    app.record_recent_file();
+                    // 내용 검색 결과에서 열었으면 결과 화면으로 복귀
+                    app.current_screen = if app.previous_screen == Some(Screen::SearchResult) && app.search_result_state.active {
+                        Screen::SearchResult
+                    } else {
+                        Screen::FilePanel
+                    };
+                    app.previous_screen = None;
+                }
+            }
+            ViewerAction::Edit if state.remote_preview.is_some() => {
+                // 원격 파일은 전체를 받아 편집기로 연다 (저장 시 업로드)
+                app.download_full_preview(true);
             }
             ViewerAction::Edit => {
                 if let Some(ref viewer_state) = app.viewer_state {
-                    if !viewer_state.is_binary {
+                    if !viewer_state.is_binary && !viewer_state.is_stdin {
                         let path = viewer_state.file_path.clone();
                         let viewer_scroll = viewer_state.scroll;
+                        let marks = viewer_state.marks.clone();
                         let mut editor = super::file_editor::EditorState::new();
                         if editor.load_file(&path).is_ok() {
+                            editor.marks = marks;
                             editor.scroll = viewer_scroll;
                             editor.cursor_line = viewer_scroll;
                             editor.cursor_col = 0;
@@ -1443,6 +1831,17 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
             ViewerAction::PrevBookmark => {
                 state.goto_prev_bookmark();
             }
+            ViewerAction::SetMark => {
+                state.mark_prompt = Some(MarkPrompt::Set);
+            }
+            ViewerAction::JumpToMark => {
+                if state.marks.is_empty() {
+                    let key = app.keybindings.viewer_first_key(ViewerAction::SetMark);
+                    state.set_message(format!("No marks. Press {} and a letter to set one", key), 30);
+                } else {
+                    state.mark_prompt = Some(MarkPrompt::Jump);
+                }
+            }
             ViewerAction::ToggleWrap => {
                 state.word_wrap = !state.word_wrap;
             }
@@ -1453,7 +1852,123 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                 state.goto_mode = true;
                 state.goto_input.clear();
             }
+            ViewerAction::ToggleLineSelection => {
+                state.toggle_line_selection();
+            }
+            ViewerAction::NextFile => {
+                app.quick_view_step(1);
+            }
+            ViewerAction::PrevFile => {
+                app.quick_view_step(-1);
+            }
+            ViewerAction::DownloadFull => {
+                if state.remote_preview.is_some() {
+                    app.download_full_preview(false);
+                } else {
+                    state.set_message("Not a remote preview", 30);
+                }
+            }
+            ViewerAction::CopyToClipboard => {
+                let what = if state.line_selection.is_some() { "Selection" } else { "File" };
+                match state.clipboard_text() {
+                    Some((text, total)) => {
+                        match crate::utils::osc52::copy(&text) {
+                            Ok(result) => {
+                                let result = crate::utils::osc52::CopyResult { total, ..result };
+                                state.set_message(result.message(what), 30);
+                            }
+                            Err(e) => state.set_message(format!("Clipboard error: {}", e), 30),
+                        }
+                        state.line_selection = None;
+                    }
+                    None => state.set_message("Binary content cannot be copied", 30),
+                }
+            }
         }
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewer_with(lines: Vec<String>) -> ViewerState {
+        let mut state = ViewerState::new();
+        state.total_lines = lines.len();
+        state.lines = lines;
+        state
+    }
+
+    fn wait_for_search(state: &mut ViewerState) {
+        for _ in 0..500 {
+            state.poll_search();
+            if !state.is_searching() {
+                return;
+            }
+            thread::sleep(std::time::Duration::from_millis(5));
+        }
+        panic!("search did not finish");
+    }
+
+    #[test]
+    fn test_new_query_cancels_running_search() {
+        let lines: Vec<String> = (0..50_000).map(|i| format!("apple {} banana", i)).collect();
+        let mut state = viewer_with(lines);
+
+        state.search_term = "apple".to_string();
+        state.perform_search();
+        let first_cancel = state.search_cancel.clone();
+        assert!(state.is_searching());
+
+        state.search_term = "banana".to_string();
+        state.perform_search();
+        // The first scan is told to stop and its results are never collected
+        assert!(first_cancel.load(Ordering::Relaxed));
+        assert!(!state.search_cancel.load(Ordering::Relaxed));
+
+        wait_for_search(&mut state);
+        assert_eq!(state.match_positions.len(), 50_000);
+        assert!(state.match_positions.iter().all(|&(line, start, end)| &state.lines[line][start..end] == "banana"));
+    }
+
+    #[test]
+    fn test_search_sends_matches_in_chunks() {
+        let total = SEARCH_CHUNK_LINES * 3;
+        let mut state = viewer_with((0..total).map(|i| format!("needle {}", i)).collect());
+        state.search_term = "needle".to_string();
+        state.perform_search();
+
+        // The scan reports the first block of lines before reading the rest
+        let first = state.search_receiver.as_ref().unwrap().recv().unwrap();
+        assert!(!first.done);
+        assert_eq!(first.positions.len(), SEARCH_CHUNK_LINES);
+        assert_eq!(first.positions.last().unwrap().0, SEARCH_CHUNK_LINES - 1);
+
+        wait_for_search(&mut state);
+        assert_eq!(state.match_positions.len(), SEARCH_CHUNK_LINES * 2);
+        assert_eq!(state.match_positions[0].0, SEARCH_CHUNK_LINES);
+    }
+
+    #[test]
+    fn test_poll_search_shows_partial_results() {
+        let lines: Vec<String> = (0..100).map(|i| format!("line {}", i)).collect();
+        let mut state = viewer_with(lines);
+        let (tx, rx) = mpsc::channel();
+        state.search_receiver = Some(rx);
+
+        tx.send(SearchChunk { positions: vec![(40, 0, 4), (40, 5, 7)], done: false }).unwrap();
+        state.poll_search();
+        // The first matches are shown (and scrolled to) while the scan goes on
+        assert!(state.is_searching());
+        assert_eq!(state.match_lines, vec![40]);
+        assert_eq!(state.match_positions.len(), 2);
+        assert_eq!(state.scroll, 35);
+
+        tx.send(SearchChunk { positions: vec![(90, 0, 4)], done: true }).unwrap();
+        state.poll_search();
+        assert!(!state.is_searching());
+        assert_eq!(state.match_lines, vec![40, 90]);
+        assert_eq!(state.scroll, 35);
+    }
+}
@@ -0,0 +1,675 @@
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::app::{App, GitLogDiffState, Screen};
+use super::git_screen::git_cmd_public;
+use super::theme::{GitLogScreenColors, Theme};
+use crate::utils::format::{pad_to_display_width, truncate_to_display_width};
+
+/// Commits read from `git log` per batch (more are read as the cursor nears the end)
+const PAGE_SIZE: usize = 200;
+
+/// Field separator of the log format (never part of the graph)
+const SEP: char = '\u{1f}';
+
+/// Hash of the empty tree, the "parent" of a root commit
+const EMPTY_TREE: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904";
+
+/// Author and date columns (hidden when the screen is narrow)
+const AUTHOR_WIDTH: usize = 16;
+const DATE_WIDTH: usize = 16;
+
+#[derive(Debug, Clone)]
+pub struct LogCommit {
+    pub hash: String,
+    pub short: String,
+    pub author: String,
+    pub date: String,
+    pub refs: String,
+    pub subject: String,
+}
+
+/// One row of `git log --graph`: graph characters and, on commit rows, the commit
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub graph: String,
+    pub commit: Option<LogCommit>,
+}
+
+fn parse_log_line(line: &str) -> LogLine {
+    let mut parts = line.splitn(7, SEP);
+    let graph = parts.next().unwrap_or("").trim_end().to_string();
+    let fields: Vec<&str> = parts.collect();
+    let commit = (fields.len() == 6).then(|| LogCommit {
+        hash: fields[0].to_string(),
+        short: fields[1].to_string(),
+        author: fields[2].to_string(),
+        date: fields[3].to_string(),
+        refs: fields[4].to_string(),
+        subject: fields[5].to_string(),
+    });
+    LogLine { graph, commit }
+}
+
+/// File changed by a commit (`old_path` for renames and copies)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangedFile {
+    pub status: char,
+    pub path: String,
+    pub old_path: Option<String>,
+}
+
+/// Parse `git diff --name-status -z` output
+fn parse_name_status(output: &[u8]) -> Vec<ChangedFile> {
+    let text = String::from_utf8_lossy(output);
+    let mut fields = text.split('\0').filter(|f| !f.is_empty());
+    let mut files = Vec::new();
+    while let Some(code) = fields.next() {
+        let status = code.chars().next().unwrap_or('M');
+        let Some(first) = fields.next() else { break };
+        if status == 'R' || status == 'C' {
+            let Some(second) = fields.next() else { break };
+            files.push(ChangedFile { status, path: second.to_string(), old_path: Some(first.to_string()) });
+        } else {
+            files.push(ChangedFile { status, path: first.to_string(), old_path: None });
+        }
+    }
+    files
+}
+
+/// First parent of `hash` (None for a root commit)
+fn first_parent(repo: &Path, hash: &str) -> Option<String> {
+    let output = git_cmd_public(repo)
+        .args(["rev-list", "--parents", "-n", "1", hash])
+        .output().ok()
+        .filter(|o| o.status.success())?;
+    String::from_utf8_lossy(&output.stdout).split_whitespace().nth(1).map(|s| s.to_string())
+}
+
+/// Files changed by `hash` compared with its first parent
+fn changed_files(repo: &Path, hash: &str, parent: Option<&str>) -> Result<Vec<ChangedFile>, String> {
+    let output = git_cmd_public(repo)
+        .args(["diff", "--name-status", "-z", "-M", parent.unwrap_or(EMPTY_TREE), hash])
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(parse_name_status(&output.stdout))
+}
+
+/// Content of `path` at `rev` (empty when the file does not exist there)
+fn file_at(repo: &Path, rev: &str, path: &str) -> Vec<u8> {
+    git_cmd_public(repo)
+        .arg("show")
+        .arg(format!("{}:{}", rev, path))
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| o.stdout)
+        .unwrap_or_default()
+}
+
+/// Runs `git log --graph` once and hands out its rows in batches of `PAGE_SIZE` commits
+struct Loader {
+    child: Child,
+    more: Sender<()>,
+    batches: Receiver<(Vec<LogLine>, bool)>,
+}
+
+impl Loader {
+    fn spawn(repo: &Path, all_branches: bool) -> Option<Self> {
+        let mut cmd = git_cmd_public(repo);
+        cmd.args([
+            "log",
+            "--graph",
+            "--no-color",
+            "--date=format:%Y-%m-%d %H:%M",
+            "--format=%x1f%H%x1f%h%x1f%an%x1f%ad%x1f%D%x1f%s",
+        ]);
+        if all_branches {
+            cmd.arg("--all");
+        }
+        let mut child = cmd.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::null()).spawn().ok()?;
+        let stdout = child.stdout.take()?;
+        let (more_tx, more_rx) = mpsc::channel::<()>();
+        let (batch_tx, batch_rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut reader = BufReader::new(stdout);
+            let mut buf = Vec::new();
+            while more_rx.recv().is_ok() {
+                let mut batch = Vec::new();
+                let mut commits = 0;
+                let finished = loop {
+                    buf.clear();
+                    match reader.read_until(b'\n', &mut buf) {
+                        Ok(n) if n > 0 => {
+                            let line = parse_log_line(String::from_utf8_lossy(&buf).trim_end_matches('\n'));
+                            commits += line.commit.is_some() as usize;
+                            batch.push(line);
+                            if commits >= PAGE_SIZE {
+                                break false;
+                            }
+                        }
+                        _ => break true,
+                    }
+                };
+                if batch_tx.send((batch, finished)).is_err() || finished {
+                    return;
+                }
+            }
+        });
+
+        Some(Self { child, more: more_tx, batches: batch_rx })
+    }
+}
+
+impl Drop for Loader {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Files of the commit opened with Enter
+struct CommitFiles {
+    commit: LogCommit,
+    parent: Option<String>,
+    files: Vec<ChangedFile>,
+    selected: usize,
+    scroll: usize,
+}
+
+pub struct GitLogScreenState {
+    pub repo_path: PathBuf,
+    /// Show every branch (`--all`) instead of the history of HEAD
+    pub all_branches: bool,
+    lines: Vec<LogLine>,
+    /// Index into `lines`, always a commit row
+    pub selected: usize,
+    pub scroll: usize,
+    /// Commits marked with Space for comparing (full hashes, at most two)
+    pub marked: Vec<String>,
+    loading: bool,
+    finished: bool,
+    loader: Option<Loader>,
+    files: Option<CommitFiles>,
+    pub message: Option<String>,
+}
+
+impl GitLogScreenState {
+    pub fn new(repo_path: PathBuf) -> Self {
+        let mut state = Self {
+            repo_path,
+            all_branches: false,
+            lines: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            marked: Vec::new(),
+            loading: false,
+            finished: false,
+            loader: None,
+            files: None,
+            message: None,
+        };
+        state.reload();
+        state
+    }
+
+    /// Start reading the history again from the top
+    fn reload(&mut self) {
+        self.lines.clear();
+        self.selected = 0;
+        self.scroll = 0;
+        self.loading = false;
+        self.loader = Loader::spawn(&self.repo_path, self.all_branches);
+        self.finished = self.loader.is_none();
+        if self.finished {
+            self.message = Some("Failed to run git log".to_string());
+        }
+        self.request_more();
+    }
+
+    fn request_more(&mut self) {
+        if self.loading || self.finished {
+            return;
+        }
+        if let Some(ref loader) = self.loader {
+            self.loading = loader.more.send(()).is_ok();
+        }
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn poll(&mut self) {
+        let Some(ref loader) = self.loader else { return };
+        let was_empty = self.lines.is_empty();
+        while let Ok((batch, finished)) = loader.batches.try_recv() {
+            self.lines.extend(batch);
+            self.loading = false;
+            self.finished = finished;
+        }
+        if self.finished {
+            self.loader = None;
+            self.loading = false;
+        }
+        if was_empty {
+            // 첫 배치: 첫 커밋 줄에 커서
+            self.selected = self.lines.iter().position(|l| l.commit.is_some()).unwrap_or(0);
+        }
+    }
+
+    pub fn commit_count(&self) -> usize {
+        self.lines.iter().filter(|l| l.commit.is_some()).count()
+    }
+
+    fn selected_commit(&self) -> Option<&LogCommit> {
+        self.lines.get(self.selected).and_then(|l| l.commit.as_ref())
+    }
+
+    /// Move over `delta` commit rows (graph-only rows are skipped)
+    fn move_selection(&mut self, delta: isize) {
+        let mut index = self.selected;
+        for _ in 0..delta.unsigned_abs() {
+            let next = if delta < 0 {
+                self.lines[..index].iter().rposition(|l| l.commit.is_some())
+            } else {
+                self.lines.iter().skip(index + 1).position(|l| l.commit.is_some()).map(|p| index + 1 + p)
+            };
+            match next {
+                Some(i) => index = i,
+                None => break,
+            }
+        }
+        self.selected = index;
+    }
+
+    fn toggle_mark(&mut self) {
+        let Some(hash) = self.selected_commit().map(|c| c.hash.clone()) else { return };
+        if let Some(pos) = self.marked.iter().position(|h| *h == hash) {
+            self.marked.remove(pos);
+        } else {
+            if self.marked.len() == 2 {
+                self.marked.remove(0);
+            }
+            self.marked.push(hash);
+        }
+    }
+
+    fn open_files(&mut self) {
+        let Some(commit) = self.selected_commit().cloned() else { return };
+        let parent = first_parent(&self.repo_path, &commit.hash);
+        match changed_files(&self.repo_path, &commit.hash, parent.as_deref()) {
+            Ok(files) => {
+                self.files = Some(CommitFiles { commit, parent, files, selected: 0, scroll: 0 });
+            }
+            Err(e) => self.message = Some(format!("git diff failed: {}", e)),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Drawing
+// ═══════════════════════════════════════════════════════════════════════════════
+
+pub fn draw(frame: &mut Frame, state: &mut GitLogScreenState, area: Rect, theme: &Theme) {
+    state.poll();
+    let colors = &theme.git_log_screen;
+
+    frame.render_widget(Block::default().style(Style::default().bg(colors.bg)), area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // header
+            Constraint::Min(3),    // content
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    draw_header(frame, state, layout[0], colors);
+    if state.files.is_some() {
+        draw_files(frame, state, layout[1], colors);
+    } else {
+        draw_log(frame, state, layout[1], colors);
+    }
+    draw_footer(frame, state, layout[2], colors);
+}
+
+fn draw_header(frame: &mut Frame, state: &GitLogScreenState, area: Rect, colors: &GitLogScreenColors) {
+    let scope = if state.all_branches { "all branches" } else { "HEAD" };
+    let count = if state.finished {
+        format!("{} commits", state.commit_count())
+    } else {
+        format!("{}+ commits", state.commit_count())
+    };
+    let prefix = format!(" [{}] {}  ", scope, count);
+    let path = state.repo_path.to_string_lossy();
+    let path = truncate_to_display_width(&path, (area.width as usize).saturating_sub(prefix.width()));
+    let spans = vec![
+        Span::styled(" [", Style::default().fg(colors.header_path)),
+        Span::styled(scope, Style::default().fg(colors.header_scope).add_modifier(Modifier::BOLD)),
+        Span::styled(format!("] {}  ", count), Style::default().fg(colors.header_path)),
+        Span::styled(path, Style::default().fg(colors.header_path)),
+    ];
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn draw_log(frame: &mut Frame, state: &mut GitLogScreenState, area: Rect, colors: &GitLogScreenColors) {
+    let visible_height = area.height as usize;
+    if state.lines.is_empty() {
+        let text = if state.loading { "  Loading history..." } else { "  No commits" };
+        frame.render_widget(Paragraph::new(Span::styled(text, Style::default().fg(colors.empty_text))), area);
+        return;
+    }
+
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    }
+    if state.selected >= state.scroll + visible_height {
+        state.scroll = state.selected + 1 - visible_height;
+    }
+    // 끝에 가까워지면 다음 배치를 미리 읽음
+    if state.scroll + visible_height * 2 >= state.lines.len() {
+        state.request_more();
+    }
+
+    let width = (area.width as usize).saturating_sub(1); // scrollbar
+    let show_columns = width >= 70;
+    let right_width = if show_columns { AUTHOR_WIDTH + DATE_WIDTH + 2 } else { 0 };
+
+    let mut lines = Vec::new();
+    for (i, row) in state.lines.iter().enumerate().skip(state.scroll).take(visible_height) {
+        let Some(ref commit) = row.commit else {
+            lines.push(Line::from(Span::styled(row.graph.clone(), Style::default().fg(colors.graph))));
+            continue;
+        };
+        let is_marked = state.marked.contains(&commit.hash);
+        let marker = if is_marked { "*" } else { " " };
+        let refs = if commit.refs.is_empty() { String::new() } else { format!("({}) ", commit.refs) };
+        let head = format!("{}{} {} ", marker, row.graph, commit.short);
+        let left_width = width.saturating_sub(right_width);
+        let subject_width = left_width.saturating_sub(head.width() + refs.width());
+        let subject = pad_to_display_width(&truncate_to_display_width(&commit.subject, subject_width), subject_width);
+        let (author, date) = if show_columns {
+            (
+                format!(" {}", pad_to_display_width(&truncate_to_display_width(&commit.author, AUTHOR_WIDTH), AUTHOR_WIDTH)),
+                format!(" {:>width$}", commit.date, width = DATE_WIDTH),
+            )
+        } else {
+            (String::new(), String::new())
+        };
+
+        if i == state.selected {
+            let text = format!("{}{}{}{}{}", head, refs, subject, author, date);
+            lines.push(Line::from(Span::styled(
+                pad_to_display_width(&text, width),
+                Style::default().fg(colors.selected_text).bg(colors.selected_bg),
+            )));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled(marker, Style::default().fg(colors.marker).add_modifier(Modifier::BOLD)),
+                Span::styled(row.graph.clone(), Style::default().fg(colors.graph)),
+                Span::styled(format!(" {} ", commit.short), Style::default().fg(colors.log_hash)),
+                Span::styled(refs, Style::default().fg(colors.refs).add_modifier(Modifier::BOLD)),
+                Span::styled(subject, Style::default().fg(colors.log_subject)),
+                Span::styled(author, Style::default().fg(colors.log_author)),
+                Span::styled(date, Style::default().fg(colors.log_date)),
+            ]));
+        }
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+
+    if state.lines.len() > visible_height {
+        let mut scrollbar_state = ScrollbarState::new(state.lines.len()).position(state.scroll);
+        let scrollbar_area = Rect::new(area.x + area.width.saturating_sub(1), area.y, 1, area.height);
+        frame.render_stateful_widget(Scrollbar::new(ScrollbarOrientation::VerticalRight), scrollbar_area, &mut scrollbar_state);
+    }
+}
+
+fn draw_files(frame: &mut Frame, state: &mut GitLogScreenState, area: Rect, colors: &GitLogScreenColors) {
+    let Some(ref mut view) = state.files else { return };
+    let width = area.width as usize;
+
+    let title = format!(" {} {} ({}, {})", view.commit.short, view.commit.subject, view.commit.author, view.commit.date);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_to_display_width(&title, width),
+            Style::default().fg(colors.detail_subject).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    let visible_height = (area.height as usize).saturating_sub(lines.len());
+    if view.selected < view.scroll {
+        view.scroll = view.selected;
+    }
+    if visible_height > 0 && view.selected >= view.scroll + visible_height {
+        view.scroll = view.selected + 1 - visible_height;
+    }
+
+    if view.files.is_empty() {
+        lines.push(Line::from(Span::styled("  No file changes (merge without conflicts?)", Style::default().fg(colors.empty_text))));
+    }
+    for (i, file) in view.files.iter().enumerate().skip(view.scroll).take(visible_height) {
+        let name = match file.old_path {
+            Some(ref old) => format!("{} -> {}", old, file.path),
+            None => file.path.clone(),
+        };
+        if i == view.selected {
+            lines.push(Line::from(Span::styled(
+                pad_to_display_width(&format!("  {} {}", file.status, name), width),
+                Style::default().fg(colors.selected_text).bg(colors.selected_bg),
+            )));
+        } else {
+            let status_color = match file.status {
+                'A' => colors.file_added,
+                'D' => colors.file_deleted,
+                'R' | 'C' => colors.file_renamed,
+                _ => colors.file_modified,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", file.status), Style::default().fg(status_color).add_modifier(Modifier::BOLD)),
+                Span::styled(name, Style::default().fg(colors.file_name)),
+            ]));
+        }
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn draw_footer(frame: &mut Frame, state: &GitLogScreenState, area: Rect, colors: &GitLogScreenColors) {
+    if let Some(ref msg) = state.message {
+        let display = truncate_to_display_width(&format!(" {} ", msg), area.width as usize);
+        frame.render_widget(
+            Paragraph::new(Span::styled(display, Style::default().fg(colors.message_text).add_modifier(Modifier::BOLD))),
+            area,
+        );
+        return;
+    }
+
+    let shortcuts: Vec<(&str, &str)> = if state.files.is_some() {
+        vec![
+            ("\u{2191}\u{2193}", "nav "),
+            ("Enter", "diff "),
+            ("Esc", "back"),
+        ]
+    } else {
+        vec![
+            ("\u{2191}\u{2193}", "nav "),
+            ("Enter", "files "),
+            ("Spc", "mark "),
+            ("d", "iff commits "),
+            ("a", if state.all_branches { "ll branches: on " } else { "ll branches: off " }),
+            ("Esc", "back"),
+        ]
+    };
+    let mut spans = Vec::new();
+    for (key, rest) in &shortcuts {
+        spans.push(Span::styled(*key, Style::default().fg(colors.footer_key)));
+        spans.push(Span::styled(":", Style::default().fg(colors.footer_text)));
+        spans.push(Span::styled(*rest, Style::default().fg(colors.footer_text)));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Input
+// ═══════════════════════════════════════════════════════════════════════════════
+
+pub fn handle_input(app: &mut App, code: KeyCode, _modifiers: KeyModifiers) {
+    let Some(mut state) = app.git_log_screen_state.take() else { return };
+    state.message = None;
+
+    if state.files.is_some() {
+        handle_files_input(app, &mut state, code);
+        app.git_log_screen_state = Some(state);
+        return;
+    }
+
+    let page = 10;
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.current_screen = Screen::FilePanel;
+            return;
+        }
+        KeyCode::Up | KeyCode::Char('k') => state.move_selection(-1),
+        KeyCode::Down | KeyCode::Char('j') => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-page),
+        KeyCode::PageDown => state.move_selection(page),
+        KeyCode::Home => state.selected = state.lines.iter().position(|l| l.commit.is_some()).unwrap_or(0),
+        KeyCode::End => state.selected = state.lines.iter().rposition(|l| l.commit.is_some()).unwrap_or(0),
+        KeyCode::Enter => state.open_files(),
+        KeyCode::Char(' ') => {
+            state.toggle_mark();
+            state.move_selection(1);
+        }
+        KeyCode::Char('a') => {
+            state.all_branches = !state.all_branches;
+            state.reload();
+        }
+        KeyCode::Char('d') => {
+            if state.marked.len() == 2 {
+                compare_marked(app, &state);
+            } else {
+                // 표시한 커밋이 두 개가 아니면 기존 커밋 선택 다이얼로그로 고름
+                app.git_log_screen_state = Some(state);
+                app.show_git_log_diff_dialog();
+                return;
+            }
+        }
+        _ => {}
+    }
+    app.git_log_screen_state = Some(state);
+}
+
+fn handle_files_input(app: &mut App, state: &mut GitLogScreenState, code: KeyCode) {
+    let Some(ref mut view) = state.files else { return };
+    let max = view.files.len().saturating_sub(1);
+    match code {
+        KeyCode::Esc | KeyCode::Left | KeyCode::Char('q') => state.files = None,
+        KeyCode::Up | KeyCode::Char('k') => view.selected = view.selected.saturating_sub(1),
+        KeyCode::Down | KeyCode::Char('j') => view.selected = (view.selected + 1).min(max),
+        KeyCode::PageUp => view.selected = view.selected.saturating_sub(10),
+        KeyCode::PageDown => view.selected = (view.selected + 10).min(max),
+        KeyCode::Home => view.selected = 0,
+        KeyCode::End => view.selected = max,
+        KeyCode::Enter => {
+            let Some(file) = view.files.get(view.selected).cloned() else { return };
+            if let Err(e) = open_file_diff(app, &state.repo_path, view, &file) {
+                state.message = Some(e);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Write both versions of `file` to a temp folder and show them in the file diff view
+fn open_file_diff(app: &mut App, repo: &Path, view: &CommitFiles, file: &ChangedFile) -> Result<(), String> {
+    let base = dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join(".cokacdir").join("tmp").join("gitlog");
+    let _ = std::fs::remove_dir_all(&base);
+
+    let name = Path::new(&file.path).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| "file".to_string());
+    let old_rev = view.parent.as_deref().unwrap_or(EMPTY_TREE);
+    let old_path = file.old_path.as_deref().unwrap_or(&file.path);
+    let left = base.join("before").join(&name);
+    let right = base.join(&view.commit.short).join(&name);
+    // An added or deleted file is missing on one side and shows up empty there
+    for (target, rev, path) in [(&left, old_rev, old_path), (&right, view.commit.hash.as_str(), file.path.as_str())] {
+        std::fs::create_dir_all(target.parent().unwrap_or(&base))
+            .and_then(|_| std::fs::write(target, file_at(repo, rev, path)))
+            .map_err(|e| format!("Cannot write {}: {}", target.display(), e))?;
+    }
+
+    let mut diff = crate::ui::diff_file_view::DiffFileViewState::new(left, right, format!("{} @ {}", file.path, view.commit.short));
+    diff.return_screen = Screen::GitLogScreen;
+    diff.create_image_protocols(app.image_picker.as_mut());
+    app.diff_file_view_state = Some(diff);
+    app.current_screen = Screen::DiffFileView;
+    Ok(())
+}
+
+/// Compare the two marked commits as folders (same as the Git log diff dialog)
+fn compare_marked(app: &mut App, state: &GitLogScreenState) {
+    let project_name = state.repo_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "project".to_string());
+    app.git_log_diff_state = Some(GitLogDiffState {
+        repo_path: state.repo_path.clone(),
+        project_name,
+        log_entries: Vec::new(),
+        selected_index: 0,
+        scroll_offset: 0,
+        selected_commits: state.marked.clone(),
+        visible_height: 0,
+    });
+    app.execute_git_log_diff();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_line() {
+        let line = parse_log_line("| * \u{1f}abc123def\u{1f}abc123d\u{1f}Jane Doe\u{1f}2024-05-01 10:20\u{1f}HEAD -> main\u{1f}Fix | pipes");
+        assert_eq!(line.graph, "| *");
+        let commit = line.commit.unwrap();
+        assert_eq!(commit.short, "abc123d");
+        assert_eq!(commit.refs, "HEAD -> main");
+        assert_eq!(commit.subject, "Fix | pipes");
+
+        let connector = parse_log_line("|\\  ");
+        assert_eq!(connector.graph, "|\\");
+        assert!(connector.commit.is_none());
+    }
+
+    #[test]
+    fn test_parse_name_status() {
+        let files = parse_name_status(b"M\0src/a.rs\0R095\0old.txt\0new.txt\0D\0gone\0");
+        assert_eq!(files, vec![
+            ChangedFile { status: 'M', path: "src/a.rs".into(), old_path: None },
+            ChangedFile { status: 'R', path: "new.txt".into(), old_path: Some("old.txt".into()) },
+            ChangedFile { status: 'D', path: "gone".into(), old_path: None },
+        ]);
+    }
+}
@@ -1,5 +1,6 @@
+use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
@@ -24,6 +25,7 @@ pub enum GitTab {
     Commit,
     Log,
     Branch,
+    Stash,
 }
 
 #[derive(Debug, Clone)]
@@ -48,18 +50,81 @@ pub struct GitBranchEntry {
     pub name: String,
     pub is_current: bool,
     pub is_remote: bool,
+    /// Upstream branch (e.g. "origin/main"), empty when none is set
+    pub upstream: String,
+    pub ahead: usize,
+    pub behind: usize,
+    /// The upstream branch no longer exists
+    pub upstream_gone: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct GitStashEntry {
+    /// Reference such as "stash@{0}"
+    pub name: String,
+    pub message: String,
+    pub date: String,
 }
 
 #[derive(Debug, Clone)]
 pub enum InputMode {
     BranchCreate,
     CommitAmend,
+    StashPush { include_untracked: bool },
 }
 
 #[derive(Debug, Clone)]
 pub enum ConfirmAction {
     BranchDelete(String),
     RestoreToCommit(String), // hash
+    StashDrop(String),       // stash@{n}
+}
+
+/// 파일 하나의 diff: 머리 줄과 hunk 목록
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDiff {
+    /// Lines before the first hunk (diff --git, index, ---, +++)
+    pub header: Vec<String>,
+    /// Hunks, each starting with its @@ line
+    pub hunks: Vec<Vec<String>>,
+}
+
+/// Commit 탭에서 파일 하나의 변경을 hunk 단위로 스테이징하는 보기
+pub struct HunkView {
+    pub file: String,
+    /// Showing the staged (index) diff, where Space unstages instead of stages
+    pub staged: bool,
+    pub diff: Option<FileDiff>,
+    pub selected: usize,
+    pub scroll: usize,
+}
+
+impl HunkView {
+    fn open(repo_path: &Path, file: &str, staged: bool) -> Self {
+        let mut view = Self { file: file.to_string(), staged, diff: None, selected: 0, scroll: 0 };
+        view.reload(repo_path);
+        view
+    }
+
+    fn reload(&mut self, repo_path: &Path) {
+        self.diff = parse_file_diff(&get_file_diff(repo_path, &self.file, self.staged));
+        self.selected = self.selected.min(self.hunk_count().saturating_sub(1));
+    }
+
+    fn hunk_count(&self) -> usize {
+        self.diff.as_ref().map(|d| d.hunks.len()).unwrap_or(0)
+    }
+
+    /// Line offset of each hunk in the displayed diff
+    fn hunk_starts(&self) -> Vec<usize> {
+        let mut starts = Vec::new();
+        let mut offset = 0;
+        for hunk in self.diff.iter().flat_map(|d| d.hunks.iter()) {
+            starts.push(offset);
+            offset += hunk.len();
+        }
+        starts
+    }
 }
 
 pub struct GitScreenState {
@@ -77,6 +142,7 @@ pub struct GitScreenState {
     pub commit_input_active: bool,
     pub commit_selected: usize,
     pub commit_scroll: usize,
+    pub hunk_view: Option<HunkView>,
 
     // Log tab
     pub log_entries: Vec<GitLogEntry>,
@@ -90,6 +156,11 @@ pub struct GitScreenState {
     pub branch_selected: usize,
     pub branch_scroll: usize,
 
+    // Stash tab
+    pub stashes: Vec<GitStashEntry>,
+    pub stash_selected: usize,
+    pub stash_scroll: usize,
+
     // Dialog
     pub input_mode: Option<InputMode>,
     pub input_buffer: String,
@@ -101,11 +172,11 @@ pub struct GitScreenState {
 
 impl GitScreenState {
     pub fn new(repo_path: PathBuf) -> Self {
-        stage_all(&repo_path);
         let branch_name = get_current_branch(&repo_path);
         let status_files = get_status(&repo_path);
         let log_entries = get_log(&repo_path, 200);
         let branches = get_branches(&repo_path);
+        let stashes = get_stashes(&repo_path);
 
         Self {
             repo_path,
@@ -118,6 +189,7 @@ impl GitScreenState {
             commit_input_active: false,
             commit_selected: 0,
             commit_scroll: 0,
+            hunk_view: None,
             log_entries,
             log_selected: 0,
             log_scroll: 0,
@@ -126,6 +198,9 @@ impl GitScreenState {
             branches,
             branch_selected: 0,
             branch_scroll: 0,
+            stashes,
+            stash_selected: 0,
+            stash_scroll: 0,
             input_mode: None,
             input_buffer: String::new(),
             confirm_action: None,
@@ -151,12 +226,16 @@ impl GitScreenState {
         self.refresh_status();
         self.log_entries = get_log(&self.repo_path, 200);
         self.branches = get_branches(&self.repo_path);
+        self.stashes = get_stashes(&self.repo_path);
         if self.log_selected >= self.log_entries.len() {
             self.log_selected = self.log_entries.len().saturating_sub(1);
         }
         if self.branch_selected >= self.branches.len() {
             self.branch_selected = self.branches.len().saturating_sub(1);
         }
+        if self.stash_selected >= self.stashes.len() {
+            self.stash_selected = self.stashes.len().saturating_sub(1);
+        }
     }
 
     fn show_msg(&mut self, msg: &str) {
@@ -364,7 +443,7 @@ fn get_branches(path: &Path) -> Vec<GitBranchEntry> {
     let output = git_cmd(path)
         .args([
             "branch",
-            "--format=%(HEAD)|%(refname:short)|%(objectname:short)|%(refname)",
+            "--format=%(HEAD)|%(refname:short)|%(objectname:short)|%(refname)|%(upstream:short)|%(upstream:track,nobracket)",
         ])
         .output();
 
@@ -377,16 +456,22 @@ fn get_branches(path: &Path) -> Vec<GitBranchEntry> {
     let mut entries = Vec::new();
 
     for line in stdout.lines() {
-        let parts: Vec<&str> = line.splitn(4, '|').collect();
+        let parts: Vec<&str> = line.splitn(6, '|').collect();
         if parts.len() >= 2 {
             let is_current = parts[0] == "*";
             let name = parts[1].to_string();
             let is_remote = parts.get(3).is_some_and(|r| r.starts_with("refs/remotes/"));
+            let upstream = parts.get(4).unwrap_or(&"").to_string();
+            let (ahead, behind, upstream_gone) = parse_upstream_track(parts.get(5).unwrap_or(&""));
 
             entries.push(GitBranchEntry {
                 name,
                 is_current,
                 is_remote,
+                upstream,
+                ahead,
+                behind,
+                upstream_gone,
             });
         }
     }
@@ -394,6 +479,109 @@ fn get_branches(path: &Path) -> Vec<GitBranchEntry> {
     entries
 }
 
+/// Parse `%(upstream:track,nobracket)`: "ahead 2, behind 1", "gone" or empty
+fn parse_upstream_track(track: &str) -> (usize, usize, bool) {
+    let mut ahead = 0;
+    let mut behind = 0;
+    for part in track.split(',').map(str::trim) {
+        if let Some(n) = part.strip_prefix("ahead ") {
+            ahead = n.parse().unwrap_or(0);
+        } else if let Some(n) = part.strip_prefix("behind ") {
+            behind = n.parse().unwrap_or(0);
+        } else if part == "gone" {
+            return (0, 0, true);
+        }
+    }
+    (ahead, behind, false)
+}
+
+fn get_stashes(path: &Path) -> Vec<GitStashEntry> {
+    let output = git_cmd(path)
+        .args(["stash", "list", "--format=%gd|%cr|%gs"])
+        .output();
+
+    let output = match output {
+        Ok(o) if o.status.success() => o,
+        _ => return Vec::new(),
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.splitn(3, '|').collect();
+            if parts.len() < 3 {
+                return None;
+            }
+            Some(GitStashEntry {
+                name: parts[0].to_string(),
+                date: parts[1].to_string(),
+                message: parts[2].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Only "stash@{N}" references are passed to git
+fn is_valid_stash_ref(name: &str) -> bool {
+    name.strip_prefix("stash@{")
+        .and_then(|rest| rest.strip_suffix('}'))
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn stash_push(path: &Path, message: &str, include_untracked: bool) -> Result<String, String> {
+    let mut cmd = git_commit_cmd(path);
+    cmd.args(["stash", "push"]);
+    if include_untracked {
+        cmd.arg("--include-untracked");
+    }
+    if !message.is_empty() {
+        cmd.args(["-m", message]);
+    }
+    let output = cmd.output().map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// `git stash apply|pop|drop <stash>`
+fn stash_command(path: &Path, action: &str, stash: &str) -> Result<(), String> {
+    if !is_valid_stash_ref(stash) {
+        return Err("Invalid stash reference".to_string());
+    }
+    let output = git_cmd(path)
+        .args(["stash", action, stash])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        // 충돌 시 git은 stdout에 설명을 남김
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        if stderr.is_empty() {
+            Err(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        } else {
+            Err(stderr)
+        }
+    }
+}
+
+fn get_stash_diff(path: &Path, stash: &str) -> String {
+    if !is_valid_stash_ref(stash) {
+        return String::new();
+    }
+    git_cmd(path)
+        .args(["stash", "show", "--stat", "--patch", stash])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        .unwrap_or_default()
+}
+
 fn stage_all(path: &Path) {
     let _ = git_cmd(path)
         .args(["add", "-A"])
@@ -426,6 +614,70 @@ fn unstage_file(path: &Path, file: &str) -> Result<(), String> {
     }
 }
 
+/// Split a single-file `git diff` into its header and hunks (None when there
+/// are no text hunks, e.g. binary files or no changes)
+fn parse_file_diff(diff: &str) -> Option<FileDiff> {
+    let mut header = Vec::new();
+    let mut hunks: Vec<Vec<String>> = Vec::new();
+    // split('\n') instead of lines() keeps the '\r' of CRLF files, which the patch needs
+    for line in diff.split('\n') {
+        if line.starts_with("@@") {
+            hunks.push(vec![line.to_string()]);
+        } else if let Some(hunk) = hunks.last_mut() {
+            hunk.push(line.to_string());
+        } else {
+            header.push(line.to_string());
+        }
+    }
+    // 마지막 줄바꿈 뒤의 빈 조각 제거
+    if let Some(hunk) = hunks.last_mut() {
+        if hunk.last().is_some_and(|l| l.is_empty()) {
+            hunk.pop();
+        }
+    }
+    if hunks.is_empty() {
+        None
+    } else {
+        Some(FileDiff { header, hunks })
+    }
+}
+
+/// Patch containing only hunk `index` of the file
+fn hunk_patch(diff: &FileDiff, index: usize) -> Option<String> {
+    let hunk = diff.hunks.get(index)?;
+    let mut patch = String::new();
+    for line in diff.header.iter().chain(hunk.iter()) {
+        patch.push_str(line);
+        patch.push('\n');
+    }
+    Some(patch)
+}
+
+/// Apply a patch to the index only (`git apply --cached`); `reverse` unstages it
+fn apply_to_index(path: &Path, patch: &str, reverse: bool) -> Result<(), String> {
+    let mut cmd = git_cmd(path);
+    cmd.args(["apply", "--cached", "--whitespace=nowarn"]);
+    if reverse {
+        cmd.arg("--reverse");
+    }
+    cmd.arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(patch.as_bytes()).map_err(|e| e.to_string())?;
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
 fn do_commit(path: &Path, message: &str) -> Result<String, String> {
     let output = git_commit_cmd(path)
         .args(["commit", "-m", message])
@@ -639,6 +891,7 @@ fn draw_tab_bar(
         (GitTab::Commit, "1:Commit"),
         (GitTab::Log, "2:Log"),
         (GitTab::Branch, "3:Branch"),
+        (GitTab::Stash, "4:Stash"),
     ];
 
     let bg_style = Style::default().bg(colors.tab_bar_bg);
@@ -671,6 +924,7 @@ fn draw_content(
         GitTab::Commit => draw_commit_tab(frame, state, area, colors),
         GitTab::Log => draw_log_tab(frame, state, area, colors),
         GitTab::Branch => draw_branch_tab(frame, state, area, colors),
+        GitTab::Stash => draw_stash_tab(frame, state, area, colors),
     }
 }
 
@@ -715,13 +969,19 @@ fn draw_commit_tab(
         draw_diff_detail(frame, state, area, colors, false);
         return;
     }
+    if state.hunk_view.is_some() {
+        draw_hunk_view(frame, state, area, colors);
+        return;
+    }
 
-    // Split: file list (top) + message input (bottom 3 lines)
+    // Split: file list (top) + message input (bottom, grows with the message up to 6 lines)
+    let message_lines = state.commit_message.split('\n').count().max(1);
+    let input_height = (message_lines as u16).min(6) + 2;
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(3),    // file list
-            Constraint::Length(3), // commit message input
+            Constraint::Min(3),               // file list
+            Constraint::Length(input_height), // commit message input
         ])
         .split(area);
 
@@ -750,7 +1010,14 @@ fn draw_commit_tab(
         for (i, entry) in state.status_files.iter().enumerate().skip(state.commit_scroll).take(visible_height) {
             let is_selected = !state.commit_input_active && i == state.commit_selected;
             let status_char = file_status_char(entry);
-            let prefix = if entry.staged { "+" } else { " " };
+            // ~ : 일부만 스테이징됨 (인덱스와 작업 트리 모두 변경)
+            let prefix = if entry.staged && entry.worktree_status != ' ' {
+                "~"
+            } else if entry.staged {
+                "+"
+            } else {
+                " "
+            };
             let text = format!(" {}[{}] {}", prefix, status_char, entry.path);
 
             let style = if is_selected {
@@ -792,32 +1059,136 @@ fn draw_commit_tab(
         .title(" Commit Message ");
 
     let inner_width = input_area.width.saturating_sub(2) as usize; // border 2칸 제외
-    let display_text = if state.commit_message.is_empty() && !state.commit_input_active {
-        "Press Tab to enter commit message...".to_string()
-    } else {
-        truncate_to_display_width(&state.commit_message, inner_width)
-    };
+    let inner_height = input_area.height.saturating_sub(2) as usize;
+    let placeholder = state.commit_message.is_empty() && !state.commit_input_active;
 
-    let text_style = if state.commit_message.is_empty() && !state.commit_input_active {
+    let text_style = if placeholder {
         Style::default().fg(colors.footer_text)
     } else {
         Style::default().fg(colors.commit_input_text)
     };
 
-    let input_paragraph = Paragraph::new(Span::styled(display_text, text_style))
-        .block(input_block);
+    // 입력 칸보다 긴 메시지는 마지막 줄들을 보여줌
+    let message_lines: Vec<&str> = state.commit_message.split('\n').collect();
+    let shown = &message_lines[message_lines.len().saturating_sub(inner_height)..];
+    let display_lines: Vec<Line> = if placeholder {
+        vec![Line::from(Span::styled("Press Tab to enter commit message...", text_style))]
+    } else {
+        shown.iter()
+            .map(|l| Line::from(Span::styled(truncate_to_display_width(l, inner_width), text_style)))
+            .collect()
+    };
+
+    let input_paragraph = Paragraph::new(display_lines).block(input_block);
     frame.render_widget(input_paragraph, input_area);
 
     // Show cursor when input is active
     if state.commit_input_active {
-        let cursor_x = input_area.x + 1 + UnicodeWidthStr::width(state.commit_message.as_str()) as u16;
-        let cursor_y = input_area.y + 1;
+        let last = shown.last().copied().unwrap_or("");
+        let cursor_x = input_area.x + 1 + UnicodeWidthStr::width(last) as u16;
+        let cursor_y = input_area.y + shown.len().max(1) as u16;
         if cursor_x < input_area.x + input_area.width - 1 {
             frame.set_cursor_position((cursor_x, cursor_y));
         }
     }
 }
 
+fn diff_line_style(line: &str, colors: &super::theme::GitScreenColors) -> Style {
+    if line.starts_with('+') && !line.starts_with("+++") {
+        Style::default().fg(colors.diff_add)
+    } else if line.starts_with('-') && !line.starts_with("---") {
+        Style::default().fg(colors.diff_remove)
+    } else if line.starts_with("@@") || line.starts_with("diff ") || line.starts_with("index ") {
+        Style::default().fg(colors.diff_header).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(colors.log_message)
+    }
+}
+
+fn draw_hunk_view(
+    frame: &mut Frame,
+    state: &mut GitScreenState,
+    area: Rect,
+    colors: &super::theme::GitScreenColors,
+) {
+    let view = match state.hunk_view.as_mut() {
+        Some(v) => v,
+        None => return,
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // file + which diff
+            Constraint::Min(1),    // hunks
+        ])
+        .split(area);
+
+    let count = view.hunk_count();
+    let which = if view.staged { "staged" } else { "unstaged" };
+    let title = format!(" {} — {} changes, hunk {}/{}", view.file, which, (view.selected + 1).min(count), count);
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            truncate_to_display_width(&title, area.width as usize),
+            Style::default().fg(colors.diff_header).add_modifier(Modifier::BOLD),
+        )),
+        chunks[0],
+    );
+
+    let body = chunks[1];
+    let diff = match view.diff {
+        Some(ref d) => d,
+        None => {
+            let msg = format!("  No {} text changes (Tab shows the {} side)", which, if view.staged { "unstaged" } else { "staged" });
+            frame.render_widget(
+                Paragraph::new(Span::styled(msg, Style::default().fg(colors.footer_text))),
+                body,
+            );
+            return;
+        }
+    };
+
+    let visible_height = body.height as usize;
+    let starts = view.hunk_starts();
+    let total: usize = diff.hunks.iter().map(|h| h.len()).sum();
+
+    view.scroll = view.scroll.min(total.saturating_sub(visible_height));
+    // 선택한 hunk가 화면에 조금도 보이지 않을 때만 그 머리 줄로 스크롤
+    let start = starts.get(view.selected).copied().unwrap_or(0);
+    let end = start + diff.hunks.get(view.selected).map(|h| h.len()).unwrap_or(0);
+    if end <= view.scroll || start >= view.scroll + visible_height {
+        view.scroll = start.min(total.saturating_sub(visible_height));
+    }
+
+    let max_width = (body.width as usize).saturating_sub(2);
+    let mut lines = Vec::new();
+    let all_lines = diff.hunks.iter().enumerate().flat_map(|(i, h)| h.iter().map(move |l| (i, l)));
+    for (hunk_idx, line) in all_lines.skip(view.scroll).take(visible_height) {
+        let gutter = if hunk_idx == view.selected {
+            Span::styled("\u{258c}", Style::default().fg(colors.tab_active))
+        } else {
+            Span::raw(" ")
+        };
+        let text = line.trim_end_matches('\r');
+        lines.push(Line::from(vec![
+            gutter,
+            Span::styled(truncate_to_display_width(text, max_width), diff_line_style(text, colors)),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines), body);
+
+    // Scrollbar
+    if total > visible_height {
+        let mut scrollbar_state = ScrollbarState::new(total).position(view.scroll);
+        let scrollbar_area = Rect::new(body.x + body.width.saturating_sub(1), body.y, 1, body.height);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            scrollbar_area,
+            &mut scrollbar_state,
+        );
+    }
+}
+
 fn draw_log_tab(
     frame: &mut Frame,
     state: &mut GitScreenState,
@@ -945,18 +1316,7 @@ fn draw_diff_detail(
 
     for line in diff_lines.iter().skip(state.log_detail_scroll).take(visible_height) {
         let truncated = truncate_to_display_width(line, max_width);
-
-        let style = if line.starts_with('+') && !line.starts_with("+++") {
-            Style::default().fg(colors.diff_add)
-        } else if line.starts_with('-') && !line.starts_with("---") {
-            Style::default().fg(colors.diff_remove)
-        } else if line.starts_with("@@") || line.starts_with("diff ") || line.starts_with("index ") {
-            Style::default().fg(colors.diff_header).add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(colors.log_message)
-        };
-
-        lines.push(Line::from(Span::styled(truncated, style)));
+        lines.push(Line::from(Span::styled(truncated, diff_line_style(line, colors))));
     }
 
     if with_left_border {
@@ -1011,7 +1371,7 @@ fn draw_branch_tab(
     for (i, branch) in state.branches.iter().enumerate().skip(state.branch_scroll).take(visible_height) {
         let is_selected = i == state.branch_selected;
         let prefix = if branch.is_current { "* " } else { "  " };
-        let text = format!(" {}{}", prefix, branch.name);
+        let text = format!(" {}{}{}", prefix, branch.name, branch_track_text(branch));
 
         let style = if is_selected {
             Style::default().fg(colors.selected_text).bg(colors.selected_bg)
@@ -1043,6 +1403,91 @@ fn draw_branch_tab(
     }
 }
 
+/// "  ↑2 ↓1 origin/main" (commits ahead/behind the upstream)
+fn branch_track_text(branch: &GitBranchEntry) -> String {
+    if branch.upstream.is_empty() {
+        return String::new();
+    }
+    if branch.upstream_gone {
+        return format!("  {} (gone)", branch.upstream);
+    }
+    let mut text = String::from("  ");
+    if branch.ahead > 0 {
+        text.push_str(&format!("\u{2191}{} ", branch.ahead));
+    }
+    if branch.behind > 0 {
+        text.push_str(&format!("\u{2193}{} ", branch.behind));
+    }
+    if branch.ahead == 0 && branch.behind == 0 {
+        text.push_str("= ");
+    }
+    text.push_str(&branch.upstream);
+    text
+}
+
+fn draw_stash_tab(
+    frame: &mut Frame,
+    state: &mut GitScreenState,
+    area: Rect,
+    colors: &super::theme::GitScreenColors,
+) {
+    if state.log_detail.is_some() {
+        draw_diff_detail(frame, state, area, colors, false);
+        return;
+    }
+
+    if state.stashes.is_empty() {
+        let msg = Paragraph::new(Line::from(Span::styled(
+            "  No stashes (n: stash changes, u: including untracked files)",
+            Style::default().fg(colors.footer_text),
+        )));
+        frame.render_widget(msg, area);
+        return;
+    }
+
+    let visible_height = area.height as usize;
+
+    if state.stash_selected < state.stash_scroll {
+        state.stash_scroll = state.stash_selected;
+    }
+    if state.stash_selected >= state.stash_scroll + visible_height {
+        state.stash_scroll = state.stash_selected - visible_height + 1;
+    }
+
+    let mut lines = Vec::new();
+    let max_width = area.width as usize;
+
+    for (i, stash) in state.stashes.iter().enumerate().skip(state.stash_scroll).take(visible_height) {
+        if i == state.stash_selected {
+            let text = format!(" {} {} ({})", stash.name, stash.message, stash.date);
+            lines.push(Line::from(Span::styled(
+                pad_to_display_width(&text, max_width),
+                Style::default().fg(colors.selected_text).bg(colors.selected_bg),
+            )));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {} ", stash.name), Style::default().fg(colors.log_hash)),
+                Span::styled(&stash.message, Style::default().fg(colors.log_message)),
+                Span::styled(format!(" ({})", stash.date), Style::default().fg(colors.log_date)),
+            ]));
+        }
+    }
+
+    frame.render_widget(Paragraph::new(lines), area);
+
+    // Scrollbar
+    if state.stashes.len() > visible_height {
+        let mut scrollbar_state = ScrollbarState::new(state.stashes.len())
+            .position(state.stash_scroll);
+        let scrollbar_area = Rect::new(area.x + area.width.saturating_sub(1), area.y, 1, area.height);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            scrollbar_area,
+            &mut scrollbar_state,
+        );
+    }
+}
+
 fn draw_footer(
     frame: &mut Frame,
     state: &GitScreenState,
@@ -1066,9 +1511,18 @@ fn draw_footer(
             if state.commit_input_active {
                 vec![
                     ("Enter", "commit "),
+                    ("A-Enter", "newline "),
                     ("Tab", "files "),
                     ("Esc", "cancel"),
                 ]
+            } else if let Some(ref view) = state.hunk_view {
+                vec![
+                    ("\u{2191}\u{2193}", "hunk "),
+                    ("Spc", if view.staged { "unstage hunk " } else { "stage hunk " }),
+                    ("Tab", if view.staged { "unstaged " } else { "staged " }),
+                    ("PgUp/Dn", "scroll "),
+                    ("Esc", "close"),
+                ]
             } else if state.log_detail.is_some() {
                 vec![
                     ("\u{2191}\u{2193}", "nav "),
@@ -1079,7 +1533,7 @@ fn draw_footer(
                 vec![
                     ("\u{2191}\u{2193}", "nav "),
                     ("Spc", "stage "),
-                    ("Enter", "diff "),
+                    ("Enter", "hunks "),
                     ("^a", "ll "),
                     ("Tab", "msg "),
                     ("a", "mend "),
@@ -1115,6 +1569,27 @@ fn draw_footer(
             ("\u{2190}\u{2192}", "tab "),
             ("Esc", "back"),
         ],
+        GitTab::Stash => {
+            if state.log_detail.is_some() {
+                vec![
+                    ("\u{2191}\u{2193}", "scroll "),
+                    ("PgUp/Dn", "page "),
+                    ("Esc", "close"),
+                ]
+            } else {
+                vec![
+                    ("\u{2191}\u{2193}", "nav "),
+                    ("Enter", "show "),
+                    ("a", "pply "),
+                    ("p", "op "),
+                    ("n", "ew "),
+                    ("u", "ntracked "),
+                    ("x", "drop "),
+                    ("\u{2190}\u{2192}", "tab "),
+                    ("Esc", "back"),
+                ]
+            }
+        }
     };
 
     let mut spans = Vec::new();
@@ -1136,6 +1611,8 @@ fn draw_input_dialog(
     let title = match &state.input_mode {
         Some(InputMode::BranchCreate) => " New Branch Name ",
         Some(InputMode::CommitAmend) => " Amend Message ",
+        Some(InputMode::StashPush { include_untracked: false }) => " Stash Message (optional) ",
+        Some(InputMode::StashPush { include_untracked: true }) => " Stash incl. Untracked (message optional) ",
         None => return,
     };
 
@@ -1179,6 +1656,7 @@ fn draw_confirm_dialog(
     let (msg, title) = match &state.confirm_action {
         Some(ConfirmAction::BranchDelete(name)) => (format!("Delete branch '{}'?", name), " Delete "),
         Some(ConfirmAction::RestoreToCommit(hash)) => (format!("Restore files to {}?", hash), " Restore "),
+        Some(ConfirmAction::StashDrop(name)) => (format!("Drop {}? Its changes are lost.", name), " Drop Stash "),
         None => return,
     };
     let cd = &theme.confirm_dialog;
@@ -1248,7 +1726,8 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
             && state.confirm_action.is_none()
             && state.input_mode.is_none()
             && !state.commit_input_active
-            && state.log_detail.is_none();
+            && state.log_detail.is_none()
+            && state.hunk_view.is_none();
 
         if should_close {
             app.current_screen = Screen::FilePanel;
@@ -1284,6 +1763,18 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
         return;
     }
 
+    // Handle stash diff scrolling in stash tab
+    if state.log_detail.is_some() && state.current_tab == GitTab::Stash {
+        handle_status_diff_input(state, code);
+        return;
+    }
+
+    // Handle hunk staging in commit tab
+    if state.hunk_view.is_some() && state.current_tab == GitTab::Commit {
+        handle_hunk_input(state, code);
+        return;
+    }
+
     // Handle diff detail scrolling in commit tab
     if state.log_detail.is_some() && state.current_tab == GitTab::Commit {
         handle_status_diff_input(state, code);
@@ -1305,11 +1796,16 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
             state.current_tab = GitTab::Branch;
             return;
         }
+        KeyCode::Char('4') => {
+            state.current_tab = GitTab::Stash;
+            return;
+        }
         KeyCode::Left => {
             state.current_tab = match state.current_tab {
-                GitTab::Commit => GitTab::Branch,
+                GitTab::Commit => GitTab::Stash,
                 GitTab::Log => GitTab::Commit,
                 GitTab::Branch => GitTab::Log,
+                GitTab::Stash => GitTab::Branch,
             };
             if matches!(state.current_tab, GitTab::Commit) {
                 state.refresh_status();
@@ -1320,7 +1816,8 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
             state.current_tab = match state.current_tab {
                 GitTab::Commit => GitTab::Log,
                 GitTab::Log => GitTab::Branch,
-                GitTab::Branch => GitTab::Commit,
+                GitTab::Branch => GitTab::Stash,
+                GitTab::Stash => GitTab::Commit,
             };
             if matches!(state.current_tab, GitTab::Commit) {
                 state.refresh_status();
@@ -1335,6 +1832,7 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
         GitTab::Commit => handle_commit_tab_input(state, code, modifiers),
         GitTab::Log => handle_log_input(state, code),
         GitTab::Branch => handle_branch_input(state, code),
+        GitTab::Stash => handle_stash_input(state, code),
     }
 }
 
@@ -1369,6 +1867,65 @@ fn handle_status_diff_input(state: &mut GitScreenState, code: KeyCode) {
     }
 }
 
+fn handle_hunk_input(state: &mut GitScreenState, code: KeyCode) {
+    let view = match state.hunk_view.as_mut() {
+        Some(v) => v,
+        None => return,
+    };
+    let count = view.hunk_count();
+
+    match code {
+        KeyCode::Esc => {
+            state.hunk_view = None;
+            state.refresh_status();
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            view.selected = view.selected.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if view.selected + 1 < count {
+                view.selected += 1;
+            }
+        }
+        KeyCode::PageUp | KeyCode::PageDown => {
+            // 스크롤하면 화면 맨 위 줄이 속한 hunk가 선택됨
+            view.scroll = if code == KeyCode::PageUp {
+                view.scroll.saturating_sub(20)
+            } else {
+                view.scroll + 20
+            };
+            let total: usize = view.diff.iter().flat_map(|d| d.hunks.iter()).map(|h| h.len()).sum();
+            view.scroll = view.scroll.min(total.saturating_sub(1));
+            view.selected = view.hunk_starts().iter().rposition(|&s| s <= view.scroll).unwrap_or(0);
+        }
+        KeyCode::Tab => {
+            view.staged = !view.staged;
+            view.selected = 0;
+            view.scroll = 0;
+            view.reload(&state.repo_path);
+        }
+        KeyCode::Char(' ') | KeyCode::Char('s') => {
+            let patch = match view.diff.as_ref().and_then(|d| hunk_patch(d, view.selected)) {
+                Some(p) => p,
+                None => return,
+            };
+            let unstage = view.staged;
+            match apply_to_index(&state.repo_path, &patch, unstage) {
+                Ok(()) => {
+                    view.reload(&state.repo_path);
+                    state.refresh_status();
+                    state.show_msg(if unstage { "Hunk unstaged" } else { "Hunk staged" });
+                }
+                Err(e) => {
+                    let short_err = e.lines().next().unwrap_or("Failed to apply hunk").to_string();
+                    state.show_msg(&short_err);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 fn handle_commit_tab_input(state: &mut GitScreenState, code: KeyCode, modifiers: KeyModifiers) {
     let len = state.status_files.len();
     if len == 0 && code != KeyCode::Tab {
@@ -1377,11 +1934,11 @@ fn handle_commit_tab_input(state: &mut GitScreenState, code: KeyCode, modifiers:
 
     // Ctrl+A: stage/unstage all
     if modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('a') {
-        let has_unstaged = state.status_files.iter().any(|f| !f.staged);
-        for entry in &state.status_files {
-            if has_unstaged && !entry.staged {
-                let _ = stage_file(&state.repo_path, &entry.path);
-            } else if !has_unstaged && entry.staged {
+        let has_unstaged = state.status_files.iter().any(|f| !f.staged || f.worktree_status != ' ');
+        if has_unstaged {
+            stage_all(&state.repo_path);
+        } else {
+            for entry in &state.status_files {
                 let _ = unstage_file(&state.repo_path, &entry.path);
             }
         }
@@ -1415,10 +1972,10 @@ fn handle_commit_tab_input(state: &mut GitScreenState, code: KeyCode, modifiers:
             }
         }
         KeyCode::Char(' ') => {
-            // Stage/unstage toggle
+            // Stage/unstage toggle (a partially staged file is staged completely)
             if let Some(entry) = state.status_files.get(state.commit_selected) {
                 let path = entry.path.clone();
-                if entry.staged {
+                if entry.staged && entry.worktree_status == ' ' {
                     let _ = unstage_file(&state.repo_path, &path);
                 } else {
                     let _ = stage_file(&state.repo_path, &path);
@@ -1427,20 +1984,21 @@ fn handle_commit_tab_input(state: &mut GitScreenState, code: KeyCode, modifiers:
             }
         }
         KeyCode::Enter => {
-            // Show diff for selected file
+            // Untracked file: show its content; otherwise stage hunk by hunk
             if let Some(entry) = state.status_files.get(state.commit_selected) {
-                let diff = get_file_diff(&state.repo_path, &entry.path, entry.staged);
-                if diff.is_empty() {
+                if entry.index_status == '?' {
                     let full_path = state.repo_path.join(&entry.path);
                     if let Ok(content) = std::fs::read_to_string(&full_path) {
                         state.log_detail = Some(content);
                     } else {
                         state.show_msg("Cannot display file");
                     }
+                    state.log_detail_scroll = 0;
                 } else {
-                    state.log_detail = Some(diff);
+                    // 작업 트리 변경이 없으면 스테이징된 쪽부터 보여줌
+                    let staged = entry.worktree_status == ' ';
+                    state.hunk_view = Some(HunkView::open(&state.repo_path, &entry.path, staged));
                 }
-                state.log_detail_scroll = 0;
             }
         }
         KeyCode::Tab => {
@@ -1463,11 +2021,18 @@ fn handle_commit_tab_input(state: &mut GitScreenState, code: KeyCode, modifiers:
     }
 }
 
-fn handle_commit_input(state: &mut GitScreenState, code: KeyCode, _modifiers: KeyModifiers) {
+fn handle_commit_input(state: &mut GitScreenState, code: KeyCode, modifiers: KeyModifiers) {
     match code {
         KeyCode::Tab | KeyCode::Esc => {
             state.commit_input_active = false;
         }
+        // Alt+Enter / Ctrl+J: 여러 줄 메시지
+        KeyCode::Enter if modifiers.contains(KeyModifiers::ALT) => {
+            state.commit_message.push('\n');
+        }
+        KeyCode::Char('j') if modifiers.contains(KeyModifiers::CONTROL) => {
+            state.commit_message.push('\n');
+        }
         KeyCode::Enter => {
             if state.commit_message.trim().is_empty() {
                 state.show_msg("Commit message is empty");
@@ -1667,6 +2232,68 @@ fn handle_branch_input(state: &mut GitScreenState, code: KeyCode) {
     }
 }
 
+fn handle_stash_input(state: &mut GitScreenState, code: KeyCode) {
+    let len = state.stashes.len();
+
+    match code {
+        KeyCode::Up => {
+            state.stash_selected = state.stash_selected.saturating_sub(1);
+        }
+        KeyCode::Down => {
+            if len > 0 && state.stash_selected + 1 < len {
+                state.stash_selected += 1;
+            }
+        }
+        KeyCode::Home => {
+            state.stash_selected = 0;
+        }
+        KeyCode::End => {
+            state.stash_selected = len.saturating_sub(1);
+        }
+        KeyCode::Char('n') | KeyCode::Char('u') => {
+            // New stash (u: include untracked files)
+            state.input_mode = Some(InputMode::StashPush { include_untracked: code == KeyCode::Char('u') });
+            state.input_buffer.clear();
+        }
+        KeyCode::Enter => {
+            if let Some(stash) = state.stashes.get(state.stash_selected) {
+                let diff = get_stash_diff(&state.repo_path, &stash.name);
+                if diff.is_empty() {
+                    state.show_msg("Cannot display stash");
+                } else {
+                    state.log_detail = Some(diff);
+                    state.log_detail_scroll = 0;
+                }
+            }
+        }
+        KeyCode::Char('a') | KeyCode::Char('p') => {
+            // Apply keeps the stash, pop removes it once applied
+            if let Some(stash) = state.stashes.get(state.stash_selected) {
+                let name = stash.name.clone();
+                let (action, done) = if code == KeyCode::Char('a') { ("apply", "Applied") } else { ("pop", "Popped") };
+                match stash_command(&state.repo_path, action, &name) {
+                    Ok(()) => {
+                        state.show_msg(&format!("{} {}", done, name));
+                        state.refresh_all();
+                    }
+                    Err(e) => {
+                        let short_err = e.lines().next().unwrap_or("Stash failed").to_string();
+                        state.show_msg(&short_err);
+                        state.refresh_all();
+                    }
+                }
+            }
+        }
+        KeyCode::Char('x') => {
+            if let Some(stash) = state.stashes.get(state.stash_selected) {
+                state.confirm_action = Some(ConfirmAction::StashDrop(stash.name.clone()));
+                state.confirm_selected_button = 1;
+            }
+        }
+        _ => {}
+    }
+}
+
 fn handle_input_mode(state: &mut GitScreenState, code: KeyCode) {
     match code {
         KeyCode::Esc => {
@@ -1675,7 +2302,9 @@ fn handle_input_mode(state: &mut GitScreenState, code: KeyCode) {
         }
         KeyCode::Enter => {
             let input = state.input_buffer.clone();
-            if input.trim().is_empty() {
+            // 스태시 메시지는 비워도 됨
+            let optional = matches!(state.input_mode, Some(InputMode::StashPush { .. }));
+            if input.trim().is_empty() && !optional {
                 state.input_mode = None;
                 state.input_buffer.clear();
                 return;
@@ -1707,6 +2336,20 @@ fn handle_input_mode(state: &mut GitScreenState, code: KeyCode) {
                         }
                     }
                 }
+                Some(InputMode::StashPush { include_untracked }) => {
+                    match stash_push(&state.repo_path, input.trim(), include_untracked) {
+                        Ok(msg) => {
+                            let short_msg = msg.lines().next().unwrap_or("Stashed").to_string();
+                            state.show_msg(&short_msg);
+                            state.stash_selected = 0;
+                            state.refresh_all();
+                        }
+                        Err(e) => {
+                            let short_err = e.lines().next().unwrap_or("Stash failed").to_string();
+                            state.show_msg(&short_err);
+                        }
+                    }
+                }
                 None => {}
             }
             state.input_buffer.clear();
@@ -1736,6 +2379,18 @@ fn execute_confirm_action(state: &mut GitScreenState) {
                     }
                 }
             }
+            ConfirmAction::StashDrop(name) => {
+                match stash_command(&state.repo_path, "drop", &name) {
+                    Ok(()) => {
+                        state.show_msg(&format!("Dropped {}", name));
+                        state.refresh_all();
+                    }
+                    Err(e) => {
+                        let short_err = e.lines().next().unwrap_or("Drop failed").to_string();
+                        state.show_msg(&short_err);
+                    }
+                }
+            }
             ConfirmAction::RestoreToCommit(hash) => {
                 match restore_to_commit(&state.repo_path, &hash) {
                     Ok(msg) => {
@@ -1781,3 +2436,106 @@ pub fn handle_paste(state: &mut GitScreenState, text: &str) {
         state.input_buffer.push_str(text);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "diff --git a/f.txt b/f.txt\nindex 1111111..2222222 100644\n--- a/f.txt\n+++ b/f.txt\n@@ -1,3 +1,3 @@\n-a\n+A\n b\n c\n@@ -10,2 +10,3 @@\n x\n y\n+z\n";
+
+    #[test]
+    fn test_parse_file_diff() {
+        let diff = parse_file_diff(DIFF).unwrap();
+        assert_eq!(diff.header.len(), 4);
+        assert_eq!(diff.hunks.len(), 2);
+        assert_eq!(diff.hunks[1], vec!["@@ -10,2 +10,3 @@", " x", " y", "+z"]);
+        assert!(parse_file_diff("").is_none());
+        assert!(parse_file_diff("diff --git a/b.png b/b.png\nBinary files differ\n").is_none());
+
+        let patch = hunk_patch(&diff, 1).unwrap();
+        assert!(patch.starts_with("diff --git a/f.txt b/f.txt\n"));
+        assert!(patch.ends_with("@@ -10,2 +10,3 @@\n x\n y\n+z\n"));
+        assert!(!patch.contains("+A"));
+        assert!(hunk_patch(&diff, 2).is_none());
+    }
+
+    #[test]
+    fn test_stage_single_hunk() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path();
+        let git = |args: &[&str]| git_commit_cmd(repo).args(args).output().map(|o| o.status.success()).unwrap_or(false);
+        if !git(&["init", "-q"]) {
+            return; // git not installed
+        }
+        let original: Vec<String> = (1..=20).map(|i| format!("line {}", i)).collect();
+        std::fs::write(repo.join("f.txt"), original.join("\n") + "\n").unwrap();
+        assert!(git(&["add", "f.txt"]));
+        assert!(git(&["commit", "-q", "-m", "init"]));
+
+        // Two changes far apart → two hunks
+        let mut changed = original.clone();
+        changed[0] = "first".to_string();
+        changed[19] = "last".to_string();
+        std::fs::write(repo.join("f.txt"), changed.join("\n") + "\n").unwrap();
+
+        let mut view = HunkView::open(repo, "f.txt", false);
+        assert_eq!(view.hunk_count(), 2);
+        let patch = hunk_patch(view.diff.as_ref().unwrap(), 1).unwrap();
+        apply_to_index(repo, &patch, false).unwrap();
+
+        let staged = get_file_diff(repo, "f.txt", true);
+        assert!(staged.contains("+last") && !staged.contains("+first"));
+        view.reload(repo);
+        assert_eq!(view.hunk_count(), 1);
+
+        // And back out of the index
+        let mut staged_view = HunkView::open(repo, "f.txt", true);
+        let patch = hunk_patch(staged_view.diff.as_ref().unwrap(), 0).unwrap();
+        apply_to_index(repo, &patch, true).unwrap();
+        staged_view.reload(repo);
+        assert!(staged_view.diff.is_none());
+    }
+
+    #[test]
+    fn test_parse_upstream_track() {
+        assert_eq!(parse_upstream_track(""), (0, 0, false));
+        assert_eq!(parse_upstream_track("ahead 2"), (2, 0, false));
+        assert_eq!(parse_upstream_track("ahead 2, behind 15"), (2, 15, false));
+        assert_eq!(parse_upstream_track("gone"), (0, 0, true));
+    }
+
+    #[test]
+    fn test_stash_roundtrip() {
+        assert!(is_valid_stash_ref("stash@{12}"));
+        assert!(!is_valid_stash_ref("stash@{}"));
+        assert!(!is_valid_stash_ref("--all"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path();
+        let git = |args: &[&str]| git_commit_cmd(repo).args(args).output().map(|o| o.status.success()).unwrap_or(false);
+        if !git(&["init", "-q"]) {
+            return; // git not installed
+        }
+        std::fs::write(repo.join("f.txt"), "one\n").unwrap();
+        assert!(git(&["add", "f.txt"]));
+        assert!(git(&["commit", "-q", "-m", "init"]));
+
+        std::fs::write(repo.join("f.txt"), "two\n").unwrap();
+        std::fs::write(repo.join("new.txt"), "untracked\n").unwrap();
+        stash_push(repo, "wip", true).unwrap();
+        assert_eq!(std::fs::read_to_string(repo.join("f.txt")).unwrap(), "one\n");
+        assert!(!repo.join("new.txt").exists());
+
+        let stashes = get_stashes(repo);
+        assert_eq!(stashes.len(), 1);
+        assert_eq!(stashes[0].name, "stash@{0}");
+        assert!(stashes[0].message.contains("wip"));
+        assert!(get_stash_diff(repo, "stash@{0}").contains("+two"));
+
+        stash_command(repo, "apply", "stash@{0}").unwrap();
+        assert_eq!(std::fs::read_to_string(repo.join("f.txt")).unwrap(), "two\n");
+        assert_eq!(get_stashes(repo).len(), 1);
+        stash_command(repo, "drop", "stash@{0}").unwrap();
+        assert!(get_stashes(repo).is_empty());
+    }
+}
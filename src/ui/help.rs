@@ -192,17 +192,31 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     lines.push(pk(PanelAction::MoveDown, "Move cursor down"));
     lines.push(pk(PanelAction::PageUp, "Page up"));
     lines.push(pk(PanelAction::PageDown, "Page down"));
+    lines.push(pk(PanelAction::HalfPageUp, "Scroll up half a page"));
+    lines.push(pk(PanelAction::HalfPageDown, "Scroll down half a page"));
+    lines.push(pk(PanelAction::ThirdPageUp, "Scroll up a third of a page"));
+    lines.push(pk(PanelAction::ThirdPageDown, "Scroll down a third of a page"));
     lines.push(pk(PanelAction::GoHome, "Go to first item"));
     lines.push(pk(PanelAction::GoEnd, "Go to last item"));
-    lines.push(pk(PanelAction::Open, "Open directory or file"));
+    lines.push(pk(PanelAction::Open, "Open directory or file (browse tar/zip archives)"));
     lines.push(pk(PanelAction::ParentDir, "Go to parent directory"));
     lines.push(pk(PanelAction::SwitchPanel, "Switch panel"));
     lines.push(pk(PanelAction::SwitchPanelLeft, "Switch to left panel"));
     lines.push(pk(PanelAction::SwitchPanelRight, "Switch to right panel"));
     lines.push(pk(PanelAction::GoHomeDir, "Go to home directory"));
     lines.push(pk(PanelAction::Refresh, "Refresh file list"));
-    lines.push(pk(PanelAction::GoToPath, "Go to path dialog"));
+    lines.push(pk(PanelAction::GoToPath, "Go to path dialog (also user@host:/path, sftp://host/path)"));
     lines.push(pk(PanelAction::ToggleBookmark, "Toggle bookmark"));
+    lines.push(pk(PanelAction::RecentFiles, "Recent files"));
+    lines.push(pk(PanelAction::JumpDirectory, "Jump to a frequently visited directory or smart folder"));
+    lines.push(pk(PanelAction::PathMenu, "Path menu (ancestors and their sibling folders)"));
+    lines.push(pk(PanelAction::AdvancedSearch, "Advanced search (name/size/date), Ctrl+S saves it as a smart folder"));
+    lines.push(key_line(
+        &format!("{}..{}", kb.panel_first_key(PanelAction::Favorite1), kb.panel_first_key(PanelAction::Favorite9)),
+        "Go to favorite directory 1-9",
+    ));
+    lines.push(pk(PanelAction::Favorites, "Favorites: add, remove, reorder"));
+    lines.push(pk(PanelAction::ToggleFavoritesBar, "Show/hide the favorites bar"));
     lines.push(pk(PanelAction::AddPanel, "Add new panel"));
     lines.push(pk(PanelAction::ClosePanel, "Close current panel"));
     lines.push(Line::from(""));
@@ -216,6 +230,9 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     lines.push(pk(PanelAction::SelectUp, "Select and move up"));
     lines.push(pk(PanelAction::SelectDown, "Select and move down"));
     lines.push(pk(PanelAction::SelectByExtension, "Select by extension"));
+    lines.push(pk(PanelAction::InvertSelection, "Invert selection"));
+    lines.push(pk(PanelAction::SelectByFilter, "Select by age/size (newer 7d, larger 10M)"));
+    lines.push(pk(PanelAction::Calculator, "Calculator / size converter (1.5 GiB in MB)"));
     lines.push(Line::from(vec![
         Span::styled("  ".to_string(), desc_style),
         Span::styled("Selected files are marked with ".to_string(), hint_style),
@@ -242,16 +259,45 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     // ═══════════════════════════════════════════════════════════════════════
     lines.push(section("File Operations"));
     lines.push(pk(PanelAction::Edit, "Edit file"));
-    lines.push(pk(PanelAction::FileInfo, "File info (properties)"));
+    lines.push(pk(PanelAction::FileInfo, "File properties (view/edit)"));
     lines.push(pk(PanelAction::Mkdir, "Create new directory"));
     lines.push(pk(PanelAction::Mkfile, "Create new file"));
     lines.push(pk(PanelAction::Rename, "Rename file/directory"));
     lines.push(pk(PanelAction::Tar, "Create tar archive"));
     lines.push(pk(PanelAction::SetHandler, "Set/Edit file handler"));
+    lines.push(pk(PanelAction::OpenWith, "Open with application"));
     lines.push(pk(PanelAction::Delete, "Delete file(s)"));
     lines.push(pk(PanelAction::EncryptAll, "Encrypt all files (AES-256)"));
     lines.push(pk(PanelAction::DecryptAll, "Decrypt .cokacenc files"));
+    lines.push(pk(PanelAction::Checksums, "Checksums / verify manifest"));
+    lines.push(pk(PanelAction::CompareChecksums, "Compare 2 files by checksum"));
+    lines.push(pk(PanelAction::CompareClipboard, "Diff file against clipboard text"));
+    lines.push(pk(PanelAction::CopyShellCommand, "Copy cd/cp shell command"));
+    lines.push(pk(PanelAction::TrashScreen, "Trash (restore / purge)"));
+    lines.push(pk(PanelAction::ToggleDeleteToTrash, "Toggle delete to trash / permanently"));
+    lines.push(pk(PanelAction::Jobs, "Background jobs (B in progress dialog)"));
+    lines.push(pk(PanelAction::ToggleDirSizes, "Toggle directory sizes (background indexer)"));
+    lines.push(pk(PanelAction::QuickFilter, "Quick filter: type to narrow, Esc restores"));
+    lines.push(pk(PanelAction::ToggleHidden, "Show/hide dotfiles in this panel"));
+    lines.push(pk(PanelAction::GoToLinkTarget, "Go to symlink target"));
+    lines.push(pk(PanelAction::SymlinkToOtherPanel, "Symlink selection into other panel"));
+    lines.push(pk(PanelAction::BulkRename, "Bulk rename (pattern, regex, case)"));
+    lines.push(pk(PanelAction::ChangeExtension, "Change extension of selected files"));
+    lines.push(pk(PanelAction::UndoExtensionChange, "Undo the last extension change"));
+    lines.push(pk(PanelAction::TogglePreviewPane, "Quick view of the file under cursor"));
+    lines.push(pk(PanelAction::DrivesScreen, "Drives (mount points, free space)"));
+    lines.push(pk(PanelAction::CleanupScreen, "Storage cleanup (node_modules, target/, caches, core dumps)"));
+    lines.push(pk(PanelAction::Backups, "Backups (scheduled incremental copies)"));
+    lines.push(pk(PanelAction::RecentChanges, "Recent changes (files modified today, this week...)"));
+    lines.push(pk(PanelAction::GitLogDiff, "Git log browser (commit graph, per-commit diffs)"));
+    lines.push(pk(PanelAction::TreeSidebar, "Directory tree sidebar (arrows expand, Enter goes, Tab back)"));
+    lines.push(pk(PanelAction::AuditLog, "Telegram bot audit log"));
+    lines.push(pk(PanelAction::QuickView, "Quick view (next file reuses the viewer)"));
     lines.push(pk(PanelAction::Search, "Find/search files"));
+    lines.push(pk(PanelAction::EditInSplit, "Edit file in tmux/zellij split"));
+    lines.push(pk(PanelAction::EditInWindow, "Edit file in tmux/zellij window"));
+    lines.push(pk(PanelAction::ShellInSplit, "Shell in tmux/zellij split"));
+    lines.push(pk(PanelAction::ShellInWindow, "Shell in tmux/zellij window"));
     #[cfg(target_os = "macos")]
     {
         lines.push(pk(PanelAction::OpenInFinder, "Open folder in Finder"));
@@ -266,9 +312,14 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     lines.push(pk(PanelAction::Copy, "Copy to clipboard"));
     lines.push(pk(PanelAction::Cut, "Cut to clipboard"));
     lines.push(pk(PanelAction::Paste, "Paste from clipboard"));
+    lines.push(pk(PanelAction::PasteExcluding, "Paste, skipping exclude patterns (target/ *.o)"));
+    lines.push(pk(PanelAction::PasteImage, "Paste clipboard image as PNG file"));
+    lines.push(pk(PanelAction::AddToBasket, "Add files to the basket (from any folder)"));
+    lines.push(pk(PanelAction::Basket, "Basket: c copy / m move / z zip all items here"));
+    lines.push(pk(PanelAction::CopyContents, "Copy file contents (OSC 52)"));
     lines.push(Line::from(vec![
         Span::styled("  ".to_string(), desc_style),
-        Span::styled("Conflict resolution: Overwrite/Skip/All".to_string(), hint_style),
+        Span::styled("Conflict resolution: Overwrite/Skip/All/If newer/If larger".to_string(), hint_style),
     ]));
     lines.push(Line::from(""));
 
@@ -305,8 +356,12 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     lines.push(ek(EditorAction::Redo, "Redo"));
     lines.push(ek(EditorAction::Find, "Find text"));
     lines.push(ek(EditorAction::Replace, "Find and replace"));
-    lines.push(ek(EditorAction::GotoLine, "Go to line"));
+    lines.push(ek(EditorAction::GotoLine, "Go to line (LINE or LINE:COL)"));
+    lines.push(ek(EditorAction::SetMark, "Set mark (then a letter)"));
+    lines.push(ek(EditorAction::JumpToMark, "Jump to mark (then a letter)"));
+    lines.push(ek(EditorAction::ToggleWordWrap, "Toggle soft wrap"));
     lines.push(ek(EditorAction::Save, "Save file"));
+    lines.push(ek(EditorAction::SudoSave, "Save with sudo/pkexec (unlocks read-only files)"));
     lines.push(ek(EditorAction::Exit, "Close editor"));
     lines.push(Line::from(""));
 
@@ -330,6 +385,8 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     lines.push(ivk(ImageViewerAction::PanDown, "Pan down"));
     lines.push(ivk(ImageViewerAction::PrevImage, "Previous image"));
     lines.push(ivk(ImageViewerAction::NextImage, "Next image"));
+    lines.push(ivk(ImageViewerAction::SaveAs, "Save as PNG/JPEG/WebP"));
+    lines.push(ivk(ImageViewerAction::Resize, "Save a resized copy"));
     lines.push(ivk(ImageViewerAction::Close, "Close viewer"));
     lines.push(Line::from(""));
 
@@ -380,7 +437,27 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     lines.push(aik(AIScreenAction::PageDown, "Page scroll down"));
     lines.push(aik(AIScreenAction::ClearHistory, "Clear conversation"));
     lines.push(aik(AIScreenAction::ToggleFullscreen, "Toggle fullscreen"));
+    lines.push(aik(AIScreenAction::RunInBackground, "Keep working while AI runs in background"));
+    lines.push(aik(AIScreenAction::NewConversation, "Start another conversation"));
+    lines.push(aik(AIScreenAction::NextConversation, "Switch to next conversation"));
+    lines.push(aik(AIScreenAction::ResponseActions, "Save/copy code blocks, go to mentioned paths"));
+    lines.push(aik(AIScreenAction::ToggleToolTimeline, "Collapse/expand tool activity"));
+    lines.push(aik(AIScreenAction::ToggleApproval, "Approval mode: confirm Bash/Write/Edit calls"));
+    lines.push(aik(AIScreenAction::ChangeDirectory, "Change AI working directory (Tab completes)"));
+    lines.push(aik(AIScreenAction::SearchSessions, "Search saved conversations, Enter resumes"));
+    lines.push(aik(AIScreenAction::GrowPane, "Make the AI pane larger (split view)"));
+    lines.push(aik(AIScreenAction::ShrinkPane, "Make the AI pane smaller (split view)"));
+    lines.push(aik(AIScreenAction::ToggleSplitLayout, "Toggle AI pane beside / below the panels"));
     lines.push(aik(AIScreenAction::Escape, "Close assistant"));
+    let cmd = |command: &str, desc: &str| -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("  {:28}", command), key_style),
+            Span::styled(desc.to_string(), desc_style),
+        ])
+    };
+    lines.push(cmd("/files", "Insert selected files of the file panel"));
+    lines.push(cmd("/tree", "Insert directory tree of the file panel"));
+    lines.push(cmd("/content <file>", "Insert file contents (up to 100 KB)"));
     lines.push(Line::from(""));
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -397,10 +474,13 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
 
     lines.push(section("Search"));
     lines.push(pk(PanelAction::Search, "Open search dialog"));
+    lines.push(key_line("Tab (in dialog)", "Search file names or file contents"));
+    lines.push(key_line("Alt+R / Alt+C (in dialog)", "Toggle regex / case-sensitive content search"));
+    lines.push(key_line("Alt+G (in dialog)", "Skip paths ignored by .gitignore/.ignore"));
     lines.push(srk(SearchResultAction::MoveUp, "Navigate up"));
     lines.push(srk(SearchResultAction::MoveDown, "Navigate down"));
-    lines.push(srk(SearchResultAction::Open, "Go to selected result"));
-    lines.push(srk(SearchResultAction::Close, "Close search"));
+    lines.push(srk(SearchResultAction::Open, "Go to selected result (content match: view at line)"));
+    lines.push(srk(SearchResultAction::Close, "Stop a running search, then close"));
     lines.push(Line::from(""));
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -438,6 +518,7 @@ fn build_help_content(theme: &Theme, kb: &Keybindings) -> Vec<Line<'static>> {
     lines.push(dsk(DiffScreenAction::CollapseDir, "Collapse directory"));
     lines.push(dsk(DiffScreenAction::ExpandAll, "Expand all"));
     lines.push(dsk(DiffScreenAction::CollapseAll, "Collapse all"));
+    lines.push(dsk(DiffScreenAction::Sync, "Sync mode: \u{2192}/\u{2190} direction, = skip, Shift+arrows all, Enter dry run, Enter again runs"));
     lines.push(dsk(DiffScreenAction::Close, "Return to file panel"));
     lines.push(Line::from(""));
 
@@ -0,0 +1,369 @@
+//! Background syntax state computation for the editor.
+//!
+//! Tokenizing a line only depends on the multi-line state (open comment or
+//! string) at its start. A worker thread computes that state for every line
+//! and streams it back in chunks, so drawing only tokenizes the visible lines
+//! instead of everything above the viewport.
+//!
+//! The worker gets the text in bounded batches copied from the UI thread as it
+//! asks for them. After an edit it starts at the changed line and stops as soon
+//! as the state at the start of a line past the edited ones matches the cached
+//! one: the text below is unchanged, so its cached states still apply. Until the
+//! worker catches up the previous states are reused, and lines with no state
+//! yet are drawn without highlighting.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+
+use super::syntax::{LineState, SyntaxHighlighter};
+
+/// Lines copied to the worker at a time
+const BATCH_LINES: usize = 2000;
+/// Batches queued ahead of the worker
+const BATCHES_AHEAD: usize = 4;
+
+struct Batch {
+    /// Index of the first line
+    start: usize,
+    lines: Vec<String>,
+    /// Cached state at the start of each line where the job may stop
+    known: Vec<Option<LineState>>,
+    /// Ends with the last line of the text
+    last: bool,
+}
+
+/// Why a job stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JobEnd {
+    /// A computed state matched the cached one; the rest is unchanged
+    Converged,
+    /// Reached the end of the text
+    TextEnd { total_lines: usize },
+}
+
+struct Chunk {
+    /// Index of the first line in `states`
+    start: usize,
+    states: Vec<LineState>,
+    end: Option<JobEnd>,
+}
+
+#[derive(Debug)]
+struct Job {
+    receiver: Receiver<Chunk>,
+    sender: Sender<Batch>,
+    /// First line not sent to the worker yet
+    next_line: usize,
+    sent_last: bool,
+}
+
+#[derive(Debug)]
+pub struct HighlightCache {
+    /// State at the start of each line (entries at or after `valid_upto` may be stale)
+    states: Vec<LineState>,
+    /// `states[..valid_upto]` match the current text
+    valid_upto: usize,
+    /// First line edited since the running job was started
+    dirty_from: Option<usize>,
+    /// Lines before this index changed and must be recomputed before a job may
+    /// stop early (`usize::MAX`: run to the end). None when everything is done
+    recompute_to: Option<usize>,
+    /// Line count the states describe (detects edits that bypassed `lines_changed`)
+    known_lines: usize,
+    job: Option<Job>,
+    cancel_flag: Arc<AtomicBool>,
+}
+
+impl Default for HighlightCache {
+    fn default() -> Self {
+        Self {
+            states: Vec::new(),
+            valid_upto: 0,
+            dirty_from: Some(0),
+            recompute_to: Some(usize::MAX),
+            known_lines: 0,
+            job: None,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl Drop for HighlightCache {
+    fn drop(&mut self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+    }
+}
+
+impl HighlightCache {
+    /// Forget everything (new file or language)
+    pub fn reset(&mut self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+        *self = Self::default();
+    }
+
+    /// Text changed at `line` in an unknown way. The state at the start of
+    /// `line` is still valid, everything after it must be recomputed.
+    pub fn invalidate_from(&mut self, line: usize) {
+        self.stop_job();
+        self.valid_upto = self.valid_upto.min(line + 1);
+        self.dirty_from = Some(self.dirty_from.map_or(line, |d| d.min(line)));
+        self.recompute_to = Some(usize::MAX);
+    }
+
+    /// Lines `line..line + removed` were replaced by `inserted` lines. Cached
+    /// states below them move with their lines, so a job can stop once it
+    /// reaches a line whose state did not change.
+    pub fn lines_changed(&mut self, line: usize, removed: usize, inserted: usize) {
+        // Removing whole lines changes the text of the line that moves up
+        let (removed, inserted) = if inserted == 0 { (removed + 1, 1) } else { (removed, inserted) };
+        self.stop_job();
+
+        if line < self.states.len() {
+            let fill = self.states[line];
+            let end = (line + removed).clamp(line + 1, self.states.len());
+            let added = if removed == 0 { inserted } else { inserted - 1 };
+            self.states.splice(line + 1..end, std::iter::repeat_n(fill, added));
+        }
+        self.known_lines = (self.known_lines + inserted).saturating_sub(removed);
+        self.states.truncate(self.known_lines);
+
+        // The start of `line` is known only if a state was stored for it
+        let first = line.min(self.states.len().saturating_sub(1));
+        self.valid_upto = self.valid_upto.min(first + 1);
+        self.dirty_from = Some(self.dirty_from.map_or(first, |d| d.min(first)));
+        let changed_to = line + inserted;
+        self.recompute_to = Some(match self.recompute_to {
+            Some(to) if to >= line + removed => to.saturating_add(inserted).saturating_sub(removed).max(changed_to),
+            Some(to) => to.max(changed_to),
+            None => changed_to,
+        });
+    }
+
+    /// Whether a background job is running
+    pub fn is_busy(&self) -> bool {
+        self.job.is_some() || self.dirty_from.is_some()
+    }
+
+    /// Best known state at the start of `line`, None if not computed yet
+    pub fn state_at(&self, line: usize) -> Option<LineState> {
+        if line == 0 {
+            return Some(LineState::default());
+        }
+        self.states.get(line).copied()
+    }
+
+    /// Collect finished chunks, feed the running job and start a new one if
+    /// the text changed. Called once per frame before drawing.
+    pub fn update(&mut self, lines: &[String], highlighter: SyntaxHighlighter) {
+        if self.known_lines != lines.len() {
+            self.invalidate_from(0);
+            self.known_lines = lines.len();
+        }
+
+        if let Some(dirty) = self.dirty_from.take() {
+            // Resume from the last line whose start state is known
+            let start = dirty.min(self.valid_upto.saturating_sub(1)).min(self.states.len().saturating_sub(1));
+            self.start_job(lines, highlighter, start);
+        }
+
+        self.poll(lines);
+    }
+
+    fn stop_job(&mut self) {
+        // Chunks still queued describe the text before the edit
+        if self.job.take().is_some() {
+            self.cancel_flag.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn poll(&mut self, lines: &[String]) {
+        loop {
+            let Some(ref job) = self.job else { return };
+            match job.receiver.try_recv() {
+                Ok(chunk) => {
+                    let end = chunk.start + chunk.states.len();
+                    if self.states.len() < end {
+                        self.states.resize(end, LineState::default());
+                    }
+                    self.states[chunk.start..end].copy_from_slice(&chunk.states);
+                    if chunk.start <= self.valid_upto {
+                        self.valid_upto = self.valid_upto.max(end);
+                    }
+                    match chunk.end {
+                        Some(JobEnd::Converged) => {
+                            self.valid_upto = self.states.len();
+                            self.finish_job();
+                            return;
+                        }
+                        Some(JobEnd::TextEnd { total_lines }) => {
+                            self.states.truncate(total_lines);
+                            self.valid_upto = total_lines;
+                            self.finish_job();
+                            return;
+                        }
+                        None => self.send_batch(lines),
+                    }
+                }
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    self.job = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    fn finish_job(&mut self) {
+        self.job = None;
+        self.recompute_to = None;
+    }
+
+    /// Copy the next batch of lines to the worker
+    fn send_batch(&mut self, lines: &[String]) {
+        let recompute_to = self.recompute_to.unwrap_or(0);
+        let Some(ref mut job) = self.job else { return };
+        if job.sent_last {
+            return;
+        }
+        let start = job.next_line.min(lines.len());
+        let end = (start + BATCH_LINES).min(lines.len());
+        let known = (start..end)
+            .map(|i| if i >= recompute_to { self.states.get(i).copied() } else { None })
+            .collect();
+        let batch = Batch { start, lines: lines[start..end].to_vec(), known, last: end == lines.len() };
+        job.next_line = end;
+        job.sent_last = batch.last;
+        let _ = job.sender.send(batch);
+    }
+
+    fn start_job(&mut self, lines: &[String], highlighter: SyntaxHighlighter, start: usize) {
+        // Stop the previous job; its remaining chunks describe outdated text
+        self.cancel_flag.store(true, Ordering::Relaxed);
+        self.cancel_flag = Arc::new(AtomicBool::new(false));
+
+        let start_state = self.state_at(start).unwrap_or_default();
+        let cancel_flag = self.cancel_flag.clone();
+        let (batch_tx, batch_rx) = mpsc::channel::<Batch>();
+        let (tx, rx) = mpsc::channel();
+        self.job = Some(Job { receiver: rx, sender: batch_tx, next_line: start, sent_last: false });
+        for _ in 0..BATCHES_AHEAD {
+            self.send_batch(lines);
+        }
+
+        thread::spawn(move || {
+            let mut hl = highlighter;
+            hl.set_line_state(start_state);
+            // Ends when the job is dropped (new edit or finished)
+            while let Ok(batch) = batch_rx.recv() {
+                let mut states = Vec::with_capacity(batch.lines.len());
+                let mut end = None;
+                for (line, known) in batch.lines.iter().zip(&batch.known) {
+                    if cancel_flag.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    let state = hl.line_state();
+                    if *known == Some(state) {
+                        end = Some(JobEnd::Converged);
+                        break;
+                    }
+                    states.push(state);
+                    hl.tokenize_line(line);
+                }
+                if end.is_none() && batch.last {
+                    end = Some(JobEnd::TextEnd { total_lines: batch.start + batch.lines.len() });
+                }
+                if tx.send(Chunk { start: batch.start, states, end }).is_err() || end.is_some() {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::syntax::Language;
+    use crate::ui::theme::Theme;
+
+    fn wait_until_done(cache: &mut HighlightCache, lines: &[String], hl: SyntaxHighlighter) {
+        for _ in 0..500 {
+            cache.update(lines, hl);
+            if !cache.is_busy() {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        panic!("highlight job did not finish");
+    }
+
+    #[test]
+    fn test_states_match_sequential_tokenizing() {
+        let hl = SyntaxHighlighter::new(Language::Rust, Theme::default().syntax);
+        let mut lines: Vec<String> = (0..5000).map(|i| format!("let x{} = {};", i, i)).collect();
+        lines[10] = "/* start".to_string();
+        lines[20] = "end */".to_string();
+
+        let mut cache = HighlightCache::default();
+        wait_until_done(&mut cache, &lines, hl);
+
+        let mut seq = hl;
+        for (i, line) in lines.iter().enumerate() {
+            assert_eq!(cache.state_at(i), Some(seq.line_state()), "line {}", i);
+            seq.tokenize_line(line);
+        }
+
+        // Closing the comment earlier changes the states after the edit
+        lines[12] = "*/".to_string();
+        cache.invalidate_from(12);
+        wait_until_done(&mut cache, &lines, hl);
+        assert_eq!(cache.state_at(13), Some(LineState::default()));
+    }
+
+    #[test]
+    fn test_edit_stops_when_state_converges() {
+        let hl = SyntaxHighlighter::new(Language::Rust, Theme::default().syntax);
+        let mut lines: Vec<String> = (0..10_000).map(|i| format!("let x{} = {};", i, i)).collect();
+        let mut cache = HighlightCache::default();
+        wait_until_done(&mut cache, &lines, hl);
+
+        // A wrong state far below the edits: a job that ran to the end would fix it
+        let mut open = hl;
+        open.tokenize_line("/* open");
+        let in_comment = open.line_state();
+        cache.states[8000] = in_comment;
+
+        lines[5000] = "let changed = 1;".to_string();
+        cache.lines_changed(5000, 1, 1);
+        wait_until_done(&mut cache, &lines, hl);
+        assert_eq!(cache.state_at(5001), Some(LineState::default()));
+        assert_eq!(cache.state_at(8000), Some(in_comment));
+
+        // Opening a comment recomputes until it is closed, then stops
+        lines[6000] = "/* open".to_string();
+        cache.lines_changed(6000, 1, 1);
+        lines[6010] = "close */".to_string();
+        cache.lines_changed(6010, 1, 1);
+        wait_until_done(&mut cache, &lines, hl);
+        assert_eq!(cache.state_at(6005), Some(in_comment));
+        assert_eq!(cache.state_at(6011), Some(LineState::default()));
+        assert_eq!(cache.state_at(8000), Some(in_comment));
+
+        // Inserted and removed lines move the cached states with their lines
+        lines.insert(3000, "let inserted = 0;".to_string());
+        cache.lines_changed(3000, 0, 1);
+        lines.remove(100);
+        cache.lines_changed(100, 1, 0);
+        wait_until_done(&mut cache, &lines, hl);
+        assert_eq!(cache.state_at(8000), Some(in_comment));
+        let mut seq = hl;
+        for (i, line) in lines.iter().enumerate() {
+            if i != 8000 {
+                assert_eq!(cache.state_at(i), Some(seq.line_state()), "line {}", i);
+            }
+            seq.tokenize_line(line);
+        }
+    }
+}
@@ -17,6 +17,7 @@ use super::{app::{App, Dialog, DialogType, Screen}, theme::Theme};
 /// Result of async image loading
 struct ImageLoadResult {
     image: Option<DynamicImage>,
+    original_size: Option<(u32, u32)>,
     error: Option<String>,
 }
 
@@ -63,6 +64,8 @@ pub fn supports_true_color() -> bool {
 pub struct ImageViewerState {
     pub path: std::path::PathBuf,
     pub image: Option<DynamicImage>,
+    /// Dimensions of the original image (`image` may be a cached thumbnail)
+    pub original_size: Option<(u32, u32)>,
     pub error: Option<String>,
     pub zoom: f32,
     pub offset_x: i32,
@@ -89,6 +92,7 @@ impl ImageViewerState {
         let mut state = Self {
             path: path.to_path_buf(),
             image: None,
+            original_size: None,
             error: None,
             zoom: 1.0,
             offset_x: 0,
@@ -103,6 +107,7 @@ impl ImageViewerState {
 
         // Start async image loading
         state.start_loading(path);
+        state.prefetch_neighbors();
         state
     }
 
@@ -110,6 +115,7 @@ impl ImageViewerState {
     fn start_loading(&mut self, path: &Path) {
         self.is_loading = true;
         self.image = None;
+        self.original_size = None;
         self.error = None;
 
         let (tx, rx): (Sender<ImageLoadResult>, Receiver<ImageLoadResult>) = mpsc::channel();
@@ -117,14 +123,17 @@ impl ImageViewerState {
 
         let path = path.to_path_buf();
         thread::spawn(move || {
-            let result = match image::open(&path) {
-                Ok(img) => ImageLoadResult {
-                    image: Some(img),
+            // Large images come from the thumbnail cache
+            let result = match crate::services::thumbnails::load(&path) {
+                Ok(loaded) => ImageLoadResult {
+                    image: Some(loaded.image),
+                    original_size: Some(loaded.original_size),
                     error: None,
                 },
                 Err(e) => ImageLoadResult {
                     image: None,
-                    error: Some(format!("Failed to load image: {}", e)),
+                    original_size: None,
+                    error: Some(e),
                 },
             };
             let _ = tx.send(result);
@@ -142,6 +151,7 @@ impl ImageViewerState {
             match receiver.try_recv() {
                 Ok(result) => {
                     self.image = result.image;
+                    self.original_size = result.original_size;
                     self.error = result.error;
                     self.is_loading = false;
                     self.receiver = None;
@@ -255,9 +265,25 @@ impl ImageViewerState {
         self.inline_protocol = None;
         // Start async loading
         self.start_loading(&new_path);
+        self.prefetch_neighbors();
         true
     }
 
+    /// Prepare thumbnails for the previous and next images so navigation is instant
+    fn prefetch_neighbors(&self) {
+        let len = self.image_list.len();
+        if len < 2 {
+            return;
+        }
+        let next = (self.current_index + 1) % len;
+        let prev = (self.current_index + len - 1) % len;
+        let mut paths = vec![self.image_list[next].clone()];
+        if prev != next {
+            paths.push(self.image_list[prev].clone());
+        }
+        crate::services::thumbnails::prefetch(&paths);
+    }
+
     /// Get current image position info (e.g., "3/10")
     pub fn get_position_info(&self) -> String {
         if self.image_list.is_empty() {
@@ -319,22 +345,8 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
     // AI 모드에서는 파일 패널 영역에만 이미지 오버레이 표시
     let overlay_area = if app.is_ai_mode() {
         // 패널 영역 계산 (draw.rs의 draw_panels와 동일한 동적 레이아웃)
-        let chunks = ratatui::layout::Layout::default()
-            .direction(ratatui::layout::Direction::Vertical)
-            .constraints([
-                ratatui::layout::Constraint::Min(5),
-                ratatui::layout::Constraint::Length(1),
-                ratatui::layout::Constraint::Length(1),
-            ])
-            .split(area);
-        let num_panels = app.panels.len();
-        let constraints: Vec<ratatui::layout::Constraint> = (0..num_panels)
-            .map(|_| ratatui::layout::Constraint::Ratio(1, num_panels as u32))
-            .collect();
-        let panel_chunks = ratatui::layout::Layout::default()
-            .direction(ratatui::layout::Direction::Horizontal)
-            .constraints(constraints)
-            .split(chunks[0]);
+        let chunks = crate::ui::draw::panel_screen_chunks(app, area);
+        let panel_chunks = crate::ui::draw::panel_areas(app, chunks[1]);
         // active_panel_index에 해당하는 패널 영역 사용
         panel_chunks[app.active_panel_index.min(panel_chunks.len().saturating_sub(1))]
     } else {
@@ -366,17 +378,18 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
     let img_dimensions = state.image.as_ref().map(|img| (img.width(), img.height()));
     let font_size = app.image_picker.as_ref().map(|p| p.font_size);
     let title = if let Some(ref img) = state.image {
+        let (orig_w, orig_h) = state.original_size.unwrap_or((img.width(), img.height()));
         if use_inline {
             // Inline protocol: no zoom info
             if position_info.is_empty() {
-                format!(" {} ({}x{}) ", filename, img.width(), img.height())
+                format!(" {} ({}x{}) ", filename, orig_w, orig_h)
             } else {
-                format!(" {} [{}] ({}x{}) ", filename, position_info, img.width(), img.height())
+                format!(" {} [{}] ({}x{}) ", filename, position_info, orig_w, orig_h)
             }
         } else if position_info.is_empty() {
-            format!(" {} ({}x{}) - {:.0}% ", filename, img.width(), img.height(), state.zoom * 100.0)
+            format!(" {} ({}x{}) - {:.0}% ", filename, orig_w, orig_h, state.zoom * 100.0)
         } else {
-            format!(" {} [{}] ({}x{}) - {:.0}% ", filename, position_info, img.width(), img.height(), state.zoom * 100.0)
+            format!(" {} [{}] ({}x{}) - {:.0}% ", filename, position_info, orig_w, orig_h, state.zoom * 100.0)
         }
     } else if position_info.is_empty() {
         format!(" {} ", filename)
@@ -472,6 +485,7 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
         vec![
             (kb.image_viewer_first_key(ImageViewerAction::PrevImage).to_string(), "prev "),
             (kb.image_viewer_first_key(ImageViewerAction::NextImage).to_string(), "next "),
+            (kb.image_viewer_first_key(ImageViewerAction::SaveAs).to_string(), "save as "),
             (kb.image_viewer_first_key(ImageViewerAction::Close).to_string(), "close"),
         ]
     } else {
@@ -481,6 +495,7 @@ pub fn draw(frame: &mut Frame, app: &mut App, area: Rect, theme: &Theme) {
             (kb.image_viewer_first_key(ImageViewerAction::ZoomIn).to_string(), "zoom+ "),
             (kb.image_viewer_first_key(ImageViewerAction::ZoomOut).to_string(), "zoom- "),
             (kb.image_viewer_first_key(ImageViewerAction::ResetView).to_string(), "reset "),
+            (kb.image_viewer_first_key(ImageViewerAction::SaveAs).to_string(), "save as "),
             (kb.image_viewer_first_key(ImageViewerAction::Close).to_string(), "close"),
         ]
     };
@@ -654,6 +669,12 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                     }
                 }
             }
+            ImageViewerAction::SaveAs => {
+                app.show_image_export_dialog(false);
+            }
+            ImageViewerAction::Resize => {
+                app.show_image_export_dialog(true);
+            }
             ImageViewerAction::Delete => {
                 let filename = state.path.file_name()
                     .map(|n| n.to_string_lossy().to_string())
@@ -662,7 +683,7 @@ pub fn handle_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                     dialog_type: DialogType::Delete,
                     input: String::new(),
                     cursor_pos: 0,
-                    message: format!("Delete {}?", filename),
+                    message: app.delete_prompt(&filename),
                     completion: None,
                     selected_button: 1,
                     selection: None,
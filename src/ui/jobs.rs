@@ -0,0 +1,227 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::ui::app::FileOperationProgress;
+use crate::ui::theme::Theme;
+use crate::utils::format::{format_duration, format_size};
+
+/// Finished jobs stay in the list this long
+const FINISHED_JOB_TTL: Duration = Duration::from_secs(10);
+/// Job rows shown when the pane is expanded
+const MAX_VISIBLE_JOBS: usize = 6;
+
+/// A file operation sent to the background from the progress dialog
+pub struct Job {
+    pub id: usize,
+    pub progress: FileOperationProgress,
+    /// Archive created by a Tar/Zip job (for the completion message)
+    pub archive_name: Option<String>,
+    /// Directory created by an Untar/Unzip job
+    pub extract_dir: Option<String>,
+    /// Completion message and when it finished
+    pub finished: Option<(String, Instant)>,
+}
+
+impl Job {
+    pub fn is_running(&self) -> bool {
+        self.finished.is_none()
+    }
+
+    /// One-line description for the Jobs pane
+    fn summary(&self) -> String {
+        if let Some((ref message, _)) = self.finished {
+            return message.clone();
+        }
+        let p = &self.progress;
+        if p.is_preparing {
+            return format!("{} - {}", p.operation_type.verb(), p.preparing_message);
+        }
+        let mut text = format!(
+            "{} {:>3}%  {}/{} files",
+            p.operation_type.verb(),
+            (p.overall_progress() * 100.0) as u32,
+            p.completed_files,
+            p.total_files
+        );
+        if let Some(speed) = p.bytes_per_sec() {
+            text.push_str(&format!("  {}/s", format_size(speed as u64)));
+        }
+        if let Some(eta) = p.eta() {
+            text.push_str(&format!("  ETA {}", format_duration(eta.as_secs())));
+        }
+        if p.cancel_flag.load(std::sync::atomic::Ordering::Relaxed) {
+            text.push_str("  (cancelling)");
+        } else if !p.current_file.is_empty() {
+            text.push_str(&format!("  {}", p.current_file));
+        }
+        text
+    }
+}
+
+/// Background file operations shown in the Jobs pane under the panels
+#[derive(Default)]
+pub struct JobsState {
+    pub jobs: Vec<Job>,
+    next_id: usize,
+    /// Show one row per job instead of the one-line summary
+    pub expanded: bool,
+    /// Keys go to the Jobs pane (select / cancel)
+    pub focused: bool,
+    pub selected: usize,
+}
+
+/// A job that finished since the last poll
+pub struct FinishedJob {
+    pub message: String,
+    pub success: bool,
+    pub started_at: Instant,
+    pub title: String,
+    /// Folders the job changed
+    pub dirs: Vec<PathBuf>,
+}
+
+impl JobsState {
+    pub fn add(&mut self, progress: FileOperationProgress, archive_name: Option<String>, extract_dir: Option<String>) {
+        self.next_id += 1;
+        self.jobs.push(Job { id: self.next_id, progress, archive_name, extract_dir, finished: None });
+    }
+
+    pub fn running_count(&self) -> usize {
+        self.jobs.iter().filter(|j| j.is_running()).count()
+    }
+
+    /// Poll running jobs and drop finished ones after FINISHED_JOB_TTL.
+    /// Returns the jobs that finished during this call.
+    pub fn poll(&mut self) -> Vec<FinishedJob> {
+        let mut finished = Vec::new();
+        for job in self.jobs.iter_mut().filter(|j| j.finished.is_none()) {
+            if job.progress.poll() {
+                continue;
+            }
+            let progress = &job.progress;
+            let message = progress
+                .completion_message(job.archive_name.as_deref(), job.extract_dir.as_deref())
+                .unwrap_or_else(|| format!("{} stopped", progress.operation_type.verb()));
+            let success = progress.result.as_ref().is_some_and(|r| r.failure_count == 0);
+            finished.push(FinishedJob {
+                message: message.clone(),
+                success,
+                started_at: progress.started_at,
+                title: format!("{:?} {}", progress.operation_type, if success { "finished" } else { "failed" }),
+                dirs: progress.dirs.clone(),
+            });
+            job.finished = Some((message, Instant::now()));
+        }
+        let before = self.jobs.len();
+        self.jobs.retain(|j| j.finished.as_ref().map_or(true, |(_, at)| at.elapsed() < FINISHED_JOB_TTL));
+        if self.jobs.len() != before {
+            self.selected = self.selected.min(self.jobs.len().saturating_sub(1));
+        }
+        if self.jobs.is_empty() {
+            self.focused = false;
+        }
+        finished
+    }
+
+    /// Request cancellation of the selected job
+    pub fn cancel_selected(&mut self) {
+        if let Some(job) = self.jobs.get_mut(self.selected).filter(|j| j.is_running()) {
+            job.progress.cancel();
+        }
+    }
+
+    /// Rows taken by the pane: nothing without jobs, one summary line when collapsed
+    pub fn height(&self) -> u16 {
+        if self.jobs.is_empty() {
+            0
+        } else if self.expanded {
+            self.jobs.len().min(MAX_VISIBLE_JOBS) as u16 + 2
+        } else {
+            1
+        }
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &JobsState, area: Rect, theme: &Theme) {
+    if area.height == 0 || state.jobs.is_empty() {
+        return;
+    }
+    let colors = &theme.jobs_screen;
+    let running = state.running_count();
+
+    if !state.expanded {
+        // 접힌 상태: 진행 중 작업 요약 한 줄
+        let text = match state.jobs.iter().find(|j| j.is_running()) {
+            Some(job) if running == 1 => format!(" Jobs: {}", job.summary()),
+            Some(_) => format!(" Jobs: {} running", running),
+            None => format!(" Jobs: {}", state.jobs.last().map(|j| j.summary()).unwrap_or_default()),
+        };
+        let line = Line::from(vec![
+            Span::styled(text, Style::default().fg(colors.summary_text)),
+        ]);
+        frame.render_widget(Paragraph::new(line).style(Style::default().bg(colors.summary_bg)), area);
+        return;
+    }
+
+    let border = if state.focused { colors.border_active } else { colors.border };
+    let title = if state.focused {
+        format!(" Jobs ({} running) - Up/Down select, Del cancel, Esc back ", running)
+    } else {
+        format!(" Jobs ({} running) ", running)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border))
+        .title(Span::styled(title, Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+
+    let height = area.height.saturating_sub(2) as usize;
+    let first = (state.selected + 1).saturating_sub(height);
+    let lines: Vec<Line> = state
+        .jobs
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(height)
+        .map(|(i, job)| {
+            let style = match job.finished {
+                None => Style::default().fg(colors.running_text),
+                Some(_) if job.progress.result.as_ref().is_some_and(|r| r.failure_count == 0) => {
+                    Style::default().fg(colors.done_text)
+                }
+                Some(_) => Style::default().fg(colors.failed_text),
+            };
+            let line = Line::from(vec![
+                Span::styled(format!("#{:<3} ", job.id), Style::default().fg(colors.id_text)),
+                Span::styled(job.summary(), style),
+            ]);
+            if state.focused && i == state.selected {
+                line.style(Style::default().bg(colors.selected_bg).fg(colors.selected_text))
+            } else {
+                line
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Handle input while the Jobs pane has focus. Returns true to give focus back to the panels.
+pub fn handle_input(state: &mut JobsState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    match code {
+        KeyCode::Esc | KeyCode::Tab => return true,
+        KeyCode::Up => state.selected = state.selected.saturating_sub(1),
+        KeyCode::Down => state.selected = (state.selected + 1).min(state.jobs.len().saturating_sub(1)),
+        KeyCode::Delete | KeyCode::Char('x') => state.cancel_selected(),
+        _ => {}
+    }
+    false
+}
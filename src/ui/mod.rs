@@ -12,6 +12,7 @@ pub mod ai_screen;
 pub mod system_info;
 pub mod advanced_search;
 pub mod syntax;
+pub mod highlight_cache;
 pub mod image_viewer;
 pub mod search_result;
 pub mod help;
@@ -19,3 +20,17 @@ pub mod diff_screen;
 pub mod diff_file_view;
 pub mod git_screen;
 pub mod dedup_screen;
+pub mod checksum_screen;
+pub mod audit_screen;
+pub mod archive_viewer;
+pub mod trash_screen;
+pub mod bulk_rename;
+pub mod drives_screen;
+pub mod recovery_screen;
+pub mod cleanup_screen;
+pub mod backup_screen;
+pub mod timeline_screen;
+pub mod git_log_screen;
+pub mod preview_pane;
+pub mod tree_sidebar;
+pub mod jobs;
@@ -5,10 +5,70 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
 };
+use std::collections::HashMap;
+
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use super::{app::{PanelState, SortBy, SortOrder}, theme::Theme};
-use crate::utils::format::{format_size, truncate_to_display_width, pad_to_display_width};
+use super::{app::{FileItem, PanelState, SortBy, SortOrder}, theme::{text_markers, Theme}};
+use crate::services::git_status::{Badges, GitBadge};
+use crate::utils::format::{format_short_datetime, format_size, short_datetime_width, truncate_to_display_width, pad_to_display_width};
+
+/// Rows kept in the row cache before it is flushed (bounds memory on huge lists)
+const ROW_CACHE_LIMIT: usize = 2048;
+
+/// Git status badge column ("M", "??" plus a space)
+const BADGE_WIDTH: usize = 3;
+
+/// Whole-list figures for the column layout and footer, computed once per load
+#[derive(Debug, Clone, Default)]
+pub struct ListStats {
+    /// Widest name including marker and icon (decides whether the Type column fits)
+    pub max_name_width: usize,
+    pub dir_count: usize,
+    pub file_count: usize,
+    pub total_size: u64,
+}
+
+impl ListStats {
+    pub fn compute(files: &[FileItem]) -> Self {
+        let mut stats = Self::default();
+        for f in files {
+            let name = f.display_name.as_deref().unwrap_or(&f.name);
+            stats.max_name_width = stats.max_name_width.max(name.width() + 2); // +2 for marker and icon
+            if f.is_directory {
+                if f.name != ".." {
+                    stats.dir_count += 1;
+                }
+            } else {
+                stats.file_count += 1;
+                stats.total_size += f.size;
+            }
+        }
+        stats
+    }
+}
+
+/// Formatted column text of one row (without marker, icon and styles)
+#[derive(Debug, Clone)]
+struct RowText {
+    name: String,
+    type_col: String,
+    size_col: String,
+    date_col: String,
+}
+
+/// Formatted rows by file index, valid while the list and column widths are unchanged
+#[derive(Debug, Default)]
+pub struct RowCache {
+    widths: (usize, usize, usize, usize),
+    rows: HashMap<usize, RowText>,
+}
+
+impl RowCache {
+    pub fn clear(&mut self) {
+        self.rows.clear();
+    }
+}
 
 pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bool, is_bookmarked: bool, diff_selected: bool, theme: &Theme) {
     let inner_width = area.width.saturating_sub(2) as usize;
@@ -16,7 +76,9 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
     // Build path display (truncate if too long, using display width)
     let path_str = panel.display_path();
     let bookmark_marker = if is_bookmarked { "✻" } else { "" };
-    let prefix = bookmark_marker.to_string();
+    // 텍스트 마커: 활성 패널 제목 앞에 ">" 표시
+    let active_marker = if is_active && text_markers() { "> " } else { "" };
+    let prefix = format!("{}{}", active_marker, bookmark_marker);
     let path_display_width = path_str.width();
     let display_path = if inner_width > 4 && path_display_width + prefix.width() > inner_width.saturating_sub(4) {
         // Calculate how many characters to show from the end (by display width)
@@ -37,7 +99,19 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
         format!("{}{}", prefix, path_str)
     };
 
-    let block = Block::default()
+    // Remote connection throughput (right side of the header), if it fits
+    let bandwidth = panel.remote_display.as_ref().and_then(|(user, host, port)| {
+        let bw = crate::services::remote::transfer_stats(user, host, *port).sample();
+        let text = format!(
+            " \u{2193}{}/s \u{2191}{}/s \u{03A3}{} ",
+            format_size(bw.down_per_sec),
+            format_size(bw.up_per_sec),
+            format_size(bw.total)
+        );
+        (bw.total > 0 && display_path.width() + text.width() + 4 <= inner_width).then_some(text)
+    });
+
+    let mut block = Block::default()
         .title(format!(" {} ", display_path))
         .title_style(if panel.is_remote() && is_active {
             Style::default()
@@ -63,6 +137,18 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
             }
         ));
 
+    if let Some(ref filter) = panel.quick_filter {
+        block = block.title_bottom(Line::from(Span::styled(
+            format!(" Filter: {}_ ", filter.text),
+            Style::default().fg(theme.panel.border_active).add_modifier(Modifier::BOLD),
+        )));
+    }
+    if let Some(text) = bandwidth {
+        block = block.title_top(
+            Line::from(Span::styled(text, Style::default().fg(theme.panel.remote_indicator))).right_aligned(),
+        );
+    }
+
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -72,17 +158,13 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
     }
 
     // Column widths - adapt to available space
-    let min_columns: u16 = 10 + 12 + 4; // size + date + padding
+    // Date column fits the configured date/time format (12 for "01-31 14:05")
+    let date_width = short_datetime_width() + 1;
+    let min_columns: u16 = 10 + date_width as u16 + 4; // size + date + padding
     let type_col_total: usize = 10; // 2 + 6 + 2 (padding + type + padding)
 
-    // Calculate max file name width (including marker and icon = 2 chars)
-    let max_name_display_width = panel.files.iter()
-        .map(|f| {
-            let name = f.display_name.as_deref().unwrap_or(&f.name);
-            name.width() + 2 // +2 for marker and icon
-        })
-        .max()
-        .unwrap_or(0);
+    // Max file name width (including marker and icon = 2 chars), cached per load
+    let max_name_display_width = panel.list_stats.max_name_width;
 
     let (name_col, type_col, size_col, date_col) = if inner.width > min_columns {
         let available_for_name = (inner.width - min_columns) as usize;
@@ -94,9 +176,9 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
 
         if show_type {
             let name_width = available_for_name - type_col_total;
-            (name_width, 6_usize, 10_usize, 12_usize)
+            (name_width, 6_usize, 10_usize, date_width)
         } else {
-            (available_for_name, 0_usize, 10_usize, 12_usize)
+            (available_for_name, 0_usize, 10_usize, date_width)
         }
     } else {
         // Very narrow: use all available width for name only, hide size/date/type
@@ -104,6 +186,10 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
         (name_width, 0_usize, 0_usize, 0_usize)
     };
 
+    // Git status badges take a narrow column after the name (header keeps "Name" across both)
+    let show_badges = !panel.git_badges.is_empty() && name_col >= 12;
+    let row_name_col = if show_badges { name_col - BADGE_WIDTH } else { name_col };
+
     // Header row
     let header = create_header_line(panel, name_col, type_col, size_col, date_col, is_active, theme);
     let header_bg = if is_active {
@@ -146,23 +232,33 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
 
     // scroll_offset 업데이트 (패널 전환 시 사용)
     panel.scroll_offset = start_index;
+    panel.visible_height = visible_height;
 
-    let visible_files = panel.files.iter().skip(start_index).take(visible_height);
+    // Formatted rows are reused until the list or the column widths change
+    let widths = (row_name_col, type_col, size_col, date_col);
+    if panel.row_cache.widths != widths || panel.row_cache.rows.len() > ROW_CACHE_LIMIT {
+        panel.row_cache.widths = widths;
+        panel.row_cache.rows.clear();
+    }
 
-    for (i, file) in visible_files.enumerate() {
+    let visible_end = (start_index + visible_height).min(total_files);
+    for (i, file) in panel.files[start_index.min(visible_end)..visible_end].iter().enumerate() {
         let actual_index = start_index + i;
         let is_cursor = actual_index == panel.selected_index;
         let is_marked = panel.selected_files.contains(&file.name);
         let show_cursor = is_cursor && is_active;
 
+        let dir_size = panel.dir_sizes.get(&file.name).copied();
+        let row = panel.row_cache.rows
+            .entry(actual_index)
+            .or_insert_with(|| format_row_text(file, dir_size, row_name_col, type_col, size_col, date_col));
         let line = create_file_line(
             file,
+            row,
             show_cursor,
             is_marked,
-            name_col,
-            type_col,
-            size_col,
-            date_col,
+            row_name_col,
+            show_badges.then_some(&panel.git_badges),
             theme,
         );
 
@@ -208,16 +304,20 @@ pub fn draw(frame: &mut Frame, panel: &mut PanelState, area: Rect, is_active: bo
     }
 
     // Footer (폴더 정보 + 디스크 용량)
-    let dir_count = panel.files.iter().filter(|f| f.name != ".." && f.is_directory).count();
-    let file_count = panel.files.iter().filter(|f| !f.is_directory).count();
-    let total_size: u64 = panel.files.iter().filter(|f| !f.is_directory).map(|f| f.size).sum();
+    let dir_count = panel.list_stats.dir_count;
+    let file_count = panel.list_stats.file_count;
+    let total_size = panel.list_stats.total_size;
 
-    // 선택된 파일 정보 계산
+    // 선택된 파일 정보 계산 (선택이 있을 때만 목록 순회)
     let selected_count = panel.selected_files.len();
-    let selected_size: u64 = panel.files.iter()
-        .filter(|f| panel.selected_files.contains(&f.name))
-        .map(|f| f.size)
-        .sum();
+    let selected_size: u64 = if selected_count > 0 {
+        panel.files.iter()
+            .filter(|f| panel.selected_files.contains(&f.name))
+            .map(|f| f.size)
+            .sum()
+    } else {
+        0
+    };
 
     let number_style = Style::default().fg(theme.panel.directory_text);
     let label_style = theme.dim_style();
@@ -334,25 +434,15 @@ fn create_header_line(panel: &PanelState, name_width: usize, type_width: usize,
 }
 
 
-fn create_file_line(
-    file: &super::app::FileItem,
-    is_cursor: bool,
-    is_marked: bool,
+/// Format the width-dependent column text of a row
+fn format_row_text(
+    file: &FileItem,
+    dir_size: Option<u64>,
     name_width: usize,
     type_width: usize,
     size_width: usize,
     date_width: usize,
-    theme: &Theme,
-) -> Line<'static> {
-    let marker = if is_marked { "✻" } else { " " };
-    let icon = if file.is_symlink {
-        theme.chars.symlink.to_string()
-    } else if file.is_directory {
-        theme.chars.folder.to_string()
-    } else {
-        theme.chars.file.to_string()
-    };
-
+) -> RowText {
     // Truncate name if needed using unicode display width
     let effective_name_width = name_width.saturating_sub(2);
     let name_str = file.display_name.as_deref().unwrap_or(&file.name);
@@ -373,10 +463,6 @@ fn create_file_line(
         }
     };
 
-    // Pad name column to exact width using unicode-aware padding
-    let name_with_prefix = format!("{}{}{}", marker, &icon, display_name);
-    let name_col = pad_to_display_width(&name_with_prefix, name_width);
-
     // Type column: show file extension (max 6 chars, center aligned)
     let type_col_str = if type_width > 0 {
         let type_str = if file.is_directory || file.name == ".." {
@@ -405,7 +491,8 @@ fn create_file_line(
     };
 
     let size_str = if file.is_directory {
-        "<DIR>".to_string()
+        // Measured by the directory size indexer, if enabled
+        dir_size.map(format_size).unwrap_or_else(|| "<DIR>".to_string())
     } else {
         format_size(file.size)
     };
@@ -418,7 +505,7 @@ fn create_file_line(
     let date_str = if file.name == ".." {
         String::new()
     } else {
-        file.modified.format("%m-%d %H:%M").to_string()
+        format_short_datetime(&file.modified)
     };
     let date_col = if date_width > 2 {
         format!("{:>width$}  ", date_str, width = date_width.saturating_sub(2))
@@ -426,6 +513,43 @@ fn create_file_line(
         String::new()
     };
 
+    RowText {
+        name: display_name,
+        type_col: type_col_str,
+        size_col,
+        date_col,
+    }
+}
+
+fn create_file_line(
+    file: &FileItem,
+    row: &RowText,
+    is_cursor: bool,
+    is_marked: bool,
+    name_width: usize,
+    badges: Option<&Badges>,
+    theme: &Theme,
+) -> Line<'static> {
+    // 텍스트 마커: 커서 ">" + 마크 "*" (색상 없이도 구분 가능)
+    let marker = if text_markers() {
+        format!("{}{}", if is_cursor { ">" } else { " " }, if is_marked { "*" } else { " " })
+    } else if is_marked {
+        "✻".to_string()
+    } else {
+        " ".to_string()
+    };
+    let icon = if file.is_symlink {
+        theme.chars.symlink.to_string()
+    } else if file.is_directory {
+        theme.chars.folder.to_string()
+    } else {
+        theme.chars.file.to_string()
+    };
+
+    // Pad name column to exact width using unicode-aware padding
+    let name_with_prefix = format!("{}{}{}", marker, &icon, row.name);
+    let name_col = pad_to_display_width(&name_with_prefix, name_width);
+
     // Cursor style: 배경색을 항목의 원래 글자색으로 설정
     let name_style = if is_cursor {
         let cursor_bg = if is_marked {
@@ -467,10 +591,27 @@ fn create_file_line(
         theme.dim_style()
     };
 
+    let badge = badges.filter(|_| file.name != "..").and_then(|b| b.get(&file.name));
+    let badge_style = match badge {
+        _ if is_cursor => other_style,
+        Some(GitBadge::Modified) => Style::default().fg(theme.panel.git_badge_modified),
+        Some(GitBadge::Added) | Some(GitBadge::Renamed) => Style::default().fg(theme.panel.git_badge_staged),
+        Some(GitBadge::Deleted) | Some(GitBadge::Conflicted) => Style::default().fg(theme.panel.git_badge_deleted),
+        Some(GitBadge::Untracked) => Style::default().fg(theme.panel.git_badge_untracked),
+        Some(GitBadge::Ignored) => Style::default().fg(theme.panel.git_badge_ignored),
+        None => theme.dim_style(),
+    };
+    let badge_col = if badges.is_some() {
+        format!("{:<width$}", badge.map(|b| b.label()).unwrap_or(""), width = BADGE_WIDTH)
+    } else {
+        String::new()
+    };
+
     Line::from(vec![
         Span::styled(name_col, name_style),
-        Span::styled(type_col_str, other_style),
-        Span::styled(size_col, other_style),
-        Span::styled(date_col, other_style),
+        Span::styled(badge_col, badge_style.add_modifier(Modifier::BOLD)),
+        Span::styled(row.type_col.clone(), other_style),
+        Span::styled(row.size_col.clone(), other_style),
+        Span::styled(row.date_col.clone(), other_style),
     ])
 }
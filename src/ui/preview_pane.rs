@@ -0,0 +1,338 @@
+//! Quick view pane: the target panel shows a live preview of the file under
+//! the cursor in the active panel (text head with syntax highlighting, image,
+//! archive listing or directory contents).
+//!
+//! Previews are built on a background thread. Only the most recent request
+//! is served, so holding an arrow key never queues up stale work, and results
+//! for a path the cursor already left are discarded.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use image::DynamicImage;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use ratatui_image::protocol::StatefulProtocol;
+
+use crate::services::archive::{self, ArchiveEntry, ArchiveKind};
+use crate::ui::image_viewer::is_image_file;
+use crate::ui::syntax::{Language, SyntaxHighlighter};
+use crate::ui::theme::Theme;
+use crate::utils::format::format_size;
+
+/// Bytes read from the head of a text file
+const TEXT_HEAD_BYTES: usize = 64 * 1024;
+
+/// Lines kept from the head of a text file or a listing
+const MAX_LINES: usize = 500;
+
+/// Preview of one file
+pub enum PreviewContent {
+    Text { lines: Vec<String>, language: Language, truncated: bool },
+    Image { image: DynamicImage, original_size: (u32, u32) },
+    Archive { entries: Vec<ArchiveEntry> },
+    Directory { entries: Vec<(String, bool)>, total: usize },
+    Binary { size: u64 },
+    Message(String),
+}
+
+pub struct PreviewPane {
+    /// Panel index the preview is drawn in place of
+    pub panel_index: usize,
+    /// File currently shown (or being loaded)
+    pub path: Option<PathBuf>,
+    pub content: Option<PreviewContent>,
+    pub loading: bool,
+    /// Inline image protocol for `PreviewContent::Image`
+    pub protocol: Option<Box<dyn StatefulProtocol>>,
+    tar_path: Option<String>,
+    requests: Sender<(PathBuf, Option<String>)>,
+    results: Receiver<(PathBuf, PreviewContent)>,
+}
+
+impl PreviewPane {
+    pub fn new(panel_index: usize, tar_path: Option<String>) -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<(PathBuf, Option<String>)>();
+        let (result_tx, result_rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            while let Ok(mut request) = request_rx.recv() {
+                // 밀린 요청은 건너뛰고 가장 최근 것만 처리
+                while let Ok(newer) = request_rx.try_recv() {
+                    request = newer;
+                }
+                let (path, tar_path) = request;
+                let content = load_preview(&path, tar_path.as_deref());
+                if result_tx.send((path, content)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            panel_index,
+            path: None,
+            content: None,
+            loading: false,
+            protocol: None,
+            tar_path,
+            requests: request_tx,
+            results: result_rx,
+        }
+    }
+
+    /// Show `path` (None clears the pane). Does nothing if it is already shown.
+    pub fn show(&mut self, path: Option<PathBuf>) {
+        if self.path == path {
+            return;
+        }
+        self.path = path.clone();
+        self.protocol = None;
+        match path {
+            Some(path) => {
+                self.loading = true;
+                let _ = self.requests.send((path, self.tar_path.clone()));
+            }
+            None => {
+                self.loading = false;
+                self.content = None;
+            }
+        }
+    }
+
+    /// Show a fixed message instead of a file preview
+    pub fn show_message(&mut self, message: &str) {
+        self.path = None;
+        self.loading = false;
+        self.protocol = None;
+        self.content = Some(PreviewContent::Message(message.to_string()));
+    }
+
+    /// Take a finished preview. Returns true when the content changed.
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        for (path, content) in self.results.try_iter() {
+            if self.path.as_ref() == Some(&path) {
+                self.content = Some(content);
+                self.loading = false;
+                self.protocol = None;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// Build the preview of `path` (runs on the worker thread)
+pub fn load_preview(path: &Path, tar_path: Option<&str>) -> PreviewContent {
+    let metadata = match std::fs::metadata(path) {
+        Ok(m) => m,
+        Err(e) => return PreviewContent::Message(format!("Cannot read: {}", e)),
+    };
+
+    if metadata.is_dir() {
+        return match std::fs::read_dir(path) {
+            Ok(read_dir) => {
+                let mut entries: Vec<(String, bool)> = read_dir
+                    .filter_map(|e| e.ok())
+                    .map(|e| (e.file_name().to_string_lossy().to_string(), e.path().is_dir()))
+                    .collect();
+                let total = entries.len();
+                entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.to_lowercase().cmp(&b.0.to_lowercase())));
+                entries.truncate(MAX_LINES);
+                PreviewContent::Directory { entries, total }
+            }
+            Err(e) => PreviewContent::Message(format!("Cannot read: {}", e)),
+        };
+    }
+
+    if is_image_file(path) {
+        return match crate::services::thumbnails::load(path) {
+            Ok(loaded) => PreviewContent::Image { image: loaded.image, original_size: loaded.original_size },
+            Err(e) => PreviewContent::Message(e),
+        };
+    }
+
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    if let Some(kind) = ArchiveKind::from_name(&name) {
+        let tar_cmd = match kind {
+            ArchiveKind::Tar => match archive::find_tar(tar_path) {
+                Some(cmd) => cmd,
+                None => return PreviewContent::Message("tar command not found".to_string()),
+            },
+            ArchiveKind::Zip => String::new(),
+        };
+        return match archive::list(kind, &tar_cmd, path) {
+            Ok(entries) => PreviewContent::Archive { entries },
+            Err(e) => PreviewContent::Message(e),
+        };
+    }
+
+    let mut head = Vec::new();
+    let read = std::fs::File::open(path)
+        .and_then(|f| f.take(TEXT_HEAD_BYTES as u64).read_to_end(&mut head));
+    if let Err(e) = read {
+        return PreviewContent::Message(format!("Cannot read: {}", e));
+    }
+    if head.contains(&0) {
+        return PreviewContent::Binary { size: metadata.len() };
+    }
+
+    let text = String::from_utf8_lossy(&head);
+    let mut lines: Vec<String> = text.lines().map(|l| l.replace('\t', "    ")).collect();
+    let mut truncated = metadata.len() > head.len() as u64;
+    if truncated && !text.ends_with('\n') {
+        // 잘린 마지막 줄은 버림
+        lines.pop();
+    }
+    if lines.len() > MAX_LINES {
+        lines.truncate(MAX_LINES);
+        truncated = true;
+    }
+    PreviewContent::Text { lines, language: Language::from_extension(path), truncated }
+}
+
+pub fn draw(frame: &mut Frame, pane: &mut PreviewPane, area: Rect, theme: &Theme) {
+    let name = pane.path.as_ref()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let detail = match &pane.content {
+        Some(PreviewContent::Image { original_size: (w, h), .. }) if !pane.loading => format!(" ({}x{})", w, h),
+        Some(PreviewContent::Archive { entries }) if !pane.loading => format!(" ({} entries)", entries.len()),
+        Some(PreviewContent::Directory { total, .. }) if !pane.loading => format!(" ({} items)", total),
+        _ => String::new(),
+    };
+    let title = if name.is_empty() {
+        " Quick view ".to_string()
+    } else {
+        format!(" Quick view: {}{} ", name, detail)
+    };
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(theme.preview_pane.title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.preview_pane.border))
+        .style(Style::default().bg(theme.preview_pane.bg));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let text_style = Style::default().fg(theme.preview_pane.text);
+    let dim_style = Style::default().fg(theme.preview_pane.dim_text);
+    if pane.loading && pane.content.is_none() {
+        frame.render_widget(Paragraph::new(Span::styled("Loading...", dim_style)), inner);
+        return;
+    }
+
+    let height = inner.height as usize;
+    let lines: Vec<Line> = match &pane.content {
+        None => Vec::new(),
+        Some(PreviewContent::Message(message)) => vec![Line::from(Span::styled(message.clone(), dim_style))],
+        Some(PreviewContent::Binary { size }) => {
+            vec![Line::from(Span::styled(format!("Binary file, {}", format_size(*size)), dim_style))]
+        }
+        Some(PreviewContent::Text { lines, language, truncated }) => {
+            let mut highlighter = SyntaxHighlighter::new(*language, theme.syntax);
+            let mut out: Vec<Line> = lines.iter().take(height).map(|line| {
+                if *language == Language::Plain {
+                    Line::from(Span::styled(line.clone(), text_style))
+                } else {
+                    let spans: Vec<Span> = highlighter.tokenize_line(line).into_iter()
+                        .map(|t| Span::styled(t.text, highlighter.style_for(t.token_type)))
+                        .collect();
+                    Line::from(spans)
+                }
+            }).collect();
+            if *truncated && lines.len() < height {
+                out.push(Line::from(Span::styled("...", dim_style)));
+            }
+            out
+        }
+        Some(PreviewContent::Archive { entries }) => {
+            let width = inner.width as usize;
+            entries.iter().take(height).map(|entry| {
+                let size = if entry.is_dir { String::new() } else { format_size(entry.size) };
+                let name_width = width.saturating_sub(size.len() + 1);
+                let path = if entry.is_dir { format!("{}/", entry.path) } else { entry.path.clone() };
+                let style = if entry.is_dir { Style::default().fg(theme.preview_pane.directory_text) } else { text_style };
+                Line::from(vec![
+                    Span::styled(format!("{:<w$.w$}", path, w = name_width), style),
+                    Span::styled(format!(" {}", size), Style::default().fg(theme.preview_pane.size_text)),
+                ])
+            }).collect()
+        }
+        Some(PreviewContent::Directory { entries, .. }) => {
+            entries.iter().take(height).map(|(name, is_dir)| {
+                if *is_dir {
+                    Line::from(Span::styled(format!("{}/", name), Style::default().fg(theme.preview_pane.directory_text)))
+                } else {
+                    Line::from(Span::styled(name.clone(), text_style))
+                }
+            }).collect()
+        }
+        Some(PreviewContent::Image { .. }) => {
+            if let Some(ref mut protocol) = pane.protocol {
+                let image_widget = ratatui_image::StatefulImage::new(None);
+                frame.render_stateful_widget(image_widget, inner, protocol);
+            }
+            return;
+        }
+    };
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_preview() {
+        let dir = tempfile::tempdir().unwrap();
+        let text = dir.path().join("main.rs");
+        std::fs::write(&text, "fn main() {\n\tprintln!();\n}\n").unwrap();
+        let binary = dir.path().join("data.bin");
+        std::fs::write(&binary, [0u8, 1, 2, 3]).unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+
+        match load_preview(&text, None) {
+            PreviewContent::Text { lines, language, truncated } => {
+                assert_eq!(lines, vec!["fn main() {", "    println!();", "}"]);
+                assert_eq!(language, Language::Rust);
+                assert!(!truncated);
+            }
+            _ => panic!("expected text preview"),
+        }
+        assert!(matches!(load_preview(&binary, None), PreviewContent::Binary { size: 4 }));
+        match load_preview(dir.path(), None) {
+            PreviewContent::Directory { entries, total } => {
+                assert_eq!(total, 3);
+                assert_eq!(entries[0], ("sub".to_string(), true));
+            }
+            _ => panic!("expected directory preview"),
+        }
+        assert!(matches!(load_preview(&dir.path().join("missing"), None), PreviewContent::Message(_)));
+    }
+
+    #[test]
+    fn test_long_text_is_truncated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("long.txt");
+        let content: String = (0..MAX_LINES + 10).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&path, content).unwrap();
+        match load_preview(&path, None) {
+            PreviewContent::Text { lines, truncated, .. } => {
+                assert_eq!(lines.len(), MAX_LINES);
+                assert!(truncated);
+            }
+            _ => panic!("expected text preview"),
+        }
+    }
+}
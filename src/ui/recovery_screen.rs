@@ -0,0 +1,295 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::services::journal::{self, Operation, OperationKind, StaleJournal};
+use crate::ui::theme::Theme;
+
+/// 목록의 한 줄: 어느 저널의 몇 번째 작업/버퍼인지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecoveryItem {
+    Operation { journal: usize, index: usize },
+    Buffer { journal: usize, index: usize },
+}
+
+/// What to do after the screen closes
+pub enum RecoveryAction {
+    /// Continue an interrupted copy/move
+    Resume(Operation),
+    /// Open `path` in the editor with the backed-up contents
+    RecoverBuffer { path: PathBuf, content: String },
+}
+
+pub struct RecoveryScreenState {
+    journals: Vec<StaleJournal>,
+    items: Vec<RecoveryItem>,
+    pub selected: usize,
+    pub scroll: usize,
+    pub message: String,
+    pub action: Option<RecoveryAction>,
+}
+
+impl RecoveryScreenState {
+    pub fn new(journals: Vec<StaleJournal>) -> Self {
+        let mut state = Self {
+            journals,
+            items: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            message: String::new(),
+            action: None,
+        };
+        state.rebuild_items();
+        state
+    }
+
+    fn rebuild_items(&mut self) {
+        self.items.clear();
+        for (j, stale) in self.journals.iter().enumerate() {
+            for index in 0..stale.journal.operations.len() {
+                self.items.push(RecoveryItem::Operation { journal: j, index });
+            }
+            for index in 0..stale.journal.buffers.len() {
+                self.items.push(RecoveryItem::Buffer { journal: j, index });
+            }
+        }
+        self.selected = self.selected.min(self.items.len().saturating_sub(1));
+    }
+
+    /// Number of items still waiting for a decision
+    pub fn remaining(&self) -> usize {
+        self.items.len()
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.items.is_empty() {
+            return;
+        }
+        let max = self.items.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    /// Take the selected item out of its journal (the journal file is updated)
+    fn take_selected(&mut self) -> Option<RecoveryItemData> {
+        let item = *self.items.get(self.selected)?;
+        let data = match item {
+            RecoveryItem::Operation { journal, index } => {
+                let stale = &mut self.journals[journal];
+                let op = stale.journal.operations.remove(index);
+                stale.save();
+                RecoveryItemData::Operation(op)
+            }
+            RecoveryItem::Buffer { journal, index } => {
+                let stale = &mut self.journals[journal];
+                let buffer = stale.journal.buffers.remove(index);
+                stale.save();
+                RecoveryItemData::Buffer(buffer)
+            }
+        };
+        self.rebuild_items();
+        Some(data)
+    }
+
+    /// Enter: resume the operation / recover the buffer
+    fn restore_selected(&mut self) -> bool {
+        match self.take_selected() {
+            Some(RecoveryItemData::Operation(op)) => {
+                self.action = Some(RecoveryAction::Resume(op));
+                true
+            }
+            Some(RecoveryItemData::Buffer(buffer)) => {
+                let content = std::fs::read_to_string(&buffer.backup);
+                let _ = std::fs::remove_file(&buffer.backup);
+                match content {
+                    Ok(content) => {
+                        self.action = Some(RecoveryAction::RecoverBuffer { path: buffer.path, content });
+                        true
+                    }
+                    Err(e) => {
+                        self.message = format!("Backup of {} is unreadable: {}", buffer.path.display(), e);
+                        false
+                    }
+                }
+            }
+            None => false,
+        }
+    }
+
+    /// c: delete the partial copy / the buffer backup
+    fn clean_up_selected(&mut self) {
+        self.message = match self.take_selected() {
+            Some(RecoveryItemData::Operation(op)) => match op.in_progress {
+                Some(ref partial) => match journal::remove_partial(partial) {
+                    Ok(()) => format!("Removed partial copy {}", partial.display()),
+                    Err(e) => format!("Failed to remove {}: {}", partial.display(), e),
+                },
+                None => "No partial copy was left; operation dismissed".to_string(),
+            },
+            Some(RecoveryItemData::Buffer(buffer)) => {
+                let _ = std::fs::remove_file(&buffer.backup);
+                format!("Discarded unsaved changes of {}", buffer.path.display())
+            }
+            None => return,
+        };
+    }
+}
+
+enum RecoveryItemData {
+    Operation(Operation),
+    Buffer(journal::UnsavedBuffer),
+}
+
+fn describe_operation(op: &Operation) -> String {
+    let verb = match op.kind {
+        OperationKind::Copy => "Copy",
+        OperationKind::Move => "Move",
+    };
+    format!(
+        "{} {} item(s), {} done: {} -> {}",
+        verb,
+        op.files.len() - op.skip.len().min(op.files.len()),
+        op.done.len(),
+        op.source_dir.display(),
+        op.target_dir.display()
+    )
+}
+
+pub fn draw(frame: &mut Frame, state: &mut RecoveryScreenState, area: Rect, theme: &Theme) {
+    let colors = &theme.recovery_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // info box
+            Constraint::Min(3),    // items
+            Constraint::Length(3), // detail / status
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Info box ──
+    let info_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Recovery ", Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+    let info = Line::from(Span::styled(
+        "A previous session ended unexpectedly. Resume or clean up what it left behind.",
+        Style::default().fg(colors.intro_text),
+    ));
+    frame.render_widget(Paragraph::new(info).block(info_block), chunks[0]);
+
+    // ── Items ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    let lines: Vec<Line> = if state.items.is_empty() {
+        vec![Line::from(Span::styled("Nothing left to recover", Style::default().fg(colors.empty_text)))]
+    } else {
+        state
+            .items
+            .iter()
+            .enumerate()
+            .skip(state.scroll)
+            .take(inner_height)
+            .map(|(i, item)| {
+                let (tag, text) = match *item {
+                    RecoveryItem::Operation { journal, index } => {
+                        ("[operation] ", describe_operation(&state.journals[journal].journal.operations[index]))
+                    }
+                    RecoveryItem::Buffer { journal, index } => (
+                        "[unsaved]   ",
+                        state.journals[journal].journal.buffers[index].path.display().to_string(),
+                    ),
+                };
+                let line = Line::from(vec![
+                    Span::styled(tag, Style::default().fg(colors.tag_text)),
+                    Span::styled(text, Style::default().fg(colors.item_text)),
+                ]);
+                if i == state.selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Detail / status ──
+    let detail_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let detail = if !state.message.is_empty() {
+        Line::from(Span::styled(state.message.clone(), Style::default().fg(colors.message_text)))
+    } else {
+        match state.items.get(state.selected) {
+            Some(RecoveryItem::Operation { journal, index }) => {
+                match state.journals[*journal].journal.operations[*index].in_progress {
+                    Some(ref partial) => Line::from(vec![
+                        Span::styled("Partial copy: ", Style::default().fg(colors.label_text)),
+                        Span::styled(partial.display().to_string(), Style::default().fg(colors.partial_path)),
+                    ]),
+                    None => Line::from(Span::styled("No partial copy left", Style::default().fg(colors.hint_text))),
+                }
+            }
+            Some(RecoveryItem::Buffer { .. }) => Line::from(Span::styled(
+                "Enter opens the file in the editor with the unsaved changes",
+                Style::default().fg(colors.hint_text),
+            )),
+            None => Line::default(),
+        }
+    };
+    frame.render_widget(Paragraph::new(detail).block(detail_block), chunks[2]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(" Decide later  "),
+        key("Enter"),
+        text(" Resume/Recover  "),
+        key("Del/c"),
+        text(" Clean up"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut RecoveryScreenState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    state.message.clear();
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => return true,
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.items.len().saturating_sub(1),
+        KeyCode::Enter => return state.restore_selected(),
+        KeyCode::Delete | KeyCode::Char('c') => {
+            state.clean_up_selected();
+            // 모두 처리했으면 닫기
+            return state.items.is_empty();
+        }
+        _ => {}
+    }
+    false
+}
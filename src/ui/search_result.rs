@@ -1,7 +1,12 @@
 use std::fs;
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+use std::sync::Arc;
 use chrono::{DateTime, Local};
 use crossterm::event::{KeyCode, KeyModifiers};
+use regex::Regex;
 use unicode_width::UnicodeWidthStr;
 use ratatui::{
     layout::Rect,
@@ -12,8 +17,40 @@ use ratatui::{
 };
 
 use super::theme::Theme;
+use super::advanced_search::{matches_criteria, SearchCriteria};
+use crate::services::gitignore::IgnoreMatcher;
 use crate::utils::format::safe_suffix;
 
+/// 내용 검색에서 이보다 큰 파일은 건너뜀
+const MAX_CONTENT_FILE_SIZE: u64 = 50 * 1024 * 1024;
+
+/// 바이너리 판별에 읽는 앞부분 크기
+const BINARY_CHECK_BYTES: usize = 8192;
+
+/// 매치 앞에 남길 문맥 (문자 수)
+const SNIPPET_CONTEXT: usize = 30;
+
+/// 매치 줄 발췌 최대 길이 (문자 수)
+const SNIPPET_MAX_CHARS: usize = 200;
+
+/// Find File 다이얼로그 옵션 (Tab: 이름/내용, Alt+R: 정규식, Alt+C: 대소문자 구분,
+/// Alt+G: .gitignore/.ignore로 무시된 경로 건너뛰기)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub content: bool,
+    pub regex: bool,
+    pub case_sensitive: bool,
+    pub respect_ignore: bool,
+}
+
+/// 내용 검색에서 일치한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineMatch {
+    pub line: usize,                  // 줄 번호 (1부터)
+    pub text: String,                 // 줄 발췌 (앞 공백 제거, 길면 매치 주변만)
+    pub ranges: Vec<(usize, usize)>,  // text 안의 매치 위치 (문자 인덱스)
+}
+
 /// 검색 결과 아이템
 #[derive(Debug, Clone)]
 pub struct SearchResultItem {
@@ -23,6 +60,15 @@ pub struct SearchResultItem {
     pub is_directory: bool,
     pub size: u64,
     pub modified: DateTime<Local>,
+    pub line_match: Option<LineMatch>, // 내용 검색 결과면 일치한 줄
+}
+
+/// 검색 스레드가 보내는 메시지
+#[derive(Debug)]
+pub enum SearchMessage {
+    Match(Box<SearchResultItem>),
+    Scanned(usize), // 지금까지 살펴본 파일 수 (이름 검색은 항목 수)
+    Done,
 }
 
 /// 검색 결과 상태
@@ -34,6 +80,12 @@ pub struct SearchResultState {
     pub search_term: String,
     pub base_path: PathBuf,     // 검색 시작 경로
     pub active: bool,
+    pub open_position: Option<(usize, usize)>, // "name:line:col" 검색 시 파일을 열 위치
+    pub content: bool,          // 내용 검색 결과 여부
+    pub searching: bool,        // 검색 스레드 진행 중
+    pub files_scanned: usize,
+    receiver: Option<Receiver<SearchMessage>>,
+    cancel_flag: Arc<AtomicBool>,
 }
 
 impl Default for SearchResultState {
@@ -45,6 +97,12 @@ impl Default for SearchResultState {
             search_term: String::new(),
             base_path: PathBuf::new(),
             active: false,
+            open_position: None,
+            content: false,
+            searching: false,
+            files_scanned: 0,
+            receiver: None,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
         }
     }
 }
@@ -55,6 +113,102 @@ impl SearchResultState {
         Self::default()
     }
 
+    /// 이름 검색 시작: `base_path` 아래에서 이름에 검색어가 들어간 항목을 찾아 도착하는 대로 표시
+    pub fn start_name_search(&mut self, base_path: PathBuf, search_term: String, max_results: usize, respect_ignore: bool) {
+        let term = search_term.clone();
+        self.start(base_path, search_term, false, move |base_path, cancel_flag, tx| {
+            name_search(base_path, &term, max_results, respect_ignore, cancel_flag, tx);
+        });
+    }
+
+    /// 스마트 폴더 열기: `base_path` 아래에서 `criteria`(이름, 크기, 수정일)에 맞는 파일을 찾아 표시.
+    /// 제목에는 스마트 폴더 이름(`label`)이 나옴
+    pub fn start_criteria_search(&mut self, base_path: PathBuf, label: String, criteria: SearchCriteria, max_results: usize) {
+        self.start(base_path, label, false, move |base_path, cancel_flag, tx| {
+            criteria_search(base_path, &criteria, max_results, cancel_flag, tx);
+        });
+    }
+
+    /// 내용 검색 시작: 이전 결과를 비우고 `re`로 `base_path` 아래 파일 내용을 검색
+    pub fn start_content_search(&mut self, base_path: PathBuf, search_term: String, re: Regex, max_results: usize, respect_ignore: bool) {
+        self.start(base_path, search_term, true, move |base_path, cancel_flag, tx| {
+            content_search(base_path, &re, max_results, respect_ignore, cancel_flag, tx);
+        });
+    }
+
+    /// 이전 검색을 중단하고 결과를 비운 뒤 `search`를 백그라운드 스레드에서 실행
+    fn start<F>(&mut self, base_path: PathBuf, search_term: String, content: bool, search: F)
+    where
+        F: FnOnce(&Path, &AtomicBool, &Sender<SearchMessage>) + Send + 'static,
+    {
+        self.cancel();
+        self.results.clear();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+        self.search_term = search_term;
+        self.base_path = base_path.clone();
+        self.open_position = None;
+        self.content = content;
+        self.searching = true;
+        self.files_scanned = 0;
+        self.active = true;
+
+        let cancel_flag = self.cancel_flag.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.receiver = Some(rx);
+        std::thread::spawn(move || {
+            search(&base_path, &cancel_flag, &tx);
+            let _ = tx.send(SearchMessage::Done);
+        });
+    }
+
+    /// 진행 중인 검색 중단 (받은 결과는 유지)
+    pub fn cancel(&mut self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+        self.cancel_flag = Arc::new(AtomicBool::new(false));
+        self.receiver = None;
+        self.searching = false;
+    }
+
+    /// 검색 스레드가 보낸 결과 수집. 새 결과가 있으면 true
+    pub fn poll(&mut self) -> bool {
+        let Some(receiver) = self.receiver.take() else { return false };
+        let mut changed = false;
+        loop {
+            match receiver.try_recv() {
+                Ok(SearchMessage::Match(item)) if self.content => {
+                    self.results.push(*item);
+                    changed = true;
+                }
+                Ok(SearchMessage::Match(item)) => {
+                    self.insert_sorted(*item);
+                    changed = true;
+                }
+                Ok(SearchMessage::Scanned(count)) => {
+                    self.files_scanned = count;
+                    changed = true;
+                }
+                Ok(SearchMessage::Done) | Err(TryRecvError::Disconnected) => {
+                    self.searching = false;
+                    return true;
+                }
+                Err(TryRecvError::Empty) => break,
+            }
+        }
+        self.receiver = Some(receiver);
+        changed
+    }
+
+    /// 이름 검색 결과를 정렬 위치(디렉토리 우선, 이름순)에 끼워 넣음.
+    /// 커서는 보고 있던 항목을 계속 가리킴
+    fn insert_sorted(&mut self, item: SearchResultItem) {
+        let index = self.results.partition_point(|r| compare_items(r, &item).is_le());
+        if index <= self.selected_index && !self.results.is_empty() {
+            self.selected_index += 1;
+        }
+        self.results.insert(index, item);
+    }
+
     /// 현재 선택된 아이템 반환
     pub fn current_item(&self) -> Option<&SearchResultItem> {
         self.results.get(self.selected_index)
@@ -98,88 +252,257 @@ impl SearchResultState {
     }
 }
 
-/// 재귀적으로 파일 검색
-pub fn recursive_search(
-    base_path: &PathBuf,
-    current_path: &PathBuf,
+/// 이름 검색 결과 순서: 디렉토리 우선, 그 다음 이름순 (대소문자 무시)
+fn compare_items(a: &SearchResultItem, b: &SearchResultItem) -> std::cmp::Ordering {
+    b.is_directory
+        .cmp(&a.is_directory)
+        .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+}
+
+/// 검색할 디렉토리 스택의 시작: `respect_ignore`면 .gitignore/.ignore 규칙과 함께
+fn walk_start(base_path: &Path, respect_ignore: bool) -> Vec<(PathBuf, Option<IgnoreMatcher>)> {
+    vec![(base_path.to_path_buf(), respect_ignore.then(|| IgnoreMatcher::new(base_path)))]
+}
+
+/// 하위 디렉토리들을 이름순으로 꺼내도록 역순으로 쌓음 (각자의 ignore 파일 규칙 추가)
+fn walk_push(stack: &mut Vec<(PathBuf, Option<IgnoreMatcher>)>, subdirs: Vec<PathBuf>, ignore: &Option<IgnoreMatcher>) {
+    stack.extend(subdirs.into_iter().rev().map(|dir| {
+        let child = ignore.as_ref().map(|m| m.child(&dir));
+        (dir, child)
+    }));
+}
+
+/// `base_path` 아래에서 이름에 `search_term`이 들어간 항목(대소문자 무시)을 찾아 하나씩 `tx`로 보냄.
+/// 디렉토리마다 살펴본 항목 수를 보내고, 심볼릭 링크 디렉토리는 따라가지 않음.
+/// `respect_ignore`면 .gitignore/.ignore로 무시된 항목과 그 아래는 건너뜀
+pub fn name_search(
+    base_path: &Path,
     search_term: &str,
-    results: &mut Vec<SearchResultItem>,
     max_results: usize,
+    respect_ignore: bool,
+    cancel_flag: &AtomicBool,
+    tx: &Sender<SearchMessage>,
 ) {
-    if results.len() >= max_results {
-        return;
-    }
-
     let lower_term = search_term.to_lowercase();
+    walk_matches(base_path, max_results, respect_ignore, cancel_flag, tx, |name, _| {
+        name.to_lowercase().contains(&lower_term)
+    });
+}
+
+/// `base_path` 아래에서 고급 검색 조건에 맞는 파일(디렉토리 제외)을 찾아 하나씩 `tx`로 보냄
+pub fn criteria_search(
+    base_path: &Path,
+    criteria: &SearchCriteria,
+    max_results: usize,
+    cancel_flag: &AtomicBool,
+    tx: &Sender<SearchMessage>,
+) {
+    walk_matches(base_path, max_results, false, cancel_flag, tx, |name, metadata| {
+        let modified = metadata.modified().ok().map(DateTime::<Local>::from).unwrap_or_else(Local::now);
+        metadata.is_file() && matches_criteria(name, metadata.len(), modified, criteria)
+    });
+}
 
-    if let Ok(entries) = fs::read_dir(current_path) {
-        for entry in entries.filter_map(|e| e.ok()) {
-            if results.len() >= max_results {
+/// 이름순으로 `base_path` 아래를 돌며 `is_match`(이름, 메타데이터)에 맞는 항목을 `tx`로 보냄
+fn walk_matches(
+    base_path: &Path,
+    max_results: usize,
+    respect_ignore: bool,
+    cancel_flag: &AtomicBool,
+    tx: &Sender<SearchMessage>,
+    is_match: impl Fn(&str, &fs::Metadata) -> bool,
+) {
+    let mut found = 0;
+    let mut scanned = 0;
+    let mut stack = walk_start(base_path, respect_ignore);
+    while let Some((dir, ignore)) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        let mut entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+        entries.sort_by_key(|e| e.file_name());
+        let mut subdirs = Vec::new();
+        for entry in entries {
+            if cancel_flag.load(Ordering::Relaxed) || found >= max_results {
                 return;
             }
-
+            scanned += 1;
             let path = entry.path();
-            let name = entry.file_name().to_string_lossy().to_string();
+            let Ok(metadata) = fs::symlink_metadata(&path) else { continue };
+            // Symlink targets: don't follow into directories to avoid cycles
+            let is_directory = metadata.is_dir();
+            if ignore.as_ref().is_some_and(|m| m.is_ignored(&path, is_directory)) {
+                continue;
+            }
+            if is_directory {
+                subdirs.push(path.clone());
+            }
 
-            let metadata = match fs::symlink_metadata(&path) {
-                Ok(m) => m,
-                Err(_) => continue,
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !is_match(&name, &metadata) {
+                continue;
+            }
+            found += 1;
+            let relative_path = path
+                .strip_prefix(base_path)
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|_| path.display().to_string());
+            let item = SearchResultItem {
+                full_path: path,
+                relative_path,
+                name,
+                is_directory,
+                size: if is_directory { 0 } else { metadata.len() },
+                modified: metadata.modified().ok().map(DateTime::<Local>::from).unwrap_or_else(Local::now),
+                line_match: None,
             };
+            if tx.send(SearchMessage::Match(Box::new(item))).is_err() {
+                return;
+            }
+        }
+        if tx.send(SearchMessage::Scanned(scanned)).is_err() {
+            return;
+        }
+        walk_push(&mut stack, subdirs, &ignore);
+    }
+}
 
-            // Symlink targets: don't follow into directories to avoid cycles
-            let is_directory = metadata.is_dir() && !metadata.file_type().is_symlink();
-
-            // 파일명이 검색어를 포함하는지 확인 (대소문자 무시)
-            if name.to_lowercase().contains(&lower_term) {
-                let relative_path = path
-                    .strip_prefix(base_path)
-                    .map(|p| p.display().to_string())
-                    .unwrap_or_else(|_| path.display().to_string());
-
-                let size = if is_directory { 0 } else { metadata.len() };
-                let modified = metadata
-                    .modified()
-                    .ok()
-                    .map(DateTime::<Local>::from)
-                    .unwrap_or_else(Local::now);
-
-                results.push(SearchResultItem {
+/// 내용 검색 정규식 생성 (정규식 옵션이 꺼져 있으면 검색어 그대로 매치)
+pub fn build_content_regex(term: &str, options: SearchOptions) -> Result<Regex, String> {
+    let pattern = if options.regex { term.to_string() } else { regex::escape(term) };
+    regex::RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+        .map_err(|e| format!("Invalid regex: {}", e))
+}
+
+/// `dir` 아래 파일 내용을 재귀 검색하여 일치하는 줄을 하나씩 `tx`로 보냄.
+/// 바이너리 파일과 너무 큰 파일은 건너뛰고, 심볼릭 링크 디렉토리는 따라가지 않음.
+/// `respect_ignore`면 .gitignore/.ignore로 무시된 파일과 디렉토리는 읽지 않음
+pub fn content_search(
+    base_path: &Path,
+    re: &Regex,
+    max_results: usize,
+    respect_ignore: bool,
+    cancel_flag: &AtomicBool,
+    tx: &Sender<SearchMessage>,
+) {
+    let mut found = 0;
+    let mut scanned = 0;
+    let mut stack = walk_start(base_path, respect_ignore);
+    while let Some((dir, ignore)) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        let mut entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+        entries.sort_by_key(|e| e.file_name());
+        let mut subdirs = Vec::new();
+        for entry in entries {
+            if cancel_flag.load(Ordering::Relaxed) || found >= max_results {
+                return;
+            }
+            let path = entry.path();
+            let Ok(metadata) = fs::symlink_metadata(&path) else { continue };
+            if ignore.as_ref().is_some_and(|m| m.is_ignored(&path, metadata.is_dir())) {
+                continue;
+            }
+            if metadata.is_dir() {
+                subdirs.push(path);
+                continue;
+            }
+            if !metadata.is_file() || metadata.len() > MAX_CONTENT_FILE_SIZE {
+                continue;
+            }
+            let matches = search_file(&path, re, max_results - found, cancel_flag);
+            scanned += 1;
+            if tx.send(SearchMessage::Scanned(scanned)).is_err() {
+                return;
+            }
+            if matches.is_empty() {
+                continue;
+            }
+            let relative_path = path
+                .strip_prefix(base_path)
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|_| path.display().to_string());
+            let name = entry.file_name().to_string_lossy().to_string();
+            let modified = metadata.modified().ok().map(DateTime::<Local>::from).unwrap_or_else(Local::now);
+            for line_match in matches {
+                found += 1;
+                let item = SearchResultItem {
                     full_path: path.clone(),
-                    relative_path,
-                    name,
-                    is_directory,
-                    size,
+                    relative_path: relative_path.clone(),
+                    name: name.clone(),
+                    is_directory: false,
+                    size: metadata.len(),
                     modified,
-                });
+                    line_match: Some(line_match),
+                };
+                if tx.send(SearchMessage::Match(Box::new(item))).is_err() {
+                    return;
+                }
             }
+        }
+        walk_push(&mut stack, subdirs, &ignore);
+    }
+}
 
-            // 디렉토리인 경우 재귀 검색
-            if is_directory {
-                recursive_search(base_path, &path, search_term, results, max_results);
+/// 한 파일에서 일치하는 줄 찾기 (최대 `limit`개, 바이너리 파일이면 없음)
+fn search_file(path: &Path, re: &Regex, limit: usize, cancel_flag: &AtomicBool) -> Vec<LineMatch> {
+    let Ok(file) = fs::File::open(path) else { return Vec::new() };
+    let mut reader = BufReader::new(file);
+
+    let mut head = Vec::with_capacity(BINARY_CHECK_BYTES);
+    if reader.by_ref().take(BINARY_CHECK_BYTES as u64).read_to_end(&mut head).is_err() || head.contains(&0) {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    let mut reader = head.as_slice().chain(reader);
+    let mut buf = Vec::new();
+    let mut line_number = 0;
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        line_number += 1;
+        if line_number % 10_000 == 0 && cancel_flag.load(Ordering::Relaxed) {
+            break;
+        }
+        let line = String::from_utf8_lossy(&buf);
+        let line = line.trim_end_matches(['\n', '\r']);
+        if let Some((text, ranges)) = snippet(line, re) {
+            matches.push(LineMatch { line: line_number, text, ranges });
+            if matches.len() >= limit {
+                break;
             }
         }
     }
+    matches
 }
 
-/// 검색 실행 및 결과 정렬
-pub fn execute_recursive_search(
-    base_path: &PathBuf,
-    search_term: &str,
-    max_results: usize,
-) -> Vec<SearchResultItem> {
-    let mut results = Vec::new();
-    recursive_search(base_path, base_path, search_term, &mut results, max_results);
-
-    // 결과 정렬: 디렉토리 우선, 그 다음 이름순
-    results.sort_by(|a, b| {
-        match (a.is_directory, b.is_directory) {
-            (true, false) => std::cmp::Ordering::Less,
-            (false, true) => std::cmp::Ordering::Greater,
-            _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-        }
-    });
+/// 매치가 있으면 줄 발췌와 발췌 안의 매치 위치(문자 인덱스) 반환.
+/// 앞 공백은 버리고, 긴 줄은 첫 매치 앞 문맥부터 `SNIPPET_MAX_CHARS`자까지만 남김
+fn snippet(line: &str, re: &Regex) -> Option<(String, Vec<(usize, usize)>)> {
+    let char_index = |byte: usize| line[..byte].chars().count();
+    let matches: Vec<(usize, usize)> = re
+        .find_iter(line)
+        .filter(|m| !m.as_str().is_empty())
+        .map(|m| (char_index(m.start()), char_index(m.end())))
+        .collect();
+    let &(first_start, _) = matches.first()?;
+
+    let chars: Vec<char> = line.chars().collect();
+    let indent = chars.iter().take_while(|c| c.is_whitespace()).count().min(first_start);
+    let start = indent.max(first_start.saturating_sub(SNIPPET_CONTEXT));
+    let end = chars.len().min(start + SNIPPET_MAX_CHARS);
+    let (prefix, offset) = if start > indent { ("…", 1) } else { ("", 0) };
 
-    results
+    let text = format!("{}{}", prefix, chars[start..end].iter().collect::<String>());
+    let ranges = matches
+        .into_iter()
+        .filter(|&(s, _)| s < end)
+        .map(|(s, e)| (s - start + offset, e.min(end) - start + offset))
+        .collect();
+    Some((text, ranges))
 }
 
 /// 검색 결과 화면 그리기
@@ -190,11 +513,18 @@ pub fn draw(
     theme: &Theme,
     kb: &crate::keybindings::Keybindings,
 ) {
-    let title = format!(
-        " Search Results: \"{}\" ({} found) ",
-        state.search_term,
-        state.results.len()
-    );
+    // 검색 중이면 스피너와 진행 상황 표시
+    let status = if state.searching {
+        let unit = if state.content { "files" } else { "entries" };
+        format!(", {} {} scanned {}", state.files_scanned, unit, spinner_frame())
+    } else {
+        String::new()
+    };
+    let title = if state.content {
+        format!(" Content Search: \"{}\" ({} found{}) ", state.search_term, state.results.len(), status)
+    } else {
+        format!(" Search Results: \"{}\" ({} found{}) ", state.search_term, state.results.len(), status)
+    };
 
     let block = Block::default()
         .title(title)
@@ -206,20 +536,44 @@ pub fn draw(
     frame.render_widget(block, area);
 
     if state.results.is_empty() {
-        // 검색 결과 없음
-        let no_result = Paragraph::new(Line::from(Span::styled(
-            "No files found matching the search term.",
-            theme.dim_style(),
-        )));
+        // 검색 결과 없음 (아직 검색 중일 수 있음)
+        let text = if state.searching {
+            "Searching..."
+        } else if state.content {
+            "No lines found matching the search term."
+        } else {
+            "No files found matching the search term."
+        };
+        let no_result = Paragraph::new(Line::from(Span::styled(text, theme.dim_style())));
         frame.render_widget(no_result, inner);
         return;
     }
 
-    // 동적 경로 열 너비 계산: 전체 너비 - 인덱스(6) - 크기(11) - 날짜(17) - 여백(3)
-    let path_width = inner.width.saturating_sub(37) as usize;
+    // 동적 경로 열 너비 계산: 전체 너비 - 인덱스(6) - 크기(11) - 날짜(설정 형식, 16자 이상 + 1) - 여백(3)
+    let date_width = crate::utils::format::datetime_width(false).max(16);
+    let path_width = (inner.width as usize).saturating_sub(21 + date_width);
+
+    // 내용 검색: 경로:줄 열 + 일치한 줄 발췌 열
+    let content_path_width = (inner.width as usize * 2 / 5).max(20);
+    let snippet_width = (inner.width as usize).saturating_sub(content_path_width + 8);
 
     // 헤더 행
-    let header_line = Line::from(vec![
+    let header_line = if state.content {
+        Line::from(vec![
+            Span::styled(
+                format!("  {:3} ", "#"),
+                Style::default().fg(theme.search_result.column_header_dim),
+            ),
+            Span::styled(
+                format!("{:<width$} ", "Path:Line", width = content_path_width),
+                Style::default().fg(theme.search_result.column_header).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "Match",
+                Style::default().fg(theme.search_result.column_header).add_modifier(Modifier::BOLD),
+            ),
+        ])
+    } else { Line::from(vec![
         Span::styled(
             format!("  {:3} ", "#"),
             Style::default().fg(theme.search_result.column_header_dim),
@@ -233,10 +587,10 @@ pub fn draw(
             Style::default().fg(theme.search_result.column_header).add_modifier(Modifier::BOLD),
         ),
         Span::styled(
-            format!("{:16}", "Modified"),
+            format!("{:width$}", "Modified", width = date_width),
             Style::default().fg(theme.search_result.column_header).add_modifier(Modifier::BOLD),
         ),
-    ]);
+    ]) };
 
     let header_area = Rect::new(inner.x, inner.y, inner.width, 1);
     frame.render_widget(Paragraph::new(header_line), header_area);
@@ -269,6 +623,19 @@ pub fn draw(
         // 인덱스 번호
         let index_str = format!("{:3} ", actual_index + 1);
 
+        if let Some(ref line_match) = item.line_match {
+            lines.push(content_line(
+                item,
+                line_match,
+                index_str,
+                is_selected,
+                content_path_width,
+                snippet_width,
+                theme,
+            ));
+            continue;
+        }
+
         // 경로 (디렉토리면 / 추가)
         let path_display = if item.is_directory {
             format!("{}/", item.relative_path)
@@ -293,7 +660,7 @@ pub fn draw(
         };
 
         // 수정일
-        let date_str = format!("{}", item.modified.format("%Y-%m-%d %H:%M"));
+        let date_str = crate::utils::format::format_datetime(&item.modified, false);
 
         // 스타일 결정
         let (index_style, path_style, size_style, date_style) = if is_selected {
@@ -349,15 +716,96 @@ pub fn draw(
         Span::styled(kb.search_result_first_key(SearchResultAction::MoveUp).to_string(), theme.header_style()),
         Span::styled(":navigate ", theme.dim_style()),
         Span::styled(kb.search_result_first_key(SearchResultAction::Open).to_string(), theme.header_style()),
-        Span::styled(":go to path ", theme.dim_style()),
+        Span::styled(if state.content { ":view at line " } else { ":go to path " }, theme.dim_style()),
         Span::styled(kb.search_result_first_key(SearchResultAction::Close).to_string(), theme.header_style()),
-        Span::styled(":close", theme.dim_style()),
+        Span::styled(if state.searching { ":stop" } else { ":close" }, theme.dim_style()),
     ]);
 
     let help_area = Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1);
     frame.render_widget(Paragraph::new(help_line), help_area);
 }
 
+/// 스피너 프레임 (현재 시각 기준)
+fn spinner_frame() -> char {
+    const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    let frame_idx = (std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() / 100) as usize % SPINNER_FRAMES.len();
+    SPINNER_FRAMES[frame_idx]
+}
+
+/// 내용 검색 결과 한 줄: 번호, 경로:줄, 매치를 강조한 줄 발췌
+fn content_line<'a>(
+    item: &SearchResultItem,
+    line_match: &LineMatch,
+    index_str: String,
+    is_selected: bool,
+    path_width: usize,
+    snippet_width: usize,
+    theme: &Theme,
+) -> Line<'a> {
+    let location = format!("{}:{}", item.relative_path, line_match.line);
+    let location = if location.width() > path_width {
+        let suffix = crate::utils::format::display_width_suffix(&location, path_width.saturating_sub(3));
+        crate::utils::format::pad_to_display_width(&format!("...{}", suffix), path_width)
+    } else {
+        crate::utils::format::pad_to_display_width(&location, path_width)
+    };
+
+    let (index_style, path_style, text_style) = if is_selected {
+        let sel_style = theme.selected_style();
+        (sel_style, sel_style, sel_style)
+    } else {
+        (
+            Style::default().fg(theme.search_result.path_text),
+            Style::default().fg(theme.search_result.file_text),
+            Style::default().fg(theme.search_result.path_text),
+        )
+    };
+    let match_style = if is_selected {
+        text_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+    } else {
+        Style::default().fg(theme.search_result.match_highlight).add_modifier(Modifier::BOLD)
+    };
+
+    let mut spans = vec![
+        Span::styled(if is_selected { "> " } else { "  " }, path_style),
+        Span::styled(index_str, index_style),
+        Span::styled(format!("{} ", location), path_style),
+    ];
+
+    // 발췌를 매치/비매치 구간으로 나눠 표시 너비 안에서 그리기
+    let chars: Vec<char> = line_match.text.chars().collect();
+    let mut pos = 0;
+    let mut used = 0;
+    let mut push = |spans: &mut Vec<Span<'a>>, from: usize, to: usize, style: Style| {
+        let mut text = String::new();
+        for &c in &chars[from..to] {
+            let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+            if used + w > snippet_width {
+                break;
+            }
+            used += w;
+            text.push(c);
+        }
+        if !text.is_empty() {
+            spans.push(Span::styled(text, style));
+        }
+    };
+    for &(start, end) in &line_match.ranges {
+        let (start, end) = (start.min(chars.len()), end.min(chars.len()));
+        if start > pos {
+            push(&mut spans, pos, start, text_style);
+        }
+        push(&mut spans, start.max(pos), end, match_style);
+        pos = pos.max(end);
+    }
+    push(&mut spans, pos, chars.len(), text_style);
+
+    Line::from(spans)
+}
+
 /// 입력 처리 - true 반환 시 화면 닫기
 pub fn handle_input(state: &mut SearchResultState, code: KeyCode, modifiers: KeyModifiers, kb: &crate::keybindings::Keybindings) -> Option<crate::keybindings::SearchResultAction> {
     use crate::keybindings::SearchResultAction;
@@ -365,6 +813,11 @@ pub fn handle_input(state: &mut SearchResultState, code: KeyCode, modifiers: Key
     if let Some(action) = kb.search_result_action(code, modifiers) {
         match action {
             SearchResultAction::Close => {
+                // 검색 중이면 검색만 멈추고 받은 결과는 유지 (한 번 더 누르면 닫기)
+                if state.searching {
+                    state.cancel();
+                    return None;
+                }
                 state.active = false;
                 return Some(SearchResultAction::Close);
             }
@@ -381,3 +834,225 @@ pub fn handle_input(state: &mut SearchResultState, code: KeyCode, modifiers: Key
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_content_search(base: &Path, re: &Regex) -> Vec<SearchResultItem> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        content_search(base, re, 100, false, &AtomicBool::new(false), &tx);
+        drop(tx);
+        rx.iter()
+            .filter_map(|msg| match msg {
+                SearchMessage::Match(item) => Some(*item),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn item(name: &str, is_directory: bool) -> SearchResultItem {
+        SearchResultItem {
+            full_path: PathBuf::from(name),
+            relative_path: name.to_string(),
+            name: name.to_string(),
+            is_directory,
+            size: 0,
+            modified: Local::now(),
+            line_match: None,
+        }
+    }
+
+    #[test]
+    fn test_name_search_streams_matches() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("docs/Report")).unwrap();
+        fs::write(dir.path().join("docs/report.txt"), "").unwrap();
+        fs::write(dir.path().join("other.txt"), "").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.path().join("docs"), dir.path().join("docs-link")).unwrap();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        name_search(dir.path(), "REPORT", 100, false, &AtomicBool::new(false), &tx);
+        drop(tx);
+        let mut scanned = 0;
+        let mut found = Vec::new();
+        for msg in rx {
+            match msg {
+                SearchMessage::Match(item) => found.push(item.relative_path),
+                SearchMessage::Scanned(count) => scanned = count,
+                SearchMessage::Done => {}
+            }
+        }
+        // Symlinked directories are not followed, so each match appears once
+        assert_eq!(found, vec!["docs/Report".to_string(), "docs/report.txt".to_string()]);
+        assert!(scanned >= 4);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        name_search(dir.path(), "report", 100, false, &AtomicBool::new(true), &tx);
+        drop(tx);
+        assert_eq!(rx.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_criteria_search_from_smart_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("logs/old.log")).unwrap();
+        fs::write(root.join("logs/big.log"), vec![b'x'; 4096]).unwrap();
+        fs::write(root.join("logs/small.log"), "x").unwrap();
+        fs::write(root.join("big.txt"), vec![b'x'; 4096]).unwrap();
+
+        let criteria = SearchCriteria {
+            name: ".LOG".to_string(),
+            min_size: Some(1024),
+            max_size: None,
+            modified_after: chrono::NaiveDate::from_ymd_opt(2000, 1, 1),
+            modified_before: None,
+        };
+        // Saving and reopening keeps the criteria
+        let folder = criteria.to_smart_folder("Big logs", &root.display().to_string());
+        assert_eq!(folder.modified_after.as_deref(), Some("2000-01-01"));
+        let criteria = SearchCriteria::from_smart_folder(&folder);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        criteria_search(root, &criteria, 100, &AtomicBool::new(false), &tx);
+        drop(tx);
+        let found: Vec<String> = rx
+            .iter()
+            .filter_map(|msg| match msg {
+                SearchMessage::Match(item) => Some(item.relative_path),
+                _ => None,
+            })
+            .collect();
+        // Directories never match, even when their name does
+        assert_eq!(found, vec!["logs/big.log".to_string()]);
+    }
+
+    #[test]
+    fn test_search_respects_ignore_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join(".gitignore"), "node_modules/\n*.log\n").unwrap();
+        fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        fs::write(root.join("node_modules/pkg/index.js"), "needle").unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/index.js"), "needle").unwrap();
+        fs::write(root.join("src/.ignore"), "generated.js\n").unwrap();
+        fs::write(root.join("src/generated.js"), "needle").unwrap();
+        fs::write(root.join("index.log"), "needle").unwrap();
+
+        let names = |respect_ignore: bool| {
+            let (tx, rx) = std::sync::mpsc::channel();
+            name_search(root, "index", 100, respect_ignore, &AtomicBool::new(false), &tx);
+            drop(tx);
+            rx.iter()
+                .filter_map(|msg| match msg {
+                    SearchMessage::Match(item) => Some(item.relative_path),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(false).len(), 3);
+        assert_eq!(names(true), vec!["src/index.js".to_string()]);
+
+        let re = Regex::new("needle").unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        content_search(root, &re, 100, true, &AtomicBool::new(false), &tx);
+        drop(tx);
+        let files: Vec<String> = rx
+            .iter()
+            .filter_map(|msg| match msg {
+                SearchMessage::Match(item) => Some(item.relative_path),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(files, vec!["src/index.js".to_string()]);
+    }
+
+    #[test]
+    fn test_streamed_name_results_stay_sorted_and_keep_cursor() {
+        let mut state = SearchResultState::new();
+        state.insert_sorted(item("beta.txt", false));
+        state.insert_sorted(item("alpha.txt", false));
+        state.selected_index = 1; // beta.txt
+        state.insert_sorted(item("zeta", true));
+        state.insert_sorted(item("Gamma.txt", false));
+
+        let names: Vec<&str> = state.results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["zeta", "alpha.txt", "beta.txt", "Gamma.txt"]);
+        assert_eq!(state.current_item().unwrap().name, "beta.txt");
+    }
+
+    #[test]
+    fn test_name_search_runs_in_background_and_close_stops_it_first() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("needle.txt"), "").unwrap();
+
+        let mut state = SearchResultState::new();
+        state.start_name_search(dir.path().to_path_buf(), "needle".to_string(), 100, false);
+        assert!(state.active && state.searching && !state.content);
+        let started = std::time::Instant::now();
+        while state.searching && started.elapsed() < std::time::Duration::from_secs(5) {
+            state.poll();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(state.results.len(), 1);
+
+        let kb = crate::keybindings::Keybindings::from_config(&Default::default());
+        // Esc while searching stops the scan but keeps the screen and results
+        state.searching = true;
+        assert!(handle_input(&mut state, KeyCode::Esc, KeyModifiers::NONE, &kb).is_none());
+        assert!(!state.searching && state.active);
+        assert_eq!(state.results.len(), 1);
+        // Esc again closes
+        assert!(handle_input(&mut state, KeyCode::Esc, KeyModifiers::NONE, &kb).is_some());
+        assert!(!state.active);
+    }
+
+    #[test]
+    fn test_content_search_finds_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "fn main() {\n    let todo = 1; // TODO\n}\n").unwrap();
+        fs::write(dir.path().join("notes.txt"), "nothing here\ntodo list\n").unwrap();
+        fs::write(dir.path().join("blob.bin"), b"todo\0\x01\x02").unwrap();
+
+        let re = build_content_regex("todo", SearchOptions { content: true, ..Default::default() }).unwrap();
+        let results = run_content_search(dir.path(), &re);
+        let found: Vec<(String, usize)> = results
+            .iter()
+            .map(|r| (r.relative_path.clone(), r.line_match.as_ref().unwrap().line))
+            .collect();
+        // Binary files are skipped; files before subdirectories, both by name
+        assert_eq!(found, vec![("notes.txt".to_string(), 2), ("src/main.rs".to_string(), 2)]);
+
+        let line = results[1].line_match.as_ref().unwrap();
+        assert_eq!(line.text, "let todo = 1; // TODO");
+        assert_eq!(line.ranges, vec![(4, 8), (17, 21)]);
+    }
+
+    #[test]
+    fn test_content_regex_options() {
+        let plain = build_content_regex("a.c", SearchOptions::default()).unwrap();
+        assert!(plain.is_match("A.C"));
+        assert!(!plain.is_match("abc"));
+
+        let options = SearchOptions { content: true, regex: true, case_sensitive: true };
+        let re = build_content_regex("a.c", options).unwrap();
+        assert!(re.is_match("abc"));
+        assert!(!re.is_match("ABC"));
+        assert!(build_content_regex("(", options).is_err());
+    }
+
+    #[test]
+    fn test_snippet_keeps_context_around_late_match() {
+        let re = Regex::new("needle").unwrap();
+        let line = format!("{}needle tail", "x".repeat(100));
+        let (text, ranges) = snippet(&line, &re).unwrap();
+        assert!(text.starts_with('…'));
+        assert_eq!(ranges, vec![(1 + SNIPPET_CONTEXT, 1 + SNIPPET_CONTEXT + 6)]);
+        assert_eq!(text.chars().skip(ranges[0].0).take(6).collect::<String>(), "needle");
+        assert!(snippet("no match", &re).is_none());
+    }
+}
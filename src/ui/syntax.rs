@@ -83,6 +83,52 @@ impl Language {
         }
     }
 
+    /// 언어 이름 또는 확장자로 언어 감지 (--lang 옵션용, 예: "rust", "rs", "c++")
+    pub fn from_name(name: &str) -> Option<Self> {
+        let lower = name.trim().trim_start_matches('.').to_lowercase();
+        let by_name = match lower.as_str() {
+            "rust" => Some(Language::Rust),
+            "python" => Some(Language::Python),
+            "javascript" => Some(Language::JavaScript),
+            "typescript" => Some(Language::TypeScript),
+            "c++" => Some(Language::Cpp),
+            "golang" => Some(Language::Go),
+            "markdown" => Some(Language::Markdown),
+            "shell" => Some(Language::Shell),
+            "ruby" => Some(Language::Ruby),
+            "kotlin" => Some(Language::Kotlin),
+            "plain" | "text" | "txt" => Some(Language::Plain),
+            _ => None,
+        };
+        by_name.or_else(|| {
+            match Language::from_extension(Path::new(&format!("x.{}", lower))) {
+                Language::Plain => None,
+                lang => Some(lang),
+            }
+        })
+    }
+
+    /// 첫 줄의 shebang으로 언어 추측 (확장자가 없는 입력용)
+    pub fn from_shebang(first_line: &str) -> Self {
+        if !first_line.starts_with("#!") {
+            return Language::Plain;
+        }
+        let interpreter = first_line.to_lowercase();
+        if interpreter.contains("python") {
+            Language::Python
+        } else if interpreter.contains("node") || interpreter.contains("deno") {
+            Language::JavaScript
+        } else if interpreter.contains("ruby") {
+            Language::Ruby
+        } else if interpreter.contains("php") {
+            Language::Php
+        } else if interpreter.contains("sh") {
+            Language::Shell
+        } else {
+            Language::Plain
+        }
+    }
+
     /// 언어 이름 반환
     pub fn name(&self) -> &'static str {
         match self {
@@ -110,6 +156,30 @@ impl Language {
             Language::Plain => "Plain",
         }
     }
+
+    /// 주석 문법 (하이라이터가 주석으로 인식하는 구분자와 동일)
+    pub fn comment_style(&self) -> Option<CommentStyle> {
+        match self {
+            Language::Rust | Language::C | Language::Cpp | Language::Java |
+            Language::JavaScript | Language::TypeScript | Language::Go |
+            Language::Swift | Language::Kotlin | Language::Php => Some(CommentStyle::Line("//")),
+            Language::Python | Language::Shell | Language::Ruby |
+            Language::Yaml | Language::Toml => Some(CommentStyle::Line("#")),
+            Language::Sql => Some(CommentStyle::Line("--")),
+            Language::Css => Some(CommentStyle::Block("/*", "*/")),
+            Language::Html | Language::Xml | Language::Markdown => Some(CommentStyle::Block("<!--", "-->")),
+            Language::Json | Language::Plain => None,
+        }
+    }
+}
+
+/// 언어별 주석 구분자
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentStyle {
+    /// 줄 주석 접두어 (예: `//`)
+    Line(&'static str),
+    /// 한 줄을 감싸는 블록 주석 (예: `<!--`, `-->`)
+    Block(&'static str, &'static str),
 }
 
 /// 토큰 타입에 따른 스타일 반환
@@ -147,6 +217,13 @@ pub struct Token {
     pub token_type: TokenType,
 }
 
+/// 줄 시작 시점의 여러 줄 토큰 상태 (다음 줄 토큰화에 필요한 유일한 상태)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LineState {
+    in_multiline_comment: bool,
+    in_multiline_string: bool,
+}
+
 /// 문법 강조기
 #[derive(Debug, Clone, Copy)]
 pub struct SyntaxHighlighter {
@@ -204,6 +281,20 @@ impl SyntaxHighlighter {
         self.in_multiline_string = false;
     }
 
+    /// 현재 여러 줄 상태
+    pub fn line_state(&self) -> LineState {
+        LineState {
+            in_multiline_comment: self.in_multiline_comment,
+            in_multiline_string: self.in_multiline_string,
+        }
+    }
+
+    /// 여러 줄 상태 복원 (해당 줄부터 토큰화 재개)
+    pub fn set_line_state(&mut self, state: LineState) {
+        self.in_multiline_comment = state.in_multiline_comment;
+        self.in_multiline_string = state.in_multiline_string;
+    }
+
     // Rust 토큰화
     fn tokenize_rust(&mut self, line: &str) -> Vec<Token> {
         let keywords = [
@@ -2204,6 +2295,18 @@ mod tests {
         assert_eq!(Language::from_extension(Path::new("test.unknown")), Language::Plain);
     }
 
+    #[test]
+    fn test_language_from_name_and_shebang() {
+        assert_eq!(Language::from_name("rust"), Some(Language::Rust));
+        assert_eq!(Language::from_name("RS"), Some(Language::Rust));
+        assert_eq!(Language::from_name(".yml"), Some(Language::Yaml));
+        assert_eq!(Language::from_name("c++"), Some(Language::Cpp));
+        assert_eq!(Language::from_name("cobol"), None);
+        assert_eq!(Language::from_shebang("#!/usr/bin/env python3"), Language::Python);
+        assert_eq!(Language::from_shebang("#!/bin/bash"), Language::Shell);
+        assert_eq!(Language::from_shebang("echo hi"), Language::Plain);
+    }
+
     #[test]
     fn test_rust_tokenization() {
         let colors = crate::ui::theme::Theme::default().syntax;
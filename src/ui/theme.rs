@@ -1,9 +1,27 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use ratatui::style::{Color, Modifier, Style};
 use supports_color::Stream;
 
 /// Default theme name used throughout the application
 pub const DEFAULT_THEME_NAME: &str = "dawn_of_coding";
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// 텍스트 마커 (색상만으로 구분하지 않기)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// settings.theme.text_markers: 커서 행(">"), 마크된 파일("*"), 활성 패널을 글자로도 표시
+static TEXT_MARKERS: AtomicBool = AtomicBool::new(false);
+
+/// Show the cursor, marks and the active panel with text markers, not color alone
+pub fn set_text_markers(enabled: bool) {
+    TEXT_MARKERS.store(enabled, Ordering::Relaxed);
+}
+
+pub fn text_markers() -> bool {
+    TEXT_MARKERS.load(Ordering::Relaxed)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // 아이콘 문자
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -85,6 +103,11 @@ pub struct PanelColors {
     pub size_text: Color,
     pub date_text: Color,
     pub remote_indicator: Color,    // [SSH] 인디케이터 색상
+    pub git_badge_modified: Color,
+    pub git_badge_staged: Color,
+    pub git_badge_deleted: Color,
+    pub git_badge_untracked: Color,
+    pub git_badge_ignored: Color,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -208,11 +231,91 @@ pub struct DialogColors {
     pub git_log_diff_button_disabled_text: Color,  // 비활성 버튼 텍스트
     pub git_log_diff_scroll_info: Color,         // 스크롤 정보
 
+    // === Open With 다이얼로그 ===
+    pub open_with_title: Color,                  // 제목
+    pub open_with_border: Color,                 // 테두리
+    pub open_with_bg: Color,                     // 배경
+    pub open_with_input_text: Color,             // 필터 입력 텍스트
+    pub open_with_entry_text: Color,             // 애플리케이션 항목 텍스트
+    pub open_with_recent_text: Color,            // 최근 사용 명령 텍스트
+    pub open_with_preferred_text: Color,         // 파일 형식 지원 앱 텍스트
+    pub open_with_command_text: Color,           // 명령어 미리보기
+    pub open_with_cursor_text: Color,            // 커서 위치 텍스트
+    pub open_with_cursor_bg: Color,              // 커서 위치 배경
+    pub open_with_scroll_info: Color,            // 스크롤 정보
+
     // === 원격 연결 다이얼로그 ===
     pub remote_bookmark_text: Color,             // 북마크 목록 내 원격 항목 텍스트
     pub remote_connect_field_label: Color,       // 원격 연결 다이얼로그 필드 레이블
     pub remote_connect_field_value: Color,       // 원격 연결 다이얼로그 필드 값
     pub remote_connect_field_selected_bg: Color, // 선택된 필드 배경
+
+    // === Jump 다이얼로그 ===
+    pub jump_title: Color,                       // 제목
+    pub jump_border: Color,                      // 테두리
+    pub jump_bg: Color,                          // 배경
+    pub jump_input_text: Color,                  // 필터 입력 텍스트
+    pub jump_entry_text: Color,                  // 폴더 항목 텍스트
+    pub jump_cursor_text: Color,                 // 커서 위치 텍스트
+    pub jump_cursor_bg: Color,                   // 커서 위치 배경
+    pub jump_scroll_info: Color,                 // 스크롤 정보
+    pub jump_hint_text: Color,                   // 단축키 안내
+
+    // === 최근 파일 다이얼로그 ===
+    pub recent_files_title: Color,               // 제목
+    pub recent_files_border: Color,              // 테두리
+    pub recent_files_bg: Color,                  // 배경
+    pub recent_files_input_text: Color,          // 필터 입력 텍스트
+    pub recent_files_entry_text: Color,          // 파일 항목 텍스트
+    pub recent_files_edited_text: Color,         // 편집한 파일 텍스트
+    pub recent_files_cursor_text: Color,         // 커서 위치 텍스트
+    pub recent_files_cursor_bg: Color,           // 커서 위치 배경
+    pub recent_files_scroll_info: Color,         // 스크롤 정보
+    pub recent_files_hint_text: Color,           // 단축키 안내
+
+    // === 경로 메뉴 다이얼로그 ===
+    pub breadcrumb_title: Color,                 // 제목
+    pub breadcrumb_border: Color,                // 테두리
+    pub breadcrumb_bg: Color,                    // 배경
+    pub breadcrumb_path_text: Color,             // 상단 경로 구성 요소
+    pub breadcrumb_separator: Color,             // 경로 구분자
+    pub breadcrumb_current_text: Color,          // 현재 경로의 폴더
+    pub breadcrumb_entry_text: Color,            // 폴더 항목 텍스트
+    pub breadcrumb_cursor_text: Color,           // 커서 위치 텍스트
+    pub breadcrumb_cursor_bg: Color,             // 커서 위치 배경
+    pub breadcrumb_scroll_info: Color,           // 스크롤 정보
+    pub breadcrumb_hint_text: Color,             // 단축키 안내
+
+    // === 즐겨찾기 다이얼로그 ===
+    pub favorites_title: Color,                  // 제목
+    pub favorites_border: Color,                 // 테두리
+    pub favorites_bg: Color,                     // 배경
+    pub favorites_entry_text: Color,             // 즐겨찾기 항목 텍스트
+    pub favorites_empty_slot_text: Color,        // 빈 슬롯
+    pub favorites_cursor_text: Color,            // 커서 위치 텍스트
+    pub favorites_cursor_bg: Color,              // 커서 위치 배경
+    pub favorites_hint_text: Color,              // 단축키 안내
+
+    // === 바구니 다이얼로그 ===
+    pub basket_title: Color,                     // 제목
+    pub basket_border: Color,                    // 테두리
+    pub basket_bg: Color,                        // 배경
+    pub basket_entry_text: Color,                // 바구니 항목 텍스트
+    pub basket_cursor_text: Color,               // 커서 위치 텍스트
+    pub basket_cursor_bg: Color,                 // 커서 위치 배경
+    pub basket_hint_text: Color,                 // 단축키 안내
+
+    // === 이미지 저장 다이얼로그 ===
+    pub image_export_title: Color,               // 제목
+    pub image_export_border: Color,              // 테두리
+    pub image_export_bg: Color,                  // 배경
+    pub image_export_label_text: Color,          // 항목 이름
+    pub image_export_value_text: Color,          // 항목 값
+    pub image_export_disabled_text: Color,       // 사용할 수 없는 항목 값
+    pub image_export_cursor_text: Color,         // 커서 위치 텍스트
+    pub image_export_cursor_bg: Color,           // 커서 위치 배경
+    pub image_export_output_text: Color,         // 저장될 파일 이름
+    pub image_export_hint_text: Color,           // 단축키 안내
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -267,6 +370,10 @@ pub struct EditorColors {
     pub match_current_bg: Color,
     pub bracket_match: Color,
     pub modified_mark: Color,
+    pub read_only_mark: Color,
+    pub sudo_mark: Color,
+    pub read_only_banner_text: Color,
+    pub read_only_banner_bg: Color,
     pub footer_bg: Color,
     pub footer_key: Color,
     pub footer_text: Color,
@@ -310,6 +417,8 @@ pub struct ViewerColors {
     pub line_number: Color,
     pub text: Color,
     pub bookmark_indicator: Color,  // 북마크 줄 표시 색상
+    pub selection_fg: Color,        // 줄 선택 영역 줄 번호 전경색
+    pub selection_bg: Color,        // 줄 선택 영역 줄 번호 배경색
     pub search_input_text: Color,
     pub search_cursor_fg: Color,
     pub search_cursor_bg: Color,
@@ -661,6 +770,284 @@ pub struct DedupScreenColors {
     pub footer_text: Color,
 }
 
+#[derive(Clone, Copy)]
+pub struct ChecksumScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub label_text: Color,
+    pub target_text: Color,
+    pub phase_text: Color,
+    pub progress_text: Color,
+    pub name_text: Color,
+    pub hash_text: Color,
+    pub ok_text: Color,
+    pub mismatch_text: Color,
+    pub missing_text: Color,
+    pub error_text: Color,
+    pub message_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct AuditScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub label_text: Color,
+    pub path_text: Color,
+    pub count_text: Color,
+    pub empty_text: Color,
+    pub time_text: Color,
+    pub user_text: Color,
+    pub summary_text: Color,
+    pub kind_rejected: Color,
+    pub kind_shell: Color,
+    pub kind_transfer: Color,
+    pub kind_other: Color,
+    pub value_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct ArchiveViewerColors {
+    pub bg: Color,
+    pub info_border: Color,
+    pub list_border: Color,
+    pub title: Color,
+    pub path_text: Color,
+    pub count_text: Color,
+    pub arrow_text: Color,
+    pub dest_text: Color,
+    pub empty_text: Color,
+    pub directory_text: Color,
+    pub file_text: Color,
+    pub marked_text: Color,
+    pub size_text: Color,
+    pub date_text: Color,
+    pub selected_bg: Color,
+    pub selected_text: Color,
+    pub message_text: Color,
+    pub footer_bg: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct TrashScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub label_text: Color,
+    pub path_text: Color,
+    pub count_text: Color,
+    pub empty_text: Color,
+    pub date_text: Color,
+    pub size_text: Color,
+    pub directory_text: Color,
+    pub file_text: Color,
+    pub origin_text: Color,
+    pub confirm_text: Color,
+    pub message_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct JobsScreenColors {
+    pub summary_text: Color,
+    pub summary_bg: Color,
+    pub bg: Color,
+    pub border: Color,
+    pub border_active: Color,
+    pub title: Color,
+    pub id_text: Color,
+    pub running_text: Color,
+    pub done_text: Color,
+    pub failed_text: Color,
+    pub selected_bg: Color,
+    pub selected_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct BulkRenameColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub label_text: Color,
+    pub label_active: Color,
+    pub input_text: Color,
+    pub input_cursor: Color,
+    pub hint_text: Color,
+    pub list_border_active: Color,
+    pub old_name: Color,
+    pub arrow_text: Color,
+    pub new_name: Color,
+    pub unchanged_name: Color,
+    pub invalid_name: Color,
+    pub edit_text: Color,
+    pub status_text: Color,
+    pub error_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct PreviewPaneColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub text: Color,
+    pub dim_text: Color,
+    pub directory_text: Color,
+    pub size_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct DrivesScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub empty_text: Color,
+    pub mount_text: Color,
+    pub bar_fill: Color,
+    pub bar_full: Color,
+    pub bar_empty: Color,
+    pub percent_text: Color,
+    pub space_text: Color,
+    pub unavailable_text: Color,
+    pub label_text: Color,
+    pub value_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct RecoveryScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub intro_text: Color,
+    pub empty_text: Color,
+    pub tag_text: Color,
+    pub item_text: Color,
+    pub label_text: Color,
+    pub partial_path: Color,
+    pub hint_text: Color,
+    pub message_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct TreeSidebarColors {
+    pub bg: Color,
+    pub border: Color,
+    pub border_active: Color,
+    pub title: Color,
+    pub title_active: Color,
+    pub message_text: Color,
+    pub directory_text: Color,
+    pub cursor_text: Color,
+    pub selected_bg: Color,
+    pub selected_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct CleanupScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub label_text: Color,
+    pub target_text: Color,
+    pub phase_text: Color,
+    pub found_size: Color,
+    pub marked_size: Color,
+    pub freed_size: Color,
+    pub empty_text: Color,
+    pub arrow_text: Color,
+    pub check_mark: Color,
+    pub category_size: Color,
+    pub category_name: Color,
+    pub category_count: Color,
+    pub item_size: Color,
+    pub item_path: Color,
+    pub confirm_text: Color,
+    pub progress_text: Color,
+    pub message_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct BackupScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub message_text: Color,
+    pub label_text: Color,
+    pub value_text: Color,
+    pub dim_text: Color,
+    pub ok_text: Color,
+    pub error_text: Color,
+    pub form_selected: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct TimelineScreenColors {
+    pub bg: Color,
+    pub border: Color,
+    pub title: Color,
+    pub root_text: Color,
+    pub window_text: Color,
+    pub info_text: Color,
+    pub empty_text: Color,
+    pub age_text: Color,
+    pub time_text: Color,
+    pub size_text: Color,
+    pub path_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct GitLogScreenColors {
+    pub bg: Color,
+    pub header_path: Color,
+    pub header_scope: Color,
+    pub graph: Color,
+    pub marker: Color,
+    pub log_hash: Color,
+    pub refs: Color,
+    pub log_subject: Color,
+    pub log_author: Color,
+    pub log_date: Color,
+    pub selected_bg: Color,
+    pub selected_text: Color,
+    pub detail_subject: Color,
+    pub file_name: Color,
+    pub file_added: Color,
+    pub file_deleted: Color,
+    pub file_renamed: Color,
+    pub file_modified: Color,
+    pub empty_text: Color,
+    pub message_text: Color,
+    pub footer_key: Color,
+    pub footer_text: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct FavoritesBarColors {
+    pub key: Color,
+    pub label: Color,
+    pub current: Color,
+    pub empty_text: Color,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // 메인 Theme 구조체
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -698,6 +1085,21 @@ pub struct Theme {
     pub diff_file_view: DiffFileViewColors,
     pub git_screen: GitScreenColors,
     pub dedup_screen: DedupScreenColors,
+    pub checksum_screen: ChecksumScreenColors,
+    pub audit_screen: AuditScreenColors,
+    pub archive_viewer: ArchiveViewerColors,
+    pub trash_screen: TrashScreenColors,
+    pub jobs_screen: JobsScreenColors,
+    pub bulk_rename: BulkRenameColors,
+    pub preview_pane: PreviewPaneColors,
+    pub drives_screen: DrivesScreenColors,
+    pub recovery_screen: RecoveryScreenColors,
+    pub tree_sidebar: TreeSidebarColors,
+    pub cleanup_screen: CleanupScreenColors,
+    pub backup_screen: BackupScreenColors,
+    pub timeline_screen: TimelineScreenColors,
+    pub git_log_screen: GitLogScreenColors,
+    pub favorites_bar: FavoritesBarColors,
 
     // 아이콘 문자
     pub chars: ThemeChars,
@@ -722,6 +1124,8 @@ impl Theme {
             "light" => Self::light(),
             "dark" => Self::dark(),
             "dawn_of_coding" => Self::dawn_of_coding(),
+            "high_contrast" => Self::high_contrast(),
+            "monochrome" => Self::monochrome(),
             _ => Self::dawn_of_coding(),
         }
     }
@@ -785,6 +1189,11 @@ impl Theme {
             size_text: Color::Indexed(251),
             date_text: Color::Indexed(251),
             remote_indicator: Color::Indexed(67),
+            git_badge_modified: Color::Indexed(136),
+            git_badge_staged: Color::Indexed(34),
+            git_badge_deleted: Color::Indexed(124),
+            git_badge_untracked: Color::Indexed(198),
+            git_badge_ignored: Color::Indexed(251),
         };
 
         // 앱 헤더
@@ -887,10 +1296,78 @@ impl Theme {
             git_log_diff_button_selected_bg: Color::Indexed(67),
             git_log_diff_button_disabled_text: Color::Indexed(251),
             git_log_diff_scroll_info: Color::Indexed(251),
+
+            // === Open With ===
+            open_with_title: Color::Indexed(238),
+            open_with_border: Color::Indexed(238),
+            open_with_bg: Color::Indexed(255),
+            open_with_input_text: Color::Indexed(238),
+            open_with_entry_text: Color::Indexed(243),
+            open_with_recent_text: Color::Indexed(34),
+            open_with_preferred_text: Color::Indexed(238),
+            open_with_command_text: Color::Indexed(251),
+            open_with_cursor_text: Color::Indexed(231),
+            open_with_cursor_bg: Color::Indexed(67),
+            open_with_scroll_info: Color::Indexed(251),
             remote_bookmark_text: Color::Indexed(67),
             remote_connect_field_label: Color::Indexed(243),
             remote_connect_field_value: Color::Indexed(238),
             remote_connect_field_selected_bg: Color::Indexed(67),
+            jump_title: Color::Indexed(238),
+            jump_border: Color::Indexed(238),
+            jump_bg: Color::Indexed(255),
+            jump_input_text: Color::Indexed(238),
+            jump_entry_text: Color::Indexed(243),
+            jump_cursor_text: Color::Indexed(231),
+            jump_cursor_bg: Color::Indexed(67),
+            jump_scroll_info: Color::Indexed(251),
+            jump_hint_text: Color::Indexed(251),
+            recent_files_title: Color::Indexed(238),
+            recent_files_border: Color::Indexed(238),
+            recent_files_bg: Color::Indexed(255),
+            recent_files_input_text: Color::Indexed(238),
+            recent_files_entry_text: Color::Indexed(243),
+            recent_files_edited_text: Color::Indexed(34),
+            recent_files_cursor_text: Color::Indexed(231),
+            recent_files_cursor_bg: Color::Indexed(67),
+            recent_files_scroll_info: Color::Indexed(251),
+            recent_files_hint_text: Color::Indexed(251),
+            breadcrumb_title: Color::Indexed(238),
+            breadcrumb_border: Color::Indexed(238),
+            breadcrumb_bg: Color::Indexed(255),
+            breadcrumb_path_text: Color::Indexed(238),
+            breadcrumb_separator: Color::Indexed(251),
+            breadcrumb_current_text: Color::Indexed(34),
+            breadcrumb_entry_text: Color::Indexed(243),
+            breadcrumb_cursor_text: Color::Indexed(231),
+            breadcrumb_cursor_bg: Color::Indexed(67),
+            breadcrumb_scroll_info: Color::Indexed(251),
+            breadcrumb_hint_text: Color::Indexed(251),
+            favorites_title: Color::Indexed(238),
+            favorites_border: Color::Indexed(238),
+            favorites_bg: Color::Indexed(255),
+            favorites_entry_text: Color::Indexed(243),
+            favorites_empty_slot_text: Color::Indexed(251),
+            favorites_cursor_text: Color::Indexed(231),
+            favorites_cursor_bg: Color::Indexed(67),
+            favorites_hint_text: Color::Indexed(251),
+            basket_title: Color::Indexed(238),
+            basket_border: Color::Indexed(238),
+            basket_bg: Color::Indexed(255),
+            basket_entry_text: Color::Indexed(243),
+            basket_cursor_text: Color::Indexed(231),
+            basket_cursor_bg: Color::Indexed(67),
+            basket_hint_text: Color::Indexed(251),
+            image_export_title: Color::Indexed(238),
+            image_export_border: Color::Indexed(238),
+            image_export_bg: Color::Indexed(255),
+            image_export_label_text: Color::Indexed(34),
+            image_export_value_text: Color::Indexed(243),
+            image_export_disabled_text: Color::Indexed(251),
+            image_export_cursor_text: Color::Indexed(231),
+            image_export_cursor_bg: Color::Indexed(67),
+            image_export_output_text: Color::Indexed(238),
+            image_export_hint_text: Color::Indexed(251),
         };
 
         // 확인 다이얼로그 (Large File/Image Confirm)
@@ -933,6 +1410,10 @@ impl Theme {
             match_current_bg: Color::Indexed(208),
             bracket_match: Color::Indexed(74),
             modified_mark: Color::Indexed(198),
+            read_only_mark: Color::Indexed(198),
+            sudo_mark: Color::Indexed(198),
+            read_only_banner_text: Color::Indexed(253),
+            read_only_banner_bg: Color::Indexed(198),
             footer_bg: Color::Indexed(253),
             footer_key: Color::Indexed(74),
             footer_text: Color::Indexed(251),
@@ -968,6 +1449,8 @@ impl Theme {
             line_number: Color::Indexed(251),
             text: Color::Indexed(243),
             bookmark_indicator: Color::Indexed(21),   // 북마크 표시 색상
+            selection_fg: Color::Indexed(255),
+            selection_bg: Color::Indexed(67),
             search_input_text: Color::Indexed(67),
             search_cursor_fg: Color::Indexed(255),
             search_cursor_bg: Color::Indexed(67),
@@ -1275,68 +1758,346 @@ impl Theme {
             footer_text: Color::Indexed(251),
         };
 
-        Self {
-            palette,
-            state,
-            panel,
-            header,
-            status_bar,
-            function_bar,
-            message,
-            dialog,
-            confirm_dialog,
-            settings,
-            editor,
-            syntax,
-            viewer,
-            process_manager,
-            ai_screen,
-            system_info,
-            search_result,
-            image_viewer,
-            file_info,
-            help,
-            advanced_search,
-            diff,
-            diff_file_view,
-            git_screen,
-            dedup_screen,
-            chars: ThemeChars::default(),
-        }
-    }
+        let checksum_screen = ChecksumScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            label_text: Color::Indexed(243),
+            target_text: Color::Indexed(34),
+            phase_text: Color::Indexed(34),
+            progress_text: Color::Indexed(243),
+            name_text: Color::Indexed(249),
+            hash_text: Color::Indexed(243),
+            ok_text: Color::Indexed(34),
+            mismatch_text: Color::Indexed(124),
+            missing_text: Color::Indexed(198),
+            error_text: Color::Indexed(124),
+            message_text: Color::Indexed(34),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
 
-    /// Dark theme
-    pub fn dark() -> Self {
-        Self::dark_256()
-    }
+        let audit_screen = AuditScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            label_text: Color::Indexed(243),
+            path_text: Color::Indexed(34),
+            count_text: Color::Indexed(34),
+            empty_text: Color::Indexed(243),
+            time_text: Color::Indexed(243),
+            user_text: Color::Indexed(249),
+            summary_text: Color::Indexed(243),
+            kind_rejected: Color::Indexed(124),
+            kind_shell: Color::Indexed(198),
+            kind_transfer: Color::Indexed(34),
+            kind_other: Color::Indexed(243),
+            value_text: Color::Indexed(249),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
 
-    fn dark_256() -> Self {
-        // 기본 팔레트 정의 (어두운 배경)
-        let palette = Palette {
-            bg: Color::Indexed(235),             // 어두운 배경
-            bg_alt: Color::Indexed(236),         // 대체 배경
-            fg: Color::Indexed(252),             // 밝은 텍스트
-            fg_dim: Color::Indexed(245),         // 흐린 텍스트
-            fg_strong: Color::Indexed(255),      // 강조 텍스트
-            fg_inverse: Color::Indexed(235),     // 반전 텍스트
-            accent: Color::Indexed(81),          // 정보성 강조
-            shortcut: Color::Indexed(117),       // 단축키
-            positive: Color::Indexed(114),       // 긍정/성공
-            highlight: Color::Indexed(204),      // 강조/경고/에러
+        let archive_viewer = ArchiveViewerColors {
+            bg: Color::Indexed(255),
+            info_border: Color::Indexed(238),
+            list_border: Color::Indexed(251),
+            title: Color::Indexed(242),
+            path_text: Color::Indexed(67),
+            count_text: Color::Indexed(251),
+            arrow_text: Color::Indexed(251),
+            dest_text: Color::Indexed(243),
+            empty_text: Color::Indexed(251),
+            directory_text: Color::Indexed(67),
+            file_text: Color::Indexed(243),
+            marked_text: Color::Indexed(198),
+            size_text: Color::Indexed(251),
+            date_text: Color::Indexed(251),
+            selected_bg: Color::Indexed(67),
+            selected_text: Color::Indexed(231),
+            message_text: Color::Indexed(251),
+            footer_bg: Color::Indexed(255),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
         };
 
-        let state = StateColors {
-            success: Color::Indexed(114),
-            warning: Color::Indexed(204),
-            error: Color::Indexed(204),
-            info: Color::Indexed(81),
+        let trash_screen = TrashScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            label_text: Color::Indexed(243),
+            path_text: Color::Indexed(34),
+            count_text: Color::Indexed(34),
+            empty_text: Color::Indexed(243),
+            date_text: Color::Indexed(243),
+            size_text: Color::Indexed(249),
+            directory_text: Color::Indexed(34),
+            file_text: Color::Indexed(243),
+            origin_text: Color::Indexed(249),
+            confirm_text: Color::Indexed(124),
+            message_text: Color::Indexed(34),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
         };
 
-        let panel = PanelColors {
-            bg: Color::Indexed(235),
-            border: Color::Indexed(245),
-            border_active: Color::Indexed(252),
-            header_bg: Color::Indexed(236),
+        let jobs_screen = JobsScreenColors {
+            summary_text: Color::Indexed(242),
+            summary_bg: Color::Indexed(254),
+            bg: Color::Indexed(255),
+            border: Color::Indexed(251),
+            border_active: Color::Indexed(238),
+            title: Color::Indexed(242),
+            id_text: Color::Indexed(251),
+            running_text: Color::Indexed(243),
+            done_text: Color::Indexed(251),
+            failed_text: Color::Indexed(198),
+            selected_bg: Color::Indexed(67),
+            selected_text: Color::Indexed(231),
+        };
+
+        let bulk_rename = BulkRenameColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            label_text: Color::Indexed(243),
+            label_active: Color::Indexed(34),
+            input_text: Color::Indexed(243),
+            input_cursor: Color::Indexed(34),
+            hint_text: Color::Indexed(249),
+            list_border_active: Color::Indexed(21),
+            old_name: Color::Indexed(243),
+            arrow_text: Color::Indexed(243),
+            new_name: Color::Indexed(34),
+            unchanged_name: Color::Indexed(249),
+            invalid_name: Color::Indexed(124),
+            edit_text: Color::Indexed(34),
+            status_text: Color::Indexed(243),
+            error_text: Color::Indexed(124),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
+
+        let preview_pane = PreviewPaneColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(251),
+            title: Color::Indexed(249),
+            text: Color::Indexed(243),
+            dim_text: Color::Indexed(251),
+            directory_text: Color::Indexed(67),
+            size_text: Color::Indexed(251),
+        };
+
+        let drives_screen = DrivesScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            empty_text: Color::Indexed(243),
+            mount_text: Color::Indexed(34),
+            bar_fill: Color::Indexed(34),
+            bar_full: Color::Indexed(124),
+            bar_empty: Color::Indexed(254),
+            percent_text: Color::Indexed(243),
+            space_text: Color::Indexed(249),
+            unavailable_text: Color::Indexed(243),
+            label_text: Color::Indexed(243),
+            value_text: Color::Indexed(249),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
+
+        let recovery_screen = RecoveryScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            intro_text: Color::Indexed(34),
+            empty_text: Color::Indexed(243),
+            tag_text: Color::Indexed(243),
+            item_text: Color::Indexed(243),
+            label_text: Color::Indexed(243),
+            partial_path: Color::Indexed(124),
+            hint_text: Color::Indexed(249),
+            message_text: Color::Indexed(34),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
+
+        let tree_sidebar = TreeSidebarColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(251),
+            border_active: Color::Indexed(238),
+            title: Color::Indexed(249),
+            title_active: Color::Indexed(242),
+            message_text: Color::Indexed(251),
+            directory_text: Color::Indexed(67),
+            cursor_text: Color::Indexed(67),
+            selected_bg: Color::Indexed(67),
+            selected_text: Color::Indexed(231),
+        };
+
+        let cleanup_screen = CleanupScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            label_text: Color::Indexed(243),
+            target_text: Color::Indexed(34),
+            phase_text: Color::Indexed(34),
+            found_size: Color::Indexed(34),
+            marked_size: Color::Indexed(198),
+            freed_size: Color::Indexed(34),
+            empty_text: Color::Indexed(243),
+            arrow_text: Color::Indexed(243),
+            check_mark: Color::Indexed(198),
+            category_size: Color::Indexed(34),
+            category_name: Color::Indexed(243),
+            category_count: Color::Indexed(249),
+            item_size: Color::Indexed(249),
+            item_path: Color::Indexed(243),
+            confirm_text: Color::Indexed(124),
+            progress_text: Color::Indexed(249),
+            message_text: Color::Indexed(34),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
+
+        let backup_screen = BackupScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            message_text: Color::Indexed(34),
+            label_text: Color::Indexed(243),
+            value_text: Color::Indexed(243),
+            dim_text: Color::Indexed(249),
+            ok_text: Color::Indexed(34),
+            error_text: Color::Indexed(124),
+            form_selected: Color::Indexed(34),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
+
+        let timeline_screen = TimelineScreenColors {
+            bg: Color::Indexed(255),
+            border: Color::Indexed(238),
+            title: Color::Indexed(21),
+            root_text: Color::Indexed(34),
+            window_text: Color::Indexed(34),
+            info_text: Color::Indexed(243),
+            empty_text: Color::Indexed(243),
+            age_text: Color::Indexed(34),
+            time_text: Color::Indexed(249),
+            size_text: Color::Indexed(249),
+            path_text: Color::Indexed(243),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
+
+        let git_log_screen = GitLogScreenColors {
+            bg: Color::Indexed(255),
+            header_path: Color::Indexed(243),
+            header_scope: Color::Indexed(34),
+            graph: Color::Indexed(243),
+            marker: Color::Indexed(34),
+            log_hash: Color::Indexed(67),
+            refs: Color::Indexed(34),
+            log_subject: Color::Indexed(243),
+            log_author: Color::Indexed(21),
+            log_date: Color::Indexed(251),
+            selected_bg: Color::Indexed(67),
+            selected_text: Color::Indexed(231),
+            detail_subject: Color::Indexed(243),
+            file_name: Color::Indexed(243),
+            file_added: Color::Indexed(34),
+            file_deleted: Color::Indexed(124),
+            file_renamed: Color::Indexed(198),
+            file_modified: Color::Indexed(136),
+            empty_text: Color::Indexed(251),
+            message_text: Color::Indexed(251),
+            footer_key: Color::Indexed(74),
+            footer_text: Color::Indexed(251),
+        };
+
+        let favorites_bar = FavoritesBarColors {
+            key: Color::Indexed(243),
+            label: Color::Indexed(251),
+            current: Color::Indexed(251),
+            empty_text: Color::Indexed(251),
+        };
+
+        Self {
+            palette,
+            state,
+            panel,
+            header,
+            status_bar,
+            function_bar,
+            message,
+            dialog,
+            confirm_dialog,
+            settings,
+            editor,
+            syntax,
+            viewer,
+            process_manager,
+            ai_screen,
+            system_info,
+            search_result,
+            image_viewer,
+            file_info,
+            help,
+            advanced_search,
+            diff,
+            diff_file_view,
+            git_screen,
+            dedup_screen,
+            checksum_screen,
+            audit_screen,
+            archive_viewer,
+            trash_screen,
+            jobs_screen,
+            bulk_rename,
+            preview_pane,
+            drives_screen,
+            recovery_screen,
+            tree_sidebar,
+            cleanup_screen,
+            backup_screen,
+            timeline_screen,
+            git_log_screen,
+            favorites_bar,
+            chars: ThemeChars::default(),
+        }
+    }
+
+    /// Dark theme
+    pub fn dark() -> Self {
+        Self::dark_256()
+    }
+
+    fn dark_256() -> Self {
+        // 기본 팔레트 정의 (어두운 배경)
+        let palette = Palette {
+            bg: Color::Indexed(235),             // 어두운 배경
+            bg_alt: Color::Indexed(236),         // 대체 배경
+            fg: Color::Indexed(252),             // 밝은 텍스트
+            fg_dim: Color::Indexed(245),         // 흐린 텍스트
+            fg_strong: Color::Indexed(255),      // 강조 텍스트
+            fg_inverse: Color::Indexed(235),     // 반전 텍스트
+            accent: Color::Indexed(81),          // 정보성 강조
+            shortcut: Color::Indexed(117),       // 단축키
+            positive: Color::Indexed(114),       // 긍정/성공
+            highlight: Color::Indexed(204),      // 강조/경고/에러
+        };
+
+        let state = StateColors {
+            success: Color::Indexed(114),
+            warning: Color::Indexed(204),
+            error: Color::Indexed(204),
+            info: Color::Indexed(81),
+        };
+
+        let panel = PanelColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            border_active: Color::Indexed(252),
+            header_bg: Color::Indexed(236),
             header_bg_active: Color::Indexed(237),
             header_text: Color::Indexed(250),
             header_text_active: Color::Indexed(255),  // 활성 패널 헤더 텍스트
@@ -1349,6 +2110,11 @@ impl Theme {
             size_text: Color::Indexed(245),
             date_text: Color::Indexed(245),
             remote_indicator: Color::Indexed(117),
+            git_badge_modified: Color::Indexed(220),
+            git_badge_staged: Color::Indexed(114),
+            git_badge_deleted: Color::Indexed(209),
+            git_badge_untracked: Color::Indexed(204),
+            git_badge_ignored: Color::Indexed(245),
         };
 
         let header = HeaderColors {
@@ -1431,10 +2197,78 @@ impl Theme {
             git_log_diff_button_selected_bg: Color::Indexed(117),
             git_log_diff_button_disabled_text: Color::Indexed(242),
             git_log_diff_scroll_info: Color::Indexed(245),
+
+            // === Open With ===
+            open_with_title: Color::Indexed(255),
+            open_with_border: Color::Indexed(252),
+            open_with_bg: Color::Indexed(236),
+            open_with_input_text: Color::Indexed(255),
+            open_with_entry_text: Color::Indexed(252),
+            open_with_recent_text: Color::Indexed(84),
+            open_with_preferred_text: Color::Indexed(255),
+            open_with_command_text: Color::Indexed(245),
+            open_with_cursor_text: Color::Indexed(235),
+            open_with_cursor_bg: Color::Indexed(117),
+            open_with_scroll_info: Color::Indexed(245),
             remote_bookmark_text: Color::Indexed(117),
             remote_connect_field_label: Color::Indexed(252),
             remote_connect_field_value: Color::Indexed(255),
             remote_connect_field_selected_bg: Color::Indexed(117),
+            jump_title: Color::Indexed(255),
+            jump_border: Color::Indexed(252),
+            jump_bg: Color::Indexed(236),
+            jump_input_text: Color::Indexed(255),
+            jump_entry_text: Color::Indexed(252),
+            jump_cursor_text: Color::Indexed(235),
+            jump_cursor_bg: Color::Indexed(117),
+            jump_scroll_info: Color::Indexed(245),
+            jump_hint_text: Color::Indexed(245),
+            recent_files_title: Color::Indexed(255),
+            recent_files_border: Color::Indexed(252),
+            recent_files_bg: Color::Indexed(236),
+            recent_files_input_text: Color::Indexed(255),
+            recent_files_entry_text: Color::Indexed(252),
+            recent_files_edited_text: Color::Indexed(84),
+            recent_files_cursor_text: Color::Indexed(235),
+            recent_files_cursor_bg: Color::Indexed(117),
+            recent_files_scroll_info: Color::Indexed(245),
+            recent_files_hint_text: Color::Indexed(245),
+            breadcrumb_title: Color::Indexed(255),
+            breadcrumb_border: Color::Indexed(252),
+            breadcrumb_bg: Color::Indexed(236),
+            breadcrumb_path_text: Color::Indexed(255),
+            breadcrumb_separator: Color::Indexed(245),
+            breadcrumb_current_text: Color::Indexed(84),
+            breadcrumb_entry_text: Color::Indexed(252),
+            breadcrumb_cursor_text: Color::Indexed(235),
+            breadcrumb_cursor_bg: Color::Indexed(117),
+            breadcrumb_scroll_info: Color::Indexed(245),
+            breadcrumb_hint_text: Color::Indexed(245),
+            favorites_title: Color::Indexed(255),
+            favorites_border: Color::Indexed(252),
+            favorites_bg: Color::Indexed(236),
+            favorites_entry_text: Color::Indexed(252),
+            favorites_empty_slot_text: Color::Indexed(245),
+            favorites_cursor_text: Color::Indexed(235),
+            favorites_cursor_bg: Color::Indexed(117),
+            favorites_hint_text: Color::Indexed(245),
+            basket_title: Color::Indexed(255),
+            basket_border: Color::Indexed(252),
+            basket_bg: Color::Indexed(236),
+            basket_entry_text: Color::Indexed(252),
+            basket_cursor_text: Color::Indexed(235),
+            basket_cursor_bg: Color::Indexed(117),
+            basket_hint_text: Color::Indexed(245),
+            image_export_title: Color::Indexed(255),
+            image_export_border: Color::Indexed(252),
+            image_export_bg: Color::Indexed(236),
+            image_export_label_text: Color::Indexed(84),
+            image_export_value_text: Color::Indexed(252),
+            image_export_disabled_text: Color::Indexed(245),
+            image_export_cursor_text: Color::Indexed(235),
+            image_export_cursor_bg: Color::Indexed(117),
+            image_export_output_text: Color::Indexed(255),
+            image_export_hint_text: Color::Indexed(245),
         };
 
         // 확인 다이얼로그 (Large File/Image Confirm)
@@ -1475,6 +2309,10 @@ impl Theme {
             match_current_bg: Color::Indexed(208),
             bracket_match: Color::Indexed(117),
             modified_mark: Color::Indexed(204),
+            read_only_mark: Color::Indexed(204),
+            sudo_mark: Color::Indexed(204),
+            read_only_banner_text: Color::Indexed(237),
+            read_only_banner_bg: Color::Indexed(204),
             footer_bg: Color::Indexed(237),
             footer_key: Color::Indexed(117),
             footer_text: Color::Indexed(245),
@@ -1509,6 +2347,8 @@ impl Theme {
             line_number: Color::Indexed(245),
             text: Color::Indexed(252),
             bookmark_indicator: Color::Indexed(81),   // 북마크 표시 색상
+            selection_fg: Color::Indexed(235),
+            selection_bg: Color::Indexed(117),
             search_input_text: Color::Indexed(117),
             search_cursor_fg: Color::Indexed(235),
             search_cursor_bg: Color::Indexed(117),
@@ -1770,82 +2610,365 @@ impl Theme {
             footer_text: Color::Indexed(245),
         };
 
-        Self {
-            palette,
-            state,
-            panel,
-            header,
-            status_bar,
-            function_bar,
-            message,
-            dialog,
-            confirm_dialog,
-            settings,
-            editor,
-            syntax,
-            viewer,
-            process_manager,
-            ai_screen,
-            system_info,
-            search_result,
-            image_viewer,
-            file_info,
-            help,
-            advanced_search,
-            diff,
-            diff_file_view,
-            git_screen,
-            dedup_screen,
-            chars: ThemeChars::default(),
-        }
-    }
-
-    /// Dawn of Coding theme - 어두운 파스텔톤의 새벽 코딩 테마
-    pub fn dawn_of_coding() -> Self {
-        // 기본 팔레트 정의 (어두운 파스텔톤)
-        let palette = Palette {
-            bg: Color::Indexed(234),
-            bg_alt: Color::Indexed(235),
-            fg: Color::Indexed(188),
-            fg_dim: Color::Indexed(102),
-            fg_strong: Color::Indexed(195),
-            fg_inverse: Color::Indexed(234),
-            accent: Color::Indexed(110),
-            shortcut: Color::Indexed(146),
-            positive: Color::Indexed(108),
-            highlight: Color::Indexed(174),
+        let checksum_screen = ChecksumScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            label_text: Color::Indexed(252),
+            target_text: Color::Indexed(114),
+            phase_text: Color::Indexed(114),
+            progress_text: Color::Indexed(252),
+            name_text: Color::Indexed(246),
+            hash_text: Color::Indexed(252),
+            ok_text: Color::Indexed(114),
+            mismatch_text: Color::Indexed(209),
+            missing_text: Color::Indexed(204),
+            error_text: Color::Indexed(209),
+            message_text: Color::Indexed(114),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
         };
 
-        let state = StateColors {
-            success: Color::Indexed(108),
-            warning: Color::Indexed(180),
-            error: Color::Indexed(167),
-            info: Color::Indexed(110),
+        let audit_screen = AuditScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            label_text: Color::Indexed(252),
+            path_text: Color::Indexed(114),
+            count_text: Color::Indexed(114),
+            empty_text: Color::Indexed(252),
+            time_text: Color::Indexed(252),
+            user_text: Color::Indexed(246),
+            summary_text: Color::Indexed(252),
+            kind_rejected: Color::Indexed(209),
+            kind_shell: Color::Indexed(204),
+            kind_transfer: Color::Indexed(114),
+            kind_other: Color::Indexed(252),
+            value_text: Color::Indexed(246),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
         };
 
-        let panel = PanelColors {
-            bg: Color::Indexed(234),
-            border: Color::Indexed(102),
-            border_active: Color::Indexed(146),
-            header_bg: Color::Indexed(235),
-            header_bg_active: Color::Indexed(236),
-            header_text: Color::Indexed(145),
-            header_text_active: Color::Indexed(195),
-            file_text: Color::Indexed(188),
-            directory_text: Color::Indexed(110),
-            symlink_text: Color::Indexed(73),
-            selected_bg: Color::Indexed(146),
-            selected_text: Color::Indexed(234),
-            marked_text: Color::Indexed(174),
-            size_text: Color::Indexed(102),
-            date_text: Color::Indexed(102),
-            remote_indicator: Color::Indexed(108),
+        let archive_viewer = ArchiveViewerColors {
+            bg: Color::Indexed(235),
+            info_border: Color::Indexed(252),
+            list_border: Color::Indexed(245),
+            title: Color::Indexed(255),
+            path_text: Color::Indexed(117),
+            count_text: Color::Indexed(245),
+            arrow_text: Color::Indexed(245),
+            dest_text: Color::Indexed(252),
+            empty_text: Color::Indexed(245),
+            directory_text: Color::Indexed(117),
+            file_text: Color::Indexed(252),
+            marked_text: Color::Indexed(204),
+            size_text: Color::Indexed(245),
+            date_text: Color::Indexed(245),
+            selected_bg: Color::Indexed(117),
+            selected_text: Color::Indexed(16),
+            message_text: Color::Indexed(245),
+            footer_bg: Color::Indexed(235),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
         };
 
-        let header = HeaderColors {
-            bg: Color::Indexed(234),
-            text: Color::Indexed(188),
-            title: Color::Indexed(146),
+        let trash_screen = TrashScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            label_text: Color::Indexed(252),
+            path_text: Color::Indexed(114),
+            count_text: Color::Indexed(114),
+            empty_text: Color::Indexed(252),
+            date_text: Color::Indexed(252),
+            size_text: Color::Indexed(246),
+            directory_text: Color::Indexed(114),
+            file_text: Color::Indexed(252),
+            origin_text: Color::Indexed(246),
+            confirm_text: Color::Indexed(209),
+            message_text: Color::Indexed(114),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let jobs_screen = JobsScreenColors {
+            summary_text: Color::Indexed(255),
+            summary_bg: Color::Indexed(236),
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            border_active: Color::Indexed(252),
+            title: Color::Indexed(255),
+            id_text: Color::Indexed(245),
+            running_text: Color::Indexed(252),
+            done_text: Color::Indexed(245),
+            failed_text: Color::Indexed(204),
+            selected_bg: Color::Indexed(117),
+            selected_text: Color::Indexed(16),
+        };
+
+        let bulk_rename = BulkRenameColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            label_text: Color::Indexed(252),
+            label_active: Color::Indexed(114),
+            input_text: Color::Indexed(252),
+            input_cursor: Color::Indexed(114),
+            hint_text: Color::Indexed(246),
+            list_border_active: Color::Indexed(81),
+            old_name: Color::Indexed(252),
+            arrow_text: Color::Indexed(252),
+            new_name: Color::Indexed(114),
+            unchanged_name: Color::Indexed(246),
+            invalid_name: Color::Indexed(209),
+            edit_text: Color::Indexed(114),
+            status_text: Color::Indexed(252),
+            error_text: Color::Indexed(209),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let preview_pane = PreviewPaneColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(250),
+            text: Color::Indexed(252),
+            dim_text: Color::Indexed(245),
+            directory_text: Color::Indexed(117),
+            size_text: Color::Indexed(245),
+        };
+
+        let drives_screen = DrivesScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            empty_text: Color::Indexed(252),
+            mount_text: Color::Indexed(114),
+            bar_fill: Color::Indexed(114),
+            bar_full: Color::Indexed(209),
+            bar_empty: Color::Indexed(237),
+            percent_text: Color::Indexed(252),
+            space_text: Color::Indexed(246),
+            unavailable_text: Color::Indexed(252),
+            label_text: Color::Indexed(252),
+            value_text: Color::Indexed(246),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let recovery_screen = RecoveryScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            intro_text: Color::Indexed(114),
+            empty_text: Color::Indexed(252),
+            tag_text: Color::Indexed(252),
+            item_text: Color::Indexed(252),
+            label_text: Color::Indexed(252),
+            partial_path: Color::Indexed(209),
+            hint_text: Color::Indexed(246),
+            message_text: Color::Indexed(114),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let tree_sidebar = TreeSidebarColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            border_active: Color::Indexed(252),
+            title: Color::Indexed(250),
+            title_active: Color::Indexed(255),
+            message_text: Color::Indexed(245),
+            directory_text: Color::Indexed(117),
+            cursor_text: Color::Indexed(117),
+            selected_bg: Color::Indexed(117),
+            selected_text: Color::Indexed(16),
+        };
+
+        let cleanup_screen = CleanupScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            label_text: Color::Indexed(252),
+            target_text: Color::Indexed(114),
+            phase_text: Color::Indexed(114),
+            found_size: Color::Indexed(114),
+            marked_size: Color::Indexed(204),
+            freed_size: Color::Indexed(114),
+            empty_text: Color::Indexed(252),
+            arrow_text: Color::Indexed(252),
+            check_mark: Color::Indexed(204),
+            category_size: Color::Indexed(114),
+            category_name: Color::Indexed(252),
+            category_count: Color::Indexed(246),
+            item_size: Color::Indexed(246),
+            item_path: Color::Indexed(252),
+            confirm_text: Color::Indexed(209),
+            progress_text: Color::Indexed(246),
+            message_text: Color::Indexed(114),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let backup_screen = BackupScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            message_text: Color::Indexed(114),
+            label_text: Color::Indexed(252),
+            value_text: Color::Indexed(252),
+            dim_text: Color::Indexed(246),
+            ok_text: Color::Indexed(114),
+            error_text: Color::Indexed(209),
+            form_selected: Color::Indexed(114),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let timeline_screen = TimelineScreenColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(245),
+            title: Color::Indexed(81),
+            root_text: Color::Indexed(114),
+            window_text: Color::Indexed(114),
+            info_text: Color::Indexed(252),
+            empty_text: Color::Indexed(252),
+            age_text: Color::Indexed(114),
+            time_text: Color::Indexed(246),
+            size_text: Color::Indexed(246),
+            path_text: Color::Indexed(252),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let git_log_screen = GitLogScreenColors {
+            bg: Color::Indexed(235),
+            header_path: Color::Indexed(252),
+            header_scope: Color::Indexed(114),
+            graph: Color::Indexed(252),
+            marker: Color::Indexed(114),
+            log_hash: Color::Indexed(117),
+            refs: Color::Indexed(114),
+            log_subject: Color::Indexed(252),
+            log_author: Color::Indexed(81),
+            log_date: Color::Indexed(245),
+            selected_bg: Color::Indexed(240),
+            selected_text: Color::Indexed(255),
+            detail_subject: Color::Indexed(252),
+            file_name: Color::Indexed(252),
+            file_added: Color::Indexed(114),
+            file_deleted: Color::Indexed(209),
+            file_renamed: Color::Indexed(204),
+            file_modified: Color::Indexed(220),
+            empty_text: Color::Indexed(245),
+            message_text: Color::Indexed(245),
+            footer_key: Color::Indexed(117),
+            footer_text: Color::Indexed(245),
+        };
+
+        let favorites_bar = FavoritesBarColors {
+            key: Color::Indexed(252),
+            label: Color::Indexed(245),
+            current: Color::Indexed(245),
+            empty_text: Color::Indexed(245),
+        };
+
+        Self {
+            palette,
+            state,
+            panel,
+            header,
+            status_bar,
+            function_bar,
+            message,
+            dialog,
+            confirm_dialog,
+            settings,
+            editor,
+            syntax,
+            viewer,
+            process_manager,
+            ai_screen,
+            system_info,
+            search_result,
+            image_viewer,
+            file_info,
+            help,
+            advanced_search,
+            diff,
+            diff_file_view,
+            git_screen,
+            dedup_screen,
+            checksum_screen,
+            audit_screen,
+            archive_viewer,
+            trash_screen,
+            jobs_screen,
+            bulk_rename,
+            preview_pane,
+            drives_screen,
+            recovery_screen,
+            tree_sidebar,
+            cleanup_screen,
+            backup_screen,
+            timeline_screen,
+            git_log_screen,
+            favorites_bar,
+            chars: ThemeChars::default(),
+        }
+    }
+
+    /// Dawn of Coding theme - 어두운 파스텔톤의 새벽 코딩 테마
+    pub fn dawn_of_coding() -> Self {
+        // 기본 팔레트 정의 (어두운 파스텔톤)
+        let palette = Palette {
+            bg: Color::Indexed(234),
+            bg_alt: Color::Indexed(235),
+            fg: Color::Indexed(188),
+            fg_dim: Color::Indexed(102),
+            fg_strong: Color::Indexed(195),
+            fg_inverse: Color::Indexed(234),
+            accent: Color::Indexed(110),
+            shortcut: Color::Indexed(146),
+            positive: Color::Indexed(108),
+            highlight: Color::Indexed(174),
+        };
+
+        let state = StateColors {
+            success: Color::Indexed(108),
+            warning: Color::Indexed(180),
+            error: Color::Indexed(167),
+            info: Color::Indexed(110),
+        };
+
+        let panel = PanelColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            border_active: Color::Indexed(146),
+            header_bg: Color::Indexed(235),
+            header_bg_active: Color::Indexed(236),
+            header_text: Color::Indexed(145),
+            header_text_active: Color::Indexed(195),
+            file_text: Color::Indexed(188),
+            directory_text: Color::Indexed(110),
+            symlink_text: Color::Indexed(73),
+            selected_bg: Color::Indexed(146),
+            selected_text: Color::Indexed(234),
+            marked_text: Color::Indexed(174),
+            size_text: Color::Indexed(102),
+            date_text: Color::Indexed(102),
+            remote_indicator: Color::Indexed(108),
+            git_badge_modified: Color::Indexed(180),
+            git_badge_staged: Color::Indexed(108),
+            git_badge_deleted: Color::Indexed(167),
+            git_badge_untracked: Color::Indexed(174),
+            git_badge_ignored: Color::Indexed(102),
+        };
+
+        let header = HeaderColors {
+            bg: Color::Indexed(234),
+            text: Color::Indexed(188),
+            title: Color::Indexed(146),
         };
 
         let status_bar = StatusBarColors {
@@ -1920,10 +3043,78 @@ impl Theme {
             git_log_diff_button_selected_bg: Color::Indexed(60),
             git_log_diff_button_disabled_text: Color::Indexed(239),
             git_log_diff_scroll_info: Color::Indexed(102),
+
+            // === Open With ===
+            open_with_title: Color::Indexed(195),
+            open_with_border: Color::Indexed(146),
+            open_with_bg: Color::Indexed(235),
+            open_with_input_text: Color::Indexed(195),
+            open_with_entry_text: Color::Indexed(188),
+            open_with_recent_text: Color::Indexed(108),
+            open_with_preferred_text: Color::Indexed(195),
+            open_with_command_text: Color::Indexed(102),
+            open_with_cursor_text: Color::Indexed(195),
+            open_with_cursor_bg: Color::Indexed(60),
+            open_with_scroll_info: Color::Indexed(102),
             remote_bookmark_text: Color::Indexed(108),
             remote_connect_field_label: Color::Indexed(145),
             remote_connect_field_value: Color::Indexed(188),
             remote_connect_field_selected_bg: Color::Indexed(60),
+            jump_title: Color::Indexed(195),
+            jump_border: Color::Indexed(146),
+            jump_bg: Color::Indexed(235),
+            jump_input_text: Color::Indexed(195),
+            jump_entry_text: Color::Indexed(188),
+            jump_cursor_text: Color::Indexed(195),
+            jump_cursor_bg: Color::Indexed(60),
+            jump_scroll_info: Color::Indexed(102),
+            jump_hint_text: Color::Indexed(102),
+            recent_files_title: Color::Indexed(195),
+            recent_files_border: Color::Indexed(146),
+            recent_files_bg: Color::Indexed(235),
+            recent_files_input_text: Color::Indexed(195),
+            recent_files_entry_text: Color::Indexed(188),
+            recent_files_edited_text: Color::Indexed(108),
+            recent_files_cursor_text: Color::Indexed(195),
+            recent_files_cursor_bg: Color::Indexed(60),
+            recent_files_scroll_info: Color::Indexed(102),
+            recent_files_hint_text: Color::Indexed(102),
+            breadcrumb_title: Color::Indexed(195),
+            breadcrumb_border: Color::Indexed(146),
+            breadcrumb_bg: Color::Indexed(235),
+            breadcrumb_path_text: Color::Indexed(195),
+            breadcrumb_separator: Color::Indexed(102),
+            breadcrumb_current_text: Color::Indexed(108),
+            breadcrumb_entry_text: Color::Indexed(188),
+            breadcrumb_cursor_text: Color::Indexed(195),
+            breadcrumb_cursor_bg: Color::Indexed(60),
+            breadcrumb_scroll_info: Color::Indexed(102),
+            breadcrumb_hint_text: Color::Indexed(102),
+            favorites_title: Color::Indexed(195),
+            favorites_border: Color::Indexed(146),
+            favorites_bg: Color::Indexed(235),
+            favorites_entry_text: Color::Indexed(188),
+            favorites_empty_slot_text: Color::Indexed(102),
+            favorites_cursor_text: Color::Indexed(195),
+            favorites_cursor_bg: Color::Indexed(60),
+            favorites_hint_text: Color::Indexed(102),
+            basket_title: Color::Indexed(195),
+            basket_border: Color::Indexed(146),
+            basket_bg: Color::Indexed(235),
+            basket_entry_text: Color::Indexed(188),
+            basket_cursor_text: Color::Indexed(195),
+            basket_cursor_bg: Color::Indexed(60),
+            basket_hint_text: Color::Indexed(102),
+            image_export_title: Color::Indexed(195),
+            image_export_border: Color::Indexed(146),
+            image_export_bg: Color::Indexed(235),
+            image_export_label_text: Color::Indexed(108),
+            image_export_value_text: Color::Indexed(188),
+            image_export_disabled_text: Color::Indexed(102),
+            image_export_cursor_text: Color::Indexed(195),
+            image_export_cursor_bg: Color::Indexed(60),
+            image_export_output_text: Color::Indexed(195),
+            image_export_hint_text: Color::Indexed(102),
         };
 
         let confirm_dialog = ConfirmDialogColors {
@@ -1963,6 +3154,10 @@ impl Theme {
             match_current_bg: Color::Indexed(132),
             bracket_match: Color::Indexed(110),
             modified_mark: Color::Indexed(174),
+            read_only_mark: Color::Indexed(174),
+            sudo_mark: Color::Indexed(174),
+            read_only_banner_text: Color::Indexed(235),
+            read_only_banner_bg: Color::Indexed(174),
             footer_bg: Color::Indexed(235),
             footer_key: Color::Indexed(146),
             footer_text: Color::Indexed(102),
@@ -1996,6 +3191,8 @@ impl Theme {
             line_number: Color::Indexed(239),
             text: Color::Indexed(188),
             bookmark_indicator: Color::Indexed(110),
+            selection_fg: Color::Indexed(195),
+            selection_bg: Color::Indexed(60),
             search_input_text: Color::Indexed(188),
             search_cursor_fg: Color::Indexed(234),
             search_cursor_bg: Color::Indexed(146),
@@ -2240,21 +3437,1972 @@ impl Theme {
             diff_header: Color::Indexed(110),
         };
 
-        let dedup_screen = DedupScreenColors {
-            bg: Color::Indexed(234),
-            border: Color::Indexed(102),
-            title: Color::Indexed(110),
-            phase_text: Color::Indexed(108),
-            stats_text: Color::Indexed(188),
-            progress_bar_fill: Color::Indexed(108),
-            progress_bar_empty: Color::Indexed(236),
-            progress_text: Color::Indexed(188),
-            log_text: Color::Indexed(188),
-            log_text_alt: Color::Indexed(144),
-            log_deleted: Color::Indexed(174),
-            log_error: Color::Indexed(167),
-            footer_key: Color::Indexed(146),
-            footer_text: Color::Indexed(102),
+        let dedup_screen = DedupScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            phase_text: Color::Indexed(108),
+            stats_text: Color::Indexed(188),
+            progress_bar_fill: Color::Indexed(108),
+            progress_bar_empty: Color::Indexed(236),
+            progress_text: Color::Indexed(188),
+            log_text: Color::Indexed(188),
+            log_text_alt: Color::Indexed(144),
+            log_deleted: Color::Indexed(174),
+            log_error: Color::Indexed(167),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let checksum_screen = ChecksumScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            label_text: Color::Indexed(188),
+            target_text: Color::Indexed(108),
+            phase_text: Color::Indexed(108),
+            progress_text: Color::Indexed(188),
+            name_text: Color::Indexed(144),
+            hash_text: Color::Indexed(188),
+            ok_text: Color::Indexed(108),
+            mismatch_text: Color::Indexed(167),
+            missing_text: Color::Indexed(174),
+            error_text: Color::Indexed(167),
+            message_text: Color::Indexed(108),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let audit_screen = AuditScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            label_text: Color::Indexed(188),
+            path_text: Color::Indexed(108),
+            count_text: Color::Indexed(108),
+            empty_text: Color::Indexed(188),
+            time_text: Color::Indexed(188),
+            user_text: Color::Indexed(144),
+            summary_text: Color::Indexed(188),
+            kind_rejected: Color::Indexed(167),
+            kind_shell: Color::Indexed(174),
+            kind_transfer: Color::Indexed(108),
+            kind_other: Color::Indexed(188),
+            value_text: Color::Indexed(144),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let archive_viewer = ArchiveViewerColors {
+            bg: Color::Indexed(234),
+            info_border: Color::Indexed(146),
+            list_border: Color::Indexed(102),
+            title: Color::Indexed(195),
+            path_text: Color::Indexed(110),
+            count_text: Color::Indexed(102),
+            arrow_text: Color::Indexed(102),
+            dest_text: Color::Indexed(188),
+            empty_text: Color::Indexed(102),
+            directory_text: Color::Indexed(110),
+            file_text: Color::Indexed(188),
+            marked_text: Color::Indexed(174),
+            size_text: Color::Indexed(102),
+            date_text: Color::Indexed(102),
+            selected_bg: Color::Indexed(146),
+            selected_text: Color::Indexed(234),
+            message_text: Color::Indexed(102),
+            footer_bg: Color::Indexed(234),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let trash_screen = TrashScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            label_text: Color::Indexed(188),
+            path_text: Color::Indexed(108),
+            count_text: Color::Indexed(108),
+            empty_text: Color::Indexed(188),
+            date_text: Color::Indexed(188),
+            size_text: Color::Indexed(144),
+            directory_text: Color::Indexed(108),
+            file_text: Color::Indexed(188),
+            origin_text: Color::Indexed(144),
+            confirm_text: Color::Indexed(167),
+            message_text: Color::Indexed(108),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let jobs_screen = JobsScreenColors {
+            summary_text: Color::Indexed(195),
+            summary_bg: Color::Indexed(235),
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            border_active: Color::Indexed(146),
+            title: Color::Indexed(195),
+            id_text: Color::Indexed(102),
+            running_text: Color::Indexed(188),
+            done_text: Color::Indexed(102),
+            failed_text: Color::Indexed(167),
+            selected_bg: Color::Indexed(146),
+            selected_text: Color::Indexed(234),
+        };
+
+        let bulk_rename = BulkRenameColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            label_text: Color::Indexed(188),
+            label_active: Color::Indexed(108),
+            input_text: Color::Indexed(188),
+            input_cursor: Color::Indexed(108),
+            hint_text: Color::Indexed(144),
+            list_border_active: Color::Indexed(110),
+            old_name: Color::Indexed(188),
+            arrow_text: Color::Indexed(188),
+            new_name: Color::Indexed(108),
+            unchanged_name: Color::Indexed(144),
+            invalid_name: Color::Indexed(167),
+            edit_text: Color::Indexed(108),
+            status_text: Color::Indexed(188),
+            error_text: Color::Indexed(167),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let preview_pane = PreviewPaneColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(145),
+            text: Color::Indexed(188),
+            dim_text: Color::Indexed(102),
+            directory_text: Color::Indexed(110),
+            size_text: Color::Indexed(102),
+        };
+
+        let drives_screen = DrivesScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            empty_text: Color::Indexed(188),
+            mount_text: Color::Indexed(108),
+            bar_fill: Color::Indexed(108),
+            bar_full: Color::Indexed(167),
+            bar_empty: Color::Indexed(236),
+            percent_text: Color::Indexed(188),
+            space_text: Color::Indexed(144),
+            unavailable_text: Color::Indexed(188),
+            label_text: Color::Indexed(188),
+            value_text: Color::Indexed(144),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let recovery_screen = RecoveryScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            intro_text: Color::Indexed(108),
+            empty_text: Color::Indexed(188),
+            tag_text: Color::Indexed(188),
+            item_text: Color::Indexed(188),
+            label_text: Color::Indexed(188),
+            partial_path: Color::Indexed(167),
+            hint_text: Color::Indexed(144),
+            message_text: Color::Indexed(108),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let tree_sidebar = TreeSidebarColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            border_active: Color::Indexed(146),
+            title: Color::Indexed(145),
+            title_active: Color::Indexed(195),
+            message_text: Color::Indexed(102),
+            directory_text: Color::Indexed(110),
+            cursor_text: Color::Indexed(110),
+            selected_bg: Color::Indexed(146),
+            selected_text: Color::Indexed(234),
+        };
+
+        let cleanup_screen = CleanupScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            label_text: Color::Indexed(188),
+            target_text: Color::Indexed(108),
+            phase_text: Color::Indexed(108),
+            found_size: Color::Indexed(108),
+            marked_size: Color::Indexed(174),
+            freed_size: Color::Indexed(108),
+            empty_text: Color::Indexed(188),
+            arrow_text: Color::Indexed(188),
+            check_mark: Color::Indexed(174),
+            category_size: Color::Indexed(108),
+            category_name: Color::Indexed(188),
+            category_count: Color::Indexed(144),
+            item_size: Color::Indexed(144),
+            item_path: Color::Indexed(188),
+            confirm_text: Color::Indexed(167),
+            progress_text: Color::Indexed(144),
+            message_text: Color::Indexed(108),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let backup_screen = BackupScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            message_text: Color::Indexed(108),
+            label_text: Color::Indexed(188),
+            value_text: Color::Indexed(188),
+            dim_text: Color::Indexed(144),
+            ok_text: Color::Indexed(108),
+            error_text: Color::Indexed(167),
+            form_selected: Color::Indexed(108),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let timeline_screen = TimelineScreenColors {
+            bg: Color::Indexed(234),
+            border: Color::Indexed(102),
+            title: Color::Indexed(110),
+            root_text: Color::Indexed(108),
+            window_text: Color::Indexed(108),
+            info_text: Color::Indexed(188),
+            empty_text: Color::Indexed(188),
+            age_text: Color::Indexed(108),
+            time_text: Color::Indexed(144),
+            size_text: Color::Indexed(144),
+            path_text: Color::Indexed(188),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let git_log_screen = GitLogScreenColors {
+            bg: Color::Indexed(234),
+            header_path: Color::Indexed(188),
+            header_scope: Color::Indexed(108),
+            graph: Color::Indexed(188),
+            marker: Color::Indexed(108),
+            log_hash: Color::Indexed(146),
+            refs: Color::Indexed(108),
+            log_subject: Color::Indexed(188),
+            log_author: Color::Indexed(110),
+            log_date: Color::Indexed(102),
+            selected_bg: Color::Indexed(239),
+            selected_text: Color::Indexed(195),
+            detail_subject: Color::Indexed(188),
+            file_name: Color::Indexed(188),
+            file_added: Color::Indexed(108),
+            file_deleted: Color::Indexed(167),
+            file_renamed: Color::Indexed(174),
+            file_modified: Color::Indexed(180),
+            empty_text: Color::Indexed(102),
+            message_text: Color::Indexed(102),
+            footer_key: Color::Indexed(146),
+            footer_text: Color::Indexed(102),
+        };
+
+        let favorites_bar = FavoritesBarColors {
+            key: Color::Indexed(146),
+            label: Color::Indexed(102),
+            current: Color::Indexed(102),
+            empty_text: Color::Indexed(102),
+        };
+
+        Self {
+            palette,
+            state,
+            panel,
+            header,
+            status_bar,
+            function_bar,
+            message,
+            dialog,
+            confirm_dialog,
+            settings,
+            editor,
+            syntax,
+            viewer,
+            process_manager,
+            ai_screen,
+            system_info,
+            search_result,
+            image_viewer,
+            file_info,
+            help,
+            advanced_search,
+            diff,
+            diff_file_view,
+            git_screen,
+            dedup_screen,
+            checksum_screen,
+            audit_screen,
+            archive_viewer,
+            trash_screen,
+            jobs_screen,
+            bulk_rename,
+            preview_pane,
+            drives_screen,
+            recovery_screen,
+            tree_sidebar,
+            cleanup_screen,
+            backup_screen,
+            timeline_screen,
+            git_log_screen,
+            favorites_bar,
+            chars: ThemeChars::default(),
+        }
+    }
+
+    /// High Contrast theme - 검정 배경에 흰 글자와 고채도 강조색을 쓰는 고대비 테마
+    pub fn high_contrast() -> Self {
+        // 기본 팔레트 정의 (검정 배경 + 고채도 강조색)
+        let palette = Palette {
+            bg: Color::Indexed(16),
+            bg_alt: Color::Indexed(233),
+            fg: Color::Indexed(231),
+            fg_dim: Color::Indexed(250),
+            fg_strong: Color::Indexed(231),
+            fg_inverse: Color::Indexed(16),
+            accent: Color::Indexed(51),
+            shortcut: Color::Indexed(226),
+            positive: Color::Indexed(46),
+            highlight: Color::Indexed(201),
+        };
+
+        let state = StateColors {
+            success: Color::Indexed(46),
+            warning: Color::Indexed(220),
+            error: Color::Indexed(203),
+            info: Color::Indexed(51),
+        };
+
+        let panel = PanelColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            border_active: Color::Indexed(226),
+            header_bg: Color::Indexed(233),
+            header_bg_active: Color::Indexed(235),
+            header_text: Color::Indexed(252),
+            header_text_active: Color::Indexed(231),
+            file_text: Color::Indexed(231),
+            directory_text: Color::Indexed(51),
+            symlink_text: Color::Indexed(87),
+            selected_bg: Color::Indexed(226),
+            selected_text: Color::Indexed(16),
+            marked_text: Color::Indexed(201),
+            size_text: Color::Indexed(250),
+            date_text: Color::Indexed(250),
+            remote_indicator: Color::Indexed(46),
+            git_badge_modified: Color::Indexed(220),
+            git_badge_staged: Color::Indexed(46),
+            git_badge_deleted: Color::Indexed(203),
+            git_badge_untracked: Color::Indexed(201),
+            git_badge_ignored: Color::Indexed(250),
+        };
+
+        let header = HeaderColors {
+            bg: Color::Indexed(16),
+            text: Color::Indexed(231),
+            title: Color::Indexed(226),
+        };
+
+        let status_bar = StatusBarColors {
+            bg: Color::Indexed(233),
+            text: Color::Indexed(231),
+            text_dim: Color::Indexed(250),
+        };
+
+        let function_bar = FunctionBarColors {
+            bg: Color::Indexed(16),
+            key: Color::Indexed(226),
+            label: Color::Indexed(250),
+        };
+
+        let message = MessageColors {
+            bg: Color::Indexed(233),
+            text: Color::Indexed(201),
+        };
+
+        let dialog = DialogColors {
+            bg: Color::Indexed(233),
+            border: Color::Indexed(226),
+            title: Color::Indexed(231),
+            text: Color::Indexed(231),
+            text_dim: Color::Indexed(250),
+            message_text: Color::Indexed(231),
+            input_text: Color::Indexed(231),
+            input_cursor_fg: Color::Indexed(16),
+            input_cursor_bg: Color::Indexed(226),
+            input_prompt: Color::Indexed(51),
+            button_text: Color::Indexed(250),
+            button_selected_bg: Color::Indexed(21),
+            button_selected_text: Color::Indexed(231),
+            autocomplete_bg: Color::Indexed(233),
+            autocomplete_text: Color::Indexed(231),
+            autocomplete_directory_text: Color::Indexed(51),
+            autocomplete_selected_bg: Color::Indexed(21),
+            autocomplete_selected_text: Color::Indexed(231),
+            autocomplete_scroll_info: Color::Indexed(250),
+            preview_suffix_text: Color::Indexed(250),
+            help_key_text: Color::Indexed(226),
+            help_label_text: Color::Indexed(250),
+            progress_label_text: Color::Indexed(250),
+            progress_value_text: Color::Indexed(231),
+            progress_bar_fill: Color::Indexed(46),
+            progress_bar_empty: Color::Indexed(244),
+            progress_percent_text: Color::Indexed(231),
+            conflict_filename_text: Color::Indexed(201),
+            conflict_count_text: Color::Indexed(250),
+            conflict_shortcut_text: Color::Indexed(51),
+            tar_exclude_title: Color::Indexed(231),
+            tar_exclude_border: Color::Indexed(226),
+            tar_exclude_bg: Color::Indexed(233),
+            tar_exclude_message_text: Color::Indexed(231),
+            tar_exclude_path_text: Color::Indexed(220),
+            tar_exclude_scroll_info: Color::Indexed(250),
+            tar_exclude_button_text: Color::Indexed(250),
+            tar_exclude_button_selected_bg: Color::Indexed(21),
+            tar_exclude_button_selected_text: Color::Indexed(231),
+
+            // === Git Log Diff ===
+            git_log_diff_title: Color::Indexed(231),
+            git_log_diff_border: Color::Indexed(226),
+            git_log_diff_bg: Color::Indexed(233),
+            git_log_diff_message_text: Color::Indexed(231),
+            git_log_diff_entry_text: Color::Indexed(231),
+            git_log_diff_selected_text: Color::Indexed(46),
+            git_log_diff_cursor_text: Color::Indexed(231),
+            git_log_diff_cursor_bg: Color::Indexed(21),
+            git_log_diff_button_text: Color::Indexed(250),
+            git_log_diff_button_selected_text: Color::Indexed(231),
+            git_log_diff_button_selected_bg: Color::Indexed(21),
+            git_log_diff_button_disabled_text: Color::Indexed(244),
+            git_log_diff_scroll_info: Color::Indexed(250),
+
+            // === Open With ===
+            open_with_title: Color::Indexed(231),
+            open_with_border: Color::Indexed(226),
+            open_with_bg: Color::Indexed(233),
+            open_with_input_text: Color::Indexed(231),
+            open_with_entry_text: Color::Indexed(231),
+            open_with_recent_text: Color::Indexed(46),
+            open_with_preferred_text: Color::Indexed(231),
+            open_with_command_text: Color::Indexed(250),
+            open_with_cursor_text: Color::Indexed(231),
+            open_with_cursor_bg: Color::Indexed(21),
+            open_with_scroll_info: Color::Indexed(250),
+            remote_bookmark_text: Color::Indexed(46),
+            remote_connect_field_label: Color::Indexed(252),
+            remote_connect_field_value: Color::Indexed(231),
+            remote_connect_field_selected_bg: Color::Indexed(21),
+            jump_title: Color::Indexed(231),
+            jump_border: Color::Indexed(226),
+            jump_bg: Color::Indexed(233),
+            jump_input_text: Color::Indexed(231),
+            jump_entry_text: Color::Indexed(231),
+            jump_cursor_text: Color::Indexed(231),
+            jump_cursor_bg: Color::Indexed(21),
+            jump_scroll_info: Color::Indexed(250),
+            jump_hint_text: Color::Indexed(250),
+            recent_files_title: Color::Indexed(231),
+            recent_files_border: Color::Indexed(226),
+            recent_files_bg: Color::Indexed(233),
+            recent_files_input_text: Color::Indexed(231),
+            recent_files_entry_text: Color::Indexed(231),
+            recent_files_edited_text: Color::Indexed(46),
+            recent_files_cursor_text: Color::Indexed(231),
+            recent_files_cursor_bg: Color::Indexed(21),
+            recent_files_scroll_info: Color::Indexed(250),
+            recent_files_hint_text: Color::Indexed(250),
+            breadcrumb_title: Color::Indexed(231),
+            breadcrumb_border: Color::Indexed(226),
+            breadcrumb_bg: Color::Indexed(233),
+            breadcrumb_path_text: Color::Indexed(231),
+            breadcrumb_separator: Color::Indexed(250),
+            breadcrumb_current_text: Color::Indexed(46),
+            breadcrumb_entry_text: Color::Indexed(231),
+            breadcrumb_cursor_text: Color::Indexed(231),
+            breadcrumb_cursor_bg: Color::Indexed(21),
+            breadcrumb_scroll_info: Color::Indexed(250),
+            breadcrumb_hint_text: Color::Indexed(250),
+            favorites_title: Color::Indexed(231),
+            favorites_border: Color::Indexed(226),
+            favorites_bg: Color::Indexed(233),
+            favorites_entry_text: Color::Indexed(231),
+            favorites_empty_slot_text: Color::Indexed(250),
+            favorites_cursor_text: Color::Indexed(231),
+            favorites_cursor_bg: Color::Indexed(21),
+            favorites_hint_text: Color::Indexed(250),
+            basket_title: Color::Indexed(231),
+            basket_border: Color::Indexed(226),
+            basket_bg: Color::Indexed(233),
+            basket_entry_text: Color::Indexed(231),
+            basket_cursor_text: Color::Indexed(231),
+            basket_cursor_bg: Color::Indexed(21),
+            basket_hint_text: Color::Indexed(250),
+            image_export_title: Color::Indexed(231),
+            image_export_border: Color::Indexed(226),
+            image_export_bg: Color::Indexed(233),
+            image_export_label_text: Color::Indexed(46),
+            image_export_value_text: Color::Indexed(231),
+            image_export_disabled_text: Color::Indexed(250),
+            image_export_cursor_text: Color::Indexed(231),
+            image_export_cursor_bg: Color::Indexed(21),
+            image_export_output_text: Color::Indexed(231),
+            image_export_hint_text: Color::Indexed(250),
+        };
+
+        let confirm_dialog = ConfirmDialogColors {
+            bg: Color::Indexed(235),
+            border: Color::Indexed(226),
+            title: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            button_text: Color::Indexed(250),
+            button_selected_bg: Color::Indexed(21),
+            button_selected_text: Color::Indexed(231),
+        };
+
+        let settings = SettingsColors {
+            bg: Color::Indexed(233),
+            border: Color::Indexed(226),
+            title: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            prompt: Color::Indexed(51),
+            value_text: Color::Indexed(16),
+            value_bg: Color::Indexed(226),
+            help_key: Color::Indexed(226),
+            help_text: Color::Indexed(250),
+        };
+
+        let editor = EditorColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            header_bg: Color::Indexed(233),
+            header_text: Color::Indexed(252),
+            header_info: Color::Indexed(250),
+            line_number: Color::Indexed(244),
+            text: Color::Indexed(231),
+            cursor: Color::Indexed(226),
+            selection_bg: Color::Indexed(21),
+            selection_text: Color::Indexed(231),
+            match_bg: Color::Indexed(214),
+            match_current_bg: Color::Indexed(226),
+            bracket_match: Color::Indexed(51),
+            modified_mark: Color::Indexed(201),
+            read_only_mark: Color::Indexed(201),
+            sudo_mark: Color::Indexed(201),
+            read_only_banner_text: Color::Indexed(233),
+            read_only_banner_bg: Color::Indexed(201),
+            footer_bg: Color::Indexed(233),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+            find_input_text: Color::Indexed(231),
+            find_option: Color::Indexed(250),
+            find_option_active: Color::Indexed(51),
+            wrap_indicator: Color::Indexed(244),
+            remote_path_text: Color::Indexed(214),
+        };
+
+        let syntax = SyntaxColors {
+            keyword: Color::Indexed(213),
+            type_name: Color::Indexed(51),
+            string: Color::Indexed(46),
+            number: Color::Indexed(220),
+            comment: Color::Indexed(246),
+            operator: Color::Indexed(231),
+            function: Color::Indexed(228),
+            macro_name: Color::Indexed(213),
+            attribute: Color::Indexed(250),
+            variable: Color::Indexed(231),
+            constant: Color::Indexed(201),
+            bracket: Color::Indexed(252),
+            normal: Color::Indexed(231),
+        };
+
+        let viewer = ViewerColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            header_text: Color::Indexed(252),
+            line_number: Color::Indexed(244),
+            text: Color::Indexed(231),
+            bookmark_indicator: Color::Indexed(51),
+            selection_fg: Color::Indexed(231),
+            selection_bg: Color::Indexed(21),
+            search_input_text: Color::Indexed(231),
+            search_cursor_fg: Color::Indexed(16),
+            search_cursor_bg: Color::Indexed(226),
+            search_match_current_bg: Color::Indexed(21),
+            search_match_current_fg: Color::Indexed(231),
+            search_match_other_bg: Color::Indexed(244),
+            search_match_other_fg: Color::Indexed(16),
+            search_info: Color::Indexed(250),
+            hex_offset: Color::Indexed(250),
+            hex_bytes: Color::Indexed(231),
+            hex_ascii: Color::Indexed(231),
+            wrap_indicator: Color::Indexed(244),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let process_manager = ProcessManagerColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            header_text: Color::Indexed(252),
+            column_header: Color::Indexed(51),
+            text: Color::Indexed(231),
+            selected_bg: Color::Indexed(21),
+            selected_text: Color::Indexed(231),
+            cpu_high: Color::Indexed(203),
+            mem_high: Color::Indexed(203),
+            confirm_text: Color::Indexed(201),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let ai_screen = AIScreenColors {
+            bg: Color::Indexed(16),
+            history_border: Color::Indexed(226),
+            history_title: Color::Indexed(231),
+            history_placeholder: Color::Indexed(250),
+            history_scroll_info: Color::Indexed(250),
+            user_prefix: Color::Indexed(51),
+            assistant_prefix: Color::Indexed(213),
+            error_prefix: Color::Indexed(203),
+            system_prefix: Color::Indexed(250),
+            message_text: Color::Indexed(231),
+            input_border: Color::Indexed(226),
+            input_prompt: Color::Indexed(51),
+            input_text: Color::Indexed(231),
+            input_cursor_fg: Color::Indexed(16),
+            input_cursor_bg: Color::Indexed(226),
+            input_placeholder: Color::Indexed(250),
+            processing_spinner: Color::Indexed(51),
+            processing_text: Color::Indexed(250),
+            error_text: Color::Indexed(203),
+            tool_use_prefix: Color::Indexed(220),
+            tool_use_name: Color::Indexed(51),
+            tool_use_input: Color::Indexed(231),
+            tool_result_prefix: Color::Indexed(46),
+            tool_result_text: Color::Indexed(231),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let system_info = SystemInfoColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            section_title: Color::Indexed(51),
+            label: Color::Indexed(231),
+            value: Color::Indexed(231),
+            bar_fill: Color::Indexed(46),
+            bar_empty: Color::Indexed(244),
+            usage_low: Color::Indexed(46),
+            usage_medium: Color::Indexed(220),
+            usage_high: Color::Indexed(203),
+            tab_active: Color::Indexed(231),
+            disk_header: Color::Indexed(51),
+            disk_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(21),
+            selected_text: Color::Indexed(231),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let search_result = SearchResultColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            header_text: Color::Indexed(252),
+            column_header: Color::Indexed(51),
+            column_header_dim: Color::Indexed(250),
+            directory_text: Color::Indexed(231),
+            file_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(21),
+            selected_text: Color::Indexed(231),
+            match_highlight: Color::Indexed(201),
+            path_text: Color::Indexed(250),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let image_viewer = ImageViewerColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            title_text: Color::Indexed(252),
+            loading_spinner: Color::Indexed(51),
+            loading_text: Color::Indexed(250),
+            error_text: Color::Indexed(203),
+            hint_text: Color::Indexed(250),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+            footer_separator: Color::Indexed(250),
+        };
+
+        let file_info = FileInfoColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            title: Color::Indexed(231),
+            label: Color::Indexed(250),
+            value: Color::Indexed(231),
+            value_name: Color::Indexed(51),
+            value_path: Color::Indexed(231),
+            value_type: Color::Indexed(231),
+            value_size: Color::Indexed(46),
+            value_permission: Color::Indexed(231),
+            value_owner: Color::Indexed(231),
+            value_date: Color::Indexed(231),
+            calculating_spinner: Color::Indexed(51),
+            calculating_text: Color::Indexed(51),
+            error_text: Color::Indexed(203),
+            hint_text: Color::Indexed(250),
+        };
+
+        let help = HelpColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            title: Color::Indexed(231),
+            section_title: Color::Indexed(51),
+            section_decorator: Color::Indexed(244),
+            key: Color::Indexed(226),
+            key_highlight: Color::Indexed(201),
+            description: Color::Indexed(231),
+            hint_text: Color::Indexed(250),
+        };
+
+        let advanced_search = AdvancedSearchColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            title: Color::Indexed(231),
+            label: Color::Indexed(231),
+            input_text: Color::Indexed(231),
+            input_cursor: Color::Indexed(226),
+            field_bracket: Color::Indexed(51),
+            checkbox_checked: Color::Indexed(46),
+            checkbox_unchecked: Color::Indexed(250),
+            button_text: Color::Indexed(250),
+            button_selected_bg: Color::Indexed(21),
+            button_selected_text: Color::Indexed(231),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let diff = DiffColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            header_text: Color::Indexed(231),
+            header_label: Color::Indexed(226),
+            column_header_bg: Color::Indexed(233),
+            column_header_text: Color::Indexed(231),
+            same_text: Color::Indexed(231),
+            modified_text: Color::Indexed(201),
+            modified_bg: Color::Indexed(52),
+            left_only_text: Color::Indexed(87),
+            left_only_bg: Color::Indexed(17),
+            right_only_text: Color::Indexed(87),
+            right_only_bg: Color::Indexed(17),
+            empty_bg: Color::Indexed(233),
+            dir_same_text: Color::Indexed(51),
+            dir_modified_text: Color::Indexed(201),
+            cursor_bg: Color::Indexed(21),
+            cursor_text: Color::Indexed(231),
+            marked_text: Color::Indexed(201),
+            size_text: Color::Indexed(250),
+            date_text: Color::Indexed(250),
+            status_bar_bg: Color::Indexed(233),
+            status_bar_text: Color::Indexed(231),
+            filter_label: Color::Indexed(226),
+            stats_text: Color::Indexed(231),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+            panel_selected_border: Color::Indexed(201),
+            progress_spinner: Color::Indexed(226),
+            progress_bar_fill: Color::Indexed(226),
+            progress_bar_empty: Color::Indexed(250),
+            progress_percent_text: Color::Indexed(231),
+            progress_value_text: Color::Indexed(231),
+            progress_hint_text: Color::Indexed(250),
+        };
+
+        let diff_file_view = DiffFileViewColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(226),
+            header_text: Color::Indexed(231),
+            line_number: Color::Indexed(244),
+            same_text: Color::Indexed(231),
+            modified_text: Color::Indexed(224),
+            modified_bg: Color::Indexed(52),
+            left_only_text: Color::Indexed(87),
+            left_only_bg: Color::Indexed(17),
+            right_only_text: Color::Indexed(87),
+            right_only_bg: Color::Indexed(17),
+            empty_bg: Color::Indexed(233),
+            inline_change_bg: Color::Indexed(226),
+            inline_change_text: Color::Indexed(16),
+            status_bar_bg: Color::Indexed(233),
+            status_bar_text: Color::Indexed(231),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let git_screen = GitScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            header_branch: Color::Indexed(46),
+            header_path: Color::Indexed(231),
+            tab_active: Color::Indexed(51),
+            tab_inactive: Color::Indexed(250),
+            tab_bar_bg: Color::Indexed(233),
+            file_staged: Color::Indexed(46),
+            file_modified: Color::Indexed(220),
+            file_untracked: Color::Indexed(201),
+            file_deleted: Color::Indexed(203),
+            selected_bg: Color::Indexed(21),
+            selected_text: Color::Indexed(231),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+            commit_input_border: Color::Indexed(250),
+            commit_input_text: Color::Indexed(231),
+            log_hash: Color::Indexed(226),
+            log_message: Color::Indexed(231),
+            log_author: Color::Indexed(51),
+            log_date: Color::Indexed(250),
+            branch_current: Color::Indexed(46),
+            branch_normal: Color::Indexed(231),
+            diff_add: Color::Indexed(46),
+            diff_remove: Color::Indexed(201),
+            diff_header: Color::Indexed(51),
+        };
+
+        let dedup_screen = DedupScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            phase_text: Color::Indexed(46),
+            stats_text: Color::Indexed(231),
+            progress_bar_fill: Color::Indexed(46),
+            progress_bar_empty: Color::Indexed(235),
+            progress_text: Color::Indexed(231),
+            log_text: Color::Indexed(231),
+            log_text_alt: Color::Indexed(187),
+            log_deleted: Color::Indexed(201),
+            log_error: Color::Indexed(203),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let checksum_screen = ChecksumScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            label_text: Color::Indexed(231),
+            target_text: Color::Indexed(46),
+            phase_text: Color::Indexed(46),
+            progress_text: Color::Indexed(231),
+            name_text: Color::Indexed(187),
+            hash_text: Color::Indexed(231),
+            ok_text: Color::Indexed(46),
+            mismatch_text: Color::Indexed(203),
+            missing_text: Color::Indexed(201),
+            error_text: Color::Indexed(203),
+            message_text: Color::Indexed(46),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let audit_screen = AuditScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            label_text: Color::Indexed(231),
+            path_text: Color::Indexed(46),
+            count_text: Color::Indexed(46),
+            empty_text: Color::Indexed(231),
+            time_text: Color::Indexed(231),
+            user_text: Color::Indexed(187),
+            summary_text: Color::Indexed(231),
+            kind_rejected: Color::Indexed(203),
+            kind_shell: Color::Indexed(201),
+            kind_transfer: Color::Indexed(46),
+            kind_other: Color::Indexed(231),
+            value_text: Color::Indexed(187),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let archive_viewer = ArchiveViewerColors {
+            bg: Color::Indexed(16),
+            info_border: Color::Indexed(226),
+            list_border: Color::Indexed(250),
+            title: Color::Indexed(231),
+            path_text: Color::Indexed(51),
+            count_text: Color::Indexed(250),
+            arrow_text: Color::Indexed(250),
+            dest_text: Color::Indexed(231),
+            empty_text: Color::Indexed(250),
+            directory_text: Color::Indexed(51),
+            file_text: Color::Indexed(231),
+            marked_text: Color::Indexed(201),
+            size_text: Color::Indexed(250),
+            date_text: Color::Indexed(250),
+            selected_bg: Color::Indexed(226),
+            selected_text: Color::Indexed(16),
+            message_text: Color::Indexed(250),
+            footer_bg: Color::Indexed(16),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let trash_screen = TrashScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            label_text: Color::Indexed(231),
+            path_text: Color::Indexed(46),
+            count_text: Color::Indexed(46),
+            empty_text: Color::Indexed(231),
+            date_text: Color::Indexed(231),
+            size_text: Color::Indexed(187),
+            directory_text: Color::Indexed(46),
+            file_text: Color::Indexed(231),
+            origin_text: Color::Indexed(187),
+            confirm_text: Color::Indexed(203),
+            message_text: Color::Indexed(46),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let jobs_screen = JobsScreenColors {
+            summary_text: Color::Indexed(231),
+            summary_bg: Color::Indexed(233),
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            border_active: Color::Indexed(226),
+            title: Color::Indexed(231),
+            id_text: Color::Indexed(250),
+            running_text: Color::Indexed(231),
+            done_text: Color::Indexed(250),
+            failed_text: Color::Indexed(203),
+            selected_bg: Color::Indexed(226),
+            selected_text: Color::Indexed(16),
+        };
+
+        let bulk_rename = BulkRenameColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            label_text: Color::Indexed(231),
+            label_active: Color::Indexed(46),
+            input_text: Color::Indexed(231),
+            input_cursor: Color::Indexed(46),
+            hint_text: Color::Indexed(187),
+            list_border_active: Color::Indexed(51),
+            old_name: Color::Indexed(231),
+            arrow_text: Color::Indexed(231),
+            new_name: Color::Indexed(46),
+            unchanged_name: Color::Indexed(187),
+            invalid_name: Color::Indexed(203),
+            edit_text: Color::Indexed(46),
+            status_text: Color::Indexed(231),
+            error_text: Color::Indexed(203),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let preview_pane = PreviewPaneColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(252),
+            text: Color::Indexed(231),
+            dim_text: Color::Indexed(250),
+            directory_text: Color::Indexed(51),
+            size_text: Color::Indexed(250),
+        };
+
+        let drives_screen = DrivesScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            empty_text: Color::Indexed(231),
+            mount_text: Color::Indexed(46),
+            bar_fill: Color::Indexed(46),
+            bar_full: Color::Indexed(203),
+            bar_empty: Color::Indexed(235),
+            percent_text: Color::Indexed(231),
+            space_text: Color::Indexed(187),
+            unavailable_text: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            value_text: Color::Indexed(187),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let recovery_screen = RecoveryScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            intro_text: Color::Indexed(46),
+            empty_text: Color::Indexed(231),
+            tag_text: Color::Indexed(231),
+            item_text: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            partial_path: Color::Indexed(203),
+            hint_text: Color::Indexed(187),
+            message_text: Color::Indexed(46),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let tree_sidebar = TreeSidebarColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            border_active: Color::Indexed(226),
+            title: Color::Indexed(252),
+            title_active: Color::Indexed(231),
+            message_text: Color::Indexed(250),
+            directory_text: Color::Indexed(51),
+            cursor_text: Color::Indexed(51),
+            selected_bg: Color::Indexed(226),
+            selected_text: Color::Indexed(16),
+        };
+
+        let cleanup_screen = CleanupScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            label_text: Color::Indexed(231),
+            target_text: Color::Indexed(46),
+            phase_text: Color::Indexed(46),
+            found_size: Color::Indexed(46),
+            marked_size: Color::Indexed(201),
+            freed_size: Color::Indexed(46),
+            empty_text: Color::Indexed(231),
+            arrow_text: Color::Indexed(231),
+            check_mark: Color::Indexed(201),
+            category_size: Color::Indexed(46),
+            category_name: Color::Indexed(231),
+            category_count: Color::Indexed(187),
+            item_size: Color::Indexed(187),
+            item_path: Color::Indexed(231),
+            confirm_text: Color::Indexed(203),
+            progress_text: Color::Indexed(187),
+            message_text: Color::Indexed(46),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let backup_screen = BackupScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            message_text: Color::Indexed(46),
+            label_text: Color::Indexed(231),
+            value_text: Color::Indexed(231),
+            dim_text: Color::Indexed(187),
+            ok_text: Color::Indexed(46),
+            error_text: Color::Indexed(203),
+            form_selected: Color::Indexed(46),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let timeline_screen = TimelineScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(250),
+            title: Color::Indexed(51),
+            root_text: Color::Indexed(46),
+            window_text: Color::Indexed(46),
+            info_text: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            age_text: Color::Indexed(46),
+            time_text: Color::Indexed(187),
+            size_text: Color::Indexed(187),
+            path_text: Color::Indexed(231),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let git_log_screen = GitLogScreenColors {
+            bg: Color::Indexed(16),
+            header_path: Color::Indexed(231),
+            header_scope: Color::Indexed(46),
+            graph: Color::Indexed(231),
+            marker: Color::Indexed(46),
+            log_hash: Color::Indexed(226),
+            refs: Color::Indexed(46),
+            log_subject: Color::Indexed(231),
+            log_author: Color::Indexed(51),
+            log_date: Color::Indexed(250),
+            selected_bg: Color::Indexed(21),
+            selected_text: Color::Indexed(231),
+            detail_subject: Color::Indexed(231),
+            file_name: Color::Indexed(231),
+            file_added: Color::Indexed(46),
+            file_deleted: Color::Indexed(203),
+            file_renamed: Color::Indexed(201),
+            file_modified: Color::Indexed(220),
+            empty_text: Color::Indexed(250),
+            message_text: Color::Indexed(250),
+            footer_key: Color::Indexed(226),
+            footer_text: Color::Indexed(250),
+        };
+
+        let favorites_bar = FavoritesBarColors {
+            key: Color::Indexed(226),
+            label: Color::Indexed(250),
+            current: Color::Indexed(250),
+            empty_text: Color::Indexed(250),
+        };
+
+        Self {
+            palette,
+            state,
+            panel,
+            header,
+            status_bar,
+            function_bar,
+            message,
+            dialog,
+            confirm_dialog,
+            settings,
+            editor,
+            syntax,
+            viewer,
+            process_manager,
+            ai_screen,
+            system_info,
+            search_result,
+            image_viewer,
+            file_info,
+            help,
+            advanced_search,
+            diff,
+            diff_file_view,
+            git_screen,
+            dedup_screen,
+            checksum_screen,
+            audit_screen,
+            archive_viewer,
+            trash_screen,
+            jobs_screen,
+            bulk_rename,
+            preview_pane,
+            drives_screen,
+            recovery_screen,
+            tree_sidebar,
+            cleanup_screen,
+            backup_screen,
+            timeline_screen,
+            git_log_screen,
+            favorites_bar,
+            chars: ThemeChars::default(),
+        }
+    }
+
+    /// Monochrome theme - 흑백 2색 테마 (커서/선택은 색 반전으로 표시)
+    pub fn monochrome() -> Self {
+        // 기본 팔레트 정의 (흑백 2색)
+        let palette = Palette {
+            bg: Color::Indexed(16),
+            bg_alt: Color::Indexed(16),
+            fg: Color::Indexed(231),
+            fg_dim: Color::Indexed(231),
+            fg_strong: Color::Indexed(231),
+            fg_inverse: Color::Indexed(16),
+            accent: Color::Indexed(231),
+            shortcut: Color::Indexed(231),
+            positive: Color::Indexed(231),
+            highlight: Color::Indexed(231),
+        };
+
+        let state = StateColors {
+            success: Color::Indexed(231),
+            warning: Color::Indexed(231),
+            error: Color::Indexed(231),
+            info: Color::Indexed(231),
+        };
+
+        let panel = PanelColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            border_active: Color::Indexed(231),
+            header_bg: Color::Indexed(16),
+            header_bg_active: Color::Indexed(16),
+            header_text: Color::Indexed(231),
+            header_text_active: Color::Indexed(231),
+            file_text: Color::Indexed(231),
+            directory_text: Color::Indexed(231),
+            symlink_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+            marked_text: Color::Indexed(231),
+            size_text: Color::Indexed(231),
+            date_text: Color::Indexed(231),
+            remote_indicator: Color::Indexed(231),
+            git_badge_modified: Color::Indexed(231),
+            git_badge_staged: Color::Indexed(231),
+            git_badge_deleted: Color::Indexed(231),
+            git_badge_untracked: Color::Indexed(231),
+            git_badge_ignored: Color::Indexed(231),
+        };
+
+        let header = HeaderColors {
+            bg: Color::Indexed(16),
+            text: Color::Indexed(231),
+            title: Color::Indexed(231),
+        };
+
+        let status_bar = StatusBarColors {
+            bg: Color::Indexed(16),
+            text: Color::Indexed(231),
+            text_dim: Color::Indexed(231),
+        };
+
+        let function_bar = FunctionBarColors {
+            bg: Color::Indexed(16),
+            key: Color::Indexed(231),
+            label: Color::Indexed(231),
+        };
+
+        let message = MessageColors {
+            bg: Color::Indexed(16),
+            text: Color::Indexed(231),
+        };
+
+        let dialog = DialogColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            text: Color::Indexed(231),
+            text_dim: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            input_text: Color::Indexed(231),
+            input_cursor_fg: Color::Indexed(16),
+            input_cursor_bg: Color::Indexed(231),
+            input_prompt: Color::Indexed(231),
+            button_text: Color::Indexed(231),
+            button_selected_bg: Color::Indexed(231),
+            button_selected_text: Color::Indexed(16),
+            autocomplete_bg: Color::Indexed(16),
+            autocomplete_text: Color::Indexed(231),
+            autocomplete_directory_text: Color::Indexed(231),
+            autocomplete_selected_bg: Color::Indexed(231),
+            autocomplete_selected_text: Color::Indexed(16),
+            autocomplete_scroll_info: Color::Indexed(231),
+            preview_suffix_text: Color::Indexed(231),
+            help_key_text: Color::Indexed(231),
+            help_label_text: Color::Indexed(231),
+            progress_label_text: Color::Indexed(231),
+            progress_value_text: Color::Indexed(231),
+            progress_bar_fill: Color::Indexed(231),
+            progress_bar_empty: Color::Indexed(16),
+            progress_percent_text: Color::Indexed(231),
+            conflict_filename_text: Color::Indexed(231),
+            conflict_count_text: Color::Indexed(231),
+            conflict_shortcut_text: Color::Indexed(231),
+            tar_exclude_title: Color::Indexed(231),
+            tar_exclude_border: Color::Indexed(231),
+            tar_exclude_bg: Color::Indexed(16),
+            tar_exclude_message_text: Color::Indexed(231),
+            tar_exclude_path_text: Color::Indexed(231),
+            tar_exclude_scroll_info: Color::Indexed(231),
+            tar_exclude_button_text: Color::Indexed(231),
+            tar_exclude_button_selected_bg: Color::Indexed(231),
+            tar_exclude_button_selected_text: Color::Indexed(16),
+
+            // === Git Log Diff ===
+            git_log_diff_title: Color::Indexed(231),
+            git_log_diff_border: Color::Indexed(231),
+            git_log_diff_bg: Color::Indexed(16),
+            git_log_diff_message_text: Color::Indexed(231),
+            git_log_diff_entry_text: Color::Indexed(231),
+            git_log_diff_selected_text: Color::Indexed(231),
+            git_log_diff_cursor_text: Color::Indexed(16),
+            git_log_diff_cursor_bg: Color::Indexed(231),
+            git_log_diff_button_text: Color::Indexed(231),
+            git_log_diff_button_selected_text: Color::Indexed(16),
+            git_log_diff_button_selected_bg: Color::Indexed(231),
+            git_log_diff_button_disabled_text: Color::Indexed(231),
+            git_log_diff_scroll_info: Color::Indexed(231),
+
+            // === Open With ===
+            open_with_title: Color::Indexed(231),
+            open_with_border: Color::Indexed(231),
+            open_with_bg: Color::Indexed(16),
+            open_with_input_text: Color::Indexed(231),
+            open_with_entry_text: Color::Indexed(231),
+            open_with_recent_text: Color::Indexed(231),
+            open_with_preferred_text: Color::Indexed(231),
+            open_with_command_text: Color::Indexed(231),
+            open_with_cursor_text: Color::Indexed(16),
+            open_with_cursor_bg: Color::Indexed(231),
+            open_with_scroll_info: Color::Indexed(231),
+            remote_bookmark_text: Color::Indexed(231),
+            remote_connect_field_label: Color::Indexed(231),
+            remote_connect_field_value: Color::Indexed(231),
+            remote_connect_field_selected_bg: Color::Indexed(231),
+            jump_title: Color::Indexed(231),
+            jump_border: Color::Indexed(231),
+            jump_bg: Color::Indexed(16),
+            jump_input_text: Color::Indexed(231),
+            jump_entry_text: Color::Indexed(231),
+            jump_cursor_text: Color::Indexed(16),
+            jump_cursor_bg: Color::Indexed(231),
+            jump_scroll_info: Color::Indexed(231),
+            jump_hint_text: Color::Indexed(231),
+            recent_files_title: Color::Indexed(231),
+            recent_files_border: Color::Indexed(231),
+            recent_files_bg: Color::Indexed(16),
+            recent_files_input_text: Color::Indexed(231),
+            recent_files_entry_text: Color::Indexed(231),
+            recent_files_edited_text: Color::Indexed(231),
+            recent_files_cursor_text: Color::Indexed(16),
+            recent_files_cursor_bg: Color::Indexed(231),
+            recent_files_scroll_info: Color::Indexed(231),
+            recent_files_hint_text: Color::Indexed(231),
+            breadcrumb_title: Color::Indexed(231),
+            breadcrumb_border: Color::Indexed(231),
+            breadcrumb_bg: Color::Indexed(16),
+            breadcrumb_path_text: Color::Indexed(231),
+            breadcrumb_separator: Color::Indexed(231),
+            breadcrumb_current_text: Color::Indexed(231),
+            breadcrumb_entry_text: Color::Indexed(231),
+            breadcrumb_cursor_text: Color::Indexed(16),
+            breadcrumb_cursor_bg: Color::Indexed(231),
+            breadcrumb_scroll_info: Color::Indexed(231),
+            breadcrumb_hint_text: Color::Indexed(231),
+            favorites_title: Color::Indexed(231),
+            favorites_border: Color::Indexed(231),
+            favorites_bg: Color::Indexed(16),
+            favorites_entry_text: Color::Indexed(231),
+            favorites_empty_slot_text: Color::Indexed(231),
+            favorites_cursor_text: Color::Indexed(16),
+            favorites_cursor_bg: Color::Indexed(231),
+            favorites_hint_text: Color::Indexed(231),
+            basket_title: Color::Indexed(231),
+            basket_border: Color::Indexed(231),
+            basket_bg: Color::Indexed(16),
+            basket_entry_text: Color::Indexed(231),
+            basket_cursor_text: Color::Indexed(16),
+            basket_cursor_bg: Color::Indexed(231),
+            basket_hint_text: Color::Indexed(231),
+            image_export_title: Color::Indexed(231),
+            image_export_border: Color::Indexed(231),
+            image_export_bg: Color::Indexed(16),
+            image_export_label_text: Color::Indexed(231),
+            image_export_value_text: Color::Indexed(231),
+            image_export_disabled_text: Color::Indexed(231),
+            image_export_cursor_text: Color::Indexed(16),
+            image_export_cursor_bg: Color::Indexed(231),
+            image_export_output_text: Color::Indexed(231),
+            image_export_hint_text: Color::Indexed(231),
+        };
+
+        let confirm_dialog = ConfirmDialogColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            button_text: Color::Indexed(231),
+            button_selected_bg: Color::Indexed(231),
+            button_selected_text: Color::Indexed(16),
+        };
+
+        let settings = SettingsColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            prompt: Color::Indexed(231),
+            value_text: Color::Indexed(16),
+            value_bg: Color::Indexed(231),
+            help_key: Color::Indexed(231),
+            help_text: Color::Indexed(231),
+        };
+
+        let editor = EditorColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            header_bg: Color::Indexed(16),
+            header_text: Color::Indexed(231),
+            header_info: Color::Indexed(231),
+            line_number: Color::Indexed(231),
+            text: Color::Indexed(231),
+            cursor: Color::Indexed(231),
+            selection_bg: Color::Indexed(231),
+            selection_text: Color::Indexed(16),
+            match_bg: Color::Indexed(231),
+            match_current_bg: Color::Indexed(231),
+            bracket_match: Color::Indexed(231),
+            modified_mark: Color::Indexed(231),
+            read_only_mark: Color::Indexed(231),
+            sudo_mark: Color::Indexed(231),
+            read_only_banner_text: Color::Indexed(16),
+            read_only_banner_bg: Color::Indexed(231),
+            footer_bg: Color::Indexed(16),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+            find_input_text: Color::Indexed(231),
+            find_option: Color::Indexed(231),
+            find_option_active: Color::Indexed(231),
+            wrap_indicator: Color::Indexed(231),
+            remote_path_text: Color::Indexed(231),
+        };
+
+        let syntax = SyntaxColors {
+            keyword: Color::Indexed(231),
+            type_name: Color::Indexed(231),
+            string: Color::Indexed(231),
+            number: Color::Indexed(231),
+            comment: Color::Indexed(231),
+            operator: Color::Indexed(231),
+            function: Color::Indexed(231),
+            macro_name: Color::Indexed(231),
+            attribute: Color::Indexed(231),
+            variable: Color::Indexed(231),
+            constant: Color::Indexed(231),
+            bracket: Color::Indexed(231),
+            normal: Color::Indexed(231),
+        };
+
+        let viewer = ViewerColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            header_text: Color::Indexed(231),
+            line_number: Color::Indexed(231),
+            text: Color::Indexed(231),
+            bookmark_indicator: Color::Indexed(231),
+            selection_fg: Color::Indexed(16),
+            selection_bg: Color::Indexed(231),
+            search_input_text: Color::Indexed(231),
+            search_cursor_fg: Color::Indexed(16),
+            search_cursor_bg: Color::Indexed(231),
+            search_match_current_bg: Color::Indexed(231),
+            search_match_current_fg: Color::Indexed(16),
+            search_match_other_bg: Color::Indexed(231),
+            search_match_other_fg: Color::Indexed(16),
+            search_info: Color::Indexed(231),
+            hex_offset: Color::Indexed(231),
+            hex_bytes: Color::Indexed(231),
+            hex_ascii: Color::Indexed(231),
+            wrap_indicator: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let process_manager = ProcessManagerColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            header_text: Color::Indexed(231),
+            column_header: Color::Indexed(231),
+            text: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+            cpu_high: Color::Indexed(231),
+            mem_high: Color::Indexed(231),
+            confirm_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let ai_screen = AIScreenColors {
+            bg: Color::Indexed(16),
+            history_border: Color::Indexed(231),
+            history_title: Color::Indexed(231),
+            history_placeholder: Color::Indexed(231),
+            history_scroll_info: Color::Indexed(231),
+            user_prefix: Color::Indexed(231),
+            assistant_prefix: Color::Indexed(231),
+            error_prefix: Color::Indexed(231),
+            system_prefix: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            input_border: Color::Indexed(231),
+            input_prompt: Color::Indexed(231),
+            input_text: Color::Indexed(231),
+            input_cursor_fg: Color::Indexed(16),
+            input_cursor_bg: Color::Indexed(231),
+            input_placeholder: Color::Indexed(231),
+            processing_spinner: Color::Indexed(231),
+            processing_text: Color::Indexed(231),
+            error_text: Color::Indexed(231),
+            tool_use_prefix: Color::Indexed(231),
+            tool_use_name: Color::Indexed(231),
+            tool_use_input: Color::Indexed(231),
+            tool_result_prefix: Color::Indexed(231),
+            tool_result_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let system_info = SystemInfoColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            section_title: Color::Indexed(231),
+            label: Color::Indexed(231),
+            value: Color::Indexed(231),
+            bar_fill: Color::Indexed(231),
+            bar_empty: Color::Indexed(16),
+            usage_low: Color::Indexed(231),
+            usage_medium: Color::Indexed(231),
+            usage_high: Color::Indexed(231),
+            tab_active: Color::Indexed(231),
+            disk_header: Color::Indexed(231),
+            disk_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let search_result = SearchResultColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            header_text: Color::Indexed(231),
+            column_header: Color::Indexed(231),
+            column_header_dim: Color::Indexed(231),
+            directory_text: Color::Indexed(231),
+            file_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+            match_highlight: Color::Indexed(231),
+            path_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let image_viewer = ImageViewerColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title_text: Color::Indexed(231),
+            loading_spinner: Color::Indexed(231),
+            loading_text: Color::Indexed(231),
+            error_text: Color::Indexed(231),
+            hint_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+            footer_separator: Color::Indexed(231),
+        };
+
+        let file_info = FileInfoColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label: Color::Indexed(231),
+            value: Color::Indexed(231),
+            value_name: Color::Indexed(231),
+            value_path: Color::Indexed(231),
+            value_type: Color::Indexed(231),
+            value_size: Color::Indexed(231),
+            value_permission: Color::Indexed(231),
+            value_owner: Color::Indexed(231),
+            value_date: Color::Indexed(231),
+            calculating_spinner: Color::Indexed(231),
+            calculating_text: Color::Indexed(231),
+            error_text: Color::Indexed(231),
+            hint_text: Color::Indexed(231),
+        };
+
+        let help = HelpColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            section_title: Color::Indexed(231),
+            section_decorator: Color::Indexed(231),
+            key: Color::Indexed(231),
+            key_highlight: Color::Indexed(231),
+            description: Color::Indexed(231),
+            hint_text: Color::Indexed(231),
+        };
+
+        let advanced_search = AdvancedSearchColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label: Color::Indexed(231),
+            input_text: Color::Indexed(231),
+            input_cursor: Color::Indexed(231),
+            field_bracket: Color::Indexed(231),
+            checkbox_checked: Color::Indexed(231),
+            checkbox_unchecked: Color::Indexed(231),
+            button_text: Color::Indexed(231),
+            button_selected_bg: Color::Indexed(231),
+            button_selected_text: Color::Indexed(16),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let diff = DiffColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            header_text: Color::Indexed(231),
+            header_label: Color::Indexed(231),
+            column_header_bg: Color::Indexed(16),
+            column_header_text: Color::Indexed(231),
+            same_text: Color::Indexed(231),
+            modified_text: Color::Indexed(231),
+            modified_bg: Color::Indexed(16),
+            left_only_text: Color::Indexed(231),
+            left_only_bg: Color::Indexed(16),
+            right_only_text: Color::Indexed(231),
+            right_only_bg: Color::Indexed(16),
+            empty_bg: Color::Indexed(16),
+            dir_same_text: Color::Indexed(231),
+            dir_modified_text: Color::Indexed(231),
+            cursor_bg: Color::Indexed(231),
+            cursor_text: Color::Indexed(16),
+            marked_text: Color::Indexed(231),
+            size_text: Color::Indexed(231),
+            date_text: Color::Indexed(231),
+            status_bar_bg: Color::Indexed(16),
+            status_bar_text: Color::Indexed(231),
+            filter_label: Color::Indexed(231),
+            stats_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+            panel_selected_border: Color::Indexed(231),
+            progress_spinner: Color::Indexed(231),
+            progress_bar_fill: Color::Indexed(231),
+            progress_bar_empty: Color::Indexed(16),
+            progress_percent_text: Color::Indexed(231),
+            progress_value_text: Color::Indexed(231),
+            progress_hint_text: Color::Indexed(231),
+        };
+
+        let diff_file_view = DiffFileViewColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            header_text: Color::Indexed(231),
+            line_number: Color::Indexed(231),
+            same_text: Color::Indexed(231),
+            modified_text: Color::Indexed(231),
+            modified_bg: Color::Indexed(16),
+            left_only_text: Color::Indexed(231),
+            left_only_bg: Color::Indexed(16),
+            right_only_text: Color::Indexed(231),
+            right_only_bg: Color::Indexed(16),
+            empty_bg: Color::Indexed(16),
+            inline_change_bg: Color::Indexed(231),
+            inline_change_text: Color::Indexed(16),
+            status_bar_bg: Color::Indexed(16),
+            status_bar_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let git_screen = GitScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            header_branch: Color::Indexed(231),
+            header_path: Color::Indexed(231),
+            tab_active: Color::Indexed(231),
+            tab_inactive: Color::Indexed(231),
+            tab_bar_bg: Color::Indexed(16),
+            file_staged: Color::Indexed(231),
+            file_modified: Color::Indexed(231),
+            file_untracked: Color::Indexed(231),
+            file_deleted: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+            commit_input_border: Color::Indexed(231),
+            commit_input_text: Color::Indexed(231),
+            log_hash: Color::Indexed(231),
+            log_message: Color::Indexed(231),
+            log_author: Color::Indexed(231),
+            log_date: Color::Indexed(231),
+            branch_current: Color::Indexed(231),
+            branch_normal: Color::Indexed(231),
+            diff_add: Color::Indexed(231),
+            diff_remove: Color::Indexed(231),
+            diff_header: Color::Indexed(231),
+        };
+
+        let dedup_screen = DedupScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            phase_text: Color::Indexed(231),
+            stats_text: Color::Indexed(231),
+            progress_bar_fill: Color::Indexed(231),
+            progress_bar_empty: Color::Indexed(16),
+            progress_text: Color::Indexed(231),
+            log_text: Color::Indexed(231),
+            log_text_alt: Color::Indexed(231),
+            log_deleted: Color::Indexed(231),
+            log_error: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let checksum_screen = ChecksumScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            target_text: Color::Indexed(231),
+            phase_text: Color::Indexed(231),
+            progress_text: Color::Indexed(231),
+            name_text: Color::Indexed(231),
+            hash_text: Color::Indexed(231),
+            ok_text: Color::Indexed(231),
+            mismatch_text: Color::Indexed(231),
+            missing_text: Color::Indexed(231),
+            error_text: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let audit_screen = AuditScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            path_text: Color::Indexed(231),
+            count_text: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            time_text: Color::Indexed(231),
+            user_text: Color::Indexed(231),
+            summary_text: Color::Indexed(231),
+            kind_rejected: Color::Indexed(231),
+            kind_shell: Color::Indexed(231),
+            kind_transfer: Color::Indexed(231),
+            kind_other: Color::Indexed(231),
+            value_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let archive_viewer = ArchiveViewerColors {
+            bg: Color::Indexed(16),
+            info_border: Color::Indexed(231),
+            list_border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            path_text: Color::Indexed(231),
+            count_text: Color::Indexed(231),
+            arrow_text: Color::Indexed(231),
+            dest_text: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            directory_text: Color::Indexed(231),
+            file_text: Color::Indexed(231),
+            marked_text: Color::Indexed(231),
+            size_text: Color::Indexed(231),
+            date_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+            message_text: Color::Indexed(231),
+            footer_bg: Color::Indexed(16),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let trash_screen = TrashScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            path_text: Color::Indexed(231),
+            count_text: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            date_text: Color::Indexed(231),
+            size_text: Color::Indexed(231),
+            directory_text: Color::Indexed(231),
+            file_text: Color::Indexed(231),
+            origin_text: Color::Indexed(231),
+            confirm_text: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let jobs_screen = JobsScreenColors {
+            summary_text: Color::Indexed(231),
+            summary_bg: Color::Indexed(16),
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            border_active: Color::Indexed(231),
+            title: Color::Indexed(231),
+            id_text: Color::Indexed(231),
+            running_text: Color::Indexed(231),
+            done_text: Color::Indexed(231),
+            failed_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+        };
+
+        let bulk_rename = BulkRenameColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            label_active: Color::Indexed(231),
+            input_text: Color::Indexed(231),
+            input_cursor: Color::Indexed(231),
+            hint_text: Color::Indexed(231),
+            list_border_active: Color::Indexed(231),
+            old_name: Color::Indexed(231),
+            arrow_text: Color::Indexed(231),
+            new_name: Color::Indexed(231),
+            unchanged_name: Color::Indexed(231),
+            invalid_name: Color::Indexed(231),
+            edit_text: Color::Indexed(231),
+            status_text: Color::Indexed(231),
+            error_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let preview_pane = PreviewPaneColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            text: Color::Indexed(231),
+            dim_text: Color::Indexed(231),
+            directory_text: Color::Indexed(231),
+            size_text: Color::Indexed(231),
+        };
+
+        let drives_screen = DrivesScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            mount_text: Color::Indexed(231),
+            bar_fill: Color::Indexed(231),
+            bar_full: Color::Indexed(231),
+            bar_empty: Color::Indexed(16),
+            percent_text: Color::Indexed(231),
+            space_text: Color::Indexed(231),
+            unavailable_text: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            value_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let recovery_screen = RecoveryScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            intro_text: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            tag_text: Color::Indexed(231),
+            item_text: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            partial_path: Color::Indexed(231),
+            hint_text: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let tree_sidebar = TreeSidebarColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            border_active: Color::Indexed(231),
+            title: Color::Indexed(231),
+            title_active: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            directory_text: Color::Indexed(231),
+            cursor_text: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+        };
+
+        let cleanup_screen = CleanupScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            target_text: Color::Indexed(231),
+            phase_text: Color::Indexed(231),
+            found_size: Color::Indexed(231),
+            marked_size: Color::Indexed(231),
+            freed_size: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            arrow_text: Color::Indexed(231),
+            check_mark: Color::Indexed(231),
+            category_size: Color::Indexed(231),
+            category_name: Color::Indexed(231),
+            category_count: Color::Indexed(231),
+            item_size: Color::Indexed(231),
+            item_path: Color::Indexed(231),
+            confirm_text: Color::Indexed(231),
+            progress_text: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let backup_screen = BackupScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            label_text: Color::Indexed(231),
+            value_text: Color::Indexed(231),
+            dim_text: Color::Indexed(231),
+            ok_text: Color::Indexed(231),
+            error_text: Color::Indexed(231),
+            form_selected: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let timeline_screen = TimelineScreenColors {
+            bg: Color::Indexed(16),
+            border: Color::Indexed(231),
+            title: Color::Indexed(231),
+            root_text: Color::Indexed(231),
+            window_text: Color::Indexed(231),
+            info_text: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            age_text: Color::Indexed(231),
+            time_text: Color::Indexed(231),
+            size_text: Color::Indexed(231),
+            path_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let git_log_screen = GitLogScreenColors {
+            bg: Color::Indexed(16),
+            header_path: Color::Indexed(231),
+            header_scope: Color::Indexed(231),
+            graph: Color::Indexed(231),
+            marker: Color::Indexed(231),
+            log_hash: Color::Indexed(231),
+            refs: Color::Indexed(231),
+            log_subject: Color::Indexed(231),
+            log_author: Color::Indexed(231),
+            log_date: Color::Indexed(231),
+            selected_bg: Color::Indexed(231),
+            selected_text: Color::Indexed(16),
+            detail_subject: Color::Indexed(231),
+            file_name: Color::Indexed(231),
+            file_added: Color::Indexed(231),
+            file_deleted: Color::Indexed(231),
+            file_renamed: Color::Indexed(231),
+            file_modified: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
+            message_text: Color::Indexed(231),
+            footer_key: Color::Indexed(231),
+            footer_text: Color::Indexed(231),
+        };
+
+        let favorites_bar = FavoritesBarColors {
+            key: Color::Indexed(231),
+            label: Color::Indexed(231),
+            current: Color::Indexed(231),
+            empty_text: Color::Indexed(231),
         };
 
         Self {
@@ -2283,6 +5431,21 @@ impl Theme {
             diff_file_view,
             git_screen,
             dedup_screen,
+            checksum_screen,
+            audit_screen,
+            archive_viewer,
+            trash_screen,
+            jobs_screen,
+            bulk_rename,
+            preview_pane,
+            drives_screen,
+            recovery_screen,
+            tree_sidebar,
+            cleanup_screen,
+            backup_screen,
+            timeline_screen,
+            git_log_screen,
+            favorites_bar,
             chars: ThemeChars::default(),
         }
     }
@@ -2380,6 +5543,12 @@ impl Theme {
         // dawn of coding: bg=234, accent=110, shortcut=146
         if bg == 234 && accent == 110 && shortcut == 146 {
             "dawn_of_coding"
+        } else if bg == 16 && accent == 51 && shortcut == 226 {
+            // high contrast: bg=16, accent=51, shortcut=226
+            "high_contrast"
+        } else if bg == 16 && accent == 231 && shortcut == 231 {
+            // monochrome: 검정 배경 + 흰색만 사용
+            "monochrome"
         } else if bg >= 250 {
             "light"
         } else {
@@ -2462,7 +5631,17 @@ impl Theme {
     "__date_text__": "수정일 컬럼의 텍스트 색상. bg 위에 표시됨. size_text와 유사한 시각적 우선순위. 보조 정보로서 파일명보다 덜 강조됨. palette.fg_dim 참조",
     "date_text": {},
     "__remote_indicator__": "원격 패널 [SSH] 인디케이터 색상. 패널 헤더에서 원격 연결 상태를 나타내는 텍스트 색상",
-    "remote_indicator": {}
+    "remote_indicator": {},
+    "__git_badge_modified__": "git 배지: 수정됨 (M)",
+    "git_badge_modified": {},
+    "__git_badge_staged__": "git 배지: 추가/이름 변경 (A, R)",
+    "git_badge_staged": {},
+    "__git_badge_deleted__": "git 배지: 삭제/충돌 (D, U)",
+    "git_badge_deleted": {},
+    "__git_badge_untracked__": "git 배지: 추적 안 됨 (??)",
+    "git_badge_untracked": {},
+    "__git_badge_ignored__": "git 배지: 무시됨 (!!)",
+    "git_badge_ignored": {}
   }},
 
   "__header__": "=== 앱 헤더: 화면 최상단의 앱 제목 및 브랜딩 영역. 항상 표시되는 고정 요소 ===",
@@ -2609,6 +5788,28 @@ impl Theme {
     "git_log_diff_button_disabled_text": {},
     "__git_log_diff_scroll_info__": "Git Log Diff 다이얼로그의 스크롤 정보. git_log_diff_bg 위에 표시됨",
     "git_log_diff_scroll_info": {},
+    "__open_with_title__": "Open With 다이얼로그의 제목. open_with_bg 위에 표시됨",
+    "open_with_title": {},
+    "__open_with_border__": "Open With 다이얼로그의 테두리. open_with_bg를 둘러쌈",
+    "open_with_border": {},
+    "__open_with_bg__": "Open With 다이얼로그의 배경색",
+    "open_with_bg": {},
+    "__open_with_input_text__": "Open With 다이얼로그의 필터/명령 입력 텍스트. open_with_bg 위에 표시됨",
+    "open_with_input_text": {},
+    "__open_with_entry_text__": "Open With 다이얼로그의 애플리케이션 항목 텍스트. open_with_bg 위에 표시됨",
+    "open_with_entry_text": {},
+    "__open_with_recent_text__": "Open With 다이얼로그에서 이 확장자에 최근 사용한 명령 텍스트. open_with_bg 위에 표시됨",
+    "open_with_recent_text": {},
+    "__open_with_preferred_text__": "Open With 다이얼로그에서 파일의 MIME 타입을 지원하는 애플리케이션 텍스트. open_with_bg 위에 표시됨",
+    "open_with_preferred_text": {},
+    "__open_with_command_text__": "Open With 다이얼로그 하단의 선택 항목 명령어 미리보기. open_with_bg 위에 표시됨",
+    "open_with_command_text": {},
+    "__open_with_cursor_text__": "Open With 다이얼로그에서 현재 커서 위치의 텍스트. open_with_cursor_bg 위에 표시됨",
+    "open_with_cursor_text": {},
+    "__open_with_cursor_bg__": "Open With 다이얼로그에서 현재 커서 위치의 배경색",
+    "open_with_cursor_bg": {},
+    "__open_with_scroll_info__": "Open With 다이얼로그의 스크롤 정보. open_with_bg 위에 표시됨",
+    "open_with_scroll_info": {},
     "__remote_bookmark_text__": "북마크 목록 내 원격 프로필 텍스트 색상. 로컬 북마크와 시각적으로 구분하기 위한 색상",
     "remote_bookmark_text": {},
     "__remote_connect_field_label__": "원격 연결 다이얼로그의 필드 레이블 (Host:, Port:, User: 등)",
@@ -2616,7 +5817,117 @@ impl Theme {
     "__remote_connect_field_value__": "원격 연결 다이얼로그의 필드 값 텍스트",
     "remote_connect_field_value": {},
     "__remote_connect_field_selected_bg__": "원격 연결 다이얼로그에서 선택된 필드의 배경색",
-    "remote_connect_field_selected_bg": {}
+    "remote_connect_field_selected_bg": {},
+    "__jump_title__": "Jump 다이얼로그의 제목. jump_bg 위에 표시됨",
+    "jump_title": {},
+    "__jump_border__": "Jump 다이얼로그의 테두리. jump_bg를 둘러쌈",
+    "jump_border": {},
+    "__jump_bg__": "Jump 다이얼로그의 배경색",
+    "jump_bg": {},
+    "__jump_input_text__": "Jump 다이얼로그의 필터 입력 텍스트",
+    "jump_input_text": {},
+    "__jump_entry_text__": "Jump 다이얼로그의 폴더 목록 텍스트",
+    "jump_entry_text": {},
+    "__jump_cursor_text__": "Jump 다이얼로그에서 커서가 있는 항목의 텍스트",
+    "jump_cursor_text": {},
+    "__jump_cursor_bg__": "Jump 다이얼로그에서 커서가 있는 항목의 배경",
+    "jump_cursor_bg": {},
+    "__jump_scroll_info__": "Jump 다이얼로그의 스크롤 정보. jump_bg 위에 표시됨",
+    "jump_scroll_info": {},
+    "__jump_hint_text__": "Jump 다이얼로그 하단의 단축키 안내",
+    "jump_hint_text": {},
+    "__recent_files_title__": "최근 파일 다이얼로그의 제목. recent_files_bg 위에 표시됨",
+    "recent_files_title": {},
+    "__recent_files_border__": "최근 파일 다이얼로그의 테두리. recent_files_bg를 둘러쌈",
+    "recent_files_border": {},
+    "__recent_files_bg__": "최근 파일 다이얼로그의 배경색",
+    "recent_files_bg": {},
+    "__recent_files_input_text__": "최근 파일 다이얼로그의 필터 입력 텍스트",
+    "recent_files_input_text": {},
+    "__recent_files_entry_text__": "최근 파일 다이얼로그의 파일 목록 텍스트",
+    "recent_files_entry_text": {},
+    "__recent_files_edited_text__": "최근 파일 다이얼로그에서 편집기로 연 파일의 텍스트",
+    "recent_files_edited_text": {},
+    "__recent_files_cursor_text__": "최근 파일 다이얼로그에서 커서가 있는 항목의 텍스트",
+    "recent_files_cursor_text": {},
+    "__recent_files_cursor_bg__": "최근 파일 다이얼로그에서 커서가 있는 항목의 배경",
+    "recent_files_cursor_bg": {},
+    "__recent_files_scroll_info__": "최근 파일 다이얼로그의 스크롤 정보. recent_files_bg 위에 표시됨",
+    "recent_files_scroll_info": {},
+    "__recent_files_hint_text__": "최근 파일 다이얼로그 하단의 단축키 안내",
+    "recent_files_hint_text": {},
+    "__breadcrumb_title__": "경로 메뉴 다이얼로그의 제목. breadcrumb_bg 위에 표시됨",
+    "breadcrumb_title": {},
+    "__breadcrumb_border__": "경로 메뉴 다이얼로그의 테두리. breadcrumb_bg를 둘러쌈",
+    "breadcrumb_border": {},
+    "__breadcrumb_bg__": "경로 메뉴 다이얼로그의 배경색",
+    "breadcrumb_bg": {},
+    "__breadcrumb_path_text__": "경로 메뉴 다이얼로그 상단 경로의 폴더 이름",
+    "breadcrumb_path_text": {},
+    "__breadcrumb_separator__": "경로 메뉴 다이얼로그 상단 경로의 구분자 (/)",
+    "breadcrumb_separator": {},
+    "__breadcrumb_current_text__": "경로 메뉴 다이얼로그 목록에서 현재 경로에 속한 폴더",
+    "breadcrumb_current_text": {},
+    "__breadcrumb_entry_text__": "경로 메뉴 다이얼로그의 폴더 목록 텍스트",
+    "breadcrumb_entry_text": {},
+    "__breadcrumb_cursor_text__": "경로 메뉴 다이얼로그에서 선택된 경로 단계와 커서 항목의 텍스트",
+    "breadcrumb_cursor_text": {},
+    "__breadcrumb_cursor_bg__": "경로 메뉴 다이얼로그에서 선택된 경로 단계와 커서 항목의 배경",
+    "breadcrumb_cursor_bg": {},
+    "__breadcrumb_scroll_info__": "경로 메뉴 다이얼로그의 스크롤 정보. breadcrumb_bg 위에 표시됨",
+    "breadcrumb_scroll_info": {},
+    "__breadcrumb_hint_text__": "경로 메뉴 다이얼로그 하단의 단축키 안내",
+    "breadcrumb_hint_text": {},
+    "__favorites_title__": "즐겨찾기 다이얼로그의 제목. favorites_bg 위에 표시됨",
+    "favorites_title": {},
+    "__favorites_border__": "즐겨찾기 다이얼로그의 테두리. favorites_bg를 둘러쌈",
+    "favorites_border": {},
+    "__favorites_bg__": "즐겨찾기 다이얼로그의 배경색",
+    "favorites_bg": {},
+    "__favorites_entry_text__": "즐겨찾기 다이얼로그의 즐겨찾기 경로 텍스트",
+    "favorites_entry_text": {},
+    "__favorites_empty_slot_text__": "즐겨찾기 다이얼로그에서 비어 있는 슬롯 번호",
+    "favorites_empty_slot_text": {},
+    "__favorites_cursor_text__": "즐겨찾기 다이얼로그에서 커서가 있는 항목의 텍스트",
+    "favorites_cursor_text": {},
+    "__favorites_cursor_bg__": "즐겨찾기 다이얼로그에서 커서가 있는 항목의 배경",
+    "favorites_cursor_bg": {},
+    "__favorites_hint_text__": "즐겨찾기 다이얼로그 하단의 단축키 안내",
+    "favorites_hint_text": {},
+    "__basket_title__": "바구니 다이얼로그의 제목. basket_bg 위에 표시됨",
+    "basket_title": {},
+    "__basket_border__": "바구니 다이얼로그의 테두리. basket_bg를 둘러쌈",
+    "basket_border": {},
+    "__basket_bg__": "바구니 다이얼로그의 배경색",
+    "basket_bg": {},
+    "__basket_entry_text__": "바구니 다이얼로그에 담긴 파일 경로 텍스트",
+    "basket_entry_text": {},
+    "__basket_cursor_text__": "바구니 다이얼로그에서 커서가 있는 항목의 텍스트",
+    "basket_cursor_text": {},
+    "__basket_cursor_bg__": "바구니 다이얼로그에서 커서가 있는 항목의 배경",
+    "basket_cursor_bg": {},
+    "__basket_hint_text__": "바구니 다이얼로그 하단의 단축키 안내",
+    "basket_hint_text": {},
+    "__image_export_title__": "이미지 저장 다이얼로그의 제목. image_export_bg 위에 표시됨",
+    "image_export_title": {},
+    "__image_export_border__": "이미지 저장 다이얼로그의 테두리. image_export_bg를 둘러쌈",
+    "image_export_border": {},
+    "__image_export_bg__": "이미지 저장 다이얼로그의 배경색",
+    "image_export_bg": {},
+    "__image_export_label_text__": "이미지 저장 다이얼로그의 항목 이름 (Format, Quality, Size)",
+    "image_export_label_text": {},
+    "__image_export_value_text__": "이미지 저장 다이얼로그의 항목 값",
+    "image_export_value_text": {},
+    "__image_export_disabled_text__": "이미지 저장 다이얼로그에서 선택한 형식이 지원하지 않는 항목 값 (예: PNG의 품질)",
+    "image_export_disabled_text": {},
+    "__image_export_cursor_text__": "이미지 저장 다이얼로그에서 커서가 있는 값의 텍스트",
+    "image_export_cursor_text": {},
+    "__image_export_cursor_bg__": "이미지 저장 다이얼로그에서 커서가 있는 값의 배경",
+    "image_export_cursor_bg": {},
+    "__image_export_output_text__": "이미지 저장 다이얼로그에서 저장될 파일 이름 미리보기",
+    "image_export_output_text": {},
+    "__image_export_hint_text__": "이미지 저장 다이얼로그 하단의 단축키 안내",
+    "image_export_hint_text": {}
   }},
 
   "__confirm_dialog__": "=== 확인 다이얼로그: 파일 삭제, 덮어쓰기, 대용량 파일 열기 등 사용자 확인이 필요한 작업의 모달 ===",
@@ -2689,6 +6000,14 @@ impl Theme {
     "bracket_match": {},
     "__modified_mark__": "수정됨 표시(*) 색상. header 영역에서 파일이 수정되었음을 나타내는 표시. header_bg 위에 표시됨. 저장하지 않은 변경사항이 있음을 주의 환기",
     "modified_mark": {},
+    "__read_only_mark__": "읽기 전용 파일 표시([Read-only]) 색상. header 영역에서 쓰기 권한이 없어 읽기 전용으로 열렸음을 표시",
+    "read_only_mark": {},
+    "__sudo_mark__": "권한 상승 저장 표시([sudo]) 색상. header 영역에서 저장 시 sudo를 사용함을 표시",
+    "sudo_mark": {},
+    "__read_only_banner_text__": "읽기 전용 파일의 footer 배너(READ-ONLY) 텍스트 색상",
+    "read_only_banner_text": {},
+    "__read_only_banner_bg__": "읽기 전용 파일의 footer 배너(READ-ONLY) 배경색",
+    "read_only_banner_bg": {},
     "__footer_bg__": "에디터 하단바의 배경색. 단축키 안내와 검색/바꾸기 UI가 표시되는 영역. bg, header_bg와 구분되어 푸터 영역임을 인식. palette.bg_alt 참조 가능",
     "footer_bg": {},
     "__footer_key__": "하단바의 단축키 텍스트(Ctrl+S, Ctrl+F 등). footer_bg 위에 표시됨. footer_text와 쌍을 이루며 키 부분을 강조. palette.shortcut 참조",
@@ -2751,6 +6070,10 @@ impl Theme {
     "text": {},
     "__bookmark_indicator__": "북마크된 줄을 표시하는 인디케이터 색상. line_number 영역 또는 줄 배경에 표시됨. 사용자가 표시해둔 위치를 즉시 인식할 수 있어야 함",
     "bookmark_indicator": {},
+    "__selection_fg__": "줄 선택 모드(v)에서 선택된 줄의 줄 번호 전경색. selection_bg 위에 표시됨",
+    "selection_fg": {},
+    "__selection_bg__": "줄 선택 모드(v)에서 선택된 줄의 줄 번호 배경색. 클립보드로 복사될 범위를 표시",
+    "selection_bg": {},
     "__search_input_text__": "검색 입력 필드의 텍스트 색상. 하단 검색 UI에서 사용자가 입력한 검색어. bg 또는 별도 입력 영역 위에 표시됨",
     "search_input_text": {},
     "__search_cursor_fg__": "검색 입력 커서의 전경색. search_cursor_bg와 함께 현재 입력 위치 표시",
@@ -3245,6 +6568,502 @@ impl Theme {
     "footer_key": {},
     "__footer_text__": "기능 바 설명",
     "footer_text": {}
+  }},
+
+  "__checksum_screen__": "=== 체크섬 화면: 파일 해시 계산, 체크섬 파일 검증, 파일 간 비교 UI ===",
+  "checksum_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__label_text__": "정보 영역 레이블 텍스트 (Target, Progress 등)",
+    "label_text": {},
+    "__target_text__": "대상 경로 텍스트",
+    "target_text": {},
+    "__phase_text__": "알고리즘/진행 단계 텍스트",
+    "phase_text": {},
+    "__progress_text__": "진행률 텍스트",
+    "progress_text": {},
+    "__name_text__": "결과 목록의 파일 이름",
+    "name_text": {},
+    "__hash_text__": "결과 목록의 해시 값",
+    "hash_text": {},
+    "__ok_text__": "검증 일치(OK) 및 IDENTICAL 결과",
+    "ok_text": {},
+    "__mismatch_text__": "검증 불일치(MISMATCH) 및 DIFFERENT 결과",
+    "mismatch_text": {},
+    "__missing_text__": "누락된 파일(MISSING) 결과",
+    "missing_text": {},
+    "__error_text__": "해시 계산 실패 및 에러 개수",
+    "error_text": {},
+    "__message_text__": "기능 바의 상태 메시지",
+    "message_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__audit_screen__": "=== 봇 감사 로그 화면: 텔레그램 봇 활동 기록을 보여주는 전체화면 UI ===",
+  "audit_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__label_text__": "레이블 텍스트 (Log, User, Files 등)",
+    "label_text": {},
+    "__path_text__": "로그 파일 경로",
+    "path_text": {},
+    "__count_text__": "항목 개수 표시",
+    "count_text": {},
+    "__empty_text__": "기록이 없을 때 안내 텍스트",
+    "empty_text": {},
+    "__time_text__": "항목 시각",
+    "time_text": {},
+    "__user_text__": "항목 사용자 ID",
+    "user_text": {},
+    "__summary_text__": "항목 요약",
+    "summary_text": {},
+    "__kind_rejected__": "거부된 요청 종류 표시",
+    "kind_rejected": {},
+    "__kind_shell__": "셸 명령 종류 표시",
+    "kind_shell": {},
+    "__kind_transfer__": "업로드/다운로드 종류 표시",
+    "kind_transfer": {},
+    "__kind_other__": "기타 종류 표시",
+    "kind_other": {},
+    "__value_text__": "상세 정보 값",
+    "value_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__archive_viewer__": "=== 압축 파일 뷰어: 압축을 풀지 않고 아카이브 내용을 탐색하고 선택 항목을 추출하는 UI ===",
+  "archive_viewer": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__info_border__": "상단 정보 영역 테두리",
+    "info_border": {},
+    "__list_border__": "항목 목록 테두리",
+    "list_border": {},
+    "__title__": "아카이브 이름 제목",
+    "title": {},
+    "__path_text__": "아카이브 내부 현재 경로",
+    "path_text": {},
+    "__count_text__": "항목/마킹 개수 표시",
+    "count_text": {},
+    "__arrow_text__": "추출 대상 화살표(→)",
+    "arrow_text": {},
+    "__dest_text__": "추출 대상 경로",
+    "dest_text": {},
+    "__empty_text__": "빈 폴더 안내 텍스트",
+    "empty_text": {},
+    "__directory_text__": "디렉토리 항목 이름",
+    "directory_text": {},
+    "__file_text__": "파일 항목 이름",
+    "file_text": {},
+    "__marked_text__": "마킹된 항목 및 마킹 표시(*)",
+    "marked_text": {},
+    "__size_text__": "항목 크기",
+    "size_text": {},
+    "__date_text__": "항목 수정 시각",
+    "date_text": {},
+    "__selected_bg__": "선택된 항목 배경",
+    "selected_bg": {},
+    "__selected_text__": "선택된 항목 텍스트",
+    "selected_text": {},
+    "__message_text__": "기능 바의 상태 메시지",
+    "message_text": {},
+    "__footer_bg__": "기능 바 배경",
+    "footer_bg": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__trash_screen__": "=== 휴지통 화면: 휴지통 항목을 복원하거나 영구 삭제하는 전체화면 UI ===",
+  "trash_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__label_text__": "레이블 텍스트 (Location, From)",
+    "label_text": {},
+    "__path_text__": "휴지통 경로",
+    "path_text": {},
+    "__count_text__": "항목 개수/전체 크기 표시",
+    "count_text": {},
+    "__empty_text__": "휴지통이 비었을 때 안내 텍스트",
+    "empty_text": {},
+    "__date_text__": "삭제 시각",
+    "date_text": {},
+    "__size_text__": "항목 크기",
+    "size_text": {},
+    "__directory_text__": "디렉토리 항목 이름",
+    "directory_text": {},
+    "__file_text__": "파일 항목 이름",
+    "file_text": {},
+    "__origin_text__": "원래 경로",
+    "origin_text": {},
+    "__confirm_text__": "영구 삭제 확인 메시지",
+    "confirm_text": {},
+    "__message_text__": "작업 결과 메시지",
+    "message_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__jobs_screen__": "=== 작업 창: 백그라운드 복사/이동 작업 목록을 보여주는 하단 창 ===",
+  "jobs_screen": {{
+    "__summary_text__": "접힌 상태의 작업 요약 텍스트",
+    "summary_text": {},
+    "__summary_bg__": "접힌 상태의 작업 요약 배경",
+    "summary_bg": {},
+    "__bg__": "펼친 상태의 배경색",
+    "bg": {},
+    "__border__": "포커스가 없을 때 테두리",
+    "border": {},
+    "__border_active__": "포커스가 있을 때 테두리",
+    "border_active": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__id_text__": "작업 번호(#N)",
+    "id_text": {},
+    "__running_text__": "진행 중인 작업",
+    "running_text": {},
+    "__done_text__": "성공적으로 끝난 작업",
+    "done_text": {},
+    "__failed_text__": "실패가 있는 작업",
+    "failed_text": {},
+    "__selected_bg__": "선택된 작업 배경",
+    "selected_bg": {},
+    "__selected_text__": "선택된 작업 텍스트",
+    "selected_text": {}
+  }},
+
+  "__bulk_rename__": "=== 일괄 이름 변경 화면: 패턴/정규식 규칙으로 여러 파일 이름을 미리보며 바꾸는 UI ===",
+  "bulk_rename": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__label_text__": "규칙 입력란 레이블",
+    "label_text": {},
+    "__label_active__": "포커스된 입력란 레이블",
+    "label_active": {},
+    "__input_text__": "규칙 입력 텍스트",
+    "input_text": {},
+    "__input_cursor__": "입력 커서(_)",
+    "input_cursor": {},
+    "__hint_text__": "입력란 도움말",
+    "hint_text": {},
+    "__list_border_active__": "포커스된 미리보기 목록 테두리",
+    "list_border_active": {},
+    "__old_name__": "현재 이름",
+    "old_name": {},
+    "__arrow_text__": "이름 변경 화살표(→)와 직접 수정 표시(*)",
+    "arrow_text": {},
+    "__new_name__": "바뀔 새 이름",
+    "new_name": {},
+    "__unchanged_name__": "바뀌지 않는 이름",
+    "unchanged_name": {},
+    "__invalid_name__": "사용할 수 없는 새 이름",
+    "invalid_name": {},
+    "__edit_text__": "직접 수정 중인 이름",
+    "edit_text": {},
+    "__status_text__": "변경될 파일 수 안내",
+    "status_text": {},
+    "__error_text__": "정규식/작업 에러 메시지",
+    "error_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__preview_pane__": "=== 미리보기 창: 반대편 패널 자리에 커서 위치 파일의 내용을 보여주는 Quick view 창 ===",
+  "preview_pane": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트 (Quick view: 파일명)",
+    "title": {},
+    "__text__": "일반 텍스트 및 파일 이름",
+    "text": {},
+    "__dim_text__": "로딩/안내 메시지 등 보조 텍스트",
+    "dim_text": {},
+    "__directory_text__": "디렉토리 이름",
+    "directory_text": {},
+    "__size_text__": "압축 파일 항목 크기",
+    "size_text": {}
+  }},
+
+  "__drives_screen__": "=== 드라이브 화면: 마운트된 파일시스템과 사용량을 보여주는 전체화면 UI ===",
+  "drives_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__empty_text__": "마운트 목록이 비었을 때 안내 텍스트",
+    "empty_text": {},
+    "__mount_text__": "마운트 지점 경로",
+    "mount_text": {},
+    "__bar_fill__": "사용량 막대 채움",
+    "bar_fill": {},
+    "__bar_full__": "90% 이상 사용 중인 사용량 막대 채움",
+    "bar_full": {},
+    "__bar_empty__": "사용량 막대 빈 영역",
+    "bar_empty": {},
+    "__percent_text__": "사용률(%) 텍스트",
+    "percent_text": {},
+    "__space_text__": "여유 공간/전체 용량 텍스트",
+    "space_text": {},
+    "__unavailable_text__": "용량을 알 수 없을 때 안내 텍스트",
+    "unavailable_text": {},
+    "__label_text__": "상세 정보 레이블 (Device, Type)",
+    "label_text": {},
+    "__value_text__": "상세 정보 값",
+    "value_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__recovery_screen__": "=== 복구 화면: 비정상 종료된 세션의 미완료 작업과 저장하지 않은 편집 내용을 보여주는 UI ===",
+  "recovery_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__intro_text__": "상단 안내 문구",
+    "intro_text": {},
+    "__empty_text__": "복구할 항목이 없을 때 안내 텍스트",
+    "empty_text": {},
+    "__tag_text__": "항목 종류 표시 ([operation], [unsaved])",
+    "tag_text": {},
+    "__item_text__": "항목 설명",
+    "item_text": {},
+    "__label_text__": "상세 정보 레이블",
+    "label_text": {},
+    "__partial_path__": "남아 있는 부분 복사본 경로",
+    "partial_path": {},
+    "__hint_text__": "상세 정보 안내 텍스트",
+    "hint_text": {},
+    "__message_text__": "작업 결과 메시지",
+    "message_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__tree_sidebar__": "=== 트리 사이드바: 패널 왼쪽에 디렉토리 계층을 보여주는 트리 창 ===",
+  "tree_sidebar": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "포커스가 없을 때 테두리",
+    "border": {},
+    "__border_active__": "포커스가 있을 때 테두리",
+    "border_active": {},
+    "__title__": "포커스가 없을 때 제목",
+    "title": {},
+    "__title_active__": "포커스가 있을 때 제목",
+    "title_active": {},
+    "__message_text__": "로딩/에러 안내 텍스트",
+    "message_text": {},
+    "__directory_text__": "디렉토리 노드 이름",
+    "directory_text": {},
+    "__cursor_text__": "포커스가 없을 때 현재 노드 이름 (굵게+밑줄)",
+    "cursor_text": {},
+    "__selected_bg__": "포커스가 있을 때 선택된 노드 배경",
+    "selected_bg": {},
+    "__selected_text__": "포커스가 있을 때 선택된 노드 텍스트",
+    "selected_text": {}
+  }},
+
+  "__cleanup_screen__": "=== 저장공간 정리 화면: 캐시, 빌드 산출물 등 공간을 많이 차지하는 항목을 찾아 삭제하는 UI ===",
+  "cleanup_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__label_text__": "정보 영역 레이블 (Target, Found, Marked, Freed)",
+    "label_text": {},
+    "__target_text__": "대상 경로",
+    "target_text": {},
+    "__phase_text__": "현재 단계 텍스트",
+    "phase_text": {},
+    "__found_size__": "찾은 항목 전체 크기",
+    "found_size": {},
+    "__marked_size__": "삭제 표시된 항목 크기",
+    "marked_size": {},
+    "__freed_size__": "확보된 공간 크기",
+    "freed_size": {},
+    "__empty_text__": "찾은 항목이 없을 때 안내 텍스트",
+    "empty_text": {},
+    "__arrow_text__": "분류 펼침/접힘 표시",
+    "arrow_text": {},
+    "__check_mark__": "삭제 표시 체크박스",
+    "check_mark": {},
+    "__category_size__": "분류 전체 크기",
+    "category_size": {},
+    "__category_name__": "분류 이름",
+    "category_name": {},
+    "__category_count__": "분류 항목 개수",
+    "category_count": {},
+    "__item_size__": "항목 크기",
+    "item_size": {},
+    "__item_path__": "항목 경로",
+    "item_path": {},
+    "__confirm_text__": "삭제 확인 메시지",
+    "confirm_text": {},
+    "__progress_text__": "검사/삭제 중인 경로",
+    "progress_text": {},
+    "__message_text__": "작업 결과 메시지",
+    "message_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__backup_screen__": "=== 백업 화면 ===",
+  "backup_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__message_text__": "진행/알림 메시지",
+    "message_text": {},
+    "__label_text__": "항목 이름, 일정, 실행 시각",
+    "label_text": {},
+    "__value_text__": "작업 이름, 경로, 설정 값",
+    "value_text": {},
+    "__dim_text__": "현재 파일, 안내, 소요 시간/크기",
+    "dim_text": {},
+    "__ok_text__": "성공한 마지막 실행",
+    "ok_text": {},
+    "__error_text__": "실패, 삭제 확인",
+    "error_text": {},
+    "__form_selected__": "입력 폼 선택 항목",
+    "form_selected": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__timeline_screen__": "=== 최근 변경 화면 ===",
+  "timeline_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__border__": "테두리",
+    "border": {},
+    "__title__": "제목 텍스트",
+    "title": {},
+    "__root_text__": "검색 폴더 경로",
+    "root_text": {},
+    "__window_text__": "기간",
+    "window_text": {},
+    "__info_text__": "깊이, 찾은 개수",
+    "info_text": {},
+    "__empty_text__": "빈 목록/검색 중 메시지",
+    "empty_text": {},
+    "__age_text__": "변경 후 경과 시간",
+    "age_text": {},
+    "__time_text__": "수정 시각",
+    "time_text": {},
+    "__size_text__": "파일 크기",
+    "size_text": {},
+    "__path_text__": "파일 경로",
+    "path_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__git_log_screen__": "=== Git 로그 화면 ===",
+  "git_log_screen": {{
+    "__bg__": "배경색",
+    "bg": {},
+    "__header_path__": "헤더 경로/개수",
+    "header_path": {},
+    "__header_scope__": "헤더 범위 (브랜치/파일)",
+    "header_scope": {},
+    "__graph__": "커밋 그래프",
+    "graph": {},
+    "__marker__": "비교 기준 표시",
+    "marker": {},
+    "__log_hash__": "커밋 해시",
+    "log_hash": {},
+    "__refs__": "브랜치/태그 이름",
+    "refs": {},
+    "__log_subject__": "커밋 제목",
+    "log_subject": {},
+    "__log_author__": "작성자",
+    "log_author": {},
+    "__log_date__": "날짜",
+    "log_date": {},
+    "__selected_bg__": "선택 배경",
+    "selected_bg": {},
+    "__selected_text__": "선택 텍스트",
+    "selected_text": {},
+    "__detail_subject__": "변경 파일 목록 위 커밋 제목",
+    "detail_subject": {},
+    "__file_name__": "변경 파일 이름",
+    "file_name": {},
+    "__file_added__": "추가된 파일 (A)",
+    "file_added": {},
+    "__file_deleted__": "삭제된 파일 (D)",
+    "file_deleted": {},
+    "__file_renamed__": "이름 변경/복사된 파일 (R, C)",
+    "file_renamed": {},
+    "__file_modified__": "수정된 파일 (M)",
+    "file_modified": {},
+    "__empty_text__": "빈 목록/불러오는 중 메시지",
+    "empty_text": {},
+    "__message_text__": "알림 메시지",
+    "message_text": {},
+    "__footer_key__": "기능 바 단축키",
+    "footer_key": {},
+    "__footer_text__": "기능 바 설명",
+    "footer_text": {}
+  }},
+
+  "__favorites_bar__": "=== 즐겨찾기 바 ===",
+  "favorites_bar": {{
+    "__key__": "슬롯 번호",
+    "key": {},
+    "__label__": "폴더 이름",
+    "label": {},
+    "__current__": "현재 패널 위치의 폴더 (굵게, 반전)",
+    "current": {},
+    "__empty_text__": "즐겨찾기가 없을 때 안내",
+    "empty_text": {}
   }}
 }}"#,
             // name
@@ -3262,7 +7081,7 @@ impl Theme {
             ci(self.panel.file_text), ci(self.panel.directory_text), ci(self.panel.symlink_text),
             ci(self.panel.selected_bg), ci(self.panel.selected_text), ci(self.panel.marked_text),
             ci(self.panel.size_text), ci(self.panel.date_text),
-            ci(self.panel.remote_indicator),
+            ci(self.panel.remote_indicator), ci(self.panel.git_badge_modified), ci(self.panel.git_badge_staged), ci(self.panel.git_badge_deleted), ci(self.panel.git_badge_untracked), ci(self.panel.git_badge_ignored),
             // header
             ci(self.header.bg), ci(self.header.text), ci(self.header.title),
             // status_bar
@@ -3297,10 +7116,43 @@ impl Theme {
             ci(self.dialog.git_log_diff_button_text), ci(self.dialog.git_log_diff_button_selected_text),
             ci(self.dialog.git_log_diff_button_selected_bg), ci(self.dialog.git_log_diff_button_disabled_text),
             ci(self.dialog.git_log_diff_scroll_info),
+            ci(self.dialog.open_with_title), ci(self.dialog.open_with_border),
+            ci(self.dialog.open_with_bg), ci(self.dialog.open_with_input_text),
+            ci(self.dialog.open_with_entry_text), ci(self.dialog.open_with_recent_text),
+            ci(self.dialog.open_with_preferred_text), ci(self.dialog.open_with_command_text),
+            ci(self.dialog.open_with_cursor_text), ci(self.dialog.open_with_cursor_bg),
+            ci(self.dialog.open_with_scroll_info),
             ci(self.dialog.remote_bookmark_text),
             ci(self.dialog.remote_connect_field_label),
             ci(self.dialog.remote_connect_field_value),
             ci(self.dialog.remote_connect_field_selected_bg),
+            ci(self.dialog.jump_title), ci(self.dialog.jump_border), ci(self.dialog.jump_bg),
+            ci(self.dialog.jump_input_text), ci(self.dialog.jump_entry_text),
+            ci(self.dialog.jump_cursor_text), ci(self.dialog.jump_cursor_bg),
+            ci(self.dialog.jump_scroll_info), ci(self.dialog.jump_hint_text),
+            ci(self.dialog.recent_files_title), ci(self.dialog.recent_files_border),
+            ci(self.dialog.recent_files_bg), ci(self.dialog.recent_files_input_text),
+            ci(self.dialog.recent_files_entry_text), ci(self.dialog.recent_files_edited_text),
+            ci(self.dialog.recent_files_cursor_text), ci(self.dialog.recent_files_cursor_bg),
+            ci(self.dialog.recent_files_scroll_info), ci(self.dialog.recent_files_hint_text),
+            ci(self.dialog.breadcrumb_title), ci(self.dialog.breadcrumb_border),
+            ci(self.dialog.breadcrumb_bg), ci(self.dialog.breadcrumb_path_text),
+            ci(self.dialog.breadcrumb_separator), ci(self.dialog.breadcrumb_current_text),
+            ci(self.dialog.breadcrumb_entry_text), ci(self.dialog.breadcrumb_cursor_text),
+            ci(self.dialog.breadcrumb_cursor_bg), ci(self.dialog.breadcrumb_scroll_info),
+            ci(self.dialog.breadcrumb_hint_text),
+            ci(self.dialog.favorites_title), ci(self.dialog.favorites_border),
+            ci(self.dialog.favorites_bg), ci(self.dialog.favorites_entry_text),
+            ci(self.dialog.favorites_empty_slot_text), ci(self.dialog.favorites_cursor_text),
+            ci(self.dialog.favorites_cursor_bg), ci(self.dialog.favorites_hint_text),
+            ci(self.dialog.basket_title), ci(self.dialog.basket_border), ci(self.dialog.basket_bg),
+            ci(self.dialog.basket_entry_text), ci(self.dialog.basket_cursor_text),
+            ci(self.dialog.basket_cursor_bg), ci(self.dialog.basket_hint_text),
+            ci(self.dialog.image_export_title), ci(self.dialog.image_export_border),
+            ci(self.dialog.image_export_bg), ci(self.dialog.image_export_label_text),
+            ci(self.dialog.image_export_value_text), ci(self.dialog.image_export_disabled_text),
+            ci(self.dialog.image_export_cursor_text), ci(self.dialog.image_export_cursor_bg),
+            ci(self.dialog.image_export_output_text), ci(self.dialog.image_export_hint_text),
             // confirm_dialog
             ci(self.confirm_dialog.bg), ci(self.confirm_dialog.border), ci(self.confirm_dialog.title),
             ci(self.confirm_dialog.message_text), ci(self.confirm_dialog.button_text),
@@ -3315,7 +7167,8 @@ impl Theme {
             ci(self.editor.header_text), ci(self.editor.header_info), ci(self.editor.line_number),
             ci(self.editor.text), ci(self.editor.cursor), ci(self.editor.selection_bg), ci(self.editor.selection_text),
             ci(self.editor.match_bg), ci(self.editor.match_current_bg), ci(self.editor.bracket_match),
-            ci(self.editor.modified_mark), ci(self.editor.footer_bg), ci(self.editor.footer_key),
+            ci(self.editor.modified_mark), ci(self.editor.read_only_mark), ci(self.editor.sudo_mark),
+            ci(self.editor.read_only_banner_text), ci(self.editor.read_only_banner_bg), ci(self.editor.footer_bg), ci(self.editor.footer_key),
             ci(self.editor.footer_text), ci(self.editor.find_input_text),
             ci(self.editor.find_option), ci(self.editor.find_option_active),
             ci(self.editor.wrap_indicator),
@@ -3327,7 +7180,8 @@ impl Theme {
             ci(self.syntax.bracket), ci(self.syntax.normal),
             // viewer
             ci(self.viewer.bg), ci(self.viewer.border), ci(self.viewer.header_text), ci(self.viewer.line_number),
-            ci(self.viewer.text), ci(self.viewer.bookmark_indicator), ci(self.viewer.search_input_text),
+            ci(self.viewer.text), ci(self.viewer.bookmark_indicator),
+            ci(self.viewer.selection_fg), ci(self.viewer.selection_bg), ci(self.viewer.search_input_text),
             ci(self.viewer.search_cursor_fg), ci(self.viewer.search_cursor_bg),
             ci(self.viewer.search_match_current_bg), ci(self.viewer.search_match_current_fg),
             ci(self.viewer.search_match_other_bg), ci(self.viewer.search_match_other_fg),
@@ -3439,6 +7293,125 @@ impl Theme {
             ci(self.dedup_screen.progress_text), ci(self.dedup_screen.log_text), ci(self.dedup_screen.log_text_alt),
             ci(self.dedup_screen.log_deleted), ci(self.dedup_screen.log_error),
             ci(self.dedup_screen.footer_key), ci(self.dedup_screen.footer_text),
+            // checksum_screen
+            ci(self.checksum_screen.bg), ci(self.checksum_screen.border), ci(self.checksum_screen.title),
+            ci(self.checksum_screen.label_text), ci(self.checksum_screen.target_text),
+            ci(self.checksum_screen.phase_text), ci(self.checksum_screen.progress_text),
+            ci(self.checksum_screen.name_text), ci(self.checksum_screen.hash_text),
+            ci(self.checksum_screen.ok_text), ci(self.checksum_screen.mismatch_text),
+            ci(self.checksum_screen.missing_text), ci(self.checksum_screen.error_text),
+            ci(self.checksum_screen.message_text), ci(self.checksum_screen.footer_key),
+            ci(self.checksum_screen.footer_text),
+            // audit_screen
+            ci(self.audit_screen.bg), ci(self.audit_screen.border), ci(self.audit_screen.title),
+            ci(self.audit_screen.label_text), ci(self.audit_screen.path_text),
+            ci(self.audit_screen.count_text), ci(self.audit_screen.empty_text),
+            ci(self.audit_screen.time_text), ci(self.audit_screen.user_text),
+            ci(self.audit_screen.summary_text), ci(self.audit_screen.kind_rejected),
+            ci(self.audit_screen.kind_shell), ci(self.audit_screen.kind_transfer),
+            ci(self.audit_screen.kind_other), ci(self.audit_screen.value_text),
+            ci(self.audit_screen.footer_key), ci(self.audit_screen.footer_text),
+            // archive_viewer
+            ci(self.archive_viewer.bg), ci(self.archive_viewer.info_border),
+            ci(self.archive_viewer.list_border), ci(self.archive_viewer.title),
+            ci(self.archive_viewer.path_text), ci(self.archive_viewer.count_text),
+            ci(self.archive_viewer.arrow_text), ci(self.archive_viewer.dest_text),
+            ci(self.archive_viewer.empty_text), ci(self.archive_viewer.directory_text),
+            ci(self.archive_viewer.file_text), ci(self.archive_viewer.marked_text),
+            ci(self.archive_viewer.size_text), ci(self.archive_viewer.date_text),
+            ci(self.archive_viewer.selected_bg), ci(self.archive_viewer.selected_text),
+            ci(self.archive_viewer.message_text), ci(self.archive_viewer.footer_bg),
+            ci(self.archive_viewer.footer_key), ci(self.archive_viewer.footer_text),
+            // trash_screen
+            ci(self.trash_screen.bg), ci(self.trash_screen.border), ci(self.trash_screen.title),
+            ci(self.trash_screen.label_text), ci(self.trash_screen.path_text),
+            ci(self.trash_screen.count_text), ci(self.trash_screen.empty_text),
+            ci(self.trash_screen.date_text), ci(self.trash_screen.size_text),
+            ci(self.trash_screen.directory_text), ci(self.trash_screen.file_text),
+            ci(self.trash_screen.origin_text), ci(self.trash_screen.confirm_text),
+            ci(self.trash_screen.message_text), ci(self.trash_screen.footer_key),
+            ci(self.trash_screen.footer_text),
+            // jobs_screen
+            ci(self.jobs_screen.summary_text), ci(self.jobs_screen.summary_bg), ci(self.jobs_screen.bg),
+            ci(self.jobs_screen.border), ci(self.jobs_screen.border_active), ci(self.jobs_screen.title),
+            ci(self.jobs_screen.id_text), ci(self.jobs_screen.running_text), ci(self.jobs_screen.done_text),
+            ci(self.jobs_screen.failed_text), ci(self.jobs_screen.selected_bg),
+            ci(self.jobs_screen.selected_text),
+            // bulk_rename
+            ci(self.bulk_rename.bg), ci(self.bulk_rename.border), ci(self.bulk_rename.title),
+            ci(self.bulk_rename.label_text), ci(self.bulk_rename.label_active),
+            ci(self.bulk_rename.input_text), ci(self.bulk_rename.input_cursor),
+            ci(self.bulk_rename.hint_text), ci(self.bulk_rename.list_border_active),
+            ci(self.bulk_rename.old_name), ci(self.bulk_rename.arrow_text), ci(self.bulk_rename.new_name),
+            ci(self.bulk_rename.unchanged_name), ci(self.bulk_rename.invalid_name),
+            ci(self.bulk_rename.edit_text), ci(self.bulk_rename.status_text), ci(self.bulk_rename.error_text),
+            ci(self.bulk_rename.footer_key), ci(self.bulk_rename.footer_text),
+            // preview_pane
+            ci(self.preview_pane.bg), ci(self.preview_pane.border), ci(self.preview_pane.title),
+            ci(self.preview_pane.text), ci(self.preview_pane.dim_text), ci(self.preview_pane.directory_text),
+            ci(self.preview_pane.size_text),
+            // drives_screen
+            ci(self.drives_screen.bg), ci(self.drives_screen.border), ci(self.drives_screen.title),
+            ci(self.drives_screen.empty_text), ci(self.drives_screen.mount_text),
+            ci(self.drives_screen.bar_fill), ci(self.drives_screen.bar_full),
+            ci(self.drives_screen.bar_empty), ci(self.drives_screen.percent_text),
+            ci(self.drives_screen.space_text), ci(self.drives_screen.unavailable_text),
+            ci(self.drives_screen.label_text), ci(self.drives_screen.value_text),
+            ci(self.drives_screen.footer_key), ci(self.drives_screen.footer_text),
+            // recovery_screen
+            ci(self.recovery_screen.bg), ci(self.recovery_screen.border), ci(self.recovery_screen.title),
+            ci(self.recovery_screen.intro_text), ci(self.recovery_screen.empty_text),
+            ci(self.recovery_screen.tag_text), ci(self.recovery_screen.item_text),
+            ci(self.recovery_screen.label_text), ci(self.recovery_screen.partial_path),
+            ci(self.recovery_screen.hint_text), ci(self.recovery_screen.message_text),
+            ci(self.recovery_screen.footer_key), ci(self.recovery_screen.footer_text),
+            // tree_sidebar
+            ci(self.tree_sidebar.bg), ci(self.tree_sidebar.border), ci(self.tree_sidebar.border_active),
+            ci(self.tree_sidebar.title), ci(self.tree_sidebar.title_active),
+            ci(self.tree_sidebar.message_text), ci(self.tree_sidebar.directory_text),
+            ci(self.tree_sidebar.cursor_text), ci(self.tree_sidebar.selected_bg),
+            ci(self.tree_sidebar.selected_text),
+            // cleanup_screen
+            ci(self.cleanup_screen.bg), ci(self.cleanup_screen.border), ci(self.cleanup_screen.title),
+            ci(self.cleanup_screen.label_text), ci(self.cleanup_screen.target_text),
+            ci(self.cleanup_screen.phase_text), ci(self.cleanup_screen.found_size),
+            ci(self.cleanup_screen.marked_size), ci(self.cleanup_screen.freed_size),
+            ci(self.cleanup_screen.empty_text), ci(self.cleanup_screen.arrow_text),
+            ci(self.cleanup_screen.check_mark), ci(self.cleanup_screen.category_size),
+            ci(self.cleanup_screen.category_name), ci(self.cleanup_screen.category_count),
+            ci(self.cleanup_screen.item_size), ci(self.cleanup_screen.item_path),
+            ci(self.cleanup_screen.confirm_text), ci(self.cleanup_screen.progress_text),
+            ci(self.cleanup_screen.message_text), ci(self.cleanup_screen.footer_key),
+            ci(self.cleanup_screen.footer_text),
+            // backup_screen
+            ci(self.backup_screen.bg), ci(self.backup_screen.border), ci(self.backup_screen.title),
+            ci(self.backup_screen.message_text), ci(self.backup_screen.label_text),
+            ci(self.backup_screen.value_text), ci(self.backup_screen.dim_text),
+            ci(self.backup_screen.ok_text), ci(self.backup_screen.error_text),
+            ci(self.backup_screen.form_selected), ci(self.backup_screen.footer_key),
+            ci(self.backup_screen.footer_text),
+            // timeline_screen
+            ci(self.timeline_screen.bg), ci(self.timeline_screen.border), ci(self.timeline_screen.title),
+            ci(self.timeline_screen.root_text), ci(self.timeline_screen.window_text),
+            ci(self.timeline_screen.info_text), ci(self.timeline_screen.empty_text),
+            ci(self.timeline_screen.age_text), ci(self.timeline_screen.time_text),
+            ci(self.timeline_screen.size_text), ci(self.timeline_screen.path_text),
+            ci(self.timeline_screen.footer_key), ci(self.timeline_screen.footer_text),
+            // git_log_screen
+            ci(self.git_log_screen.bg), ci(self.git_log_screen.header_path),
+            ci(self.git_log_screen.header_scope), ci(self.git_log_screen.graph),
+            ci(self.git_log_screen.marker), ci(self.git_log_screen.log_hash), ci(self.git_log_screen.refs),
+            ci(self.git_log_screen.log_subject), ci(self.git_log_screen.log_author),
+            ci(self.git_log_screen.log_date), ci(self.git_log_screen.selected_bg),
+            ci(self.git_log_screen.selected_text), ci(self.git_log_screen.detail_subject),
+            ci(self.git_log_screen.file_name), ci(self.git_log_screen.file_added),
+            ci(self.git_log_screen.file_deleted), ci(self.git_log_screen.file_renamed),
+            ci(self.git_log_screen.file_modified), ci(self.git_log_screen.empty_text),
+            ci(self.git_log_screen.message_text), ci(self.git_log_screen.footer_key),
+            ci(self.git_log_screen.footer_text),
+            // favorites_bar
+            ci(self.favorites_bar.key), ci(self.favorites_bar.label), ci(self.favorites_bar.current),
+            ci(self.favorites_bar.empty_text),
         )
     }
 }
@@ -62,6 +62,36 @@ pub struct ThemeJson {
     pub git_screen: GitScreenColorsJson,
     #[serde(default)]
     pub dedup_screen: DedupScreenColorsJson,
+    #[serde(default)]
+    pub checksum_screen: ChecksumScreenColorsJson,
+    #[serde(default)]
+    pub audit_screen: AuditScreenColorsJson,
+    #[serde(default)]
+    pub archive_viewer: ArchiveViewerColorsJson,
+    #[serde(default)]
+    pub trash_screen: TrashScreenColorsJson,
+    #[serde(default)]
+    pub jobs_screen: JobsScreenColorsJson,
+    #[serde(default)]
+    pub bulk_rename: BulkRenameColorsJson,
+    #[serde(default)]
+    pub preview_pane: PreviewPaneColorsJson,
+    #[serde(default)]
+    pub drives_screen: DrivesScreenColorsJson,
+    #[serde(default)]
+    pub recovery_screen: RecoveryScreenColorsJson,
+    #[serde(default)]
+    pub tree_sidebar: TreeSidebarColorsJson,
+    #[serde(default)]
+    pub cleanup_screen: CleanupScreenColorsJson,
+    #[serde(default)]
+    pub backup_screen: BackupScreenColorsJson,
+    #[serde(default)]
+    pub timeline_screen: TimelineScreenColorsJson,
+    #[serde(default)]
+    pub git_log_screen: GitLogScreenColorsJson,
+    #[serde(default)]
+    pub favorites_bar: FavoritesBarColorsJson,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -134,6 +164,16 @@ pub struct PanelColorsJson {
     pub date_text: u8,
     #[serde(default = "default_67")]
     pub remote_indicator: u8,
+    #[serde(default = "default_136")]
+    pub git_badge_modified: u8,
+    #[serde(default = "default_34")]
+    pub git_badge_staged: u8,
+    #[serde(default = "default_124")]
+    pub git_badge_deleted: u8,
+    #[serde(default = "default_198")]
+    pub git_badge_untracked: u8,
+    #[serde(default = "default_251")]
+    pub git_badge_ignored: u8,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -280,6 +320,28 @@ pub struct DialogColorsJson {
     pub git_log_diff_button_disabled_text: u8,
     #[serde(default = "default_251")]
     pub git_log_diff_scroll_info: u8,
+    #[serde(default = "default_238")]
+    pub open_with_title: u8,
+    #[serde(default = "default_238")]
+    pub open_with_border: u8,
+    #[serde(default = "default_255")]
+    pub open_with_bg: u8,
+    #[serde(default = "default_238")]
+    pub open_with_input_text: u8,
+    #[serde(default = "default_243")]
+    pub open_with_entry_text: u8,
+    #[serde(default = "default_34")]
+    pub open_with_recent_text: u8,
+    #[serde(default = "default_238")]
+    pub open_with_preferred_text: u8,
+    #[serde(default = "default_251")]
+    pub open_with_command_text: u8,
+    #[serde(default = "default_231")]
+    pub open_with_cursor_text: u8,
+    #[serde(default = "default_67")]
+    pub open_with_cursor_bg: u8,
+    #[serde(default = "default_251")]
+    pub open_with_scroll_info: u8,
     #[serde(default = "default_67")]
     pub remote_bookmark_text: u8,
     #[serde(default = "default_243")]
@@ -288,6 +350,116 @@ pub struct DialogColorsJson {
     pub remote_connect_field_value: u8,
     #[serde(default = "default_67")]
     pub remote_connect_field_selected_bg: u8,
+    #[serde(default = "default_238")]
+    pub jump_title: u8,
+    #[serde(default = "default_238")]
+    pub jump_border: u8,
+    #[serde(default = "default_255")]
+    pub jump_bg: u8,
+    #[serde(default = "default_238")]
+    pub jump_input_text: u8,
+    #[serde(default = "default_243")]
+    pub jump_entry_text: u8,
+    #[serde(default = "default_231")]
+    pub jump_cursor_text: u8,
+    #[serde(default = "default_67")]
+    pub jump_cursor_bg: u8,
+    #[serde(default = "default_251")]
+    pub jump_scroll_info: u8,
+    #[serde(default = "default_251")]
+    pub jump_hint_text: u8,
+    #[serde(default = "default_238")]
+    pub recent_files_title: u8,
+    #[serde(default = "default_238")]
+    pub recent_files_border: u8,
+    #[serde(default = "default_255")]
+    pub recent_files_bg: u8,
+    #[serde(default = "default_238")]
+    pub recent_files_input_text: u8,
+    #[serde(default = "default_243")]
+    pub recent_files_entry_text: u8,
+    #[serde(default = "default_34")]
+    pub recent_files_edited_text: u8,
+    #[serde(default = "default_231")]
+    pub recent_files_cursor_text: u8,
+    #[serde(default = "default_67")]
+    pub recent_files_cursor_bg: u8,
+    #[serde(default = "default_251")]
+    pub recent_files_scroll_info: u8,
+    #[serde(default = "default_251")]
+    pub recent_files_hint_text: u8,
+    #[serde(default = "default_238")]
+    pub breadcrumb_title: u8,
+    #[serde(default = "default_238")]
+    pub breadcrumb_border: u8,
+    #[serde(default = "default_255")]
+    pub breadcrumb_bg: u8,
+    #[serde(default = "default_238")]
+    pub breadcrumb_path_text: u8,
+    #[serde(default = "default_251")]
+    pub breadcrumb_separator: u8,
+    #[serde(default = "default_34")]
+    pub breadcrumb_current_text: u8,
+    #[serde(default = "default_243")]
+    pub breadcrumb_entry_text: u8,
+    #[serde(default = "default_231")]
+    pub breadcrumb_cursor_text: u8,
+    #[serde(default = "default_67")]
+    pub breadcrumb_cursor_bg: u8,
+    #[serde(default = "default_251")]
+    pub breadcrumb_scroll_info: u8,
+    #[serde(default = "default_251")]
+    pub breadcrumb_hint_text: u8,
+    #[serde(default = "default_238")]
+    pub favorites_title: u8,
+    #[serde(default = "default_238")]
+    pub favorites_border: u8,
+    #[serde(default = "default_255")]
+    pub favorites_bg: u8,
+    #[serde(default = "default_243")]
+    pub favorites_entry_text: u8,
+    #[serde(default = "default_251")]
+    pub favorites_empty_slot_text: u8,
+    #[serde(default = "default_231")]
+    pub favorites_cursor_text: u8,
+    #[serde(default = "default_67")]
+    pub favorites_cursor_bg: u8,
+    #[serde(default = "default_251")]
+    pub favorites_hint_text: u8,
+    #[serde(default = "default_238")]
+    pub basket_title: u8,
+    #[serde(default = "default_238")]
+    pub basket_border: u8,
+    #[serde(default = "default_255")]
+    pub basket_bg: u8,
+    #[serde(default = "default_243")]
+    pub basket_entry_text: u8,
+    #[serde(default = "default_231")]
+    pub basket_cursor_text: u8,
+    #[serde(default = "default_67")]
+    pub basket_cursor_bg: u8,
+    #[serde(default = "default_251")]
+    pub basket_hint_text: u8,
+    #[serde(default = "default_238")]
+    pub image_export_title: u8,
+    #[serde(default = "default_238")]
+    pub image_export_border: u8,
+    #[serde(default = "default_255")]
+    pub image_export_bg: u8,
+    #[serde(default = "default_34")]
+    pub image_export_label_text: u8,
+    #[serde(default = "default_243")]
+    pub image_export_value_text: u8,
+    #[serde(default = "default_251")]
+    pub image_export_disabled_text: u8,
+    #[serde(default = "default_231")]
+    pub image_export_cursor_text: u8,
+    #[serde(default = "default_67")]
+    pub image_export_cursor_bg: u8,
+    #[serde(default = "default_238")]
+    pub image_export_output_text: u8,
+    #[serde(default = "default_251")]
+    pub image_export_hint_text: u8,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -376,6 +548,14 @@ pub struct EditorColorsJson {
     pub bracket_match: u8,
     #[serde(default = "default_198")]
     pub modified_mark: u8,
+    #[serde(default = "default_198")]
+    pub read_only_mark: u8,
+    #[serde(default = "default_198")]
+    pub sudo_mark: u8,
+    #[serde(default = "default_253")]
+    pub read_only_banner_text: u8,
+    #[serde(default = "default_198")]
+    pub read_only_banner_bg: u8,
     #[serde(default = "default_253")]
     pub footer_bg: u8,
     #[serde(default = "default_74")]
@@ -438,6 +618,10 @@ pub struct ViewerColorsJson {
     pub text: u8,
     #[serde(default = "default_21")]
     pub bookmark_indicator: u8,
+    #[serde(default = "default_255")]
+    pub selection_fg: u8,
+    #[serde(default = "default_67")]
+    pub selection_bg: u8,
     #[serde(default = "default_67")]
     pub search_input_text: u8,
     #[serde(default = "default_255")]
@@ -961,117 +1145,786 @@ impl Default for DedupScreenColorsJson {
     }
 }
 
-// 기본값 함수들
-fn default_21() -> u8 { 21 }
-fn default_22() -> u8 { 22 }
-fn default_24() -> u8 { 24 }
-fn default_25() -> u8 { 25 }
-fn default_28() -> u8 { 28 }
-fn default_34() -> u8 { 34 }
-fn default_136() -> u8 { 136 }
-fn default_37() -> u8 { 37 }
-fn default_58() -> u8 { 58 }
-fn default_67() -> u8 { 67 }
-fn default_74() -> u8 { 74 }
-fn default_91() -> u8 { 91 }
-fn default_94() -> u8 { 94 }
-fn default_102() -> u8 { 102 }
-fn default_117() -> u8 { 117 }
-fn default_127() -> u8 { 127 }
-fn default_130() -> u8 { 130 }
-fn default_161() -> u8 { 161 }
-fn default_166() -> u8 { 166 }
-fn default_189() -> u8 { 189 }
-fn default_194() -> u8 { 194 }
-fn default_198() -> u8 { 198 }
-fn default_208() -> u8 { 208 }
-fn default_220() -> u8 { 220 }
-fn default_222() -> u8 { 222 }
-fn default_230() -> u8 { 230 }
-fn default_231() -> u8 { 231 }
-fn default_235() -> u8 { 235 }
-fn default_236() -> u8 { 236 }
-fn default_237() -> u8 { 237 }
-fn default_238() -> u8 { 238 }
-fn default_239() -> u8 { 239 }
-fn default_240() -> u8 { 240 }
-fn default_241() -> u8 { 241 }
-fn default_242() -> u8 { 242 }
-fn default_243() -> u8 { 243 }
-fn default_245() -> u8 { 245 }
-fn default_248() -> u8 { 248 }
-fn default_249() -> u8 { 249 }
-fn default_252() -> u8 { 252 }
-fn default_251() -> u8 { 251 }
-fn default_253() -> u8 { 253 }
-fn default_254() -> u8 { 254 }
-fn default_255() -> u8 { 255 }
-fn default_108() -> u8 { 108 }
-fn default_110() -> u8 { 110 }
-fn default_167() -> u8 { 167 }
-fn default_174() -> u8 { 174 }
-fn default_180() -> u8 { 180 }
-fn default_188() -> u8 { 188 }
-fn default_195() -> u8 { 195 }
-fn default_144() -> u8 { 144 }
-fn default_146() -> u8 { 146 }
-fn default_214() -> u8 { 214 }
-fn default_234() -> u8 { 234 }
-
-// ═══════════════════════════════════════════════════════════════════════════════
-// 테마 로딩 함수
-// ═══════════════════════════════════════════════════════════════════════════════
-
-/// 테마 디렉토리 경로 (~/.cokacdir/themes)
-pub fn themes_dir() -> Option<PathBuf> {
-    dirs::home_dir().map(|h| h.join(".cokacdir").join("themes"))
+#[derive(Debug, Deserialize)]
+pub struct ChecksumScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_108")]
+    pub target_text: u8,
+    #[serde(default = "default_108")]
+    pub phase_text: u8,
+    #[serde(default = "default_188")]
+    pub progress_text: u8,
+    #[serde(default = "default_144")]
+    pub name_text: u8,
+    #[serde(default = "default_188")]
+    pub hash_text: u8,
+    #[serde(default = "default_108")]
+    pub ok_text: u8,
+    #[serde(default = "default_167")]
+    pub mismatch_text: u8,
+    #[serde(default = "default_174")]
+    pub missing_text: u8,
+    #[serde(default = "default_167")]
+    pub error_text: u8,
+    #[serde(default = "default_108")]
+    pub message_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
 }
 
-/// 테마 파일 경로 (~/.cokacdir/themes/{name}.json)
-/// Security: Validates theme name to prevent path traversal attacks
-pub fn theme_path(name: &str) -> Option<PathBuf> {
-    // Prevent path traversal attacks
-    if name.contains('/') || name.contains('\\') || name.contains("..") {
-        return None;
-    }
-
-    // Validate filename
-    if name.is_empty() || name.len() > 64 {
-        return None;
-    }
-
-    // Reject names with control characters
-    if name.chars().any(|c| c.is_control()) {
-        return None;
-    }
-
-    let themes = themes_dir()?;
-    let path = themes.join(format!("{}.json", name));
-
-    // Verify the path stays within themes directory after canonicalization
-    if let (Ok(canonical_path), Ok(canonical_themes)) =
-        (path.canonicalize(), themes.canonicalize())
-    {
-        if canonical_path.starts_with(&canonical_themes) {
-            return Some(path);
-        }
-        return None;
-    }
-
-    // For new files (canonicalize fails), verify parent is themes dir
-    if let Some(parent) = path.parent() {
-        if parent == themes {
-            return Some(path);
+impl Default for ChecksumScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, label_text: 188, target_text: 108,
+            phase_text: 108, progress_text: 188, name_text: 144, hash_text: 188,
+            ok_text: 108, mismatch_text: 167, missing_text: 174,
+            error_text: 167, message_text: 108, footer_key: 146,
+            footer_text: 102,
         }
     }
+}
 
-    None
+#[derive(Debug, Deserialize)]
+pub struct AuditScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_108")]
+    pub path_text: u8,
+    #[serde(default = "default_108")]
+    pub count_text: u8,
+    #[serde(default = "default_188")]
+    pub empty_text: u8,
+    #[serde(default = "default_188")]
+    pub time_text: u8,
+    #[serde(default = "default_144")]
+    pub user_text: u8,
+    #[serde(default = "default_188")]
+    pub summary_text: u8,
+    #[serde(default = "default_167")]
+    pub kind_rejected: u8,
+    #[serde(default = "default_174")]
+    pub kind_shell: u8,
+    #[serde(default = "default_108")]
+    pub kind_transfer: u8,
+    #[serde(default = "default_188")]
+    pub kind_other: u8,
+    #[serde(default = "default_144")]
+    pub value_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
 }
 
-/// JSON 파일에서 테마 로드
-pub fn load_theme(name: &str) -> Option<Theme> {
-    let path = theme_path(name)?;
-    load_theme_from_path(&path)
+impl Default for AuditScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, label_text: 188, path_text: 108,
+            count_text: 108, empty_text: 188, time_text: 188, user_text: 144,
+            summary_text: 188, kind_rejected: 167, kind_shell: 174,
+            kind_transfer: 108, kind_other: 188, value_text: 144,
+            footer_key: 146, footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArchiveViewerColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_146")]
+    pub info_border: u8,
+    #[serde(default = "default_102")]
+    pub list_border: u8,
+    #[serde(default = "default_195")]
+    pub title: u8,
+    #[serde(default = "default_110")]
+    pub path_text: u8,
+    #[serde(default = "default_102")]
+    pub count_text: u8,
+    #[serde(default = "default_102")]
+    pub arrow_text: u8,
+    #[serde(default = "default_188")]
+    pub dest_text: u8,
+    #[serde(default = "default_102")]
+    pub empty_text: u8,
+    #[serde(default = "default_110")]
+    pub directory_text: u8,
+    #[serde(default = "default_188")]
+    pub file_text: u8,
+    #[serde(default = "default_174")]
+    pub marked_text: u8,
+    #[serde(default = "default_102")]
+    pub size_text: u8,
+    #[serde(default = "default_102")]
+    pub date_text: u8,
+    #[serde(default = "default_146")]
+    pub selected_bg: u8,
+    #[serde(default = "default_234")]
+    pub selected_text: u8,
+    #[serde(default = "default_102")]
+    pub message_text: u8,
+    #[serde(default = "default_234")]
+    pub footer_bg: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for ArchiveViewerColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, info_border: 146, list_border: 102, title: 195,
+            path_text: 110, count_text: 102, arrow_text: 102, dest_text: 188,
+            empty_text: 102, directory_text: 110, file_text: 188,
+            marked_text: 174, size_text: 102, date_text: 102, selected_bg: 146,
+            selected_text: 234, message_text: 102, footer_bg: 234,
+            footer_key: 146, footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrashScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_108")]
+    pub path_text: u8,
+    #[serde(default = "default_108")]
+    pub count_text: u8,
+    #[serde(default = "default_188")]
+    pub empty_text: u8,
+    #[serde(default = "default_188")]
+    pub date_text: u8,
+    #[serde(default = "default_144")]
+    pub size_text: u8,
+    #[serde(default = "default_108")]
+    pub directory_text: u8,
+    #[serde(default = "default_188")]
+    pub file_text: u8,
+    #[serde(default = "default_144")]
+    pub origin_text: u8,
+    #[serde(default = "default_167")]
+    pub confirm_text: u8,
+    #[serde(default = "default_108")]
+    pub message_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for TrashScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, label_text: 188, path_text: 108,
+            count_text: 108, empty_text: 188, date_text: 188, size_text: 144,
+            directory_text: 108, file_text: 188, origin_text: 144,
+            confirm_text: 167, message_text: 108, footer_key: 146,
+            footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JobsScreenColorsJson {
+    #[serde(default = "default_195")]
+    pub summary_text: u8,
+    #[serde(default = "default_235")]
+    pub summary_bg: u8,
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_146")]
+    pub border_active: u8,
+    #[serde(default = "default_195")]
+    pub title: u8,
+    #[serde(default = "default_102")]
+    pub id_text: u8,
+    #[serde(default = "default_188")]
+    pub running_text: u8,
+    #[serde(default = "default_102")]
+    pub done_text: u8,
+    #[serde(default = "default_167")]
+    pub failed_text: u8,
+    #[serde(default = "default_146")]
+    pub selected_bg: u8,
+    #[serde(default = "default_234")]
+    pub selected_text: u8,
+}
+
+impl Default for JobsScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            summary_text: 195, summary_bg: 235, bg: 234, border: 102,
+            border_active: 146, title: 195, id_text: 102, running_text: 188,
+            done_text: 102, failed_text: 167, selected_bg: 146,
+            selected_text: 234,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkRenameColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_108")]
+    pub label_active: u8,
+    #[serde(default = "default_188")]
+    pub input_text: u8,
+    #[serde(default = "default_108")]
+    pub input_cursor: u8,
+    #[serde(default = "default_144")]
+    pub hint_text: u8,
+    #[serde(default = "default_110")]
+    pub list_border_active: u8,
+    #[serde(default = "default_188")]
+    pub old_name: u8,
+    #[serde(default = "default_188")]
+    pub arrow_text: u8,
+    #[serde(default = "default_108")]
+    pub new_name: u8,
+    #[serde(default = "default_144")]
+    pub unchanged_name: u8,
+    #[serde(default = "default_167")]
+    pub invalid_name: u8,
+    #[serde(default = "default_108")]
+    pub edit_text: u8,
+    #[serde(default = "default_188")]
+    pub status_text: u8,
+    #[serde(default = "default_167")]
+    pub error_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for BulkRenameColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, label_text: 188,
+            label_active: 108, input_text: 188, input_cursor: 108,
+            hint_text: 144, list_border_active: 110, old_name: 188,
+            arrow_text: 188, new_name: 108, unchanged_name: 144,
+            invalid_name: 167, edit_text: 108, status_text: 188,
+            error_text: 167, footer_key: 146, footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PreviewPaneColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_145")]
+    pub title: u8,
+    #[serde(default = "default_188")]
+    pub text: u8,
+    #[serde(default = "default_102")]
+    pub dim_text: u8,
+    #[serde(default = "default_110")]
+    pub directory_text: u8,
+    #[serde(default = "default_102")]
+    pub size_text: u8,
+}
+
+impl Default for PreviewPaneColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 145, text: 188, dim_text: 102,
+            directory_text: 110, size_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DrivesScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_188")]
+    pub empty_text: u8,
+    #[serde(default = "default_108")]
+    pub mount_text: u8,
+    #[serde(default = "default_108")]
+    pub bar_fill: u8,
+    #[serde(default = "default_167")]
+    pub bar_full: u8,
+    #[serde(default = "default_236")]
+    pub bar_empty: u8,
+    #[serde(default = "default_188")]
+    pub percent_text: u8,
+    #[serde(default = "default_144")]
+    pub space_text: u8,
+    #[serde(default = "default_188")]
+    pub unavailable_text: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_144")]
+    pub value_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for DrivesScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, empty_text: 188, mount_text: 108,
+            bar_fill: 108, bar_full: 167, bar_empty: 236, percent_text: 188,
+            space_text: 144, unavailable_text: 188, label_text: 188,
+            value_text: 144, footer_key: 146, footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecoveryScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_108")]
+    pub intro_text: u8,
+    #[serde(default = "default_188")]
+    pub empty_text: u8,
+    #[serde(default = "default_188")]
+    pub tag_text: u8,
+    #[serde(default = "default_188")]
+    pub item_text: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_167")]
+    pub partial_path: u8,
+    #[serde(default = "default_144")]
+    pub hint_text: u8,
+    #[serde(default = "default_108")]
+    pub message_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for RecoveryScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, intro_text: 108, empty_text: 188,
+            tag_text: 188, item_text: 188, label_text: 188, partial_path: 167,
+            hint_text: 144, message_text: 108, footer_key: 146,
+            footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TreeSidebarColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_146")]
+    pub border_active: u8,
+    #[serde(default = "default_145")]
+    pub title: u8,
+    #[serde(default = "default_195")]
+    pub title_active: u8,
+    #[serde(default = "default_102")]
+    pub message_text: u8,
+    #[serde(default = "default_110")]
+    pub directory_text: u8,
+    #[serde(default = "default_110")]
+    pub cursor_text: u8,
+    #[serde(default = "default_146")]
+    pub selected_bg: u8,
+    #[serde(default = "default_234")]
+    pub selected_text: u8,
+}
+
+impl Default for TreeSidebarColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, border_active: 146, title: 145,
+            title_active: 195, message_text: 102, directory_text: 110,
+            cursor_text: 110, selected_bg: 146, selected_text: 234,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CleanupScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_108")]
+    pub target_text: u8,
+    #[serde(default = "default_108")]
+    pub phase_text: u8,
+    #[serde(default = "default_108")]
+    pub found_size: u8,
+    #[serde(default = "default_174")]
+    pub marked_size: u8,
+    #[serde(default = "default_108")]
+    pub freed_size: u8,
+    #[serde(default = "default_188")]
+    pub empty_text: u8,
+    #[serde(default = "default_188")]
+    pub arrow_text: u8,
+    #[serde(default = "default_174")]
+    pub check_mark: u8,
+    #[serde(default = "default_108")]
+    pub category_size: u8,
+    #[serde(default = "default_188")]
+    pub category_name: u8,
+    #[serde(default = "default_144")]
+    pub category_count: u8,
+    #[serde(default = "default_144")]
+    pub item_size: u8,
+    #[serde(default = "default_188")]
+    pub item_path: u8,
+    #[serde(default = "default_167")]
+    pub confirm_text: u8,
+    #[serde(default = "default_144")]
+    pub progress_text: u8,
+    #[serde(default = "default_108")]
+    pub message_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for CleanupScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, label_text: 188, target_text: 108,
+            phase_text: 108, found_size: 108, marked_size: 174, freed_size: 108,
+            empty_text: 188, arrow_text: 188, check_mark: 174,
+            category_size: 108, category_name: 188, category_count: 144,
+            item_size: 144, item_path: 188, confirm_text: 167,
+            progress_text: 144, message_text: 108, footer_key: 146,
+            footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BackupScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_108")]
+    pub message_text: u8,
+    #[serde(default = "default_188")]
+    pub label_text: u8,
+    #[serde(default = "default_188")]
+    pub value_text: u8,
+    #[serde(default = "default_144")]
+    pub dim_text: u8,
+    #[serde(default = "default_108")]
+    pub ok_text: u8,
+    #[serde(default = "default_167")]
+    pub error_text: u8,
+    #[serde(default = "default_108")]
+    pub form_selected: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for BackupScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, message_text: 108,
+            label_text: 188, value_text: 188, dim_text: 144, ok_text: 108,
+            error_text: 167, form_selected: 108, footer_key: 146,
+            footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TimelineScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_102")]
+    pub border: u8,
+    #[serde(default = "default_110")]
+    pub title: u8,
+    #[serde(default = "default_108")]
+    pub root_text: u8,
+    #[serde(default = "default_108")]
+    pub window_text: u8,
+    #[serde(default = "default_188")]
+    pub info_text: u8,
+    #[serde(default = "default_188")]
+    pub empty_text: u8,
+    #[serde(default = "default_108")]
+    pub age_text: u8,
+    #[serde(default = "default_144")]
+    pub time_text: u8,
+    #[serde(default = "default_144")]
+    pub size_text: u8,
+    #[serde(default = "default_188")]
+    pub path_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for TimelineScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, border: 102, title: 110, root_text: 108, window_text: 108,
+            info_text: 188, empty_text: 188, age_text: 108, time_text: 144,
+            size_text: 144, path_text: 188, footer_key: 146, footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitLogScreenColorsJson {
+    #[serde(default = "default_234")]
+    pub bg: u8,
+    #[serde(default = "default_188")]
+    pub header_path: u8,
+    #[serde(default = "default_108")]
+    pub header_scope: u8,
+    #[serde(default = "default_188")]
+    pub graph: u8,
+    #[serde(default = "default_108")]
+    pub marker: u8,
+    #[serde(default = "default_146")]
+    pub log_hash: u8,
+    #[serde(default = "default_108")]
+    pub refs: u8,
+    #[serde(default = "default_188")]
+    pub log_subject: u8,
+    #[serde(default = "default_110")]
+    pub log_author: u8,
+    #[serde(default = "default_102")]
+    pub log_date: u8,
+    #[serde(default = "default_239")]
+    pub selected_bg: u8,
+    #[serde(default = "default_195")]
+    pub selected_text: u8,
+    #[serde(default = "default_188")]
+    pub detail_subject: u8,
+    #[serde(default = "default_188")]
+    pub file_name: u8,
+    #[serde(default = "default_108")]
+    pub file_added: u8,
+    #[serde(default = "default_167")]
+    pub file_deleted: u8,
+    #[serde(default = "default_174")]
+    pub file_renamed: u8,
+    #[serde(default = "default_180")]
+    pub file_modified: u8,
+    #[serde(default = "default_102")]
+    pub empty_text: u8,
+    #[serde(default = "default_102")]
+    pub message_text: u8,
+    #[serde(default = "default_146")]
+    pub footer_key: u8,
+    #[serde(default = "default_102")]
+    pub footer_text: u8,
+}
+
+impl Default for GitLogScreenColorsJson {
+    fn default() -> Self {
+        Self {
+            bg: 234, header_path: 188, header_scope: 108, graph: 188,
+            marker: 108, log_hash: 146, refs: 108, log_subject: 188,
+            log_author: 110, log_date: 102, selected_bg: 239,
+            selected_text: 195, detail_subject: 188, file_name: 188,
+            file_added: 108, file_deleted: 167, file_renamed: 174,
+            file_modified: 180, empty_text: 102, message_text: 102,
+            footer_key: 146, footer_text: 102,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FavoritesBarColorsJson {
+    #[serde(default = "default_146")]
+    pub key: u8,
+    #[serde(default = "default_102")]
+    pub label: u8,
+    #[serde(default = "default_102")]
+    pub current: u8,
+    #[serde(default = "default_102")]
+    pub empty_text: u8,
+}
+
+impl Default for FavoritesBarColorsJson {
+    fn default() -> Self {
+        Self {
+            key: 146, label: 102, current: 102, empty_text: 102,
+        }
+    }
+}
+
+// 기본값 함수들
+fn default_21() -> u8 { 21 }
+fn default_22() -> u8 { 22 }
+fn default_24() -> u8 { 24 }
+fn default_25() -> u8 { 25 }
+fn default_28() -> u8 { 28 }
+fn default_34() -> u8 { 34 }
+fn default_136() -> u8 { 136 }
+fn default_37() -> u8 { 37 }
+fn default_58() -> u8 { 58 }
+fn default_67() -> u8 { 67 }
+fn default_74() -> u8 { 74 }
+fn default_91() -> u8 { 91 }
+fn default_94() -> u8 { 94 }
+fn default_102() -> u8 { 102 }
+fn default_117() -> u8 { 117 }
+fn default_127() -> u8 { 127 }
+fn default_130() -> u8 { 130 }
+fn default_161() -> u8 { 161 }
+fn default_166() -> u8 { 166 }
+fn default_189() -> u8 { 189 }
+fn default_194() -> u8 { 194 }
+fn default_198() -> u8 { 198 }
+fn default_208() -> u8 { 208 }
+fn default_220() -> u8 { 220 }
+fn default_222() -> u8 { 222 }
+fn default_230() -> u8 { 230 }
+fn default_231() -> u8 { 231 }
+fn default_235() -> u8 { 235 }
+fn default_236() -> u8 { 236 }
+fn default_237() -> u8 { 237 }
+fn default_238() -> u8 { 238 }
+fn default_239() -> u8 { 239 }
+fn default_240() -> u8 { 240 }
+fn default_241() -> u8 { 241 }
+fn default_242() -> u8 { 242 }
+fn default_243() -> u8 { 243 }
+fn default_245() -> u8 { 245 }
+fn default_248() -> u8 { 248 }
+fn default_249() -> u8 { 249 }
+fn default_252() -> u8 { 252 }
+fn default_251() -> u8 { 251 }
+fn default_253() -> u8 { 253 }
+fn default_254() -> u8 { 254 }
+fn default_255() -> u8 { 255 }
+fn default_108() -> u8 { 108 }
+fn default_110() -> u8 { 110 }
+fn default_167() -> u8 { 167 }
+fn default_174() -> u8 { 174 }
+fn default_180() -> u8 { 180 }
+fn default_188() -> u8 { 188 }
+fn default_195() -> u8 { 195 }
+fn default_144() -> u8 { 144 }
+fn default_146() -> u8 { 146 }
+fn default_214() -> u8 { 214 }
+fn default_234() -> u8 { 234 }
+fn default_124() -> u8 { 124 }
+fn default_145() -> u8 { 145 }
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// 테마 로딩 함수
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// 테마 디렉토리 경로 (~/.cokacdir/themes)
+pub fn themes_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".cokacdir").join("themes"))
+}
+
+/// 테마 파일 경로 (~/.cokacdir/themes/{name}.json)
+/// Security: Validates theme name to prevent path traversal attacks
+pub fn theme_path(name: &str) -> Option<PathBuf> {
+    // Prevent path traversal attacks
+    if name.contains('/') || name.contains('\\') || name.contains("..") {
+        return None;
+    }
+
+    // Validate filename
+    if name.is_empty() || name.len() > 64 {
+        return None;
+    }
+
+    // Reject names with control characters
+    if name.chars().any(|c| c.is_control()) {
+        return None;
+    }
+
+    let themes = themes_dir()?;
+    let path = themes.join(format!("{}.json", name));
+
+    // Verify the path stays within themes directory after canonicalization
+    if let (Ok(canonical_path), Ok(canonical_themes)) =
+        (path.canonicalize(), themes.canonicalize())
+    {
+        if canonical_path.starts_with(&canonical_themes) {
+            return Some(path);
+        }
+        return None;
+    }
+
+    // For new files (canonicalize fails), verify parent is themes dir
+    if let Some(parent) = path.parent() {
+        if parent == themes {
+            return Some(path);
+        }
+    }
+
+    None
+}
+
+/// JSON 파일에서 테마 로드
+pub fn load_theme(name: &str) -> Option<Theme> {
+    let path = theme_path(name)?;
+    load_theme_from_path(&path)
 }
 
 /// 지정된 경로에서 테마 로드
@@ -1125,6 +1978,11 @@ pub fn theme_from_json(json: &ThemeJson) -> Theme {
         size_text: idx(json.panel.size_text),
         date_text: idx(json.panel.date_text),
         remote_indicator: idx(json.panel.remote_indicator),
+        git_badge_modified: idx(json.panel.git_badge_modified),
+        git_badge_staged: idx(json.panel.git_badge_staged),
+        git_badge_deleted: idx(json.panel.git_badge_deleted),
+        git_badge_untracked: idx(json.panel.git_badge_untracked),
+        git_badge_ignored: idx(json.panel.git_badge_ignored),
     };
 
     let header = HeaderColors {
@@ -1203,10 +2061,76 @@ pub fn theme_from_json(json: &ThemeJson) -> Theme {
         git_log_diff_button_selected_bg: idx(json.dialog.git_log_diff_button_selected_bg),
         git_log_diff_button_disabled_text: idx(json.dialog.git_log_diff_button_disabled_text),
         git_log_diff_scroll_info: idx(json.dialog.git_log_diff_scroll_info),
+        open_with_title: idx(json.dialog.open_with_title),
+        open_with_border: idx(json.dialog.open_with_border),
+        open_with_bg: idx(json.dialog.open_with_bg),
+        open_with_input_text: idx(json.dialog.open_with_input_text),
+        open_with_entry_text: idx(json.dialog.open_with_entry_text),
+        open_with_recent_text: idx(json.dialog.open_with_recent_text),
+        open_with_preferred_text: idx(json.dialog.open_with_preferred_text),
+        open_with_command_text: idx(json.dialog.open_with_command_text),
+        open_with_cursor_text: idx(json.dialog.open_with_cursor_text),
+        open_with_cursor_bg: idx(json.dialog.open_with_cursor_bg),
+        open_with_scroll_info: idx(json.dialog.open_with_scroll_info),
         remote_bookmark_text: idx(json.dialog.remote_bookmark_text),
         remote_connect_field_label: idx(json.dialog.remote_connect_field_label),
         remote_connect_field_value: idx(json.dialog.remote_connect_field_value),
         remote_connect_field_selected_bg: idx(json.dialog.remote_connect_field_selected_bg),
+        jump_title: idx(json.dialog.jump_title),
+        jump_border: idx(json.dialog.jump_border),
+        jump_bg: idx(json.dialog.jump_bg),
+        jump_input_text: idx(json.dialog.jump_input_text),
+        jump_entry_text: idx(json.dialog.jump_entry_text),
+        jump_cursor_text: idx(json.dialog.jump_cursor_text),
+        jump_cursor_bg: idx(json.dialog.jump_cursor_bg),
+        jump_scroll_info: idx(json.dialog.jump_scroll_info),
+        jump_hint_text: idx(json.dialog.jump_hint_text),
+        recent_files_title: idx(json.dialog.recent_files_title),
+        recent_files_border: idx(json.dialog.recent_files_border),
+        recent_files_bg: idx(json.dialog.recent_files_bg),
+        recent_files_input_text: idx(json.dialog.recent_files_input_text),
+        recent_files_entry_text: idx(json.dialog.recent_files_entry_text),
+        recent_files_edited_text: idx(json.dialog.recent_files_edited_text),
+        recent_files_cursor_text: idx(json.dialog.recent_files_cursor_text),
+        recent_files_cursor_bg: idx(json.dialog.recent_files_cursor_bg),
+        recent_files_scroll_info: idx(json.dialog.recent_files_scroll_info),
+        recent_files_hint_text: idx(json.dialog.recent_files_hint_text),
+        breadcrumb_title: idx(json.dialog.breadcrumb_title),
+        breadcrumb_border: idx(json.dialog.breadcrumb_border),
+        breadcrumb_bg: idx(json.dialog.breadcrumb_bg),
+        breadcrumb_path_text: idx(json.dialog.breadcrumb_path_text),
+        breadcrumb_separator: idx(json.dialog.breadcrumb_separator),
+        breadcrumb_current_text: idx(json.dialog.breadcrumb_current_text),
+        breadcrumb_entry_text: idx(json.dialog.breadcrumb_entry_text),
+        breadcrumb_cursor_text: idx(json.dialog.breadcrumb_cursor_text),
+        breadcrumb_cursor_bg: idx(json.dialog.breadcrumb_cursor_bg),
+        breadcrumb_scroll_info: idx(json.dialog.breadcrumb_scroll_info),
+        breadcrumb_hint_text: idx(json.dialog.breadcrumb_hint_text),
+        favorites_title: idx(json.dialog.favorites_title),
+        favorites_border: idx(json.dialog.favorites_border),
+        favorites_bg: idx(json.dialog.favorites_bg),
+        favorites_entry_text: idx(json.dialog.favorites_entry_text),
+        favorites_empty_slot_text: idx(json.dialog.favorites_empty_slot_text),
+        favorites_cursor_text: idx(json.dialog.favorites_cursor_text),
+        favorites_cursor_bg: idx(json.dialog.favorites_cursor_bg),
+        favorites_hint_text: idx(json.dialog.favorites_hint_text),
+        basket_title: idx(json.dialog.basket_title),
+        basket_border: idx(json.dialog.basket_border),
+        basket_bg: idx(json.dialog.basket_bg),
+        basket_entry_text: idx(json.dialog.basket_entry_text),
+        basket_cursor_text: idx(json.dialog.basket_cursor_text),
+        basket_cursor_bg: idx(json.dialog.basket_cursor_bg),
+        basket_hint_text: idx(json.dialog.basket_hint_text),
+        image_export_title: idx(json.dialog.image_export_title),
+        image_export_border: idx(json.dialog.image_export_border),
+        image_export_bg: idx(json.dialog.image_export_bg),
+        image_export_label_text: idx(json.dialog.image_export_label_text),
+        image_export_value_text: idx(json.dialog.image_export_value_text),
+        image_export_disabled_text: idx(json.dialog.image_export_disabled_text),
+        image_export_cursor_text: idx(json.dialog.image_export_cursor_text),
+        image_export_cursor_bg: idx(json.dialog.image_export_cursor_bg),
+        image_export_output_text: idx(json.dialog.image_export_output_text),
+        image_export_hint_text: idx(json.dialog.image_export_hint_text),
     };
 
     let confirm_dialog = ConfirmDialogColors {
@@ -1246,6 +2170,10 @@ pub fn theme_from_json(json: &ThemeJson) -> Theme {
         match_current_bg: idx(json.editor.match_current_bg),
         bracket_match: idx(json.editor.bracket_match),
         modified_mark: idx(json.editor.modified_mark),
+        read_only_mark: idx(json.editor.read_only_mark),
+        sudo_mark: idx(json.editor.sudo_mark),
+        read_only_banner_text: idx(json.editor.read_only_banner_text),
+        read_only_banner_bg: idx(json.editor.read_only_banner_bg),
         footer_bg: idx(json.editor.footer_bg),
         footer_key: idx(json.editor.footer_key),
         footer_text: idx(json.editor.footer_text),
@@ -1279,6 +2207,8 @@ pub fn theme_from_json(json: &ThemeJson) -> Theme {
         line_number: idx(json.viewer.line_number),
         text: idx(json.viewer.text),
         bookmark_indicator: idx(json.viewer.bookmark_indicator),
+        selection_fg: idx(json.viewer.selection_fg),
+        selection_bg: idx(json.viewer.selection_bg),
         search_input_text: idx(json.viewer.search_input_text),
         search_cursor_fg: idx(json.viewer.search_cursor_fg),
         search_cursor_bg: idx(json.viewer.search_cursor_bg),
@@ -1540,6 +2470,269 @@ pub fn theme_from_json(json: &ThemeJson) -> Theme {
         footer_text: idx(json.dedup_screen.footer_text),
     };
 
+    let checksum_screen = ChecksumScreenColors {
+        bg: idx(json.checksum_screen.bg),
+        border: idx(json.checksum_screen.border),
+        title: idx(json.checksum_screen.title),
+        label_text: idx(json.checksum_screen.label_text),
+        target_text: idx(json.checksum_screen.target_text),
+        phase_text: idx(json.checksum_screen.phase_text),
+        progress_text: idx(json.checksum_screen.progress_text),
+        name_text: idx(json.checksum_screen.name_text),
+        hash_text: idx(json.checksum_screen.hash_text),
+        ok_text: idx(json.checksum_screen.ok_text),
+        mismatch_text: idx(json.checksum_screen.mismatch_text),
+        missing_text: idx(json.checksum_screen.missing_text),
+        error_text: idx(json.checksum_screen.error_text),
+        message_text: idx(json.checksum_screen.message_text),
+        footer_key: idx(json.checksum_screen.footer_key),
+        footer_text: idx(json.checksum_screen.footer_text),
+    };
+
+    let audit_screen = AuditScreenColors {
+        bg: idx(json.audit_screen.bg),
+        border: idx(json.audit_screen.border),
+        title: idx(json.audit_screen.title),
+        label_text: idx(json.audit_screen.label_text),
+        path_text: idx(json.audit_screen.path_text),
+        count_text: idx(json.audit_screen.count_text),
+        empty_text: idx(json.audit_screen.empty_text),
+        time_text: idx(json.audit_screen.time_text),
+        user_text: idx(json.audit_screen.user_text),
+        summary_text: idx(json.audit_screen.summary_text),
+        kind_rejected: idx(json.audit_screen.kind_rejected),
+        kind_shell: idx(json.audit_screen.kind_shell),
+        kind_transfer: idx(json.audit_screen.kind_transfer),
+        kind_other: idx(json.audit_screen.kind_other),
+        value_text: idx(json.audit_screen.value_text),
+        footer_key: idx(json.audit_screen.footer_key),
+        footer_text: idx(json.audit_screen.footer_text),
+    };
+
+    let archive_viewer = ArchiveViewerColors {
+        bg: idx(json.archive_viewer.bg),
+        info_border: idx(json.archive_viewer.info_border),
+        list_border: idx(json.archive_viewer.list_border),
+        title: idx(json.archive_viewer.title),
+        path_text: idx(json.archive_viewer.path_text),
+        count_text: idx(json.archive_viewer.count_text),
+        arrow_text: idx(json.archive_viewer.arrow_text),
+        dest_text: idx(json.archive_viewer.dest_text),
+        empty_text: idx(json.archive_viewer.empty_text),
+        directory_text: idx(json.archive_viewer.directory_text),
+        file_text: idx(json.archive_viewer.file_text),
+        marked_text: idx(json.archive_viewer.marked_text),
+        size_text: idx(json.archive_viewer.size_text),
+        date_text: idx(json.archive_viewer.date_text),
+        selected_bg: idx(json.archive_viewer.selected_bg),
+        selected_text: idx(json.archive_viewer.selected_text),
+        message_text: idx(json.archive_viewer.message_text),
+        footer_bg: idx(json.archive_viewer.footer_bg),
+        footer_key: idx(json.archive_viewer.footer_key),
+        footer_text: idx(json.archive_viewer.footer_text),
+    };
+
+    let trash_screen = TrashScreenColors {
+        bg: idx(json.trash_screen.bg),
+        border: idx(json.trash_screen.border),
+        title: idx(json.trash_screen.title),
+        label_text: idx(json.trash_screen.label_text),
+        path_text: idx(json.trash_screen.path_text),
+        count_text: idx(json.trash_screen.count_text),
+        empty_text: idx(json.trash_screen.empty_text),
+        date_text: idx(json.trash_screen.date_text),
+        size_text: idx(json.trash_screen.size_text),
+        directory_text: idx(json.trash_screen.directory_text),
+        file_text: idx(json.trash_screen.file_text),
+        origin_text: idx(json.trash_screen.origin_text),
+        confirm_text: idx(json.trash_screen.confirm_text),
+        message_text: idx(json.trash_screen.message_text),
+        footer_key: idx(json.trash_screen.footer_key),
+        footer_text: idx(json.trash_screen.footer_text),
+    };
+
+    let jobs_screen = JobsScreenColors {
+        summary_text: idx(json.jobs_screen.summary_text),
+        summary_bg: idx(json.jobs_screen.summary_bg),
+        bg: idx(json.jobs_screen.bg),
+        border: idx(json.jobs_screen.border),
+        border_active: idx(json.jobs_screen.border_active),
+        title: idx(json.jobs_screen.title),
+        id_text: idx(json.jobs_screen.id_text),
+        running_text: idx(json.jobs_screen.running_text),
+        done_text: idx(json.jobs_screen.done_text),
+        failed_text: idx(json.jobs_screen.failed_text),
+        selected_bg: idx(json.jobs_screen.selected_bg),
+        selected_text: idx(json.jobs_screen.selected_text),
+    };
+
+    let bulk_rename = BulkRenameColors {
+        bg: idx(json.bulk_rename.bg),
+        border: idx(json.bulk_rename.border),
+        title: idx(json.bulk_rename.title),
+        label_text: idx(json.bulk_rename.label_text),
+        label_active: idx(json.bulk_rename.label_active),
+        input_text: idx(json.bulk_rename.input_text),
+        input_cursor: idx(json.bulk_rename.input_cursor),
+        hint_text: idx(json.bulk_rename.hint_text),
+        list_border_active: idx(json.bulk_rename.list_border_active),
+        old_name: idx(json.bulk_rename.old_name),
+        arrow_text: idx(json.bulk_rename.arrow_text),
+        new_name: idx(json.bulk_rename.new_name),
+        unchanged_name: idx(json.bulk_rename.unchanged_name),
+        invalid_name: idx(json.bulk_rename.invalid_name),
+        edit_text: idx(json.bulk_rename.edit_text),
+        status_text: idx(json.bulk_rename.status_text),
+        error_text: idx(json.bulk_rename.error_text),
+        footer_key: idx(json.bulk_rename.footer_key),
+        footer_text: idx(json.bulk_rename.footer_text),
+    };
+
+    let preview_pane = PreviewPaneColors {
+        bg: idx(json.preview_pane.bg),
+        border: idx(json.preview_pane.border),
+        title: idx(json.preview_pane.title),
+        text: idx(json.preview_pane.text),
+        dim_text: idx(json.preview_pane.dim_text),
+        directory_text: idx(json.preview_pane.directory_text),
+        size_text: idx(json.preview_pane.size_text),
+    };
+
+    let drives_screen = DrivesScreenColors {
+        bg: idx(json.drives_screen.bg),
+        border: idx(json.drives_screen.border),
+        title: idx(json.drives_screen.title),
+        empty_text: idx(json.drives_screen.empty_text),
+        mount_text: idx(json.drives_screen.mount_text),
+        bar_fill: idx(json.drives_screen.bar_fill),
+        bar_full: idx(json.drives_screen.bar_full),
+        bar_empty: idx(json.drives_screen.bar_empty),
+        percent_text: idx(json.drives_screen.percent_text),
+        space_text: idx(json.drives_screen.space_text),
+        unavailable_text: idx(json.drives_screen.unavailable_text),
+        label_text: idx(json.drives_screen.label_text),
+        value_text: idx(json.drives_screen.value_text),
+        footer_key: idx(json.drives_screen.footer_key),
+        footer_text: idx(json.drives_screen.footer_text),
+    };
+
+    let recovery_screen = RecoveryScreenColors {
+        bg: idx(json.recovery_screen.bg),
+        border: idx(json.recovery_screen.border),
+        title: idx(json.recovery_screen.title),
+        intro_text: idx(json.recovery_screen.intro_text),
+        empty_text: idx(json.recovery_screen.empty_text),
+        tag_text: idx(json.recovery_screen.tag_text),
+        item_text: idx(json.recovery_screen.item_text),
+        label_text: idx(json.recovery_screen.label_text),
+        partial_path: idx(json.recovery_screen.partial_path),
+        hint_text: idx(json.recovery_screen.hint_text),
+        message_text: idx(json.recovery_screen.message_text),
+        footer_key: idx(json.recovery_screen.footer_key),
+        footer_text: idx(json.recovery_screen.footer_text),
+    };
+
+    let tree_sidebar = TreeSidebarColors {
+        bg: idx(json.tree_sidebar.bg),
+        border: idx(json.tree_sidebar.border),
+        border_active: idx(json.tree_sidebar.border_active),
+        title: idx(json.tree_sidebar.title),
+        title_active: idx(json.tree_sidebar.title_active),
+        message_text: idx(json.tree_sidebar.message_text),
+        directory_text: idx(json.tree_sidebar.directory_text),
+        cursor_text: idx(json.tree_sidebar.cursor_text),
+        selected_bg: idx(json.tree_sidebar.selected_bg),
+        selected_text: idx(json.tree_sidebar.selected_text),
+    };
+
+    let cleanup_screen = CleanupScreenColors {
+        bg: idx(json.cleanup_screen.bg),
+        border: idx(json.cleanup_screen.border),
+        title: idx(json.cleanup_screen.title),
+        label_text: idx(json.cleanup_screen.label_text),
+        target_text: idx(json.cleanup_screen.target_text),
+        phase_text: idx(json.cleanup_screen.phase_text),
+        found_size: idx(json.cleanup_screen.found_size),
+        marked_size: idx(json.cleanup_screen.marked_size),
+        freed_size: idx(json.cleanup_screen.freed_size),
+        empty_text: idx(json.cleanup_screen.empty_text),
+        arrow_text: idx(json.cleanup_screen.arrow_text),
+        check_mark: idx(json.cleanup_screen.check_mark),
+        category_size: idx(json.cleanup_screen.category_size),
+        category_name: idx(json.cleanup_screen.category_name),
+        category_count: idx(json.cleanup_screen.category_count),
+        item_size: idx(json.cleanup_screen.item_size),
+        item_path: idx(json.cleanup_screen.item_path),
+        confirm_text: idx(json.cleanup_screen.confirm_text),
+        progress_text: idx(json.cleanup_screen.progress_text),
+        message_text: idx(json.cleanup_screen.message_text),
+        footer_key: idx(json.cleanup_screen.footer_key),
+        footer_text: idx(json.cleanup_screen.footer_text),
+    };
+
+    let backup_screen = BackupScreenColors {
+        bg: idx(json.backup_screen.bg),
+        border: idx(json.backup_screen.border),
+        title: idx(json.backup_screen.title),
+        message_text: idx(json.backup_screen.message_text),
+        label_text: idx(json.backup_screen.label_text),
+        value_text: idx(json.backup_screen.value_text),
+        dim_text: idx(json.backup_screen.dim_text),
+        ok_text: idx(json.backup_screen.ok_text),
+        error_text: idx(json.backup_screen.error_text),
+        form_selected: idx(json.backup_screen.form_selected),
+        footer_key: idx(json.backup_screen.footer_key),
+        footer_text: idx(json.backup_screen.footer_text),
+    };
+
+    let timeline_screen = TimelineScreenColors {
+        bg: idx(json.timeline_screen.bg),
+        border: idx(json.timeline_screen.border),
+        title: idx(json.timeline_screen.title),
+        root_text: idx(json.timeline_screen.root_text),
+        window_text: idx(json.timeline_screen.window_text),
+        info_text: idx(json.timeline_screen.info_text),
+        empty_text: idx(json.timeline_screen.empty_text),
+        age_text: idx(json.timeline_screen.age_text),
+        time_text: idx(json.timeline_screen.time_text),
+        size_text: idx(json.timeline_screen.size_text),
+        path_text: idx(json.timeline_screen.path_text),
+        footer_key: idx(json.timeline_screen.footer_key),
+        footer_text: idx(json.timeline_screen.footer_text),
+    };
+
+    let git_log_screen = GitLogScreenColors {
+        bg: idx(json.git_log_screen.bg),
+        header_path: idx(json.git_log_screen.header_path),
+        header_scope: idx(json.git_log_screen.header_scope),
+        graph: idx(json.git_log_screen.graph),
+        marker: idx(json.git_log_screen.marker),
+        log_hash: idx(json.git_log_screen.log_hash),
+        refs: idx(json.git_log_screen.refs),
+        log_subject: idx(json.git_log_screen.log_subject),
+        log_author: idx(json.git_log_screen.log_author),
+        log_date: idx(json.git_log_screen.log_date),
+        selected_bg: idx(json.git_log_screen.selected_bg),
+        selected_text: idx(json.git_log_screen.selected_text),
+        detail_subject: idx(json.git_log_screen.detail_subject),
+        file_name: idx(json.git_log_screen.file_name),
+        file_added: idx(json.git_log_screen.file_added),
+        file_deleted: idx(json.git_log_screen.file_deleted),
+        file_renamed: idx(json.git_log_screen.file_renamed),
+        file_modified: idx(json.git_log_screen.file_modified),
+        empty_text: idx(json.git_log_screen.empty_text),
+        message_text: idx(json.git_log_screen.message_text),
+        footer_key: idx(json.git_log_screen.footer_key),
+        footer_text: idx(json.git_log_screen.footer_text),
+    };
+
+    let favorites_bar = FavoritesBarColors {
+        key: idx(json.favorites_bar.key),
+        label: idx(json.favorites_bar.label),
+        current: idx(json.favorites_bar.current),
+        empty_text: idx(json.favorites_bar.empty_text),
+    };
+
     Theme {
         palette,
         state,
@@ -1566,6 +2759,21 @@ pub fn theme_from_json(json: &ThemeJson) -> Theme {
         diff_file_view,
         git_screen,
         dedup_screen,
+        checksum_screen,
+        audit_screen,
+        archive_viewer,
+        trash_screen,
+        jobs_screen,
+        bulk_rename,
+        preview_pane,
+        drives_screen,
+        recovery_screen,
+        tree_sidebar,
+        cleanup_screen,
+        backup_screen,
+        timeline_screen,
+        git_log_screen,
+        favorites_bar,
         chars: ThemeChars::default(),
     }
 }
@@ -0,0 +1,221 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::services::timeline::{self, TimeWindow, TimelineEntry};
+use crate::ui::theme::Theme;
+use crate::utils::format::{format_relative_age, format_short_datetime, format_size};
+
+/// 기본 탐색 깊이와 최대값
+const DEFAULT_DEPTH: usize = 5;
+const MAX_DEPTH: usize = 20;
+
+pub struct TimelineScreenState {
+    pub root: PathBuf,
+    pub window: TimeWindow,
+    pub depth: usize,
+    entries: Vec<TimelineEntry>,
+    pub selected: usize,
+    pub scroll: usize,
+    pub scanning: bool,
+    /// File chosen with Enter (the active panel moves to it on close)
+    pub chosen: Option<PathBuf>,
+    receiver: Option<Receiver<Vec<TimelineEntry>>>,
+    cancel_flag: Arc<AtomicBool>,
+}
+
+impl TimelineScreenState {
+    pub fn new(root: PathBuf) -> Self {
+        let mut state = Self {
+            root,
+            window: TimeWindow::Today,
+            depth: DEFAULT_DEPTH,
+            entries: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            scanning: false,
+            chosen: None,
+            receiver: None,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+        };
+        state.rescan();
+        state
+    }
+
+    /// Restart the scan with the current window and depth
+    fn rescan(&mut self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.cancel_flag = Arc::new(AtomicBool::new(false));
+        let flag_clone = self.cancel_flag.clone();
+        let root = self.root.clone();
+        let depth = self.depth;
+        let since = self.window.since(SystemTime::now());
+        std::thread::spawn(move || {
+            let _ = tx.send(timeline::recent_files(&root, depth, since, &flag_clone));
+        });
+        self.receiver = Some(rx);
+        self.scanning = true;
+    }
+
+    pub fn poll(&mut self) {
+        let Some(ref rx) = self.receiver else { return };
+        if let Ok(entries) = rx.try_recv() {
+            self.entries = entries;
+            self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+            self.scanning = false;
+            self.receiver = None;
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let max = self.entries.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+}
+
+impl Drop for TimelineScreenState {
+    fn drop(&mut self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut TimelineScreenState, area: Rect, theme: &Theme) {
+    state.poll();
+
+    let colors = &theme.timeline_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // info box
+            Constraint::Min(3),    // files
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Info box ──
+    let info_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Recent Changes ", Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+    let found = if state.scanning {
+        "Scanning...".to_string()
+    } else if state.entries.len() >= timeline::MAX_ENTRIES {
+        format!("newest {} files", state.entries.len())
+    } else {
+        format!("{} files", state.entries.len())
+    };
+    let info = Line::from(vec![
+        Span::styled(state.root.display().to_string(), Style::default().fg(colors.root_text)),
+        Span::styled("  |  ", Style::default().fg(colors.info_text)),
+        Span::styled(state.window.label(), Style::default().fg(colors.window_text).add_modifier(Modifier::BOLD)),
+        Span::styled(format!("  |  depth {}", state.depth), Style::default().fg(colors.info_text)),
+        Span::styled(format!("  |  {}", found), Style::default().fg(colors.info_text)),
+    ]);
+    frame.render_widget(Paragraph::new(info).block(info_block), chunks[0]);
+
+    // ── Files ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    let now = SystemTime::now();
+    let lines: Vec<Line> = if state.entries.is_empty() {
+        let text = if state.scanning { "Looking for changes..." } else { "No files changed in this period" };
+        vec![Line::from(Span::styled(text, Style::default().fg(colors.empty_text)))]
+    } else {
+        state.entries.iter().enumerate().skip(state.scroll).take(inner_height).map(|(i, entry)| {
+            let age = now.duration_since(entry.modified).map(|d| d.as_secs()).unwrap_or(0);
+            let time: DateTime<Local> = entry.modified.into();
+            let shown = entry.path.strip_prefix(&state.root).unwrap_or(&entry.path);
+            let line = Line::from(vec![
+                Span::styled(format!("{:>9}  ", format_relative_age(age)), Style::default().fg(colors.age_text)),
+                Span::styled(format!("{}  ", format_short_datetime(&time)), Style::default().fg(colors.time_text)),
+                Span::styled(format!("{:>9}  ", format_size(entry.size)), Style::default().fg(colors.size_text)),
+                Span::styled(shown.display().to_string(), Style::default().fg(colors.path_text)),
+            ]);
+            if i == state.selected {
+                line.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        }).collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(" Close  "),
+        key("Enter"),
+        text(" Go to file  "),
+        key("t"),
+        text(" Time window  "),
+        key("+/-"),
+        text(" Depth  "),
+        key("F5"),
+        text(" Rescan"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[2]);
+}
+
+/// Returns true when the screen should close
+pub fn handle_input(state: &mut TimelineScreenState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => return true,
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.entries.len().saturating_sub(1),
+        KeyCode::Enter => {
+            if let Some(entry) = state.entries.get(state.selected) {
+                state.chosen = Some(entry.path.clone());
+                return true;
+            }
+        }
+        KeyCode::Char('t') => {
+            state.window = state.window.next();
+            state.rescan();
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') if state.depth < MAX_DEPTH => {
+            state.depth += 1;
+            state.rescan();
+        }
+        KeyCode::Char('-') if state.depth > 1 => {
+            state.depth -= 1;
+            state.rescan();
+        }
+        KeyCode::F(5) => state.rescan(),
+        _ => {}
+    }
+    false
+}
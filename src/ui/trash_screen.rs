@@ -0,0 +1,280 @@
+use std::sync::mpsc::{self, Receiver};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::services::trash::{self, TrashItem};
+use crate::ui::theme::Theme;
+use crate::utils::format::format_size;
+
+/// 확인이 필요한 영구 삭제 작업
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PendingPurge {
+    Selected,
+    All,
+}
+
+pub struct TrashScreenState {
+    /// Most recently deleted first
+    pub items: Vec<TrashItem>,
+    pub selected: usize,
+    pub scroll: usize,
+    pub message: String,
+    pending: Option<PendingPurge>,
+    /// Something was restored (panels need a refresh on close)
+    pub restored: bool,
+    /// Directory sizes measured in the background (name under `files/`, size)
+    size_receiver: Option<Receiver<(String, u64)>>,
+}
+
+impl TrashScreenState {
+    pub fn new() -> Self {
+        let mut state = Self {
+            items: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            message: String::new(),
+            pending: None,
+            restored: false,
+            size_receiver: None,
+        };
+        state.reload();
+        state
+    }
+
+    pub fn reload(&mut self) {
+        self.items = trash::list();
+        self.selected = self.selected.min(self.items.len().saturating_sub(1));
+
+        let unmeasured: Vec<TrashItem> = self.items.iter().filter(|i| i.size.is_none()).cloned().collect();
+        if unmeasured.is_empty() {
+            self.size_receiver = None;
+            return;
+        }
+        let (tx, rx) = mpsc::channel();
+        self.size_receiver = Some(rx);
+        std::thread::spawn(move || {
+            for item in unmeasured {
+                // Receiver dropped: the screen was closed or reloaded
+                if tx.send((item.name.clone(), trash::stored_size(&item))).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+
+    fn poll(&mut self) {
+        let Some(ref rx) = self.size_receiver else { return };
+        for (name, size) in rx.try_iter() {
+            if let Some(item) = self.items.iter_mut().find(|i| i.name == name) {
+                item.size = Some(size);
+            }
+        }
+        if self.items.iter().all(|i| i.size.is_some()) {
+            self.size_receiver = None;
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.items.is_empty() {
+            return;
+        }
+        let max = self.items.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    fn restore_selected(&mut self) {
+        let Some(item) = self.items.get(self.selected) else { return };
+        self.message = match trash::restore(item) {
+            Ok(path) => {
+                self.restored = true;
+                format!("Restored to {}", path.display())
+            }
+            Err(e) => format!("Restore failed: {}", e),
+        };
+        self.reload();
+    }
+
+    fn purge(&mut self, what: PendingPurge) {
+        let targets: Vec<TrashItem> = match what {
+            PendingPurge::Selected => self.items.get(self.selected).cloned().into_iter().collect(),
+            PendingPurge::All => self.items.clone(),
+        };
+        let total = targets.len();
+        let mut last_error = None;
+        for item in &targets {
+            if let Err(e) = trash::purge(item) {
+                last_error = Some(format!("{}: {}", item.name, e));
+            }
+        }
+        self.message = match last_error {
+            None => format!("Permanently deleted {} item(s)", total),
+            Some(e) => format!("Delete failed: {}", e),
+        };
+        self.reload();
+    }
+}
+
+pub fn draw(frame: &mut Frame, state: &mut TrashScreenState, area: Rect, theme: &Theme) {
+    state.poll();
+
+    let colors = &theme.trash_screen;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // info box
+            Constraint::Min(3),    // entries
+            Constraint::Length(3), // original path / status
+            Constraint::Length(1), // footer
+        ])
+        .split(area);
+
+    // ── Info box ──
+    let info_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .title(Span::styled(" Trash ", Style::default().fg(colors.title).add_modifier(Modifier::BOLD)))
+        .style(Style::default().bg(colors.bg));
+    let trash_path = trash::trash_dir().map(|p| p.display().to_string()).unwrap_or_default();
+    let total_size: u64 = state.items.iter().filter_map(|i| i.size).sum();
+    // "+" while directories are still being measured
+    let measuring = if state.size_receiver.is_some() { "+" } else { "" };
+    let info = Line::from(vec![
+        Span::styled("Location: ", Style::default().fg(colors.label_text)),
+        Span::styled(trash_path, Style::default().fg(colors.path_text)),
+        Span::raw("  "),
+        Span::styled(
+            format!("[{} items, {}{}]", state.items.len(), format_size(total_size), measuring),
+            Style::default().fg(colors.count_text).add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(info).block(info_block), chunks[0]);
+
+    // ── Entries ──
+    let list_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    if state.selected < state.scroll {
+        state.scroll = state.selected;
+    } else if inner_height > 0 && state.selected >= state.scroll + inner_height {
+        state.scroll = state.selected + 1 - inner_height;
+    }
+
+    let lines: Vec<Line> = if state.items.is_empty() {
+        vec![Line::from(Span::styled("Trash is empty", Style::default().fg(colors.empty_text)))]
+    } else {
+        state
+            .items
+            .iter()
+            .enumerate()
+            .skip(state.scroll)
+            .take(inner_height)
+            .map(|(i, item)| {
+                let name = item
+                    .original_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| item.name.clone());
+                let (name, name_style) = if item.is_dir {
+                    (format!("{}/", name), Style::default().fg(colors.directory_text))
+                } else {
+                    (name, Style::default().fg(colors.file_text))
+                };
+                let size = item.size.map(format_size).unwrap_or_else(|| "...".to_string());
+                let line = Line::from(vec![
+                    Span::styled(format!("{}  ", item.deleted_at.replace('T', " ")), Style::default().fg(colors.date_text)),
+                    Span::styled(format!("{:>10}  ", size), Style::default().fg(colors.size_text)),
+                    Span::styled(name, name_style),
+                ]);
+                if i == state.selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+    frame.render_widget(Paragraph::new(lines).block(list_block), chunks[1]);
+
+    // ── Original path / status ──
+    let detail_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let detail = match state.pending {
+        Some(PendingPurge::Selected) => Line::from(Span::styled(
+            "Permanently delete the selected item? (y/n)",
+            Style::default().fg(colors.confirm_text).add_modifier(Modifier::BOLD),
+        )),
+        Some(PendingPurge::All) => Line::from(Span::styled(
+            format!("Permanently delete all {} items? (y/n)", state.items.len()),
+            Style::default().fg(colors.confirm_text).add_modifier(Modifier::BOLD),
+        )),
+        None if !state.message.is_empty() => {
+            Line::from(Span::styled(state.message.clone(), Style::default().fg(colors.message_text)))
+        }
+        None => match state.items.get(state.selected) {
+            Some(item) => Line::from(vec![
+                Span::styled("From: ", Style::default().fg(colors.label_text)),
+                Span::styled(item.original_path.display().to_string(), Style::default().fg(colors.origin_text)),
+            ]),
+            None => Line::default(),
+        },
+    };
+    frame.render_widget(Paragraph::new(detail).block(detail_block), chunks[2]);
+
+    // ── Footer ──
+    let key = |k: &'static str| Span::styled(k, Style::default().fg(colors.footer_key).add_modifier(Modifier::BOLD));
+    let text = |t: &'static str| Span::styled(t, Style::default().fg(colors.footer_text));
+    let footer = Paragraph::new(Line::from(vec![
+        key(" Esc"),
+        text(" Close  "),
+        key("Enter/r"),
+        text(" Restore  "),
+        key("Del/d"),
+        text(" Delete  "),
+        key("E"),
+        text(" Empty trash"),
+    ]))
+    .style(Style::default().bg(colors.bg));
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// Handle input. Returns true if screen should close.
+pub fn handle_input(state: &mut TrashScreenState, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    // 영구 삭제 확인 중에는 y만 실행, 나머지 키는 취소
+    if let Some(pending) = state.pending.take() {
+        if matches!(code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            state.purge(pending);
+        }
+        return false;
+    }
+    state.message.clear();
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => return true,
+        KeyCode::Up => state.move_selection(-1),
+        KeyCode::Down => state.move_selection(1),
+        KeyCode::PageUp => state.move_selection(-10),
+        KeyCode::PageDown => state.move_selection(10),
+        KeyCode::Home => state.selected = 0,
+        KeyCode::End => state.selected = state.items.len().saturating_sub(1),
+        KeyCode::Enter | KeyCode::Char('r') => state.restore_selected(),
+        KeyCode::Delete | KeyCode::Char('d') if !state.items.is_empty() => {
+            state.pending = Some(PendingPurge::Selected);
+        }
+        KeyCode::Char('E') if !state.items.is_empty() => state.pending = Some(PendingPurge::All),
+        KeyCode::F(5) => state.reload(),
+        _ => {}
+    }
+    false
+}
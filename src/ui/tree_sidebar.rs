@@ -0,0 +1,374 @@
+//! Directory tree sidebar on the left edge of the panel area.
+//!
+//! The tree starts at `/` and follows the active panel: the panel's folder
+//! and its ancestors are expanded and highlighted. Children are read only
+//! when a node is expanded, and collapsing a node drops its subtree, so
+//! expanding it again shows the current contents.
+//!
+//! The tree is kept as a flat list in display order: an expanded node is
+//! followed by its children (depth + 1), which keeps drawing and moving the
+//! cursor simple.
+
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::ui::theme::Theme;
+
+#[derive(Debug, Clone)]
+struct TreeNode {
+    path: PathBuf,
+    name: String,
+    depth: usize,
+    expanded: bool,
+    /// Expanded and found to have no subdirectories
+    leaf: bool,
+}
+
+impl TreeNode {
+    fn new(path: PathBuf, depth: usize) -> Self {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+        Self { path, name, depth, expanded: false, leaf: false }
+    }
+}
+
+pub struct TreeSidebar {
+    nodes: Vec<TreeNode>,
+    pub selected: usize,
+    scroll: usize,
+    /// Keys go to the tree instead of the panel
+    pub focused: bool,
+    /// Include dot directories (follows the active panel)
+    show_hidden: bool,
+    /// Folder of the active panel the tree was last synced to
+    current: Option<PathBuf>,
+    /// Shown instead of the tree (remote panels)
+    message: Option<String>,
+    /// Folder chosen with Enter; the active panel moves there
+    pub chosen: Option<PathBuf>,
+}
+
+/// Subdirectories of `dir`, sorted by name (case insensitive)
+fn read_subdirs(dir: &Path, show_hidden: bool) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| show_hidden || !e.file_name().to_string_lossy().starts_with('.'))
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort_by_cached_key(|p| p.file_name().map(|n| n.to_string_lossy().to_lowercase()).unwrap_or_default());
+    dirs
+}
+
+impl TreeSidebar {
+    pub fn new(show_hidden: bool) -> Self {
+        Self {
+            nodes: vec![TreeNode::new(PathBuf::from("/"), 0)],
+            selected: 0,
+            scroll: 0,
+            focused: false,
+            show_hidden,
+            current: None,
+            message: None,
+            chosen: None,
+        }
+    }
+
+    /// Follow the active panel: expand down to `path` and select it.
+    /// `None` (remote panel) replaces the tree with a message.
+    pub fn sync(&mut self, path: Option<&Path>, show_hidden: bool) {
+        let Some(path) = path else {
+            self.message = Some("Not available for remote panels".to_string());
+            self.current = None;
+            return;
+        };
+        self.message = None;
+        if show_hidden != self.show_hidden {
+            *self = Self { focused: self.focused, ..Self::new(show_hidden) };
+        }
+        if self.current.as_deref() == Some(path) {
+            return;
+        }
+        self.current = Some(path.to_path_buf());
+        self.reveal(path);
+    }
+
+    /// Expand the ancestors of `path` and select its node
+    fn reveal(&mut self, path: &Path) {
+        let mut index = 0;
+        // `/` is the root node; walk the remaining ancestors from the top
+        let mut ancestors: Vec<&Path> = path.ancestors().collect();
+        ancestors.reverse();
+        for ancestor in ancestors.into_iter().skip(1) {
+            self.expand(index);
+            let end = self.subtree_end(index);
+            index = match (index + 1..end).find(|&i| self.nodes[i].path == ancestor) {
+                Some(i) => i,
+                None => {
+                    // Hidden or unreadable ancestor: show it anyway so the path stays reachable
+                    let depth = self.nodes[index].depth + 1;
+                    self.nodes[index].leaf = false;
+                    self.nodes.insert(index + 1, TreeNode::new(ancestor.to_path_buf(), depth));
+                    index + 1
+                }
+            };
+        }
+        self.selected = index;
+    }
+
+    /// Index after the last descendant of `index`
+    fn subtree_end(&self, index: usize) -> usize {
+        let depth = self.nodes[index].depth;
+        (index + 1..self.nodes.len())
+            .find(|&i| self.nodes[i].depth <= depth)
+            .unwrap_or(self.nodes.len())
+    }
+
+    fn expand(&mut self, index: usize) {
+        if self.nodes[index].expanded {
+            return;
+        }
+        let depth = self.nodes[index].depth + 1;
+        let children: Vec<TreeNode> = read_subdirs(&self.nodes[index].path, self.show_hidden)
+            .into_iter()
+            .map(|p| TreeNode::new(p, depth))
+            .collect();
+        let node = &mut self.nodes[index];
+        node.expanded = true;
+        node.leaf = children.is_empty();
+        self.nodes.splice(index + 1..index + 1, children);
+    }
+
+    fn collapse(&mut self, index: usize) {
+        let end = self.subtree_end(index);
+        self.nodes.drain(index + 1..end);
+        self.nodes[index].expanded = false;
+        if self.selected >= end {
+            self.selected -= end - index - 1;
+        } else if self.selected > index {
+            self.selected = index;
+        }
+    }
+
+    fn parent_of(&self, index: usize) -> Option<usize> {
+        let depth = self.nodes[index].depth;
+        (0..index).rev().find(|&i| self.nodes[i].depth < depth)
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let max = self.nodes.len() as isize - 1;
+        self.selected = (self.selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    pub fn selected_path(&self) -> Option<&Path> {
+        self.nodes.get(self.selected).map(|n| n.path.as_path())
+    }
+}
+
+/// Width of the sidebar for a panel area of `width` columns (0 = too narrow to show)
+pub fn width_for(width: u16) -> u16 {
+    if width < 60 {
+        0
+    } else {
+        (width / 4).clamp(20, 40)
+    }
+}
+
+pub fn draw(frame: &mut Frame, tree: &mut TreeSidebar, area: Rect, theme: &Theme) {
+    let border = if tree.focused { theme.tree_sidebar.border_active } else { theme.tree_sidebar.border };
+    let block = Block::default()
+        .title(" Tree ")
+        .title_style(Style::default().fg(if tree.focused { theme.tree_sidebar.title_active } else { theme.tree_sidebar.title }))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border))
+        .style(Style::default().bg(theme.tree_sidebar.bg));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if let Some(ref message) = tree.message {
+        frame.render_widget(
+            Paragraph::new(message.as_str()).style(Style::default().fg(theme.tree_sidebar.message_text)),
+            inner,
+        );
+        return;
+    }
+
+    let height = inner.height as usize;
+    if tree.selected < tree.scroll {
+        tree.scroll = tree.selected;
+    } else if height > 0 && tree.selected >= tree.scroll + height {
+        tree.scroll = tree.selected + 1 - height;
+    }
+
+    let lines: Vec<Line> = tree
+        .nodes
+        .iter()
+        .enumerate()
+        .skip(tree.scroll)
+        .take(height)
+        .map(|(i, node)| {
+            let marker = if !node.expanded {
+                "▸ "
+            } else if node.leaf {
+                "  "
+            } else {
+                "▾ "
+            };
+            let cursor = if crate::ui::theme::text_markers() {
+                if i == tree.selected { "> " } else { "  " }
+            } else {
+                ""
+            };
+            let text = format!("{}{}{}{}", cursor, "  ".repeat(node.depth), marker, node.name);
+            let style = if i == tree.selected && tree.focused {
+                Style::default().fg(theme.tree_sidebar.selected_text).bg(theme.tree_sidebar.selected_bg)
+            } else if i == tree.selected {
+                Style::default().fg(theme.tree_sidebar.cursor_text).add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+            } else {
+                Style::default().fg(theme.tree_sidebar.directory_text)
+            };
+            Line::from(Span::styled(text, style))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Handle input while the tree has focus. Returns true to give focus back to the panel.
+pub fn handle_input(tree: &mut TreeSidebar, code: KeyCode, _modifiers: KeyModifiers) -> bool {
+    if tree.message.is_some() {
+        return matches!(code, KeyCode::Esc | KeyCode::Tab);
+    }
+    match code {
+        KeyCode::Esc | KeyCode::Tab => return true,
+        KeyCode::Up => tree.move_selection(-1),
+        KeyCode::Down => tree.move_selection(1),
+        KeyCode::PageUp => tree.move_selection(-10),
+        KeyCode::PageDown => tree.move_selection(10),
+        KeyCode::Home => tree.selected = 0,
+        KeyCode::End => tree.selected = tree.nodes.len() - 1,
+        KeyCode::Right => {
+            // 펼치기, 이미 펼쳐져 있으면 첫 자식으로
+            let index = tree.selected;
+            if tree.nodes[index].expanded {
+                if !tree.nodes[index].leaf {
+                    tree.selected = index + 1;
+                }
+            } else {
+                tree.expand(index);
+            }
+        }
+        KeyCode::Left => {
+            // 접기, 이미 접혀 있으면 부모로
+            let index = tree.selected;
+            if tree.nodes[index].expanded && !tree.nodes[index].leaf {
+                tree.collapse(index);
+            } else if let Some(parent) = tree.parent_of(index) {
+                tree.selected = parent;
+            }
+        }
+        KeyCode::Enter => {
+            tree.chosen = tree.selected_path().map(Path::to_path_buf);
+            // 패널이 옮겨 간 폴더를 다시 따라가지 않도록 현재 위치로 기록
+            tree.current = tree.chosen.clone();
+        }
+        _ => {}
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(tree: &TreeSidebar) -> Vec<String> {
+        tree.nodes.iter().map(|n| format!("{}{}", "-".repeat(n.depth), n.name)).collect()
+    }
+
+    #[test]
+    fn test_reveal_expands_ancestors() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(base.join("b/deep")).unwrap();
+        std::fs::create_dir_all(base.join("A")).unwrap();
+        std::fs::create_dir_all(base.join(".hidden/inner")).unwrap();
+        std::fs::write(base.join("file.txt"), "x").unwrap();
+
+        let mut tree = TreeSidebar::new(false);
+        tree.sync(Some(&base.join("b")), false);
+        assert_eq!(tree.selected_path(), Some(base.join("b").as_path()));
+        let start = tree.nodes.iter().position(|n| n.path == base).unwrap();
+        let depth = tree.nodes[start].depth;
+        // Directories only, sorted case insensitively, dot directories hidden
+        let children: Vec<&str> = tree.nodes[start + 1..]
+            .iter()
+            .take_while(|n| n.depth > depth)
+            .filter(|n| n.depth == depth + 1)
+            .map(|n| n.name.as_str())
+            .collect();
+        assert_eq!(children, vec!["A", "b"]);
+        // b itself is not expanded until asked
+        assert!(!tree.nodes[tree.selected].expanded);
+
+        // A hidden folder on the panel's path is still shown
+        tree.sync(Some(&base.join(".hidden/inner")), false);
+        assert_eq!(tree.selected_path(), Some(base.join(".hidden/inner").as_path()));
+    }
+
+    #[test]
+    fn test_expand_collapse_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(base.join("a/x")).unwrap();
+        std::fs::create_dir_all(base.join("a/y")).unwrap();
+        std::fs::create_dir_all(base.join("b")).unwrap();
+
+        let mut tree = TreeSidebar::new(false);
+        tree.sync(Some(&base.join("a")), false);
+        let a = tree.selected;
+        let before = tree.nodes.len();
+
+        handle_input(&mut tree, KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(tree.nodes.len(), before + 2);
+        handle_input(&mut tree, KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(tree.selected_path(), Some(base.join("a/x").as_path()));
+
+        // x has no subdirectories: Left goes to the parent, then collapses it
+        handle_input(&mut tree, KeyCode::Right, KeyModifiers::NONE);
+        assert!(tree.nodes[tree.selected].leaf);
+        handle_input(&mut tree, KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(tree.selected, a);
+        handle_input(&mut tree, KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(tree.nodes.len(), before);
+        assert!(names(&tree).iter().all(|n| !n.ends_with("-x")));
+
+        handle_input(&mut tree, KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(tree.chosen.as_deref(), Some(base.join("a").as_path()));
+        assert!(handle_input(&mut tree, KeyCode::Esc, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_collapse_keeps_selection_below() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(base.join("a/x")).unwrap();
+        std::fs::create_dir_all(base.join("b")).unwrap();
+
+        let mut tree = TreeSidebar::new(false);
+        tree.sync(Some(&base.join("a/x")), false);
+        tree.sync(Some(&base.join("b")), false);
+        let a = tree.nodes.iter().position(|n| n.path == base.join("a")).unwrap();
+        tree.collapse(a);
+        assert_eq!(tree.selected_path(), Some(base.join("b").as_path()));
+    }
+}
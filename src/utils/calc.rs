@@ -0,0 +1,350 @@
+//! Small calculator for the quick-calc dialog: arithmetic with byte units.
+//!
+//! `KiB`/`MiB`/`GiB`/`TiB`/`PiB` and the bare `K`/`M`/`G`/`T`/`P` (as in
+//! `ls -h` and the size filters) are powers of 1024; `kB`/`MB`/`GB`/`TB`/`PB`
+//! are powers of 1000. A trailing `in <unit>` (or `to <unit>`) converts the
+//! result, e.g. `1.5 GiB in MB`.
+
+use crate::utils::format::format_number;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit {
+    pub name: &'static str,
+    pub factor: f64,
+}
+
+const KI: f64 = 1024.0;
+
+/// Recognised units (matched case-insensitively)
+const UNITS: &[Unit] = &[
+    Unit { name: "B", factor: 1.0 },
+    Unit { name: "K", factor: KI },
+    Unit { name: "KiB", factor: KI },
+    Unit { name: "kB", factor: 1e3 },
+    Unit { name: "M", factor: KI * KI },
+    Unit { name: "MiB", factor: KI * KI },
+    Unit { name: "MB", factor: 1e6 },
+    Unit { name: "G", factor: KI * KI * KI },
+    Unit { name: "GiB", factor: KI * KI * KI },
+    Unit { name: "GB", factor: 1e9 },
+    Unit { name: "T", factor: KI * KI * KI * KI },
+    Unit { name: "TiB", factor: KI * KI * KI * KI },
+    Unit { name: "TB", factor: 1e12 },
+    Unit { name: "P", factor: KI * KI * KI * KI * KI },
+    Unit { name: "PiB", factor: KI * KI * KI * KI * KI },
+    Unit { name: "PB", factor: 1e15 },
+];
+
+fn find_unit(name: &str) -> Option<Unit> {
+    UNITS.iter().copied().find(|u| u.name.eq_ignore_ascii_case(name))
+}
+
+/// Evaluated expression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalcResult {
+    pub value: f64,
+    /// The value is a size in bytes (a unit took part in the calculation)
+    pub bytes: bool,
+    /// Unit requested with `in <unit>`
+    pub target: Option<Unit>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Word(String),
+    Op(char),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '0' && chars.get(i + 1).is_some_and(|x| *x == 'x' || *x == 'X') {
+            let start = i + 2;
+            let mut end = start;
+            while end < chars.len() && (chars[end].is_ascii_hexdigit() || chars[end] == '_') {
+                end += 1;
+            }
+            let digits: String = chars[start..end].iter().filter(|c| **c != '_').collect();
+            let n = u64::from_str_radix(&digits, 16).map_err(|_| "invalid hex number".to_string())?;
+            tokens.push(Token::Number(n as f64));
+            i = end;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.' || chars[i] == '_') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().filter(|c| **c != '_').collect();
+            let n: f64 = text.parse().map_err(|_| format!("invalid number '{}'", text))?;
+            tokens.push(Token::Number(n));
+        } else if c.is_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i].is_alphabetic() {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else if "+-*/%^()".contains(c) {
+            tokens.push(Token::Op(c));
+            i += 1;
+        } else {
+            return Err(format!("unexpected '{}'", c));
+        }
+    }
+    Ok(tokens)
+}
+
+/// (value, is_bytes)
+type Quantity = (f64, bool);
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat_op(&mut self, op: char) -> bool {
+        if self.peek() == Some(&Token::Op(op)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expr(&mut self) -> Result<Quantity, String> {
+        let mut left = self.term()?;
+        loop {
+            if self.eat_op('+') {
+                let right = self.term()?;
+                left = (left.0 + right.0, left.1 || right.1);
+            } else if self.eat_op('-') {
+                let right = self.term()?;
+                left = (left.0 - right.0, left.1 || right.1);
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Quantity, String> {
+        let mut left = self.unary()?;
+        loop {
+            if self.eat_op('*') {
+                let right = self.unary()?;
+                left = (left.0 * right.0, left.1 || right.1);
+            } else if self.eat_op('/') {
+                let right = self.unary()?;
+                if right.0 == 0.0 {
+                    return Err("division by zero".to_string());
+                }
+                // 크기 / 크기 = 비율
+                left = (left.0 / right.0, left.1 && !right.1);
+            } else if self.eat_op('%') {
+                let right = self.unary()?;
+                if right.0 == 0.0 {
+                    return Err("division by zero".to_string());
+                }
+                left = (left.0 % right.0, left.1 || right.1);
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Quantity, String> {
+        if self.eat_op('-') {
+            let (v, b) = self.unary()?;
+            return Ok((-v, b));
+        }
+        if self.eat_op('+') {
+            return self.unary();
+        }
+        let base = self.primary()?;
+        if self.eat_op('^') {
+            let exp = self.unary()?;
+            return Ok((base.0.powf(exp.0), base.1));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Quantity, String> {
+        let mut value = match self.tokens.get(self.pos).cloned() {
+            Some(Token::Number(n)) => {
+                self.pos += 1;
+                (n, false)
+            }
+            Some(Token::Op('(')) => {
+                self.pos += 1;
+                let inner = self.expr()?;
+                if !self.eat_op(')') {
+                    return Err("missing ')'".to_string());
+                }
+                inner
+            }
+            Some(Token::Word(w)) => return Err(format!("unexpected '{}'", w)),
+            Some(Token::Op(c)) => return Err(format!("unexpected '{}'", c)),
+            None => return Err("incomplete expression".to_string()),
+        };
+        // 숫자 뒤의 단위: 1.5GiB, (1+2) MB
+        if let Some(Token::Word(w)) = self.peek() {
+            if !is_conversion_keyword(w) {
+                let unit = find_unit(w).ok_or_else(|| format!("unknown unit '{}'", w))?;
+                self.pos += 1;
+                value = (value.0 * unit.factor, true);
+            }
+        }
+        Ok(value)
+    }
+}
+
+fn is_conversion_keyword(word: &str) -> bool {
+    word.eq_ignore_ascii_case("in") || word.eq_ignore_ascii_case("to")
+}
+
+pub fn evaluate(input: &str) -> Result<CalcResult, String> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Err("empty expression".to_string());
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let (value, bytes) = parser.expr()?;
+
+    let mut target = None;
+    if let Some(Token::Word(w)) = parser.peek().cloned() {
+        if is_conversion_keyword(&w) {
+            parser.pos += 1;
+            match parser.peek().cloned() {
+                Some(Token::Word(u)) => {
+                    target = Some(find_unit(&u).ok_or_else(|| format!("unknown unit '{}'", u))?);
+                    parser.pos += 1;
+                }
+                _ => return Err(format!("unit expected after '{}'", w)),
+            }
+        }
+    }
+    if let Some(token) = parser.peek() {
+        return Err(match token {
+            Token::Number(n) => format!("unexpected '{}'", n),
+            Token::Word(w) => format!("unexpected '{}'", w),
+            Token::Op(c) => format!("unexpected '{}'", c),
+        });
+    }
+    if !value.is_finite() {
+        return Err("result is out of range".to_string());
+    }
+    // 단위로 변환하면 일반 숫자도 바이트로 취급
+    let bytes = bytes || target.is_some();
+    Ok(CalcResult { value, bytes, target })
+}
+
+/// Number with at most `decimals` fraction digits, trailing zeros removed
+fn trim_number(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+/// Size in the largest unit of the given family that keeps it >= 1
+fn scaled(bytes: f64, base: f64, names: [&str; 6]) -> String {
+    let mut value = bytes;
+    let mut idx = 0;
+    while value.abs() >= base && idx < names.len() - 1 {
+        value /= base;
+        idx += 1;
+    }
+    format!("{} {}", trim_number(value, 2), names[idx])
+}
+
+/// Text shown under the input: "= 1,610,612,736 B = 1.5 GiB = 1.61 GB"
+pub fn describe(result: &CalcResult) -> String {
+    if let Some(unit) = result.target {
+        return format!("= {} {}", trim_number(result.value / unit.factor, 6), unit.name);
+    }
+    if result.bytes {
+        let exact = if result.value.fract() == 0.0 && result.value >= 0.0 && result.value < u64::MAX as f64 {
+            format_number(result.value as u64)
+        } else {
+            trim_number(result.value, 2)
+        };
+        return format!(
+            "= {} B = {} = {}",
+            exact,
+            scaled(result.value, KI, ["B", "KiB", "MiB", "GiB", "TiB", "PiB"]),
+            scaled(result.value, 1e3, ["B", "kB", "MB", "GB", "TB", "PB"]),
+        );
+    }
+    let plain = trim_number(result.value, 10);
+    if result.value.fract() == 0.0 && result.value >= 0.0 && result.value < u64::MAX as f64 {
+        format!("= {} (0x{:X})", plain, result.value as u64)
+    } else {
+        format!("= {}", plain)
+    }
+}
+
+/// Result as input text, so a calculation can be continued
+pub fn as_input(result: &CalcResult) -> String {
+    match result.target {
+        Some(unit) => format!("{}{}", trim_number(result.value / unit.factor, 6), unit.name),
+        None if result.bytes => format!("{}B", trim_number(result.value, 2)),
+        None => trim_number(result.value, 10),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(input: &str) -> f64 {
+        evaluate(input).unwrap().value
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(value("1 + 2 * 3"), 7.0);
+        assert_eq!(value("(1 + 2) * 3"), 9.0);
+        assert_eq!(value("-2^2"), -4.0);
+        assert_eq!(value("2^3^2"), 512.0);
+        assert_eq!(value("10 % 4"), 2.0);
+        assert_eq!(value("0xFF + 1_000"), 1255.0);
+        assert!(evaluate("1 / 0").is_err());
+        assert!(evaluate("(1 + 2").is_err());
+        assert!(evaluate("1 +").is_err());
+        assert!(evaluate("3 apples").is_err());
+    }
+
+    #[test]
+    fn test_units() {
+        assert_eq!(value("1.5GiB"), 1.5 * 1024.0 * 1024.0 * 1024.0);
+        assert_eq!(value("2 MB"), 2e6);
+        assert_eq!(value("1K + 1kb"), 2024.0);
+        assert!(evaluate("4 GiB").unwrap().bytes);
+        // Size / size is a plain ratio
+        let ratio = evaluate("1 TB / 500 GB").unwrap();
+        assert_eq!((ratio.value, ratio.bytes), (2.0, false));
+
+        let converted = evaluate("1 GiB in MB").unwrap();
+        assert_eq!(describe(&converted), "= 1073.741824 MB");
+        assert_eq!(as_input(&converted), "1073.741824MB");
+        assert!(evaluate("1 GiB in apples").is_err());
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(&evaluate("255").unwrap()), "= 255 (0xFF)");
+        assert_eq!(describe(&evaluate("1/4").unwrap()), "= 0.25");
+        let size = describe(&evaluate("1536 KiB").unwrap());
+        assert!(size.ends_with("B = 1.5 MiB = 1.57 MB"), "{}", size);
+    }
+}
@@ -1,4 +1,8 @@
 // === UTF-8 safe string slicing utilities ===
+use std::sync::RwLock;
+
+use chrono::{DateTime, Local, TimeZone};
+use serde::{Deserialize, Serialize};
 use unicode_width::{UnicodeWidthStr, UnicodeWidthChar};
 
 /// Byte index를 가장 가까운 char boundary로 내림
@@ -52,20 +56,370 @@ pub fn safe_truncate(s: &mut String, max_bytes: usize) {
     }
 }
 
+// === Locale-aware formatting of sizes, numbers and dates ===
+//
+// `settings.format` picks the units, clock, date order and digit grouping;
+// "auto" values follow the locale (LC_ALL, then LC_TIME / LC_NUMERIC, then
+// LANG). Until `configure` is called the plain defaults apply: 1024-based
+// units, 24-hour clock, year-month-day dates and no digit grouping.
+
+/// Units for file sizes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeUnits {
+    /// 1 KB = 1024 bytes
+    #[default]
+    Binary,
+    /// 1 kB = 1000 bytes
+    Si,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClockStyle {
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "24h")]
+    H24,
+    #[serde(rename = "12h")]
+    H12,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateOrder {
+    #[default]
+    Auto,
+    /// 2024-01-31
+    Ymd,
+    /// 31.01.2024 (31/01/2024 in English, French, Spanish, ...)
+    Dmy,
+    /// 01/31/2024
+    Mdy,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DigitGrouping {
+    #[default]
+    Auto,
+    /// 1234567.5
+    #[serde(rename = "none")]
+    Plain,
+    /// 1,234,567.5
+    Comma,
+    /// 1.234.567,5
+    Period,
+    /// 1 234 567,5
+    Space,
+}
+
+/// `settings.format`
+/// Example: {"size_units": "si", "clock": "12h", "date_order": "dmy", "digit_grouping": "auto"}
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatSettings {
+    pub size_units: SizeUnits,
+    pub clock: ClockStyle,
+    pub date_order: DateOrder,
+    pub digit_grouping: DigitGrouping,
+}
+
+/// Formatting rules with "auto" settings resolved against the locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub si_units: bool,
+    pub twelve_hour: bool,
+    /// Never `DateOrder::Auto`
+    pub date_order: DateOrder,
+    pub date_separator: char,
+    pub thousands_separator: Option<char>,
+    pub decimal_separator: char,
+}
+
+/// Languages writing day-month-year dates with slashes (others use dots)
+const SLASH_DATE_LANGUAGES: &[&str] = &["en", "fr", "es", "it", "pt", "ca", "el", "vi", "id"];
+/// Languages grouping digits as 1.234,5
+const PERIOD_GROUPING_LANGUAGES: &[&str] = &[
+    "de", "es", "it", "nl", "pt", "da", "id", "tr", "el", "ro", "hr", "sl", "sr", "vi", "ca",
+];
+/// Languages grouping digits as 1 234,5
+const SPACE_GROUPING_LANGUAGES: &[&str] = &[
+    "fr", "ru", "pl", "cs", "sk", "fi", "sv", "nb", "nn", "no", "uk", "bg", "hu", "lt", "lv", "et", "be", "kk",
+];
+/// Languages writing year-month-day dates
+const YMD_LANGUAGES: &[&str] = &["ko", "ja", "zh", "hu", "lt", "mn", "sv"];
+/// Territories with a 12-hour clock
+const TWELVE_HOUR_TERRITORIES: &[&str] = &["US", "AU", "NZ", "PH", "IN", "PK", "EG"];
+
+/// Language and territory of a locale name like "de_DE.UTF-8@euro"
+/// ("" for C/POSIX or unset)
+fn parse_locale(locale: &str) -> (String, String) {
+    let name = locale.split(['.', '@']).next().unwrap_or("");
+    if name.is_empty() || name == "C" || name == "POSIX" {
+        return (String::new(), String::new());
+    }
+    let (language, territory) = name.split_once(['_', '-']).unwrap_or((name, ""));
+    (language.to_ascii_lowercase(), territory.to_ascii_uppercase())
+}
+
+/// Locale for a category: LC_ALL, then the category (LC_TIME, LC_NUMERIC), then LANG
+fn locale_env(category: &str) -> String {
+    ["LC_ALL", category, "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default()
+}
+
+impl FormatOptions {
+    pub const DEFAULT: Self = Self {
+        si_units: false,
+        twelve_hour: false,
+        date_order: DateOrder::Ymd,
+        date_separator: '-',
+        thousands_separator: None,
+        decimal_separator: '.',
+    };
+
+    /// Resolve `settings` with the time and numeric locales (e.g. "en_US.UTF-8")
+    pub fn resolve(settings: &FormatSettings, time_locale: &str, numeric_locale: &str) -> Self {
+        let (time_lang, time_territory) = parse_locale(time_locale);
+        let (num_lang, _) = parse_locale(numeric_locale);
+
+        let twelve_hour = match settings.clock {
+            ClockStyle::H24 => false,
+            ClockStyle::H12 => true,
+            ClockStyle::Auto => TWELVE_HOUR_TERRITORIES.contains(&time_territory.as_str()),
+        };
+        let date_order = match settings.date_order {
+            DateOrder::Auto if time_lang.is_empty() => DateOrder::Ymd,
+            DateOrder::Auto if time_territory == "US" || time_territory == "PH" => DateOrder::Mdy,
+            DateOrder::Auto if YMD_LANGUAGES.contains(&time_lang.as_str()) => DateOrder::Ymd,
+            DateOrder::Auto => DateOrder::Dmy,
+            explicit => explicit,
+        };
+        let date_separator = match date_order {
+            DateOrder::Mdy => '/',
+            DateOrder::Dmy if SLASH_DATE_LANGUAGES.contains(&time_lang.as_str()) => '/',
+            DateOrder::Dmy => '.',
+            _ => '-',
+        };
+        let grouping = match settings.digit_grouping {
+            DigitGrouping::Auto if num_lang.is_empty() => DigitGrouping::Plain,
+            DigitGrouping::Auto if PERIOD_GROUPING_LANGUAGES.contains(&num_lang.as_str()) => DigitGrouping::Period,
+            DigitGrouping::Auto if SPACE_GROUPING_LANGUAGES.contains(&num_lang.as_str()) => DigitGrouping::Space,
+            DigitGrouping::Auto => DigitGrouping::Comma,
+            explicit => explicit,
+        };
+        let (thousands_separator, decimal_separator) = match grouping {
+            DigitGrouping::Comma => (Some(','), '.'),
+            DigitGrouping::Period => (Some('.'), ','),
+            DigitGrouping::Space => (Some(' '), ','),
+            _ => (None, '.'),
+        };
+
+        Self {
+            si_units: settings.size_units == SizeUnits::Si,
+            twelve_hour,
+            date_order,
+            date_separator,
+            thousands_separator,
+            decimal_separator,
+        }
+    }
+
+    /// Whole number with the thousands separator
+    pub fn number(&self, n: u64) -> String {
+        let digits = n.to_string();
+        let Some(separator) = self.thousands_separator else { return digits };
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                out.push(separator);
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    /// One decimal place, with the locale's separators
+    fn decimal(&self, value: f64) -> String {
+        let text = format!("{:.1}", value);
+        let (whole, fraction) = text.split_once('.').unwrap_or((&text, "0"));
+        let whole = whole.parse::<u64>().map(|n| self.number(n)).unwrap_or_else(|_| whole.to_string());
+        format!("{}{}{}", whole, self.decimal_separator, fraction)
+    }
+
+    /// File size in B, KB, MB or GB (kB/MB/GB with SI units)
+    pub fn size(&self, bytes: u64) -> String {
+        let (base, units) = if self.si_units {
+            (1000.0, ["kB", "MB", "GB"])
+        } else {
+            (1024.0, ["KB", "MB", "GB"])
+        };
+        let mut value = bytes as f64;
+        if value < base {
+            return format!("{} B", self.number(bytes));
+        }
+        let mut unit = 0;
+        value /= base;
+        while value >= base && unit < units.len() - 1 {
+            value /= base;
+            unit += 1;
+        }
+        format!("{} {}", self.decimal(value), units[unit])
+    }
+
+    fn date_pattern(&self, with_year: bool) -> String {
+        let s = self.date_separator;
+        match (self.date_order, with_year) {
+            (DateOrder::Dmy, true) => format!("%d{s}%m{s}%Y"),
+            (DateOrder::Dmy, false) => format!("%d{s}%m"),
+            (DateOrder::Mdy, true) => format!("%m{s}%d{s}%Y"),
+            (DateOrder::Mdy, false) => format!("%m{s}%d"),
+            (_, true) => format!("%Y{s}%m{s}%d"),
+            (_, false) => format!("%m{s}%d"),
+        }
+    }
+
+    /// Date and time, e.g. "2024-01-31 14:05" or "01/31/2024 02:05 PM"
+    pub fn datetime<Tz: TimeZone>(&self, dt: &DateTime<Tz>, seconds: bool) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        let time = match (self.twelve_hour, seconds) {
+            (false, false) => "%H:%M",
+            (false, true) => "%H:%M:%S",
+            (true, false) => "%I:%M %p",
+            (true, true) => "%I:%M:%S %p",
+        };
+        dt.format(&format!("{} {}", self.date_pattern(true), time)).to_string()
+    }
+
+    /// Compact date and time without the year for list columns,
+    /// e.g. "01-31 14:05" or "31.01 02:05PM"
+    pub fn short_datetime<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        let time = if self.twelve_hour { "%I:%M%p" } else { "%H:%M" };
+        dt.format(&format!("{} {}", self.date_pattern(false), time)).to_string()
+    }
+}
+
+static OPTIONS: RwLock<FormatOptions> = RwLock::new(FormatOptions::DEFAULT);
+
+/// Apply `settings.format` (at startup and when settings are reloaded)
+pub fn configure(settings: &FormatSettings) {
+    let options = FormatOptions::resolve(settings, &locale_env("LC_TIME"), &locale_env("LC_NUMERIC"));
+    *OPTIONS.write().unwrap_or_else(|e| e.into_inner()) = options;
+}
+
+/// Formatting rules in effect
+pub fn options() -> FormatOptions {
+    *OPTIONS.read().unwrap_or_else(|e| e.into_inner())
+}
+
 /// Format file size in human-readable format
 pub fn format_size(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-
-    if bytes < KB {
-        format!("{} B", bytes)
-    } else if bytes < MB {
-        format!("{:.1} KB", bytes as f64 / KB as f64)
-    } else if bytes < GB {
-        format!("{:.1} MB", bytes as f64 / MB as f64)
+    options().size(bytes)
+}
+
+/// Format a count with the locale's thousands separator
+pub fn format_number(n: u64) -> String {
+    options().number(n)
+}
+
+/// Format a date and time (optionally with seconds) in the configured order and clock
+pub fn format_datetime(dt: &DateTime<Local>, seconds: bool) -> String {
+    options().datetime(dt, seconds)
+}
+
+/// Format a date and time without the year, for list columns
+pub fn format_short_datetime(dt: &DateTime<Local>) -> String {
+    options().short_datetime(dt)
+}
+
+/// Width of `format_datetime` output (all fields are zero-padded)
+pub fn datetime_width(seconds: bool) -> usize {
+    format_datetime(&sample_datetime(), seconds).width()
+}
+
+/// Width of `format_short_datetime` output
+pub fn short_datetime_width() -> usize {
+    format_short_datetime(&sample_datetime()).width()
+}
+
+fn sample_datetime() -> DateTime<Local> {
+    Local.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).earliest().unwrap_or_else(Local::now)
+}
+
+/// Format a duration as "m:ss", or "h:mm:ss" from one hour on
+pub fn format_duration(secs: u64) -> String {
+    let (h, m, s) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
     } else {
-        format!("{:.1} GB", bytes as f64 / GB as f64)
+        format!("{}:{:02}", m, s)
+    }
+}
+
+/// Format an age in seconds as "just now", "5m ago", "3h ago", "2d ago" or "6w ago"
+pub fn format_relative_age(secs: u64) -> String {
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86399 => format!("{}h ago", secs / 3600),
+        86400..=1209599 => format!("{}d ago", secs / 86400),
+        _ => format!("{}w ago", secs / 604800),
+    }
+}
+
+/// Parse a size like "500", "10K", "1.5M" or "2GB" (1024-based units)
+pub fn parse_size(s: &str) -> Option<u64> {
+    let upper = s.trim().to_uppercase();
+    let upper = upper.strip_suffix('B').unwrap_or(&upper);
+    let split = upper.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(upper.len());
+    let (num, unit) = upper.split_at(split);
+    let num: f64 = num.parse().ok()?;
+    let multiplier: u64 = match unit.trim() {
+        "" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        "T" => 1024 * 1024 * 1024 * 1024,
+        _ => return None,
+    };
+    Some((num * multiplier as f64) as u64)
+}
+
+/// Parse an age like "30s", "15m", "2h", "7d" or "2w" into seconds (days without a unit)
+pub fn parse_age(s: &str) -> Option<u64> {
+    let lower = s.trim().to_lowercase();
+    let split = lower.find(|c: char| !c.is_ascii_digit()).unwrap_or(lower.len());
+    let (num, unit) = lower.split_at(split);
+    let num: u64 = num.parse().ok()?;
+    let multiplier: u64 = match unit.trim() {
+        "s" => 1,
+        "m" | "min" => 60,
+        "h" => 3600,
+        "" | "d" => 86400,
+        "w" => 7 * 86400,
+        _ => return None,
+    };
+    num.checked_mul(multiplier)
+}
+
+/// Quote a string for POSIX sh. Plain words (letters, digits, `-_./=:,+@%`)
+/// are returned unchanged, everything else is single-quoted.
+pub fn shell_escape(s: &str) -> String {
+    let plain = !s.is_empty()
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+    if plain {
+        s.to_string()
+    } else {
+        format!("'{}'", s.replace('\'', "'\\''"))
     }
 }
 
@@ -200,7 +554,47 @@ mod tests {
         assert_eq!(format_size(1073741824), "1.0 GB");
     }
 
+    #[test]
+    fn test_parse_size_and_age() {
+        assert_eq!(parse_size("500"), Some(500));
+        assert_eq!(parse_size("10k"), Some(10 * 1024));
+        assert_eq!(parse_size("1.5M"), Some(1536 * 1024));
+        assert_eq!(parse_size("2GB"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("10x"), None);
+        assert_eq!(parse_age("30s"), Some(30));
+        assert_eq!(parse_age("15m"), Some(900));
+        assert_eq!(parse_age("2h"), Some(7200));
+        assert_eq!(parse_age("7"), Some(7 * 86400));
+        assert_eq!(parse_age("2w"), Some(14 * 86400));
+        assert_eq!(parse_age("d"), None);
+    }
+
+    #[test]
+    fn test_shell_escape() {
+        assert_eq!(shell_escape("/tmp/file.txt"), "/tmp/file.txt");
+        assert_eq!(shell_escape("a b"), "'a b'");
+        assert_eq!(shell_escape("it's"), "'it'\\''s'");
+        assert_eq!(shell_escape("$HOME"), "'$HOME'");
+        assert_eq!(shell_escape(""), "''");
+    }
+
     #[cfg(unix)]
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0:00");
+        assert_eq!(format_duration(65), "1:05");
+        assert_eq!(format_duration(3723), "1:02:03");
+    }
+
+    #[test]
+    fn test_format_relative_age() {
+        assert_eq!(format_relative_age(5), "just now");
+        assert_eq!(format_relative_age(125), "2m ago");
+        assert_eq!(format_relative_age(3 * 3600 + 10), "3h ago");
+        assert_eq!(format_relative_age(2 * 86400), "2d ago");
+        assert_eq!(format_relative_age(30 * 86400), "4w ago");
+    }
+
     #[test]
     fn test_format_permissions_short() {
         assert_eq!(format_permissions_short(0o755), "rwxr-xr-x");
@@ -246,4 +640,100 @@ mod tests {
         // CJK: "한글test" → 뒤에서 5칸 = "test" (4칸)... '글'=2칸 넣으면 6칸 초과 → "test"
         assert_eq!(display_width_suffix("한글test", 5), "ltest");
     }
+
+    #[test]
+    fn test_format_options_resolve_locales() {
+        let auto = FormatSettings::default();
+
+        assert_eq!(FormatOptions::resolve(&auto, "C", "C"), FormatOptions::DEFAULT);
+        assert_eq!(FormatOptions::resolve(&auto, "", ""), FormatOptions::DEFAULT);
+
+        let us = FormatOptions::resolve(&auto, "en_US.UTF-8", "en_US.UTF-8");
+        assert!(us.twelve_hour);
+        assert_eq!((us.date_order, us.date_separator), (DateOrder::Mdy, '/'));
+        assert_eq!((us.thousands_separator, us.decimal_separator), (Some(','), '.'));
+
+        let de = FormatOptions::resolve(&auto, "de_DE.UTF-8@euro", "de_DE.UTF-8");
+        assert!(!de.twelve_hour);
+        assert_eq!((de.date_order, de.date_separator), (DateOrder::Dmy, '.'));
+        assert_eq!((de.thousands_separator, de.decimal_separator), (Some('.'), ','));
+
+        let fr = FormatOptions::resolve(&auto, "fr_FR.UTF-8", "fr_FR.UTF-8");
+        assert_eq!((fr.date_order, fr.date_separator), (DateOrder::Dmy, '/'));
+        assert_eq!(fr.thousands_separator, Some(' '));
+
+        let ko = FormatOptions::resolve(&auto, "ko_KR.UTF-8", "ko_KR.UTF-8");
+        assert_eq!((ko.date_order, ko.date_separator), (DateOrder::Ymd, '-'));
+    }
+
+    #[test]
+    fn test_format_options_explicit_settings_override_locale() {
+        let settings = FormatSettings {
+            size_units: SizeUnits::Si,
+            clock: ClockStyle::H24,
+            date_order: DateOrder::Ymd,
+            digit_grouping: DigitGrouping::Plain,
+        };
+        let options = FormatOptions::resolve(&settings, "en_US.UTF-8", "en_US.UTF-8");
+        assert!(options.si_units);
+        assert!(!options.twelve_hour);
+        assert_eq!(options.date_order, DateOrder::Ymd);
+        assert_eq!(options.thousands_separator, None);
+    }
+
+    #[test]
+    fn test_format_options_number_and_size() {
+        let plain = FormatOptions::DEFAULT;
+        assert_eq!(plain.number(1234567), "1234567");
+        assert_eq!(plain.size(512), "512 B");
+        assert_eq!(plain.size(1536), "1.5 KB");
+        assert_eq!(plain.size(5 * 1024 * 1024), "5.0 MB");
+
+        let si = FormatOptions { si_units: true, ..plain };
+        assert_eq!(si.size(1500), "1.5 kB");
+        assert_eq!(si.size(1000), "1.0 kB");
+        assert_eq!(si.size(2_500_000_000), "2.5 GB");
+
+        let comma = FormatOptions { thousands_separator: Some(','), ..plain };
+        assert_eq!(comma.number(0), "0");
+        assert_eq!(comma.number(999), "999");
+        assert_eq!(comma.number(1000), "1,000");
+        assert_eq!(comma.number(1234567), "1,234,567");
+
+        let period = FormatOptions { thousands_separator: Some('.'), decimal_separator: ',', ..plain };
+        assert_eq!(period.size(1536), "1,5 KB");
+        assert_eq!(period.size(1023), "1.023 B");
+    }
+
+    #[test]
+    fn test_format_options_datetime() {
+        let dt = Local.with_ymd_and_hms(2024, 1, 31, 14, 5, 9).earliest().unwrap();
+
+        let plain = FormatOptions::DEFAULT;
+        assert_eq!(plain.datetime(&dt, false), "2024-01-31 14:05");
+        assert_eq!(plain.datetime(&dt, true), "2024-01-31 14:05:09");
+        assert_eq!(plain.short_datetime(&dt), "01-31 14:05");
+
+        let us = FormatOptions { twelve_hour: true, date_order: DateOrder::Mdy, date_separator: '/', ..plain };
+        assert_eq!(us.datetime(&dt, false), "01/31/2024 02:05 PM");
+        assert_eq!(us.short_datetime(&dt), "01/31 02:05PM");
+
+        let de = FormatOptions { date_order: DateOrder::Dmy, date_separator: '.', ..plain };
+        assert_eq!(de.datetime(&dt, true), "31.01.2024 14:05:09");
+        assert_eq!(de.short_datetime(&dt), "31.01 14:05");
+    }
+
+    #[test]
+    fn test_format_settings_serde() {
+        let settings: FormatSettings =
+            serde_json::from_str(r#"{"size_units": "si", "clock": "24h", "digit_grouping": "none"}"#).unwrap();
+        assert_eq!(settings.size_units, SizeUnits::Si);
+        assert_eq!(settings.clock, ClockStyle::H24);
+        assert_eq!(settings.date_order, DateOrder::Auto);
+        assert_eq!(settings.digit_grouping, DigitGrouping::Plain);
+
+        let json = serde_json::to_string(&FormatSettings::default()).unwrap();
+        assert!(json.contains(r#""clock":"auto""#));
+        assert!(json.contains(r#""size_units":"binary""#));
+    }
 }
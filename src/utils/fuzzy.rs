@@ -0,0 +1,30 @@
+//! Subsequence (fuzzy) matching shared by the list filters and frecency lookups.
+
+/// Fuzzy match: check if all characters in pattern appear in text in order
+/// e.g., "thse" matches "/path/to/base" (t-h-s-e appear in sequence)
+pub fn fuzzy_match(text: &str, pattern: &str) -> bool {
+    let mut text_chars = text.chars().peekable();
+    for pattern_char in pattern.chars() {
+        loop {
+            match text_chars.next() {
+                Some(c) if c == pattern_char => break,
+                Some(_) => continue,
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("/path/to/base", "thse"));
+        assert!(fuzzy_match("anything", ""));
+        assert!(!fuzzy_match("/path/to/base", "esab"));
+        assert!(!fuzzy_match("Base", "base"));
+    }
+}
@@ -0,0 +1,77 @@
+//! Wildcard file name matching and `~` expansion for patterns and folders
+//! entered by the user (watch rules, exclude lists, download rules, ...).
+
+use std::path::PathBuf;
+
+/// Expand a leading `~` to the home directory
+pub fn expand_home(path: &str) -> PathBuf {
+    if let Some(rest) = path.strip_prefix('~') {
+        if let Some(home) = dirs::home_dir() {
+            let rest = rest.strip_prefix('/').unwrap_or(rest);
+            return if rest.is_empty() { home } else { home.join(rest) };
+        }
+    }
+    PathBuf::from(path)
+}
+
+/// Match `name` against a wildcard pattern (`*` = any run, `?` = one character).
+/// With `case_sensitive` false both sides are lowercased first, so `*.pdf`
+/// also matches `REPORT.PDF`; there are no character classes or escapes.
+pub fn glob_match(pattern: &str, name: &str, case_sensitive: bool) -> bool {
+    let fold = |s: &str| if case_sensitive { s.to_string() } else { s.to_lowercase() };
+    let pattern: Vec<char> = fold(pattern).chars().collect();
+    let name: Vec<char> = fold(name).chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Position of the last '*' and the name index it was tried at, for backtracking
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((sp, sn)) = star {
+            p = sp + 1;
+            n = sn + 1;
+            star = Some((sp, sn + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.pdf", "report.pdf", false));
+        assert!(glob_match("*.pdf", "Report.PDF", false));
+        assert!(!glob_match("*.pdf", "report.pdf.part", false));
+        assert!(glob_match("img_??.*", "img_01.jpg", false));
+        assert!(!glob_match("img_??.*", "img_1.jpg", false));
+        assert!(glob_match("*", "anything", false));
+        assert!(glob_match("a*b*c", "aXXbYYc", false));
+        assert!(!glob_match("a*b*c", "aXXbYY", false));
+    }
+
+    #[test]
+    fn test_glob_match_case_sensitive() {
+        assert!(glob_match("*.pdf", "report.pdf", true));
+        assert!(!glob_match("*.pdf", "Report.PDF", true));
+        assert!(glob_match("Make*", "Makefile", true));
+        assert!(!glob_match("make*", "Makefile", true));
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home("~/Downloads"), home.join("Downloads"));
+        assert_eq!(expand_home("/tmp/~x"), PathBuf::from("/tmp/~x"));
+    }
+}
@@ -1,2 +1,6 @@
+pub mod calc;
 pub mod format;
+pub mod fuzzy;
+pub mod glob;
 pub mod markdown;
+pub mod osc52;
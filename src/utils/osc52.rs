@@ -0,0 +1,90 @@
+//! System clipboard via OSC 52 escape sequence.
+//!
+//! The terminal emulator sets the clipboard itself, so this works over SSH
+//! and inside tmux/screen (wrapped in a DCS passthrough) without any
+//! clipboard tool installed on the host.
+
+use std::io::{self, Write};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+
+use crate::utils::format::{format_size, safe_prefix};
+
+/// Maximum payload copied in one sequence. Many terminals drop sequences
+/// longer than ~100KB of base64, so larger contents are truncated.
+pub const MAX_COPY_BYTES: usize = 74_994;
+
+/// Result of a clipboard copy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyResult {
+    /// Bytes actually sent to the clipboard
+    pub copied: usize,
+    /// Size of the original text
+    pub total: usize,
+}
+
+impl CopyResult {
+    pub fn truncated(&self) -> bool {
+        self.copied < self.total
+    }
+
+    /// Status message for the user, warning when the content was truncated
+    pub fn message(&self, what: &str) -> String {
+        if self.truncated() {
+            format!(
+                "{} truncated: copied first {} of {} (clipboard limit)",
+                what,
+                format_size(self.copied as u64),
+                format_size(self.total as u64)
+            )
+        } else {
+            format!("{} copied to clipboard ({})", what, format_size(self.copied as u64))
+        }
+    }
+}
+
+/// Build the OSC 52 sequence for `text`, wrapped for tmux or GNU screen
+fn sequence(text: &str, in_tmux: bool, in_screen: bool) -> String {
+    let osc = format!("\x1b]52;c;{}\x07", BASE64.encode(text.as_bytes()));
+    if in_tmux {
+        // tmux passthrough: ESC inside the payload must be doubled
+        format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+    } else if in_screen {
+        format!("\x1bP{}\x1b\\", osc)
+    } else {
+        osc
+    }
+}
+
+/// Copy text to the system clipboard, truncating at `MAX_COPY_BYTES`
+pub fn copy(text: &str) -> io::Result<CopyResult> {
+    let payload = safe_prefix(text, MAX_COPY_BYTES);
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    let in_screen = std::env::var("TERM").map(|t| t.starts_with("screen")).unwrap_or(false) && !in_tmux;
+    let mut out = io::stdout();
+    out.write_all(sequence(payload, in_tmux, in_screen).as_bytes())?;
+    out.flush()?;
+    Ok(CopyResult { copied: payload.len(), total: text.len() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_encoding() {
+        assert_eq!(sequence("hello", false, false), "\x1b]52;c;aGVsbG8=\x07");
+        assert_eq!(sequence("hello", true, false), "\x1bPtmux;\x1b\x1b]52;c;aGVsbG8=\x07\x1b\\");
+        assert_eq!(sequence("hello", false, true), "\x1bP\x1b]52;c;aGVsbG8=\x07\x1b\\");
+    }
+
+    #[test]
+    fn test_copy_result_truncated() {
+        let result = CopyResult { copied: MAX_COPY_BYTES, total: MAX_COPY_BYTES + 1 };
+        assert!(result.truncated());
+        assert!(result.message("File").contains("truncated"));
+        let result = CopyResult { copied: 10, total: 10 };
+        assert!(!result.truncated());
+        assert!(result.message("File").starts_with("File copied to clipboard"));
+    }
+}